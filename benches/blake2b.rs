@@ -0,0 +1,66 @@
+#![allow(non_snake_case)]
+#![cfg(feature = "blake2b")]
+
+mod util;
+use util::core_cycles;
+
+use crrl::blake2b::Blake2b512;
+
+fn bench_blake2b_short() -> (f64, u8) {
+    let z = core_cycles();
+    let mut seed = [0u8; 64];
+    for i in 0..8 {
+        seed[(8 * i)..(8 * i + 8)].copy_from_slice(&z.to_le_bytes());
+    }
+    let mut tt = [0; 100];
+    let mut sh = Blake2b512::new();
+    for i in 0..(tt.len() + 1000) {
+        let begin = core_cycles();
+        for _ in 0..100 {
+            sh.update(&seed);
+            sh.finalize_reset_write(&mut seed);
+        }
+        let end = core_cycles();
+        if i >= 1000 {
+            tt[i - 1000] = end.wrapping_sub(begin);
+        }
+    }
+    tt.sort();
+    ((tt[tt.len() >> 1] as f64) / 100.0, seed[0])
+}
+
+fn bench_blake2b_4096() -> (f64, u8) {
+    let z = core_cycles();
+    let mut sh = Blake2b512::new();
+    let mut buf = [0u8; 4096];
+    for i in 0..(buf.len() >> 6) {
+        sh.update(&z.to_le_bytes());
+        sh.update(&(i as u64).to_le_bytes());
+        sh.finalize_reset_write(&mut buf[(i << 6)..]);
+    }
+    let mut tt = [0; 100];
+    for i in 0..tt.len() {
+        let begin = core_cycles();
+        for _ in 0..(buf.len() >> 6) {
+            sh.update(&buf);
+            sh.finalize_reset_write(&mut buf[((i & 63) << 6)..]);
+        }
+        let end = core_cycles();
+        tt[i] = end.wrapping_sub(begin);
+    }
+    tt.sort();
+    ((tt[tt.len() >> 1] as f64) / ((buf.len() >> 6) as f64), buf[0])
+}
+
+fn main() {
+    let mut bx = 0u8;
+
+    let (v, x) = bench_blake2b_short();
+    bx ^= x;
+    println!("BLAKE2b (short):               {:13.2}", v);
+    let (v, x) = bench_blake2b_4096();
+    bx ^= x;
+    println!("BLAKE2b (4096 bytes):          {:13.2}", v);
+
+    println!("{}", bx);
+}
@@ -4,7 +4,7 @@
 mod util;
 use util::core_cycles;
 
-use crrl::blake2s::Blake2s256;
+use crrl::blake2s::{Blake2s, Blake2s256};
 
 fn bench_blake2s_short() -> (f64, u8) {
     let z = core_cycles();
@@ -53,6 +53,36 @@ fn bench_blake2s_4096() -> (f64, u8) {
     ((tt[tt.len() >> 1] as f64) / ((buf.len() >> 5) as f64), buf[0])
 }
 
+fn bench_blake2s_x8_64() -> (f64, u8) {
+    let z = core_cycles();
+    let mut buf = [[0u8; 64]; 8];
+    for (i, b) in buf.iter_mut().enumerate() {
+        b[..8].copy_from_slice(&z.to_le_bytes());
+        b[8] = i as u8;
+    }
+    let mut out = [[0u8; 32]; 8];
+    let mut tt = [0; 100];
+    for i in 0..(tt.len() + 1000) {
+        let begin = core_cycles();
+        for _ in 0..100 {
+            let mm: [&[u8]; 8] = [
+                &buf[0], &buf[1], &buf[2], &buf[3],
+                &buf[4], &buf[5], &buf[6], &buf[7],
+            ];
+            Blake2s::hash_x8(32, &mm, &mut out);
+            for j in 0..8 {
+                buf[j][..32].copy_from_slice(&out[j]);
+            }
+        }
+        let end = core_cycles();
+        if i >= 1000 {
+            tt[i - 1000] = end.wrapping_sub(begin);
+        }
+    }
+    tt.sort();
+    ((tt[tt.len() >> 1] as f64) / 800.0, out[0][0])
+}
+
 fn main() {
     let mut bx = 0u8;
 
@@ -62,6 +92,9 @@ fn main() {
     let (v, x) = bench_blake2s_4096();
     bx ^= x;
     println!("BLAKE2s (4096 bytes):          {:13.2}", v);
+    let (v, x) = bench_blake2s_x8_64();
+    bx ^= x;
+    println!("BLAKE2s x8 (64 bytes, per msg):{:13.2}", v);
 
     println!("{}", bx);
 }
@@ -4,7 +4,7 @@
 mod util;
 use util::core_cycles;
 
-use crrl::secp256k1::{Point, Scalar, PrivateKey};
+use crrl::secp256k1::{Point, Scalar, PrivateKey, XOnlyPublicKey};
 use sha2::{Sha256, Digest};
 
 fn bench_mulgen() -> (f64, u8) {
@@ -156,6 +156,63 @@ fn bench_pkey_verify() -> (f64, u8) {
     ((tt[tt.len() >> 1] as f64) / 128.0, msg[0])
 }
 
+fn bench_schnorr_sign() -> (f64, u8) {
+    let z = core_cycles();
+    let mut sh = Sha256::new();
+    sh.update(&z.to_le_bytes());
+    let seed = sh.finalize_reset();
+    let skey = PrivateKey::from_seed(&seed);
+    let mut tt = [0; 100];
+    let mut msg = [0u8; 32];
+    for i in 0..tt.len() {
+        let begin = core_cycles();
+        for _ in 0..100 {
+            let sig = skey.sign_schnorr(&msg, &[0u8; 32]);
+            msg[..].copy_from_slice(&sig[0..32]);
+        }
+        let end = core_cycles();
+        tt[i] = end.wrapping_sub(begin);
+    }
+    tt.sort();
+    ((tt[tt.len() >> 1] as f64) / 100.0, msg[0])
+}
+
+fn bench_schnorr_verify(batch: bool) -> (f64, u8) {
+    let z = core_cycles();
+    let mut sh = Sha256::new();
+    sh.update(&z.to_le_bytes());
+    let seed = sh.finalize_reset();
+    let skey = PrivateKey::from_seed(&seed);
+    let pkey = skey.to_xonly_public_key();
+    let mut sigs = [[0u8; 64]; 64];
+    let mut msgs = [[0u8; 32]; 64];
+    for i in 0..64 {
+        msgs[i] = [i as u8; 32];
+        sigs[i] = skey.sign_schnorr(&msgs[i], &[0u8; 32]);
+    }
+    let pks = [pkey; 64];
+    let mut tt = [0; 100];
+    let mut x = 0u8;
+    for i in 0..tt.len() {
+        let begin = core_cycles();
+        if batch {
+            let sr: [&[u8]; 64] = core::array::from_fn(|j| &sigs[j][..]);
+            let mr: [&[u8]; 64] = core::array::from_fn(|j| &msgs[j][..]);
+            let ff = XOnlyPublicKey::verify_batch(&pks, &sr, &mr);
+            x ^= ff as u8;
+        } else {
+            for j in 0..64 {
+                let ff = pkey.verify(&sigs[j], &msgs[j]);
+                x ^= ff as u8;
+            }
+        }
+        let end = core_cycles();
+        tt[i] = end.wrapping_sub(begin);
+    }
+    tt.sort();
+    ((tt[tt.len() >> 1] as f64) / 64.0, x)
+}
+
 fn main() {
     let mut bx = 0u8;
 
@@ -174,6 +231,15 @@ fn main() {
     let (v, x) = bench_pkey_verify();
     bx ^= x;
     println!("secp256k1 verify:              {:13.2}", v);
+    let (v, x) = bench_schnorr_sign();
+    bx ^= x;
+    println!("secp256k1 schnorr sign:        {:13.2}", v);
+    let (v, x) = bench_schnorr_verify(false);
+    bx ^= x;
+    println!("secp256k1 schnorr verify:      {:13.2}", v);
+    let (v, x) = bench_schnorr_verify(true);
+    bx ^= x;
+    println!("secp256k1 schnorr batch (/sig):{:13.2}", v);
 
     println!("{}", bx);
 }
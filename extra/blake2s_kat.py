@@ -0,0 +1,142 @@
+#! /usr/bin/env python3
+
+# Generates the BLAKE2sp and BLAKE2Xs known-answer values used by the
+# tests in src/blake2s.rs. The input and key follow the convention of the
+# BLAKE2 reference test vectors: the input is the sequence of bytes
+# 00 01 02 .. (modulo 256), and the key is 00 01 02 .. 1F.
+#
+# BLAKE2sp leaf nodes and the initial BLAKE2Xs hash use Python's
+# hashlib.blake2s, which exposes the tree hashing parameters. Two
+# parameter blocks cannot be expressed with hashlib: the BLAKE2sp root
+# node has a nonzero key length but does not process a key block, and
+# the BLAKE2Xs output blocks use a depth of 0. These are computed with
+# the small BLAKE2s implementation below, which takes an explicit
+# parameter block and is checked against hashlib.blake2s first.
+
+import hashlib
+
+IV = [
+    0x6A09E667, 0xBB67AE85, 0x3C6EF372, 0xA54FF53A,
+    0x510E527F, 0x9B05688C, 0x1F83D9AB, 0x5BE0CD19,
+]
+
+SIGMA = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    [14, 10, 4, 8, 9, 15, 13, 6, 1, 12, 0, 2, 11, 7, 5, 3],
+    [11, 8, 12, 0, 5, 2, 15, 13, 10, 14, 3, 6, 7, 1, 9, 4],
+    [7, 9, 3, 1, 13, 12, 11, 14, 2, 6, 5, 10, 4, 0, 15, 8],
+    [9, 0, 5, 7, 2, 4, 10, 15, 14, 1, 11, 12, 6, 8, 3, 13],
+    [2, 12, 6, 10, 0, 11, 8, 3, 4, 13, 7, 5, 15, 14, 1, 9],
+    [12, 5, 1, 15, 14, 13, 4, 10, 0, 7, 6, 3, 9, 2, 8, 11],
+    [13, 11, 7, 14, 12, 1, 3, 9, 5, 0, 15, 4, 8, 6, 2, 10],
+    [6, 15, 14, 9, 11, 3, 0, 8, 12, 2, 13, 7, 1, 4, 10, 5],
+    [10, 2, 8, 4, 7, 6, 1, 5, 15, 11, 9, 14, 3, 12, 13, 0],
+]
+
+M32 = 0xFFFFFFFF
+
+def ror(x, n):
+    return ((x >> n) | (x << (32 - n))) & M32
+
+def compress(h, block, t, last, last_node):
+    m = [int.from_bytes(block[4 * i:4 * i + 4], 'little') for i in range(16)]
+    v = h[:] + IV[:]
+    v[12] ^= t & M32
+    v[13] ^= t >> 32
+    if last:
+        v[14] ^= M32
+        if last_node:
+            v[15] ^= M32
+    def g(a, b, c, d, x, y):
+        v[a] = (v[a] + v[b] + x) & M32
+        v[d] = ror(v[d] ^ v[a], 16)
+        v[c] = (v[c] + v[d]) & M32
+        v[b] = ror(v[b] ^ v[c], 12)
+        v[a] = (v[a] + v[b] + y) & M32
+        v[d] = ror(v[d] ^ v[a], 8)
+        v[c] = (v[c] + v[d]) & M32
+        v[b] = ror(v[b] ^ v[c], 7)
+    for r in range(10):
+        s = SIGMA[r]
+        g(0, 4, 8, 12, m[s[0]], m[s[1]])
+        g(1, 5, 9, 13, m[s[2]], m[s[3]])
+        g(2, 6, 10, 14, m[s[4]], m[s[5]])
+        g(3, 7, 11, 15, m[s[6]], m[s[7]])
+        g(0, 5, 10, 15, m[s[8]], m[s[9]])
+        g(1, 6, 11, 12, m[s[10]], m[s[11]])
+        g(2, 7, 8, 13, m[s[12]], m[s[13]])
+        g(3, 4, 9, 14, m[s[14]], m[s[15]])
+    return [h[i] ^ v[i] ^ v[i + 8] for i in range(8)]
+
+def blake2s_param(param, data, last_node=False):
+    # BLAKE2s with an explicit 32-byte parameter block; no key block.
+    h = [IV[i] ^ int.from_bytes(param[4 * i:4 * i + 4], 'little')
+        for i in range(8)]
+    t = 0
+    while len(data) > 64:
+        t += 64
+        h = compress(h, data[:64], t, False, False)
+        data = data[64:]
+    t += len(data)
+    h = compress(h, data + bytes(64 - len(data)), t, True, last_node)
+    out = b''.join(x.to_bytes(4, 'little') for x in h)
+    return out[:param[0]]
+
+def param_block(outlen, keylen, fanout, depth, leaf_len, node_off,
+    node_depth, inner_len):
+    return (bytes([outlen, keylen, fanout, depth])
+        + leaf_len.to_bytes(4, 'little') + node_off.to_bytes(6, 'little')
+        + bytes([node_depth, inner_len]) + bytes(16))
+
+def self_check():
+    for n in (0, 1, 64, 65, 200):
+        d = bytes(i & 0xFF for i in range(n))
+        p = param_block(32, 0, 8, 2, 0, 3, 1, 32)
+        assert blake2s_param(p, d, True) == hashlib.blake2s(d, fanout=8,
+            depth=2, node_offset=3, node_depth=1, inner_size=32,
+            last_node=True).digest()
+        p = param_block(20, 0, 1, 1, 0, 0, 0, 0)
+        assert blake2s_param(p, d) == hashlib.blake2s(d,
+            digest_size=20).digest()
+
+def blake2sp(key, data):
+    # Same structure as blake2sp.c in the BLAKE2 reference code.
+    leaves = []
+    for i in range(8):
+        chunks = b''.join(data[j:j + 64]
+            for j in range(64 * i, len(data), 512))
+        leaves.append(hashlib.blake2s(chunks, key=key, fanout=8, depth=2,
+            node_offset=i, node_depth=0, inner_size=32,
+            last_node=(i == 7)).digest())
+    p = param_block(32, len(key), 8, 2, 0, 0, 1, 32)
+    return blake2s_param(p, b''.join(leaves), True)
+
+def blake2xs(outlen, key, data):
+    # BLAKE2X specification (BLAKE2 reference code, blake2xs.c); the
+    # XOF length occupies bytes 12-13 of the parameter block, i.e. the
+    # top 16 bits of the 48-bit node offset field of hashlib.
+    h0 = hashlib.blake2s(data, key=key, node_offset=(outlen << 32)).digest()
+    out = b''
+    i = 0
+    while len(out) < outlen:
+        ln = min(32, outlen - len(out))
+        p = param_block(ln, 0, 0, 0, 32, (outlen << 32) | i, 0, 32)
+        out += blake2s_param(p, h0)
+        i += 1
+    return out
+
+def main():
+    self_check()
+    key = bytes(range(32))
+    print('BLAKE2sp:')
+    for n in (0, 1, 2, 3, 31, 32, 63, 64, 65, 127, 128, 255, 511, 512,
+            513, 1000, 4096, 4097):
+        data = bytes(i & 0xFF for i in range(n))
+        print(n, blake2sp(key, data).hex())
+    print('BLAKE2Xs:')
+    data = bytes(range(256))
+    for n in (1, 2, 3, 31, 32, 33, 63, 64, 65, 100, 256, 1000):
+        print(n, blake2xs(n, key, data).hex())
+
+if __name__ == '__main__':
+    main()
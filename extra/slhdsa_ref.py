@@ -0,0 +1,433 @@
+#! /usr/bin/env python3
+
+# Straightforward SLH-DSA (FIPS 205) implementation, written directly from
+# the algorithms in the standard (sections 4 to 11), for test vector
+# generation. It is slow and not constant-time; it is meant only to
+# provide an independent check of the Rust code in src/slhdsa.rs.
+#
+# Usage:
+#   slhdsa_ref.py keygen PARAMS SK_SEED SK_PRF PK_SEED
+#       Prints PK.root.
+#   slhdsa_ref.py sign PARAMS SK MSG [ADDRND]
+#       Prints SHA-256(slh_sign_internal(MSG, SK, ADDRND)); without
+#       ADDRND, the deterministic variant is used.
+# All values are in hexadecimal; PARAMS is e.g. "SHA2-192s".
+
+import hashlib
+import hmac
+import sys
+
+# (n, h, d, hp, a, k, m) from FIPS 205, table 2.
+PARAMS = {
+    '128s': (16, 63, 7, 9, 12, 14, 30),
+    '128f': (16, 66, 22, 3, 6, 33, 34),
+    '192s': (24, 63, 7, 9, 14, 17, 39),
+    '192f': (24, 66, 22, 3, 8, 33, 42),
+    '256s': (32, 64, 8, 8, 14, 22, 47),
+    '256f': (32, 68, 17, 4, 9, 35, 49),
+}
+
+WOTS_HASH = 0
+WOTS_PK = 1
+TREE = 2
+FORS_TREE = 3
+FORS_ROOTS = 4
+WOTS_PRF = 5
+FORS_PRF = 6
+
+LG_W = 4
+W = 16
+
+def to_int(x):
+    return int.from_bytes(x, 'big')
+
+def to_byte(x, n):
+    return x.to_bytes(n, 'big')
+
+def base_2b(x, b, out_len):
+    # Algorithm 4.
+    i = 0
+    bits = 0
+    total = 0
+    r = []
+    for _ in range(out_len):
+        while bits < b:
+            total = (total << 8) + x[i]
+            i += 1
+            bits += 8
+        bits -= b
+        r.append((total >> bits) & ((1 << b) - 1))
+    return r
+
+class Adrs:
+    # Section 4.2; 32-byte address, as a mutable byte array.
+    def __init__(self):
+        self.a = bytearray(32)
+
+    def copy(self):
+        c = Adrs()
+        c.a = bytearray(self.a)
+        return c
+
+    def _set(self, off, ln, v):
+        self.a[off:off + ln] = to_byte(v, ln)
+
+    def _get(self, off, ln):
+        return to_int(self.a[off:off + ln])
+
+    def set_layer(self, v):
+        self._set(0, 4, v)
+
+    def set_tree(self, v):
+        self._set(4, 12, v)
+
+    def set_type_and_clear(self, t):
+        self._set(16, 4, t)
+        self.a[20:32] = bytes(12)
+
+    def set_keypair(self, v):
+        self._set(20, 4, v)
+
+    def get_keypair(self):
+        return self._get(20, 4)
+
+    def set_chain(self, v):
+        self._set(24, 4, v)
+
+    def set_tree_height(self, v):
+        self._set(24, 4, v)
+
+    def set_hash(self, v):
+        self._set(28, 4, v)
+
+    def set_tree_index(self, v):
+        self._set(28, 4, v)
+
+    def get_tree_index(self):
+        return self._get(28, 4)
+
+    def compressed(self):
+        # Section 11.2 (SHA2 instances).
+        a = self.a
+        return bytes(a[3:4] + a[8:16] + a[19:20] + a[20:32])
+
+class Shake:
+    # Section 11.1.
+    def __init__(self, n, m):
+        self.n = n
+        self.m = m
+
+    def _shake(self, data, ln):
+        return hashlib.shake_256(data).digest(ln)
+
+    def H_msg(self, R, pk_seed, pk_root, M):
+        return self._shake(R + pk_seed + pk_root + M, self.m)
+
+    def PRF(self, pk_seed, sk_seed, adrs):
+        return self._shake(pk_seed + bytes(adrs.a) + sk_seed, self.n)
+
+    def PRF_msg(self, sk_prf, opt_rand, M):
+        return self._shake(sk_prf + opt_rand + M, self.n)
+
+    def F(self, pk_seed, adrs, M):
+        return self._shake(pk_seed + bytes(adrs.a) + M, self.n)
+
+    H = F
+    T = F
+
+class Sha2:
+    # Sections 11.2.1 (n = 16) and 11.2.2 (n = 24 or 32).
+    def __init__(self, n, m):
+        self.n = n
+        self.m = m
+        if n == 16:
+            self.big = hashlib.sha256
+            self.big_bl = 64
+        else:
+            self.big = hashlib.sha512
+            self.big_bl = 128
+
+    def _mgf1(self, seed, ln):
+        r = b''
+        c = 0
+        while len(r) < ln:
+            r += self.big(seed + to_byte(c, 4)).digest()
+            c += 1
+        return r[:ln]
+
+    def H_msg(self, R, pk_seed, pk_root, M):
+        d = self.big(R + pk_seed + pk_root + M).digest()
+        return self._mgf1(R + pk_seed + d, self.m)
+
+    def PRF(self, pk_seed, sk_seed, adrs):
+        return hashlib.sha256(pk_seed + bytes(64 - self.n)
+            + adrs.compressed() + sk_seed).digest()[:self.n]
+
+    def PRF_msg(self, sk_prf, opt_rand, M):
+        return hmac.new(sk_prf, opt_rand + M, self.big).digest()[:self.n]
+
+    def F(self, pk_seed, adrs, M):
+        return hashlib.sha256(pk_seed + bytes(64 - self.n)
+            + adrs.compressed() + M).digest()[:self.n]
+
+    def H(self, pk_seed, adrs, M):
+        return self.big(pk_seed + bytes(self.big_bl - self.n)
+            + adrs.compressed() + M).digest()[:self.n]
+
+    T = H
+
+class SlhDsa:
+    def __init__(self, name):
+        fam, ps = name.split('-')
+        (self.n, self.h, self.d, self.hp, self.a, self.k,
+            self.m) = PARAMS[ps]
+        n = self.n
+        self.len1 = 2 * n
+        self.len2 = 3
+        self.len = self.len1 + self.len2
+        if fam == 'SHA2':
+            self.hf = Sha2(n, self.m)
+        elif fam == 'SHAKE':
+            self.hf = Shake(n, self.m)
+        else:
+            raise ValueError(name)
+
+    # Algorithm 5.
+    def chain(self, X, i, s, pk_seed, adrs):
+        tmp = X
+        for j in range(i, i + s):
+            adrs.set_hash(j)
+            tmp = self.hf.F(pk_seed, adrs, tmp)
+        return tmp
+
+    def wots_sk(self, sk_seed, pk_seed, adrs, i):
+        sk_adrs = adrs.copy()
+        sk_adrs.set_type_and_clear(WOTS_PRF)
+        sk_adrs.set_keypair(adrs.get_keypair())
+        sk_adrs.set_chain(i)
+        return self.hf.PRF(pk_seed, sk_seed, sk_adrs)
+
+    def wots_pk_compress(self, pk_seed, adrs, tmp):
+        pk_adrs = adrs.copy()
+        pk_adrs.set_type_and_clear(WOTS_PK)
+        pk_adrs.set_keypair(adrs.get_keypair())
+        return self.hf.T(pk_seed, pk_adrs, b''.join(tmp))
+
+    # Algorithm 6.
+    def wots_pkgen(self, sk_seed, pk_seed, adrs):
+        tmp = []
+        for i in range(self.len):
+            sk = self.wots_sk(sk_seed, pk_seed, adrs, i)
+            adrs.set_chain(i)
+            tmp.append(self.chain(sk, 0, W - 1, pk_seed, adrs))
+        return self.wots_pk_compress(pk_seed, adrs, tmp)
+
+    def wots_digits(self, M):
+        msg = base_2b(M, LG_W, self.len1)
+        csum = 0
+        for x in msg:
+            csum += W - 1 - x
+        csum <<= (8 - ((self.len2 * LG_W) % 8)) % 8
+        msg += base_2b(to_byte(csum, (self.len2 * LG_W + 7) // 8),
+            LG_W, self.len2)
+        return msg
+
+    # Algorithm 7.
+    def wots_sign(self, M, sk_seed, pk_seed, adrs):
+        msg = self.wots_digits(M)
+        sig = []
+        for i in range(self.len):
+            sk = self.wots_sk(sk_seed, pk_seed, adrs, i)
+            adrs.set_chain(i)
+            sig.append(self.chain(sk, 0, msg[i], pk_seed, adrs))
+        return sig
+
+    # Algorithm 8.
+    def wots_pk_from_sig(self, sig, M, pk_seed, adrs):
+        msg = self.wots_digits(M)
+        tmp = []
+        for i in range(self.len):
+            adrs.set_chain(i)
+            tmp.append(self.chain(sig[i], msg[i], W - 1 - msg[i],
+                pk_seed, adrs))
+        return self.wots_pk_compress(pk_seed, adrs, tmp)
+
+    # Algorithm 9.
+    def xmss_node(self, sk_seed, i, z, pk_seed, adrs):
+        if z == 0:
+            adrs.set_type_and_clear(WOTS_HASH)
+            adrs.set_keypair(i)
+            return self.wots_pkgen(sk_seed, pk_seed, adrs)
+        lnode = self.xmss_node(sk_seed, 2 * i, z - 1, pk_seed, adrs)
+        rnode = self.xmss_node(sk_seed, 2 * i + 1, z - 1, pk_seed, adrs)
+        adrs.set_type_and_clear(TREE)
+        adrs.set_tree_height(z)
+        adrs.set_tree_index(i)
+        return self.hf.H(pk_seed, adrs, lnode + rnode)
+
+    # Algorithm 10.
+    def xmss_sign(self, M, sk_seed, idx, pk_seed, adrs):
+        auth = []
+        for j in range(self.hp):
+            k = (idx >> j) ^ 1
+            auth.append(self.xmss_node(sk_seed, k, j, pk_seed, adrs))
+        adrs.set_type_and_clear(WOTS_HASH)
+        adrs.set_keypair(idx)
+        sig = self.wots_sign(M, sk_seed, pk_seed, adrs)
+        return (sig, auth)
+
+    # Algorithm 11.
+    def xmss_pk_from_sig(self, idx, sig_xmss, M, pk_seed, adrs):
+        sig, auth = sig_xmss
+        adrs.set_type_and_clear(WOTS_HASH)
+        adrs.set_keypair(idx)
+        node = self.wots_pk_from_sig(sig, M, pk_seed, adrs)
+        adrs.set_type_and_clear(TREE)
+        adrs.set_tree_index(idx)
+        for k in range(self.hp):
+            adrs.set_tree_height(k + 1)
+            if ((idx >> k) & 1) == 0:
+                adrs.set_tree_index(adrs.get_tree_index() // 2)
+                node = self.hf.H(pk_seed, adrs, node + auth[k])
+            else:
+                adrs.set_tree_index((adrs.get_tree_index() - 1) // 2)
+                node = self.hf.H(pk_seed, adrs, auth[k] + node)
+        return node
+
+    # Algorithm 12.
+    def ht_sign(self, M, sk_seed, pk_seed, idx_tree, idx_leaf):
+        adrs = Adrs()
+        adrs.set_tree(idx_tree)
+        sigs = []
+        s = self.xmss_sign(M, sk_seed, idx_leaf, pk_seed, adrs)
+        sigs.append(s)
+        root = self.xmss_pk_from_sig(idx_leaf, s, M, pk_seed, adrs)
+        for j in range(1, self.d):
+            idx_leaf = idx_tree & ((1 << self.hp) - 1)
+            idx_tree >>= self.hp
+            adrs.set_layer(j)
+            adrs.set_tree(idx_tree)
+            s = self.xmss_sign(root, sk_seed, idx_leaf, pk_seed, adrs)
+            sigs.append(s)
+            if j < self.d - 1:
+                root = self.xmss_pk_from_sig(idx_leaf, s, root,
+                    pk_seed, adrs)
+        return sigs
+
+    # Algorithm 14.
+    def fors_skgen(self, sk_seed, pk_seed, adrs, idx):
+        sk_adrs = adrs.copy()
+        sk_adrs.set_type_and_clear(FORS_PRF)
+        sk_adrs.set_keypair(adrs.get_keypair())
+        sk_adrs.set_tree_index(idx)
+        return self.hf.PRF(pk_seed, sk_seed, sk_adrs)
+
+    # Algorithm 15.
+    def fors_node(self, sk_seed, i, z, pk_seed, adrs):
+        if z == 0:
+            sk = self.fors_skgen(sk_seed, pk_seed, adrs, i)
+            adrs.set_tree_height(0)
+            adrs.set_tree_index(i)
+            return self.hf.F(pk_seed, adrs, sk)
+        lnode = self.fors_node(sk_seed, 2 * i, z - 1, pk_seed, adrs)
+        rnode = self.fors_node(sk_seed, 2 * i + 1, z - 1, pk_seed, adrs)
+        adrs.set_tree_height(z)
+        adrs.set_tree_index(i)
+        return self.hf.H(pk_seed, adrs, lnode + rnode)
+
+    # Algorithm 16.
+    def fors_sign(self, md, sk_seed, pk_seed, adrs):
+        a = self.a
+        indices = base_2b(md, a, self.k)
+        sig = []
+        for i in range(self.k):
+            sk = self.fors_skgen(sk_seed, pk_seed, adrs,
+                (i << a) + indices[i])
+            auth = []
+            for j in range(a):
+                s = (indices[i] >> j) ^ 1
+                auth.append(self.fors_node(sk_seed, (i << (a - j)) + s, j,
+                    pk_seed, adrs))
+            sig.append((sk, auth))
+        return sig
+
+    # Algorithm 17.
+    def fors_pk_from_sig(self, sig, md, pk_seed, adrs):
+        a = self.a
+        indices = base_2b(md, a, self.k)
+        roots = []
+        for i in range(self.k):
+            sk, auth = sig[i]
+            adrs.set_tree_height(0)
+            adrs.set_tree_index((i << a) + indices[i])
+            node = self.hf.F(pk_seed, adrs, sk)
+            for j in range(a):
+                adrs.set_tree_height(j + 1)
+                if ((indices[i] >> j) & 1) == 0:
+                    adrs.set_tree_index(adrs.get_tree_index() // 2)
+                    node = self.hf.H(pk_seed, adrs, node + auth[j])
+                else:
+                    adrs.set_tree_index((adrs.get_tree_index() - 1) // 2)
+                    node = self.hf.H(pk_seed, adrs, auth[j] + node)
+            roots.append(node)
+        pk_adrs = adrs.copy()
+        pk_adrs.set_type_and_clear(FORS_ROOTS)
+        pk_adrs.set_keypair(adrs.get_keypair())
+        return self.hf.T(pk_seed, pk_adrs, b''.join(roots))
+
+    # Algorithm 18.
+    def keygen_internal(self, sk_seed, sk_prf, pk_seed):
+        adrs = Adrs()
+        adrs.set_layer(self.d - 1)
+        return self.xmss_node(sk_seed, 0, self.hp, pk_seed, adrs)
+
+    # Algorithm 19.
+    def sign_internal(self, M, sk, addrnd):
+        n = self.n
+        sk_seed = sk[0:n]
+        sk_prf = sk[n:2 * n]
+        pk_seed = sk[2 * n:3 * n]
+        pk_root = sk[3 * n:4 * n]
+        adrs = Adrs()
+        opt_rand = pk_seed if addrnd is None else addrnd
+        R = self.hf.PRF_msg(sk_prf, opt_rand, M)
+        digest = self.hf.H_msg(R, pk_seed, pk_root, M)
+        l_md = (self.k * self.a + 7) // 8
+        l_tree = (self.h - self.hp + 7) // 8
+        l_leaf = (self.hp + 7) // 8
+        md = digest[:l_md]
+        idx_tree = to_int(digest[l_md:l_md + l_tree])
+        idx_tree &= (1 << (self.h - self.hp)) - 1
+        idx_leaf = to_int(digest[l_md + l_tree:l_md + l_tree + l_leaf])
+        idx_leaf &= (1 << self.hp) - 1
+        adrs.set_tree(idx_tree)
+        adrs.set_type_and_clear(FORS_TREE)
+        adrs.set_keypair(idx_leaf)
+        sig_fors = self.fors_sign(md, sk_seed, pk_seed, adrs)
+        pk_fors = self.fors_pk_from_sig(sig_fors, md, pk_seed, adrs)
+        sig_ht = self.ht_sign(pk_fors, sk_seed, pk_seed, idx_tree, idx_leaf)
+        out = R
+        for sk, auth in sig_fors:
+            out += sk + b''.join(auth)
+        for sig, auth in sig_ht:
+            out += b''.join(sig) + b''.join(auth)
+        return out
+
+def main(args):
+    if len(args) >= 5 and args[0] == 'keygen':
+        p = SlhDsa(args[1])
+        sk_seed, sk_prf, pk_seed = (bytes.fromhex(x) for x in args[2:5])
+        print(p.keygen_internal(sk_seed, sk_prf, pk_seed).hex())
+    elif len(args) >= 4 and args[0] == 'sign':
+        p = SlhDsa(args[1])
+        sk = bytes.fromhex(args[2])
+        msg = bytes.fromhex(args[3])
+        addrnd = bytes.fromhex(args[4]) if len(args) >= 5 else None
+        sig = p.sign_internal(msg, sk, addrnd)
+        print(hashlib.sha256(sig).hexdigest())
+    else:
+        print(__doc__ or 'see the comments at the top of this file')
+        sys.exit(1)
+
+if __name__ == '__main__':
+    main(sys.argv[1:])
@@ -0,0 +1,776 @@
+//! AES and AES-GCM implementation.
+//!
+//! The AES block cipher (FIPS 197) is implemented with bitslicing, using
+//! 64-bit words: four blocks are processed in parallel, and the S-box is
+//! computed with the Boyar-Peralta circuit. This makes the code
+//! constant-time (no secret-dependent table lookup) while still
+//! portable. Only encryption is implemented, since it is all that CTR
+//! mode and GCM need. Keys of 128, 192 and 256 bits are supported.
+//!
+//! GCM (NIST SP 800-38D) uses a 96-bit nonce and a 16-byte tag. The
+//! GHASH multiplications use integer multiplications with "holes" so
+//! that carry-less products are obtained in constant time. As with
+//! `chacha20poly1305`, the AEAD functions work in place and use detached
+//! tags.
+
+use core::convert::TryFrom;
+
+/// AES block cipher (encryption only), with a 128-, 192- or 256-bit key.
+#[derive(Clone, Copy)]
+pub struct Aes {
+    // Bitsliced round keys (8 words per round key).
+    skey: [u64; 120],
+    num_rounds: usize,
+}
+
+impl Aes {
+
+    /// Create a new instance with the provided key. The key length MUST
+    /// be 16, 24 or 32 bytes.
+    pub fn new(key: &[u8]) -> Self {
+        let num_rounds = match key.len() {
+            16 => 10,
+            24 => 12,
+            32 => 14,
+            _ => panic!("invalid AES key length"),
+        };
+
+        // Classic key expansion, with 32-bit words (little-endian
+        // convention, as in the bitsliced representation).
+        let nk = key.len() >> 2;
+        let nkf = (num_rounds + 1) << 2;
+        let mut w = [0u32; 60];
+        for i in 0..nk {
+            w[i] = u32::from_le_bytes(*<&[u8; 4]>::try_from(
+                &key[(4 * i)..(4 * i + 4)]).unwrap());
+        }
+        let mut rcon = 1u32;
+        for i in nk..nkf {
+            let mut t = w[i - 1];
+            if (i % nk) == 0 {
+                t = sub_word(t.rotate_right(8)) ^ rcon;
+                rcon = (rcon << 1) ^ (0x11B & (rcon >> 7).wrapping_neg());
+            } else if nk > 6 && (i % nk) == 4 {
+                t = sub_word(t);
+            }
+            w[i] = w[i - nk] ^ t;
+        }
+
+        // Each round key is bitsliced with the same value in all four
+        // block slots, so that it can be XORed directly into the state.
+        let mut skey = [0u64; 120];
+        for i in 0..=num_rounds {
+            let q = &mut skey[(8 * i)..(8 * i + 8)];
+            for j in 0..4 {
+                let (q0, q1) = interleave_in(&w[(4 * i)..(4 * i + 4)]);
+                q[j] = q0;
+                q[j + 4] = q1;
+            }
+            ortho(q);
+        }
+        Self { skey, num_rounds }
+    }
+
+    /// Encrypt one block (in place).
+    pub fn encrypt_block(&self, block: &mut [u8; 16]) {
+        let mut bb = [0u8; 64];
+        bb[..16].copy_from_slice(block);
+        self.encrypt4(&mut bb);
+        block.copy_from_slice(&bb[..16]);
+    }
+
+    // Encrypt four consecutive blocks (in place).
+    fn encrypt4(&self, blocks: &mut [u8; 64]) {
+        let mut w = [0u32; 16];
+        for i in 0..16 {
+            w[i] = u32::from_le_bytes(*<&[u8; 4]>::try_from(
+                &blocks[(4 * i)..(4 * i + 4)]).unwrap());
+        }
+        let mut q = [0u64; 8];
+        for i in 0..4 {
+            let (q0, q1) = interleave_in(&w[(4 * i)..(4 * i + 4)]);
+            q[i] = q0;
+            q[i + 4] = q1;
+        }
+        ortho(&mut q);
+        add_round_key(&mut q, &self.skey[0..8]);
+        for u in 1..self.num_rounds {
+            sbox(&mut q);
+            shift_rows(&mut q);
+            mix_columns(&mut q);
+            add_round_key(&mut q, &self.skey[(8 * u)..(8 * u + 8)]);
+        }
+        sbox(&mut q);
+        shift_rows(&mut q);
+        let u = self.num_rounds;
+        add_round_key(&mut q, &self.skey[(8 * u)..(8 * u + 8)]);
+        ortho(&mut q);
+        for i in 0..4 {
+            interleave_out(&mut w[(4 * i)..(4 * i + 4)], q[i], q[i + 4]);
+        }
+        for i in 0..16 {
+            blocks[(4 * i)..(4 * i + 4)].copy_from_slice(&w[i].to_le_bytes());
+        }
+    }
+
+    /// Apply AES in CTR mode, with the GCM counter convention: the
+    /// provided 16-byte block `iv` is the first counter block, and the
+    /// counter is its last four bytes (big-endian), incremented modulo
+    /// 2^32 for each subsequent block. The data is XORed with the key
+    /// stream (this both encrypts and decrypts). The updated counter
+    /// block (for the next data block) is returned.
+    pub fn ctr32(&self, iv: &[u8; 16], data: &mut [u8]) -> [u8; 16] {
+        let mut cb = *iv;
+        let mut ctr = u32::from_be_bytes(*<&[u8; 4]>::try_from(
+            &iv[12..16]).unwrap());
+        for chunk in data.chunks_mut(64) {
+            let mut ks = [0u8; 64];
+            for j in 0..4 {
+                cb[12..16].copy_from_slice(
+                    &ctr.wrapping_add(j as u32).to_be_bytes());
+                ks[(16 * j)..(16 * j + 16)].copy_from_slice(&cb);
+            }
+            self.encrypt4(&mut ks);
+            for (x, y) in chunk.iter_mut().zip(ks.iter()) {
+                *x ^= *y;
+            }
+            ctr = ctr.wrapping_add(((chunk.len() + 15) >> 4) as u32);
+        }
+        cb[12..16].copy_from_slice(&ctr.to_be_bytes());
+        cb
+    }
+}
+
+// Bitsliced S-box (Boyar-Peralta circuit), applied on all 32 bytes of
+// the state.
+fn sbox(q: &mut [u64]) {
+    let x0 = q[7];
+    let x1 = q[6];
+    let x2 = q[5];
+    let x3 = q[4];
+    let x4 = q[3];
+    let x5 = q[2];
+    let x6 = q[1];
+    let x7 = q[0];
+
+    // Top linear transformation.
+    let y14 = x3 ^ x5;
+    let y13 = x0 ^ x6;
+    let y9 = x0 ^ x3;
+    let y8 = x0 ^ x5;
+    let t0 = x1 ^ x2;
+    let y1 = t0 ^ x7;
+    let y4 = y1 ^ x3;
+    let y12 = y13 ^ y14;
+    let y2 = y1 ^ x0;
+    let y5 = y1 ^ x6;
+    let y3 = y5 ^ y8;
+    let t1 = x4 ^ y12;
+    let y15 = t1 ^ x5;
+    let y20 = t1 ^ x1;
+    let y6 = y15 ^ x7;
+    let y10 = y15 ^ t0;
+    let y11 = y20 ^ y9;
+    let y7 = x7 ^ y11;
+    let y17 = y10 ^ y11;
+    let y19 = y10 ^ y8;
+    let y16 = t0 ^ y11;
+    let y21 = y13 ^ y16;
+    let y18 = x0 ^ y16;
+
+    // Non-linear section.
+    let t2 = y12 & y15;
+    let t3 = y3 & y6;
+    let t4 = t3 ^ t2;
+    let t5 = y4 & x7;
+    let t6 = t5 ^ t2;
+    let t7 = y13 & y16;
+    let t8 = y5 & y1;
+    let t9 = t8 ^ t7;
+    let t10 = y2 & y7;
+    let t11 = t10 ^ t7;
+    let t12 = y9 & y11;
+    let t13 = y14 & y17;
+    let t14 = t13 ^ t12;
+    let t15 = y8 & y10;
+    let t16 = t15 ^ t12;
+    let t17 = t4 ^ t14;
+    let t18 = t6 ^ t16;
+    let t19 = t9 ^ t14;
+    let t20 = t11 ^ t16;
+    let t21 = t17 ^ y20;
+    let t22 = t18 ^ y19;
+    let t23 = t19 ^ y21;
+    let t24 = t20 ^ y18;
+
+    let t25 = t21 ^ t22;
+    let t26 = t21 & t23;
+    let t27 = t24 ^ t26;
+    let t28 = t25 & t27;
+    let t29 = t28 ^ t22;
+    let t30 = t23 ^ t24;
+    let t31 = t22 ^ t26;
+    let t32 = t31 & t30;
+    let t33 = t32 ^ t24;
+    let t34 = t23 ^ t33;
+    let t35 = t27 ^ t33;
+    let t36 = t24 & t35;
+    let t37 = t36 ^ t34;
+    let t38 = t27 ^ t36;
+    let t39 = t29 & t38;
+    let t40 = t25 ^ t39;
+
+    let t41 = t40 ^ t37;
+    let t42 = t29 ^ t33;
+    let t43 = t29 ^ t40;
+    let t44 = t33 ^ t37;
+    let t45 = t42 ^ t41;
+    let z0 = t44 & y15;
+    let z1 = t37 & y6;
+    let z2 = t33 & x7;
+    let z3 = t43 & y16;
+    let z4 = t40 & y1;
+    let z5 = t29 & y7;
+    let z6 = t42 & y11;
+    let z7 = t45 & y17;
+    let z8 = t41 & y10;
+    let z9 = t44 & y12;
+    let z10 = t37 & y3;
+    let z11 = t33 & y4;
+    let z12 = t43 & y13;
+    let z13 = t40 & y5;
+    let z14 = t29 & y2;
+    let z15 = t42 & y9;
+    let z16 = t45 & y14;
+    let z17 = t41 & y8;
+
+    // Bottom linear transformation.
+    let t46 = z15 ^ z16;
+    let t47 = z10 ^ z11;
+    let t48 = z5 ^ z13;
+    let t49 = z9 ^ z10;
+    let t50 = z2 ^ z12;
+    let t51 = z2 ^ z5;
+    let t52 = z7 ^ z8;
+    let t53 = z0 ^ z3;
+    let t54 = z6 ^ z7;
+    let t55 = z16 ^ z17;
+    let t56 = z12 ^ t48;
+    let t57 = t50 ^ t53;
+    let t58 = z4 ^ t46;
+    let t59 = z3 ^ t54;
+    let t60 = t46 ^ t57;
+    let t61 = z14 ^ t57;
+    let t62 = t52 ^ t58;
+    let t63 = t49 ^ t58;
+    let t64 = z4 ^ t59;
+    let t65 = t61 ^ t62;
+    let t66 = z1 ^ t63;
+    let s0 = t59 ^ t63;
+    let s6 = t56 ^ !t62;
+    let s7 = t48 ^ !t60;
+    let t67 = t64 ^ t65;
+    let s3 = t53 ^ t66;
+    let s4 = t51 ^ t66;
+    let s5 = t47 ^ t65;
+    let s1 = t64 ^ !s3;
+    let s2 = t55 ^ !t67;
+
+    q[7] = s0;
+    q[6] = s1;
+    q[5] = s2;
+    q[4] = s3;
+    q[3] = s4;
+    q[2] = s5;
+    q[1] = s6;
+    q[0] = s7;
+}
+
+// Transpose the state between the "interleaved" and the bitsliced
+// representations (this function is its own inverse).
+fn ortho(q: &mut [u64]) {
+    macro_rules! swapn {
+        ($cl: expr, $ch: expr, $s: expr, $x: expr, $y: expr) => {
+            let a = q[$x];
+            let b = q[$y];
+            q[$x] = (a & $cl) | ((b & $cl) << $s);
+            q[$y] = ((a & $ch) >> $s) | (b & $ch);
+        }
+    }
+    macro_rules! swap2 {
+        ($x: expr, $y: expr) => {
+            swapn!(0x5555555555555555, 0xAAAAAAAAAAAAAAAA, 1, $x, $y);
+        }
+    }
+    macro_rules! swap4 {
+        ($x: expr, $y: expr) => {
+            swapn!(0x3333333333333333, 0xCCCCCCCCCCCCCCCC, 2, $x, $y);
+        }
+    }
+    macro_rules! swap8 {
+        ($x: expr, $y: expr) => {
+            swapn!(0x0F0F0F0F0F0F0F0F, 0xF0F0F0F0F0F0F0F0, 4, $x, $y);
+        }
+    }
+
+    swap2!(0, 1);
+    swap2!(2, 3);
+    swap2!(4, 5);
+    swap2!(6, 7);
+
+    swap4!(0, 2);
+    swap4!(1, 3);
+    swap4!(4, 6);
+    swap4!(5, 7);
+
+    swap8!(0, 4);
+    swap8!(1, 5);
+    swap8!(2, 6);
+    swap8!(3, 7);
+}
+
+// Spread the four 32-bit words of a block into two 64-bit words.
+fn interleave_in(w: &[u32]) -> (u64, u64) {
+    let mut x0 = w[0] as u64;
+    let mut x1 = w[1] as u64;
+    let mut x2 = w[2] as u64;
+    let mut x3 = w[3] as u64;
+    x0 |= x0 << 16;
+    x1 |= x1 << 16;
+    x2 |= x2 << 16;
+    x3 |= x3 << 16;
+    x0 &= 0x0000FFFF0000FFFF;
+    x1 &= 0x0000FFFF0000FFFF;
+    x2 &= 0x0000FFFF0000FFFF;
+    x3 &= 0x0000FFFF0000FFFF;
+    x0 |= x0 << 8;
+    x1 |= x1 << 8;
+    x2 |= x2 << 8;
+    x3 |= x3 << 8;
+    x0 &= 0x00FF00FF00FF00FF;
+    x1 &= 0x00FF00FF00FF00FF;
+    x2 &= 0x00FF00FF00FF00FF;
+    x3 &= 0x00FF00FF00FF00FF;
+    (x0 | (x2 << 8), x1 | (x3 << 8))
+}
+
+// Inverse of interleave_in().
+fn interleave_out(w: &mut [u32], q0: u64, q1: u64) {
+    let mut x0 = q0 & 0x00FF00FF00FF00FF;
+    let mut x1 = q1 & 0x00FF00FF00FF00FF;
+    let mut x2 = (q0 >> 8) & 0x00FF00FF00FF00FF;
+    let mut x3 = (q1 >> 8) & 0x00FF00FF00FF00FF;
+    x0 |= x0 >> 8;
+    x1 |= x1 >> 8;
+    x2 |= x2 >> 8;
+    x3 |= x3 >> 8;
+    x0 &= 0x0000FFFF0000FFFF;
+    x1 &= 0x0000FFFF0000FFFF;
+    x2 &= 0x0000FFFF0000FFFF;
+    x3 &= 0x0000FFFF0000FFFF;
+    w[0] = (x0 as u32) | ((x0 >> 16) as u32);
+    w[1] = (x1 as u32) | ((x1 >> 16) as u32);
+    w[2] = (x2 as u32) | ((x2 >> 16) as u32);
+    w[3] = (x3 as u32) | ((x3 >> 16) as u32);
+}
+
+// Apply the S-box on each byte of a 32-bit word (for the key schedule).
+fn sub_word(x: u32) -> u32 {
+    let mut q = [0u64; 8];
+    q[0] = x as u64;
+    ortho(&mut q);
+    sbox(&mut q);
+    ortho(&mut q);
+    q[0] as u32
+}
+
+fn add_round_key(q: &mut [u64; 8], sk: &[u64]) {
+    for i in 0..8 {
+        q[i] ^= sk[i];
+    }
+}
+
+fn shift_rows(q: &mut [u64; 8]) {
+    for x in q.iter_mut() {
+        let v = *x;
+        *x = (v & 0x000000000000FFFF)
+            | ((v & 0x00000000FFF00000) >> 4)
+            | ((v & 0x00000000000F0000) << 12)
+            | ((v & 0x0000FF0000000000) >> 8)
+            | ((v & 0x000000FF00000000) << 8)
+            | ((v & 0xF000000000000000) >> 12)
+            | ((v & 0x0FFF000000000000) << 4);
+    }
+}
+
+fn mix_columns(q: &mut [u64; 8]) {
+    let q0 = q[0];
+    let q1 = q[1];
+    let q2 = q[2];
+    let q3 = q[3];
+    let q4 = q[4];
+    let q5 = q[5];
+    let q6 = q[6];
+    let q7 = q[7];
+    let r0 = q0.rotate_right(16);
+    let r1 = q1.rotate_right(16);
+    let r2 = q2.rotate_right(16);
+    let r3 = q3.rotate_right(16);
+    let r4 = q4.rotate_right(16);
+    let r5 = q5.rotate_right(16);
+    let r6 = q6.rotate_right(16);
+    let r7 = q7.rotate_right(16);
+
+    q[0] = q7 ^ r7 ^ r0 ^ (q0 ^ r0).rotate_right(32);
+    q[1] = q0 ^ r0 ^ q7 ^ r7 ^ r1 ^ (q1 ^ r1).rotate_right(32);
+    q[2] = q1 ^ r1 ^ r2 ^ (q2 ^ r2).rotate_right(32);
+    q[3] = q2 ^ r2 ^ q7 ^ r7 ^ r3 ^ (q3 ^ r3).rotate_right(32);
+    q[4] = q3 ^ r3 ^ q7 ^ r7 ^ r4 ^ (q4 ^ r4).rotate_right(32);
+    q[5] = q4 ^ r4 ^ r5 ^ (q5 ^ r5).rotate_right(32);
+    q[6] = q5 ^ r5 ^ r6 ^ (q6 ^ r6).rotate_right(32);
+    q[7] = q6 ^ r6 ^ r7 ^ (q7 ^ r7).rotate_right(32);
+}
+
+/// AES-GCM AEAD, with a 128-, 192- or 256-bit key, a 96-bit nonce and a
+/// 128-bit tag.
+#[derive(Clone, Copy)]
+pub struct AesGcm {
+    aes: Aes,
+    // GHASH key, bit-reversed.
+    h: u128,
+}
+
+impl AesGcm {
+
+    /// Nonce length (in bytes).
+    pub const NONCE_LEN: usize = 12;
+
+    /// Tag length (in bytes).
+    pub const TAG_LEN: usize = 16;
+
+    /// Create a new instance with the provided key. The key length MUST
+    /// be 16, 24 or 32 bytes.
+    pub fn new(key: &[u8]) -> Self {
+        let aes = Aes::new(key);
+        let mut h = [0u8; 16];
+        aes.encrypt_block(&mut h);
+        Self { aes, h: u128::from_be_bytes(h).reverse_bits() }
+    }
+
+    /// Encrypt some data in place. The additional authenticated data
+    /// (`aad`) is not encrypted but covered by the returned tag.
+    ///
+    /// A given nonce MUST NOT be used twice with the same key.
+    pub fn encrypt_in_place(&self, nonce: &[u8; 12], aad: &[u8],
+        data: &mut [u8]) -> [u8; 16]
+    {
+        self.encrypt_in_place_iv(nonce, aad, data)
+    }
+
+    /// Decrypt some data in place. Returned value is `true` if the tag
+    /// (`tag`) matches, in which case the data has been decrypted; if
+    /// the tag does not match, then `false` is returned and the data is
+    /// left unmodified (still encrypted).
+    pub fn decrypt_in_place(&self, nonce: &[u8; 12], aad: &[u8],
+        data: &mut [u8], tag: &[u8]) -> bool
+    {
+        self.decrypt_in_place_iv(nonce, aad, data, tag)
+    }
+
+    /// Encrypt some data in place, with an IV of arbitrary (non-zero)
+    /// length. A 12-byte IV is used directly as the nonce; other lengths
+    /// are hashed with GHASH, as specified by GCM.
+    ///
+    /// A given IV MUST NOT be used twice with the same key.
+    pub fn encrypt_in_place_iv(&self, iv: &[u8], aad: &[u8],
+        data: &mut [u8]) -> [u8; 16]
+    {
+        let j0 = self.make_j0(iv);
+        self.aes.ctr32(&inc32(&j0), data);
+        self.compute_tag(&j0, aad, data)
+    }
+
+    /// Decrypt some data in place, with an IV of arbitrary (non-zero)
+    /// length (see `encrypt_in_place_iv()`). Returned value is `true` if
+    /// the tag matches; otherwise, `false` is returned and the data is
+    /// left unmodified.
+    pub fn decrypt_in_place_iv(&self, iv: &[u8], aad: &[u8],
+        data: &mut [u8], tag: &[u8]) -> bool
+    {
+        if tag.len() != 16 {
+            return false;
+        }
+        let j0 = self.make_j0(iv);
+        let t = self.compute_tag(&j0, aad, data);
+        let mut z = 0u8;
+        for (x, y) in t.iter().zip(tag.iter()) {
+            z |= x ^ y;
+        }
+        if z != 0 {
+            return false;
+        }
+        self.aes.ctr32(&inc32(&j0), data);
+        true
+    }
+
+    // Compute the initial counter block from the IV.
+    fn make_j0(&self, iv: &[u8]) -> [u8; 16] {
+        assert!(!iv.is_empty());
+        if iv.len() == 12 {
+            let mut j0 = [0u8; 16];
+            j0[..12].copy_from_slice(iv);
+            j0[15] = 1;
+            return j0;
+        }
+        let y = self.ghash(0, iv);
+        let y = gmul(y ^ ((iv.len() as u128) << 3).reverse_bits(), self.h);
+        y.reverse_bits().to_be_bytes()
+    }
+
+    // Process some data (padded with zeros to a multiple of 16 bytes)
+    // with GHASH.
+    fn ghash(&self, mut y: u128, data: &[u8]) -> u128 {
+        for chunk in data.chunks(16) {
+            let mut b = [0u8; 16];
+            b[..chunk.len()].copy_from_slice(chunk);
+            y = gmul(y ^ u128::from_be_bytes(b).reverse_bits(), self.h);
+        }
+        y
+    }
+
+    // Compute the tag over the AAD and the ciphertext.
+    fn compute_tag(&self, j0: &[u8; 16], aad: &[u8], ct: &[u8]) -> [u8; 16] {
+        let y = self.ghash(0, aad);
+        let mut y = self.ghash(y, ct);
+        let lens = ((aad.len() as u128) << 67) | ((ct.len() as u128) << 3);
+        y = gmul(y ^ lens.reverse_bits(), self.h);
+        let mut ek = *j0;
+        self.aes.encrypt_block(&mut ek);
+        (u128::from_be_bytes(ek) ^ y.reverse_bits()).to_be_bytes()
+    }
+}
+
+// Increment the counter (last four bytes) of a counter block.
+fn inc32(cb: &[u8; 16]) -> [u8; 16] {
+    let mut r = *cb;
+    let ctr = u32::from_be_bytes(*<&[u8; 4]>::try_from(&cb[12..16]).unwrap());
+    r[12..16].copy_from_slice(&ctr.wrapping_add(1).to_be_bytes());
+    r
+}
+
+// Carry-less multiplication of two 32-bit words (constant-time). Bits are
+// split into four groups with "holes" of three bits, so that carries in
+// the integer products do not spill into the next bit of the same group
+// (each output position receives at most 8 contributions).
+fn bmul32(x: u32, y: u32) -> u64 {
+    let x0 = (x & 0x11111111) as u64;
+    let x1 = (x & 0x22222222) as u64;
+    let x2 = (x & 0x44444444) as u64;
+    let x3 = (x & 0x88888888) as u64;
+    let y0 = (y & 0x11111111) as u64;
+    let y1 = (y & 0x22222222) as u64;
+    let y2 = (y & 0x44444444) as u64;
+    let y3 = (y & 0x88888888) as u64;
+    let z0 = (x0 * y0) ^ (x1 * y3) ^ (x2 * y2) ^ (x3 * y1);
+    let z1 = (x0 * y1) ^ (x1 * y0) ^ (x2 * y3) ^ (x3 * y2);
+    let z2 = (x0 * y2) ^ (x1 * y1) ^ (x2 * y0) ^ (x3 * y3);
+    let z3 = (x0 * y3) ^ (x1 * y2) ^ (x2 * y1) ^ (x3 * y0);
+    (z0 & 0x1111111111111111) | (z1 & 0x2222222222222222)
+        | (z2 & 0x4444444444444444) | (z3 & 0x8888888888888888)
+}
+
+// Carry-less multiplication of two 64-bit words (Karatsuba).
+fn bmul64(x: u64, y: u64) -> u128 {
+    let xl = x as u32;
+    let xh = (x >> 32) as u32;
+    let yl = y as u32;
+    let yh = (y >> 32) as u32;
+    let a = bmul32(xl, yl);
+    let b = bmul32(xh, yh);
+    let c = bmul32(xl ^ xh, yl ^ yh) ^ a ^ b;
+    (a as u128) ^ ((c as u128) << 32) ^ ((b as u128) << 64)
+}
+
+// Multiplication in GF(2^128), modulo x^128 + x^7 + x^2 + x + 1. Operands
+// use the "natural" bit order (bit i is the coefficient of x^i), i.e. the
+// bit-reversed order of GCM.
+fn gmul(x: u128, y: u128) -> u128 {
+    let xl = x as u64;
+    let xh = (x >> 64) as u64;
+    let yl = y as u64;
+    let yh = (y >> 64) as u64;
+    let a = bmul64(xl, yl);
+    let b = bmul64(xh, yh);
+    let c = bmul64(xl ^ xh, yl ^ yh) ^ a ^ b;
+    let lo = a ^ (c << 64);
+    let hi = b ^ (c >> 64);
+
+    // Reduction: x^128 = x^7 + x^2 + x + 1.
+    let ov = (hi >> 127) ^ (hi >> 126) ^ (hi >> 121);
+    lo ^ hi ^ (hi << 1) ^ (hi << 2) ^ (hi << 7)
+        ^ ov ^ (ov << 1) ^ (ov << 2) ^ (ov << 7)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::Vec;
+
+    // FIPS 197, appendix C.
+    #[test]
+    fn aes_block() {
+        let pt: [u8; 16] = *<&[u8; 16]>::try_from(&hex::decode(
+            "00112233445566778899aabbccddeeff").unwrap()[..]).unwrap();
+        let kat = [
+            ("000102030405060708090a0b0c0d0e0f",
+             "69c4e0d86a7b0430d8cdb78070b4c55a"),
+            ("000102030405060708090a0b0c0d0e0f1011121314151617",
+             "dda97ca4864cdfe06eaf70a0ec0d7191"),
+            ("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f",
+             "8ea2b7ca516745bfeafc49904b496089"),
+        ];
+        for (key, ct) in kat.iter() {
+            let aes = Aes::new(&hex::decode(key).unwrap());
+            let mut b = pt;
+            aes.encrypt_block(&mut b);
+            assert!(b[..] == hex::decode(ct).unwrap());
+        }
+    }
+
+    // Test vectors: key, nonce, aad, plaintext, ciphertext, tag. The first
+    // two are from the GCM specification (test cases 15 and 16), the others
+    // have been generated with OpenSSL.
+    const KAT_GCM: [&str; 6 * 9] = [
+        "feffe9928665731c6d6a8f9467308308feffe9928665731c6d6a8f9467308308",
+        "cafebabefacedbaddecaf888",
+        "",
+        "d9313225f88406e5a55909c5aff5269a86a7a9531534f7da2e4c303d8a318a721c3c0c95956809532fcf0e2449a6b525b16aedf5aa0de657ba637b391aafd255",
+        "522dc1f099567d07f47f37a32a84427d643a8cdcbfe5c0c97598a2bd2555d1aa8cb08e48590dbb3da7b08b1056828838c5f61e6393ba7a0abcc9f662898015ad",
+        "b094dac5d93471bdec1a502270e3cc6c",
+
+        "feffe9928665731c6d6a8f9467308308feffe9928665731c6d6a8f9467308308",
+        "cafebabefacedbaddecaf888",
+        "feedfacedeadbeeffeedfacedeadbeefabaddad2",
+        "d9313225f88406e5a55909c5aff5269a86a7a9531534f7da2e4c303d8a318a721c3c0c95956809532fcf0e2449a6b525b16aedf5aa0de657ba637b39",
+        "522dc1f099567d07f47f37a32a84427d643a8cdcbfe5c0c97598a2bd2555d1aa8cb08e48590dbb3da7b08b1056828838c5f61e6393ba7a0abcc9f662",
+        "76fc6ece0f4e1768cddf8853bb2d551b",
+
+        "3a86674c5d75f3399ba01c7bf88a877c",
+        "1e84e482bfcedec5f7462307",
+        "",
+        "",
+        "",
+        "aadb56cbc0d7b68a134f48aeb626d82e",
+
+        "497e17614b76da3d421600df6d37ef06",
+        "e4b59e8d149a394b6a92f430",
+        "b536fe9ad102a03ccc70b8e13a",
+        "ba",
+        "cf",
+        "e97d271cb335a3931be5e16aaf0c4659",
+
+        "508ab9ed23e429294f629791913ca7042fe47b96f6bcd262",
+        "e9e55fc1244a8940790f52e4",
+        "781080aa946280a2210cce7d6732fe9897ae86d5",
+        "8338a19da5f95aec514de541276aa5e18b6e7f77dbe2186230cd92e4c45895eed450e4f1e5a6f2bf20c0717b3c8f0efb7d16e19d92c9e2bd4f4d6ee44a73c8",
+        "5edb6cfa3716bd0ad3c34ea05670557ce1673c3ac3d456716e60ea3302b8aa2ffc9fe68fc04335c1fe180753d1b308c5e00bef5a7fc15e1546d081e6580f76",
+        "811af50d113388f9b8f462b9363c1397",
+
+        "a12976c2c51588fcaea87d830d57744a252b18d47e6af8046c052e80e319ed15",
+        "05dfd28e6935f214e98be970",
+        "",
+        "65dfe0ab9dda519af8556b5a34a26c7fd739d19256863993aa07bd74e6815c4e3b0e7dd59a45f7dfbd4ff511ce144d624426644b3393c1dfe8d17435c6ee59a8",
+        "9d1b2e6dff1f2f7ee136d822c7cc6bf2c0eeddfacc61acafdc9b7276e9762dfc9bfd46a197e550b1f8150c6093346edb1502b90c1cbbed60168e8a488bde5f60",
+        "f252d0f2bf6575965006d52cc8d0c4aa",
+
+        "6e9720955b54371812d73ecdfc5e7498558ba0f1b8581b9b3af8f6623157fb5f",
+        "679c7efda8fb89c0a06b04a1",
+        "12e66e3ed476fcae12e1c967ffa8ba70ed",
+        "2782acbbab807c36c7999b460aded39353a659a201d74378487e8295662655224a1cbb6062eccbe722be8e2ed7fb03be3af491875795da4731dbfc6628bf79a951",
+        "66f62cb14cd72891851cf4c018abab20f5007050340b338758389f5767717b105968adfacfde1adbf2444aff073c02409434de7f42e56fa6e0d941b170f83becc9",
+        "9232be0aceae5793f5825b70ad4ff63b",
+
+        "e96f93f32f79d024351eac49507b7415",
+        "eb80e4df762c6ff011e8298a",
+        "2ca23b571eee22c34835eae38969fa241d93915ec1da84eb4757bd48e3d7fccfdfb969a56de870c9dc68f3fca38c509910748cf9d30aab6949846b2858c4dc31",
+        "bc8b2208c20dc037b7c8e90c5a0a084bffdf2569e97e1b722797fb38a80cf11fdbac5e1a6cbda8fe3b6400f3a8431ba8531b4b67dc4998ff8cb01cbd48cc4675cad13fa1a1757983b702324600f8a059d9d824b906d46e04f6988cfe47e026d455ea3ef782263cf146efad8fcf8fa1eea9b14c9daa4e7c808192e4ed6fd3ff64af48bbfc7ea81e6bbbd9f089bd5b21cf4b9f19c6eb8e1105afb54a5bcd935a6dbb3d55d4f5457ae735a0e726e09631896c45d35972956158fc4eaf5ebba91b6b32b1b3d724954d7b",
+        "fd8a28de35cca880434d0aa5beaeb5cb3107f560c4eb3e092bbcc25d66b228e07544e944e23e4b19d0b461e58e57ba5bdaabd24fd6abade190d493541b472f690ae7b8eb07183456152036d9fba5e5d3b139bf97fcdcab47eeee0ebe8209faaa964510f8a5a0fdb60c6389d5f028b4004172904c671e153ba07cf6204bf29b39cb2633cbd862a2468896e56163cf73598ae303de422687b9b3faa056ff7e1d453b2bcc328949a967572b0523d92f85ab8e83c63c585fa2f37e318efe4aac9cde4c69567bcaede9f9",
+        "ac1ce35e8ccc87fec99aff48f26a217d",
+
+        "11cd19d048eed20a1259287e1ceaa9d8a7fd2cb445fa11bf6a6068c48b025ae4",
+        "5ad0875412664e4c842c08c4",
+        "ec16a0c9ca",
+        "6d9284ea0a20e095c03f7a7e1f7530996dbeca6a95493720c90b553419d039de34",
+        "79b792f6abaf53479163c0f4a6d0f5bb9a60b3be45b06860c5e54de9afafbf444c",
+        "1cd3f58bee46922613c8312c4f660b9c",
+    ];
+
+    #[test]
+    fn gcm() {
+        for i in 0..(KAT_GCM.len() / 6) {
+            let key = hex::decode(KAT_GCM[6 * i]).unwrap();
+            let nonce: [u8; 12] = *<&[u8; 12]>::try_from(
+                &hex::decode(KAT_GCM[6 * i + 1]).unwrap()[..]).unwrap();
+            let aad = hex::decode(KAT_GCM[6 * i + 2]).unwrap();
+            let pt = hex::decode(KAT_GCM[6 * i + 3]).unwrap();
+            let ct = hex::decode(KAT_GCM[6 * i + 4]).unwrap();
+            let tag = hex::decode(KAT_GCM[6 * i + 5]).unwrap();
+            let aead = AesGcm::new(&key);
+            let mut buf: Vec<u8> = pt.clone();
+            let t = aead.encrypt_in_place(&nonce, &aad, &mut buf);
+            assert!(buf == ct);
+            assert!(t[..] == tag[..]);
+            assert!(aead.decrypt_in_place(&nonce, &aad, &mut buf, &t));
+            assert!(buf == pt);
+        }
+    }
+
+    // Test vectors for IVs with lengths other than 12 bytes (generated
+    // with OpenSSL); same format as KAT_GCM.
+    const KAT_GCM_IV: [&str; 6 * 3] = [
+        "b71a9c2e1def4aa5b01ca459fa275ce988f5586d6873c375dde8de2a220454d6",
+        "f7075a4a272f2e74e7927fcaf88364ef",
+        "",
+        "e2a70838185fcc49a5c5f5c56daa950688af9d89bb35683d8228508a53c6d830d8bc57e7eadd1628",
+        "8b1f253ff4f65cb0717b802cdebd8aa0c79bd0c52f16611898f6bdb3c8923f28b53c6e19e14e47f6",
+        "1536302db5afac767771e211bcf23062",
+
+        "1dd2e2445b683960050dcb836576ba07",
+        "ac849a3e37c533fc",
+        "0e52885a31df19",
+        "0f142dbc5ecf10017614c8d0e0bfb1cc",
+        "8f33d904584a240f736e13bd55208790",
+        "9740768e7181160c7224df8ab5f41ac5",
+
+        "bfb4eca08744e83773d8662354d21c24c25efb0c1dd1516ba8b48de9de0a5ffa",
+        "a3213714a0abb44cec094022f49581c4a818c2debad32162cdbe6dd953e55480c84aae8c2a38a2d188a182d0a1584766259da7221b8b08a374f26132",
+        "6163e1",
+        "8e5f81df95790848a70e5b307abe68c66281e07220325b6732c7176b30f98e499361dea7705fea728865ddb3262ebf7bd6551dd17bf7299a836714fddb3447dead298cb7b8234403b9a4b51479583c1050305ce24d5d08fab8d2aff78ef04ffe4d59f97f",
+        "1edb168a826babb5bd7785d485523343242a6b6196732fa8ba2ffb187a95f56ffda234a30c101d7e78b778607fb060b02c0f30e94b0ddb791be6899f487ae5eb477a5107734c58fed70db12d5f50838db0c3df9ec2472b8a770ad6b22758ebd842127a93",
+        "f0ceceb99edbf12fdfc85fd5759a5d40",
+    ];
+
+    #[test]
+    fn gcm_iv() {
+        for i in 0..(KAT_GCM_IV.len() / 6) {
+            let key = hex::decode(KAT_GCM_IV[6 * i]).unwrap();
+            let iv = hex::decode(KAT_GCM_IV[6 * i + 1]).unwrap();
+            let aad = hex::decode(KAT_GCM_IV[6 * i + 2]).unwrap();
+            let pt = hex::decode(KAT_GCM_IV[6 * i + 3]).unwrap();
+            let ct = hex::decode(KAT_GCM_IV[6 * i + 4]).unwrap();
+            let tag = hex::decode(KAT_GCM_IV[6 * i + 5]).unwrap();
+            let aead = AesGcm::new(&key);
+            let mut buf: Vec<u8> = pt.clone();
+            let t = aead.encrypt_in_place_iv(&iv, &aad, &mut buf);
+            assert!(buf == ct);
+            assert!(t[..] == tag[..]);
+            let mut bad = t;
+            bad[0] ^= 0x80;
+            assert!(!aead.decrypt_in_place_iv(&iv, &aad, &mut buf, &bad));
+            assert!(buf == ct);
+            assert!(aead.decrypt_in_place_iv(&iv, &aad, &mut buf, &t));
+            assert!(buf == pt);
+        }
+    }
+}
@@ -5,17 +5,21 @@
 use core::convert::TryFrom;
 
 /// BLAKE2s context (unkeyed).
+#[derive(Clone)]
 #[repr(align(32))]
 pub struct Blake2s {
     h: [u32; 8],
     buf: [u8; BUF_LEN],
     ctr: u64,
     out_len: usize,
+    iv: [u32; 8],
+    last_node: bool,
 }
 
 /// BLAKE2s context (with a key). The key is saved internally, so that
 /// multiple successive hashing operations can be performed with the same
 /// context without reinjecting the key each time.
+#[derive(Clone)]
 #[repr(align(32))]
 pub struct KeyedBlake2s {
     ctx: Blake2s,
@@ -25,9 +29,160 @@ pub struct KeyedBlake2s {
 
 const BUF_LEN: usize = 64;
 
+/// BLAKE2s parameter block.
+///
+/// The default parameters (from `new()`) are those of plain sequential
+/// hashing with a 32-byte output. The setters can be chained, e.g.:
+/// `Blake2sParams::new().digest_length(16).personal(b"MyProto")`. The
+/// key length field is not set here, but by `KeyedBlake2s::with_params()`.
+/// The node offset and XOF length fields follow the BLAKE2X layout
+/// (32-bit node offset, 16-bit XOF length); for plain tree hashing, the
+/// XOF length is zero.
+#[derive(Clone, Copy, Debug)]
+pub struct Blake2sParams {
+    digest_length: u8,
+    key_length: u8,
+    fanout: u8,
+    depth: u8,
+    leaf_length: u32,
+    node_offset: u32,
+    xof_length: u16,
+    node_depth: u8,
+    inner_length: u8,
+    salt: [u8; 8],
+    personal: [u8; 8],
+    last_node: bool,
+}
+
+impl Blake2sParams {
+
+    /// Get the default parameters (sequential mode, 32-byte output, no
+    /// salt or personalization).
+    pub const fn new() -> Self {
+        Self {
+            digest_length: 32,
+            key_length: 0,
+            fanout: 1,
+            depth: 1,
+            leaf_length: 0,
+            node_offset: 0,
+            xof_length: 0,
+            node_depth: 0,
+            inner_length: 0,
+            salt: [0u8; 8],
+            personal: [0u8; 8],
+            last_node: false,
+        }
+    }
+
+    /// Set the digest (output) length, in bytes (1 to 32).
+    pub fn digest_length(mut self, len: usize) -> Self {
+        assert!((1..=32).contains(&len));
+        self.digest_length = len as u8;
+        self
+    }
+
+    /// Set the fanout (0 for unlimited).
+    pub fn fanout(mut self, fanout: u8) -> Self {
+        self.fanout = fanout;
+        self
+    }
+
+    /// Set the maximal depth (1 to 255 for hashing; 0 is used only
+    /// internally by BLAKE2Xs).
+    pub fn depth(mut self, depth: u8) -> Self {
+        self.depth = depth;
+        self
+    }
+
+    /// Set the leaf maximal byte length (0 for unlimited).
+    pub fn leaf_length(mut self, len: u32) -> Self {
+        self.leaf_length = len;
+        self
+    }
+
+    /// Set the node offset.
+    pub fn node_offset(mut self, offset: u32) -> Self {
+        self.node_offset = offset;
+        self
+    }
+
+    /// Set the XOF digest length (BLAKE2X only; 0 otherwise).
+    pub fn xof_length(mut self, len: u16) -> Self {
+        self.xof_length = len;
+        self
+    }
+
+    /// Set the node depth (0 for leaves).
+    pub fn node_depth(mut self, depth: u8) -> Self {
+        self.node_depth = depth;
+        self
+    }
+
+    /// Set the inner hash byte length (0 to 32).
+    pub fn inner_length(mut self, len: usize) -> Self {
+        assert!(len <= 32);
+        self.inner_length = len as u8;
+        self
+    }
+
+    /// Set the salt (at most 8 bytes; a shorter value is padded with
+    /// zeros).
+    pub fn salt(mut self, salt: &[u8]) -> Self {
+        assert!(salt.len() <= 8);
+        self.salt = [0u8; 8];
+        self.salt[..salt.len()].copy_from_slice(salt);
+        self
+    }
+
+    /// Set the personalization string (at most 8 bytes; a shorter value
+    /// is padded with zeros).
+    pub fn personal(mut self, personal: &[u8]) -> Self {
+        assert!(personal.len() <= 8);
+        self.personal = [0u8; 8];
+        self.personal[..personal.len()].copy_from_slice(personal);
+        self
+    }
+
+    /// Set the "last node" flag (for the last node of each level in
+    /// tree hashing).
+    pub fn last_node(mut self, last_node: bool) -> Self {
+        self.last_node = last_node;
+        self
+    }
+
+    // Get the parameter block as eight 32-bit words.
+    fn to_words(self) -> [u32; 8] {
+        [
+            (self.digest_length as u32)
+                | ((self.key_length as u32) << 8)
+                | ((self.fanout as u32) << 16)
+                | ((self.depth as u32) << 24),
+            self.leaf_length,
+            self.node_offset,
+            (self.xof_length as u32)
+                | ((self.node_depth as u32) << 16)
+                | ((self.inner_length as u32) << 24),
+            u32::from_le_bytes(*<&[u8; 4]>::try_from(&self.salt[..4]).unwrap()),
+            u32::from_le_bytes(*<&[u8; 4]>::try_from(&self.salt[4..]).unwrap()),
+            u32::from_le_bytes(*<&[u8; 4]>::try_from(&self.personal[..4]).unwrap()),
+            u32::from_le_bytes(*<&[u8; 4]>::try_from(&self.personal[4..]).unwrap()),
+        ]
+    }
+}
+
+impl Default for Blake2sParams {
+
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Convenience wrapper for BLAKE2s (unkeyed) with a 256-bit output, which
 /// is the most common combination. That wrapper offers finalization functions
 /// that return the computed output as a fixed-size 32-byte array.
+#[derive(Clone)]
 pub struct Blake2s256(Blake2s);
 
 impl Blake2s256 {
@@ -92,12 +247,20 @@ impl KeyedBlake2s {
     /// if the key has length 0, then this is equivalent to unkeyed
     /// hashing.
     pub fn new(out_len: usize, key: &[u8]) -> Self {
+        Self::with_params(&Blake2sParams::new().digest_length(out_len), key)
+    }
+
+    /// Initialize the context with the provided parameters. The key
+    /// length must be between 0 and 32 bytes; the key length field of
+    /// the parameter block is set from the key.
+    pub fn with_params(params: &Blake2sParams, key: &[u8]) -> Self {
         assert!(key.len() <= 32);
-        let mut ctx = Blake2s::new(out_len);
+        let mut p = *params;
+        p.key_length = key.len() as u8;
+        let mut ctx = Blake2s::with_params(&p);
         let mut saved_key = [0u8; 32];
         let saved_key_len = key.len();
         if saved_key_len > 0 {
-            ctx.h[0] ^= (saved_key_len as u32) << 8;
             saved_key[..saved_key_len].copy_from_slice(key);
             ctx.buf[..saved_key_len].copy_from_slice(key);
             ctx.ctr = BUF_LEN as u64;
@@ -116,8 +279,8 @@ impl KeyedBlake2s {
     pub fn reset(&mut self) {
         self.ctx.reset();
         if self.saved_key_len > 0 {
-            self.ctx.h[0] ^= (self.saved_key_len as u32) << 8;
-            self.ctx.buf[..self.saved_key_len].copy_from_slice(&self.saved_key);
+            self.ctx.buf[..self.saved_key_len].copy_from_slice(
+                &self.saved_key[..self.saved_key_len]);
             self.ctx.ctr = BUF_LEN as u64;
         }
     }
@@ -150,6 +313,560 @@ impl KeyedBlake2s {
         sh.update(data);
         sh.finalize_write(out);
     }
+
+    /// Multi-buffer hashing: hash eight independent messages (which may
+    /// have distinct lengths) with the same key and output length. The
+    /// output for `data[i]` is written into the first `out_len` bytes of
+    /// `out[i]`. See `Blake2s::hash_x8()`.
+    pub fn hash_x8(out_len: usize, key: &[u8], data: &[&[u8]; 8],
+        out: &mut [[u8; 32]; 8])
+    {
+        hash_x8_inner(&Blake2sParams::new(), key, data, out_len, out);
+    }
+
+    /// Multi-buffer hashing with explicit parameters (see `hash_x8()`);
+    /// the output length is the digest length from the parameters.
+    pub fn hash_x8_with_params(params: &Blake2sParams, key: &[u8],
+        data: &[&[u8]; 8], out: &mut [[u8; 32]; 8])
+    {
+        hash_x8_inner(params, key, data, params.digest_length as usize, out);
+    }
+}
+
+// Block processing function for x86_64 with SSSE3 and SSE4.1 or AVX2.
+// The two versions differ only in the blend operation: _mm_blend_epi32()
+// requires AVX2, while _mm_blend_epi16() (with each mask bit doubled)
+// requires only SSE4.1.
+#[cfg(target_arch = "x86_64")]
+macro_rules! blend_avx2 { ($a: expr, $b: expr, $m: expr) => {
+    _mm_blend_epi32($a, $b, $m)
+} }
+
+#[cfg(target_arch = "x86_64")]
+macro_rules! blend_sse41 { ($a: expr, $b: expr, $m: expr) => {
+    _mm_blend_epi16($a, $b,
+        (($m & 1) * 0x03) | (($m & 2) * 0x06)
+        | (($m & 4) * 0x0C) | (($m & 8) * 0x18))
+} }
+
+#[cfg(target_arch = "x86_64")]
+macro_rules! define_process_block_simd { ($name: ident, $feat: expr, $blend: ident) => {
+    #[target_feature(enable = $feat)]
+    #[allow(dead_code)]
+    unsafe fn $name(h: &mut [u32; 8], block: &[u8], ctr: u64,
+        last: bool, last_node: bool)
+    {
+        // x86_64 + SSSE3 + (SSE4.1 or AVX2)
+        use core::arch::x86_64::*;
+
+        let xror8 = _mm_setr_epi8(
+            1, 2, 3, 0, 5, 6, 7, 4,
+            9, 10, 11, 8, 13, 14, 15, 12);
+        let xror16 = _mm_setr_epi8(
+            2, 3, 0, 1, 6, 7, 4, 5,
+            10, 11, 8, 9, 14, 15, 12, 13);
+
+        // Initialize state.
+        let xh0 = _mm_loadu_si128(h.as_ptr() as *const __m128i);
+        let xh1 = _mm_loadu_si128(h.as_ptr().add(4) as *const __m128i);
+        let mut xv0 = xh0;
+        let mut xv1 = xh1;
+        let mut xv2 = _mm_loadu_si128(Self::IV.as_ptr() as *const __m128i);
+        let mut xv3 = _mm_loadu_si128(
+            Self::IV.as_ptr().add(4) as *const __m128i);
+        xv3 = _mm_xor_si128(xv3, _mm_setr_epi32(
+            ctr as i32, (ctr >> 32) as i32,
+            -(last as i32), -((last & last_node) as i32)));
+
+        // Load data and move it into the proper order for the first round:
+        //   xm0:  0  2  4  6
+        //   xm1:  1  3  5  7
+        //   xm2:  8 10 12 14
+        //   xm3:  9 11 13 15
+        let block = &block[..64];
+        let xm0 = _mm_loadu_si128(block.as_ptr() as *const __m128i);
+        let xm1 = _mm_loadu_si128(block.as_ptr().add(16) as *const __m128i);
+        let xm2 = _mm_loadu_si128(block.as_ptr().add(32) as *const __m128i);
+        let xm3 = _mm_loadu_si128(block.as_ptr().add(48) as *const __m128i);
+
+        let xn0 = _mm_shuffle_epi32(xm0, 0xD8);
+        let xn1 = _mm_shuffle_epi32(xm1, 0xD8);
+        let xm0 = _mm_unpacklo_epi64(xn0, xn1);
+        let xm1 = _mm_unpackhi_epi64(xn0, xn1);
+
+        let xn2 = _mm_shuffle_epi32(xm2, 0xD8);
+        let xn3 = _mm_shuffle_epi32(xm3, 0xD8);
+        let xm2 = _mm_unpacklo_epi64(xn2, xn3);
+        let xm3 = _mm_unpackhi_epi64(xn2, xn3);
+
+        macro_rules! g4 { ($xx: expr, $xy: expr) => {
+            xv0 = _mm_add_epi32(xv0, _mm_add_epi32(xv1, $xx));
+            xv3 = _mm_shuffle_epi8(_mm_xor_si128(xv0, xv3), xror16);
+            xv2 = _mm_add_epi32(xv2, xv3);
+            let xtg = _mm_xor_si128(xv1, xv2);
+            xv1 = _mm_or_si128(
+                _mm_srli_epi32(xtg, 12), _mm_slli_epi32(xtg, 20));
+            xv0 = _mm_add_epi32(xv0, _mm_add_epi32(xv1, $xy));
+            xv3 = _mm_shuffle_epi8(_mm_xor_si128(xv0, xv3), xror8);
+            xv2 = _mm_add_epi32(xv2, xv3);
+            let xtg = _mm_xor_si128(xv1, xv2);
+            xv1 = _mm_or_si128(
+                _mm_srli_epi32(xtg, 7), _mm_slli_epi32(xtg, 25));
+        } }
+
+        macro_rules! rr { ($i0: expr, $i1: expr, $i2: expr, $i3: expr) => {
+            g4!($i0, $i1);
+            xv1 = _mm_shuffle_epi32(xv1, 0x39);
+            xv2 = _mm_shuffle_epi32(xv2, 0x4E);
+            xv3 = _mm_shuffle_epi32(xv3, 0x93);
+            g4!($i2, $i3);
+            xv1 = _mm_shuffle_epi32(xv1, 0x93);
+            xv2 = _mm_shuffle_epi32(xv2, 0x4E);
+            xv3 = _mm_shuffle_epi32(xv3, 0x39);
+        } }
+
+        // round 0
+        rr!(xm0, xm1, xm2, xm3);
+
+        // round 1
+        let xt0 = _mm_shuffle_epi32(xm0, 0x00);
+        let xt1 = _mm_shuffle_epi32(xm0, 0xC8);
+        let xt2 = _mm_shuffle_epi32(xm1, 0x70);
+        let xt3 = _mm_shuffle_epi32(xm1, 0x80);
+        let xt4 = _mm_shuffle_epi32(xm2, 0x01);
+        let xt5 = _mm_shuffle_epi32(xm2, 0x02);
+        let xt6 = _mm_shuffle_epi32(xm2, 0x03);
+        let xt7 = _mm_shuffle_epi32(xm3, 0x80);
+        let xt8 = _mm_shuffle_epi32(xm3, 0x10);
+        let xt9 = _mm_shuffle_epi32(xm3, 0x30);
+        let xn0 = $blend!(
+            $blend!(xt6, xt1, 0x02),
+            xt7, 0x0C);
+        let xn1 = $blend!(
+            $blend!(xt4, xt9, 0x04),
+            xt1, 0x08);
+        let xn2 = $blend!(
+            $blend!(xt3, xt0, 0x02),
+            xt8, 0x04);
+        let xn3 = $blend!(
+            $blend!(xt5, xm0, 0x02),
+            xt2, 0x0C);
+        rr!(xn0, xn1, xn2, xn3);
+
+        // round 2
+        let xt0 = _mm_shuffle_epi32(xn0, 0x40);
+        let xt1 = _mm_shuffle_epi32(xn0, 0x80);
+        let xt2 = _mm_shuffle_epi32(xn1, 0x80);
+        let xt3 = _mm_shuffle_epi32(xn1, 0x0D);
+        let xt4 = _mm_shuffle_epi32(xn2, 0x04);
+        let xt5 = _mm_shuffle_epi32(xn2, 0x32);
+        let xt6 = _mm_shuffle_epi32(xn3, 0x10);
+        let xt7 = _mm_shuffle_epi32(xn3, 0x2C);
+        let xm0 = $blend!(
+            $blend!(xt5, xt6, 0x02),
+            xt2, 0x08);
+        let xm1 = $blend!(
+            $blend!(xt3, xt4, 0x02),
+            $blend!(xt6, xn0, 0x08), 0x0C);
+        let xm2 = $blend!(
+            $blend!(xt2, xt7, 0x06),
+            xt1, 0x08);
+        let xm3 = $blend!(
+            $blend!(xt0, xt3, 0x02),
+            xt4, 0x04);
+        rr!(xm0, xm1, xm2, xm3);
+
+        // round 3
+        let xt0 = _mm_shuffle_epi32(xm0, 0x10);
+        let xt1 = _mm_shuffle_epi32(xm0, 0xC8);
+        let xt2 = _mm_shuffle_epi32(xm1, 0x10);
+        let xt3 = _mm_shuffle_epi32(xm1, 0x32);
+        let xt4 = _mm_shuffle_epi32(xm2, 0x03);
+        let xt5 = _mm_shuffle_epi32(xm2, 0x06);
+        let xt6 = _mm_shuffle_epi32(xm3, 0x39);
+        let xn0 = $blend!(
+            $blend!(xt5, xt3, 0x04),
+            xt0, 0x08);
+        let xn1 = $blend!(
+            $blend!(xt4, xt6, 0x0A),
+            xt0, 0x04);
+        let xn2 = $blend!(
+            $blend!(xt3, xt1, 0x0A),
+            xt6, 0x04);
+        let xn3 = $blend!(
+            $blend!(xt6, xt4, 0x02),
+            xt2, 0x0C);
+        rr!(xn0, xn1, xn2, xn3);
+
+        // round 4
+        let xt0 = _mm_shuffle_epi32(xn0, 0x80);
+        let xt1 = _mm_shuffle_epi32(xn0, 0x4C);
+        let xt2 = _mm_shuffle_epi32(xn1, 0x09);
+        let xt3 = _mm_shuffle_epi32(xn1, 0x03);
+        let xt4 = _mm_shuffle_epi32(xn2, 0x04);
+        let xt5 = _mm_shuffle_epi32(xn3, 0x40);
+        let xt6 = _mm_shuffle_epi32(xn3, 0x32);
+        let xm0 = $blend!(
+            $blend!(xn1, xt4, 0x06),
+            xt5, 0x08);
+        let xm1 = $blend!(
+            $blend!(xt6, xt0, 0x02),
+            xn2, 0x0C);
+        let xm2 = $blend!(
+            $blend!(xt3, xt1, 0x0A),
+            xt5, 0x04);
+        let xm3 = $blend!(
+            $blend!(xt2, xt6, 0x04),
+            xt0, 0x08);
+        rr!(xm0, xm1, xm2, xm3);
+
+        // round 5
+        let xt0 = _mm_shuffle_epi32(xm0, 0x04);
+        let xt1 = _mm_shuffle_epi32(xm0, 0x0E);
+        let xt2 = _mm_shuffle_epi32(xm1, 0x04);
+        let xt3 = _mm_shuffle_epi32(xm1, 0x32);
+        let xt4 = _mm_shuffle_epi32(xm2, 0x08);
+        let xt5 = _mm_shuffle_epi32(xm2, 0xD0);
+        let xt6 = _mm_shuffle_epi32(xm3, 0x01);
+        let xt7 = _mm_shuffle_epi32(xm3, 0x83);
+        let xn0 = $blend!(
+            $blend!(xt1, xt4, 0x02),
+            $blend!(xt2, xt7, 0x08), 0x0C);
+        let xn1 = $blend!(
+            $blend!(xt6, xt1, 0x02),
+            xt5, 0x0C);
+        let xn2 = $blend!(
+            $blend!(xt3, xt2, 0x02),
+            xt6, 0x08);
+        let xn3 = $blend!(
+            $blend!(xt7, xt0, 0x0A),
+            xt4, 0x04);
+        rr!(xn0, xn1, xn2, xn3);
+
+        // round 6
+        let xt0 = _mm_shuffle_epi32(xn0, 0xC6);
+        let xt1 = _mm_shuffle_epi32(xn1, 0x40);
+        let xt2 = _mm_shuffle_epi32(xn1, 0x8C);
+        let xt3 = _mm_shuffle_epi32(xn2, 0x09);
+        let xt4 = _mm_shuffle_epi32(xn2, 0x0C);
+        let xt5 = _mm_shuffle_epi32(xn3, 0x01);
+        let xt6 = _mm_shuffle_epi32(xn3, 0x30);
+        let xm0 = $blend!(
+            $blend!(xt1, xt4, 0x0A),
+            xn3, 0x04);
+        let xm1 = $blend!(
+            $blend!(xt5, xt3, 0x02),
+            xt1, 0x08);
+        let xm2 = $blend!(xt0, xt6, 0x04);
+        let xm3 = $blend!(
+            $blend!(xt3, xt2, 0x0A),
+            xt0, 0x04);
+        rr!(xm0, xm1, xm2, xm3);
+
+        // round 7
+        let xt0 = _mm_shuffle_epi32(xm0, 0x0C);
+        let xt1 = _mm_shuffle_epi32(xm0, 0x18);
+        let xt2 = _mm_shuffle_epi32(xm1, 0xC2);
+        let xt3 = _mm_shuffle_epi32(xm2, 0x10);
+        let xt4 = _mm_shuffle_epi32(xm2, 0xB0);
+        let xt5 = _mm_shuffle_epi32(xm3, 0x40);
+        let xt6 = _mm_shuffle_epi32(xm3, 0x83);
+        let xn0 = $blend!(
+            $blend!(xt2, xt5, 0x0A),
+            xt0, 0x04);
+        let xn1 = $blend!(
+            $blend!(xt6, xt1, 0x06),
+            xt4, 0x08);
+        let xn2 = $blend!(
+            $blend!(xm1, xt4, 0x04),
+            xt6, 0x08);
+        let xn3 = $blend!(
+            $blend!(xt3, xt0, 0x02),
+            xt2, 0x08);
+        rr!(xn0, xn1, xn2, xn3);
+
+        // round 8
+        let xt0 = _mm_shuffle_epi32(xn0, 0x02);
+        let xt1 = _mm_shuffle_epi32(xn0, 0x34);
+        let xt2 = _mm_shuffle_epi32(xn1, 0x0C);
+        let xt3 = _mm_shuffle_epi32(xn2, 0x03);
+        let xt4 = _mm_shuffle_epi32(xn2, 0x81);
+        let xt5 = _mm_shuffle_epi32(xn3, 0x02);
+        let xt6 = _mm_shuffle_epi32(xn3, 0xD0);
+        let xm0 = $blend!(
+            $blend!(xt5, xn1, 0x02),
+            xt2, 0x04);
+        let xm1 = $blend!(
+            $blend!(xt4, xt2, 0x02),
+            xt1, 0x04);
+        let xm2 = $blend!(
+            $blend!(xt0, xn1, 0x04),
+            xt6, 0x08);
+        let xm3 = $blend!(
+            $blend!(xt3, xt1, 0x02),
+            xt6, 0x04);
+        rr!(xm0, xm1, xm2, xm3);
+
+        // round 9
+        let xt0 = _mm_shuffle_epi32(xm0, 0xC6);
+        let xt1 = _mm_shuffle_epi32(xm1, 0x2C);
+        let xt2 = _mm_shuffle_epi32(xm2, 0x40);
+        let xt3 = _mm_shuffle_epi32(xm2, 0x83);
+        let xt4 = _mm_shuffle_epi32(xm3, 0xD8);
+        let xn0 = $blend!(
+            $blend!(xt3, xt1, 0x02),
+            xt4, 0x04);
+        let xn1 = $blend!(xt4, xt0, 0x04);
+        let xn2 = $blend!(
+            $blend!(xm1, xt1, 0x04),
+            xt2, 0x08);
+        let xn3 = $blend!(xt0, xt2, 0x04);
+        rr!(xn0, xn1, xn2, xn3);
+
+        let xh0 = _mm_xor_si128(xh0, _mm_xor_si128(xv0, xv2));
+        let xh1 = _mm_xor_si128(xh1, _mm_xor_si128(xv1, xv3));
+        _mm_storeu_si128(h.as_mut_ptr() as *mut __m128i, xh0);
+        _mm_storeu_si128(h.as_mut_ptr().add(4) as *mut __m128i, xh1);
+    }
+} }
+
+// SIMD implementation level on x86_64: 1 = SSE2, 2 = SSE4.1, 3 = AVX2.
+// If AVX2 support is known at compile-time, then it is used. Otherwise,
+// with the standard library, the CPU is queried at runtime (once); without
+// the standard library, only compile-time target features are used.
+#[cfg(target_arch = "x86_64")]
+#[inline(always)]
+fn simd_level() -> u32 {
+    #[cfg(target_feature = "avx2")]
+    {
+        3
+    }
+
+    #[cfg(all(not(target_feature = "avx2"), feature = "std"))]
+    {
+        use core::sync::atomic::{AtomicU32, Ordering};
+        static LEVEL: AtomicU32 = AtomicU32::new(0);
+        let mut x = LEVEL.load(Ordering::Relaxed);
+        if x == 0 {
+            x = if std::is_x86_feature_detected!("avx2") {
+                3
+            } else if std::is_x86_feature_detected!("sse4.1")
+                && std::is_x86_feature_detected!("ssse3")
+            {
+                2
+            } else {
+                1
+            };
+            LEVEL.store(x, Ordering::Relaxed);
+        }
+        x
+    }
+
+    #[cfg(all(not(target_feature = "avx2"), not(feature = "std"),
+        target_feature = "sse4.1", target_feature = "ssse3"))]
+    {
+        2
+    }
+
+    #[cfg(all(not(target_feature = "avx2"), not(feature = "std"),
+        not(all(target_feature = "sse4.1", target_feature = "ssse3"))))]
+    {
+        1
+    }
+}
+
+// Multi-buffer hashing: eight independent messages, with the same
+// parameters and key. On x86_64 with AVX2, the eight messages are
+// processed in parallel (one message per 32-bit lane); otherwise, they
+// are processed one at a time.
+fn hash_x8_inner(params: &Blake2sParams, key: &[u8], data: &[&[u8]; 8],
+    out_len: usize, out: &mut [[u8; 32]; 8])
+{
+    assert!(key.len() <= 32);
+    let mut p = params.digest_length(out_len);
+    p.key_length = key.len() as u8;
+
+    #[cfg(target_arch = "x86_64")]
+    if simd_level() >= 3 {
+        unsafe {
+            hash_x8_avx2(&p, key, data, out);
+        }
+        return;
+    }
+
+    hash_x8_portable(&p, key, data, out);
+}
+
+// Multi-buffer hashing, one message at a time. The key length has
+// already been set in the parameters.
+fn hash_x8_portable(params: &Blake2sParams, key: &[u8], data: &[&[u8]; 8],
+    out: &mut [[u8; 32]; 8])
+{
+    for (d, o) in data.iter().zip(out.iter_mut()) {
+        let mut sh = KeyedBlake2s::with_params(params, key);
+        sh.update(d);
+        sh.finalize_write(o);
+    }
+}
+
+// Multi-buffer hashing with AVX2. The key length has already been set in
+// the parameters.
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+unsafe fn hash_x8_avx2(params: &Blake2sParams, key: &[u8], data: &[&[u8]; 8],
+    out: &mut [[u8; 32]; 8])
+{
+    use core::arch::x86_64::*;
+
+    const SIGMA: [[usize; 16]; 10] = [
+        [  0,  1,  2,  3,  4,  5,  6,  7,  8,  9, 10, 11, 12, 13, 14, 15 ],
+        [ 14, 10,  4,  8,  9, 15, 13,  6,  1, 12,  0,  2, 11,  7,  5,  3 ],
+        [ 11,  8, 12,  0,  5,  2, 15, 13, 10, 14,  3,  6,  7,  1,  9,  4 ],
+        [  7,  9,  3,  1, 13, 12, 11, 14,  2,  6,  5, 10,  4,  0, 15,  8 ],
+        [  9,  0,  5,  7,  2,  4, 10, 15, 14,  1, 11, 12,  6,  8,  3, 13 ],
+        [  2, 12,  6, 10,  0, 11,  8,  3,  4, 13,  7,  5, 15, 14,  1,  9 ],
+        [ 12,  5,  1, 15, 14, 13,  4, 10,  0,  7,  6,  3,  9,  2,  8, 11 ],
+        [ 13, 11,  7, 14, 12,  1,  3,  9,  5,  0, 15,  4,  8,  6,  2, 10 ],
+        [  6, 15, 14,  9, 11,  3,  0,  8, 12,  2, 13,  7,  1,  4, 10,  5 ],
+        [ 10,  2,  8,  4,  7,  6,  1,  5, 15, 11,  9, 14,  3, 12, 13,  0 ],
+    ];
+
+    let yror8 = _mm256_setr_epi8(
+        1, 2, 3, 0, 5, 6, 7, 4, 9, 10, 11, 8, 13, 14, 15, 12,
+        1, 2, 3, 0, 5, 6, 7, 4, 9, 10, 11, 8, 13, 14, 15, 12);
+    let yror16 = _mm256_setr_epi8(
+        2, 3, 0, 1, 6, 7, 4, 5, 10, 11, 8, 9, 14, 15, 12, 13,
+        2, 3, 0, 1, 6, 7, 4, 5, 10, 11, 8, 9, 14, 15, 12, 13);
+
+    // Initial state (same for all lanes).
+    let pw = params.to_words();
+    let mut yh = [_mm256_setzero_si256(); 8];
+    for i in 0..8 {
+        yh[i] = _mm256_set1_epi32((Blake2s::IV[i] ^ pw[i]) as i32);
+    }
+
+    // Each message is preceded by the key block, if there is a key. The
+    // total length is the key block length plus the message length; the
+    // number of blocks is at least 1 (for an empty input).
+    let mut kb = [0u8; BUF_LEN];
+    kb[..key.len()].copy_from_slice(key);
+    let klen = if key.is_empty() { 0 } else { BUF_LEN };
+    let mut tlen = [0u64; 8];
+    let mut nb = [0usize; 8];
+    let mut max_nb = 0;
+    for i in 0..8 {
+        let t = klen + data[i].len();
+        tlen[i] = t as u64;
+        nb[i] = core::cmp::max(1, (t + BUF_LEN - 1) >> 6);
+        max_nb = core::cmp::max(max_nb, nb[i]);
+    }
+    let f1 = if params.last_node { -1 } else { 0 };
+
+    for t in 0..max_nb {
+        // Gather the message words, counters and flags for each lane.
+        // Lanes which are already finished get a zero mask so that their
+        // state is not modified.
+        let mut mw = [[0u32; 8]; 16];
+        let mut ctr_lo = [0u32; 8];
+        let mut ctr_hi = [0u32; 8];
+        let mut fl0 = [0i32; 8];
+        let mut fl1 = [0i32; 8];
+        let mut act = [0i32; 8];
+        for i in 0..8 {
+            if t >= nb[i] {
+                continue;
+            }
+            let mut blk = [0u8; BUF_LEN];
+            if t == 0 && klen != 0 {
+                blk = kb;
+            } else {
+                let off = t * BUF_LEN - klen;
+                let end = core::cmp::min(off + BUF_LEN, data[i].len());
+                blk[..(end - off)].copy_from_slice(&data[i][off..end]);
+            }
+            for j in 0..16 {
+                mw[j][i] = u32::from_le_bytes(*<&[u8; 4]>::try_from(
+                    &blk[(4 * j)..(4 * j + 4)]).unwrap());
+            }
+            let c = core::cmp::min(((t + 1) * BUF_LEN) as u64, tlen[i]);
+            ctr_lo[i] = c as u32;
+            ctr_hi[i] = (c >> 32) as u32;
+            if t == nb[i] - 1 {
+                fl0[i] = -1;
+                fl1[i] = f1;
+            }
+            act[i] = -1;
+        }
+
+        let mut ym = [_mm256_setzero_si256(); 16];
+        for j in 0..16 {
+            ym[j] = _mm256_loadu_si256(mw[j].as_ptr() as *const __m256i);
+        }
+        let mut v = [_mm256_setzero_si256(); 16];
+        v[..8].copy_from_slice(&yh);
+        for i in 0..4 {
+            v[8 + i] = _mm256_set1_epi32(Blake2s::IV[i] as i32);
+        }
+        v[12] = _mm256_xor_si256(_mm256_set1_epi32(Blake2s::IV[4] as i32),
+            _mm256_loadu_si256(ctr_lo.as_ptr() as *const __m256i));
+        v[13] = _mm256_xor_si256(_mm256_set1_epi32(Blake2s::IV[5] as i32),
+            _mm256_loadu_si256(ctr_hi.as_ptr() as *const __m256i));
+        v[14] = _mm256_xor_si256(_mm256_set1_epi32(Blake2s::IV[6] as i32),
+            _mm256_loadu_si256(fl0.as_ptr() as *const __m256i));
+        v[15] = _mm256_xor_si256(_mm256_set1_epi32(Blake2s::IV[7] as i32),
+            _mm256_loadu_si256(fl1.as_ptr() as *const __m256i));
+
+        macro_rules! gg {
+            ($a: expr, $b: expr, $c: expr, $d: expr, $x: expr, $y: expr)
+            => {
+                v[$a] = _mm256_add_epi32(v[$a], _mm256_add_epi32(v[$b], $x));
+                v[$d] = _mm256_shuffle_epi8(
+                    _mm256_xor_si256(v[$d], v[$a]), yror16);
+                v[$c] = _mm256_add_epi32(v[$c], v[$d]);
+                let yt = _mm256_xor_si256(v[$b], v[$c]);
+                v[$b] = _mm256_or_si256(
+                    _mm256_srli_epi32(yt, 12), _mm256_slli_epi32(yt, 20));
+                v[$a] = _mm256_add_epi32(v[$a], _mm256_add_epi32(v[$b], $y));
+                v[$d] = _mm256_shuffle_epi8(
+                    _mm256_xor_si256(v[$d], v[$a]), yror8);
+                v[$c] = _mm256_add_epi32(v[$c], v[$d]);
+                let yt = _mm256_xor_si256(v[$b], v[$c]);
+                v[$b] = _mm256_or_si256(
+                    _mm256_srli_epi32(yt, 7), _mm256_slli_epi32(yt, 25));
+            }
+        }
+
+        for s in SIGMA.iter() {
+            gg!(0, 4,  8, 12, ym[s[ 0]], ym[s[ 1]]);
+            gg!(1, 5,  9, 13, ym[s[ 2]], ym[s[ 3]]);
+            gg!(2, 6, 10, 14, ym[s[ 4]], ym[s[ 5]]);
+            gg!(3, 7, 11, 15, ym[s[ 6]], ym[s[ 7]]);
+            gg!(0, 5, 10, 15, ym[s[ 8]], ym[s[ 9]]);
+            gg!(1, 6, 11, 12, ym[s[10]], ym[s[11]]);
+            gg!(2, 7,  8, 13, ym[s[12]], ym[s[13]]);
+            gg!(3, 4,  9, 14, ym[s[14]], ym[s[15]]);
+        }
+
+        let yact = _mm256_loadu_si256(act.as_ptr() as *const __m256i);
+        for i in 0..8 {
+            let yn = _mm256_xor_si256(yh[i], _mm256_xor_si256(v[i], v[i + 8]));
+            yh[i] = _mm256_blendv_epi8(yh[i], yn, yact);
+        }
+    }
+
+    // Write out the results.
+    let out_len = params.digest_length as usize;
+    let mut hw = [[0u32; 8]; 8];
+    for i in 0..8 {
+        _mm256_storeu_si256(hw[i].as_mut_ptr() as *mut __m256i, yh[i]);
+    }
+    for (k, o) in out.iter_mut().enumerate() {
+        let mut r = [0u8; 32];
+        for i in 0..8 {
+            r[(4 * i)..(4 * i + 4)].copy_from_slice(&hw[i][k].to_le_bytes());
+        }
+        o[..out_len].copy_from_slice(&r[..out_len]);
+    }
 }
 
 impl Blake2s {
@@ -162,14 +879,24 @@ impl Blake2s {
     /// Initialize the context. The output length (in bytes) MUST be
     /// between 1 and 32 bytes (inclusive).
     pub fn new(out_len: usize) -> Self {
-        assert!(1 <= out_len && out_len <= 32);
-        let mut h = Self::IV;
-        h[0] ^= 0x01010000 ^ (out_len as u32);
+        Self::with_params(&Blake2sParams::new().digest_length(out_len))
+    }
+
+    /// Initialize the context with the provided parameters. The output
+    /// length is the digest length from the parameter block.
+    pub fn with_params(params: &Blake2sParams) -> Self {
+        let mut iv = Self::IV;
+        let pw = params.to_words();
+        for i in 0..8 {
+            iv[i] ^= pw[i];
+        }
         Self {
-            h: h,
+            h: iv,
             buf: [0u8; BUF_LEN],
             ctr: 0,
-            out_len: out_len,
+            out_len: params.digest_length as usize,
+            iv,
+            last_node: params.last_node,
         }
     }
 
@@ -198,7 +925,7 @@ impl Blake2s {
         }
 
         // Process the buffered block.
-        Self::process_block(&mut self.h, &self.buf, self.ctr, false);
+        Self::process_block(&mut self.h, &self.buf, self.ctr, false, false);
 
         // Process all subsequent full blocks, except the last.
         while j < data.len() {
@@ -210,7 +937,7 @@ impl Blake2s {
             }
             self.ctr += BUF_LEN as u64;
             let j2 = j + BUF_LEN;
-            Self::process_block(&mut self.h, &data[j..j2], self.ctr, false);
+            Self::process_block(&mut self.h, &data[j..j2], self.ctr, false, false);
             j = j2;
         }
     }
@@ -218,8 +945,7 @@ impl Blake2s {
     /// Reset this context.
     #[inline]
     pub fn reset(&mut self) {
-        self.h[..].copy_from_slice(&Self::IV);
-        self.h[0] ^= 0x01010000 ^ (self.out_len as u32);
+        self.h = self.iv;
         self.buf[..].copy_from_slice(&[0u8; BUF_LEN]);
         self.ctr = 0;
     }
@@ -255,6 +981,15 @@ impl Blake2s {
         sh.finalize_write(out);
     }
 
+    /// Multi-buffer hashing: hash eight independent messages (which may
+    /// have distinct lengths) with the same output length. The output for
+    /// `data[i]` is written into the first `out_len` bytes of `out[i]`.
+    /// The result is identical to that of `hash_into()` on each message,
+    /// but with AVX2 the eight messages are processed in parallel.
+    pub fn hash_x8(out_len: usize, data: &[&[u8]; 8], out: &mut [[u8; 32]; 8]) {
+        hash_x8_inner(&Blake2sParams::new(), &[], data, out_len, out);
+    }
+
     // Finalize this context and get a 32-byte output. Nominally, that
     // output should be truncated to the configured output size.
     fn inner_finalize(&mut self) -> [u8; 32] {
@@ -269,7 +1004,8 @@ impl Blake2s {
         }
 
         // Process the last (padded) block.
-        Self::process_block(&mut self.h, &self.buf, self.ctr, true);
+        Self::process_block(&mut self.h, &self.buf, self.ctr,
+            true, self.last_node);
 
         // Write out the result.
         let mut r = [0u8; 32];
@@ -292,631 +1028,766 @@ impl Blake2s {
 
     // Internal block processing function. 8-word state is `h`; the block
     // data is 64 bytes. The current input counter (`ctr`) is provided.
-    // For the final block, `last` is `true`.
-    fn process_block(h: &mut [u32; 8], block: &[u8], ctr: u64, last: bool) {
-        #[cfg(not(any(
-            target_arch = "x86_64")))]
-        {
-            let mut v = [0u32; 16];
-            v[..8].copy_from_slice(&h[..]);
-            v[8..].copy_from_slice(&Self::IV);
-            v[12] ^= ctr as u32;
-            v[13] ^= (ctr >> 32) as u32;
-            if last {
-                v[14] = !v[14];
+    // For the final block, `last` is `true`; `last_node` is then set for
+    // the last node of a tree level (tree hashing modes only).
+    fn process_block(h: &mut [u32; 8], block: &[u8], ctr: u64,
+        last: bool, last_node: bool)
+    {
+        #[cfg(target_arch = "x86_64")]
+        unsafe {
+            match simd_level() {
+                3 => Self::process_block_avx2(h, block, ctr, last, last_node),
+                2 => Self::process_block_sse41(h, block, ctr, last, last_node),
+                _ => Self::process_block_sse2(h, block, ctr, last, last_node),
             }
+        }
 
-            let mut m = [0u32; 16];
-            for i in 0..16 {
-                m[i] = u32::from_le_bytes(*<&[u8; 4]>::try_from(
-                    &block[(4 * i)..(4 * i + 4)]).unwrap());
-            }
+        #[cfg(not(target_arch = "x86_64"))]
+        Self::process_block_portable(h, block, ctr, last, last_node);
+    }
 
-            macro_rules! gg {
-                ($a: expr, $b: expr, $c: expr, $d: expr, $x: expr, $y: expr)
-                => {
-                    v[$a] = v[$a].wrapping_add(v[$b].wrapping_add($x));
-                    v[$d] = (v[$d] ^ v[$a]).rotate_right(16);
-                    v[$c] = v[$c].wrapping_add(v[$d]);
-                    v[$b] = (v[$b] ^ v[$c]).rotate_right(12);
-                    v[$a] = v[$a].wrapping_add(v[$b].wrapping_add($y));
-                    v[$d] = (v[$d] ^ v[$a]).rotate_right(8);
-                    v[$c] = v[$c].wrapping_add(v[$d]);
-                    v[$b] = (v[$b] ^ v[$c]).rotate_right(7);
-                }
+    // Portable implementation of the block processing function.
+    #[allow(dead_code)]
+    fn process_block_portable(h: &mut [u32; 8], block: &[u8], ctr: u64,
+        last: bool, last_node: bool)
+    {
+        let mut v = [0u32; 16];
+        v[..8].copy_from_slice(&h[..]);
+        v[8..].copy_from_slice(&Self::IV);
+        v[12] ^= ctr as u32;
+        v[13] ^= (ctr >> 32) as u32;
+        if last {
+            v[14] = !v[14];
+            if last_node {
+                v[15] = !v[15];
             }
+        }
 
-            macro_rules! rr {
-                ($s0: expr, $s1: expr, $s2: expr, $s3: expr,
-                 $s4: expr, $s5: expr, $s6: expr, $s7: expr,
-                 $s8: expr, $s9: expr, $sA: expr, $sB: expr,
-                 $sC: expr, $sD: expr, $sE: expr, $sF: expr)
-                => {
-                    gg!(0, 4,  8, 12, m[$s0], m[$s1]);
-                    gg!(1, 5,  9, 13, m[$s2], m[$s3]);
-                    gg!(2, 6, 10, 14, m[$s4], m[$s5]);
-                    gg!(3, 7, 11, 15, m[$s6], m[$s7]);
-                    gg!(0, 5, 10, 15, m[$s8], m[$s9]);
-                    gg!(1, 6, 11, 12, m[$sA], m[$sB]);
-                    gg!(2, 7,  8, 13, m[$sC], m[$sD]);
-                    gg!(3, 4,  9, 14, m[$sE], m[$sF]);
-                }
-            }
-            rr!( 0,  1,  2,  3,  4,  5,  6,  7,  8,  9, 10, 11, 12, 13, 14, 15);
-            rr!(14, 10,  4,  8,  9, 15, 13,  6,  1, 12,  0,  2, 11,  7,  5,  3);
-            rr!(11,  8, 12,  0,  5,  2, 15, 13, 10, 14,  3,  6,  7,  1,  9,  4);
-            rr!( 7,  9,  3,  1, 13, 12, 11, 14,  2,  6,  5, 10,  4,  0, 15,  8);
-            rr!( 9,  0,  5,  7,  2,  4, 10, 15, 14,  1, 11, 12,  6,  8,  3, 13);
-            rr!( 2, 12,  6, 10,  0, 11,  8,  3,  4, 13,  7,  5, 15, 14,  1,  9);
-            rr!(12,  5,  1, 15, 14, 13,  4, 10,  0,  7,  6,  3,  9,  2,  8, 11);
-            rr!(13, 11,  7, 14, 12,  1,  3,  9,  5,  0, 15,  4,  8,  6,  2, 10);
-            rr!( 6, 15, 14,  9, 11,  3,  0,  8, 12,  2, 13,  7,  1,  4, 10,  5);
-            rr!(10,  2,  8,  4,  7,  6,  1,  5, 15, 11,  9, 14,  3, 12, 13,  0);
+        let mut m = [0u32; 16];
+        for i in 0..16 {
+            m[i] = u32::from_le_bytes(*<&[u8; 4]>::try_from(
+                &block[(4 * i)..(4 * i + 4)]).unwrap());
+        }
 
-            for i in 0..8 {
-                h[i] ^= v[i] ^ v[i + 8];
+        // Rounds use the shared BLAKE2s round macro (defined in lib.rs).
+        macro_rules! rr { ($($s: expr),*) => {
+            blake2s_round!(v, m, $($s),*)
+        } }
+
+        rr!( 0,  1,  2,  3,  4,  5,  6,  7,  8,  9, 10, 11, 12, 13, 14, 15);
+        rr!(14, 10,  4,  8,  9, 15, 13,  6,  1, 12,  0,  2, 11,  7,  5,  3);
+        rr!(11,  8, 12,  0,  5,  2, 15, 13, 10, 14,  3,  6,  7,  1,  9,  4);
+        rr!( 7,  9,  3,  1, 13, 12, 11, 14,  2,  6,  5, 10,  4,  0, 15,  8);
+        rr!( 9,  0,  5,  7,  2,  4, 10, 15, 14,  1, 11, 12,  6,  8,  3, 13);
+        rr!( 2, 12,  6, 10,  0, 11,  8,  3,  4, 13,  7,  5, 15, 14,  1,  9);
+        rr!(12,  5,  1, 15, 14, 13,  4, 10,  0,  7,  6,  3,  9,  2,  8, 11);
+        rr!(13, 11,  7, 14, 12,  1,  3,  9,  5,  0, 15,  4,  8,  6,  2, 10);
+        rr!( 6, 15, 14,  9, 11,  3,  0,  8, 12,  2, 13,  7,  1,  4, 10,  5);
+        rr!(10,  2,  8,  4,  7,  6,  1,  5, 15, 11,  9, 14,  3, 12, 13,  0);
+
+        for i in 0..8 {
+            h[i] ^= v[i] ^ v[i + 8];
+        }
+    }
+
+    // x86_64 + AVX2 (uses _mm_blend_epi32()).
+    #[cfg(target_arch = "x86_64")]
+    define_process_block_simd!(process_block_avx2, "avx2", blend_avx2);
+
+    // x86_64 + SSE4.1 (same code, with _mm_blend_epi16()).
+    #[cfg(target_arch = "x86_64")]
+    define_process_block_simd!(process_block_sse41, "ssse3,sse4.1", blend_sse41);
+
+    // x86_64, using SSE2 only.
+    #[cfg(target_arch = "x86_64")]
+    #[allow(dead_code)]
+    unsafe fn process_block_sse2(h: &mut [u32; 8], block: &[u8], ctr: u64,
+        last: bool, last_node: bool)
+    {
+        // x86_64, using SSE2.
+        // Contrary to the AVX2 version, we do not have _mm_shuffle_epi8()
+        // nor _mm_blend_epi32().
+        use core::arch::x86_64::*;
+
+        // Initialize state.
+        let xh0 = _mm_loadu_si128(h.as_ptr() as *const __m128i);
+        let xh1 = _mm_loadu_si128(h.as_ptr().add(4) as *const __m128i);
+        let mut xv0 = xh0;
+        let mut xv1 = xh1;
+        let mut xv2 = _mm_loadu_si128(Self::IV.as_ptr() as *const __m128i);
+        let mut xv3 = _mm_loadu_si128(
+            Self::IV.as_ptr().add(4) as *const __m128i);
+        xv3 = _mm_xor_si128(xv3, _mm_setr_epi32(
+            ctr as i32, (ctr >> 32) as i32,
+            -(last as i32), -((last & last_node) as i32)));
+
+        // Load data and move it into the proper order for the first round:
+        //   xm0:  0  2  4  6
+        //   xm1:  1  3  5  7
+        //   xm2:  8 10 12 14
+        //   xm3:  9 11 13 15
+        let block = &block[..64];
+        let xm0 = _mm_loadu_si128(block.as_ptr() as *const __m128i);
+        let xm1 = _mm_loadu_si128(block.as_ptr().add(16) as *const __m128i);
+        let xm2 = _mm_loadu_si128(block.as_ptr().add(32) as *const __m128i);
+        let xm3 = _mm_loadu_si128(block.as_ptr().add(48) as *const __m128i);
+
+        let xn0 = _mm_shuffle_epi32(xm0, 0xD8);
+        let xn1 = _mm_shuffle_epi32(xm1, 0xD8);
+        let xm0 = _mm_unpacklo_epi64(xn0, xn1);
+        let xm1 = _mm_unpackhi_epi64(xn0, xn1);
+
+        let xn2 = _mm_shuffle_epi32(xm2, 0xD8);
+        let xn3 = _mm_shuffle_epi32(xm3, 0xD8);
+        let xm2 = _mm_unpacklo_epi64(xn2, xn3);
+        let xm3 = _mm_unpackhi_epi64(xn2, xn3);
+
+        macro_rules! g4 { ($xx: expr, $xy: expr) => {
+            xv0 = _mm_add_epi32(xv0, _mm_add_epi32(xv1, $xx));
+            let xtg = _mm_xor_si128(xv0, xv3);
+            xv3 = _mm_or_si128(
+                _mm_srli_epi32(xtg, 16), _mm_slli_epi32(xtg, 16));
+            xv2 = _mm_add_epi32(xv2, xv3);
+            let xtg = _mm_xor_si128(xv1, xv2);
+            xv1 = _mm_or_si128(
+                _mm_srli_epi32(xtg, 12), _mm_slli_epi32(xtg, 20));
+            xv0 = _mm_add_epi32(xv0, _mm_add_epi32(xv1, $xy));
+            let xtg = _mm_xor_si128(xv0, xv3);
+            xv3 = _mm_or_si128(
+                _mm_srli_epi32(xtg, 8), _mm_slli_epi32(xtg, 24));
+            xv2 = _mm_add_epi32(xv2, xv3);
+            let xtg = _mm_xor_si128(xv1, xv2);
+            xv1 = _mm_or_si128(
+                _mm_srli_epi32(xtg, 7), _mm_slli_epi32(xtg, 25));
+        } }
+
+        macro_rules! rr { ($i0: expr, $i1: expr, $i2: expr, $i3: expr) => {
+            g4!($i0, $i1);
+            xv1 = _mm_shuffle_epi32(xv1, 0x39);
+            xv2 = _mm_shuffle_epi32(xv2, 0x4E);
+            xv3 = _mm_shuffle_epi32(xv3, 0x93);
+            g4!($i2, $i3);
+            xv1 = _mm_shuffle_epi32(xv1, 0x93);
+            xv2 = _mm_shuffle_epi32(xv2, 0x4E);
+            xv3 = _mm_shuffle_epi32(xv3, 0x39);
+        } }
+
+        let xz1 = _mm_setr_epi32(-1, 0, 0, 0);
+        let xz2 = _mm_setr_epi32(0, -1, 0, 0);
+        let xz3 = _mm_setr_epi32(-1, -1, 0, 0);
+        let xz4 = _mm_setr_epi32(0, 0, -1, 0);
+        let xz5 = _mm_setr_epi32(-1, 0, -1, 0);
+        let xz6 = _mm_setr_epi32(0, -1, -1, 0);
+        let xz7 = _mm_setr_epi32(-1, -1, -1, 0);
+
+        // round 0
+        rr!(xm0, xm1, xm2, xm3);
+
+        // round 1
+        let xt0 = _mm_shuffle_epi32(xm0, 0x00);
+        let xt1 = _mm_shuffle_epi32(xm0, 0xC8);
+        let xt2 = _mm_shuffle_epi32(xm1, 0x70);
+        let xt3 = _mm_shuffle_epi32(xm1, 0x80);
+        let xt4 = _mm_shuffle_epi32(xm2, 0x01);
+        let xt5 = _mm_shuffle_epi32(xm2, 0x02);
+        let xt6 = _mm_shuffle_epi32(xm2, 0x03);
+        let xt7 = _mm_shuffle_epi32(xm3, 0x80);
+        let xt8 = _mm_shuffle_epi32(xm3, 0x10);
+        let xt9 = _mm_shuffle_epi32(xm3, 0x30);
+        let xn0 = _mm_or_si128(
+            _mm_or_si128(_mm_and_si128(xz1, xt6), _mm_and_si128(xz2, xt1)),
+            _mm_andnot_si128(xz3, xt7));
+        let xn1 = _mm_or_si128(
+            _mm_or_si128(_mm_and_si128(xz3, xt4), _mm_and_si128(xz4, xt9)),
+            _mm_andnot_si128(xz7, xt1));
+        let xn2 = _mm_or_si128(
+            _mm_or_si128(_mm_andnot_si128(xz6, xt3), _mm_and_si128(xz2, xt0)),
+            _mm_and_si128(xz4, xt8));
+        let xn3 = _mm_or_si128(
+            _mm_or_si128(_mm_and_si128(xz1, xt5), _mm_and_si128(xz2, xm0)),
+            _mm_andnot_si128(xz3, xt2));
+        rr!(xn0, xn1, xn2, xn3);
+
+        // round 2
+        let xt0 = _mm_shuffle_epi32(xn0, 0x40);
+        let xt1 = _mm_shuffle_epi32(xn0, 0x80);
+        let xt2 = _mm_shuffle_epi32(xn1, 0x80);
+        let xt3 = _mm_shuffle_epi32(xn1, 0x0D);
+        let xt4 = _mm_shuffle_epi32(xn2, 0x04);
+        let xt5 = _mm_shuffle_epi32(xn2, 0x32);
+        let xt6 = _mm_shuffle_epi32(xn3, 0x10);
+        let xt7 = _mm_shuffle_epi32(xn3, 0x2C);
+        let xm0 = _mm_or_si128(
+            _mm_or_si128(_mm_and_si128(xz5, xt5), _mm_and_si128(xz2, xt6)),
+            _mm_andnot_si128(xz7, xt2));
+        let xm1 = _mm_or_si128(
+            _mm_or_si128(_mm_and_si128(xz1, xt3), _mm_and_si128(xz2, xt4)),
+            _mm_or_si128(_mm_and_si128(xz4, xt6), _mm_andnot_si128(xz7, xn0)));
+        let xm2 = _mm_or_si128(
+            _mm_or_si128(_mm_and_si128(xz1, xt2), _mm_and_si128(xz6, xt7)),
+            _mm_andnot_si128(xz7, xt1));
+        let xm3 = _mm_or_si128(
+            _mm_or_si128(_mm_andnot_si128(xz6, xt0), _mm_and_si128(xz2, xt3)),
+            _mm_and_si128(xz4, xt4));
+        rr!(xm0, xm1, xm2, xm3);
+
+        // round 3
+        let xt0 = _mm_shuffle_epi32(xm0, 0x10);
+        let xt1 = _mm_shuffle_epi32(xm0, 0xC8);
+        let xt2 = _mm_shuffle_epi32(xm1, 0x10);
+        let xt3 = _mm_shuffle_epi32(xm1, 0x32);
+        let xt4 = _mm_shuffle_epi32(xm2, 0x03);
+        let xt5 = _mm_shuffle_epi32(xm2, 0x06);
+        let xt6 = _mm_shuffle_epi32(xm3, 0x39);
+        let xn0 = _mm_or_si128(
+            _mm_or_si128(_mm_and_si128(xz3, xt5), _mm_and_si128(xz4, xt3)),
+            _mm_andnot_si128(xz7, xt0));
+        let xn1 = _mm_or_si128(
+            _mm_or_si128(_mm_and_si128(xz1, xt4), _mm_andnot_si128(xz5, xt6)),
+            _mm_and_si128(xz4, xt0));
+        let xn2 = _mm_or_si128(
+            _mm_or_si128(_mm_and_si128(xz1, xt3), _mm_andnot_si128(xz5, xt1)),
+            _mm_and_si128(xz4, xt6));
+        let xn3 = _mm_or_si128(
+            _mm_or_si128(_mm_and_si128(xz1, xt6), _mm_and_si128(xz2, xt4)),
+            _mm_andnot_si128(xz3, xt2));
+        rr!(xn0, xn1, xn2, xn3);
+
+        // round 4
+        let xt0 = _mm_shuffle_epi32(xn0, 0x80);
+        let xt1 = _mm_shuffle_epi32(xn0, 0x4C);
+        let xt2 = _mm_shuffle_epi32(xn1, 0x09);
+        let xt3 = _mm_shuffle_epi32(xn1, 0x03);
+        let xt4 = _mm_shuffle_epi32(xn2, 0x04);
+        let xt5 = _mm_shuffle_epi32(xn3, 0x40);
+        let xt6 = _mm_shuffle_epi32(xn3, 0x32);
+        let xm0 = _mm_or_si128(
+            _mm_or_si128(_mm_and_si128(xz1, xn1), _mm_and_si128(xz6, xt4)),
+            _mm_andnot_si128(xz7, xt5));
+        let xm1 = _mm_or_si128(
+            _mm_or_si128(_mm_and_si128(xz1, xt6), _mm_and_si128(xz2, xt0)),
+            _mm_andnot_si128(xz3, xn2));
+        let xm2 = _mm_or_si128(
+            _mm_or_si128(_mm_and_si128(xz1, xt3), _mm_andnot_si128(xz5, xt1)),
+            _mm_and_si128(xz4, xt5));
+        let xm3 = _mm_or_si128(
+            _mm_or_si128(_mm_and_si128(xz3, xt2), _mm_and_si128(xz4, xt6)),
+            _mm_andnot_si128(xz7, xt0));
+        rr!(xm0, xm1, xm2, xm3);
+
+        // round 5
+        let xt0 = _mm_shuffle_epi32(xm0, 0x04);
+        let xt1 = _mm_shuffle_epi32(xm0, 0x0E);
+        let xt2 = _mm_shuffle_epi32(xm1, 0x04);
+        let xt3 = _mm_shuffle_epi32(xm1, 0x32);
+        let xt4 = _mm_shuffle_epi32(xm2, 0x08);
+        let xt5 = _mm_shuffle_epi32(xm2, 0xD0);
+        let xt6 = _mm_shuffle_epi32(xm3, 0x01);
+        let xt7 = _mm_shuffle_epi32(xm3, 0x83);
+        let xn0 = _mm_or_si128(
+            _mm_or_si128(_mm_and_si128(xz1, xt1), _mm_and_si128(xz2, xt4)),
+            _mm_or_si128(_mm_and_si128(xz4, xt2), _mm_andnot_si128(xz7, xt7)));
+        let xn1 = _mm_or_si128(
+            _mm_or_si128(_mm_and_si128(xz1, xt6), _mm_and_si128(xz2, xt1)),
+            _mm_andnot_si128(xz3, xt5));
+        let xn2 = _mm_or_si128(
+            _mm_or_si128(_mm_and_si128(xz5, xt3), _mm_and_si128(xz2, xt2)),
+            _mm_andnot_si128(xz7, xt6));
+        let xn3 = _mm_or_si128(
+            _mm_or_si128(_mm_and_si128(xz1, xt7), _mm_andnot_si128(xz5, xt0)),
+            _mm_and_si128(xz4, xt4));
+        rr!(xn0, xn1, xn2, xn3);
+
+        // round 6
+        let xt0 = _mm_shuffle_epi32(xn0, 0xC6);
+        let xt1 = _mm_shuffle_epi32(xn1, 0x40);
+        let xt2 = _mm_shuffle_epi32(xn1, 0x8C);
+        let xt3 = _mm_shuffle_epi32(xn2, 0x09);
+        let xt4 = _mm_shuffle_epi32(xn2, 0x0C);
+        let xt5 = _mm_shuffle_epi32(xn3, 0x01);
+        let xt6 = _mm_shuffle_epi32(xn3, 0x30);
+        let xm0 = _mm_or_si128(
+            _mm_or_si128(_mm_and_si128(xz1, xt1), _mm_andnot_si128(xz5, xt4)),
+            _mm_and_si128(xz4, xn3));
+        let xm1 = _mm_or_si128(
+            _mm_or_si128(_mm_and_si128(xz5, xt5), _mm_and_si128(xz2, xt3)),
+            _mm_andnot_si128(xz7, xt1));
+        let xm2 = _mm_or_si128(_mm_andnot_si128(xz4, xt0), _mm_and_si128(xz4, xt6));
+        let xm3 = _mm_or_si128(
+            _mm_or_si128(_mm_and_si128(xz1, xt3), _mm_andnot_si128(xz5, xt2)),
+            _mm_and_si128(xz4, xt0));
+        rr!(xm0, xm1, xm2, xm3);
+
+        // round 7
+        let xt0 = _mm_shuffle_epi32(xm0, 0x0C);
+        let xt1 = _mm_shuffle_epi32(xm0, 0x18);
+        let xt2 = _mm_shuffle_epi32(xm1, 0xC2);
+        let xt3 = _mm_shuffle_epi32(xm2, 0x10);
+        let xt4 = _mm_shuffle_epi32(xm2, 0xB0);
+        let xt5 = _mm_shuffle_epi32(xm3, 0x40);
+        let xt6 = _mm_shuffle_epi32(xm3, 0x83);
+        let xn0 = _mm_or_si128(
+            _mm_or_si128(_mm_and_si128(xz1, xt2), _mm_andnot_si128(xz5, xt5)),
+            _mm_and_si128(xz4, xt0));
+        let xn1 = _mm_or_si128(
+            _mm_or_si128(_mm_and_si128(xz1, xt6), _mm_and_si128(xz6, xt1)),
+            _mm_andnot_si128(xz7, xt4));
+        let xn2 = _mm_or_si128(
+            _mm_or_si128(_mm_and_si128(xz3, xm1), _mm_and_si128(xz4, xt4)),
+            _mm_andnot_si128(xz7, xt6));
+        let xn3 = _mm_or_si128(
+            _mm_or_si128(_mm_and_si128(xz5, xt3), _mm_and_si128(xz2, xt0)),
+            _mm_andnot_si128(xz7, xt2));
+        rr!(xn0, xn1, xn2, xn3);
+
+        // round 8
+        let xt0 = _mm_shuffle_epi32(xn0, 0x02);
+        let xt1 = _mm_shuffle_epi32(xn0, 0x34);
+        let xt2 = _mm_shuffle_epi32(xn1, 0x0C);
+        let xt3 = _mm_shuffle_epi32(xn2, 0x03);
+        let xt4 = _mm_shuffle_epi32(xn2, 0x81);
+        let xt5 = _mm_shuffle_epi32(xn3, 0x02);
+        let xt6 = _mm_shuffle_epi32(xn3, 0xD0);
+        let xm0 = _mm_or_si128(
+            _mm_or_si128(_mm_andnot_si128(xz6, xt5), _mm_and_si128(xz2, xn1)),
+            _mm_and_si128(xz4, xt2));
+        let xm1 = _mm_or_si128(
+            _mm_or_si128(_mm_andnot_si128(xz6, xt4), _mm_and_si128(xz2, xt2)),
+            _mm_and_si128(xz4, xt1));
+        let xm2 = _mm_or_si128(
+            _mm_or_si128(_mm_and_si128(xz3, xt0), _mm_and_si128(xz4, xn1)),
+            _mm_andnot_si128(xz7, xt6));
+        let xm3 = _mm_or_si128(
+            _mm_or_si128(_mm_andnot_si128(xz6, xt3), _mm_and_si128(xz2, xt1)),
+            _mm_and_si128(xz4, xt6));
+        rr!(xm0, xm1, xm2, xm3);
+
+        // round 9
+        let xt0 = _mm_shuffle_epi32(xm0, 0xC6);
+        let xt1 = _mm_shuffle_epi32(xm1, 0x2C);
+        let xt2 = _mm_shuffle_epi32(xm2, 0x40);
+        let xt3 = _mm_shuffle_epi32(xm2, 0x83);
+        let xt4 = _mm_shuffle_epi32(xm3, 0xD8);
+        let xn0 = _mm_or_si128(
+            _mm_or_si128(_mm_andnot_si128(xz6, xt3), _mm_and_si128(xz2, xt1)),
+            _mm_and_si128(xz4, xt4));
+        let xn1 = _mm_or_si128(_mm_andnot_si128(xz4, xt4), _mm_and_si128(xz4, xt0));
+        let xn2 = _mm_or_si128(
+            _mm_or_si128(_mm_and_si128(xz3, xm1), _mm_and_si128(xz4, xt1)),
+            _mm_andnot_si128(xz7, xt2));
+        let xn3 = _mm_or_si128(_mm_andnot_si128(xz4, xt0), _mm_and_si128(xz4, xt2));
+        rr!(xn0, xn1, xn2, xn3);
+
+        let xh0 = _mm_xor_si128(xh0, _mm_xor_si128(xv0, xv2));
+        let xh1 = _mm_xor_si128(xh1, _mm_xor_si128(xv1, xv3));
+        _mm_storeu_si128(h.as_mut_ptr() as *mut __m128i, xh0);
+        _mm_storeu_si128(h.as_mut_ptr().add(4) as *mut __m128i, xh1);
+    }
+}
+
+/// BLAKE2sp context (8-way parallel BLAKE2s, with an optional key).
+///
+/// The input is split into 64-byte blocks, which are distributed over
+/// eight leaf BLAKE2s instances in a round-robin way; the root instance
+/// then hashes the eight leaf outputs. This mode is defined in the
+/// BLAKE2 specification, and its output is distinct from that of plain
+/// BLAKE2s.
+pub struct Blake2sp {
+    leaves: [KeyedBlake2s; 8],
+    root: Blake2s,
+    pos: u64,
+}
+
+impl Blake2sp {
+
+    /// Initialize the context. The output length (in bytes) must be
+    /// between 1 and 32. The key length must be between 0 and 32 bytes;
+    /// if the key has length 0, then this is unkeyed hashing.
+    pub fn new(out_len: usize, key: &[u8]) -> Self {
+        let p = Blake2sParams::new()
+            .digest_length(out_len)
+            .fanout(8)
+            .depth(2)
+            .inner_length(32);
+        let leaf = |i: u32| KeyedBlake2s::with_params(
+            &p.node_offset(i).last_node(i == 7), key);
+        let leaves = [
+            leaf(0), leaf(1), leaf(2), leaf(3),
+            leaf(4), leaf(5), leaf(6), leaf(7),
+        ];
+
+        // The root node uses the key length in its parameters, but does
+        // not process a key block.
+        assert!(key.len() <= 32);
+        let mut rp = p.node_depth(1).last_node(true);
+        rp.key_length = key.len() as u8;
+        let root = Blake2s::with_params(&rp);
+        Self { leaves, root, pos: 0 }
+    }
+
+    /// Inject some more bytes into the context.
+    pub fn update(&mut self, data: &[u8]) {
+        let mut j = 0;
+        while j < data.len() {
+            let k = ((self.pos >> 6) & 7) as usize;
+            let off = (self.pos as usize) & (BUF_LEN - 1);
+            let clen = core::cmp::min(BUF_LEN - off, data.len() - j);
+            self.leaves[k].update(&data[j..(j + clen)]);
+            self.pos += clen as u64;
+            j += clen;
+        }
+    }
+
+    /// Reset this context.
+    pub fn reset(&mut self) {
+        for leaf in self.leaves.iter_mut() {
+            leaf.reset();
+        }
+        self.root.reset();
+        self.pos = 0;
+    }
+
+    /// Finalize this context and get the output. The output (`out_len` bytes)
+    /// is written into the provided slice. The output size is returned.
+    /// The context is NOT reset and must not be used for further hashing.
+    pub fn finalize_write(&mut self, out: &mut [u8]) -> usize {
+        // Leaf outputs always have length 32 bytes.
+        for leaf in self.leaves.iter_mut() {
+            let hv = leaf.ctx.inner_finalize();
+            self.root.update(&hv);
+        }
+        self.root.finalize_write(out)
+    }
+
+    /// Finalize this context and get the output. The output (`out_len` bytes)
+    /// is written into the provided slice. The output size is returned.
+    /// The context is automatically reset and can be used for a new
+    /// hashing operation.
+    pub fn finalize_reset_write(&mut self, out: &mut [u8]) -> usize {
+        let r = self.finalize_write(out);
+        self.reset();
+        r
+    }
+
+    /// One-stop function for hashing some input (and a key) into an output
+    /// buffer. The output length is provided explicitly; the output buffer
+    /// (`out`) may be larger.
+    #[inline(always)]
+    pub fn hash_into(out_len: usize, key: &[u8], data: &[u8], out: &mut [u8]) {
+        let mut sh = Self::new(out_len, key);
+        sh.update(data);
+        sh.finalize_write(out);
+    }
+}
+
+/// BLAKE2Xs context (extendable-output function, with an optional key).
+///
+/// The output length is set when the context is created, and must be
+/// between 1 and 65534 bytes; the special value 65535 means that the
+/// output length is not known in advance, in which case an arbitrary
+/// amount of output (up to 128 GiB) can be obtained. Input is injected
+/// with `update()`; output is then obtained with one or several calls to
+/// `read()`, or all at once with `finalize_write()` (known length only).
+/// Once output has been read, no more input can be injected until the
+/// context is reset.
+pub struct Blake2xs {
+    ctx: KeyedBlake2s,
+    xof_len: usize,
+    h0: [u8; 32],
+    obuf: [u8; 32],
+    opos: u64,
+}
+
+impl Blake2xs {
+
+    /// Value for the output length that means "unknown length".
+    pub const UNKNOWN_LENGTH: usize = 0xFFFF;
+
+    /// Initialize the context. The output length (in bytes) must be
+    /// between 1 and 65535 (`UNKNOWN_LENGTH`). The key length must be
+    /// between 0 and 32 bytes.
+    pub fn new(out_len: usize, key: &[u8]) -> Self {
+        assert!((1..=Self::UNKNOWN_LENGTH).contains(&out_len));
+        let p = Blake2sParams::new().xof_length(out_len as u16);
+        Self {
+            ctx: KeyedBlake2s::with_params(&p, key),
+            xof_len: out_len,
+            h0: [0u8; 32],
+            obuf: [0u8; 32],
+            opos: !0u64,
+        }
+    }
+
+    /// Inject some more bytes into the context. This MUST NOT be called
+    /// after some output has been read (unless the context is reset).
+    #[inline]
+    pub fn update(&mut self, data: &[u8]) {
+        assert!(self.opos == !0u64);
+        self.ctx.update(data);
+    }
+
+    /// Reset this context.
+    #[inline]
+    pub fn reset(&mut self) {
+        self.ctx.reset();
+        self.opos = !0u64;
+    }
+
+    /// Get the next output bytes. The total output length cannot exceed
+    /// the configured output length (unless that length is unknown).
+    pub fn read(&mut self, out: &mut [u8]) {
+        if self.opos == !0u64 {
+            self.h0 = self.ctx.ctx.inner_finalize();
+            self.opos = 0;
+        }
+        if self.xof_len == Self::UNKNOWN_LENGTH {
+            assert!(self.opos + (out.len() as u64) <= (1u64 << 37));
+        } else {
+            assert!(self.opos + (out.len() as u64) <= (self.xof_len as u64));
+        }
+        let mut j = 0;
+        while j < out.len() {
+            let off = (self.opos as usize) & 31;
+            if off == 0 {
+                self.next_block();
             }
+            let clen = core::cmp::min(32 - off, out.len() - j);
+            out[j..(j + clen)].copy_from_slice(&self.obuf[off..(off + clen)]);
+            self.opos += clen as u64;
+            j += clen;
         }
+    }
 
-        #[cfg(all(target_arch = "x86_64", target_feature = "avx2"))]
-        unsafe {
-            // x86_64 + AVX2
-            use core::arch::x86_64::*;
-            use core::mem::transmute;
-
-            let xror8 = _mm_setr_epi8(
-                1, 2, 3, 0, 5, 6, 7, 4,
-                9, 10, 11, 8, 13, 14, 15, 12);
-            let xror16 = _mm_setr_epi8(
-                2, 3, 0, 1, 6, 7, 4, 5,
-                10, 11, 8, 9, 14, 15, 12, 13);
-
-            // Initialize state.
-            let xh0 = _mm_loadu_si128(transmute(&h[0]));
-            let xh1 = _mm_loadu_si128(transmute(&h[4]));
-            let mut xv0 = xh0;
-            let mut xv1 = xh1;
-            let mut xv2 = _mm_loadu_si128(transmute(&Self::IV[0]));
-            let mut xv3 = _mm_loadu_si128(transmute(&Self::IV[4]));
-            xv3 = _mm_xor_si128(xv3, _mm_setr_epi32(
-                ctr as i32, (ctr >> 32) as i32, -(last as i32), 0));
-
-            // Load data and move it into the proper order for the first round:
-            //   xm0:  0  2  4  6
-            //   xm1:  1  3  5  7
-            //   xm2:  8 10 12 14
-            //   xm3:  9 11 13 15
-            let xm0 = _mm_loadu_si128(transmute(&block[ 0]));
-            let xm1 = _mm_loadu_si128(transmute(&block[16]));
-            let xm2 = _mm_loadu_si128(transmute(&block[32]));
-            let xm3 = _mm_loadu_si128(transmute(&block[48]));
-
-            let xn0 = _mm_shuffle_epi32(xm0, 0xD8);
-            let xn1 = _mm_shuffle_epi32(xm1, 0xD8);
-            let xm0 = _mm_unpacklo_epi64(xn0, xn1);
-            let xm1 = _mm_unpackhi_epi64(xn0, xn1);
-
-            let xn2 = _mm_shuffle_epi32(xm2, 0xD8);
-            let xn3 = _mm_shuffle_epi32(xm3, 0xD8);
-            let xm2 = _mm_unpacklo_epi64(xn2, xn3);
-            let xm3 = _mm_unpackhi_epi64(xn2, xn3);
-
-            macro_rules! g4 { ($xx: expr, $xy: expr) => {
-                xv0 = _mm_add_epi32(xv0, _mm_add_epi32(xv1, $xx));
-                xv3 = _mm_shuffle_epi8(_mm_xor_si128(xv0, xv3), xror16);
-                xv2 = _mm_add_epi32(xv2, xv3);
-                let xtg = _mm_xor_si128(xv1, xv2);
-                xv1 = _mm_or_si128(
-                    _mm_srli_epi32(xtg, 12), _mm_slli_epi32(xtg, 20));
-                xv0 = _mm_add_epi32(xv0, _mm_add_epi32(xv1, $xy));
-                xv3 = _mm_shuffle_epi8(_mm_xor_si128(xv0, xv3), xror8);
-                xv2 = _mm_add_epi32(xv2, xv3);
-                let xtg = _mm_xor_si128(xv1, xv2);
-                xv1 = _mm_or_si128(
-                    _mm_srli_epi32(xtg, 7), _mm_slli_epi32(xtg, 25));
-            } }
-
-            macro_rules! rr { ($i0: expr, $i1: expr, $i2: expr, $i3: expr) => {
-                g4!($i0, $i1);
-                xv1 = _mm_shuffle_epi32(xv1, 0x39);
-                xv2 = _mm_shuffle_epi32(xv2, 0x4E);
-                xv3 = _mm_shuffle_epi32(xv3, 0x93);
-                g4!($i2, $i3);
-                xv1 = _mm_shuffle_epi32(xv1, 0x93);
-                xv2 = _mm_shuffle_epi32(xv2, 0x4E);
-                xv3 = _mm_shuffle_epi32(xv3, 0x39);
-            } }
-
-            // round 0
-            rr!(xm0, xm1, xm2, xm3);
-
-            // round 1
-            let xt0 = _mm_shuffle_epi32(xm0, 0x00);
-            let xt1 = _mm_shuffle_epi32(xm0, 0xC8);
-            let xt2 = _mm_shuffle_epi32(xm1, 0x70);
-            let xt3 = _mm_shuffle_epi32(xm1, 0x80);
-            let xt4 = _mm_shuffle_epi32(xm2, 0x01);
-            let xt5 = _mm_shuffle_epi32(xm2, 0x02);
-            let xt6 = _mm_shuffle_epi32(xm2, 0x03);
-            let xt7 = _mm_shuffle_epi32(xm3, 0x80);
-            let xt8 = _mm_shuffle_epi32(xm3, 0x10);
-            let xt9 = _mm_shuffle_epi32(xm3, 0x30);
-            let xn0 = _mm_blend_epi32(
-                _mm_blend_epi32(xt6, xt1, 0x02),
-                xt7, 0x0C);
-            let xn1 = _mm_blend_epi32(
-                _mm_blend_epi32(xt4, xt9, 0x04),
-                xt1, 0x08);
-            let xn2 = _mm_blend_epi32(
-                _mm_blend_epi32(xt3, xt0, 0x02),
-                xt8, 0x04);
-            let xn3 = _mm_blend_epi32(
-                _mm_blend_epi32(xt5, xm0, 0x02),
-                xt2, 0x0C);
-            rr!(xn0, xn1, xn2, xn3);
-
-            // round 2
-            let xt0 = _mm_shuffle_epi32(xn0, 0x40);
-            let xt1 = _mm_shuffle_epi32(xn0, 0x80);
-            let xt2 = _mm_shuffle_epi32(xn1, 0x80);
-            let xt3 = _mm_shuffle_epi32(xn1, 0x0D);
-            let xt4 = _mm_shuffle_epi32(xn2, 0x04);
-            let xt5 = _mm_shuffle_epi32(xn2, 0x32);
-            let xt6 = _mm_shuffle_epi32(xn3, 0x10);
-            let xt7 = _mm_shuffle_epi32(xn3, 0x2C);
-            let xm0 = _mm_blend_epi32(
-                _mm_blend_epi32(xt5, xt6, 0x02),
-                xt2, 0x08);
-            let xm1 = _mm_blend_epi32(
-                _mm_blend_epi32(xt3, xt4, 0x02),
-                _mm_blend_epi32(xt6, xn0, 0x08), 0x0C);
-            let xm2 = _mm_blend_epi32(
-                _mm_blend_epi32(xt2, xt7, 0x06),
-                xt1, 0x08);
-            let xm3 = _mm_blend_epi32(
-                _mm_blend_epi32(xt0, xt3, 0x02),
-                xt4, 0x04);
-            rr!(xm0, xm1, xm2, xm3);
-
-            // round 3
-            let xt0 = _mm_shuffle_epi32(xm0, 0x10);
-            let xt1 = _mm_shuffle_epi32(xm0, 0xC8);
-            let xt2 = _mm_shuffle_epi32(xm1, 0x10);
-            let xt3 = _mm_shuffle_epi32(xm1, 0x32);
-            let xt4 = _mm_shuffle_epi32(xm2, 0x03);
-            let xt5 = _mm_shuffle_epi32(xm2, 0x06);
-            let xt6 = _mm_shuffle_epi32(xm3, 0x39);
-            let xn0 = _mm_blend_epi32(
-                _mm_blend_epi32(xt5, xt3, 0x04),
-                xt0, 0x08);
-            let xn1 = _mm_blend_epi32(
-                _mm_blend_epi32(xt4, xt6, 0x0A),
-                xt0, 0x04);
-            let xn2 = _mm_blend_epi32(
-                _mm_blend_epi32(xt3, xt1, 0x0A),
-                xt6, 0x04);
-            let xn3 = _mm_blend_epi32(
-                _mm_blend_epi32(xt6, xt4, 0x02),
-                xt2, 0x0C);
-            rr!(xn0, xn1, xn2, xn3);
-
-            // round 4
-            let xt0 = _mm_shuffle_epi32(xn0, 0x80);
-            let xt1 = _mm_shuffle_epi32(xn0, 0x4C);
-            let xt2 = _mm_shuffle_epi32(xn1, 0x09);
-            let xt3 = _mm_shuffle_epi32(xn1, 0x03);
-            let xt4 = _mm_shuffle_epi32(xn2, 0x04);
-            let xt5 = _mm_shuffle_epi32(xn3, 0x40);
-            let xt6 = _mm_shuffle_epi32(xn3, 0x32);
-            let xm0 = _mm_blend_epi32(
-                _mm_blend_epi32(xn1, xt4, 0x06),
-                xt5, 0x08);
-            let xm1 = _mm_blend_epi32(
-                _mm_blend_epi32(xt6, xt0, 0x02),
-                xn2, 0x0C);
-            let xm2 = _mm_blend_epi32(
-                _mm_blend_epi32(xt3, xt1, 0x0A),
-                xt5, 0x04);
-            let xm3 = _mm_blend_epi32(
-                _mm_blend_epi32(xt2, xt6, 0x04),
-                xt0, 0x08);
-            rr!(xm0, xm1, xm2, xm3);
-
-            // round 5
-            let xt0 = _mm_shuffle_epi32(xm0, 0x04);
-            let xt1 = _mm_shuffle_epi32(xm0, 0x0E);
-            let xt2 = _mm_shuffle_epi32(xm1, 0x04);
-            let xt3 = _mm_shuffle_epi32(xm1, 0x32);
-            let xt4 = _mm_shuffle_epi32(xm2, 0x08);
-            let xt5 = _mm_shuffle_epi32(xm2, 0xD0);
-            let xt6 = _mm_shuffle_epi32(xm3, 0x01);
-            let xt7 = _mm_shuffle_epi32(xm3, 0x83);
-            let xn0 = _mm_blend_epi32(
-                _mm_blend_epi32(xt1, xt4, 0x02),
-                _mm_blend_epi32(xt2, xt7, 0x08), 0x0C);
-            let xn1 = _mm_blend_epi32(
-                _mm_blend_epi32(xt6, xt1, 0x02),
-                xt5, 0x0C);
-            let xn2 = _mm_blend_epi32(
-                _mm_blend_epi32(xt3, xt2, 0x02),
-                xt6, 0x08);
-            let xn3 = _mm_blend_epi32(
-                _mm_blend_epi32(xt7, xt0, 0x0A),
-                xt4, 0x04);
-            rr!(xn0, xn1, xn2, xn3);
-
-            // round 6
-            let xt0 = _mm_shuffle_epi32(xn0, 0xC6);
-            let xt1 = _mm_shuffle_epi32(xn1, 0x40);
-            let xt2 = _mm_shuffle_epi32(xn1, 0x8C);
-            let xt3 = _mm_shuffle_epi32(xn2, 0x09);
-            let xt4 = _mm_shuffle_epi32(xn2, 0x0C);
-            let xt5 = _mm_shuffle_epi32(xn3, 0x01);
-            let xt6 = _mm_shuffle_epi32(xn3, 0x30);
-            let xm0 = _mm_blend_epi32(
-                _mm_blend_epi32(xt1, xt4, 0x0A),
-                xn3, 0x04);
-            let xm1 = _mm_blend_epi32(
-                _mm_blend_epi32(xt5, xt3, 0x02),
-                xt1, 0x08);
-            let xm2 = _mm_blend_epi32(xt0, xt6, 0x04);
-            let xm3 = _mm_blend_epi32(
-                _mm_blend_epi32(xt3, xt2, 0x0A),
-                xt0, 0x04);
-            rr!(xm0, xm1, xm2, xm3);
-
-            // round 7
-            let xt0 = _mm_shuffle_epi32(xm0, 0x0C);
-            let xt1 = _mm_shuffle_epi32(xm0, 0x18);
-            let xt2 = _mm_shuffle_epi32(xm1, 0xC2);
-            let xt3 = _mm_shuffle_epi32(xm2, 0x10);
-            let xt4 = _mm_shuffle_epi32(xm2, 0xB0);
-            let xt5 = _mm_shuffle_epi32(xm3, 0x40);
-            let xt6 = _mm_shuffle_epi32(xm3, 0x83);
-            let xn0 = _mm_blend_epi32(
-                _mm_blend_epi32(xt2, xt5, 0x0A),
-                xt0, 0x04);
-            let xn1 = _mm_blend_epi32(
-                _mm_blend_epi32(xt6, xt1, 0x06),
-                xt4, 0x08);
-            let xn2 = _mm_blend_epi32(
-                _mm_blend_epi32(xm1, xt4, 0x04),
-                xt6, 0x08);
-            let xn3 = _mm_blend_epi32(
-                _mm_blend_epi32(xt3, xt0, 0x02),
-                xt2, 0x08);
-            rr!(xn0, xn1, xn2, xn3);
-
-            // round 8
-            let xt0 = _mm_shuffle_epi32(xn0, 0x02);
-            let xt1 = _mm_shuffle_epi32(xn0, 0x34);
-            let xt2 = _mm_shuffle_epi32(xn1, 0x0C);
-            let xt3 = _mm_shuffle_epi32(xn2, 0x03);
-            let xt4 = _mm_shuffle_epi32(xn2, 0x81);
-            let xt5 = _mm_shuffle_epi32(xn3, 0x02);
-            let xt6 = _mm_shuffle_epi32(xn3, 0xD0);
-            let xm0 = _mm_blend_epi32(
-                _mm_blend_epi32(xt5, xn1, 0x02),
-                xt2, 0x04);
-            let xm1 = _mm_blend_epi32(
-                _mm_blend_epi32(xt4, xt2, 0x02),
-                xt1, 0x04);
-            let xm2 = _mm_blend_epi32(
-                _mm_blend_epi32(xt0, xn1, 0x04),
-                xt6, 0x08);
-            let xm3 = _mm_blend_epi32(
-                _mm_blend_epi32(xt3, xt1, 0x02),
-                xt6, 0x04);
-            rr!(xm0, xm1, xm2, xm3);
-
-            // round 9
-            let xt0 = _mm_shuffle_epi32(xm0, 0xC6);
-            let xt1 = _mm_shuffle_epi32(xm1, 0x2C);
-            let xt2 = _mm_shuffle_epi32(xm2, 0x40);
-            let xt3 = _mm_shuffle_epi32(xm2, 0x83);
-            let xt4 = _mm_shuffle_epi32(xm3, 0xD8);
-            let xn0 = _mm_blend_epi32(
-                _mm_blend_epi32(xt3, xt1, 0x02),
-                xt4, 0x04);
-            let xn1 = _mm_blend_epi32(xt4, xt0, 0x04);
-            let xn2 = _mm_blend_epi32(
-                _mm_blend_epi32(xm1, xt1, 0x04),
-                xt2, 0x08);
-            let xn3 = _mm_blend_epi32(xt0, xt2, 0x04);
-            rr!(xn0, xn1, xn2, xn3);
-
-            let xh0 = _mm_xor_si128(xh0, _mm_xor_si128(xv0, xv2));
-            let xh1 = _mm_xor_si128(xh1, _mm_xor_si128(xv1, xv3));
-            _mm_storeu_si128(transmute(&h[0]), xh0);
-            _mm_storeu_si128(transmute(&h[4]), xh1);
+    /// Finalize this context and get the output. The output (`out_len` bytes)
+    /// is written into the provided slice. The output size is returned.
+    /// This is for a known output length only; the context is NOT reset
+    /// and must not be used for further hashing.
+    pub fn finalize_write(&mut self, out: &mut [u8]) -> usize {
+        assert!(self.xof_len != Self::UNKNOWN_LENGTH);
+        assert!(self.opos == !0u64);
+        self.read(&mut out[..self.xof_len]);
+        self.xof_len
+    }
+
+    /// Finalize this context and get the output. The output (`out_len` bytes)
+    /// is written into the provided slice. The output size is returned.
+    /// The context is automatically reset and can be used for a new
+    /// hashing operation.
+    pub fn finalize_reset_write(&mut self, out: &mut [u8]) -> usize {
+        let r = self.finalize_write(out);
+        self.reset();
+        r
+    }
+
+    /// One-stop function for hashing some input (and a key) into an output
+    /// buffer. The output length is provided explicitly (and must be
+    /// between 1 and 65534); the output buffer (`out`) may be larger.
+    #[inline(always)]
+    pub fn hash_into(out_len: usize, key: &[u8], data: &[u8], out: &mut [u8]) {
+        let mut sh = Self::new(out_len, key);
+        sh.update(data);
+        sh.finalize_write(out);
+    }
+
+    // Compute the next output block into `obuf`. Block i is the hash of
+    // the root hash with node offset i; the last block of a known-length
+    // output is truncated to the remaining length.
+    fn next_block(&mut self) {
+        let i = self.opos >> 5;
+        let dlen = if self.xof_len == Self::UNKNOWN_LENGTH {
+            32
+        } else {
+            core::cmp::min(32, self.xof_len - ((i as usize) << 5))
+        };
+        let p = Blake2sParams::new()
+            .digest_length(dlen)
+            .fanout(0)
+            .depth(0)
+            .leaf_length(32)
+            .node_offset(i as u32)
+            .xof_length(self.xof_len as u16)
+            .inner_length(32);
+        let mut sh = Blake2s::with_params(&p);
+        sh.update(&self.h0);
+        sh.finalize_write(&mut self.obuf);
+    }
+}
+
+// Implementations of the RustCrypto `digest` traits. Blake2s256 is a
+// plain hash function (`Digest` is obtained through the blanket
+// implementation); Blake2s supports variable-length output; KeyedBlake2s
+// is a MAC with a 32-byte output (when initialized through `KeyInit`, the
+// key length may be anything between 0 and 32 bytes).
+
+impl Default for Blake2s256 {
+
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl digest::HashMarker for Blake2s256 {}
+
+impl digest::OutputSizeUser for Blake2s256 {
+    type OutputSize = digest::consts::U32;
+}
+
+impl digest::core_api::BlockSizeUser for Blake2s256 {
+    type BlockSize = digest::consts::U64;
+}
+
+impl digest::Update for Blake2s256 {
+
+    #[inline(always)]
+    fn update(&mut self, data: &[u8]) {
+        self.0.update(data);
+    }
+}
+
+impl digest::FixedOutput for Blake2s256 {
+
+    #[inline(always)]
+    fn finalize_into(mut self, out: &mut digest::Output<Self>) {
+        self.0.finalize_write(out);
+    }
+}
+
+impl digest::FixedOutputReset for Blake2s256 {
+
+    #[inline(always)]
+    fn finalize_into_reset(&mut self, out: &mut digest::Output<Self>) {
+        self.0.finalize_reset_write(out);
+    }
+}
+
+impl digest::Reset for Blake2s256 {
+
+    #[inline(always)]
+    fn reset(&mut self) {
+        self.0.reset();
+    }
+}
+
+impl digest::Update for Blake2s {
+
+    #[inline(always)]
+    fn update(&mut self, data: &[u8]) {
+        Blake2s::update(self, data);
+    }
+}
+
+impl digest::Reset for Blake2s {
+
+    #[inline(always)]
+    fn reset(&mut self) {
+        Blake2s::reset(self);
+    }
+}
+
+impl digest::VariableOutput for Blake2s {
+
+    const MAX_OUTPUT_SIZE: usize = 32;
+
+    fn new(output_size: usize) -> Result<Self, digest::InvalidOutputSize> {
+        if output_size == 0 || output_size > 32 {
+            return Err(digest::InvalidOutputSize);
         }
+        Ok(Blake2s::new(output_size))
+    }
 
-        #[cfg(all(target_arch = "x86_64", not(target_feature = "avx2")))]
-        unsafe {
-            // x86_64, using SSE2.
-            // Contrary to the AVX2 version, we do not have _mm_shuffle_epi8()
-            // nor _mm_blend_epi32().
-            use core::arch::x86_64::*;
-            use core::mem::transmute;
-
-            // Initialize state.
-            let xh0 = _mm_loadu_si128(transmute(&h[0]));
-            let xh1 = _mm_loadu_si128(transmute(&h[4]));
-            let mut xv0 = xh0;
-            let mut xv1 = xh1;
-            let mut xv2 = _mm_loadu_si128(transmute(&Self::IV[0]));
-            let mut xv3 = _mm_loadu_si128(transmute(&Self::IV[4]));
-            xv3 = _mm_xor_si128(xv3, _mm_setr_epi32(
-                ctr as i32, (ctr >> 32) as i32, -(last as i32), 0));
-
-            // Load data and move it into the proper order for the first round:
-            //   xm0:  0  2  4  6
-            //   xm1:  1  3  5  7
-            //   xm2:  8 10 12 14
-            //   xm3:  9 11 13 15
-            let xm0 = _mm_loadu_si128(transmute(&block[ 0]));
-            let xm1 = _mm_loadu_si128(transmute(&block[16]));
-            let xm2 = _mm_loadu_si128(transmute(&block[32]));
-            let xm3 = _mm_loadu_si128(transmute(&block[48]));
-
-            let xn0 = _mm_shuffle_epi32(xm0, 0xD8);
-            let xn1 = _mm_shuffle_epi32(xm1, 0xD8);
-            let xm0 = _mm_unpacklo_epi64(xn0, xn1);
-            let xm1 = _mm_unpackhi_epi64(xn0, xn1);
-
-            let xn2 = _mm_shuffle_epi32(xm2, 0xD8);
-            let xn3 = _mm_shuffle_epi32(xm3, 0xD8);
-            let xm2 = _mm_unpacklo_epi64(xn2, xn3);
-            let xm3 = _mm_unpackhi_epi64(xn2, xn3);
-
-            macro_rules! g4 { ($xx: expr, $xy: expr) => {
-                xv0 = _mm_add_epi32(xv0, _mm_add_epi32(xv1, $xx));
-                let xtg = _mm_xor_si128(xv0, xv3);
-                xv3 = _mm_or_si128(
-                    _mm_srli_epi32(xtg, 16), _mm_slli_epi32(xtg, 16));
-                xv2 = _mm_add_epi32(xv2, xv3);
-                let xtg = _mm_xor_si128(xv1, xv2);
-                xv1 = _mm_or_si128(
-                    _mm_srli_epi32(xtg, 12), _mm_slli_epi32(xtg, 20));
-                xv0 = _mm_add_epi32(xv0, _mm_add_epi32(xv1, $xy));
-                let xtg = _mm_xor_si128(xv0, xv3);
-                xv3 = _mm_or_si128(
-                    _mm_srli_epi32(xtg, 8), _mm_slli_epi32(xtg, 24));
-                xv2 = _mm_add_epi32(xv2, xv3);
-                let xtg = _mm_xor_si128(xv1, xv2);
-                xv1 = _mm_or_si128(
-                    _mm_srli_epi32(xtg, 7), _mm_slli_epi32(xtg, 25));
-            } }
-
-            macro_rules! rr { ($i0: expr, $i1: expr, $i2: expr, $i3: expr) => {
-                g4!($i0, $i1);
-                xv1 = _mm_shuffle_epi32(xv1, 0x39);
-                xv2 = _mm_shuffle_epi32(xv2, 0x4E);
-                xv3 = _mm_shuffle_epi32(xv3, 0x93);
-                g4!($i2, $i3);
-                xv1 = _mm_shuffle_epi32(xv1, 0x93);
-                xv2 = _mm_shuffle_epi32(xv2, 0x4E);
-                xv3 = _mm_shuffle_epi32(xv3, 0x39);
-            } }
-
-            let xz1 = _mm_setr_epi32(-1, 0, 0, 0);
-            let xz2 = _mm_setr_epi32(0, -1, 0, 0);
-            let xz3 = _mm_setr_epi32(-1, -1, 0, 0);
-            let xz4 = _mm_setr_epi32(0, 0, -1, 0);
-            let xz5 = _mm_setr_epi32(-1, 0, -1, 0);
-            let xz6 = _mm_setr_epi32(0, -1, -1, 0);
-            let xz7 = _mm_setr_epi32(-1, -1, -1, 0);
-
-            // round 0
-            rr!(xm0, xm1, xm2, xm3);
-
-            // round 1
-            let xt0 = _mm_shuffle_epi32(xm0, 0x00);
-            let xt1 = _mm_shuffle_epi32(xm0, 0xC8);
-            let xt2 = _mm_shuffle_epi32(xm1, 0x70);
-            let xt3 = _mm_shuffle_epi32(xm1, 0x80);
-            let xt4 = _mm_shuffle_epi32(xm2, 0x01);
-            let xt5 = _mm_shuffle_epi32(xm2, 0x02);
-            let xt6 = _mm_shuffle_epi32(xm2, 0x03);
-            let xt7 = _mm_shuffle_epi32(xm3, 0x80);
-            let xt8 = _mm_shuffle_epi32(xm3, 0x10);
-            let xt9 = _mm_shuffle_epi32(xm3, 0x30);
-            let xn0 = _mm_or_si128(
-                _mm_or_si128(_mm_and_si128(xz1, xt6), _mm_and_si128(xz2, xt1)),
-                _mm_andnot_si128(xz3, xt7));
-            let xn1 = _mm_or_si128(
-                _mm_or_si128(_mm_and_si128(xz3, xt4), _mm_and_si128(xz4, xt9)),
-                _mm_andnot_si128(xz7, xt1));
-            let xn2 = _mm_or_si128(
-                _mm_or_si128(_mm_andnot_si128(xz6, xt3), _mm_and_si128(xz2, xt0)),
-                _mm_and_si128(xz4, xt8));
-            let xn3 = _mm_or_si128(
-                _mm_or_si128(_mm_and_si128(xz1, xt5), _mm_and_si128(xz2, xm0)),
-                _mm_andnot_si128(xz3, xt2));
-            rr!(xn0, xn1, xn2, xn3);
-
-            // round 2
-            let xt0 = _mm_shuffle_epi32(xn0, 0x40);
-            let xt1 = _mm_shuffle_epi32(xn0, 0x80);
-            let xt2 = _mm_shuffle_epi32(xn1, 0x80);
-            let xt3 = _mm_shuffle_epi32(xn1, 0x0D);
-            let xt4 = _mm_shuffle_epi32(xn2, 0x04);
-            let xt5 = _mm_shuffle_epi32(xn2, 0x32);
-            let xt6 = _mm_shuffle_epi32(xn3, 0x10);
-            let xt7 = _mm_shuffle_epi32(xn3, 0x2C);
-            let xm0 = _mm_or_si128(
-                _mm_or_si128(_mm_and_si128(xz5, xt5), _mm_and_si128(xz2, xt6)),
-                _mm_andnot_si128(xz7, xt2));
-            let xm1 = _mm_or_si128(
-                _mm_or_si128(_mm_and_si128(xz1, xt3), _mm_and_si128(xz2, xt4)),
-                _mm_or_si128(_mm_and_si128(xz4, xt6), _mm_andnot_si128(xz7, xn0)));
-            let xm2 = _mm_or_si128(
-                _mm_or_si128(_mm_and_si128(xz1, xt2), _mm_and_si128(xz6, xt7)),
-                _mm_andnot_si128(xz7, xt1));
-            let xm3 = _mm_or_si128(
-                _mm_or_si128(_mm_andnot_si128(xz6, xt0), _mm_and_si128(xz2, xt3)),
-                _mm_and_si128(xz4, xt4));
-            rr!(xm0, xm1, xm2, xm3);
-
-            // round 3
-            let xt0 = _mm_shuffle_epi32(xm0, 0x10);
-            let xt1 = _mm_shuffle_epi32(xm0, 0xC8);
-            let xt2 = _mm_shuffle_epi32(xm1, 0x10);
-            let xt3 = _mm_shuffle_epi32(xm1, 0x32);
-            let xt4 = _mm_shuffle_epi32(xm2, 0x03);
-            let xt5 = _mm_shuffle_epi32(xm2, 0x06);
-            let xt6 = _mm_shuffle_epi32(xm3, 0x39);
-            let xn0 = _mm_or_si128(
-                _mm_or_si128(_mm_and_si128(xz3, xt5), _mm_and_si128(xz4, xt3)),
-                _mm_andnot_si128(xz7, xt0));
-            let xn1 = _mm_or_si128(
-                _mm_or_si128(_mm_and_si128(xz1, xt4), _mm_andnot_si128(xz5, xt6)),
-                _mm_and_si128(xz4, xt0));
-            let xn2 = _mm_or_si128(
-                _mm_or_si128(_mm_and_si128(xz1, xt3), _mm_andnot_si128(xz5, xt1)),
-                _mm_and_si128(xz4, xt6));
-            let xn3 = _mm_or_si128(
-                _mm_or_si128(_mm_and_si128(xz1, xt6), _mm_and_si128(xz2, xt4)),
-                _mm_andnot_si128(xz3, xt2));
-            rr!(xn0, xn1, xn2, xn3);
-
-            // round 4
-            let xt0 = _mm_shuffle_epi32(xn0, 0x80);
-            let xt1 = _mm_shuffle_epi32(xn0, 0x4C);
-            let xt2 = _mm_shuffle_epi32(xn1, 0x09);
-            let xt3 = _mm_shuffle_epi32(xn1, 0x03);
-            let xt4 = _mm_shuffle_epi32(xn2, 0x04);
-            let xt5 = _mm_shuffle_epi32(xn3, 0x40);
-            let xt6 = _mm_shuffle_epi32(xn3, 0x32);
-            let xm0 = _mm_or_si128(
-                _mm_or_si128(_mm_and_si128(xz1, xn1), _mm_and_si128(xz6, xt4)),
-                _mm_andnot_si128(xz7, xt5));
-            let xm1 = _mm_or_si128(
-                _mm_or_si128(_mm_and_si128(xz1, xt6), _mm_and_si128(xz2, xt0)),
-                _mm_andnot_si128(xz3, xn2));
-            let xm2 = _mm_or_si128(
-                _mm_or_si128(_mm_and_si128(xz1, xt3), _mm_andnot_si128(xz5, xt1)),
-                _mm_and_si128(xz4, xt5));
-            let xm3 = _mm_or_si128(
-                _mm_or_si128(_mm_and_si128(xz3, xt2), _mm_and_si128(xz4, xt6)),
-                _mm_andnot_si128(xz7, xt0));
-            rr!(xm0, xm1, xm2, xm3);
-
-            // round 5
-            let xt0 = _mm_shuffle_epi32(xm0, 0x04);
-            let xt1 = _mm_shuffle_epi32(xm0, 0x0E);
-            let xt2 = _mm_shuffle_epi32(xm1, 0x04);
-            let xt3 = _mm_shuffle_epi32(xm1, 0x32);
-            let xt4 = _mm_shuffle_epi32(xm2, 0x08);
-            let xt5 = _mm_shuffle_epi32(xm2, 0xD0);
-            let xt6 = _mm_shuffle_epi32(xm3, 0x01);
-            let xt7 = _mm_shuffle_epi32(xm3, 0x83);
-            let xn0 = _mm_or_si128(
-                _mm_or_si128(_mm_and_si128(xz1, xt1), _mm_and_si128(xz2, xt4)),
-                _mm_or_si128(_mm_and_si128(xz4, xt2), _mm_andnot_si128(xz7, xt7)));
-            let xn1 = _mm_or_si128(
-                _mm_or_si128(_mm_and_si128(xz1, xt6), _mm_and_si128(xz2, xt1)),
-                _mm_andnot_si128(xz3, xt5));
-            let xn2 = _mm_or_si128(
-                _mm_or_si128(_mm_and_si128(xz5, xt3), _mm_and_si128(xz2, xt2)),
-                _mm_andnot_si128(xz7, xt6));
-            let xn3 = _mm_or_si128(
-                _mm_or_si128(_mm_and_si128(xz1, xt7), _mm_andnot_si128(xz5, xt0)),
-                _mm_and_si128(xz4, xt4));
-            rr!(xn0, xn1, xn2, xn3);
-
-            // round 6
-            let xt0 = _mm_shuffle_epi32(xn0, 0xC6);
-            let xt1 = _mm_shuffle_epi32(xn1, 0x40);
-            let xt2 = _mm_shuffle_epi32(xn1, 0x8C);
-            let xt3 = _mm_shuffle_epi32(xn2, 0x09);
-            let xt4 = _mm_shuffle_epi32(xn2, 0x0C);
-            let xt5 = _mm_shuffle_epi32(xn3, 0x01);
-            let xt6 = _mm_shuffle_epi32(xn3, 0x30);
-            let xm0 = _mm_or_si128(
-                _mm_or_si128(_mm_and_si128(xz1, xt1), _mm_andnot_si128(xz5, xt4)),
-                _mm_and_si128(xz4, xn3));
-            let xm1 = _mm_or_si128(
-                _mm_or_si128(_mm_and_si128(xz5, xt5), _mm_and_si128(xz2, xt3)),
-                _mm_andnot_si128(xz7, xt1));
-            let xm2 = _mm_or_si128(_mm_andnot_si128(xz4, xt0), _mm_and_si128(xz4, xt6));
-            let xm3 = _mm_or_si128(
-                _mm_or_si128(_mm_and_si128(xz1, xt3), _mm_andnot_si128(xz5, xt2)),
-                _mm_and_si128(xz4, xt0));
-            rr!(xm0, xm1, xm2, xm3);
-
-            // round 7
-            let xt0 = _mm_shuffle_epi32(xm0, 0x0C);
-            let xt1 = _mm_shuffle_epi32(xm0, 0x18);
-            let xt2 = _mm_shuffle_epi32(xm1, 0xC2);
-            let xt3 = _mm_shuffle_epi32(xm2, 0x10);
-            let xt4 = _mm_shuffle_epi32(xm2, 0xB0);
-            let xt5 = _mm_shuffle_epi32(xm3, 0x40);
-            let xt6 = _mm_shuffle_epi32(xm3, 0x83);
-            let xn0 = _mm_or_si128(
-                _mm_or_si128(_mm_and_si128(xz1, xt2), _mm_andnot_si128(xz5, xt5)),
-                _mm_and_si128(xz4, xt0));
-            let xn1 = _mm_or_si128(
-                _mm_or_si128(_mm_and_si128(xz1, xt6), _mm_and_si128(xz6, xt1)),
-                _mm_andnot_si128(xz7, xt4));
-            let xn2 = _mm_or_si128(
-                _mm_or_si128(_mm_and_si128(xz3, xm1), _mm_and_si128(xz4, xt4)),
-                _mm_andnot_si128(xz7, xt6));
-            let xn3 = _mm_or_si128(
-                _mm_or_si128(_mm_and_si128(xz5, xt3), _mm_and_si128(xz2, xt0)),
-                _mm_andnot_si128(xz7, xt2));
-            rr!(xn0, xn1, xn2, xn3);
-
-            // round 8
-            let xt0 = _mm_shuffle_epi32(xn0, 0x02);
-            let xt1 = _mm_shuffle_epi32(xn0, 0x34);
-            let xt2 = _mm_shuffle_epi32(xn1, 0x0C);
-            let xt3 = _mm_shuffle_epi32(xn2, 0x03);
-            let xt4 = _mm_shuffle_epi32(xn2, 0x81);
-            let xt5 = _mm_shuffle_epi32(xn3, 0x02);
-            let xt6 = _mm_shuffle_epi32(xn3, 0xD0);
-            let xm0 = _mm_or_si128(
-                _mm_or_si128(_mm_andnot_si128(xz6, xt5), _mm_and_si128(xz2, xn1)),
-                _mm_and_si128(xz4, xt2));
-            let xm1 = _mm_or_si128(
-                _mm_or_si128(_mm_andnot_si128(xz6, xt4), _mm_and_si128(xz2, xt2)),
-                _mm_and_si128(xz4, xt1));
-            let xm2 = _mm_or_si128(
-                _mm_or_si128(_mm_and_si128(xz3, xt0), _mm_and_si128(xz4, xn1)),
-                _mm_andnot_si128(xz7, xt6));
-            let xm3 = _mm_or_si128(
-                _mm_or_si128(_mm_andnot_si128(xz6, xt3), _mm_and_si128(xz2, xt1)),
-                _mm_and_si128(xz4, xt6));
-            rr!(xm0, xm1, xm2, xm3);
-
-            // round 9
-            let xt0 = _mm_shuffle_epi32(xm0, 0xC6);
-            let xt1 = _mm_shuffle_epi32(xm1, 0x2C);
-            let xt2 = _mm_shuffle_epi32(xm2, 0x40);
-            let xt3 = _mm_shuffle_epi32(xm2, 0x83);
-            let xt4 = _mm_shuffle_epi32(xm3, 0xD8);
-            let xn0 = _mm_or_si128(
-                _mm_or_si128(_mm_andnot_si128(xz6, xt3), _mm_and_si128(xz2, xt1)),
-                _mm_and_si128(xz4, xt4));
-            let xn1 = _mm_or_si128(_mm_andnot_si128(xz4, xt4), _mm_and_si128(xz4, xt0));
-            let xn2 = _mm_or_si128(
-                _mm_or_si128(_mm_and_si128(xz3, xm1), _mm_and_si128(xz4, xt1)),
-                _mm_andnot_si128(xz7, xt2));
-            let xn3 = _mm_or_si128(_mm_andnot_si128(xz4, xt0), _mm_and_si128(xz4, xt2));
-            rr!(xn0, xn1, xn2, xn3);
-
-            let xh0 = _mm_xor_si128(xh0, _mm_xor_si128(xv0, xv2));
-            let xh1 = _mm_xor_si128(xh1, _mm_xor_si128(xv1, xv3));
-            _mm_storeu_si128(transmute(&h[0]), xh0);
-            _mm_storeu_si128(transmute(&h[4]), xh1);
+    fn output_size(&self) -> usize {
+        self.out_len
+    }
+
+    fn finalize_variable(mut self, out: &mut [u8])
+        -> Result<(), digest::InvalidBufferSize>
+    {
+        if out.len() != self.out_len {
+            return Err(digest::InvalidBufferSize);
+        }
+        self.finalize_write(out);
+        Ok(())
+    }
+}
+
+impl digest::VariableOutputReset for Blake2s {
+
+    fn finalize_variable_reset(&mut self, out: &mut [u8])
+        -> Result<(), digest::InvalidBufferSize>
+    {
+        if out.len() != self.out_len {
+            return Err(digest::InvalidBufferSize);
+        }
+        self.finalize_reset_write(out);
+        Ok(())
+    }
+}
+
+impl digest::MacMarker for KeyedBlake2s {}
+
+impl digest::crypto_common::KeySizeUser for KeyedBlake2s {
+    type KeySize = digest::consts::U32;
+}
+
+impl digest::KeyInit for KeyedBlake2s {
+
+    #[inline(always)]
+    fn new(key: &digest::Key<Self>) -> Self {
+        KeyedBlake2s::new(32, key)
+    }
+
+    fn new_from_slice(key: &[u8]) -> Result<Self, digest::InvalidLength> {
+        if key.len() > 32 {
+            return Err(digest::InvalidLength);
         }
+        Ok(KeyedBlake2s::new(32, key))
+    }
+}
+
+impl digest::OutputSizeUser for KeyedBlake2s {
+    type OutputSize = digest::consts::U32;
+}
+
+impl digest::Update for KeyedBlake2s {
+
+    #[inline(always)]
+    fn update(&mut self, data: &[u8]) {
+        KeyedBlake2s::update(self, data);
+    }
+}
+
+// The fixed-output API requires a 32-byte output length, which is always
+// the case for contexts created with `KeyInit`.
+impl digest::FixedOutput for KeyedBlake2s {
+
+    fn finalize_into(mut self, out: &mut digest::Output<Self>) {
+        assert!(self.ctx.out_len == 32);
+        self.finalize_write(out);
+    }
+}
+
+impl digest::FixedOutputReset for KeyedBlake2s {
+
+    fn finalize_into_reset(&mut self, out: &mut digest::Output<Self>) {
+        assert!(self.ctx.out_len == 32);
+        self.finalize_reset_write(out);
+    }
+}
+
+impl digest::Reset for KeyedBlake2s {
+
+    #[inline(always)]
+    fn reset(&mut self) {
+        KeyedBlake2s::reset(self);
     }
 }
 
 #[cfg(test)]
 mod tests {
 
-    use super::{Blake2s256, Blake2s, KeyedBlake2s};
+    use super::{Blake2s256, Blake2s, KeyedBlake2s, Blake2sParams, Blake2sp, Blake2xs};
+    use super::{hash_x8_portable, BUF_LEN};
 
     static KAT_BLAKE2S: [[&str; 3]; 257] = [
         // Each group of three values is:
@@ -2029,4 +2900,315 @@ mod tests {
 
         assert!(ctx.finalize() == BLAKE2S_RES);
     }
+    static KAT_BLAKE2SP: [(usize, &str); 18] = [
+        // Each pair is the input length and the output (in hexadecimal).
+        // The input is the sequence of bytes 0x00, 0x01, 0x02... (modulo
+        // 256), and the key is 00 01 02 .. 1F; this is the same
+        // convention as in the BLAKE2 reference test vectors
+        // (https://github.com/BLAKE2/BLAKE2/). The outputs were produced
+        // by extra/blake2s_kat.py, which builds BLAKE2sp on top of
+        // Python's hashlib.blake2s; the entry for the empty input
+        // matches the reference file (blake2sp-kat.txt).
+        (0, "715cb13895aeb678f6124160bff21465b30f4f6874193fc851b4621043f09cc6"),
+        (1, "40578ffa52bf51ae1866f4284d3a157fc1bcd36ac13cbdcb0377e4d0cd0b6603"),
+        (2, "67e3097545bad7e852d74d4eb548eca7c219c202a7d088db0efeac0eac304249"),
+        (3, "8dbcc0589a3d17296a7a58e2f1eff0e2aa4210b58d1f88b86d7ba5f29dd3b583"),
+        (31, "fab235d59348ab8ce49bec77c0f19328fd045dfd608a530336df4f94e172a5c8"),
+        (32, "8aaa8d805c58881ff379fbd42c6bf6f14c6c73df8071b3b228981109ccc015f9"),
+        (63, "e85594700e3922a1e8e41eb8b064e7ac6d949d13b5a34523e5a6beac03c8ab29"),
+        (64, "1d3701a5661bd31ab20562bd07b74dd19ac8f3524b73ce7bc996b788afd2f317"),
+        (65, "874e1938033d7d383597a2a65f58b554e41106f6d1d50e9ba0eb685f6b6da071"),
+        (127, "44cb6311d0750b7e33f7333aa78aaca9c34ad5f79c1b1591ec33951e69c4c461"),
+        (128, "0c6ce32a3ea05612c5f8090f6a7e87f5ab30e41b707dcbe54155620ad770a340"),
+        (255, "0c8a36597d7461c63a94732821c941856c668376606c86a52de0ee4104c615db"),
+        (511, "3e3948f0b6602348b699dab0ea15c0781fd694183531142fb5bc88477cacbe76"),
+        (512, "3246bc18b42253f58d3bc21dd51c14290c0b78d4d9d5274087bff2ca297c51fc"),
+        (513, "583dc2f1f106e8b85fab4795371576d75eca0fad5a0cc5ede81ad54bd405d873"),
+        (1000, "686d695f449e5156d70c54cd7c3f740c9233dca172ffcadba9488414da9c1415"),
+        (4096, "8d30e5ddb0756b3a26181f39a7e6acc030f8eea1224428af2a5339cb8a96bc35"),
+        (4097, "0114efee7a980b65db06a1eb23d97135451374c1f84b818389ef44090af1973e"),
+    ];
+
+    static KAT_BLAKE2XS: [(usize, &str); 12] = [
+        // Each pair is the output length and the output (in hexadecimal).
+        // The input is the 256 bytes 00 01 02 .. FF, and the key is
+        // 00 01 02 .. 1F, following the convention of the BLAKE2
+        // reference test vectors. The outputs were produced by
+        // extra/blake2s_kat.py, which builds BLAKE2Xs on top of Python's
+        // hashlib.blake2s.
+        (1,
+         "0e"),
+        (2,
+         "5196"),
+        (3,
+         "ad6bad"),
+        (31,
+         "02dd758fa23113a14fd94830e50e0f6b86faec4e551e808b0ca8d00fef2a15"),
+        (32,
+         "a4fe2bd0f96a215fa7164ae1a405f4030a586c12b0c29806a099d7d7fdd8dd72"),
+        (33,
+         "7dce710a20f42ab687ec6ea83b53faaa418229ce0d5a2ff2a5e66defb0b65c03c9"),
+        (63,
+         "f73dfb046def3362d6de36077dae2cee2587fe95fe0800548bb7d99737897096ba59052e0dadcc1fb0ccb5535391875328637a0376a43a4d89366758dfe3e2"),
+        (64,
+         "ec470d0aa932c78c5bcf86203ec0014314114765fa679c3daef214f883a17e1b4ca12f44433772a6e4ef685c904b2fc35586c6bd88f325b965968b06d808d73f"),
+        (65,
+         "cf601753ffa09fe48a8a84c37769991e96290e200bbaf1910c57760f989bd0c72e6128e294528ee861ad7eee70d589de3cf4a0c35f7197e1925a64d0133628d87d"),
+        (100,
+         "3366860c77804fe0b4f368b02bb5b0d150821d957e3ba37842da9fc8d336e9d702c8446ecafbd19d79b868702f32405853bc17695873a7306e0ce4573cd9ac0b7fc7dd35534d7635198d152a1802f7d8d6a4bb07600fcdaacfaa1c3f40a09bc02e974c99"),
+        (256,
+         "5784e614d538f7f26c803191deb464a884817002988c36448dcbecfad1997fe51ab0b3853c51ed49ce9f4e477522fb3f32cc50515b753c18fb89a8d965afcf1ed5e099b22c4225732baeb986f5c5bc88e4582d27915e2a19126d3d4555fab4f6516a6a156dbfeed9e982fc589e33ce2b9e1ba2b416e11852ddeab93025974267ac82c84f071c3d07f215f47e3565fd1d962c76e0d635892ea71488273765887d31f250a26c4ddc377ed89b17326e259f6cc1de0e63158e83aebb7f5a7c08c63c767876c8203639958a407acca096d1f606c04b4f4b3fd771781a5901b1c3cee7c04c3b6870226eee309b74f51edbf70a3817cc8da87875301e04d0416a65dc5d"),
+        (1000,
+         "c56bcccacf14b5ab500ff3e0edcb56b739403f71d2ccd9a513e1006ff71d94fc52098e4ff9ee5c324a47f1ff29d548b599416e738d2190180a57d6d9497918076275fd0baf3c02169fd61b3745241c757b13abe7924ee747807f455822c611c5847274881890586ff148bd61b0d469542c2e86d09b933c1015f28e5ee417a6292332f08031743bbcd8405dfd63bcd8d7bee8f5871a9e6e6734ddb7bca71eea23b6c9ff3ec61327eb270e447ec3a915566350d8c291757301c5a4566969be55fbb0ada56f649fd16ac0426902b35c573067c6b8d001a57eeebb4ef88af96a0b378381c85e872439313889f18bcd2356fe6e79037adb9ad560220f26866fa5715435002f4559a89d524a859fb47c9d9891735c4981739bd166692a112e80757b18193da294d69314a11246cb450d1679c5dd7a48fb75986dde119a1891730277d13799d1e2f8a9ed82d4e75eca42b22628ef388a06d943c2e9875e0955c81a9d1bce379a75fb4ffe004df25371a18829aded65014586422f85955aff4eb0e6f070fff4ca540e002ded5532f1b61f9fde8898472dbe0d2828056f3314c686dc1f30094fe54030aba5245e528f0c1fa1dd071ce2e2a826e4c2b63e410a3f1a4c0ba68ac7185b3ea556e277e58f5f5aaa611efc88dcdff73946c7e116bda3957482fbe71656f7d024fc3a390247eb67c2f4f5cd3c7f6fdd2cc4046c2e3eab1895f756dddf277f7e7edb70b38836f6aecf5e5418a15aad278a9c7698295d3804b6e13601d233df86618118eaa9b90d2e85396ca8f832fd8a19d45a0aab1ac1ec5b553ef22bc7f913b1120588606d3adeec42749a9afea9bbf7532edf28112a7b7f5dd04d4a5705f9fef23243b3787315cd64a2d419c08ae0a34a7f255ee3743ba609dbf58d8394803f257b5c645cb0c2c34bbc5531a5272f90d2ba75b0a480eaa2aa16baacc4d24b28aa040b4d153a7095a5d71305d734dee0ab2bbdcb44a5d6fba7d155dbf7b52e4003c85ea9c366cd8ff8be919c83aab8d4aee8217694619ec8f38ab02237eefd1d9c91687654acd250ae5119d9163835ec684ff6dfbcde646ce505eeec4bae70f59032b60212172b033c44b8b2d438eb5e303941aad97cbebf4b60686cc0df676e817d9c880b1abb92184c2187d96d5ad6c3b6b2b81ada7cccb1612991f0cb6456caf5176774dcd84655e1225026a8079817546e25be7edba3e00ec004af5706a9d932cc3e2bae96d3fd125ee260296961b69d184d1c3e166df30be9411db2dc50f1f0f671c94dc04165d764d74b09370e66326947128f5ac3de6efcdb6f198e433d4ee4f2497838e3a4346de4e1b165a7a3c35adf9c704ccb9e1f6e017dd647b49c3093b8aa289560d7c0bf073e533fd7c94b37ff743dad3920e5fa637de3fe816ceb"),
+    ];
+
+    #[test]
+    fn params() {
+        // Reference outputs computed with Python's hashlib (which uses
+        // the BLAKE2 reference code).
+        let data = b"The quick brown fox jumps over the lazy dog";
+        let mut buf = [0u8; 32];
+
+        let p = Blake2sParams::new().salt(b"saltsalt").personal(b"personal");
+        let mut sh = Blake2s::with_params(&p);
+        sh.update(data);
+        assert!(sh.finalize_reset_write(&mut buf) == 32);
+        assert!(buf[..] == hex::decode("ade00f78906fb77298089e78b73979822c597035be451a29395a583edab23c43").unwrap());
+        sh.update(&data[..10]);
+        sh.update(&data[10..]);
+        sh.finalize_write(&mut buf);
+        assert!(buf[..] == hex::decode("ade00f78906fb77298089e78b73979822c597035be451a29395a583edab23c43").unwrap());
+
+        let p = Blake2sParams::new()
+            .digest_length(20).salt(b"ab").personal(b"jq255e");
+        let mut sh = KeyedBlake2s::with_params(&p, b"secret key");
+        sh.update(data);
+        assert!(sh.finalize_reset_write(&mut buf) == 20);
+        assert!(buf[..20] == hex::decode("d43dcfc14da59ca9d2874de179c017f5bb30cacc").unwrap());
+        // Reset with a key shorter than 32 bytes.
+        sh.update(data);
+        assert!(sh.finalize_reset_write(&mut buf) == 20);
+        assert!(buf[..20] == hex::decode("d43dcfc14da59ca9d2874de179c017f5bb30cacc").unwrap());
+
+        let p = Blake2sParams::new()
+            .fanout(4).depth(3).leaf_length(4096).node_offset(7)
+            .node_depth(1).inner_length(32).last_node(true);
+        let mut sh = Blake2s::with_params(&p);
+        sh.update(data);
+        sh.finalize_write(&mut buf);
+        assert!(buf[..] == hex::decode("a73248dc9ce8719ee502e77e3a6e54224144a493c5357b78f775de276ab5b49a").unwrap());
+
+        // Default parameters are plain BLAKE2s.
+        let mut sh = Blake2s::with_params(&Blake2sParams::default());
+        sh.update(data);
+        sh.finalize_write(&mut buf);
+        assert!(buf == Blake2s256::hash(data));
+    }
+
+    #[test]
+    fn blake2sp() {
+        let mut key = [0u8; 32];
+        for (i, b) in key.iter_mut().enumerate() {
+            *b = i as u8;
+        }
+        let mut data = [0u8; 5000];
+        for (i, b) in data.iter_mut().enumerate() {
+            *b = i as u8;
+        }
+        let mut sh = Blake2sp::new(32, &key);
+        let mut buf = [0u8; 32];
+        for &(len, hv) in KAT_BLAKE2SP.iter() {
+            let refout = hex::decode(hv).unwrap();
+            Blake2sp::hash_into(32, &key, &data[..len], &mut buf);
+            assert!(buf[..] == refout[..]);
+
+            // Streaming with chunks that do not match block boundaries.
+            for chunk in data[..len].chunks(37) {
+                sh.update(chunk);
+            }
+            assert!(sh.finalize_reset_write(&mut buf) == 32);
+            assert!(buf[..] == refout[..]);
+        }
+    }
+
+    #[test]
+    fn blake2xs() {
+        let mut key = [0u8; 32];
+        for (i, b) in key.iter_mut().enumerate() {
+            *b = i as u8;
+        }
+        let mut data = [0u8; 256];
+        for (i, b) in data.iter_mut().enumerate() {
+            *b = i as u8;
+        }
+        let mut buf = [0u8; 1000];
+        for &(len, hv) in KAT_BLAKE2XS.iter() {
+            let refout = hex::decode(hv).unwrap();
+            Blake2xs::hash_into(len, &key, &data, &mut buf);
+            assert!(buf[..len] == refout[..]);
+
+            // Output obtained in several chunks.
+            let mut sh = Blake2xs::new(len, &key);
+            sh.update(&data[..100]);
+            sh.update(&data[100..]);
+            let mut j = 0;
+            while j < len {
+                let clen = core::cmp::min(len - j, 7 + (j % 40));
+                sh.read(&mut buf[j..(j + clen)]);
+                j += clen;
+            }
+            assert!(buf[..len] == refout[..]);
+            sh.reset();
+            sh.update(&data);
+            assert!(sh.finalize_reset_write(&mut buf) == len);
+            assert!(buf[..len] == refout[..]);
+        }
+
+        // Unknown output length: all blocks are 32 bytes.
+        let mut sh = Blake2xs::new(Blake2xs::UNKNOWN_LENGTH, &key);
+        sh.update(&data);
+        sh.read(&mut buf[..50]);
+        sh.read(&mut buf[50..70]);
+        assert!(buf[..70] == hex::decode("2a9a6977d915a2c4dd07dbcafe1918bf1682e56d9c8e567ecd19bfd7cd93528833c764d12b34a5e2a219c9fd463dab45e972c5574d73f45de5b2e23af72530d8e0cbe417cf12").unwrap());
+    }
+
+
+    #[test]
+    fn process_block_impl() {
+        // All implementations of the block processing function must
+        // return the same results as the portable code.
+        let mut block = [0u8; BUF_LEN];
+        let mut h = [0u32; 8];
+        for i in 0..100u32 {
+            let mut sh = Blake2s256::new();
+            sh.update(&i.to_le_bytes());
+            let seed = sh.finalize();
+            for j in 0..BUF_LEN {
+                block[j] = seed[j & 31] ^ (j as u8);
+            }
+            for j in 0..8 {
+                h[j] = (seed[j] as u32).wrapping_mul(0x9E3779B9);
+            }
+            let ctr = ((seed[0] as u64) << 32) | (i as u64);
+            for k in 0..3 {
+                let (last, last_node) = (k >= 1, k == 2);
+                let mut h1 = h;
+                Blake2s::process_block_portable(
+                    &mut h1, &block, ctr, last, last_node);
+                #[cfg(target_arch = "x86_64")]
+                unsafe {
+                    let mut h2 = h;
+                    Blake2s::process_block_sse2(
+                        &mut h2, &block, ctr, last, last_node);
+                    assert!(h1 == h2);
+                    if std::is_x86_feature_detected!("sse4.1")
+                        && std::is_x86_feature_detected!("ssse3")
+                    {
+                        let mut h2 = h;
+                        Blake2s::process_block_sse41(
+                            &mut h2, &block, ctr, last, last_node);
+                        assert!(h1 == h2);
+                    }
+                    if std::is_x86_feature_detected!("avx2") {
+                        let mut h2 = h;
+                        Blake2s::process_block_avx2(
+                            &mut h2, &block, ctr, last, last_node);
+                        assert!(h1 == h2);
+                    }
+                }
+                h = h1;
+            }
+        }
+    }
+
+    #[test]
+    fn multi_buffer() {
+        let mut data = [0u8; 1008];
+        for (i, b) in data.iter_mut().enumerate() {
+            *b = (i as u8).wrapping_mul(17).wrapping_add(5);
+        }
+        let key = b"multi-buffer key";
+        let lens = [
+            [ 0, 1, 2, 3, 4, 5, 6, 7 ],
+            [ 63, 64, 65, 127, 128, 129, 0, 1000 ],
+            [ 500, 500, 500, 500, 500, 500, 500, 500 ],
+            [ 1000, 0, 64, 192, 17, 999, 256, 3 ],
+        ];
+        for ll in lens.iter() {
+            let mut mm: [&[u8]; 8] = [&[]; 8];
+            for i in 0..8 {
+                mm[i] = &data[i..(i + ll[i])];
+            }
+            let mut out = [[0u8; 32]; 8];
+            let mut buf = [0u8; 32];
+
+            Blake2s::hash_x8(32, &mm, &mut out);
+            for i in 0..8 {
+                assert!(out[i] == Blake2s256::hash(mm[i]));
+            }
+
+            KeyedBlake2s::hash_x8(20, key, &mm, &mut out);
+            for i in 0..8 {
+                KeyedBlake2s::hash_into(20, key, mm[i], &mut buf);
+                assert!(out[i][..20] == buf[..20]);
+            }
+
+            let p = Blake2sParams::new()
+                .personal(b"x8").node_offset(3).last_node(true);
+            KeyedBlake2s::hash_x8_with_params(&p, key, &mm, &mut out);
+            let mut out2 = [[0u8; 32]; 8];
+            let mut p2 = p;
+            p2.key_length = key.len() as u8;
+            hash_x8_portable(&p2, key, &mm, &mut out2);
+            assert!(out == out2);
+            for i in 0..8 {
+                let mut sh = KeyedBlake2s::with_params(&p, key);
+                sh.update(mm[i]);
+                sh.finalize_write(&mut buf);
+                assert!(out[i] == buf);
+            }
+        }
+    }
+
+
+    #[test]
+    fn digest_traits() {
+        use digest::{Digest, Mac, KeyInit, VariableOutput, VariableOutputReset};
+
+        let mut data = [0u8; 300];
+        for (i, x) in data.iter_mut().enumerate() {
+            *x = i as u8;
+        }
+        let key = [0x5Au8; 20];
+
+        let mut sh = <Blake2s256 as Digest>::new();
+        Digest::update(&mut sh, &data[..]);
+        assert!(sh.finalize()[..] == Blake2s256::hash(&data)[..]);
+        assert!(<Blake2s256 as Digest>::digest(&data[..])[..]
+            == Blake2s256::hash(&data)[..]);
+
+        let mut ref_out = [0u8; 32];
+        Blake2s::hash_into(20, &data, &mut ref_out);
+        let mut sh = <Blake2s as VariableOutput>::new(20).unwrap();
+        digest::Update::update(&mut sh, &data[..]);
+        let mut out = [0u8; 20];
+        assert!(sh.clone().finalize_variable(&mut out[..19]).is_err());
+        sh.finalize_variable_reset(&mut out).unwrap();
+        assert!(out[..] == ref_out[..20]);
+        digest::Update::update(&mut sh, &data[..]);
+        sh.finalize_variable(&mut out).unwrap();
+        assert!(out[..] == ref_out[..20]);
+        assert!(<Blake2s as VariableOutput>::new(0).is_err());
+        assert!(<Blake2s as VariableOutput>::new(33).is_err());
+
+        KeyedBlake2s::hash_into(32, &key, &data, &mut ref_out);
+        let mut mac = <KeyedBlake2s as KeyInit>::new_from_slice(&key).unwrap();
+        Mac::update(&mut mac, &data[..]);
+        let tag = mac.clone().finalize().into_bytes();
+        assert!(tag[..] == ref_out[..]);
+        assert!(mac.clone().verify_slice(&ref_out).is_ok());
+        ref_out[5] ^= 0x01;
+        assert!(mac.verify_slice(&ref_out).is_err());
+        assert!(<KeyedBlake2s as KeyInit>::new_from_slice(&[0u8; 33]).is_err());
+    }
 }
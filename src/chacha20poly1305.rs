@@ -0,0 +1,391 @@
+//! ChaCha20-Poly1305 implementation.
+//!
+//! This follows RFC 8439: ChaCha20 with a 32-bit block counter and a
+//! 96-bit nonce, Poly1305, and their combination as an AEAD. The AEAD
+//! functions work in place and use detached 16-byte tags, so that no
+//! heap allocation is needed. The implementation is portable and
+//! constant-time (Poly1305 uses 44-bit limbs and 64x64->128
+//! multiplications).
+
+#![allow(non_snake_case)]
+
+use core::convert::TryFrom;
+
+/// ChaCha20-Poly1305 AEAD, with a 32-byte key.
+#[derive(Clone, Copy)]
+pub struct ChaCha20Poly1305 {
+    key: [u8; 32],
+}
+
+impl ChaCha20Poly1305 {
+
+    /// Key length (in bytes).
+    pub const KEY_LEN: usize = 32;
+
+    /// Nonce length (in bytes).
+    pub const NONCE_LEN: usize = 12;
+
+    /// Tag length (in bytes).
+    pub const TAG_LEN: usize = 16;
+
+    /// Create a new instance with the provided key.
+    pub fn new(key: &[u8; 32]) -> Self {
+        Self { key: *key }
+    }
+
+    /// Encrypt some data in place. The additional authenticated data
+    /// (`aad`) is not encrypted but covered by the returned tag.
+    ///
+    /// A given nonce MUST NOT be used twice with the same key.
+    pub fn encrypt_in_place(&self, nonce: &[u8; 12], aad: &[u8],
+        data: &mut [u8]) -> [u8; 16]
+    {
+        chacha20(&self.key, 1, nonce, data);
+        self.compute_tag(nonce, aad, data)
+    }
+
+    /// Decrypt some data in place. Returned value is `true` if the tag
+    /// (`tag`) matches, in which case the data has been decrypted; if
+    /// the tag does not match, then `false` is returned and the data is
+    /// left unmodified (still encrypted).
+    pub fn decrypt_in_place(&self, nonce: &[u8; 12], aad: &[u8],
+        data: &mut [u8], tag: &[u8]) -> bool
+    {
+        if tag.len() != 16 {
+            return false;
+        }
+        let t = self.compute_tag(nonce, aad, data);
+        let mut z = 0u8;
+        for (x, y) in t.iter().zip(tag.iter()) {
+            z |= x ^ y;
+        }
+        if z != 0 {
+            return false;
+        }
+        chacha20(&self.key, 1, nonce, data);
+        true
+    }
+
+    // Compute the tag over the AAD and the ciphertext.
+    fn compute_tag(&self, nonce: &[u8; 12], aad: &[u8], ct: &[u8])
+        -> [u8; 16]
+    {
+        let mut otk = [0u8; 32];
+        chacha20(&self.key, 0, nonce, &mut otk);
+        let mut pm = Poly1305::new(&otk);
+        pm.update_padded(aad);
+        pm.update_padded(ct);
+        let mut lens = [0u8; 16];
+        lens[..8].copy_from_slice(&(aad.len() as u64).to_le_bytes());
+        lens[8..].copy_from_slice(&(ct.len() as u64).to_le_bytes());
+        pm.update_padded(&lens);
+        pm.finalize()
+    }
+}
+
+/// Apply ChaCha20 (RFC 8439) on the provided data, i.e. XOR the data with
+/// the ChaCha20 key stream for the given key, nonce and initial block
+/// counter. This both encrypts and decrypts.
+pub fn chacha20(key: &[u8; 32], counter: u32, nonce: &[u8; 12],
+    data: &mut [u8])
+{
+    let mut st = [0u32; 16];
+    st[0] = 0x61707865;
+    st[1] = 0x3320646E;
+    st[2] = 0x79622D32;
+    st[3] = 0x6B206574;
+    for i in 0..8 {
+        st[4 + i] = u32::from_le_bytes(*<&[u8; 4]>::try_from(
+            &key[(4 * i)..(4 * i + 4)]).unwrap());
+    }
+    st[12] = counter;
+    for i in 0..3 {
+        st[13 + i] = u32::from_le_bytes(*<&[u8; 4]>::try_from(
+            &nonce[(4 * i)..(4 * i + 4)]).unwrap());
+    }
+
+    macro_rules! qr {
+        ($v: expr, $a: expr, $b: expr, $c: expr, $d: expr) => {
+            $v[$a] = $v[$a].wrapping_add($v[$b]);
+            $v[$d] = ($v[$d] ^ $v[$a]).rotate_left(16);
+            $v[$c] = $v[$c].wrapping_add($v[$d]);
+            $v[$b] = ($v[$b] ^ $v[$c]).rotate_left(12);
+            $v[$a] = $v[$a].wrapping_add($v[$b]);
+            $v[$d] = ($v[$d] ^ $v[$a]).rotate_left(8);
+            $v[$c] = $v[$c].wrapping_add($v[$d]);
+            $v[$b] = ($v[$b] ^ $v[$c]).rotate_left(7);
+        }
+    }
+
+    for chunk in data.chunks_mut(64) {
+        let mut v = st;
+        for _ in 0..10 {
+            qr!(v, 0, 4,  8, 12);
+            qr!(v, 1, 5,  9, 13);
+            qr!(v, 2, 6, 10, 14);
+            qr!(v, 3, 7, 11, 15);
+            qr!(v, 0, 5, 10, 15);
+            qr!(v, 1, 6, 11, 12);
+            qr!(v, 2, 7,  8, 13);
+            qr!(v, 3, 4,  9, 14);
+        }
+        let mut ks = [0u8; 64];
+        for i in 0..16 {
+            ks[(4 * i)..(4 * i + 4)].copy_from_slice(
+                &v[i].wrapping_add(st[i]).to_le_bytes());
+        }
+        for (x, k) in chunk.iter_mut().zip(ks.iter()) {
+            *x ^= *k;
+        }
+        st[12] = st[12].wrapping_add(1);
+    }
+}
+
+// Poly1305 state; the accumulator and the r key use 44-bit limbs
+// (44+44+42 bits).
+struct Poly1305 {
+    r: [u64; 3],
+    h: [u64; 3],
+    pad: [u64; 2],
+}
+
+const M44: u64 = (1u64 << 44) - 1;
+const M42: u64 = (1u64 << 42) - 1;
+
+impl Poly1305 {
+
+    fn new(key: &[u8; 32]) -> Self {
+        let t0 = u64::from_le_bytes(*<&[u8; 8]>::try_from(&key[0..8]).unwrap());
+        let t1 = u64::from_le_bytes(*<&[u8; 8]>::try_from(&key[8..16]).unwrap());
+        let p0 = u64::from_le_bytes(*<&[u8; 8]>::try_from(&key[16..24]).unwrap());
+        let p1 = u64::from_le_bytes(*<&[u8; 8]>::try_from(&key[24..32]).unwrap());
+        // r is clamped.
+        let r0 = t0 & 0xFFC0FFFFFFF;
+        let r1 = ((t0 >> 44) | (t1 << 20)) & 0xFFFFFC0FFFF;
+        let r2 = (t1 >> 24) & 0x00FFFFFFC0F;
+        Self { r: [r0, r1, r2], h: [0; 3], pad: [p0, p1] }
+    }
+
+    // Process one 16-byte block; `hibit` is 2^40 for full blocks (it is
+    // the 2^128 bit, relatively to the top limb).
+    fn block(&mut self, b: &[u8], hibit: u64) {
+        let t0 = u64::from_le_bytes(*<&[u8; 8]>::try_from(&b[0..8]).unwrap());
+        let t1 = u64::from_le_bytes(*<&[u8; 8]>::try_from(&b[8..16]).unwrap());
+        let [r0, r1, r2] = self.r;
+        let s1 = r1 * 20;
+        let s2 = r2 * 20;
+        let h0 = self.h[0] + (t0 & M44);
+        let h1 = self.h[1] + (((t0 >> 44) | (t1 << 20)) & M44);
+        let h2 = self.h[2] + (((t1 >> 24) & M42) | hibit);
+
+        let mm = |a: u64, b: u64| (a as u128) * (b as u128);
+        let d0 = mm(h0, r0) + mm(h1, s2) + mm(h2, s1);
+        let mut d1 = mm(h0, r1) + mm(h1, r0) + mm(h2, s2);
+        let mut d2 = mm(h0, r2) + mm(h1, r1) + mm(h2, r0);
+
+        let mut c = (d0 >> 44) as u64;
+        let mut h0 = (d0 as u64) & M44;
+        d1 += c as u128;
+        c = (d1 >> 44) as u64;
+        let h1 = (d1 as u64) & M44;
+        d2 += c as u128;
+        c = (d2 >> 42) as u64;
+        let h2 = (d2 as u64) & M42;
+        h0 += c * 5;
+        c = h0 >> 44;
+        h0 &= M44;
+        self.h = [h0, h1 + c, h2];
+    }
+
+    // Inject some data, padded with zeros to a multiple of 16 bytes.
+    fn update_padded(&mut self, data: &[u8]) {
+        let mut chunks = data.chunks_exact(16);
+        for b in &mut chunks {
+            self.block(b, 1u64 << 40);
+        }
+        let rem = chunks.remainder();
+        if !rem.is_empty() {
+            let mut tmp = [0u8; 16];
+            tmp[..rem.len()].copy_from_slice(rem);
+            self.block(&tmp, 1u64 << 40);
+        }
+    }
+
+    fn finalize(&self) -> [u8; 16] {
+        let [mut h0, mut h1, mut h2] = self.h;
+
+        // Full carry propagation.
+        let mut c = h1 >> 44;
+        h1 &= M44;
+        h2 += c;
+        c = h2 >> 42;
+        h2 &= M42;
+        h0 += c * 5;
+        c = h0 >> 44;
+        h0 &= M44;
+        h1 += c;
+        c = h1 >> 44;
+        h1 &= M44;
+        h2 += c;
+        c = h2 >> 42;
+        h2 &= M42;
+        h0 += c * 5;
+        c = h0 >> 44;
+        h0 &= M44;
+        h1 += c;
+
+        // Compute h - p = h + 5 - 2^130, and keep it if it is not
+        // negative.
+        let mut g0 = h0 + 5;
+        c = g0 >> 44;
+        g0 &= M44;
+        let mut g1 = h1 + c;
+        c = g1 >> 44;
+        g1 &= M44;
+        let g2 = (h2 + c).wrapping_sub(1u64 << 42);
+        let m = (g2 >> 63).wrapping_sub(1);
+        h0 = (h0 & !m) | (g0 & m);
+        h1 = (h1 & !m) | (g1 & m);
+        h2 = (h2 & !m) | (g2 & m);
+
+        // Add the pad (modulo 2^128).
+        let [p0, p1] = self.pad;
+        h0 += p0 & M44;
+        c = h0 >> 44;
+        h0 &= M44;
+        h1 += (((p0 >> 44) | (p1 << 20)) & M44) + c;
+        c = h1 >> 44;
+        h1 &= M44;
+        h2 += ((p1 >> 24) & M42) + c;
+
+        let w0 = h0 | (h1 << 44);
+        let w1 = (h1 >> 20) | (h2 << 24);
+        let mut r = [0u8; 16];
+        r[..8].copy_from_slice(&w0.to_le_bytes());
+        r[8..].copy_from_slice(&w1.to_le_bytes());
+        r
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::{ChaCha20Poly1305, chacha20};
+    use core::convert::TryFrom;
+
+    static KAT_CHACHA20POLY1305: [[&str; 5]; 10] = [
+        // Each group of five values is:
+        //   key
+        //   nonce
+        //   additional authenticated data
+        //   plaintext
+        //   ciphertext and tag
+        // First vector is from RFC 8439 (section 2.8.2); the others were
+        // generated with OpenSSL (through Python's cryptography package).
+        [
+            "808182838485868788898a8b8c8d8e8f909192939495969798999a9b9c9d9e9f",
+            "070000004041424344454647",
+            "50515253c0c1c2c3c4c5c6c7",
+            "4c616469657320616e642047656e746c656d656e206f662074686520636c617373206f66202739393a204966204920636f756c64206f6666657220796f75206f6e6c79206f6e652074697020666f7220746865206675747572652c2073756e73637265656e20776f756c642062652069742e",
+            "d31a8d34648e60db7b86afbc53ef7ec2a4aded51296e08fea9e2b5a736ee62d63dbea45e8ca9671282fafb69da92728b1a71de0a9e060b2905d6a5b67ecd3b3692ddbd7f2d778b8c9803aee328091b58fab324e4fad675945585808b4831d7bc3ff4def08e4b7a9de576d26586cec64b61161ae10b594f09e26a7e902ecbd0600691",
+        ], [
+            "d1a5ac9a015fac2ef7b341673635512a1511f41fe37d111b267f039eec5d4f58",
+            "820d5d8baf762ec66dcd56fe",
+            "",
+            "",
+            "6b0715925d97b261153badf78fdeda22",
+        ], [
+            "6ab9f1eb8f7d3388f4f9d586f66e99fd54080df2c446f0e58668b09c08a16dd0",
+            "676b8bb84ce7267dd520deca",
+            "f5",
+            "01",
+            "bc4a1e8b2a0e0a5e6a0430788eef410183",
+        ], [
+            "5bd4ca497530b4b3490e350f3590bba9fe216128b35a578f459fc9ab8745ae88",
+            "bb0ec63bac973cad2d8735a0",
+            "75c47b1ef767a30eb39cdb4d964623",
+            "0f161d242b323940474e555c636a71",
+            "5b20d371cc122e48e0aca33ddee2625c1a5ae6516a10ebc2fe102edd50a1f6",
+        ], [
+            "8d68c655677c5c72d8118949e899757191592f3252412e16dc64fa7e44ffa095",
+            "5b82a3069343d3c9ec3e471e",
+            "5f95a7d242e4ea751ed82afbbbc35bf0",
+            "10171e252c333a41484f565d646b7279",
+            "01aaf8aa2b5f5e11d00e606d78bb0a1b5a10775945d25278798443471f38bf1b",
+        ], [
+            "6c47c1de66e7e5ebd7b1fe0746ea1a360bcf043929829fb18cbd7a07c25d7639",
+            "45415bc117ae92dd968f64dd",
+            "622dd0c704d6af123eea761a8572143f99",
+            "11181f262d343b424950575e656c737a81",
+            "97ccd4eb557a13411852ade9644ec0d7a6e0b95188f29b2e2badd852023a70a090",
+        ], [
+            "32fdefc7a4275265dc4f0115eb325f7a1e2bece6031131fe37039ba730619623",
+            "1f972106ee64cf1cd26bf745",
+            "3ffa06a969d64318a092a576292b6c0d13",
+            "3f464d545b626970777e858c939aa1a8afb6bdc4cbd2d9e0e7eef5fc030a11181f262d343b424950575e656c737a81888f969da4abb2b9c0c7ced5dce3eaf1",
+            "32e60c6a8853f10baa2906ddd8ffa6b723768ab57db336ec0e1033f852544176db3c6008eec51c1ca8dd4918f5df1ae2eb8972fa29ee34b717fd5a46b72bbbcd44e6f34a0993ad127d448756178cad",
+        ], [
+            "6fc10b7e5ca73348f06f158655109b55f617370d81f73662d8131a04f3a07484",
+            "2616f2173c5b9cb5292a1676",
+            "17bf0eb6d2c9f8cd288b846e282835501244",
+            "40474e555c636a71787f868d949ba2a9b0b7bec5ccd3dae1e8eff6fd040b121920272e353c434a51585f666d747b828990979ea5acb3bac1c8cfd6dde4ebf2f9",
+            "cde25cf8177e72dbbd5220eece84ce9a4039901b0ec9c10d88caef6426cc8d01e9a80f3b03e41f8834c28b0051a60c61ef39c55a13bc1452a3b3747b7b31f4bf15d5db845f2659a68e918aabd7931476",
+        ], [
+            "b66f6e5a0c22cea447ead7edf7c46e1276f8ead1f7b6a45d20ca0cd204f24945",
+            "f7d5e6c002f39b915a0f5d1a",
+            "8514648bb2e54e6dbf0c38cfa67a01ac0351bf",
+            "41484f565d646b727980878e959ca3aab1b8bfc6cdd4dbe2e9f0f7fe050c131a21282f363d444b525960676e757c838a91989fa6adb4bbc2c9d0d7dee5ecf3fa01",
+            "f27a4c544c326c9ed45f510d0a05c0b77223fc8c83c0c788c845f65c6d581e27c3d6c96bbf3484e748a16e76d798b65371e65c0fac6818fd97acdb1cdb95ba51d24869f21ffe52818bbafc5f42328fd31e",
+        ], [
+            "9032e18a2af274d1a3a52597e277ad6621ed3065923f7c87db0c2acd91f50e78",
+            "56fd91119f07e5b12b9a9d0c",
+            "c444abe783bcf9bf59fc29744f57dd04",
+            "c8cfd6dde4ebf2f900070e151c232a31383f464d545b626970777e858c939aa1a8afb6bdc4cbd2d9e0e7eef5fc030a11181f262d343b424950575e656c737a81888f969da4abb2b9c0c7ced5dce3eaf1f8ff060d141b222930373e454c535a61686f767d848b9299a0a7aeb5bcc3cad1d8dfe6edf4fb020910171e252c333a41484f565d646b727980878e959ca3aab1b8bfc6cdd4dbe2e9f0f7fe050c131a21282f363d444b525960676e757c838a91989fa6adb4bbc2c9d0d7dee5ecf3fa01080f161d242b3239",
+            "9e2e055b8baa6f98e118a345e82410c8bb556a771ad0cd28b788cc084e6e228236c8aeb3eb31e0851f30906a7435b54dc71a2bf000c3db73f0f02eda65ab140c481e8761ed918c3a871fd55e98ac60ee65030397d2347bf19c3820d2a1ec2d007e5f3ad96dfffc501483581c55bc3186faa1630726458034e1baf4917fba7de8868af64278b403bb98346572d6b08da56eb1d2ef8e57187f6d12c17c7c5190ebd6de458f4ecf7bf6817d3b5f30ef91d163e074a2c8fd3d8507a2c4a61a8953c184992651de04a1dd0461e49800f4e0f3439a7cb5874b4590",
+        ],
+    ];
+
+    #[test]
+    fn KAT() {
+        for kv in KAT_CHACHA20POLY1305.iter() {
+            let key = hex::decode(kv[0]).unwrap();
+            let nonce = hex::decode(kv[1]).unwrap();
+            let aad = hex::decode(kv[2]).unwrap();
+            let pt = hex::decode(kv[3]).unwrap();
+            let ct = hex::decode(kv[4]).unwrap();
+            let n = pt.len();
+            let aead = ChaCha20Poly1305::new(&<[u8; 32]>::try_from(&key[..]).unwrap());
+            let nonce = <[u8; 12]>::try_from(&nonce[..]).unwrap();
+
+            let mut buf = pt.clone();
+            let tag = aead.encrypt_in_place(&nonce, &aad, &mut buf);
+            assert!(buf[..] == ct[..n]);
+            assert!(tag[..] == ct[n..]);
+
+            assert!(aead.decrypt_in_place(&nonce, &aad, &mut buf, &tag));
+            assert!(buf == pt);
+
+            let mut buf = ct[..n].to_vec();
+            let mut tag = tag;
+            tag[3] ^= 0x10;
+            assert!(!aead.decrypt_in_place(&nonce, &aad, &mut buf, &tag));
+            assert!(buf[..] == ct[..n]);
+            assert!(!aead.decrypt_in_place(&nonce, &aad, &mut buf, &tag[..15]));
+        }
+    }
+
+    #[test]
+    fn chacha20_stream() {
+        // RFC 8439, section 2.4.2.
+        let mut key = [0u8; 32];
+        for (i, x) in key.iter_mut().enumerate() {
+            *x = i as u8;
+        }
+        let nonce = hex::decode("000000000000004a00000000").unwrap();
+        let pt = b"Ladies and Gentlemen of the class of '99: If I could offer you only one tip for the future, sunscreen would be it.";
+        let ct = hex::decode("6e2e359a2568f98041ba0728dd0d6981e97e7aec1d4360c20a27afccfd9fae0bf91b65c5524733ab8f593dabcd62b3571639d624e65152ab8f530c359f0861d807ca0dbf500d6a6156a38e088a22b65e52bc514d16ccf806818ce91ab77937365af90bbf74a35be6b40b8eedf2785e42874d").unwrap();
+        let mut buf = pt.to_vec();
+        chacha20(&key, 1, &<[u8; 12]>::try_from(&nonce[..]).unwrap(), &mut buf);
+        assert!(buf == ct);
+    }
+}
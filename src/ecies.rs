@@ -0,0 +1,817 @@
+//! ECIES implementation.
+//!
+//! This module implements the Elliptic Curve Integrated Encryption Scheme
+//! over the curves P-256 (`P256`) and secp256k1 (`Secp256k1`). The sender
+//! generates an ephemeral key pair, performs an ECDH with the recipient
+//! public key, derives a symmetric key from the shared secret, and
+//! encrypts the message with an AEAD. The ephemeral public key (in
+//! compressed or uncompressed format) is sent along with the ciphertext.
+//! Three variants are supported, selected with the `Scheme` type:
+//!
+//!  - `Scheme::Sec1`: SEC 1 (section 5.1) key derivation: the KDF input
+//!    is the x-coordinate of the shared point. The `shared_info`
+//!    parameter is SharedInfo1 (KDF input), while the `aad` parameter is
+//!    SharedInfo2 (authenticated by the AEAD). The DEM is not the SEC 1
+//!    one (symmetric encryption followed by a separate MAC): the message
+//!    is encrypted with an AEAD and an all-zero nonce, so ciphertexts are
+//!    not interoperable with other SEC 1 implementations. Output is the
+//!    ephemeral public key, followed by the encrypted message and the
+//!    16-byte tag.
+//!
+//!  - `Scheme::Iso18033`: ISO 18033-2 ECIES-KEM key derivation with
+//!    SingleHashMode = 0: the KDF input is the encoded ephemeral public
+//!    key followed by the x-coordinate of the shared point. The DEM and
+//!    the output format are the same as with `Scheme::Sec1`.
+//!
+//!  - `Scheme::EciesPy`: the variant used by the eciespy library (and
+//!    its eciesjs/eciesrs companions): the KDF input is the encoded
+//!    ephemeral public key followed by the encoding of the shared point,
+//!    in the same format (compressed or uncompressed) as the ephemeral
+//!    key, and a random nonce is used (16 bytes for AES-GCM, 12 bytes
+//!    for ChaCha20+Poly1305). Output is the ephemeral public key, the
+//!    nonce, the tag, then the encrypted message. `Params::ECIESPY`
+//!    corresponds to the default eciespy configuration (HKDF-SHA256,
+//!    AES-256-GCM, uncompressed ephemeral keys, no shared info and no
+//!    additional authenticated data); setting `compressed` matches
+//!    eciespy with both `is_ephemeral_key_compressed` and
+//!    `is_hkdf_key_compressed` enabled. ChaCha20+Poly1305 with a 12-byte
+//!    nonce is not an eciespy configuration (eciespy uses XChaCha20).
+//!
+//! The KDF is either the ANSI X9.63 KDF with SHA-256, or HKDF-SHA256
+//! (with an empty salt; `shared_info` is used as the HKDF info). The
+//! AEAD is AES-128-GCM, AES-256-GCM or ChaCha20+Poly1305. With `Sec1`
+//! and `Iso18033`, the symmetric key is used for a single message, and
+//! the AEAD nonce is all-zeros.
+//!
+//! Decryption uses the same parameters as encryption. It returns `None`
+//! if the ephemeral public key is invalid or if the tag does not match.
+
+use core::marker::PhantomData;
+use sha2::Sha256;
+use super::{CryptoRng, RngCore};
+use super::aes::AesGcm;
+use super::chacha20poly1305::ChaCha20Poly1305;
+use super::kdf::{x963_kdf, Hkdf};
+
+#[cfg(feature = "alloc")]
+use crate::Vec;
+
+/// Curve on which ECIES is performed.
+pub trait Curve {
+    /// Private key type.
+    type PrivateKey: Copy;
+
+    /// Public key type.
+    type PublicKey: Copy;
+
+    /// Generates a new private key.
+    fn generate_private_key<T: CryptoRng + RngCore>(rng: &mut T)
+        -> Self::PrivateKey;
+
+    /// Gets the public key corresponding to a private key.
+    fn to_public_key(sk: &Self::PrivateKey) -> Self::PublicKey;
+
+    /// Encodes a public key (33 or 65 bytes). The encoded length is
+    /// returned.
+    fn encode_public_key(pk: &Self::PublicKey, compressed: bool,
+        out: &mut [u8]) -> usize;
+
+    /// Decodes a public key (compressed or uncompressed). The neutral
+    /// point is rejected.
+    fn decode_public_key(buf: &[u8]) -> Option<Self::PublicKey>;
+
+    /// Computes the ECDH shared secret (x-coordinate of the shared point)
+    /// with the encoded peer public key (`PrivateKey::ECDH()`). The
+    /// status is 0xFFFFFFFF on success, 0x00000000 if the peer public key
+    /// is invalid.
+    fn ecdh(sk: &Self::PrivateKey, peer_pk: &[u8]) -> ([u8; 32], u32);
+
+    /// Same as `ecdh()`, but the whole shared point is returned, in
+    /// uncompressed format (65 bytes).
+    fn ecdh_point(sk: &Self::PrivateKey, peer_pk: &[u8]) -> ([u8; 65], u32);
+}
+
+macro_rules! define_curve {
+    ($typename:ident, $m:ident, $name:expr) => {
+        #[doc = concat!("ECIES over ", $name, ".")]
+        #[derive(Clone, Copy, Debug)]
+        pub struct $typename;
+
+        impl Curve for $typename {
+            type PrivateKey = super::$m::PrivateKey;
+            type PublicKey = super::$m::PublicKey;
+
+            fn generate_private_key<T: CryptoRng + RngCore>(rng: &mut T)
+                -> Self::PrivateKey
+            {
+                super::$m::PrivateKey::generate(rng)
+            }
+
+            fn to_public_key(sk: &Self::PrivateKey) -> Self::PublicKey {
+                sk.to_public_key()
+            }
+
+            fn encode_public_key(pk: &Self::PublicKey, compressed: bool,
+                out: &mut [u8]) -> usize
+            {
+                if compressed {
+                    out[..33].copy_from_slice(&pk.encode_compressed());
+                    33
+                } else {
+                    out[..65].copy_from_slice(&pk.encode_uncompressed());
+                    65
+                }
+            }
+
+            fn decode_public_key(buf: &[u8]) -> Option<Self::PublicKey> {
+                super::$m::PublicKey::decode(buf)
+            }
+
+            fn ecdh(sk: &Self::PrivateKey, peer_pk: &[u8]) -> ([u8; 32], u32) {
+                sk.ECDH(peer_pk)
+            }
+
+            fn ecdh_point(sk: &Self::PrivateKey, peer_pk: &[u8])
+                -> ([u8; 65], u32)
+            {
+                let (q, ok) = sk.ecdh_point(peer_pk);
+                (q.encode_uncompressed(), ok)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "p256")]
+define_curve!(P256, p256, "P-256");
+
+#[cfg(feature = "secp256k1")]
+define_curve!(Secp256k1, secp256k1, "secp256k1");
+
+/// ECIES variant (see the module documentation).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Scheme {
+    Sec1,
+    Iso18033,
+    EciesPy,
+}
+
+/// Key derivation function.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Kdf {
+    /// ANSI X9.63 KDF (also SEC 1 KDF2) with SHA-256.
+    X963Sha256,
+    /// HKDF-SHA256 with an empty salt.
+    HkdfSha256,
+}
+
+/// Authenticated encryption algorithm.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Aead {
+    Aes128Gcm,
+    Aes256Gcm,
+    ChaCha20Poly1305,
+}
+
+/// ECIES parameters.
+#[derive(Clone, Copy, Debug)]
+pub struct Params {
+    pub scheme: Scheme,
+    pub kdf: Kdf,
+    pub aead: Aead,
+    /// If true, ephemeral public keys use the compressed format (33
+    /// bytes); otherwise, the uncompressed format (65 bytes) is used.
+    pub compressed: bool,
+}
+
+impl Params {
+
+    /// Default eciespy configuration.
+    pub const ECIESPY: Self = Self {
+        scheme: Scheme::EciesPy,
+        kdf: Kdf::HkdfSha256,
+        aead: Aead::Aes256Gcm,
+        compressed: false,
+    };
+
+    // Length (in bytes) of the encoded ephemeral public key.
+    fn eph_len(&self) -> usize {
+        if self.compressed { 33 } else { 65 }
+    }
+
+    // Length (in bytes) of the nonce included in the ciphertext.
+    fn nonce_len(&self) -> usize {
+        match (self.scheme, self.aead) {
+            (Scheme::EciesPy, Aead::ChaCha20Poly1305) => 12,
+            (Scheme::EciesPy, _) => 16,
+            _ => 0,
+        }
+    }
+
+    // Length (in bytes) of the symmetric key.
+    fn key_len(&self) -> usize {
+        match self.aead {
+            Aead::Aes128Gcm => 16,
+            _ => 32,
+        }
+    }
+
+    /// Gets the ciphertext overhead (in bytes): ephemeral public key,
+    /// nonce (if any) and tag.
+    pub fn overhead(&self) -> usize {
+        self.eph_len() + self.nonce_len() + 16
+    }
+}
+
+/// ECIES encryptor/decryptor over a given curve.
+#[derive(Clone, Copy, Debug)]
+pub struct Ecies<C: Curve> {
+    params: Params,
+    _c: PhantomData<C>,
+}
+
+impl<C: Curve> Ecies<C> {
+
+    /// Creates an instance with the provided parameters.
+    pub fn new(params: Params) -> Self {
+        Self { params, _c: PhantomData }
+    }
+
+    /// Gets the parameters used by this instance.
+    pub fn params(&self) -> Params {
+        self.params
+    }
+
+    /// Gets the length (in bytes) of the ciphertext for a plaintext of
+    /// length `pt_len` bytes.
+    pub fn ciphertext_len(&self, pt_len: usize) -> usize {
+        pt_len + self.params.overhead()
+    }
+
+    /// Encrypts the plaintext `pt` for the recipient public key `pk`.
+    /// The ciphertext is written into `out` (which must be large enough,
+    /// see `ciphertext_len()`), and its length is returned.
+    pub fn encrypt_to<T: CryptoRng + RngCore>(&self, rng: &mut T,
+        pk: &C::PublicKey, shared_info: &[u8], aad: &[u8], pt: &[u8],
+        out: &mut [u8]) -> usize
+    {
+        let p = &self.params;
+        let eph_len = p.eph_len();
+        let nonce_len = p.nonce_len();
+        let ct_len = self.ciphertext_len(pt.len());
+        assert!(out.len() >= ct_len);
+
+        // Ephemeral key pair and key derivation.
+        let esk = C::generate_private_key(rng);
+        let epk = C::to_public_key(&esk);
+        C::encode_public_key(&epk, p.compressed, &mut out[..eph_len]);
+        let mut pkb = [0u8; 65];
+        C::encode_public_key(pk, false, &mut pkb);
+        let mut shared = [0u8; 65];
+        // The recipient public key is valid, hence this cannot fail.
+        let shared_len = self.shared_secret(&esk, &pkb, &mut shared)
+            .unwrap();
+        let mut key = [0u8; 32];
+        self.derive_key(&out[..eph_len], &shared[..shared_len], shared_info,
+            &mut key);
+
+        // Nonce (eciespy only) and encryption.
+        if p.scheme == Scheme::EciesPy {
+            let (hd, data) = out[..ct_len].split_at_mut(
+                eph_len + nonce_len + 16);
+            rng.fill_bytes(&mut hd[eph_len..eph_len + nonce_len]);
+            data.copy_from_slice(pt);
+            let tag = self.aead_encrypt(&key,
+                &hd[eph_len..eph_len + nonce_len], aad, data);
+            hd[eph_len + nonce_len..].copy_from_slice(&tag);
+        } else {
+            let (data, tag) = out[eph_len..ct_len].split_at_mut(pt.len());
+            data.copy_from_slice(pt);
+            tag.copy_from_slice(
+                &self.aead_encrypt(&key, &[0u8; 12], aad, data));
+        }
+        ct_len
+    }
+
+    /// Decrypts the ciphertext `ct` with the private key `sk`. The
+    /// plaintext is written into `out` (which must be large enough), and
+    /// its length is returned. `None` is returned if the ciphertext is
+    /// too short, the ephemeral public key is invalid, or the tag does
+    /// not match; in the latter case, `out` may contain garbage.
+    pub fn decrypt_to(&self, sk: &C::PrivateKey, shared_info: &[u8],
+        aad: &[u8], ct: &[u8], out: &mut [u8]) -> Option<usize>
+    {
+        let p = &self.params;
+        let eph_len = p.eph_len();
+        let nonce_len = p.nonce_len();
+        if ct.len() < p.overhead() {
+            return None;
+        }
+        let pt_len = ct.len() - p.overhead();
+        assert!(out.len() >= pt_len);
+
+        let mut shared = [0u8; 65];
+        let shared_len = self.shared_secret(sk, &ct[..eph_len],
+            &mut shared)?;
+        let mut key = [0u8; 32];
+        self.derive_key(&ct[..eph_len], &shared[..shared_len], shared_info,
+            &mut key);
+
+        let data = &mut out[..pt_len];
+        let r = match p.scheme {
+            Scheme::EciesPy => {
+                let nonce = &ct[eph_len..eph_len + nonce_len];
+                let tag = &ct[eph_len + nonce_len..eph_len + nonce_len + 16];
+                data.copy_from_slice(&ct[eph_len + nonce_len + 16..]);
+                self.aead_decrypt(&key, nonce, aad, data, tag)
+            }
+            _ => {
+                data.copy_from_slice(&ct[eph_len..ct.len() - 16]);
+                self.aead_decrypt(&key, &[0u8; 12], aad, data,
+                    &ct[ct.len() - 16..])
+            }
+        };
+        if r {
+            Some(pt_len)
+        } else {
+            None
+        }
+    }
+
+    /// Encrypts the plaintext `pt` for the recipient public key `pk`.
+    /// The ciphertext is returned in a newly allocated vector.
+    #[cfg(feature = "alloc")]
+    pub fn encrypt<T: CryptoRng + RngCore>(&self, rng: &mut T,
+        pk: &C::PublicKey, shared_info: &[u8], aad: &[u8], pt: &[u8])
+        -> Vec<u8>
+    {
+        let mut out = vec![0u8; self.ciphertext_len(pt.len())];
+        self.encrypt_to(rng, pk, shared_info, aad, pt, &mut out);
+        out
+    }
+
+    /// Decrypts the ciphertext `ct` with the private key `sk`. The
+    /// plaintext is returned in a newly allocated vector.
+    #[cfg(feature = "alloc")]
+    pub fn decrypt(&self, sk: &C::PrivateKey, shared_info: &[u8],
+        aad: &[u8], ct: &[u8]) -> Option<Vec<u8>>
+    {
+        if ct.len() < self.params.overhead() {
+            return None;
+        }
+        let mut out = vec![0u8; ct.len() - self.params.overhead()];
+        self.decrypt_to(sk, shared_info, aad, ct, &mut out)?;
+        Some(out)
+    }
+
+    // Computes the ECDH shared secret with the encoded peer public key
+    // into `shared`, and returns its length: the x-coordinate of the
+    // shared point (32 bytes), or, with eciespy, the whole uncompressed
+    // point (65 bytes), since the KDF input then includes the
+    // y-coordinate. `None` is returned if the peer public key is invalid.
+    fn shared_secret(&self, sk: &C::PrivateKey, peer_pk: &[u8],
+        shared: &mut [u8; 65]) -> Option<usize>
+    {
+        let (len, ok) = if self.params.scheme == Scheme::EciesPy {
+            let (q, ok) = C::ecdh_point(sk, peer_pk);
+            *shared = q;
+            (65, ok)
+        } else {
+            let (x, ok) = C::ecdh(sk, peer_pk);
+            shared[..32].copy_from_slice(&x);
+            (32, ok)
+        };
+        if ok != 0 {
+            Some(len)
+        } else {
+            None
+        }
+    }
+
+    // Derives the symmetric key from the encoded ephemeral public key and
+    // the shared secret (as returned by shared_secret()). The key is
+    // written in the first key_len() bytes of `key`.
+    fn derive_key(&self, eph: &[u8], shared: &[u8], shared_info: &[u8],
+        key: &mut [u8; 32])
+    {
+        let p = &self.params;
+        let mut z = [0u8; 65 + 65];
+        let z_len = match p.scheme {
+            Scheme::Sec1 => {
+                z[..32].copy_from_slice(shared);
+                32
+            }
+            Scheme::Iso18033 => {
+                z[..eph.len()].copy_from_slice(eph);
+                z[eph.len()..eph.len() + 32].copy_from_slice(shared);
+                eph.len() + 32
+            }
+            Scheme::EciesPy => {
+                // The shared point uses the same format as the ephemeral
+                // public key.
+                z[..eph.len()].copy_from_slice(eph);
+                if p.compressed {
+                    z[33] = 0x02 | (shared[64] & 0x01);
+                    z[34..66].copy_from_slice(&shared[1..33]);
+                    66
+                } else {
+                    z[65..].copy_from_slice(shared);
+                    130
+                }
+            }
+        };
+        let key = &mut key[..p.key_len()];
+        match p.kdf {
+            Kdf::X963Sha256 => x963_kdf::<Sha256>(&z[..z_len], shared_info, key),
+            Kdf::HkdfSha256 =>
+                Hkdf::<Sha256>::derive(&[], &z[..z_len], shared_info, key),
+        }
+    }
+
+    fn aead_encrypt(&self, key: &[u8; 32], nonce: &[u8], aad: &[u8],
+        data: &mut [u8]) -> [u8; 16]
+    {
+        match self.params.aead {
+            Aead::ChaCha20Poly1305 => {
+                let mut n = [0u8; 12];
+                n.copy_from_slice(nonce);
+                ChaCha20Poly1305::new(key).encrypt_in_place(&n, aad, data)
+            }
+            _ => AesGcm::new(&key[..self.params.key_len()])
+                .encrypt_in_place_iv(nonce, aad, data),
+        }
+    }
+
+    fn aead_decrypt(&self, key: &[u8; 32], nonce: &[u8], aad: &[u8],
+        data: &mut [u8], tag: &[u8]) -> bool
+    {
+        match self.params.aead {
+            Aead::ChaCha20Poly1305 => {
+                let mut n = [0u8; 12];
+                n.copy_from_slice(nonce);
+                ChaCha20Poly1305::new(key)
+                    .decrypt_in_place(&n, aad, data, tag)
+            }
+            _ => AesGcm::new(&key[..self.params.key_len()])
+                .decrypt_in_place_iv(nonce, aad, data, tag),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::kdf::HmacDrbgSha256;
+
+    type Kat = (Scheme, Kdf, Aead, bool, &'static str, &'static str,
+        &'static str, &'static str);
+
+    // Test vectors generated with OpenSSL (through the Python
+    // "cryptography" package), using its ECDH, X9.63 KDF, HKDF, AES-GCM
+    // and ChaCha20+Poly1305 implementations. For each entry: scheme, KDF,
+    // AEAD, compressed ephemeral key, shared info, AAD, plaintext,
+    // ciphertext.
+    // Recipient private key: c1a0d74af2123b189a44c294d2ea7481b69e4d0b33a5aee8ed4641b10f6a0f87
+    const KAT_P256: &[Kat] = &[
+        (Scheme::Sec1, Kdf::X963Sha256, Aead::Aes128Gcm, false,
+         "shared info 0", "aad 0",
+         "plaintext message number 0 for P256",
+         "0432a2c4886f94a9259d52373249099e29253f5eb3c64e1c026d360ca41ed4de\
+          8d5f0f4c4173d2bbdedba8be275b889e9a17b385d87f4d25b1b016df77972289\
+          1558b0794abf06eb6a543b747292a5c4e956d10c0187fede21283ad892f73b71\
+          22e1a828c3bc6df88ab583be5d3eae8773fca1f5"),
+        (Scheme::Sec1, Kdf::HkdfSha256, Aead::Aes256Gcm, true,
+         "shared info 1", "aad 1",
+         "plaintext message number 1 for P256",
+         "03624e0aaa92b94bfbfaf752deebb6da81c898f7c404ded3c877f93d53431862\
+          1304ca11b455894040eff4e8d9874e30506aa8a605578d8b289819919d965cfd\
+          a62222d0df32d6b0ec6a6acae812b1874f4a2aa5"),
+        (Scheme::Iso18033, Kdf::X963Sha256, Aead::ChaCha20Poly1305, true,
+         "shared info 2", "aad 2",
+         "plaintext message number 2 for P256",
+         "0243da29659298069f7bb7f7f5f7beefa7142e7f4b63ffdd300bef1d712d6844\
+          fe472412e43a00a1d40244622c033453be8dba2c38af181b7b49da4121b96cc3\
+          d797bac899416503d8eaed7aebc9eccaaa303531"),
+        (Scheme::Iso18033, Kdf::HkdfSha256, Aead::Aes256Gcm, false,
+         "shared info 3", "aad 3",
+         "plaintext message number 3 for P256",
+         "047220fedad5bcd7a07a6dd3ba608f879057bdf0db003fe7530ee284c9bc0fea\
+          990b0e9219613828cc55da5325e1c6f01f5a280f2b0d7046469b25fa96476a87\
+          82801c9e7b640dd2c02f2ea2b39ac86bb5dea77e94fb6566a8051d26e2b3a11f\
+          58458cdd90b5b0762b117a4fb1a1eec6d14b19d9"),
+        (Scheme::EciesPy, Kdf::HkdfSha256, Aead::Aes256Gcm, false,
+         "", "",
+         "plaintext message number 4 for P256",
+         "04a380c3e440a396529112b14e4c2206382d6234b0fb2d7491c77c3bb562d079\
+          50b7e2353bd3208fcda993e1fb444358650894e81b391325e50c865dca9b7d24\
+          a6b1cc0f7eeb651c5f15ce2de8ec424985454346972b4b02fa585df66b1c7868\
+          247f5e1494e891c21d1bf119806c4ea219a0e8a546c93d94be1613a144c0605c\
+          78418987"),
+        (Scheme::EciesPy, Kdf::HkdfSha256, Aead::Aes256Gcm, true,
+         "", "",
+         "plaintext message number 5 for P256",
+         "03ad0e13a5161b334a59f1b3abfb95ef45b17d3dd43bf17c5a3147a309749a82\
+          e7008be47f1a35eebca8d99538949278086a1aac32a414c6ac3a03ba526c6954\
+          e6cb582d2bca5ea322b33112d108da421f8e8defa60fa9eed493fc61daafe25f\
+          6f55ee44"),
+        (Scheme::EciesPy, Kdf::X963Sha256, Aead::ChaCha20Poly1305, false,
+         "", "",
+         "plaintext message number 6 for P256",
+         "0462f40d45f247d439421120a21021bf8973a120e4e7e7d83c487f0b34d60e2f\
+          81fefb50e96966ee35b40d8cdd051a776864c04cfed386f1bb415ed0eb1bb0a2\
+          baebcb3120434e49148a86cd86e1f7856669dc378e2884089975886e75bd0fa7\
+          75728739ac805a11f17b86865cbdb6c192a901c26c3a6d62c90cb2fbe7948f6b"),
+    ];
+
+    // Recipient private key: 55b1af8cdbe457facbcac4114b65d0fe78fbc1b50c8d7d2c3692d02972ce37dd
+    const KAT_SECP256K1: &[Kat] = &[
+        (Scheme::Sec1, Kdf::X963Sha256, Aead::Aes128Gcm, false,
+         "shared info 0", "aad 0",
+         "plaintext message number 0 for Secp256k1",
+         "041e6c0c0577b605e1c1bca135f049077c37e9e537ac256f005a029f83460d3b\
+          0d85e0007847ce8957f73a1fc6722e7fae82de5169606e40dd5b5cb770d9ef00\
+          583171adbddc2f00ea72c4e3d7f994f11bdbd973a65a2f73fdff25a2c595fca1\
+          880c908b02a017fd1e1104e1b002fc0839cafcb925ec441f78"),
+        (Scheme::Sec1, Kdf::HkdfSha256, Aead::Aes256Gcm, true,
+         "shared info 1", "aad 1",
+         "plaintext message number 1 for Secp256k1",
+         "035ee5b2a76d6e13cf302546d51c9ca5c1db50d794383e9b9f5cf0a8b4eea53f\
+          e5905f9113b01b14b5b96ebb4151220256a34453f437e94efcf369e9a964c95b\
+          06bd04d1b608a40b5a2ccf03e35afc7fd646ad456d3825efb8"),
+        (Scheme::Iso18033, Kdf::X963Sha256, Aead::ChaCha20Poly1305, true,
+         "shared info 2", "aad 2",
+         "plaintext message number 2 for Secp256k1",
+         "028221631cc46087d09b56e7c8eb0da4e85bbe540d365472cd4668fe796b106e\
+          0b04e411292dc856df5b0f57fd1ac1a5b2fabf571c8e025594b9937d6cd88a15\
+          c072515821b923d6e273bcab8d2a8959bb7e449173d6336106"),
+        (Scheme::Iso18033, Kdf::HkdfSha256, Aead::Aes256Gcm, false,
+         "shared info 3", "aad 3",
+         "plaintext message number 3 for Secp256k1",
+         "04e151910872a81014da518093b6771c3289f790d1f91b080385881367a0cbf2\
+          efaa843cd4dd509af115e113177dadf5b3609eaabb0d28904937b218ff7e4494\
+          56b6849c346cfac245eac3c441625a5189263633a9b722805e8d82e43fb3c31a\
+          829eca33f7327468e24480c07c9debf61b1e6ab14eee7946bc"),
+        (Scheme::EciesPy, Kdf::HkdfSha256, Aead::Aes256Gcm, false,
+         "", "",
+         "plaintext message number 4 for Secp256k1",
+         "040e9862297863ba8b4af9ceab493acf3bd3fa3f7e3c6f1fe281a37f48a35260\
+          af5301ebcdb06c764f471873524e69b051baa23f530525497a52ddac18cd7753\
+          b83b1250df2c41046764d0f81a0100fcb3f42b971ab247515c664f2171e07d83\
+          9aa63a9e3c1905a2df9019aa4388d382666a48756c3c0801df20f1c578fccfda\
+          0fb758f7498845b6fb"),
+        (Scheme::EciesPy, Kdf::HkdfSha256, Aead::Aes256Gcm, true,
+         "", "",
+         "plaintext message number 5 for Secp256k1",
+         "02d4dff4ede436d419dd035623c0bd601646b04f8e053b860c6d361a98e7be45\
+          af7625c8a78e0e076c5b48833eb86be397c213a1bffb555581cd74f7ee2c1fe0\
+          30298adae1452e5dc5152fa61536795d3dcf4a747c4db97d0a3e6aeb66117e4e\
+          a4e4b3825ad3453742"),
+        (Scheme::EciesPy, Kdf::X963Sha256, Aead::ChaCha20Poly1305, false,
+         "", "",
+         "plaintext message number 6 for Secp256k1",
+         "04d41a6f4adeb3366af85082c158d098c3b42abea2cbae25e248b768824ae254\
+          225484341f6107949eddcee9383bac37f901faec5bdf5f9a2c85961227389069\
+          e25e1dca97bc29f9bec2a6838fb0422a29530ad7371f96c52e7e87007e947bc4\
+          a87d43f564099be405afe64a98f219c20d431d261143fe9a2eb3e99705b9918c\
+          0b2baef9d3"),
+    ];
+
+    fn check_kat<C: Curve>(skb: &str,
+        kat: &[Kat],
+        decode_sk: fn(&[u8]) -> Option<C::PrivateKey>)
+    {
+        let sk = decode_sk(&hex::decode(skb).unwrap()).unwrap();
+        for &(scheme, kdf, aead, compressed, si, aad, pt, ct) in kat {
+            let e = Ecies::<C>::new(Params { scheme, kdf, aead, compressed });
+            let mut ct = hex::decode(ct).unwrap();
+            assert!(e.ciphertext_len(pt.len()) == ct.len());
+            let mut out = [0u8; 64];
+            let n = e.decrypt_to(&sk, si.as_bytes(), aad.as_bytes(),
+                &ct, &mut out).unwrap();
+            assert!(&out[..n] == pt.as_bytes());
+
+            // Wrong shared info, wrong AAD, or modified ciphertext.
+            assert!(e.decrypt_to(&sk, b"", aad.as_bytes(), &ct, &mut out)
+                .is_none() || si.is_empty());
+            assert!(e.decrypt_to(&sk, si.as_bytes(), b"x", &ct, &mut out)
+                .is_none());
+            for i in [1, ct.len() - 17, ct.len() - 1] {
+                ct[i] ^= 0x01;
+                assert!(e.decrypt_to(&sk, si.as_bytes(), aad.as_bytes(),
+                    &ct, &mut out).is_none());
+                ct[i] ^= 0x01;
+            }
+        }
+    }
+
+    #[test]
+    fn kat_p256() {
+        check_kat::<P256>(
+            "c1a0d74af2123b189a44c294d2ea7481b69e4d0b33a5aee8ed4641b10f6a0f87",
+            KAT_P256, crate::p256::PrivateKey::decode);
+    }
+
+    #[test]
+    fn kat_secp256k1() {
+        check_kat::<Secp256k1>(
+            "55b1af8cdbe457facbcac4114b65d0fe78fbc1b50c8d7d2c3692d02972ce37dd",
+            KAT_SECP256K1, crate::secp256k1::PrivateKey::decode);
+    }
+
+    // Known ciphertext from the eciesrs test suite (eciespy default
+    // configuration, secp256k1), and the shared secrets derived by the
+    // eciesrs encapsulate() function with private key 2 and peer public
+    // key 3*G, with the uncompressed and compressed formats (eciespy
+    // with is_ephemeral_key_compressed and is_hkdf_key_compressed both
+    // false, or both true).
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn eciespy_known() {
+        use crate::secp256k1::PrivateKey;
+
+        let sk = PrivateKey::decode(&hex::decode("e520872701d9ec44dbac2eab85512ad14ad0c42e01de56d7b528abd8524fcb47").unwrap()).unwrap();
+        let ct = hex::decode("047be1885aeb48d4d4db0c992996725d3264784fef88c5b60782f8d0f940c213227fc3f904f846d5ec3d0fba6653754501e8ebadc421aa3892a20fef33cff0206047058a4cfb4efbeae96b2d019b4ab2edce33328748a0d008a69c8f5816b72d45bd9b5a41bb6ea0127ab23057ec6fcd").unwrap();
+        let e = Ecies::<Secp256k1>::new(Params::ECIESPY);
+        assert!(e.decrypt(&sk, b"", b"", &ct).unwrap()
+            == "hello world\u{1F30D}".as_bytes());
+
+        let mut b2 = [0u8; 32];
+        b2[31] = 2;
+        let mut b3 = [0u8; 32];
+        b3[31] = 3;
+        let sk2 = PrivateKey::decode(&b2).unwrap();
+        let pk3 = PrivateKey::decode(&b3).unwrap().to_public_key();
+        let (shared, _) = Secp256k1::ecdh_point(&sk2,
+            &pk3.encode_uncompressed());
+        let kat = [
+            (false, "6f982d63e8590c9d9b5b4c1959ff80315d772edd8f60287c9361d548d5200f82"),
+            (true, "b192b226edb3f02da11ef9c6ce4afe1c7e40be304e05ae3b988f4834b1cb6c69"),
+        ];
+        for &(compressed, kv) in kat.iter() {
+            let e = Ecies::<Secp256k1>::new(
+                Params { compressed, ..Params::ECIESPY });
+            let mut eph = [0u8; 65];
+            let n = Secp256k1::encode_public_key(&sk2.to_public_key(),
+                compressed, &mut eph);
+            let mut key = [0u8; 32];
+            e.derive_key(&eph[..n], &shared, b"", &mut key);
+            assert!(key[..] == hex::decode(kv).unwrap()[..]);
+        }
+    }
+
+    type KemKat = (&'static str, &'static str, &'static str, &'static str);
+
+    // ISO 18033-2 ECIES-KEM outputs, computed with Botan 3.13.0
+    // (ECIES_KA_Operation), with KDF2 over SHA-256 (i.e. the X9.63 KDF
+    // with no shared info) and a 32-byte output. For each entry:
+    // ephemeral private key r, encoded ephemeral public key C0, secret
+    // with SingleHashMode = 0 (KDF input is C0 || PEH, as in
+    // `Scheme::Iso18033`), secret with SingleHashMode = 1 (KDF input is
+    // PEH, as in `Scheme::Sec1`). The recipient private keys are those of
+    // the KAT_P256 and KAT_SECP256K1 tests.
+    const KEM_P256: &[KemKat] = &[
+        ("0f9e2a6cd1b7386540a1fd52b8e0c4739d6a5b21e87f4c0d3a96b5e2178cf43b",
+         "04eef21f4521ae56f1b96ed6e0c7ec8b555be40cd356c2aaac69294e5e41621b\
+          5350dbbee2c3660da87531032e47ff6f81f0044ead0aef895086d1ee60b32ede\
+          12",
+         "c313a2ca6c2c405732c22aa26df637df7aede4e4ea5eb1f5dd74bab92c9295dd",
+         "eda26b8a96e95315092a55cbc5ff847fe07903160f1da31393a735a246b3a611"),
+        ("0f9e2a6cd1b7386540a1fd52b8e0c4739d6a5b21e87f4c0d3a96b5e2178cf43b",
+         "02eef21f4521ae56f1b96ed6e0c7ec8b555be40cd356c2aaac69294e5e41621b\
+          53",
+         "8a62f0973f7194458c0e8f066ad2b84ef615b46ca3fa39661ecb6528382659a3",
+         "eda26b8a96e95315092a55cbc5ff847fe07903160f1da31393a735a246b3a611"),
+    ];
+
+    const KEM_SECP256K1: &[KemKat] = &[
+        ("6b3f0d92a4e87c15d2a90b6e3c571f48e09d2b6a8c4f31e7d5a20c9b86e4f713",
+         "04c2261baa521c14ec6e59915a25efedb340adfef3e333dc1bd027facd9a5626\
+          80ccddb872487cc76475c72e9031790f70c90fc6833cb4071ffabe82cada27ed\
+          09",
+         "200ca1cb7fc71c15269a91473adf9362ee7c42d7b3209005eb67bef4b4e426f5",
+         "c45a7ee98c71e1036acc095417e2ba1c7db4ac25033f327d516cc8573f34f341"),
+        ("6b3f0d92a4e87c15d2a90b6e3c571f48e09d2b6a8c4f31e7d5a20c9b86e4f713",
+         "03c2261baa521c14ec6e59915a25efedb340adfef3e333dc1bd027facd9a5626\
+          80",
+         "cc26da1e590806551f688982ca43052c71f91f446981ea06fb8a48c8d52f45b4",
+         "c45a7ee98c71e1036acc095417e2ba1c7db4ac25033f327d516cc8573f34f341"),
+    ];
+
+    fn check_kem<C: Curve>(skb: &str,
+        kat: &[KemKat],
+        decode_sk: fn(&[u8]) -> Option<C::PrivateKey>)
+    {
+        let sk = decode_sk(&hex::decode(skb).unwrap()).unwrap();
+        for &(rb, c0, k_iso, k_sec1) in kat {
+            let r = decode_sk(&hex::decode(rb).unwrap()).unwrap();
+            let c0 = hex::decode(c0).unwrap();
+            let compressed = c0.len() == 33;
+            let mut eph = [0u8; 65];
+            let n = C::encode_public_key(&C::to_public_key(&r), compressed,
+                &mut eph);
+            assert!(eph[..n] == c0[..]);
+            let (shared, ok) = C::ecdh(&sk, &c0);
+            assert!(ok == 0xFFFFFFFF);
+            let mut pkb = [0u8; 65];
+            C::encode_public_key(&C::to_public_key(&sk), false, &mut pkb);
+            assert!(C::ecdh(&r, &pkb).0 == shared);
+            for &(scheme, kv) in
+                [(Scheme::Iso18033, k_iso), (Scheme::Sec1, k_sec1)].iter()
+            {
+                let e = Ecies::<C>::new(Params { scheme,
+                    kdf: Kdf::X963Sha256, aead: Aead::Aes256Gcm, compressed });
+                let mut key = [0u8; 32];
+                e.derive_key(&c0, &shared, b"", &mut key);
+                assert!(key[..] == hex::decode(kv).unwrap()[..]);
+            }
+        }
+    }
+
+    #[test]
+    fn kem_p256() {
+        check_kem::<P256>(
+            "c1a0d74af2123b189a44c294d2ea7481b69e4d0b33a5aee8ed4641b10f6a0f87",
+            KEM_P256, crate::p256::PrivateKey::decode);
+    }
+
+    #[test]
+    fn kem_secp256k1() {
+        check_kem::<Secp256k1>(
+            "55b1af8cdbe457facbcac4114b65d0fe78fbc1b50c8d7d2c3692d02972ce37dd",
+            KEM_SECP256K1, crate::secp256k1::PrivateKey::decode);
+    }
+
+    fn roundtrip<C: Curve>() {
+        let mut rng = HmacDrbgSha256::new(b"ecies roundtrip", b"", b"");
+        let schemes = [ Scheme::Sec1, Scheme::Iso18033, Scheme::EciesPy ];
+        let kdfs = [ Kdf::X963Sha256, Kdf::HkdfSha256 ];
+        let aeads = [ Aead::Aes128Gcm, Aead::Aes256Gcm,
+            Aead::ChaCha20Poly1305 ];
+        let sk = C::generate_private_key(&mut rng);
+        let pk = C::to_public_key(&sk);
+        let sk2 = C::generate_private_key(&mut rng);
+        for &scheme in schemes.iter() {
+            for &kdf in kdfs.iter() {
+                for &aead in aeads.iter() {
+                    for &compressed in [false, true].iter() {
+                        let e = Ecies::<C>::new(
+                            Params { scheme, kdf, aead, compressed });
+                        let mut pt = [0u8; 37];
+                        rng.fill_bytes(&mut pt);
+                        let mut ct = [0u8; 37 + 65 + 16 + 16];
+                        let ct_len = e.encrypt_to(&mut rng, &pk,
+                            b"info", b"aad", &pt, &mut ct);
+                        assert!(ct_len == e.ciphertext_len(pt.len()));
+                        let ct = &mut ct[..ct_len];
+                        let mut out = [0u8; 37];
+                        assert!(e.decrypt_to(&sk, b"info", b"aad", ct,
+                            &mut out) == Some(37));
+                        assert!(out == pt);
+                        assert!(e.decrypt_to(&sk2, b"info", b"aad", ct,
+                            &mut out).is_none());
+                        assert!(e.decrypt_to(&sk, b"info", b"aad",
+                            &ct[..e.params().overhead() - 1], &mut out)
+                            .is_none());
+
+                        // An invalid ephemeral public key is rejected.
+                        ct[1] ^= 0x01;
+                        if C::decode_public_key(
+                            &ct[..e.params().eph_len()]).is_none()
+                        {
+                            assert!(e.decrypt_to(&sk, b"info", b"aad", ct,
+                                &mut out).is_none());
+                        }
+                        ct[1] ^= 0x01;
+
+                        // The neutral point is rejected.
+                        let mut ct2 = [0u8; 37 + 65 + 16 + 16];
+                        ct2[..ct_len].copy_from_slice(ct);
+                        for x in ct2[..e.params().eph_len()].iter_mut() {
+                            *x = 0;
+                        }
+                        assert!(e.decrypt_to(&sk, b"info", b"aad",
+                            &ct2[..ct_len], &mut out).is_none());
+                    }
+                }
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        {
+            let e = Ecies::<C>::new(Params::ECIESPY);
+            let ct = e.encrypt(&mut rng, &pk, b"", b"", b"hello ECIES");
+            assert!(e.decrypt(&sk, b"", b"", &ct).unwrap() == b"hello ECIES");
+            assert!(e.decrypt(&sk, b"", b"", &ct[..10]).is_none());
+        }
+    }
+
+    #[test]
+    fn roundtrip_p256() {
+        roundtrip::<P256>();
+    }
+
+    #[test]
+    fn roundtrip_secp256k1() {
+        roundtrip::<Secp256k1>();
+    }
+}
@@ -48,6 +48,14 @@
 //! available on `PublicKey`, again for Ed25519, Ed25519ctx and
 //! Ed25519ph.
 //!
+//! Adaptor signatures (in "Ed25519" mode) are computed with
+//! `PrivateKey::presign_raw()`, relatively to an adaptor point `T`, and
+//! verified with `PublicKey::verify_presig_raw()`. The resulting
+//! `PreSignature` is converted into a normal signature with the discrete
+//! logarithm `t` of `T` (`PreSignature::adapt()`); conversely, `t` can be
+//! extracted from the pre-signature and the signature
+//! (`PreSignature::extract()`).
+//!
 //! # Ed25519 Edge Cases
 //!
 //! It is known that there is a great amount of variation about how
@@ -2288,6 +2296,145 @@ impl PublicKey {
     }
 }
 
+/// An Ed25519 pre-signature (adaptor signature).
+///
+/// A pre-signature is computed relatively to an adaptor point `T`; it
+/// can be verified with the public key and `T`, and it is converted
+/// into a valid Ed25519 signature with the discrete logarithm `t` of
+/// `T` (see `PrivateKey::presign_raw()`).
+#[derive(Clone, Copy, Debug)]
+pub struct PreSignature {
+    R: Point,
+    R_enc: [u8; 32],
+    s: Scalar,
+}
+
+impl PrivateKey {
+
+    /// Computes a pre-signature on a message, relatively to the adaptor
+    /// point `T`.
+    ///
+    /// This is the "Ed25519" mode of RFC 8032 (no pre-hashing, no
+    /// context), with the point R replaced with `r*B + T`. Adapting
+    /// the pre-signature with the discrete logarithm `t` of `T` (i.e.
+    /// `T = t*B`) yields a signature that `PublicKey::verify_raw()`
+    /// accepts. The per-signature secret `r` is derived
+    /// deterministically from the private key, the message and `T`,
+    /// with a domain separation prefix which makes it distinct from the
+    /// one used in plain signatures.
+    pub fn presign_raw(self, m: &[u8], T: &Point) -> PreSignature {
+        // SHA-512(dom2(0xFF, "") || T || prefix || M) -> scalar r
+        // phflag = 0xFF is not used by any RFC 8032 variant.
+        let mut sh = Sha512::new();
+        sh.update(HASH_HEAD);
+        sh.update([0xFFu8, 0x00u8]);
+        sh.update(T.encode());
+        sh.update(self.h);
+        sh.update(m);
+        let hv1 = sh.finalize_reset();
+        let r = Scalar::decode_reduce(&hv1);
+
+        // R = r*B + T
+        let R = Point::mulgen(&r) + T;
+        let R_enc = R.encode();
+
+        // SHA-512(R || A || M) -> scalar k
+        sh.update(R_enc);
+        sh.update(self.public_key.encoded);
+        sh.update(m);
+        let hv2 = sh.finalize();
+        let k = Scalar::decode_reduce(&hv2);
+
+        PreSignature { R, R_enc, s: r + k * self.s }
+    }
+}
+
+impl PublicKey {
+
+    /// Verifies a pre-signature on a message, relatively to the adaptor
+    /// point `T`.
+    ///
+    /// If this function returns `true`, then adapting the pre-signature
+    /// with the discrete logarithm of `T` yields a signature which is
+    /// valid for this public key and the message (in "Ed25519" mode).
+    ///
+    /// Note: this function is not constant-time; it assumes that the
+    /// public key, pre-signature and adaptor point are public data.
+    pub fn verify_presig_raw(self, presig: &PreSignature, m: &[u8],
+                             T: &Point) -> bool
+    {
+        // SHA-512(R || A || M) -> scalar k
+        let mut sh = Sha512::new();
+        sh.update(presig.R_enc);
+        sh.update(self.encoded);
+        sh.update(m);
+        let hv2 = sh.finalize();
+        let k = Scalar::decode_reduce(&hv2);
+
+        // Check the verification equation 8*S'*B = 8*(R - T) + 8*k*A.
+        self.point.verify_helper_vartime(&(presig.R - T), &presig.s, &k)
+    }
+}
+
+impl PreSignature {
+
+    /// Adapts this pre-signature into an Ed25519 signature, using the
+    /// discrete logarithm `t` of the adaptor point.
+    pub fn adapt(self, t: &Scalar) -> [u8; 64] {
+        let mut sig = [0u8; 64];
+        sig[0..32].copy_from_slice(&self.R_enc);
+        sig[32..64].copy_from_slice(&(self.s + t).encode());
+        sig
+    }
+
+    /// Extracts the discrete logarithm of the adaptor point from this
+    /// pre-signature and the corresponding signature.
+    ///
+    /// `None` is returned if the signature does not match this
+    /// pre-signature. If the pre-signature was verified, and the
+    /// signature is valid, then the returned scalar is the discrete
+    /// logarithm of the adaptor point.
+    pub fn extract(self, sig: &[u8]) -> Option<Scalar> {
+        if sig.len() != 64 || sig[0..32] != self.R_enc {
+            return None;
+        }
+        let (s, ok) = Scalar::decode32(&sig[32..64]);
+        if ok == 0 {
+            return None;
+        }
+        Some(s - self.s)
+    }
+
+    /// Encodes this pre-signature into exactly 64 bytes.
+    ///
+    /// The format is the same as that of a signature: the encoded point
+    /// R, followed by the encoded scalar.
+    pub fn encode(self) -> [u8; 64] {
+        let mut buf = [0u8; 64];
+        buf[0..32].copy_from_slice(&self.R_enc);
+        buf[32..64].copy_from_slice(&self.s.encode());
+        buf
+    }
+
+    /// Decodes a pre-signature from bytes.
+    ///
+    /// `None` is returned if the source does not have length exactly
+    /// 64 bytes, or if either half is not canonically encoded.
+    pub fn decode(buf: &[u8]) -> Option<Self> {
+        if buf.len() != 64 {
+            return None;
+        }
+        let R = Point::decode(&buf[0..32])?;
+        let (s, ok) = Scalar::decode32(&buf[32..64]);
+        if ok == 0 {
+            return None;
+        }
+        let mut R_enc = [0u8; 32];
+        R_enc[..].copy_from_slice(&buf[0..32]);
+        Some(Self { R, R_enc, s })
+    }
+}
+
 // ========================================================================
 
 // We hardcode known multiples of the points B, (2^65)*B, (2^130)*B
@@ -8261,7 +8408,7 @@ static UX_COMP: [u64; 16385] = [
 #[cfg(test)]
 mod tests {
 
-    use super::{Point, Scalar, PrivateKey, PublicKey};
+    use super::{Point, Scalar, PrivateKey, PublicKey, PreSignature};
     use sha2::{Sha256, Sha512, Digest};
 
     /* unused
@@ -8703,6 +8850,36 @@ mod tests {
         }
     }
 
+    #[test]
+    fn adaptor() {
+        let mut sh = Sha512::new();
+        for i in 0..20 {
+            let mut seed = [0u8; 32];
+            seed[0] = i as u8;
+            let skey = PrivateKey::from_seed(&seed);
+            let pkey = skey.public_key;
+            sh.update(seed);
+            let t = Scalar::decode_reduce(&sh.finalize_reset());
+            let T = Point::mulgen(&t);
+            let msg = &[i as u8; 20][..i];
+
+            let presig = skey.presign_raw(msg, &T);
+            assert!(pkey.verify_presig_raw(&presig, msg, &T));
+            assert!(!pkey.verify_presig_raw(&presig, &[0xFFu8], &T));
+            assert!(!pkey.verify_presig_raw(&presig, msg, &T.double()));
+            let enc = presig.encode();
+            assert!(!pkey.verify_raw(&enc, msg));
+            let presig = PreSignature::decode(&enc).unwrap();
+            assert!(presig.encode() == enc);
+
+            let sig = presig.adapt(&t);
+            assert!(pkey.verify_raw(&sig, msg));
+            assert!(!pkey.verify_raw(&presig.adapt(&(t + Scalar::ONE)), msg));
+            assert!(presig.extract(&sig).unwrap().equals(t) != 0);
+            assert!(presig.extract(&skey.sign_raw(msg)).is_none());
+        }
+    }
+
     #[test]
     fn in_subgroup() {
         let T8_enc: [u8; 32] = [
@@ -72,6 +72,26 @@
 //!   accepted. The full 57 bytes are used: the last byte, and the top
 //!   two bits of the second to last byte, though always of value 0, are
 //!   checked.
+//!
+//! # Truncated Signatures
+//!
+//! The `PublicKey::verify_trunc_*()` functions support _truncated
+//! signatures_: a 114-byte signature is provided, but the last few bits
+//! (between 8 and 32) are considered to have been reused for encoding
+//! other data, and thus are ignored. The verification function then
+//! tries to recompute the complete, original, untruncated signature.
+//! This process is safe since neither truncation nor reconstruction
+//! involve usage of the private key, and the original signature is
+//! obtained as an outcome of the process.
+//!
+//! Since the top 10 bits of a signature are always zero, only `rm - 10`
+//! bits actually need to be rebuilt; this is done with a
+//! baby-step/giant-step search, with about 2^((rm-10)/2) point
+//! additions, using a table that fits on the stack (no dynamic memory
+//! allocation is needed). When no more than 10 bits are ignored, the
+//! cost is that of a plain verification; when 32 bits are ignored, the
+//! verification cost is about 15 times the cost of verifying an
+//! untruncated signature.
 
 // Projective/fractional coordinates traditionally use uppercase letters,
 // using lowercase only for affine coordinates.
@@ -1715,6 +1735,303 @@ impl PublicKey {
         // Check the verification equation 4*S*B = 4*R + 4*k*A.
         self.point.verify_helper_vartime(&R, &S, &k)
     }
+
+    /// Verifies a truncated signature on a message.
+    ///
+    /// This is the "Ed448" mode of RFC 8032 (no pre-hashing, no
+    /// context). This is equivalent to `verify_trunc_ctx()` with an
+    /// empty (zero-length) context. The signature slice (`sig`) MUST
+    /// have length exactly 114 bytes; however, this function assumes
+    /// that the last `rm` bits of the signature have been reused to
+    /// store other data, and thus it ignores these bits. `rm` MUST be
+    /// in the 8 to 32 range (inclusive); ignored elements are the last
+    /// `floor(rm/8)` bytes, and the top (most significant) `rm%8` bits
+    /// of the last non-ignored byte.
+    ///
+    /// If the original, untruncated signature was valid, then this
+    /// function rebuilds it and returns it; otherwise, it returns `None`
+    /// (if a rebuilt signature value is returned, then it has been
+    /// verified to be valid and there is no need to validate it again).
+    ///
+    /// Note: this function is not constant-time; it assumes that the
+    /// public key and signature value are public data.
+    pub fn verify_trunc_raw(self,
+        sig: &[u8], rm: usize, m: &[u8]) -> Option<[u8; 114]>
+    {
+        self.verify_trunc_inner(sig, rm, 0, &[0u8; 0], m)
+    }
+
+    /// Verifies a truncated signature on a message (with context).
+    ///
+    /// This is the "Ed448" mode of RFC 8032 (no pre-hashing, a
+    /// context is provided). The context string MUST have length at most
+    /// 255 bytes. The signature slice (`sig`) MUST have length exactly
+    /// 114 bytes; however, this function assumes that the last `rm` bits
+    /// of the signature have been reused to store other data, and thus
+    /// it ignores these bits. `rm` MUST be in the 8 to 32 range
+    /// (inclusive); ignored elements are the last `floor(rm/8)` bytes,
+    /// and the top (most significant) `rm%8` bits of the last non-ignored
+    /// byte.
+    ///
+    /// If the original, untruncated signature was valid, then this
+    /// function rebuilds it and returns it; otherwise, it returns `None`
+    /// (if a rebuilt signature value is returned, then it has been
+    /// verified to be valid and there is no need to validate it again).
+    ///
+    /// Note: this function is not constant-time; it assumes that the
+    /// public key and signature value are public data.
+    pub fn verify_trunc_ctx(self,
+        sig: &[u8], rm: usize, ctx: &[u8], m: &[u8]) -> Option<[u8; 114]>
+    {
+        self.verify_trunc_inner(sig, rm, 0, ctx, m)
+    }
+
+    /// Verifies a truncated signature on a hashed message.
+    ///
+    /// This is the "Ed448ph" mode of RFC 8032 (message is pre-hashed),
+    /// also known as "HashEdDSA on Curve448". The hashed message `hm`
+    /// is provided (presumably, that hash value was obtained with
+    /// SHAKE256 and a 64-byte output; the caller does the hashing itself).
+    /// A context string is also provided; it MUST have length at most
+    /// 255 bytes. The signature slice (`sig`) MUST have length exactly
+    /// 114 bytes; however, this function assumes that the last `rm` bits
+    /// of the signature have been reused to store other data, and thus
+    /// it ignores these bits. `rm` MUST be in the 8 to 32 range
+    /// (inclusive); ignored elements are the last `floor(rm/8)` bytes,
+    /// and the top (most significant) `rm%8` bits of the last non-ignored
+    /// byte.
+    ///
+    /// If the original, untruncated signature was valid, then this
+    /// function rebuilds it and returns it; otherwise, it returns `None`
+    /// (if a rebuilt signature value is returned, then it has been
+    /// verified to be valid and there is no need to validate it again).
+    ///
+    /// Note: this function is not constant-time; it assumes that the
+    /// public key and signature value are public data.
+    pub fn verify_trunc_ph(self,
+        sig: &[u8], rm: usize, ctx: &[u8], hm: &[u8]) -> Option<[u8; 114]>
+    {
+        self.verify_trunc_inner(sig, rm, 1, ctx, hm)
+    }
+
+    /// Inner truncated signature verification function.
+    fn verify_trunc_inner(self, sig: &[u8], rm: usize,
+        phflag: u8, ctx: &[u8], msg: &[u8]) -> Option<[u8; 114]>
+    {
+        // Code is meant for removing between 8 and 32 bits.
+        assert!((8..=32).contains(&rm));
+
+        // Signature array must have length 114 bytes exactly; but we
+        // ignore the last rm bits. We copy the non-ignored bits to sig2,
+        // and clear the rest.
+        if sig.len() != 114 {
+            return None;
+        }
+        let n = (919 - rm) >> 3;
+        let mut sig2 = [0u8; 114];
+        sig2[0..n].copy_from_slice(&sig[0..n]);
+        if (rm & 7) != 0 {
+            sig2[n - 1] &= 0xFFu8 >> (rm & 7);
+        }
+
+        // In a valid signature, the top 10 bits (the last byte, and the
+        // top two bits of the second to last byte) are always zero. If
+        // no more than these bits were removed, then there is nothing
+        // to search.
+        if rm <= 10 {
+            if self.verify_inner(&sig2, phflag, ctx, msg) {
+                return Some(sig2);
+            } else {
+                return None;
+            }
+        }
+
+        // First half of the signature is the encoded point R; second
+        // half is the scalar S (here truncated, we decode it as s0).
+        // R must decode successfully (the decoding function enforces
+        // canonicality). Scalar S was truncated by clearing the high
+        // bits so it will always decode successfully.
+        let R_enc = &sig2[0..57];
+        let R = Point::decode(R_enc)?;
+        let (s0, _) = Scalar::decode_ct(&sig2[57..113]);
+
+        // SHAKE256(dom4(F, C) || R || A || PH(M), 114) -> scalar k
+        let mut sh = Shake256::default();
+        assert!(ctx.len() <= 255);
+        let clen = ctx.len() as u8;
+        sh.update(&HASH_HEAD);
+        sh.update(&[phflag]);
+        sh.update(&[clen]);
+        sh.update(ctx);
+        sh.update(R_enc);
+        sh.update(&self.encoded);
+        sh.update(msg);
+        let mut hv2 = [0u8; 114];
+        sh.finalize_xof_reset().read(&mut hv2);
+        let k = Scalar::decode_reduce(&hv2);
+
+        // The verification equation is 4*S*B = 4*R + 4*k*A, but we
+        // do not have the complete S, only a truncated version s0. Since
+        // S < L < 2^446, we can write:
+        //   n = 456 - rm      (number of bits of S that we received)
+        //   m = 446 - n = rm - 10
+        //   S = s0 + 2^445 + t*2^n
+        // with -2^(m-1) <= t < +2^(m-1).
+        // We rewrite the equation as:
+        //    t*(4*2^n*B) = 4*R + 4*k*A - 4*(s0 + 2^445)*B
+        // i.e. an equation:
+        //    t*U = V
+        // for two known points U and V.
+        //
+        // All points P on the curve have the same y coordinate as -P;
+        // thus, we can get two tests for the cost of one. We write:
+        //    t = a + I*b
+        // with I = 2^nI, J = 2^nJ, nI + nJ = m - 1, 0 <= a < I and
+        // -J <= b < +J. Then:
+        //
+        //   1. We compute all points U_j = j*I*U for 0 <= j <= J.
+        //   2. We extract the y coordinates of the U_j and sort them
+        //      appropriately to allow easy searches.
+        //   3. For 0 <= i < +I, we compute V_i = V - i*U.
+        //   4. We lookup the y coordinate of V_i in the values extracted
+        //      at step 2. If V_i has the same y coordinate as U_j,
+        //      then t = i + I*j and t = i - I*j are possible solutions.
+        //
+        // Since (a + I*b)*U = V, we have:
+        //    b*I*U = V - a*U
+        // hence, V_a = V - a*U has the same y coordinate as U_{|b|}.
+        // We only keep the low 48 bits of each y coordinate in the
+        // sorted table (this is enough to make matches, provided that
+        // we verify them). Since m <= 22, we have J <= 2^11, and the
+        // table is small enough to be allocated on the stack.
+        let n = 456 - rm;
+        let m = rm - 10;
+        let nJ = m >> 1;
+        let nI = m - 1 - nJ;
+        let I = 1usize << nI;
+        let J = 1usize << nJ;
+
+        // U = 4*(2^n)*B
+        let U = Point::BASE.xdouble((n + 2) as u32);
+
+        // V = 4*(R + k*A - (s0 + 2^445)*B)
+        let mut tmp = [0u8; 56];
+        tmp[55] = 0x20;
+        let c = Scalar::decode_reduce(&tmp);
+        let V = (R + self.point.mul_add_mulgen_vartime(&k, &-(s0 + c)))
+            .xdouble(2);
+
+        // Compute the U_j and build the sorted table of y coordinates.
+        // We process points by batches of 100 so that they can be
+        // normalized with a single inversion.
+        let IU = U.xdouble(nI as u32);
+        let mut uy = [0u64; 2049];
+        let mut Uj = Point::NEUTRAL;
+        let mut j = 0;
+        while j <= J {
+            let blen = if (J + 1 - j) < 100 { J + 1 - j } else { 100 };
+            let mut Yj = [GF448::ZERO; 100];
+            let mut Zj = [GF448::ZERO; 100];
+            for j0 in 0..blen {
+                Yj[j0] = Uj.Y;
+                Zj[j0] = Uj.Z;
+                Uj += IU;
+            }
+            GF448::batch_invert(&mut Zj[..blen]);
+            for j0 in 0..blen {
+                let mut tmp = [0u8; 8];
+                tmp[0..2].copy_from_slice(&((j + j0) as u16).to_le_bytes());
+                tmp[2..8].copy_from_slice(&(Yj[j0] * Zj[j0]).encode()[0..6]);
+                uy[j + j0] = u64::from_le_bytes(tmp);
+            }
+            j += blen;
+        }
+        let uy = &mut uy[..(J + 1)];
+        uy.sort_unstable();
+
+        // Compute the V_i = V - i*U (by batches) and look them up.
+        let mut Vi = V;
+        let mut ii = 0;
+        while ii < I {
+            let blen = if (I - ii) < 100 { I - ii } else { 100 };
+            let mut Yi = [GF448::ZERO; 100];
+            let mut Zi = [GF448::ZERO; 100];
+            for i0 in 0..blen {
+                Yi[i0] = Vi.Y;
+                Zi[i0] = Vi.Z;
+                Vi -= U;
+            }
+            GF448::batch_invert(&mut Zi[..blen]);
+
+            for i0 in 0..blen {
+                // Extract the low 48 bits of the y coordinate of the
+                // point. We make a searchable value with the 16-bit index
+                // set to 0xFFFF.
+                let mut tmp = [0u8; 8];
+                tmp[0] = 0xFF;
+                tmp[1] = 0xFF;
+                tmp[2..8].copy_from_slice(&(Yi[i0] * Zi[i0]).encode()[0..6]);
+                let y = u64::from_le_bytes(tmp);
+
+                // Look for y in the array: we look for the largest value
+                // which is lower than the search key; potential matches
+                // are all the values that have the same high 48 bits.
+                if y < uy[0] {
+                    continue;
+                }
+                let mut j1 = 0;
+                let mut j2 = uy.len();
+                while (j2 - j1) > 1 {
+                    let jm = (j1 + j2) >> 1;
+                    if y < uy[jm] {
+                        j2 = jm;
+                    } else {
+                        j1 = jm;
+                    }
+                }
+                loop {
+                    let ym = uy[j1];
+                    if ((ym ^ y) >> 16) != 0 {
+                        break;
+                    }
+
+                    // Possible solutions are t = i + I*j and t = i - I*j.
+                    // We rebuild the corresponding S values and verify
+                    // the complete signatures.
+                    let i = (ii + i0) as i64;
+                    let j = (ym & 0xFFFF) as i64;
+                    for t in [i + (I as i64) * j, i - (I as i64) * j] {
+                        // s1 = t + 2^(m-1) must be in the 0 to 2^m-1 range.
+                        let s1 = t + (1i64 << (m - 1));
+                        if s1 < 0 || s1 >= (1i64 << m) {
+                            continue;
+                        }
+                        let mut sig3 = sig2;
+                        let mut v = (s1 as u64) << (n & 7);
+                        let mut d = 57 + (n >> 3);
+                        while v != 0 {
+                            sig3[d] |= v as u8;
+                            v >>= 8;
+                            d += 1;
+                        }
+                        if self.verify_inner(&sig3, phflag, ctx, msg) {
+                            return Some(sig3);
+                        }
+                    }
+
+                    if j1 == 0 {
+                        break;
+                    }
+                    j1 -= 1;
+                }
+            }
+
+            ii += blen;
+        }
+
+        // No match; signature is not valid.
+        None
+    }
 }
 
 // ========================================================================
@@ -3067,6 +3384,63 @@ mod tests {
         }
     }
 
+    #[test]
+    fn signatures_trunc() {
+        let skey = PrivateKey::from_seed(&[0u8; 57]);
+        let pkey = skey.public_key;
+        for i in 0..3 {
+            let mut msg = [0u8; 8];
+            msg[..].copy_from_slice(&(i as u64).to_le_bytes());
+            let ctx = [0x42u8; 3];
+            let mut hm = [0u8; 64];
+            hm[..8].copy_from_slice(&msg);
+            let sig = match i {
+                0 => skey.sign_raw(&msg),
+                1 => skey.sign_ctx(&ctx, &msg),
+                _ => skey.sign_ph(&ctx, &hm),
+            };
+            for rm in 8..33 {
+                // Ignored bits are set to 1 (even rm) or to the bits of
+                // the R part of the signature (odd rm); they must not
+                // matter, and the original signature must be rebuilt.
+                let mut sig1 = sig;
+                for j in (912 - rm)..912 {
+                    let b = if (rm & 1) == 0 {
+                        1
+                    } else {
+                        sig[(j >> 3) & 31] >> (j & 7)
+                    };
+                    sig1[j >> 3] = (sig1[j >> 3] & !(1u8 << (j & 7)))
+                        | ((b & 1) << (j & 7));
+                }
+                assert!(sig1 != sig);
+                let vv = match i {
+                    0 => pkey.verify_trunc_raw(&sig1, rm, &msg),
+                    1 => pkey.verify_trunc_ctx(&sig1, rm, &ctx, &msg),
+                    _ => pkey.verify_trunc_ph(&sig1, rm, &ctx, &hm),
+                };
+                assert!(vv.is_some());
+                let sig2 = vv.unwrap();
+                assert!(sig2 == sig);
+                assert!(match i {
+                    0 => pkey.verify_raw(&sig2, &msg),
+                    1 => pkey.verify_ctx(&sig2, &ctx, &msg),
+                    _ => pkey.verify_ph(&sig2, &ctx, &hm),
+                });
+                msg[0] ^= 1;
+                hm[0] ^= 1;
+                let vv = match i {
+                    0 => pkey.verify_trunc_raw(&sig1, rm, &msg),
+                    1 => pkey.verify_trunc_ctx(&sig1, rm, &ctx, &msg),
+                    _ => pkey.verify_trunc_ph(&sig1, rm, &ctx, &hm),
+                };
+                assert!(vv.is_none());
+                msg[0] ^= 1;
+                hm[0] ^= 1;
+            }
+        }
+    }
+
     #[test]
     fn in_subgroup() {
         // A generator for the low-order points (i.e. a point of order
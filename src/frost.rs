@@ -42,10 +42,30 @@
 //!  - `Coordinator`: the permanent state of a coordinator, who organizes
 //!    the signature generation and assembles the signature shares (that
 //!    state consists of the signature threshold and the group public key)
+//!  - `SigningSession`: the state of a coordinator for the generation of
+//!    one signature (received commitments, chosen signers, received
+//!    signature shares, and blamed signers); it can be persisted
+//!  - `BlameReport`: the signers who caused a signing session to fail
 //!  - `Nonce`: a per-signature nonce produced by an individual signer
 //!  - `Commitment`: a per-signature commitment produced by an individual signer
 //!  - `SignatureShare`: a signature share, produced by an individual signer
 //!  - `Signature`: a generated FROST signature
+//!  - `DkgParticipant`: the secret state of a participant in the
+//!    distributed key generation (DKG), used instead of `KeySplitter`
+//!    when no trusted dealer is available
+//!  - `DkgRound1Message`: a DKG first round message (broadcast to all
+//!    other participants)
+//!  - `DkgRound2Message`: a DKG second round message (sent privately to
+//!    a single other participant)
+//!  - `RefreshParticipant`: the secret state of a signer in a proactive
+//!    share refresh (new shares for the same group public key)
+//!  - `RefreshRound1Message`: a share refresh first round message
+//!  - `ReshareDealer`: the secret state of a signer who reshares its
+//!    key share towards a new set of participants (with possibly a
+//!    different threshold)
+//!  - `ReshareRound1Message`: a resharing first round message
+//!  - `RepairMessage`: a message in the share repair protocol, which
+//!    rebuilds a lost share or enrolls a new signer
 //!
 //! All the types that are meant to be either transmitted or stored on a
 //! non-volatile medium have encoding and decoding functions; the encoding
@@ -194,6 +214,171 @@ macro_rules! define_frost_core { () => {
         group_pk: GroupPublicKey,
     }
 
+    /// A coordinator's signing session.
+    ///
+    /// The session tracks the generation of one signature on a given
+    /// message: it records the commitments received from the signers,
+    /// chooses the signers (with `Coordinator::choose()`), collects the
+    /// signature shares, and verifies them. If some shares are invalid,
+    /// then the cheating signers are blamed and excluded from the session,
+    /// and the session can be restarted with a different subset of signers.
+    /// The session state can be encoded into bytes and decoded back, so
+    /// that it can be persisted between requests.
+    #[derive(Clone, Debug)]
+    pub struct SigningSession {
+        coordinator: Coordinator,
+        msg: Vec<u8>,
+        pool: Vec<Commitment>,
+        chosen: Vec<Commitment>,
+        shares: Vec<SignatureShare>,
+        blamed: Vec<Scalar>,
+        signature: Option<Signature>,
+    }
+
+    /// A blame report, returned by `SigningSession::finish()` when the
+    /// signature could not be assembled.
+    #[derive(Clone, Debug)]
+    pub struct BlameReport {
+        /// Identifiers of the signers who sent an invalid signature share;
+        /// they are excluded from the session.
+        pub cheaters: Vec<Scalar>,
+        /// Identifiers of the chosen signers whose signature share has
+        /// not been received yet.
+        pub missing: Vec<Scalar>,
+        /// Identifiers of the chosen signers whose public key was not
+        /// provided to `SigningSession::finish()`; they are not blamed.
+        pub unknown: Vec<Scalar>,
+    }
+
+    /// A participant in the distributed key generation.
+    ///
+    /// Distributed key generation (DKG) replaces the trusted dealer: each
+    /// of the `max_signers` participants chooses a random secret polynomial
+    /// of degree `min_signers - 1`, and the group private key is the sum of
+    /// the constant terms of all polynomials; nobody ever knows it. This
+    /// structure contains the participant's secret polynomial; it must be
+    /// kept secret, and can be discarded once the participant's private key
+    /// share has been obtained.
+    #[derive(Clone, Debug)]
+    pub struct DkgParticipant {
+        /// Participant identifier
+        pub ident: Scalar,
+        min_signers: usize,
+        max_signers: usize,
+        coefficients: Vec<Scalar>,
+        round1: DkgRound1Message,
+    }
+
+    /// A DKG first round message.
+    ///
+    /// This message contains the commitment to the sender's secret
+    /// polynomial (as a list of VSS elements), and a proof of knowledge
+    /// of the constant term of that polynomial. It must be sent to all
+    /// other participants over a broadcast channel (all participants must
+    /// receive the same message).
+    #[derive(Clone, Debug)]
+    pub struct DkgRound1Message {
+        /// Sender identifier
+        pub ident: Scalar,
+        commitment: Vec<VSSElement>,
+        proof_R: Point,
+        proof_mu: Scalar,
+    }
+
+    /// A DKG second round message.
+    ///
+    /// This message contains the evaluation of the sender's secret
+    /// polynomial for the receiver's identifier. It is secret, and must
+    /// be sent to its receiver over a confidential and authenticated
+    /// channel. The same message type is used for the second round of
+    /// share refresh (`RefreshParticipant`) and resharing
+    /// (`ReshareDealer`).
+    #[derive(Clone, Copy, Debug)]
+    pub struct DkgRound2Message {
+        /// Sender identifier
+        pub sender: Scalar,
+        /// Receiver identifier
+        pub receiver: Scalar,
+        share: Scalar,
+    }
+
+    /// A participant in a proactive share refresh.
+    ///
+    /// In a share refresh, all `max_signers` signers conjointly generate
+    /// new private key shares for the same group public key; the old
+    /// shares then become useless (provided that they are erased), so
+    /// that a share leaked before the refresh cannot be combined with
+    /// shares leaked after the refresh. Each participant adds to its
+    /// share the sum of the evaluations of random polynomials whose
+    /// constant term is zero. This structure contains the participant's
+    /// secret state.
+    #[derive(Clone, Debug)]
+    pub struct RefreshParticipant {
+        share: SignerPrivateKeyShare,
+        min_signers: usize,
+        max_signers: usize,
+        coefficients: Vec<Scalar>,
+        round1: RefreshRound1Message,
+    }
+
+    /// A share refresh first round message.
+    ///
+    /// This message contains the commitment to the sender's secret
+    /// polynomial (with a zero constant term, hence only `min_signers - 1`
+    /// VSS elements). It must be broadcast to all other participants.
+    #[derive(Clone, Debug)]
+    pub struct RefreshRound1Message {
+        /// Sender identifier
+        pub ident: Scalar,
+        commitment: Vec<VSSElement>,
+    }
+
+    /// A dealer in a resharing.
+    ///
+    /// Resharing allows a set of at least `min_signers` signers (the
+    /// dealers) to generate shares of the same group private key for a
+    /// new set of participants, with possibly a different threshold
+    /// and a different number of participants. Each dealer shares, with
+    /// a new random polynomial, its own private key share multiplied by
+    /// its Lagrange coefficient; the constant term of the polynomial is
+    /// committed to, and the commitment is verifiable against the old
+    /// VSS commitment. This structure contains the dealer's secret state.
+    #[derive(Clone, Debug)]
+    pub struct ReshareDealer {
+        new_max_signers: usize,
+        coefficients: Vec<Scalar>,
+        round1: ReshareRound1Message,
+    }
+
+    /// A resharing first round message.
+    ///
+    /// This message contains the commitment to the dealer's secret
+    /// polynomial. It must be broadcast to all new participants.
+    #[derive(Clone, Debug)]
+    pub struct ReshareRound1Message {
+        /// Dealer identifier
+        pub ident: Scalar,
+        commitment: Vec<VSSElement>,
+    }
+
+    /// A share repair message.
+    ///
+    /// Share repair allows a set of `min_signers` signers (the helpers)
+    /// to compute the private key share of another participant, either
+    /// to replace a lost share, or to enroll a new participant. This
+    /// message is used for both steps of the repair process (from helper
+    /// to helper, then from helper to the repaired participant). It is
+    /// secret, and must be sent to its receiver over a confidential and
+    /// authenticated channel.
+    #[derive(Clone, Copy, Debug)]
+    pub struct RepairMessage {
+        /// Sender identifier
+        pub sender: Scalar,
+        /// Receiver identifier
+        pub receiver: Scalar,
+        value: Scalar,
+    }
+
     impl GroupPrivateKey {
 
         /// Encoded private key length (in bytes).
@@ -438,6 +623,104 @@ macro_rules! define_frost_core { () => {
             }
             (signer_pk_list, group_pk)
         }
+
+        /// Completes a resharing (on the side of a new participant).
+        ///
+        /// `index` is the new participant's index (1 to `new_max_signers`).
+        /// `vsscomm` is the group VSS commitment before resharing.
+        /// `dealers` is the list of dealer identifiers (in ascending order,
+        /// as used by the dealers). `round1_msgs` and `round2_msgs` are the
+        /// messages received from all the dealers, in any order. Each
+        /// dealer's commitment is verified against its public key share
+        /// (derived from `vsscomm`), and each received value is verified
+        /// against the dealer's commitment.
+        ///
+        /// If any message is invalid, missing, or duplicated, or if there
+        /// are not enough dealers, then `None` is returned. Otherwise, the
+        /// new private key share is returned, along with the new group VSS
+        /// commitment (`new_min_signers` elements, the same for all new
+        /// participants). The group public key is unchanged.
+        pub fn reshare_finish(index: usize,
+            new_min_signers: usize, new_max_signers: usize,
+            vsscomm: &[VSSElement], dealers: &[Scalar],
+            round1_msgs: &[ReshareRound1Message],
+            round2_msgs: &[DkgRound2Message])
+            -> Option<(SignerPrivateKeyShare, Vec<VSSElement>)>
+        {
+            assert!(new_min_signers >= 2);
+            assert!(new_min_signers <= new_max_signers);
+            assert!(new_max_signers <= Self::MAX_MAX_SIGNERS);
+            assert!(index >= 1 && index <= new_max_signers);
+            if vsscomm.len() < 2 || !check_ident_list(dealers)
+                || dealers.len() < vsscomm.len()
+                || round1_msgs.len() != dealers.len()
+                || round2_msgs.len() != dealers.len()
+            {
+                return None;
+            }
+
+            let ident = Scalar::from_u64(index as u64);
+            let group_pk = GroupPublicKey {
+                pk: vsscomm[0].0,
+                pk_enc: point_encode(vsscomm[0].0),
+            };
+            let mut new_vsscomm: Vec<VSSElement> = Vec::new();
+            for _ in 0..new_min_signers {
+                new_vsscomm.push(VSSElement(Point::NEUTRAL));
+            }
+            let mut sk = Scalar::ZERO;
+            for d in dealers.iter() {
+                let m1 = find_unique(round1_msgs, *d, |x| x.ident)?;
+                let m2 = find_unique(round2_msgs, *d, |x| x.sender)?;
+                if m1.commitment.len() != new_min_signers
+                    || m2.receiver.equals(ident) == 0
+                {
+                    return None;
+                }
+
+                // The constant term of the dealer's polynomial must be
+                // its share of the group private key, multiplied by its
+                // Lagrange coefficient.
+                let lambda = derive_interpolating_value(*d, dealers);
+                let Q = vss_eval(vsscomm, *d) * lambda;
+                if Q.equals(m1.commitment[0].0) == 0 {
+                    return None;
+                }
+
+                let ss = SignerPrivateKeyShare {
+                    ident,
+                    sk: m2.share,
+                    pk: Point::mulgen(&m2.share),
+                    group_pk,
+                };
+                if !ss.verify_split(&m1.commitment) {
+                    return None;
+                }
+                sk += m2.share;
+                for (v, w) in new_vsscomm.iter_mut().zip(m1.commitment.iter()) {
+                    v.0 += w.0;
+                }
+            }
+            if sk.iszero() != 0 || new_vsscomm[0].0.equals(group_pk.pk) == 0 {
+                return None;
+            }
+            for v in new_vsscomm.iter() {
+                if v.0.isneutral() != 0 {
+                    return None;
+                }
+            }
+
+            let share = SignerPrivateKeyShare {
+                ident,
+                sk,
+                pk: Point::mulgen(&sk),
+                group_pk,
+            };
+            if !share.verify_split(&new_vsscomm) {
+                return None;
+            }
+            Some((share, new_vsscomm))
+        }
     }
 
     impl SignerPrivateKeyShare {
@@ -518,6 +801,117 @@ macro_rules! define_frost_core { () => {
             self.pk.equals(Q) != 0
         }
 
+        /// Share repair, first step (on the side of a helper).
+        ///
+        /// `helpers` is the list of identifiers of the helpers, sorted in
+        /// ascending order; it must include this signer's identifier, and
+        /// must contain at least as many helpers as the signing threshold.
+        /// `target` is the identifier of the participant whose share is
+        /// to be rebuilt (it may be a new identifier, to enroll a new
+        /// participant); it must not be in `helpers`.
+        ///
+        /// This function returns one message for each helper (in the
+        /// order of `helpers`, including this signer); each message must
+        /// be sent privately to its receiver. If the list of helpers is
+        /// not valid, then `None` is returned.
+        pub fn repair_step1<T: CryptoRng + RngCore>(self, rng: &mut T,
+            helpers: &[Scalar], target: Scalar) -> Option<Vec<RepairMessage>>
+        {
+            if target.iszero() != 0 || !check_ident_list(helpers)
+                || !helpers.iter().any(|h| h.equals(self.ident) != 0)
+                || helpers.iter().any(|h| h.equals(target) != 0)
+            {
+                return None;
+            }
+
+            // Our contribution to the target's share is split into as
+            // many random values as there are helpers.
+            let lambda = derive_interpolating_value_at(
+                self.ident, helpers, target);
+            let mut delta = lambda * self.sk;
+            let mut r: Vec<RepairMessage> = Vec::with_capacity(helpers.len());
+            for (i, h) in helpers.iter().enumerate() {
+                let value = if i + 1 < helpers.len() {
+                    random_scalar(rng)
+                } else {
+                    delta
+                };
+                delta -= value;
+                r.push(RepairMessage { sender: self.ident, receiver: *h, value });
+            }
+            Some(r)
+        }
+
+        /// Share repair, second step (on the side of a helper).
+        ///
+        /// `helpers` and `target` must be the same as in the first step.
+        /// `msgs` must contain the messages received from all helpers
+        /// (including this signer) in the first step, in any order. The
+        /// returned message must be sent privately to the target
+        /// participant. If any message is missing, duplicated, or not
+        /// addressed to this signer, then `None` is returned.
+        pub fn repair_step2(self, helpers: &[Scalar], target: Scalar,
+            msgs: &[RepairMessage]) -> Option<RepairMessage>
+        {
+            if !check_ident_list(helpers) || msgs.len() != helpers.len() {
+                return None;
+            }
+            let mut value = Scalar::ZERO;
+            for h in helpers.iter() {
+                let m = find_unique(msgs, *h, |x| x.sender)?;
+                if m.receiver.equals(self.ident) == 0 {
+                    return None;
+                }
+                value += m.value;
+            }
+            Some(RepairMessage { sender: self.ident, receiver: target, value })
+        }
+
+        /// Share repair, final step (on the side of the target participant).
+        ///
+        /// `target` is the identifier of the repaired (or enrolled)
+        /// participant. `helpers` is the list of helper identifiers (as
+        /// used by the helpers). `msgs` must contain the second step
+        /// messages from all helpers, in any order. `vsscomm` is the
+        /// group VSS commitment; it is used to verify the rebuilt share.
+        ///
+        /// If any message is missing, duplicated, or not addressed to the
+        /// target, or if the rebuilt share does not match the VSS
+        /// commitment, then `None` is returned.
+        pub fn repair(target: Scalar, helpers: &[Scalar],
+            msgs: &[RepairMessage], vsscomm: &[VSSElement]) -> Option<Self>
+        {
+            if target.iszero() != 0 || vsscomm.len() < 2
+                || !check_ident_list(helpers) || msgs.len() != helpers.len()
+            {
+                return None;
+            }
+            let mut sk = Scalar::ZERO;
+            for h in helpers.iter() {
+                let m = find_unique(msgs, *h, |x| x.sender)?;
+                if m.receiver.equals(target) == 0 {
+                    return None;
+                }
+                sk += m.value;
+            }
+            if sk.iszero() != 0 {
+                return None;
+            }
+            let share = Self {
+                ident: target,
+                sk,
+                pk: Point::mulgen(&sk),
+                group_pk: GroupPublicKey {
+                    pk: vsscomm[0].0,
+                    pk_enc: point_encode(vsscomm[0].0),
+                },
+            };
+            if !share.verify_split(vsscomm) {
+                return None;
+            }
+            Some(share)
+        }
+
         /// Internal generation of a new nonce.
         ///
         /// As per the specification, the nonce is obtained by hashing the
@@ -1041,27 +1435,1018 @@ macro_rules! define_frost_core { () => {
                 let spk = signer_public_keys.into_iter().find(
                     |&x| x.ident.equals(id) != 0)?;
 
-                // Verify the share.
-                if !spk.inner_verify_signature_share(
-                    *ss, commitment_list, &binding_factor_list, challenge)
-                {
-                    return None;
-                }
+                // Verify the share.
+                if !spk.inner_verify_signature_share(
+                    *ss, commitment_list, &binding_factor_list, challenge)
+                {
+                    return None;
+                }
+
+                verified_shares.push(*ss);
+            }
+
+            // Assemble the signature value.
+            let (R, z) = aggregate(group_commitment, &verified_shares);
+
+            // Verify the signature. We already computed the challenge,
+            // so we only have to check the verification equation.
+            if !self.group_pk.pk.verify_helper_vartime(&R, &z, &challenge) {
+                return None;
+            }
+
+            // All good, return the signature.
+            Some(Signature { R, z })
+        }
+    }
+
+    impl SigningSession {
+
+        /// Creates a new signing session for the provided message.
+        pub fn new(coordinator: Coordinator, msg: &[u8]) -> Self {
+            Self {
+                coordinator,
+                msg: msg.to_vec(),
+                pool: Vec::new(),
+                chosen: Vec::new(),
+                shares: Vec::new(),
+                blamed: Vec::new(),
+                signature: None,
+            }
+        }
+
+        /// Records a commitment received from a signer.
+        ///
+        /// Commitments can be received at any time before the signature
+        /// is complete; they are used by the next call to `choose()`. If
+        /// a commitment from the same signer was already recorded (and not
+        /// yet used), then it is replaced. This function returns `false`
+        /// (and ignores the commitment) if the signer has been blamed, or
+        /// if the signer is part of the currently chosen signers, or if
+        /// the signature is already complete.
+        pub fn add_commitment(&mut self, comm: Commitment) -> bool {
+            if self.signature.is_some()
+                || contains_ident(&self.blamed, comm.ident)
+                || self.chosen.iter().any(|c| c.ident.equals(comm.ident) != 0)
+            {
+                return false;
+            }
+            self.pool.retain(|c| c.ident.equals(comm.ident) == 0);
+            self.pool.push(comm);
+            true
+        }
+
+        /// Chooses the signers.
+        ///
+        /// If no signer is currently chosen, and there are enough recorded
+        /// commitments, then this function chooses `min_signers` of them
+        /// (with `Coordinator::choose()`) and returns the corresponding
+        /// ordered list of commitments, which must be sent to all chosen
+        /// signers. The chosen commitments are removed from the pool of
+        /// available commitments (each commitment can be used only once).
+        /// Otherwise, this function returns `None`.
+        pub fn choose(&mut self) -> Option<Vec<Commitment>> {
+            if self.signature.is_some() || !self.chosen.is_empty() {
+                return None;
+            }
+            let chosen = self.coordinator.choose(&self.pool)?;
+            self.pool.retain(|c| !chosen.iter().any(
+                |d| d.ident.equals(c.ident) != 0));
+            self.chosen = chosen.clone();
+            Some(chosen)
+        }
+
+        /// Gets the currently chosen list of commitments (empty if no
+        /// signer is currently chosen).
+        pub fn get_chosen(&self) -> &[Commitment] {
+            &self.chosen
+        }
+
+        /// Records a signature share received from a signer.
+        ///
+        /// The share is not verified at this point (this is done by
+        /// `finish()`). This function returns `false` (and ignores the
+        /// share) if the signer is not part of the currently chosen
+        /// signers, or if a different share was already received from
+        /// the same signer.
+        pub fn add_share(&mut self, share: SignatureShare) -> bool {
+            if self.signature.is_some()
+                || !self.chosen.iter().any(|c| c.ident.equals(share.ident) != 0)
+            {
+                return false;
+            }
+            match self.shares.iter().find(
+                |x| x.ident.equals(share.ident) != 0)
+            {
+                Some(x) => x.zi.equals(share.zi) != 0,
+                None => {
+                    self.shares.push(share);
+                    true
+                }
+            }
+        }
+
+        /// Gets the identifiers of the currently chosen signers whose
+        /// signature share has not been received yet.
+        pub fn missing(&self) -> Vec<Scalar> {
+            let mut r: Vec<Scalar> = Vec::new();
+            for c in self.chosen.iter() {
+                if !contains_ident_share(&self.shares, c.ident) {
+                    r.push(c.ident);
+                }
+            }
+            r
+        }
+
+        /// Verifies the signature shares and assembles the signature.
+        ///
+        /// `signer_public_keys` must contain the public keys of the chosen
+        /// signers (it may contain extra public keys). On success, the
+        /// signature is returned (and remembered by the session). On
+        /// failure, a blame report is returned:
+        ///
+        ///  - If some shares are missing, then the report lists the
+        ///    corresponding signers in `missing`, and the session is
+        ///    unchanged (more shares can be added).
+        ///
+        ///  - If some public keys are missing from `signer_public_keys`,
+        ///    then the report lists the corresponding signers in `unknown`,
+        ///    and the session is unchanged (the call can be made again with
+        ///    the complete list of public keys). Such signers are not
+        ///    blamed, since the error is on the caller's side.
+        ///
+        ///  - If some shares are invalid, then the report lists the
+        ///    corresponding signers in `cheaters`; these signers are
+        ///    permanently excluded from the session, and the session is
+        ///    restarted (see `restart()`).
+        ///
+        /// If no signer is currently chosen, then the returned report
+        /// is empty.
+        pub fn finish(&mut self, signer_public_keys: &[SignerPublicKey])
+            -> Result<Signature, BlameReport>
+        {
+            if let Some(sig) = self.signature {
+                return Ok(sig);
+            }
+            let missing = self.missing();
+            if self.chosen.is_empty() || !missing.is_empty() {
+                return Err(BlameReport {
+                    cheaters: Vec::new(),
+                    missing,
+                    unknown: Vec::new(),
+                });
+            }
+
+            // Get the public keys of all chosen signers.
+            let mut spks: Vec<&SignerPublicKey> =
+                Vec::with_capacity(self.chosen.len());
+            let mut unknown: Vec<Scalar> = Vec::new();
+            for c in self.chosen.iter() {
+                match signer_public_keys.iter().find(
+                    |x| x.ident.equals(c.ident) != 0)
+                {
+                    Some(spk) => spks.push(spk),
+                    None => unknown.push(c.ident),
+                }
+            }
+            if !unknown.is_empty() {
+                return Err(BlameReport {
+                    cheaters: Vec::new(),
+                    missing: Vec::new(),
+                    unknown,
+                });
+            }
+
+            // Verify all shares.
+            let group_pk = self.coordinator.group_pk;
+            let binding_factor_list = compute_binding_factors(
+                group_pk, &self.chosen, &self.msg);
+            let group_commitment = compute_group_commitment(
+                &self.chosen, &binding_factor_list);
+            let challenge = compute_challenge(
+                group_commitment, &group_pk.pk_enc, &self.msg);
+            let mut verified_shares: Vec<SignatureShare> =
+                Vec::with_capacity(self.chosen.len());
+            let mut cheaters: Vec<Scalar> = Vec::new();
+            for (c, spk) in self.chosen.iter().zip(spks.iter()) {
+                let id = c.ident;
+                let ss = *self.shares.iter().find(
+                    |x| x.ident.equals(id) != 0).unwrap();
+                if spk.inner_verify_signature_share(
+                    ss, &self.chosen, &binding_factor_list, challenge)
+                {
+                    verified_shares.push(ss);
+                } else {
+                    cheaters.push(id);
+                }
+            }
+            if !cheaters.is_empty() {
+                self.blamed.extend_from_slice(&cheaters);
+                self.restart();
+                return Err(BlameReport {
+                    cheaters,
+                    missing: Vec::new(),
+                    unknown: Vec::new(),
+                });
+            }
+
+            // Assemble and verify the signature.
+            let (R, z) = aggregate(group_commitment, &verified_shares);
+            if !group_pk.pk.verify_helper_vartime(&R, &z, &challenge) {
+                return Err(BlameReport {
+                    cheaters: Vec::new(),
+                    missing: Vec::new(),
+                    unknown: Vec::new(),
+                });
+            }
+            let sig = Signature { R, z };
+            self.signature = Some(sig);
+            Ok(sig)
+        }
+
+        /// Restarts the session.
+        ///
+        /// The currently chosen signers, and the received signature shares,
+        /// are discarded. Since the commitments of the chosen signers have
+        /// been used, these signers must send new commitments in order to
+        /// be chosen again; signers who do not respond can thus be left
+        /// out of the next attempt. This function does nothing if the
+        /// signature is already complete.
+        pub fn restart(&mut self) {
+            if self.signature.is_none() {
+                self.chosen.clear();
+                self.shares.clear();
+            }
+        }
+
+        /// Gets the identifiers of the signers who have been blamed in
+        /// this session.
+        pub fn get_blamed(&self) -> &[Scalar] {
+            &self.blamed
+        }
+
+        /// Gets the signature, if the session is complete.
+        pub fn get_signature(&self) -> Option<Signature> {
+            self.signature
+        }
+
+        /// Encodes this session into bytes.
+        pub fn encode(&self) -> Vec<u8> {
+            let mut r: Vec<u8> = Vec::new();
+            r.extend_from_slice(&self.coordinator.group_pk.pk_enc);
+            r.extend_from_slice(
+                &(self.coordinator.min_signers as u32).to_le_bytes());
+            r.extend_from_slice(&(self.msg.len() as u64).to_le_bytes());
+            r.extend_from_slice(&self.msg);
+            for list in [&self.pool, &self.chosen] {
+                r.extend_from_slice(&(list.len() as u32).to_le_bytes());
+                for c in list.iter() {
+                    r.extend_from_slice(&c.encode());
+                }
+            }
+            r.extend_from_slice(&(self.shares.len() as u32).to_le_bytes());
+            for ss in self.shares.iter() {
+                r.extend_from_slice(&ss.encode());
+            }
+            r.extend_from_slice(&(self.blamed.len() as u32).to_le_bytes());
+            for id in self.blamed.iter() {
+                r.extend_from_slice(&scalar_encode(*id));
+            }
+            match self.signature {
+                Some(sig) => {
+                    r.push(1);
+                    r.extend_from_slice(&sig.encode());
+                }
+                None => r.push(0),
+            }
+            r
+        }
+
+        /// Decodes a session from bytes.
+        ///
+        /// This function returns `None` if the source slice is not a
+        /// valid encoded session (including if it has trailing garbage,
+        /// or if the encoded state is not consistent).
+        pub fn decode(buf: &[u8]) -> Option<Self> {
+            let mut off = 0;
+            let group_pk = GroupPublicKey::decode(take_bytes(buf, &mut off, NE)?)?;
+            let min_signers = take_u32(buf, &mut off)? as usize;
+            let coordinator = Coordinator::new(min_signers, group_pk)?;
+            let mut tb = [0u8; 8];
+            tb.copy_from_slice(take_bytes(buf, &mut off, 8)?);
+            let msg_len = u64::from_le_bytes(tb);
+            if msg_len > ((buf.len() - off) as u64) {
+                return None;
+            }
+            let msg = take_bytes(buf, &mut off, msg_len as usize)?.to_vec();
+            let mut lists: [Vec<Commitment>; 2] = [Vec::new(), Vec::new()];
+            for list in lists.iter_mut() {
+                let n = take_u32(buf, &mut off)?;
+                for _ in 0..n {
+                    list.push(Commitment::decode(
+                        take_bytes(buf, &mut off, Commitment::ENC_LEN)?)?);
+                }
+            }
+            let [pool, chosen] = lists;
+            let n = take_u32(buf, &mut off)?;
+            let mut shares: Vec<SignatureShare> = Vec::new();
+            for _ in 0..n {
+                shares.push(SignatureShare::decode(
+                    take_bytes(buf, &mut off, SignatureShare::ENC_LEN)?)?);
+            }
+            let n = take_u32(buf, &mut off)?;
+            let mut blamed: Vec<Scalar> = Vec::new();
+            for _ in 0..n {
+                blamed.push(scalar_decode(take_bytes(buf, &mut off, NS)?)?);
+            }
+            let signature = match take_bytes(buf, &mut off, 1)?[0] {
+                0 => None,
+                1 => Some(Signature::decode(
+                    take_bytes(buf, &mut off, Signature::ENC_LEN)?)?),
+                _ => return None,
+            };
+            if off != buf.len() {
+                return None;
+            }
+
+            // Check consistency: the chosen list must be empty or a
+            // proper output of choose(); the pool must not contain any
+            // chosen or blamed signer, nor duplicates; shares must come
+            // from distinct chosen signers.
+            if !chosen.is_empty() {
+                if chosen.len() != min_signers {
+                    return None;
+                }
+                for i in 1..chosen.len() {
+                    if scalar_cmp_vartime(chosen[i - 1].ident, chosen[i].ident)
+                        != Ordering::Less
+                    {
+                        return None;
+                    }
+                }
+            }
+            for (i, c) in pool.iter().enumerate() {
+                if contains_ident(&blamed, c.ident)
+                    || chosen.iter().any(|d| d.ident.equals(c.ident) != 0)
+                    || pool[..i].iter().any(|d| d.ident.equals(c.ident) != 0)
+                {
+                    return None;
+                }
+            }
+            for (i, ss) in shares.iter().enumerate() {
+                if !chosen.iter().any(|d| d.ident.equals(ss.ident) != 0)
+                    || contains_ident_share(&shares[..i], ss.ident)
+                {
+                    return None;
+                }
+            }
+            Some(Self {
+                coordinator, msg, pool, chosen, shares, blamed, signature,
+            })
+        }
+    }
+
+    impl DkgParticipant {
+
+        /// Starts the distributed key generation for a new participant.
+        ///
+        /// This function corresponds to the first round of the DKG
+        /// described in the FROST paper (and RFC 9591, appendix C).
+        ///
+        /// `index` is the participant's index; it must be between 1 and
+        /// `max_signers` (inclusive), and all participants must use
+        /// distinct indices. The participant identifier is the scalar
+        /// corresponding to that index, as with `KeySplitter::trusted_split`.
+        /// `min_signers` is the signing threshold; it must be at least 2.
+        /// `max_signers` is the number of participants; it must not be
+        /// lower than `min_signers`, and must not exceed
+        /// `KeySplitter::MAX_MAX_SIGNERS`.
+        ///
+        /// The participant state is returned, along with the first round
+        /// message that must be broadcast to all other participants.
+        pub fn new<T: CryptoRng + RngCore>(rng: &mut T, index: usize,
+            min_signers: usize, max_signers: usize)
+            -> (Self, DkgRound1Message)
+        {
+            assert!(min_signers >= 2);
+            assert!(min_signers <= max_signers);
+            assert!(max_signers <= KeySplitter::MAX_MAX_SIGNERS);
+            assert!(index >= 1 && index <= max_signers);
+
+            let ident = Scalar::from_u64(index as u64);
+            let mut coefficients: Vec<Scalar> = Vec::with_capacity(min_signers);
+            let mut commitment: Vec<VSSElement> =
+                Vec::with_capacity(min_signers);
+            for _ in 0..min_signers {
+                // A zero coefficient would yield a neutral VSS element,
+                // which cannot be encoded in all ciphersuites.
+                let mut coef = random_scalar(rng);
+                coef.set_cond(&Scalar::ONE, coef.iszero());
+                coefficients.push(coef);
+                commitment.push(VSSElement(Point::mulgen(&coef)));
+            }
+
+            // Proof of knowledge of the constant term (Schnorr signature
+            // over the participant identifier and the first VSS element).
+            let k = random_scalar(rng);
+            let proof_R = Point::mulgen(&k);
+            let c = dkg_challenge(ident, commitment[0].0, proof_R);
+            let proof_mu = k + c * coefficients[0];
+
+            let round1 = DkgRound1Message {
+                ident, commitment, proof_R, proof_mu,
+            };
+            (Self {
+                ident,
+                min_signers,
+                max_signers,
+                coefficients,
+                round1: round1.clone(),
+            }, round1)
+        }
+
+        /// Processes the first round messages and computes the second
+        /// round messages.
+        ///
+        /// `round1_msgs` must contain the first round messages from all
+        /// other participants, in any order; it may also contain this
+        /// participant's own first round message. All messages are
+        /// verified (including the proofs of knowledge); if any message
+        /// is invalid, or missing, or duplicated, then this function
+        /// returns `None`, and the DKG must be aborted.
+        ///
+        /// On success, the returned list contains one second round message
+        /// for each other participant (by ascending identifier); each
+        /// message must be sent privately to its receiver.
+        pub fn round2(&self, round1_msgs: &[DkgRound1Message])
+            -> Option<Vec<DkgRound2Message>>
+        {
+            let others = self.check_round1(round1_msgs)?;
+            let mut r: Vec<DkgRound2Message> = Vec::with_capacity(others.len());
+            for m in others.iter() {
+                r.push(DkgRound2Message {
+                    sender: self.ident,
+                    receiver: m.ident,
+                    share: self.eval(m.ident),
+                });
+            }
+            Some(r)
+        }
+
+        /// Completes the distributed key generation.
+        ///
+        /// `round1_msgs` must contain the same first round messages as
+        /// were provided to `round2()`. `round2_msgs` must contain the
+        /// second round messages received from all other participants,
+        /// in any order. Each received share is verified against the VSS
+        /// commitment of its sender (with `SignerPrivateKeyShare::verify_split()`).
+        /// If any message is invalid, or missing, or duplicated, or not
+        /// addressed to this participant, then this function returns `None`.
+        ///
+        /// On success, the participant's private key share is returned,
+        /// along with the group public key and the group VSS commitment
+        /// (`min_signers` elements). The VSS commitment is the same for all
+        /// participants, and can be used with
+        /// `KeySplitter::derive_group_info()` to obtain the public keys of
+        /// all signers.
+        pub fn finish(self, round1_msgs: &[DkgRound1Message],
+            round2_msgs: &[DkgRound2Message])
+            -> Option<(SignerPrivateKeyShare, GroupPublicKey, Vec<VSSElement>)>
+        {
+            let others = self.check_round1(round1_msgs)?;
+            if round2_msgs.len() != others.len() {
+                return None;
+            }
+
+            // The group VSS commitment is the sum of the VSS commitments
+            // of all participants.
+            let mut vsscomm = self.round1.commitment.clone();
+            for m in others.iter() {
+                for (v, w) in vsscomm.iter_mut().zip(m.commitment.iter()) {
+                    v.0 += w.0;
+                }
+            }
+            for v in vsscomm.iter() {
+                if v.0.isneutral() != 0 {
+                    return None;
+                }
+            }
+            let group_pk = GroupPublicKey {
+                pk: vsscomm[0].0,
+                pk_enc: point_encode(vsscomm[0].0),
+            };
+
+            // Verify and accumulate the received shares. The number of
+            // messages matches the number of other participants, and each
+            // other participant must be the sender of one message, so
+            // there can be no duplicate.
+            let mut sk = self.eval(self.ident);
+            for m in others.iter() {
+                let mut ff = false;
+                for rm in round2_msgs.iter() {
+                    if rm.sender.equals(m.ident) != 0 {
+                        if rm.receiver.equals(self.ident) == 0 {
+                            return None;
+                        }
+                        let ss = SignerPrivateKeyShare {
+                            ident: self.ident,
+                            sk: rm.share,
+                            pk: Point::mulgen(&rm.share),
+                            group_pk,
+                        };
+                        if !ss.verify_split(&m.commitment) {
+                            return None;
+                        }
+                        sk += rm.share;
+                        ff = true;
+                        break;
+                    }
+                }
+                if !ff {
+                    return None;
+                }
+            }
+            if sk.iszero() != 0 {
+                return None;
+            }
+
+            let share = SignerPrivateKeyShare {
+                ident: self.ident,
+                sk,
+                pk: Point::mulgen(&sk),
+                group_pk,
+            };
+            if !share.verify_split(&vsscomm) {
+                return None;
+            }
+            Some((share, group_pk, vsscomm))
+        }
+
+        /// Evaluates the secret polynomial for a given identifier.
+        fn eval(&self, x: Scalar) -> Scalar {
+            poly_eval(&self.coefficients, x)
+        }
+
+        /// Verifies the first round messages, and returns the messages
+        /// from the other participants, ordered by ascending identifier.
+        fn check_round1(&self, round1_msgs: &[DkgRound1Message])
+            -> Option<Vec<DkgRound1Message>>
+        {
+            collect_broadcast(round1_msgs, self.ident, self.max_signers,
+                |m| m.ident,
+                |m| m.encode() == self.round1.encode(),
+                |m| m.commitment.len() == self.min_signers && m.verify_proof())
+        }
+    }
+
+    impl DkgRound1Message {
+
+        /// Gets the VSS commitment of the sender.
+        pub fn get_commitment(&self) -> &[VSSElement] {
+            &self.commitment
+        }
+
+        /// Verifies the proof of knowledge of the sender's secret
+        /// constant term.
+        pub fn verify_proof(&self) -> bool {
+            let c = dkg_challenge(self.ident, self.commitment[0].0, self.proof_R);
+            self.commitment[0].0.verify_helper_vartime(
+                &self.proof_R, &self.proof_mu, &c)
+        }
+
+        /// Encodes this message into bytes.
+        ///
+        /// The encoding length is `NS + NE + NS + min_signers*NE` bytes
+        /// (identifier, proof of knowledge, and VSS commitment).
+        pub fn encode(&self) -> Vec<u8> {
+            let mut r: Vec<u8> = Vec::with_capacity(
+                NS + NE + NS + NE * self.commitment.len());
+            r.extend_from_slice(&scalar_encode(self.ident));
+            r.extend_from_slice(&point_encode(self.proof_R));
+            r.extend_from_slice(&scalar_encode(self.proof_mu));
+            r.extend_from_slice(&VSSElement::encode_list(&self.commitment));
+            r
+        }
+
+        /// Decodes a message from bytes.
+        ///
+        /// This function returns `None` if the source slice does not have
+        /// a proper length, or does not contain canonical encodings of
+        /// a non-zero identifier, a proof of knowledge, and a VSS commitment
+        /// of at least two elements. The proof of knowledge is NOT verified
+        /// by this function.
+        pub fn decode(buf: &[u8]) -> Option<Self> {
+            if buf.len() < NS + NE + NS {
+                return None;
+            }
+            let ident = scalar_decode(&buf[0..NS])?;
+            if ident.iszero() != 0 {
+                return None;
+            }
+            let proof_R = point_decode(&buf[NS..NS + NE])?;
+            let proof_mu = scalar_decode(&buf[NS + NE..NS + NE + NS])?;
+            let commitment = VSSElement::decode_list(&buf[NS + NE + NS..])?;
+            Some(Self { ident, commitment, proof_R, proof_mu })
+        }
+    }
+
+    impl DkgRound2Message {
+
+        /// Encoded message length (in bytes).
+        pub const ENC_LEN: usize = 3 * NS;
+
+        /// Encodes this message into bytes.
+        ///
+        /// The encoded message contains a secret value, and must be sent
+        /// only over a confidential channel.
+        pub fn encode(self) -> [u8; Self::ENC_LEN] {
+            let mut buf = [0u8; Self::ENC_LEN];
+            buf[0..NS].copy_from_slice(&scalar_encode(self.sender));
+            buf[NS..2 * NS].copy_from_slice(&scalar_encode(self.receiver));
+            buf[2 * NS..3 * NS].copy_from_slice(&scalar_encode(self.share));
+            buf
+        }
+
+        /// Decodes a message from bytes.
+        ///
+        /// The process fails (i.e. returns `None`) if the source slice
+        /// does not have a proper length or does not contain properly
+        /// canonical encodings of the (non-zero) sender and receiver
+        /// identifiers, and the share.
+        pub fn decode(buf: &[u8]) -> Option<Self> {
+            if buf.len() != Self::ENC_LEN {
+                return None;
+            }
+            let sender = scalar_decode(&buf[0..NS])?;
+            let receiver = scalar_decode(&buf[NS..2 * NS])?;
+            if sender.iszero() != 0 || receiver.iszero() != 0 {
+                return None;
+            }
+            let share = scalar_decode(&buf[2 * NS..3 * NS])?;
+            Some(Self { sender, receiver, share })
+        }
+    }
+
+    impl RefreshParticipant {
+
+        /// Starts a share refresh.
+        ///
+        /// All `max_signers` signers must take part in the refresh; each
+        /// signer identifier must be between 1 and `max_signers`
+        /// (inclusive), as produced by `KeySplitter::trusted_split()` or
+        /// `DkgParticipant`. `min_signers` is the signing threshold (it is
+        /// unchanged by the refresh).
+        ///
+        /// The participant state is returned, along with the first round
+        /// message that must be broadcast to all other participants.
+        pub fn new<T: CryptoRng + RngCore>(rng: &mut T,
+            share: SignerPrivateKeyShare, min_signers: usize,
+            max_signers: usize) -> (Self, RefreshRound1Message)
+        {
+            assert!(min_signers >= 2);
+            assert!(min_signers <= max_signers);
+            assert!(max_signers <= KeySplitter::MAX_MAX_SIGNERS);
+            assert!(ident_to_index(share.ident, max_signers).is_some());
+
+            // The constant term is zero and is not stored; the other
+            // coefficients are random non-zero scalars.
+            let mut coefficients: Vec<Scalar> =
+                Vec::with_capacity(min_signers - 1);
+            let mut commitment: Vec<VSSElement> =
+                Vec::with_capacity(min_signers - 1);
+            for _ in 1..min_signers {
+                let mut coef = random_scalar(rng);
+                coef.set_cond(&Scalar::ONE, coef.iszero());
+                coefficients.push(coef);
+                commitment.push(VSSElement(Point::mulgen(&coef)));
+            }
+            let round1 = RefreshRound1Message {
+                ident: share.ident,
+                commitment,
+            };
+            (Self {
+                share,
+                min_signers,
+                max_signers,
+                coefficients,
+                round1: round1.clone(),
+            }, round1)
+        }
+
+        /// Processes the first round messages and computes the second
+        /// round messages.
+        ///
+        /// `round1_msgs` must contain the first round messages from all
+        /// other participants, in any order; it may also contain this
+        /// participant's own first round message. If any message is
+        /// invalid, missing, or duplicated, then this function returns
+        /// `None`, and the refresh must be aborted.
+        ///
+        /// On success, the returned list contains one second round message
+        /// for each other participant (by ascending identifier); each
+        /// message must be sent privately to its receiver.
+        pub fn round2(&self, round1_msgs: &[RefreshRound1Message])
+            -> Option<Vec<DkgRound2Message>>
+        {
+            let others = self.check_round1(round1_msgs)?;
+            let mut r: Vec<DkgRound2Message> = Vec::with_capacity(others.len());
+            for m in others.iter() {
+                r.push(DkgRound2Message {
+                    sender: self.share.ident,
+                    receiver: m.ident,
+                    share: self.eval(m.ident),
+                });
+            }
+            Some(r)
+        }
+
+        /// Completes the share refresh.
+        ///
+        /// `round1_msgs` must contain the same first round messages as
+        /// were provided to `round2()`. `round2_msgs` must contain the
+        /// second round messages received from all other participants,
+        /// in any order. `vsscomm` is the current group VSS commitment;
+        /// this participant's current share must match it. Each received
+        /// value is verified against the commitment of its sender.
+        ///
+        /// On success, the new private key share is returned, along with
+        /// the new group VSS commitment (which is the same for all
+        /// participants). The group public key is unchanged. The old share
+        /// should be erased.
+        pub fn finish(self, round1_msgs: &[RefreshRound1Message],
+            round2_msgs: &[DkgRound2Message], vsscomm: &[VSSElement])
+            -> Option<(SignerPrivateKeyShare, Vec<VSSElement>)>
+        {
+            if vsscomm.len() != self.min_signers
+                || !self.share.verify_split(vsscomm)
+            {
+                return None;
+            }
+            let others = self.check_round1(round1_msgs)?;
+            if round2_msgs.len() != others.len() {
+                return None;
+            }
+
+            // Update the VSS commitment (the first element, which is the
+            // group public key, does not change).
+            let mut new_vsscomm = vsscomm.to_vec();
+            let mut sk = self.share.sk + self.eval(self.share.ident);
+            for m in core::iter::once(&self.round1).chain(others.iter()) {
+                for (v, w) in new_vsscomm[1..].iter_mut()
+                    .zip(m.commitment.iter())
+                {
+                    v.0 += w.0;
+                }
+            }
+            for v in new_vsscomm.iter() {
+                if v.0.isneutral() != 0 {
+                    return None;
+                }
+            }
+
+            // Verify and accumulate the received values.
+            for m in others.iter() {
+                let rm = find_unique(round2_msgs, m.ident, |x| x.sender)?;
+                if rm.receiver.equals(self.share.ident) == 0 {
+                    return None;
+                }
+                let ss = SignerPrivateKeyShare {
+                    ident: self.share.ident,
+                    sk: rm.share,
+                    pk: Point::mulgen(&rm.share),
+                    group_pk: self.share.group_pk,
+                };
+                if !ss.verify_split(&m.full_commitment()) {
+                    return None;
+                }
+                sk += rm.share;
+            }
+            if sk.iszero() != 0 {
+                return None;
+            }
+
+            let share = SignerPrivateKeyShare {
+                ident: self.share.ident,
+                sk,
+                pk: Point::mulgen(&sk),
+                group_pk: self.share.group_pk,
+            };
+            if !share.verify_split(&new_vsscomm) {
+                return None;
+            }
+            Some((share, new_vsscomm))
+        }
+
+        /// Evaluates the secret polynomial for a given identifier.
+        fn eval(&self, x: Scalar) -> Scalar {
+            poly_eval(&self.coefficients, x) * x
+        }
+
+        /// Verifies the first round messages, and returns the messages
+        /// from the other participants, ordered by ascending identifier.
+        fn check_round1(&self, round1_msgs: &[RefreshRound1Message])
+            -> Option<Vec<RefreshRound1Message>>
+        {
+            collect_broadcast(round1_msgs, self.share.ident, self.max_signers,
+                |m| m.ident,
+                |m| m.encode() == self.round1.encode(),
+                |m| m.commitment.len() == self.min_signers - 1)
+        }
+    }
+
+    impl RefreshRound1Message {
+
+        /// Gets the VSS commitment of the sender; this includes the
+        /// commitment to the zero constant term (the neutral element).
+        pub fn full_commitment(&self) -> Vec<VSSElement> {
+            let mut r: Vec<VSSElement> =
+                Vec::with_capacity(self.commitment.len() + 1);
+            r.push(VSSElement(Point::NEUTRAL));
+            r.extend_from_slice(&self.commitment);
+            r
+        }
+
+        /// Encodes this message into bytes.
+        ///
+        /// The encoding length is `NS + (min_signers - 1)*NE` bytes
+        /// (identifier, and VSS commitment without its first element).
+        pub fn encode(&self) -> Vec<u8> {
+            let mut r: Vec<u8> = Vec::with_capacity(
+                NS + NE * self.commitment.len());
+            r.extend_from_slice(&scalar_encode(self.ident));
+            r.extend_from_slice(&VSSElement::encode_list(&self.commitment));
+            r
+        }
+
+        /// Decodes a message from bytes.
+        ///
+        /// This function returns `None` if the source slice does not have
+        /// a proper length, or does not contain canonical encodings of
+        /// a non-zero identifier and at least one non-neutral VSS element.
+        pub fn decode(buf: &[u8]) -> Option<Self> {
+            if buf.len() < NS + NE || (buf.len() - NS) % NE != 0 {
+                return None;
+            }
+            let ident = scalar_decode(&buf[0..NS])?;
+            if ident.iszero() != 0 {
+                return None;
+            }
+            let n = (buf.len() - NS) / NE;
+            let mut commitment: Vec<VSSElement> = Vec::with_capacity(n);
+            for i in 0..n {
+                let j = NS + NE * i;
+                commitment.push(VSSElement(point_decode(&buf[j..j + NE])?));
+            }
+            Some(Self { ident, commitment })
+        }
+    }
+
+    impl ReshareDealer {
 
-                verified_shares.push(*ss);
+        /// Starts a resharing.
+        ///
+        /// `share` is the dealer's private key share. `dealers` is the
+        /// list of identifiers of all dealers, sorted in ascending order;
+        /// it must include this dealer's identifier, and must contain at
+        /// least as many dealers as the current signing threshold.
+        /// `new_min_signers` and `new_max_signers` are the threshold and
+        /// number of participants after resharing; the new participants
+        /// have identifiers 1 to `new_max_signers`.
+        ///
+        /// If the list of dealers is not valid, then this function
+        /// returns `None`. Otherwise, the dealer state is returned, along
+        /// with the first round message that must be broadcast to all
+        /// new participants.
+        pub fn new<T: CryptoRng + RngCore>(rng: &mut T,
+            share: SignerPrivateKeyShare, dealers: &[Scalar],
+            new_min_signers: usize, new_max_signers: usize)
+            -> Option<(Self, ReshareRound1Message)>
+        {
+            assert!(new_min_signers >= 2);
+            assert!(new_min_signers <= new_max_signers);
+            assert!(new_max_signers <= KeySplitter::MAX_MAX_SIGNERS);
+            if !check_ident_list(dealers)
+                || !dealers.iter().any(|d| d.equals(share.ident) != 0)
+            {
+                return None;
             }
 
-            // Assemble the signature value.
-            let (R, z) = aggregate(group_commitment, &verified_shares);
+            let lambda = derive_interpolating_value(share.ident, dealers);
+            let mut coefficients: Vec<Scalar> =
+                Vec::with_capacity(new_min_signers);
+            let mut commitment: Vec<VSSElement> =
+                Vec::with_capacity(new_min_signers);
+            coefficients.push(lambda * share.sk);
+            commitment.push(VSSElement(share.pk * lambda));
+            for _ in 1..new_min_signers {
+                let mut coef = random_scalar(rng);
+                coef.set_cond(&Scalar::ONE, coef.iszero());
+                coefficients.push(coef);
+                commitment.push(VSSElement(Point::mulgen(&coef)));
+            }
+            let round1 = ReshareRound1Message {
+                ident: share.ident,
+                commitment,
+            };
+            Some((Self {
+                new_max_signers,
+                coefficients,
+                round1: round1.clone(),
+            }, round1))
+        }
 
-            // Verify the signature. We already computed the challenge,
-            // so we only have to check the verification equation.
-            if !self.group_pk.pk.verify_helper_vartime(&R, &z, &challenge) {
+        /// Computes the second round messages.
+        ///
+        /// The returned list contains one message for each new participant
+        /// (identifiers 1 to `new_max_signers`, in that order, including
+        /// this dealer if it is also a new participant); each message must
+        /// be sent privately to its receiver.
+        pub fn round2(&self) -> Vec<DkgRound2Message> {
+            let mut r: Vec<DkgRound2Message> =
+                Vec::with_capacity(self.new_max_signers);
+            for i in 1..=self.new_max_signers {
+                let x = Scalar::from_u64(i as u64);
+                r.push(DkgRound2Message {
+                    sender: self.round1.ident,
+                    receiver: x,
+                    share: poly_eval(&self.coefficients, x),
+                });
+            }
+            r
+        }
+    }
+
+    impl ReshareRound1Message {
+
+        /// Gets the VSS commitment of the dealer.
+        pub fn get_commitment(&self) -> &[VSSElement] {
+            &self.commitment
+        }
+
+        /// Encodes this message into bytes.
+        ///
+        /// The encoding length is `NS + new_min_signers*NE` bytes
+        /// (identifier and VSS commitment).
+        pub fn encode(&self) -> Vec<u8> {
+            let mut r: Vec<u8> = Vec::with_capacity(
+                NS + NE * self.commitment.len());
+            r.extend_from_slice(&scalar_encode(self.ident));
+            r.extend_from_slice(&VSSElement::encode_list(&self.commitment));
+            r
+        }
+
+        /// Decodes a message from bytes.
+        ///
+        /// This function returns `None` if the source slice does not have
+        /// a proper length, or does not contain canonical encodings of
+        /// a non-zero identifier and a VSS commitment of at least two
+        /// elements.
+        pub fn decode(buf: &[u8]) -> Option<Self> {
+            if buf.len() < NS {
+                return None;
+            }
+            let ident = scalar_decode(&buf[0..NS])?;
+            if ident.iszero() != 0 {
                 return None;
             }
+            let commitment = VSSElement::decode_list(&buf[NS..])?;
+            Some(Self { ident, commitment })
+        }
+    }
 
-            // All good, return the signature.
-            Some(Signature { R, z })
+    impl RepairMessage {
+
+        /// Encoded message length (in bytes).
+        pub const ENC_LEN: usize = 3 * NS;
+
+        /// Encodes this message into bytes.
+        ///
+        /// The encoded message contains a secret value, and must be sent
+        /// only over a confidential channel.
+        pub fn encode(self) -> [u8; Self::ENC_LEN] {
+            let mut buf = [0u8; Self::ENC_LEN];
+            buf[0..NS].copy_from_slice(&scalar_encode(self.sender));
+            buf[NS..2 * NS].copy_from_slice(&scalar_encode(self.receiver));
+            buf[2 * NS..3 * NS].copy_from_slice(&scalar_encode(self.value));
+            buf
+        }
+
+        /// Decodes a message from bytes.
+        ///
+        /// The process fails (i.e. returns `None`) if the source slice
+        /// does not have a proper length or does not contain properly
+        /// canonical encodings of the (non-zero) sender and receiver
+        /// identifiers, and the value.
+        pub fn decode(buf: &[u8]) -> Option<Self> {
+            if buf.len() != Self::ENC_LEN {
+                return None;
+            }
+            let sender = scalar_decode(&buf[0..NS])?;
+            let receiver = scalar_decode(&buf[NS..2 * NS])?;
+            if sender.iszero() != 0 || receiver.iszero() != 0 {
+                return None;
+            }
+            let value = scalar_decode(&buf[2 * NS..3 * NS])?;
+            Some(Self { sender, receiver, value })
         }
     }
 
@@ -1175,13 +2560,24 @@ macro_rules! define_frost_core { () => {
         }
         assert!(ff);
 
-        // Compute the coefficient.
+        derive_interpolating_value_at(x, L, Scalar::ZERO)
+    }
+
+    /// Derive the Lagrange interpolation coefficient for a given scalar x,
+    /// and a set of x-coordinates, for evaluation at point `at` (instead
+    /// of zero).
+    ///
+    /// The caller is responsible for checking that `x` is part of `L`,
+    /// and that `L` contains no duplicates.
+    fn derive_interpolating_value_at(x: Scalar, L: &[Scalar], at: Scalar)
+        -> Scalar
+    {
         let mut numerator = Scalar::ONE;
         let mut denominator = Scalar::ONE;
         let xi = x;
         for xj in L.iter() {
             if xi.equals(*xj) == 0 {
-                numerator *= xj;
+                numerator *= xj - at;
                 denominator *= xj - xi;
             }
         }
@@ -1206,6 +2602,159 @@ macro_rules! define_frost_core { () => {
         (group_commitment, z)
     }
 
+    /// Computes the challenge for the DKG proof of knowledge.
+    fn dkg_challenge(ident: Scalar, phi0: Point, R: Point) -> Scalar {
+        HDKG(&scalar_encode(ident), &point_encode(phi0), &point_encode(R))
+    }
+
+    /// Converts an identifier into the corresponding index (1 to
+    /// `max_signers`, inclusive); `None` is returned if the identifier
+    /// is not in that range.
+    /// Note: this is not constant-time.
+    fn ident_to_index(ident: Scalar, max_signers: usize) -> Option<usize> {
+        let xb = scalar_encode_le(ident);
+        if xb[8..].iter().any(|&b| b != 0) {
+            return None;
+        }
+        let mut tb = [0u8; 8];
+        tb.copy_from_slice(&xb[..8]);
+        let i = u64::from_le_bytes(tb);
+        if i == 0 || i > (max_signers as u64) {
+            return None;
+        }
+        Some(i as usize)
+    }
+
+    /// Evaluates a polynomial (given by its coefficients, constant term
+    /// first) for a given scalar.
+    fn poly_eval(coefficients: &[Scalar], x: Scalar) -> Scalar {
+        let n = coefficients.len();
+        let mut y = coefficients[n - 1];
+        for j in (0..(n - 1)).rev() {
+            y = (y * x) + coefficients[j];
+        }
+        y
+    }
+
+    /// Evaluates a VSS commitment for a given identifier; this yields
+    /// the public key corresponding to the share for that identifier.
+    fn vss_eval(vsscomm: &[VSSElement], x: Scalar) -> Point {
+        let mut Q = vsscomm[0].0;
+        let mut z = x;
+        for v in vsscomm[1..].iter() {
+            Q += v.0 * z;
+            z *= x;
+        }
+        Q
+    }
+
+    /// Collects broadcast messages from all participants (identifiers 1
+    /// to `max_signers`) other than `own_ident`, and returns them ordered
+    /// by ascending identifier. The participant's own message may be
+    /// included in the source list, in which case `is_own()` must accept
+    /// it; all other messages must be accepted by `is_valid()`. If any
+    /// message is missing, duplicated, or rejected, then `None` is
+    /// returned.
+    fn collect_broadcast<M: Clone>(msgs: &[M], own_ident: Scalar,
+        max_signers: usize, ident: impl Fn(&M) -> Scalar,
+        is_own: impl Fn(&M) -> bool, is_valid: impl Fn(&M) -> bool)
+        -> Option<Vec<M>>
+    {
+        let own = ident_to_index(own_ident, max_signers)?;
+        let mut slots: Vec<Option<M>> = Vec::with_capacity(max_signers);
+        for _ in 0..max_signers {
+            slots.push(None);
+        }
+        for m in msgs.iter() {
+            let i = ident_to_index(ident(m), max_signers)?;
+            if i == own {
+                if !is_own(m) {
+                    return None;
+                }
+                continue;
+            }
+            if slots[i - 1].is_some() || !is_valid(m) {
+                return None;
+            }
+            slots[i - 1] = Some(m.clone());
+        }
+        let mut r: Vec<M> = Vec::with_capacity(max_signers - 1);
+        for (i, m) in slots.into_iter().enumerate() {
+            if i + 1 != own {
+                r.push(m?);
+            }
+        }
+        Some(r)
+    }
+
+    /// Finds the unique message with the given identifier in a list; if
+    /// there is no such message, or more than one, then `None` is returned.
+    fn find_unique<M>(msgs: &[M], id: Scalar, ident: impl Fn(&M) -> Scalar)
+        -> Option<&M>
+    {
+        let mut r = None;
+        for m in msgs.iter() {
+            if ident(m).equals(id) != 0 {
+                if r.is_some() {
+                    return None;
+                }
+                r = Some(m);
+            }
+        }
+        r
+    }
+
+    /// Checks that a list of identifiers is non-empty, contains only
+    /// non-zero values, and is sorted in strictly ascending order.
+    /// Note: this is not constant-time.
+    fn check_ident_list(L: &[Scalar]) -> bool {
+        if L.is_empty() {
+            return false;
+        }
+        for i in 0..L.len() {
+            if L[i].iszero() != 0 {
+                return false;
+            }
+            if i > 0 && scalar_cmp_vartime(L[i - 1], L[i]) != Ordering::Less {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Tells whether a list of identifiers contains a given identifier.
+    fn contains_ident(L: &[Scalar], id: Scalar) -> bool {
+        L.iter().any(|x| x.equals(id) != 0)
+    }
+
+    /// Tells whether a list of signature shares contains a share for a
+    /// given identifier.
+    fn contains_ident_share(L: &[SignatureShare], id: Scalar) -> bool {
+        L.iter().any(|x| x.ident.equals(id) != 0)
+    }
+
+    /// Gets the next `n` bytes from a source buffer, at offset `*off`
+    /// (which is updated); `None` is returned if there are not enough
+    /// bytes.
+    fn take_bytes<'a>(buf: &'a [u8], off: &mut usize, n: usize)
+        -> Option<&'a [u8]>
+    {
+        if n > buf.len() - *off {
+            return None;
+        }
+        let r = &buf[*off..*off + n];
+        *off += n;
+        Some(r)
+    }
+
+    /// Decodes a 32-bit integer (little-endian) from a source buffer,
+    /// at offset `*off` (which is updated).
+    fn take_u32(buf: &[u8], off: &mut usize) -> Option<u32> {
+        let mut tb = [0u8; 4];
+        tb.copy_from_slice(take_bytes(buf, off, 4)?);
+        Some(u32::from_le_bytes(tb))
+    }
+
     /// Compare scalars numerically. For comparison purposes, scalars are
     /// converted to their unique integer representative in the 0 to p-1
     /// range (for a scalar modulus p).
@@ -1232,7 +2781,11 @@ macro_rules! define_frost_tests { () => {
 
     use super::{GroupPrivateKey, GroupPublicKey, KeySplitter, VSSElement};
     use super::{SignerPrivateKeyShare, SignerPublicKey};
+    use super::{DkgParticipant, DkgRound1Message, DkgRound2Message};
+    use super::{RefreshParticipant, RefreshRound1Message};
+    use super::{ReshareDealer, ReshareRound1Message, RepairMessage};
     use super::{Nonce, Commitment, SignatureShare, Signature, Coordinator};
+    use super::SigningSession;
     use super::{Point, Scalar, scalar_cmp_vartime};
     use super::{compute_binding_factors, point_decode, scalar_decode};
     use crate::{CryptoRng, RngCore, RngError};
@@ -1463,6 +3016,423 @@ macro_rules! define_frost_tests { () => {
         }
     }
 
+    // Computes a signature with the provided signers (exactly as many as
+    // the threshold), and verifies it.
+    fn sign_check(rng: &mut DRNG, signers: &[SignerPrivateKeyShare],
+        signer_public_keys: &[SignerPublicKey], group_pk: GroupPublicKey)
+    {
+        let msg: &[u8] = b"sample";
+        let mut nonces: Vec<(Nonce, Commitment)> = Vec::new();
+        let mut comms: Vec<Commitment> = Vec::new();
+        for ssk in signers.iter() {
+            let (nonce, comm) = ssk.commit(rng);
+            nonces.push((nonce, comm));
+            comms.push(comm);
+        }
+        let coor = Coordinator::new(signers.len(), group_pk).unwrap();
+        let comms = coor.choose(&comms).unwrap();
+        let mut sig_shares: Vec<SignatureShare> = Vec::new();
+        for (ssk, (nonce, comm)) in signers.iter().zip(nonces.iter()) {
+            sig_shares.push(ssk.sign(*nonce, *comm, msg, &comms).unwrap());
+        }
+        let sig = coor.assemble_signature(
+            &sig_shares, &comms, signer_public_keys, msg).unwrap();
+        assert!(group_pk.verify(sig, msg));
+        assert!(!group_pk.verify(sig, b"not the same message"));
+    }
+
+    fn test_dkg(min_signers: usize, max_signers: usize) {
+        let mut rng = DRNG::from_seed(
+            &((min_signers + (max_signers << 16)) as u32 + 0x80000000)
+            .to_le_bytes());
+
+        // Round 1: all participants broadcast their commitments. Messages
+        // go through encoding and decoding.
+        let mut parts: Vec<DkgParticipant> = Vec::new();
+        let mut round1_msgs: Vec<DkgRound1Message> = Vec::new();
+        for i in 1..=max_signers {
+            let (p, m1) = DkgParticipant::new(
+                &mut rng, i, min_signers, max_signers);
+            assert!(p.ident.equals(Scalar::from_u64(i as u64)) != 0);
+            let em1 = m1.encode();
+            let m2 = DkgRound1Message::decode(&em1).unwrap();
+            assert!(m2.encode() == em1);
+            assert!(m2.get_commitment().len() == min_signers);
+            assert!(m2.verify_proof());
+            parts.push(p);
+            round1_msgs.push(m2);
+        }
+
+        // A forged proof of knowledge is detected.
+        let mut bad1 = round1_msgs.clone();
+        bad1[0].proof_mu += Scalar::ONE;
+        assert!(!bad1[0].verify_proof());
+        assert!(parts[1].round2(&bad1).is_none());
+        assert!(parts[0].round2(&round1_msgs[1..]).is_some());
+        assert!(parts[1].round2(&round1_msgs[1..]).is_none());
+
+        // Round 2: each participant sends a share to every other
+        // participant.
+        let mut inbox: Vec<Vec<DkgRound2Message>> = Vec::new();
+        for _ in 0..max_signers {
+            inbox.push(Vec::new());
+        }
+        for p in parts.iter() {
+            let r2 = p.round2(&round1_msgs).unwrap();
+            assert!(r2.len() == max_signers - 1);
+            for m in r2.iter() {
+                assert!(m.sender.equals(p.ident) != 0);
+                let m2 = DkgRound2Message::decode(&m.encode()).unwrap();
+                let j = (0..max_signers).find(|&j|
+                    parts[j].ident.equals(m2.receiver) != 0).unwrap();
+                inbox[j].push(m2);
+            }
+        }
+
+        // A bad share is detected, as well as a missing one.
+        let mut bad2 = inbox[0].clone();
+        bad2[0].share += Scalar::ONE;
+        assert!(parts[0].clone().finish(&round1_msgs, &bad2).is_none());
+        assert!(parts[0].clone().finish(
+            &round1_msgs, &inbox[0][1..]).is_none());
+
+        // Finish the DKG; all participants must agree on the group
+        // public key and the group VSS commitment.
+        let mut sk_shares: Vec<SignerPrivateKeyShare> = Vec::new();
+        let mut vss: Vec<VSSElement> = Vec::new();
+        let mut group_pk = None;
+        for (p, ib) in parts.into_iter().zip(inbox.iter()) {
+            let (ssk, gpk, v) = p.finish(&round1_msgs, ib).unwrap();
+            assert!(v.len() == min_signers);
+            assert!(ssk.verify_split(&v));
+            assert!(ssk.group_pk.pk_enc == gpk.pk_enc);
+            match group_pk {
+                None => {
+                    group_pk = Some(gpk);
+                    vss = v;
+                }
+                Some(gpk2) => {
+                    assert!(gpk2.pk_enc == gpk.pk_enc);
+                    assert!(VSSElement::encode_list(&v)
+                        == VSSElement::encode_list(&vss));
+                }
+            }
+            let ssk2 = SignerPrivateKeyShare::decode(&ssk.encode()).unwrap();
+            sk_shares.push(ssk2);
+        }
+        let group_pk = group_pk.unwrap();
+        let (signer_public_keys, gpk2) =
+            KeySplitter::derive_group_info(max_signers, vss);
+        assert!(gpk2.pk_enc == group_pk.pk_enc);
+        for (spk, ssk) in signer_public_keys.iter().zip(sk_shares.iter()) {
+            assert!(spk.ident.equals(ssk.ident) != 0);
+            assert!(spk.pk.equals(ssk.pk) != 0);
+        }
+
+        // Sign with the last min_signers signers.
+        sign_check(&mut rng, &sk_shares[(max_signers - min_signers)..],
+            &signer_public_keys, group_pk);
+    }
+
+    #[test]
+    fn dkg() {
+        for max_signers in 2..6 {
+            for min_signers in 2..=max_signers {
+                test_dkg(min_signers, max_signers);
+            }
+        }
+    }
+
+    fn test_refresh_repair(min_signers: usize, max_signers: usize) {
+        let mut rng = DRNG::from_seed(
+            &((min_signers + (max_signers << 16)) as u32 + 0x40000000)
+            .to_le_bytes());
+        let group_sk = GroupPrivateKey::generate(&mut rng);
+        let group_pk = group_sk.get_public_key();
+        let (sk_shares, vss) = KeySplitter::trusted_split(
+            &mut rng, group_sk, min_signers, max_signers);
+
+        // Share refresh, with all messages going through encoding and
+        // decoding.
+        let mut parts: Vec<RefreshParticipant> = Vec::new();
+        let mut round1_msgs: Vec<RefreshRound1Message> = Vec::new();
+        for ssk in sk_shares.iter() {
+            let (p, m1) = RefreshParticipant::new(
+                &mut rng, *ssk, min_signers, max_signers);
+            let m2 = RefreshRound1Message::decode(&m1.encode()).unwrap();
+            assert!(m2.encode() == m1.encode());
+            assert!(m2.full_commitment().len() == min_signers);
+            parts.push(p);
+            round1_msgs.push(m2);
+        }
+        let mut inbox: Vec<Vec<DkgRound2Message>> = Vec::new();
+        for _ in 0..max_signers {
+            inbox.push(Vec::new());
+        }
+        for p in parts.iter() {
+            for m in p.round2(&round1_msgs).unwrap().iter() {
+                let m2 = DkgRound2Message::decode(&m.encode()).unwrap();
+                let j = (0..max_signers).find(|&j|
+                    sk_shares[j].ident.equals(m2.receiver) != 0).unwrap();
+                inbox[j].push(m2);
+            }
+        }
+        let mut bad = inbox[0].clone();
+        bad[0].share += Scalar::ONE;
+        assert!(parts[0].clone().finish(&round1_msgs, &bad, &vss).is_none());
+        assert!(parts[0].clone().finish(
+            &round1_msgs, &inbox[0], &vss[1..]).is_none());
+        let mut new_shares: Vec<SignerPrivateKeyShare> = Vec::new();
+        let mut new_vss: Vec<VSSElement> = Vec::new();
+        for (p, ib) in parts.into_iter().zip(inbox.iter()) {
+            let (ssk, v) = p.finish(&round1_msgs, ib, &vss).unwrap();
+            assert!(v[0].0.equals(group_pk.pk) != 0);
+            if new_vss.is_empty() {
+                new_vss = v;
+            } else {
+                assert!(VSSElement::encode_list(&v)
+                    == VSSElement::encode_list(&new_vss));
+            }
+            new_shares.push(ssk);
+        }
+        for (ssk, old) in new_shares.iter().zip(sk_shares.iter()) {
+            assert!(ssk.ident.equals(old.ident) != 0);
+            assert!(ssk.sk.equals(old.sk) == 0);
+            assert!(ssk.group_pk.pk_enc == group_pk.pk_enc);
+            assert!(ssk.verify_split(&new_vss));
+            assert!(!old.verify_split(&new_vss));
+        }
+        let (signer_public_keys, gpk2) =
+            KeySplitter::derive_group_info(max_signers, new_vss.clone());
+        assert!(gpk2.pk_enc == group_pk.pk_enc);
+        sign_check(&mut rng, &new_shares[(max_signers - min_signers)..],
+            &signer_public_keys, group_pk);
+
+        // Repair the first share, and enroll a new participant, using
+        // the last min_signers signers as helpers.
+        let helper_shares = &new_shares[(max_signers - min_signers)..];
+        let helpers: Vec<Scalar> = helper_shares.iter()
+            .map(|s| s.ident).collect();
+        let lost = new_shares[0];
+        let new_ident = Scalar::from_u64((max_signers as u64) + 1);
+        for target in [lost.ident, new_ident] {
+            if helpers.iter().any(|h| h.equals(target) != 0) {
+                assert!(helper_shares[0].repair_step1(
+                    &mut rng, &helpers, target).is_none());
+                continue;
+            }
+            let mut inbox: Vec<Vec<RepairMessage>> = Vec::new();
+            for _ in 0..helpers.len() {
+                inbox.push(Vec::new());
+            }
+            for h in helper_shares.iter() {
+                let ms = h.repair_step1(&mut rng, &helpers, target).unwrap();
+                assert!(ms.len() == helpers.len());
+                for (j, m) in ms.iter().enumerate() {
+                    inbox[j].push(RepairMessage::decode(&m.encode()).unwrap());
+                }
+            }
+            let mut msgs: Vec<RepairMessage> = Vec::new();
+            for (h, ib) in helper_shares.iter().zip(inbox.iter()) {
+                assert!(h.repair_step2(&helpers, target, &ib[1..]).is_none());
+                msgs.push(h.repair_step2(&helpers, target, ib).unwrap());
+            }
+            let rs = SignerPrivateKeyShare::repair(
+                target, &helpers, &msgs, &new_vss).unwrap();
+            assert!(rs.ident.equals(target) != 0);
+            assert!(rs.group_pk.pk_enc == group_pk.pk_enc);
+            if target.equals(lost.ident) != 0 {
+                assert!(rs.sk.equals(lost.sk) != 0);
+            }
+            let mut bad = msgs.clone();
+            bad[0].value += Scalar::ONE;
+            assert!(SignerPrivateKeyShare::repair(
+                target, &helpers, &bad, &new_vss).is_none());
+            assert!(SignerPrivateKeyShare::repair(
+                target, &helpers, &msgs[1..], &new_vss).is_none());
+        }
+
+        // Reshare towards a larger group with a higher threshold, using
+        // the first min_signers signers as dealers.
+        let new_min = min_signers + 1;
+        let new_max = max_signers + 1;
+        let dealer_shares = &new_shares[..min_signers];
+        let dealers: Vec<Scalar> = dealer_shares.iter()
+            .map(|s| s.ident).collect();
+        let mut round1_msgs: Vec<ReshareRound1Message> = Vec::new();
+        let mut inbox: Vec<Vec<DkgRound2Message>> = Vec::new();
+        for _ in 0..new_max {
+            inbox.push(Vec::new());
+        }
+        for ds in dealer_shares.iter() {
+            let (d, m1) = ReshareDealer::new(
+                &mut rng, *ds, &dealers, new_min, new_max).unwrap();
+            let m2 = ReshareRound1Message::decode(&m1.encode()).unwrap();
+            assert!(m2.get_commitment().len() == new_min);
+            round1_msgs.push(m2);
+            for (j, m) in d.round2().iter().enumerate() {
+                inbox[j].push(DkgRound2Message::decode(&m.encode()).unwrap());
+            }
+        }
+        assert!(ReshareDealer::new(&mut rng, new_shares[max_signers - 1],
+            &dealers[..1], new_min, new_max).is_none());
+        assert!(KeySplitter::reshare_finish(1, new_min, new_max, &new_vss,
+            &dealers[1..], &round1_msgs[1..], &inbox[0][1..]).is_none());
+        let mut bad = inbox[0].clone();
+        bad[0].share += Scalar::ONE;
+        assert!(KeySplitter::reshare_finish(1, new_min, new_max, &new_vss,
+            &dealers, &round1_msgs, &bad).is_none());
+        let mut reshared: Vec<SignerPrivateKeyShare> = Vec::new();
+        let mut reshared_vss: Vec<VSSElement> = Vec::new();
+        for (i, ib) in inbox.iter().enumerate() {
+            let (ssk, v) = KeySplitter::reshare_finish(i + 1,
+                new_min, new_max, &new_vss, &dealers, &round1_msgs, ib)
+                .unwrap();
+            assert!(v.len() == new_min);
+            assert!(ssk.group_pk.pk_enc == group_pk.pk_enc);
+            if reshared_vss.is_empty() {
+                reshared_vss = v;
+            } else {
+                assert!(VSSElement::encode_list(&v)
+                    == VSSElement::encode_list(&reshared_vss));
+            }
+            reshared.push(ssk);
+        }
+        let (signer_public_keys, gpk2) =
+            KeySplitter::derive_group_info(new_max, reshared_vss);
+        assert!(gpk2.pk_enc == group_pk.pk_enc);
+        sign_check(&mut rng, &reshared[(new_max - new_min)..],
+            &signer_public_keys, group_pk);
+    }
+
+    #[test]
+    fn refresh_repair() {
+        for max_signers in 2..6 {
+            for min_signers in 2..=max_signers {
+                test_refresh_repair(min_signers, max_signers);
+            }
+        }
+    }
+
+    #[test]
+    fn signing_session() {
+        let mut rng = DRNG::from_seed(b"signing_session");
+        let (min_signers, max_signers) = (3, 5);
+        let group_sk = GroupPrivateKey::generate(&mut rng);
+        let group_pk = group_sk.get_public_key();
+        let (sk_shares, vss) = KeySplitter::trusted_split(
+            &mut rng, group_sk, min_signers, max_signers);
+        let (signer_public_keys, _) =
+            KeySplitter::derive_group_info(max_signers, vss);
+        let msg: &[u8] = b"sample";
+
+        // The session goes through encoding and decoding between all
+        // steps, as it would in a stateless coordinator.
+        fn reload(sess: SigningSession) -> SigningSession {
+            let enc = sess.encode();
+            assert!(SigningSession::decode(&enc[..enc.len() - 1]).is_none());
+            let sess2 = SigningSession::decode(&enc).unwrap();
+            assert!(sess2.encode() == enc);
+            sess2
+        }
+
+        let coor = Coordinator::new(min_signers, group_pk).unwrap();
+        let mut sess = SigningSession::new(coor, msg);
+        let mut nonces: Vec<Option<Nonce>> = Vec::new();
+        for ssk in sk_shares.iter() {
+            let (nonce, comm) = ssk.commit(&mut rng);
+            nonces.push(Some(nonce));
+            assert!(sess.add_commitment(comm));
+            sess = reload(sess);
+        }
+        assert!(sess.finish(&signer_public_keys).is_err());
+
+        // First attempt: one of the chosen signers cheats.
+        let comms = sess.choose().unwrap();
+        assert!(comms.len() == min_signers);
+        assert!(sess.choose().is_none());
+        sess = reload(sess);
+        let cheater = comms[1].ident;
+        for c in comms.iter() {
+            let i = (0..max_signers).find(|&i|
+                sk_shares[i].ident.equals(c.ident) != 0).unwrap();
+            let (n2, c2) = sk_shares[i].commit(&mut rng);
+            assert!(!sess.add_commitment(c2));
+            let nonce = nonces[i].take().unwrap();
+            nonces[i] = Some(n2);
+            let mut ss = sk_shares[i].sign(
+                nonce, *c, msg, sess.get_chosen()).unwrap();
+            if c.ident.equals(cheater) != 0 {
+                ss.zi += Scalar::ONE;
+            } else {
+                match sess.finish(&signer_public_keys) {
+                    Err(br) => {
+                        assert!(br.cheaters.is_empty());
+                        assert!(!br.missing.is_empty());
+                    }
+                    Ok(_) => panic!(),
+                }
+            }
+            assert!(sess.add_share(ss));
+            assert!(sess.add_share(ss));
+            sess = reload(sess);
+        }
+        assert!(sess.missing().is_empty());
+
+        // A missing public key is reported, but nobody is blamed and the
+        // session is unchanged.
+        let partial_keys: Vec<SignerPublicKey> = signer_public_keys.iter()
+            .filter(|spk| spk.ident.equals(comms[0].ident) == 0)
+            .cloned().collect();
+        match sess.finish(&partial_keys) {
+            Err(br) => {
+                assert!(br.missing.is_empty());
+                assert!(br.cheaters.is_empty());
+                assert!(br.unknown.len() == 1);
+                assert!(br.unknown[0].equals(comms[0].ident) != 0);
+            }
+            Ok(_) => panic!(),
+        }
+        assert!(sess.get_chosen().len() == min_signers);
+        assert!(sess.get_blamed().is_empty());
+
+        match sess.finish(&signer_public_keys) {
+            Err(br) => {
+                assert!(br.missing.is_empty());
+                assert!(br.unknown.is_empty());
+                assert!(br.cheaters.len() == 1);
+                assert!(br.cheaters[0].equals(cheater) != 0);
+            }
+            Ok(_) => panic!(),
+        }
+        assert!(sess.get_chosen().is_empty());
+        assert!(sess.get_blamed().len() == 1);
+        sess = reload(sess);
+
+        // Second attempt: honest signers send fresh commitments; the
+        // cheater is excluded.
+        for (i, ssk) in sk_shares.iter().enumerate() {
+            let comm = nonces[i].unwrap().get_commitment();
+            assert!(sess.add_commitment(comm) == (ssk.ident.equals(cheater) == 0));
+        }
+        let comms = sess.choose().unwrap();
+        for c in comms.iter() {
+            assert!(c.ident.equals(cheater) == 0);
+            let i = (0..max_signers).find(|&i|
+                sk_shares[i].ident.equals(c.ident) != 0).unwrap();
+            let ss = sk_shares[i].sign(nonces[i].unwrap(), *c, msg,
+                sess.get_chosen()).unwrap();
+            assert!(sess.add_share(ss));
+            sess = reload(sess);
+        }
+        let sig = sess.finish(&signer_public_keys).unwrap();
+        assert!(group_pk.verify(sig, msg));
+        sess = reload(sess);
+        assert!(sess.get_signature().unwrap().encode() == sig.encode());
+        assert!(sess.finish(&signer_public_keys).unwrap().encode()
+            == sig.encode());
+    }
+
     // A pretend RNG for test purposes (deterministic engine that returns
     // a preset stream of 64 bytes).
     struct R64RNG {
@@ -1722,6 +3692,16 @@ pub mod ed25519 {
         Scalar::decode_reduce(&sh.finalize())
     }
 
+    fn HDKG(ident_enc: &[u8], phi0_enc: &[u8], R_enc: &[u8]) -> Scalar {
+        let mut sh = Sha512::new();
+        sh.update(CONTEXT_STRING);
+        sh.update(b"dkg");
+        sh.update(ident_enc);
+        sh.update(phi0_enc);
+        sh.update(R_enc);
+        Scalar::decode_reduce(&sh.finalize())
+    }
+
     #[cfg(test)]
     mod tests {
 
@@ -1876,6 +3856,16 @@ pub mod ristretto255 {
         Scalar::decode_reduce(&sh.finalize())
     }
 
+    fn HDKG(ident_enc: &[u8], phi0_enc: &[u8], R_enc: &[u8]) -> Scalar {
+        let mut sh = Sha512::new();
+        sh.update(CONTEXT_STRING);
+        sh.update(b"dkg");
+        sh.update(ident_enc);
+        sh.update(phi0_enc);
+        sh.update(R_enc);
+        Scalar::decode_reduce(&sh.finalize())
+    }
+
     #[cfg(test)]
     mod tests {
 
@@ -2039,6 +4029,18 @@ pub mod ed448 {
         Scalar::decode_reduce(&buf)
     }
 
+    fn HDKG(ident_enc: &[u8], phi0_enc: &[u8], R_enc: &[u8]) -> Scalar {
+        let mut sh = Shake256::default();
+        sh.update(CONTEXT_STRING);
+        sh.update(b"dkg");
+        sh.update(ident_enc);
+        sh.update(phi0_enc);
+        sh.update(R_enc);
+        let mut buf = [0u8; 114];
+        sh.finalize_xof().read(&mut buf);
+        Scalar::decode_reduce(&buf)
+    }
+
     #[cfg(test)]
     mod tests {
 
@@ -2238,6 +4240,10 @@ pub mod p256 {
         expand_message_xmd(b"single-signer", pk_enc, sk_enc, seed, msg)
     }
 
+    fn HDKG(ident_enc: &[u8], phi0_enc: &[u8], R_enc: &[u8]) -> Scalar {
+        expand_message_xmd(b"dkg", ident_enc, phi0_enc, R_enc, &U8_EMPTY)
+    }
+
     #[cfg(test)]
     mod tests {
 
@@ -2421,6 +4427,10 @@ pub mod secp256k1 {
         expand_message_xmd(b"single-signer", pk_enc, sk_enc, seed, msg)
     }
 
+    fn HDKG(ident_enc: &[u8], phi0_enc: &[u8], R_enc: &[u8]) -> Scalar {
+        expand_message_xmd(b"dkg", ident_enc, phi0_enc, R_enc, &U8_EMPTY)
+    }
+
     #[cfg(test)]
     mod tests {
 
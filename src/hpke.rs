@@ -0,0 +1,2045 @@
+//! HPKE implementation.
+//!
+//! This follows RFC 9180 (Hybrid Public Key Encryption). All four modes
+//! are supported (base, psk, auth and auth_psk); the mode is selected by
+//! the optional pre-shared key and sender key parameters of the setup
+//! functions. A cipher suite is a combination of three types:
+//!
+//!  - A KEM, implementing the `Kem` trait: `DhKemX25519`, `DhKemX448`
+//!    and `DhKemP256` are the standard DHKEMs. `DhKemJq255e` and
+//!    `DhKemGls254` are non-standard KEMs built on the `ECDH()` functions
+//!    of jq255e and GLS254, with experimental KEM identifiers (0xFF01 and
+//!    0xFF02) that are not registered with IANA; they are meant for
+//!    experiments and benchmarks only.
+//!
+//!  - A KDF, implementing the `Kdf` trait: `HkdfSha256`, `HkdfSha384`
+//!    or `HkdfSha512`.
+//!
+//!  - An AEAD, implementing the `Aead` trait: `ChaCha20Poly1305`,
+//!    `Aes128Gcm` and `Aes256Gcm` (with the `aes` feature), or
+//!    `ExportOnly` for the export-only mode (no encryption).
+//!
+//! Setup functions return a `Context`, which is used for encryption
+//! (sender) or decryption (recipient) of successive messages, and for
+//! exporting secrets. Encryption and decryption work in place, with
+//! detached tags; `seal()` and `open()` variants that allocate the output
+//! are also provided when the `alloc` feature is enabled.
+
+#![allow(non_snake_case)]
+
+use core::marker::PhantomData;
+use digest::Digest;
+use digest::core_api::BlockSizeUser;
+use super::{CryptoRng, RngCore};
+use super::kdf::Hkdf;
+
+#[cfg(feature = "alloc")]
+use crate::Vec;
+
+// Maximum sizes (in bytes) of encoded public keys, DH outputs, and
+// hash function outputs.
+const MAX_PK_LEN: usize = 65;
+const MAX_DH_LEN: usize = 56;
+const MAX_HASH_LEN: usize = 64;
+
+/// Mode identifiers.
+pub const MODE_BASE: u8 = 0x00;
+pub const MODE_PSK: u8 = 0x01;
+pub const MODE_AUTH: u8 = 0x02;
+pub const MODE_AUTH_PSK: u8 = 0x03;
+
+// LabeledExtract() from RFC 9180: the returned context contains the
+// extracted secret.
+fn labeled_extract<D: Digest + BlockSizeUser + Clone>(suite_id: &[u8],
+    salt: &[u8], label: &[u8], ikm: &[u8]) -> Hkdf<D>
+{
+    Hkdf::<D>::new_multi(salt, &[b"HPKE-v1", suite_id, label, ikm])
+}
+
+// LabeledExpand() from RFC 9180; the output length is that of `out`.
+fn labeled_expand<D: Digest + BlockSizeUser + Clone>(prk: &Hkdf<D>,
+    suite_id: &[u8], label: &[u8], info: &[u8], out: &mut [u8])
+{
+    assert!(out.len() <= 0xFFFF);
+    let lb = (out.len() as u16).to_be_bytes();
+    prk.expand_multi(&[&lb, b"HPKE-v1", suite_id, label, info], out);
+}
+
+/// Key encapsulation mechanism (Diffie-Hellman based).
+///
+/// The KEM is defined over a Diffie-Hellman group; the generic DHKEM
+/// construction (encapsulation and decapsulation, with or without sender
+/// authentication) is implemented on top of the group operations
+/// defined by this trait.
+pub trait Kem {
+    /// KEM identifier.
+    const KEM_ID: u16;
+    /// Length of the KEM shared secret (in bytes).
+    const N_SECRET: usize;
+    /// Length of an encoded public key, and of an encapsulated key.
+    const N_PK: usize;
+    /// Length of an encoded private key.
+    const N_SK: usize;
+    /// Length of the Diffie-Hellman output.
+    const N_DH: usize;
+
+    /// Hash function used by the KEM (with HKDF).
+    type Hash: Digest + BlockSizeUser + Clone;
+    /// Private key type.
+    type PrivateKey: Clone;
+    /// Public key type.
+    type PublicKey: Clone;
+    /// Encoded public key type (`N_PK` bytes).
+    type EncodedPublicKey: AsRef<[u8]> + Copy;
+    /// Encoded private key type (`N_SK` bytes).
+    type EncodedPrivateKey: AsRef<[u8]> + Copy;
+
+    /// Deterministically derive a key pair from the input key material
+    /// (`DeriveKeyPair()`). The input should have at least `N_SK` bytes
+    /// of entropy.
+    fn derive_key_pair(ikm: &[u8]) -> (Self::PrivateKey, Self::PublicKey);
+
+    /// Generate a new key pair, from a random source.
+    fn generate_key_pair<T: CryptoRng + RngCore>(rng: &mut T)
+        -> (Self::PrivateKey, Self::PublicKey)
+    {
+        let mut ikm = [0u8; 64];
+        rng.fill_bytes(&mut ikm[..Self::N_SK]);
+        Self::derive_key_pair(&ikm[..Self::N_SK])
+    }
+
+    /// Get the public key corresponding to a private key.
+    fn public_key(sk: &Self::PrivateKey) -> Self::PublicKey;
+
+    /// Encode a public key (`SerializePublicKey()`).
+    fn encode_public_key(pk: &Self::PublicKey) -> Self::EncodedPublicKey;
+
+    /// Decode a public key (`DeserializePublicKey()`); `None` is returned
+    /// if the encoding is invalid.
+    fn decode_public_key(buf: &[u8]) -> Option<Self::PublicKey>;
+
+    /// Encode a private key (`SerializePrivateKey()`).
+    fn encode_private_key(sk: &Self::PrivateKey) -> Self::EncodedPrivateKey;
+
+    /// Decode a private key (`DeserializePrivateKey()`); `None` is
+    /// returned if the encoding is invalid.
+    fn decode_private_key(buf: &[u8]) -> Option<Self::PrivateKey>;
+
+    /// Diffie-Hellman operation; the output (`N_DH` bytes) is written into
+    /// `out`. Returned value is `false` if the operation failed (invalid
+    /// peer public key).
+    fn dh(sk: &Self::PrivateKey, pk: &Self::PublicKey, out: &mut [u8])
+        -> bool;
+}
+
+// Get the KEM suite identifier ("KEM" || I2OSP(kem_id, 2)).
+fn kem_suite_id<K: Kem>() -> [u8; 5] {
+    let id = K::KEM_ID.to_be_bytes();
+    [b'K', b'E', b'M', id[0], id[1]]
+}
+
+// Common DeriveKeyPair() for X25519 and X448: the private key is obtained
+// directly from LabeledExpand().
+fn derive_sk_bytes<K: Kem>(ikm: &[u8], label: &[u8], ctr: Option<u8>,
+    out: &mut [u8])
+{
+    let sid = kem_suite_id::<K>();
+    let dkp_prk = labeled_extract::<K::Hash>(&sid, b"", b"dkp_prk", ikm);
+    match ctr {
+        Some(c) => labeled_expand(&dkp_prk, &sid, label, &[c], out),
+        None => labeled_expand(&dkp_prk, &sid, label, b"", out),
+    }
+}
+
+// ExtractAndExpand() from the DHKEM definition.
+fn extract_and_expand<K: Kem>(dh: &[u8], kem_context: &[&[u8]],
+    out: &mut [u8])
+{
+    let sid = kem_suite_id::<K>();
+    let eae_prk = labeled_extract::<K::Hash>(&sid, b"", b"eae_prk", dh);
+    let mut kc = [0u8; 3 * MAX_PK_LEN];
+    let mut n = 0;
+    for x in kem_context.iter() {
+        kc[n..(n + x.len())].copy_from_slice(x);
+        n += x.len();
+    }
+    labeled_expand(&eae_prk, &sid, b"shared_secret", &kc[..n], out);
+}
+
+// Encap() or AuthEncap() with a provided ephemeral key pair. The
+// shared secret is written into `shared` (`N_SECRET` bytes).
+fn encap<K: Kem>(sk_e: &K::PrivateKey, pk_r: &K::PublicKey,
+    sk_s: Option<&K::PrivateKey>, shared: &mut [u8])
+    -> Option<K::EncodedPublicKey>
+{
+    let mut dh = [0u8; 2 * MAX_DH_LEN];
+    if !K::dh(sk_e, pk_r, &mut dh[..K::N_DH]) {
+        return None;
+    }
+    let enc = K::encode_public_key(&K::public_key(sk_e));
+    let pk_rm = K::encode_public_key(pk_r);
+    match sk_s {
+        None => {
+            extract_and_expand::<K>(&dh[..K::N_DH],
+                &[enc.as_ref(), pk_rm.as_ref()], &mut shared[..K::N_SECRET]);
+        }
+        Some(sk_s) => {
+            if !K::dh(sk_s, pk_r, &mut dh[K::N_DH..(2 * K::N_DH)]) {
+                return None;
+            }
+            let pk_sm = K::encode_public_key(&K::public_key(sk_s));
+            extract_and_expand::<K>(&dh[..(2 * K::N_DH)],
+                &[enc.as_ref(), pk_rm.as_ref(), pk_sm.as_ref()],
+                &mut shared[..K::N_SECRET]);
+        }
+    }
+    Some(enc)
+}
+
+// Decap() or AuthDecap(). The shared secret is written into `shared`
+// (`N_SECRET` bytes).
+fn decap<K: Kem>(enc: &[u8], sk_r: &K::PrivateKey,
+    pk_s: Option<&K::PublicKey>, shared: &mut [u8]) -> bool
+{
+    let pk_e = match K::decode_public_key(enc) {
+        Some(pk) => pk,
+        None => return false,
+    };
+    let mut dh = [0u8; 2 * MAX_DH_LEN];
+    if !K::dh(sk_r, &pk_e, &mut dh[..K::N_DH]) {
+        return false;
+    }
+    let pk_rm = K::encode_public_key(&K::public_key(sk_r));
+    match pk_s {
+        None => {
+            extract_and_expand::<K>(&dh[..K::N_DH],
+                &[enc, pk_rm.as_ref()], &mut shared[..K::N_SECRET]);
+        }
+        Some(pk_s) => {
+            if !K::dh(sk_r, pk_s, &mut dh[K::N_DH..(2 * K::N_DH)]) {
+                return false;
+            }
+            let pk_sm = K::encode_public_key(pk_s);
+            extract_and_expand::<K>(&dh[..(2 * K::N_DH)],
+                &[enc, pk_rm.as_ref(), pk_sm.as_ref()],
+                &mut shared[..K::N_SECRET]);
+        }
+    }
+    true
+}
+
+/// DHKEM(X25519, HKDF-SHA256).
+#[cfg(feature = "x25519")]
+pub struct DhKemX25519;
+
+#[cfg(feature = "x25519")]
+impl Kem for DhKemX25519 {
+    const KEM_ID: u16 = 0x0020;
+    const N_SECRET: usize = 32;
+    const N_PK: usize = 32;
+    const N_SK: usize = 32;
+    const N_DH: usize = 32;
+    type Hash = sha2::Sha256;
+    type PrivateKey = [u8; 32];
+    type PublicKey = [u8; 32];
+    type EncodedPublicKey = [u8; 32];
+    type EncodedPrivateKey = [u8; 32];
+
+    fn derive_key_pair(ikm: &[u8]) -> ([u8; 32], [u8; 32]) {
+        let mut sk = [0u8; 32];
+        derive_sk_bytes::<Self>(ikm, b"sk", None, &mut sk);
+        (sk, Self::public_key(&sk))
+    }
+
+    fn public_key(sk: &[u8; 32]) -> [u8; 32] {
+        super::x25519::x25519_base(sk)
+    }
+
+    fn encode_public_key(pk: &[u8; 32]) -> [u8; 32] {
+        *pk
+    }
+
+    fn decode_public_key(buf: &[u8]) -> Option<[u8; 32]> {
+        if buf.len() != 32 {
+            return None;
+        }
+        let mut pk = [0u8; 32];
+        pk.copy_from_slice(buf);
+        Some(pk)
+    }
+
+    fn encode_private_key(sk: &[u8; 32]) -> [u8; 32] {
+        *sk
+    }
+
+    fn decode_private_key(buf: &[u8]) -> Option<[u8; 32]> {
+        Self::decode_public_key(buf)
+    }
+
+    fn dh(sk: &[u8; 32], pk: &[u8; 32], out: &mut [u8]) -> bool {
+        let r = super::x25519::x25519(pk, sk);
+        out[..32].copy_from_slice(&r);
+        // An all-zero output (low order peer point) is rejected.
+        let mut z = 0u8;
+        for x in r.iter() {
+            z |= *x;
+        }
+        z != 0
+    }
+}
+
+/// DHKEM(X448, HKDF-SHA512).
+#[cfg(feature = "x448")]
+pub struct DhKemX448;
+
+#[cfg(feature = "x448")]
+impl Kem for DhKemX448 {
+    const KEM_ID: u16 = 0x0021;
+    const N_SECRET: usize = 64;
+    const N_PK: usize = 56;
+    const N_SK: usize = 56;
+    const N_DH: usize = 56;
+    type Hash = sha2::Sha512;
+    type PrivateKey = [u8; 56];
+    type PublicKey = [u8; 56];
+    type EncodedPublicKey = [u8; 56];
+    type EncodedPrivateKey = [u8; 56];
+
+    fn derive_key_pair(ikm: &[u8]) -> ([u8; 56], [u8; 56]) {
+        let mut sk = [0u8; 56];
+        derive_sk_bytes::<Self>(ikm, b"sk", None, &mut sk);
+        (sk, Self::public_key(&sk))
+    }
+
+    fn public_key(sk: &[u8; 56]) -> [u8; 56] {
+        super::x448::x448_base(sk)
+    }
+
+    fn encode_public_key(pk: &[u8; 56]) -> [u8; 56] {
+        *pk
+    }
+
+    fn decode_public_key(buf: &[u8]) -> Option<[u8; 56]> {
+        if buf.len() != 56 {
+            return None;
+        }
+        let mut pk = [0u8; 56];
+        pk.copy_from_slice(buf);
+        Some(pk)
+    }
+
+    fn encode_private_key(sk: &[u8; 56]) -> [u8; 56] {
+        *sk
+    }
+
+    fn decode_private_key(buf: &[u8]) -> Option<[u8; 56]> {
+        Self::decode_public_key(buf)
+    }
+
+    fn dh(sk: &[u8; 56], pk: &[u8; 56], out: &mut [u8]) -> bool {
+        let r = super::x448::x448(pk, sk);
+        out[..56].copy_from_slice(&r);
+        // An all-zero output (low order peer point) is rejected.
+        let mut z = 0u8;
+        for x in r.iter() {
+            z |= *x;
+        }
+        z != 0
+    }
+}
+
+/// DHKEM(P-256, HKDF-SHA256).
+#[cfg(feature = "p256")]
+pub struct DhKemP256;
+
+#[cfg(feature = "p256")]
+impl Kem for DhKemP256 {
+    const KEM_ID: u16 = 0x0010;
+    const N_SECRET: usize = 32;
+    const N_PK: usize = 65;
+    const N_SK: usize = 32;
+    const N_DH: usize = 32;
+    type Hash = sha2::Sha256;
+    type PrivateKey = super::p256::PrivateKey;
+    type PublicKey = super::p256::PublicKey;
+    type EncodedPublicKey = [u8; 65];
+    type EncodedPrivateKey = [u8; 32];
+
+    fn derive_key_pair(ikm: &[u8]) -> (Self::PrivateKey, Self::PublicKey) {
+        // Rejection sampling, as per RFC 9180, section 7.1.3 (the
+        // bitmask is 0xFF for P-256).
+        for ctr in 0..=255u8 {
+            let mut sk = [0u8; 32];
+            derive_sk_bytes::<Self>(ikm, b"candidate", Some(ctr), &mut sk);
+            if let Some(sk) = super::p256::PrivateKey::decode(&sk) {
+                return (sk, sk.to_public_key());
+            }
+        }
+        panic!("DeriveKeyPair failure");
+    }
+
+    fn public_key(sk: &Self::PrivateKey) -> Self::PublicKey {
+        sk.to_public_key()
+    }
+
+    fn encode_public_key(pk: &Self::PublicKey) -> [u8; 65] {
+        pk.encode_uncompressed()
+    }
+
+    fn decode_public_key(buf: &[u8]) -> Option<Self::PublicKey> {
+        // Only the uncompressed format is allowed; the point at infinity
+        // is rejected by the length check.
+        if buf.len() != 65 {
+            return None;
+        }
+        super::p256::PublicKey::decode(buf)
+    }
+
+    fn encode_private_key(sk: &Self::PrivateKey) -> [u8; 32] {
+        sk.encode()
+    }
+
+    fn decode_private_key(buf: &[u8]) -> Option<Self::PrivateKey> {
+        super::p256::PrivateKey::decode(buf)
+    }
+
+    fn dh(sk: &Self::PrivateKey, pk: &Self::PublicKey, out: &mut [u8])
+        -> bool
+    {
+        let (k, r) = sk.ECDH(&pk.encode_uncompressed());
+        out[..32].copy_from_slice(&k);
+        r != 0
+    }
+}
+
+/// Experimental (non-standard) KEM over jq255e, using
+/// `jq255e::PrivateKey::ECDH()` as the Diffie-Hellman function (with
+/// HKDF-SHA256). The KEM identifier (0xFF01) is not registered.
+#[cfg(feature = "jq255e")]
+pub struct DhKemJq255e;
+
+#[cfg(feature = "jq255e")]
+impl Kem for DhKemJq255e {
+    const KEM_ID: u16 = 0xFF01;
+    const N_SECRET: usize = 32;
+    const N_PK: usize = 32;
+    const N_SK: usize = 32;
+    const N_DH: usize = 32;
+    type Hash = sha2::Sha256;
+    type PrivateKey = super::jq255e::PrivateKey;
+    type PublicKey = super::jq255e::PublicKey;
+    type EncodedPublicKey = [u8; 32];
+    type EncodedPrivateKey = [u8; 32];
+
+    fn derive_key_pair(ikm: &[u8]) -> (Self::PrivateKey, Self::PublicKey) {
+        // We use 64 bytes for the reduction, so that the bias is
+        // negligible.
+        for ctr in 0..=255u8 {
+            let mut sk = [0u8; 64];
+            derive_sk_bytes::<Self>(ikm, b"candidate", Some(ctr), &mut sk);
+            let s = super::jq255e::Scalar::decode_reduce(&sk);
+            if s.iszero() == 0 {
+                let sk = super::jq255e::PrivateKey::from_scalar(&s);
+                return (sk, sk.public_key);
+            }
+        }
+        panic!("DeriveKeyPair failure");
+    }
+
+    fn public_key(sk: &Self::PrivateKey) -> Self::PublicKey {
+        sk.public_key
+    }
+
+    fn encode_public_key(pk: &Self::PublicKey) -> [u8; 32] {
+        pk.encode()
+    }
+
+    fn decode_public_key(buf: &[u8]) -> Option<Self::PublicKey> {
+        super::jq255e::PublicKey::decode(buf)
+    }
+
+    fn encode_private_key(sk: &Self::PrivateKey) -> [u8; 32] {
+        sk.encode()
+    }
+
+    fn decode_private_key(buf: &[u8]) -> Option<Self::PrivateKey> {
+        super::jq255e::PrivateKey::decode(buf)
+    }
+
+    fn dh(sk: &Self::PrivateKey, pk: &Self::PublicKey, out: &mut [u8])
+        -> bool
+    {
+        let (r, ok) = sk.ECDH(&pk.encoded);
+        out[..32].copy_from_slice(&r);
+        ok != 0
+    }
+}
+
+/// Experimental (non-standard) KEM over GLS254, using
+/// `gls254::PrivateKey::ECDH()` as the Diffie-Hellman function (with
+/// HKDF-SHA256). The KEM identifier (0xFF02) is not registered.
+#[cfg(feature = "gls254")]
+pub struct DhKemGls254;
+
+#[cfg(feature = "gls254")]
+impl Kem for DhKemGls254 {
+    const KEM_ID: u16 = 0xFF02;
+    const N_SECRET: usize = 32;
+    const N_PK: usize = 32;
+    const N_SK: usize = 32;
+    const N_DH: usize = 32;
+    type Hash = sha2::Sha256;
+    type PrivateKey = super::gls254::PrivateKey;
+    type PublicKey = super::gls254::PublicKey;
+    type EncodedPublicKey = [u8; 32];
+    type EncodedPrivateKey = [u8; 32];
+
+    fn derive_key_pair(ikm: &[u8]) -> (Self::PrivateKey, Self::PublicKey) {
+        // We use 64 bytes for the reduction, so that the bias is
+        // negligible.
+        for ctr in 0..=255u8 {
+            let mut sk = [0u8; 64];
+            derive_sk_bytes::<Self>(ikm, b"candidate", Some(ctr), &mut sk);
+            let s = super::gls254::Scalar::decode_reduce(&sk);
+            if s.iszero() == 0 {
+                let sk = super::gls254::PrivateKey::from_scalar(&s);
+                return (sk, sk.public_key);
+            }
+        }
+        panic!("DeriveKeyPair failure");
+    }
+
+    fn public_key(sk: &Self::PrivateKey) -> Self::PublicKey {
+        sk.public_key
+    }
+
+    fn encode_public_key(pk: &Self::PublicKey) -> [u8; 32] {
+        pk.encode()
+    }
+
+    fn decode_public_key(buf: &[u8]) -> Option<Self::PublicKey> {
+        super::gls254::PublicKey::decode(buf)
+    }
+
+    fn encode_private_key(sk: &Self::PrivateKey) -> [u8; 32] {
+        sk.encode()
+    }
+
+    fn decode_private_key(buf: &[u8]) -> Option<Self::PrivateKey> {
+        super::gls254::PrivateKey::decode(buf)
+    }
+
+    fn dh(sk: &Self::PrivateKey, pk: &Self::PublicKey, out: &mut [u8])
+        -> bool
+    {
+        let (r, ok) = sk.ECDH(&pk.encoded);
+        out[..32].copy_from_slice(&r);
+        ok != 0
+    }
+}
+
+/// Key derivation function.
+pub trait Kdf {
+    /// KDF identifier.
+    const KDF_ID: u16;
+    /// Hash function (used with HKDF).
+    type Hash: Digest + BlockSizeUser + Clone;
+}
+
+/// HKDF-SHA256.
+pub struct HkdfSha256;
+
+impl Kdf for HkdfSha256 {
+    const KDF_ID: u16 = 0x0001;
+    type Hash = sha2::Sha256;
+}
+
+/// HKDF-SHA384.
+pub struct HkdfSha384;
+
+impl Kdf for HkdfSha384 {
+    const KDF_ID: u16 = 0x0002;
+    type Hash = sha2::Sha384;
+}
+
+/// HKDF-SHA512.
+pub struct HkdfSha512;
+
+impl Kdf for HkdfSha512 {
+    const KDF_ID: u16 = 0x0003;
+    type Hash = sha2::Sha512;
+}
+
+/// Authenticated encryption with associated data.
+pub trait Aead {
+    /// AEAD identifier.
+    const AEAD_ID: u16;
+    /// Key length (in bytes).
+    const N_K: usize;
+    /// Nonce length (in bytes).
+    const N_N: usize;
+    /// Tag length (in bytes).
+    const N_T: usize;
+
+    /// Encrypt `data` in place, and write the tag (`N_T` bytes) into
+    /// `tag`. Returned value is `false` if encryption is not supported
+    /// (export-only mode).
+    fn seal(key: &[u8], nonce: &[u8], aad: &[u8], data: &mut [u8],
+        tag: &mut [u8]) -> bool;
+
+    /// Decrypt `data` in place, after verifying the tag. Returned value
+    /// is `false` on failure (in which case `data` is unmodified).
+    fn open(key: &[u8], nonce: &[u8], aad: &[u8], data: &mut [u8],
+        tag: &[u8]) -> bool;
+}
+
+/// ChaCha20-Poly1305 AEAD.
+pub struct ChaCha20Poly1305;
+
+impl Aead for ChaCha20Poly1305 {
+    const AEAD_ID: u16 = 0x0003;
+    const N_K: usize = 32;
+    const N_N: usize = 12;
+    const N_T: usize = 16;
+
+    fn seal(key: &[u8], nonce: &[u8], aad: &[u8], data: &mut [u8],
+        tag: &mut [u8]) -> bool
+    {
+        let (k, n) = chacha_params(key, nonce);
+        let t = super::chacha20poly1305::ChaCha20Poly1305::new(&k)
+            .encrypt_in_place(&n, aad, data);
+        tag[..16].copy_from_slice(&t);
+        true
+    }
+
+    fn open(key: &[u8], nonce: &[u8], aad: &[u8], data: &mut [u8],
+        tag: &[u8]) -> bool
+    {
+        let (k, n) = chacha_params(key, nonce);
+        super::chacha20poly1305::ChaCha20Poly1305::new(&k)
+            .decrypt_in_place(&n, aad, data, tag)
+    }
+}
+
+fn chacha_params(key: &[u8], nonce: &[u8]) -> ([u8; 32], [u8; 12]) {
+    let mut k = [0u8; 32];
+    k.copy_from_slice(&key[..32]);
+    let mut n = [0u8; 12];
+    n.copy_from_slice(&nonce[..12]);
+    (k, n)
+}
+
+/// AES-128-GCM AEAD.
+#[cfg(feature = "aes")]
+pub struct Aes128Gcm;
+
+#[cfg(feature = "aes")]
+impl Aead for Aes128Gcm {
+    const AEAD_ID: u16 = 0x0001;
+    const N_K: usize = 16;
+    const N_N: usize = 12;
+    const N_T: usize = 16;
+
+    fn seal(key: &[u8], nonce: &[u8], aad: &[u8], data: &mut [u8],
+        tag: &mut [u8]) -> bool
+    {
+        aes_gcm_seal(&key[..16], nonce, aad, data, tag)
+    }
+
+    fn open(key: &[u8], nonce: &[u8], aad: &[u8], data: &mut [u8],
+        tag: &[u8]) -> bool
+    {
+        aes_gcm_open(&key[..16], nonce, aad, data, tag)
+    }
+}
+
+/// AES-256-GCM AEAD.
+#[cfg(feature = "aes")]
+pub struct Aes256Gcm;
+
+#[cfg(feature = "aes")]
+impl Aead for Aes256Gcm {
+    const AEAD_ID: u16 = 0x0002;
+    const N_K: usize = 32;
+    const N_N: usize = 12;
+    const N_T: usize = 16;
+
+    fn seal(key: &[u8], nonce: &[u8], aad: &[u8], data: &mut [u8],
+        tag: &mut [u8]) -> bool
+    {
+        aes_gcm_seal(&key[..32], nonce, aad, data, tag)
+    }
+
+    fn open(key: &[u8], nonce: &[u8], aad: &[u8], data: &mut [u8],
+        tag: &[u8]) -> bool
+    {
+        aes_gcm_open(&key[..32], nonce, aad, data, tag)
+    }
+}
+
+#[cfg(feature = "aes")]
+fn aes_gcm_seal(key: &[u8], nonce: &[u8], aad: &[u8], data: &mut [u8],
+    tag: &mut [u8]) -> bool
+{
+    let mut n = [0u8; 12];
+    n.copy_from_slice(&nonce[..12]);
+    let t = super::aes::AesGcm::new(key).encrypt_in_place(&n, aad, data);
+    tag[..16].copy_from_slice(&t);
+    true
+}
+
+#[cfg(feature = "aes")]
+fn aes_gcm_open(key: &[u8], nonce: &[u8], aad: &[u8], data: &mut [u8],
+    tag: &[u8]) -> bool
+{
+    let mut n = [0u8; 12];
+    n.copy_from_slice(&nonce[..12]);
+    super::aes::AesGcm::new(key).decrypt_in_place(&n, aad, data, tag)
+}
+
+/// Export-only mode: no encryption, only the secret export interface
+/// can be used.
+pub struct ExportOnly;
+
+impl Aead for ExportOnly {
+    const AEAD_ID: u16 = 0xFFFF;
+    const N_K: usize = 0;
+    const N_N: usize = 0;
+    const N_T: usize = 0;
+
+    fn seal(_key: &[u8], _nonce: &[u8], _aad: &[u8], _data: &mut [u8],
+        _tag: &mut [u8]) -> bool
+    {
+        false
+    }
+
+    fn open(_key: &[u8], _nonce: &[u8], _aad: &[u8], _data: &mut [u8],
+        _tag: &[u8]) -> bool
+    {
+        false
+    }
+}
+
+/// HPKE context, obtained from a sender or recipient setup function.
+///
+/// A sender context encrypts messages with `seal_in_place()`; a recipient
+/// context decrypts them with `open_in_place()`, in the same order. Both
+/// can export secrets with `export()`.
+pub struct Context<K: Kem, F: Kdf, A: Aead> {
+    key: [u8; 32],
+    base_nonce: [u8; 12],
+    exporter_secret: [u8; MAX_HASH_LEN],
+    seq: u64,
+    _kem: PhantomData<K>,
+    _kdf: PhantomData<F>,
+    _aead: PhantomData<A>,
+}
+
+impl<K: Kem, F: Kdf, A: Aead> Context<K, F, A> {
+
+    // Get the HPKE suite identifier.
+    fn suite_id() -> [u8; 10] {
+        let k = K::KEM_ID.to_be_bytes();
+        let f = F::KDF_ID.to_be_bytes();
+        let a = A::AEAD_ID.to_be_bytes();
+        [b'H', b'P', b'K', b'E', k[0], k[1], f[0], f[1], a[0], a[1]]
+    }
+
+    // KeySchedule() from RFC 9180. The PSK parameters have been verified.
+    fn key_schedule(mode: u8, shared_secret: &[u8], info: &[u8],
+        psk: &[u8], psk_id: &[u8]) -> Self
+    {
+        let sid = Self::suite_id();
+        let nh = <F::Hash as Digest>::output_size();
+        let mut ksc = [0u8; 1 + 2 * MAX_HASH_LEN];
+        ksc[0] = mode;
+        labeled_extract::<F::Hash>(&sid, b"", b"psk_id_hash", psk_id)
+            .prk_write(&mut ksc[1..]);
+        labeled_extract::<F::Hash>(&sid, b"", b"info_hash", info)
+            .prk_write(&mut ksc[(1 + nh)..]);
+        let ksc = &ksc[..(1 + 2 * nh)];
+
+        let secret = labeled_extract::<F::Hash>(&sid, shared_secret,
+            b"secret", psk);
+        let mut ctx = Self {
+            key: [0u8; 32],
+            base_nonce: [0u8; 12],
+            exporter_secret: [0u8; MAX_HASH_LEN],
+            seq: 0,
+            _kem: PhantomData,
+            _kdf: PhantomData,
+            _aead: PhantomData,
+        };
+        labeled_expand(&secret, &sid, b"key", ksc, &mut ctx.key[..A::N_K]);
+        labeled_expand(&secret, &sid, b"base_nonce", ksc,
+            &mut ctx.base_nonce[..A::N_N]);
+        labeled_expand(&secret, &sid, b"exp", ksc,
+            &mut ctx.exporter_secret[..nh]);
+        ctx
+    }
+
+    // Get the mode from the PSK and authentication parameters; this
+    // returns `None` if the PSK parameters are invalid (the PSK and its
+    // identifier must be both non-empty).
+    fn get_mode<'a>(psk: Option<(&'a [u8], &'a [u8])>, auth: bool)
+        -> Option<(u8, &'a [u8], &'a [u8])>
+    {
+        match psk {
+            None => Some((if auth { MODE_AUTH } else { MODE_BASE }, b"", b"")),
+            Some((psk, psk_id)) => {
+                if psk.is_empty() || psk_id.is_empty() {
+                    return None;
+                }
+                Some((if auth { MODE_AUTH_PSK } else { MODE_PSK },
+                    psk, psk_id))
+            }
+        }
+    }
+
+    /// Set up a sender context, towards the recipient public key `pk_r`.
+    ///
+    /// `psk` is the optional pre-shared key and its identifier (both
+    /// must be non-empty); `sk_s` is the optional sender private key, for
+    /// the authenticated modes. The mode (base, psk, auth or auth_psk)
+    /// is inferred from these two parameters. On success, the
+    /// encapsulated key (to send to the recipient) and the context are
+    /// returned. `None` is returned on failure (invalid PSK parameters,
+    /// or invalid recipient public key).
+    pub fn setup_sender<T: CryptoRng + RngCore>(rng: &mut T,
+        pk_r: &K::PublicKey, info: &[u8], psk: Option<(&[u8], &[u8])>,
+        sk_s: Option<&K::PrivateKey>) -> Option<(K::EncodedPublicKey, Self)>
+    {
+        let (sk_e, _) = K::generate_key_pair(rng);
+        Self::setup_sender_with_ephemeral(&sk_e, pk_r, info, psk, sk_s)
+    }
+
+    /// Set up a sender context, with an ephemeral key pair derived from
+    /// the provided input key material (`ikm_e`). This is meant for
+    /// tests; `ikm_e` MUST be unique and unpredictable. Parameters are
+    /// otherwise identical to `setup_sender()`.
+    pub fn setup_sender_with_ikm(ikm_e: &[u8], pk_r: &K::PublicKey,
+        info: &[u8], psk: Option<(&[u8], &[u8])>,
+        sk_s: Option<&K::PrivateKey>) -> Option<(K::EncodedPublicKey, Self)>
+    {
+        let (sk_e, _) = K::derive_key_pair(ikm_e);
+        Self::setup_sender_with_ephemeral(&sk_e, pk_r, info, psk, sk_s)
+    }
+
+    fn setup_sender_with_ephemeral(sk_e: &K::PrivateKey,
+        pk_r: &K::PublicKey, info: &[u8], psk: Option<(&[u8], &[u8])>,
+        sk_s: Option<&K::PrivateKey>) -> Option<(K::EncodedPublicKey, Self)>
+    {
+        let (mode, psk, psk_id) = Self::get_mode(psk, sk_s.is_some())?;
+        let mut shared = [0u8; MAX_HASH_LEN];
+        let enc = encap::<K>(sk_e, pk_r, sk_s, &mut shared)?;
+        let ctx = Self::key_schedule(mode, &shared[..K::N_SECRET], info,
+            psk, psk_id);
+        Some((enc, ctx))
+    }
+
+    /// Set up a recipient context, from the encapsulated key (`enc`) and
+    /// the recipient private key `sk_r`.
+    ///
+    /// `psk` and `pk_s` (sender public key) must match the parameters
+    /// used by the sender. `None` is returned on failure (invalid PSK
+    /// parameters, or invalid encapsulated key).
+    pub fn setup_recipient(enc: &[u8], sk_r: &K::PrivateKey, info: &[u8],
+        psk: Option<(&[u8], &[u8])>, pk_s: Option<&K::PublicKey>)
+        -> Option<Self>
+    {
+        let (mode, psk, psk_id) = Self::get_mode(psk, pk_s.is_some())?;
+        let mut shared = [0u8; MAX_HASH_LEN];
+        if !decap::<K>(enc, sk_r, pk_s, &mut shared) {
+            return None;
+        }
+        Some(Self::key_schedule(mode, &shared[..K::N_SECRET], info,
+            psk, psk_id))
+    }
+
+    // Compute the nonce for the current sequence number.
+    fn compute_nonce(&self) -> [u8; 12] {
+        let mut nonce = self.base_nonce;
+        let sb = self.seq.to_be_bytes();
+        for i in 0..8 {
+            nonce[A::N_N - 8 + i] ^= sb[i];
+        }
+        nonce
+    }
+
+    /// Encrypt the next message in place; the tag is returned. `None` is
+    /// returned if encryption is not possible (export-only mode, or
+    /// sequence number overflow).
+    pub fn seal_in_place(&mut self, aad: &[u8], data: &mut [u8])
+        -> Option<[u8; 16]>
+    {
+        if self.seq == u64::MAX || A::N_K == 0 {
+            return None;
+        }
+        let nonce = self.compute_nonce();
+        let mut tag = [0u8; 16];
+        if !A::seal(&self.key[..A::N_K], &nonce[..A::N_N], aad, data,
+            &mut tag[..A::N_T])
+        {
+            return None;
+        }
+        self.seq += 1;
+        Some(tag)
+    }
+
+    /// Decrypt the next message in place. Returned value is `true` on
+    /// success. On failure, the data is unmodified, and the sequence
+    /// number is not incremented.
+    pub fn open_in_place(&mut self, aad: &[u8], data: &mut [u8], tag: &[u8])
+        -> bool
+    {
+        if self.seq == u64::MAX || A::N_K == 0 || tag.len() != A::N_T {
+            return false;
+        }
+        let nonce = self.compute_nonce();
+        if !A::open(&self.key[..A::N_K], &nonce[..A::N_N], aad, data, tag) {
+            return false;
+        }
+        self.seq += 1;
+        true
+    }
+
+    /// Encrypt the next message; the ciphertext (with the appended tag)
+    /// is returned.
+    #[cfg(feature = "alloc")]
+    pub fn seal(&mut self, aad: &[u8], pt: &[u8]) -> Option<Vec<u8>> {
+        let mut ct = pt.to_vec();
+        let tag = self.seal_in_place(aad, &mut ct)?;
+        ct.extend_from_slice(&tag[..A::N_T]);
+        Some(ct)
+    }
+
+    /// Decrypt the next message (ciphertext with the appended tag); the
+    /// plaintext is returned, or `None` on failure.
+    #[cfg(feature = "alloc")]
+    pub fn open(&mut self, aad: &[u8], ct: &[u8]) -> Option<Vec<u8>> {
+        if ct.len() < A::N_T {
+            return None;
+        }
+        let n = ct.len() - A::N_T;
+        let mut pt = ct[..n].to_vec();
+        if self.open_in_place(aad, &mut pt, &ct[n..]) {
+            Some(pt)
+        } else {
+            None
+        }
+    }
+
+    /// Export a secret, for the provided exporter context; the output
+    /// length is that of `out`, which must not exceed 255 times the KDF
+    /// hash output length.
+    pub fn export(&self, exporter_context: &[u8], out: &mut [u8]) {
+        let nh = <F::Hash as Digest>::output_size();
+        let prk = Hkdf::<F::Hash>::from_prk(&self.exporter_secret[..nh]);
+        labeled_expand(&prk, &Self::suite_id(), b"sec", exporter_context,
+            out);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use sha2::Sha256;
+
+    type SuiteX25519 = Context<DhKemX25519, HkdfSha256, ChaCha20Poly1305>;
+
+    // RFC 9180, appendix A.2.1 (DHKEM(X25519, HKDF-SHA256), HKDF-SHA256,
+    // ChaCha20Poly1305, base mode).
+    #[test]
+    fn rfc9180_base() {
+        let info = hex::decode("4f6465206f6e2061204772656369616e2055726e").unwrap();
+        let ikm_e = hex::decode("909a9b35d3dc4713a5e72a4da274b55d3d3821a37e5d099e74a647db583a904b").unwrap();
+        let ikm_r = hex::decode("1ac01f181fdf9f352797655161c58b75c656a6cc2716dcb66372da835542e1df").unwrap();
+
+        let (sk_e, pk_e) = DhKemX25519::derive_key_pair(&ikm_e);
+        assert!(sk_e[..] == hex::decode("f4ec9b33b792c372c1d2c2063507b684ef925b8c75a42dbcbf57d63ccd381600").unwrap());
+        assert!(pk_e[..] == hex::decode("1afa08d3dec047a643885163f1180476fa7ddb54c6a8029ea33f95796bf2ac4a").unwrap());
+        let (sk_r, pk_r) = DhKemX25519::derive_key_pair(&ikm_r);
+        assert!(sk_r[..] == hex::decode("8057991eef8f1f1af18f4a9491d16a1ce333f695d4db8e38da75975c4478e0fb").unwrap());
+        assert!(pk_r[..] == hex::decode("4310ee97d88cc1f088a5576c77ab0cf5c3ac797f3d95139c6c84b5429c59662a").unwrap());
+
+        let (enc, mut ctx_s) = SuiteX25519::setup_sender_with_ikm(
+            &ikm_e, &pk_r, &info, None, None).unwrap();
+        assert!(enc == pk_e);
+        let mut ctx_r = SuiteX25519::setup_recipient(
+            &enc, &sk_r, &info, None, None).unwrap();
+        assert!(ctx_s.key[..] == hex::decode("ad2744de8e17f4ebba575b3f5f5a8fa1f69c2a07f6e7500bc60ca6e3e3ec1c91").unwrap());
+        assert!(ctx_s.base_nonce[..] == hex::decode("5c4d98150661b848853b547f").unwrap());
+        assert!(ctx_s.exporter_secret[..32] == hex::decode("a3b010d4994890e2c6968a36f64470d3c824c8f5029942feb11e7a74b2921922").unwrap());
+
+        let pt = hex::decode("4265617574792069732074727574682c20747275746820626561757479").unwrap();
+        let kat = [
+            ("436f756e742d30", "1c5250d8034ec2b784ba2cfd69dbdb8af406cfe3ff938e131f0def8c8b60b4db21993c62ce81883d2dd1b51a28"),
+            ("436f756e742d31", "6b53c051e4199c518de79594e1c4ab18b96f081549d45ce015be002090bb119e85285337cc95ba5f59992dc98c"),
+        ];
+        for (aad, ct) in kat.iter() {
+            let aad = hex::decode(aad).unwrap();
+            let ct = hex::decode(ct).unwrap();
+            let mut buf = pt.clone();
+            let tag = ctx_s.seal_in_place(&aad, &mut buf).unwrap();
+            assert!(buf[..] == ct[..pt.len()]);
+            assert!(tag[..] == ct[pt.len()..]);
+            assert!(ctx_r.open_in_place(&aad, &mut buf, &tag));
+            assert!(buf == pt);
+        }
+
+        let kat = [
+            ("", "4bbd6243b8bb54cec311fac9df81841b6fd61f56538a775e7c80a9f40160606e"),
+            ("00", "8c1df14732580e5501b00f82b10a1647b40713191b7c1240ac80e2b68808ba69"),
+            ("54657374436f6e74657874", "5acb09211139c43b3090489a9da433e8a30ee7188ba8b0a9a1ccf0c229283e53"),
+        ];
+        for (ctx, ev) in kat.iter() {
+            let ctx = hex::decode(ctx).unwrap();
+            let mut out = [0u8; 32];
+            ctx_s.export(&ctx, &mut out);
+            assert!(out[..] == hex::decode(ev).unwrap());
+            ctx_r.export(&ctx, &mut out);
+            assert!(out[..] == hex::decode(ev).unwrap());
+        }
+    }
+
+    // Recipient side only, with decoded (not derived) private keys:
+    // first message of the DHKEM(X25519, HKDF-SHA256), HKDF-SHA512,
+    // ChaCha20Poly1305 and DHKEM(P-256, HKDF-SHA256), HKDF-SHA256,
+    // ChaCha20Poly1305 (RFC 9180, appendix A.5.1) base mode vectors, from
+    // the RFC 9180 test vector set (test-vectors.json in the
+    // draft-irtf-cfrg-hpke repository). Each message is the encapsulated
+    // key followed by the ciphertext and tag.
+    #[test]
+    fn interop() {
+        let info = hex::decode("4f6465206f6e2061204772656369616e2055726e").unwrap();
+        let pt = hex::decode("4265617574792069732074727574682c20747275746820626561757479").unwrap();
+
+        let skb = hex::decode("fad15f488c09c167bd18d8f48f282e30d944d624c5676742ad820119de44ea91").unwrap();
+        let sk_r = DhKemX25519::decode_private_key(&skb).unwrap();
+        let ct = hex::decode("1d38fc578d4209ea0ef3ee5f1128ac4876a9549d74dc2d2f46e75942a618824472da9627fd7eb3a8b7169c6d97419b80adefca751c6b52b39a2e084d35ce3eb4487aadaca5a9c590e0938c48b9").unwrap();
+        let mut ctx = Context::<DhKemX25519, HkdfSha512, ChaCha20Poly1305>
+            ::setup_recipient(&ct[..32], &sk_r, &info, None, None).unwrap();
+        assert!(ctx.open(b"Count-0", &ct[32..]).unwrap() == pt);
+
+        #[cfg(feature = "p256")]
+        {
+            let skb = hex::decode("a4d1c55836aa30f9b3fbb6ac98d338c877c2867dd3a77396d13f68d3ab150d3b").unwrap();
+            let sk_r = DhKemP256::decode_private_key(&skb).unwrap();
+            let ct = hex::decode("04c07836a0206e04e31d8ae99bfd549380b072a1b1b82e563c935c095827824fc1559eac6fb9e3c70cd3193968994e7fe9781aa103f5b50e934b5b2f387e3812916469c41c5c81d3aa85432531ecf6460ec945bde1eb428cb2fedf7a29f5a685b4ccb0d057f03ea2952a27bb458b").unwrap();
+            let mut ctx = Context::<DhKemP256, HkdfSha256, ChaCha20Poly1305>
+                ::setup_recipient(&ct[..65], &sk_r, &info, None, None)
+                .unwrap();
+            assert!(ctx.open(b"Count-0", &ct[65..]).unwrap() == pt);
+        }
+    }
+
+    // A test vector; empty strings are used for the sender key (ikm_s,
+    // pk_s) and the PSK parameters when the mode does not use them, and
+    // for the key and base nonce with the export-only AEAD. Encryptions
+    // are (sequence number, aad, ciphertext and tag); all use the same
+    // plaintext. Exports are (exporter context, exported value).
+    struct TestVector {
+        mode: u8,
+        info: &'static str,
+        ikm_e: &'static str,
+        ikm_r: &'static str,
+        ikm_s: &'static str,
+        sk_r: &'static str,
+        pk_r: &'static str,
+        pk_s: &'static str,
+        psk: &'static str,
+        psk_id: &'static str,
+        enc: &'static str,
+        key: &'static str,
+        base_nonce: &'static str,
+        exporter_secret: &'static str,
+        encryptions: &'static [(u64, &'static str, &'static str)],
+        exports: &'static [(&'static str, &'static str)],
+    }
+
+    // Test vectors from RFC 9180, appendix A (and the corresponding
+    // test-vectors.json file in the draft-irtf-cfrg-hpke repository, for
+    // the suites that are not printed in the RFC). Only the encryptions
+    // for sequence numbers 0, 1 and 256 are included.
+    // RFC 9180, appendix A.1: DHKEM(X25519, HKDF-SHA256), HKDF-SHA256,
+    // AES-128-GCM (base, psk, auth and auth_psk modes).
+    #[cfg(feature = "aes")]
+    static RFC9180_A1: [TestVector; 4] = [
+        TestVector {
+            mode: 0,
+            info: "4f6465206f6e2061204772656369616e2055726e",
+            ikm_e: "7268600d403fce431561aef583ee1613527cff655c1343f29812e66706df3234",
+            ikm_r: "6db9df30aa07dd42ee5e8181afdb977e538f5e1fec8a06223f33f7013e525037",
+            ikm_s: "",
+            sk_r: "4612c550263fc8ad58375df3f557aac531d26850903e55a9f23f21d8534e8ac8",
+            pk_r: "3948cfe0ad1ddb695d780e59077195da6c56506b027329794ab02bca80815c4d",
+            pk_s: "",
+            psk: "",
+            psk_id: "",
+            enc: "37fda3567bdbd628e88668c3c8d7e97d1d1253b6d4ea6d44c150f741f1bf4431",
+            key: "4531685d41d65f03dc48f6b8302c05b0",
+            base_nonce: "56d890e5accaaf011cff4b7d",
+            exporter_secret: "45ff1c2e220db587171952c0592d5f5ebe103f1561a2614e38f2ffd47e99e3f8",
+            encryptions: &[
+                (0, "436f756e742d30",
+                 "f938558b5d72f1a23810b4be2ab4f84331acc02fc97babc53a52ae8218a355a9\
+                     6d8770ac83d07bea87e13c512a"),
+                (1, "436f756e742d31",
+                 "af2d7e9ac9ae7e270f46ba1f975be53c09f8d875bdc8535458c2494e8a6eab25\
+                     1c03d0c22a56b8ca42c2063b84"),
+                (256, "436f756e742d323536",
+                 "957f9800542b0b8891badb026d79cc54597cb2d225b54c00c5238c25d05c30e3\
+                     fbeda97d2e0e1aba483a2df9f2"),
+            ],
+            exports: &[
+                ("", "3853fe2b4035195a573ffc53856e77058e15d9ea064de3e59f4961d0095250ee"),
+                ("00", "2e8f0b54673c7029649d4eb9d5e33bf1872cf76d623ff164ac185da9e88c21a5"),
+                ("54657374436f6e74657874", "e9e43065102c3836401bed8c3c3c75ae46be1639869391d62c61f1ec7af54931"),
+            ],
+        },
+        TestVector {
+            mode: 1,
+            info: "4f6465206f6e2061204772656369616e2055726e",
+            ikm_e: "78628c354e46f3e169bd231be7b2ff1c77aa302460a26dbfa15515684c00130b",
+            ikm_r: "d4a09d09f575fef425905d2ab396c1449141463f698f8efdb7accfaff8995098",
+            ikm_s: "",
+            sk_r: "c5eb01eb457fe6c6f57577c5413b931550a162c71a03ac8d196babbd4e5ce0fd",
+            pk_r: "9fed7e8c17387560e92cc6462a68049657246a09bfa8ade7aefe589672016366",
+            pk_s: "",
+            psk: "0247fd33b913760fa1fa51e1892d9f307fbe65eb171e8132c2af18555a738b82",
+            psk_id: "456e6e796e20447572696e206172616e204d6f726961",
+            enc: "0ad0950d9fb9588e59690b74f1237ecdf1d775cd60be2eca57af5a4b0471c91b",
+            key: "15026dba546e3ae05836fc7de5a7bb26",
+            base_nonce: "9518635eba129d5ce0914555",
+            exporter_secret: "3d76025dbbedc49448ec3f9080a1abab6b06e91c0b11ad23c912f043a0ee7655",
+            encryptions: &[
+                (0, "436f756e742d30",
+                 "e52c6fed7f758d0cf7145689f21bc1be6ec9ea097fef4e959440012f4feb73fb\
+                     611b946199e681f4cfc34db8ea"),
+                (1, "436f756e742d31",
+                 "49f3b19b28a9ea9f43e8c71204c00d4a490ee7f61387b6719db765e948123b45\
+                     b61633ef059ba22cd62437c8ba"),
+                (256, "436f756e742d323536",
+                 "c5bf246d4a790a12dcc9eed5eae525081e6fb541d5849e9ce8abd92a3bc15517\
+                     76bea16b4a518f23e237c14b59"),
+            ],
+            exports: &[
+                ("", "dff17af354c8b41673567db6259fd6029967b4e1aad13023c2ae5df8f4f43bf6"),
+                ("00", "6a847261d8207fe596befb52928463881ab493da345b10e1dcc645e3b94e2d95"),
+                ("54657374436f6e74657874", "8aff52b45a1be3a734bc7a41e20b4e055ad4c4d22104b0c20285a7c4302401cd"),
+            ],
+        },
+        TestVector {
+            mode: 2,
+            info: "4f6465206f6e2061204772656369616e2055726e",
+            ikm_e: "6e6d8f200ea2fb20c30b003a8b4f433d2f4ed4c2658d5bc8ce2fef718059c9f7",
+            ikm_r: "f1d4a30a4cef8d6d4e3b016e6fd3799ea057db4f345472ed302a67ce1c20cdec",
+            ikm_s: "94b020ce91d73fca4649006c7e7329a67b40c55e9e93cc907d282bbbff386f58",
+            sk_r: "fdea67cf831f1ca98d8e27b1f6abeb5b7745e9d35348b80fa407ff6958f9137e",
+            pk_r: "1632d5c2f71c2b38d0a8fcc359355200caa8b1ffdf28618080466c909cb69b2e",
+            pk_s: "8b0c70873dc5aecb7f9ee4e62406a397b350e57012be45cf53b7105ae731790b",
+            psk: "",
+            psk_id: "",
+            enc: "23fb952571a14a25e3d678140cd0e5eb47a0961bb18afcf85896e5453c312e76",
+            key: "b062cb2c4dd4bca0ad7c7a12bbc341e6",
+            base_nonce: "a1bc314c1942ade7051ffed0",
+            exporter_secret: "ee1a093e6e1c393c162ea98fdf20560c75909653550540a2700511b65c88c6f1",
+            encryptions: &[
+                (0, "436f756e742d30",
+                 "5fd92cc9d46dbf8943e72a07e42f363ed5f721212cd90bcfd072bfd9f44e06b8\
+                     0fd17824947496e21b680c141b"),
+                (1, "436f756e742d31",
+                 "d3736bb256c19bfa93d79e8f80b7971262cb7c887e35c26370cfed62254369a1\
+                     b52e3d505b79dd699f002bc8ed"),
+                (256, "436f756e742d323536",
+                 "42fa248a0e67ccca688f2b1d13ba4ba84755acf764bd797c8f7ba3b9b1dc3330\
+                     326f8d172fef6003c79ec72319"),
+            ],
+            exports: &[
+                ("", "28c70088017d70c896a8420f04702c5a321d9cbf0279fba899b59e51bac72c85"),
+                ("00", "25dfc004b0892be1888c3914977aa9c9bbaf2c7471708a49e1195af48a6f29ce"),
+                ("54657374436f6e74657874", "5a0131813abc9a522cad678eb6bafaabc43389934adb8097d23c5ff68059eb64"),
+            ],
+        },
+        TestVector {
+            mode: 3,
+            info: "4f6465206f6e2061204772656369616e2055726e",
+            ikm_e: "4303619085a20ebcf18edd22782952b8a7161e1dbae6e46e143a52a96127cf84",
+            ikm_r: "4b16221f3b269a88e207270b5e1de28cb01f847841b344b8314d6a622fe5ee90",
+            ikm_s: "62f77dcf5df0dd7eac54eac9f654f426d4161ec850cc65c54f8b65d2e0b4e345",
+            sk_r: "cb29a95649dc5656c2d054c1aa0d3df0493155e9d5da6d7e344ed8b6a64a9423",
+            pk_r: "1d11a3cd247ae48e901939659bd4d79b6b959e1f3e7d66663fbc9412dd4e0976",
+            pk_s: "2bfb2eb18fcad1af0e4f99142a1c474ae74e21b9425fc5c589382c69b50cc57e",
+            psk: "0247fd33b913760fa1fa51e1892d9f307fbe65eb171e8132c2af18555a738b82",
+            psk_id: "456e6e796e20447572696e206172616e204d6f726961",
+            enc: "820818d3c23993492cc5623ab437a48a0a7ca3e9639c140fe1e33811eb844b7c",
+            key: "1364ead92c47aa7becfa95203037b19a",
+            base_nonce: "99d8b5c54669807e9fc70df1",
+            exporter_secret: "f048d55eacbf60f9c6154bd4021774d1075ebf963c6adc71fa846f183ab2dde6",
+            encryptions: &[
+                (0, "436f756e742d30",
+                 "a84c64df1e11d8fd11450039d4fe64ff0c8a99fca0bd72c2d4c3e0400bc14a40\
+                     f27e45e141a24001697737533e"),
+                (1, "436f756e742d31",
+                 "4d19303b848f424fc3c3beca249b2c6de0a34083b8e909b6aa4c3688505c05ff\
+                     e0c8f57a0a4c5ab9da127435d9"),
+                (256, "436f756e742d323536",
+                 "13239bab72e25e9fd5bb09695d23c90a24595158b99127505c8a9ff9f127e0d6\
+                     57f71af59d67d4f4971da028f9"),
+            ],
+            exports: &[
+                ("", "08f7e20644bb9b8af54ad66d2067457c5f9fcb2a23d9f6cb4445c0797b330067"),
+                ("00", "52e51ff7d436557ced5265ff8b94ce69cf7583f49cdb374e6aad801fc063b010"),
+                ("54657374436f6e74657874", "a30c20370c026bbea4dca51cb63761695132d342bae33a6a11527d3e7679436d"),
+            ],
+        },
+    ];
+
+    // RFC 9180, appendix A.2: DHKEM(X25519, HKDF-SHA256), HKDF-SHA256,
+    // ChaCha20Poly1305.
+    static RFC9180_A2: [TestVector; 4] = [
+        TestVector {
+            mode: 0,
+            info: "4f6465206f6e2061204772656369616e2055726e",
+            ikm_e: "909a9b35d3dc4713a5e72a4da274b55d3d3821a37e5d099e74a647db583a904b",
+            ikm_r: "1ac01f181fdf9f352797655161c58b75c656a6cc2716dcb66372da835542e1df",
+            ikm_s: "",
+            sk_r: "8057991eef8f1f1af18f4a9491d16a1ce333f695d4db8e38da75975c4478e0fb",
+            pk_r: "4310ee97d88cc1f088a5576c77ab0cf5c3ac797f3d95139c6c84b5429c59662a",
+            pk_s: "",
+            psk: "",
+            psk_id: "",
+            enc: "1afa08d3dec047a643885163f1180476fa7ddb54c6a8029ea33f95796bf2ac4a",
+            key: "ad2744de8e17f4ebba575b3f5f5a8fa1f69c2a07f6e7500bc60ca6e3e3ec1c91",
+            base_nonce: "5c4d98150661b848853b547f",
+            exporter_secret: "a3b010d4994890e2c6968a36f64470d3c824c8f5029942feb11e7a74b2921922",
+            encryptions: &[
+                (0, "436f756e742d30",
+                 "1c5250d8034ec2b784ba2cfd69dbdb8af406cfe3ff938e131f0def8c8b60b4db\
+                     21993c62ce81883d2dd1b51a28"),
+                (1, "436f756e742d31",
+                 "6b53c051e4199c518de79594e1c4ab18b96f081549d45ce015be002090bb119e\
+                     85285337cc95ba5f59992dc98c"),
+                (256, "436f756e742d323536",
+                 "7a4a13e9ef23978e2c520fd4d2e757514ae160cd0cd05e556ef692370ca53076\
+                     214c0c40d4c728d6ed9e727a5b"),
+            ],
+            exports: &[
+                ("", "4bbd6243b8bb54cec311fac9df81841b6fd61f56538a775e7c80a9f40160606e"),
+                ("00", "8c1df14732580e5501b00f82b10a1647b40713191b7c1240ac80e2b68808ba69"),
+                ("54657374436f6e74657874", "5acb09211139c43b3090489a9da433e8a30ee7188ba8b0a9a1ccf0c229283e53"),
+            ],
+        },
+        TestVector {
+            mode: 1,
+            info: "4f6465206f6e2061204772656369616e2055726e",
+            ikm_e: "35706a0b09fb26fb45c39c2f5079c709c7cf98e43afa973f14d88ece7e29c2e3",
+            ikm_r: "26b923eade72941c8a85b09986cdfa3f1296852261adedc52d58d2930269812b",
+            ikm_s: "",
+            sk_r: "77d114e0212be51cb1d76fa99dd41cfd4d0166b08caa09074430a6c59ef17879",
+            pk_r: "13640af826b722fc04feaa4de2f28fbd5ecc03623b317834e7ff4120dbe73062",
+            pk_s: "",
+            psk: "0247fd33b913760fa1fa51e1892d9f307fbe65eb171e8132c2af18555a738b82",
+            psk_id: "456e6e796e20447572696e206172616e204d6f726961",
+            enc: "2261299c3f40a9afc133b969a97f05e95be2c514e54f3de26cbe5644ac735b04",
+            key: "600d2fdb0313a7e5c86a9ce9221cd95bed069862421744cfb4ab9d7203a9c019",
+            base_nonce: "112e0465562045b7368653e7",
+            exporter_secret: "73b506dc8b6b4269027f80b0362def5cbb57ee50eed0c2873dac9181f453c5ac",
+            encryptions: &[
+                (0, "436f756e742d30",
+                 "4a177f9c0d6f15cfdf533fb65bf84aecdc6ab16b8b85b4cf65a370e07fc1d78d\
+                     28fb073214525276f4a89608ff"),
+                (1, "436f756e742d31",
+                 "5c3cabae2f0b3e124d8d864c116fd8f20f3f56fda988c3573b40b09997fd6c76\
+                     9e77c8eda6cda4f947f5b704a8"),
+                (256, "436f756e742d323536",
+                 "c567ae1c3f0f75abe1dd9e4532b422600ed4a6e5b9484dafb1e43ab9f5fd662b\
+                     28c00e2e81d3cde955dae7e218"),
+            ],
+            exports: &[
+                ("", "813c1bfc516c99076ae0f466671f0ba5ff244a41699f7b2417e4c59d46d39f40"),
+                ("00", "2745cf3d5bb65c333658732954ee7af49eb895ce77f8022873a62a13c94cb4e1"),
+                ("54657374436f6e74657874", "ad40e3ae14f21c99bfdebc20ae14ab86f4ca2dc9a4799d200f43a25f99fa78ae"),
+            ],
+        },
+        TestVector {
+            mode: 2,
+            info: "4f6465206f6e2061204772656369616e2055726e",
+            ikm_e: "938d3daa5a8904540bc24f48ae90eed3f4f7f11839560597b55e7c9598c996c0",
+            ikm_r: "64835d5ee64aa7aad57c6f2e4f758f7696617f8829e70bc9ac7a5ef95d1c756c",
+            ikm_s: "9d8f94537d5a3ddef71234c0baedfad4ca6861634d0b94c3007fed557ad17df6",
+            sk_r: "3ca22a6d1cda1bb9480949ec5329d3bf0b080ca4c45879c95eddb55c70b80b82",
+            pk_r: "1a478716d63cb2e16786ee93004486dc151e988b34b475043d3e0175bdb01c44",
+            pk_s: "f0f4f9e96c54aeed3f323de8534fffd7e0577e4ce269896716bcb95643c8712b",
+            psk: "",
+            psk_id: "",
+            enc: "f7674cc8cd7baa5872d1f33dbaffe3314239f6197ddf5ded1746760bfc847e0e",
+            key: "b071fd1136680600eb447a845a967d35e9db20749cdf9ce098bcc4deef4b1356",
+            base_nonce: "d20577dff16d7cea2c4bf780",
+            exporter_secret: "be2d93b82071318cdb88510037cf504344151f2f9b9da8ab48974d40a2251dd7",
+            encryptions: &[
+                (0, "436f756e742d30",
+                 "ab1a13c9d4f01a87ec3440dbd756e2677bd2ecf9df0ce7ed73869b98e00c09be\
+                     111cb9fdf077347aeb88e61bdf"),
+                (1, "436f756e742d31",
+                 "3265c7807ffff7fdace21659a2c6ccffee52a26d270c76468ed74202a65478bf\
+                     aedfff9c2b7634e24f10b71016"),
+                (256, "436f756e742d323536",
+                 "3be14e8b3bbd1028cf2b7d0a691dbbeff71321e7dec92d3c2cfb30a0994ab246\
+                     af76168480285a60037b4ba13a"),
+            ],
+            exports: &[
+                ("", "070cffafd89b67b7f0eeb800235303a223e6ff9d1e774dce8eac585c8688c872"),
+                ("00", "2852e728568d40ddb0edde284d36a4359c56558bb2fb8837cd3d92e46a3a14a8"),
+                ("54657374436f6e74657874", "1df39dc5dd60edcbf5f9ae804e15ada66e885b28ed7929116f768369a3f950ee"),
+            ],
+        },
+        TestVector {
+            mode: 3,
+            info: "4f6465206f6e2061204772656369616e2055726e",
+            ikm_e: "49d6eac8c6c558c953a0a252929a818745bb08cd3d29e15f9f5db5eb2e7d4b84",
+            ikm_r: "f3304ddcf15848488271f12b75ecaf72301faabf6ad283654a14c398832eb184",
+            ikm_s: "20ade1d5203de1aadfb261c4700b6432e260d0d317be6ebbb8d7fffb1f86ad9d",
+            sk_r: "7b36a42822e75bf3362dfabbe474b3016236408becb83b859a6909e22803cb0c",
+            pk_r: "a5099431c35c491ec62ca91df1525d6349cb8aa170c51f9581f8627be6334851",
+            pk_s: "3ac5bd4dd66ff9f2740bef0d6ccb66daa77bff7849d7895182b07fb74d087c45",
+            psk: "0247fd33b913760fa1fa51e1892d9f307fbe65eb171e8132c2af18555a738b82",
+            psk_id: "456e6e796e20447572696e206172616e204d6f726961",
+            enc: "656a2e00dc9990fd189e6e473459392df556e9a2758754a09db3f51179a3fc02",
+            key: "49c7e6d7d2d257aded2a746fe6a9bf12d4de8007c4862b1fdffe8c35fb65054c",
+            base_nonce: "abac79931e8c1bcb8a23960a",
+            exporter_secret: "7c6cc1bb98993cd93e2599322247a58fd41fdecd3db895fb4c5fd8d6bbe606b5",
+            encryptions: &[
+                (0, "436f756e742d30",
+                 "9aa52e29274fc6172e38a4461361d2342585d3aeec67fb3b721ecd63f059577c\
+                     7fe886be0ede01456ebc67d597"),
+                (1, "436f756e742d31",
+                 "59460bacdbe7a920ef2806a74937d5a691d6d5062d7daafcad7db7e4d8c649ad\
+                     ffe575c1889c5c2e3a49af8e3e"),
+                (256, "436f756e742d323536",
+                 "9b7f84224922d2a9edd7b2c2057f3bcf3a547f17570575e626202e593bfdd99e\
+                     9878a1af9e41ded58c7fb77d2f"),
+            ],
+            exports: &[
+                ("", "c23ebd4e7a0ad06a5dddf779f65004ce9481069ce0f0e6dd51a04539ddcbd5cd"),
+                ("00", "ed7ff5ca40a3d84561067ebc8e01702bc36cf1eb99d42a92004642b9dfaadd37"),
+                ("54657374436f6e74657874", "d3bae066aa8da27d527d85c040f7dd6ccb60221c902ee36a82f70bcd62a60ee4"),
+            ],
+        },
+    ];
+
+    // RFC 9180, appendix A.3: DHKEM(P-256, HKDF-SHA256), HKDF-SHA256,
+    // AES-128-GCM.
+    #[cfg(all(feature = "p256", feature = "aes"))]
+    static RFC9180_A3: [TestVector; 4] = [
+        TestVector {
+            mode: 0,
+            info: "4f6465206f6e2061204772656369616e2055726e",
+            ikm_e: "4270e54ffd08d79d5928020af4686d8f6b7d35dbe470265f1f5aa22816ce860e",
+            ikm_r: "668b37171f1072f3cf12ea8a236a45df23fc13b82af3609ad1e354f6ef817550",
+            ikm_s: "",
+            sk_r: "f3ce7fdae57e1a310d87f1ebbde6f328be0a99cdbcadf4d6589cf29de4b8ffd2",
+            pk_r: "04fe8c19ce0905191ebc298a9245792531f26f0cece2460639e8bc39cb7f706a\
+                826a779b4cf969b8a0e539c7f62fb3d30ad6aa8f80e30f1d128aafd68a2ce72e\
+                a0",
+            pk_s: "",
+            psk: "",
+            psk_id: "",
+            enc: "04a92719c6195d5085104f469a8b9814d5838ff72b60501e2c4466e5e67b325a\
+                c98536d7b61a1af4b78e5b7f951c0900be863c403ce65c9bfcb9382657222d18\
+                c4",
+            key: "868c066ef58aae6dc589b6cfdd18f97e",
+            base_nonce: "4e0bc5018beba4bf004cca59",
+            exporter_secret: "14ad94af484a7ad3ef40e9f3be99ecc6fa9036df9d4920548424df127ee0d99f",
+            encryptions: &[
+                (0, "436f756e742d30",
+                 "5ad590bb8baa577f8619db35a36311226a896e7342a6d836d8b7bcd2f20b6c7f\
+                     9076ac232e3ab2523f39513434"),
+                (1, "436f756e742d31",
+                 "fa6f037b47fc21826b610172ca9637e82d6e5801eb31cbd3748271affd4ecb06\
+                     646e0329cbdf3c3cd655b28e82"),
+                (256, "436f756e742d323536",
+                 "10f179686aa2caec1758c8e554513f16472bd0a11e2a907dde0b212cbe87d74f\
+                     367f8ffe5e41cd3e9962a6afb2"),
+            ],
+            exports: &[
+                ("", "5e9bc3d236e1911d95e65b576a8a86d478fb827e8bdfe77b741b289890490d4d"),
+                ("00", "6cff87658931bda83dc857e6353efe4987a201b849658d9b047aab4cf216e796"),
+                ("54657374436f6e74657874", "d8f1ea7942adbba7412c6d431c62d01371ea476b823eb697e1f6e6cae1dab85a"),
+            ],
+        },
+        TestVector {
+            mode: 1,
+            info: "4f6465206f6e2061204772656369616e2055726e",
+            ikm_e: "2afa611d8b1a7b321c761b483b6a053579afa4f767450d3ad0f84a39fda587a6",
+            ikm_r: "d42ef874c1913d9568c9405407c805baddaffd0898a00f1e84e154fa787b2429",
+            ikm_s: "",
+            sk_r: "438d8bcef33b89e0e9ae5eb0957c353c25a94584b0dd59c991372a75b43cb661",
+            pk_r: "040d97419ae99f13007a93996648b2674e5260a8ebd2b822e84899cd52d87446\
+                ea394ca76223b76639eccdf00e1967db10ade37db4e7db476261fcc8df97c5ff\
+                d1",
+            pk_s: "",
+            psk: "0247fd33b913760fa1fa51e1892d9f307fbe65eb171e8132c2af18555a738b82",
+            psk_id: "456e6e796e20447572696e206172616e204d6f726961",
+            enc: "04305d35563527bce037773d79a13deabed0e8e7cde61eecee403496959e89e4\
+                d0ca701726696d1485137ccb5341b3c1c7aaee90a4a02449725e744b1193b53b\
+                5f",
+            key: "55d9eb9d26911d4c514a990fa8d57048",
+            base_nonce: "b595dc6b2d7e2ed23af529b1",
+            exporter_secret: "895a723a1eab809804973a53c0ee18ece29b25a7555a4808277ad2651d66d705",
+            encryptions: &[
+                (0, "436f756e742d30",
+                 "90c4deb5b75318530194e4bb62f890b019b1397bbf9d0d6eb918890e1fb2be1a\
+                     c2603193b60a49c2126b75d0eb"),
+                (1, "436f756e742d31",
+                 "9e223384a3620f4a75b5a52f546b7262d8826dea18db5a365feb8b997180b22d\
+                     72dc1287f7089a1073a7102c27"),
+                (256, "436f756e742d323536",
+                 "faf985208858b1253b97b60aecd28bc18737b58d1242370e7703ec33b73a4c31\
+                     a1afee300e349adef9015bbbfd"),
+            ],
+            exports: &[
+                ("", "a115a59bf4dd8dc49332d6a0093af8efca1bcbfd3627d850173f5c4a55d0c185"),
+                ("00", "4517eaede0669b16aac7c92d5762dd459c301fa10e02237cd5aeb9be969430c4"),
+                ("54657374436f6e74657874", "164e02144d44b607a7722e58b0f4156e67c0c2874d74cf71da6ca48a4cbdc5e0"),
+            ],
+        },
+        TestVector {
+            mode: 2,
+            info: "4f6465206f6e2061204772656369616e2055726e",
+            ikm_e: "798d82a8d9ea19dbc7f2c6dfa54e8a6706f7cdc119db0813dacf8440ab37c857",
+            ikm_r: "7bc93bde8890d1fb55220e7f3b0c107ae7e6eda35ca4040bb6651284bf0747ee",
+            ikm_s: "874baa0dcf93595a24a45a7f042e0d22d368747daaa7e19f80a802af19204ba8",
+            sk_r: "d929ab4be2e59f6954d6bedd93e638f02d4046cef21115b00cdda2acb2a4440e",
+            pk_r: "04423e363e1cd54ce7b7573110ac121399acbc9ed815fae03b72ffbd4c18b018\
+                36835c5a09513f28fc971b7266cfde2e96afe84bb0f266920e82c4f53b36e1a7\
+                8d",
+            pk_s: "04a817a0902bf28e036d66add5d544cc3a0457eab150f104285df1e293b5c10e\
+                ef8651213e43d9cd9086c80b309df22cf37609f58c1127f7607e85f210b2804f\
+                73",
+            psk: "",
+            psk_id: "",
+            enc: "042224f3ea800f7ec55c03f29fc9865f6ee27004f818fcbdc6dc68932c1e52e1\
+                5b79e264a98f2c535ef06745f3d308624414153b22c7332bc1e691cb4af4d534\
+                54",
+            key: "19aa8472b3fdc530392b0e54ca17c0f5",
+            base_nonce: "b390052d26b67a5b8a8fcaa4",
+            exporter_secret: "f152759972660eb0e1db880835abd5de1c39c8e9cd269f6f082ed80e28acb164",
+            encryptions: &[
+                (0, "436f756e742d30",
+                 "82ffc8c44760db691a07c5627e5fc2c08e7a86979ee79b494a17cc3405446ac2\
+                     bdb8f265db4a099ed3289ffe19"),
+                (1, "436f756e742d31",
+                 "b0a705a54532c7b4f5907de51c13dffe1e08d55ee9ba59686114b05945494d96\
+                     725b239468f1229e3966aa1250"),
+                (256, "436f756e742d323536",
+                 "28e874512f8940fafc7d06135e7589f6b4198bc0f3a1c64702e72c9e6abaf9f0\
+                     5cb0d2f11b03a517898815c934"),
+            ],
+            exports: &[
+                ("", "837e49c3ff629250c8d80d3c3fb957725ed481e59e2feb57afd9fe9a8c7c4497"),
+                ("00", "594213f9018d614b82007a7021c3135bda7b380da4acd9ab27165c508640dbda"),
+                ("54657374436f6e74657874", "14fe634f95ca0d86e15247cca7de7ba9b73c9b9deb6437e1c832daf7291b79d5"),
+            ],
+        },
+        TestVector {
+            mode: 3,
+            info: "4f6465206f6e2061204772656369616e2055726e",
+            ikm_e: "3c1fceb477ec954c8d58ef3249e4bb4c38241b5925b95f7486e4d9f1d0d35fbb",
+            ikm_r: "abcc2da5b3fa81d8aabd91f7f800a8ccf60ec37b1b585a5d1d1ac77f258b6cca",
+            ikm_s: "6262031f040a9db853edd6f91d2272596eabbc78a2ed2bd643f770ecd0f19b82",
+            sk_r: "bdf4e2e587afdf0930644a0c45053889ebcadeca662d7c755a353d5b4e2a8394",
+            pk_r: "04d824d7e897897c172ac8a9e862e4bd820133b8d090a9b188b8233a64dfbc5f\
+                725aa0aa52c8462ab7c9188f1c4872f0c99087a867e8a773a13df48a627058e1\
+                b3",
+            pk_s: "049f158c750e55d8d5ad13ede66cf6e79801634b7acadcad72044eac2ae1d048\
+                0069133d6488bf73863fa988c4ba8bde1c2e948b761274802b4d8012af4f13af\
+                9e",
+            psk: "0247fd33b913760fa1fa51e1892d9f307fbe65eb171e8132c2af18555a738b82",
+            psk_id: "456e6e796e20447572696e206172616e204d6f726961",
+            enc: "046a1de3fc26a3d43f4e4ba97dbe24f7e99181136129c48fbe872d4743e2b131\
+                357ed4f29a7b317dc22509c7b00991ae990bf65f8b236700c82ab7c11a845114\
+                01",
+            key: "4d567121d67fae1227d90e11585988fb",
+            base_nonce: "67c9d05330ca21e5116ecda6",
+            exporter_secret: "3f479020ae186788e4dfd4a42a21d24f3faabb224dd4f91c2b2e5e9524ca27b2",
+            encryptions: &[
+                (0, "436f756e742d30",
+                 "b9f36d58d9eb101629a3e5a7b63d2ee4af42b3644209ab37e0a272d44365407d\
+                     b8e655c72e4fa46f4ff81b9246"),
+                (1, "436f756e742d31",
+                 "51788c4e5d56276771032749d015d3eea651af0c7bb8e3da669effffed299ea1\
+                     f641df621af65579c10fc09736"),
+                (256, "436f756e742d323536",
+                 "f380e19d291e12c5e378b51feb5cd50f6d00df6cb2af8393794c4df342126c2e\
+                     29633fe7e8ce49587531affd4d"),
+            ],
+            exports: &[
+                ("", "595ce0eff405d4b3bb1d08308d70a4e77226ce11766e0a94c4fdb5d90025c978"),
+                ("00", "110472ee0ae328f57ef7332a9886a1992d2c45b9b8d5abc9424ff68630f7d38d"),
+                ("54657374436f6e74657874", "18ee4d001a9d83a4c67e76f88dd747766576cac438723bad0700a910a4d717e6"),
+            ],
+        },
+    ];
+
+    // RFC 9180, appendix A.5: DHKEM(P-256, HKDF-SHA256), HKDF-SHA256,
+    // ChaCha20Poly1305.
+    #[cfg(feature = "p256")]
+    static RFC9180_A5: [TestVector; 4] = [
+        TestVector {
+            mode: 0,
+            info: "4f6465206f6e2061204772656369616e2055726e",
+            ikm_e: "f1f1a3bc95416871539ecb51c3a8f0cf608afb40fbbe305c0a72819d35c33f1f",
+            ikm_r: "61092f3f56994dd424405899154a9918353e3e008171517ad576b900ddb275e7",
+            ikm_s: "",
+            sk_r: "a4d1c55836aa30f9b3fbb6ac98d338c877c2867dd3a77396d13f68d3ab150d3b",
+            pk_r: "04a697bffde9405c992883c5c439d6cc358170b51af72812333b015621dc0f40\
+                bad9bb726f68a5c013806a790ec716ab8669f84f6b694596c2987cf35baba2a0\
+                06",
+            pk_s: "",
+            psk: "",
+            psk_id: "",
+            enc: "04c07836a0206e04e31d8ae99bfd549380b072a1b1b82e563c935c095827824f\
+                c1559eac6fb9e3c70cd3193968994e7fe9781aa103f5b50e934b5b2f387e3812\
+                91",
+            key: "a8f45490a92a3b04d1dbf6cf2c3939ad8bfc9bfcb97c04bffe116730c9dfe3fc",
+            base_nonce: "726b4390ed2209809f58c693",
+            exporter_secret: "4f9bd9b3a8db7d7c3a5b9d44fdc1f6e37d5d77689ade5ec44a7242016e6aa205",
+            encryptions: &[
+                (0, "436f756e742d30",
+                 "6469c41c5c81d3aa85432531ecf6460ec945bde1eb428cb2fedf7a29f5a685b4\
+                     ccb0d057f03ea2952a27bb458b"),
+                (1, "436f756e742d31",
+                 "f1564199f7e0e110ec9c1bcdde332177fc35c1adf6e57f8d1df24022227ffa87\
+                     16862dbda2b1dc546c9d114374"),
+                (256, "436f756e742d323536",
+                 "b45b69d419a9be7219d8c94365b89ad6951caf4576ea4774ea40e9b7047a09d6\
+                     537d1aa2f7c12d6ae4b729b4d0"),
+            ],
+            exports: &[
+                ("", "9b13c510416ac977b553bf1741018809c246a695f45eff6d3b0356dbefe1e660"),
+                ("00", "6c8b7be3a20a5684edecb4253619d9051ce8583baf850e0cb53c402bdcaf8ebb"),
+                ("54657374436f6e74657874", "477a50d804c7c51941f69b8e32fe8288386ee1a84905fe4938d58972f24ac938"),
+            ],
+        },
+        TestVector {
+            mode: 1,
+            info: "4f6465206f6e2061204772656369616e2055726e",
+            ikm_e: "e1a4e1d50c4bfcf890f2b4c7d6b2d2aca61368eddc3c84162df2856843e1057a",
+            ikm_r: "ee51dec304abf993ef8fd52aacdd3b539108bbf6e491943266c1de89ec596a17",
+            ikm_s: "",
+            sk_r: "12ecde2c8bc2d5d7ed2219c71f27e3943d92b344174436af833337c557c300b3",
+            pk_r: "041eb8f4f20ab72661af369ff3231a733672fa26f385ffb959fd1bae46bfda43\
+                ad55e2d573b880831381d9367417f554ce5b2134fbba5235b44db465feffc618\
+                9e",
+            pk_s: "",
+            psk: "0247fd33b913760fa1fa51e1892d9f307fbe65eb171e8132c2af18555a738b82",
+            psk_id: "456e6e796e20447572696e206172616e204d6f726961",
+            enc: "04f336578b72ad7932fe867cc4d2d44a718a318037a0ec271163699cee653fa8\
+                05c1fec955e562663e0c2061bb96a87d78892bff0cc0bad7906c2d998ebe1a72\
+                46",
+            key: "6d61cb330b7771168c8619498e753f16198aad9566d1f1c6c70e2bc1a1a8b142",
+            base_nonce: "0de7655fb65e1cd51a38864e",
+            exporter_secret: "754ca00235b245e72d1f722a7718e7145bd113050a2aa3d89586d4cb7514bfdb",
+            encryptions: &[
+                (0, "436f756e742d30",
+                 "21433eaff24d7706f3ed5b9b2e709b07230e2b11df1f2b1fe07b3c70d5948a53\
+                     d6fa5c8bed194020bd9df0877b"),
+                (1, "436f756e742d31",
+                 "c74a764b4892072ea8c2c56b9bcd46c7f1e9ca8cb0a263f8b40c2ba59ac9c857\
+                     033f176019562218769d3e0452"),
+                (256, "436f756e742d323536",
+                 "1ea6326c8098ed0437a553c466550114fb2ca1412cca7de98709b9ccdf19206e\
+                     52c3d39180e2cf62b3e9f4baf4"),
+            ],
+            exports: &[
+                ("", "530bbc2f68f078dccc89cc371b4f4ade372c9472bafe4601a8432cbb934f528d"),
+                ("00", "6e25075ddcc528c90ef9218f800ca3dfe1b8ff4042de5033133adb8bd54c401d"),
+                ("54657374436f6e74657874", "6f6fbd0d1c7733f796461b3235a856cc34f676fe61ed509dfc18fa16efe6be78"),
+            ],
+        },
+        TestVector {
+            mode: 2,
+            info: "4f6465206f6e2061204772656369616e2055726e",
+            ikm_e: "0ecd212019008138a31f9104d5dba76b9f8e34d5b996041fff9e3df221dd0d5d",
+            ikm_r: "d32236d8378b9563840653789eb7bc33c3c720e537391727bf1c812d0eac110f",
+            ikm_s: "0e6be0851283f9327295fd49858a8c8908ea9783212945eef6c598ee0a3cedbb",
+            sk_r: "3cb2c125b8c5a81d165a333048f5dcae29a2ab2072625adad66dbb0f48689af9",
+            pk_r: "0444f6ee41818d9fe0f8265bffd016b7e2dd3964d610d0f7514244a60dbb7a11\
+                ece876bb110a97a2ac6a9542d7344bf7d2bd59345e3e75e497f7416cf38d2962\
+                33",
+            pk_s: "04265529a04d4f46ab6fa3af4943774a9f1127821656a75a35fade898a9a1b01\
+                4f64d874e88cddb24c1c3d79004d3a587db67670ca357ff4fba7e8b56ec013b9\
+                8b",
+            psk: "",
+            psk_id: "",
+            enc: "040d5176aedba55bc41709261e9195c5146bb62d783031280775f32e507d79b5\
+                cbc5748b6be6359760c73cfe10ca19521af704ca6d91ff32fc0739527b9385d4\
+                15",
+            key: "cf292f8a4313280a462ce55cde05b5aa5744fe4ca89a5d81b0146a5eaca8092d",
+            base_nonce: "7e45c21e20e869ae00492123",
+            exporter_secret: "dba6e307f71769ba11e2c687cc19592f9d436da0c81e772d7a8a9fd28e54355f",
+            encryptions: &[
+                (0, "436f756e742d30",
+                 "25881f219935eec5ba70d7b421f13c35005734f3e4d959680270f55d71e2f5cb\
+                     3bd2daced2770bf3d9d4916872"),
+                (1, "436f756e742d31",
+                 "653f0036e52a376f5d2dd85b3204b55455b7835c231255ae098d09ed138719b9\
+                     7185129786338ab6543f753193"),
+                (256, "436f756e742d323536",
+                 "e2276ec5047bc4b6ed57d6da7da2fb47a77502f0a30f17d040247c73da336d72\
+                     2bc6c89adf68396a0912c6d152"),
+            ],
+            exports: &[
+                ("", "56c4d6c1d3a46c70fd8f4ecda5d27c70886e348efb51bd5edeaa39ff6ce34389"),
+                ("00", "d2d3e48ed76832b6b3f28fa84be5f11f09533c0e3c71825a34fb0f1320891b51"),
+                ("54657374436f6e74657874", "eb0d312b6263995b4c7761e64b688c215ffd6043ff3bad2368c862784cbe6eff"),
+            ],
+        },
+        TestVector {
+            mode: 3,
+            info: "4f6465206f6e2061204772656369616e2055726e",
+            ikm_e: "f3a07f194703e321ef1f753a1b9fe27a498dfdfa309151d70bedd896c239c499",
+            ikm_r: "1240e55a0a03548d7f963ef783b6a7362cb505e6b31dfd04c81d9b294543bfbd",
+            ikm_s: "ce2a0387a2eb8870a3a92c34a2975f0f3f271af4384d446c7dc1524a6c6c515a",
+            sk_r: "c29fc577b7e74d525c0043f1c27540a1248e4f2c8d297298e99010a92e94865c",
+            pk_r: "04d383fd920c42d018b9d57fd73a01f1eee480008923f67d35169478e55d2e88\
+                17068daf62a06b10e0aad4a9e429fa7f904481be96b79a9c231a33e956c20b81\
+                b6",
+            pk_s: "0492cf8c9b144b742fe5a63d9a181a19d416f3ec8705f24308ad316564823c34\
+                4e018bd7c03a33c926bb271b28ef5bf28c0ca00abff249fee5ef7f33315ff34f\
+                db",
+            psk: "0247fd33b913760fa1fa51e1892d9f307fbe65eb171e8132c2af18555a738b82",
+            psk_id: "456e6e796e20447572696e206172616e204d6f726961",
+            enc: "043539917ee26f8ae0aa5f784a387981b13de33124a3cde88b94672030183110\
+                f331400115855808244ff0c5b6ca6104483ac95724481d41bdcd9f15b430ad16\
+                f6",
+            key: "31e140c8856941315d4067239fdc4ebe077fbf45a6fc78a61e7a6c8b3bacb10a",
+            base_nonce: "75838a8010d2e4760254dd56",
+            exporter_secret: "600895965755db9c5027f25f039a6e3e506c35b3b7084ce33c4a48d59ee1f0e3",
+            encryptions: &[
+                (0, "436f756e742d30",
+                 "9eadfa0f954835e7e920ffe56dec6b31a046271cf71fdda55db72926e1d8fae9\
+                     4cc6280fcfabd8db71eaa65c05"),
+                (1, "436f756e742d31",
+                 "e357ad10d75240224d4095c9f6150a2ed2179c0f878e4f2db8ca95d365d174d0\
+                     59ff8c3eb38ea9a65cfc8eaeb8"),
+                (256, "436f756e742d323536",
+                 "fb857f4185ce5286c1a52431867537204963ea66a3eee8d2a74419fd8751faee\
+                     066d08277ac7880473aa4143ba"),
+            ],
+            exports: &[
+                ("", "c52b4592cd33dd38b2a3613108ddda28dcf7f03d30f2a09703f758bfa8029c9a"),
+                ("00", "2f03bebc577e5729e148554991787222b5c2a02b77e9b1ac380541f710e5a318"),
+                ("54657374436f6e74657874", "e01dd49e8bfc3d9216abc1be832f0418adf8b47a7b5a330a7436c31e33d765d7"),
+            ],
+        },
+    ];
+
+    // RFC 9180, appendix A.7: DHKEM(X25519, HKDF-SHA256), HKDF-SHA256,
+    // export-only AEAD.
+    static RFC9180_A7: [TestVector; 4] = [
+        TestVector {
+            mode: 0,
+            info: "4f6465206f6e2061204772656369616e2055726e",
+            ikm_e: "55bc245ee4efda25d38f2d54d5bb6665291b99f8108a8c4b686c2b14893ea5d9",
+            ikm_r: "683ae0da1d22181e74ed2e503ebf82840deb1d5e872cade20f4b458d99783e31",
+            ikm_s: "",
+            sk_r: "33d196c830a12f9ac65d6e565a590d80f04ee9b19c83c87f2c170d972a812848",
+            pk_r: "194141ca6c3c3beb4792cd97ba0ea1faff09d98435012345766ee33aae2d7664",
+            pk_s: "",
+            psk: "",
+            psk_id: "",
+            enc: "e5e8f9bfff6c2f29791fc351d2c25ce1299aa5eaca78a757c0b4fb4bcd830918",
+            key: "",
+            base_nonce: "",
+            exporter_secret: "79dc8e0509cf4a3364ca027e5a0138235281611ca910e435e8ed58167c72f79b",
+            encryptions: &[],
+            exports: &[
+                ("", "7a36221bd56d50fb51ee65edfd98d06a23c4dc87085aa5866cb7087244bd2a36"),
+                ("00", "d5535b87099c6c3ce80dc112a2671c6ec8e811a2f284f948cec6dd1708ee33f0"),
+                ("54657374436f6e74657874", "ffaabc85a776136ca0c378e5d084c9140ab552b78f039d2e8775f26efff4c70e"),
+            ],
+        },
+        TestVector {
+            mode: 1,
+            info: "4f6465206f6e2061204772656369616e2055726e",
+            ikm_e: "c51211a8799f6b8a0021fcba673d9c4067a98ebc6794232e5b06cb9febcbbdf5",
+            ikm_r: "5e0516b1b29c0e13386529da16525210c796f7d647c37eac118023a6aa9eb89a",
+            ikm_s: "",
+            sk_r: "98f304d4ecb312689690b113973c61ffe0aa7c13f2fbe365e48f3ed09e5a6a0c",
+            pk_r: "d53af36ea5f58f8868bb4a1333ed4cc47e7a63b0040eb54c77b9c8ec456da824",
+            pk_s: "",
+            psk: "0247fd33b913760fa1fa51e1892d9f307fbe65eb171e8132c2af18555a738b82",
+            psk_id: "456e6e796e20447572696e206172616e204d6f726961",
+            enc: "d3805a97cbcd5f08babd21221d3e6b362a700572d14f9bbeb94ec078d051ae3d",
+            key: "",
+            base_nonce: "",
+            exporter_secret: "04261818aeae99d6aba5101bd35ddf3271d909a756adcef0d41389d9ed9ab153",
+            encryptions: &[],
+            exports: &[
+                ("", "be6c76955334376aa23e936be013ba8bbae90ae74ed995c1c6157e6f08dd5316"),
+                ("00", "1721ed2aa852f84d44ad020c2e2be4e2e6375098bf48775a533505fd56a3f416"),
+                ("54657374436f6e74657874", "7c9d79876a288507b81a5a52365a7d39cc0fa3f07e34172984f96fec07c44cba"),
+            ],
+        },
+        TestVector {
+            mode: 2,
+            info: "4f6465206f6e2061204772656369616e2055726e",
+            ikm_e: "43b078912a54b591a7b09b16ce89a1955a9dd60b29fb611e044260046e8b061b",
+            ikm_r: "fc9407ae72ed614901ebf44257fb540f617284b5361cfecd620bafc4aba36f73",
+            ikm_s: "2ff4c37a17b2e54046a076bf5fea9c3d59250d54d0dc8572bc5f7c046307040c",
+            sk_r: "ed88cda0e91ca5da64b6ad7fc34a10f096fa92f0b9ceff9d2c55124304ed8b4a",
+            pk_r: "ffd7ac24694cb17939d95feb7c4c6539bb31621deb9b96d715a64abdd9d14b10",
+            pk_s: "89eb1feae431159a5250c5186f72a15962c8d0debd20a8389d8b6e4996e14306",
+            psk: "",
+            psk_id: "",
+            enc: "5ac1671a55c5c3875a8afe74664aa8bc68830be9ded0c5f633cd96400e8b5c05",
+            key: "",
+            base_nonce: "",
+            exporter_secret: "276d87e5cb0655c7d3dad95e76e6fc02746739eb9d968955ccf8a6346c97509e",
+            encryptions: &[],
+            exports: &[
+                ("", "83c1bac00a45ed4cb6bd8a6007d2ce4ec501f55e485c5642bd01bf6b6d7d6f0a"),
+                ("00", "08a1d1ad2af3ef5bc40232a64f920650eb9b1034fac3892f729f7949621bf06e"),
+                ("54657374436f6e74657874", "ff3b0e37a9954247fea53f251b799e2edd35aac7152c5795751a3da424feca73"),
+            ],
+        },
+        TestVector {
+            mode: 3,
+            info: "4f6465206f6e2061204772656369616e2055726e",
+            ikm_e: "94efae91e96811a3a49fd1b20eb0344d68ead6ac01922c2360779aa172487f40",
+            ikm_r: "4dfde6fadfe5cb50fced4034e84e6d3a104aa4bf2971360032c1c0580e286663",
+            ikm_s: "26c12fef8d71d13bbbf08ce8157a283d5e67ecf0f345366b0e90341911110f1b",
+            sk_r: "c4962a7f97d773a47bdf40db4b01dc6a56797c9e0deaab45f4ea3aa9b1d72904",
+            pk_r: "f47cd9d6993d2e2234eb122b425accfb486ee80f89607b087094e9f413253c2d",
+            pk_s: "29a5bf3867a6128bbdf8e070abe7fe70ca5e07b629eba5819af73810ee20112f",
+            psk: "0247fd33b913760fa1fa51e1892d9f307fbe65eb171e8132c2af18555a738b82",
+            psk_id: "456e6e796e20447572696e206172616e204d6f726961",
+            enc: "81cbf4bd7eee97dd0b600252a1c964ea186846252abb340be47087cc78f3d87c",
+            key: "",
+            base_nonce: "",
+            exporter_secret: "695b1faa479c0e0518b6414c3b46e8ef5caea04c0a192246843765ae6a8a78e0",
+            encryptions: &[],
+            exports: &[
+                ("", "dafd8beb94c5802535c22ff4c1af8946c98df2c417e187c6ccafe45335810b58"),
+                ("00", "7346bb0b56caf457bcc1aa63c1b97d9834644bdacac8f72dbbe3463e4e46b0dd"),
+                ("54657374436f6e74657874", "84f3466bd5a03bde6444324e63d7560e7ac790da4e5bbab01e7c4d575728c34a"),
+            ],
+        },
+    ];
+
+    // DHKEM(X448, HKDF-SHA512), HKDF-SHA512, ChaCha20Poly1305 (not
+    // printed in RFC 9180, but part of the same test vector set).
+    #[cfg(feature = "x448")]
+    static RFC9180_X448: [TestVector; 2] = [
+        TestVector {
+            mode: 0,
+            info: "4f6465206f6e2061204772656369616e2055726e",
+            ikm_e: "178e4db14a03ebf5b5205e11a3c3918431b4d4bb143b62a52bebdd61d107d231\
+                22868395cca3dbc46e98964d4c1dfdc4b0e05cbb2934d9e5",
+            ikm_r: "1a91ec4a112661d663caad07437e07486dcc80b499c83c6bf17fb2faba77c180\
+                404d983bd32ed4284fa1aee3bb3887b61402036b058c3c8d",
+            ikm_s: "",
+            sk_r: "c2f51845154d6bb6917e44ef0fa0a1fbf1d80f61d199486e75295e8a7e50432d\
+                548a7f8040953826c4f1bce79e433dedb4469391c3cc98a1",
+            pk_r: "f2fdb31a7829a6d2d78b9d8b670397457c92cb2417af37dbe0c1c12a9547e4ed\
+                a9fde09fc3fe0f359bb7b4151e8a6fb592530af71d9dc0b5",
+            pk_s: "",
+            psk: "",
+            psk_id: "",
+            enc: "3d4f6aa08c635205bcd96a0791695d08638714474b4d2c0132b69e25cdb826e1\
+                a2a84bc0c40c4fc75f52051b034e0afa82b8457e28794f92",
+            key: "87ad565738a70049699288c975dc90faddb076f6280136cee4c26c3111f64e0d",
+            base_nonce: "b76f001f82b908e92ad2639a",
+            exporter_secret: "d42d015324e068d95aa4e5d3dc53a7165f4963a5c30c8d073ce286ee4ecd29e3\
+                7df81b897e1698e943d4273397f860299c37db445aafe499ece9f6cb1bbfb768",
+            encryptions: &[
+                (0, "436f756e742d30",
+                 "4df124bd68d45b84dd5b82146597cdab8b56ab618166f814c2fe98ce35f43b09\
+                     917283a58810aac71e852bff0a"),
+                (1, "436f756e742d31",
+                 "5b78efb13bcbbbc2bb69aed60c30287c20c15fc708ed19fe007ffa796e5be083\
+                     2cb09ca389b4afc15101acf3c4"),
+                (256, "436f756e742d323536",
+                 "5fb4eb4203afa6d24d86577d09062dd989cdfac0ec2b979bade53cad9fd9972a\
+                     2426d58337bbe4d862f12285c9"),
+            ],
+            exports: &[
+                ("", "d13d9f30a9de3369f25b8de6a733d9c5b68a79b148a662a44cb84e9296419ed6"),
+                ("00", "e584af331daaab516a39e2ba8a3421e428918e108c88dda9e921fc6ecb86fd5f"),
+                ("54657374436f6e74657874", "f4b7df9f1f608657f97084d9847cab976e88083fd2d35f3636dcaa9a14ce62e5"),
+            ],
+        },
+        TestVector {
+            mode: 3,
+            info: "4f6465206f6e2061204772656369616e2055726e",
+            ikm_e: "963266c3f339c24f0bd233a2951a8b829efcdb7b598cb48b6c5cb30446f986ff\
+                c2a78f3fdebab08c58431f2b67c6beb4a2167e9b423feca0",
+            ikm_r: "abb2f8e7bece4050fccbc8ae0a70ea83bb2d829dbbc20d480238f3226cd8f42a\
+                93cc83e72010fff033c1638a20421ceac4288ca372d2c088",
+            ikm_s: "01cbb9affb519cdae3e479a5d76f5829e9fb3b5ef81fee15e33ee7244508fe41\
+                263168780a23226d601f4cfbe04a6e94165a684f72e076c6",
+            sk_r: "c6c5c40c8d6e90140dceadd36207a3663d63d1e3c77ce66c64eff2f7842106b2\
+                e1f7eb27308c1b5059937dcb1d0219ac7aada27641913810",
+            pk_r: "3e594e34092e43967bb2de3ff8238240ec42ac0ead806f220909ebea97e5bad5\
+                4dff5ce4e42cb9fec8f9e1080cce7bab3e432d6c4e40fcab",
+            pk_s: "a30529359f7e0c3d9ff9fc337ef7e58bec802f8a70c1e5a79eb7b0a86f37225c\
+                79d337f8d450b329bba26a2afbfc807fd3b6061903ac650e",
+            psk: "0247fd33b913760fa1fa51e1892d9f307fbe65eb171e8132c2af18555a738b82",
+            psk_id: "456e6e796e20447572696e206172616e204d6f726961",
+            enc: "154435de9c8b92bf3619abe9ec981b1d4116b77fdcd38da89d0bb6e0dbacfe19\
+                21f08e8afaed9dc2972c14c24516aaa9de168fcb14a65344",
+            key: "c4f74b6c33abac5e5d38f8d6dd7ef2dfa22102bf1183ddd3a635882ce328ac83",
+            base_nonce: "3903dda4a1f7d6f915790a0b",
+            exporter_secret: "afff14fec0130383cc29c6d36209271ea5f6fedaa91b46dd1b58eaacc27cd208\
+                113456e84a774bbba159addf3c6450af0ca02e2b4067482d92d84445a45e0c92",
+            encryptions: &[
+                (0, "436f756e742d30",
+                 "b792898afbc2f976bd287e3975d4f1ef838e4af161f77f1d78dbf0027fe846fd\
+                     33a4b120e5d67a0acd12d904d4"),
+                (1, "436f756e742d31",
+                 "94fe1a8f9ab2c2408be2593636b6c6e746bb95df8910f79a47cd8eef3aab3b57\
+                     0971ad94b6e5e6351c40bd98b2"),
+                (256, "436f756e742d323536",
+                 "f6c7c47c6fabde774f6e8cec6ced7d05e1070f6aa456125d6209f537956d7528\
+                     ebba2c06dbb4423ad8c259974b"),
+            ],
+            exports: &[
+                ("", "2394e29f37a25f70847dae21097ec400d70a89e808fd7169b58561ebdb41157d"),
+                ("00", "bffc1b9dcc7c4037bebed54b315270a703b99cd9fbce2caad7115dce707c6fdb"),
+                ("54657374436f6e74657874", "65bdf3ccccae2d2b1dbc3fe2939b4a88d43f068105d149aca16356174abe73cd"),
+            ],
+        },
+    ];
+
+    fn check_vectors<K: Kem, F: Kdf, A: Aead>(tvs: &[TestVector]) {
+        let pt = hex::decode("4265617574792069732074727574682c20747275746820626561757479").unwrap();
+        for tv in tvs.iter() {
+            let info = hex::decode(tv.info).unwrap();
+            let ikm_e = hex::decode(tv.ikm_e).unwrap();
+            let (sk_r, pk_r) = K::derive_key_pair(
+                &hex::decode(tv.ikm_r).unwrap());
+            assert!(K::encode_private_key(&sk_r).as_ref()
+                == &hex::decode(tv.sk_r).unwrap()[..]);
+            assert!(K::encode_public_key(&pk_r).as_ref()
+                == &hex::decode(tv.pk_r).unwrap()[..]);
+            let sk_r = K::decode_private_key(
+                &hex::decode(tv.sk_r).unwrap()).unwrap();
+            let psk = hex::decode(tv.psk).unwrap();
+            let psk_id = hex::decode(tv.psk_id).unwrap();
+            let psk = if psk.is_empty() {
+                None
+            } else {
+                Some((&psk[..], &psk_id[..]))
+            };
+            let ks = if tv.ikm_s.is_empty() {
+                None
+            } else {
+                let (sk_s, pk_s) = K::derive_key_pair(
+                    &hex::decode(tv.ikm_s).unwrap());
+                assert!(K::encode_public_key(&pk_s).as_ref()
+                    == &hex::decode(tv.pk_s).unwrap()[..]);
+                Some((sk_s, pk_s))
+            };
+            let sk_s = ks.as_ref().map(|k| &k.0);
+            let pk_s = ks.as_ref().map(|k| &k.1);
+            assert!(Context::<K, F, A>::get_mode(psk, ks.is_some())
+                .unwrap().0 == tv.mode);
+
+            let (enc, mut ctx_s) = Context::<K, F, A>::setup_sender_with_ikm(
+                &ikm_e, &pk_r, &info, psk, sk_s).unwrap();
+            assert!(enc.as_ref() == &hex::decode(tv.enc).unwrap()[..]);
+            let mut ctx_r = Context::<K, F, A>::setup_recipient(
+                enc.as_ref(), &sk_r, &info, psk, pk_s).unwrap();
+            let nh = <F::Hash as Digest>::output_size();
+            for ctx in [&ctx_s, &ctx_r].iter() {
+                assert!(ctx.key[..A::N_K] == hex::decode(tv.key).unwrap()[..]);
+                assert!(ctx.base_nonce[..A::N_N]
+                    == hex::decode(tv.base_nonce).unwrap()[..]);
+                assert!(ctx.exporter_secret[..nh]
+                    == hex::decode(tv.exporter_secret).unwrap()[..]);
+            }
+
+            for &(seq, aad, ct) in tv.encryptions.iter() {
+                let aad = hex::decode(aad).unwrap();
+                let ct = hex::decode(ct).unwrap();
+                ctx_s.seq = seq;
+                ctx_r.seq = seq;
+                let mut buf = pt.clone();
+                let tag = ctx_s.seal_in_place(&aad, &mut buf).unwrap();
+                assert!(buf[..] == ct[..pt.len()]);
+                assert!(tag[..A::N_T] == ct[pt.len()..]);
+                assert!(ctx_r.open_in_place(&aad, &mut buf, &tag[..A::N_T]));
+                assert!(buf == pt);
+            }
+
+            for &(ec, ev) in tv.exports.iter() {
+                let ec = hex::decode(ec).unwrap();
+                let ev = hex::decode(ev).unwrap();
+                let mut out = [0u8; 32];
+                ctx_s.export(&ec, &mut out);
+                assert!(out[..] == ev[..]);
+                ctx_r.export(&ec, &mut out);
+                assert!(out[..] == ev[..]);
+            }
+        }
+    }
+
+    #[cfg(feature = "aes")]
+    #[test]
+    fn rfc9180_x25519_aes128gcm() {
+        check_vectors::<DhKemX25519, HkdfSha256, Aes128Gcm>(&RFC9180_A1);
+    }
+
+    #[test]
+    fn rfc9180_x25519_chacha20poly1305() {
+        check_vectors::<DhKemX25519, HkdfSha256, ChaCha20Poly1305>(
+            &RFC9180_A2);
+    }
+
+    #[cfg(all(feature = "p256", feature = "aes"))]
+    #[test]
+    fn rfc9180_p256_aes128gcm() {
+        check_vectors::<DhKemP256, HkdfSha256, Aes128Gcm>(&RFC9180_A3);
+    }
+
+    #[cfg(feature = "p256")]
+    #[test]
+    fn rfc9180_p256_chacha20poly1305() {
+        check_vectors::<DhKemP256, HkdfSha256, ChaCha20Poly1305>(
+            &RFC9180_A5);
+    }
+
+    #[test]
+    fn rfc9180_x25519_export_only() {
+        check_vectors::<DhKemX25519, HkdfSha256, ExportOnly>(&RFC9180_A7);
+    }
+
+    #[cfg(feature = "x448")]
+    #[test]
+    fn rfc9180_x448_chacha20poly1305() {
+        check_vectors::<DhKemX448, HkdfSha512, ChaCha20Poly1305>(
+            &RFC9180_X448);
+    }
+
+    fn roundtrip<K: Kem, F: Kdf, A: Aead>() {
+        let mut sh = Sha256::new();
+        for i in 0..20u8 {
+            sh.update(K::KEM_ID.to_be_bytes());
+            sh.update([i]);
+            let seed = sh.clone().finalize();
+            let (sk_r, pk_r) = K::derive_key_pair(&seed[..16]);
+            let (sk_s, pk_s) = K::derive_key_pair(&seed[16..]);
+            let skb = K::encode_private_key(&sk_r);
+            let pkb = K::encode_public_key(&pk_r);
+            assert!(skb.as_ref().len() == K::N_SK);
+            assert!(pkb.as_ref().len() == K::N_PK);
+            let sk_r = K::decode_private_key(skb.as_ref()).unwrap();
+            let pk_r = K::decode_public_key(pkb.as_ref()).unwrap();
+            let psk: &[u8] = &seed[..];
+            let psk_id: &[u8] = b"psk id";
+            let info = [i; 5];
+
+            for mode in 0..4 {
+                let psk = if (mode & 1) != 0 {
+                    Some((psk, psk_id))
+                } else {
+                    None
+                };
+                let (sk_s, pk_s) = if (mode & 2) != 0 {
+                    (Some(&sk_s), Some(&pk_s))
+                } else {
+                    (None, None)
+                };
+                let (enc, mut ctx_s) = Context::<K, F, A>
+                    ::setup_sender_with_ikm(&seed, &pk_r, &info, psk, sk_s)
+                    .unwrap();
+                let mut ctx_r = Context::<K, F, A>::setup_recipient(
+                    enc.as_ref(), &sk_r, &info, psk, pk_s).unwrap();
+                let mut e1 = [0u8; 40];
+                let mut e2 = [0u8; 40];
+                ctx_s.export(b"exp", &mut e1);
+                ctx_r.export(b"exp", &mut e2);
+                assert!(e1 == e2);
+
+                // Recipient with the wrong parameters gets a different
+                // context.
+                let bad_info = [i; 4];
+                let ctx_x = Context::<K, F, A>::setup_recipient(
+                    enc.as_ref(), &sk_r, &bad_info, psk, pk_s).unwrap();
+                ctx_x.export(b"exp", &mut e2);
+                assert!(e1 != e2);
+
+                if A::N_K == 0 {
+                    let mut buf = [0u8; 5];
+                    assert!(ctx_s.seal_in_place(b"", &mut buf).is_none());
+                    continue;
+                }
+                for j in 0..3 {
+                    let mut msg = [i; 17];
+                    msg[0] = j;
+                    let mut buf = msg;
+                    let tag = ctx_s.seal_in_place(&[j], &mut buf).unwrap();
+                    assert!(buf != msg);
+                    let mut bad_tag = tag;
+                    bad_tag[3] ^= 0x01;
+                    assert!(!ctx_r.open_in_place(&[j], &mut buf, &bad_tag));
+                    assert!(!ctx_r.open_in_place(&[j, 0], &mut buf, &tag));
+                    assert!(ctx_r.open_in_place(&[j], &mut buf, &tag));
+                    assert!(buf == msg);
+                }
+            }
+
+            // Empty PSK parameters are rejected.
+            assert!(Context::<K, F, A>::setup_sender_with_ikm(&seed, &pk_r,
+                &info, Some((b"", psk_id)), None).is_none());
+            assert!(Context::<K, F, A>::setup_recipient(
+                K::encode_public_key(&pk_s).as_ref(), &sk_r,
+                &info, Some((psk, b"")), None).is_none());
+        }
+    }
+
+    #[test]
+    fn roundtrip_x25519() {
+        roundtrip::<DhKemX25519, HkdfSha256, ChaCha20Poly1305>();
+        roundtrip::<DhKemX25519, HkdfSha384, ExportOnly>();
+    }
+
+    #[cfg(feature = "x448")]
+    #[test]
+    fn roundtrip_x448() {
+        roundtrip::<DhKemX448, HkdfSha512, ChaCha20Poly1305>();
+    }
+
+    #[cfg(feature = "p256")]
+    #[test]
+    fn roundtrip_p256() {
+        roundtrip::<DhKemP256, HkdfSha256, ChaCha20Poly1305>();
+    }
+
+    #[cfg(feature = "jq255e")]
+    #[test]
+    fn roundtrip_jq255e() {
+        roundtrip::<DhKemJq255e, HkdfSha256, ChaCha20Poly1305>();
+    }
+
+    #[cfg(feature = "gls254")]
+    #[test]
+    fn roundtrip_gls254() {
+        roundtrip::<DhKemGls254, HkdfSha256, ChaCha20Poly1305>();
+    }
+
+    #[test]
+    fn low_order() {
+        // X25519: an all-zero DH output (low order point) is rejected.
+        let (sk_r, _) = DhKemX25519::derive_key_pair(b"low order test");
+        assert!(SuiteX25519::setup_recipient(&[0u8; 32], &sk_r, b"",
+            None, None).is_none());
+        let mut one = [0u8; 32];
+        one[0] = 1;
+        assert!(SuiteX25519::setup_sender_with_ikm(b"ikm", &one, b"",
+            None, None).is_none());
+    }
+}
@@ -37,6 +37,31 @@
 //! of the last byte) is always zero. The decoding process verifies that
 //! the top bit is indeed zero.
 //!
+//! # Truncated Signatures
+//!
+//! Signatures are 48 bytes (a 16-byte challenge followed by a 32-byte
+//! scalar). `PublicKey::verify_trunc()` supports _truncated signatures_:
+//! the last few bits (between 8 and 32) are considered to have been
+//! reused for encoding other data, and thus are ignored. The
+//! verification function then tries to recompute the complete,
+//! original, untruncated signature, which it returns on success. This
+//! process does not involve the private key and is thus safe.
+//!
+//! Contrary to ECDSA or EdDSA, the per-signature point is not part of
+//! the signature and must be recomputed for each candidate value of the
+//! missing bits, followed by a hash function invocation: the search is
+//! exhaustive. Since the scalar is lower than the group order `r`
+//! (close to 2^254), removing `rm` bits leaves about 2^(rm-2)
+//! candidates; each costs one point addition, an amortized
+//! normalization, and one BLAKE2s evaluation over the message data,
+//! i.e. about 1/50 of the cost of a plain signature verification (for a
+//! short message). With `rm` = 16, a truncated verification thus costs
+//! up to about 350 plain verifications, and each extra removed bit
+//! doubles that cost, so that truncating more than 20 bits or so is
+//! impractical in most contexts. Up to 32 bits can still be removed,
+//! but with `rm` = 32 the search is 65536 times more expensive than
+//! with `rm` = 16.
+//!
 //! [double-odd site]: https://doubleodd.group/
 
 // Projective/fractional coordinates traditionally use uppercase letters,
@@ -47,7 +72,7 @@ use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 use core::convert::TryFrom;
 use super::field::{GF255e, ModInt256};
 use super::blake2s::Blake2s256;
-use super::{CryptoRng, RngCore};
+use super::{CryptoRng, RngCore, HashName};
 use super::{Zu128, Zu256, Zu384};
 
 /// An element in the jq255e group.
@@ -1628,6 +1653,20 @@ impl PrivateKey {
         self.sign_seeded(&[0u8; 0], hash_name, data)
     }
 
+    /// Signs a message with this private key.
+    ///
+    /// The message has been hashed into the provided hasher (`hasher`),
+    /// which is finalized here; the hash value is signed along with the
+    /// name of the hash function (`D::HASH_NAME`). This is equivalent to
+    /// calling `sign()` with the hash value and the hash function name.
+    ///
+    /// This function uses a deterministic process to compute the
+    /// per-signature secret scalar.
+    pub fn sign_digest<D: HashName>(self, hasher: D) -> [u8; 48] {
+        let hv = digest::Digest::finalize(hasher);
+        self.sign(D::HASH_NAME, &hv)
+    }
+
     /// Signs a message with this private key.
     ///
     /// The data to sign is provided as `data`. When using raw data,
@@ -1704,7 +1743,8 @@ impl PrivateKey {
 
         // Use k to generate the signature.
         let R = Point::mulgen(&k);
-        let cb = make_challenge(&R, &self.public_key.encoded, hash_name, data);
+        let cb = make_challenge(&R.encode(), &self.public_key.encoded,
+            hash_name, data);
         let s = k + self.sec * Scalar::from_u128(u128::from_le_bytes(cb));
         let mut sig = [0u8; 48];
         sig[ 0..16].copy_from_slice(&cb);
@@ -1843,22 +1883,147 @@ impl PublicKey {
             return false;
         }
         let R = (-self.point).mul128_add_mulgen_vartime(c, &s);
-        let cb = make_challenge(&R, &self.encoded, hash_name, data);
+        let cb = make_challenge(&R.encode(), &self.encoded, hash_name, data);
         return cb[..] == sig[0..16];
     }
+
+    /// Verifies a signature on a message against this public key.
+    ///
+    /// The message has been hashed into the provided hasher (`hasher`),
+    /// which is finalized here; this is equivalent to calling `verify()`
+    /// with the hash value and the hash function name (`D::HASH_NAME`).
+    ///
+    /// Note: this function is not constant-time; it assumes that the
+    /// public key and signature value are public data.
+    pub fn verify_digest<D: HashName>(self, sig: &[u8], hasher: D) -> bool {
+        let hv = digest::Digest::finalize(hasher);
+        self.verify(sig, D::HASH_NAME, &hv)
+    }
+
+    /// Verifies a truncated signature on a message against this public
+    /// key.
+    ///
+    /// The signature slice (`sig`) MUST have length exactly 48 bytes;
+    /// however, this function assumes that the last `rm` bits of the
+    /// signature have been reused to store other data, and thus it
+    /// ignores these bits. `rm` MUST be in the 8 to 32 range (inclusive);
+    /// ignored elements are the last `floor(rm/8)` bytes, and the top
+    /// (most significant) `rm%8` bits of the last non-ignored byte. The
+    /// message is provided as `data` and `hash_name`, with the same
+    /// rules as in `verify()`.
+    ///
+    /// If the original, untruncated signature was valid, then this
+    /// function rebuilds it and returns it; otherwise, it returns `None`
+    /// (if a rebuilt signature value is returned, then it has been
+    /// verified to be valid and there is no need to validate it again).
+    /// The reconstruction is an exhaustive search, whose cost is about
+    /// 2^(rm-2) point additions and BLAKE2s evaluations (see the module
+    /// documentation).
+    ///
+    /// Note: this function is not constant-time; it assumes that the
+    /// public key and signature value are public data.
+    pub fn verify_trunc(self, sig: &[u8], rm: usize,
+        hash_name: &str, data: &[u8]) -> Option<[u8; 48]>
+    {
+        // Code is meant for removing between 8 and 32 bits. The search
+        // is exhaustive, over up to 2^(rm-2) candidates; for rm = 24,
+        // this is several million point additions and BLAKE2s
+        // evaluations, i.e. several seconds on a modern CPU, and each
+        // extra bit doubles the cost. With rm <= 32, s1 fits on 64 bits
+        // and the shift below (n - 192 >= 32) is valid.
+        assert!((8..=32).contains(&rm));
+
+        // Signature array must have length 48 bytes exactly; but we
+        // ignore the last rm bits. We copy the non-ignored bits to sig2,
+        // and clear the rest.
+        if sig.len() != 48 {
+            return None;
+        }
+        let n = (391 - rm) >> 3;
+        let mut sig2 = [0u8; 48];
+        sig2[0..n].copy_from_slice(&sig[0..n]);
+        if (rm & 7) != 0 {
+            sig2[n - 1] &= 0xFFu8 >> (rm & 7);
+        }
+
+        // The signature is the challenge c (16 bytes) followed by the
+        // scalar s (32 bytes); truncation removed the top bits of s,
+        // which we write as:
+        //   n = 256 - rm      (number of bits of s that we received)
+        //   s = s0 + s1*2^n
+        // with 0 <= s1 <= (r-1)/2^n. The point R = s*G - c*Q must hash
+        // (along with the public key and the message) into c; there is
+        // no algebraic shortcut here, so we try all possible values of
+        // s1 in ascending order, using R_(s1+1) = R_s1 + (2^n)*G. For
+        // each candidate, we need the encoding of R_s1 (normalization
+        // is done by batches so that the inversion cost is shared) and
+        // one BLAKE2s computation.
+        let c = u128::from_le_bytes(*<&[u8; 16]>::try_from(&sig2[0..16]).unwrap());
+        let (s0, _) = Scalar::decode32(&sig2[16..48]);
+        let n = 256 - rm;
+        let rb = (-Scalar::ONE).encode();
+        let smax = u64::from_le_bytes(*<&[u8; 8]>::try_from(&rb[24..32]).unwrap())
+            >> (n - 192);
+        let D = Point::BASE.xdouble(n as u32);
+        let mut R = (-self.point).mul128_add_mulgen_vartime(c, &s0);
+
+        let mut s1 = 0u64;
+        while s1 <= smax {
+            let blen = if (smax + 1 - s1) < 100 {
+                (smax + 1 - s1) as usize
+            } else {
+                100
+            };
+            let mut Ri = [Point::NEUTRAL; 100];
+            let mut iZ = [GF255e::ZERO; 100];
+            for i in 0..blen {
+                Ri[i] = R;
+                iZ[i] = R.Z;
+                R += D;
+            }
+            GF255e::batch_invert(&mut iZ[..blen]);
+            for i in 0..blen {
+                // Encode the point (same process as in Point::encode()).
+                let mut u = Ri[i].U * iZ[i];
+                let sgn = (((Ri[i].E * iZ[i]).encode()[0] & 1) as u32)
+                    .wrapping_neg();
+                u.set_cond(&-u, sgn);
+                let cb = make_challenge(&u.encode(), &self.encoded,
+                    hash_name, data);
+                if cb[..] != sig2[0..16] {
+                    continue;
+                }
+
+                // Rebuild the complete signature and verify it (this
+                // also checks that s is in the proper range).
+                let mut sig3 = sig2;
+                let hi = u64::from_le_bytes(
+                    *<&[u8; 8]>::try_from(&sig3[40..48]).unwrap())
+                    | ((s1 + i as u64) << (n - 192));
+                sig3[40..48].copy_from_slice(&hi.to_le_bytes());
+                if self.verify(&sig3, hash_name, data) {
+                    return Some(sig3);
+                }
+            }
+            s1 += blen as u64;
+        }
+
+        // No match; signature is not valid.
+        None
+    }
 }
 
 /// Computes the 16-byte "challenge" of a signature.
 ///
-/// The per-signature point R, encoded public key, and (hashed) data
-/// are provided. Use an empty string for `hash_name` if the `data`
+/// The per-signature point R (encoded), encoded public key, and (hashed)
+/// data are provided. Use an empty string for `hash_name` if the `data`
 /// is raw (unhashed). This function is used for both signature generation
 /// and signature verification.
-fn make_challenge(R: &Point, enc_pk: &[u8; 32], hash_name: &str, data: &[u8])
-    -> [u8; 16]
+fn make_challenge(R_enc: &[u8; 32], enc_pk: &[u8; 32], hash_name: &str,
+    data: &[u8]) -> [u8; 16]
 {
     let mut sh = Blake2s256::new();
-    sh.update(&R.encode());
+    sh.update(R_enc);
     sh.update(enc_pk);
     if hash_name.len() == 0 {
         sh.update(&[0x52u8]);
@@ -3297,6 +3462,116 @@ mod tests {
         }
     }
 
+    #[test]
+    fn signature_trunc() {
+        for i in 0..3 {
+            let sk = PrivateKey::decode(&hex::decode(KAT_SIGN[i][0]).unwrap()).unwrap();
+            let pk = sk.public_key;
+            let seed = hex::decode(KAT_SIGN[i][2]).unwrap();
+            let mut hv = hex::decode(KAT_SIGN[i][3]).unwrap();
+            let sig = sk.sign_seeded(&seed, Point::HASHNAME_BLAKE2S, &hv);
+            for rm in 8..15 {
+                // Ignored bits are set to 1 (even rm) or to the bits of
+                // the message hash (odd rm); they must not matter, and
+                // the original signature must be rebuilt.
+                let mut sig2 = sig;
+                for j in (384 - rm)..384 {
+                    let b = if (rm & 1) == 0 {
+                        1
+                    } else {
+                        hv[(j >> 3) & 31] >> (j & 7)
+                    };
+                    sig2[j >> 3] = (sig2[j >> 3] & !(1u8 << (j & 7)))
+                        | ((b & 1) << (j & 7));
+                }
+                assert!(sig2 != sig);
+                let vv = pk.verify_trunc(&sig2, rm, Point::HASHNAME_BLAKE2S, &hv);
+                assert!(vv.unwrap() == sig);
+                hv[31] ^= 0x80;
+                assert!(pk.verify_trunc(&sig2, rm, Point::HASHNAME_BLAKE2S, &hv).is_none());
+                hv[31] ^= 0x80;
+            }
+        }
+    }
+
+    #[test]
+    fn signature_trunc_large() {
+        // The search is exhaustive over the missing high bits of s, so
+        // that a complete search for large values of rm would be too
+        // expensive for a test. We use a signature whose scalar s is
+        // lower than 2^238 (the seed was found by trying successive
+        // integers), so that the search for rm = 32 stops after at most
+        // 2^14 candidates. For the same reason, only the valid signature
+        // is tested.
+        let sk = PrivateKey::decode(&hex::decode(KAT_SIGN[0][0]).unwrap()).unwrap();
+        let pk = sk.public_key;
+        let hv = hex::decode(KAT_SIGN[0][3]).unwrap();
+        let seed = 15238u64.to_le_bytes();
+        let sig = sk.sign_seeded(&seed, Point::HASHNAME_BLAKE2S, &hv);
+        assert!(sig[47] == 0 && sig[46] == 0 && (sig[45] & 0xC0) == 0);
+        for rm in 15..33 {
+            // Ignored bits are set to 1 (even rm) or to the bits of the
+            // message hash (odd rm).
+            let mut sig2 = sig;
+            for j in (384 - rm)..384 {
+                let b = if (rm & 1) == 0 {
+                    1
+                } else {
+                    hv[(j >> 3) & 31] >> (j & 7)
+                };
+                sig2[j >> 3] = (sig2[j >> 3] & !(1u8 << (j & 7)))
+                    | ((b & 1) << (j & 7));
+            }
+            let vv = pk.verify_trunc(&sig2, rm, Point::HASHNAME_BLAKE2S, &hv);
+            assert!(vv.unwrap() == sig);
+        }
+    }
+
+    #[test]
+    fn signature_digest() {
+        let sk = PrivateKey::decode(&hex::decode(KAT_SIGN[0][0]).unwrap()).unwrap();
+        let pk = sk.public_key;
+        let msg = b"sample message";
+
+        let mut sh = Sha256::new();
+        sh.update(&msg[..]);
+        let sig = sk.sign_digest(sh.clone());
+        let hv = sh.clone().finalize();
+        assert!(sig == sk.sign(Point::HASHNAME_SHA256, &hv));
+        assert!(pk.verify_digest(&sig, sh));
+        let mut sh = Sha256::new();
+        sh.update(&msg[1..]);
+        assert!(!pk.verify_digest(&sig, sh));
+
+        let mut sh = Blake2s256::new();
+        sh.update(&msg[..]);
+        let sig = sk.sign_digest(sh.clone());
+        let hv = Blake2s256::hash(&msg[..]);
+        assert!(sig == sk.sign(Point::HASHNAME_BLAKE2S, &hv));
+        assert!(pk.verify_digest(&sig, sh));
+        assert!(!pk.verify(&sig, Point::HASHNAME_SHA256, &hv));
+
+        #[cfg(feature = "blake2b")]
+        {
+            use crate::blake2b::Blake2b512;
+            let sh = <Blake2b512 as digest::Digest>::new_with_prefix(&msg[..]);
+            let sig = sk.sign_digest(sh.clone());
+            let hv = Blake2b512::hash(&msg[..]);
+            assert!(sig == sk.sign(Point::HASHNAME_BLAKE2B, &hv));
+            assert!(pk.verify_digest(&sig, sh));
+        }
+
+        #[cfg(feature = "blake3")]
+        {
+            use crate::blake3::Blake3;
+            let sh = <Blake3 as digest::Digest>::new_with_prefix(&msg[..]);
+            let sig = sk.sign_digest(sh);
+            let hv = Blake3::hash(&msg[..]);
+            assert!(sig == sk.sign(Point::HASHNAME_BLAKE3, &hv));
+            assert!(pk.verify_digest(&sig, sh));
+        }
+    }
+
     static KAT_ECDH: [[&str; 5]; 20] = [
         // Each group of five values is:
         //   private key
@@ -0,0 +1,627 @@
+//! HMAC, HKDF, HMAC-DRBG and the X9.63 KDF.
+//!
+//! This module implements HMAC (RFC 2104), HKDF (RFC 5869), HMAC-DRBG
+//! (NIST SP 800-90A, section 10.1.2) and the ANSI X9.63 KDF. All of them
+//! are generic over the underlying hash function, which can be any
+//! `digest::Digest` type with a known block size, e.g. `sha2::Sha256`,
+//! `sha3::Sha3_256`, or `crrl::blake2s::Blake2s256`. Type aliases are
+//! defined for the common combinations (`HmacSha256`, `HkdfSha512`,
+//! `HmacDrbgSha256`...).
+//!
+//! `HmacDrbg` implements the `RngCore` and `CryptoRng` traits, and does
+//! not need any operating system support. It can thus be seeded once
+//! (e.g. from a hardware entropy source on an embedded system) and then
+//! used as the random source for key pair generation functions such as
+//! `PrivateKey::generate()`. Through the `RngCore` trait, the DRBG uses
+//! no additional input, i.e. its output is entirely determined by its
+//! seed. Callers who have fresh (even possibly weak) randomness available
+//! should mix it in explicitly, either with `HmacDrbg::reseed()` or as
+//! the additional input of `HmacDrbg::generate()`.
+
+#![allow(non_snake_case)]
+
+use core::marker::PhantomData;
+use digest::Digest;
+use digest::core_api::BlockSizeUser;
+use super::{CryptoRng, RngCore, RngError};
+
+// Largest supported block size (in bytes); this is the block size of
+// SHA3-224, the largest of all SHA-2 and SHA-3 functions.
+const MAX_BLOCK_LEN: usize = 144;
+
+// Largest supported output size (in bytes).
+const MAX_OUT_LEN: usize = 64;
+
+/// HMAC context.
+///
+/// The key is processed when the context is created, so that the context
+/// can be reset and reused with the same key without reprocessing the key.
+#[derive(Clone)]
+pub struct Hmac<D: Digest + BlockSizeUser + Clone> {
+    inner: D,
+    outer: D,
+    cur: D,
+}
+
+/// HMAC with SHA-256.
+pub type HmacSha256 = Hmac<sha2::Sha256>;
+/// HMAC with SHA-384.
+pub type HmacSha384 = Hmac<sha2::Sha384>;
+/// HMAC with SHA-512.
+pub type HmacSha512 = Hmac<sha2::Sha512>;
+/// HMAC with SHA3-256.
+pub type HmacSha3_256 = Hmac<sha3::Sha3_256>;
+/// HMAC with SHA3-512.
+pub type HmacSha3_512 = Hmac<sha3::Sha3_512>;
+/// HMAC with BLAKE2s (256-bit output).
+#[cfg(feature = "blake2s")]
+pub type HmacBlake2s = Hmac<crate::blake2s::Blake2s256>;
+
+impl<D: Digest + BlockSizeUser + Clone> Hmac<D> {
+
+    /// Create a new HMAC context with the provided key. The key may have
+    /// any length; keys larger than the block size of the hash function
+    /// are first hashed.
+    pub fn new(key: &[u8]) -> Self {
+        let blen = <D as BlockSizeUser>::block_size();
+        assert!(blen <= MAX_BLOCK_LEN);
+        let mut kb = [0u8; MAX_BLOCK_LEN];
+        if key.len() > blen {
+            let hk = D::digest(key);
+            kb[..hk.len()].copy_from_slice(&hk);
+        } else {
+            kb[..key.len()].copy_from_slice(key);
+        }
+        for x in kb[..blen].iter_mut() {
+            *x ^= 0x36;
+        }
+        let mut inner = D::new();
+        inner.update(&kb[..blen]);
+        for x in kb[..blen].iter_mut() {
+            *x ^= 0x36 ^ 0x5C;
+        }
+        let mut outer = D::new();
+        outer.update(&kb[..blen]);
+        Self { cur: inner.clone(), inner, outer }
+    }
+
+    /// Get the output length (in bytes).
+    pub fn output_len() -> usize {
+        <D as Digest>::output_size()
+    }
+
+    /// Inject some more bytes into the context.
+    #[inline(always)]
+    pub fn update(&mut self, data: &[u8]) {
+        self.cur.update(data);
+    }
+
+    /// Reset this context; the key is kept.
+    #[inline(always)]
+    pub fn reset(&mut self) {
+        self.cur = self.inner.clone();
+    }
+
+    /// Finalize this context and get the output, which is written into
+    /// the provided slice. The output size is returned. The context is
+    /// automatically reset and can be used for a new computation with the
+    /// same key.
+    pub fn finalize_reset_write(&mut self, out: &mut [u8]) -> usize {
+        let ih = core::mem::replace(&mut self.cur, self.inner.clone())
+            .finalize();
+        let mut sh = self.outer.clone();
+        sh.update(&ih);
+        let r = sh.finalize();
+        out[..r.len()].copy_from_slice(&r);
+        r.len()
+    }
+
+    /// Finalize this context and compare the output with the provided
+    /// tag value (`tag`), which may be truncated (but not to less than 4
+    /// bytes). Returned value is `true` on match. The comparison is
+    /// constant-time. The context is automatically reset.
+    pub fn verify_reset(&mut self, tag: &[u8]) -> bool {
+        let mut buf = [0u8; MAX_OUT_LEN];
+        let n = self.finalize_reset_write(&mut buf);
+        if tag.len() < 4 || tag.len() > n {
+            return false;
+        }
+        let mut z = 0u8;
+        for (x, y) in buf.iter().zip(tag.iter()) {
+            z |= x ^ y;
+        }
+        z == 0
+    }
+
+    /// One-stop function for computing a HMAC value. The output is
+    /// written into the provided slice; the output size is returned.
+    pub fn mac(key: &[u8], data: &[u8], out: &mut [u8]) -> usize {
+        let mut hm = Self::new(key);
+        hm.update(data);
+        hm.finalize_reset_write(out)
+    }
+}
+
+/// HKDF context.
+///
+/// The context contains the pseudorandom key (PRK), obtained from the
+/// input key material with the HKDF-Extract step (`new()`) or provided
+/// directly (`from_prk()`); it can then be used for any number of
+/// HKDF-Expand operations.
+#[derive(Clone)]
+pub struct Hkdf<D: Digest + BlockSizeUser + Clone> {
+    prk: [u8; MAX_OUT_LEN],
+    hm: Hmac<D>,
+}
+
+/// HKDF with SHA-256.
+pub type HkdfSha256 = Hkdf<sha2::Sha256>;
+/// HKDF with SHA-384.
+pub type HkdfSha384 = Hkdf<sha2::Sha384>;
+/// HKDF with SHA-512.
+pub type HkdfSha512 = Hkdf<sha2::Sha512>;
+/// HKDF with SHA3-256.
+pub type HkdfSha3_256 = Hkdf<sha3::Sha3_256>;
+/// HKDF with SHA3-512.
+pub type HkdfSha3_512 = Hkdf<sha3::Sha3_512>;
+/// HKDF with BLAKE2s (256-bit output).
+#[cfg(feature = "blake2s")]
+pub type HkdfBlake2s = Hkdf<crate::blake2s::Blake2s256>;
+
+impl<D: Digest + BlockSizeUser + Clone> Hkdf<D> {
+
+    /// HKDF-Extract: make a new context from a salt and input key
+    /// material. An empty salt is equivalent to a salt consisting of
+    /// as many zeros as the hash output length.
+    pub fn new(salt: &[u8], ikm: &[u8]) -> Self {
+        Self::new_multi(salt, &[ikm])
+    }
+
+    /// HKDF-Extract, with the input key material provided as the
+    /// concatenation of several slices.
+    pub fn new_multi(salt: &[u8], ikm: &[&[u8]]) -> Self {
+        let mut hm = Hmac::<D>::new(salt);
+        for x in ikm.iter() {
+            hm.update(x);
+        }
+        let mut prk = [0u8; MAX_OUT_LEN];
+        let n = hm.finalize_reset_write(&mut prk);
+        Self::from_prk(&prk[..n])
+    }
+
+    /// Make a new context from a pseudorandom key (PRK), skipping the
+    /// HKDF-Extract step. The PRK should have the length of the hash
+    /// function output.
+    pub fn from_prk(prk: &[u8]) -> Self {
+        assert!(<D as Digest>::output_size() <= MAX_OUT_LEN);
+        let mut p = [0u8; MAX_OUT_LEN];
+        let n = core::cmp::min(prk.len(), MAX_OUT_LEN);
+        p[..n].copy_from_slice(&prk[..n]);
+        Self { prk: p, hm: Hmac::new(prk) }
+    }
+
+    /// Get the pseudorandom key (PRK), written into the provided slice.
+    /// The PRK length (i.e. the hash function output length) is returned.
+    pub fn prk_write(&self, out: &mut [u8]) -> usize {
+        let n = <D as Digest>::output_size();
+        out[..n].copy_from_slice(&self.prk[..n]);
+        n
+    }
+
+    /// HKDF-Expand: fill `out` with bytes derived from the PRK and the
+    /// `info` string. The output length must not exceed 255 times the
+    /// hash function output length.
+    pub fn expand(&self, info: &[u8], out: &mut [u8]) {
+        self.expand_multi(&[info], out);
+    }
+
+    /// HKDF-Expand, with the `info` string provided as the concatenation
+    /// of several slices.
+    pub fn expand_multi(&self, info: &[&[u8]], out: &mut [u8]) {
+        let hlen = <D as Digest>::output_size();
+        assert!(out.len() <= 255 * hlen);
+        let mut hm = self.hm.clone();
+        let mut t = [0u8; MAX_OUT_LEN];
+        let mut tlen = 0;
+        let mut j = 0;
+        let mut ctr = 1u8;
+        while j < out.len() {
+            hm.update(&t[..tlen]);
+            for x in info.iter() {
+                hm.update(x);
+            }
+            hm.update(&[ctr]);
+            tlen = hm.finalize_reset_write(&mut t);
+            let clen = core::cmp::min(tlen, out.len() - j);
+            out[j..(j + clen)].copy_from_slice(&t[..clen]);
+            j += clen;
+            ctr = ctr.wrapping_add(1);
+        }
+    }
+
+    /// One-stop function for HKDF (extract then expand).
+    pub fn derive(salt: &[u8], ikm: &[u8], info: &[u8], out: &mut [u8]) {
+        Self::new(salt, ikm).expand(info, out);
+    }
+}
+
+/// ANSI X9.63 key derivation function (also specified in SEC 1, section
+/// 3.6.1, and as KDF2 in ISO 18033-2 when `shared_info` is empty).
+///
+/// The output is the concatenation of `Hash(z || counter || shared_info)`
+/// for a 32-bit big-endian counter starting at 1, truncated to the
+/// length of `out`.
+pub fn x963_kdf<D: Digest>(z: &[u8], shared_info: &[u8], out: &mut [u8]) {
+    for (i, chunk) in out.chunks_mut(<D as Digest>::output_size())
+        .enumerate()
+    {
+        let mut h = D::new();
+        h.update(z);
+        h.update((i as u32 + 1).to_be_bytes());
+        h.update(shared_info);
+        let hv = h.finalize();
+        chunk.copy_from_slice(&hv[..chunk.len()]);
+    }
+}
+
+/// HMAC-DRBG (NIST SP 800-90A).
+///
+/// The DRBG is instantiated with some entropy, a nonce and an optional
+/// personalization string (`new()`). It can be reseeded at any time with
+/// `reseed()`. This implementation does not enforce a reseed interval;
+/// callers with an entropy source should reseed periodically. Each
+/// generation call produces at most 65536 bytes (larger requests are
+/// split into several generation calls).
+///
+/// This type deliberately does not implement `Clone`: a copy of the
+/// DRBG would produce the exact same output as the original, e.g. the
+/// same nonces or private keys.
+pub struct HmacDrbg<D: Digest + BlockSizeUser + Clone> {
+    K: [u8; MAX_OUT_LEN],
+    V: [u8; MAX_OUT_LEN],
+    reseed_counter: u64,
+    _d: PhantomData<D>,
+}
+
+/// HMAC-DRBG with SHA-256.
+pub type HmacDrbgSha256 = HmacDrbg<sha2::Sha256>;
+/// HMAC-DRBG with SHA-384.
+pub type HmacDrbgSha384 = HmacDrbg<sha2::Sha384>;
+/// HMAC-DRBG with SHA-512.
+pub type HmacDrbgSha512 = HmacDrbg<sha2::Sha512>;
+
+impl<D: Digest + BlockSizeUser + Clone> HmacDrbg<D> {
+
+    // Maximum output length per generation call (2^19 bits).
+    const MAX_REQUEST: usize = 65536;
+
+    /// Instantiate a new DRBG from the provided entropy, nonce and
+    /// personalization string. The entropy input should contain at least
+    /// as many bits of entropy as the intended security level; the nonce
+    /// may be empty if the entropy input is large enough (at least 3/2 of
+    /// the security level). The personalization string may be empty.
+    pub fn new(entropy: &[u8], nonce: &[u8], personalization: &[u8])
+        -> Self
+    {
+        let hlen = <D as Digest>::output_size();
+        assert!(hlen <= MAX_OUT_LEN);
+        let mut d = Self {
+            K: [0x00u8; MAX_OUT_LEN],
+            V: [0x01u8; MAX_OUT_LEN],
+            reseed_counter: 1,
+            _d: PhantomData,
+        };
+        d.update(&[entropy, nonce, personalization]);
+        d
+    }
+
+    /// Instantiate a new DRBG from a seed obtained from another random
+    /// source (e.g. the operating system RNG). 48 bytes are obtained from
+    /// the provided source, used as entropy input and nonce.
+    pub fn from_rng<T: CryptoRng + RngCore>(rng: &mut T,
+        personalization: &[u8]) -> Self
+    {
+        let mut seed = [0u8; 48];
+        rng.fill_bytes(&mut seed);
+        Self::new(&seed[..32], &seed[32..], personalization)
+    }
+
+    // HMAC_DRBG_Update, with the provided data being the concatenation
+    // of the slices in `data`.
+    fn update(&mut self, data: &[&[u8]]) {
+        let hlen = <D as Digest>::output_size();
+        let empty = data.iter().all(|x| x.is_empty());
+        for b in 0..2u8 {
+            let mut hm = Hmac::<D>::new(&self.K[..hlen]);
+            hm.update(&self.V[..hlen]);
+            hm.update(&[b]);
+            for x in data.iter() {
+                hm.update(x);
+            }
+            hm.finalize_reset_write(&mut self.K);
+            let mut hm = Hmac::<D>::new(&self.K[..hlen]);
+            hm.update(&self.V[..hlen]);
+            hm.finalize_reset_write(&mut self.V);
+            if empty {
+                break;
+            }
+        }
+    }
+
+    /// Reseed the DRBG with some new entropy and an optional additional
+    /// input.
+    pub fn reseed(&mut self, entropy: &[u8], additional: &[u8]) {
+        self.update(&[entropy, additional]);
+        self.reseed_counter = 1;
+    }
+
+    /// Get the number of generation calls since the DRBG was instantiated
+    /// or last reseeded, plus one (this is the SP 800-90A reseed counter).
+    pub fn reseed_counter(&self) -> u64 {
+        self.reseed_counter
+    }
+
+    /// Generate some pseudorandom bytes into `out`, with an optional
+    /// additional input (which may be empty).
+    pub fn generate(&mut self, out: &mut [u8], additional: &[u8]) {
+        let hlen = <D as Digest>::output_size();
+        for chunk in out.chunks_mut(Self::MAX_REQUEST) {
+            if !additional.is_empty() {
+                self.update(&[additional]);
+            }
+            let hm = Hmac::<D>::new(&self.K[..hlen]);
+            let mut j = 0;
+            while j < chunk.len() {
+                let mut hm = hm.clone();
+                hm.update(&self.V[..hlen]);
+                hm.finalize_reset_write(&mut self.V);
+                let clen = core::cmp::min(hlen, chunk.len() - j);
+                chunk[j..(j + clen)].copy_from_slice(&self.V[..clen]);
+                j += clen;
+            }
+            self.update(&[additional]);
+            self.reseed_counter += 1;
+        }
+    }
+}
+
+impl<D: Digest + BlockSizeUser + Clone> RngCore for HmacDrbg<D> {
+
+    fn next_u32(&mut self) -> u32 {
+        let mut buf = [0u8; 4];
+        self.generate(&mut buf, &[]);
+        u32::from_le_bytes(buf)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut buf = [0u8; 8];
+        self.generate(&mut buf, &[]);
+        u64::from_le_bytes(buf)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.generate(dest, &[]);
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), RngError> {
+        self.generate(dest, &[]);
+        Ok(())
+    }
+}
+
+impl<D: Digest + BlockSizeUser + Clone> CryptoRng for HmacDrbg<D> {}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use sha2::{Sha256, Sha384, Sha512};
+    use sha3::{Sha3_256, Sha3_512};
+    #[cfg(feature = "blake2s")]
+    use crate::blake2s::Blake2s256;
+
+    static KAT_HMAC: [[&str; 8]; 4] = [
+        // Each group of eight values is:
+        //   key
+        //   data
+        //   HMAC/SHA-256
+        //   HMAC/SHA-384
+        //   HMAC/SHA-512
+        //   HMAC/SHA3-256
+        //   HMAC/SHA3-512
+        //   HMAC/BLAKE2s
+        // Keys and data are from RFC 4231 (test cases 1, 2, 6 and 7);
+        // SHA-2 outputs are from RFC 4231.
+        [
+            "0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b",
+            "4869205468657265",
+            "b0344c61d8db38535ca8afceaf0bf12b881dc200c9833da726e9376c2e32cff7",
+            "afd03944d84895626b0825f4ab46907f15f9dadbe4101ec682aa034c7cebc59cfaea9ea9076ede7f4af152e8b2fa9cb6",
+            "87aa7cdea5ef619d4ff0b4241a1d6cb02379f4e2ce4ec2787ad0b30545e17cdedaa833b7d6b8a702038b274eaea3f4e4be9d914eeb61f1702e696c203a126854",
+            "ba85192310dffa96e2a3a40e69774351140bb7185e1202cdcc917589f95e16bb",
+            "eb3fbd4b2eaab8f5c504bd3a41465aacec15770a7cabac531e482f860b5ec7ba47ccb2c6f2afce8f88d22b6dc61380f23a668fd3888bb80537c0a0b86407689e",
+            "65a8b7c5cc9136d424e82c37e2707e74e913c0655b99c75f40edf387453a3260",
+        ], [
+            "4a656665",
+            "7768617420646f2079612077616e7420666f72206e6f7468696e673f",
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843",
+            "af45d2e376484031617f78d2b58a6b1b9c7ef464f5a01b47e42ec3736322445e8e2240ca5e69e2c78b3239ecfab21649",
+            "164b7a7bfcf819e2e395fbe73b56e0a387bd64222e831fd610270cd7ea2505549758bf75c05a994a6d034f65f8f0e6fdcaeab1a34d4a6b4b636e070a38bce737",
+            "c7d4072e788877ae3596bbb0da73b887c9171f93095b294ae857fbe2645e1ba5",
+            "5a4bfeab6166427c7a3647b747292b8384537cdb89afb3bf5665e4c5e709350b287baec921fd7ca0ee7a0c31d022a95e1fc92ba9d77df883960275beb4e62024",
+            "90b6281e2f3038c9056af0b4a7e763cae6fe5d9eb4386a0ec95237890c104ff0",
+        ], [
+            "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+            "54657374205573696e67204c6172676572205468616e20426c6f636b2d53697a65204b6579202d2048617368204b6579204669727374",
+            "60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54",
+            "4ece084485813e9088d2c63a041bc5b44f9ef1012a2b588f3cd11f05033ac4c60c2ef6ab4030fe8296248df163f44952",
+            "80b24263c7c1a3ebb71493c1dd7be8b49b46d1f41b4aeec1121b013783f8f3526b56d037e05f2598bd0fd2215d6a1e5295e64f73f63f0aec8b915a985d786598",
+            "ed73a374b96c005235f948032f09674a58c0ce555cfc1f223b02356560312c3b",
+            "00f751a9e50695b090ed6911a4b65524951cdc15a73a5d58bb55215ea2cd839ac79d2b44a39bafab27e83fde9e11f6340b11d991b1b91bf2eee7fc872426c3a4",
+            "d23d79394f53d536a096e6514447eeaabb05ded01be32c1937da6a8f7103bc4e",
+        ], [
+            "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+            "5468697320697320612074657374207573696e672061206c6172676572207468616e20626c6f636b2d73697a65206b657920616e642061206c6172676572207468616e20626c6f636b2d73697a6520646174612e20546865206b6579206e6565647320746f20626520686173686564206265666f7265206265696e6720757365642062792074686520484d414320616c676f726974686d2e",
+            "9b09ffa71b942fcb27635fbcd5b0e944bfdc63644f0713938a7f51535c3a35e2",
+            "6617178e941f020d351e2f254e8fd32c602420feb0b8fb9adccebb82461e99c5a678cc31e799176d3860e6110c46523e",
+            "e37b6a775dc87dbaa4dfa9f96e5e3ffddebd71f8867289865df5a32d20cdc944b6022cac3c4982b10d5eeb55c3e4de15134676fb6de0446065c97440fa8c6a58",
+            "65c5b06d4c3de32a7aef8763261e49adb6e2293ec8e7c61e8de61701fc63e123",
+            "38a456a004bd10d32c9ab8336684112862c3db61adcca31829355eaf46fd5c73d06a1f0d13fec9a652fb3811b577b1b1d1b9789f97ae5b83c6f44dfcf1d67eba",
+            "cb60f6a791f140bf8aa2e51ff358cdb2cc5c0333045b7fb77aba7ab3b0cfb237",
+        ],
+    ];
+
+    fn check_hmac<D: Digest + BlockSizeUser + Clone>(key: &[u8], data: &[u8],
+        refout: &str)
+    {
+        let refout = hex::decode(refout).unwrap();
+        let mut buf = [0u8; 64];
+        assert!(Hmac::<D>::mac(key, data, &mut buf) == refout.len());
+        assert!(buf[..refout.len()] == refout[..]);
+
+        let mut hm = Hmac::<D>::new(key);
+        for i in 0..data.len() {
+            hm.update(&data[i..(i + 1)]);
+        }
+        assert!(hm.finalize_reset_write(&mut buf) == refout.len());
+        assert!(buf[..refout.len()] == refout[..]);
+        hm.update(data);
+        assert!(hm.verify_reset(&refout));
+        hm.update(data);
+        assert!(hm.verify_reset(&refout[..16]));
+        hm.update(&data[1..]);
+        assert!(!hm.verify_reset(&refout));
+    }
+
+    #[test]
+    fn hmac() {
+        for kv in KAT_HMAC.iter() {
+            let key = hex::decode(kv[0]).unwrap();
+            let data = hex::decode(kv[1]).unwrap();
+            check_hmac::<Sha256>(&key, &data, kv[2]);
+            check_hmac::<Sha384>(&key, &data, kv[3]);
+            check_hmac::<Sha512>(&key, &data, kv[4]);
+            check_hmac::<Sha3_256>(&key, &data, kv[5]);
+            check_hmac::<Sha3_512>(&key, &data, kv[6]);
+            #[cfg(feature = "blake2s")]
+            check_hmac::<Blake2s256>(&key, &data, kv[7]);
+        }
+    }
+
+    static KAT_HKDF: [[&str; 5]; 3] = [
+        // Each group of five values is:
+        //   IKM
+        //   salt
+        //   info
+        //   PRK
+        //   OKM
+        // Vectors are from RFC 5869 (test cases 1, 2 and 3, HKDF-SHA256).
+        [
+            "0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b",
+            "000102030405060708090a0b0c",
+            "f0f1f2f3f4f5f6f7f8f9",
+            "077709362c2e32df0ddc3f0dc47bba6390b6c73bb50f9c3122ec844ad7c2b3e5",
+            "3cb25f25faacd57a90434f64d0362f2a2d2d0a90cf1a5a4c5db02d56ecc4c5bf34007208d5b887185865",
+        ], [
+            "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f202122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f404142434445464748494a4b4c4d4e4f",
+            "606162636465666768696a6b6c6d6e6f707172737475767778797a7b7c7d7e7f808182838485868788898a8b8c8d8e8f909192939495969798999a9b9c9d9e9fa0a1a2a3a4a5a6a7a8a9aaabacadaeaf",
+            "b0b1b2b3b4b5b6b7b8b9babbbcbdbebfc0c1c2c3c4c5c6c7c8c9cacbcccdcecfd0d1d2d3d4d5d6d7d8d9dadbdcdddedfe0e1e2e3e4e5e6e7e8e9eaebecedeeeff0f1f2f3f4f5f6f7f8f9fafbfcfdfeff",
+            "06a6b88c5853361a06104c9ceb35b45cef760014904671014a193f40c15fc244",
+            "b11e398dc80327a1c8e7f78c596a49344f012eda2d4efad8a050cc4c19afa97c59045a99cac7827271cb41c65e590e09da3275600c2f09b8367793a9aca3db71cc30c58179ec3e87c14c01d5c1f3434f1d87",
+        ], [
+            "0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b",
+            "",
+            "",
+            "19ef24a32c717b167f33a91d6f648bdf96596776afdb6377ac434c1c293ccb04",
+            "8da4e775a563c18f715f802a063c5a31b8a11f5c5ee1879ec3454e5f3c738d2d9d201395faa4b61a96c8",
+        ],
+    ];
+
+    #[test]
+    fn hkdf() {
+        for kv in KAT_HKDF.iter() {
+            let ikm = hex::decode(kv[0]).unwrap();
+            let salt = hex::decode(kv[1]).unwrap();
+            let info = hex::decode(kv[2]).unwrap();
+            let prk = hex::decode(kv[3]).unwrap();
+            let okm = hex::decode(kv[4]).unwrap();
+
+            let hk = HkdfSha256::new(&salt, &ikm);
+            let mut buf = [0u8; 100];
+            assert!(hk.prk_write(&mut buf) == 32);
+            assert!(buf[..32] == prk[..]);
+            hk.expand(&info, &mut buf[..okm.len()]);
+            assert!(buf[..okm.len()] == okm[..]);
+
+            let hk = HkdfSha256::new_multi(&salt, &[&ikm[..5], &ikm[5..]]);
+            let (info1, info2) = info.split_at(info.len() >> 1);
+            hk.expand_multi(&[info1, info2], &mut buf[..okm.len()]);
+            assert!(buf[..okm.len()] == okm[..]);
+
+            let hk = HkdfSha256::from_prk(&prk);
+            hk.expand(&info, &mut buf[..okm.len()]);
+            assert!(buf[..okm.len()] == okm[..]);
+
+            HkdfSha256::derive(&salt, &ikm, &info, &mut buf[..okm.len()]);
+            assert!(buf[..okm.len()] == okm[..]);
+        }
+    }
+
+    #[test]
+    fn x963() {
+        // Test vectors from NIST CAVP (SP 800-135, ANSI X9.63, SHA-256).
+        let mut buf = [0u8; 128];
+        let z = hex::decode("96c05619d56c328ab95fe84b18264b08725b85e33fd34f08").unwrap();
+        x963_kdf::<Sha256>(&z, &[], &mut buf[..16]);
+        assert!(buf[..16] == hex::decode("443024c3dae66b95e6f5670601558f71").unwrap()[..]);
+        let z = hex::decode("22518b10e70f2a3f243810ae3254139efbee04aa57c7af7d").unwrap();
+        let si = hex::decode("75eef81aa3041e33b80971203d2c0c52").unwrap();
+        x963_kdf::<Sha256>(&z, &si, &mut buf);
+        assert!(buf[..] == hex::decode("c498af77161cc59f2962b9a713e2b215152d139766ce34a776df11866a69bf2e52a13d9c7c6fc878c50c5ea0bc7b00e0da2447cfd874f6cf92f30d0097111485500c90c3af8b487872d04685d14c8d1dc8d7fa08beb0ce0ababc11f0bd496269142d43525a78e5bc79a17f59676a5706dc54d54d4d1f0bd7e386128ec26afc21").unwrap()[..]);
+    }
+
+    #[test]
+    fn hmac_drbg() {
+        // Test vector from NIST CAVP (HMAC_DRBG, SHA-256, no prediction
+        // resistance, no reseed, first vector): output is from the
+        // second generate call.
+        let entropy = hex::decode("ca851911349384bffe89de1cbdc46e6831e44d34a4fb935ee285dd14b71a7488").unwrap();
+        let nonce = hex::decode("659ba96c601dc69fc902940805ec0ca8").unwrap();
+        let refout = hex::decode("e528e9abf2dece54d47c7e75e5fe302149f817ea9fb4bee6f4199697d04d5b89d54fbb978a15b5c443c9ec21036d2460b6f73ebad0dc2aba6e624abf07745bc107694bb7547bb0995f70de25d6b29e2d3011bb19d27676c07162c8b5ccde0668961df86803482cb37ed6d5c0bb8d50cf1f50d476aa0458bdaba806f48be9dcb8").unwrap();
+        let mut drbg = HmacDrbgSha256::new(&entropy, &nonce, &[]);
+        let mut buf = [0u8; 128];
+        drbg.generate(&mut buf, &[]);
+        drbg.generate(&mut buf, &[]);
+        assert!(buf[..] == refout[..]);
+        assert!(drbg.reseed_counter() == 3);
+
+        // Same DRBG used through RngCore.
+        let mut drbg = HmacDrbgSha256::new(&entropy, &nonce, &[]);
+        drbg.fill_bytes(&mut buf);
+        drbg.fill_bytes(&mut buf);
+        assert!(buf[..] == refout[..]);
+
+        // Personalization string, additional input and reseed (SHA-512).
+        let mut entropy = [0u8; 48];
+        for (i, x) in entropy.iter_mut().enumerate() {
+            *x = i as u8;
+        }
+        let mut nonce = [0u8; 16];
+        for (i, x) in nonce.iter_mut().enumerate() {
+            *x = 100 + i as u8;
+        }
+        let mut drbg = HmacDrbgSha512::new(&entropy, &nonce,
+            b"personalization");
+        let mut buf = [0u8; 150];
+        drbg.generate(&mut buf[..100], b"additional 1");
+        assert!(buf[..100] == hex::decode("186122961d61b8fe4a23be7a980fbae5a7f91989e7bbe381cb3061f9777cee1a907e43afd14041b4be0653ab903ccee1106ec0a79df5a135b4e0a2325ff2a375b43d23ab73dfb3c16263fe128e0040bbfeeab461795df972563737a7fbf0523af271138a").unwrap()[..]);
+        let mut entropy2 = [0u8; 32];
+        for (i, x) in entropy2.iter_mut().enumerate() {
+            *x = 200 + i as u8;
+        }
+        drbg.reseed(&entropy2, b"reseed");
+        assert!(drbg.reseed_counter() == 1);
+        drbg.generate(&mut buf, &[]);
+        assert!(buf[..] == hex::decode("03291cd06d767c02f28c55a4f0f0cbd0f0161f0c04a5d87006418b707088075132f4faae97c42840276c924c1288b0593123aaecac5634d7a14a18c1a5443debb599de5a83a69e58e42412fd56c7746df5835f848988dc92fb7012875dc0d409fdad11ca6a0a955add39b18e2e6f71658ac07929fd03bba7ba31a643a05b8f4d43176a138e7feb8c265fbc5d0a87117d4113ec5f2b91").unwrap()[..]);
+    }
+}
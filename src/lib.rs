@@ -18,19 +18,24 @@
 //! ristretto255 (internally based on edwards25519) is defined in the
 //! `ristretto255` module. NIST curve P-256 (aka "secp256r1" and
 //! "prime256v1") is implemented in the `p256` module (with the ECDSA
-//! signature algorithm). Double-odd curves jq255e and jq255s are
-//! implemented by `jq255e` and `jq255s`, respectively (including
-//! signature and key exchange schemes). Secp256k1 is implemented in
-//! `secp256k1`. Edwards448 is in `ed448`, while the specialized X448
-//! function is in `x448`. The prime-order decaf448 group is implemented
-//! in `decaf448`.
+//! signature algorithm and ECDH key exchange). Double-odd curves jq255e
+//! and jq255s are implemented by `jq255e` and `jq255s`, respectively
+//! (including signature and key exchange schemes). Secp256k1 is
+//! implemented in `secp256k1` (with ECDSA and BIP-340 Schnorr
+//! signatures, and ECDH); MuSig2 multi-signatures over secp256k1
+//! (BIP-327) are in `musig2`. Edwards448 is in `ed448`, while the
+//! specialized X448 function is in `x448`. The prime-order decaf448
+//! group is implemented in `decaf448`.
 //!
 //! # Usage
 //!
 //! The library is "mostly `no_std`". By default, it compiles against the
 //! standard library. It can be compiled in `no_std` mode, in which case
-//! all functionality is still available, except verification of truncated
-//! ECDSA signatures with curve P-256.
+//! most functionality is still available. Without the `alloc` feature,
+//! the FROST implementation and the HSS scheme in `lms::hss` are
+//! excluded, and the ECIES and HPKE modules only provide encryption and
+//! decryption into caller-provided buffers (the variants that return a
+//! `Vec<u8>` are omitted).
 //!
 //! # Conventions
 //!
@@ -57,20 +62,25 @@
 //! # Truncated Signatures
 //!
 //! Apart from standard support for curve operations and signature
-//! algorithms, _truncated signatures_ are implemented for both Ed25519
-//! (Schnorr signatures over edwards25519) and ECDSA (over P-256). A
-//! truncated signature is a shrunk version, by up to 32 bits, of a
-//! normal signature; the verification process is then more expensive,
-//! though not necessarily intolerably expensive, depending on usage
-//! context (the most expensive verification function is for ECDSA on
-//! P-256, with maximal 32-bit truncation; in that case, verification
-//! cost can be up to about 0.65 seconds on a 500 MHz ARM Cortex A53; but
-//! Ed25519 signatures with 32-bit truncation can be verified in less
-//! than 0.05 seconds on the same hardware). Signature truncation can be
-//! useful in situations with strong I/O constraints, where every data
-//! bit counts, but where use of fully standard Ed25519 or ECDSA
-//! signature generators is made mandatory because of some regulatory or
-//! physical constraints of the signing hardware.
+//! algorithms, _truncated signatures_ are implemented for Ed25519 and
+//! Ed448 (Schnorr signatures over edwards25519 and edwards448), ECDSA
+//! (over P-256 and secp256k1), and the 48-byte Schnorr signatures of
+//! jq255e, jq255s and GLS254. A truncated signature is a shrunk
+//! version, by up to 32 bits, of a normal signature; the verification
+//! process is then more expensive, though not necessarily intolerably
+//! expensive, depending on usage context (for ECDSA on P-256, with
+//! maximal 32-bit truncation, verification cost can be up to about
+//! 0.65 seconds on a 500 MHz ARM Cortex A53; but Ed25519 signatures
+//! with 32-bit truncation can be verified in less than 0.05 seconds on
+//! the same hardware). The jq255e, jq255s and GLS254 signatures do not
+//! include the per-signature point, so that their reconstruction is an
+//! exhaustive search whose cost doubles with each removed bit; up to 32
+//! bits are accepted, but in practice only light truncation (up to 16
+//! bits or so) is usable for them. Signature truncation can be useful
+//! in situations with strong I/O constraints, where every data bit
+//! counts, but where use of fully standard Ed25519 or ECDSA signature
+//! generators is made mandatory because of some regulatory or physical
+//! constraints of the signing hardware.
 //! 
 //! # Performance
 //!
@@ -112,14 +122,114 @@ extern crate std;
 #[allow(unused_imports)]
 pub(crate) use alloc::vec::Vec;
 
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+#[allow(unused_imports)]
+pub(crate) use alloc::boxed::Box;
+
 #[cfg(feature = "std")]
 #[allow(unused_imports)]
 pub(crate) use std::vec::Vec;
 
+#[cfg(feature = "std")]
+#[allow(unused_imports)]
+pub(crate) use std::boxed::Box;
+
 /// The `rand_core` types are re-exported so that users of crrl do not
 /// have to worry about using the exact correct version of `rand_core`.
 pub use rand_core::{CryptoRng, RngCore, Error as RngError};
 
+/// The `digest` crate is re-exported so that users of crrl do not have to
+/// worry about using the exact correct version of `digest` when they use
+/// the `digest` traits implemented by the crrl hash functions.
+pub use digest;
+
+/// Hash functions with a registered name for the signature schemes that
+/// sign pre-hashed messages (jq255e, jq255s, gls254).
+///
+/// `HASH_NAME` is the value that the `hash_name` parameter of these
+/// schemes takes when the signed data is a hash value computed with this
+/// function (e.g. `"sha256"` for SHA-256). This allows signing and
+/// verifying with a finished hasher, e.g. with
+/// `jq255e::PrivateKey::sign_digest()`.
+pub trait HashName: digest::Digest {
+    const HASH_NAME: &'static str;
+}
+
+// The registered names are the `HASHNAME_*` constants of the `Point`
+// type in these schemes (all three define the same set).
+#[cfg(feature = "jq255e")]
+use jq255e::Point as HashNames;
+#[cfg(all(feature = "jq255s", not(feature = "jq255e")))]
+use jq255s::Point as HashNames;
+#[cfg(all(feature = "gls254",
+    not(feature = "jq255e"), not(feature = "jq255s")))]
+use gls254::Point as HashNames;
+
+macro_rules! define_hash_name {
+    ($typename:ty, $name:ident) => {
+        #[cfg(any(feature = "jq255e", feature = "jq255s", feature = "gls254"))]
+        impl HashName for $typename {
+            const HASH_NAME: &'static str = HashNames::$name;
+        }
+    }
+}
+
+define_hash_name!(sha2::Sha224, HASHNAME_SHA224);
+define_hash_name!(sha2::Sha256, HASHNAME_SHA256);
+define_hash_name!(sha2::Sha384, HASHNAME_SHA384);
+define_hash_name!(sha2::Sha512, HASHNAME_SHA512);
+define_hash_name!(sha2::Sha512_224, HASHNAME_SHA512_224);
+define_hash_name!(sha2::Sha512_256, HASHNAME_SHA512_256);
+define_hash_name!(sha3::Sha3_224, HASHNAME_SHA3_224);
+define_hash_name!(sha3::Sha3_256, HASHNAME_SHA3_256);
+define_hash_name!(sha3::Sha3_384, HASHNAME_SHA3_384);
+define_hash_name!(sha3::Sha3_512, HASHNAME_SHA3_512);
+#[cfg(feature = "blake2b")]
+define_hash_name!(blake2b::Blake2b512, HASHNAME_BLAKE2B);
+#[cfg(feature = "blake2s")]
+define_hash_name!(blake2s::Blake2s256, HASHNAME_BLAKE2S);
+#[cfg(feature = "blake3")]
+define_hash_name!(blake3::Blake3, HASHNAME_BLAKE3);
+
+// BLAKE2s G function, over the 16-word state `v`; `x` and `y` are the
+// two message words. BLAKE3 uses the same function.
+#[allow(unused_macros)]
+macro_rules! blake2s_g {
+    ($v: ident, $a: expr, $b: expr, $c: expr, $d: expr, $x: expr, $y: expr)
+    => {
+        $v[$a] = $v[$a].wrapping_add($v[$b].wrapping_add($x));
+        $v[$d] = ($v[$d] ^ $v[$a]).rotate_right(16);
+        $v[$c] = $v[$c].wrapping_add($v[$d]);
+        $v[$b] = ($v[$b] ^ $v[$c]).rotate_right(12);
+        $v[$a] = $v[$a].wrapping_add($v[$b].wrapping_add($y));
+        $v[$d] = ($v[$d] ^ $v[$a]).rotate_right(8);
+        $v[$c] = $v[$c].wrapping_add($v[$d]);
+        $v[$b] = ($v[$b] ^ $v[$c]).rotate_right(7);
+    }
+}
+
+// One BLAKE2s round over the state `v` and the message block `m` (16
+// words); the message schedule for the round is provided as 16 indices.
+// BLAKE3 rounds have the same structure (with another schedule).
+#[allow(unused_macros)]
+macro_rules! blake2s_round {
+    ($v: ident, $m: ident,
+     $s0: expr, $s1: expr, $s2: expr, $s3: expr,
+     $s4: expr, $s5: expr, $s6: expr, $s7: expr,
+     $s8: expr, $s9: expr, $sA: expr, $sB: expr,
+     $sC: expr, $sD: expr, $sE: expr, $sF: expr)
+    => {
+        blake2s_g!($v, 0, 4,  8, 12, $m[$s0], $m[$s1]);
+        blake2s_g!($v, 1, 5,  9, 13, $m[$s2], $m[$s3]);
+        blake2s_g!($v, 2, 6, 10, 14, $m[$s4], $m[$s5]);
+        blake2s_g!($v, 3, 7, 11, 15, $m[$s6], $m[$s7]);
+        blake2s_g!($v, 0, 5, 10, 15, $m[$s8], $m[$s9]);
+        blake2s_g!($v, 1, 6, 11, 12, $m[$sA], $m[$sB]);
+        blake2s_g!($v, 2, 7,  8, 13, $m[$sC], $m[$sD]);
+        blake2s_g!($v, 3, 4,  9, 14, $m[$sE], $m[$sF]);
+    }
+}
+
 #[allow(unused_macros)]
 macro_rules! static_assert {
     ($condition:expr) => {
@@ -171,5 +281,38 @@ pub mod frost;
 #[cfg(feature = "lms")]
 pub mod lms;
 
+#[cfg(feature = "xmss")]
+pub mod xmss;
+
+#[cfg(feature = "slhdsa")]
+pub mod slhdsa;
+
+#[cfg(feature = "musig2")]
+pub mod musig2;
+
+#[cfg(feature = "blake2b")]
+pub mod blake2b;
+
 #[cfg(feature = "blake2s")]
 pub mod blake2s;
+
+#[cfg(feature = "blake3")]
+pub mod blake3;
+
+#[cfg(feature = "aes")]
+pub mod aes;
+
+#[cfg(feature = "chacha20poly1305")]
+pub mod chacha20poly1305;
+
+#[cfg(feature = "ecies")]
+pub mod ecies;
+
+#[cfg(feature = "hpke")]
+pub mod hpke;
+
+#[cfg(feature = "kdf")]
+pub mod kdf;
+
+#[cfg(feature = "noise")]
+pub mod noise;
@@ -4,45 +4,265 @@
 //! draft-fluhrer-lms-more-parm-sets-09.txt (which itself copies
 //! the parameters from NIST SP 800-208).
 //!
+//! All combinations of hash function (SHA-256, SHA-256/192, SHAKE256
+//! with 256-bit or 192-bit output), tree height (5, 10, 15, 20 or 25)
+//! and Winternitz parameter (1, 2, 4 or 8) are defined, each in its own
+//! module (e.g. `LMS_SHA256_M32_H10_SHA256_N32_W4`). The private key does
+//! not keep the whole Merkle tree; it uses a fractal traversal, with the
+//! tree split into layers of subtrees of height 5: memory usage is about
+//! `2*64*h/5` hash values, and each signature computes at most `h/5 - 1`
+//! tree leaves (in addition to the signature itself). Key pair generation
+//! still needs to compute the whole tree, which is expensive for large
+//! heights; for large numbers of signatures, HSS with smaller trees should
+//! be preferred. Private key decoding also recomputes the tree, unless
+//! the traversal state was saved (`PrivateKey::encode_traversal()`) and
+//! is provided again (`PrivateKey::decode_with_traversal()`).
+//!
 //! WARNING: LMS is a stateful signature scheme; each signature modifies
 //! the private key. If the same private key state is used to generate
 //! two signatures (even if both are on the same data), then attackers
 //! observing the two signature values learn enough to make forgeries.
-//! This implementation always mutates the `PrivateKey` structure when
-//! generating a signature, but it is up to the caller to ensure that the
-//! new private key state is properly committed to stable storage before
-//! showing the signature value to any third party.
+//! To prevent such reuse, signatures can only be generated through a
+//! `Reservation`: `PrivateKey::reserve()` allocates a number of leaves,
+//! and calls a caller-provided function with the encoded updated private
+//! key, which must commit it to stable storage before returning `true`.
+//! Only once the new state is committed is the reservation returned, and
+//! the reserved leaves can then be used to sign. Leaves that were reserved
+//! but not used are simply lost. `PrivateKey` is deliberately neither
+//! `Copy` nor `Clone`.
+//!
+//! Public keys use the RFC 8554 encoding (`PublicKey::encode()` and
+//! `PublicKey::decode()`). Private keys use a compact, non-standard
+//! format that contains the LMS and LM-OTS types, the leaf counter `q`,
+//! the identifier `I` and the secret seed `SEED`; the Merkle tree is
+//! recomputed when decoding, unless a saved traversal state is used.
 //!
-//! This code was written mostly for verifying the test vectors in the
-//! parameter sets in the new draft; it does not include facilities for
-//! serialization of private and public keys. It also does NOT implement
-//! HSS, the hierarchical scheme that builds on top of LMS (in RFC 8554,
-//! section 6).
+//! HSS, the hierarchical scheme that builds on top of LMS (RFC 8554,
+//! section 6), is implemented in the `hss` sub-module (which requires
+//! the `alloc` feature). Each HSS level may use any of the LMS parameter
+//! sets defined here.
 
 // We use the constant names from RFC 8554, which do not following the
 // default casing style rules of Rust.
 #![allow(non_snake_case)]
 #![allow(non_upper_case_globals)]
 
+#[cfg(feature = "alloc")]
+use crate::{Box, Vec};
+
+/// Identification of an LMS parameter set, by its LMS and LM-OTS type
+/// codes (as registered by IANA).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LmsType {
+    /// LMS type (e.g. 5 for `LMS_SHA256_M32_H5`).
+    pub lms: u32,
+    /// LM-OTS type (e.g. 4 for `LMOTS_SHA256_N32_W8`).
+    pub lmots: u32,
+}
+
+// A private key for one LMS tree, used as a level in HSS. This is
+// implemented by the `PrivateKey` type of each parameter set.
+#[cfg(feature = "alloc")]
+trait HssLevel {
+
+    // Gets the encoded public key (RFC 8554 format).
+    fn public_encoded(&self) -> Vec<u8>;
+
+    // Signs a message with leaf q. If rnd is None, then the randomizer
+    // C is derived deterministically from the private key and q; this
+    // is used for signing child public keys, so that regenerating a
+    // child and signing it again yields the same signature.
+    fn sign_encoded(&mut self, q: u32, rnd: Option<&[u8; 32]>, msg: &[u8])
+        -> Vec<u8>;
+
+    // Derives the identifier and seed for the child tree under leaf q.
+    fn child(&self, q: u32) -> ([u8; 16], [u8; 32]);
+}
+
+// Per-parameter set operations, for use by HSS.
+#[cfg(feature = "alloc")]
+#[derive(Debug)]
+struct LmsOps {
+    typ: LmsType,
+    height: u32,
+    pk_len: usize,
+    sig_len: usize,
+    verify: fn(&[u8], &[u8], &[u8]) -> bool,
+    new_level: fn(&[u8; 16], &[u8; 32]) -> Box<dyn HssLevel>,
+}
+
+#[cfg(feature = "alloc")]
+static ALL_LMS: [&LmsOps; 80] = [
+    &LMS_SHA256_M32_H5_SHA256_N32_W1::OPS,
+    &LMS_SHA256_M32_H5_SHA256_N32_W2::OPS,
+    &LMS_SHA256_M32_H5_SHA256_N32_W4::OPS,
+    &LMS_SHA256_M32_H5_SHA256_N32_W8::OPS,
+    &LMS_SHA256_M32_H10_SHA256_N32_W1::OPS,
+    &LMS_SHA256_M32_H10_SHA256_N32_W2::OPS,
+    &LMS_SHA256_M32_H10_SHA256_N32_W4::OPS,
+    &LMS_SHA256_M32_H10_SHA256_N32_W8::OPS,
+    &LMS_SHA256_M32_H15_SHA256_N32_W1::OPS,
+    &LMS_SHA256_M32_H15_SHA256_N32_W2::OPS,
+    &LMS_SHA256_M32_H15_SHA256_N32_W4::OPS,
+    &LMS_SHA256_M32_H15_SHA256_N32_W8::OPS,
+    &LMS_SHA256_M32_H20_SHA256_N32_W1::OPS,
+    &LMS_SHA256_M32_H20_SHA256_N32_W2::OPS,
+    &LMS_SHA256_M32_H20_SHA256_N32_W4::OPS,
+    &LMS_SHA256_M32_H20_SHA256_N32_W8::OPS,
+    &LMS_SHA256_M32_H25_SHA256_N32_W1::OPS,
+    &LMS_SHA256_M32_H25_SHA256_N32_W2::OPS,
+    &LMS_SHA256_M32_H25_SHA256_N32_W4::OPS,
+    &LMS_SHA256_M32_H25_SHA256_N32_W8::OPS,
+    &LMS_SHA256_M24_H5_SHA256_N24_W1::OPS,
+    &LMS_SHA256_M24_H5_SHA256_N24_W2::OPS,
+    &LMS_SHA256_M24_H5_SHA256_N24_W4::OPS,
+    &LMS_SHA256_M24_H5_SHA256_N24_W8::OPS,
+    &LMS_SHA256_M24_H10_SHA256_N24_W1::OPS,
+    &LMS_SHA256_M24_H10_SHA256_N24_W2::OPS,
+    &LMS_SHA256_M24_H10_SHA256_N24_W4::OPS,
+    &LMS_SHA256_M24_H10_SHA256_N24_W8::OPS,
+    &LMS_SHA256_M24_H15_SHA256_N24_W1::OPS,
+    &LMS_SHA256_M24_H15_SHA256_N24_W2::OPS,
+    &LMS_SHA256_M24_H15_SHA256_N24_W4::OPS,
+    &LMS_SHA256_M24_H15_SHA256_N24_W8::OPS,
+    &LMS_SHA256_M24_H20_SHA256_N24_W1::OPS,
+    &LMS_SHA256_M24_H20_SHA256_N24_W2::OPS,
+    &LMS_SHA256_M24_H20_SHA256_N24_W4::OPS,
+    &LMS_SHA256_M24_H20_SHA256_N24_W8::OPS,
+    &LMS_SHA256_M24_H25_SHA256_N24_W1::OPS,
+    &LMS_SHA256_M24_H25_SHA256_N24_W2::OPS,
+    &LMS_SHA256_M24_H25_SHA256_N24_W4::OPS,
+    &LMS_SHA256_M24_H25_SHA256_N24_W8::OPS,
+    &LMS_SHAKE_M32_H5_SHAKE_N32_W1::OPS,
+    &LMS_SHAKE_M32_H5_SHAKE_N32_W2::OPS,
+    &LMS_SHAKE_M32_H5_SHAKE_N32_W4::OPS,
+    &LMS_SHAKE_M32_H5_SHAKE_N32_W8::OPS,
+    &LMS_SHAKE_M32_H10_SHAKE_N32_W1::OPS,
+    &LMS_SHAKE_M32_H10_SHAKE_N32_W2::OPS,
+    &LMS_SHAKE_M32_H10_SHAKE_N32_W4::OPS,
+    &LMS_SHAKE_M32_H10_SHAKE_N32_W8::OPS,
+    &LMS_SHAKE_M32_H15_SHAKE_N32_W1::OPS,
+    &LMS_SHAKE_M32_H15_SHAKE_N32_W2::OPS,
+    &LMS_SHAKE_M32_H15_SHAKE_N32_W4::OPS,
+    &LMS_SHAKE_M32_H15_SHAKE_N32_W8::OPS,
+    &LMS_SHAKE_M32_H20_SHAKE_N32_W1::OPS,
+    &LMS_SHAKE_M32_H20_SHAKE_N32_W2::OPS,
+    &LMS_SHAKE_M32_H20_SHAKE_N32_W4::OPS,
+    &LMS_SHAKE_M32_H20_SHAKE_N32_W8::OPS,
+    &LMS_SHAKE_M32_H25_SHAKE_N32_W1::OPS,
+    &LMS_SHAKE_M32_H25_SHAKE_N32_W2::OPS,
+    &LMS_SHAKE_M32_H25_SHAKE_N32_W4::OPS,
+    &LMS_SHAKE_M32_H25_SHAKE_N32_W8::OPS,
+    &LMS_SHAKE_M24_H5_SHAKE_N24_W1::OPS,
+    &LMS_SHAKE_M24_H5_SHAKE_N24_W2::OPS,
+    &LMS_SHAKE_M24_H5_SHAKE_N24_W4::OPS,
+    &LMS_SHAKE_M24_H5_SHAKE_N24_W8::OPS,
+    &LMS_SHAKE_M24_H10_SHAKE_N24_W1::OPS,
+    &LMS_SHAKE_M24_H10_SHAKE_N24_W2::OPS,
+    &LMS_SHAKE_M24_H10_SHAKE_N24_W4::OPS,
+    &LMS_SHAKE_M24_H10_SHAKE_N24_W8::OPS,
+    &LMS_SHAKE_M24_H15_SHAKE_N24_W1::OPS,
+    &LMS_SHAKE_M24_H15_SHAKE_N24_W2::OPS,
+    &LMS_SHAKE_M24_H15_SHAKE_N24_W4::OPS,
+    &LMS_SHAKE_M24_H15_SHAKE_N24_W8::OPS,
+    &LMS_SHAKE_M24_H20_SHAKE_N24_W1::OPS,
+    &LMS_SHAKE_M24_H20_SHAKE_N24_W2::OPS,
+    &LMS_SHAKE_M24_H20_SHAKE_N24_W4::OPS,
+    &LMS_SHAKE_M24_H20_SHAKE_N24_W8::OPS,
+    &LMS_SHAKE_M24_H25_SHAKE_N24_W1::OPS,
+    &LMS_SHAKE_M24_H25_SHAKE_N24_W2::OPS,
+    &LMS_SHAKE_M24_H25_SHAKE_N24_W4::OPS,
+    &LMS_SHAKE_M24_H25_SHAKE_N24_W8::OPS,
+];
+
+#[cfg(feature = "alloc")]
+fn find_ops(lms: u32, lmots: u32) -> Option<&'static LmsOps> {
+    ALL_LMS.iter().copied().find(|o| o.typ.lms == lms && o.typ.lmots == lmots)
+}
+
+// Derivation of the secrets of a child tree in HSS (non-standard; RFC 8554
+// leaves it to the implementation). The child seed and identifier are
+// obtained with SHA-256 over the parent identifier, leaf index and seed,
+// with the same layout as the LM-OTS private key derivation (RFC 8554,
+// appendix A), but with index values that are never used there.
+#[cfg(feature = "alloc")]
+fn derive_child(I: &[u8; 16], q: u32, SEED: &[u8]) -> ([u8; 16], [u8; 32]) {
+    use sha2::{Sha256, Digest};
+    let mut cSEED = [0u8; 32];
+    let mut cI = [0u8; 16];
+    let mut sh = Sha256::new();
+    sh.update(I);
+    sh.update(q.to_be_bytes());
+    sh.update([0xFF, 0xFE, 0xFF]);
+    sh.update(SEED);
+    cSEED.copy_from_slice(&sh.finalize());
+    let mut sh = Sha256::new();
+    sh.update(I);
+    sh.update(q.to_be_bytes());
+    sh.update([0xFF, 0xFF, 0xFF]);
+    sh.update(SEED);
+    cI.copy_from_slice(&sh.finalize()[..16]);
+    (cI, cSEED)
+}
+
 macro_rules! define_lms_core { () => {
 
     use crate::{CryptoRng, RngCore};
     use core::convert::TryFrom;
 
-    #[derive(Clone, Copy, Debug)]
+    /// An LMS private key.
+    ///
+    /// The private key contains the leaf counter, which is updated when
+    /// leaves are reserved (see `reserve()`). This type is neither `Copy`
+    /// nor `Clone`, so that a given state cannot be inadvertently
+    /// duplicated; it does not implement `Debug` either, so that the
+    /// secret seed is not written into logs.
     pub struct PrivateKey {
         I: [u8; 16],
         SEED: [u8; m],
         current_leaf: u32,
-        T: [[u8; m]; 1usize << (h + 1)],
+        T1: [u8; m],
+        trav: Traversal,
     }
 
+    // Merkle tree traversal state (fractal traversal). The tree is split
+    // into nL layers of subtrees of height SUB_H; for each layer, we keep
+    // the subtree that contains the current leaf (all nodes, in heap
+    // order, index 1 being the subtree root), and the next subtree of the
+    // same layer, which is built incrementally (one tree leaf per
+    // signature) and whose bottom nodes (indices 32 to 63) are filled as
+    // they are obtained. The top layer has a single subtree, which never
+    // changes.
+    #[derive(Debug)]
+    struct Traversal {
+        leaf: u32,
+        cur: [[[u8; m]; SUB_N]; nL],
+        next: [[[u8; m]; SUB_N]; nL],
+        next_leaf: [u32; nL],
+        stack: [[[u8; m]; h]; nL],
+        stack_level: [[usize; h]; nL],
+        stack_len: [usize; nL],
+    }
+
+    /// An LMS public key.
     #[derive(Clone, Copy, Debug)]
     pub struct PublicKey {
         I: [u8; 16],
         T1: [u8; m],
     }
 
+    /// A reservation of leaves for signing.
+    ///
+    /// A reservation is obtained from `PrivateKey::reserve()`, after the
+    /// updated private key state has been committed. Each signature uses
+    /// the next reserved leaf; once all reserved leaves have been used,
+    /// signing fails.
+    pub struct Reservation<'a> {
+        sk: &'a mut PrivateKey,
+        next_leaf: u32,
+        end_leaf: u32,
+    }
+
     const p: usize = make_p();
     const ls: usize = make_ls();
 
@@ -78,6 +298,10 @@ macro_rules! define_lms_core { () => {
     const D_INTR: [u8; 2] = [ 0x83, 0x83, ];
 
     const ots_siglen: usize = 4 + n + n * p;
+
+    const SUB_H: usize = 5;
+    const SUB_N: usize = 1usize << (SUB_H + 1);
+    const nL: usize = h / SUB_H;
     const lms_siglen: usize = 4 + ots_siglen + 4 + h * m;
 
     fn checksum(Q: &[u8]) -> u16 {
@@ -96,39 +320,176 @@ macro_rules! define_lms_core { () => {
 
     impl PrivateKey {
 
+        /// Generates a new private key.
+        ///
+        /// The leaf counter of the new key is zero; the key state is
+        /// committed to storage by the first call to `reserve()`.
         pub fn generate<T: CryptoRng + RngCore>(rng: &mut T) -> Self {
             let mut I = [0u8; 16];
             let mut SEED = [0u8; m];
             rng.fill_bytes(&mut I);
             rng.fill_bytes(&mut SEED);
-            let current_leaf = 0u32;
-            let mut sk = Self {
-                I, SEED, current_leaf,
-                T: [[0u8; m]; 1usize << (h + 1)],
-            };
-            sk.compute_tree();
+            let mut sk = Self::new_empty(I, SEED, 0);
+            sk.init_traversal(0);
             sk
         }
 
-        fn compute_tree(&mut self) {
-            for r in (1u32 << h)..(1u32 << (h + 1)) {
-                let q = r - (1u32 << h);
-                let x = self.make_ots_x(q);
-                let y = self.make_ots_pub_y(q, &x);
-                self.T[r as usize] = Hm(&self.I, &r.to_be_bytes(), &D_LEAF,
-                    &self.make_ots_pub_hash(q, &y), &Z);
+        fn new_empty(I: [u8; 16], SEED: [u8; m], current_leaf: u32) -> Self {
+            Self {
+                I, SEED, current_leaf,
+                T1: [0u8; m],
+                trav: Traversal {
+                    leaf: 0,
+                    cur: [[[0u8; m]; SUB_N]; nL],
+                    next: [[[0u8; m]; SUB_N]; nL],
+                    next_leaf: [0u32; nL],
+                    stack: [[[0u8; m]; h]; nL],
+                    stack_level: [[0usize; h]; nL],
+                    stack_len: [0usize; nL],
+                },
+            }
+        }
+
+        /// Gets the public key corresponding to this private key.
+        pub fn compute_public(&self) -> PublicKey {
+            PublicKey { I: self.I, T1: self.T1 }
+        }
+
+        // Computes the tree node for leaf q.
+        fn leaf_node(&self, q: u32) -> [u8; m] {
+            let x = self.make_ots_x(q);
+            let y = self.make_ots_pub_y(q, &x);
+            let r = (1u32 << h) + q;
+            Hm(&self.I, &r.to_be_bytes(), &D_LEAF,
+                &self.make_ots_pub_hash(q, &y), &Z)
+        }
+
+        // Computes the internal node at level l (leaves are at level 0)
+        // and index j within that level, from its two children.
+        fn intr_node(&self, l: usize, j: u32, left: &[u8; m], right: &[u8; m])
+            -> [u8; m]
+        {
+            let r = (1u32 << (h - l)) + j;
+            Hm(&self.I, &r.to_be_bytes(), &D_INTR, left, right)
+        }
+
+        // Computes the whole tree, and sets the traversal state for
+        // leaf q: the current subtree of each layer is the one that
+        // contains leaf q, and the next subtree of each layer is complete.
+        fn init_traversal(&mut self, q: u32) {
+            let mut stack = [[0u8; m]; h + 1];
+            let mut levels = [0usize; h + 1];
+            let mut sp = 0;
+            for x in 0..(1u32 << h) {
+                let mut node = self.leaf_node(x);
+                let mut l = 0;
+                let mut j = x;
+                self.store_init(q, l, j, &node);
+                while sp > 0 && levels[sp - 1] == l {
+                    sp -= 1;
+                    node = self.intr_node(l + 1, j >> 1, &stack[sp], &node);
+                    l += 1;
+                    j >>= 1;
+                    self.store_init(q, l, j, &node);
+                }
+                stack[sp] = node;
+                levels[sp] = l;
+                sp += 1;
+            }
+            self.T1 = stack[0];
+            let tr = &mut self.trav;
+            tr.leaf = q;
+            for i in 0..nL {
+                let sh = SUB_H * (i + 1);
+                tr.next_leaf[i] = (((q >> sh) + 2) << sh).min(1u32 << h);
+                tr.stack_len[i] = 0;
+            }
+        }
+
+        // Stores a node, obtained while computing the whole tree, in the
+        // relevant subtrees for leaf q.
+        fn store_init(&mut self, q: u32, l: usize, j: u32, node: &[u8; m]) {
+            for i in 0..nL {
+                if l < SUB_H * i || l > SUB_H * (i + 1) {
+                    continue;
+                }
+                let d = SUB_H * (i + 1) - l;
+                let s = j >> d;
+                let pos = (1usize << d) + (j as usize & ((1usize << d) - 1));
+                let c = q >> (SUB_H * (i + 1));
+                if s == c {
+                    self.trav.cur[i][pos] = *node;
+                } else if s == c + 1 {
+                    self.trav.next[i][pos] = *node;
+                }
+            }
+        }
+
+        // Advances the traversal state to the next leaf.
+        fn advance(&mut self) {
+            let q = self.trav.leaf + 1;
+            self.trav.leaf = q;
+            if q >= (1u32 << h) {
+                return;
             }
-            for r in (1..(1u32 << h)).rev() {
-                self.T[r as usize] = Hm(&self.I, &r.to_be_bytes(), &D_INTR,
-                    &self.T[(2 * r) as usize], &self.T[(2 * r + 1) as usize]);
+            // The top layer has a single subtree, and is never updated
+            // (its next subtree is empty).
+            for i in 0..nL {
+                let sh = SUB_H * (i + 1);
+                let c = q >> sh;
+                // The subtree under construction is the one after the
+                // subtree of the previous leaf.
+                let end = ((((q - 1) >> sh) + 2) << sh).min(1u32 << h);
+                if self.trav.next_leaf[i] < end {
+                    self.build_step(i);
+                }
+                if (q & ((1u32 << sh) - 1)) == 0 {
+                    // We just entered a new subtree for this layer; it
+                    // was completed as the next subtree.
+                    for pos in (1..(SUB_N >> 1)).rev() {
+                        let d = 31 - (pos as u32).leading_zeros() as usize;
+                        let j = (c << d) + (pos as u32 - (1u32 << d));
+                        self.trav.next[i][pos] = self.intr_node(sh - d, j,
+                            &self.trav.next[i][2 * pos],
+                            &self.trav.next[i][2 * pos + 1]);
+                    }
+                    self.trav.cur[i] = self.trav.next[i];
+                    self.trav.next_leaf[i] = ((c + 1) << sh).min(1u32 << h);
+                    self.trav.stack_len[i] = 0;
+                }
             }
         }
 
-        pub fn compute_public(self) -> PublicKey {
-            PublicKey { I: self.I, T1: self.T[1] }
+        // Processes one tree leaf for the next subtree of layer i.
+        fn build_step(&mut self, i: usize) {
+            let x = self.trav.next_leaf[i];
+            self.trav.next_leaf[i] = x + 1;
+            let mut node = self.leaf_node(x);
+            let mut l = 0;
+            let mut j = x;
+            let tl = SUB_H * i;
+            loop {
+                if l == tl {
+                    let pos = (SUB_N >> 1) + (j as usize & ((SUB_N >> 1) - 1));
+                    self.trav.next[i][pos] = node;
+                    return;
+                }
+                let sp = self.trav.stack_len[i];
+                if sp == 0 || self.trav.stack_level[i][sp - 1] != l {
+                    self.trav.stack[i][sp] = node;
+                    self.trav.stack_level[i][sp] = l;
+                    self.trav.stack_len[i] = sp + 1;
+                    return;
+                }
+                self.trav.stack_len[i] = sp - 1;
+                node = self.intr_node(l + 1, j >> 1,
+                    &self.trav.stack[i][sp - 1], &node);
+                l += 1;
+                j >>= 1;
+            }
         }
 
-        fn make_ots_x(self, q: u32) -> [[u8; n]; p] {
+        fn make_ots_x(&self, q: u32) -> [[u8; n]; p] {
             let mut x = [[0u8; n]; p];
             let eq = q.to_be_bytes();
             for i in 0..p {
@@ -138,7 +499,7 @@ macro_rules! define_lms_core { () => {
             x
         }
 
-        fn make_ots_pub_y(self, q: u32, x: &[[u8; n]; p]) -> [[u8; n]; p] {
+        fn make_ots_pub_y(&self, q: u32, x: &[[u8; n]; p]) -> [[u8; n]; p] {
             let mut y = [[0u8; n]; p];
             let eq = q.to_be_bytes();
             for i in 0..p {
@@ -152,21 +513,18 @@ macro_rules! define_lms_core { () => {
             y
         }
 
-        fn make_ots_pub_hash(self, q: u32, y: &[[u8; n]; p]) -> [u8; n] {
+        fn make_ots_pub_hash(&self, q: u32, y: &[[u8; n]; p]) -> [u8; n] {
             Hnx(&self.I, &q.to_be_bytes(), &D_PBLC, &y)
         }
 
-        fn ots_sign<T: CryptoRng + RngCore>(self, rng: &mut T,
-            q: u32, msg: &[u8]) -> [u8; ots_siglen]
+        fn ots_sign(&self, C: &[u8; n], q: u32, msg: &[u8])
+            -> [u8; ots_siglen]
         {
             let mut sig = [0u8; ots_siglen];
             sig[0..4].copy_from_slice(&ots_type.to_be_bytes());
+            sig[4..(n + 4)].copy_from_slice(C);
 
-            let mut C = [0u8; n];
-            rng.fill_bytes(&mut C);
-            sig[4..(n + 4)].copy_from_slice(&C);
-
-            let Q = Hn(&self.I, &q.to_be_bytes(), &D_MESG, &C, msg);
+            let Q = Hn(&self.I, &q.to_be_bytes(), &D_MESG, C, msg);
             let mut Qck = [0u8; n + 2];
             Qck[..n].copy_from_slice(&Q);
             Qck[n..].copy_from_slice(&(checksum(&Q).to_be_bytes()));
@@ -184,32 +542,307 @@ macro_rules! define_lms_core { () => {
             sig
         }
 
-        pub fn sign<T: CryptoRng + RngCore>(&mut self, rng: &mut T, msg: &[u8])
-            -> Option<[u8; 4 + ots_siglen + 4 + h * m]>
+        /// Encoded private key length (in bytes).
+        pub const ENC_LEN: usize = 4 + 4 + 4 + 16 + m;
+
+        /// Encodes this private key into bytes.
+        ///
+        /// The encoding contains the LMS type, LM-OTS type, leaf counter,
+        /// identifier and seed.
+        pub fn encode(&self) -> [u8; Self::ENC_LEN] {
+            let mut buf = [0u8; Self::ENC_LEN];
+            buf[0..4].copy_from_slice(&key_type.to_be_bytes());
+            buf[4..8].copy_from_slice(&ots_type.to_be_bytes());
+            buf[8..12].copy_from_slice(&self.current_leaf.to_be_bytes());
+            buf[12..28].copy_from_slice(&self.I);
+            buf[28..].copy_from_slice(&self.SEED);
+            buf
+        }
+
+        /// Decodes a private key from bytes.
+        ///
+        /// The Merkle tree is recomputed (this has the same cost as key
+        /// pair generation); use `decode_with_traversal()` to avoid that
+        /// cost. This function returns `None`
+        /// if the source does not have the proper length, or if the
+        /// encoded LMS and LM-OTS types do not match this parameter set,
+        /// or if the leaf counter is out of range.
+        pub fn decode(buf: &[u8]) -> Option<Self> {
+            if buf.len() != Self::ENC_LEN {
+                return None;
+            }
+            let kt = u32::from_be_bytes(*<&[u8; 4]>::try_from(&buf[0..4]).unwrap());
+            let ot = u32::from_be_bytes(*<&[u8; 4]>::try_from(&buf[4..8]).unwrap());
+            let q = u32::from_be_bytes(*<&[u8; 4]>::try_from(&buf[8..12]).unwrap());
+            if kt != key_type || ot != ots_type || q > (1u32 << h) {
+                return None;
+            }
+            let mut I = [0u8; 16];
+            let mut SEED = [0u8; m];
+            I.copy_from_slice(&buf[12..28]);
+            SEED.copy_from_slice(&buf[28..]);
+            let mut sk = Self::new_empty(I, SEED, q);
+            sk.init_traversal(q.min((1u32 << h) - 1));
+            Some(sk)
+        }
+
+        /// Encoded traversal state length (in bytes).
+        pub const TRAV_LEN: usize = 16 + 4
+            + nL * (4 + 1 + h + (2 * SUB_N + h) * m);
+
+        /// Encodes the Merkle tree traversal state of this private key.
+        ///
+        /// The private key encoding (`encode()`) does not include the
+        /// Merkle tree, so that `decode()` must recompute the whole tree.
+        /// The traversal state can be saved separately, and provided
+        /// again to `decode_with_traversal()`, which then skips that
+        /// computation. The traversal state contains only tree nodes
+        /// (no secret values), and it does not need to be committed
+        /// along with the private key: an outdated traversal state is
+        /// still usable (it is advanced, or recomputed, as needed when
+        /// signing).
+        pub fn encode_traversal(&self) -> [u8; Self::TRAV_LEN] {
+            let tr = &self.trav;
+            let mut buf = [0u8; Self::TRAV_LEN];
+            buf[0..16].copy_from_slice(&self.I);
+            buf[16..20].copy_from_slice(&tr.leaf.to_be_bytes());
+            let mut j = 20;
+            for i in 0..nL {
+                buf[j..(j + 4)].copy_from_slice(&tr.next_leaf[i].to_be_bytes());
+                buf[j + 4] = tr.stack_len[i] as u8;
+                j += 5;
+                for k in 0..h {
+                    buf[j + k] = tr.stack_level[i][k] as u8;
+                }
+                j += h;
+                for node in tr.cur[i].iter().chain(tr.next[i].iter())
+                    .chain(tr.stack[i].iter())
+                {
+                    buf[j..(j + m)].copy_from_slice(node);
+                    j += m;
+                }
+            }
+            buf
+        }
+
+        /// Decodes a private key from bytes, along with a traversal state
+        /// previously obtained with `encode_traversal()`.
+        ///
+        /// The private key `buf` uses the same format as with `decode()`.
+        /// The traversal state is checked against the private key (it must
+        /// have been saved from the same key, and the authentication path
+        /// of its current leaf must lead to the tree root); the cost of
+        /// that check is that of a single LM-OTS key pair generation. This
+        /// function returns `None` if the private key or the traversal
+        /// state cannot be decoded, or if they do not match each other.
+        pub fn decode_with_traversal(buf: &[u8], trav: &[u8])
+            -> Option<Self>
         {
-            let q = self.current_leaf;
-            if q >= (1u32 << h) {
+            if buf.len() != Self::ENC_LEN || trav.len() != Self::TRAV_LEN {
+                return None;
+            }
+            let kt = u32::from_be_bytes(*<&[u8; 4]>::try_from(&buf[0..4]).unwrap());
+            let ot = u32::from_be_bytes(*<&[u8; 4]>::try_from(&buf[4..8]).unwrap());
+            let q = u32::from_be_bytes(*<&[u8; 4]>::try_from(&buf[8..12]).unwrap());
+            if kt != key_type || ot != ots_type || q > (1u32 << h) {
+                return None;
+            }
+            if buf[12..28] != trav[0..16] {
+                return None;
+            }
+            let mut I = [0u8; 16];
+            let mut SEED = [0u8; m];
+            I.copy_from_slice(&buf[12..28]);
+            SEED.copy_from_slice(&buf[28..]);
+            let mut sk = Self::new_empty(I, SEED, q);
+            let tr = &mut sk.trav;
+            tr.leaf = u32::from_be_bytes(*<&[u8; 4]>::try_from(&trav[16..20]).unwrap());
+            if tr.leaf >= (1u32 << h) {
+                return None;
+            }
+            let mut j = 20;
+            for i in 0..nL {
+                tr.next_leaf[i] = u32::from_be_bytes(*<&[u8; 4]>::try_from(&trav[j..(j + 4)]).unwrap());
+                tr.stack_len[i] = trav[j + 4] as usize;
+                if tr.next_leaf[i] > (1u32 << h) || tr.stack_len[i] > h {
+                    return None;
+                }
+                j += 5;
+                for k in 0..h {
+                    tr.stack_level[i][k] = trav[j + k] as usize;
+                    if tr.stack_level[i][k] >= h {
+                        return None;
+                    }
+                }
+                j += h;
+                for node in tr.cur[i].iter_mut().chain(tr.next[i].iter_mut())
+                    .chain(tr.stack[i].iter_mut())
+                {
+                    node.copy_from_slice(&trav[j..(j + m)]);
+                    j += m;
+                }
+            }
+
+            // The root of the top subtree is the tree root. We recompute
+            // it from the current leaf and its authentication path.
+            sk.T1 = sk.trav.cur[nL - 1][1];
+            let x = sk.trav.leaf;
+            let mut node = sk.leaf_node(x);
+            for l in 0..h {
+                let i = l / SUB_H;
+                let d = SUB_H * (i + 1) - l;
+                let k = (x >> l) ^ 1;
+                let pos = (1usize << d) + (k as usize & ((1usize << d) - 1));
+                let sib = &sk.trav.cur[i][pos];
+                node = if (x >> l) & 1 == 0 {
+                    sk.intr_node(l + 1, x >> (l + 1), &node, sib)
+                } else {
+                    sk.intr_node(l + 1, x >> (l + 1), sib, &node)
+                };
+            }
+            if node != sk.T1 {
+                return None;
+            }
+            Some(sk)
+        }
+
+        /// Gets the number of leaves that have not been reserved yet
+        /// (i.e. the number of signatures that can still be generated).
+        pub fn remaining(&self) -> u32 {
+            (1u32 << h) - self.current_leaf
+        }
+
+        /// Reserves `count` leaves for signing.
+        ///
+        /// The leaf counter is advanced by `count`, and the encoded updated
+        /// private key is provided to `persist`, which must commit it to
+        /// stable storage. If `persist` returns `true`, then the reservation
+        /// is returned, and can be used to generate up to `count`
+        /// signatures. If `persist` returns `false`, then the private key
+        /// is left unchanged and `None` is returned. This function also
+        /// returns `None` (without calling `persist`) if `count` is zero or
+        /// greater than the number of remaining leaves.
+        ///
+        /// The committed encoding does not contain the Merkle tree; if it
+        /// is later reloaded with `decode()`, then the whole tree is
+        /// recomputed, which costs as much as key pair generation (2^h
+        /// LM-OTS public keys, i.e. about `2^h * p * 2^w` hash function
+        /// invocations; with height 20 and above, this takes minutes or
+        /// hours). Applications should therefore keep the `PrivateKey`
+        /// instance in memory and make several reservations with it, and
+        /// also save the traversal state (`encode_traversal()`) so that
+        /// reloading after a restart can use `decode_with_traversal()`.
+        /// Reserving more leaves at once reduces the number of commits,
+        /// but not the reload cost.
+        pub fn reserve<F>(&mut self, count: u32, persist: F)
+            -> Option<Reservation<'_>>
+            where F: FnOnce(&[u8; Self::ENC_LEN]) -> bool
+        {
+            if count == 0 || count > self.remaining() {
+                return None;
+            }
+            let next_leaf = self.current_leaf;
+            let end_leaf = next_leaf + count;
+            let mut enc = self.encode();
+            enc[8..12].copy_from_slice(&end_leaf.to_be_bytes());
+            if !persist(&enc) {
                 return None;
             }
-            self.current_leaf = q + 1;
-            let ots_sig = self.ots_sign(rng, q, msg);
+            self.current_leaf = end_leaf;
+            Some(Reservation { sk: self, next_leaf, end_leaf })
+        }
+
+        fn sign_leaf(&mut self, C: &[u8; n], q: u32, msg: &[u8])
+            -> [u8; lms_siglen]
+        {
+            // Leaves are normally used in order. If some leaves were
+            // skipped (unused reservation), then we advance the traversal
+            // state, unless recomputing the whole tree is cheaper.
+            let lq = self.trav.leaf;
+            if q < lq || (((q - lq) as u64) * ((nL - 1) as u64)) >= (1u64 << h) {
+                self.init_traversal(q);
+            }
+            while self.trav.leaf < q {
+                self.advance();
+            }
+            let ots_sig = self.ots_sign(C, q, msg);
             let mut sig = [0u8; 4 + ots_siglen + 4 + h * m];
             sig[0..4].copy_from_slice(&q.to_be_bytes());
             sig[4..(ots_siglen + 4)].copy_from_slice(&ots_sig);
             sig[(ots_siglen + 4)..(ots_siglen + 8)].copy_from_slice(&key_type.to_be_bytes());
-            let mut r = q + (1u32 << h);
-            for i in 0..h {
-                let k = if (r & 1) == 0 { r + 1 } else { r - 1 };
-                let j = 4 + ots_siglen + 4 + i * m;
-                sig[j..(j + m)].copy_from_slice(&self.T[k as usize]);
-                r = r >> 1;
+            for l in 0..h {
+                let i = l / SUB_H;
+                let d = SUB_H * (i + 1) - l;
+                let k = (q >> l) ^ 1;
+                let pos = (1usize << d) + (k as usize & ((1usize << d) - 1));
+                let j = 4 + ots_siglen + 4 + l * m;
+                sig[j..(j + m)].copy_from_slice(&self.trav.cur[i][pos]);
             }
-            Some(sig)
+            sig
+        }
+    }
+
+    impl<'a> Reservation<'a> {
+
+        /// Gets the number of reserved leaves that have not been used yet.
+        pub fn remaining(&self) -> u32 {
+            self.end_leaf - self.next_leaf
+        }
+
+        /// Signs a message with the next reserved leaf.
+        ///
+        /// This function returns `None` if all reserved leaves have
+        /// already been used.
+        pub fn sign<T: CryptoRng + RngCore>(&mut self, rng: &mut T,
+            msg: &[u8]) -> Option<[u8; lms_siglen]>
+        {
+            if self.next_leaf >= self.end_leaf {
+                return None;
+            }
+            let q = self.next_leaf;
+            self.next_leaf = q + 1;
+            let mut C = [0u8; n];
+            rng.fill_bytes(&mut C);
+            Some(self.sk.sign_leaf(&C, q, msg))
         }
     }
 
     impl PublicKey {
 
+        /// Encoded public key length (in bytes).
+        pub const ENC_LEN: usize = 4 + 4 + 16 + m;
+
+        /// Encodes this public key into bytes (RFC 8554 format).
+        pub fn encode(&self) -> [u8; Self::ENC_LEN] {
+            let mut buf = [0u8; Self::ENC_LEN];
+            buf[0..4].copy_from_slice(&key_type.to_be_bytes());
+            buf[4..8].copy_from_slice(&ots_type.to_be_bytes());
+            buf[8..24].copy_from_slice(&self.I);
+            buf[24..].copy_from_slice(&self.T1);
+            buf
+        }
+
+        /// Decodes a public key from bytes (RFC 8554 format).
+        ///
+        /// This function returns `None` if the source does not have the
+        /// proper length, or if the encoded LMS and LM-OTS types do not
+        /// match this parameter set.
+        pub fn decode(buf: &[u8]) -> Option<Self> {
+            if buf.len() != Self::ENC_LEN {
+                return None;
+            }
+            let kt = u32::from_be_bytes(*<&[u8; 4]>::try_from(&buf[0..4]).unwrap());
+            let ot = u32::from_be_bytes(*<&[u8; 4]>::try_from(&buf[4..8]).unwrap());
+            if kt != key_type || ot != ots_type {
+                return None;
+            }
+            let mut I = [0u8; 16];
+            let mut T1 = [0u8; m];
+            I.copy_from_slice(&buf[8..24]);
+            T1.copy_from_slice(&buf[24..]);
+            Some(Self { I, T1 })
+        }
+
         fn ots_verify(self, q: u32, sig: &[u8], msg: &[u8]) -> Option<[u8; n]> {
             if sig.len() != ots_siglen {
                 return None;
@@ -239,6 +872,7 @@ macro_rules! define_lms_core { () => {
             Some(Hnx(&self.I, &eq, &D_PBLC, &z))
         }
 
+        /// Verifies a signature on a message.
         pub fn verify(self, sig: &[u8], msg: &[u8]) -> bool {
             if sig.len() != lms_siglen {
                 return false;
@@ -274,14 +908,159 @@ macro_rules! define_lms_core { () => {
         }
     }
 
+    /// The LMS and LM-OTS types of this parameter set.
+    pub const TYPE: super::LmsType = super::LmsType {
+        lms: key_type,
+        lmots: ots_type,
+    };
+
+    #[cfg(feature = "alloc")]
+    pub(super) static OPS: super::LmsOps = super::LmsOps {
+        typ: TYPE,
+        height: h as u32,
+        pk_len: PublicKey::ENC_LEN,
+        sig_len: lms_siglen,
+        verify: verify_encoded,
+        new_level,
+    };
+
+    #[cfg(feature = "alloc")]
+    fn verify_encoded(pk: &[u8], sig: &[u8], msg: &[u8]) -> bool {
+        match PublicKey::decode(pk) {
+            Some(pk) => pk.verify(sig, msg),
+            None => false,
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    fn new_level(I: &[u8; 16], SEED: &[u8; 32])
+        -> crate::Box<dyn super::HssLevel>
+    {
+        let mut sk = crate::Box::new(PrivateKey::new_empty(
+            *I, *<&[u8; m]>::try_from(&SEED[..m]).unwrap(), 0));
+        sk.init_traversal(0);
+        sk
+    }
+
+    #[cfg(feature = "alloc")]
+    impl super::HssLevel for PrivateKey {
+
+        fn public_encoded(&self) -> crate::Vec<u8> {
+            self.compute_public().encode().to_vec()
+        }
+
+        fn sign_encoded(&mut self, q: u32, rnd: Option<&[u8; 32]>, msg: &[u8])
+            -> crate::Vec<u8>
+        {
+            let C = match rnd {
+                Some(r) => *<&[u8; n]>::try_from(&r[..n]).unwrap(),
+                None => Hn(&self.I, &q.to_be_bytes(), &[0xFF, 0xFD],
+                    &[0xFF], &self.SEED),
+            };
+            self.sign_leaf(&C, q, msg).to_vec()
+        }
+
+        fn child(&self, q: u32) -> ([u8; 16], [u8; 32]) {
+            super::derive_child(&self.I, q, &self.SEED)
+        }
+    }
+
 } } // end of macro define_lms_core
 
+// Hash functions for the SHA-256 parameter sets (SHA-256 and SHA-256/192).
+macro_rules! define_lms_sha256 { () => {
+
+    use sha2::{Sha256, Digest};
+
+    fn Hn(m1: &[u8], m2: &[u8], m3: &[u8], m4: &[u8], m5: &[u8]) -> [u8; n] {
+        let mut sh = Sha256::new();
+        sh.update(m1);
+        sh.update(m2);
+        sh.update(m3);
+        sh.update(m4);
+        sh.update(m5);
+        let mut r = [0u8; n];
+        r[..].copy_from_slice(&sh.finalize()[..n]);
+        r
+    }
+
+    fn Hnx(m1: &[u8], m2: &[u8], m3: &[u8], mm: &[[u8; n]; p]) -> [u8; n] {
+        let mut sh = Sha256::new();
+        sh.update(m1);
+        sh.update(m2);
+        sh.update(m3);
+        for i in 0..p {
+            sh.update(&mm[i]);
+        }
+        let mut r = [0u8; n];
+        r[..].copy_from_slice(&sh.finalize()[..n]);
+        r
+    }
+
+    fn Hm(m1: &[u8], m2: &[u8], m3: &[u8], m4: &[u8], m5: &[u8]) -> [u8; m] {
+        let mut sh = Sha256::new();
+        sh.update(m1);
+        sh.update(m2);
+        sh.update(m3);
+        sh.update(m4);
+        sh.update(m5);
+        let mut r = [0u8; m];
+        r[..].copy_from_slice(&sh.finalize()[..m]);
+        r
+    }
+
+} } // end of macro define_lms_sha256
+
+// Hash functions for the SHAKE256 parameter sets.
+macro_rules! define_lms_shake { () => {
+
+    use sha3::{Shake256, digest::{Update, ExtendableOutput, XofReader}};
+
+    fn Hn(m1: &[u8], m2: &[u8], m3: &[u8], m4: &[u8], m5: &[u8]) -> [u8; n] {
+        let mut sh = Shake256::default();
+        sh.update(m1);
+        sh.update(m2);
+        sh.update(m3);
+        sh.update(m4);
+        sh.update(m5);
+        let mut r = [0u8; n];
+        sh.finalize_xof().read(&mut r);
+        r
+    }
+
+    fn Hnx(m1: &[u8], m2: &[u8], m3: &[u8], mm: &[[u8; n]; p]) -> [u8; n] {
+        let mut sh = Shake256::default();
+        sh.update(m1);
+        sh.update(m2);
+        sh.update(m3);
+        for i in 0..p {
+            sh.update(&mm[i]);
+        }
+        let mut r = [0u8; n];
+        sh.finalize_xof().read(&mut r);
+        r
+    }
+
+    fn Hm(m1: &[u8], m2: &[u8], m3: &[u8], m4: &[u8], m5: &[u8]) -> [u8; m] {
+        let mut sh = Shake256::default();
+        sh.update(m1);
+        sh.update(m2);
+        sh.update(m3);
+        sh.update(m4);
+        sh.update(m5);
+        let mut r = [0u8; m];
+        sh.finalize_xof().read(&mut r);
+        r
+    }
+
+} } // end of macro define_lms_shake
+
 // ========================================================================
 
 #[cfg(test)]
 macro_rules! define_lms_tests { () => {
 
-    use super::{PrivateKey};
+    use super::{PrivateKey, PublicKey, h, m};
     use crate::{CryptoRng, RngCore, RngError};
     use core::num::NonZeroU32;
 
@@ -344,69 +1123,169 @@ macro_rules! define_lms_tests { () => {
         assert!(&pk.T1[..] == T1ref);
 
         let msg = hex::decode(KAT_MSG).unwrap();
-        let sig = sk.sign(&mut rng, &msg).unwrap();
+        let sig = sk.reserve(1, |_| true).unwrap().sign(&mut rng, &msg).unwrap();
         let sigref = hex::decode(KAT_SIG).unwrap();
         assert!(sig[..] == sigref);
 
         assert!(pk.verify(&sig, &msg) == true);
         assert!(pk.verify(&sig, &msg[1..]) == false);
-    }
 
-} } // end of macro define_lms_tests
+        // The same signature, as a single-level HSS signature.
+        #[cfg(feature = "alloc")]
+        {
+            use crate::lms::hss::hss_verify;
+            let mut hpk = 1u32.to_be_bytes().to_vec();
+            hpk.extend_from_slice(&pk.encode());
+            let mut hsig = 0u32.to_be_bytes().to_vec();
+            hsig.extend_from_slice(&sig);
+            assert!(hss_verify(&hpk, &hsig, &msg));
+            assert!(!hss_verify(&hpk, &hsig, &msg[1..]));
+            assert!(!hss_verify(&hpk, &hsig[..(hsig.len() - 1)], &msg));
+            hsig[3] = 1;
+            assert!(!hss_verify(&hpk, &hsig, &msg));
+            hpk[3] = 2;
+            assert!(!hss_verify(&hpk, &hsig, &msg));
+        }
+    }
 
-// ========================================================================
+    #[test]
+    fn reserve_encode() {
+        let rng_tape = [0x5Au8; 16 + m + (m << h)];
+        let mut rng = FRNG::from_tape(&rng_tape);
+        let mut sk = PrivateKey::generate(&mut rng);
+        let pk = sk.compute_public();
+        let epk = pk.encode();
+        let pk2 = PublicKey::decode(&epk).unwrap();
+        assert!(pk2.encode() == epk);
+        assert!(PublicKey::decode(&epk[1..]).is_none());
+        let mut bad = epk;
+        bad[3] ^= 1;
+        assert!(PublicKey::decode(&bad).is_none());
 
-/// LMS_SHA256_M32_H5 with LMOTS_SHA256_N32_W8
-pub mod LMS_SHA256_M32_H5_SHA256_N32_W8 {
+        // A failed commit leaves the key unchanged.
+        let total = sk.remaining();
+        assert!(sk.reserve(2, |_| false).is_none());
+        assert!(sk.remaining() == total);
+        assert!(sk.reserve(0, |_| true).is_none());
+        assert!(sk.reserve(total + 1, |_| true).is_none());
 
-    use sha2::{Sha256, Digest};
+        // The committed state already accounts for the reserved leaves.
+        let mut stored = [0u8; PrivateKey::ENC_LEN];
+        let msg = b"sample";
+        {
+            let mut res = sk.reserve(3, |e| { stored = *e; true }).unwrap();
+            assert!(res.remaining() == 3);
+            for _ in 0..3 {
+                let sig = res.sign(&mut rng, msg).unwrap();
+                assert!(pk2.verify(&sig, msg));
+                assert!(!pk2.verify(&sig, b"other"));
+            }
+            assert!(res.sign(&mut rng, msg).is_none());
+        }
+        assert!(sk.remaining() == total - 3);
+        assert!(stored == sk.encode());
 
-    define_lms_core!{}
+        // Decoding the stored state yields the same key, which resumes
+        // after the reserved leaves.
+        let mut sk2 = PrivateKey::decode(&stored).unwrap();
+        assert!(sk2.compute_public().encode() == epk);
+        assert!(sk2.remaining() == total - 3);
+        let sig = sk2.reserve(1, |_| true).unwrap().sign(&mut rng, msg).unwrap();
+        assert!(sig[0..4] == 3u32.to_be_bytes());
+        assert!(pk.verify(&sig, msg));
+        assert!(PrivateKey::decode(&stored[1..]).is_none());
 
-    const n: usize = 32;
-    const m: usize = 32;
-    const w: usize = 8;
-    const h: usize = 5;
-    const key_type: u32 = 0x00000005;
-    const ots_type: u32 = 0x00000004;
+        // Use all remaining leaves.
+        let n = sk2.remaining();
+        let mut res = sk2.reserve(n, |_| true).unwrap();
+        for _ in 0..n {
+            assert!(pk.verify(&res.sign(&mut rng, msg).unwrap(), msg));
+        }
+        assert!(sk2.remaining() == 0);
+        assert!(sk2.reserve(1, |_| true).is_none());
+    }
 
-    fn Hn(m1: &[u8], m2: &[u8], m3: &[u8], m4: &[u8], m5: &[u8]) -> [u8; n] {
-        let mut sh = Sha256::new();
-        sh.update(m1);
-        sh.update(m2);
-        sh.update(m3);
-        sh.update(m4);
-        sh.update(m5);
-        let mut r = [0u8; n];
-        r[..].copy_from_slice(&sh.finalize());
-        r
-    }
+    #[test]
+    fn decode_traversal() {
+        let rng_tape = [0xA5u8; 4096];
+        let mut rng = FRNG::from_tape(&rng_tape);
+        let mut sk = PrivateKey::generate(&mut rng);
+        let pk = sk.compute_public();
+        let epk = pk.encode();
+        let msg = b"sample";
+        let trav0 = sk.encode_traversal();
+        let mut stored = [0u8; PrivateKey::ENC_LEN];
+        {
+            let mut res = sk.reserve(7, |e| { stored = *e; true }).unwrap();
+            for _ in 0..7 {
+                assert!(pk.verify(&res.sign(&mut rng, msg).unwrap(), msg));
+            }
+        }
+        let trav = sk.encode_traversal();
 
-    fn Hnx(m1: &[u8], m2: &[u8], m3: &[u8], mm: &[[u8; n]; p]) -> [u8; n] {
-        let mut sh = Sha256::new();
-        sh.update(m1);
-        sh.update(m2);
-        sh.update(m3);
-        for i in 0..p {
-            sh.update(&mm[i]);
+        // Reference signatures, from a key decoded without the saved
+        // traversal state.
+        let c_tape = [0x3Cu8; 256];
+        let mut sk2 = PrivateKey::decode(&stored).unwrap();
+        let mut rng2 = FRNG::from_tape(&c_tape);
+        let mut res = sk2.reserve(2, |_| true).unwrap();
+        let sig1 = res.sign(&mut rng2, msg).unwrap();
+        let sig2 = res.sign(&mut rng2, msg).unwrap();
+        assert!(pk.verify(&sig1, msg) && pk.verify(&sig2, msg));
+
+        // Both the current and an outdated traversal state can be used.
+        for t in [&trav[..], &trav0[..]].iter() {
+            let mut sk3 = PrivateKey::decode_with_traversal(&stored, t).unwrap();
+            assert!(sk3.compute_public().encode() == epk);
+            assert!(sk3.remaining() == sk.remaining());
+            assert!(sk3.encode() == stored);
+            let mut rng3 = FRNG::from_tape(&c_tape);
+            let mut res = sk3.reserve(2, |_| true).unwrap();
+            assert!(res.sign(&mut rng3, msg).unwrap()[..] == sig1[..]);
+            assert!(res.sign(&mut rng3, msg).unwrap()[..] == sig2[..]);
         }
-        let mut r = [0u8; n];
-        r[..].copy_from_slice(&sh.finalize());
-        r
-    }
 
-    fn Hm(m1: &[u8], m2: &[u8], m3: &[u8], m4: &[u8], m5: &[u8]) -> [u8; m] {
-        let mut sh = Sha256::new();
-        sh.update(m1);
-        sh.update(m2);
-        sh.update(m3);
-        sh.update(m4);
-        sh.update(m5);
-        let mut r = [0u8; m];
-        r[..].copy_from_slice(&sh.finalize());
-        r
+        // Mismatched or damaged traversal states are rejected.
+        assert!(PrivateKey::decode_with_traversal(&stored, &trav[1..]).is_none());
+        let mut bad = trav;
+        bad[0] ^= 1;
+        assert!(PrivateKey::decode_with_traversal(&stored, &bad).is_none());
+        let mut bad = trav;
+        bad[16..20].copy_from_slice(&(1u32 << h).to_be_bytes());
+        assert!(PrivateKey::decode_with_traversal(&stored, &bad).is_none());
+        // First node of the authentication path of the last used leaf
+        // (leaf 6).
+        let mut bad = trav;
+        bad[20 + 5 + h + (32 + 7) * m] ^= 1;
+        assert!(PrivateKey::decode_with_traversal(&stored, &bad).is_none());
+        // Tree root.
+        let mut bad = trav;
+        bad[PrivateKey::TRAV_LEN - (2 * 64 + h - 1) * m] ^= 1;
+        assert!(PrivateKey::decode_with_traversal(&stored, &bad).is_none());
+        let other = [0x5Au8; 64];
+        let sk4 = PrivateKey::generate(&mut FRNG::from_tape(&other));
+        assert!(PrivateKey::decode_with_traversal(&stored,
+            &sk4.encode_traversal()).is_none());
     }
 
+} } // end of macro define_lms_tests
+
+// ========================================================================
+
+/// LMS_SHA256_M32_H5 with LMOTS_SHA256_N32_W8
+pub mod LMS_SHA256_M32_H5_SHA256_N32_W8 {
+
+    define_lms_core!{}
+
+    const n: usize = 32;
+    const m: usize = 32;
+    const w: usize = 8;
+    const h: usize = 5;
+    const key_type: u32 = 0x00000005;
+    const ots_type: u32 = 0x00000004;
+
+    define_lms_sha256!{}
+
     #[cfg(test)]
     mod tests {
 
@@ -510,8 +1389,6 @@ pub mod LMS_SHA256_M32_H5_SHA256_N32_W8 {
 /// LMS_SHA256_M24_H5 with LMOTS_SHA256_N24_W8
 pub mod LMS_SHA256_M24_H5_SHA256_N24_W8 {
 
-    use sha2::{Sha256, Digest};
-
     define_lms_core!{}
 
     const n: usize = 24;
@@ -521,42 +1398,7 @@ pub mod LMS_SHA256_M24_H5_SHA256_N24_W8 {
     const key_type: u32 = 0x0000000a;
     const ots_type: u32 = 0x00000008;
 
-    fn Hn(m1: &[u8], m2: &[u8], m3: &[u8], m4: &[u8], m5: &[u8]) -> [u8; n] {
-        let mut sh = Sha256::new();
-        sh.update(m1);
-        sh.update(m2);
-        sh.update(m3);
-        sh.update(m4);
-        sh.update(m5);
-        let mut r = [0u8; n];
-        r[..].copy_from_slice(&sh.finalize()[..24]);
-        r
-    }
-
-    fn Hnx(m1: &[u8], m2: &[u8], m3: &[u8], mm: &[[u8; n]; p]) -> [u8; n] {
-        let mut sh = Sha256::new();
-        sh.update(m1);
-        sh.update(m2);
-        sh.update(m3);
-        for i in 0..p {
-            sh.update(&mm[i]);
-        }
-        let mut r = [0u8; n];
-        r[..].copy_from_slice(&sh.finalize()[..24]);
-        r
-    }
-
-    fn Hm(m1: &[u8], m2: &[u8], m3: &[u8], m4: &[u8], m5: &[u8]) -> [u8; m] {
-        let mut sh = Sha256::new();
-        sh.update(m1);
-        sh.update(m2);
-        sh.update(m3);
-        sh.update(m4);
-        sh.update(m5);
-        let mut r = [0u8; m];
-        r[..].copy_from_slice(&sh.finalize()[..24]);
-        r
-    }
+    define_lms_sha256!{}
 
     #[cfg(test)]
     mod tests {
@@ -645,8 +1487,6 @@ pub mod LMS_SHA256_M24_H5_SHA256_N24_W8 {
 /// LMS_SHAKE_M24_H5 with LMOTS_SHAKE_N24_W8
 pub mod LMS_SHAKE_M24_H5_SHAKE_N24_W8 {
 
-    use sha3::{Shake256, digest::{Update, ExtendableOutput, XofReader}};
-
     define_lms_core!{}
 
     const n: usize = 24;
@@ -656,42 +1496,7 @@ pub mod LMS_SHAKE_M24_H5_SHAKE_N24_W8 {
     const key_type: u32 = 0x00000014;
     const ots_type: u32 = 0x00000010;
 
-    fn Hn(m1: &[u8], m2: &[u8], m3: &[u8], m4: &[u8], m5: &[u8]) -> [u8; n] {
-        let mut sh = Shake256::default();
-        sh.update(m1);
-        sh.update(m2);
-        sh.update(m3);
-        sh.update(m4);
-        sh.update(m5);
-        let mut r = [0u8; n];
-        sh.finalize_xof().read(&mut r);
-        r
-    }
-
-    fn Hnx(m1: &[u8], m2: &[u8], m3: &[u8], mm: &[[u8; n]; p]) -> [u8; n] {
-        let mut sh = Shake256::default();
-        sh.update(m1);
-        sh.update(m2);
-        sh.update(m3);
-        for i in 0..p {
-            sh.update(&mm[i]);
-        }
-        let mut r = [0u8; n];
-        sh.finalize_xof().read(&mut r);
-        r
-    }
-
-    fn Hm(m1: &[u8], m2: &[u8], m3: &[u8], m4: &[u8], m5: &[u8]) -> [u8; m] {
-        let mut sh = Shake256::default();
-        sh.update(m1);
-        sh.update(m2);
-        sh.update(m3);
-        sh.update(m4);
-        sh.update(m5);
-        let mut r = [0u8; m];
-        sh.finalize_xof().read(&mut r);
-        r
-    }
+    define_lms_shake!{}
 
     #[cfg(test)]
     mod tests {
@@ -780,8 +1585,6 @@ pub mod LMS_SHAKE_M24_H5_SHAKE_N24_W8 {
 /// LMS_SHAKE_M32_H5 with LMOTS_SHAKE_N32_W8
 pub mod LMS_SHAKE_M32_H5_SHAKE_N32_W8 {
 
-    use sha3::{Shake256, digest::{Update, ExtendableOutput, XofReader}};
-
     define_lms_core!{}
 
     const n: usize = 32;
@@ -791,42 +1594,7 @@ pub mod LMS_SHAKE_M32_H5_SHAKE_N32_W8 {
     const key_type: u32 = 0x0000000f;
     const ots_type: u32 = 0x0000000c;
 
-    fn Hn(m1: &[u8], m2: &[u8], m3: &[u8], m4: &[u8], m5: &[u8]) -> [u8; n] {
-        let mut sh = Shake256::default();
-        sh.update(m1);
-        sh.update(m2);
-        sh.update(m3);
-        sh.update(m4);
-        sh.update(m5);
-        let mut r = [0u8; n];
-        sh.finalize_xof().read(&mut r);
-        r
-    }
-
-    fn Hnx(m1: &[u8], m2: &[u8], m3: &[u8], mm: &[[u8; n]; p]) -> [u8; n] {
-        let mut sh = Shake256::default();
-        sh.update(m1);
-        sh.update(m2);
-        sh.update(m3);
-        for i in 0..p {
-            sh.update(&mm[i]);
-        }
-        let mut r = [0u8; n];
-        sh.finalize_xof().read(&mut r);
-        r
-    }
-
-    fn Hm(m1: &[u8], m2: &[u8], m3: &[u8], m4: &[u8], m5: &[u8]) -> [u8; m] {
-        let mut sh = Shake256::default();
-        sh.update(m1);
-        sh.update(m2);
-        sh.update(m3);
-        sh.update(m4);
-        sh.update(m5);
-        let mut r = [0u8; m];
-        sh.finalize_xof().read(&mut r);
-        r
-    }
+    define_lms_shake!{}
 
     #[cfg(test)]
     mod tests {
@@ -927,3 +1695,2599 @@ pub mod LMS_SHAKE_M32_H5_SHAKE_N32_W8 {
         define_lms_tests!{}
     }
 }
+
+/// LMS_SHA256_M32_H5 with LMOTS_SHA256_N32_W1
+pub mod LMS_SHA256_M32_H5_SHA256_N32_W1 {
+
+    define_lms_core!{}
+
+    const n: usize = 32;
+    const m: usize = 32;
+    const w: usize = 1;
+    const h: usize = 5;
+    const key_type: u32 = 0x00000005;
+    const ots_type: u32 = 0x00000001;
+
+    define_lms_sha256!{}
+
+    #[cfg(test)]
+    mod tests {
+
+        // Test vector generated with the reference implementation
+        // (https://github.com/cisco/hash-sigs), from the Botan test suite
+        // (LMS_SHA256_N32_H5 with LMOTS_SHA256_N32_W1). The randomizer C is derived from
+        // the seed, as in the reference implementation.
+
+        static KAT_RNG_TAPE: &str = "66320db73158a35a255d051758e95ed467c6697351ff4aec29cdbaabf2fbe3467cc254f81be8e78d765a2e63339fc99a8ebea1c5c841a4fb1357d69c5f556782a1d729a2f6edbf41febc33536290b5d9";
+        static KAT_PK_I: &str = "66320db73158a35a255d051758e95ed4";
+        static KAT_PK_T1: &str = "a5c8d702b44ff9b23bcae0963e9f757cb974bfaf29bbf07fa68191b53a85c264";
+        static KAT_MSG: &str = "deadbeef";
+        static KAT_LEAFNUM: u32 = 5u32;
+        static KAT_SIG: &str =
+            "00000005\
+            00000001\
+            8ebea1c5c841a4fb1357d69c5f556782\
+            a1d729a2f6edbf41febc33536290b5d9\
+            1ab3cb03e21bd627cb4277691af859ce\
+            d64086e6df30db7c3fbc5b888c1fc981\
+            da39b8e0acabbba3876336d959e062f0\
+            352e2ff0317d739ae9573456f7bcdee2\
+            a70d0aa92327069f598ec33aeef47748\
+            93d9ac86f5123ee16f77fc52c9abf50e\
+            871479a146240f38cb73180d258d5c06\
+            127ecca4b8f7cc73217efa75d4810b2a\
+            eee58bde402cfb060b7183c59beea021\
+            bbf6b435c067e508cfcd82e6a5f60647\
+            dcf4c58ee6ebd0a5bc2b3d120299b0a3\
+            9ddc1fdad9b347ecead7369abb2939d7\
+            3092b0af6d6e5500ae2814ca5033b3ef\
+            138f961887ecbba80883490eb4f810de\
+            ccca91fd918741a1eaf5a0846bab6075\
+            2edb96c9c089c60aa21deef38fe0696d\
+            d34cadb72b26654cd72ac5a8edebbb2b\
+            7144d363508c5cfbb99eeac097eb149f\
+            a926d2519233551ab784808c3914c5db\
+            ff0ca4826ee0471f075a58c2a07cfb3c\
+            71745a6344be986c5dfde363fc51142d\
+            7dd56da76cbbdc65379b6c5ae5618ee4\
+            d08d030550273ee5a85067afda1ef00b\
+            37cfb2df0c0b9a34b4955c7eed4cf34c\
+            d20d8d903d36084b0534ae2da329762c\
+            dbe96eae910d54de5679a0dae79dde45\
+            7344e67f600acdf33ebf6bf2c5ffbd40\
+            1b6ea874394762a404a8399a340c2e94\
+            a87bb82c53d062cc3d7925d115c6c73a\
+            f3f4ab934be8041be03faad1ace91396\
+            2e2b87c5333e48fcdff8ca47ff491a3a\
+            cfc289762b46b7ca71e854029aff0d9d\
+            205ea055d695626a404a1457305b1229\
+            a53b9b7ba5ee77fad30be1e7cb8b0f29\
+            1ca26aaea2c1c8d925f00c78a41a718b\
+            270f82f7dfdbd7f5a73a661ba2fe66f1\
+            fe7299080d0d8b4a8ca350a887e4be89\
+            8e18118bffd6cdfbb44398ac8f58a242\
+            a3d5d413c37a4a65aa79f4de37fd5c98\
+            196b9f75588ef011708c9e8a4eac36ea\
+            81c26245c58f67a24af91c5745faecba\
+            da4094b5ebaaf7852cec0f17e7d713c0\
+            0dc174f0606301abd3a2ccb074aaa4af\
+            9717cbbcffb57828810f76307dfe0654\
+            5711747e3b581357c7b7d38df00166a5\
+            8a38d93e7e794f8c030d98feab77ddfa\
+            2df9d1e5c0150d5b45ebdec47d840881\
+            7dc569d9178e814158736b354be28987\
+            bef2e56903b587634e9a727174201df5\
+            4be4e2c9fda59740871f8f5fc1cd9520\
+            472bba31ef15d92cd48e22d2c503bb93\
+            eb663bb4a97c5a03da5656d2b1b4bca9\
+            53d77c6485ea7bdfd0eb00b964cdaba7\
+            4ced013478768e0eded28932c37f3a11\
+            7dab19e310189f1547e8c2adaabe9a71\
+            49ea231447dcfa04181884a9115bfd1a\
+            0cce2def289b5c3dbe985c672461305b\
+            13d0329812a40b248fff79362a2266f9\
+            7342ca775a4442bf5fbfd47acf975e40\
+            3cfa1d1082f9934a5fea3a22379ab629\
+            8d8f203e98d497a2eeb4d05c9a1f7ea4\
+            bcf4dd07ce3230492b556fa0615709ac\
+            6c9effd693b05542e17cbd5ba904e465\
+            d3d369a115deb83fba99b63c2d261e2a\
+            4030da81a336c2996160d6851e2b9502\
+            949379be16dcdfae937fa9711f5f6b3f\
+            3d455d336f41d50312102e9ad77242b5\
+            6154755c0d78c0cd916defbbc224a463\
+            9502b151935f54d3d72357bdff1e7928\
+            d837b3c1dcd9b43190944d72da7e3971\
+            dcde1367bb0f4693bfa9bdd9190a9f60\
+            f3b009db299a4cc99fb63a4e6c572595\
+            2211d91c3ef769a74d45b48c4a7895db\
+            1177bcd8b1d4438e406c83bc744d27d6\
+            f4e124d9d93a181caf2ad6a56923fb0e\
+            01f91d51555271c77bc9b70ec55dc5f2\
+            33a5b2584b89ffab7a275427dda336f1\
+            6a0750af4ca09cf0bb0e50b2e4c7f77f\
+            de0c2e01dfa0818229507d044796e050\
+            05e66ebdc2d50e0d1fd809a1e4c86400\
+            bc02fe504cf536f3a2d4acb09ea02c2c\
+            e3b01cb0a46b9bf33041bc0fb8d2f9bc\
+            07964d3f646f48734eeb3a78de622f4c\
+            e10d64b2802ca46dedac9ab253e31d8d\
+            417966bb344d8f3fbf96329e0d251e8f\
+            8330a6a388ceb38ce45de83b6bfd0011\
+            f3f894095e16e9cb206b7ebb2ddef07f\
+            2894b451b4733552aa5d22aa3472f063\
+            9897850cfe81c6d5eed49225ac3ebf3a\
+            fdf4ddaf92d499310de1705f6ef9b010\
+            7bcce2644e4b3a256af8854a8e2f74d5\
+            d55604a2342db0fdfb64aa53ffb2341d\
+            df9157c349a9fe3f6b9b0eb9df84d361\
+            c2d3ceb2bab47e6cb249135d1f238ab6\
+            24d61cd0510874076e9afca5f648fb00\
+            d8f6faca1c304ab06ee52baaaac85ef9\
+            b2860db4773c538ab74dd85b49101b45\
+            1dd0691f18501edac834acc07fa17c24\
+            2dc5472d272d156ca18925c37de3bbd5\
+            a62fb261082c37f0345597aad08bdfa4\
+            e36069492e738f4e8519bc816acd10db\
+            c8ad27d0146ad1025c3dcd707e28559f\
+            400322be9042ddcaf2d3c438f933039d\
+            59285ca9e60638b0d69f9765d6b24b01\
+            5395cea5256a39a65295b8e2bbc85f21\
+            216c70929e49f16d988b590c8b85ea93\
+            d499f5e99cb6959e29df5f37425cac22\
+            4c450ec5a8e57969fa3c9a6a75aa7675\
+            48f3d7318433a93be045ad5a837d5be8\
+            a2a02e5409575643b0d4380c32e9c36a\
+            52bd208239a48cc2c97ee808f5607d68\
+            e9b496b3be0f2a8ce7f7db9ed7ee2615\
+            123060f13eec82f45063f6f854e9ed49\
+            e8f98d642d2735f68f541bf9f338e633\
+            549605f71d0b8ef3416df6d2598b1862\
+            6dfda9af24b113c4475a993021d8dcb0\
+            6e272bfe8298a50169f6532b6d5330a2\
+            022d57e17bca25f0e95c9a25fea8544c\
+            7b09a1d83c65a78a1e0c9ac6c3e70a6a\
+            387ed5612a58c828674fbaae4cc37ef9\
+            2e6f031428761e0faaa9789e44aa4a4b\
+            852a56f347cd5bfea40762b4c78595a5\
+            f4e04eed428d2da9046a3e073070c72c\
+            2a78bf389aa22d59746c4cd0662df13f\
+            616f0e94de5c35e91ef701f25d6ce991\
+            de13b3cea96e2d4cf8cdd1c5c93d28f3\
+            80f8aac94e8b736c2e4ba47a822e24d5\
+            7e4b5590a1f2948ec734336af7bf1c3d\
+            26d59970e5e556431208f393ea5458bb\
+            109b8d974f7fd67ac2bf9fc50ac482a7\
+            cc123fd04480d4037c5497275a7b83e7\
+            4c3781525ceb71a78813d62cc4310a1a\
+            5f2dacd1558663098e84779d6dddb284\
+            ad4ffba820d033b63e0d0e208761e798\
+            c2de34d8c4891d145a346d339c4444f9\
+            c97bd0caee09eb3edb2f6f1da6bc09f7\
+            ae946e84b5c0b82d8e080b79aa129242\
+            18b62d3d73f807623b36a799e381600a\
+            39f97e34eef9c20e7b77b74c6869e6f0\
+            0d20fd997479eed33bb87e97684239b4\
+            42c1280c3e15db8eeed9eea96dd82feb\
+            8d14e031f32d4f26cd67c388721084cd\
+            ea0f7fc2471b9bb2230af3981eeee119\
+            07d52be2455c1760ce22cc58e744fe0a\
+            cbb6cd3ae33195db0908aa015a0e320e\
+            e7c2a39793b6e1a6eaf8ba560d0ad5e4\
+            90650ba0f29ccf0eafecedaf1fd81797\
+            b4303da52da914437e05a3f09996dd96\
+            36453e423545e4cee9ac593b0d95f3b2\
+            d9993db080ffbc8afd1ebcc6f25b3a45\
+            7f523a73af389701a152840c8e31975b\
+            b9372717456c4a36bd1b23f553c16bd9\
+            f889a6eba3485fd228d633c8ac68e303\
+            16546d5db2f1668c33399227edf7f476\
+            fc13267d2b35ba3e2b7a4c0be67f3252\
+            c0845fcce0e020890c2d65e9c44bf56c\
+            2655a56057f91d68ed059018909a2fe1\
+            567565fbccaef57eb7230be63d5d6810\
+            e785e3f575d253839c6b397277abf01b\
+            a38f1933b7cb5e5a57326fbc95bff228\
+            75db3ecf04699d5051e7a21ce4421ae0\
+            95c339d5a27cbc4b7de9a0d66fd5629e\
+            c6d1a283f4539c50189c9b26ff0b2b69\
+            c377cb54b851fdf4b5a8152c3752c5c2\
+            4feead50882d6472623bf70a19f0f9e0\
+            7b6ff270a27f3a042de67f535b81aa50\
+            d25934639582eeac7e9722cf718b7371\
+            290ecc555ac0236738270364baec52b0\
+            fb803c7198039a2eb737396edeb4adf3\
+            43adb2d56a6165b320db869d170a1223\
+            7baccee7eb8c1946b8eb2f90103f5134\
+            a934e5eb1d074079b703197e3e9e67ae\
+            45e5de496d92f9f2ecb7447bca05cd28\
+            312c43997af64f67ad3947b3255f34a4\
+            1e3f69022fc9d48b85a123becd968f79\
+            c9dbe7794b59cfdbbe6c6be15b6769bc\
+            3daaa926fe085c4614ef7085104ef1bf\
+            8c499cbdac641ae32a99e0e402c0bc55\
+            8d774ba9d4decfe7c7011b5caefd60b6\
+            0a1cad1230d6905d1950ca497e5e93d7\
+            f0e0cd27942be097e439cf05d660fe3c\
+            12269f80895d10aaed8acba2c17e28c4\
+            f80f8b9afa8104a9836da95dff068afa\
+            70554ffa2b095e92fd111aa6f4605b64\
+            63590ee62080350b442aa4a29bd805ca\
+            692eeb985e772376c8ceef21614e184e\
+            9a8efb77dc101f99b1987acbcaf88f62\
+            2d0d77175a9e729c34e51714797285e5\
+            0c7fc3f0169021a64b8f156168d2b5d1\
+            7146d779736eaaa758a30f19b2f0f73f\
+            e67432beb8e8be23d4e1368850b1a9e3\
+            19d7426a3c17124e38648fb0cc31817c\
+            5d971b213e7fb7d5f5a7409c297c7c07\
+            e955afd80343fd20c3ceda0c06594a8c\
+            61631036e81eba730c0bae4e914eb59b\
+            6e6ba7399bb0ba81ac82fb714bd71d2c\
+            dd7c74793a9f7256f0ca5eb73169cbf5\
+            1d124cdeccba9256924d9355eabfe4c6\
+            37dae4cb2128c32f4055099d71b35cd4\
+            e8fa9b2a658ff9b8d658fba43eea07c7\
+            0194c511acd955d5fefcd383d36f00ca\
+            a8bf4901a986522dd678290f32116415\
+            5280dcfc9646e2f0f4a8b89388e944ab\
+            07179db4fdb415e613c11de97f216675\
+            aad8e841a92fd9f1fa797db74cc5f4b9\
+            88d8cc400ca6ba08b703df2bd4d48107\
+            0b53deed2ba72e752a2220812f4483b9\
+            90a4e7b1c9d66a0d7de456eb76b63fc3\
+            e30ba4f98682da340bf3c1eb5ca67060\
+            e281ba8456ca91298fc3f1ea536d9efb\
+            cf7371ade14a87486cd1fc5efff550f7\
+            b32bf97add9fe8f14a209b29b7593140\
+            6a0f1793ad270a12be5a648abef84511\
+            43fa4313f2dcaa9cd7b28b12d82b6812\
+            56f4d50e47322842a80a14928a6985e2\
+            4277bdfa8c28b985902290ca30f38dfe\
+            f10d17c4aa8eb57e638f8610ebf33a2f\
+            a2ff6a84d806b8ee5d1039a3d557ba61\
+            5c771086d2fad33ab983fa28e232a205\
+            111c5a9db5dc7ffb777b38f9a3c0eaf1\
+            4b259497a7604ea646fb0972675b3004\
+            aeb7ce00faa4359f4a01c49915dea9b0\
+            69ad29984b73bdacf0956431156017af\
+            a653cc1b288ccd11fa2ac936854dce15\
+            8574261998fcabcafbc9b1af0c2a1252\
+            68689b87ff62fb05368ef3b2e699d3c0\
+            99315eec2e650ebf97983792204a747d\
+            1e505577dd8ac38903fb05761dda5386\
+            ba96da0e4f70f62f054c5d4a600fa898\
+            b5336c4876072d16122c69bd9d24a506\
+            c5c92637f28ee0520ef9b510729ba687\
+            70d113d6d827c21d69b7f7d408ed814c\
+            173d543d27a6f57176f6f9e227644c70\
+            2845fa85dec028494dcc4e616fd73898\
+            466428f9f4b62ac2204ede6e4038b777\
+            549073b49efdb6f08cd70dd0b05fa352\
+            184265d99a97e8ecaf7500113b9e9f24\
+            b24784290caa577edc5dd0105afc3769\
+            3989951ce8f34b9e46f7081fbf7bbabf\
+            489df5446b3e02e3d52c45f10c76d1f9\
+            ed2ca37014baa5d6131001633ea6f87b\
+            68577557328a2fb1e7411e89d9164a83\
+            cef7d2c7b0d2663a23c2cc2f48953787\
+            db417999a7144e62c025cde9866823fe\
+            71dc968f3cdf0e2288a9af77632ae722\
+            887c267f550be5c3d8b7d7c9e23eadf0\
+            5a9b1fa01791a874559604283906cf57\
+            a36e6d032bb132af8037e90492204106\
+            a512d7e0738d5f1a62b935664ae40724\
+            c83d5b05e498421aa52f3e582f78c008\
+            c722ea8d1e948fdcb55ebf8507732c0f\
+            47de255dff0231172dfb9faa7e5a9620\
+            24fe1ad8a2e8ba2fd608d27ce8556cf0\
+            2c3f0b393cd4b7701ea63a9dea4c4bb1\
+            acac0c959ff1b7b95b97c0a074c663aa\
+            424b3f951f403586649f59c73567d43a\
+            b9e50533cfcfa3f4632bb347253c0f91\
+            1c3827298b075f09efc6d5f33cb0037d\
+            d68c8090e582065799bd2df091478910\
+            39e687daf478213a60546988c39ea724\
+            1623522a7051b24fb06291255cbb90b8\
+            209d97c61863db36dc73c94d0998ec09\
+            a15b5cd8586d22b10dfe0ec24a6872a4\
+            d371d900d9afe27e015f2e65476d8366\
+            1c14a040d487c93dda549e5796421d3e\
+            005393dfefa184c5385d7b7eddb27487\
+            0526b7e8a634cee339939bfb0e843add\
+            1f57d049dba5ba44a15583433bd61437\
+            62860097e11f53022fea764236b298b5\
+            63f02b5967c7e2e40511faa50901453b\
+            c70486db2b2d02ae34893cd364463a80\
+            71bd74d36c9f1496ed696ebcbbd7faa2\
+            c10bfdbf8c99ef4bed273178fb6b0a94\
+            6fe2e2b3fffb2a73597f2cdebe99561b\
+            1614b444317599b2ecdad78a567e3d7c\
+            514ee50cfa70773e2fb35b6e5544a919\
+            f3cc6370cc002c2df4f24590eca83214\
+            f79797fc5376dc5708dab8307898fc5d\
+            1034de0cd0bc05b37f7c0c3e20e370f7\
+            81979e8fde9c4b68fb0d5c517d69e7f2\
+            e551ae27583922ff1008820a8f7018c8\
+            b36f885d7791fe5e98e1a6c16220864b\
+            660601b5c548ec2f1c198718264a3ff5\
+            8d5b6c3dd7888cbefeecd3275a5547f0\
+            1ed70b41b038b6200c6fa2b65db70ec1\
+            dbc801222c9fca77fe5a9f2747d6eb79\
+            675d56942841236347ac5bbe09a48cc4\
+            39f0b088bedbb04916c60010d1415066\
+            9b3afbf9ac77a8f51b3da083e5656ff0\
+            8b50bc0f59a4ecd30f5a7729b7118972\
+            b09f3706386c1588ca64636797f30acd\
+            bef84dd9b3ce3dc31d78236c0268c875\
+            f23b7d544c8897689dde236152a98716\
+            6f3f995bf5149abe840880dd7fd962b8\
+            e7c8ea5a09dffad5dcc52895311ec139\
+            20b028fa6e2eff2597dd85f156511bf2\
+            65604fb50220ef0234d3ad0b1132f851\
+            9297331634ca95892ee05c537fd4e3ab\
+            e8528a6a4ea6f15de1cd01c5c87e245c\
+            c609807bd702473b70b4e3e386491f8b\
+            47177827dda7b089e8dbaefe7fd6c332\
+            ca1d3c138fb1e89010d769a53644e253\
+            098b7687fe9d5d24bcd4252fd4e68129\
+            8d00fd76ca135fb0b43ed30f4c3c7625\
+            5041927a945fc6ceacf6f3b260b8bb3e\
+            c04880c43b260777c96dd1f803133ae6\
+            9656ba20e9a603b1823dfee6cdccd078\
+            b40b850103b128aef28734650a22ad3b\
+            396b69b3155dba82cc3af876a0bfd727\
+            16bf3cd096a5b22de039101fda6423bd\
+            47361ab01ce0273e503e30dad1e95bc7\
+            648a950077c136e9fb9424bfaf6a10e5\
+            7e03d6136520345b48b13c1103fa3c00\
+            d66f412bf6927c3af70d4dbf277c77b5\
+            e348b53e7a4a239b94da1f4099b517de\
+            0310651b48488ff98f668b1085d722b4\
+            ca1a5cb97c97b6ce1f43b348a033cb4c\
+            892b161fbaf6d3b7c99be33b729c1cae\
+            8c9c04c6edf2ea76d5e2c5728ab6d99e\
+            491a58db42270a0c244c827d9885c5ca\
+            964f69e5e4fa982598c4575a51516856\
+            0e161f0c753dc48cf0cd95ce228b5ee8\
+            be0f7361a6588cb8b63dbe946965fec9\
+            3763e6ce6a9a292883bf51b8118c059f\
+            a1721dc121e715be71b3ac67dc59f1be\
+            689b3c123df2154a0fdcde80c553a430\
+            d8efda3f17cc8e0c9921df6a6cac5bf8\
+            efa475696e50b1fb3e66b0d9c183d472\
+            9ed2bce122792af436c6d197cb4b2376\
+            a5beceee1b7d40888be20f4c7361a8c7\
+            7ffd5135cd03a3d6e6e77cada9c63a97\
+            15337f015e66293bcd5d0500226fc6f8\
+            15980a6d54b86a771a7596e96251ea59\
+            d0a26d525994f0ef9dceeceab6980925\
+            a034132f408c06a50e67de82109595f0\
+            b6558999929ff4d2a2d4e32b7ec38381\
+            dc6a424705a14420ddf2493a9235fc96\
+            6aebcd16bdd965de88e546c6c4b34b0b\
+            9d06d4727ca1e2dbac68b6e16c902023\
+            0ee37a1b806753635bc6c763bb860323\
+            ab762c3e84aa329810be1fcf5a20b1a6\
+            116ac880fb9674c5d87091d397e8e1c1\
+            c6b80436706863bafb7c62ba150c467b\
+            2b86991e746bb914b429046b03e3d317\
+            f057f34aa0dd9bc439a958b14ebae08d\
+            62da7ad5024f71c6cbf053f24c6137dc\
+            c21de4d1bfcf531203b29e97c1c6f730\
+            7bb20ba41a07ecbe002d41ce114efd54\
+            684ed3694cf1a8b7b841b0d2a92bde03\
+            fa864b39ed32ba532ed258890232109c\
+            32bb668596ed616ed6ab7cf2129205bd\
+            d6afcc6aac7b91042518e6a453bc1b82\
+            4ca81aa5dfa2a3d7982dd73bee0ffbc6\
+            8ffb9e578eb6d04a7cc66344ca12c070\
+            2867f7de38ba9935f4e6b64e6b72e0cc\
+            33425cf1620aca896719ac01a7dbce1f\
+            b24d2a33e414f95a9c2606adca1b4838\
+            6f8bec9a7bfe5144e7fcf466959377fa\
+            0c7a335f36060360e80fc0e4981ae81f\
+            06108582ec4a83cc7ce1af323bfd80d9\
+            fcfeaa12b017e2168cdbc79810e84f5d\
+            cdc9a2cc1a5132ee7f3df31891b9edf2\
+            ccec5c714fb6eeaa7865c09a8658dcfa\
+            0f749568de8f248de3830e2afa0e6add\
+            c8975e5721a7979446410bc431178070\
+            d365ff7ce13bf6a99ebb8c4edda23538\
+            39ee574d36c0619d4491627e88aa6fc3\
+            1eb41ffe53a6619377a6c3cc1aac8138\
+            7a1f23de2e93856e0149de8d43fdc9c6\
+            1902192842ba49b38f44db0db5751e4a\
+            942592492f67d20ad575c880c0c2be0f\
+            6f324b7d70f93b21110143f8e234e72c\
+            ff89843b4f148ecc95d53a0a5baa2ef0\
+            1237066ae2050daffdd645bad37d38d7\
+            4b30312210b177bc1022db15056c1324\
+            2dbb9d6f6fe241ebf9c73a06831b042e\
+            661721bb0238f6731b0990fafead8586\
+            506383b338c3141894012a916b547abc\
+            fa0d46fbdbfb0dc31b2b18708887c4c7\
+            20f1f5ce7a2dfeb453bb52d067b64398\
+            6ee653ffb07c0a47dd550526c11c3c0a\
+            e188799dd7c6ad9c4ecdfc9f05311042\
+            c27a02e445d4bdd0efb2bc2d6f9304d9\
+            abcb76aeb07f7214dd30e03c22c808e1\
+            0212e047fea0dc1140ad77b9440e35a2\
+            42191500053e3fea383e3abe6061270f\
+            14dca23e33c647b4553fbb1d4f2790d6\
+            9717c203d41b1196994c4b933b104177\
+            14adc943519db137edf179ce4231024a\
+            e0054fd751e06f1f3721f2a960928e33\
+            a976a0bc91c52ec6332cbcb6053b5b47\
+            990919040283c39efbb953ef198f4104\
+            41dba680eef0403fff3e978cad67d7ab\
+            c717835354c8456a8f2f70552d3923ee\
+            7dee59f49c223ed6399db0c7b9aa62bc\
+            b656eea22217cc98f3028ebef7e42d99\
+            e0ede445fbdf563e7dc7b6991c5ca733\
+            e840f642c335e8cb7cff45eec3b3d513\
+            66c1bcd61d29fe431add0ce83c2b5cc6\
+            dd3a084c4ff401575c5b588ffcfceac9\
+            a47bd95fc9e790e5d1fc1a19b92480d4\
+            5f4f733a042b2b5489a5a45c439c7ccc\
+            6e142755ab7b8b0035381b2bb8abf3e8\
+            7723988d6df5282e6fc1d84003a11ca7\
+            edf2bc0b262c31022f4dacf74d157f95\
+            3954470d42b5e4ffaaf0e68bfd92d61e\
+            1d99253ce13893d09e3a47f8c218650b\
+            caba5b5f53fa54c09ea38d43ae864453\
+            a5d521bcd16e74d055eca4ed9b9730df\
+            3f8861b829f5aadc6acd61f611508f56\
+            58fd230f707e43b0940cd158fdd5e76f\
+            758c1f31b831d8636d976d7a41940e28\
+            de3aea45156136b0437c34a34de955d1\
+            35d61f8cde040aebcf63525fee26c9e0\
+            c4c21859a00bbb5cc9098df0c1b2f4a9\
+            ce33f6cd1620cecc626ae115fe7b2ba2\
+            c7ab1cc9b40cc32e3a5fb89fe1ce74f4\
+            9b7b436a1f781a51d5fb69bd5a4784a3\
+            85b9b7bb61b08b0600d6f11de7dc4d44\
+            dd86fb1fbc7e489df3372587e6cccd47\
+            fbf3eb63390f8d2295a24afd11a2a29c\
+            3a23a13de07b1ec05315af50813ec967\
+            591efa72ac705534f95e2005dd63ad54\
+            2c1bd3ca648cfe925c4df28bf48479fc\
+            3c92afacdb6597fa0dc76422f8e0a87c\
+            fa5e259f86737eebaa36ab3b277312e7\
+            b6570fa43f53d311dc196493f27187d1\
+            11ce9bd983b78a275d19f5fd74ba717d\
+            0d91f100e7ec2c9d0e0e89785ec080b6\
+            6b258abd0f6fab879024bf331523e287\
+            328f338ece77e542a55cb3607592729a\
+            ffc1c42cffb8fc1bd724bb4e680eca1b\
+            460a16e7dcbcd315434c75fda1b013f1\
+            061c8bc50f0a4391b3f9025b6d4170c1\
+            3688f8b2b3558989d9c9c97d57905308\
+            5847ca9fe194161c61776b0c7f68e9ce\
+            7f24abe946589bc452d097bf8d80827d\
+            9533aba2b70f12cd9bdd087e6f965568\
+            42f8be441a641e64a724ca467f9a6c02\
+            d7f573aa1fe7fd3ce07a0c58b135f686\
+            9a20673a88482e69190bf89abfd2c95a\
+            b6263701146ed458db7e29a5baff6f60\
+            470a351e9979db48e761193ac2dfb4ed\
+            f4153812718d792a2a682ea7e97c8700\
+            da092122ac23d62518654b4320e20773\
+            6aee1a379de7bc69e430a23060b35d3a\
+            4de5d9e25cbb800905224132e402302a\
+            338f422e4972d04f9f1dcdf806b6c4d2\
+            bdee2e633b93f624994d5a016d8e78d6\
+            b8d89f53df892a48611f68e77223b476\
+            9ec8e32d496ea348f5a163bd565ea024\
+            39ec2d0fcb8835e87065c797904a370e\
+            050ecf8d54667f838f8348c9596f6cf7\
+            686b0482ae2858e5f50a5a9d65966f9b\
+            d09110a780c40a73b50bc0b2630d9307\
+            5efd0101fc4a151ba0d147e3a3886dd1\
+            66df596788f67de7a4b28cf140261ee0\
+            9f87476c43ce6235ef63b32e1ec93640\
+            3cbe5f6caecbc134ccc35355fbefc2a8\
+            d3f8b795928fec807e10167d6b5735d0\
+            32939f8d95882c67a3926ef957f433c8\
+            d50263aa725f8b121e8e9ec9a36754ec\
+            d76917d516088852a9bd4c9795dda0a4\
+            abf439a73747e70679fd1c246a99ad69\
+            1879b301bd70adbeb0cd9487231548f3\
+            e1d2bba500ae029c3b7730728044ab8a\
+            bbda29ba59892c7df73f1613a78d0fda\
+            9f9acc62169a7dc9dafb47b744a0e53a\
+            d9a602dd40ea99f00e893e11284c36af\
+            f9d7b5259e78c772dfe96c1db4b3f917\
+            471b9756c98b3106b667483840c92d75\
+            58b04fad0b8ea0052762d30906c49a25\
+            d1792811a3782618a3b8da9ff057367a\
+            0610c3c2f2a00ffa0fe93d63199c7607\
+            3417e93fd6df605b8f5d50936644fb57\
+            a40c8090fd8d961f01ba9c7fc8dba52e\
+            6a06a4d59bd28001f291d7dceac07c03\
+            a704cc8a1434c235d60b58c5fa9d3940\
+            56f1787a2dddd0f8d57d9b225d2898d4\
+            cd4be2b83f054a8a80a8620caceddf65\
+            8ab17aef3bf37dc794e466483a3b03ea\
+            a8de0969d7c571151e70d9360bf811c4\
+            ee6db51fa0ab2f51975f7ada8732dc03\
+            ce4e622c6d8a0afeb553edc06299d1b6\
+            b327be8f135828c93b13b3e29719dd08\
+            1c19b8d8ac6be9b7b1c90f65cfd81452\
+            b0ce2f60af02f07177395cdf09b26655\
+            eeee225f0a3ebdb56b94b98c7bf6778c\
+            a03fd6df38f2d2049f17ef161b6f7483\
+            6097dab3bba34de87e6964607cfe78b5\
+            62f26d49947ccb049d6f5434ab9cc97e\
+            09907f3fe0efe0ba0c4a72c11ef36eef\
+            7f3f5fb3082c0e6668f675a669227dd0\
+            3fa866d98100df70f58cf54dfa6c31dd\
+            7ee1f5ff36e0a7dfa2b885174fe7b349\
+            b9882360b0b4af347f9632a91bbd3bcb\
+            2f53a3c33638f5097b125b5e97cba516\
+            7afa9ec8ec529ee5223a62f9dd772e48\
+            ef08b5ed5c722b2ebdc6f45ff759cb84\
+            de6540a2388454e692e850ff49355f56\
+            25e5300ceee09db960843a04766b78a3\
+            65ab3eccc24988cf8bc637a7ab6dbb78\
+            8bccdeca785a1357a464b560861570b4\
+            559e3888a588d256125a2a8d6c02a433\
+            86369f1eb1ca851f7463ed5f85bcae30\
+            8cc5480d63ae2e7d0c10e5ec28c027a3\
+            a32f2fe73225e6b59c9061d45ea6de53\
+            38569bfd9b793384fc6997c5e9fd66df\
+            bd0d789bd6dac9d007aaabbf65eba337\
+            c373b87cc55c808d8d4aba3e3242039a\
+            c6eb74a01ff3c9d4a2131dde1b87e6b6\
+            4944ef17f54628651443f9bbb1cb8eea\
+            7cd77a20df364afbfb3358d8e61d91bc\
+            d60171beb9595e97919495994a421013\
+            4507e2b4d5a48d8ef0db39cff409f26d\
+            beb6f4a841f288c604a0b6af70a2ebda\
+            1da52a1efe52a7ef34cecde0d89cd452\
+            b2f9d3461e300d37f3aa11de6bdc980d\
+            3c65488fd2d7fdc2c7cb369732875b42\
+            70192c7d5b5a1bbeaafe18b3602242be\
+            a41f6aa30f02ffbf1fa7f21e4ae8a18a\
+            eb36390b0b24c8739ff9fc0af11f1224\
+            5833b2d6b4977c57abb8f6e55099d792\
+            1c8433da4e71814ccf3234e248069dad\
+            e41de0def17879a9f56b694e59f50ae7\
+            e9b5131a95eab0342ef1d420750ac6d2\
+            12c2b4f7ddc9fe4ac73ce860af153170\
+            da56a78eccf52956c74b2e2107df4f62\
+            b692e81b38a1486e3781108db55eee24\
+            5b95be6aa68c019d7cd3b4a8296a9198\
+            00000005f9b4671f0820d963f134b862\
+            d3ff4c8bc3ab8558e189a8016c0d11ce\
+            4ea6b39168bb48b598d44624ece3477a\
+            ca1b4a5ecd8b2b1837d133714cea2f19\
+            2cbd622dd078c1e9a6c66a3c4f5838bd\
+            a2301dd6cdcf3f58d2b0a8aaaddbe2a9\
+            d9e17bf97c064a213c8f034b41891c1a\
+            f0802ce7c354be0a62a8b4e04f771edf\
+            d0f0224601e91b012c2ca4c4f9d6880b\
+            d0941c4a9ed569acc1ef0bc298e8e6c5\
+            d8ec3f54";
+
+        define_lms_tests!{}
+    }
+}
+
+/// LMS_SHA256_M32_H10 with LMOTS_SHA256_N32_W2
+pub mod LMS_SHA256_M32_H10_SHA256_N32_W2 {
+
+    define_lms_core!{}
+
+    const n: usize = 32;
+    const m: usize = 32;
+    const w: usize = 2;
+    const h: usize = 10;
+    const key_type: u32 = 0x00000006;
+    const ots_type: u32 = 0x00000002;
+
+    define_lms_sha256!{}
+
+    #[cfg(test)]
+    mod tests {
+
+        // Test vector generated with the reference implementation
+        // (https://github.com/cisco/hash-sigs), from the Botan test suite
+        // (LMS_SHA256_N32_H10 with LMOTS_SHA256_N32_W2). The randomizer C is derived from
+        // the seed, as in the reference implementation.
+
+        static KAT_RNG_TAPE: &str = "66320db73158a35a255d051758e95ed467c6697351ff4aec29cdbaabf2fbe3467cc254f81be8e78d765a2e63339fc99a8ebea1c5c841a4fb1357d69c5f556782a1d729a2f6edbf41febc33536290b5d9";
+        static KAT_PK_I: &str = "66320db73158a35a255d051758e95ed4";
+        static KAT_PK_T1: &str = "547c0449d8b3531f210b7e34fea7301771b6160e7cef454f4b83374d68cd1a76";
+        static KAT_MSG: &str = "caca010bb1acce55e5b100d1e55f055115";
+        static KAT_LEAFNUM: u32 = 5u32;
+        static KAT_SIG: &str =
+            "00000005\
+            00000002\
+            8ebea1c5c841a4fb1357d69c5f556782\
+            a1d729a2f6edbf41febc33536290b5d9\
+            0f01226b0922c524ce26fa7dcedaaaf0\
+            fe6a51a467e486cc502048d56062b428\
+            aeaf1102e98ad5320c4308c224c93229\
+            dc13a06026a75e9d8f9761378c167fbd\
+            7d8768d366eb417f2ec725728a066647\
+            4654a23f0415901ef5a79d1fc78541d8\
+            871479a146240f38cb73180d258d5c06\
+            127ecca4b8f7cc73217efa75d4810b2a\
+            4f3d02ba74f6d6fa56a91fdb11b2c77d\
+            6bbd5a4fa7777d5bf12566aaa539d943\
+            57a1eca7d341d544a0581b87568c3772\
+            9fa34877d94517f7be8fcabfdc42c186\
+            c6d69e23769343223d45b28cee4a2d30\
+            a83bc084a4be9353a57ff18b525fd41b\
+            23d546f9a3105a287e9946c5a0f8cfc6\
+            06d2e5bb87ff12304da0425a3367c17c\
+            0c81ba0a68a7b5130b49ef979d7b658e\
+            1622f2c599801bb7d66fb1c08a9823ec\
+            ed413697baccd8e4f2bedd306c6f7295\
+            018f4857aa5ab57e4c3b9f6c7a9075a3\
+            d8e136aa3cbd41c6999a28cd5296c179\
+            ed8998fdf2de702f1bc217e7ba254462\
+            5d3849bf7d5a2884b0685fd0223ebe4d\
+            246a02214ab48e5adb27f9518d2cf484\
+            7124544fd9287d437d698f6ead56eb0b\
+            632f6952ba4978e22038a0926645b95c\
+            7344e67f600acdf33ebf6bf2c5ffbd40\
+            1b6ea874394762a404a8399a340c2e94\
+            636b82d0be737ba1fbd4dfb1eb3fd9cc\
+            87159cc644dbeadeacbeaf8cc5ae9119\
+            648f4a0fcbbc968428b9778faa46c53a\
+            b9a1f2c8742297d89bb955eec859f987\
+            a780df4bd6d7f9d48800e42c67eeb11b\
+            8406d43549333b0a379d5ed77ab8c792\
+            1ca26aaea2c1c8d925f00c78a41a718b\
+            270f82f7dfdbd7f5a73a661ba2fe66f1\
+            da7c7bc35873dfdf769544f275914a1a\
+            44c41460dbfdab81282e9fb9bfa8f99c\
+            a3d5d413c37a4a65aa79f4de37fd5c98\
+            196b9f75588ef011708c9e8a4eac36ea\
+            8d9a518ec530c791f01cafb0f7d4f47d\
+            8c1a300af6f3e74b35d2fd717bcaf67b\
+            0dc174f0606301abd3a2ccb074aaa4af\
+            9717cbbcffb57828810f76307dfe0654\
+            c594b4c9c3bc9b16c73f7be4a1b59d68\
+            ae92c090b0b7be8af177d9b52ec84ace\
+            278aaf2f8b15f1718061902846c9ab80\
+            1189206fc335f6065623530c891b44eb\
+            76d32c57ca2c5637c06a79381edf16d8\
+            5507789d3e7e4a68f21cda3b45476164\
+            597ce4e0da6fad2ee1ac9a8a21eb8a2d\
+            67dbc63a1161a63f726e8ea893c5eb56\
+            5fd515ef0f916bff1c77ab3d4e93d07e\
+            6711c68dc07fe68e0d269a21c6ca7361\
+            95ab8353811e002e3c2f259f41aa0cb5\
+            e6325649b82f3870addff21296555774\
+            a8af8315013ff0ae64234f69fbff72d2\
+            8424459f7974077deb68bc2e5f984ec4\
+            7342ca775a4442bf5fbfd47acf975e40\
+            3cfa1d1082f9934a5fea3a22379ab629\
+            e33d8143aef89c7ae5fac752743e51d4\
+            ab5b246e691d6120c3be921c10519fce\
+            b29b0ff42ba1d2c29b1ebfdeab216b77\
+            a254317214bb0ed4a621d757b7a2e546\
+            d4dffdbd7f4663e79ea7e2fa549315dd\
+            f3eea7cc2a204ad77d1eda9e8fa8ddfc\
+            3d455d336f41d50312102e9ad77242b5\
+            6154755c0d78c0cd916defbbc224a463\
+            9502b151935f54d3d72357bdff1e7928\
+            d837b3c1dcd9b43190944d72da7e3971\
+            f74c547ab8c0f57f670bc50c8044b5a6\
+            360d9c120c857f28efb3e3509f493a19\
+            ab3ad542b0128ae95e2c6fd7d7433e87\
+            52a9e30b2b8c97cb4d0f5bcd7a651d60\
+            47104840dd2a8181fd934a78d1d1d3d3\
+            fba3a0b69d8dc19c1b7e7a59462d9fd2\
+            33a5b2584b89ffab7a275427dda336f1\
+            6a0750af4ca09cf0bb0e50b2e4c7f77f\
+            de0c2e01dfa0818229507d044796e050\
+            05e66ebdc2d50e0d1fd809a1e4c86400\
+            23760453958186395fd3bee3340fee95\
+            a196aa7eb010631f66288243df997ff7\
+            d6f19ddc404768c36f097a37f08614d5\
+            bc415287ac70b9af6aa69080e1b7546f\
+            5b837956968e919114bff157b22b3a6b\
+            434b65b29d6424197929a140455debac\
+            8fb047a1916a9e239e8885731198e01b\
+            a229e2ff50529341db8fe8725b7a7d8d\
+            b4475c9dc7e791c4355bb4be4d2b32b3\
+            a55764ae4bb9ae4ac10ba34f5f46c17f\
+            7bcce2644e4b3a256af8854a8e2f74d5\
+            d55604a2342db0fdfb64aa53ffb2341d\
+            f1595234ecd88bf799fa04c9280bd9e7\
+            b51582899763d76da14e8ae3a1920f92\
+            bb71e7097b99888ade37e27a4ba3650c\
+            31b278feb1b59a6e37e270be7f79b848\
+            75ec0f62a98aa0b3b1b034f904d4af13\
+            c3d69e0e209afe54b5b00e8a91363b90\
+            2dc5472d272d156ca18925c37de3bbd5\
+            a62fb261082c37f0345597aad08bdfa4\
+            db77f5f7010ca65b62f50d45bcd4471d\
+            dd91406c7ec1cf38bb45a510d0d40569\
+            79ae9140ebd039b998c4ea9fb9665fcd\
+            45efcdd19994fa24b725068bffd20581\
+            5395cea5256a39a65295b8e2bbc85f21\
+            216c70929e49f16d988b590c8b85ea93\
+            27114c95b3e459874016702bc0081136\
+            6e403a21bcd7ce90207539a3caa07aab\
+            d785aacf5c539965d4de1ee748c18b51\
+            467c550ac40b7e348f6bb83933daceae\
+            3e6ceeab245f84d739812c419da7cc1b\
+            db411d700858fb49e3f7aeee03b9449f\
+            0522d7c0984821049bac6ef0c2587658\
+            ef8fa667cd2069da327c963e7de851d9\
+            46fbf291c4a5e70de48d2c29e3918a88\
+            eddd8d57c00edd7bb6d3d2939a75b8dd\
+            67ff7654765fadb4167c7725679cb33e\
+            dafaf7fb592bed7a8f9bab9b3fb2bbe9\
+            b15158c9a56349b72dcc4d0e7bdfa6ae\
+            d86f8f95d8371b5a34d5dbb91f80c3fd\
+            2e6f031428761e0faaa9789e44aa4a4b\
+            852a56f347cd5bfea40762b4c78595a5\
+            4444fcb653535189372f80c43843ec55\
+            43d7fa62e919e515fd00524be04e2fcf\
+            616f0e94de5c35e91ef701f25d6ce991\
+            de13b3cea96e2d4cf8cdd1c5c93d28f3\
+            7eee242b4275db9b19f25250e01eb3e5\
+            759882506ba68f54926815c146cacfe8\
+            b46bd326168a621f05a44b826223afd9\
+            df258cc014a2ef69abf8074128e8d452\
+            a4d447fc39c699b8b1c635a898d8f8d1\
+            b5bca595cb69c3b9665a0eeea94a9dc9\
+            5f2dacd1558663098e84779d6dddb284\
+            ad4ffba820d033b63e0d0e208761e798\
+            c2de34d8c4891d145a346d339c4444f9\
+            c97bd0caee09eb3edb2f6f1da6bc09f7\
+            8a59a53234f12dbe44598df72d3341ab\
+            ff79a68a8018966057ffb784775a7075\
+            39f97e34eef9c20e7b77b74c6869e6f0\
+            0d20fd997479eed33bb87e97684239b4\
+            0f7f0bb491dfd82ad0269754a500d56b\
+            134c5a1b683f9de48f1ee74c7f89614a\
+            a45b74fd0672ec6693588a9ac860e86c\
+            85dfc3550973169fa31eeef009cb2331\
+            29c635c51ef6220b7cae46dff6838aad\
+            b833b5019210df55c5c6d04b7f6054ad\
+            64a5a1fdd3157de4e39a6015155a2fbb\
+            7d087c754de92060ceb23b7d27e1a261\
+            8e39f89b43f2fb178dc8e6167cf058fe\
+            e5dce01867cc4ad3a46a3c772a592caa\
+            19c70b2b880f958417a1e52373c19982\
+            286fba06827a98a36ad192d614fbd253\
+            458b7669002790e57e4db36801a6df22\
+            099f64241a003db3d6b0028487ce6a8b\
+            fc13267d2b35ba3e2b7a4c0be67f3252\
+            c0845fcce0e020890c2d65e9c44bf56c\
+            746c2e39e97b38ebe031ba2ffe501189\
+            e92f7591f54f2bda1b7f37f3da6e6add\
+            49f6584588c478c1ce8d9b431aa3bda9\
+            459d3eb96fbd6f333e4c6ccf8ddec2b8\
+            7c62c5a9ad2bed9ba65964cce203fb0f\
+            5127c44fb241ceac1f159d248ff71afe\
+            382cc4ce87e876a7d23b859840bb5a70\
+            371e7b0a9d6d7f265d1266f6903f8dd5\
+            4feead50882d6472623bf70a19f0f9e0\
+            7b6ff270a27f3a042de67f535b81aa50\
+            f789dce1a3f0c58dc41836216111b821\
+            6483e5b43eea452e7b8cea278cf4909b\
+            dbc27f3e664cc6c5b5e749580660e043\
+            0eaa8c415781255c831de9635001ef69\
+            4f623d535ab37ef009fbe403661f9426\
+            e791d2641969a8e11763c4e41566c82f\
+            57a846c57c54be4d22dd633af5a09ccb\
+            e98f1596052e46ea534cd74028f53c51\
+            036d697f41888aa235742ce2fd162d67\
+            2c46b506b5e0ab15e9a29e055c9e020e\
+            c92182fabec9903e952fc610996333f3\
+            34049b79ba6d43102c03f24a16de5261\
+            db14f29328effd6704ff54c23172b8d6\
+            26fc61ff3877970b0a22bc6eb980cefa\
+            b49107ea08f247f9a60e0953c37dcd35\
+            b1391903f53d8e5ebe9c8ebbb8119982\
+            182092cf21128345bd79a2ef648bb187\
+            5efe7da86939f259c2688c0f858bbc4a\
+            63590ee62080350b442aa4a29bd805ca\
+            692eeb985e772376c8ceef21614e184e\
+            b2158c9519eb32b037f74ab6571c3a9a\
+            74917cad24c67347e4fc0d7320900ee2\
+            09e8da4d90c4dfc9a8974daed3caf331\
+            2117ba5a622aa0fe8bc23057faca3841\
+            e67432beb8e8be23d4e1368850b1a9e3\
+            19d7426a3c17124e38648fb0cc31817c\
+            b9f0fe8029f66b669cfbffb2a3331394\
+            7ad67c03a5cad5a9a42fdaba3882a615\
+            5f341e77672a9684d05c78f24231e204\
+            69e891fd02b964210e0eb166a0e9fcf8\
+            a2b97f74c9ef2a986206bc3477ddb378\
+            13adf809eee7ca0e0b4166429bc1ff86\
+            982abd6b5b0a768de611bf1613d33d7e\
+            674b3ab852108217f54bc6d5cfdbbb41\
+            0194c511acd955d5fefcd383d36f00ca\
+            a8bf4901a986522dd678290f32116415\
+            e33ca72975ad90ab93ebfd9da2b59340\
+            11ed1d5cf9712adf9adc630ce8d18a42\
+            494eefb7ccb189c4f57fd19d3856dc85\
+            0f5c373aaf5bcc23e509bde4732df402\
+            0b53deed2ba72e752a2220812f4483b9\
+            90a4e7b1c9d66a0d7de456eb76b63fc3\
+            cb2acd41dd7b4068ad9b5bf102103b4a\
+            f9525374b96985e2d5dadbfb8ce34e63\
+            76586facdee2812c495a17362f075958\
+            97a9ddad1c513c97d634d4841b45fc9a\
+            7c0c5e89c2d538d47194177f0e293c1a\
+            d11d0c6e5048fb6539a3b6af99aba515\
+            9249752155669f25364db2c56ff16a97\
+            8e7b8783d147dbaede337349fd562e19\
+            14b606165e7c0d098201e51001b5c361\
+            a03e5831c44b1b1d5bc2044d6652b617\
+            d8f87d77d1a9388664179c7c98826484\
+            9866c3f18a3bfd3ec70a9601c664fac5\
+            4b259497a7604ea646fb0972675b3004\
+            aeb7ce00faa4359f4a01c49915dea9b0\
+            f9fcccaa61dcf327b9ff787b9e53046b\
+            931631f4ebd6d0c15bbd6764ad2cef78\
+            b6d5301f3e13f4af1593b4455ace4694\
+            bafb8f1cddbf12a271b9f846324562e2\
+            0cf7a80390d734f8da01e1855c9f0fc6\
+            380e3a2a4f1b2b0a0b9440f38c0f6404\
+            de422ae300d5f806e57643a53a4da839\
+            2638d30de690456238be74ac34c05e3e\
+            c5c92637f28ee0520ef9b510729ba687\
+            70d113d6d827c21d69b7f7d408ed814c\
+            9995bb2476d945350c393b57fc29702b\
+            93b247f9a935307af05ed2e22244b7ff\
+            ea17ef59c74b6ac88a0cbdd1d9f7d9f6\
+            2ef0d58b6f3025e9addcadba64179572\
+            4bec568ee38f6792470573278da858a0\
+            cfd1c6dde8cab41d8d22ff309e4a0556\
+            3989951ce8f34b9e46f7081fbf7bbabf\
+            489df5446b3e02e3d52c45f10c76d1f9\
+            ed2ca37014baa5d6131001633ea6f87b\
+            68577557328a2fb1e7411e89d9164a83\
+            3b6e6542596983a88bfe23d2983476c8\
+            d0bd0a0c0621f4ce80a4678d848c53cd\
+            29236a06d79a31a7b2c2b0f7933d7837\
+            7c74ce58e35378b78afa15b0ad400f3a\
+            df21f04f126c25e8e322f7d774bae1f7\
+            20c98deac9a27a78215317901d4e3d4e\
+            a512d7e0738d5f1a62b935664ae40724\
+            c83d5b05e498421aa52f3e582f78c008\
+            c722ea8d1e948fdcb55ebf8507732c0f\
+            47de255dff0231172dfb9faa7e5a9620\
+            4465b730c7178961b836213c56befd43\
+            6eefe760380917e8559f3b4b79f20687\
+            acac0c959ff1b7b95b97c0a074c663aa\
+            424b3f951f403586649f59c73567d43a\
+            8fa0030ee9e3cfed1fe1a66401a26a69\
+            c061a05a74c372099810652f2ca142e8\
+            3e08d5283caaeae3879d77e0486c3d2c\
+            6d63860a18fbcf007dca0098af84fd45\
+            2988cb1d2539da1d8c382e66d87b66ab\
+            2b3054126c7a0ba79b2d36d7e307344f\
+            7eb8ccd3ec8eb55e45ee044da3eb41b2\
+            9b7f79338f3132b8091e555058c5bb7c\
+            7e4b361dccee0071255aec112cfc71b8\
+            7c615bb1b0d363d3e6265cdbf430307b\
+            00000006d88b7df8551a868b56f2362e\
+            d9cb3e4cdf4a1d74a1061c89b3b64176\
+            0808f202eaa94edf0ce93a36a241c58c\
+            4910c0de6a4a8219b4c3bec025a26014\
+            717f76535962867daf69ddc8743b78dc\
+            26f3a343fc62822b383a3906d839bcde\
+            cf277a4b8bb30ec339a2f6eff79a4bbf\
+            09a34389429ee8f0e308d4124ca5df83\
+            31258e856802df7a036d9fc3266e83f5\
+            92fb7b02d7819e0a2b4b5d0a248b70bd\
+            5bce48e8c34e524338a67a4968a0a6aa\
+            6e431f1961c2a123fdb3b2a64885dfe4\
+            1e6e0eff82251152f32956745568ef4c\
+            c2964dfcb0acb12e5947e591fbf5a5d2\
+            df0a254351fe63acfb4819e5cd1500f5\
+            3980c9d96a4966f9968f4f4252c9fac4\
+            0e87fa53a2f1cda45516bc4b3f1cc635\
+            0ffcb14b03e21c0664684d76a8d54c9a\
+            d068908c254bdabefb8d976fe2291396\
+            396095188c6860308835c546cf2be142\
+            404e4190";
+
+        define_lms_tests!{}
+    }
+}
+
+// ========================================================================
+
+// Defines a parameter set module (without known-answer tests).
+macro_rules! define_lms_params {
+    ($(#[$attr:meta])* $name:ident, $hash:ident, $nm:expr, $w:expr, $h:expr,
+        $kt:expr, $ot:expr) =>
+    {
+        $(#[$attr])*
+        pub mod $name {
+
+            define_lms_core!{}
+
+            const n: usize = $nm;
+            const m: usize = $nm;
+            const w: usize = $w;
+            const h: usize = $h;
+            const key_type: u32 = $kt;
+            const ots_type: u32 = $ot;
+
+            $hash!{}
+        }
+    }
+}
+
+define_lms_params!{
+    /// LMS_SHA256_M32_H5 with LMOTS_SHA256_N32_W2
+    LMS_SHA256_M32_H5_SHA256_N32_W2, define_lms_sha256, 32, 2, 5, 0x00000005, 0x00000002
+}
+
+define_lms_params!{
+    /// LMS_SHA256_M32_H5 with LMOTS_SHA256_N32_W4
+    LMS_SHA256_M32_H5_SHA256_N32_W4, define_lms_sha256, 32, 4, 5, 0x00000005, 0x00000003
+}
+
+define_lms_params!{
+    /// LMS_SHA256_M32_H10 with LMOTS_SHA256_N32_W1
+    LMS_SHA256_M32_H10_SHA256_N32_W1, define_lms_sha256, 32, 1, 10, 0x00000006, 0x00000001
+}
+
+define_lms_params!{
+    /// LMS_SHA256_M32_H10 with LMOTS_SHA256_N32_W4
+    LMS_SHA256_M32_H10_SHA256_N32_W4, define_lms_sha256, 32, 4, 10, 0x00000006, 0x00000003
+}
+
+define_lms_params!{
+    /// LMS_SHA256_M32_H10 with LMOTS_SHA256_N32_W8
+    LMS_SHA256_M32_H10_SHA256_N32_W8, define_lms_sha256, 32, 8, 10, 0x00000006, 0x00000004
+}
+
+define_lms_params!{
+    /// LMS_SHA256_M32_H15 with LMOTS_SHA256_N32_W1
+    LMS_SHA256_M32_H15_SHA256_N32_W1, define_lms_sha256, 32, 1, 15, 0x00000007, 0x00000001
+}
+
+define_lms_params!{
+    /// LMS_SHA256_M32_H15 with LMOTS_SHA256_N32_W2
+    LMS_SHA256_M32_H15_SHA256_N32_W2, define_lms_sha256, 32, 2, 15, 0x00000007, 0x00000002
+}
+
+define_lms_params!{
+    /// LMS_SHA256_M32_H15 with LMOTS_SHA256_N32_W4
+    LMS_SHA256_M32_H15_SHA256_N32_W4, define_lms_sha256, 32, 4, 15, 0x00000007, 0x00000003
+}
+
+define_lms_params!{
+    /// LMS_SHA256_M32_H15 with LMOTS_SHA256_N32_W8
+    LMS_SHA256_M32_H15_SHA256_N32_W8, define_lms_sha256, 32, 8, 15, 0x00000007, 0x00000004
+}
+
+define_lms_params!{
+    /// LMS_SHA256_M32_H20 with LMOTS_SHA256_N32_W1
+    LMS_SHA256_M32_H20_SHA256_N32_W1, define_lms_sha256, 32, 1, 20, 0x00000008, 0x00000001
+}
+
+define_lms_params!{
+    /// LMS_SHA256_M32_H20 with LMOTS_SHA256_N32_W2
+    LMS_SHA256_M32_H20_SHA256_N32_W2, define_lms_sha256, 32, 2, 20, 0x00000008, 0x00000002
+}
+
+define_lms_params!{
+    /// LMS_SHA256_M32_H20 with LMOTS_SHA256_N32_W4
+    LMS_SHA256_M32_H20_SHA256_N32_W4, define_lms_sha256, 32, 4, 20, 0x00000008, 0x00000003
+}
+
+define_lms_params!{
+    /// LMS_SHA256_M32_H20 with LMOTS_SHA256_N32_W8
+    LMS_SHA256_M32_H20_SHA256_N32_W8, define_lms_sha256, 32, 8, 20, 0x00000008, 0x00000004
+}
+
+define_lms_params!{
+    /// LMS_SHA256_M32_H25 with LMOTS_SHA256_N32_W1
+    LMS_SHA256_M32_H25_SHA256_N32_W1, define_lms_sha256, 32, 1, 25, 0x00000009, 0x00000001
+}
+
+define_lms_params!{
+    /// LMS_SHA256_M32_H25 with LMOTS_SHA256_N32_W2
+    LMS_SHA256_M32_H25_SHA256_N32_W2, define_lms_sha256, 32, 2, 25, 0x00000009, 0x00000002
+}
+
+define_lms_params!{
+    /// LMS_SHA256_M32_H25 with LMOTS_SHA256_N32_W4
+    LMS_SHA256_M32_H25_SHA256_N32_W4, define_lms_sha256, 32, 4, 25, 0x00000009, 0x00000003
+}
+
+define_lms_params!{
+    /// LMS_SHA256_M32_H25 with LMOTS_SHA256_N32_W8
+    LMS_SHA256_M32_H25_SHA256_N32_W8, define_lms_sha256, 32, 8, 25, 0x00000009, 0x00000004
+}
+
+define_lms_params!{
+    /// LMS_SHA256_M24_H5 with LMOTS_SHA256_N24_W1
+    LMS_SHA256_M24_H5_SHA256_N24_W1, define_lms_sha256, 24, 1, 5, 0x0000000a, 0x00000005
+}
+
+define_lms_params!{
+    /// LMS_SHA256_M24_H5 with LMOTS_SHA256_N24_W2
+    LMS_SHA256_M24_H5_SHA256_N24_W2, define_lms_sha256, 24, 2, 5, 0x0000000a, 0x00000006
+}
+
+define_lms_params!{
+    /// LMS_SHA256_M24_H5 with LMOTS_SHA256_N24_W4
+    LMS_SHA256_M24_H5_SHA256_N24_W4, define_lms_sha256, 24, 4, 5, 0x0000000a, 0x00000007
+}
+
+define_lms_params!{
+    /// LMS_SHA256_M24_H10 with LMOTS_SHA256_N24_W1
+    LMS_SHA256_M24_H10_SHA256_N24_W1, define_lms_sha256, 24, 1, 10, 0x0000000b, 0x00000005
+}
+
+define_lms_params!{
+    /// LMS_SHA256_M24_H10 with LMOTS_SHA256_N24_W2
+    LMS_SHA256_M24_H10_SHA256_N24_W2, define_lms_sha256, 24, 2, 10, 0x0000000b, 0x00000006
+}
+
+define_lms_params!{
+    /// LMS_SHA256_M24_H10 with LMOTS_SHA256_N24_W4
+    LMS_SHA256_M24_H10_SHA256_N24_W4, define_lms_sha256, 24, 4, 10, 0x0000000b, 0x00000007
+}
+
+define_lms_params!{
+    /// LMS_SHA256_M24_H10 with LMOTS_SHA256_N24_W8
+    LMS_SHA256_M24_H10_SHA256_N24_W8, define_lms_sha256, 24, 8, 10, 0x0000000b, 0x00000008
+}
+
+define_lms_params!{
+    /// LMS_SHA256_M24_H15 with LMOTS_SHA256_N24_W1
+    LMS_SHA256_M24_H15_SHA256_N24_W1, define_lms_sha256, 24, 1, 15, 0x0000000c, 0x00000005
+}
+
+define_lms_params!{
+    /// LMS_SHA256_M24_H15 with LMOTS_SHA256_N24_W2
+    LMS_SHA256_M24_H15_SHA256_N24_W2, define_lms_sha256, 24, 2, 15, 0x0000000c, 0x00000006
+}
+
+define_lms_params!{
+    /// LMS_SHA256_M24_H15 with LMOTS_SHA256_N24_W4
+    LMS_SHA256_M24_H15_SHA256_N24_W4, define_lms_sha256, 24, 4, 15, 0x0000000c, 0x00000007
+}
+
+define_lms_params!{
+    /// LMS_SHA256_M24_H15 with LMOTS_SHA256_N24_W8
+    LMS_SHA256_M24_H15_SHA256_N24_W8, define_lms_sha256, 24, 8, 15, 0x0000000c, 0x00000008
+}
+
+define_lms_params!{
+    /// LMS_SHA256_M24_H20 with LMOTS_SHA256_N24_W1
+    LMS_SHA256_M24_H20_SHA256_N24_W1, define_lms_sha256, 24, 1, 20, 0x0000000d, 0x00000005
+}
+
+define_lms_params!{
+    /// LMS_SHA256_M24_H20 with LMOTS_SHA256_N24_W2
+    LMS_SHA256_M24_H20_SHA256_N24_W2, define_lms_sha256, 24, 2, 20, 0x0000000d, 0x00000006
+}
+
+define_lms_params!{
+    /// LMS_SHA256_M24_H20 with LMOTS_SHA256_N24_W4
+    LMS_SHA256_M24_H20_SHA256_N24_W4, define_lms_sha256, 24, 4, 20, 0x0000000d, 0x00000007
+}
+
+define_lms_params!{
+    /// LMS_SHA256_M24_H20 with LMOTS_SHA256_N24_W8
+    LMS_SHA256_M24_H20_SHA256_N24_W8, define_lms_sha256, 24, 8, 20, 0x0000000d, 0x00000008
+}
+
+define_lms_params!{
+    /// LMS_SHA256_M24_H25 with LMOTS_SHA256_N24_W1
+    LMS_SHA256_M24_H25_SHA256_N24_W1, define_lms_sha256, 24, 1, 25, 0x0000000e, 0x00000005
+}
+
+define_lms_params!{
+    /// LMS_SHA256_M24_H25 with LMOTS_SHA256_N24_W2
+    LMS_SHA256_M24_H25_SHA256_N24_W2, define_lms_sha256, 24, 2, 25, 0x0000000e, 0x00000006
+}
+
+define_lms_params!{
+    /// LMS_SHA256_M24_H25 with LMOTS_SHA256_N24_W4
+    LMS_SHA256_M24_H25_SHA256_N24_W4, define_lms_sha256, 24, 4, 25, 0x0000000e, 0x00000007
+}
+
+define_lms_params!{
+    /// LMS_SHA256_M24_H25 with LMOTS_SHA256_N24_W8
+    LMS_SHA256_M24_H25_SHA256_N24_W8, define_lms_sha256, 24, 8, 25, 0x0000000e, 0x00000008
+}
+
+define_lms_params!{
+    /// LMS_SHAKE_M32_H5 with LMOTS_SHAKE_N32_W1
+    LMS_SHAKE_M32_H5_SHAKE_N32_W1, define_lms_shake, 32, 1, 5, 0x0000000f, 0x00000009
+}
+
+define_lms_params!{
+    /// LMS_SHAKE_M32_H5 with LMOTS_SHAKE_N32_W2
+    LMS_SHAKE_M32_H5_SHAKE_N32_W2, define_lms_shake, 32, 2, 5, 0x0000000f, 0x0000000a
+}
+
+define_lms_params!{
+    /// LMS_SHAKE_M32_H5 with LMOTS_SHAKE_N32_W4
+    LMS_SHAKE_M32_H5_SHAKE_N32_W4, define_lms_shake, 32, 4, 5, 0x0000000f, 0x0000000b
+}
+
+define_lms_params!{
+    /// LMS_SHAKE_M32_H10 with LMOTS_SHAKE_N32_W1
+    LMS_SHAKE_M32_H10_SHAKE_N32_W1, define_lms_shake, 32, 1, 10, 0x00000010, 0x00000009
+}
+
+define_lms_params!{
+    /// LMS_SHAKE_M32_H10 with LMOTS_SHAKE_N32_W2
+    LMS_SHAKE_M32_H10_SHAKE_N32_W2, define_lms_shake, 32, 2, 10, 0x00000010, 0x0000000a
+}
+
+define_lms_params!{
+    /// LMS_SHAKE_M32_H10 with LMOTS_SHAKE_N32_W4
+    LMS_SHAKE_M32_H10_SHAKE_N32_W4, define_lms_shake, 32, 4, 10, 0x00000010, 0x0000000b
+}
+
+define_lms_params!{
+    /// LMS_SHAKE_M32_H10 with LMOTS_SHAKE_N32_W8
+    LMS_SHAKE_M32_H10_SHAKE_N32_W8, define_lms_shake, 32, 8, 10, 0x00000010, 0x0000000c
+}
+
+define_lms_params!{
+    /// LMS_SHAKE_M32_H15 with LMOTS_SHAKE_N32_W1
+    LMS_SHAKE_M32_H15_SHAKE_N32_W1, define_lms_shake, 32, 1, 15, 0x00000011, 0x00000009
+}
+
+define_lms_params!{
+    /// LMS_SHAKE_M32_H15 with LMOTS_SHAKE_N32_W2
+    LMS_SHAKE_M32_H15_SHAKE_N32_W2, define_lms_shake, 32, 2, 15, 0x00000011, 0x0000000a
+}
+
+define_lms_params!{
+    /// LMS_SHAKE_M32_H15 with LMOTS_SHAKE_N32_W4
+    LMS_SHAKE_M32_H15_SHAKE_N32_W4, define_lms_shake, 32, 4, 15, 0x00000011, 0x0000000b
+}
+
+define_lms_params!{
+    /// LMS_SHAKE_M32_H15 with LMOTS_SHAKE_N32_W8
+    LMS_SHAKE_M32_H15_SHAKE_N32_W8, define_lms_shake, 32, 8, 15, 0x00000011, 0x0000000c
+}
+
+define_lms_params!{
+    /// LMS_SHAKE_M32_H20 with LMOTS_SHAKE_N32_W1
+    LMS_SHAKE_M32_H20_SHAKE_N32_W1, define_lms_shake, 32, 1, 20, 0x00000012, 0x00000009
+}
+
+define_lms_params!{
+    /// LMS_SHAKE_M32_H20 with LMOTS_SHAKE_N32_W2
+    LMS_SHAKE_M32_H20_SHAKE_N32_W2, define_lms_shake, 32, 2, 20, 0x00000012, 0x0000000a
+}
+
+define_lms_params!{
+    /// LMS_SHAKE_M32_H20 with LMOTS_SHAKE_N32_W4
+    LMS_SHAKE_M32_H20_SHAKE_N32_W4, define_lms_shake, 32, 4, 20, 0x00000012, 0x0000000b
+}
+
+define_lms_params!{
+    /// LMS_SHAKE_M32_H20 with LMOTS_SHAKE_N32_W8
+    LMS_SHAKE_M32_H20_SHAKE_N32_W8, define_lms_shake, 32, 8, 20, 0x00000012, 0x0000000c
+}
+
+define_lms_params!{
+    /// LMS_SHAKE_M32_H25 with LMOTS_SHAKE_N32_W1
+    LMS_SHAKE_M32_H25_SHAKE_N32_W1, define_lms_shake, 32, 1, 25, 0x00000013, 0x00000009
+}
+
+define_lms_params!{
+    /// LMS_SHAKE_M32_H25 with LMOTS_SHAKE_N32_W2
+    LMS_SHAKE_M32_H25_SHAKE_N32_W2, define_lms_shake, 32, 2, 25, 0x00000013, 0x0000000a
+}
+
+define_lms_params!{
+    /// LMS_SHAKE_M32_H25 with LMOTS_SHAKE_N32_W4
+    LMS_SHAKE_M32_H25_SHAKE_N32_W4, define_lms_shake, 32, 4, 25, 0x00000013, 0x0000000b
+}
+
+define_lms_params!{
+    /// LMS_SHAKE_M32_H25 with LMOTS_SHAKE_N32_W8
+    LMS_SHAKE_M32_H25_SHAKE_N32_W8, define_lms_shake, 32, 8, 25, 0x00000013, 0x0000000c
+}
+
+define_lms_params!{
+    /// LMS_SHAKE_M24_H5 with LMOTS_SHAKE_N24_W1
+    LMS_SHAKE_M24_H5_SHAKE_N24_W1, define_lms_shake, 24, 1, 5, 0x00000014, 0x0000000d
+}
+
+define_lms_params!{
+    /// LMS_SHAKE_M24_H5 with LMOTS_SHAKE_N24_W2
+    LMS_SHAKE_M24_H5_SHAKE_N24_W2, define_lms_shake, 24, 2, 5, 0x00000014, 0x0000000e
+}
+
+define_lms_params!{
+    /// LMS_SHAKE_M24_H5 with LMOTS_SHAKE_N24_W4
+    LMS_SHAKE_M24_H5_SHAKE_N24_W4, define_lms_shake, 24, 4, 5, 0x00000014, 0x0000000f
+}
+
+define_lms_params!{
+    /// LMS_SHAKE_M24_H10 with LMOTS_SHAKE_N24_W1
+    LMS_SHAKE_M24_H10_SHAKE_N24_W1, define_lms_shake, 24, 1, 10, 0x00000015, 0x0000000d
+}
+
+define_lms_params!{
+    /// LMS_SHAKE_M24_H10 with LMOTS_SHAKE_N24_W2
+    LMS_SHAKE_M24_H10_SHAKE_N24_W2, define_lms_shake, 24, 2, 10, 0x00000015, 0x0000000e
+}
+
+define_lms_params!{
+    /// LMS_SHAKE_M24_H10 with LMOTS_SHAKE_N24_W4
+    LMS_SHAKE_M24_H10_SHAKE_N24_W4, define_lms_shake, 24, 4, 10, 0x00000015, 0x0000000f
+}
+
+define_lms_params!{
+    /// LMS_SHAKE_M24_H10 with LMOTS_SHAKE_N24_W8
+    LMS_SHAKE_M24_H10_SHAKE_N24_W8, define_lms_shake, 24, 8, 10, 0x00000015, 0x00000010
+}
+
+define_lms_params!{
+    /// LMS_SHAKE_M24_H15 with LMOTS_SHAKE_N24_W1
+    LMS_SHAKE_M24_H15_SHAKE_N24_W1, define_lms_shake, 24, 1, 15, 0x00000016, 0x0000000d
+}
+
+define_lms_params!{
+    /// LMS_SHAKE_M24_H15 with LMOTS_SHAKE_N24_W2
+    LMS_SHAKE_M24_H15_SHAKE_N24_W2, define_lms_shake, 24, 2, 15, 0x00000016, 0x0000000e
+}
+
+define_lms_params!{
+    /// LMS_SHAKE_M24_H15 with LMOTS_SHAKE_N24_W4
+    LMS_SHAKE_M24_H15_SHAKE_N24_W4, define_lms_shake, 24, 4, 15, 0x00000016, 0x0000000f
+}
+
+define_lms_params!{
+    /// LMS_SHAKE_M24_H15 with LMOTS_SHAKE_N24_W8
+    LMS_SHAKE_M24_H15_SHAKE_N24_W8, define_lms_shake, 24, 8, 15, 0x00000016, 0x00000010
+}
+
+define_lms_params!{
+    /// LMS_SHAKE_M24_H20 with LMOTS_SHAKE_N24_W1
+    LMS_SHAKE_M24_H20_SHAKE_N24_W1, define_lms_shake, 24, 1, 20, 0x00000017, 0x0000000d
+}
+
+define_lms_params!{
+    /// LMS_SHAKE_M24_H20 with LMOTS_SHAKE_N24_W2
+    LMS_SHAKE_M24_H20_SHAKE_N24_W2, define_lms_shake, 24, 2, 20, 0x00000017, 0x0000000e
+}
+
+define_lms_params!{
+    /// LMS_SHAKE_M24_H20 with LMOTS_SHAKE_N24_W4
+    LMS_SHAKE_M24_H20_SHAKE_N24_W4, define_lms_shake, 24, 4, 20, 0x00000017, 0x0000000f
+}
+
+define_lms_params!{
+    /// LMS_SHAKE_M24_H20 with LMOTS_SHAKE_N24_W8
+    LMS_SHAKE_M24_H20_SHAKE_N24_W8, define_lms_shake, 24, 8, 20, 0x00000017, 0x00000010
+}
+
+define_lms_params!{
+    /// LMS_SHAKE_M24_H25 with LMOTS_SHAKE_N24_W1
+    LMS_SHAKE_M24_H25_SHAKE_N24_W1, define_lms_shake, 24, 1, 25, 0x00000018, 0x0000000d
+}
+
+define_lms_params!{
+    /// LMS_SHAKE_M24_H25 with LMOTS_SHAKE_N24_W2
+    LMS_SHAKE_M24_H25_SHAKE_N24_W2, define_lms_shake, 24, 2, 25, 0x00000018, 0x0000000e
+}
+
+define_lms_params!{
+    /// LMS_SHAKE_M24_H25 with LMOTS_SHAKE_N24_W4
+    LMS_SHAKE_M24_H25_SHAKE_N24_W4, define_lms_shake, 24, 4, 25, 0x00000018, 0x0000000f
+}
+
+define_lms_params!{
+    /// LMS_SHAKE_M24_H25 with LMOTS_SHAKE_N24_W8
+    LMS_SHAKE_M24_H25_SHAKE_N24_W8, define_lms_shake, 24, 8, 25, 0x00000018, 0x00000010
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use sha2::{Sha256, Digest};
+    use crate::{CryptoRng, RngCore, RngError};
+
+    // A deterministic pseudo-random generator for tests (SHA-256
+    // in counter mode).
+    pub(super) struct DRNG {
+        pub(super) seed: [u8; 32],
+        pub(super) ctr: u64,
+    }
+
+    impl RngCore for DRNG {
+        fn next_u32(&mut self) -> u32 {
+            let mut buf = [0u8; 4];
+            self.fill_bytes(&mut buf);
+            u32::from_le_bytes(buf)
+        }
+        fn next_u64(&mut self) -> u64 {
+            let mut buf = [0u8; 8];
+            self.fill_bytes(&mut buf);
+            u64::from_le_bytes(buf)
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            for chunk in dst.chunks_mut(32) {
+                let mut sh = Sha256::new();
+                sh.update(self.seed);
+                sh.update(self.ctr.to_le_bytes());
+                self.ctr += 1;
+                chunk.copy_from_slice(&sh.finalize()[..chunk.len()]);
+            }
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), RngError> {
+            self.fill_bytes(dst);
+            Ok(())
+        }
+    }
+
+    impl CryptoRng for DRNG { }
+
+    // Signs and verifies a few messages with a fresh key.
+    macro_rules! check_sign { ($name:ident) => { {
+        let mut rng = DRNG { seed: [7u8; 32], ctr: 0 };
+        let mut sk = $name::PrivateKey::generate(&mut rng);
+        let pk = sk.compute_public();
+        let mut res = sk.reserve(3, |_| true).unwrap();
+        let sig1 = res.sign(&mut rng, b"sample").unwrap();
+        let sig2 = res.sign(&mut rng, b"other").unwrap();
+        assert!(pk.verify(&sig1, b"sample"));
+        assert!(pk.verify(&sig2, b"other"));
+        assert!(!pk.verify(&sig1, b"other"));
+        assert!(!pk.verify(&sig2, b"sample"));
+    } } }
+
+    #[test]
+    fn winternitz() {
+        check_sign!(LMS_SHA256_M32_H5_SHA256_N32_W1);
+        check_sign!(LMS_SHA256_M32_H5_SHA256_N32_W2);
+        check_sign!(LMS_SHA256_M32_H5_SHA256_N32_W4);
+        check_sign!(LMS_SHA256_M24_H5_SHA256_N24_W1);
+        check_sign!(LMS_SHA256_M24_H5_SHA256_N24_W2);
+        check_sign!(LMS_SHA256_M24_H5_SHA256_N24_W4);
+        check_sign!(LMS_SHAKE_M32_H5_SHAKE_N32_W1);
+        check_sign!(LMS_SHAKE_M24_H5_SHAKE_N24_W2);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn types() {
+        for (i, op) in ALL_LMS.iter().enumerate() {
+            assert!(core::ptr::eq(find_ops(op.typ.lms, op.typ.lmots).unwrap(), *op));
+            for op2 in ALL_LMS[..i].iter() {
+                assert!(op2.typ != op.typ);
+            }
+        }
+        assert!(LMS_SHA256_M32_H25_SHA256_N32_W1::TYPE
+            == LmsType { lms: 9, lmots: 1 });
+        assert!(LMS_SHAKE_M24_H25_SHAKE_N24_W4::TYPE
+            == LmsType { lms: 0x18, lmots: 0x0f });
+        assert!(find_ops(5, 5).is_none());
+    }
+
+    #[test]
+    fn traversal() {
+        // Use all leaves of a two-layer tree; some leaves are skipped.
+        use LMS_SHA256_M24_H10_SHA256_N24_W1::PrivateKey;
+        let mut rng = DRNG { seed: [8u8; 32], ctr: 0 };
+        let mut sk = PrivateKey::generate(&mut rng);
+        let pk = sk.compute_public();
+        let msg = b"sample";
+        let mut stored = [0u8; PrivateKey::ENC_LEN];
+        for k in 0..2 {
+            let count = if k == 0 { 510 } else { 400 };
+            {
+                let mut res = sk.reserve(count, |e| { stored = *e; true })
+                    .unwrap();
+                for _ in 0..count {
+                    let sig = res.sign(&mut rng, msg).unwrap();
+                    assert!(pk.verify(&sig, msg));
+                }
+            }
+            assert!(sk.reserve(50, |_| true).is_some());
+        }
+
+        // A decoded key produces the same signatures.
+        let mut sk2 = PrivateKey::decode(&stored).unwrap();
+        let mut rng1 = DRNG { seed: [9u8; 32], ctr: 0 };
+        let mut rng2 = DRNG { seed: [9u8; 32], ctr: 0 };
+        let mut res1 = sk.reserve(sk.remaining(), |_| true).unwrap();
+        assert!(sk2.reserve(50, |_| true).is_some());
+        let mut res2 = sk2.reserve(4, |_| true).unwrap();
+        for _ in 0..4 {
+            let sig1 = res1.sign(&mut rng1, msg).unwrap();
+            let sig2 = res2.sign(&mut rng2, msg).unwrap();
+            assert!(sig1 == sig2);
+        }
+        while let Some(sig) = res1.sign(&mut rng, msg) {
+            assert!(pk.verify(&sig, msg));
+        }
+    }
+
+    #[test]
+    fn traversal_three_layers() {
+        // Resume a three-layer tree before the end of the first subtree
+        // of the middle layer, and sign until after the end of the
+        // second one (which is built incrementally).
+        use LMS_SHA256_M24_H15_SHA256_N24_W1::{PrivateKey, PublicKey, TYPE};
+        let mut enc = [0u8; PrivateKey::ENC_LEN];
+        enc[0..4].copy_from_slice(&TYPE.lms.to_be_bytes());
+        enc[4..8].copy_from_slice(&TYPE.lmots.to_be_bytes());
+        enc[8..12].copy_from_slice(&1020u32.to_be_bytes());
+        enc[12..].copy_from_slice(&[0x33u8; 16 + 24]);
+        let mut sk = PrivateKey::decode(&enc).unwrap();
+        let pk = sk.compute_public();
+        let pk = PublicKey::decode(&pk.encode()).unwrap();
+        let mut rng = DRNG { seed: [10u8; 32], ctr: 0 };
+        let msg = b"sample";
+        let mut res = sk.reserve(1040, |_| true).unwrap();
+        for j in 0..1040u32 {
+            let sig = res.sign(&mut rng, msg).unwrap();
+            assert!(sig[0..4] == (1020 + j).to_be_bytes());
+            if !(8..1020).contains(&j) {
+                assert!(pk.verify(&sig, msg));
+            }
+        }
+    }
+}
+
+// ========================================================================
+
+/// HSS (hierarchical signature system, RFC 8554, section 6).
+///
+/// An HSS private key is a hierarchy of `L` LMS trees (1 to 8 levels);
+/// each level may use a different LMS parameter set. The top tree signs
+/// the public key of a tree at the next level, and so on; only the trees
+/// at the bottom level sign messages. The total number of signatures is
+/// 2 to the power of the sum of the tree heights; e.g. with four levels
+/// of height 5, there are 2^20 signatures per root key. Child trees are
+/// generated on demand when signing; only one tree per level is kept in
+/// memory at any time.
+///
+/// The secrets of child trees are derived deterministically from the
+/// root seed, so that the private key state consists only in the root
+/// seed and identifier, the parameter sets, and a 64-bit signature
+/// counter. As with plain LMS, signatures are produced through a
+/// `Reservation` obtained after committing the updated state. Signatures
+/// and public keys use the RFC 8554 wire format.
+#[cfg(feature = "alloc")]
+pub mod hss {
+
+    use super::{LmsType, LmsOps, HssLevel, find_ops};
+    use crate::{CryptoRng, RngCore, Box, Vec};
+    use core::convert::TryFrom;
+
+    /// Maximum number of levels in an HSS hierarchy.
+    pub const MAX_LEVELS: usize = 8;
+
+    /// An HSS private key.
+    ///
+    /// This type is neither `Copy` nor `Clone`, so that a given state
+    /// cannot be inadvertently duplicated; it does not implement `Debug`
+    /// either, so that the secret seed is not written into logs.
+    pub struct PrivateKey {
+        ops: Vec<&'static LmsOps>,
+        I: [u8; 16],
+        SEED: [u8; 32],
+        counter: u64,
+        capacity: u64,
+        // Current tree at each level.
+        levels: Vec<Box<dyn HssLevel>>,
+        // Leaf index, in level i, that signs the current tree at level i+1.
+        leaves: Vec<u32>,
+        // For level i < L-1, signature (by level i) of the public key of
+        // the current tree at level i+1, followed by that public key.
+        signed_pubs: Vec<Vec<u8>>,
+    }
+
+    /// An HSS public key.
+    #[derive(Clone, Debug)]
+    pub struct PublicKey {
+        enc: Vec<u8>,
+    }
+
+    /// A reservation of HSS signatures.
+    ///
+    /// A reservation is obtained from `PrivateKey::reserve()`, after the
+    /// updated private key state has been committed.
+    pub struct Reservation<'a> {
+        sk: &'a mut PrivateKey,
+        next: u64,
+        end: u64,
+    }
+
+    fn be32(buf: &[u8], off: usize) -> u32 {
+        u32::from_be_bytes(*<&[u8; 4]>::try_from(&buf[off..(off + 4)]).unwrap())
+    }
+
+    // Gets the operations for each level; this returns None if the
+    // number of levels is not in the 1 to MAX_LEVELS range, if a type is
+    // not supported, or if the total height exceeds 63.
+    fn get_ops(types: &[LmsType]) -> Option<(Vec<&'static LmsOps>, u64)> {
+        if types.is_empty() || types.len() > MAX_LEVELS {
+            return None;
+        }
+        let mut ops = Vec::with_capacity(types.len());
+        let mut th = 0;
+        for t in types.iter() {
+            let op = find_ops(t.lms, t.lmots)?;
+            th += op.height;
+            ops.push(op);
+        }
+        if th > 63 {
+            return None;
+        }
+        Some((ops, 1u64 << th))
+    }
+
+    impl PrivateKey {
+
+        /// Generates a new private key.
+        ///
+        /// The `types` slice contains the LMS parameter set for each level,
+        /// starting with the top level. This function returns `None` if
+        /// the number of levels is not between 1 and `MAX_LEVELS`, if one
+        /// of the parameter sets is not supported, or if the sum of the
+        /// tree heights exceeds 63.
+        pub fn generate<T: CryptoRng + RngCore>(rng: &mut T,
+            types: &[LmsType]) -> Option<Self>
+        {
+            let mut I = [0u8; 16];
+            let mut SEED = [0u8; 32];
+            rng.fill_bytes(&mut I);
+            rng.fill_bytes(&mut SEED);
+            Self::from_parts(types, I, SEED, 0)
+        }
+
+        fn from_parts(types: &[LmsType], I: [u8; 16], SEED: [u8; 32],
+            counter: u64) -> Option<Self>
+        {
+            let (ops, capacity) = get_ops(types)?;
+            if counter > capacity {
+                return None;
+            }
+            let root = (ops[0].new_level)(&I, &SEED);
+            let mut sk = Self {
+                ops, I, SEED, counter, capacity,
+                levels: Vec::new(),
+                leaves: Vec::new(),
+                signed_pubs: Vec::new(),
+            };
+            sk.levels.push(root);
+            let leaves = sk.leaf_indices(counter);
+            sk.rebuild_from(0, &leaves);
+            Some(sk)
+        }
+
+        // Gets the leaf index at each level for the signature with the
+        // provided index. If the counter is exhausted, the indices of the
+        // last signature are returned.
+        fn leaf_indices(&self, idx: u64) -> Vec<u32> {
+            let idx = core::cmp::min(idx, self.capacity - 1);
+            let mut r = Vec::with_capacity(self.ops.len());
+            let mut shift = 0;
+            for op in self.ops.iter().rev() {
+                r.push(((idx >> shift) & ((1u64 << op.height) - 1)) as u32);
+                shift += op.height;
+            }
+            r.reverse();
+            r
+        }
+
+        // Regenerates all trees below level i, so that level i signs with
+        // leaf leaves[i], and so on.
+        fn rebuild_from(&mut self, i: usize, leaves: &[u32]) {
+            let L = self.ops.len();
+            self.levels.truncate(i + 1);
+            self.leaves.truncate(i);
+            self.signed_pubs.truncate(i);
+            for (j, &q) in leaves.iter().enumerate().take(L - 1).skip(i) {
+                let (cI, cSEED) = self.levels[j].child(q);
+                let child = (self.ops[j + 1].new_level)(&cI, &cSEED);
+                let cpk = child.public_encoded();
+                let mut sp = self.levels[j].sign_encoded(q, None, &cpk);
+                sp.extend_from_slice(&cpk);
+                self.levels.push(child);
+                self.leaves.push(q);
+                self.signed_pubs.push(sp);
+            }
+        }
+
+        /// Gets the public key corresponding to this private key.
+        pub fn compute_public(&self) -> PublicKey {
+            let mut enc = Vec::with_capacity(4 + self.ops[0].pk_len);
+            enc.extend_from_slice(&(self.ops.len() as u32).to_be_bytes());
+            enc.extend_from_slice(&self.levels[0].public_encoded());
+            PublicKey { enc }
+        }
+
+        /// Gets the number of signatures that have not been reserved yet.
+        pub fn remaining(&self) -> u64 {
+            self.capacity - self.counter
+        }
+
+        /// Encodes this private key into bytes.
+        ///
+        /// The encoding is non-standard: number of levels (32 bits), LMS
+        /// and LM-OTS types for each level (32 bits each), signature
+        /// counter (64 bits), root identifier (16 bytes) and root seed
+        /// (32 bytes). All integers use big-endian.
+        pub fn encode(&self) -> Vec<u8> {
+            let mut buf = Vec::with_capacity(4 + 8 * self.ops.len() + 56);
+            buf.extend_from_slice(&(self.ops.len() as u32).to_be_bytes());
+            for op in self.ops.iter() {
+                buf.extend_from_slice(&op.typ.lms.to_be_bytes());
+                buf.extend_from_slice(&op.typ.lmots.to_be_bytes());
+            }
+            buf.extend_from_slice(&self.counter.to_be_bytes());
+            buf.extend_from_slice(&self.I);
+            buf.extend_from_slice(&self.SEED);
+            buf
+        }
+
+        /// Decodes a private key from bytes.
+        ///
+        /// The current tree at each level is regenerated. This function
+        /// returns `None` if the encoding is invalid, uses unsupported
+        /// parameter sets, or if the signature counter is out of range.
+        pub fn decode(buf: &[u8]) -> Option<Self> {
+            if buf.len() < 4 {
+                return None;
+            }
+            let L = be32(buf, 0) as usize;
+            if L == 0 || L > MAX_LEVELS || buf.len() != 4 + 8 * L + 56 {
+                return None;
+            }
+            let mut types = Vec::with_capacity(L);
+            for i in 0..L {
+                types.push(LmsType {
+                    lms: be32(buf, 4 + 8 * i),
+                    lmots: be32(buf, 8 + 8 * i),
+                });
+            }
+            let off = 4 + 8 * L;
+            let counter = u64::from_be_bytes(
+                *<&[u8; 8]>::try_from(&buf[off..(off + 8)]).unwrap());
+            let mut I = [0u8; 16];
+            let mut SEED = [0u8; 32];
+            I.copy_from_slice(&buf[(off + 8)..(off + 24)]);
+            SEED.copy_from_slice(&buf[(off + 24)..]);
+            Self::from_parts(&types, I, SEED, counter)
+        }
+
+        /// Reserves `count` signatures.
+        ///
+        /// The signature counter is advanced by `count`, and the encoded
+        /// updated private key is provided to `persist`, which must commit
+        /// it to stable storage. If `persist` returns `true`, then the
+        /// reservation is returned, and can be used to generate up to
+        /// `count` signatures. If `persist` returns `false`, then the
+        /// private key is left unchanged and `None` is returned. This
+        /// function also returns `None` (without calling `persist`) if
+        /// `count` is zero or greater than the number of remaining
+        /// signatures.
+        ///
+        /// As with LMS keys, reloading the committed state with `decode()`
+        /// recomputes the Merkle tree of every level (the cost is that of
+        /// key pair generation); the `PrivateKey` instance should be kept
+        /// in memory across reservations.
+        pub fn reserve<F>(&mut self, count: u64, persist: F)
+            -> Option<Reservation<'_>>
+            where F: FnOnce(&[u8]) -> bool
+        {
+            if count == 0 || count > self.remaining() {
+                return None;
+            }
+            let next = self.counter;
+            let end = next + count;
+            let mut enc = self.encode();
+            let off = 4 + 8 * self.ops.len();
+            enc[off..(off + 8)].copy_from_slice(&end.to_be_bytes());
+            if !persist(&enc) {
+                return None;
+            }
+            self.counter = end;
+            Some(Reservation { sk: self, next, end })
+        }
+    }
+
+    impl<'a> Reservation<'a> {
+
+        /// Gets the number of reserved signatures that have not been
+        /// used yet.
+        pub fn remaining(&self) -> u64 {
+            self.end - self.next
+        }
+
+        /// Signs a message with the next reserved signature index.
+        ///
+        /// Child trees are regenerated as needed. The signature uses the
+        /// RFC 8554 format. This function returns `None` if all reserved
+        /// signatures have already been used.
+        pub fn sign<T: CryptoRng + RngCore>(&mut self, rng: &mut T,
+            msg: &[u8]) -> Option<Vec<u8>>
+        {
+            if self.next >= self.end {
+                return None;
+            }
+            let idx = self.next;
+            self.next = idx + 1;
+            let sk = &mut *self.sk;
+            let L = sk.ops.len();
+            let leaves = sk.leaf_indices(idx);
+            if let Some(i) = (0..(L - 1)).find(|&i| sk.leaves[i] != leaves[i]) {
+                sk.rebuild_from(i, &leaves);
+            }
+            let mut rnd = [0u8; 32];
+            rng.fill_bytes(&mut rnd);
+            let mut sig = Vec::new();
+            sig.extend_from_slice(&((L - 1) as u32).to_be_bytes());
+            for sp in sk.signed_pubs.iter() {
+                sig.extend_from_slice(sp);
+            }
+            sig.extend_from_slice(
+                &sk.levels[L - 1].sign_encoded(leaves[L - 1], Some(&rnd), msg));
+            Some(sig)
+        }
+    }
+
+    impl PublicKey {
+
+        /// Encodes this public key into bytes (RFC 8554 format).
+        pub fn encode(&self) -> Vec<u8> {
+            self.enc.clone()
+        }
+
+        /// Decodes a public key from bytes (RFC 8554 format).
+        ///
+        /// This function returns `None` if the encoding is invalid, or
+        /// uses an unsupported LMS parameter set.
+        pub fn decode(buf: &[u8]) -> Option<Self> {
+            if buf.len() < 12 {
+                return None;
+            }
+            let L = be32(buf, 0) as usize;
+            if L == 0 || L > MAX_LEVELS {
+                return None;
+            }
+            let op = find_ops(be32(buf, 4), be32(buf, 8))?;
+            if buf.len() != 4 + op.pk_len {
+                return None;
+            }
+            Some(Self { enc: buf.to_vec() })
+        }
+
+        /// Verifies a signature (RFC 8554 format) on a message.
+        pub fn verify(&self, sig: &[u8], msg: &[u8]) -> bool {
+            hss_verify(&self.enc, sig, msg)
+        }
+    }
+
+    /// Verifies an HSS signature.
+    ///
+    /// Both the public key `pk` and the signature `sig` use the RFC 8554
+    /// wire format. The LMS parameter sets used at each level are taken
+    /// from the public key and signature; they must all be supported by
+    /// this implementation.
+    pub fn hss_verify(pk: &[u8], sig: &[u8], msg: &[u8]) -> bool {
+        if pk.len() < 12 || sig.len() < 4 {
+            return false;
+        }
+        let L = be32(pk, 0) as usize;
+        if L == 0 || L > MAX_LEVELS {
+            return false;
+        }
+        let Nspk = be32(sig, 0) as usize;
+        if Nspk != L - 1 {
+            return false;
+        }
+        let mut key = &pk[4..];
+        let mut kop = match find_ops(be32(key, 0), be32(key, 4)) {
+            Some(op) if op.pk_len == key.len() => op,
+            _ => return false,
+        };
+        let mut off = 4;
+        for _ in 0..Nspk {
+            // The signature length is given by the type of the key
+            // that verifies it.
+            if sig.len() < off + kop.sig_len + 8 {
+                return false;
+            }
+            let s = &sig[off..(off + kop.sig_len)];
+            off += kop.sig_len;
+            let cop = match find_ops(be32(sig, off), be32(sig, off + 4)) {
+                Some(op) => op,
+                None => return false,
+            };
+            if sig.len() < off + cop.pk_len {
+                return false;
+            }
+            let child = &sig[off..(off + cop.pk_len)];
+            off += cop.pk_len;
+            if !(kop.verify)(key, s, child) {
+                return false;
+            }
+            key = child;
+            kop = cop;
+        }
+        (kop.verify)(key, &sig[off..], msg)
+    }
+
+    #[cfg(test)]
+    mod tests {
+
+        use super::{PrivateKey, PublicKey, hss_verify};
+        use crate::lms::{LmsType, LMS_SHA256_M32_H5_SHA256_N32_W8,
+            LMS_SHA256_M24_H5_SHA256_N24_W8};
+        use crate::lms::tests::DRNG;
+        use crate::Vec;
+
+        #[test]
+        fn params() {
+            let mut rng = DRNG { seed: [1u8; 32], ctr: 0 };
+            let t = LMS_SHA256_M32_H5_SHA256_N32_W8::TYPE;
+            assert!(PrivateKey::generate(&mut rng, &[]).is_none());
+            assert!(PrivateKey::generate(&mut rng, &[t; 9]).is_none());
+            let bad = LmsType { lms: 5, lmots: 8 };
+            assert!(PrivateKey::generate(&mut rng, &[t, bad]).is_none());
+        }
+
+        #[test]
+        fn sign_verify() {
+            let mut rng = DRNG { seed: [2u8; 32], ctr: 0 };
+            let types = [
+                LMS_SHA256_M32_H5_SHA256_N32_W8::TYPE,
+                LMS_SHA256_M24_H5_SHA256_N24_W8::TYPE,
+            ];
+            let mut sk = PrivateKey::generate(&mut rng, &types).unwrap();
+            assert!(sk.remaining() == 1024);
+            let pk = sk.compute_public();
+            let epk = pk.encode();
+            assert!(epk.len() == 4 + 56);
+            assert!(epk[0..4] == 2u32.to_be_bytes());
+            let pk2 = PublicKey::decode(&epk).unwrap();
+            assert!(PublicKey::decode(&epk[1..]).is_none());
+
+            // Sign across the boundary between the first two child trees.
+            let mut stored = Vec::new();
+            assert!(sk.reserve(29, |_| true).is_some());
+            let msg = b"sample";
+            let mut sigs = Vec::new();
+            {
+                let mut res = sk.reserve(5, |e| { stored = e.to_vec(); true })
+                    .unwrap();
+                for _ in 0..5 {
+                    sigs.push(res.sign(&mut rng, msg).unwrap());
+                }
+                assert!(res.sign(&mut rng, msg).is_none());
+            }
+            assert!(sk.remaining() == 1024 - 34);
+            assert!(stored == sk.encode());
+            for (j, sig) in sigs.iter().enumerate() {
+                let idx = 29 + j as u32;
+                assert!(sig[0..4] == 1u32.to_be_bytes());
+                // Leaf index in the top tree, then in the bottom tree.
+                assert!(sig[4..8] == (idx >> 5).to_be_bytes());
+                let off = 4 + 1292 + 48;
+                assert!(sig[off..(off + 4)] == (idx & 31).to_be_bytes());
+                assert!(pk2.verify(sig, msg));
+                assert!(hss_verify(&epk, sig, msg));
+                assert!(!hss_verify(&epk, sig, b"other"));
+                assert!(!hss_verify(&epk, &sig[..(sig.len() - 1)], msg));
+                let mut bad = sig.clone();
+                bad[0..4].copy_from_slice(&0u32.to_be_bytes());
+                assert!(!hss_verify(&epk, &bad, msg));
+            }
+            // The signed child public key is the same for all signatures
+            // from the same child tree.
+            assert!(sigs[0][..1344] == sigs[2][..1344]);
+            assert!(sigs[2][..1344] != sigs[3][..1344]);
+
+            // Decoding the stored state yields the same key, which
+            // resumes after the reserved signatures.
+            let mut sk2 = PrivateKey::decode(&stored).unwrap();
+            assert!(sk2.compute_public().encode() == epk);
+            assert!(sk2.remaining() == 1024 - 34);
+            let sig = sk2.reserve(1, |_| true).unwrap()
+                .sign(&mut rng, msg).unwrap();
+            assert!(sig[..1344] == sigs[4][..1344]);
+            assert!(pk.verify(&sig, msg));
+            assert!(PrivateKey::decode(&stored[1..]).is_none());
+        }
+
+        #[test]
+        fn exhaust() {
+            let mut rng = DRNG { seed: [3u8; 32], ctr: 0 };
+            let types = [ LMS_SHA256_M24_H5_SHA256_N24_W8::TYPE ];
+            let mut sk = PrivateKey::generate(&mut rng, &types).unwrap();
+            let pk = sk.compute_public();
+            let msg = b"sample";
+            {
+                let mut res = sk.reserve(32, |_| true).unwrap();
+                for _ in 0..32 {
+                    let sig = res.sign(&mut rng, msg).unwrap();
+                    assert!(sig[0..4] == 0u32.to_be_bytes());
+                    assert!(pk.verify(&sig, msg));
+                }
+            }
+            assert!(sk.remaining() == 0);
+            assert!(sk.reserve(1, |_| true).is_none());
+            let sk2 = PrivateKey::decode(&sk.encode()).unwrap();
+            assert!(sk2.remaining() == 0);
+        }
+
+        // HSS test vectors: (public key, message, signature), all in the
+        // RFC 8554 wire format. The first two are the two-level test cases
+        // from RFC 8554, Appendix F; the next three are the test cases
+        // from draft-fluhrer-lms-more-parm-sets-11 for the SHA-256/192,
+        // SHAKE256/192 and SHAKE256/256 parameter sets of NIST SP 800-208
+        // (single LMS trees, wrapped as one-level HSS keys and signatures).
+        // The last three are from the liboqs known-answer tests
+        // (tests/KATs/sig_stfl/lms), for larger trees.
+        static KAT_HSS: [(&str, &str, &str); 8] = [
+            // RFC 8554, Appendix F, test case 1
+            (
+                "00000002000000050000000461a5d57d37f5e46bfb7520806b07a1b850650e3b\
+                31fe4a773ea29a07f09cf2ea30e579f0df58ef8e298da0434cb2b878",
+                "54686520706f77657273206e6f742064656c65676174656420746f2074686520\
+                556e69746564205374617465732062792074686520436f6e737469747574696f\
+                6e2c206e6f722070726f6869626974656420627920697420746f207468652053\
+                74617465732c2061726520726573657276656420746f20746865205374617465\
+                7320726573706563746976656c792c206f7220746f207468652070656f706c65\
+                2e0a",
+                "000000010000000500000004d32b56671d7eb98833c49b433c272586bc4a1c8a\
+                8970528ffa04b966f9426eb9965a25bfd37f196b9073f3d4a232feb69128ec45\
+                146f86292f9dff9610a7bf95a64c7f60f6261a62043f86c70324b7707f5b4a8a\
+                6e19c114c7be866d488778a0e05fd5c6509a6e61d559cf1a77a970de927d60c7\
+                0d3de31a7fa0100994e162a2582e8ff1b10cd99d4e8e413ef469559f7d7ed12c\
+                838342f9b9c96b83a4943d1681d84b15357ff48ca579f19f5e71f18466f2bbef\
+                4bf660c2518eb20de2f66e3b14784269d7d876f5d35d3fbfc7039a462c716bb9\
+                f6891a7f41ad133e9e1f6d9560b960e7777c52f060492f2d7c660e1471e07e72\
+                655562035abc9a701b473ecbc3943c6b9c4f2405a3cb8bf8a691ca51d3f6ad2f\
+                428bab6f3a30f55dd9625563f0a75ee390e385e3ae0b906961ecf41ae073a059\
+                0c2eb6204f44831c26dd768c35b167b28ce8dc988a3748255230cef99ebf14e7\
+                30632f27414489808afab1d1e783ed04516de012498682212b07810579b25036\
+                5941bcc98142da13609e9768aaf65de7620dabec29eb82a17fde35af15ad238c\
+                73f81bdb8dec2fc0e7f932701099762b37f43c4a3c20010a3d72e2f606be108d\
+                310e639f09ce7286800d9ef8a1a40281cc5a7ea98d2adc7c7400c2fe5a101552\
+                df4e3cccfd0cbf2ddf5dc6779cbbc68fee0c3efe4ec22b83a2caa3e48e0809a0\
+                a750b73ccdcf3c79e6580c154f8a58f7f24335eec5c5eb5e0cf01dcf44394240\
+                95fceb077f66ded5bec73b27c5b9f64a2a9af2f07c05e99e5cf80f00252e39db\
+                32f6c19674f190c9fbc506d826857713afd2ca6bb85cd8c107347552f30575a5\
+                417816ab4db3f603f2df56fbc413e7d0acd8bdd81352b2471fc1bc4f1ef296fe\
+                a1220403466b1afe78b94f7ecf7cc62fb92be14f18c2192384ebceaf8801afdf\
+                947f698ce9c6ceb696ed70e9e87b0144417e8d7baf25eb5f70f09f016fc925b4\
+                db048ab8d8cb2a661ce3b57ada67571f5dd546fc22cb1f97e0ebd1a65926b123\
+                4fd04f171cf469c76b884cf3115cce6f792cc84e36da58960c5f1d760f32c12f\
+                aef477e94c92eb75625b6a371efc72d60ca5e908b3a7dd69fef0249150e3eebd\
+                fed39cbdc3ce9704882a2072c75e13527b7a581a556168783dc1e97545e31865\
+                ddc46b3c957835da252bb7328d3ee2062445dfb85ef8c35f8e1f3371af34023c\
+                ef626e0af1e0bc017351aae2ab8f5c612ead0b729a1d059d02bfe18efa971b73\
+                00e882360a93b025ff97e9e0eec0f3f3f13039a17f88b0cf808f488431606cb1\
+                3f9241f40f44e537d302c64a4f1f4ab949b9feefadcb71ab50ef27d6d6ca8510\
+                f150c85fb525bf25703df7209b6066f09c37280d59128d2f0f637c7d7d7fad4e\
+                d1c1ea04e628d221e3d8db77b7c878c9411cafc5071a34a00f4cf07738912753\
+                dfce48f07576f0d4f94f42c6d76f7ce973e9367095ba7e9a3649b7f461d9f9ac\
+                1332a4d1044c96aefee67676401b64457c54d65fef6500c59cdfb69af7b6dddf\
+                cb0f086278dd8ad0686078dfb0f3f79cd893d314168648499898fbc0ced5f95b\
+                74e8ff14d735cdea968bee7400000005d8b8112f9200a5e50c4a262165bd342c\
+                d800b8496810bc716277435ac376728d129ac6eda839a6f357b5a04387c5ce97\
+                382a78f2a4372917eefcbf93f63bb59112f5dbe400bd49e4501e859f885bf073\
+                6e90a509b30a26bfac8c17b5991c157eb5971115aa39efd8d564a6b90282c316\
+                8af2d30ef89d51bf14654510a12b8a144cca1848cf7da59cc2b3d9d0692dd2a2\
+                0ba3863480e25b1b85ee860c62bf51360000000500000004d2f14ff6346af964\
+                569f7d6cb880a1b66c5004917da6eafe4d9ef6c6407b3db0e5485b122d9ebe15\
+                cda93cfec582d7ab0000000a000000040703c491e7558b35011ece3592eaa5da\
+                4d918786771233e8353bc4f62323185c95cae05b899e35dffd71705470620998\
+                8ebfdf6e37960bb5c38d7657e8bffeef9bc042da4b4525650485c66d0ce19b31\
+                7587c6ba4bffcc428e25d08931e72dfb6a120c5612344258b85efdb7db1db9e1\
+                865a73caf96557eb39ed3e3f426933ac9eeddb03a1d2374af7bf771855774562\
+                37f9de2d60113c23f846df26fa942008a698994c0827d90e86d43e0df7f4bfcd\
+                b09b86a373b98288b7094ad81a0185ac100e4f2c5fc38c003c1ab6fea479eb2f\
+                5ebe48f584d7159b8ada03586e65ad9c969f6aecbfe44cf356888a7b15a3ff07\
+                4f771760b26f9c04884ee1faa329fbf4e61af23aee7fa5d4d9a5dfcf43c4c26c\
+                e8aea2ce8a2990d7ba7b57108b47dabfbeadb2b25b3cacc1ac0cef346cbb90fb\
+                044beee4fac2603a442bdf7e507243b7319c9944b1586e899d431c7f91bcccc8\
+                690dbf59b28386b2315f3d36ef2eaa3cf30b2b51f48b71b003dfb08249484201\
+                043f65f5a3ef6bbd61ddfee81aca9ce60081262a00000480dcbc9a3da6fbef5c\
+                1c0a55e48a0e729f9184fcb1407c31529db268f6fe50032a363c9801306837fa\
+                fabdf957fd97eafc80dbd165e435d0e2dfd836a28b354023924b6fb7e48bc0b3\
+                ed95eea64c2d402f4d734c8dc26f3ac591825daef01eae3c38e3328d00a77dc6\
+                57034f287ccb0f0e1c9a7cbdc828f627205e4737b84b58376551d44c12c3c215\
+                c812a0970789c83de51d6ad787271963327f0a5fbb6b5907dec02c9a90934af5\
+                a1c63b72c82653605d1dcce51596b3c2b45696689f2eb382007497557692caac\
+                4d57b5de9f5569bc2ad0137fd47fb47e664fcb6db4971f5b3e07aceda9ac130e\
+                9f38182de994cff192ec0e82fd6d4cb7f3fe00812589b7a7ce51544045643301\
+                6b84a59bec6619a1c6c0b37dd1450ed4f2d8b584410ceda8025f5d2d8dd0d217\
+                6fc1cf2cc06fa8c82bed4d944e71339ece780fd025bd41ec34ebff9d4270a322\
+                4e019fcb444474d482fd2dbe75efb20389cc10cd600abb54c47ede93e08c114e\
+                db04117d714dc1d525e11bed8756192f929d15462b939ff3f52f2252da2ed64d\
+                8fae88818b1efa2c7b08c8794fb1b214aa233db3162833141ea4383f1a6f120b\
+                e1db82ce3630b3429114463157a64e91234d475e2f79cbf05e4db6a9407d72c6\
+                bff7d1198b5c4d6aad2831db61274993715a0182c7dc8089e32c8531deed4f74\
+                31c07c02195eba2ef91efb5613c37af7ae0c066babc69369700e1dd26eddc0d2\
+                16c781d56e4ce47e3303fa73007ff7b949ef23be2aa4dbf25206fe45c20dd888\
+                395b2526391a724996a44156beac808212858792bf8e74cba49dee5e8812e019\
+                da87454bff9e847ed83db07af313743082f880a278f682c2bd0ad6887cb59f65\
+                2e155987d61bbf6a88d36ee93b6072e6656d9ccbaae3d655852e38deb3a2dcf8\
+                058dc9fb6f2ab3d3b3539eb77b248a661091d05eb6e2f297774fe6053598457c\
+                c61908318de4b826f0fc86d4bb117d33e865aa805009cc2918d9c2f840c4da43\
+                a703ad9f5b5806163d7161696b5a0adc00000005d5c0d1bebb06048ed6fe2ef2\
+                c6cef305b3ed633941ebc8b3bec9738754cddd60e1920ada52f43d055b5031ce\
+                e6192520d6a5115514851ce7fd448d4a39fae2ab2335b525f484e9b40d6a4a96\
+                9394843bdcf6d14c48e8015e08ab92662c05c6e9f90b65a7a6201689999f32bf\
+                d368e5e3ec9cb70ac7b8399003f175c40885081a09ab3034911fe125631051df\
+                0408b3946b0bde790911e8978ba07dd56c73e7ee",
+            ),
+            // RFC 8554, Appendix F, test case 2
+            (
+                "000000020000000600000003d08fabd4a2091ff0a8cb4ed834e7453432a58885\
+                cd9ba0431235466bff9651c6c92124404d45fa53cf161c28f1ad5a8e",
+                "54686520656e756d65726174696f6e20696e2074686520436f6e737469747574\
+                696f6e2c206f66206365727461696e207269676874732c207368616c6c206e6f\
+                7420626520636f6e73747275656420746f2064656e79206f7220646973706172\
+                616765206f74686572732072657461696e6564206279207468652070656f706c\
+                652e0a",
+                "0000000100000003000000033d46bee8660f8f215d3f96408a7a64cf1c4da02b\
+                63a55f62c666ef5707a914ce0674e8cb7a55f0c48d484f31f3aa4af9719a74f2\
+                2cf823b94431d01c926e2a76bb71226d279700ec81c9e95fb11a0d10d065279a\
+                5796e265ae17737c44eb8c594508e126a9a7870bf4360820bdeb9a01d9693779\
+                e416828e75bddd7d8c70d50a0ac8ba39810909d445f44cb5bb58de737e60cb43\
+                45302786ef2c6b14af212ca19edeaa3bfcfe8baa6621ce88480df2371dd37add\
+                732c9de4ea2ce0dffa53c92649a18d39a50788f4652987f226a1d48168205df6\
+                ae7c58e049a25d4907edc1aa90da8aa5e5f7671773e941d8055360215c6b60dd\
+                35463cf2240a9c06d694e9cb54e7b1e1bf494d0d1a28c0d31acc75161f4f485d\
+                fd3cb9578e836ec2dc722f37ed30872e07f2b8bd0374eb57d22c614e09150f6c\
+                0d8774a39a6e168211035dc52988ab46eaca9ec597fb18b4936e66ef2f0df26e\
+                8d1e34da28cbb3af752313720c7b345434f72d65314328bbb030d0f0f6d5e47b\
+                28ea91008fb11b05017705a8be3b2adb83c60a54f9d1d1b2f476f9e393eb5695\
+                203d2ba6ad815e6a111ea293dcc21033f9453d49c8e5a6387f588b1ea4f70621\
+                7c151e05f55a6eb7997be09d56a326a32f9cba1fbe1c07bb49fa04cecf9df1a1\
+                b815483c75d7a27cc88ad1b1238e5ea986b53e087045723ce16187eda22e33b2\
+                c70709e53251025abde8939645fc8c0693e97763928f00b2e3c75af3942d8dda\
+                ee81b59a6f1f67efda0ef81d11873b59137f67800b35e81b01563d187c4a1575\
+                a1acb92d087b517a8833383f05d357ef4678de0c57ff9f1b2da61dfde5d88318\
+                bcdde4d9061cc75c2de3cd4740dd7739ca3ef66f1930026f47d9ebaa713b0717\
+                6f76f953e1c2e7f8f271a6ca375dbfb83d719b1635a7d8a13891957944b1c29b\
+                b101913e166e11bd5f34186fa6c0a555c9026b256a6860f4866bd6d0b5bf9062\
+                7086c6149133f8282ce6c9b3622442443d5eca959d6c14ca8389d12c4068b503\
+                e4e3c39b635bea245d9d05a2558f249c9661c0427d2e489ca5b5dde220a90333\
+                f4862aec793223c781997da98266c12c50ea28b2c438e7a379eb106eca0c7fd6\
+                006e9bf612f3ea0a454ba3bdb76e8027992e60de01e9094fddeb3349883914fb\
+                17a9621ab929d970d101e45f8278c14b032bcab02bd15692d21b6c5c204abbf0\
+                77d465553bd6eda645e6c3065d33b10d518a61e15ed0f092c32226281a29c8a0\
+                f50cde0a8c66236e29c2f310a375cebda1dc6bb9a1a01dae6c7aba8ebedc6371\
+                a7d52aacb955f83bd6e4f84d2949dcc198fb77c7e5cdf6040b0f84faf82808bf\
+                985577f0a2acf2ec7ed7c0b0ae8a270e951743ff23e0b2dd12e9c3c828fb5598\
+                a22461af94d568f29240ba2820c4591f71c088f96e095dd98beae456579ebbba\
+                36f6d9ca2613d1c26eee4d8c73217ac5962b5f3147b492e8831597fd89b64aa7\
+                fde82e1974d2f6779504dc21435eb3109350756b9fdabe1c6f368081bd40b27e\
+                bcb9819a75d7df8bb07bb05db1bab705a4b7e37125186339464ad8faaa4f052c\
+                c1272919fde3e025bb64aa8e0eb1fcbfcc25acb5f718ce4f7c2182fb393a1814\
+                b0e942490e52d3bca817b2b26e90d4c9b0cc38608a6cef5eb153af0858acc867\
+                c9922aed43bb67d7b33acc519313d28d41a5c6fe6cf3595dd5ee63f0a4c4065a\
+                083590b275788bee7ad875a7f88dd73720708c6c6c0ecf1f43bbaadae6f20855\
+                7fdc07bd4ed91f88ce4c0de842761c70c186bfdafafc444834bd3418be4253a7\
+                1eaf41d718753ad07754ca3effd5960b0336981795721426803599ed5b2b7516\
+                920efcbe32ada4bcf6c73bd29e3fa152d9adeca36020fdeeee1b739521d3ea8c\
+                0da497003df1513897b0f54794a873670b8d93bcca2ae47e64424b7423e1f078\
+                d9554bb5232cc6de8aae9b83fa5b9510beb39ccf4b4e1d9c0f19d5e17f58e5b8\
+                705d9a6837a7d9bf99cd13387af256a8491671f1f2f22af253bcff54b673199b\
+                db7d05d81064ef05f80f0153d0be7919684b23da8d42ff3effdb7ca0985033f3\
+                89181f47659138003d712b5ec0a614d31cc7487f52de8664916af79c98456b2c\
+                94a8038083db55391e3475862250274a1de2584fec975fb09536792cfbfcf619\
+                2856cc76eb5b13dc4709e2f7301ddff26ec1b23de2d188c999166c74e1e14bbc\
+                15f457cf4e471ae13dcbdd9c50f4d646fc6278e8fe7eb6cb5c94100fa8701873\
+                80b777ed19d7868fd8ca7ceb7fa7d5cc861c5bdac98e7495eb0a2ceec1924ae9\
+                79f44c5390ebedddc65d6ec11287d978b8df064219bc5679f7d7b264a76ff272\
+                b2ac9f2f7cfc9fdcfb6a51428240027afd9d52a79b647c90c2709e060ed70f87\
+                299dd798d68f4fadd3da6c51d839f851f98f67840b964ebe73f8cec41572538e\
+                c6bc131034ca2894eb736b3bda93d9f5f6fa6f6c0f03ce43362b8414940355fb\
+                54d3dfdd03633ae108f3de3ebc85a3ff51efeea3bc2cf27e1658f1789ee612c8\
+                3d0f5fd56f7cd071930e2946beeecaa04dccea9f97786001475e0294bc2852f6\
+                2eb5d39bb9fbeef75916efe44a662ecae37ede27e9d6eadfdeb8f8b2b2dbccbf\
+                96fa6dbaf7321fb0e701f4d429c2f4dcd153a2742574126e5eaccc77686acf6e\
+                3ee48f423766e0fc466810a905ff5453ec99897b56bc55dd49b991142f65043f\
+                2d744eeb935ba7f4ef23cf80cc5a8a335d3619d781e7454826df720eec82e060\
+                34c44699b5f0c44a8787752e057fa3419b5bb0e25d30981e41cb1361322dba8f\
+                69931cf42fad3f3bce6ded5b8bfc3d20a2148861b2afc14562ddd27f12897abf\
+                0685288dcc5c4982f826026846a24bf77e383c7aacab1ab692b29ed8c018a65f\
+                3dc2b87ff619a633c41b4fadb1c78725c1f8f922f6009787b1964247df0136b1\
+                bc614ab575c59a16d089917bd4a8b6f04d95c581279a139be09fcf6e98a470a0\
+                bceca191fce476f9370021cbc05518a7efd35d89d8577c990a5e19961ba16203\
+                c959c91829ba7497cffcbb4b294546454fa5388a23a22e805a5ca35f95659884\
+                8bda678615fec28afd5da61a00000006b326493313053ced3876db9d23714818\
+                1b7173bc7d042cefb4dbe94d2e58cd21a769db4657a103279ba8ef3a629ca84e\
+                e836172a9c50e51f45581741cf8083150b491cb4ecbbabec128e7c81a46e62a6\
+                7b57640a0a78be1cbf7dd9d419a10cd8686d16621a80816bfdb5bdc56211d72c\
+                a70b81f1117d129529a7570cf79cf52a7028a48538ecdd3b38d3d5d62d262465\
+                95c4fb73a525a5ed2c30524ebb1d8cc82e0c19bc4977c6898ff95fd3d310b0ba\
+                e71696cef93c6a552456bf96e9d075e383bb7543c675842bafbfc7cdb88483b3\
+                276c29d4f0a341c2d406e40d4653b7e4d045851acf6a0a0ea9c710b805cced46\
+                35ee8c107362f0fc8d80c14d0ac49c516703d26d14752f34c1c0d2c4247581c1\
+                8c2cf4de48e9ce949be7c888e9caebe4a415e291fd107d21dc1f084b11582082\
+                49f28f4f7c7e931ba7b3bd0d824a45700000000500000004215f83b7ccb9acbc\
+                d08db97b0d04dc2ba1cd035833e0e90059603f26e07ad2aad152338e7a5e5984\
+                bcd5f7bb4eba40b700000004000000040eb1ed54a2460d512388cad533138d24\
+                0534e97b1e82d33bd927d201dfc24ebb11b3649023696f85150b189e50c00e98\
+                850ac343a77b3638319c347d7310269d3b7714fa406b8c35b021d54d4fdada7b\
+                9ce5d4ba5b06719e72aaf58c5aae7aca057aa0e2e74e7dcfd17a0823429db629\
+                65b7d563c57b4cec942cc865e29c1dad83cac8b4d61aacc457f336e6a10b6632\
+                3f5887bf3523dfcadee158503bfaa89dc6bf59daa82afd2b5ebb2a9ca6572a60\
+                67cee7c327e9039b3b6ea6a1edc7fdc3df927aade10c1c9f2d5ff446450d2a39\
+                98d0f9f6202b5e07c3f97d2458c69d3c8190643978d7a7f4d64e97e3f1c4a08a\
+                7c5bc03fd55682c017e2907eab07e5bb2f190143475a6043d5e6d5263471f4ee\
+                cf6e2575fbc6ff37edfa249d6cda1a09f797fd5a3cd53a066700f45863f04b6c\
+                8a58cfd341241e002d0d2c0217472bf18b636ae547c1771368d9f317835c9b0e\
+                f430b3df4034f6af00d0da44f4af7800bc7a5cf8a5abdb12dc718b559b74cab9\
+                090e33cc58a955300981c420c4da8ffd67df540890a062fe40dba8b2c1c548ce\
+                d22473219c534911d48ccaabfb71bc71862f4a24ebd376d288fd4e6fb06ed870\
+                5787c5fedc813cd2697e5b1aac1ced45767b14ce88409eaebb601a93559aae89\
+                3e143d1c395bc326da821d79a9ed41dcfbe549147f71c092f4f3ac522b5cc572\
+                90706650487bae9bb5671ecc9ccc2ce51ead87ac01985268521222fb9057df7e\
+                d41810b5ef0d4f7cc67368c90f573b1ac2ce956c365ed38e893ce7b2fae15d36\
+                85a3df2fa3d4cc098fa57dd60d2c9754a8ade980ad0f93f6787075c3f680a2ba\
+                1936a8c61d1af52ab7e21f416be09d2a8d64c3d3d8582968c2839902229f85ae\
+                e297e717c094c8df4a23bb5db658dd377bf0f4ff3ffd8fba5e383a48574802ed\
+                545bbe7a6b4753533353d73706067640135a7ce517279cd683039747d218647c\
+                86e097b0daa2872d54b8f3e5085987629547b830d8118161b65079fe7bc59a99\
+                e9c3c7380e3e70b7138fe5d9be2551502b698d09ae193972f27d40f38dea264a\
+                0126e637d74ae4c92a6249fa103436d3eb0d4029ac712bfc7a5eacbdd7518d6d\
+                4fe903a5ae65527cd65bb0d4e9925ca24fd7214dc617c150544e423f450c99ce\
+                51ac8005d33acd74f1bed3b17b7266a4a3bb86da7eba80b101e15cb79de9a207\
+                852cf91249ef480619ff2af8cabca83125d1faa94cbb0a03a906f683b3f47a97\
+                c871fd513e510a7a25f283b196075778496152a91c2bf9da76ebe089f4654877\
+                f2d586ae7149c406e663eadeb2b5c7e82429b9e8cb4834c83464f079995332e4\
+                b3c8f5a72bb4b8c6f74b0d45dc6c1f79952c0b7420df525e37c15377b5f09843\
+                19c3993921e5ccd97e097592064530d33de3afad5733cbe7703c5296263f7734\
+                2efbf5a04755b0b3c997c4328463e84caa2de3ffdcd297baaaacd7ae646e44b5\
+                c0f16044df38fabd296a47b3a838a913982fb2e370c078edb042c84db34ce36b\
+                46ccb76460a690cc86c302457dd1cde197ec8075e82b393d542075134e2a17ee\
+                70a5e187075d03ae3c853cff60729ba4000000054de1f6965bdabc676c5a4dc7\
+                c35f97f82cb0e31c68d04f1dad96314ff09e6b3de96aeee300d1f68bf1bca9fc\
+                58e4032336cd819aaf578744e50d1357a0e4286704d341aa0a337b19fe4bc43c\
+                2e79964d4f351089f2e0e41c7c43ae0d49e7f404b0f75be80ea3af098c975242\
+                0a8ac0ea2bbb1f4eeba05238aef0d8ce63f0c6e5e4041d95398a6f7f3e0ee97c\
+                c1591849d4ed236338b147abde9f51ef9fd4e1c1",
+            ),
+            // draft-fluhrer-lms-more-parm-sets-11, test case 1 (SHA-256/192)
+            (
+                "000000010000000a00000008202122232425262728292a2b2c2d2e2f2c571450\
+                aed99cfb4f4ac285da14882796618314508b12d2",
+                "54657374206d65737361676520666f72205348413235362d3139320a",
+                "0000000000000005000000080b5040a18c1b5cabcbc85b047402ec6294a30dd8\
+                da8fc3dae13b9f0875f09361dc77fcc4481ea463c073716249719193614b835b\
+                4694c059f12d3aedd34f3db93f3580fb88743b8b3d0648c0537b7a50e433d7ea\
+                9d6672fffc5f42770feab4f98eb3f3b23fd2061e4d0b38f832860ae76673ad1a\
+                1a52a9005dcf1bfb56fe16ff723627612f9a48f790f3c47a67f870b81e919d99\
+                919c8db48168838cece0abfb683da48b9209868be8ec10c63d8bf80d36498dfc\
+                205dc45d0dd870572d6d8f1d90177cf5137b8bbf7bcb67a46f86f26cfa5a44cb\
+                caa4e18da099a98b0b3f96d5ac8ac375d8da2a7c248004ba11d7ac775b921835\
+                9cddab4cf8ccc6d54cb7e1b35a36ddc9265c087063d2fc6742a7177876476a32\
+                4b03295bfed99f2eaf1f38970583c1b2b616aad0f31cd7a4b1bb0a51e477e94a\
+                01bbb4d6f8866e2528a159df3d6ce244d2b6518d1f0212285a3c2d4a927054a1\
+                e1620b5b02aab0c8c10ed48ae518ea73cba81fcfff88bff461dac51e7ab4ca75\
+                f47a6259d24820b9995792d139f61ae2a8186ae4e3c9bfe0af2cc717f424f41a\
+                a67f03faedb0665115f2067a46843a4cbbd297d5e83bc1aafc18d1d03b3d894e\
+                8595a6526073f02ab0f08b99fd9eb208b59ff6317e5545e6f9ad5f9c183abd04\
+                3d5acd6eb2dd4da3f02dbc3167b468720a4b8b92ddfe7960998bb7a0ecf2a26a\
+                37598299413f7b2aecd39a30cec527b4d9710c4473639022451f50d01c045712\
+                5da0fa4429c07dad859c846cbbd93ab5b91b01bc770b089cfede6f651e86dd7c\
+                15989c8b5321dea9ca608c71fd862323072b827cee7a7e28e4e2b999647233c3\
+                456944bb7aef9187c96b3f5b79fb98bc76c3574dd06f0e95685e5b3aef3a54c4\
+                155fe3ad817749629c30adbe897c4f4454c86c490000000ae9ca10eaa811b22a\
+                e07fb195e3590a334ea64209942fbae338d19f152182c807d3c40b189d3fcbea\
+                942f44682439b191332d33ae0b761a2a8f984b56b2ac2fd4ab08223a69ed1f77\
+                19c7aa7e9eee96504b0e60c6bb5c942d695f0493eb25f80a5871cffd131d0e04\
+                ffe5065bc7875e82d34b40b69dd9f3c1",
+            ),
+            // draft-fluhrer-lms-more-parm-sets-11, test case 2 (SHAKE256/192)
+            (
+                "000000010000001400000010505152535455565758595a5b5c5d5e5fdb54a450\
+                9901051c01e26d9990e550347986da87924ff0b1",
+                "54657374206d65737361676520666f72205348414b453235362d3139320a",
+                "00000000000000060000001084219da9ce9fffb16edb94527c6d10565587db28\
+                062deac4208e62fc4fbe9d85deb3c6bd2c01640accb387d8a6093d68511234a6\
+                a1a50108091c034cb1777e02b5df466149a66969a498e4200c0a0c1bf5d100cd\
+                b97d2dd40efd3cada278acc5a570071a043956112c6deebd1eb3a7b56f5f6791\
+                515a7b5ffddb0ec2d9094bfbc889ea15c3c7b9bea953efb75ed648f535b9acab\
+                66a2e9631e426e4e99b733caa6c55963929b77fec54a7e703d8162e736875cb6\
+                a455d4a9015c7a6d8fd5fe75e402b47036dc3770f4a1dd0a559cb478c7fb1726\
+                005321be9d1ac2de94d731ee4ca79cff454c811f46d11980909f047b2005e84b\
+                6e15378446b1ca691efe491ea98acc9d3c0f785caba5e2eb3c306811c240ba22\
+                802923827d582639304a1e9783ba5bc9d69d999a7db8f749770c3c04a152856d\
+                c726d8067921465b61b3f847b13b2635a45379e5adc6ff58a99b00e60ac767f7\
+                f30175f9f7a140257e218be307954b1250c9b41902c4fa7c90d8a592945c66e8\
+                6a76defcb84500b55598a1990faaa10077c74c94895731585c8f900de1a1c675\
+                bd8b0c180ebe2b5eb3ef8019ece3e1ea7223eb7906a2042b6262b4aa25c4b8a0\
+                5f205c8befeef11ceff1282508d71bc2a8cfa0a99f73f3e3a74bb4b3c0d8ca2a\
+                bd0e1c2c17dafe18b4ee2298e87bcfb1305b3c069e6d385569a4067ed547486d\
+                d1a50d6f4a58aab96e2fa883a9a39e1bd45541eee94efc32faa9a94be66dc853\
+                8b2dab05aee5efa6b3b2efb3fd020fe789477a93afff9a3e636dbba864a5bffa\
+                3e28d13d49bb597d94865bde88c4627f206ab2b465084d6b780666e952f8710e\
+                fd748bd0f1ae8f1035087f5028f14affcc5fffe332121ae4f87ac5f1eac90626\
+                08c7d87708f1723f38b23237a4edf4b49a5cd3d700000014dd4bdc8f928fb526\
+                f6fb7cdb944a7ebaa7fb05d995b5721a27096a5007d82f79d063acd434a04e97\
+                f61552f7f81a9317b4ec7c87a5ed10c881928fc6ebce6dfce9daae9cc9dba690\
+                7ca9a9dd5f9f573704d5e6cf22a43b04e64c1ffc7e1c442ecb495ba265f465c5\
+                6291a902e62a461f6dfda232457fad14",
+            ),
+            // draft-fluhrer-lms-more-parm-sets-11, test case 3 (SHAKE256/256)
+            (
+                "000000010000000f0000000c808182838485868788898a8b8c8d8e8f9bb7faee\
+                411cae806c16a466c3191a8b65d0ac31932bbf0c2d07c7a4a36379fe",
+                "54657374206d657361676520666f72205348414b453235362d3235360a",
+                "00000000000000070000000cb82709f0f00e83759190996233d1ee4f4ec50534\
+                473c02ffa145e8ca2874e32b16b228118c62b96c9c77678b33183730debaade8\
+                fe607f05c6697bc971519a341d69c00129680b67e75b3bd7d8aa5c8b71f02669\
+                d177a2a0eea896dcd1660f16864b302ff321f9c4b8354408d06760504f768ebd\
+                4e545a9b0ac058c575078e6c1403160fb45450d61a9c8c81f6bd69bdfa26a16e\
+                12a265baf79e9e233eb71af634ecc66dc88e10c6e0142942d4843f70a0242727\
+                bc5a2aabf7b0ec12a99090d8caeef21303f8ac58b9f200371dc9e41ab956e1a3\
+                efed9d4bbb38975b46c28d5f5b3ed19d847bd0a737177263cbc1a2262d40e808\
+                15ee149b6cce2714384c9b7fceb3bbcbd25228dda8306536376f8793ecadd602\
+                0265dab9075f64c773ef97d07352919995b74404cc69a6f3b469445c9286a6b2\
+                c9f6dc839be76618f053de763da3571ef70f805c9cc54b8e501a98b98c70785e\
+                eb61737eced78b0e380ded4f769a9d422786def59700eef3278017babbe5f906\
+                3b468ae0dd61d94f9f99d5cc36fbec4178d2bda3ad31e1644a2bcce208d72d50\
+                a7637851aa908b94dc4376120d5beab0fb805e1945c41834dd6085e6db1a3aa7\
+                8fcb59f62bde68236a10618cff123abe64dae8dabb2e84ca705309c2ab986d4f\
+                8326ba0642272cb3904eb96f6f5e3bb8813997881b6a33cac0714e4b5e7a882a\
+                d87e141931f97d612b84e903e773139ae377f5ba19ac86198d485fca97742568\
+                f6ff758120a89bf19059b8a6bfe2d86b12778164436ab2659ba866767fcc4355\
+                84125fb7924201ee67b535daf72c5cb31f5a0b1d926324c26e67d4c3836e301a\
+                a09bae8fb3f91f1622b1818ccf440f52ca9b5b9b99aba8a6754aae2b967c4954\
+                fa85298ad9b1e74f27a46127c36131c8991f0cc2ba57a15d35c91cf8bc48e8e2\
+                0d625af4e85d8f9402ec44afbd4792b924b839332a64788a7701a30094b9ec4b\
+                9f4b648f168bf457fbb3c9594fa87920b645e42aa2fecc9e21e000ca7d3ff914\
+                e15c40a8bc533129a7fd39529376430f355aaf96a0a13d13f2419141b3cc2584\
+                3e8c90d0e551a355dd90ad770ea7255214ce11238605de2f000d200104d0c3a3\
+                e35ae64ea10a3eff37ac7e9549217cdf52f307172e2f6c7a2a4543e143140365\
+                25b1ad53eeaddf0e24b1f36914ed22483f2889f61e62b6fb78f5645bdbb02c9e\
+                5bf97db7a0004e87c2a55399b61958786c97bd52fa199c27f6bb4d68c4907933\
+                562755bfec5d4fb52f06c289d6e852cf6bc773ffd4c07ee2d6cc55f57edcfbc8\
+                e8692a49ad47a121fe3c1b16cab1cc285faf6793ffad7a8c341a49c5d2dce706\
+                9e464cb90a00b2903648b23c81a68e21d748a7e7b1df8a593f3894b2477e8316\
+                947ca725d141135202a9442e1db33bbd390d2c04401c39b253b78ce297b0e147\
+                55e46ec08a146d279c67af70de256890804d83d6ec5ca3286f1fca9c72abf6ef\
+                868e7f6eb0fddda1b040ecec9bbc69e2fd8618e9db3bdb0af13dda06c6617e95\
+                afa522d6a2552de15324d99119f55e9af11ae3d5614b564c642dbfec6c644198\
+                ce80d2433ac8ee738f9d825e0000000f71d585a35c3a908379f4072d070311db\
+                5d65b242b714bc5a756ba5e228abfa0d1329978a05d5e815cf4d74c1e547ec4a\
+                a3ca956ae927df8b29fb9fab3917a7a4ae61ba57e5342e9db12caf6f6dbc5253\
+                de5268d4b0c4ce4ebe6852f012b162fc1c12b9ffc3bcb1d3ac8589777655e22c\
+                d9b99ff1e4346fd0efeaa1da044692e7ad6bfc337db69849e54411df8920c228\
+                a2b7762c11e4b1c49efb74486d3931ea",
+            ),
+            // liboqs, LMS_SHA256_M32_H10 with LMOTS_SHA256_N32_W4
+            (
+                "0000000100000006000000031fc1a45b518c620d9ecff3b054dc2104f35f80df\
+                fa2515819e569eaf3594081dd7029a581ae1e818b181368d1cabd76e",
+                "54686520706f77657273206e6f742064656c65676174656420746f2074686520\
+                556e69746564205374617465732062792074686520436f6e737469747574696f\
+                6e2c206e6f722070726f6869626974656420627920697420746f207468652053\
+                74617465732c2061726520726573657276656420746f20746865205374617465\
+                7320726573706563746976656c792c206f7220746f207468652070656f706c65\
+                2e2e0a0a",
+                "000000000000000100000003584a33ceb2d50a9af04409357774dbaf8b8ea517\
+                370e62c4dc06882c1407baca174157ebbde27d8e255d5c522c78617f3cfa03b8\
+                11e32593ca965f771f8d88fb3059a272c3b9af8e445b37650f3616b9a06d2a79\
+                e74e847dceddcb6f854fbae8e6790d241e5e496a66244ae738bca8482630b536\
+                9e2c45e27c7ed919fa298c1d5ca5efdde0a12b258a584c9f44799b5c40f3889b\
+                1e8f3c274a355e24c088bcdb5288a47bf40a4677062662f4feef135493ea4498\
+                640c8efca349dbedbf5525af5425a8aabbfb671de5f71072eb07867e15c383a0\
+                6f39ca2a09ae0d53c4db18370011d4ab6842a35c3bab93e642bee89310ac457e\
+                68869f4289f9e93ba553e39892b2969578ca5a02ae8a0272f1b2d6c8a022dac6\
+                a397d84bd2ea56136ea45aaccc1122aca34bd4ba6a7a06b2cfede81ce193ff87\
+                8368f9f2181b8116185558cee1cac048f61109ae5c7b3973241919d9974f8b44\
+                dde66f1a8b2830abebf3ba1a115f4563453fe20f7ac023f6a02544d24b38e661\
+                a25a68ccb44c287f28d3fb0769048eee6d8f41046423d77e56344f7d5771f8eb\
+                acda4a4b5069f0c4746a74e77aa8b5615257b2dbac9863937e6d9c905217341a\
+                825c0460dce7a13845db6449d1e1f86d42731df8eb6f4381d71dff4defc11fa5\
+                d6bc9a0309a47e298dcf55e559c750f3c5d0fc1a1fedc8c8c0248a7babf6390f\
+                bcf70ad7b51259b336a2a39e1306968abb7f39a94a36374910ffa96a436a2254\
+                36f7753eb7a28198750c3b5deffa1092eeec7857244b4a4d13d1eec3adf0efcc\
+                e3ea75507f376a6c7effcd9e904ef2bd7b3c8c092066d46955c7ca38ed79ca8a\
+                7ffb1617d1619026c8fcb3cdc23c7403cc3f90e3c3d88123b20d40e8763c0aec\
+                caadf79c41a69667cdbdfb73b33642f1d1e3b021cf2400e6252526d3fe62edc9\
+                d022e88ef111b6b5b668d2b20e23baccd9746668fb63f3916630506d0b696d85\
+                0dcc60eec42504398f4d25e2eb6f36a417bd71c20c780f1a5075f86e953302ef\
+                0b7a6a9b909857d0ee6fd4c1ade224cf667067d9df328433b19e136bed536e36\
+                4f82ac05705439967560c8606cd1c26b7146b82e825a233dca703ce03ab7df5c\
+                5345b5285be16d542a47a1fb4af74e786cbfd01c0456b8f86fd0e68513761722\
+                4ff627b5f3dfbb75c9258484d9347ad6282e170c2657f5721e24d5643a4705d6\
+                f9aa335294278421da783d44ac38cfc7676740a399f9002ecac9a78b41c70c31\
+                88298dca26452b72a8d582d167c3c965d5f5c8c8f57022f417b0941f78287219\
+                51978d568a3d2ee0b7a843e5c697b0c7fc0975f0f09fd18856a16fc63db04e0f\
+                3ce3a357e4d3208546460814c270629d7810e452454927f29666a919fc8fb104\
+                f3c9c1fd38c19c6574d4dddb5c18683a8973cd061faf5f5c18b66734bf8c0876\
+                6612897d0d9025e380c6317d88791d0861484e51f205c14e0bd83d2d61c394df\
+                3645eb0d66c80ded707429fe5e1fa77f3c3389ac59b353ca657e6aa88dcf6498\
+                479e76bbba01e75e9d992e6d3181eccd53e122c0c3ed428d809eef91934ee657\
+                364151bf711f0c922b6490fa2a9ea9ef02a9fad3a655cd305a2ccff01f60b9ca\
+                0b3e8381cd83960c08c066a30a9f46585e7ba143da7700216cb5234406301beb\
+                66a0cecd7d8122429992f8eab74cebaa1e7c8ce4d54587056bdad8636c46326e\
+                484c63b5aef8b6560e289acc20b953e8bac254da2f22751d83845be6132c6595\
+                cbf91b0d2ff7402fd789c92249af031afc1076bc99b9e9cd6e42a1a2dcf8baa4\
+                2a0c34dc343508e70bb0760e5f9d8ec6390995bbca810dff3a0474c59cf9fafd\
+                9d09bf190bbafe003c7576960a6dcfa0226f874a084b4a50d6a680f88f1f3f35\
+                2c71eb26df521e5ac9c2c6cc410c51ecbfcd090d7c09541810c80cafa5710c84\
+                9ed324ee734a8ec0610a1a0ccda429f86b28ca0d0cfdc7d29ec03ea1b97021db\
+                b00a7837d5ec8f6e6ae198ae31c9b4365aec42e83e92e879fb641915ca129324\
+                176444171873a75b3df1322cc74796b5e6cac0f950d92c322a8a3c6c0a824f8f\
+                92ae30aa110c8a415687ce6dae5135b4121b67fe4e5bff65fda4e0cde3372a09\
+                37488d22211c92192eebb9c0b38770356fc65533a86efb75a759fcb02c62aff2\
+                f61d3af079ce09d6b01ec9d6fd3fa6f5e2f60273f4b9c3de7bed546311557c19\
+                7dd2c5398853a5b768db5d45b64d2860137c1c1e9bf3bbaf8f44cda1ba1c8879\
+                8bf91c962195a11d1d43b937801243ad3483d951ce3c250de463aa136273c9fb\
+                9404ea92a204ad15a161852182d271e6dccce0c1385eb8a192d063b0a018cfa3\
+                6d8357d89f1257aef5be8be3f26051dcd238e4977324204029f2d70a4dd2a9e9\
+                cdef1831c7163276bcfff2f2530f29dff5dac9a7026f3ebf9ee0449465a61db5\
+                72b18434dcbd30fb0843ed81560473f8529191b25abb086d5ee06022b10a64b3\
+                a1ff18aeeccf277c4b33c9ecf4097bd5097e6bd0a30ad4d52cf5db089d15f462\
+                7a44e0689781a6c6a8ce08d744123f6364b1e7428aea701b20985f2ef76cdb30\
+                23297c894c7f14892a3e891f396a3edc2507892034a142556e78deba31ee286f\
+                0e2752eea28a0322789fecdeec35306d87105ca2c19f977597bdce1de1f8cc1f\
+                00c45ab2ecc7dd6f2d8e8e7f625be39a2348e77688819ed541d2c12ed74688d2\
+                01fa307d958ebc4b8ce08a6b0e9a3116b89a038807e587bceb9cc98cb91d69f8\
+                84c67e88e30c7c8d72b208dca96f6af60a73c4444d5e7f5b472fd2ca3b36cfa2\
+                b4bcb767acea4d8085b080e57f76ccc0dce2c2943b6c806ce20c88300bed34a3\
+                83e5bbcdfad496910b9198bd43364da92d1fec89b90b53ba54ac634d5ac1f7fa\
+                f0385358b6b0b5f5d06e31df6d3b17eb9181cfa131897dacbde42ae3c66010b9\
+                78ea391944db2b52e52e47ff7312b1600e569b46859bfb6af8c4402ccaa3632e\
+                b293ced20d61552c75f0068b5a0a7d71f276367f0061f01e7fe71985ecd70fea\
+                79d406217f42260791f554b1a1b943e5312ee65251ac228d78c90f517949f646\
+                0dd3664f09b092f483d6ef2c00000006ecf1ce60192137cb466675f2310292d4\
+                62477d811c672cb1890ffff4979ded0aa791c87c602307be68e7e3ed69e59b7c\
+                22458d9d49f353679f1f89cba91cd886900b1eb3666e5f0e78dec37a110c5cf0\
+                9c1571f66e5862d48f6f4dc01812fb600ab2b3ddb1c5585cb2127e4a5e5faf00\
+                636beeeafb3b18f2481de5a4dbb9182e93a5cad39c43bab87f66eefd1428d0a4\
+                26e0ee8c41fab4baf40bb982b9b5f1ce9eb65ad914744b45a4f00c11dc8fb012\
+                844cde05816d91fd387be9a2087c3758634e4ae3658a035e9b9e2d50a98ecb21\
+                489779c538aac7fde32b293e4f809494a800bb16c5ad2e8d4f22d61fadd2cf82\
+                bcc9ac210bada1a203f90f67b115cca1f9c109cc955776335779e6d9153a9880\
+                c91c9af9eac9e88b7a5e5bceb3bba9ec9432fe015b672ce4c04cc49ec42b8650\
+                38b7166de32c1f745c27ee919bc25d0a",
+            ),
+            // liboqs, LMS_SHA256_M32_H20 with LMOTS_SHA256_N32_W8
+            (
+                "0000000100000008000000042fd1d7b686c84cbe1eaaf415f6095268787d809d\
+                3b8d9cd021a0967b3972c847cfdc0935346b1ce9f3f6e21a2fcde0cd",
+                "54686520706f77657273206e6f742064656c65676174656420746f2074686520\
+                556e69746564205374617465732062792074686520436f6e737469747574696f\
+                6e2c206e6f722070726f6869626974656420627920697420746f207468652053\
+                74617465732c2061726520726573657276656420746f20746865205374617465\
+                7320726573706563746976656c792c206f7220746f207468652070656f706c65\
+                2e2e0a0a",
+                "000000000000000000000004c77901fbdf3723a0e739e33dad60d6438b3a33ad\
+                fc72c33f3e1a3e13791a362aa3a1cf96aefb864bcab9cf5714de25794d8d5942\
+                8eb4585ba22ccb719b12dd7301bb456156130d9c8d34e1624025b402dca6c4d2\
+                d5462ef59c5842a9aa242c72cdf29daa26527f5f529c01af89066ed129477f27\
+                96f7a2bbf91233f4bd3a9f658e995fcf6221a40547c442f3f5dad46aecff3a4b\
+                061bd72c2bd725af87940805954d9b9cef4395fe8e914c736cff67cb8f481c5e\
+                9842ddfb95a4c7f9f1310c3bdf951acc06337ca482b677992f207e11fc3520c9\
+                23d69fcf8b863bf113531e0266246adbd818300bce8ab72b081d453f109bb298\
+                e72bd499614f9e609a12bd1e7171187b65e70e9f175b7f03eec038f2faeea0a6\
+                c13058264f3b21ef4cfd27c3502eccaf94c68060a468353f1e3c111226bf3baa\
+                e1af78d783c91c32a344ea5b1e3875f1571cf381e4a142f154c749a572ee5873\
+                95b1bc49df8c979623dd8b4b09bf1622d7bd1846bf5f65d7be5f28f69a40eb9c\
+                b0f25cb30b1090205f85b067870d2abebc20f7bb67a35afe9eb9e1c8f9d90d32\
+                b18728b313e055c22671682ae85de2277aebfa94380bbc7ca11c090430cc5196\
+                95f3af0c5667a3595bf565e7b1114e069d9343132d36adc42043cf0ff1d075f8\
+                2147d4f8baa9e38e68ba8aa9170c855f3c6e3be4840dc92f65cb5f2e1e24730b\
+                bf6d4f73349c3d85e4507b8c4f39bc8b07eeee8f2fff240b07858d391a9e24d3\
+                4d7f219bad1688546106ecdc177589ea3cda62c02b3c862d7ec1e995e6555beb\
+                0d2fecb6d6d86c232dc76f063fb90b1bb4f20116bafdd7436825a53fcd26d154\
+                4e71a1feeb0b154ea298948c3ba9ba0adeacdd46860c6d28da3630b36acc8913\
+                ecfbc1288417513b8988d977613b93fe7c5c883a4df6096ca35875f24b4e2f7c\
+                6c5cf715aa9428069b365e1a121323556113a0ed9a2b8c1cbb944eba56e4917a\
+                e1dd6dca34dbb4098a52651af8a8b32a0416b85a9ec8c3e305268c518f713edb\
+                977fd6b17cac7fe85d574003bc5133b590d117e5b471a0abe73f96a069b0162b\
+                afd492a01900cb3c9bf6ffa6c30f11e9d1ce0eb5385187990f54387a1f66be91\
+                b8e9cc09acc5cf0545da67470ffbe95b856f90335573b14cb487787a1440166a\
+                4b5249c8108b7d7e07f340f2a30f986cbc2b1e3055cf425dde138deb3fb12a9b\
+                b2cfae7020b2503ff50840ad2f026282bb9725e82e310cb2c32a1fc1e7291817\
+                f7539055d8b61335643d2a6754033df4326da389ed5f83a6a1c88125975548fb\
+                213a2fd7287d98e8d815f42524e10ad9117cdbf88464a8354a96e6c1127167f7\
+                cf00088440501d660e670bf98e174cbb5bef986899bd2ed23f0051d1d776e6f4\
+                eaf6a8b4c973f12fc85b218c17d346dc8e8f820f71716219165fecc90ac1101a\
+                58d0325a586e9a2fed1a2ca2a96b86c94892551712aa032e2a474315ef6e0aea\
+                bb2d92356a65e55ccdd371f79a5e6f59e902667731512f1faf7bbbc3ed307927\
+                323b1a51f52268d696be32066cdb859662fdb69f8e2a718462537a6129873775\
+                52f3e34919ff9e044ebfef0f000000088688c398c528e063a27c2654d8ef6fcf\
+                86cccf6111384367569f78e0c5544ac0c32f502398b9fb43c94ce6b0c2fdff38\
+                7ca058d8b3d70edf444567347c32180e5f579935bee605d8d59580c7092b374c\
+                e27758f3fabbdc1210c52e7cdea694281ba308427afff30f71588a9280f7ebc5\
+                6fe9b80c84ea65867cbe443ce760d0dfa3c7889846873ca76dcf464c7ac41ce0\
+                82605d115f02ac350289557bf7afa58157cbed69a0dee6991af022ce6cd54867\
+                c1a05e71949fef85c941fca240e0cb67ea053725b7fa8de0ef248b9203f3de20\
+                2fad5415112aa621ffaa5504fe4d20929b4f761d9b8621a63726e079b36c2e04\
+                bff0782374e2cb103c54842768c164ee77668753efebca2f6f075f47002f7dfb\
+                3689e27f1096236cd904645108e7ee25a0f6f3a24ca2db67b53a13a5e9c39601\
+                d526b5bf1658c9e9ce2b80f0a6eda7ddeb8811c6458dcea902dad8c1e0da4854\
+                59d01a9349bebc7e03c7da4366fd53ed29b30c7bb9d28d758c5574f7347cc02e\
+                1becabc07964ef20bb27950f4352c92382eba52126a33d4fbe947a1a70dcc622\
+                115e4a1e61d283cb7e21a26d43f5ef2317acba85a2aafd6fcdc6fd902bf7821d\
+                e2b21f252d73efb0f632e46097ed8b9d7c859175c8b4c54d7509ed221131702f\
+                ef73ca28d9f25f7e1e915be840ff3e56b8d09323f93da0a6a041b5fe66a19107\
+                07f5a06c805efe8cbbbcef36a20680cfbbc4d793bf7ba9be868e290e4fe99c1f\
+                44eff6f1444775ce1f2c2bf2479b38db8029024f7cca496cc0c1d4013f96a179\
+                1e73b72d4220d649d7333761aad155a45e23e00b83bf3c80848c4c3bef451b33\
+                102338b11e4fa27c0e63800f1fb10c72029c3034e04d606bed563c0a001f5ad8\
+                c718d4056acce6076a1a652b28699928",
+            ),
+            // liboqs, two levels: LMS_SHA256_M32_H10 with
+            // LMOTS_SHA256_N32_W4, then LMS_SHA256_M32_H5 with LMOTS_SHA256_N32_W8
+            (
+                "000000020000000600000003d08fabd4a2091ff0a8cb4ed834e7453432a58885\
+                cd9ba0431235466bff9651c6c92124404d45fa53cf161c28f1ad5a8e",
+                "54686520656e756d65726174696f6e20696e2074686520436f6e737469747574\
+                696f6e2c206f66206365727461696e207269676874732c207368616c6c206e6f\
+                7420626520636f6e73747275656420746f2064656e79206f7220646973706172\
+                616765206f74686572732072657461696e6564206279207468652070656f706c\
+                652e0a",
+                "0000000100000003000000033d46bee8660f8f215d3f96408a7a64cf1c4da02b\
+                63a55f62c666ef5707a914ce0674e8cb7a55f0c48d484f31f3aa4af9719a74f2\
+                2cf823b94431d01c926e2a76bb71226d279700ec81c9e95fb11a0d10d065279a\
+                5796e265ae17737c44eb8c594508e126a9a7870bf4360820bdeb9a01d9693779\
+                e416828e75bddd7d8c70d50a0ac8ba39810909d445f44cb5bb58de737e60cb43\
+                45302786ef2c6b14af212ca19edeaa3bfcfe8baa6621ce88480df2371dd37add\
+                732c9de4ea2ce0dffa53c92649a18d39a50788f4652987f226a1d48168205df6\
+                ae7c58e049a25d4907edc1aa90da8aa5e5f7671773e941d8055360215c6b60dd\
+                35463cf2240a9c06d694e9cb54e7b1e1bf494d0d1a28c0d31acc75161f4f485d\
+                fd3cb9578e836ec2dc722f37ed30872e07f2b8bd0374eb57d22c614e09150f6c\
+                0d8774a39a6e168211035dc52988ab46eaca9ec597fb18b4936e66ef2f0df26e\
+                8d1e34da28cbb3af752313720c7b345434f72d65314328bbb030d0f0f6d5e47b\
+                28ea91008fb11b05017705a8be3b2adb83c60a54f9d1d1b2f476f9e393eb5695\
+                203d2ba6ad815e6a111ea293dcc21033f9453d49c8e5a6387f588b1ea4f70621\
+                7c151e05f55a6eb7997be09d56a326a32f9cba1fbe1c07bb49fa04cecf9df1a1\
+                b815483c75d7a27cc88ad1b1238e5ea986b53e087045723ce16187eda22e33b2\
+                c70709e53251025abde8939645fc8c0693e97763928f00b2e3c75af3942d8dda\
+                ee81b59a6f1f67efda0ef81d11873b59137f67800b35e81b01563d187c4a1575\
+                a1acb92d087b517a8833383f05d357ef4678de0c57ff9f1b2da61dfde5d88318\
+                bcdde4d9061cc75c2de3cd4740dd7739ca3ef66f1930026f47d9ebaa713b0717\
+                6f76f953e1c2e7f8f271a6ca375dbfb83d719b1635a7d8a13891957944b1c29b\
+                b101913e166e11bd5f34186fa6c0a555c9026b256a6860f4866bd6d0b5bf9062\
+                7086c6149133f8282ce6c9b3622442443d5eca959d6c14ca8389d12c4068b503\
+                e4e3c39b635bea245d9d05a2558f249c9661c0427d2e489ca5b5dde220a90333\
+                f4862aec793223c781997da98266c12c50ea28b2c438e7a379eb106eca0c7fd6\
+                006e9bf612f3ea0a454ba3bdb76e8027992e60de01e9094fddeb3349883914fb\
+                17a9621ab929d970d101e45f8278c14b032bcab02bd15692d21b6c5c204abbf0\
+                77d465553bd6eda645e6c3065d33b10d518a61e15ed0f092c32226281a29c8a0\
+                f50cde0a8c66236e29c2f310a375cebda1dc6bb9a1a01dae6c7aba8ebedc6371\
+                a7d52aacb955f83bd6e4f84d2949dcc198fb77c7e5cdf6040b0f84faf82808bf\
+                985577f0a2acf2ec7ed7c0b0ae8a270e951743ff23e0b2dd12e9c3c828fb5598\
+                a22461af94d568f29240ba2820c4591f71c088f96e095dd98beae456579ebbba\
+                36f6d9ca2613d1c26eee4d8c73217ac5962b5f3147b492e8831597fd89b64aa7\
+                fde82e1974d2f6779504dc21435eb3109350756b9fdabe1c6f368081bd40b27e\
+                bcb9819a75d7df8bb07bb05db1bab705a4b7e37125186339464ad8faaa4f052c\
+                c1272919fde3e025bb64aa8e0eb1fcbfcc25acb5f718ce4f7c2182fb393a1814\
+                b0e942490e52d3bca817b2b26e90d4c9b0cc38608a6cef5eb153af0858acc867\
+                c9922aed43bb67d7b33acc519313d28d41a5c6fe6cf3595dd5ee63f0a4c4065a\
+                083590b275788bee7ad875a7f88dd73720708c6c6c0ecf1f43bbaadae6f20855\
+                7fdc07bd4ed91f88ce4c0de842761c70c186bfdafafc444834bd3418be4253a7\
+                1eaf41d718753ad07754ca3effd5960b0336981795721426803599ed5b2b7516\
+                920efcbe32ada4bcf6c73bd29e3fa152d9adeca36020fdeeee1b739521d3ea8c\
+                0da497003df1513897b0f54794a873670b8d93bcca2ae47e64424b7423e1f078\
+                d9554bb5232cc6de8aae9b83fa5b9510beb39ccf4b4e1d9c0f19d5e17f58e5b8\
+                705d9a6837a7d9bf99cd13387af256a8491671f1f2f22af253bcff54b673199b\
+                db7d05d81064ef05f80f0153d0be7919684b23da8d42ff3effdb7ca0985033f3\
+                89181f47659138003d712b5ec0a614d31cc7487f52de8664916af79c98456b2c\
+                94a8038083db55391e3475862250274a1de2584fec975fb09536792cfbfcf619\
+                2856cc76eb5b13dc4709e2f7301ddff26ec1b23de2d188c999166c74e1e14bbc\
+                15f457cf4e471ae13dcbdd9c50f4d646fc6278e8fe7eb6cb5c94100fa8701873\
+                80b777ed19d7868fd8ca7ceb7fa7d5cc861c5bdac98e7495eb0a2ceec1924ae9\
+                79f44c5390ebedddc65d6ec11287d978b8df064219bc5679f7d7b264a76ff272\
+                b2ac9f2f7cfc9fdcfb6a51428240027afd9d52a79b647c90c2709e060ed70f87\
+                299dd798d68f4fadd3da6c51d839f851f98f67840b964ebe73f8cec41572538e\
+                c6bc131034ca2894eb736b3bda93d9f5f6fa6f6c0f03ce43362b8414940355fb\
+                54d3dfdd03633ae108f3de3ebc85a3ff51efeea3bc2cf27e1658f1789ee612c8\
+                3d0f5fd56f7cd071930e2946beeecaa04dccea9f97786001475e0294bc2852f6\
+                2eb5d39bb9fbeef75916efe44a662ecae37ede27e9d6eadfdeb8f8b2b2dbccbf\
+                96fa6dbaf7321fb0e701f4d429c2f4dcd153a2742574126e5eaccc77686acf6e\
+                3ee48f423766e0fc466810a905ff5453ec99897b56bc55dd49b991142f65043f\
+                2d744eeb935ba7f4ef23cf80cc5a8a335d3619d781e7454826df720eec82e060\
+                34c44699b5f0c44a8787752e057fa3419b5bb0e25d30981e41cb1361322dba8f\
+                69931cf42fad3f3bce6ded5b8bfc3d20a2148861b2afc14562ddd27f12897abf\
+                0685288dcc5c4982f826026846a24bf77e383c7aacab1ab692b29ed8c018a65f\
+                3dc2b87ff619a633c41b4fadb1c78725c1f8f922f6009787b1964247df0136b1\
+                bc614ab575c59a16d089917bd4a8b6f04d95c581279a139be09fcf6e98a470a0\
+                bceca191fce476f9370021cbc05518a7efd35d89d8577c990a5e19961ba16203\
+                c959c91829ba7497cffcbb4b294546454fa5388a23a22e805a5ca35f95659884\
+                8bda678615fec28afd5da61a00000006b326493313053ced3876db9d23714818\
+                1b7173bc7d042cefb4dbe94d2e58cd21a769db4657a103279ba8ef3a629ca84e\
+                e836172a9c50e51f45581741cf8083150b491cb4ecbbabec128e7c81a46e62a6\
+                7b57640a0a78be1cbf7dd9d419a10cd8686d16621a80816bfdb5bdc56211d72c\
+                a70b81f1117d129529a7570cf79cf52a7028a48538ecdd3b38d3d5d62d262465\
+                95c4fb73a525a5ed2c30524ebb1d8cc82e0c19bc4977c6898ff95fd3d310b0ba\
+                e71696cef93c6a552456bf96e9d075e383bb7543c675842bafbfc7cdb88483b3\
+                276c29d4f0a341c2d406e40d4653b7e4d045851acf6a0a0ea9c710b805cced46\
+                35ee8c107362f0fc8d80c14d0ac49c516703d26d14752f34c1c0d2c4247581c1\
+                8c2cf4de48e9ce949be7c888e9caebe4a415e291fd107d21dc1f084b11582082\
+                49f28f4f7c7e931ba7b3bd0d824a45700000000500000004215f83b7ccb9acbc\
+                d08db97b0d04dc2ba1cd035833e0e90059603f26e07ad2aad152338e7a5e5984\
+                bcd5f7bb4eba40b700000004000000040eb1ed54a2460d512388cad533138d24\
+                0534e97b1e82d33bd927d201dfc24ebb11b3649023696f85150b189e50c00e98\
+                850ac343a77b3638319c347d7310269d3b7714fa406b8c35b021d54d4fdada7b\
+                9ce5d4ba5b06719e72aaf58c5aae7aca057aa0e2e74e7dcfd17a0823429db629\
+                65b7d563c57b4cec942cc865e29c1dad83cac8b4d61aacc457f336e6a10b6632\
+                3f5887bf3523dfcadee158503bfaa89dc6bf59daa82afd2b5ebb2a9ca6572a60\
+                67cee7c327e9039b3b6ea6a1edc7fdc3df927aade10c1c9f2d5ff446450d2a39\
+                98d0f9f6202b5e07c3f97d2458c69d3c8190643978d7a7f4d64e97e3f1c4a08a\
+                7c5bc03fd55682c017e2907eab07e5bb2f190143475a6043d5e6d5263471f4ee\
+                cf6e2575fbc6ff37edfa249d6cda1a09f797fd5a3cd53a066700f45863f04b6c\
+                8a58cfd341241e002d0d2c0217472bf18b636ae547c1771368d9f317835c9b0e\
+                f430b3df4034f6af00d0da44f4af7800bc7a5cf8a5abdb12dc718b559b74cab9\
+                090e33cc58a955300981c420c4da8ffd67df540890a062fe40dba8b2c1c548ce\
+                d22473219c534911d48ccaabfb71bc71862f4a24ebd376d288fd4e6fb06ed870\
+                5787c5fedc813cd2697e5b1aac1ced45767b14ce88409eaebb601a93559aae89\
+                3e143d1c395bc326da821d79a9ed41dcfbe549147f71c092f4f3ac522b5cc572\
+                90706650487bae9bb5671ecc9ccc2ce51ead87ac01985268521222fb9057df7e\
+                d41810b5ef0d4f7cc67368c90f573b1ac2ce956c365ed38e893ce7b2fae15d36\
+                85a3df2fa3d4cc098fa57dd60d2c9754a8ade980ad0f93f6787075c3f680a2ba\
+                1936a8c61d1af52ab7e21f416be09d2a8d64c3d3d8582968c2839902229f85ae\
+                e297e717c094c8df4a23bb5db658dd377bf0f4ff3ffd8fba5e383a48574802ed\
+                545bbe7a6b4753533353d73706067640135a7ce517279cd683039747d218647c\
+                86e097b0daa2872d54b8f3e5085987629547b830d8118161b65079fe7bc59a99\
+                e9c3c7380e3e70b7138fe5d9be2551502b698d09ae193972f27d40f38dea264a\
+                0126e637d74ae4c92a6249fa103436d3eb0d4029ac712bfc7a5eacbdd7518d6d\
+                4fe903a5ae65527cd65bb0d4e9925ca24fd7214dc617c150544e423f450c99ce\
+                51ac8005d33acd74f1bed3b17b7266a4a3bb86da7eba80b101e15cb79de9a207\
+                852cf91249ef480619ff2af8cabca83125d1faa94cbb0a03a906f683b3f47a97\
+                c871fd513e510a7a25f283b196075778496152a91c2bf9da76ebe089f4654877\
+                f2d586ae7149c406e663eadeb2b5c7e82429b9e8cb4834c83464f079995332e4\
+                b3c8f5a72bb4b8c6f74b0d45dc6c1f79952c0b7420df525e37c15377b5f09843\
+                19c3993921e5ccd97e097592064530d33de3afad5733cbe7703c5296263f7734\
+                2efbf5a04755b0b3c997c4328463e84caa2de3ffdcd297baaaacd7ae646e44b5\
+                c0f16044df38fabd296a47b3a838a913982fb2e370c078edb042c84db34ce36b\
+                46ccb76460a690cc86c302457dd1cde197ec8075e82b393d542075134e2a17ee\
+                70a5e187075d03ae3c853cff60729ba4000000054de1f6965bdabc676c5a4dc7\
+                c35f97f82cb0e31c68d04f1dad96314ff09e6b3de96aeee300d1f68bf1bca9fc\
+                58e4032336cd819aaf578744e50d1357a0e4286704d341aa0a337b19fe4bc43c\
+                2e79964d4f351089f2e0e41c7c43ae0d49e7f404b0f75be80ea3af098c975242\
+                0a8ac0ea2bbb1f4eeba05238aef0d8ce63f0c6e5e4041d95398a6f7f3e0ee97c\
+                c1591849d4ed236338b147abde9f51ef9fd4e1c1",
+            ),
+        ];
+
+        #[test]
+        fn kat_hss() {
+            for &(epk, emsg, esig) in KAT_HSS.iter() {
+                let pk = hex::decode(epk).unwrap();
+                let msg = hex::decode(emsg).unwrap();
+                let sig = hex::decode(esig).unwrap();
+                assert!(hss_verify(&pk, &sig, &msg));
+                let pk2 = PublicKey::decode(&pk).unwrap();
+                assert!(pk2.encode() == pk);
+                assert!(pk2.verify(&sig, &msg));
+
+                let mut msg2 = msg.clone();
+                msg2[0] ^= 0x01;
+                assert!(!hss_verify(&pk, &sig, &msg2));
+                let mut sig2 = sig.clone();
+                let n = sig2.len();
+                sig2[n - 1] ^= 0x01;
+                assert!(!hss_verify(&pk, &sig2, &msg));
+                sig2[n - 1] ^= 0x01;
+                sig2.push(0);
+                assert!(!hss_verify(&pk, &sig2, &msg));
+                assert!(!hss_verify(&pk, &sig[..(n - 1)], &msg));
+                // Wrong number of signed public keys.
+                let mut sig3 = sig.clone();
+                sig3[3] ^= 0x01;
+                assert!(!hss_verify(&pk, &sig3, &msg));
+            }
+        }
+    }
+}
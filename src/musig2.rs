@@ -0,0 +1,1093 @@
+//! MuSig2 multi-signatures over secp256k1.
+//!
+//! This module implements the MuSig2 protocol, as specified in
+//! [BIP-327]. A group of signers, each with their own secp256k1 key
+//! pair, aggregate their public keys into a single public key; they can
+//! then conjointly compute, in two rounds, a signature relatively to
+//! that aggregate key. Output signatures are plain [BIP-340] Schnorr
+//! signatures, that can be verified with
+//! `secp256k1::XOnlyPublicKey::verify()`. All signers must participate
+//! (this is an n-of-n scheme); for threshold signatures, see the `frost`
+//! module.
+//!
+//! The following types are defined:
+//!
+//!  - `KeyAggContext`: the result of the aggregation of the signers'
+//!    public keys, possibly with some subsequent tweaks (either "plain"
+//!    or "x-only" tweaks, the latter being used for BIP-341 Taproot
+//!    outputs).
+//!
+//!  - `SecNonce`: a per-signature secret nonce, generated by a signer
+//!    at the start of the first round; it MUST NOT be reused (signing
+//!    with a `SecNonce` clears it).
+//!
+//!  - `PubNonce`: the public nonce corresponding to a `SecNonce`; each
+//!    signer broadcasts it during the first round.
+//!
+//!  - `AggNonce`: the aggregation of all public nonces.
+//!
+//!  - `Session`: the state for the second round, computed from the key
+//!    aggregation context, the aggregate nonce and the message.
+//!
+//!  - `PartialSig`: a signature share, computed by a signer in the
+//!    second round. Partial signatures can be individually verified,
+//!    then assembled into the final signature with `Session::aggregate()`.
+//!
+//! All the types that are meant to be transmitted or stored have
+//! encoding and decoding functions; encoding functions return a
+//! fixed-size array of bytes (of length `ENC_LEN`), while decoding
+//! functions return an `Option` type. `SecNonce` is not one of them: it
+//! cannot be encoded, since a stored nonce could be decoded and used
+//! twice, which would leak the private key; it must be kept in memory
+//! between the two rounds. Encoding formats for `PubNonce`, `AggNonce`
+//! and `PartialSig` are those of BIP-327. The
+//! `KeyAggContext` and `Session` encodings are specific to this
+//! implementation; they allow a signer to save the session state between
+//! the two rounds without keeping the complete list of public keys.
+//!
+//! Signers' public keys are handled as `secp256k1::PublicKey` instances
+//! (the BIP-327 "plain" public keys, encoded in compressed format over
+//! 33 bytes). Contrary to the BIP-327 `Sign` algorithm, `Session::sign()`
+//! does not verify that the signer's public key is part of the aggregated
+//! keys, since the key list is not kept in the context.
+//!
+//! Operations involving secret values are constant-time. Verification
+//! of partial signatures is not constant-time (it involves only public
+//! values).
+//!
+//! [BIP-327]: https://github.com/bitcoin/bips/blob/master/bip-0327.mediawiki
+//! [BIP-340]: https://github.com/bitcoin/bips/blob/master/bip-0340.mediawiki
+
+#![allow(non_snake_case)]
+
+use super::secp256k1::{Point, Scalar, PrivateKey, PublicKey, XOnlyPublicKey};
+use super::secp256k1::{bswap32, tagged_sha256, schnorr_challenge};
+use super::{CryptoRng, RngCore};
+use sha2::Digest;
+
+/// A key aggregation context.
+///
+/// This contains the aggregate public key Q, and the accumulated sign
+/// (`gacc`) and tweak (`tacc`) values from the tweaks applied so far.
+/// It also contains the hash of the list of public keys, and the
+/// "second key" of that list, which are needed to compute the key
+/// aggregation coefficient of each signer.
+#[derive(Clone, Copy, Debug)]
+pub struct KeyAggContext {
+    Q: Point,
+    gacc: Scalar,
+    tacc: Scalar,
+    pk_hash: [u8; 32],
+    pk2: [u8; 33],
+}
+
+/// A per-signature secret nonce.
+///
+/// It contains the two secret scalars (k1, k2), and the signer's public
+/// key (compressed encoding). This type is neither `Copy` nor `Clone`
+/// (and does not implement `Debug`), so that a nonce cannot be
+/// inadvertently duplicated and used in two signatures.
+pub struct SecNonce {
+    k1: Scalar,
+    k2: Scalar,
+    pk: [u8; 33],
+}
+
+/// A per-signature public nonce.
+///
+/// It contains two points, which are never the neutral.
+#[derive(Clone, Copy, Debug)]
+pub struct PubNonce {
+    R1: Point,
+    R2: Point,
+}
+
+/// An aggregate nonce.
+///
+/// It contains two points, which may be the neutral.
+#[derive(Clone, Copy, Debug)]
+pub struct AggNonce {
+    R1: Point,
+    R2: Point,
+}
+
+/// A signing session.
+///
+/// The session is computed from the key aggregation context, the
+/// aggregate nonce and the message. It contains the nonce coefficient
+/// (`b`), the final nonce point (`R`) and the challenge (`e`).
+#[derive(Clone, Copy, Debug)]
+pub struct Session {
+    keyagg: KeyAggContext,
+    b: Scalar,
+    R: Point,
+    e: Scalar,
+}
+
+/// A partial signature.
+#[derive(Clone, Copy, Debug)]
+pub struct PartialSig {
+    s: Scalar,
+}
+
+/// Encodes a scalar into 32 bytes (unsigned big-endian convention).
+fn scalar_encode_be(x: Scalar) -> [u8; 32] {
+    bswap32(&x.encode())
+}
+
+/// Decodes a scalar from 32 bytes (unsigned big-endian convention);
+/// values not lower than the curve order are rejected.
+fn scalar_decode_be(buf: &[u8]) -> Option<Scalar> {
+    if buf.len() != 32 {
+        return None;
+    }
+    let (x, r) = Scalar::decode32(&bswap32(buf));
+    if r != 0 {
+        Some(x)
+    } else {
+        None
+    }
+}
+
+/// Decodes a point from its "extended compressed" format (33 bytes);
+/// 33 bytes of value zero are interpreted as the neutral point.
+fn point_decode_ext(buf: &[u8]) -> Option<Point> {
+    if buf.iter().all(|&x| x == 0) {
+        Some(Point::NEUTRAL)
+    } else {
+        let P = Point::decode(buf)?;
+        if P.isneutral() != 0 {
+            return None;
+        }
+        Some(P)
+    }
+}
+
+/// Computes the hash of an encoded key and the list hash (the
+/// BIP-327 `KeyAggCoeffInternal()` function).
+fn key_agg_coeff_internal(pk_hash: &[u8; 32], pk2: &[u8; 33], pk: &[u8; 33])
+    -> Scalar
+{
+    if pk == pk2 {
+        return Scalar::ONE;
+    }
+    let mut sh = tagged_sha256(b"KeyAgg coefficient");
+    sh.update(pk_hash);
+    sh.update(pk);
+    Scalar::decode_reduce(&bswap32(&sh.finalize()))
+}
+
+impl KeyAggContext {
+
+    /// Encoded context length (in bytes).
+    pub const ENC_LEN: usize = 162;
+
+    /// Aggregates some public keys.
+    ///
+    /// The order of the public keys matters: the same keys in a
+    /// different order yield a different aggregate key. Duplicate keys
+    /// are allowed. This function returns `None` if the list of keys is
+    /// empty, or if the aggregate key is the neutral point (which
+    /// cannot happen unless some keys were specially crafted).
+    pub fn new(pubkeys: &[PublicKey]) -> Option<Self> {
+        if pubkeys.is_empty() {
+            return None;
+        }
+
+        // L = hash_{KeyAgg list}(pk_1 || pk_2 || ... || pk_u)
+        let mut sh = tagged_sha256(b"KeyAgg list");
+        for pk in pubkeys.iter() {
+            sh.update(pk.encode_compressed());
+        }
+        let pk_hash: [u8; 32] = sh.finalize().into();
+
+        // The second key is the first one which differs from the first
+        // key in the list. If there is no such key, then we use 33 bytes
+        // of value zero (which never match an encoded key).
+        let pk1 = pubkeys[0].encode_compressed();
+        let mut pk2 = [0u8; 33];
+        for pk in pubkeys.iter() {
+            let epk = pk.encode_compressed();
+            if epk != pk1 {
+                pk2 = epk;
+                break;
+            }
+        }
+
+        // Q = sum_i a_i*P_i
+        let mut Q = Point::NEUTRAL;
+        for pk in pubkeys.iter() {
+            let a = key_agg_coeff_internal(
+                &pk_hash, &pk2, &pk.encode_compressed());
+            Q += pk.point * a;
+        }
+        if Q.isneutral() != 0 {
+            return None;
+        }
+        Some(Self {
+            Q,
+            gacc: Scalar::ONE,
+            tacc: Scalar::ZERO,
+            pk_hash,
+            pk2,
+        })
+    }
+
+    /// Gets the key aggregation coefficient for the provided public key.
+    ///
+    /// The public key is assumed to be one of the keys which were
+    /// aggregated into this context; if this is not the case, then the
+    /// returned value is meaningless.
+    pub fn key_agg_coeff(self, pk: &PublicKey) -> Scalar {
+        key_agg_coeff_internal(&self.pk_hash, &self.pk2,
+            &pk.encode_compressed())
+    }
+
+    /// Gets the aggregate public key (plain, with its full y coordinate).
+    ///
+    /// The returned key includes all tweaks applied so far.
+    pub fn public_key(self) -> PublicKey {
+        PublicKey { point: self.Q }
+    }
+
+    /// Gets the aggregate public key, in x-only format.
+    ///
+    /// This is the key relatively to which the output signatures are
+    /// valid (BIP-340 verification). It includes all tweaks applied so
+    /// far.
+    pub fn xonly_public_key(self) -> XOnlyPublicKey {
+        let mut point = self.Q;
+        point.set_condneg(self.Q.has_odd_y());
+        XOnlyPublicKey { point }
+    }
+
+    /// Applies a plain tweak to this context.
+    ///
+    /// The tweak (`tweak`) is a 32-byte value, interpreted as an integer
+    /// in unsigned big-endian convention. The aggregate public key Q is
+    /// replaced with Q + t*G. A failure is reported (`None` is
+    /// returned) if the tweak is not lower than the curve order, or if
+    /// the resulting key is the neutral point.
+    pub fn tweak_plain(self, tweak: &[u8]) -> Option<Self> {
+        self.apply_tweak(tweak, false)
+    }
+
+    /// Applies an x-only tweak to this context.
+    ///
+    /// This is similar to `tweak_plain()`, except that the aggregate key
+    /// Q is first replaced with -Q if its y coordinate is odd. This is
+    /// the kind of tweak used by BIP-341 (Taproot) outputs.
+    pub fn tweak_xonly(self, tweak: &[u8]) -> Option<Self> {
+        self.apply_tweak(tweak, true)
+    }
+
+    fn apply_tweak(self, tweak: &[u8], is_xonly: bool) -> Option<Self> {
+        let t = scalar_decode_be(tweak)?;
+        let neg = if is_xonly { self.Q.has_odd_y() } else { 0 };
+        let mut Q = self.Q;
+        Q.set_condneg(neg);
+        Q += Point::mulgen(&t);
+        if Q.isneutral() != 0 {
+            return None;
+        }
+        let mut gacc = self.gacc;
+        gacc.set_cond(&-gacc, neg);
+        let mut tacc = self.tacc;
+        tacc.set_cond(&-tacc, neg);
+        tacc += t;
+        Some(Self { Q, gacc, tacc, pk_hash: self.pk_hash, pk2: self.pk2 })
+    }
+
+    /// Encodes this context into bytes.
+    pub fn encode(self) -> [u8; Self::ENC_LEN] {
+        let mut buf = [0u8; Self::ENC_LEN];
+        buf[0..33].copy_from_slice(&self.Q.encode_compressed());
+        buf[33..65].copy_from_slice(&scalar_encode_be(self.gacc));
+        buf[65..97].copy_from_slice(&scalar_encode_be(self.tacc));
+        buf[97..129].copy_from_slice(&self.pk_hash);
+        buf[129..162].copy_from_slice(&self.pk2);
+        buf
+    }
+
+    /// Decodes a context from bytes.
+    ///
+    /// This function returns `None` if the source does not have the
+    /// exact length of an encoded context, or if it is not
+    /// syntactically correct.
+    pub fn decode(buf: &[u8]) -> Option<Self> {
+        if buf.len() != Self::ENC_LEN {
+            return None;
+        }
+        let Q = Point::decode(&buf[0..33])?;
+        let gacc = scalar_decode_be(&buf[33..65])?;
+        let tacc = scalar_decode_be(&buf[65..97])?;
+        if gacc.iszero() != 0 {
+            return None;
+        }
+        let mut pk_hash = [0u8; 32];
+        pk_hash[..].copy_from_slice(&buf[97..129]);
+        let mut pk2 = [0u8; 33];
+        pk2[..].copy_from_slice(&buf[129..162]);
+        Some(Self { Q, gacc, tacc, pk_hash, pk2 })
+    }
+}
+
+impl SecNonce {
+
+    // Encoded secret nonce length (in bytes).
+    #[cfg(test)]
+    pub(crate) const ENC_LEN: usize = 97;
+
+    /// Generates a new nonce.
+    ///
+    /// The signer's public key (`pk`) is mandatory. The other parameters
+    /// are optional; if provided, they strengthen the nonce generation
+    /// process against a weak random source:
+    ///
+    ///  - `sk`: the signer's private key;
+    ///  - `aggpk`: the aggregate public key;
+    ///  - `msg`: the message to sign;
+    ///  - `extra_in`: some additional input.
+    ///
+    /// The secret nonce must be kept by the signer until the second
+    /// round; the public nonce is sent to the other signers.
+    pub fn generate<T: CryptoRng + RngCore>(rng: &mut T,
+        sk: Option<&PrivateKey>, pk: &PublicKey,
+        aggpk: Option<&XOnlyPublicKey>, msg: Option<&[u8]>,
+        extra_in: Option<&[u8]>) -> (Self, PubNonce)
+    {
+        let mut rand = [0u8; 32];
+        rng.fill_bytes(&mut rand);
+        Self::generate_seeded(&rand, sk, pk, aggpk, msg, extra_in)
+    }
+
+    /// Generates a new nonce (seeded).
+    ///
+    /// This is the BIP-327 `NonceGen()` function, with the provided
+    /// 32-byte seed (`rand`) in the role of the random value `rand'`.
+    /// Other parameters are as in `generate()`. The seed MUST be
+    /// generated with a cryptographically secure random generator, and
+    /// never be reused: reusing the same seed with the same other
+    /// parameters leads to the same nonce, which then leaks the private
+    /// key.
+    pub fn generate_seeded(rand: &[u8; 32],
+        sk: Option<&PrivateKey>, pk: &PublicKey,
+        aggpk: Option<&XOnlyPublicKey>, msg: Option<&[u8]>,
+        extra_in: Option<&[u8]>) -> (Self, PubNonce)
+    {
+        // If the private key is provided, then it is XORed with
+        // hash_{MuSig/aux}(rand').
+        let mut rr = *rand;
+        if let Some(sk) = sk {
+            let mut sh = tagged_sha256(b"MuSig/aux");
+            sh.update(rand);
+            let h = sh.finalize();
+            let skb = sk.encode();
+            for i in 0..32 {
+                rr[i] = skb[i] ^ h[i];
+            }
+        }
+        let epk = pk.encode_compressed();
+
+        // k_i = hash_{MuSig/nonce}(rand || len(pk) || pk || len(aggpk)
+        //         || aggpk || msg_prefixed || len(extra_in) || extra_in
+        //         || i - 1)
+        let mut k = [Scalar::ZERO; 2];
+        for (i, ki) in k.iter_mut().enumerate() {
+            let mut sh = tagged_sha256(b"MuSig/nonce");
+            sh.update(rr);
+            sh.update([33u8]);
+            sh.update(epk);
+            match aggpk {
+                Some(aggpk) => {
+                    sh.update([32u8]);
+                    sh.update(aggpk.encode());
+                }
+                None => {
+                    sh.update([0u8]);
+                }
+            }
+            match msg {
+                Some(msg) => {
+                    sh.update([1u8]);
+                    sh.update((msg.len() as u64).to_be_bytes());
+                    sh.update(msg);
+                }
+                None => {
+                    sh.update([0u8]);
+                }
+            }
+            let extra_in = extra_in.unwrap_or(&[]);
+            sh.update((extra_in.len() as u32).to_be_bytes());
+            sh.update(extra_in);
+            sh.update([i as u8]);
+            *ki = Scalar::decode_reduce(&bswap32(&sh.finalize()));
+
+            // A zero scalar happens only with negligible probability;
+            // we use 1 in that case.
+            ki.set_cond(&Scalar::ONE, ki.iszero());
+        }
+
+        let sn = Self { k1: k[0], k2: k[1], pk: epk };
+        let pn = PubNonce {
+            R1: Point::mulgen(&k[0]),
+            R2: Point::mulgen(&k[1]),
+        };
+        (sn, pn)
+    }
+
+    /// Gets the public nonce corresponding to this secret nonce.
+    pub fn public_nonce(&self) -> PubNonce {
+        PubNonce {
+            R1: Point::mulgen(&self.k1),
+            R2: Point::mulgen(&self.k2),
+        }
+    }
+
+    // Encodes this secret nonce into bytes (BIP-327 format).
+    //
+    // This is used only for the BIP-327 test vectors. It is deliberately
+    // not public: a stored nonce could be decoded twice and used for two
+    // signatures, which would leak the private key.
+    #[cfg(test)]
+    pub(crate) fn encode(&self) -> [u8; Self::ENC_LEN] {
+        let mut buf = [0u8; Self::ENC_LEN];
+        buf[0..32].copy_from_slice(&scalar_encode_be(self.k1));
+        buf[32..64].copy_from_slice(&scalar_encode_be(self.k2));
+        buf[64..97].copy_from_slice(&self.pk);
+        buf
+    }
+
+    // Decodes a secret nonce from bytes (BIP-327 format).
+    //
+    // This returns `None` if the source does not have the exact length
+    // of an encoded secret nonce, or if one of the scalars is out of
+    // range. A nonce with zero scalars (as obtained after a nonce has
+    // been used) can be decoded, but cannot be used for signing. As for
+    // encode(), this is used only for the BIP-327 test vectors.
+    #[cfg(test)]
+    pub(crate) fn decode(buf: &[u8]) -> Option<Self> {
+        if buf.len() != Self::ENC_LEN {
+            return None;
+        }
+        let k1 = scalar_decode_be(&buf[0..32])?;
+        let k2 = scalar_decode_be(&buf[32..64])?;
+        let mut pk = [0u8; 33];
+        pk[..].copy_from_slice(&buf[64..97]);
+        Some(Self { k1, k2, pk })
+    }
+}
+
+impl PubNonce {
+
+    /// Encoded public nonce length (in bytes).
+    pub const ENC_LEN: usize = 66;
+
+    /// Encodes this public nonce into bytes.
+    pub fn encode(self) -> [u8; Self::ENC_LEN] {
+        let mut buf = [0u8; Self::ENC_LEN];
+        buf[0..33].copy_from_slice(&self.R1.encode_compressed());
+        buf[33..66].copy_from_slice(&self.R2.encode_compressed());
+        buf
+    }
+
+    /// Decodes a public nonce from bytes.
+    ///
+    /// This function returns `None` if the source does not have the
+    /// exact length of an encoded public nonce, or if it does not
+    /// contain two valid compressed encodings of curve points.
+    pub fn decode(buf: &[u8]) -> Option<Self> {
+        if buf.len() != Self::ENC_LEN {
+            return None;
+        }
+        let R1 = Point::decode(&buf[0..33])?;
+        let R2 = Point::decode(&buf[33..66])?;
+        Some(Self { R1, R2 })
+    }
+}
+
+impl AggNonce {
+
+    /// Encoded aggregate nonce length (in bytes).
+    pub const ENC_LEN: usize = 66;
+
+    /// Aggregates some public nonces.
+    ///
+    /// The public nonces of all signers must be provided (in any order).
+    pub fn aggregate(pubnonces: &[PubNonce]) -> Self {
+        let mut R1 = Point::NEUTRAL;
+        let mut R2 = Point::NEUTRAL;
+        for pn in pubnonces.iter() {
+            R1 += pn.R1;
+            R2 += pn.R2;
+        }
+        Self { R1, R2 }
+    }
+
+    /// Encodes this aggregate nonce into bytes.
+    ///
+    /// A neutral point is encoded as 33 bytes of value zero.
+    pub fn encode(self) -> [u8; Self::ENC_LEN] {
+        let mut buf = [0u8; Self::ENC_LEN];
+        buf[0..33].copy_from_slice(&self.R1.encode_compressed());
+        buf[33..66].copy_from_slice(&self.R2.encode_compressed());
+        buf
+    }
+
+    /// Decodes an aggregate nonce from bytes.
+    ///
+    /// This function returns `None` if the source does not have the
+    /// exact length of an encoded aggregate nonce, or if it does not
+    /// contain two valid encodings of curve points (33 bytes of value
+    /// zero stand for the neutral point).
+    pub fn decode(buf: &[u8]) -> Option<Self> {
+        if buf.len() != Self::ENC_LEN {
+            return None;
+        }
+        let R1 = point_decode_ext(&buf[0..33])?;
+        let R2 = point_decode_ext(&buf[33..66])?;
+        Some(Self { R1, R2 })
+    }
+}
+
+impl Session {
+
+    /// Encoded session length (in bytes).
+    pub const ENC_LEN: usize = KeyAggContext::ENC_LEN + 97;
+
+    /// Starts a new signing session.
+    ///
+    /// The key aggregation context (`keyagg`), including all tweaks, the
+    /// aggregate nonce (`aggnonce`) and the message (`msg`, of arbitrary
+    /// length) must be the same for all signers.
+    pub fn new(keyagg: &KeyAggContext, aggnonce: &AggNonce, msg: &[u8])
+        -> Self
+    {
+        let xQ = keyagg.Q.encode_xonly();
+
+        // b = hash_{MuSig/noncecoef}(aggnonce || xbytes(Q) || m)
+        let mut sh = tagged_sha256(b"MuSig/noncecoef");
+        sh.update(aggnonce.encode());
+        sh.update(xQ);
+        sh.update(msg);
+        let b = Scalar::decode_reduce(&bswap32(&sh.finalize()));
+
+        // R = R1 + b*R2 (replaced with G if it is the neutral)
+        let mut R = aggnonce.R1 + aggnonce.R2 * b;
+        R.set_cond(&Point::BASE, R.isneutral());
+
+        let e = schnorr_challenge(&R.encode_xonly(), &xQ, msg);
+        Self { keyagg: *keyagg, b, R, e }
+    }
+
+    /// Computes a partial signature.
+    ///
+    /// The secret nonce (`secnonce`) is cleared by this function, so
+    /// that it cannot be used again. A failure is reported (`None` is
+    /// returned) if the secret nonce was already used, or if it was
+    /// not generated for the public key matching the private key `sk`.
+    ///
+    /// Note: it is not verified that the signer's public key is one of
+    /// the keys that were aggregated.
+    pub fn sign(self, secnonce: &mut SecNonce, sk: &PrivateKey)
+        -> Option<PartialSig>
+    {
+        let mut k1 = secnonce.k1;
+        let mut k2 = secnonce.k2;
+        secnonce.k1 = Scalar::ZERO;
+        secnonce.k2 = Scalar::ZERO;
+        if (k1.iszero() | k2.iszero()) != 0 {
+            return None;
+        }
+        let pk = sk.to_public_key();
+        if pk.encode_compressed() != secnonce.pk {
+            return None;
+        }
+
+        // Nonces are negated if R has an odd y coordinate.
+        let nr = self.R.has_odd_y();
+        k1.set_cond(&-k1, nr);
+        k2.set_cond(&-k2, nr);
+
+        // d = g*gacc*d', with g = -1 if Q has an odd y coordinate.
+        let a = self.keyagg.key_agg_coeff(&pk);
+        let mut gd = self.keyagg.gacc * sk.x;
+        gd.set_cond(&-gd, self.keyagg.Q.has_odd_y());
+
+        let s = k1 + self.b * k2 + self.e * a * gd;
+        Some(PartialSig { s })
+    }
+
+    /// Verifies a partial signature.
+    ///
+    /// The partial signature (`psig`) is verified against the signer's
+    /// public nonce (`pubnonce`) and public key (`pk`).
+    ///
+    /// Note: this function is not constant-time; it assumes that all
+    /// the inputs are public data.
+    pub fn partial_verify(self, psig: &PartialSig, pubnonce: &PubNonce,
+        pk: &PublicKey) -> bool
+    {
+        // Re = R1 + b*R2, negated if R has an odd y coordinate.
+        let mut Re = pubnonce.R1 + pubnonce.R2 * self.b;
+        Re.set_condneg(self.R.has_odd_y());
+
+        // Check that s*G = Re + e*a*g*gacc*P.
+        let a = self.keyagg.key_agg_coeff(pk);
+        let mut g = self.keyagg.gacc;
+        g.set_cond(&-g, self.keyagg.Q.has_odd_y());
+        let T = pk.point.mul_add_mulgen_vartime(&-(self.e * a * g), &psig.s);
+        T.equals(Re) != 0
+    }
+
+    /// Aggregates partial signatures into the final signature.
+    ///
+    /// The partial signatures of all signers must be provided (in any
+    /// order). The returned signature is a BIP-340 Schnorr signature
+    /// relatively to the x-only aggregate public key. This function
+    /// does not verify the partial signatures; if the output signature
+    /// turns out to be invalid, then the partial signatures can be
+    /// verified individually with `partial_verify()`.
+    pub fn aggregate(self, psigs: &[PartialSig]) -> [u8; 64] {
+        let mut s = Scalar::ZERO;
+        for ps in psigs.iter() {
+            s += ps.s;
+        }
+        let mut et = self.e * self.keyagg.tacc;
+        et.set_cond(&-et, self.keyagg.Q.has_odd_y());
+        s += et;
+        let mut sig = [0u8; 64];
+        sig[0..32].copy_from_slice(&self.R.encode_xonly());
+        sig[32..64].copy_from_slice(&scalar_encode_be(s));
+        sig
+    }
+
+    /// Encodes this session into bytes.
+    pub fn encode(self) -> [u8; Self::ENC_LEN] {
+        const K: usize = KeyAggContext::ENC_LEN;
+        let mut buf = [0u8; Self::ENC_LEN];
+        buf[0..K].copy_from_slice(&self.keyagg.encode());
+        buf[K..K + 32].copy_from_slice(&scalar_encode_be(self.b));
+        buf[K + 32..K + 65].copy_from_slice(&self.R.encode_compressed());
+        buf[K + 65..K + 97].copy_from_slice(&scalar_encode_be(self.e));
+        buf
+    }
+
+    /// Decodes a session from bytes.
+    ///
+    /// This function returns `None` if the source does not have the
+    /// exact length of an encoded session, or if it is not syntactically
+    /// correct.
+    pub fn decode(buf: &[u8]) -> Option<Self> {
+        const K: usize = KeyAggContext::ENC_LEN;
+        if buf.len() != Self::ENC_LEN {
+            return None;
+        }
+        let keyagg = KeyAggContext::decode(&buf[0..K])?;
+        let b = scalar_decode_be(&buf[K..K + 32])?;
+        let R = Point::decode(&buf[K + 32..K + 65])?;
+        let e = scalar_decode_be(&buf[K + 65..K + 97])?;
+        Some(Self { keyagg, b, R, e })
+    }
+}
+
+impl PartialSig {
+
+    /// Encoded partial signature length (in bytes).
+    pub const ENC_LEN: usize = 32;
+
+    /// Encodes this partial signature into bytes.
+    pub fn encode(self) -> [u8; Self::ENC_LEN] {
+        scalar_encode_be(self.s)
+    }
+
+    /// Decodes a partial signature from bytes.
+    ///
+    /// This function returns `None` if the source does not have length
+    /// exactly 32 bytes, or if the value is not lower than the curve
+    /// order.
+    pub fn decode(buf: &[u8]) -> Option<Self> {
+        let s = scalar_decode_be(buf)?;
+        Some(Self { s })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::{KeyAggContext, SecNonce, PubNonce, AggNonce, Session, PartialSig};
+    use crate::secp256k1::{PrivateKey, PublicKey, XOnlyPublicKey};
+    use sha2::{Sha256, Digest};
+    use crate::Vec;
+
+    // Test vectors from BIP-327 (as included in libsecp256k1,
+    // src/modules/musig/vectors.h).
+
+    fn h(s: &str) -> Vec<u8> {
+        hex::decode(s).unwrap()
+    }
+
+    fn keyagg(pks: &[&str], ki: &[usize], tw: &[&str], ti: &[usize],
+        xo: &[bool]) -> Option<KeyAggContext>
+    {
+        let mut keys = Vec::new();
+        for &i in ki.iter() {
+            keys.push(PublicKey::decode(&h(pks[i]))?);
+        }
+        let mut ctx = KeyAggContext::new(&keys)?;
+        for j in 0..ti.len() {
+            let t = h(tw[ti[j]]);
+            ctx = if xo[j] { ctx.tweak_xonly(&t)? } else { ctx.tweak_plain(&t)? };
+        }
+        Some(ctx)
+    }
+
+    #[test]
+    fn key_agg() {
+        let pks = [
+            "02f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9",
+            "03dff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba659",
+            "023590a94e768f8e1815c2f24b4d80a8e3149316c3518ce7b7ad338368d038ca66",
+            "020000000000000000000000000000000000000000000000000000000000000005",
+            "02fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc30",
+            "04f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9",
+            "03935f972da013f80ae011890fa89b67a27b7be6ccb24d3274d18b2d4067f261a9",
+        ];
+        let tweaks = [
+            "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141",
+            "252e4bd67410a76cdf933d30eaa1608214037f1b105a013eccd3c5c184a6110b",
+        ];
+        let valid: [(&[usize], &str); 4] = [
+            (&[0, 1, 2], "90539eede565f5d054f32cc0c220126889ed1e5d193baf15aef344fe59d4610c"),
+            (&[2, 1, 0], "6204de8b083426dc6eaf9502d27024d53fc826bf7d2012148a0575435df54b2b"),
+            (&[0, 0, 0], "b436e3bad62b8cd409969a224731c193d051162d8c5ae8b109306127da3aa935"),
+            (&[0, 0, 1, 1], "69bc22bfa5d106306e48a20679de1d7389386124d07571d0d872686028c26a3e"),
+        ];
+        for (ki, exp) in valid.iter() {
+            let ctx = keyagg(&pks, ki, &[], &[], &[]).unwrap();
+            assert!(ctx.xonly_public_key().encode()[..] == h(exp));
+            let ctx2 = KeyAggContext::decode(&ctx.encode()).unwrap();
+            assert!(ctx2.encode() == ctx.encode());
+        }
+
+        // Invalid public keys.
+        assert!(PublicKey::decode(&h(pks[3])).is_none());
+        assert!(PublicKey::decode(&h(pks[4])).is_none());
+        assert!(PublicKey::decode(&h(pks[5])).is_none());
+        assert!(keyagg(&pks, &[0, 1], &[], &[], &[]).is_some());
+
+        // Tweak out of range; tweaked key is the point-at-infinity.
+        assert!(keyagg(&pks, &[0, 1], &tweaks, &[0], &[true]).is_none());
+        assert!(keyagg(&pks, &[6], &tweaks, &[1], &[false]).is_none());
+        assert!(keyagg(&pks, &[6], &tweaks, &[1], &[true]).is_some());
+    }
+
+    #[test]
+    fn nonce_gen() {
+        let rand: [u8; 32] = [0x0F; 32];
+        let sk = PrivateKey::decode(&[0x02; 32]).unwrap();
+        let pk = PublicKey::decode(&h(
+            "024d4b6cd1361032ca9bd2aeb9d900aa4d45d9ead80ac9423374c451a7254d0766"))
+            .unwrap();
+        let aggpk = XOnlyPublicKey::decode(&[0x07; 32]).unwrap();
+        let (sn, pn) = SecNonce::generate_seeded(&rand,
+            Some(&sk), &pk, Some(&aggpk), Some(&[0x01; 32]), Some(&[0x08; 32]));
+        assert!(sn.encode()[..] == h("b114e502beaa4e301dd08a50264172c84e41650e6cb726b410c0694d59effb6495b5caf28d045b973d63e3c99a44b807bde375fd6cb39e46dc4a511708d0e9d2024d4b6cd1361032ca9bd2aeb9d900aa4d45d9ead80ac9423374c451a7254d0766"));
+        assert!(pn.encode()[..] == h("02f7be7089e8376eb355272368766b17e88e7db72047d05e56aa881ea52b3b35df02c29c8046fdd0ded4c7e55869137200fbdbfe2eb654267b6d7013602caed3115a"));
+        assert!(sn.public_nonce().encode() == pn.encode());
+        let sn2 = SecNonce::decode(&sn.encode()).unwrap();
+        assert!(sn2.encode() == sn.encode());
+
+        let pk = PublicKey::decode(&h(
+            "02f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9"))
+            .unwrap();
+        let (sn, pn) = SecNonce::generate_seeded(&rand,
+            None, &pk, None, None, None);
+        assert!(sn.encode()[..] == h("89bdd787d0284e5e4d5fc572e49e316bab7e21e3b1830de37dfe80156fa41a6d0b17ae8d024c53679699a6fd7944d9c4a366b514baf43088e0708b1023dd289702f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9"));
+        assert!(pn.encode()[..] == h("02c96e7cb1e8aa5dac64d872947914198f607d90ecde5200de52978ad5ded63c000299ec5117c2d29edee8a2092587c3909be694d5cff0667d6c02ea4059f7cd9786"));
+    }
+
+    #[test]
+    fn nonce_agg() {
+        let pnonces = [
+            "020151c80f435648df67a22b749cd798ce54e0321d034b92b709b567d60a42e66603ba47fbc1834437b3212e89a84d8425e7bf12e0245d98262268ebdcb385d50641",
+            "03ff406ffd8adb9cd29877e4985014f66a59f6cd01c0e88caa8e5f3166b1f676a60248c264cdd57d3c24d79990b0f865674eb62a0f9018277a95011b41bfc193b833",
+            "020151c80f435648df67a22b749cd798ce54e0321d034b92b709b567d60a42e6660279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+            "03ff406ffd8adb9cd29877e4985014f66a59f6cd01c0e88caa8e5f3166b1f676a60379be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+            "04ff406ffd8adb9cd29877e4985014f66a59f6cd01c0e88caa8e5f3166b1f676a60248c264cdd57d3c24d79990b0f865674eb62a0f9018277a95011b41bfc193b833",
+            "03ff406ffd8adb9cd29877e4985014f66a59f6cd01c0e88caa8e5f3166b1f676a60248c264cdd57d3c24d79990b0f865674eb62a0f9018277a95011b41bfc193b831",
+            "03ff406ffd8adb9cd29877e4985014f66a59f6cd01c0e88caa8e5f3166b1f676a602fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc30",
+        ];
+        let valid = [
+            ([0, 1], "035fe1873b4f2967f52fea4a06ad5a8eccbe9d0fd73068012c894e2e87ccb5804b024725377345bde0e9c33af3c43c0a29a9249f2f2956fa8cfeb55c8573d0262dc8"),
+            ([2, 3], "035fe1873b4f2967f52fea4a06ad5a8eccbe9d0fd73068012c894e2e87ccb5804b000000000000000000000000000000000000000000000000000000000000000000"),
+        ];
+        for (ni, exp) in valid.iter() {
+            let pn = [
+                PubNonce::decode(&h(pnonces[ni[0]])).unwrap(),
+                PubNonce::decode(&h(pnonces[ni[1]])).unwrap(),
+            ];
+            let an = AggNonce::aggregate(&pn);
+            assert!(an.encode()[..] == h(exp));
+            assert!(AggNonce::decode(&h(exp)).unwrap().encode() == an.encode());
+        }
+        for pn in pnonces[4..7].iter() {
+            assert!(PubNonce::decode(&h(pn)).is_none());
+        }
+    }
+
+    const SV_PKS: [&str; 4] = [
+        "03935f972da013f80ae011890fa89b67a27b7be6ccb24d3274d18b2d4067f261a9",
+        "02f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9",
+        "02dff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba661",
+        "020000000000000000000000000000000000000000000000000000000000000007",
+    ];
+    const SV_SK: &str =
+        "7fb9e0e687ada1eebf7ecfe2f21e73ebdb51a7d450948dfe8d76d7f2d1007671";
+    const SV_SECNONCE: &str = "508b81a611f100a6b2b6b29656590898af488bcf2e1f55cf22e5cfb84421fe61fa27fd49b1d50085b481285e1ca205d55c82cc1b31ff5cd54a489829355901f703935f972da013f80ae011890fa89b67a27b7be6ccb24d3274d18b2d4067f261a9";
+    const SV_PUBNONCES: [&str; 5] = [
+        "0337c87821afd50a8644d820a8f3e02e499c931865c2360fb43d0a0d20dafe07ea0287bf891d2a6deaebadc909352aa9405d1428c15f4b75f04dae642a95c2548480",
+        "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f817980279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+        "032de2662628c90b03f5e720284eb52ff7d71f4284f627b68a853d78c78e1ffe9303e4c5524e83ffe1493b9077cf1ca6beb2090c93d930321071ad40b2f44e599046",
+        "0237c87821afd50a8644d820a8f3e02e499c931865c2360fb43d0a0d20dafe07ea0387bf891d2a6deaebadc909352aa9405d1428c15f4b75f04dae642a95c2548480",
+        "0200000000000000000000000000000000000000000000000000000000000000090287bf891d2a6deaebadc909352aa9405d1428c15f4b75f04dae642a95c2548480",
+    ];
+    const SV_MSG: &str =
+        "f95466d086770e689964664219266fe5ed215c92ae20bab5c9d79addddf3c0cf";
+
+    fn aggnonce(pnonces: &[&str], ni: &[usize]) -> Option<AggNonce> {
+        let mut pn = Vec::new();
+        for &i in ni.iter() {
+            pn.push(PubNonce::decode(&h(pnonces[i]))?);
+        }
+        Some(AggNonce::aggregate(&pn))
+    }
+
+    #[test]
+    fn sign_verify() {
+        let sk = PrivateKey::decode(&h(SV_SK)).unwrap();
+        let pk = PublicKey::decode(&h(SV_PKS[0])).unwrap();
+        let pn0 = PubNonce::decode(&h(SV_PUBNONCES[0])).unwrap();
+        let msg = h(SV_MSG);
+        let aggnonces = [
+            "028465fcf0bbdbcf443aabcce533d42b4b5a10966ac09a49655e8c42daab8fcd61037496a3cc86926d452cafcfd55d25972ca1675d549310de296bff42f72eeea8c9",
+            "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000",
+            "048465fcf0bbdbcf443aabcce533d42b4b5a10966ac09a49655e8c42daab8fcd61037496a3cc86926d452cafcfd55d25972ca1675d549310de296bff42f72eeea8c9",
+            "028465fcf0bbdbcf443aabcce533d42b4b5a10966ac09a49655e8c42daab8fcd61020000000000000000000000000000000000000000000000000000000000000009",
+            "028465fcf0bbdbcf443aabcce533d42b4b5a10966ac09a49655e8c42daab8fcd6102fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc30",
+        ];
+
+        // (key indices, nonce indices, aggnonce index, expected psig)
+        let valid: [(&[usize], &[usize], usize, &str); 4] = [
+            (&[0, 1, 2], &[0, 1, 2], 0, "012abbcb52b3016ac03ad82395a1a415c48b93def78718e62a7a90052fe224fb"),
+            (&[1, 0, 2], &[1, 0, 2], 0, "9ff2f7aaa856150cc8819254218d3adeeb0535269051897724f9db3789513a52"),
+            (&[1, 2, 0], &[1, 2, 0], 0, "fa23c359f6fac4e7796bb93bc9f0532a95468c539ba20ff86d7c76ed92227900"),
+            (&[0, 1], &[0, 3], 1, "ae386064b26105404798f75de2eb9af5eda5387b064b83d049cb7c5e08879531"),
+        ];
+        for (ki, ni, ai, exp) in valid.iter() {
+            let ctx = keyagg(&SV_PKS, ki, &[], &[], &[]).unwrap();
+            let an = AggNonce::decode(&h(aggnonces[*ai])).unwrap();
+            assert!(aggnonce(&SV_PUBNONCES, ni).unwrap().encode() == an.encode());
+            let session = Session::new(&ctx, &an, &msg);
+            let mut sn = SecNonce::decode(&h(SV_SECNONCE)).unwrap();
+            let psig = session.sign(&mut sn, &sk).unwrap();
+            assert!(psig.encode()[..] == h(exp));
+            assert!(session.partial_verify(&psig, &pn0, &pk));
+
+            // The secret nonce cannot be reused.
+            assert!(session.sign(&mut sn, &sk).is_none());
+
+            // Session can be saved and restored.
+            let s2 = Session::decode(&session.encode()).unwrap();
+            assert!(s2.encode() == session.encode());
+            assert!(s2.partial_verify(&psig, &pn0, &pk));
+        }
+
+        // Sign errors. The first BIP-327 case (signer's key not in the
+        // list) is not detected by this implementation.
+        assert!(keyagg(&SV_PKS, &[1, 0, 3], &[], &[], &[]).is_none());
+        for an in aggnonces[2..5].iter() {
+            assert!(AggNonce::decode(&h(an)).is_none());
+        }
+        let ctx = keyagg(&SV_PKS, &[0, 1, 2], &[], &[], &[]).unwrap();
+        let an = AggNonce::decode(&h(aggnonces[0])).unwrap();
+        let session = Session::new(&ctx, &an, &msg);
+        let mut sn = SecNonce::decode(&h("0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000003935f972da013f80ae011890fa89b67a27b7be6ccb24d3274d18b2d4067f261a9")).unwrap();
+        assert!(session.sign(&mut sn, &sk).is_none());
+        let mut sn = SecNonce::decode(&h(SV_SECNONCE)).unwrap();
+        let sk2 = PrivateKey::decode(&[0x01; 32]).unwrap();
+        assert!(session.sign(&mut sn, &sk2).is_none());
+
+        // Verification failures.
+        let an = aggnonce(&SV_PUBNONCES, &[0, 1, 2]).unwrap();
+        let session = Session::new(&ctx, &an, &msg);
+        let psig = PartialSig::decode(&h(
+            "fed54434ad4cfe953fc527dc6a5e5be8f6234907b7c187559557ce87a0541c46"))
+            .unwrap();
+        assert!(!session.partial_verify(&psig, &pn0, &pk));
+        let psig = PartialSig::decode(&h(
+            "012abbcb52b3016ac03ad82395a1a415c48b93def78718e62a7a90052fe224fb"))
+            .unwrap();
+        assert!(session.partial_verify(&psig, &pn0, &pk));
+        let pn1 = PubNonce::decode(&h(SV_PUBNONCES[1])).unwrap();
+        let pk1 = PublicKey::decode(&h(SV_PKS[1])).unwrap();
+        assert!(!session.partial_verify(&psig, &pn1, &pk1));
+        assert!(PartialSig::decode(&h(
+            "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141"))
+            .is_none());
+
+        // Verification errors (invalid public nonce or public key).
+        assert!(PubNonce::decode(&h(SV_PUBNONCES[4])).is_none());
+        assert!(PublicKey::decode(&h(SV_PKS[3])).is_none());
+    }
+
+    #[test]
+    fn tweak() {
+        let sk = PrivateKey::decode(&h(SV_SK)).unwrap();
+        let pks = [
+            "03935f972da013f80ae011890fa89b67a27b7be6ccb24d3274d18b2d4067f261a9",
+            "02f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9",
+            "02dff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba659",
+        ];
+        let tweaks = [
+            "e8f791ff9225a2af0102afff4a9a723d9612a682a25ebe79802b263cdfcd83bb",
+            "ae2ea797cc0fe72ac5b97b97f3c6957d7e4199a167a58eb08bcaffda70ac0455",
+            "f52ecbc565b3d8bea2dfd5b75a4f457e54369809322e4120831626f290fa87e0",
+            "1969ad73cc177fa0b4fced6df1f7bf9907e665fde9ba196a74fed0a3cf5aef9d",
+            "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141",
+        ];
+        let an = AggNonce::decode(&h("028465fcf0bbdbcf443aabcce533d42b4b5a10966ac09a49655e8c42daab8fcd61037496a3cc86926d452cafcfd55d25972ca1675d549310de296bff42f72eeea8c9")).unwrap();
+        assert!(aggnonce(&SV_PUBNONCES[..3], &[1, 2, 0]).unwrap().encode() == an.encode());
+        let msg = h(SV_MSG);
+        let pk = PublicKey::decode(&h(pks[0])).unwrap();
+        let pn0 = PubNonce::decode(&h(SV_PUBNONCES[0])).unwrap();
+
+        let valid: [(&[usize], &[bool], &str); 5] = [
+            (&[0], &[true], "e28a5c66e61e178c2ba19db77b6cf9f7e2f0f56c17918cd13135e60cc848fe91"),
+            (&[0], &[false], "38b0767798252f21bf5702c48028b095428320f73a4b14db1e25de58543d2d2d"),
+            (&[0, 1], &[false, true], "408a0a21c4a0f5dacaf9646ad6eb6fecd7f7a11f03ed1f48dfff2185bc2c2408"),
+            (&[0, 1, 2, 3], &[false, false, true, true], "45abd206e61e3df2ec9e264a6fec8292141a633c28586388235541f9ade75435"),
+            (&[0, 1, 2, 3], &[true, false, true, false], "b255fdcac27b40c7ce7848e2d3b7bf5ea0ed756da81565ac804ccca3e1d5d239"),
+        ];
+        for (ti, xo, exp) in valid.iter() {
+            let ctx = keyagg(&pks, &[1, 2, 0], &tweaks, ti, xo).unwrap();
+            let session = Session::new(&ctx, &an, &msg);
+            let mut sn = SecNonce::decode(&h(SV_SECNONCE)).unwrap();
+            let psig = session.sign(&mut sn, &sk).unwrap();
+            assert!(psig.encode()[..] == h(exp));
+            assert!(session.partial_verify(&psig, &pn0, &pk));
+        }
+        assert!(keyagg(&pks, &[1, 2, 0], &tweaks, &[4], &[false]).is_none());
+    }
+
+    #[test]
+    fn sig_agg() {
+        let pks = [
+            "03935f972da013f80ae011890fa89b67a27b7be6ccb24d3274d18b2d4067f261a9",
+            "02d2dc6f5df7c56acf38c7fa0ae7a759ae30e19b37359dfde015872324c7ef6e05",
+            "03c7fb101d97ff930acd0c6760852ef64e69083de0b06ac6335724754bb4b0522c",
+            "02352433b21e7e05d3b452b81cae566e06d2e003ece16d1074aaba4289e0e3d581",
+        ];
+        let tweaks = [
+            "b511da492182a91b0ffb9a98020d55f260ae86d7ecbd0399c7383d59a5f2af7c",
+            "a815fe049ee3c5aab66310477fbc8bcccac2f3395f59f921c364acd78a2f48dc",
+            "75448a87274b056468b977be06eb1e9f657577b7320b0a3376ea51fd420d18a8",
+        ];
+        let psigs = [
+            "b15d2cd3c3d22b04dae438ce653f6b4ecf042f42cfded7c41b64aaf9b4af53fb",
+            "6193d6ac61b354e9105bbdc8937a3454a6d705b6d57322a5a472a02ce99fcb64",
+            "9a87d3b79ec67228cb97878b76049b15dbd05b8158d17b5b9114d3c226887505",
+            "66f82ea90923689b855d36c6b7e032fb9970301481b99e01cdb4d6ac7c347a15",
+            "4f5aee41510848a6447dcd1bbc78457ef69024944c87f40250d3ef2c25d33efe",
+            "ddef427bbb847cc027beff4edb01038148917832253ebc355fc33f4a8e2fcce4",
+            "97b890a26c981da8102d3bc294159d171d72810fdf7c6a691def02f0f7af3fdc",
+            "53fa9e08ba5243cbcb0d797c5ee83bc6728e539eb76c2d0bf0f971ee4e909971",
+            "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141",
+        ];
+        let msg = h("599c67ea410d005b9da90817cf03ed3b1c868e4da4edf00a5880b0082c237869");
+
+        // (key indices, tweak indices, is_xonly, aggnonce, psig indices,
+        // expected signature)
+        type SigAggCase<'a> =
+            (&'a [usize], &'a [usize], &'a [bool], &'a str, [usize; 2], &'a str);
+        let valid: [SigAggCase; 4] = [
+            (&[0, 1], &[], &[],
+             "0341432722c5cd0268d829c702cf0d1cbce57033eed201fd335191385227c3210c03d377f2d258b64aadc0e16f26462323d701d286046a2ea93365656afd9875982b",
+             [0, 1],
+             "041da22223ce65c92c9a0d6c2cac828aaf1eee56304fec371ddf91ebb2b9ef0912f1038025857fedeb3ff696f8b99fa4bb2c5812f6095a2e0004ec99ce18de1e"),
+            (&[0, 2], &[], &[],
+             "0224afd36c902084058b51b5d36676bba4dc97c775873768e58822f87fe437d792028cb15929099eee2f5dae404cd39357591ba32e9af4e162b8d3e7cb5efe31cb20",
+             [2, 3],
+             "1069b67ec3d2f3c7c08291accb17a9c9b8f2819a52eb5df8726e17e7d6b52e9f01800260a7e9dac450f4be522de4ce12ba91aeaf2b4279219ef74be1d286add9"),
+            (&[0, 2], &[0], &[false],
+             "0208c5c438c710f4f96a61e9ff3c37758814b8c3ae12bfea0ed2c87ff6954ff186020b1816ea104b4fca2d304d733e0e19cead51303ff6420bfd222335caa402916d",
+             [4, 5],
+             "5c558e1dcade86da0b2f02626a512e30a22cf5255caea7ee32c38e9a71a0e9148ba6c0e6ec7683b64220f0298696f1b878cd47b107b81f7188812d593971e0cc"),
+            (&[0, 3], &[0, 1, 2], &[true, false, true],
+             "02b5ad07afcd99b6d92cb433fbd2a28fdeb98eae2eb09b6014ef0f8197cd58403302e8616910f9293cf692c49f351db86b25e352901f0e237bafda11f1c1cef29ffd",
+             [6, 7],
+             "839b08820b681dba8daf4cc7b104e8f2638f9388f8d7a555dc17b6e6971d7426ce07bf6ab01f1db50e4e33719295f4094572b79868e440fb3defd3fac1db589e"),
+        ];
+        for (ki, ti, xo, an, pi, exp) in valid.iter() {
+            let ctx = keyagg(&pks, ki, &tweaks, ti, xo).unwrap();
+            let an = AggNonce::decode(&h(an)).unwrap();
+            let session = Session::new(&ctx, &an, &msg);
+            let ps = [
+                PartialSig::decode(&h(psigs[pi[0]])).unwrap(),
+                PartialSig::decode(&h(psigs[pi[1]])).unwrap(),
+            ];
+            let sig = session.aggregate(&ps);
+            assert!(sig[..] == h(exp));
+            assert!(ctx.xonly_public_key().verify(&sig, &msg));
+        }
+        assert!(PartialSig::decode(&h(psigs[8])).is_none());
+    }
+
+    #[test]
+    fn full_session() {
+        let mut sh = Sha256::new();
+        for n in 1..5 {
+            let mut sks = Vec::new();
+            let mut pks = Vec::new();
+            for i in 0..n {
+                sh.update((i as u64).to_le_bytes());
+                sh.update((n as u64).to_le_bytes());
+                let seed: [u8; 32] = sh.finalize_reset().into();
+                let sk = PrivateKey::from_seed(&seed);
+                pks.push(sk.to_public_key());
+                sks.push(sk);
+            }
+            let ctx = KeyAggContext::new(&pks).unwrap()
+                .tweak_plain(&[0x11; 32]).unwrap()
+                .tweak_xonly(&[0x22; 32]).unwrap();
+            let aggpk = ctx.xonly_public_key();
+            let msg = b"MuSig2 test message";
+
+            // First round.
+            let mut sns = Vec::new();
+            let mut pns = Vec::new();
+            for i in 0..n {
+                sh.update(b"nonce");
+                sh.update((i as u64).to_le_bytes());
+                let rand: [u8; 32] = sh.finalize_reset().into();
+                let (sn, pn) = SecNonce::generate_seeded(&rand,
+                    Some(&sks[i]), &pks[i], Some(&aggpk), Some(msg), None);
+                sns.push(sn);
+                pns.push(pn);
+            }
+            let an = AggNonce::aggregate(&pns);
+
+            // Second round.
+            let session = Session::new(&ctx, &an, msg);
+            let mut psigs = Vec::new();
+            for i in 0..n {
+                let psig = session.sign(&mut sns[i], &sks[i]).unwrap();
+                assert!(session.partial_verify(&psig, &pns[i], &pks[i]));
+                psigs.push(psig);
+            }
+            let sig = session.aggregate(&psigs);
+            assert!(aggpk.verify(&sig, msg));
+            assert!(!aggpk.verify(&sig, b"other message"));
+        }
+    }
+}
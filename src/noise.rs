@@ -0,0 +1,2314 @@
+//! Noise Protocol Framework.
+//!
+//! This module implements the Noise handshakes (revision 34 of the
+//! specification): `CipherState`, `SymmetricState` and `HandshakeState`
+//! follow the objects defined in the specification. A protocol is
+//! selected with three type parameters:
+//!
+//!  - A DH function, implementing the `Dh` trait: `Dh25519` and `Dh448`
+//!    are the standard functions. `DhJq255e` and `DhGls254` use the
+//!    `ECDH()` functions of jq255e and GLS254; they are not part of the
+//!    specification (their names, "jq255e" and "gls254", are not
+//!    registered).
+//!
+//!  - A cipher, implementing the `Cipher` trait: `ChaChaPoly` or
+//!    `AesGcm`.
+//!
+//!  - A hash function, implementing the `NoiseHash` trait: SHA-256,
+//!    SHA-512 or BLAKE2s.
+//!
+//! The fundamental handshake patterns (N, K, X, and the interactive NN,
+//! NK, NX, KN, KK, KX, XN, XK, XX, IN, IK and IX) are provided as
+//! constants of `HandshakePattern`; PSK modifiers are added with
+//! `HandshakePattern::with_psk()`. Handshake messages are written into
+//! caller-provided buffers, so that no heap allocation is needed.
+//!
+//! # Usage
+//!
+//! Each party creates a `HandshakeState`, then calls alternatively
+//! `write_message()` and `read_message()`, as defined by the pattern.
+//! When the handshake is finished, `split()` returns the two
+//! `CipherState` instances for the transport messages. If any function
+//! reports a failure during the handshake, then the handshake MUST be
+//! aborted (the state is then unusable).
+
+use core::marker::PhantomData;
+use digest::Digest;
+use digest::core_api::BlockSizeUser;
+use super::{CryptoRng, RngCore};
+use super::kdf::Hkdf;
+
+// Maximum length of a DH public key or output.
+const MAX_DHLEN: usize = 56;
+
+// Maximum hash output length.
+const MAX_HASHLEN: usize = 64;
+
+/// Maximum length of a Noise message (in bytes).
+pub const MAX_MESSAGE_LEN: usize = 65535;
+
+/// DH function.
+pub trait Dh {
+    /// Name of the DH function (in protocol names).
+    const NAME: &'static str;
+    /// Length (in bytes) of private keys, public keys and DH outputs.
+    const DHLEN: usize;
+
+    /// Compute the public key (`DHLEN` bytes) from the private key
+    /// (`DHLEN` bytes).
+    fn public_key(sk: &[u8], pk: &mut [u8]);
+
+    /// Compute the DH output (`DHLEN` bytes) from a private key and a
+    /// peer public key. Returned value is `false` if the peer public key
+    /// is invalid.
+    fn dh(sk: &[u8], pk: &[u8], out: &mut [u8]) -> bool;
+}
+
+/// The "25519" DH function (X25519).
+#[cfg(feature = "x25519")]
+pub struct Dh25519;
+
+#[cfg(feature = "x25519")]
+impl Dh for Dh25519 {
+    const NAME: &'static str = "25519";
+    const DHLEN: usize = 32;
+
+    fn public_key(sk: &[u8], pk: &mut [u8]) {
+        let mut s = [0u8; 32];
+        s.copy_from_slice(&sk[..32]);
+        pk[..32].copy_from_slice(&super::x25519::x25519_base(&s));
+    }
+
+    fn dh(sk: &[u8], pk: &[u8], out: &mut [u8]) -> bool {
+        let mut s = [0u8; 32];
+        s.copy_from_slice(&sk[..32]);
+        let mut p = [0u8; 32];
+        p.copy_from_slice(&pk[..32]);
+        let r = super::x25519::x25519(&p, &s);
+        out[..32].copy_from_slice(&r);
+        // An all-zero output (low order peer point) is rejected.
+        let mut z = 0u8;
+        for x in r.iter() {
+            z |= *x;
+        }
+        z != 0
+    }
+}
+
+/// The "448" DH function (X448).
+#[cfg(feature = "x448")]
+pub struct Dh448;
+
+#[cfg(feature = "x448")]
+impl Dh for Dh448 {
+    const NAME: &'static str = "448";
+    const DHLEN: usize = 56;
+
+    fn public_key(sk: &[u8], pk: &mut [u8]) {
+        let mut s = [0u8; 56];
+        s.copy_from_slice(&sk[..56]);
+        pk[..56].copy_from_slice(&super::x448::x448_base(&s));
+    }
+
+    fn dh(sk: &[u8], pk: &[u8], out: &mut [u8]) -> bool {
+        let mut s = [0u8; 56];
+        s.copy_from_slice(&sk[..56]);
+        let mut p = [0u8; 56];
+        p.copy_from_slice(&pk[..56]);
+        let r = super::x448::x448(&p, &s);
+        out[..56].copy_from_slice(&r);
+        // An all-zero output (low order peer point) is rejected.
+        let mut z = 0u8;
+        for x in r.iter() {
+            z |= *x;
+        }
+        z != 0
+    }
+}
+
+/// Non-standard DH function over jq255e (`jq255e::PrivateKey::ECDH()`).
+/// The private key is any sequence of 32 bytes, decoded as an integer
+/// with unsigned little-endian convention and reduced modulo the group
+/// order (it must not be zero).
+#[cfg(feature = "jq255e")]
+pub struct DhJq255e;
+
+#[cfg(feature = "jq255e")]
+impl Dh for DhJq255e {
+    const NAME: &'static str = "jq255e";
+    const DHLEN: usize = 32;
+
+    fn public_key(sk: &[u8], pk: &mut [u8]) {
+        let s = super::jq255e::Scalar::decode_reduce(&sk[..32]);
+        let sk = super::jq255e::PrivateKey::from_scalar(&s);
+        pk[..32].copy_from_slice(&sk.public_key.encoded);
+    }
+
+    fn dh(sk: &[u8], pk: &[u8], out: &mut [u8]) -> bool {
+        let s = super::jq255e::Scalar::decode_reduce(&sk[..32]);
+        let sk = super::jq255e::PrivateKey::from_scalar(&s);
+        let (r, ok) = sk.ECDH(&pk[..32]);
+        out[..32].copy_from_slice(&r);
+        ok != 0
+    }
+}
+
+/// Non-standard DH function over GLS254 (`gls254::PrivateKey::ECDH()`).
+/// The private key is any sequence of 32 bytes, decoded as an integer
+/// with unsigned little-endian convention and reduced modulo the group
+/// order (it must not be zero).
+#[cfg(feature = "gls254")]
+pub struct DhGls254;
+
+#[cfg(feature = "gls254")]
+impl Dh for DhGls254 {
+    const NAME: &'static str = "gls254";
+    const DHLEN: usize = 32;
+
+    fn public_key(sk: &[u8], pk: &mut [u8]) {
+        let s = super::gls254::Scalar::decode_reduce(&sk[..32]);
+        let sk = super::gls254::PrivateKey::from_scalar(&s);
+        pk[..32].copy_from_slice(&sk.public_key.encoded);
+    }
+
+    fn dh(sk: &[u8], pk: &[u8], out: &mut [u8]) -> bool {
+        let s = super::gls254::Scalar::decode_reduce(&sk[..32]);
+        let sk = super::gls254::PrivateKey::from_scalar(&s);
+        let (r, ok) = sk.ECDH(&pk[..32]);
+        out[..32].copy_from_slice(&r);
+        ok != 0
+    }
+}
+
+/// Cipher function (AEAD with a 32-byte key and a 64-bit nonce).
+pub trait Cipher {
+    /// Name of the cipher (in protocol names).
+    const NAME: &'static str;
+
+    /// Encrypt `data` in place, with nonce `n` and associated data `ad`;
+    /// the 16-byte tag is returned.
+    fn encrypt(k: &[u8; 32], n: u64, ad: &[u8], data: &mut [u8]) -> [u8; 16];
+
+    /// Decrypt `data` in place, with nonce `n` and associated data `ad`.
+    /// Returned value is `false` if the tag does not match (the data is
+    /// then unmodified).
+    fn decrypt(k: &[u8; 32], n: u64, ad: &[u8], data: &mut [u8],
+        tag: &[u8]) -> bool;
+}
+
+/// The "ChaChaPoly" cipher (ChaCha20-Poly1305).
+#[cfg(feature = "chacha20poly1305")]
+pub struct ChaChaPoly;
+
+#[cfg(feature = "chacha20poly1305")]
+impl Cipher for ChaChaPoly {
+    const NAME: &'static str = "ChaChaPoly";
+
+    fn encrypt(k: &[u8; 32], n: u64, ad: &[u8], data: &mut [u8]) -> [u8; 16] {
+        // The nonce is four zero bytes followed by the 64-bit counter
+        // (little-endian).
+        let mut nonce = [0u8; 12];
+        nonce[4..].copy_from_slice(&n.to_le_bytes());
+        super::chacha20poly1305::ChaCha20Poly1305::new(k)
+            .encrypt_in_place(&nonce, ad, data)
+    }
+
+    fn decrypt(k: &[u8; 32], n: u64, ad: &[u8], data: &mut [u8],
+        tag: &[u8]) -> bool
+    {
+        let mut nonce = [0u8; 12];
+        nonce[4..].copy_from_slice(&n.to_le_bytes());
+        super::chacha20poly1305::ChaCha20Poly1305::new(k)
+            .decrypt_in_place(&nonce, ad, data, tag)
+    }
+}
+
+/// The "AESGCM" cipher (AES-256-GCM).
+#[cfg(feature = "aes")]
+pub struct AesGcm;
+
+#[cfg(feature = "aes")]
+impl Cipher for AesGcm {
+    const NAME: &'static str = "AESGCM";
+
+    fn encrypt(k: &[u8; 32], n: u64, ad: &[u8], data: &mut [u8]) -> [u8; 16] {
+        // The nonce is four zero bytes followed by the 64-bit counter
+        // (big-endian).
+        let mut nonce = [0u8; 12];
+        nonce[4..].copy_from_slice(&n.to_be_bytes());
+        super::aes::AesGcm::new(k).encrypt_in_place(&nonce, ad, data)
+    }
+
+    fn decrypt(k: &[u8; 32], n: u64, ad: &[u8], data: &mut [u8],
+        tag: &[u8]) -> bool
+    {
+        let mut nonce = [0u8; 12];
+        nonce[4..].copy_from_slice(&n.to_be_bytes());
+        super::aes::AesGcm::new(k).decrypt_in_place(&nonce, ad, data, tag)
+    }
+}
+
+/// Hash function usable with Noise.
+pub trait NoiseHash: Digest + BlockSizeUser + Clone {
+    /// Name of the hash function (in protocol names).
+    const NAME: &'static str;
+}
+
+impl NoiseHash for sha2::Sha256 {
+    const NAME: &'static str = "SHA256";
+}
+
+impl NoiseHash for sha2::Sha512 {
+    const NAME: &'static str = "SHA512";
+}
+
+#[cfg(feature = "blake2s")]
+impl NoiseHash for super::blake2s::Blake2s256 {
+    const NAME: &'static str = "BLAKE2s";
+}
+
+/// Cipher state: a cipher key (possibly absent) and a nonce.
+pub struct CipherState<C: Cipher> {
+    k: [u8; 32],
+    has_key: bool,
+    n: u64,
+    _c: PhantomData<C>,
+}
+
+impl<C: Cipher> CipherState<C> {
+
+    /// Create a new cipher state, without a key.
+    pub fn new() -> Self {
+        Self { k: [0u8; 32], has_key: false, n: 0, _c: PhantomData }
+    }
+
+    /// Set the key (and reset the nonce to zero).
+    pub fn initialize_key(&mut self, k: &[u8; 32]) {
+        self.k = *k;
+        self.has_key = true;
+        self.n = 0;
+    }
+
+    /// Returns `true` if a key has been set.
+    pub fn has_key(&self) -> bool {
+        self.has_key
+    }
+
+    /// Set the nonce (for out-of-order transport messages).
+    pub fn set_nonce(&mut self, n: u64) {
+        self.n = n;
+    }
+
+    /// Encrypt `plaintext` with associated data `ad`. The ciphertext
+    /// (with the 16-byte tag) is written into `out`, and its length is
+    /// returned; if no key is set, then the plaintext is copied as is.
+    /// `None` is returned if the nonce space is exhausted.
+    ///
+    /// `out` MUST be large enough for the ciphertext.
+    pub fn encrypt_with_ad(&mut self, ad: &[u8], plaintext: &[u8],
+        out: &mut [u8]) -> Option<usize>
+    {
+        let len = plaintext.len();
+        out[..len].copy_from_slice(plaintext);
+        if !self.has_key {
+            return Some(len);
+        }
+        // The maximum nonce value is reserved.
+        if self.n == u64::MAX {
+            return None;
+        }
+        let tag = C::encrypt(&self.k, self.n, ad, &mut out[..len]);
+        out[len..(len + 16)].copy_from_slice(&tag);
+        self.n += 1;
+        Some(len + 16)
+    }
+
+    /// Decrypt `ciphertext` (including the tag) with associated data
+    /// `ad`. The plaintext is written into `out`, and its length is
+    /// returned; if no key is set, then the ciphertext is copied as is.
+    /// `None` is returned on failure (the nonce is then not incremented).
+    ///
+    /// `out` MUST be large enough for the plaintext.
+    pub fn decrypt_with_ad(&mut self, ad: &[u8], ciphertext: &[u8],
+        out: &mut [u8]) -> Option<usize>
+    {
+        if !self.has_key {
+            out[..ciphertext.len()].copy_from_slice(ciphertext);
+            return Some(ciphertext.len());
+        }
+        if self.n == u64::MAX || ciphertext.len() < 16 {
+            return None;
+        }
+        let len = ciphertext.len() - 16;
+        out[..len].copy_from_slice(&ciphertext[..len]);
+        if !C::decrypt(&self.k, self.n, ad, &mut out[..len],
+            &ciphertext[len..])
+        {
+            return None;
+        }
+        self.n += 1;
+        Some(len)
+    }
+
+    /// Replace the key with a new key derived from it (default `REKEY()`
+    /// function). The nonce is not modified.
+    pub fn rekey(&mut self) {
+        let mut z = [0u8; 32];
+        C::encrypt(&self.k, u64::MAX, b"", &mut z);
+        self.k = z;
+    }
+}
+
+impl<C: Cipher> Clone for CipherState<C> {
+    fn clone(&self) -> Self {
+        Self { k: self.k, has_key: self.has_key, n: self.n, _c: PhantomData }
+    }
+}
+
+impl<C: Cipher> Default for CipherState<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Symmetric state: chaining key, handshake hash, and a cipher state.
+pub struct SymmetricState<C: Cipher, H: NoiseHash> {
+    cs: CipherState<C>,
+    ck: [u8; MAX_HASHLEN],
+    h: [u8; MAX_HASHLEN],
+    _h: PhantomData<H>,
+}
+
+impl<C: Cipher, H: NoiseHash> SymmetricState<C, H> {
+
+    /// Initialize the symmetric state from the protocol name.
+    pub fn new(protocol_name: &[u8]) -> Self {
+        let hl = Self::hash_len();
+        let mut h = [0u8; MAX_HASHLEN];
+        if protocol_name.len() <= hl {
+            h[..protocol_name.len()].copy_from_slice(protocol_name);
+        } else {
+            h[..hl].copy_from_slice(&H::digest(protocol_name));
+        }
+        Self {
+            cs: CipherState::new(),
+            ck: h,
+            h,
+            _h: PhantomData,
+        }
+    }
+
+    fn hash_len() -> usize {
+        <H as Digest>::output_size()
+    }
+
+    // HKDF() from the specification, with two or three outputs (the
+    // length of `out` is a multiple of HASHLEN).
+    fn hkdf(&self, ikm: &[u8], out: &mut [u8]) {
+        Hkdf::<H>::new(&self.ck[..Self::hash_len()], ikm).expand(b"", out);
+    }
+
+    /// Mix some input key material into the chaining key, and set the
+    /// cipher key.
+    pub fn mix_key(&mut self, ikm: &[u8]) {
+        let hl = Self::hash_len();
+        let mut tmp = [0u8; 2 * MAX_HASHLEN];
+        self.hkdf(ikm, &mut tmp[..(2 * hl)]);
+        self.ck[..hl].copy_from_slice(&tmp[..hl]);
+        let mut k = [0u8; 32];
+        k.copy_from_slice(&tmp[hl..(hl + 32)]);
+        self.cs.initialize_key(&k);
+    }
+
+    /// Mix some data into the handshake hash.
+    pub fn mix_hash(&mut self, data: &[u8]) {
+        let hl = Self::hash_len();
+        let mut hh = H::new();
+        hh.update(&self.h[..hl]);
+        hh.update(data);
+        self.h[..hl].copy_from_slice(&hh.finalize());
+    }
+
+    /// Mix some input key material into both the chaining key and the
+    /// handshake hash (used for pre-shared keys).
+    pub fn mix_key_and_hash(&mut self, ikm: &[u8]) {
+        let hl = Self::hash_len();
+        let mut tmp = [0u8; 3 * MAX_HASHLEN];
+        self.hkdf(ikm, &mut tmp[..(3 * hl)]);
+        self.ck[..hl].copy_from_slice(&tmp[..hl]);
+        self.mix_hash(&tmp[hl..(2 * hl)]);
+        let mut k = [0u8; 32];
+        k.copy_from_slice(&tmp[(2 * hl)..(2 * hl + 32)]);
+        self.cs.initialize_key(&k);
+    }
+
+    /// Get the current handshake hash.
+    pub fn get_handshake_hash(&self) -> &[u8] {
+        &self.h[..Self::hash_len()]
+    }
+
+    /// Encrypt some data (if a key is set), with the handshake hash as
+    /// associated data, then mix the ciphertext into the handshake hash.
+    /// The ciphertext is written into `out`, and its length is returned.
+    pub fn encrypt_and_hash(&mut self, plaintext: &[u8], out: &mut [u8])
+        -> Option<usize>
+    {
+        let hl = Self::hash_len();
+        let len = self.cs.encrypt_with_ad(&self.h[..hl], plaintext, out)?;
+        self.mix_hash(&out[..len]);
+        Some(len)
+    }
+
+    /// Decrypt some data (if a key is set), with the handshake hash as
+    /// associated data, and mix the ciphertext into the handshake hash.
+    /// The plaintext is written into `out`, and its length is returned.
+    pub fn decrypt_and_hash(&mut self, ciphertext: &[u8], out: &mut [u8])
+        -> Option<usize>
+    {
+        let hl = Self::hash_len();
+        let len = self.cs.decrypt_with_ad(&self.h[..hl], ciphertext, out)?;
+        self.mix_hash(ciphertext);
+        Some(len)
+    }
+
+    /// Get the two cipher states for transport messages (the first one
+    /// is for messages from the initiator to the responder).
+    pub fn split(&self) -> (CipherState<C>, CipherState<C>) {
+        let hl = Self::hash_len();
+        let mut tmp = [0u8; 2 * MAX_HASHLEN];
+        self.hkdf(b"", &mut tmp[..(2 * hl)]);
+        let mut c1 = CipherState::new();
+        let mut c2 = CipherState::new();
+        let mut k = [0u8; 32];
+        k.copy_from_slice(&tmp[..32]);
+        c1.initialize_key(&k);
+        k.copy_from_slice(&tmp[hl..(hl + 32)]);
+        c2.initialize_key(&k);
+        (c1, c2)
+    }
+}
+
+impl<C: Cipher, H: NoiseHash> Clone for SymmetricState<C, H> {
+    fn clone(&self) -> Self {
+        Self { cs: self.cs.clone(), ck: self.ck, h: self.h, _h: PhantomData }
+    }
+}
+
+/// Handshake pattern tokens.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Token {
+    E,
+    S,
+    EE,
+    ES,
+    SE,
+    SS,
+    Psk,
+}
+
+/// Handshake pattern.
+///
+/// A pattern consists of pre-messages (only static keys are supported in
+/// pre-messages) and a sequence of messages, alternatively from the
+/// initiator and from the responder; PSK modifiers are handled
+/// separately.
+#[derive(Clone, Copy, Debug)]
+pub struct HandshakePattern {
+    name: &'static str,
+    pre_initiator: bool,
+    pre_responder: bool,
+    messages: &'static [&'static [Token]],
+    psk: u32,
+}
+
+use Token::*;
+
+impl HandshakePattern {
+
+    pub const N: Self = Self::new("N", false, true, &[&[E, ES]]);
+    pub const K: Self = Self::new("K", true, true, &[&[E, ES, SS]]);
+    pub const X: Self = Self::new("X", false, true, &[&[E, ES, S, SS]]);
+    pub const NN: Self = Self::new("NN", false, false,
+        &[&[E], &[E, EE]]);
+    pub const NK: Self = Self::new("NK", false, true,
+        &[&[E, ES], &[E, EE]]);
+    pub const NX: Self = Self::new("NX", false, false,
+        &[&[E], &[E, EE, S, ES]]);
+    pub const KN: Self = Self::new("KN", true, false,
+        &[&[E], &[E, EE, SE]]);
+    pub const KK: Self = Self::new("KK", true, true,
+        &[&[E, ES, SS], &[E, EE, SE]]);
+    pub const KX: Self = Self::new("KX", true, false,
+        &[&[E], &[E, EE, SE, S, ES]]);
+    pub const XN: Self = Self::new("XN", false, false,
+        &[&[E], &[E, EE], &[S, SE]]);
+    pub const XK: Self = Self::new("XK", false, true,
+        &[&[E, ES], &[E, EE], &[S, SE]]);
+    pub const XX: Self = Self::new("XX", false, false,
+        &[&[E], &[E, EE, S, ES], &[S, SE]]);
+    pub const IN: Self = Self::new("IN", false, false,
+        &[&[E, S], &[E, EE, SE]]);
+    pub const IK: Self = Self::new("IK", false, true,
+        &[&[E, ES, S, SS], &[E, EE, SE]]);
+    pub const IX: Self = Self::new("IX", false, false,
+        &[&[E, S], &[E, EE, SE, S, ES]]);
+
+    /// Define a new handshake pattern. `pre_initiator` (respectively
+    /// `pre_responder`) is `true` if the initiator's (responder's)
+    /// static public key is a pre-message. The messages MUST NOT contain
+    /// `Psk` tokens; use `with_psk()` instead.
+    pub const fn new(name: &'static str, pre_initiator: bool,
+        pre_responder: bool, messages: &'static [&'static [Token]]) -> Self
+    {
+        Self { name, pre_initiator, pre_responder, messages, psk: 0 }
+    }
+
+    /// Get the pattern for a name, possibly with PSK modifiers (e.g.
+    /// `"XXpsk3"` or `"NNpsk0+psk2"`). Only the fundamental patterns are
+    /// recognized.
+    pub fn from_name(name: &str) -> Option<Self> {
+        let (base, mods) = match name.find("psk") {
+            Some(i) => (&name[..i], &name[i..]),
+            None => (name, ""),
+        };
+        let mut p = match base {
+            "N" => Self::N,
+            "K" => Self::K,
+            "X" => Self::X,
+            "NN" => Self::NN,
+            "NK" => Self::NK,
+            "NX" => Self::NX,
+            "KN" => Self::KN,
+            "KK" => Self::KK,
+            "KX" => Self::KX,
+            "XN" => Self::XN,
+            "XK" => Self::XK,
+            "XX" => Self::XX,
+            "IN" => Self::IN,
+            "IK" => Self::IK,
+            "IX" => Self::IX,
+            _ => return None,
+        };
+        if !mods.is_empty() {
+            for m in mods.split('+') {
+                let n = m.strip_prefix("psk")?.parse::<usize>().ok()?;
+                if n > p.messages.len() {
+                    return None;
+                }
+                p = p.with_psk(n);
+            }
+        }
+        Some(p)
+    }
+
+    /// Add the `pskN` modifier: with `n = 0`, a `Psk` token is inserted
+    /// at the start of the first message; otherwise, it is appended to
+    /// message `n` (counting from 1). `n` MUST NOT exceed the number of
+    /// messages.
+    pub fn with_psk(self, n: usize) -> Self {
+        assert!(n <= self.messages.len() && n < 32);
+        let mut p = self;
+        p.psk |= 1u32 << n;
+        p
+    }
+
+    /// Get the number of messages in the pattern.
+    pub fn num_messages(&self) -> usize {
+        self.messages.len()
+    }
+
+    /// Returns `true` if the pattern uses a pre-shared key.
+    pub fn has_psk(&self) -> bool {
+        self.psk != 0
+    }
+
+    // Get the tokens of message i (0-based): the pattern tokens, and
+    // whether a Psk token comes first and/or last.
+    fn tokens(&self, i: usize) -> (bool, &'static [Token], bool) {
+        let first = i == 0 && (self.psk & 1) != 0;
+        let last = (self.psk & (2u32 << i)) != 0;
+        (first, self.messages[i], last)
+    }
+
+    // Returns true if the tokens for the initiator (if `initiator` is
+    // true) or responder include the local static key.
+    fn uses_static(&self, initiator: bool) -> bool {
+        if initiator && self.pre_initiator {
+            return true;
+        }
+        if !initiator && self.pre_responder {
+            return true;
+        }
+        for (i, m) in self.messages.iter().enumerate() {
+            if ((i & 1) == 0) == initiator && m.contains(&S) {
+                return true;
+            }
+        }
+        false
+    }
+
+    // Write the pattern name with the modifiers into the buffer; the
+    // length is returned.
+    fn write_name(&self, out: &mut [u8]) -> usize {
+        let mut n = append(out, 0, self.name.as_bytes());
+        let mut first = true;
+        for i in 0..32 {
+            if (self.psk & (1u32 << i)) != 0 {
+                if !first {
+                    n = append(out, n, b"+");
+                }
+                first = false;
+                n = append(out, n, b"psk");
+                if i >= 10 {
+                    n = append(out, n, &[b'0' + (i / 10) as u8]);
+                }
+                n = append(out, n, &[b'0' + (i % 10) as u8]);
+            }
+        }
+        n
+    }
+}
+
+fn append(out: &mut [u8], n: usize, data: &[u8]) -> usize {
+    out[n..(n + data.len())].copy_from_slice(data);
+    n + data.len()
+}
+
+/// Handshake state.
+pub struct HandshakeState<D: Dh, C: Cipher, H: NoiseHash> {
+    ss: SymmetricState<C, H>,
+    s: Option<([u8; MAX_DHLEN], [u8; MAX_DHLEN])>,
+    e: Option<([u8; MAX_DHLEN], [u8; MAX_DHLEN])>,
+    rs: Option<[u8; MAX_DHLEN]>,
+    re: Option<[u8; MAX_DHLEN]>,
+    psk: Option<[u8; 32]>,
+    pattern: HandshakePattern,
+    initiator: bool,
+    msg_index: usize,
+    _d: PhantomData<D>,
+}
+
+impl<D: Dh, C: Cipher, H: NoiseHash> Clone for HandshakeState<D, C, H> {
+    fn clone(&self) -> Self {
+        Self {
+            ss: self.ss.clone(),
+            s: self.s,
+            e: self.e,
+            rs: self.rs,
+            re: self.re,
+            psk: self.psk,
+            pattern: self.pattern,
+            initiator: self.initiator,
+            msg_index: self.msg_index,
+            _d: PhantomData,
+        }
+    }
+}
+
+impl<D: Dh, C: Cipher, H: NoiseHash> HandshakeState<D, C, H> {
+
+    /// Initialize a new handshake state.
+    ///
+    /// `s` is the local static private key (if the pattern uses one),
+    /// and `rs` the remote static public key (if it is known in advance
+    /// in the pattern). The local ephemeral key is generated with the
+    /// provided random source. `None` is returned if a static key
+    /// required by the pattern is missing, or if a key has the wrong
+    /// length.
+    pub fn new<T: CryptoRng + RngCore>(rng: &mut T,
+        pattern: HandshakePattern, initiator: bool, prologue: &[u8],
+        s: Option<&[u8]>, rs: Option<&[u8]>) -> Option<Self>
+    {
+        let mut name = [0u8; 128];
+        let mut n = append(&mut name, 0, b"Noise_");
+        n += pattern.write_name(&mut name[n..]);
+        n = append(&mut name, n, b"_");
+        n = append(&mut name, n, D::NAME.as_bytes());
+        n = append(&mut name, n, b"_");
+        n = append(&mut name, n, C::NAME.as_bytes());
+        n = append(&mut name, n, b"_");
+        n = append(&mut name, n, H::NAME.as_bytes());
+
+        let mut st = Self {
+            ss: SymmetricState::new(&name[..n]),
+            s: None,
+            e: None,
+            rs: None,
+            re: None,
+            psk: None,
+            pattern,
+            initiator,
+            msg_index: 0,
+            _d: PhantomData,
+        };
+        st.ss.mix_hash(prologue);
+
+        if let Some(s) = s {
+            if s.len() != D::DHLEN {
+                return None;
+            }
+            st.s = Some(Self::make_keypair(s));
+        } else if pattern.uses_static(initiator) {
+            return None;
+        }
+        if let Some(rs) = rs {
+            if rs.len() != D::DHLEN {
+                return None;
+            }
+            let mut x = [0u8; MAX_DHLEN];
+            x[..D::DHLEN].copy_from_slice(rs);
+            st.rs = Some(x);
+        } else if (initiator && pattern.pre_responder)
+            || (!initiator && pattern.pre_initiator)
+        {
+            return None;
+        }
+        let mut e = [0u8; MAX_DHLEN];
+        rng.fill_bytes(&mut e[..D::DHLEN]);
+        st.e = Some(Self::make_keypair(&e[..D::DHLEN]));
+
+        // Pre-messages.
+        if pattern.pre_initiator {
+            let pk = if initiator { st.s.unwrap().1 } else { st.rs.unwrap() };
+            st.ss.mix_hash(&pk[..D::DHLEN]);
+        }
+        if pattern.pre_responder {
+            let pk = if initiator { st.rs.unwrap() } else { st.s.unwrap().1 };
+            st.ss.mix_hash(&pk[..D::DHLEN]);
+        }
+        Some(st)
+    }
+
+    fn make_keypair(sk: &[u8]) -> ([u8; MAX_DHLEN], [u8; MAX_DHLEN]) {
+        let mut x = [0u8; MAX_DHLEN];
+        let mut p = [0u8; MAX_DHLEN];
+        x[..D::DHLEN].copy_from_slice(sk);
+        D::public_key(&x[..D::DHLEN], &mut p[..D::DHLEN]);
+        (x, p)
+    }
+
+    /// Set the local ephemeral private key (instead of the one generated
+    /// from the random source). This is meant for tests only, and must
+    /// be called before the local ephemeral key is used.
+    pub fn set_ephemeral(&mut self, e: &[u8]) {
+        assert!(e.len() == D::DHLEN);
+        self.e = Some(Self::make_keypair(e));
+    }
+
+    /// Set the pre-shared key. This must be called before the first
+    /// `Psk` token is processed, if the pattern uses PSK modifiers.
+    pub fn set_psk(&mut self, psk: &[u8; 32]) {
+        self.psk = Some(*psk);
+    }
+
+    /// Returns `true` if the handshake is finished.
+    pub fn is_finished(&self) -> bool {
+        self.msg_index >= self.pattern.messages.len()
+    }
+
+    /// Returns `true` if the next message is to be written by the local
+    /// party (the handshake must not be finished).
+    pub fn is_my_turn(&self) -> bool {
+        ((self.msg_index & 1) == 0) == self.initiator
+    }
+
+    /// Get the current handshake hash.
+    pub fn get_handshake_hash(&self) -> &[u8] {
+        self.ss.get_handshake_hash()
+    }
+
+    /// Get the remote static public key (if known).
+    pub fn get_remote_static(&self) -> Option<&[u8]> {
+        self.rs.as_ref().map(|x| &x[..D::DHLEN])
+    }
+
+    // Apply a DH token.
+    fn mix_dh(&mut self, t: Token) -> bool {
+        let (sk, pk) = match (t, self.initiator) {
+            (EE, _) => (self.e.map(|x| x.0), self.re),
+            (ES, true) | (SE, false) => (self.e.map(|x| x.0), self.rs),
+            (ES, false) | (SE, true) => (self.s.map(|x| x.0), self.re),
+            _ => (self.s.map(|x| x.0), self.rs),
+        };
+        let (sk, pk) = match (sk, pk) {
+            (Some(sk), Some(pk)) => (sk, pk),
+            _ => return false,
+        };
+        let mut out = [0u8; MAX_DHLEN];
+        if !D::dh(&sk[..D::DHLEN], &pk[..D::DHLEN], &mut out[..D::DHLEN]) {
+            return false;
+        }
+        self.ss.mix_key(&out[..D::DHLEN]);
+        true
+    }
+
+    fn mix_psk(&mut self) -> bool {
+        match self.psk {
+            Some(psk) => {
+                self.ss.mix_key_and_hash(&psk);
+                true
+            }
+            None => false,
+        }
+    }
+
+    // Compute the length of the next message, for the provided payload
+    // length.
+    fn message_len(&self, payload_len: usize) -> usize {
+        let (first, tokens, _) = self.pattern.tokens(self.msg_index);
+        let mut has_key = self.ss.cs.has_key() || first;
+        let mut len = payload_len;
+        for t in tokens.iter() {
+            match *t {
+                E => {
+                    len += D::DHLEN;
+                    has_key |= self.pattern.has_psk();
+                }
+                S => {
+                    len += D::DHLEN + if has_key { 16 } else { 0 };
+                }
+                _ => {
+                    has_key = true;
+                }
+            }
+        }
+        // A trailing Psk token also sets the key.
+        if self.pattern.tokens(self.msg_index).2 {
+            has_key = true;
+        }
+        len + if has_key { 16 } else { 0 }
+    }
+
+    /// Write the next handshake message, with the provided payload, into
+    /// `out`. The message length is returned. `None` is returned on
+    /// failure (not our turn to write, handshake finished, missing
+    /// pre-shared key, invalid remote key, or message too long).
+    ///
+    /// `out` MUST be large enough for the message (payload length plus
+    /// at most twice `DHLEN`, plus 16 bytes for each encrypted element).
+    pub fn write_message(&mut self, payload: &[u8], out: &mut [u8])
+        -> Option<usize>
+    {
+        if self.is_finished() || !self.is_my_turn() {
+            return None;
+        }
+        if self.message_len(payload.len()) > MAX_MESSAGE_LEN {
+            return None;
+        }
+        let (first, tokens, last) = self.pattern.tokens(self.msg_index);
+        if first && !self.mix_psk() {
+            return None;
+        }
+        let mut n = 0;
+        for t in tokens.iter() {
+            match *t {
+                E => {
+                    let pk = self.e.unwrap().1;
+                    out[n..(n + D::DHLEN)].copy_from_slice(&pk[..D::DHLEN]);
+                    n += D::DHLEN;
+                    self.ss.mix_hash(&pk[..D::DHLEN]);
+                    if self.pattern.has_psk() {
+                        self.ss.mix_key(&pk[..D::DHLEN]);
+                    }
+                }
+                S => {
+                    let pk = self.s?.1;
+                    n += self.ss.encrypt_and_hash(&pk[..D::DHLEN],
+                        &mut out[n..])?;
+                }
+                Psk => {
+                    if !self.mix_psk() {
+                        return None;
+                    }
+                }
+                t => {
+                    if !self.mix_dh(t) {
+                        return None;
+                    }
+                }
+            }
+        }
+        if last && !self.mix_psk() {
+            return None;
+        }
+        n += self.ss.encrypt_and_hash(payload, &mut out[n..])?;
+        self.msg_index += 1;
+        Some(n)
+    }
+
+    /// Read the next handshake message; the payload is written into
+    /// `payload`, and its length is returned. `None` is returned on
+    /// failure (not our turn to read, handshake finished, missing
+    /// pre-shared key, invalid message or keys).
+    ///
+    /// `payload` MUST be large enough for the payload (at most the
+    /// message length).
+    pub fn read_message(&mut self, msg: &[u8], payload: &mut [u8])
+        -> Option<usize>
+    {
+        if self.is_finished() || self.is_my_turn()
+            || msg.len() > MAX_MESSAGE_LEN
+        {
+            return None;
+        }
+        let (first, tokens, last) = self.pattern.tokens(self.msg_index);
+        if first && !self.mix_psk() {
+            return None;
+        }
+        let mut n = 0;
+        for t in tokens.iter() {
+            match *t {
+                E => {
+                    if msg.len() < n + D::DHLEN {
+                        return None;
+                    }
+                    let mut pk = [0u8; MAX_DHLEN];
+                    pk[..D::DHLEN].copy_from_slice(&msg[n..(n + D::DHLEN)]);
+                    n += D::DHLEN;
+                    self.re = Some(pk);
+                    self.ss.mix_hash(&pk[..D::DHLEN]);
+                    if self.pattern.has_psk() {
+                        self.ss.mix_key(&pk[..D::DHLEN]);
+                    }
+                }
+                S => {
+                    let len = D::DHLEN
+                        + if self.ss.cs.has_key() { 16 } else { 0 };
+                    if msg.len() < n + len {
+                        return None;
+                    }
+                    let mut pk = [0u8; MAX_DHLEN + 16];
+                    self.ss.decrypt_and_hash(&msg[n..(n + len)], &mut pk)?;
+                    n += len;
+                    let mut x = [0u8; MAX_DHLEN];
+                    x[..D::DHLEN].copy_from_slice(&pk[..D::DHLEN]);
+                    self.rs = Some(x);
+                }
+                Psk => {
+                    if !self.mix_psk() {
+                        return None;
+                    }
+                }
+                t => {
+                    if !self.mix_dh(t) {
+                        return None;
+                    }
+                }
+            }
+        }
+        if last && !self.mix_psk() {
+            return None;
+        }
+        let len = self.ss.decrypt_and_hash(&msg[n..], payload)?;
+        self.msg_index += 1;
+        Some(len)
+    }
+
+    /// Get the cipher states for transport messages, once the handshake
+    /// is finished: the first one is for sending, the second one for
+    /// receiving. In one-way patterns, only the initiator sends. `None`
+    /// is returned if the handshake is not finished.
+    pub fn split(&self) -> Option<(CipherState<C>, CipherState<C>)> {
+        if !self.is_finished() {
+            return None;
+        }
+        let (c1, c2) = self.ss.split();
+        if self.initiator {
+            Some((c1, c2))
+        } else {
+            Some((c2, c1))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::Vec;
+    use crate::kdf::HmacDrbgSha256;
+    use crate::blake2s::Blake2s256;
+    use sha2::Sha256;
+
+    struct NoiseVector {
+        protocol_name: &'static str,
+        prologue: &'static str,
+        psk: &'static str,
+        init_static: &'static str,
+        init_ephemeral: &'static str,
+        init_remote_static: &'static str,
+        resp_static: &'static str,
+        resp_ephemeral: &'static str,
+        resp_remote_static: &'static str,
+        handshake_hash: &'static str,
+        messages: &'static [(&'static str, &'static str)],
+    }
+
+    fn opt_key(s: &str) -> Option<Vec<u8>> {
+        if s.is_empty() {
+            None
+        } else {
+            Some(hex::decode(s).unwrap())
+        }
+    }
+
+    fn run_vector<D: Dh, C: Cipher, H: NoiseHash>(v: &NoiseVector) {
+        let mut rng = HmacDrbgSha256::new(v.protocol_name.as_bytes(), b"", b"");
+        let pattern = HandshakePattern::from_name(
+            v.protocol_name.split('_').nth(1).unwrap()).unwrap();
+        let prologue = hex::decode(v.prologue).unwrap();
+        let mut hs = [
+            HandshakeState::<D, C, H>::new(&mut rng, pattern, true, &prologue,
+                opt_key(v.init_static).as_deref(),
+                opt_key(v.init_remote_static).as_deref()).unwrap(),
+            HandshakeState::<D, C, H>::new(&mut rng, pattern, false, &prologue,
+                opt_key(v.resp_static).as_deref(),
+                opt_key(v.resp_remote_static).as_deref()).unwrap(),
+        ];
+        hs[0].set_ephemeral(&hex::decode(v.init_ephemeral).unwrap());
+        if !v.resp_ephemeral.is_empty() {
+            hs[1].set_ephemeral(&hex::decode(v.resp_ephemeral).unwrap());
+        }
+        if let Some(psk) = opt_key(v.psk) {
+            let mut k = [0u8; 32];
+            k.copy_from_slice(&psk);
+            hs[0].set_psk(&k);
+            hs[1].set_psk(&k);
+        }
+
+        let mut msg = [0u8; 1024];
+        let mut pl = [0u8; 1024];
+        let mut transport = None;
+        for (i, (payload, ct)) in v.messages.iter().enumerate() {
+            let payload = hex::decode(payload).unwrap();
+            let ct = hex::decode(ct).unwrap();
+            // In one-way patterns, all messages are from the initiator.
+            let w = if pattern.num_messages() == 1 { 0 } else { i & 1 };
+            let r = 1 - w;
+            if i < pattern.num_messages() {
+                assert!(hs[w].is_my_turn());
+                let n = hs[w].write_message(&payload, &mut msg).unwrap();
+                assert!(msg[..n] == ct[..], "{} message {}",
+                    v.protocol_name, i);
+                let n = hs[r].read_message(&ct, &mut pl).unwrap();
+                assert!(pl[..n] == payload[..]);
+                if i + 1 == pattern.num_messages() {
+                    assert!(hs[0].is_finished() && hs[1].is_finished());
+                    assert!(hs[0].get_handshake_hash()
+                        == &hex::decode(v.handshake_hash).unwrap()[..]);
+                    assert!(hs[1].get_handshake_hash()
+                        == hs[0].get_handshake_hash());
+                    transport = Some([hs[0].split().unwrap(),
+                        hs[1].split().unwrap()]);
+                }
+            } else {
+                let cs = transport.as_mut().unwrap();
+                let n = cs[w].0.encrypt_with_ad(b"", &payload, &mut msg)
+                    .unwrap();
+                assert!(msg[..n] == ct[..], "{} message {}",
+                    v.protocol_name, i);
+                let n = cs[r].1.decrypt_with_ad(b"", &ct, &mut pl).unwrap();
+                assert!(pl[..n] == payload[..]);
+            }
+        }
+    }
+
+    #[test]
+    fn cacophony() {
+        for v in KAT_NOISE.iter() {
+            let suite = v.protocol_name.splitn(3, '_').nth(2).unwrap();
+            match suite {
+                "25519_ChaChaPoly_BLAKE2s" =>
+                    run_vector::<Dh25519, ChaChaPoly, Blake2s256>(v),
+                "25519_AESGCM_SHA256" =>
+                    run_vector::<Dh25519, AesGcm, Sha256>(v),
+                #[cfg(feature = "x448")]
+                "448_ChaChaPoly_SHA512" =>
+                    run_vector::<Dh448, ChaChaPoly, sha2::Sha512>(v),
+                #[cfg(feature = "x448")]
+                "448_AESGCM_BLAKE2s" =>
+                    run_vector::<Dh448, AesGcm, Blake2s256>(v),
+                // Suites whose DH function is not compiled in are skipped.
+                _ => { }
+            }
+        }
+    }
+
+    fn roundtrip<D: Dh>() {
+        type C = ChaChaPoly;
+        type H = Blake2s256;
+        let mut rng = HmacDrbgSha256::new(D::NAME.as_bytes(), b"", b"");
+        let mut sk_i = [0u8; 32];
+        let mut sk_r = [0u8; 32];
+        rng.fill_bytes(&mut sk_i);
+        rng.fill_bytes(&mut sk_r);
+        let mut pk_i = [0u8; 32];
+        let mut pk_r = [0u8; 32];
+        D::public_key(&sk_i, &mut pk_i);
+        D::public_key(&sk_r, &mut pk_r);
+        for name in ["XX", "IK", "KKpsk0", "NKpsk2"].iter() {
+            let pattern = HandshakePattern::from_name(name).unwrap();
+            let mut hi = HandshakeState::<D, C, H>::new(&mut rng, pattern,
+                true, b"p", Some(&sk_i), Some(&pk_r)).unwrap();
+            let mut hr = HandshakeState::<D, C, H>::new(&mut rng, pattern,
+                false, b"p", Some(&sk_r), Some(&pk_i)).unwrap();
+            hi.set_psk(&[0x55; 32]);
+            hr.set_psk(&[0x55; 32]);
+            let mut msg = [0u8; 256];
+            let mut pl = [0u8; 256];
+            while !hi.is_finished() {
+                let (w, r) = if hi.is_my_turn() {
+                    (&mut hi, &mut hr)
+                } else {
+                    (&mut hr, &mut hi)
+                };
+                let n = w.write_message(b"payload", &mut msg).unwrap();
+                // A modified message is rejected (if the payload is
+                // encrypted).
+                if w.ss.cs.has_key() {
+                    let mut bad = msg;
+                    bad[n - 1] ^= 0x01;
+                    let mut r2 = r.clone();
+                    assert!(r2.read_message(&bad[..n], &mut pl).is_none());
+                }
+                let m = r.read_message(&msg[..n], &mut pl).unwrap();
+                assert!(&pl[..m] == b"payload");
+            }
+            assert!(hr.is_finished());
+            assert!(hi.get_remote_static().unwrap() == pk_r);
+            assert!(hr.get_remote_static().unwrap() == pk_i);
+            let (mut si, mut ri) = hi.split().unwrap();
+            let (mut sr, mut rr) = hr.split().unwrap();
+            let n = si.encrypt_with_ad(b"", b"hello", &mut msg).unwrap();
+            let m = rr.decrypt_with_ad(b"", &msg[..n], &mut pl).unwrap();
+            assert!(&pl[..m] == b"hello");
+            let n = sr.encrypt_with_ad(b"", b"world", &mut msg).unwrap();
+            let m = ri.decrypt_with_ad(b"", &msg[..n], &mut pl).unwrap();
+            assert!(&pl[..m] == b"world");
+            si.rekey();
+            rr.rekey();
+            let n = si.encrypt_with_ad(b"ad", b"again", &mut msg).unwrap();
+            assert!(rr.decrypt_with_ad(b"", &msg[..n], &mut pl).is_none());
+            let m = rr.decrypt_with_ad(b"ad", &msg[..n], &mut pl).unwrap();
+            assert!(&pl[..m] == b"again");
+        }
+
+        // Missing keys are reported.
+        assert!(HandshakeState::<D, C, H>::new(&mut rng,
+            HandshakePattern::XX, true, b"", None, None).is_none());
+        assert!(HandshakeState::<D, C, H>::new(&mut rng,
+            HandshakePattern::NK, true, b"", None, None).is_none());
+        let mut hi = HandshakeState::<D, C, H>::new(&mut rng,
+            HandshakePattern::NN.with_psk(0), true, b"", None, None).unwrap();
+        let mut msg = [0u8; 256];
+        assert!(hi.write_message(b"", &mut msg).is_none());
+    }
+
+    #[cfg(feature = "jq255e")]
+    #[test]
+    fn roundtrip_jq255e() {
+        roundtrip::<DhJq255e>();
+    }
+
+    #[cfg(feature = "gls254")]
+    #[test]
+    fn roundtrip_gls254() {
+        roundtrip::<DhGls254>();
+    }
+
+    // Test vectors from the cacophony project.
+    static KAT_NOISE: [NoiseVector; 42] = [
+        NoiseVector {
+            protocol_name: "Noise_NN_25519_ChaChaPoly_BLAKE2s",
+            prologue: "4a6f686e2047616c74",
+            psk: "",
+            init_static: "",
+            init_ephemeral: "893e28b9dc6ca8d611ab664754b8ceb7bac5117349a4439a6b0569da977c464a",
+            init_remote_static: "",
+            resp_static: "",
+            resp_ephemeral: "bbdb4cdbd309f1a1f2e1456967fe288cadd6f712d65dc7b7793d5e63da6b375b",
+            resp_remote_static: "",
+            handshake_hash: "a621e3943a29c1d984b43727697fbec096107d0b569031ac7e0f1131de19f4f4",
+            messages: &[
+                ("4c756477696720766f6e204d69736573",
+                 "ca35def5ae56cec33dc2036731ab14896bc4c75dbb07a61f879f8e3afa4c79444c756477696720766f6e204d69736573"),
+                ("4d757272617920526f746862617264",
+                 "95ebc60d2b1fa672c1f46a8aa265ef51bfe38e7ccb39ec5be34069f144808843ff34a6759d06e7733c83aeb5556c15bc762b664b3ba0556b1e7eaea4168bb6"),
+                ("462e20412e20486179656b",
+                 "79285da88da3535f52b07b70006c85706de7ddb1fd3dddac995b7e"),
+                ("4361726c204d656e676572",
+                 "ffdad3a7f0db4c39077f223659c5c1d107666405566ecdf4ab53bf"),
+                ("4a65616e2d426170746973746520536179",
+                 "2b9801f5084b9a7e9df57382fb4af099a63cd8ff97bc3284c4c5f28994be58ae46"),
+                ("457567656e2042f6686d20766f6e2042617765726b",
+                 "6c94a97c5de175c870fb9e8d5c50c59d20752b0695baf24e151011ee46a184a65b444e9d97"),
+            ],
+        },
+        NoiseVector {
+            protocol_name: "Noise_KN_25519_ChaChaPoly_BLAKE2s",
+            prologue: "4a6f686e2047616c74",
+            psk: "",
+            init_static: "e61ef9919cde45dd5f82166404bd08e38bceb5dfdfded0a34c8df7ed542214d1",
+            init_ephemeral: "893e28b9dc6ca8d611ab664754b8ceb7bac5117349a4439a6b0569da977c464a",
+            init_remote_static: "",
+            resp_static: "",
+            resp_ephemeral: "bbdb4cdbd309f1a1f2e1456967fe288cadd6f712d65dc7b7793d5e63da6b375b",
+            resp_remote_static: "6bc3822a2aa7f4e6981d6538692b3cdf3e6df9eea6ed269eb41d93c22757b75a",
+            handshake_hash: "dc86d3046a5b05f8e6149269ef5696a0dda595d8125c31e6d9af11137b5a0e0f",
+            messages: &[
+                ("4c756477696720766f6e204d69736573",
+                 "ca35def5ae56cec33dc2036731ab14896bc4c75dbb07a61f879f8e3afa4c79444c756477696720766f6e204d69736573"),
+                ("4d757272617920526f746862617264",
+                 "95ebc60d2b1fa672c1f46a8aa265ef51bfe38e7ccb39ec5be34069f1448088439007d1439c3dc50d0f9ded2680d0995f10ec0e960871aa8a01b8165e6e297f"),
+                ("462e20412e20486179656b",
+                 "b79d477f052726df83371225d9f14290b85be44811e6a5479ac49c"),
+                ("4361726c204d656e676572",
+                 "c31f5db821af2a7b24fe039810b8d4f07653e16b33c8b954c8d86c"),
+                ("4a65616e2d426170746973746520536179",
+                 "004c129957669013562bc14cb11c868ecd4fab4dbaac1794916b0e7a49ee27e19d"),
+                ("457567656e2042f6686d20766f6e2042617765726b",
+                 "1a50c6939a635df3d49d310f8f5dd1a98ca799aabcb7210e2c0c610580978e6caadaf7c913"),
+            ],
+        },
+        NoiseVector {
+            protocol_name: "Noise_NK_25519_ChaChaPoly_BLAKE2s",
+            prologue: "4a6f686e2047616c74",
+            psk: "",
+            init_static: "",
+            init_ephemeral: "893e28b9dc6ca8d611ab664754b8ceb7bac5117349a4439a6b0569da977c464a",
+            init_remote_static: "31e0303fd6418d2f8c0e78b91f22e8caed0fbe48656dcf4767e4834f701b8f62",
+            resp_static: "4a3acbfdb163dec651dfa3194dece676d437029c62a408b4c5ea9114246e4893",
+            resp_ephemeral: "bbdb4cdbd309f1a1f2e1456967fe288cadd6f712d65dc7b7793d5e63da6b375b",
+            resp_remote_static: "",
+            handshake_hash: "d7244d974066aae2376f7ba5534f60a6e4e82cd7c9751e226cae3928e6b49f14",
+            messages: &[
+                ("4c756477696720766f6e204d69736573",
+                 "ca35def5ae56cec33dc2036731ab14896bc4c75dbb07a61f879f8e3afa4c794454ae7612d1724af42adb130160a9a94e67b5b169b4e00c189f6467cd17eb7cad"),
+                ("4d757272617920526f746862617264",
+                 "95ebc60d2b1fa672c1f46a8aa265ef51bfe38e7ccb39ec5be34069f144808843986a5c929337e337ac8b4a074af12ab9f76318a5f18c8b599a443af07383ce"),
+                ("462e20412e20486179656b",
+                 "550027c7a5d450017bcb5e12b8253b1c53fd2213aeda84891d5f95"),
+                ("4361726c204d656e676572",
+                 "dfbce0c38210ccee35e830aca9dd8b8b3997b933e75bfc8864b759"),
+                ("4a65616e2d426170746973746520536179",
+                 "4c487a88330c7c65e44d430addf3d92d2a15b081a2892b96693e00b68aec0adac2"),
+                ("457567656e2042f6686d20766f6e2042617765726b",
+                 "471cb9f8252d8ae7b25c93f4b4aebdbf25e5baa23f14bc743559e3ef7fd065e69cfaef55ee"),
+            ],
+        },
+        NoiseVector {
+            protocol_name: "Noise_KK_25519_ChaChaPoly_BLAKE2s",
+            prologue: "4a6f686e2047616c74",
+            psk: "",
+            init_static: "e61ef9919cde45dd5f82166404bd08e38bceb5dfdfded0a34c8df7ed542214d1",
+            init_ephemeral: "893e28b9dc6ca8d611ab664754b8ceb7bac5117349a4439a6b0569da977c464a",
+            init_remote_static: "31e0303fd6418d2f8c0e78b91f22e8caed0fbe48656dcf4767e4834f701b8f62",
+            resp_static: "4a3acbfdb163dec651dfa3194dece676d437029c62a408b4c5ea9114246e4893",
+            resp_ephemeral: "bbdb4cdbd309f1a1f2e1456967fe288cadd6f712d65dc7b7793d5e63da6b375b",
+            resp_remote_static: "6bc3822a2aa7f4e6981d6538692b3cdf3e6df9eea6ed269eb41d93c22757b75a",
+            handshake_hash: "1362b8627a00907ce11e558aba8ce7cbca88e83f0e84ce7db5159b1c3e25ab59",
+            messages: &[
+                ("4c756477696720766f6e204d69736573",
+                 "ca35def5ae56cec33dc2036731ab14896bc4c75dbb07a61f879f8e3afa4c7944266a5f53784aa3becb0f7485c2759c328937867a4cbaafef07422b0725e098be"),
+                ("4d757272617920526f746862617264",
+                 "95ebc60d2b1fa672c1f46a8aa265ef51bfe38e7ccb39ec5be34069f144808843008aeea5d76d6abcbab87a18502c8a8352d9933ac11e2a7d228038d721e31e"),
+                ("462e20412e20486179656b",
+                 "5f92113edf78c3e56e6d67201f5f9e0c8f2930c3e1ffb64ede0358"),
+                ("4361726c204d656e676572",
+                 "30ebbd9cdcef7f40d99c8cd11e880dac28f5c9e5032c1059b3b56a"),
+                ("4a65616e2d426170746973746520536179",
+                 "b011620dc31f88abd1788db50912952fe45da56e9d0907ab2cbce5f609b58b1cf2"),
+                ("457567656e2042f6686d20766f6e2042617765726b",
+                 "a0661971e9047b28a815c7b1f62fefb471e4d34bc2a5b48149e7f80c3772b8e4aae8b44baa"),
+            ],
+        },
+        NoiseVector {
+            protocol_name: "Noise_KK_448_ChaChaPoly_SHA512",
+            prologue: "4a6f686e2047616c74",
+            psk: "",
+            init_static: "34d564c4be963d1b2a89fcfe83e6a72b5e3f5e3127f9f596ffc7575e418dfc1f4e827cfc10c9fed38e92ad56ddf8f08571430df2e76d5411",
+            init_ephemeral: "7fd26c8b8a0d5c98c85ff9ca1d7bc66d78578b9f2c4c170850748b27992767e6ea6cc9992a561c9d19dfc342e260c280ef4f3f9b8f879d4e",
+            init_remote_static: "bd200fa6d50db3a743797b00aca1b70f417bfc381b28b21b5835d84cf7a6da6abba19e3ba7d46b253412b74665d4627b65fcef3f29c95d3e",
+            resp_static: "a9b45971180882a79b89a3399544a425ef8136d278efa443ed67d3ff9d36e883bc330c6295bbf6ed73ff6fd10cbed767ad05ce03ebd27c7c",
+            resp_ephemeral: "3facf7503ebee252465689f1d4e3b1dd219639ef9de4ffd6049d6d71a0f62126840febb99042421ce12af6626d98d9170260390fbc8399a5",
+            resp_remote_static: "301551eca1788f4451c269beafed110b51f08c0494a8de614a184ff3d467d7defdfc7c138e4669591108b69a056d25cafda289f22d1f32c0",
+            handshake_hash: "098cfd95832d746ca158007c68de21067e5c1db092505c29ad616b7d0f481cf956db9b15229056ecc60e2f2fbbeac01baf9db77a2fa74f441e06a549285efa43",
+            messages: &[
+                ("4c756477696720766f6e204d69736573",
+                 "6cfcb98ae6b1bc5659cadc595bf664e17094404eae6b45fde6fc40ca937d1dbe1464cb66eb21fdbaa487cd0d11d6dce5aa07b8219bfdc49a7f8e83e83f34885a0c0519256c9c279d5d3c0b925d29f558fe48b31c49961384"),
+                ("4d757272617920526f746862617264",
+                 "f7eb9a09468f9564819de07ada77a6cf5d5eacd84682067538bf2c4e4c905e5cc35cc3ff41241e47ae3bd296477a236ef185e5a8a0f18d65b25974494a3903f47f74ef4be731b9e21421e199fb2e65b1502ad5cfd57537"),
+                ("462e20412e20486179656b",
+                 "cf600476a3aa1a5ba2f465ce35a3c5962ab4d5a46628c5abe7c32b"),
+                ("4361726c204d656e676572",
+                 "c4dc1af2617f47628ea828c8f74122d6a7d1b7a38ba7a468d06e96"),
+                ("4a65616e2d426170746973746520536179",
+                 "93d528a175d2d3e19194b5ca5ea6682f029679b9c6e9b6265dbc3f6763172b066b"),
+                ("457567656e2042f6686d20766f6e2042617765726b",
+                 "3361ddc11e32e90e788b3415d9ba731e4cae5a3ec8ffc9d0b612c22dc0dbb44a01a35eeeb2"),
+            ],
+        },
+        NoiseVector {
+            protocol_name: "Noise_NX_25519_ChaChaPoly_BLAKE2s",
+            prologue: "4a6f686e2047616c74",
+            psk: "",
+            init_static: "",
+            init_ephemeral: "893e28b9dc6ca8d611ab664754b8ceb7bac5117349a4439a6b0569da977c464a",
+            init_remote_static: "",
+            resp_static: "4a3acbfdb163dec651dfa3194dece676d437029c62a408b4c5ea9114246e4893",
+            resp_ephemeral: "bbdb4cdbd309f1a1f2e1456967fe288cadd6f712d65dc7b7793d5e63da6b375b",
+            resp_remote_static: "",
+            handshake_hash: "ea36347617d324907de1d80582ea1fcd4a535cabb321876a517a4ca498a083cd",
+            messages: &[
+                ("4c756477696720766f6e204d69736573",
+                 "ca35def5ae56cec33dc2036731ab14896bc4c75dbb07a61f879f8e3afa4c79444c756477696720766f6e204d69736573"),
+                ("4d757272617920526f746862617264",
+                 "95ebc60d2b1fa672c1f46a8aa265ef51bfe38e7ccb39ec5be34069f1448088431b7ab475ba0987fba04b749be49e6b43fe538cfca25a1c591a7ed09f19c9b9e7d042761a2fd2762cf2cb2062ce2c61253452b8383eb2ddc9ba2237b96d97b4e866ba73f55165a736ad03e68594ce25"),
+                ("462e20412e20486179656b",
+                 "5ab8adddb31ab4f1086c55c3f3ed053f4d78eca7aaf7ba09d486f8"),
+                ("4361726c204d656e676572",
+                 "f3bbada5c0a4cd615bed55ee18046ad55efc4f30d318c57b4941e1"),
+                ("4a65616e2d426170746973746520536179",
+                 "c1372cf03d2727f6b74f656b587735109ebb6159434a40a65e2e6095c12db5f01c"),
+                ("457567656e2042f6686d20766f6e2042617765726b",
+                 "de040777d38c7bf60c4b8c0ca730a9526ff067db990848ac33e9e9970b01efdf00bab518d0"),
+            ],
+        },
+        NoiseVector {
+            protocol_name: "Noise_KX_25519_ChaChaPoly_BLAKE2s",
+            prologue: "4a6f686e2047616c74",
+            psk: "",
+            init_static: "e61ef9919cde45dd5f82166404bd08e38bceb5dfdfded0a34c8df7ed542214d1",
+            init_ephemeral: "893e28b9dc6ca8d611ab664754b8ceb7bac5117349a4439a6b0569da977c464a",
+            init_remote_static: "",
+            resp_static: "4a3acbfdb163dec651dfa3194dece676d437029c62a408b4c5ea9114246e4893",
+            resp_ephemeral: "bbdb4cdbd309f1a1f2e1456967fe288cadd6f712d65dc7b7793d5e63da6b375b",
+            resp_remote_static: "6bc3822a2aa7f4e6981d6538692b3cdf3e6df9eea6ed269eb41d93c22757b75a",
+            handshake_hash: "a6d9bdc26a304e22c57cbafefa5c880050cab606aa64da5bf26c9c97e8570976",
+            messages: &[
+                ("4c756477696720766f6e204d69736573",
+                 "ca35def5ae56cec33dc2036731ab14896bc4c75dbb07a61f879f8e3afa4c79444c756477696720766f6e204d69736573"),
+                ("4d757272617920526f746862617264",
+                 "95ebc60d2b1fa672c1f46a8aa265ef51bfe38e7ccb39ec5be34069f1448088430f37fda6c6abae4b0f54f9ad38b22fec739d5c4925a8d76de6cc7cf4a931711cd826b2104f120d624f4c7f3861f79d1e2a0b5867b1013a1ae3fd76ef9443424eee0ffdf5b6aff9fd4f162e6bcbc2e8"),
+                ("462e20412e20486179656b",
+                 "3644419f0cd1f8d29bfa77ae0102ab35d947e9de5d26588c885168"),
+                ("4361726c204d656e676572",
+                 "9e2d00ad34457ff17b09c8bbe65e840d5899d8abfb9cad8b62e008"),
+                ("4a65616e2d426170746973746520536179",
+                 "ce3704a625817987d94952215471ee2f38c1ce68a6b60630780a569fed6efe1d95"),
+                ("457567656e2042f6686d20766f6e2042617765726b",
+                 "466b03c085d7426507a6d510c695e5a311a0e43576bd381afe4f67243d1e17cd41df9387e2"),
+            ],
+        },
+        NoiseVector {
+            protocol_name: "Noise_XN_25519_ChaChaPoly_BLAKE2s",
+            prologue: "4a6f686e2047616c74",
+            psk: "",
+            init_static: "e61ef9919cde45dd5f82166404bd08e38bceb5dfdfded0a34c8df7ed542214d1",
+            init_ephemeral: "893e28b9dc6ca8d611ab664754b8ceb7bac5117349a4439a6b0569da977c464a",
+            init_remote_static: "",
+            resp_static: "",
+            resp_ephemeral: "bbdb4cdbd309f1a1f2e1456967fe288cadd6f712d65dc7b7793d5e63da6b375b",
+            resp_remote_static: "",
+            handshake_hash: "cf4747b1ea3e0f0d81a1bbbc8c3a2d6b086585fe210099ae08d6d012da6179dd",
+            messages: &[
+                ("4c756477696720766f6e204d69736573",
+                 "ca35def5ae56cec33dc2036731ab14896bc4c75dbb07a61f879f8e3afa4c79444c756477696720766f6e204d69736573"),
+                ("4d757272617920526f746862617264",
+                 "95ebc60d2b1fa672c1f46a8aa265ef51bfe38e7ccb39ec5be34069f144808843dc00ccf629492772082cf28c171db3ec2dbc406aa59cca67a7a174501ccdca"),
+                ("462e20412e20486179656b",
+                 "0bb0ae2b390d37a5aea005ffac23173e212f2234bbb4da3013ba0ad8ad8ec2f8a1e941c22a19c6904bee596238ecc6f5fadbb2881461b78ad9230a7838743e6160919412061d383a547510"),
+                ("4361726c204d656e676572",
+                 "a378ce38a1df8f3e80a85c5a8709f3a17581ff8a2888e2a8446f65"),
+                ("4a65616e2d426170746973746520536179",
+                 "c9df700a1e9c118572703d0d7f55c33fe4b07be30914a7a804a4cd6fdae90a486e"),
+                ("457567656e2042f6686d20766f6e2042617765726b",
+                 "e371be686b36e1a101a7989f805d8e1520fc031b3a4a6085df1e386da28bac940d615cd9bb"),
+            ],
+        },
+        NoiseVector {
+            protocol_name: "Noise_IN_25519_ChaChaPoly_BLAKE2s",
+            prologue: "4a6f686e2047616c74",
+            psk: "",
+            init_static: "e61ef9919cde45dd5f82166404bd08e38bceb5dfdfded0a34c8df7ed542214d1",
+            init_ephemeral: "893e28b9dc6ca8d611ab664754b8ceb7bac5117349a4439a6b0569da977c464a",
+            init_remote_static: "",
+            resp_static: "",
+            resp_ephemeral: "bbdb4cdbd309f1a1f2e1456967fe288cadd6f712d65dc7b7793d5e63da6b375b",
+            resp_remote_static: "",
+            handshake_hash: "cc3f374de495bd8f50dcd911378f2bc90aea5a69d2b7bd46197403f25a632bab",
+            messages: &[
+                ("4c756477696720766f6e204d69736573",
+                 "ca35def5ae56cec33dc2036731ab14896bc4c75dbb07a61f879f8e3afa4c79446bc3822a2aa7f4e6981d6538692b3cdf3e6df9eea6ed269eb41d93c22757b75a4c756477696720766f6e204d69736573"),
+                ("4d757272617920526f746862617264",
+                 "95ebc60d2b1fa672c1f46a8aa265ef51bfe38e7ccb39ec5be34069f1448088432830411f43b780306e3f94b9e3becb18016c41fd51fa7ed38f1a6217bdee11"),
+                ("462e20412e20486179656b",
+                 "822184f6ad708b7539c99ed858caf5ba56f2c57ba55d34dd3b6778"),
+                ("4361726c204d656e676572",
+                 "2f97e72757dd3b46921ce96827cca0d01e819cfc7db9aaa85019b5"),
+                ("4a65616e2d426170746973746520536179",
+                 "bea8ecf42785759819282424c5547c1f98b871a67d1d6e3fdcfb6c2c65d54f2ea1"),
+                ("457567656e2042f6686d20766f6e2042617765726b",
+                 "3c9d968a1c6036ef29ef6a031678c621d1629cb96e25d8f11dfaa29e1591c5648e22089217"),
+            ],
+        },
+        NoiseVector {
+            protocol_name: "Noise_XK_25519_ChaChaPoly_BLAKE2s",
+            prologue: "4a6f686e2047616c74",
+            psk: "",
+            init_static: "e61ef9919cde45dd5f82166404bd08e38bceb5dfdfded0a34c8df7ed542214d1",
+            init_ephemeral: "893e28b9dc6ca8d611ab664754b8ceb7bac5117349a4439a6b0569da977c464a",
+            init_remote_static: "31e0303fd6418d2f8c0e78b91f22e8caed0fbe48656dcf4767e4834f701b8f62",
+            resp_static: "4a3acbfdb163dec651dfa3194dece676d437029c62a408b4c5ea9114246e4893",
+            resp_ephemeral: "bbdb4cdbd309f1a1f2e1456967fe288cadd6f712d65dc7b7793d5e63da6b375b",
+            resp_remote_static: "",
+            handshake_hash: "899891a0f1a8db67f8bfa46b8bced371c1c25de377f20cf882fdd06fc15517fd",
+            messages: &[
+                ("4c756477696720766f6e204d69736573",
+                 "ca35def5ae56cec33dc2036731ab14896bc4c75dbb07a61f879f8e3afa4c7944e953bb4cd3450eecab157a8ce632f74fcac39a3fcd5be08267d5923ca353d4f0"),
+                ("4d757272617920526f746862617264",
+                 "95ebc60d2b1fa672c1f46a8aa265ef51bfe38e7ccb39ec5be34069f14480884382521c3ea09af48bfa39627819b007e7c0e179dad4a9a7482841bae32ec8eb"),
+                ("462e20412e20486179656b",
+                 "f032de86c8d3c2099478fefb9b2e6a1fef904d3b2470949858ae9f497ff068dbb6ff7cb43fa51946bcd8a87863849aa7f0e663cd83961c752ce3be41384de8a849e4d130d9a2d717a5c7e8"),
+                ("4361726c204d656e676572",
+                 "cb54ca2168a55a150760c409e2157b9e57ceab823d897bff36eeab"),
+                ("4a65616e2d426170746973746520536179",
+                 "948e26c8a5348aec2711343de8e7c8faa7cae4b6bf51e9026eab234ed4f3e8e8fc"),
+                ("457567656e2042f6686d20766f6e2042617765726b",
+                 "dbf0cedc457d87e0eaa4629b7167a7e552ac5197d5436a20a1b5ba001ca21116e22669773c"),
+            ],
+        },
+        NoiseVector {
+            protocol_name: "Noise_IK_25519_ChaChaPoly_BLAKE2s",
+            prologue: "4a6f686e2047616c74",
+            psk: "",
+            init_static: "e61ef9919cde45dd5f82166404bd08e38bceb5dfdfded0a34c8df7ed542214d1",
+            init_ephemeral: "893e28b9dc6ca8d611ab664754b8ceb7bac5117349a4439a6b0569da977c464a",
+            init_remote_static: "31e0303fd6418d2f8c0e78b91f22e8caed0fbe48656dcf4767e4834f701b8f62",
+            resp_static: "4a3acbfdb163dec651dfa3194dece676d437029c62a408b4c5ea9114246e4893",
+            resp_ephemeral: "bbdb4cdbd309f1a1f2e1456967fe288cadd6f712d65dc7b7793d5e63da6b375b",
+            resp_remote_static: "",
+            handshake_hash: "48f3cb8bc9319da4ba1e9933991b1c4ed4034f1f126a76d3a1fbcfd7f94248d4",
+            messages: &[
+                ("4c756477696720766f6e204d69736573",
+                 "ca35def5ae56cec33dc2036731ab14896bc4c75dbb07a61f879f8e3afa4c79440b03ddc7aac5123d06a1b23b71670e32e76c28239a7ca4ac8f784de7e44c1adbfc6e83fef7352a58d9d56157400c0a737b1d171ce368229c7b752ac25b8faf4eca690f6d896f543be02c996ab2b86b76"),
+                ("4d757272617920526f746862617264",
+                 "95ebc60d2b1fa672c1f46a8aa265ef51bfe38e7ccb39ec5be34069f144808843d9b5a8927f0ac9655ef76833bc7e5561f42e691ac8404efd6fbd6308b6a27c"),
+                ("462e20412e20486179656b",
+                 "2c256ed08fcd08c2980f954ee4beaccb61c9581340f5dd2fd1cf3b"),
+                ("4361726c204d656e676572",
+                 "d6033f70eee20945c7c9dba304e397ee3b284ff5e00fd9efb095d3"),
+                ("4a65616e2d426170746973746520536179",
+                 "a9c068ca5d8babf72560652d8e851adbfac35c8a66e810d560863173e96adf4cfe"),
+                ("457567656e2042f6686d20766f6e2042617765726b",
+                 "2a09d8f459e5927e40fdd2eddc99bdafb04e13a26f145cb5cfe9e6ba34c94331ebc17d5156"),
+            ],
+        },
+        NoiseVector {
+            protocol_name: "Noise_XX_25519_AESGCM_SHA256",
+            prologue: "4a6f686e2047616c74",
+            psk: "",
+            init_static: "e61ef9919cde45dd5f82166404bd08e38bceb5dfdfded0a34c8df7ed542214d1",
+            init_ephemeral: "893e28b9dc6ca8d611ab664754b8ceb7bac5117349a4439a6b0569da977c464a",
+            init_remote_static: "",
+            resp_static: "4a3acbfdb163dec651dfa3194dece676d437029c62a408b4c5ea9114246e4893",
+            resp_ephemeral: "bbdb4cdbd309f1a1f2e1456967fe288cadd6f712d65dc7b7793d5e63da6b375b",
+            resp_remote_static: "",
+            handshake_hash: "1b7aefb1125762aa21a252890d00af54519638b76437444538f9a52f21e2e0dc",
+            messages: &[
+                ("4c756477696720766f6e204d69736573",
+                 "ca35def5ae56cec33dc2036731ab14896bc4c75dbb07a61f879f8e3afa4c79444c756477696720766f6e204d69736573"),
+                ("4d757272617920526f746862617264",
+                 "95ebc60d2b1fa672c1f46a8aa265ef51bfe38e7ccb39ec5be34069f144808843757117acceb05bd7a45733bc22015c97a9d0cbaf41b80446d5988ff5127235d76b79eade70f473d6a4ef521fdcbeda5340d01e028ba793fc059f2724a83af05f12dda0448a7621a926b379a92477fd"),
+                ("462e20412e20486179656b",
+                 "c90f1cf77eba4e50edb038991565e36c9758943a989229b6051244dc4fbecb6946744b401af2ee1a5881b65fbb87fd07cb6a328ececc9ce6ce84c399dc332d4fd521fa4bb7f467ce909395"),
+                ("4361726c204d656e676572",
+                 "bc3fa77f6aca3e8466d7dc6bea10013e88a6a29add5132b461806c"),
+                ("4a65616e2d426170746973746520536179",
+                 "250b01074cdfe0df2ecf8ccbf1737b15a2ddb5b52fd9a396604e9c793cee3b3bb9"),
+                ("457567656e2042f6686d20766f6e2042617765726b",
+                 "449d4d433b3cdc3d02bf6fc881774b9df54366ebcffb9689bb13f14709822cd7ef42bcdb4d"),
+            ],
+        },
+        NoiseVector {
+            protocol_name: "Noise_XX_25519_ChaChaPoly_BLAKE2s",
+            prologue: "4a6f686e2047616c74",
+            psk: "",
+            init_static: "e61ef9919cde45dd5f82166404bd08e38bceb5dfdfded0a34c8df7ed542214d1",
+            init_ephemeral: "893e28b9dc6ca8d611ab664754b8ceb7bac5117349a4439a6b0569da977c464a",
+            init_remote_static: "",
+            resp_static: "4a3acbfdb163dec651dfa3194dece676d437029c62a408b4c5ea9114246e4893",
+            resp_ephemeral: "bbdb4cdbd309f1a1f2e1456967fe288cadd6f712d65dc7b7793d5e63da6b375b",
+            resp_remote_static: "",
+            handshake_hash: "6c4c56cf71612f72d05ceb96c0155e6f4ea54a26b504c93de632a2db4a49d200",
+            messages: &[
+                ("4c756477696720766f6e204d69736573",
+                 "ca35def5ae56cec33dc2036731ab14896bc4c75dbb07a61f879f8e3afa4c79444c756477696720766f6e204d69736573"),
+                ("4d757272617920526f746862617264",
+                 "95ebc60d2b1fa672c1f46a8aa265ef51bfe38e7ccb39ec5be34069f1448088437c365eb362a1c991b0557fe8a7fb187d99346765d93ec63db6c1b01504ebeec55a2298d2dbff80eff034d20595153f63a196a6cead1e11b2bb13e336fa13616dd3e8b0a070c882ed3f1a78c7c06c93"),
+                ("462e20412e20486179656b",
+                 "46c3307de83b014258717d97781c1f50936d8b7d50c0722a1739654d10392d415b670c114f79b9a4f80541570f77ce88802efa4220cff733e7b5668ba38059ec904b4b8eef9448085faf51"),
+                ("4361726c204d656e676572",
+                 "d5e83adfaac5dc324a68f1862df54549e56d209fba707205f328b2"),
+                ("4a65616e2d426170746973746520536179",
+                 "d102c9029b1f55c788f561ba7737afbccef9c9f1bf2f238167fd40ba9c1c134867"),
+                ("457567656e2042f6686d20766f6e2042617765726b",
+                 "cb1ce80960382c6d5d5e740ffb724d1432f0310b200fb6f8424120f506092744baa415e155"),
+            ],
+        },
+        NoiseVector {
+            protocol_name: "Noise_XX_448_ChaChaPoly_SHA512",
+            prologue: "4a6f686e2047616c74",
+            psk: "",
+            init_static: "34d564c4be963d1b2a89fcfe83e6a72b5e3f5e3127f9f596ffc7575e418dfc1f4e827cfc10c9fed38e92ad56ddf8f08571430df2e76d5411",
+            init_ephemeral: "7fd26c8b8a0d5c98c85ff9ca1d7bc66d78578b9f2c4c170850748b27992767e6ea6cc9992a561c9d19dfc342e260c280ef4f3f9b8f879d4e",
+            init_remote_static: "",
+            resp_static: "a9b45971180882a79b89a3399544a425ef8136d278efa443ed67d3ff9d36e883bc330c6295bbf6ed73ff6fd10cbed767ad05ce03ebd27c7c",
+            resp_ephemeral: "3facf7503ebee252465689f1d4e3b1dd219639ef9de4ffd6049d6d71a0f62126840febb99042421ce12af6626d98d9170260390fbc8399a5",
+            resp_remote_static: "",
+            handshake_hash: "0dad111c440d62ee7ad18c6e9a498896fd8a41b2a8bf9116dbe8a269d53dc7d85ffa81d8b2244d150863c800f5f33fb4d67a563c95738a81c7ff085f86c5b642",
+            messages: &[
+                ("4c756477696720766f6e204d69736573",
+                 "6cfcb98ae6b1bc5659cadc595bf664e17094404eae6b45fde6fc40ca937d1dbe1464cb66eb21fdbaa487cd0d11d6dce5aa07b8219bfdc49a4c756477696720766f6e204d69736573"),
+                ("4d757272617920526f746862617264",
+                 "f7eb9a09468f9564819de07ada77a6cf5d5eacd84682067538bf2c4e4c905e5cc35cc3ff41241e47ae3bd296477a236ef185e5a8a0f18d65e97ed56bb229af09cd5a7853fd48a0a764b27663602b4774d42e7c61a3cce33e5c7ed3595135578d9c620976f42d01273f17242291e4a2f2ee3b0621d7ab1e8c531b6e95cddf2b38aac9c9d4bdc18ee6008326dc2b736cc5ef91b1e07b8e781d2b4baca02fcbcf"),
+                ("462e20412e20486179656b",
+                 "7f269e88932739be86484a89fce5151d0afac70a26384b7acbcd76fe782bbfb060ecf37d59e8a0223fb9aa890aa6ca472cd0aa728341db6da7fd91a29be0ffb0d4ea2e7b1457c05373425b9189c9e8567a11a9666bef8f3257b693647c9a79ae14550b"),
+                ("4361726c204d656e676572",
+                 "2b52a782a91f25f8a4e0520be46b344531308a3da31b4554bfef31"),
+                ("4a65616e2d426170746973746520536179",
+                 "832bf36d0a584dd31866ac80425b0c1034d615fe48cfc47254679aa801a6372bd6"),
+                ("457567656e2042f6686d20766f6e2042617765726b",
+                 "6f853b19b1eef37217d3b0c6aa81a3ffe1357dd6af97a77cfe34bd17c0541142f68419c59b"),
+            ],
+        },
+        NoiseVector {
+            protocol_name: "Noise_IX_25519_ChaChaPoly_BLAKE2s",
+            prologue: "4a6f686e2047616c74",
+            psk: "",
+            init_static: "e61ef9919cde45dd5f82166404bd08e38bceb5dfdfded0a34c8df7ed542214d1",
+            init_ephemeral: "893e28b9dc6ca8d611ab664754b8ceb7bac5117349a4439a6b0569da977c464a",
+            init_remote_static: "",
+            resp_static: "4a3acbfdb163dec651dfa3194dece676d437029c62a408b4c5ea9114246e4893",
+            resp_ephemeral: "bbdb4cdbd309f1a1f2e1456967fe288cadd6f712d65dc7b7793d5e63da6b375b",
+            resp_remote_static: "",
+            handshake_hash: "c6ee4cf7102f1077793673c5daec6ceebda421179135487f3d9a8c8ec3745f82",
+            messages: &[
+                ("4c756477696720766f6e204d69736573",
+                 "ca35def5ae56cec33dc2036731ab14896bc4c75dbb07a61f879f8e3afa4c79446bc3822a2aa7f4e6981d6538692b3cdf3e6df9eea6ed269eb41d93c22757b75a4c756477696720766f6e204d69736573"),
+                ("4d757272617920526f746862617264",
+                 "95ebc60d2b1fa672c1f46a8aa265ef51bfe38e7ccb39ec5be34069f14480884398e7f90d906b0948dbc71ea7020ce711a6cfde5ed7ad1d43def67fb5be6190b5028fbb2556e9378b65b5e86195a7cd4cadddad64de91fbd1aaaae8621d31358a73dbfd6b68b96fb5bb8972bc28c2e2"),
+                ("462e20412e20486179656b",
+                 "62bc36955e7d6399c18531eb05fc8f4646da466a98a7e5cf1942e7"),
+                ("4361726c204d656e676572",
+                 "6be3ee3f7e5ccc4152754e4b22d87ee0045e6cd84654fd2ceb3720"),
+                ("4a65616e2d426170746973746520536179",
+                 "19b242089e28f5b8c2881f36dacb6953de1b576b722359a0ab8ac478c3c8fcacb1"),
+                ("457567656e2042f6686d20766f6e2042617765726b",
+                 "8db09f596ff2651900ff82316220328bb0ac49a520c58ff2504c67bb02c550d9546c483708"),
+            ],
+        },
+        NoiseVector {
+            protocol_name: "Noise_N_25519_AESGCM_SHA256",
+            prologue: "4a6f686e2047616c74",
+            psk: "",
+            init_static: "",
+            init_ephemeral: "893e28b9dc6ca8d611ab664754b8ceb7bac5117349a4439a6b0569da977c464a",
+            init_remote_static: "31e0303fd6418d2f8c0e78b91f22e8caed0fbe48656dcf4767e4834f701b8f62",
+            resp_static: "4a3acbfdb163dec651dfa3194dece676d437029c62a408b4c5ea9114246e4893",
+            resp_ephemeral: "",
+            resp_remote_static: "",
+            handshake_hash: "a0ade8324bb678105734fd68f9968c4045f993547de138803ab5aa8e7169b53b",
+            messages: &[
+                ("4c756477696720766f6e204d69736573",
+                 "ca35def5ae56cec33dc2036731ab14896bc4c75dbb07a61f879f8e3afa4c7944579bcbff029d662564fea10d563023312ca97f6dcd2a0ff611e8ee5352825435"),
+                ("4d757272617920526f746862617264",
+                 "ece41448702945ed9004d6d83e98f24eadf3ba377084829bcc1508f37ebf52"),
+                ("462e20412e20486179656b",
+                 "c23a5f1fbd44cc5ccf9f5173dbdc269cd62e4d3da636f9f7d86da8"),
+                ("4361726c204d656e676572",
+                 "3f75522cb7de92072d28d7f2aed8eaec0a16e4a2f72cfc533656c8"),
+                ("4a65616e2d426170746973746520536179",
+                 "a6d69707c4915cb7322a678c01e212005f11a948e5fb22506aa81943793c6c289f"),
+                ("457567656e2042f6686d20766f6e2042617765726b",
+                 "6d1a4b057667d4b8ae113f219c53b57c4c3574b259701f0e0e77d762c1188b04fa76d255f4"),
+            ],
+        },
+        NoiseVector {
+            protocol_name: "Noise_N_25519_ChaChaPoly_BLAKE2s",
+            prologue: "4a6f686e2047616c74",
+            psk: "",
+            init_static: "",
+            init_ephemeral: "893e28b9dc6ca8d611ab664754b8ceb7bac5117349a4439a6b0569da977c464a",
+            init_remote_static: "31e0303fd6418d2f8c0e78b91f22e8caed0fbe48656dcf4767e4834f701b8f62",
+            resp_static: "4a3acbfdb163dec651dfa3194dece676d437029c62a408b4c5ea9114246e4893",
+            resp_ephemeral: "",
+            resp_remote_static: "",
+            handshake_hash: "39a2ce8290b63e1e7c94fb9244cea84c645161c0dced1b3f5d0672cf4c6ee4e8",
+            messages: &[
+                ("4c756477696720766f6e204d69736573",
+                 "ca35def5ae56cec33dc2036731ab14896bc4c75dbb07a61f879f8e3afa4c79441b168ed8bbe8220b52bbbde6593d109d78c299b567f6e69276efcf2659c39073"),
+                ("4d757272617920526f746862617264",
+                 "a7b5d1962001e9c4d965ea5f133941e9e6989094bcde637a582c34b954f34a"),
+                ("462e20412e20486179656b",
+                 "16ff2557d5d671abe58c88d2a31b58e3a494ab3a6498124be0ea3f"),
+                ("4361726c204d656e676572",
+                 "1a6e85b0ef71c38db2c2bf3ebef1d41dc93e26bea6899187d5633d"),
+                ("4a65616e2d426170746973746520536179",
+                 "00ad2b7d0a03a748d0aefd3accee7bbbcc0bb0ed64d685b2ee8af78997a0245e3f"),
+                ("457567656e2042f6686d20766f6e2042617765726b",
+                 "5631105c749b9550b27d7926dec0c5b83d4bf207688deccd51b50dd7fc9d5e337bba9c3177"),
+            ],
+        },
+        NoiseVector {
+            protocol_name: "Noise_K_25519_ChaChaPoly_BLAKE2s",
+            prologue: "4a6f686e2047616c74",
+            psk: "",
+            init_static: "e61ef9919cde45dd5f82166404bd08e38bceb5dfdfded0a34c8df7ed542214d1",
+            init_ephemeral: "893e28b9dc6ca8d611ab664754b8ceb7bac5117349a4439a6b0569da977c464a",
+            init_remote_static: "31e0303fd6418d2f8c0e78b91f22e8caed0fbe48656dcf4767e4834f701b8f62",
+            resp_static: "4a3acbfdb163dec651dfa3194dece676d437029c62a408b4c5ea9114246e4893",
+            resp_ephemeral: "",
+            resp_remote_static: "6bc3822a2aa7f4e6981d6538692b3cdf3e6df9eea6ed269eb41d93c22757b75a",
+            handshake_hash: "5bc4f2a41423bc4ca48bfa47151056389a9e0a19087aba0d73152239b0febb6a",
+            messages: &[
+                ("4c756477696720766f6e204d69736573",
+                 "ca35def5ae56cec33dc2036731ab14896bc4c75dbb07a61f879f8e3afa4c79443ab57eb07c96791ebddff95c2ed2ccfe412d87270c753c0a5b5fe46164087647"),
+                ("4d757272617920526f746862617264",
+                 "3e7b4d83fa0cca62cc0b6d202da416c0b59289e518982742851e534f1916f8"),
+                ("462e20412e20486179656b",
+                 "d52fe3eee4de396b592afea7eb632020587aa4384200ed9bca9585"),
+                ("4361726c204d656e676572",
+                 "51476b0e939b9901d9c265533d2845591813dcca1ce834090f977d"),
+                ("4a65616e2d426170746973746520536179",
+                 "24848a58c0cf7be87fb648166f3ac49cb6e76d08a353d4c4836006d48bc40275f1"),
+                ("457567656e2042f6686d20766f6e2042617765726b",
+                 "95f88b7496841fd0df89d5834b31640bddc9ca51d4b466c929a8833d263c2771d19720a5df"),
+            ],
+        },
+        NoiseVector {
+            protocol_name: "Noise_X_25519_ChaChaPoly_BLAKE2s",
+            prologue: "4a6f686e2047616c74",
+            psk: "",
+            init_static: "e61ef9919cde45dd5f82166404bd08e38bceb5dfdfded0a34c8df7ed542214d1",
+            init_ephemeral: "893e28b9dc6ca8d611ab664754b8ceb7bac5117349a4439a6b0569da977c464a",
+            init_remote_static: "31e0303fd6418d2f8c0e78b91f22e8caed0fbe48656dcf4767e4834f701b8f62",
+            resp_static: "4a3acbfdb163dec651dfa3194dece676d437029c62a408b4c5ea9114246e4893",
+            resp_ephemeral: "",
+            resp_remote_static: "",
+            handshake_hash: "f781a940343a817adc2483932dd05e7036171cdcf1d0a0bf0cd869f7aa557c6a",
+            messages: &[
+                ("4c756477696720766f6e204d69736573",
+                 "ca35def5ae56cec33dc2036731ab14896bc4c75dbb07a61f879f8e3afa4c79448bc3b729d16d3944f1bfae9fa98e0d306234bfadc44880f99a69c6e55b6c1458e9c9dacab3f29aac44b435c57dc436d0830ae461a4479228789a38085be55b13e0128564987994de842e73dd0a5c328b"),
+                ("4d757272617920526f746862617264",
+                 "aee89720731c98ccf15f4495ae3f6f2f7ed8e2164a1494c9e785b076e69cfc"),
+                ("462e20412e20486179656b",
+                 "c88787701dc4365fe9dee7c0f23d91afdc214a459eadbc9f1d0220"),
+                ("4361726c204d656e676572",
+                 "d784542b85444798fb7d5bd1317f61ad701b43dd63fe3503efb267"),
+                ("4a65616e2d426170746973746520536179",
+                 "fd60a2da59e84a83e247f291752c71036b01f5ca996d8c24f324bf9260b6809d02"),
+                ("457567656e2042f6686d20766f6e2042617765726b",
+                 "1897139789b0cf8063b7ae9eba73d1e49e753ab7bb3f19316e54d3e20c69f25e819789c85f"),
+            ],
+        },
+        NoiseVector {
+            protocol_name: "Noise_NNpsk0_25519_ChaChaPoly_BLAKE2s",
+            prologue: "4a6f686e2047616c74",
+            psk: "54686973206973206d7920417573747269616e20706572737065637469766521",
+            init_static: "",
+            init_ephemeral: "893e28b9dc6ca8d611ab664754b8ceb7bac5117349a4439a6b0569da977c464a",
+            init_remote_static: "",
+            resp_static: "",
+            resp_ephemeral: "bbdb4cdbd309f1a1f2e1456967fe288cadd6f712d65dc7b7793d5e63da6b375b",
+            resp_remote_static: "",
+            handshake_hash: "b3e9c846d264120a4211e18307da91157a21e92e69b639c50f027f101db3e1a6",
+            messages: &[
+                ("4c756477696720766f6e204d69736573",
+                 "ca35def5ae56cec33dc2036731ab14896bc4c75dbb07a61f879f8e3afa4c7944fda936bec35a8adfdff198386f7d5475880897edaaf7495314c99095a2e4d66a"),
+                ("4d757272617920526f746862617264",
+                 "95ebc60d2b1fa672c1f46a8aa265ef51bfe38e7ccb39ec5be34069f1448088434cd2a371993ba41ea11448024fca32766b169183c9e691a7a433279da7e729"),
+                ("462e20412e20486179656b",
+                 "bc44da303ae0beb08075fc4eb4e58235c67c2d1f53a4f2fff0bca7"),
+                ("4361726c204d656e676572",
+                 "416d1af83e9fa6966ce4e871156b131aa9bd7e9a1d6f8794f4872a"),
+                ("4a65616e2d426170746973746520536179",
+                 "8a7d81b77bcc6c072f2b807da066efba6b5fab9edf71a7faceb2c8454b0cfef608"),
+                ("457567656e2042f6686d20766f6e2042617765726b",
+                 "1e2ee010f72894824a25a867664ff298f2548a145dc4e9d27b1cad83f32fa7c54d69dc3279"),
+            ],
+        },
+        NoiseVector {
+            protocol_name: "Noise_NNpsk2_25519_ChaChaPoly_BLAKE2s",
+            prologue: "4a6f686e2047616c74",
+            psk: "54686973206973206d7920417573747269616e20706572737065637469766521",
+            init_static: "",
+            init_ephemeral: "893e28b9dc6ca8d611ab664754b8ceb7bac5117349a4439a6b0569da977c464a",
+            init_remote_static: "",
+            resp_static: "",
+            resp_ephemeral: "bbdb4cdbd309f1a1f2e1456967fe288cadd6f712d65dc7b7793d5e63da6b375b",
+            resp_remote_static: "",
+            handshake_hash: "84a621ae15c80eab5b340cf10fee7a5364bd2c94ada0cc06ef27ecd14797b0fa",
+            messages: &[
+                ("4c756477696720766f6e204d69736573",
+                 "ca35def5ae56cec33dc2036731ab14896bc4c75dbb07a61f879f8e3afa4c79447dabf550042b63cd69e1826848d383fce196ed4a9d55205c3e555ef49aaa3239"),
+                ("4d757272617920526f746862617264",
+                 "95ebc60d2b1fa672c1f46a8aa265ef51bfe38e7ccb39ec5be34069f1448088437ec230bbb3c3c83e65e2678f34d59bf01abb502670bb0e53b6bc8adb0646ea"),
+                ("462e20412e20486179656b",
+                 "1767dbf2433c64ad3ba968745e0b84f6b560d2dc1083058cc8fac2"),
+                ("4361726c204d656e676572",
+                 "99d59bf6f0c25b4ae6d683675edfe7eba6b3fdcef797833973805f"),
+                ("4a65616e2d426170746973746520536179",
+                 "b09f1a88b362d1f5873a843788dad3b62bb2d9e539857135c9c0e24c301de44b98"),
+                ("457567656e2042f6686d20766f6e2042617765726b",
+                 "642a09ab5ad552d34a819c5432ff09c0c4d616e78374bfd323b59482302b130b6413a2e5d4"),
+            ],
+        },
+        NoiseVector {
+            protocol_name: "Noise_NKpsk0_448_AESGCM_BLAKE2s",
+            prologue: "4a6f686e2047616c74",
+            psk: "54686973206973206d7920417573747269616e20706572737065637469766521",
+            init_static: "",
+            init_ephemeral: "7fd26c8b8a0d5c98c85ff9ca1d7bc66d78578b9f2c4c170850748b27992767e6ea6cc9992a561c9d19dfc342e260c280ef4f3f9b8f879d4e",
+            init_remote_static: "bd200fa6d50db3a743797b00aca1b70f417bfc381b28b21b5835d84cf7a6da6abba19e3ba7d46b253412b74665d4627b65fcef3f29c95d3e",
+            resp_static: "a9b45971180882a79b89a3399544a425ef8136d278efa443ed67d3ff9d36e883bc330c6295bbf6ed73ff6fd10cbed767ad05ce03ebd27c7c",
+            resp_ephemeral: "3facf7503ebee252465689f1d4e3b1dd219639ef9de4ffd6049d6d71a0f62126840febb99042421ce12af6626d98d9170260390fbc8399a5",
+            resp_remote_static: "",
+            handshake_hash: "c85403f7dc5f55cd3578de7a384b779a045cdd11074cdf0db2d1c8fcf59c065d",
+            messages: &[
+                ("4c756477696720766f6e204d69736573",
+                 "6cfcb98ae6b1bc5659cadc595bf664e17094404eae6b45fde6fc40ca937d1dbe1464cb66eb21fdbaa487cd0d11d6dce5aa07b8219bfdc49a1392c32f447ddd47623289eba08d72de38dbaba193c8315c4e996ba3e833f20c"),
+                ("4d757272617920526f746862617264",
+                 "f7eb9a09468f9564819de07ada77a6cf5d5eacd84682067538bf2c4e4c905e5cc35cc3ff41241e47ae3bd296477a236ef185e5a8a0f18d65d7ed029b1056cf0662fc343c3ca311162db9b11b1876156ca62fe0c0910772"),
+                ("462e20412e20486179656b",
+                 "ac7736f3476f7987e3749763de428718a869405730e6b2b9aebe56"),
+                ("4361726c204d656e676572",
+                 "8df1f7e00bc60bfa3aae3dbe65efa41984d33ebcdd6690b1718754"),
+                ("4a65616e2d426170746973746520536179",
+                 "e14d73617375e49ec9fa3a3bb64672f3b64619c16f71810544826e23b44737512e"),
+                ("457567656e2042f6686d20766f6e2042617765726b",
+                 "1c009cc25cc2260ae51a66fe1a4c1431d2df7ca8e5e9faba2297f004eb4c4f4bcebcab880a"),
+            ],
+        },
+        NoiseVector {
+            protocol_name: "Noise_NKpsk0_25519_ChaChaPoly_BLAKE2s",
+            prologue: "4a6f686e2047616c74",
+            psk: "54686973206973206d7920417573747269616e20706572737065637469766521",
+            init_static: "",
+            init_ephemeral: "893e28b9dc6ca8d611ab664754b8ceb7bac5117349a4439a6b0569da977c464a",
+            init_remote_static: "31e0303fd6418d2f8c0e78b91f22e8caed0fbe48656dcf4767e4834f701b8f62",
+            resp_static: "4a3acbfdb163dec651dfa3194dece676d437029c62a408b4c5ea9114246e4893",
+            resp_ephemeral: "bbdb4cdbd309f1a1f2e1456967fe288cadd6f712d65dc7b7793d5e63da6b375b",
+            resp_remote_static: "",
+            handshake_hash: "6bd69bd4066f41f32e47134976f5bf01606f7a4a0e04369fe61158b06f3a144e",
+            messages: &[
+                ("4c756477696720766f6e204d69736573",
+                 "ca35def5ae56cec33dc2036731ab14896bc4c75dbb07a61f879f8e3afa4c794427635ede06947b2d3acd77a36788aaaf17e9f5a8ac252e560fb421ba161a2cf8"),
+                ("4d757272617920526f746862617264",
+                 "95ebc60d2b1fa672c1f46a8aa265ef51bfe38e7ccb39ec5be34069f144808843d682eb9cf4fee6816c8c8cfd34c15774321e234e3a426d7cfd3f13e5e84d04"),
+                ("462e20412e20486179656b",
+                 "b6645684db57679aa08f0b3352d58f32ec7f1e1a02083d5bd54277"),
+                ("4361726c204d656e676572",
+                 "473a9a4109eba0939e934640d318984df8d0900aa922f0195a09ad"),
+                ("4a65616e2d426170746973746520536179",
+                 "c8c44a16fff728f83e61272382149feadd3eb0ee1bab6313f84c72fe1581225236"),
+                ("457567656e2042f6686d20766f6e2042617765726b",
+                 "21354f87158ac5e357529e87e8c84cfcdb49c8a080550c8f908d05ef7ea82ca525e3d1398e"),
+            ],
+        },
+        NoiseVector {
+            protocol_name: "Noise_NKpsk2_25519_ChaChaPoly_BLAKE2s",
+            prologue: "4a6f686e2047616c74",
+            psk: "54686973206973206d7920417573747269616e20706572737065637469766521",
+            init_static: "",
+            init_ephemeral: "893e28b9dc6ca8d611ab664754b8ceb7bac5117349a4439a6b0569da977c464a",
+            init_remote_static: "31e0303fd6418d2f8c0e78b91f22e8caed0fbe48656dcf4767e4834f701b8f62",
+            resp_static: "4a3acbfdb163dec651dfa3194dece676d437029c62a408b4c5ea9114246e4893",
+            resp_ephemeral: "bbdb4cdbd309f1a1f2e1456967fe288cadd6f712d65dc7b7793d5e63da6b375b",
+            resp_remote_static: "",
+            handshake_hash: "7468183b713ce7e8ad83eec3fa7dae84ad9d64679ffa386d618721b7f1ae95b6",
+            messages: &[
+                ("4c756477696720766f6e204d69736573",
+                 "ca35def5ae56cec33dc2036731ab14896bc4c75dbb07a61f879f8e3afa4c79449b81e7722cc191126a9d3892203ec4cd791774188424a23f684ff03c726273de"),
+                ("4d757272617920526f746862617264",
+                 "95ebc60d2b1fa672c1f46a8aa265ef51bfe38e7ccb39ec5be34069f144808843d06453b74535a533d3ccb782a50b4f48c80f82d3b6d1bf72692144691a634f"),
+                ("462e20412e20486179656b",
+                 "a6f7f4f5af57e015ee7e1a4113e09f637b9ed27d24cda23ab29262"),
+                ("4361726c204d656e676572",
+                 "847a9067b69a7c5455900d88f5ce079487866a505ad8844929ebcc"),
+                ("4a65616e2d426170746973746520536179",
+                 "200d2686b66fe57c3ca8f24c37c04c64e6cba6fe08bbd5301d6d4734c1caf5b634"),
+                ("457567656e2042f6686d20766f6e2042617765726b",
+                 "b78d4f43dbbc99b97a64865b55e1856f4c97e95638666437c805a3f331ad4b48c5c31e7623"),
+            ],
+        },
+        NoiseVector {
+            protocol_name: "Noise_NXpsk2_25519_ChaChaPoly_BLAKE2s",
+            prologue: "4a6f686e2047616c74",
+            psk: "54686973206973206d7920417573747269616e20706572737065637469766521",
+            init_static: "",
+            init_ephemeral: "893e28b9dc6ca8d611ab664754b8ceb7bac5117349a4439a6b0569da977c464a",
+            init_remote_static: "",
+            resp_static: "4a3acbfdb163dec651dfa3194dece676d437029c62a408b4c5ea9114246e4893",
+            resp_ephemeral: "bbdb4cdbd309f1a1f2e1456967fe288cadd6f712d65dc7b7793d5e63da6b375b",
+            resp_remote_static: "",
+            handshake_hash: "60638b74f631be6f910b0350bffb9053554c00b2e34bdd84761645d2f19e6ec6",
+            messages: &[
+                ("4c756477696720766f6e204d69736573",
+                 "ca35def5ae56cec33dc2036731ab14896bc4c75dbb07a61f879f8e3afa4c794497bdcf5dc128b7bc5b8f2b6ac1a46dff9f9469337cfac0098f87b2a577cece84"),
+                ("4d757272617920526f746862617264",
+                 "95ebc60d2b1fa672c1f46a8aa265ef51bfe38e7ccb39ec5be34069f144808843c39479e89953f195c89ee9a53f2e291727e15ab09a61b1ca623ee98d3d2549bda7af1881b0ae7ba4bb6e8f71e119927c6c8510ae728cd8c258c6200b71c86e16f934ba80fe35e708f8a52a5e193346"),
+                ("462e20412e20486179656b",
+                 "499085038a82c4bc9895c069b9a71ead87545a9184a395d74378e1"),
+                ("4361726c204d656e676572",
+                 "966e81056dafc90a22e1b23039427325cab7791b92bed9a562808e"),
+                ("4a65616e2d426170746973746520536179",
+                 "f5731b781c54f95e5c75bbd6b9b88113de6097618936495b0ba90d545187a3512c"),
+                ("457567656e2042f6686d20766f6e2042617765726b",
+                 "1ad6f2ce261f2f6773363a6f3efc2105c98d960b910629da596e394b052389c66ae988bd84"),
+            ],
+        },
+        NoiseVector {
+            protocol_name: "Noise_XNpsk3_25519_ChaChaPoly_BLAKE2s",
+            prologue: "4a6f686e2047616c74",
+            psk: "54686973206973206d7920417573747269616e20706572737065637469766521",
+            init_static: "e61ef9919cde45dd5f82166404bd08e38bceb5dfdfded0a34c8df7ed542214d1",
+            init_ephemeral: "893e28b9dc6ca8d611ab664754b8ceb7bac5117349a4439a6b0569da977c464a",
+            init_remote_static: "",
+            resp_static: "",
+            resp_ephemeral: "bbdb4cdbd309f1a1f2e1456967fe288cadd6f712d65dc7b7793d5e63da6b375b",
+            resp_remote_static: "",
+            handshake_hash: "74be92e6c7f9227e160a379106378ccea1322f6d32ef87ff482c957c65dcccf9",
+            messages: &[
+                ("4c756477696720766f6e204d69736573",
+                 "ca35def5ae56cec33dc2036731ab14896bc4c75dbb07a61f879f8e3afa4c7944c5e7d2bbee60bd4d39b7f4cb74dce7fd3b39d29e5c927bd14b0aff695f892ba7"),
+                ("4d757272617920526f746862617264",
+                 "95ebc60d2b1fa672c1f46a8aa265ef51bfe38e7ccb39ec5be34069f1448088430391ed5f1918d5d5b8725c3667ffb2e6d1bdd909f51cb00d3ac926093bf8bf"),
+                ("462e20412e20486179656b",
+                 "ccfbe8bf2ec03c2ff56fae2ea8e773e16810d2938fb0e04f08ea0176b37ca90979fc26e537738c4f24ac8ad5696ff3a57be22f3eddfbce3561ee5e47024e3805403581cc98f251ab7c3ca2"),
+                ("4361726c204d656e676572",
+                 "08f332992fec2351c9cf9395bd6ca83bebd49760091caf0819d740"),
+                ("4a65616e2d426170746973746520536179",
+                 "9f47bc527a22044cc36f0ed5de112a465ad0c488217d41b25a555c767609fa159b"),
+                ("457567656e2042f6686d20766f6e2042617765726b",
+                 "8a661c1c1618a5f3cdc0c0e143fbf409b63e3c03433f030250131a7be9607e131c5d7920aa"),
+            ],
+        },
+        NoiseVector {
+            protocol_name: "Noise_XKpsk3_25519_ChaChaPoly_BLAKE2s",
+            prologue: "4a6f686e2047616c74",
+            psk: "54686973206973206d7920417573747269616e20706572737065637469766521",
+            init_static: "e61ef9919cde45dd5f82166404bd08e38bceb5dfdfded0a34c8df7ed542214d1",
+            init_ephemeral: "893e28b9dc6ca8d611ab664754b8ceb7bac5117349a4439a6b0569da977c464a",
+            init_remote_static: "31e0303fd6418d2f8c0e78b91f22e8caed0fbe48656dcf4767e4834f701b8f62",
+            resp_static: "4a3acbfdb163dec651dfa3194dece676d437029c62a408b4c5ea9114246e4893",
+            resp_ephemeral: "bbdb4cdbd309f1a1f2e1456967fe288cadd6f712d65dc7b7793d5e63da6b375b",
+            resp_remote_static: "",
+            handshake_hash: "9137100800712f6768741a8b83e43ece838aafdefafcc755cb4b600f90588ec6",
+            messages: &[
+                ("4c756477696720766f6e204d69736573",
+                 "ca35def5ae56cec33dc2036731ab14896bc4c75dbb07a61f879f8e3afa4c79446f78efab3dd17dddf573d7f399c41a491e3d4a8c643e419bdf51d1933b652b3a"),
+                ("4d757272617920526f746862617264",
+                 "95ebc60d2b1fa672c1f46a8aa265ef51bfe38e7ccb39ec5be34069f14480884363bbc83fb0e2a44b36feb19c5ce545adb9cc59b96cc6b987ec62c8bb0db6e6"),
+                ("462e20412e20486179656b",
+                 "285922ecd27adc8258a798d4f85ad5fcc86e7862210ea3dfa3cb23659a19630c6c2ff6890a0485e793a3620d87a652e527a394ac202551878895c866e86c74ab489720317c7dea72d8e652"),
+                ("4361726c204d656e676572",
+                 "fc97959e232b766114c282617cda61c902ed282468130ec94e0efa"),
+                ("4a65616e2d426170746973746520536179",
+                 "78d7d2f41577b2ff7b1b2c62df539b3b0b45acd5ccb01d07e6e889c5f7a7682f06"),
+                ("457567656e2042f6686d20766f6e2042617765726b",
+                 "8040fee7bccafbb0ffbeffd38f1df4fdc0ac0c7ec182df49c81245d97838638df46d77158e"),
+            ],
+        },
+        NoiseVector {
+            protocol_name: "Noise_XXpsk3_25519_ChaChaPoly_BLAKE2s",
+            prologue: "4a6f686e2047616c74",
+            psk: "54686973206973206d7920417573747269616e20706572737065637469766521",
+            init_static: "e61ef9919cde45dd5f82166404bd08e38bceb5dfdfded0a34c8df7ed542214d1",
+            init_ephemeral: "893e28b9dc6ca8d611ab664754b8ceb7bac5117349a4439a6b0569da977c464a",
+            init_remote_static: "",
+            resp_static: "4a3acbfdb163dec651dfa3194dece676d437029c62a408b4c5ea9114246e4893",
+            resp_ephemeral: "bbdb4cdbd309f1a1f2e1456967fe288cadd6f712d65dc7b7793d5e63da6b375b",
+            resp_remote_static: "",
+            handshake_hash: "fc0819f08aebc23de9a783653d8d7d6395b7d243d9deec12f5d6fe2f4c206673",
+            messages: &[
+                ("4c756477696720766f6e204d69736573",
+                 "ca35def5ae56cec33dc2036731ab14896bc4c75dbb07a61f879f8e3afa4c7944325ea71699951ece20f284b6ad9604a029eb335bf84564c308b6ade90ae45078"),
+                ("4d757272617920526f746862617264",
+                 "95ebc60d2b1fa672c1f46a8aa265ef51bfe38e7ccb39ec5be34069f1448088432645535233ffe1432564d66a85227b677ced6fc2730ae0998ff49aa1dc56b8186e31b16e416f5d9c03c71f6c34fd37ec013105020070a8b00c000ce7ed56629c119795f96463274bc05519d5c24dc1"),
+                ("462e20412e20486179656b",
+                 "adf16c5375ec4172576783fd59f2bfa5c7a320d0a13b759592e1a2ddf5524cce59ccbb92ff5d321fced3bdb2840596df562c0e68aad41b090abd285f6d300130072e06964a6ba494e58d47"),
+                ("4361726c204d656e676572",
+                 "dcdc045c8e9ec36c8ea4078552e5849f87cb9bdfbd2a4eee3baaf6"),
+                ("4a65616e2d426170746973746520536179",
+                 "4d11ed1f242e199dbcbc9773495834a95e8a6109e2b555aeb50780e69b152821e4"),
+                ("457567656e2042f6686d20766f6e2042617765726b",
+                 "4d1e6873ffcc88490be6914928590f63253c2db434f1f206f083f89ca559a3e60a8dcc4f12"),
+            ],
+        },
+        NoiseVector {
+            protocol_name: "Noise_KNpsk0_25519_ChaChaPoly_BLAKE2s",
+            prologue: "4a6f686e2047616c74",
+            psk: "54686973206973206d7920417573747269616e20706572737065637469766521",
+            init_static: "e61ef9919cde45dd5f82166404bd08e38bceb5dfdfded0a34c8df7ed542214d1",
+            init_ephemeral: "893e28b9dc6ca8d611ab664754b8ceb7bac5117349a4439a6b0569da977c464a",
+            init_remote_static: "",
+            resp_static: "",
+            resp_ephemeral: "bbdb4cdbd309f1a1f2e1456967fe288cadd6f712d65dc7b7793d5e63da6b375b",
+            resp_remote_static: "6bc3822a2aa7f4e6981d6538692b3cdf3e6df9eea6ed269eb41d93c22757b75a",
+            handshake_hash: "235b9c97b25db005a88c83045904cc07b349f28eb3643053a03adb9817d5c874",
+            messages: &[
+                ("4c756477696720766f6e204d69736573",
+                 "ca35def5ae56cec33dc2036731ab14896bc4c75dbb07a61f879f8e3afa4c794483acf0be48f87c43c498f486d7c1874d0747701aa7ec7ab1e36f83c59f9fbb13"),
+                ("4d757272617920526f746862617264",
+                 "95ebc60d2b1fa672c1f46a8aa265ef51bfe38e7ccb39ec5be34069f1448088439beb9a4b1f2306829aa2435daf14cb7f154f143feae1b87bc93c90fd5496e1"),
+                ("462e20412e20486179656b",
+                 "f80c04074a17c90c01c97433b4f7b133f9495dfc1e7b1505a825fd"),
+                ("4361726c204d656e676572",
+                 "cf2fffd0b7b3218b93a7c3b3952e48add6853e9012f050df974642"),
+                ("4a65616e2d426170746973746520536179",
+                 "3d6cf45526f1e3fbbfcf4d653a99bdd25429895e347fc41e5b6af8d5d0f8abee63"),
+                ("457567656e2042f6686d20766f6e2042617765726b",
+                 "5cca487eecaeecd6025c5e7ee0cb89a6862c847b6ac42cfb577bf58a3e30b7eab1b7996258"),
+            ],
+        },
+        NoiseVector {
+            protocol_name: "Noise_KNpsk2_25519_ChaChaPoly_BLAKE2s",
+            prologue: "4a6f686e2047616c74",
+            psk: "54686973206973206d7920417573747269616e20706572737065637469766521",
+            init_static: "e61ef9919cde45dd5f82166404bd08e38bceb5dfdfded0a34c8df7ed542214d1",
+            init_ephemeral: "893e28b9dc6ca8d611ab664754b8ceb7bac5117349a4439a6b0569da977c464a",
+            init_remote_static: "",
+            resp_static: "",
+            resp_ephemeral: "bbdb4cdbd309f1a1f2e1456967fe288cadd6f712d65dc7b7793d5e63da6b375b",
+            resp_remote_static: "6bc3822a2aa7f4e6981d6538692b3cdf3e6df9eea6ed269eb41d93c22757b75a",
+            handshake_hash: "4c35410f45bd38f636934f2e8894fb9ae72a928e649ba4fdab62f67b67fea602",
+            messages: &[
+                ("4c756477696720766f6e204d69736573",
+                 "ca35def5ae56cec33dc2036731ab14896bc4c75dbb07a61f879f8e3afa4c7944d8b18198501b129b05163c3b4ea9e59ef49238f28730d4398699fba2e78391c0"),
+                ("4d757272617920526f746862617264",
+                 "95ebc60d2b1fa672c1f46a8aa265ef51bfe38e7ccb39ec5be34069f14480884374519fa9659111620fe0c21e8b62e878e1819f85da30424693628ca755fc24"),
+                ("462e20412e20486179656b",
+                 "9295326f750e4cc6238088c6127bae20cbe8c0a278ad9c970ce8f2"),
+                ("4361726c204d656e676572",
+                 "be3e544073b0db44e045633b1f9b2ec43764095c84f96bdfef7f4c"),
+                ("4a65616e2d426170746973746520536179",
+                 "f42c2439ddfe2f82efa4eabe67f26b971ddfedc499554c5ec1c1ac888b184a0c7f"),
+                ("457567656e2042f6686d20766f6e2042617765726b",
+                 "b9533b3fcfb737497cab64a70ab09dc5de68d022ace8c833b3aa8fa51da7a2ceddd86fd5cd"),
+            ],
+        },
+        NoiseVector {
+            protocol_name: "Noise_KKpsk0_25519_ChaChaPoly_BLAKE2s",
+            prologue: "4a6f686e2047616c74",
+            psk: "54686973206973206d7920417573747269616e20706572737065637469766521",
+            init_static: "e61ef9919cde45dd5f82166404bd08e38bceb5dfdfded0a34c8df7ed542214d1",
+            init_ephemeral: "893e28b9dc6ca8d611ab664754b8ceb7bac5117349a4439a6b0569da977c464a",
+            init_remote_static: "31e0303fd6418d2f8c0e78b91f22e8caed0fbe48656dcf4767e4834f701b8f62",
+            resp_static: "4a3acbfdb163dec651dfa3194dece676d437029c62a408b4c5ea9114246e4893",
+            resp_ephemeral: "bbdb4cdbd309f1a1f2e1456967fe288cadd6f712d65dc7b7793d5e63da6b375b",
+            resp_remote_static: "6bc3822a2aa7f4e6981d6538692b3cdf3e6df9eea6ed269eb41d93c22757b75a",
+            handshake_hash: "cb6446644ec2b5f98feac9826aadfc558ed504e3c4b44395b7ad37c773962a96",
+            messages: &[
+                ("4c756477696720766f6e204d69736573",
+                 "ca35def5ae56cec33dc2036731ab14896bc4c75dbb07a61f879f8e3afa4c794416088e45dd5bcdb9bee7037e09be96e5c9750d48aded34648f0663750995e4fa"),
+                ("4d757272617920526f746862617264",
+                 "95ebc60d2b1fa672c1f46a8aa265ef51bfe38e7ccb39ec5be34069f144808843fcf5c1d990871f224ffe090498a03bd50db64dcf448db09194f5a93e1aa73b"),
+                ("462e20412e20486179656b",
+                 "4703888dd8d47d781af6a5c61ba22562e2f657883f13d29817d1b6"),
+                ("4361726c204d656e676572",
+                 "930c11f54ccb098a7f851e6026aaab4c56ec9100f356d95a9543cd"),
+                ("4a65616e2d426170746973746520536179",
+                 "f31e8e0a4cf849ce4e931cb2cddb10ced898b94164a51bcd9808bea50359674bbb"),
+                ("457567656e2042f6686d20766f6e2042617765726b",
+                 "7144af46873ca3061ca9f2c020b55a8087bba51d2fb7aacec53d39ce6ccf70da0b3e02949a"),
+            ],
+        },
+        NoiseVector {
+            protocol_name: "Noise_KKpsk2_25519_ChaChaPoly_BLAKE2s",
+            prologue: "4a6f686e2047616c74",
+            psk: "54686973206973206d7920417573747269616e20706572737065637469766521",
+            init_static: "e61ef9919cde45dd5f82166404bd08e38bceb5dfdfded0a34c8df7ed542214d1",
+            init_ephemeral: "893e28b9dc6ca8d611ab664754b8ceb7bac5117349a4439a6b0569da977c464a",
+            init_remote_static: "31e0303fd6418d2f8c0e78b91f22e8caed0fbe48656dcf4767e4834f701b8f62",
+            resp_static: "4a3acbfdb163dec651dfa3194dece676d437029c62a408b4c5ea9114246e4893",
+            resp_ephemeral: "bbdb4cdbd309f1a1f2e1456967fe288cadd6f712d65dc7b7793d5e63da6b375b",
+            resp_remote_static: "6bc3822a2aa7f4e6981d6538692b3cdf3e6df9eea6ed269eb41d93c22757b75a",
+            handshake_hash: "eb1610880c6172485422a6ba2e5af214b48481f3745d791eb40cf847ca1cf02d",
+            messages: &[
+                ("4c756477696720766f6e204d69736573",
+                 "ca35def5ae56cec33dc2036731ab14896bc4c75dbb07a61f879f8e3afa4c794449af0184c65dee97ea7a62c425167842186a38ba37a2240d792e0adfa651f02d"),
+                ("4d757272617920526f746862617264",
+                 "95ebc60d2b1fa672c1f46a8aa265ef51bfe38e7ccb39ec5be34069f144808843ae4b9f90df714c75293849a0c2f7ba8080ae48c13cbf90e2c69fd23df280eb"),
+                ("462e20412e20486179656b",
+                 "aaa6fbdefc0c1c2c65cb912552fe0f9647b12fce48f3d2a66d9fac"),
+                ("4361726c204d656e676572",
+                 "b872a76b5197ced1b61f9043789be7b32281aa8670d9fa166a6e95"),
+                ("4a65616e2d426170746973746520536179",
+                 "9e39948aa43a63d23e775e2bf15b4e80fad721d09e8060c242eea9970cfecf4a1f"),
+                ("457567656e2042f6686d20766f6e2042617765726b",
+                 "3ab72ae66cd9d291ae0ace1a71047dd55c3f36d662c250c711a06de3c6e44310c2913728dd"),
+            ],
+        },
+        NoiseVector {
+            protocol_name: "Noise_KXpsk2_25519_ChaChaPoly_BLAKE2s",
+            prologue: "4a6f686e2047616c74",
+            psk: "54686973206973206d7920417573747269616e20706572737065637469766521",
+            init_static: "e61ef9919cde45dd5f82166404bd08e38bceb5dfdfded0a34c8df7ed542214d1",
+            init_ephemeral: "893e28b9dc6ca8d611ab664754b8ceb7bac5117349a4439a6b0569da977c464a",
+            init_remote_static: "",
+            resp_static: "4a3acbfdb163dec651dfa3194dece676d437029c62a408b4c5ea9114246e4893",
+            resp_ephemeral: "bbdb4cdbd309f1a1f2e1456967fe288cadd6f712d65dc7b7793d5e63da6b375b",
+            resp_remote_static: "6bc3822a2aa7f4e6981d6538692b3cdf3e6df9eea6ed269eb41d93c22757b75a",
+            handshake_hash: "ce5aa5c0463271b6a8ea4c351fce1ab0c82341364a1dea8d345e6bbb5cae5c51",
+            messages: &[
+                ("4c756477696720766f6e204d69736573",
+                 "ca35def5ae56cec33dc2036731ab14896bc4c75dbb07a61f879f8e3afa4c7944e57f4cade9b799f5cb6f5572ef0015c86978d0987c6b70e507846a2294e0a599"),
+                ("4d757272617920526f746862617264",
+                 "95ebc60d2b1fa672c1f46a8aa265ef51bfe38e7ccb39ec5be34069f144808843a86bff5db480c3f3c8b0b35a0d17ef3c0db131a24758fbab2783bb0519fcad9aaae34ac919a51e8eead1152372d27225521d41e288e751c914cd590cd86572f457350e80acada2ab0f430e999b5df0"),
+                ("462e20412e20486179656b",
+                 "e28b96e12073b069fc5d3bfd2c799a4e362c0785ab94cff079f104"),
+                ("4361726c204d656e676572",
+                 "09fc0d3f0309bb3c63b680ebc87b24140c425f6e93411e034e58cc"),
+                ("4a65616e2d426170746973746520536179",
+                 "3aacd9ed59695e2f2ab3e2a8dc64c0f4a9772541feac7988d9f0fca3ea5d14e98f"),
+                ("457567656e2042f6686d20766f6e2042617765726b",
+                 "e859f4fe72cc72cdeeca82ad3821fde4872362d8c3f68301633603a3afb3c349ce10b9d477"),
+            ],
+        },
+        NoiseVector {
+            protocol_name: "Noise_INpsk1_25519_ChaChaPoly_BLAKE2s",
+            prologue: "4a6f686e2047616c74",
+            psk: "54686973206973206d7920417573747269616e20706572737065637469766521",
+            init_static: "e61ef9919cde45dd5f82166404bd08e38bceb5dfdfded0a34c8df7ed542214d1",
+            init_ephemeral: "893e28b9dc6ca8d611ab664754b8ceb7bac5117349a4439a6b0569da977c464a",
+            init_remote_static: "",
+            resp_static: "",
+            resp_ephemeral: "bbdb4cdbd309f1a1f2e1456967fe288cadd6f712d65dc7b7793d5e63da6b375b",
+            resp_remote_static: "",
+            handshake_hash: "4d31baa37544e1ea83bbf5bf0665331afb6d1052afa53f210a1b522f7f3ab793",
+            messages: &[
+                ("4c756477696720766f6e204d69736573",
+                 "ca35def5ae56cec33dc2036731ab14896bc4c75dbb07a61f879f8e3afa4c7944b176e1321b6fad80cc0061e427c7f26f1ab6b27c1a19efffa2bb856394ed2076a6ece2790b022a8aad416d95a34e9e496e41c8f23860ff8370837b246baf6ee01aa19f4e7df52f2084f610c30ee69869"),
+                ("4d757272617920526f746862617264",
+                 "95ebc60d2b1fa672c1f46a8aa265ef51bfe38e7ccb39ec5be34069f14480884359f7be8d068d9fb4e2577e8c23de6f7e758d48d7a455ccb70546083277a438"),
+                ("462e20412e20486179656b",
+                 "7c2709807ef27264430900f89690ae9816886e24478f5d3cdd867b"),
+                ("4361726c204d656e676572",
+                 "498bcf0fe7fc095ed82f40c32505d4114d3aae5bcc8d2ae49b8928"),
+                ("4a65616e2d426170746973746520536179",
+                 "10a7cb90fdfa4a98a016d22bc8cad2836582f24f79bf32ee8acbae3f7ab9a8c53b"),
+                ("457567656e2042f6686d20766f6e2042617765726b",
+                 "77deacedc4e25dad434104a7aab852d5b9e043ef203873651ea052d8374eefa93726f462db"),
+            ],
+        },
+        NoiseVector {
+            protocol_name: "Noise_INpsk2_25519_ChaChaPoly_BLAKE2s",
+            prologue: "4a6f686e2047616c74",
+            psk: "54686973206973206d7920417573747269616e20706572737065637469766521",
+            init_static: "e61ef9919cde45dd5f82166404bd08e38bceb5dfdfded0a34c8df7ed542214d1",
+            init_ephemeral: "893e28b9dc6ca8d611ab664754b8ceb7bac5117349a4439a6b0569da977c464a",
+            init_remote_static: "",
+            resp_static: "",
+            resp_ephemeral: "bbdb4cdbd309f1a1f2e1456967fe288cadd6f712d65dc7b7793d5e63da6b375b",
+            resp_remote_static: "",
+            handshake_hash: "9e063b724b8e30c826ef3b8d2ca967feef224d4b8c2bb1db7249ba824897caf1",
+            messages: &[
+                ("4c756477696720766f6e204d69736573",
+                 "ca35def5ae56cec33dc2036731ab14896bc4c75dbb07a61f879f8e3afa4c794433ebdb3ea81aa07d44de08a018ddf003b4bd6940108601702597bcbc51ca4911757720089ea5558c01e08672a172df4841717c72ac72e9250f6e761c187c19f0872e3dad40c431da18d78f6751a0c303"),
+                ("4d757272617920526f746862617264",
+                 "95ebc60d2b1fa672c1f46a8aa265ef51bfe38e7ccb39ec5be34069f1448088438868e7df37d23588e3372133ac0f86dd8bc5af7dfb3a16fda77a760862e665"),
+                ("462e20412e20486179656b",
+                 "bb506f7e9982f8dadd94bd9b118f86ae126b7b8f67429a296c66d7"),
+                ("4361726c204d656e676572",
+                 "cec1423051a567b0c4fbcdaf85820abb6e9930a64a24d3b9aa3716"),
+                ("4a65616e2d426170746973746520536179",
+                 "9f232e89164755ad63919c90c2de142fc9ec03ac0a15734eaf9895ed7bbff0a06b"),
+                ("457567656e2042f6686d20766f6e2042617765726b",
+                 "0829c89da7c7fd9a8225b9e2f0c5eaa49d7d312c1ca72a881f2ecfd1d307ec093fd8420423"),
+            ],
+        },
+        NoiseVector {
+            protocol_name: "Noise_IKpsk1_25519_ChaChaPoly_BLAKE2s",
+            prologue: "4a6f686e2047616c74",
+            psk: "54686973206973206d7920417573747269616e20706572737065637469766521",
+            init_static: "e61ef9919cde45dd5f82166404bd08e38bceb5dfdfded0a34c8df7ed542214d1",
+            init_ephemeral: "893e28b9dc6ca8d611ab664754b8ceb7bac5117349a4439a6b0569da977c464a",
+            init_remote_static: "31e0303fd6418d2f8c0e78b91f22e8caed0fbe48656dcf4767e4834f701b8f62",
+            resp_static: "4a3acbfdb163dec651dfa3194dece676d437029c62a408b4c5ea9114246e4893",
+            resp_ephemeral: "bbdb4cdbd309f1a1f2e1456967fe288cadd6f712d65dc7b7793d5e63da6b375b",
+            resp_remote_static: "",
+            handshake_hash: "a02debd3baac76b19863f7d1175927193fcee661e9f7ae87b6d086cb4926c783",
+            messages: &[
+                ("4c756477696720766f6e204d69736573",
+                 "ca35def5ae56cec33dc2036731ab14896bc4c75dbb07a61f879f8e3afa4c794498e192a0a94102bd8fa1a182979c012f4fa2558d899e2e58d4d4aba041a56b35297560de33bf7fe93f8e567791039539f59e76a00721ea7c1095fbccf10a13df79f3b5605bfb0617c309698737c73429"),
+                ("4d757272617920526f746862617264",
+                 "95ebc60d2b1fa672c1f46a8aa265ef51bfe38e7ccb39ec5be34069f1448088434523a21bc9f1ce57af3dc28365e1e33c25f577fc4aa2149d5d6a2ab0911beb"),
+                ("462e20412e20486179656b",
+                 "dc15d1ceff592ff648bba38f9bc63c0049600307fba700ba2a0b2b"),
+                ("4361726c204d656e676572",
+                 "85f1e8c573c0d9fd188080532a0ad1a6d457974c91f2ff0f21ecaf"),
+                ("4a65616e2d426170746973746520536179",
+                 "11d83f8ff550ef18c1314540ade9c7b9e5fb5245889221856ea55b0b8e64bdf1bc"),
+                ("457567656e2042f6686d20766f6e2042617765726b",
+                 "b7b3a985fe737290fb597224ccad3f9ad3caa3d396bf201233891db26172d267f4298d47c2"),
+            ],
+        },
+        NoiseVector {
+            protocol_name: "Noise_IKpsk2_25519_AESGCM_SHA256",
+            prologue: "4a6f686e2047616c74",
+            psk: "54686973206973206d7920417573747269616e20706572737065637469766521",
+            init_static: "e61ef9919cde45dd5f82166404bd08e38bceb5dfdfded0a34c8df7ed542214d1",
+            init_ephemeral: "893e28b9dc6ca8d611ab664754b8ceb7bac5117349a4439a6b0569da977c464a",
+            init_remote_static: "31e0303fd6418d2f8c0e78b91f22e8caed0fbe48656dcf4767e4834f701b8f62",
+            resp_static: "4a3acbfdb163dec651dfa3194dece676d437029c62a408b4c5ea9114246e4893",
+            resp_ephemeral: "bbdb4cdbd309f1a1f2e1456967fe288cadd6f712d65dc7b7793d5e63da6b375b",
+            resp_remote_static: "",
+            handshake_hash: "48c3f1eb397c797af6b139032c6074cb41282ff033ee25d5aee4a4badd3a2c3f",
+            messages: &[
+                ("4c756477696720766f6e204d69736573",
+                 "ca35def5ae56cec33dc2036731ab14896bc4c75dbb07a61f879f8e3afa4c79445a1baf339186d7867062b0dd31f5de322d370165d217939b2b7ed9e2bae7840458ea65c2ce1554e0e8077fe7334c4bd514c658fd04d97b86d216f58f59714a7e24144155045c8f36bed85a2cb0fd9af7"),
+                ("4d757272617920526f746862617264",
+                 "95ebc60d2b1fa672c1f46a8aa265ef51bfe38e7ccb39ec5be34069f1448088432fe8d675e949799d2a968211a66cc7ba2b0f4cad8eda3c2bd37eae737bbf88"),
+                ("462e20412e20486179656b",
+                 "38cbf3835c78b7802df0dd0d9640ff8575debd0cb61cfb52590da1"),
+                ("4361726c204d656e676572",
+                 "ffc365ff6955fc66c5d809b31ac995f812c5770bd29f0df12f0ffd"),
+                ("4a65616e2d426170746973746520536179",
+                 "a7f2828174c4ead2491ffeb77c41c890fd1229ee36f23ccb9066a795daff1cad6b"),
+                ("457567656e2042f6686d20766f6e2042617765726b",
+                 "3b86963d5c0e6e3b4153d77153836606393eefc4e5e6dae97796552d1265839ee3825cb80c"),
+            ],
+        },
+        NoiseVector {
+            protocol_name: "Noise_IKpsk2_25519_ChaChaPoly_BLAKE2s",
+            prologue: "4a6f686e2047616c74",
+            psk: "54686973206973206d7920417573747269616e20706572737065637469766521",
+            init_static: "e61ef9919cde45dd5f82166404bd08e38bceb5dfdfded0a34c8df7ed542214d1",
+            init_ephemeral: "893e28b9dc6ca8d611ab664754b8ceb7bac5117349a4439a6b0569da977c464a",
+            init_remote_static: "31e0303fd6418d2f8c0e78b91f22e8caed0fbe48656dcf4767e4834f701b8f62",
+            resp_static: "4a3acbfdb163dec651dfa3194dece676d437029c62a408b4c5ea9114246e4893",
+            resp_ephemeral: "bbdb4cdbd309f1a1f2e1456967fe288cadd6f712d65dc7b7793d5e63da6b375b",
+            resp_remote_static: "",
+            handshake_hash: "f5191b875290abcd41347ac3622d9679688a7e980229cb937ef748336cfde0e5",
+            messages: &[
+                ("4c756477696720766f6e204d69736573",
+                 "ca35def5ae56cec33dc2036731ab14896bc4c75dbb07a61f879f8e3afa4c7944001e21de9f98ddd8e2ad57527207feb56253c9c94a9e496782ecfcb2a75fbcaf1b52948cc48daefe660c62119ab5000980c84831215f2441eba616548e832985464cf17e51ee93109008399a21f7e13f"),
+                ("4d757272617920526f746862617264",
+                 "95ebc60d2b1fa672c1f46a8aa265ef51bfe38e7ccb39ec5be34069f144808843cb765f2caef0751b8f007572dab0322217755c0632f365717edbf34d33e87a"),
+                ("462e20412e20486179656b",
+                 "8153ca9833bc3c1b91a7e66e5f4d4f5b59bf9e64c2f20d15f0bba7"),
+                ("4361726c204d656e676572",
+                 "07af0c9c86e1b4e80f36b04ff7688d51141af3debd0332f0a705ef"),
+                ("4a65616e2d426170746973746520536179",
+                 "6ab1467c0448cc78394494abaaf23afce0e234315d6e2624dcbfa8a21c1c4d073d"),
+                ("457567656e2042f6686d20766f6e2042617765726b",
+                 "dfc346c0d2296ae6cf1acf6f12b8456a1dba228cf8d8b774aacf1c47fc53aa80ebc7a4c292"),
+            ],
+        },
+        NoiseVector {
+            protocol_name: "Noise_IXpsk2_25519_ChaChaPoly_BLAKE2s",
+            prologue: "4a6f686e2047616c74",
+            psk: "54686973206973206d7920417573747269616e20706572737065637469766521",
+            init_static: "e61ef9919cde45dd5f82166404bd08e38bceb5dfdfded0a34c8df7ed542214d1",
+            init_ephemeral: "893e28b9dc6ca8d611ab664754b8ceb7bac5117349a4439a6b0569da977c464a",
+            init_remote_static: "",
+            resp_static: "4a3acbfdb163dec651dfa3194dece676d437029c62a408b4c5ea9114246e4893",
+            resp_ephemeral: "bbdb4cdbd309f1a1f2e1456967fe288cadd6f712d65dc7b7793d5e63da6b375b",
+            resp_remote_static: "",
+            handshake_hash: "b2876e50a630be52ef66dc0c15f01ad73091c5c56972447e0fc0e5e59f2020c5",
+            messages: &[
+                ("4c756477696720766f6e204d69736573",
+                 "ca35def5ae56cec33dc2036731ab14896bc4c75dbb07a61f879f8e3afa4c7944c8d2ef6130dbd187858adbd6cbf5281bcbd8ed8253e496e2be8f83c38a03ae1075e06f2fd04fe41b76a52f2b9ed57fbdd1c3c468603b6d942fe1568198a424d65e64498e9ccd9441632cafad7ce6eb5a"),
+                ("4d757272617920526f746862617264",
+                 "95ebc60d2b1fa672c1f46a8aa265ef51bfe38e7ccb39ec5be34069f144808843558e79dd0608c24bb316b7fc9d9bf26bcb90e1cd3020e2bac84a563d7bd2bff4f29d1354443b13730c5828e687fc5de3964690435faef56fcc0449b352a6b8ba6abf71077221a40ad8030f431e4601"),
+                ("462e20412e20486179656b",
+                 "cdd4dfd488c6958f8c12f622b4a73e771037d9d7b04df36292bad5"),
+                ("4361726c204d656e676572",
+                 "79b9b105e77aa3b1960f2369d31bd2d771bd327dbcf4b7339aa040"),
+                ("4a65616e2d426170746973746520536179",
+                 "5a51ac5826e9cdeb8c1f53fa098f443ad7caceebb0201390a05612275d456cd1df"),
+                ("457567656e2042f6686d20766f6e2042617765726b",
+                 "c69fa1a246b2dfe63b4c006ef602bea55a44f68c1826fe6c82956110373ce50863cd3abf50"),
+            ],
+        },
+        NoiseVector {
+            protocol_name: "Noise_Npsk0_25519_ChaChaPoly_BLAKE2s",
+            prologue: "4a6f686e2047616c74",
+            psk: "54686973206973206d7920417573747269616e20706572737065637469766521",
+            init_static: "",
+            init_ephemeral: "893e28b9dc6ca8d611ab664754b8ceb7bac5117349a4439a6b0569da977c464a",
+            init_remote_static: "31e0303fd6418d2f8c0e78b91f22e8caed0fbe48656dcf4767e4834f701b8f62",
+            resp_static: "4a3acbfdb163dec651dfa3194dece676d437029c62a408b4c5ea9114246e4893",
+            resp_ephemeral: "",
+            resp_remote_static: "",
+            handshake_hash: "0dfb6479246ece9c27d879cf7709d1a5b48fd06b965344dacea76730ca6e2134",
+            messages: &[
+                ("4c756477696720766f6e204d69736573",
+                 "ca35def5ae56cec33dc2036731ab14896bc4c75dbb07a61f879f8e3afa4c7944425cfde31517d0b610bab9bbd6e699b966415e2ce1454c0d5357dd445756df1f"),
+                ("4d757272617920526f746862617264",
+                 "06aaf2d9845c8324f528f20bd1c8f8e11f88b55bc7681798e11d3f745c4264"),
+                ("462e20412e20486179656b",
+                 "a1ce8e06add10426bc54463a1e7dc3d9f9526f7b44225cfa8eda3a"),
+                ("4361726c204d656e676572",
+                 "8d07ff4b04a1beba3ac8cf27a3fd5cebdc462383862bc71cb727da"),
+                ("4a65616e2d426170746973746520536179",
+                 "9ee57cd3df98a99d460c8948c8fad51636a1f6a548d1b0bf5068d3562afc1461f4"),
+                ("457567656e2042f6686d20766f6e2042617765726b",
+                 "3474938c4fac7a52c90be1e0a7c36c48d03a367e292e44a335e7f236eb5f385ec582737be8"),
+            ],
+        },
+        NoiseVector {
+            protocol_name: "Noise_Kpsk0_25519_ChaChaPoly_BLAKE2s",
+            prologue: "4a6f686e2047616c74",
+            psk: "54686973206973206d7920417573747269616e20706572737065637469766521",
+            init_static: "e61ef9919cde45dd5f82166404bd08e38bceb5dfdfded0a34c8df7ed542214d1",
+            init_ephemeral: "893e28b9dc6ca8d611ab664754b8ceb7bac5117349a4439a6b0569da977c464a",
+            init_remote_static: "31e0303fd6418d2f8c0e78b91f22e8caed0fbe48656dcf4767e4834f701b8f62",
+            resp_static: "4a3acbfdb163dec651dfa3194dece676d437029c62a408b4c5ea9114246e4893",
+            resp_ephemeral: "",
+            resp_remote_static: "6bc3822a2aa7f4e6981d6538692b3cdf3e6df9eea6ed269eb41d93c22757b75a",
+            handshake_hash: "e29a69d3f755629e22e273fd1505f92a0a703f12bcc89bbb8a76a53321e7dc30",
+            messages: &[
+                ("4c756477696720766f6e204d69736573",
+                 "ca35def5ae56cec33dc2036731ab14896bc4c75dbb07a61f879f8e3afa4c79443b0588c609a0bd9a0fb1d3d84bc37d74f73c8129a00a76a49227b64fdac65b59"),
+                ("4d757272617920526f746862617264",
+                 "1696d649da9b1097e75bdba3769aa2861bad1de0ed782b7be6dd2b0ef56960"),
+                ("462e20412e20486179656b",
+                 "e3a19dbc2d8e912e4e79ebbf4df96e06b6a98de3ef59abbf3be526"),
+                ("4361726c204d656e676572",
+                 "e7d5f5db72092c35b70848efb126fb4a5910fc97b63e5e3eb7b2b6"),
+                ("4a65616e2d426170746973746520536179",
+                 "32247d5e7da91884952be4b0623b6390fb4ff40175fa84df79387d840cf16a72e8"),
+                ("457567656e2042f6686d20766f6e2042617765726b",
+                 "f06db65fb64b63764f82cbb628205620b55bc3900c7fbeaeb4c649e389d1c5a40b17455d1e"),
+            ],
+        },
+        NoiseVector {
+            protocol_name: "Noise_Xpsk1_25519_ChaChaPoly_BLAKE2s",
+            prologue: "4a6f686e2047616c74",
+            psk: "54686973206973206d7920417573747269616e20706572737065637469766521",
+            init_static: "e61ef9919cde45dd5f82166404bd08e38bceb5dfdfded0a34c8df7ed542214d1",
+            init_ephemeral: "893e28b9dc6ca8d611ab664754b8ceb7bac5117349a4439a6b0569da977c464a",
+            init_remote_static: "31e0303fd6418d2f8c0e78b91f22e8caed0fbe48656dcf4767e4834f701b8f62",
+            resp_static: "4a3acbfdb163dec651dfa3194dece676d437029c62a408b4c5ea9114246e4893",
+            resp_ephemeral: "",
+            resp_remote_static: "",
+            handshake_hash: "12d1bf6e1327e20398d92727a16965e0769a5b0ddf58d77bfd219cfc68f57d5a",
+            messages: &[
+                ("4c756477696720766f6e204d69736573",
+                 "ca35def5ae56cec33dc2036731ab14896bc4c75dbb07a61f879f8e3afa4c794479be957c06c64483c69607f17a61f440528418499b7f686adfb8091fb03643ac32b5823d51c15e00d9355d5623c817a552a0bb264052946463c288d45d9ede7c6ea227faafbb5f1dd11166d6ad3f7cc5"),
+                ("4d757272617920526f746862617264",
+                 "cfbc17a5950121da51b421b0f95dbaa4745e70477be8da8871edd89049f998"),
+                ("462e20412e20486179656b",
+                 "a6a910d1067d991c63e8520bf327fa1f530a74fb47c58b8e3ff2a9"),
+                ("4361726c204d656e676572",
+                 "b7cf2eb3291ef4b09514aa0f67ffc8b31cb1b2a323631bab0506c8"),
+                ("4a65616e2d426170746973746520536179",
+                 "70ed51f6d218aa0d44a229ea4a6961d154f92868f832cb2471287e8af49460de90"),
+                ("457567656e2042f6686d20766f6e2042617765726b",
+                 "2abf8cc72678e7c569817896cfddca8247274a794be86ac4e9b0a754f9332cf8ed784da75a"),
+            ],
+        },
+    ];
+}
@@ -76,6 +76,28 @@
 //! and `s` starts), and that the two `r` and `s` values are still in the
 //! proper range (i.e. lower than the curve order).
 //!
+//! `PrivateKey::sign_hash_recoverable()` computes the same signature as
+//! `sign_hash()` but also returns a "recovery id" (0 to 3), with which
+//! the public key can be rebuilt from the signature and the hashed
+//! message, with `PublicKey::recover_from_hash()`.
+//!
+//! ECDH key exchange is performed with `PrivateKey::ECDH()`, which
+//! returns the x-coordinate of the shared point (as in NIST SP 800-56A
+//! and RFC 5903), or `PrivateKey::ECDH_hashed()`, which returns the
+//! SHA-256 hash of the compressed shared point (as the default
+//! `secp256k1_ecdh()` function of libsecp256k1). The peer public key is
+//! provided encoded (compressed or uncompressed); if it is invalid, this
+//! is reported through the returned status, and a key unguessable by
+//! outsiders is returned instead of the shared secret.
+//!
+//! Signatures can be converted to and from the ASN.1 DER format (a
+//! `SEQUENCE` of two `INTEGER` values, as used in X.509 and TLS) with
+//! the `encode_der()` and `decode_der()` functions; decoding is strict
+//! and rejects BER-only encodings. `normalize_s()` converts a signature
+//! to its "low-S" form (s is replaced with n - s if it is greater than
+//! n/2), and `PublicKey::verify_hash_strict()` rejects signatures which
+//! are not in that form.
+//!
 //! # Truncated Signatures
 //!
 //! The `PublicKey::verify_trunc_hash()` function supports _truncated
@@ -102,11 +124,15 @@
 //! truncation); when 32 bits are ignored, the verification cost is about
 //! 300 to 450 times the cost of verifying an untruncated signature.
 //!
-//! Truncated signature verification on curve P-256 requires dynamic
-//! memory allocation; it is not available if this library is compiled
-//! without the `std` or `alloc` feature (default compilation uses `std`
-//! and thus `std::vec::Vec`; without `std` but with `alloc`,
-//! `alloc::vec::Vec` is used).
+//! Truncated signature verification does not require dynamic memory
+//! allocation, and is available in `no_std` builds. It needs a scratch
+//! buffer whose size depends on the number of ignored bits: 1 kB for
+//! up to 16 bits, 16 kB for up to 24 bits, and 256 kB for up to 32 bits.
+//! `verify_trunc_hash()` allocates that buffer on the stack. On systems
+//! with a small stack (e.g. embedded systems), `verify_trunc_hash_buf()`
+//! should be used instead: it takes a caller-provided buffer, which can
+//! be static, and accepts smaller buffers at the cost of a slower search
+//! (see its documentation for details).
 //!
 //! [FIPS 186-4]: https://csrc.nist.gov/publications/detail/fips/186/4/final
 //! [RFC 6979]: https://datatracker.ietf.org/doc/html/rfc6979
@@ -119,9 +145,8 @@ use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 use super::field::{GFp256, ModInt256};
 use sha2::{Sha256, Sha512, Digest};
 use super::{CryptoRng, RngCore};
+use super::kdf::HmacDrbgSha256;
 
-#[cfg(feature = "alloc")]
-use crate::Vec;
 
 /// A point on the short Weierstraß curve P-256.
 #[derive(Clone, Copy, Debug)]
@@ -155,6 +180,15 @@ fn bswap32(x: &[u8]) -> [u8; 32] {
     y
 }
 
+/// Returns `true` if the provided scalar is greater than n/2 (with n
+/// being the curve order), i.e. if the "s" value of a signature is not
+/// in "low-S" form.
+fn is_high_s(s: &Scalar) -> bool {
+    // Since n is odd, s > n/2 if and only if s > n - s (as integers).
+    // Comparison is performed in big-endian convention.
+    bswap32(&s.encode()) > bswap32(&(-s).encode())
+}
+
 impl Point {
 
     // Curve equation is: y^2 = x^3 - 3*x + b  (for a given constant b)
@@ -428,11 +462,11 @@ impl Point {
         let mut bb = self.encode_uncompressed();
 
         // First byte is 0x00 for the neutral, 0x04 for other points.
-        let r = (((bb[0] as i32) - 1) >> 8) as u32;
+        let r = !((((bb[0] as i32) - 1) >> 8) as u32);
 
         // For the neutral, we got zeros for x and y, but we want x = 1
         // in that case.
-        bb[32] |= (r & 1) as u8;
+        bb[32] |= (!r & 1) as u8;
 
         // The values necessarily decode successfully.
         let (x, _) = GFp256::decode32(&bswap32(&bb[1..33]));
@@ -2025,29 +2059,29 @@ impl PrivateKey {
     /// of the actual message, then the signature generation process
     /// follows RFC 6979.
     pub fn sign_hash(self, hv: &[u8], extra_rand: &[u8]) -> [u8; 64] {
+        self.sign_hash_recoverable(hv, extra_rand).0
+    }
 
-        // Feed a SHA-256 context with the starter block for HMAC/SHA-256,
-        // using a 32-byte key.
-        fn hmac_start(sh: &mut Sha256, key: &[u8; 32]) {
-            let mut tmp = [0x36u8; 64];
-            for i in 0..32 {
-                tmp[i] ^= key[i];
-            }
-            sh.update(&tmp);
-        }
-
-        // Finalize a HMAC/SHA-256 computation; the 32-byte key is provided
-        // again. The SHA-256 context is automatically reinitialized.
-        fn hmac_end(sh: &mut Sha256, key: &[u8; 32]) -> [u8; 32] {
-            let v = sh.finalize_reset();
-            let mut tmp = [0x5Cu8; 64];
-            for i in 0..32 {
-                tmp[i] ^= key[i];
-            }
-            sh.update(&tmp);
-            sh.update(&v);
-            sh.finalize_reset().into()
-        }
+    /// Signs a hash value with ECDSA, and also returns the recovery id.
+    ///
+    /// This function computes exactly the same signature as `sign_hash()`
+    /// (with the same parameters), and additionally returns the recovery
+    /// id, an integer in the 0 to 3 range which allows rebuilding the
+    /// public key from the signature and the hashed message (see
+    /// `PublicKey::recover_from_hash()`):
+    ///
+    ///  - bit 0 of the recovery id is the parity of y(R);
+    ///
+    ///  - bit 1 is set if x(R) is not lower than the curve order n (this
+    ///    happens only with negligible probability).
+    ///
+    /// Here, R = k*G is the point computed during signature generation,
+    /// and r = x(R) mod n. The "Ethereum-style" 65-byte signature format
+    /// is the concatenation of the 64-byte signature with a final byte
+    /// that encodes the recovery id (often with 27 added to it).
+    pub fn sign_hash_recoverable(self, hv: &[u8], extra_rand: &[u8])
+        -> ([u8; 64], u8)
+    {
 
         // Convert the input hash value into an integer modulo n:
         //  - If hv.len() > 32, keep only the leftmost 32 bytes.
@@ -2068,42 +2102,11 @@ impl PrivateKey {
         // Get the byte representation of the private key itself.
         let xb = bswap32(&self.x.encode());
 
-        // Generate a pseudorandom k as per RFC 6979, section 3.2.
-        let mut sh = Sha256::new();
-        let V = [0x01u8; 32];
-        let K = [0x00u8; 32];
-
-        // 3.2.d
-        hmac_start(&mut sh, &K);
-        sh.update(&V);
-        sh.update(&[0x00u8]);
-        sh.update(&xb);
-        sh.update(&hb);
-        if extra_rand.len() > 0 {
-            sh.update(&extra_rand);
-        }
-        let K = hmac_end(&mut sh, &K);
-
-        // 3.2.e
-        hmac_start(&mut sh, &K);
-        sh.update(&V);
-        let V = hmac_end(&mut sh, &K);
-
-        // 3.2.f
-        hmac_start(&mut sh, &K);
-        sh.update(&V);
-        sh.update(&[0x01u8]);
-        sh.update(&xb);
-        sh.update(&hb);
-        if extra_rand.len() > 0 {
-            sh.update(&extra_rand);
-        }
-        let mut K = hmac_end(&mut sh, &K);
-
-        // 3.2.g
-        hmac_start(&mut sh, &K);
-        sh.update(&V);
-        let mut V = hmac_end(&mut sh, &K);
+        // Generate a pseudorandom k as per RFC 6979, section 3.2. Steps
+        // 3.2.b to 3.2.g are the instantiation of HMAC-DRBG with the
+        // private key as entropy input, and the hash value (followed by
+        // the extra randomness, if any) as nonce.
+        let mut drbg = HmacDrbgSha256::new(&xb, &hb, extra_rand);
 
         // 3.2.h
         // We loop in case we get a zero for k or for s (either case is
@@ -2112,18 +2115,23 @@ impl PrivateKey {
             // Get k. Since SHA-256 outputs 256 bits, and the curve order
             // has size 256 bits as well, we only need one HMAC call, with
             // no truncation.
-            hmac_start(&mut sh, &K);
-            sh.update(&V);
-            V[..].copy_from_slice(&hmac_end(&mut sh, &K));
+            let mut V = [0u8; 32];
+            drbg.generate(&mut V, &[]);
             let (k, cc) = Scalar::decode32(&bswap32(&V));
             if cc != 0 && k.iszero() == 0 {
                 // We got k, compute the signature.
 
                 // R = k*G; then encode x(R), and decode-reduce as a scalar
                 let R = Point::mulgen(&k);
-                let xR_le = bswap32(&R.encode_compressed()[1..33]);
+                let Rc = R.encode_compressed();
+                let xR_le = bswap32(&Rc[1..33]);
                 let r = Scalar::decode_reduce(&xR_le);
 
+                // Recovery id: parity of y(R), and whether x(R) >= n
+                // (decode32() reports a failure exactly in that case).
+                let (_, cx) = Scalar::decode32(&xR_le);
+                let recid = (Rc[0] & 1) | ((!cx & 2) as u8);
+
                 // Compute s.
                 let s = (h + self.x * r) / k;
 
@@ -2132,19 +2140,12 @@ impl PrivateKey {
                     let mut sig = [0u8; 64];
                     sig[..32].copy_from_slice(&bswap32(&r.encode()));
                     sig[32..].copy_from_slice(&bswap32(&s.encode()));
-                    return sig;
+                    return (sig, recid);
                 }
             }
 
-            // Bad k, try again (very improbable).
-            hmac_start(&mut sh, &K);
-            sh.update(&V);
-            sh.update(&[0x00u8]);
-            let nK = hmac_end(&mut sh, &K);
-            K[..].copy_from_slice(&nK);
-            hmac_start(&mut sh, &K);
-            sh.update(&V);
-            V[..].copy_from_slice(&hmac_end(&mut sh, &K));
+            // Bad k, try again (very improbable). The K and V update
+            // of RFC 6979 (step 3.2.h.3) is part of the DRBG generation.
         }
     }
 
@@ -2225,6 +2226,74 @@ impl PrivateKey {
 
         Some(nsig)
     }
+
+    /// ECDH key exchange.
+    ///
+    /// Given this private key, and the provided peer public key (encoded),
+    /// return the 32-byte shared secret. The shared secret is the
+    /// x-coordinate of the shared point, in unsigned big-endian
+    /// convention, as specified by NIST SP 800-56A (primitive "ECC CDH")
+    /// and RFC 5903. The peer public key may use the compressed (33
+    /// bytes) or uncompressed (65 bytes) format. The process fails if the `peer_pk`
+    /// slice does not have one of these two lengths, or does not encode
+    /// a valid curve point, or encodes the neutral point. On success,
+    /// the shared secret is returned along with 0xFFFFFFFFu32. On failure,
+    /// a different value (unguessable by outsiders) is returned, along
+    /// with 0x00000000u32.
+    ///
+    /// Processing is constant-time. If the `peer_pk` slice has length
+    /// exactly 33 or 65 bytes, then outsiders cannot know through
+    /// timing-based side-channels whether the process succeeded or failed
+    /// (though the encoding format of the peer public key is not hidden).
+    /// The raw x-coordinate is not uniformly distributed and should be
+    /// used only as input to a key derivation function.
+    pub fn ECDH(self, peer_pk: &[u8]) -> ([u8; 32], u32) {
+        let (Q, ok) = self.ecdh_point(peer_pk);
+        let mut shared = [0u8; 32];
+        shared[..].copy_from_slice(&Q.encode_compressed()[1..33]);
+        (shared, ok)
+    }
+
+    /// ECDH key exchange, with a hashed output.
+    ///
+    /// This function is similar to `ECDH()`, but the returned shared key
+    /// is the SHA-256 hash of the compressed encoding (33 bytes) of the
+    /// shared point. This matches the default output of the
+    /// `secp256k1_ecdh()` function of libsecp256k1. Failure conditions,
+    /// implicit rejection, and constant-time properties are the same as
+    /// with `ECDH()`.
+    pub fn ECDH_hashed(self, peer_pk: &[u8]) -> ([u8; 32], u32) {
+        let (Q, ok) = self.ecdh_point(peer_pk);
+        let mut key = [0u8; 32];
+        key[..].copy_from_slice(&Sha256::digest(Q.encode_compressed()));
+        (key, ok)
+    }
+
+    // Computes the ECDH shared point with the provided peer public key.
+    // If the peer public key is invalid, then the returned point is
+    // instead the product of the generator with a secret scalar derived
+    // from our private key and the peer bytes, so that the output is
+    // unknown by outsiders but still deterministic relatively to the
+    // received peer bytes; the returned status is then 0x00000000.
+    pub(crate) fn ecdh_point(self, peer_pk: &[u8]) -> (Point, u32) {
+        // Decode peer public key.
+        let mut Q = Point::NEUTRAL;
+        let mut ok = Q.set_decode(peer_pk);
+        ok &= !Q.isneutral();
+
+        // Alternate scalar for the failure case. A custom prefix
+        // ("crrl P-256 ECDH" in ASCII) is used for domain separation.
+        let mut sh = Sha512::new();
+        sh.update(b"crrl P-256 ECDH");
+        sh.update(self.encode());
+        sh.update(peer_pk);
+        let mut s = Scalar::decode_reduce(&sh.finalize()[..]);
+        s.set_cond(&Scalar::ONE, s.iszero());
+
+        Q.set_cond(&Point::BASE, !ok);
+        s.set_cond(&self.x, ok);
+        (Q * s, ok)
+    }
 }
 
 impl PublicKey {
@@ -2323,6 +2392,103 @@ impl PublicKey {
         return r.equals(rr) != 0;
     }
 
+    /// Verifies a signature on a given hashed message, in strict mode.
+    ///
+    /// This function is similar to `verify_hash()`, except that the
+    /// signature MUST have length exactly 64 bytes, and MUST be in
+    /// "low-S" form (i.e. s must not be greater than n/2, see
+    /// `normalize_s()`); otherwise, it is rejected.
+    ///
+    /// Note: this function is not constant-time; it assumes that the
+    /// public key and signature value are public data.
+    pub fn verify_hash_strict(self, sig: &[u8], hv: &[u8]) -> bool {
+        if sig.len() != 64 {
+            return false;
+        }
+        let (s, cs) = Scalar::decode32(&bswap32(&sig[32..]));
+        if cs == 0 || is_high_s(&s) {
+            return false;
+        }
+        self.verify_hash(sig, hv)
+    }
+
+    /// Recovers the public key from a signature and a hashed message.
+    ///
+    /// The signature (`sig`) MUST have length exactly 64 bytes (r and s,
+    /// both over 32 bytes, unsigned big-endian convention), and
+    /// `recid` is the recovery id, as returned by
+    /// `PrivateKey::sign_hash_recoverable()` (in the 0 to 3 range). The
+    /// hashed message `hv` is handled in the same way as in
+    /// `verify_hash()`. If the signature and recovery id are such that
+    /// a public key can be obtained, then that key is returned; the
+    /// signature is then valid relatively to that key and the provided
+    /// hashed message. Otherwise, `None` is returned.
+    ///
+    /// Note: this function is not constant-time; it assumes that the
+    /// signature, recovery id and hashed message are public data.
+    pub fn recover_from_hash(sig: &[u8], recid: u8, hv: &[u8])
+        -> Option<Self>
+    {
+        // Decode r and s; zeros and out-of-range values are rejected.
+        if sig.len() != 64 || recid > 3 {
+            return None;
+        }
+        let (r, cr) = Scalar::decode32(&bswap32(&sig[..32]));
+        if cr == 0 || r.iszero() != 0 {
+            return None;
+        }
+        let (s, cs) = Scalar::decode32(&bswap32(&sig[32..]));
+        if cs == 0 || s.iszero() != 0 {
+            return None;
+        }
+
+        // Rebuild the compressed encoding of R. Its x coordinate is r,
+        // or r + n if bit 1 of the recovery id is set (in which case the
+        // value must not overflow; the decoding of R then verifies that
+        // it is lower than the field modulus p).
+        let mut Rc = [0u8; 33];
+        Rc[0] = 0x02 | (recid & 1);
+        Rc[1..].copy_from_slice(&sig[..32]);
+        if (recid & 2) != 0 {
+            // n is odd, so its little-endian encoding is that of n - 1
+            // with the low bit set.
+            let mut nb = (-Scalar::ONE).encode();
+            nb[0] |= 1;
+            let mut cc = 0u32;
+            for i in 0..32 {
+                cc += (Rc[32 - i] as u32) + (nb[i] as u32);
+                Rc[32 - i] = cc as u8;
+                cc >>= 8;
+            }
+            if cc != 0 {
+                return None;
+            }
+        }
+        let R = Point::decode(&Rc)?;
+
+        // Convert the input hash value into an integer modulo n.
+        let mut tmp = [0u8; 32];
+        if hv.len() >= 32 {
+            tmp[..].copy_from_slice(&hv[..32]);
+        } else {
+            tmp[32 - hv.len() .. 32].copy_from_slice(hv);
+        }
+        let h = Scalar::decode_reduce(&bswap32(&tmp));
+
+        // Q = (s*R - h*G)/r
+        let w = Scalar::ONE / r;
+        let Q = R.mul_add_mulgen_vartime(&(s * w), &-(h * w));
+        if Q.isneutral() != 0 {
+            return None;
+        }
+        Some(Self { point: Q })
+    }
+
+    /// Size (in 64-bit words) of the scratch buffer which is sufficient
+    /// for `verify_trunc_hash_buf()` to run at full speed, regardless of
+    /// the number of ignored bits.
+    pub const TRUNC_BUF_LEN: usize = 32769;
+
     /// Verifies a truncated signature on a given hashed message.
     ///
     /// The signature (`sig`) MUST have length 64 bytes and MUST have
@@ -2344,19 +2510,73 @@ impl PublicKey {
     /// then it has been verified to be valid and there is no need to
     /// validate it again).
     ///
+    /// This function uses a scratch buffer allocated on the stack, whose
+    /// size depends on `rm`, so that the reconstruction always runs at
+    /// full speed: 129 words (about 1 kB) for `rm` <= 16, 2049 words
+    /// (about 16 kB) for `rm` <= 24, and `TRUNC_BUF_LEN` words (256 kB)
+    /// for larger values of `rm`. On systems with a small stack, use
+    /// `verify_trunc_hash_buf()` with a caller-provided buffer instead.
+    ///
     /// Note: this function is not constant-time; it assumes that the
     /// public key and signature value are public data.
-    ///
-    /// Note: this function is available only if heap allocation support
-    /// was configured. Only public data is allocated on the heap.
-    #[cfg(feature = "alloc")]
     pub fn verify_trunc_hash(self,
         sig: &[u8], rm: usize, hv: &[u8]) -> Option<[u8; 64]>
+    {
+        // For rm ignored bits, 2^floor((rm-1)/2) + 1 words are enough
+        // for full speed. Each buffer size is handled by a distinct
+        // non-inlined function, so that the stack usage for small
+        // values of rm is not inflated by the largest buffer.
+        if rm <= 16 {
+            self.verify_trunc_hash_stack::<129>(sig, rm, hv)
+        } else if rm <= 24 {
+            self.verify_trunc_hash_stack::<2049>(sig, rm, hv)
+        } else {
+            self.verify_trunc_hash_stack::<{ Self::TRUNC_BUF_LEN }>(
+                sig, rm, hv)
+        }
+    }
+
+    #[inline(never)]
+    fn verify_trunc_hash_stack<const N: usize>(self,
+        sig: &[u8], rm: usize, hv: &[u8]) -> Option<[u8; 64]>
+    {
+        let mut scratch = [0u64; N];
+        self.verify_trunc_hash_buf(sig, rm, hv, &mut scratch)
+    }
+
+    /// Verifies a truncated signature on a given hashed message, using
+    /// a caller-provided scratch buffer.
+    ///
+    /// This function is identical to `verify_trunc_hash()`, except that
+    /// the scratch buffer is provided by the caller, as the `scratch` slice.
+    /// That buffer MUST contain at least two elements. If it contains at
+    /// least `TRUNC_BUF_LEN` elements, then the reconstruction runs at
+    /// full speed; with a smaller buffer, the search uses a smaller table
+    /// and more lookups, and is slower: for 32 ignored bits, the cost
+    /// roughly doubles whenever the buffer size is divided by 2, below
+    /// 2^14 words or so. For `rm` ignored bits, a buffer of
+    /// 2^floor((rm-1)/2) + 1 words is sufficient for full speed (129
+    /// words for `rm` = 16, 2049 words for `rm` = 24). As an example of
+    /// the slowdown with a smaller buffer, on a 64-bit x86 system, a
+    /// 129-word (1 kB) buffer makes the verification about 7 times
+    /// slower for `rm` = 24, and a 2049-word (16 kB) buffer makes it
+    /// about 10 times slower for `rm` = 32, than with a full-size
+    /// buffer.
+    ///
+    /// The buffer contents on input are ignored; on output, they are
+    /// unspecified. Only public data is written into the buffer.
+    ///
+    /// Note: this function is not constant-time; it assumes that the
+    /// public key and signature value are public data.
+    pub fn verify_trunc_hash_buf(self,
+        sig: &[u8], rm: usize, hv: &[u8], scratch: &mut [u64])
+        -> Option<[u8; 64]>
     {
         // Check that we removed at least 8 bits. We also prevent trying
         // to remove more than 32 bits because the cost would be excessive;
         // it also allows keeping U_i[] indices over 16 bits.
-        assert!(rm >= 8 && rm <= 32);
+        assert!((8..=32).contains(&rm));
+        assert!(scratch.len() >= 2);
 
         // Signature array must have length 64 bytes exactly; but
         // we ignore the last rm bits. We copy the non-ignored bits
@@ -2421,7 +2641,7 @@ impl PublicKey {
         // Let a and b such that:
         //   s1 = a + 2^k*b
         // with:
-        //   k = ceil(m/2)
+        //   k = ceil(m/2)   (or more, if the scratch buffer is small)
         //   0 <= a < 2^k
         //   0 <= b < +2^(m-k)
         //
@@ -2453,15 +2673,28 @@ impl PublicKey {
         //   s1 = a + b*2^k = j + i*2^k (first case)
         //   s1 = 2^k - j + (i - 1)*2^k = -j + i*2^k (second case)
         //
-        // We compute all U_i, then extract 64 bits out of each x coordinate
-        // and sort them so that we may do efficient binary searches. We
-        // then compute all V_j and perform lookups. We proceed by batches
-        // since normalization to affine coordinates is much more efficient
-        // that way.
+        // We compute all U_i, and extract 64 bits out of each x coordinate
+        // (48 bits of the coordinate, and the 16-bit index i); these
+        // values are stored in the scratch buffer and sorted so that we
+        // may do efficient binary searches. We then compute all V_j and
+        // perform lookups. We proceed by batches since normalization to
+        // affine coordinates is much more efficient that way. We do not
+        // keep the complete x coordinates of the U_i: a match on 48 bits
+        // is confirmed by recomputing the points U_i and V_j; false
+        // positives are rare enough not to impact performance.
+        //
+        // The table contains 2^(m-k) + 1 values; the cost of building it
+        // is about the same as the cost of computing the 2^k + 1 values
+        // V_j (and sorting is comparatively inexpensive), so that we get
+        // optimal performance with k = ceil(m/2). If the provided buffer
+        // is too small, then we increase k accordingly.
 
         let n = 256 - rm;      // s0 has size n bits
         let m = 255 - n;
-        let k = (m + 1) >> 1;
+        let mut k = (m + 1) >> 1;
+        while (1usize << (m - k)) >= scratch.len() {
+            k += 1;
+        }
         let U = R.xdouble(n as u32);
         let V = self.point.mul_add_mulgen_vartime(&r, &h);
         let Rb = s0 * R;
@@ -2473,28 +2706,31 @@ impl PublicKey {
 
         // Compute all U_i = s0*R + i*(2^k)*U for 0 <= i <= +2^(m-k)
         // Since 8 <= rm <= 32, we have 7 <= m <= 31, hence m-k <= 15.
-        // We only need the x coordinates.
+        // We only need the x coordinates, from which we extract the
+        // search keys.
         let Uk = U.xdouble(k as u32);
-        let mut Ui = Vec::with_capacity(I + 1);
-        Ui.resize(I + 1, GFp256::ZERO);
-        let (x0, x1, xq) = Point::to_x_affine_diff(Rb, Rb + Uk);
-        Point::x_sequence_vartime(x0, x1, xq, &mut Ui[..]);
-
-        let mut ux = Vec::with_capacity(Ui.len());
-        for i in 0..Ui.len() {
-            let mut tmp = [0u8; 8];
-            tmp[0..2].copy_from_slice(&(i as u16).to_le_bytes());
-            tmp[2..8].copy_from_slice(&Ui[i].encode()[0..6]);
-            ux.push(u64::from_le_bytes(tmp));
+        let ux = &mut scratch[..(I + 1)];
+        let (mut x0, mut x1, xq) = Point::to_x_affine_diff(Rb, Rb + Uk);
+        let mut ii = 0;
+        while ii <= I {
+            let mut Ui = [GFp256::ZERO; 198];
+            let blen = if (I + 1 - ii) < 198 { I + 1 - ii } else { 198 };
+            (x0, x1) = Point::x_sequence_vartime(x0, x1, xq, &mut Ui[..blen]);
+            for (i, x) in Ui[..blen].iter().enumerate() {
+                let mut tmp = [0u8; 8];
+                tmp[0..2].copy_from_slice(&((ii + i) as u16).to_le_bytes());
+                tmp[2..8].copy_from_slice(&x.encode()[0..6]);
+                ux[ii + i] = u64::from_le_bytes(tmp);
+            }
+            ii += blen;
         }
-        ux.sort();
+        ux.sort_unstable();
 
         // For all V_j = V - j*U, look for the x coordinates among those
         // of the U_i; we use the ux[] array for that, and confirm any
-        // match with the complete X coordinates in the Ui[] array.
-        // We compute the V_j by batches of 100 so that normalization
-        // to affine coordinates is properly optimized, but we still
-        // stop early when possible.
+        // match by recomputing the points. We compute the V_j by batches
+        // of 100 so that normalization to affine coordinates is properly
+        // optimized, but we still stop early when possible.
         let (mut x0, mut x1, xq) = Point::to_x_affine_diff(V, V - U);
         let mut jj = 0;
         loop {
@@ -2504,14 +2740,14 @@ impl PublicKey {
                 blen = 100;
             }
             (x0, x1) = Point::x_sequence_vartime(x0, x1, xq, &mut Vj);
-            for j in 0..blen {
+            for (j, xv) in Vj[..blen].iter().enumerate() {
                 // Extract the search key from the x coordinate of V_j.
                 // We set the low 16 bits to 1, so that potential matches
                 // are keys which are lower than this value.
                 let mut tmp = [0u8; 8];
                 tmp[0] = 0xFF;
                 tmp[1] = 0xFF;
-                tmp[2..8].copy_from_slice(&Vj[j].encode()[0..6]);
+                tmp[2..8].copy_from_slice(&xv.encode()[0..6]);
                 let x = u64::from_le_bytes(tmp);
 
                 // Perform a search in ux[].
@@ -2537,43 +2773,46 @@ impl PublicKey {
                         break;
                     }
                     let i = (xm & 0xFFFF) as usize;
-                    if Vj[j].equals(Ui[i]) != 0 {
-                        // U_i and V_j have the same x coordinate. Therefore,
-                        // one of the following holds:
-                        //   U_i = V_j
-                        //  -U_i = V_j
-                        // First case yields:
-                        //   s0*R + i*(2^k)*U = h*G + r*Q - j*U
-                        // i.e.:
-                        //   s = s0 + (j + i*2^k)*2^n
-                        // Second case yields:
-                        //  -(s0*R + i*(2^k)*U) = h*G + r*Q - j*U
-                        // i.e.:
-                        //   s = s0 + (-j + i*2^k)*2^n
-                        //
-                        // We do not have the complete U_i and V_j (we
-                        // computed only the x coordinates) but we can
-                        // recompute them cheaply using the values we
-                        // already have:
-                        //   Rb = s0*R
-                        //   U = (2^n)*R
-                        //   Uk = (2^k)*(2^n)*R
-                        //   V = h*G + r*Q
-                        let j = jj + j;  // the "real" j
-                        let zUi = Rb + (i as u64) * Uk;
-                        let zVj = V - (j as u64) * U;
-                        let ni = Scalar::from_w64le(0, 0, 0,
-                            (i as u64) << (n + k - 192));
-                        let nj = Scalar::from_w64le(0, 0, 0,
-                            (j as u64) << (n - 192));
-                        let s = if zUi.equals(zVj) != 0 {
-                            s0 + ni + nj
-                        } else {
-                            debug_assert!(zUi.equals(-zVj) != 0);
-                            s0 + ni - nj
-                        };
-                        // sig2[] already contains r, we just have to encode
-                        // the complete s in it.
+
+                    // We do not have the complete U_i and V_j (we
+                    // only kept 48 bits of their x coordinates) but we
+                    // can recompute them cheaply using the values we
+                    // already have:
+                    //   Rb = s0*R
+                    //   U = (2^n)*R
+                    //   Uk = (2^k)*(2^n)*R
+                    //   V = h*G + r*Q
+                    // If U_i and V_j have the same x coordinate, then
+                    // one of the following holds:
+                    //   U_i = V_j
+                    //  -U_i = V_j
+                    // First case yields:
+                    //   s0*R + i*(2^k)*U = h*G + r*Q - j*U
+                    // i.e.:
+                    //   s = s0 + (j + i*2^k)*2^n
+                    // Second case yields:
+                    //  -(s0*R + i*(2^k)*U) = h*G + r*Q - j*U
+                    // i.e.:
+                    //   s = s0 + (-j + i*2^k)*2^n
+                    // If neither holds, then this was a false positive
+                    // (only 48 bits matched) and we continue the search.
+                    let j = jj + j;  // the "real" j
+                    let zUi = Rb + (i as u64) * Uk;
+                    let zVj = V - (j as u64) * U;
+                    let ni = Scalar::from_w64le(0, 0, 0,
+                        (i as u64) << (n + k - 192));
+                    let nj = Scalar::from_w64le(0, 0, 0,
+                        (j as u64) << (n - 192));
+                    let s = if zUi.equals(zVj) != 0 {
+                        Some(s0 + ni + nj)
+                    } else if zUi.equals(-zVj) != 0 {
+                        Some(s0 + ni - nj)
+                    } else {
+                        None
+                    };
+                    if let Some(s) = s {
+                        // sig2[] already contains r, we just have to
+                        // encode the complete s in it.
                         sig2[32..64].copy_from_slice(&bswap32(&s.encode()));
                         return Some(sig2);
                     }
@@ -2586,7 +2825,7 @@ impl PublicKey {
             }
 
             jj += blen;
-            if jj >= J + 1 {
+            if jj > J {
                 break;
             }
         }
@@ -2596,6 +2835,135 @@ impl PublicKey {
     }
 }
 
+/// Encodes a signature with the ASN.1 DER format.
+///
+/// The source signature uses the same format as the output of
+/// `PrivateKey::sign_hash()` (r and s in unsigned big-endian
+/// convention, over the same length, concatenated); an even length of
+/// up to 64 bytes is accepted. The output is the DER encoding of an ASN.1
+/// `SEQUENCE` of two `INTEGER` values (as used in X.509 and TLS);
+/// it is written in the first bytes of the returned array, and the
+/// second returned value is the encoded length (between 8 and 72
+/// bytes). `None` is returned if the source length is not valid.
+pub fn encode_der(sig: &[u8]) -> Option<([u8; 72], usize)> {
+    let siglen = sig.len();
+    if (siglen & 1) != 0 || siglen == 0 || siglen > 64 {
+        return None;
+    }
+    let numlen = siglen >> 1;
+
+    let mut der = [0u8; 72];
+    let mut j = 2;
+    for v in [&sig[..numlen], &sig[numlen..]] {
+        // Skip leading zeros (but keep at least one byte), and add
+        // a 0x00 byte if the top bit is set, since INTEGER uses
+        // signed big-endian convention.
+        let mut i = 0;
+        while i < (numlen - 1) && v[i] == 0 {
+            i += 1;
+        }
+        let pad = (v[i] >> 7) as usize;
+        let len = numlen - i + pad;
+        der[j] = 0x02;
+        der[j + 1] = len as u8;
+        der[j + 2] = 0x00;
+        der[(j + 2 + pad)..(j + 2 + len)].copy_from_slice(&v[i..]);
+        j += 2 + len;
+    }
+    der[0] = 0x30;
+    der[1] = (j - 2) as u8;
+    Some((der, j))
+}
+
+/// Decodes a signature from the ASN.1 DER format.
+///
+/// The source must be the DER encoding of an ASN.1 `SEQUENCE` of
+/// two `INTEGER` values (r and s); the signature is returned in the
+/// format used by `PublicKey::verify_hash()` (64 bytes). Decoding is strict:
+/// the BER-only variants (e.g. non-minimal lengths or integer
+/// encodings, indefinite lengths, trailing garbage) are rejected,
+/// as are negative integers and values that do not fit over 32
+/// bytes. The range of r and s is not checked here, but in
+/// `PublicKey::verify_hash()`.
+pub fn decode_der(der: &[u8]) -> Option<[u8; 64]> {
+    // The SEQUENCE length is at most 70 bytes, thus always uses the
+    // short form.
+    if der.len() < 2 || der[0] != 0x30 || (der[1] as usize) != der.len() - 2 {
+        return None;
+    }
+    let mut sig = [0u8; 64];
+    let mut j = 2;
+    for k in 0..2 {
+        if der.len() < j + 2 || der[j] != 0x02 {
+            return None;
+        }
+        let mut len = der[j + 1] as usize;
+        j += 2;
+        if len == 0 || len > 33 || der.len() < j + len {
+            return None;
+        }
+
+        // Negative integers are rejected. A leading 0x00 byte is
+        // allowed only if it is needed (i.e. the next byte has its
+        // top bit set).
+        if (der[j] & 0x80) != 0 {
+            return None;
+        }
+        if der[j] == 0x00 && len > 1 {
+            if (der[j + 1] & 0x80) == 0 {
+                return None;
+            }
+            j += 1;
+            len -= 1;
+        }
+        if len > 32 {
+            return None;
+        }
+        sig[(32 * k + 32 - len)..(32 * k + 32)]
+            .copy_from_slice(&der[j..(j + len)]);
+        j += len;
+    }
+    if j != der.len() {
+        return None;
+    }
+    Some(sig)
+}
+
+/// Normalizes a signature to its "low-S" form.
+///
+/// If (r, s) is a valid signature, then so is (r, n - s). The low-S
+/// form uses whichever of s and n - s is lower than n/2; this makes
+/// signatures non-malleable, and it is mandated in some contexts (see
+/// `PublicKey::verify_hash_strict()`). The source signature uses the
+/// same format as in `encode_der()`; the returned signature always
+/// has length 64 bytes. `None` is returned if the source length is
+/// not valid, or if r or s is zero or out of range.
+pub fn normalize_s(sig: &[u8]) -> Option<[u8; 64]> {
+    let siglen = sig.len();
+    if (siglen & 1) != 0 || siglen == 0 || siglen > 64 {
+        return None;
+    }
+    let numlen = siglen >> 1;
+    let mut rb = [0u8; 32];
+    let mut sb = [0u8; 32];
+    rb[(32 - numlen)..].copy_from_slice(&sig[..numlen]);
+    sb[(32 - numlen)..].copy_from_slice(&sig[numlen..]);
+    let (r, cr) = Scalar::decode32(&bswap32(&rb));
+    let (s, cs) = Scalar::decode32(&bswap32(&sb));
+    if (cr & cs & !r.iszero() & !s.iszero()) == 0 {
+        return None;
+    }
+
+    let mut nsig = [0u8; 64];
+    nsig[..32].copy_from_slice(&rb);
+    if is_high_s(&s) {
+        nsig[32..].copy_from_slice(&bswap32(&(-s).encode()));
+    } else {
+        nsig[32..].copy_from_slice(&sb);
+    }
+    Some(nsig)
+}
+
 // ========================================================================
 
 // We hardcode known multiples of the points B, (2^65)*B, (2^130)*B
@@ -2951,13 +3319,10 @@ static PRECOMP_G195: [PointAffine; 16] = [
 #[cfg(test)]
 mod tests {
 
-    use super::{Point, Scalar, PrivateKey};
+    use super::{Point, Scalar, PrivateKey, PublicKey};
+    use super::{encode_der, decode_der, normalize_s};
     use sha2::{Sha256, Digest};
 
-    #[cfg(feature = "alloc")]
-    use crate::Vec;
-
-    #[cfg(feature = "alloc")]
     use crate::field::GFp256;
 
     /* unused
@@ -2987,6 +3352,19 @@ mod tests {
     }
     */
 
+    #[test]
+    fn to_affine() {
+        let (x, y, r) = Point::NEUTRAL.to_affine();
+        assert!(r == 0);
+        assert!(x.equals(GFp256::ONE) == 0xFFFFFFFF);
+        assert!(y.iszero() == 0xFFFFFFFF);
+        let (x, y, r) = Point::BASE.to_affine();
+        assert!(r == 0xFFFFFFFF);
+        let mut P = Point::NEUTRAL;
+        assert!(P.set_affine(x, y) == 0xFFFFFFFF);
+        assert!(P.equals(Point::BASE) == 0xFFFFFFFF);
+    }
+
     #[test]
     fn base_arith() {
         // Encoding of neutral.
@@ -3314,17 +3692,16 @@ mod tests {
         }
     }
 
-    #[cfg(feature = "alloc")]
     #[test]
     fn x_sequence() {
         fn tt(P0: Point, P1: Point, n: usize) {
-            let mut xx = Vec::with_capacity(n);
-            xx.resize(n, GFp256::ZERO);
+            let mut xx = [GFp256::ZERO; 400];
+            let xx = &mut xx[..n];
             let Q = P1 - P0;
             let (x0, _, _) = P0.to_affine();
             let (x1, _, _) = P1.to_affine();
             let (xq, _, _) = Q.to_affine();
-            let (xf0, xf1) = Point::x_sequence_vartime(x0, x1, xq, &mut xx[..]);
+            let (xf0, xf1) = Point::x_sequence_vartime(x0, x1, xq, xx);
             let mut T = P0;
             for i in 0..n {
                 if T.isneutral() != 0 {
@@ -3441,7 +3818,148 @@ mod tests {
         assert!(!pkey.verify_hash(&sig2, &hv1));
     }
 
-    #[cfg(feature = "alloc")]
+    #[test]
+    fn recovery() {
+        // Test vector from RFC 6979, section A.2.5
+        let skey = PrivateKey::decode(&hex::decode(
+            "C9AFA9D845BA75166B5C215767B1D6934E50C3DB36E89B127B8A622B120F6721")
+            .unwrap()).unwrap();
+        let pkey = skey.to_public_key();
+        let mut sh = Sha256::new();
+        sh.update(b"sample");
+        let hv: [u8; 32] = sh.finalize_reset().into();
+        let (sig, recid) = skey.sign_hash_recoverable(&hv, &[]);
+        assert!(sig[..] == hex::decode(
+            "EFD48B2AACB6A8FD1140DD9CD45E81D69D2C877B56AAF991C34D0EA84EAF3716\
+             F7CB1C942D657C41D436C7A1B6E29F65F3E900DBB9AFF4064DC4AB2F843ACDA8")
+            .unwrap());
+        let pk2 = PublicKey::recover_from_hash(&sig, recid, &hv).unwrap();
+        assert!(pk2.point.equals(pkey.point) != 0);
+        for rr in 0..4 {
+            if rr != recid {
+                match PublicKey::recover_from_hash(&sig, rr, &hv) {
+                    Some(pk3) => {
+                        assert!(pk3.point.equals(pkey.point) == 0);
+                        assert!(pk3.verify_hash(&sig, &hv));
+                    }
+                    None => { assert!(rr >= 2); }
+                }
+            }
+        }
+
+        for i in 0..20 {
+            sh.update((i as u64).to_le_bytes());
+            let seed: [u8; 32] = sh.finalize_reset().into();
+            let sk = PrivateKey::from_seed(&seed);
+            let pk = sk.to_public_key();
+            let (sig, recid) = sk.sign_hash_recoverable(&seed, &[]);
+            assert!(sig == sk.sign_hash(&seed, &[]));
+            assert!(recid <= 1);
+            let pk2 = PublicKey::recover_from_hash(&sig, recid, &seed).unwrap();
+            assert!(pk2.point.equals(pk.point) != 0);
+            let pk3 = PublicKey::recover_from_hash(&sig, recid ^ 1, &seed)
+                .unwrap();
+            assert!(pk3.point.equals(pk.point) == 0);
+            assert!(!pk3.verify_hash(&sig, &seed[1..]));
+            assert!(PublicKey::recover_from_hash(&sig, 4, &seed).is_none());
+        }
+    }
+
+    #[test]
+    fn der_low_s() {
+        let mut sh = Sha256::new();
+        let mut num_high = 0;
+        for i in 0..40 {
+            sh.update((i as u64).to_le_bytes());
+            let seed: [u8; 32] = sh.finalize_reset().into();
+            let sk = PrivateKey::from_seed(&seed);
+            let pk = sk.to_public_key();
+            let sig = sk.sign_hash(&seed, &[]);
+
+            let (der, len) = encode_der(&sig).unwrap();
+            assert!(len <= 72 && der[0] == 0x30 && der[1] as usize == len - 2);
+            assert!(decode_der(&der[..len]).unwrap() == sig);
+            assert!(decode_der(&der[..(len - 1)]).is_none());
+            let mut der2 = [0u8; 73];
+            der2[..len].copy_from_slice(&der[..len]);
+            assert!(decode_der(&der2[..(len + 1)]).is_none());
+
+            let nsig = normalize_s(&sig).unwrap();
+            assert!(pk.verify_hash(&nsig, &seed));
+            assert!(pk.verify_hash_strict(&nsig, &seed));
+            assert!(nsig[32] < 0x80);
+            assert!(normalize_s(&nsig).unwrap() == nsig);
+            if nsig != sig {
+                num_high += 1;
+                assert!(nsig[..32] == sig[..32]);
+                assert!(!pk.verify_hash_strict(&sig, &seed));
+            } else {
+                assert!(pk.verify_hash_strict(&sig, &seed));
+            }
+        }
+        assert!(num_high > 0 && num_high < 40);
+
+        // Small values and leading zeros.
+        let mut sig = [0u8; 64];
+        sig[31] = 0x01;
+        sig[32] = 0x80;
+        let (der, len) = encode_der(&sig).unwrap();
+        assert!(der[..len] == hex::decode(
+            "3026020101022100800000000000000000000000000000000000000000000000000000000000000000")
+            .unwrap()[..len]);
+        assert!(decode_der(&der[..len]).unwrap() == sig);
+        let (der, len) = encode_der(&[0x00, 0x7F]).unwrap();
+        assert!(der[..len] == [0x30, 0x06, 0x02, 0x01, 0x00, 0x02, 0x01, 0x7F]);
+        assert!(encode_der(&sig[..63]).is_none());
+        assert!(normalize_s(&[0u8; 64]).is_none());
+
+        // Non-minimal encodings and negative integers are rejected.
+        for bad in [
+            "3007020101020200010000",
+            "300702020001020101",
+            "3006020101020180",
+            "30060201ff020101",
+            "3081060201010201010000",
+            "3080020101020101000000",
+            "31060201010201010000",
+        ] {
+            assert!(decode_der(&hex::decode(bad).unwrap()).is_none());
+        }
+        assert!(decode_der(&hex::decode(
+            "3006020101020101").unwrap()).is_some());
+    }
+
+    #[test]
+    fn wycheproof() {
+        let mut pk = None;
+        for line in WYCHEPROOF_VECTORS.lines() {
+            let mut f = line.split(',');
+            let pk_enc = f.next().unwrap();
+            if !pk_enc.is_empty() {
+                pk = Some(PublicKey::decode(&hex::decode(pk_enc).unwrap())
+                    .unwrap());
+            }
+            let pk = pk.unwrap();
+            let msg = hex::decode(f.next().unwrap()).unwrap();
+            let der = hex::decode(f.next().unwrap()).unwrap();
+            let valid = f.next().unwrap() == "1";
+            let mut sh = Sha256::new();
+            sh.update(&msg);
+            let hv = sh.finalize();
+            let ok = match decode_der(&der) {
+                Some(sig) => pk.verify_hash(&sig, &hv),
+                None => false,
+            };
+            assert!(ok == valid);
+            if valid {
+                // Valid signatures use DER, which is canonical.
+                let sig = decode_der(&der).unwrap();
+                let (der2, len) = encode_der(&sig).unwrap();
+                assert!(der2[..len] == der[..]);
+            }
+        }
+    }
+
     #[test]
     fn signatures_trunc() {
         let mut seed = [0u8; 48];
@@ -3473,4 +3991,857 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn signatures_trunc_buf() {
+        let mut sh = Sha256::new();
+        sh.update([2u8]);
+        let skey = PrivateKey::from_seed(&sh.finalize_reset()[..]);
+        let pkey = skey.to_public_key();
+        sh.update([3u8]);
+        let hv = sh.finalize_reset();
+        let sig1 = skey.sign_hash(&hv[..], &[]);
+        let sig2 = PrivateKey::prepare_truncate(&sig1).unwrap();
+        let mut scratch = [0u64; 1000];
+        for (rm, blen) in [(8, 2), (8, 3), (13, 5), (20, 17), (20, 1000)] {
+            // Ignored bits are set to 1; they must not matter.
+            let mut sig3 = sig2;
+            for i in (512 - rm)..512 {
+                sig3[i >> 3] |= 1u8 << (i & 7);
+            }
+            let sig4 = pkey.verify_trunc_hash(&sig3, rm, &hv[..]).unwrap();
+            assert!(pkey.verify_hash(&sig4, &hv[..]));
+
+            // Smaller buffers must yield the same result (only slower).
+            let sig5 = pkey.verify_trunc_hash_buf(&sig3, rm, &hv[..],
+                &mut scratch[..blen]).unwrap();
+            assert!(sig4 == sig5);
+            assert!(pkey.verify_trunc_hash_buf(&sig3, rm, &sig1[..32],
+                &mut scratch[..blen]).is_none());
+        }
+    }
+
+    // Wycheproof test vectors (file ecdsa_secp256r1_sha256_test.json). Each line
+    // contains the public key (uncompressed format, omitted when the
+    // same as in the previous line), the message (hashed with SHA-256),
+    // the DER-encoded signature, and the expected result (1 = valid).
+    static WYCHEPROOF_VECTORS: &str = concat!(
+        "042927b10512bae3eddcfe467828128bad2903269919f7086069c8c4df6c732838c7787964eaac00e5921fb1498a60f4606766b3d9685001558d1a974e7341513e,313233343030,304402202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e1802204cd60b855d442f5b3c7b11eb6c4e0ae7525fe710fab9aa7c77a67f79e6fadd76,1\n",
+        ",313233343030,304502202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,1\n",
+        ",313233343030,30814502202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,3082004502202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,304602202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,304402202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,3085010000004502202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,308901000000000000004502202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,30847fffffff02202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,3084ffffffff02202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,3085ffffffffff02202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,3088ffffffffffffffff02202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,30ff02202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,308002202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,304502802ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,304502202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18028000b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,,0\n",
+        ",313233343030,30,0\n",
+        ",313233343030,304702202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db0000,0\n",
+        ",313233343030,3047000002202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,304502202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db0000,0\n",
+        ",313233343030,304702202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db0500,0\n",
+        ",313233343030,304a498177304502202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,30492500304502202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,3047304502202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db0004deadbeef,0\n",
+        ",313233343030,304a222549817702202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,30492224250002202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,304d222202202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e180004deadbeef022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,304a02202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e182226498177022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,304902202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e1822252500022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,304d02202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e182223022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db0004deadbeef,0\n",
+        ",313233343030,304daa00bb00cd00304502202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,304baa02aabb304502202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,304d2228aa00bb00cd0002202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,304b2226aa02aabb02202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,304d02202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e182229aa00bb00cd00022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,304b02202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e182227aa02aabb022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,3081,0\n",
+        ",313233343030,3080304502202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db0000,0\n",
+        ",313233343030,3049228002202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e180000022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,304902202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e182280022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db0000,0\n",
+        ",313233343030,3080314502202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db0000,0\n",
+        ",313233343030,3049228003202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e180000022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,304902202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e182280032100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db0000,0\n",
+        ",313233343030,0500,0\n",
+        ",313233343030,2e4502202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,2f4502202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,314502202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,324502202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,ff4502202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,3000,0\n",
+        ",313233343030,30493001023044202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,304402202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847,0\n",
+        ",313233343030,3044202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,308002202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db0000,0\n",
+        ",313233343030,308002202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db00,0\n",
+        ",313233343030,308002202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db05000000,0\n",
+        ",313233343030,308002202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db060811220000,0\n",
+        ",313233343030,308002202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db0000fe02beef,0\n",
+        ",313233343030,308002202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db0002beef,0\n",
+        ",313233343030,3047300002202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,304702202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db3000,0\n",
+        ",313233343030,304802202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847dbbf7f00,0\n",
+        ",313233343030,3047304502202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,302202202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18,0\n",
+        ",313233343030,306802202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,30460281202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,304602202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e1802812100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,3047028200202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,304702202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e180282002100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,304502212ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,3045021f2ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,304502202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022200b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,304502202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022000b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,304a028501000000202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,304a02202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e180285010000002100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,304e02890100000000000000202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,304e02202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18028901000000000000002100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,304902847fffffff2ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,304902202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e1802847fffffff00b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,30490284ffffffff2ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,304902202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e180284ffffffff00b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,304a0285ffffffffff2ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,304a02202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e180285ffffffffff00b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,304d0288ffffffffffffffff2ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,304d02202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e180288ffffffffffffffff00b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,304502ff2ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,304502202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e1802ff00b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,3023022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,302402022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,302302202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e1802,0\n",
+        ",313233343030,304702222ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e180000022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,304702202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022300b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db0000,0\n",
+        ",313233343030,3047022200002ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,304702202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e180223000000b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,304702202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e180000022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,304702222ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e180500022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,304702202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022300b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db0500,0\n",
+        ",313233343030,30250281022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,302402202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e180281,0\n",
+        ",313233343030,30250500022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,302402202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e180500,0\n",
+        ",313233343030,304500202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,304501202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,304503202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,304504202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,3045ff202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,304502202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18002100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,304502202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18012100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,304502202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18032100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,304502202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18042100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,304502202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18ff2100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,30250200022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,302402202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e180200,0\n",
+        ",313233343030,3049222402012b021fa3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,304902202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e1822250201000220b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,3045022029a3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,304502202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022102b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,304502202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e98022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,304502202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b491568475b,0\n",
+        ",313233343030,3044021f2ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,3044021fa3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,304402202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022000b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847,0\n",
+        ",313233343030,30460221ff2ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,304602202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e180222ff00b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,3026090180022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,302502202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18090180,0\n",
+        ",313233343030,3026020100022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,302502202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18020100,0\n",
+        ",313233343030,30460221012ba3a8bd6b94d5ed80a6d9d1190a436ebccc0833490686deac8635bcb9bf5369022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,30460221ff2ba3a8bf6b94d5eb80a6d9d1190a436f42fe12d7fad749d4c512a036c0f908c7022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,30450220d45c5741946b2a137f59262ee6f5bc91001af27a5e1117a64733950642a3d1e8022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,3046022100d45c5740946b2a147f59262ee6f5bc90bd01ed280528b62b3aed5fc93f06f739022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,30460221fed45c5742946b2a127f59262ee6f5bc914333f7ccb6f979215379ca434640ac97022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,30460221012ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,3046022100d45c5741946b2a137f59262ee6f5bc91001af27a5e1117a64733950642a3d1e8022100b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,304502202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022101b329f478a2bbd0a6c384ee1493b1f518276e0e4a5375928d6fcd160c11cb6d2c,0\n",
+        ",313233343030,304402202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e180220b329f47aa2bbd0a4c384ee1493b1f518ada018ef05465583885980861905228a,0\n",
+        ",313233343030,304502202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e180221ff4cd60b865d442f5a3c7b11eb6c4e0ae79578ec6353a20bf783ecb4b6ea97b825,0\n",
+        ",313233343030,304502202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e180221fe4cd60b875d442f593c7b11eb6c4e0ae7d891f1b5ac8a6d729032e9f3ee3492d4,0\n",
+        ",313233343030,304502202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e18022101b329f479a2bbd0a5c384ee1493b1f5186a87139cac5df4087c134b49156847db,0\n",
+        ",313233343030,304402202ba3a8be6b94d5ec80a6d9d1190a436effe50d85a1eee859b8cc6af9bd5c2e1802204cd60b865d442f5a3c7b11eb6c4e0ae79578ec6353a20bf783ecb4b6ea97b825,0\n",
+        ",313233343030,3006020100020100,0\n",
+        ",313233343030,3006020100020101,0\n",
+        ",313233343030,30060201000201ff,0\n",
+        ",313233343030,3026020100022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551,0\n",
+        ",313233343030,3026020100022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632550,0\n",
+        ",313233343030,3026020100022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632552,0\n",
+        ",313233343030,3026020100022100ffffffff00000001000000000000000000000000ffffffffffffffffffffffff,0\n",
+        ",313233343030,3026020100022100ffffffff00000001000000000000000000000001000000000000000000000000,0\n",
+        ",313233343030,3008020100090380fe01,0\n",
+        ",313233343030,3006020100090142,0\n",
+        ",313233343030,3006020101020100,0\n",
+        ",313233343030,3006020101020101,0\n",
+        ",313233343030,30060201010201ff,0\n",
+        ",313233343030,3026020101022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551,0\n",
+        ",313233343030,3026020101022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632550,0\n",
+        ",313233343030,3026020101022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632552,0\n",
+        ",313233343030,3026020101022100ffffffff00000001000000000000000000000000ffffffffffffffffffffffff,0\n",
+        ",313233343030,3026020101022100ffffffff00000001000000000000000000000001000000000000000000000000,0\n",
+        ",313233343030,3008020101090380fe01,0\n",
+        ",313233343030,3006020101090142,0\n",
+        ",313233343030,30060201ff020100,0\n",
+        ",313233343030,30060201ff020101,0\n",
+        ",313233343030,30060201ff0201ff,0\n",
+        ",313233343030,30260201ff022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551,0\n",
+        ",313233343030,30260201ff022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632550,0\n",
+        ",313233343030,30260201ff022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632552,0\n",
+        ",313233343030,30260201ff022100ffffffff00000001000000000000000000000000ffffffffffffffffffffffff,0\n",
+        ",313233343030,30260201ff022100ffffffff00000001000000000000000000000001000000000000000000000000,0\n",
+        ",313233343030,30080201ff090380fe01,0\n",
+        ",313233343030,30060201ff090142,0\n",
+        ",313233343030,3026022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551020100,0\n",
+        ",313233343030,3026022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551020101,0\n",
+        ",313233343030,3026022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc6325510201ff,0\n",
+        ",313233343030,3046022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551,0\n",
+        ",313233343030,3046022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632550,0\n",
+        ",313233343030,3046022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632552,0\n",
+        ",313233343030,3046022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551022100ffffffff00000001000000000000000000000000ffffffffffffffffffffffff,0\n",
+        ",313233343030,3046022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551022100ffffffff00000001000000000000000000000001000000000000000000000000,0\n",
+        ",313233343030,3028022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551090380fe01,0\n",
+        ",313233343030,3026022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551090142,0\n",
+        ",313233343030,3026022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632550020100,0\n",
+        ",313233343030,3026022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632550020101,0\n",
+        ",313233343030,3026022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc6325500201ff,0\n",
+        ",313233343030,3046022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632550022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551,0\n",
+        ",313233343030,3046022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632550022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632550,0\n",
+        ",313233343030,3046022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632550022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632552,0\n",
+        ",313233343030,3046022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632550022100ffffffff00000001000000000000000000000000ffffffffffffffffffffffff,0\n",
+        ",313233343030,3046022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632550022100ffffffff00000001000000000000000000000001000000000000000000000000,0\n",
+        ",313233343030,3028022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632550090380fe01,0\n",
+        ",313233343030,3026022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632550090142,0\n",
+        ",313233343030,3026022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632552020100,0\n",
+        ",313233343030,3026022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632552020101,0\n",
+        ",313233343030,3026022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc6325520201ff,0\n",
+        ",313233343030,3046022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632552022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551,0\n",
+        ",313233343030,3046022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632552022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632550,0\n",
+        ",313233343030,3046022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632552022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632552,0\n",
+        ",313233343030,3046022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632552022100ffffffff00000001000000000000000000000000ffffffffffffffffffffffff,0\n",
+        ",313233343030,3046022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632552022100ffffffff00000001000000000000000000000001000000000000000000000000,0\n",
+        ",313233343030,3028022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632552090380fe01,0\n",
+        ",313233343030,3026022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632552090142,0\n",
+        ",313233343030,3026022100ffffffff00000001000000000000000000000000ffffffffffffffffffffffff020100,0\n",
+        ",313233343030,3026022100ffffffff00000001000000000000000000000000ffffffffffffffffffffffff020101,0\n",
+        ",313233343030,3026022100ffffffff00000001000000000000000000000000ffffffffffffffffffffffff0201ff,0\n",
+        ",313233343030,3046022100ffffffff00000001000000000000000000000000ffffffffffffffffffffffff022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551,0\n",
+        ",313233343030,3046022100ffffffff00000001000000000000000000000000ffffffffffffffffffffffff022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632550,0\n",
+        ",313233343030,3046022100ffffffff00000001000000000000000000000000ffffffffffffffffffffffff022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632552,0\n",
+        ",313233343030,3046022100ffffffff00000001000000000000000000000000ffffffffffffffffffffffff022100ffffffff00000001000000000000000000000000ffffffffffffffffffffffff,0\n",
+        ",313233343030,3046022100ffffffff00000001000000000000000000000000ffffffffffffffffffffffff022100ffffffff00000001000000000000000000000001000000000000000000000000,0\n",
+        ",313233343030,3028022100ffffffff00000001000000000000000000000000ffffffffffffffffffffffff090380fe01,0\n",
+        ",313233343030,3026022100ffffffff00000001000000000000000000000000ffffffffffffffffffffffff090142,0\n",
+        ",313233343030,3026022100ffffffff00000001000000000000000000000001000000000000000000000000020100,0\n",
+        ",313233343030,3026022100ffffffff00000001000000000000000000000001000000000000000000000000020101,0\n",
+        ",313233343030,3026022100ffffffff000000010000000000000000000000010000000000000000000000000201ff,0\n",
+        ",313233343030,3046022100ffffffff00000001000000000000000000000001000000000000000000000000022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551,0\n",
+        ",313233343030,3046022100ffffffff00000001000000000000000000000001000000000000000000000000022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632550,0\n",
+        ",313233343030,3046022100ffffffff00000001000000000000000000000001000000000000000000000000022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632552,0\n",
+        ",313233343030,3046022100ffffffff00000001000000000000000000000001000000000000000000000000022100ffffffff00000001000000000000000000000000ffffffffffffffffffffffff,0\n",
+        ",313233343030,3046022100ffffffff00000001000000000000000000000001000000000000000000000000022100ffffffff00000001000000000000000000000001000000000000000000000000,0\n",
+        ",313233343030,3028022100ffffffff00000001000000000000000000000001000000000000000000000000090380fe01,0\n",
+        ",313233343030,3026022100ffffffff00000001000000000000000000000001000000000000000000000000090142,0\n",
+        ",313233343030,30060201010c0130,0\n",
+        ",313233343030,30050201010c00,0\n",
+        ",313233343030,30090c0225730c03732573,0\n",
+        ",313233343030,30080201013003020100,0\n",
+        ",313233343030,3003020101,0\n",
+        ",313233343030,3006020101010100,0\n",
+        ",3639383139,3044022064a1aab5000d0e804f3e2fc02bdee9be8ff312334e2ba16d11547c97711c898e02206af015971cc30be6d1a206d4e013e0997772a2f91d73286ffd683b9bb2cf4f1b,1\n",
+        ",343236343739373234,3044022016aea964a2f6506d6f78c81c91fc7e8bded7d397738448de1e19a0ec580bf2660220252cd762130c6667cfe8b7bc47d27d78391e8e80c578d1cd38c3ff033be928e9,1\n",
+        ",37313338363834383931,30450221009cc98be2347d469bf476dfc26b9b733df2d26d6ef524af917c665baccb23c8820220093496459effe2d8d70727b82462f61d0ec1b7847929d10ea631dacb16b56c32,1\n",
+        ",3130333539333331363638,3044022073b3c90ecd390028058164524dde892703dce3dea0d53fa8093999f07ab8aa4302202f67b0b8e20636695bb7d8bf0a651c802ed25a395387b5f4188c0c4075c88634,1\n",
+        ",33393439343031323135,3046022100bfab3098252847b328fadf2f89b95c851a7f0eb390763378f37e90119d5ba3dd022100bdd64e234e832b1067c2d058ccb44d978195ccebb65c2aaf1e2da9b8b4987e3b,1\n",
+        ",31333434323933303739,30440220204a9784074b246d8bf8bf04a4ceb1c1f1c9aaab168b1596d17093c5cd21d2cd022051cce41670636783dc06a759c8847868a406c2506fe17975582fe648d1d88b52,1\n",
+        ",33373036323131373132,3046022100ed66dc34f551ac82f63d4aa4f81fe2cb0031a91d1314f835027bca0f1ceeaa0302210099ca123aa09b13cd194a422e18d5fda167623c3f6e5d4d6abb8953d67c0c48c7,1\n",
+        ",333433363838373132,30450220060b700bef665c68899d44f2356a578d126b062023ccc3c056bf0f60a237012b0221008d186c027832965f4fcc78a3366ca95dedbb410cbef3f26d6be5d581c11d3610,1\n",
+        ",31333531353330333730,30460221009f6adfe8d5eb5b2c24d7aa7934b6cf29c93ea76cd313c9132bb0c8e38c96831d022100b26a9c9e40e55ee0890c944cf271756c906a33e66b5bd15e051593883b5e9902,1\n",
+        ",36353533323033313236,3045022100a1af03ca91677b673ad2f33615e56174a1abf6da168cebfa8868f4ba273f16b7022020aa73ffe48afa6435cd258b173d0c2377d69022e7d098d75caf24c8c5e06b1c,1\n",
+        ",31353634333436363033,3045022100fdc70602766f8eed11a6c99a71c973d5659355507b843da6e327a28c11893db902203df5349688a085b137b1eacf456a9e9e0f6d15ec0078ca60a7f83f2b10d21350,1\n",
+        ",34343239353339313137,3046022100b516a314f2fce530d6537f6a6c49966c23456f63c643cf8e0dc738f7b876e675022100d39ffd033c92b6d717dd536fbc5efdf1967c4bd80954479ba66b0120cd16fff2,1\n",
+        ",3130393533323631333531,304402203b2cbf046eac45842ecb7984d475831582717bebb6492fd0a485c101e29ff0a802204c9b7b47a98b0f82de512bc9313aaf51701099cac5f76e68c8595fc1c1d99258,1\n",
+        ",35393837333530303431,3044022030c87d35e636f540841f14af54e2f9edd79d0312cfa1ab656c3fb15bfde48dcf022047c15a5a82d24b75c85a692bd6ecafeb71409ede23efd08e0db9abf6340677ed,1\n",
+        ",33343633303036383738,3044022038686ff0fda2cef6bc43b58cfe6647b9e2e8176d168dec3c68ff262113760f520220067ec3b651f422669601662167fa8717e976e2db5e6a4cf7c2ddabb3fde9d67d,1\n",
+        ",39383137333230323837,3044022044a3e23bf314f2b344fc25c7f2de8b6af3e17d27f5ee844b225985ab6e2775cf02202d48e223205e98041ddc87be532abed584f0411f5729500493c9cc3f4dd15e86,1\n",
+        ",33323232303431303436,304402202ded5b7ec8e90e7bf11f967a3d95110c41b99db3b5aa8d330eb9d638781688e902207d5792c53628155e1bfc46fb1a67e3088de049c328ae1f44ec69238a009808f9,1\n",
+        ",36363636333037313034,3046022100bdae7bcb580bf335efd3bc3d31870f923eaccafcd40ec2f605976f15137d8b8f022100f6dfa12f19e525270b0106eecfe257499f373a4fb318994f24838122ce7ec3c7,1\n",
+        ",31303335393531383938,3045022050f9c4f0cd6940e162720957ffff513799209b78596956d21ece251c2401f1c6022100d7033a0a787d338e889defaaabb106b95a4355e411a59c32aa5167dfab244726,1\n",
+        ",31383436353937313935,3045022100f612820687604fa01906066a378d67540982e29575d019aabe90924ead5c860d02203f9367702dd7dd4f75ea98afd20e328a1a99f4857b316525328230ce294b0fef,1\n",
+        ",33313336303436313839,30460221009505e407657d6e8bc93db5da7aa6f5081f61980c1949f56b0f2f507da5782a7a022100c60d31904e3669738ffbeccab6c3656c08e0ed5cb92b3cfa5e7f71784f9c5021,1\n",
+        ",32363633373834323534,3046022100bbd16fbbb656b6d0d83e6a7787cd691b08735aed371732723e1c68a40404517d0221009d8e35dba96028b7787d91315be675877d2d097be5e8ee34560e3e7fd25c0f00,1\n",
+        ",31363532313030353234,304402202ec9760122db98fd06ea76848d35a6da442d2ceef7559a30cf57c61e92df327e02207ab271da90859479701fccf86e462ee3393fb6814c27b760c4963625c0a19878,1\n",
+        ",35373438303831363936,3044022054e76b7683b6650baa6a7fc49b1c51eed9ba9dd463221f7a4f1005a89fe00c5902202ea076886c773eb937ec1cc8374b7915cfd11b1c1ae1166152f2f7806a31c8fd,1\n",
+        ",36333433393133343638,304402205291deaf24659ffbbce6e3c26f6021097a74abdbb69be4fb10419c0c496c9466022065d6fcf336d27cc7cdb982bb4e4ecef5827f84742f29f10abf83469270a03dc3,1\n",
+        ",31353431313033353938,30450220207a3241812d75d947419dc58efb05e8003b33fc17eb50f9d15166a88479f107022100cdee749f2e492b213ce80b32d0574f62f1c5d70793cf55e382d5caadf7592767,1\n",
+        ",3130343738353830313238,304502206554e49f82a855204328ac94913bf01bbe84437a355a0a37c0dee3cf81aa7728022100aea00de2507ddaf5c94e1e126980d3df16250a2eaebc8be486effe7f22b4f929,1\n",
+        ",3130353336323835353638,3046022100a54c5062648339d2bff06f71c88216c26c6e19b4d80a8c602990ac82707efdfc022100e99bbe7fcfafae3e69fd016777517aa01056317f467ad09aff09be73c9731b0d,1\n",
+        ",393533393034313035,3045022100975bd7157a8d363b309f1f444012b1a1d23096593133e71b4ca8b059cff37eaf02207faa7a28b1c822baa241793f2abc930bd4c69840fe090f2aacc46786bf919622,1\n",
+        ",393738383438303339,304402205694a6f84b8f875c276afd2ebcfe4d61de9ec90305afb1357b95b3e0da43885e02200dffad9ffd0b757d8051dec02ebdf70d8ee2dc5c7870c0823b6ccc7c679cbaa4,1\n",
+        ",33363130363732343432,3045022100a0c30e8026fdb2b4b4968a27d16a6d08f7098f1a98d21620d7454ba9790f1ba602205e470453a8a399f15baf463f9deceb53acc5ca64459149688bd2760c65424339,1\n",
+        ",31303534323430373035,30440220614ea84acf736527dd73602cd4bb4eea1dfebebd5ad8aca52aa0228cf7b99a880220737cc85f5f2d2f60d1b8183f3ed490e4de14368e96a9482c2a4dd193195c902f,1\n",
+        ",35313734343438313937,3045022100bead6734ebe44b810d3fb2ea00b1732945377338febfd439a8d74dfbd0f942fa02206bb18eae36616a7d3cad35919fd21a8af4bbe7a10f73b3e036a46b103ef56e2a,1\n",
+        ",31393637353631323531,30440220499625479e161dacd4db9d9ce64854c98d922cbf212703e9654fae182df9bad2022042c177cf37b8193a0131108d97819edd9439936028864ac195b64fca76d9d693,1\n",
+        ",33343437323533333433,3045022008f16b8093a8fb4d66a2c8065b541b3d31e3bfe694f6b89c50fb1aaa6ff6c9b20221009d6455e2d5d1779748573b611cb95d4a21f967410399b39b535ba3e5af81ca2e,1\n",
+        ",333638323634333138,3046022100be26231b6191658a19dd72ddb99ed8f8c579b6938d19bce8eed8dc2b338cb5f8022100e1d9a32ee56cffed37f0f22b2dcb57d5c943c14f79694a03b9c5e96952575c89,1\n",
+        ",33323631313938363038,3045022015e76880898316b16204ac920a02d58045f36a229d4aa4f812638c455abe0443022100e74d357d3fcb5c8c5337bd6aba4178b455ca10e226e13f9638196506a1939123,1\n",
+        ",39363738373831303934,30440220352ecb53f8df2c503a45f9846fc28d1d31e6307d3ddbffc1132315cc07f16dad02201348dfa9c482c558e1d05c5242ca1c39436726ecd28258b1899792887dd0a3c6,1\n",
+        ",34393538383233383233,304402204a40801a7e606ba78a0da9882ab23c7677b8642349ed3d652c5bfa5f2a9558fb02203a49b64848d682ef7f605f2832f7384bdc24ed2925825bf8ea77dc5981725782,1\n",
+        ",383234363337383337,3045022100eacc5e1a8304a74d2be412b078924b3bb3511bac855c05c9e5e9e44df3d61e9602207451cd8e18d6ed1885dd827714847f96ec4bb0ed4c36ce9808db8f714204f6d1,1\n",
+        ",3131303230383333373736,304502202f7a5e9e5771d424f30f67fdab61e8ce4f8cd1214882adb65f7de94c31577052022100ac4e69808345809b44acb0b2bd889175fb75dd050c5a449ab9528f8f78daa10c,1\n",
+        ",313333383731363438,3045022100ffcda40f792ce4d93e7e0f0e95e1a2147dddd7f6487621c30a03d710b3300219022079938b55f8a17f7ed7ba9ade8f2065a1fa77618f0b67add8d58c422c2453a49a,1\n",
+        ",333232313434313632,304602210081f2359c4faba6b53d3e8c8c3fcc16a948350f7ab3a588b28c17603a431e39a8022100cd6f6a5cc3b55ead0ff695d06c6860b509e46d99fccefb9f7f9e101857f74300,1\n",
+        ",3130363836363535353436,3045022100dfc8bf520445cbb8ee1596fb073ea283ea130251a6fdffa5c3f5f2aaf75ca8080220048e33efce147c9dd92823640e338e68bfd7d0dc7a4905b3a7ac711e577e90e7,1\n",
+        ",3632313535323436,3046022100ad019f74c6941d20efda70b46c53db166503a0e393e932f688227688ba6a576202210093320eb7ca0710255346bdbb3102cdcf7964ef2e0988e712bc05efe16c199345,1\n",
+        ",37303330383138373734,3046022100ac8096842e8add68c34e78ce11dd71e4b54316bd3ebf7fffdeb7bd5a3ebc1883022100f5ca2f4f23d674502d4caf85d187215d36e3ce9f0ce219709f21a3aac003b7a8,1\n",
+        ",35393234353233373434,30440220677b2d3a59b18a5ff939b70ea002250889ddcd7b7b9d776854b4943693fb92f702206b4ba856ade7677bf30307b21f3ccda35d2f63aee81efd0bab6972cc0795db55,1\n",
+        ",31343935353836363231,30450220479e1ded14bcaed0379ba8e1b73d3115d84d31d4b7c30e1f05e1fc0d5957cfb0022100918f79e35b3d89487cf634a4f05b2e0c30857ca879f97c771e877027355b2443,1\n",
+        ",34303035333134343036,3044022043dfccd0edb9e280d9a58f01164d55c3d711e14b12ac5cf3b64840ead512a0a302201dbe33fa8ba84533cd5c4934365b3442ca1174899b78ef9a3199f49584389772,1\n",
+        ",33303936343537353132,304402205b09ab637bd4caf0f4c7c7e4bca592fea20e9087c259d26a38bb4085f0bbff11022045b7eb467b6748af618e9d80d6fdcd6aa24964e5a13f885bca8101de08eb0d75,1\n",
+        ",32373834303235363230,304502205e9b1c5a028070df5728c5c8af9b74e0667afa570a6cfa0114a5039ed15ee06f022100b1360907e2d9785ead362bb8d7bd661b6c29eeffd3c5037744edaeb9ad990c20,1\n",
+        ",32363138373837343138,304502200671a0a85c2b72d54a2fb0990e34538b4890050f5a5712f6d1a7a5fb8578f32e022100db1846bab6b7361479ab9c3285ca41291808f27fd5bd4fdac720e5854713694c,1\n",
+        ",31363432363235323632,304402207673f8526748446477dbbb0590a45492c5d7d69859d301abbaedb35b2095103a02203dc70ddf9c6b524d886bed9e6af02e0e4dec0d417a414fed3807ef4422913d7c,1\n",
+        ",36383234313839343336,304402207f085441070ecd2bb21285089ebb1aa6450d1a06c36d3ff39dfd657a796d12b50220249712012029870a2459d18d47da9aa492a5e6cb4b2d8dafa9e4c5c54a2b9a8b,1\n",
+        ",343834323435343235,3046022100914c67fb61dd1e27c867398ea7322d5ab76df04bc5aa6683a8e0f30a5d287348022100fa07474031481dda4953e3ac1959ee8cea7e66ec412b38d6c96d28f6d37304ea,1\n",
+        "040ad99500288d466940031d72a9f5445a4d43784640855bf0a69874d2de5fe103c5011e6ef2c42dcd50d5d3d29f99ae6eba2c80c9244f4c5422f0979ff0c3ba5e,313233343030,303502104319055358e8617b0c46353d039cdaab022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc63254e,1\n",
+        ",313233343030,3046022100ffffffff00000001000000000000000000000000fffffffffffffffffffffffc022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc63254e,0\n",
+        "04ab05fd9d0de26b9ce6f4819652d9fc69193d0aa398f0fba8013e09c58220455419235271228c786759095d12b75af0692dd4103f19f6a8c32f49435a1e9b8d45,313233343030,3046022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc63254f022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc63254e,1\n",
+        "0480984f39a1ff38a86a68aa4201b6be5dfbfecf876219710b07badf6fdd4c6c5611feb97390d9826e7a06dfb41871c940d74415ed3cac2089f1445019bb55ed95,313233343030,304502207ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffd022100909135bdb6799286170f5ead2de4f6511453fe50914f3df2de54a36383df8dd4,1\n",
+        "044201b4272944201c3294f5baa9a3232b6dd687495fcc19a70a95bc602b4f7c0595c37eba9ee8171c1bb5ac6feaf753bc36f463e3aef16629572c0c0a8fb0800e,313233343030,304402207ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffd022027b4577ca009376f71303fd5dd227dcef5deb773ad5f5a84360644669ca249a5,1\n",
+        "04a71af64de5126a4a4e02b7922d66ce9415ce88a4c9d25514d91082c8725ac9575d47723c8fbe580bb369fec9c2665d8e30a435b9932645482e7c9f11e872296b,313233343030,3006020105020101,1\n",
+        "046627cec4f0731ea23fc2931f90ebe5b7572f597d20df08fc2b31ee8ef16b15726170ed77d8d0a14fc5c9c3c4c9be7f0d3ee18f709bb275eaf2073e258fe694a5,313233343030,3006020105020103,1\n",
+        "045a7c8825e85691cce1f5e7544c54e73f14afc010cb731343262ca7ec5a77f5bfef6edf62a4497c1bd7b147fb6c3d22af3c39bfce95f30e13a16d3d7b2812f813,313233343030,3006020105020105,1\n",
+        "04cbe0c29132cd738364fedd603152990c048e5e2fff996d883fa6caca7978c73770af6a8ce44cb41224b2603606f4c04d188e80bff7cc31ad5189d4ab0d70e8c1,313233343030,3006020105020106,1\n",
+        ",313233343030,3026022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632556020106,0\n",
+        "044be4178097002f0deab68f0d9a130e0ed33a6795d02a20796db83444b037e13920f13051e0eecdcfce4dacea0f50d1f247caa669f193c1b4075b51ae296d2d56,313233343030,3026020105022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc75fbd8,0\n",
+        "04d0f73792203716afd4be4329faa48d269f15313ebbba379d7783c97bf3e890d9971f4a3206605bec21782bf5e275c714417e8f566549e6bc68690d2363c89cc1,313233343030,3027020201000221008f1e3c7862c58b16bb76eddbb76eddbb516af4f63f2d74d76e0d28c9bb75ea88,1\n",
+        "044838b2be35a6276a80ef9e228140f9d9b96ce83b7a254f71ccdebbb8054ce05ffa9cbc123c919b19e00238198d04069043bd660a828814051fcb8aac738a6c6b,313233343030,302c02072d9b4d347952d6022100ef3043e7329581dbb3974497710ab11505ee1c87ff907beebadd195a0ffe6d7a,1\n",
+        "047393983ca30a520bbc4783dc9960746aab444ef520c0a8e771119aa4e74b0f64e9d7be1ab01a0bf626e709863e6a486dbaf32793afccf774e2c6cd27b1857526,313233343030,3032020d1033e67e37b32b445580bf4eff0221008b748b74000000008b748b748b748b7466e769ad4a16d3dcd87129b8e91d1b4d,1\n",
+        "045ac331a1103fe966697379f356a937f350588a05477e308851b8a502d5dfcdc5fe9993df4b57939b2b8da095bf6d794265204cfe03be995a02e65d408c871c0b,313233343030,302702020100022100ef9f6ba4d97c09d03178fa20b4aaad83be3cf9cb824a879fec3270fc4b81ef5b,1\n",
+        "041d209be8de2de877095a399d3904c74cc458d926e27bb8e58e5eae5767c41509dd59e04c214f7b18dce351fc2a549893a6860e80163f38cc60a4f2c9d040d8c9,313233343030,3032020d062522bbd3ecbe7c39e93e7c25022100ef9f6ba4d97c09d03178fa20b4aaad83be3cf9cb824a879fec3270fc4b81ef5b,1\n",
+        "04083539fbee44625e3acaafa2fcb41349392cef0633a1b8fabecee0c133b10e99915c1ebe7bf00df8535196770a58047ae2a402f26326bb7d41d4d7616337911e,313233343030,3045022100ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc6324d50220555555550000000055555555555555553ef7a8e48d07df81a693439654210c70,1\n",
+        "048aeb368a7027a4d64abdea37390c0c1d6a26f399e2d9734de1eb3d0e1937387405bd13834715e1dbae9b875cf07bd55e1b6691c7f7536aef3b19bf7a4adf576d,313233343030,30250220555555550000000055555555555555553ef7a8e48d07df81a693439654210c70020101,1\n",
+        ",313233343030,30250220555555550000000055555555555555553ef7a8e48d07df81a693439654210c70020100,0\n",
+        "04b533d4695dd5b8c5e07757e55e6e516f7e2c88fa0239e23f60e8ec07dd70f2871b134ee58cc583278456863f33c3a85d881f7d4a39850143e29d4eaf009afe47,313233343030,304402207fffffff800000007fffffffffffffffde737d56d38bcf4279dce5617e3192a80220555555550000000055555555555555553ef7a8e48d07df81a693439654210c70,0\n",
+        "04f50d371b91bfb1d7d14e1323523bc3aa8cbf2c57f9e284de628c8b4536787b86f94ad887ac94d527247cd2e7d0c8b1291c553c9730405380b14cbb209f5fa2dd,313233343030,304402207fffffff800000007fffffffffffffffde737d56d38bcf4279dce5617e3192a902207fffffff800000007fffffffffffffffde737d56d38bcf4279dce5617e3192a8,1\n",
+        "0468ec6e298eafe16539156ce57a14b04a7047c221bafc3a582eaeb0d857c4d94697bed1af17850117fdb39b2324f220a5698ed16c426a27335bb385ac8ca6fb30,313233343030,304402207fffffff800000007fffffffffffffffde737d56d38bcf4279dce5617e3192a902207fffffff800000007fffffffffffffffde737d56d38bcf4279dce5617e3192a9,1\n",
+        "0469da0364734d2e530fece94019265fefb781a0f1b08f6c8897bdf6557927c8b866d2d3c7dcd518b23d726960f069ad71a933d86ef8abbcce8b20f71e2a847002,313233343030,30450220555555550000000055555555555555553ef7a8e48d07df81a693439654210c70022100bb5a52f42f9c9261ed4361f59422a1e30036e7c32b270c8807a419feca605023,1\n",
+        "04d8adc00023a8edc02576e2b63e3e30621a471e2b2320620187bf067a1ac1ff3233e2b50ec09807accb36131fff95ed12a09a86b4ea9690aa32861576ba2362e1,313233343030,30440220555555550000000055555555555555553ef7a8e48d07df81a693439654210c70022044a5ad0ad0636d9f12bc9e0a6bdd5e1cbcb012ea7bf091fcec15b0c43202d52e,1\n",
+        "043623ac973ced0a56fa6d882f03a7d5c7edca02cfc7b2401fab3690dbe75ab7858db06908e64b28613da7257e737f39793da8e713ba0643b92e9bb3252be7f8fe,313233343030,30440220555555550000000055555555555555553ef7a8e48d07df81a693439654210c700220555555550000000055555555555555553ef7a8e48d07df81a693439654210c70,1\n",
+        "04cf04ea77e9622523d894b93ff52dc3027b31959503b6fa3890e5e04263f922f1e8528fb7c006b3983c8b8400e57b4ed71740c2f3975438821199bedeaecab2e9,313233343030,30450220555555550000000055555555555555553ef7a8e48d07df81a693439654210c70022100aaaaaaaa00000000aaaaaaaaaaaaaaaa7def51c91a0fbf034d26872ca84218e1,1\n",
+        "04db7a2c8a1ab573e5929dc24077b508d7e683d49227996bda3e9f78dbeff773504f417f3bc9a88075c2e0aadd5a13311730cf7cc76a82f11a36eaf08a6c99a206,313233343030,304502207ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffd022100e91e1ba60fdedb76a46bcb51dc0b8b4b7e019f0a28721885fa5d3a8196623397,1\n",
+        "04dead11c7a5b396862f21974dc4752fadeff994efe9bbd05ab413765ea80b6e1f1de3f0640e8ac6edcf89cff53c40e265bb94078a343736df07aa0318fc7fe1ff,313233343030,304502207ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffd022100fdea5843ffeb73af94313ba4831b53fe24f799e525b1e8e8c87b59b95b430ad9,1\n",
+        "04d0bc472e0d7c81ebaed3a6ef96c18613bb1fea6f994326fbe80e00dfde67c7e9986c723ea4843d48389b946f64ad56c83ad70ff17ba85335667d1bb9fa619efd,313233343030,304402207ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffd022003ffcabf2f1b4d2a65190db1680d62bb994e41c5251cd73b3c3dfc5e5bafc035,1\n",
+        "04a0a44ca947d66a2acb736008b9c08d1ab2ad03776e02640f78495d458dd51c326337fe5cf8c4604b1f1c409dc2d872d4294a4762420df43a30a2392e40426add,313233343030,304402207ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffd02204dfbc401f971cd304b33dfdb17d0fed0fe4c1a88ae648e0d2847f74977534989,1\n",
+        "04c9c2115290d008b45fb65fad0f602389298c25420b775019d42b62c3ce8a96b73877d25a8080dc02d987ca730f0405c2c9dbefac46f9e601cc3f06e9713973fd,313233343030,304502207ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffd022100bc4024761cd2ffd43dfdb17d0fed112b988977055cd3a8e54971eba9cda5ca71,1\n",
+        "045eca1ef4c287dddc66b8bccf1b88e8a24c0018962f3c5e7efa83bc1a5ff6033e5e79c4cb2c245b8c45abdce8a8e4da758d92a607c32cd407ecaef22f1c934a71,313233343030,304402207ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffd0220788048ed39a5ffa77bfb62fa1fda2257742bf35d128fb3459f2a0c909ee86f91,1\n",
+        "045caaa030e7fdf0e4936bc7ab5a96353e0a01e4130c3f8bf22d473e317029a47adeb6adc462f7058f2a20d371e9702254e9b201642005b3ceda926b42b178bef9,313233343030,304402207ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffd0220476d9131fd381bd917d0fed112bc9e0a5924b5ed5b11167edd8b23582b3cb15e,1\n",
+        "04c2fd20bac06e555bb8ac0ce69eb1ea20f83a1fc3501c8a66469b1a31f619b0986237050779f52b615bd7b8d76a25fc95ca2ed32525c75f27ffc87ac397e6cbaf,313233343030,304502207ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffd0221008374253e3e21bd154448d0a8f640fe46fafa8b19ce78d538f6cc0a19662d3601,1\n",
+        "043fd6a1ca7f77fb3b0bbe726c372010068426e11ea6ae78ce17bedae4bba86ced03ce5516406bf8cfaab8745eac1cd69018ad6f50b5461872ddfc56e0db3c8ff4,313233343030,304402207ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffd0220357cfd3be4d01d413c5b9ede36cba5452c11ee7fe14879e749ae6a2d897a52d6,1\n",
+        "049cb8e51e27a5ae3b624a60d6dc32734e4989db20e9bca3ede1edf7b086911114b4c104ab3c677e4b36d6556e8ad5f523410a19f2e277aa895fc57322b4427544,313233343030,304402207ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffd022029798c5c0ee287d4a5e8e6b799fd86b8df5225298e6ffc807cd2f2bc27a0a6d8,1\n",
+        "04a3e52c156dcaf10502620b7955bc2b40bc78ef3d569e1223c262512d8f49602a4a2039f31c1097024ad3cc86e57321de032355463486164cf192944977df147f,313233343030,304402207ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffd02200b70f22c781092452dca1a5711fa3a5a1f72add1bf52c2ff7cae4820b30078dd,1\n",
+        "04f19b78928720d5bee8e670fb90010fb15c37bf91b58a5157c3f3c059b2655e88cf701ec962fb4a11dcf273f5dc357e58468560c7cfeb942d074abd4329260509,313233343030,304402207ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffd022016e1e458f021248a5b9434ae23f474b43ee55ba37ea585fef95c90416600f1ba,1\n",
+        "0483a744459ecdfb01a5cf52b27a05bb7337482d242f235d7b4cb89345545c90a8c05d49337b9649813287de9ffe90355fd905df5f3c32945828121f37cc50de6e,313233343030,304402207ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffd02202252d6856831b6cf895e4f0535eeaf0e5e5809753df848fe760ad86219016a97,1\n",
+        "04dd13c6b34c56982ddae124f039dfd23f4b19bbe88cee8e528ae51e5d6f3a21d7bfad4c2e6f263fe5eb59ca974d039fc0e4c3345692fb5320bdae4bd3b42a45ff,313233343030,304502207ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffd02210081ffe55f178da695b28c86d8b406b15dab1a9e39661a3ae017fbe390ac0972c3,1\n",
+        "0467e6f659cdde869a2f65f094e94e5b4dfad636bbf95192feeed01b0f3deb7460a37e0a51f258b7aeb51dfe592f5cfd5685bbe58712c8d9233c62886437c38ba0,313233343030,304402207ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffd02207fffffffaaaaaaaaffffffffffffffffe9a2538f37b28a2c513dee40fecbb71a,1\n",
+        "042eb6412505aec05c6545f029932087e490d05511e8ec1f599617bb367f9ecaaf805f51efcc4803403f9b1ae0124890f06a43fedcddb31830f6669af292895cb0,313233343030,304502207ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffd022100b62f26b5f2a2b26f6de86d42ad8a13da3ab3cccd0459b201de009e526adf21f2,1\n",
+        "0484db645868eab35e3a9fd80e056e2e855435e3a6b68d75a50a854625fe0d7f356d2589ac655edc9a11ef3e075eddda9abf92e72171570ef7bf43a2ee39338cfe,313233343030,304502207ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffd022100bb1d9ac949dd748cd02bbbe749bd351cd57b38bb61403d700686aa7b4c90851e,1\n",
+        "0491b9e47c56278662d75c0983b22ca8ea6aa5059b7a2ff7637eb2975e386ad66349aa8ff283d0f77c18d6d11dc062165fd13c3c0310679c1408302a16854ecfbd,313233343030,304402207ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffd022066755a00638cdaec1c732513ca0234ece52545dac11f816e818f725b4f60aaf2,1\n",
+        "04f3ec2f13caf04d0192b47fb4c5311fb6d4dc6b0a9e802e5327f7ec5ee8e4834df97e3e468b7d0db867d6ecfe81e2b0f9531df87efdb47c1338ac321fefe5a432,313233343030,304402207ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffd022055a00c9fcdaebb6032513ca0234ecfffe98ebe492fdf02e48ca48e982beb3669,1\n",
+        "04d92b200aefcab6ac7dafd9acaf2fa10b3180235b8f46b4503e4693c670fccc885ef2f3aebf5b317475336256768f7c19efb7352d27e4cccadc85b6b8ab922c72,313233343030,304502207ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffd022100ab40193f9b5d76c064a27940469d9fffd31d7c925fbe05c919491d3057d66cd2,1\n",
+        "040a88361eb92ecca2625b38e5f98bbabb96bf179b3d76fc48140a3bcd881523cde6bdf56033f84a5054035597375d90866aa2c96b86a41ccf6edebf47298ad489,313233343030,304502207ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffd022100ca0234ebb5fdcb13ca0234ecffffffffcb0dadbbc7f549f8a26b4408d0dc8600,1\n",
+        "04d0fb17ccd8fafe827e0c1afc5d8d80366e2b20e7f14a563a2ba50469d84375e868612569d39e2bb9f554355564646de99ac602cc6349cf8c1e236a7de7637d93,313233343030,304502207ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffd022100bfffffff3ea3677e082b9310572620ae19933a9e65b285598711c77298815ad3,1\n",
+        "04836f33bbc1dc0d3d3abbcef0d91f11e2ac4181076c9af0a22b1e4309d3edb2769ab443ff6f901e30c773867582997c2bec2b0cb8120d760236f3a95bbe881f75,313233343030,304402207ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffd0220266666663bbbbbbbe6666666666666665b37902e023fab7c8f055d86e5cc41f4,1\n",
+        "0492f99fbe973ed4a299719baee4b432741237034dec8d72ba5103cb33e55feeb8033dd0e91134c734174889f3ebcf1b7a1ac05767289280ee7a794cebd6e69697,313233343030,304502207ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffd022100bfffffff36db6db7a492492492492492146c573f4c6dfc8d08a443e258970b09,1\n",
+        "04d35ba58da30197d378e618ec0fa7e2e2d12cffd73ebbb2049d130bba434af09eff83986e6875e41ea432b7585a49b3a6c77cbb3c47919f8e82874c794635c1d2,313233343030,304502207ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffd022100bfffffff2aaaaaab7fffffffffffffffc815d0e60b3e596ecb1ad3a27cfd49c4,1\n",
+        "048651ce490f1b46d73f3ff475149be29136697334a519d7ddab0725c8d0793224e11c65bd8ca92dc8bc9ae82911f0b52751ce21dd9003ae60900bd825f590cc28,313233343030,304402207ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffd02207fffffff55555555ffffffffffffffffd344a71e6f651458a27bdc81fd976e37,1\n",
+        "046d8e1b12c831a0da8795650ff95f101ed921d9e2f72b15b1cdaca9826b9cfc6def6d63e2bc5c089570394a4bc9f892d5e6c7a6a637b20469a58c106ad486bf37,313233343030,304402207ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffd02203fffffff800000007fffffffffffffffde737d56d38bcf4279dce5617e3192aa,1\n",
+        "040ae580bae933b4ef2997cbdbb0922328ca9a410f627a0f7dff24cb4d920e15428911e7f8cc365a8a88eb81421a361ccc2b99e309d8dcd9a98ba83c3949d893e3,313233343030,304402207ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffd02205d8ecd64a4eeba466815ddf3a4de9a8e6abd9c5db0a01eb80343553da648428f,1\n",
+        "045b812fd521aafa69835a849cce6fbdeb6983b442d2444fe70e134c027fc46963838a40f2a36092e9004e92d8d940cf5638550ce672ce8b8d4e15eba5499249e9,313233343030,304502206f2347cab7dd76858fe0555ac3bc99048c4aacafdfb6bcbe05ea6c42c4934569022100bb726660235793aa9957a61e76e00c2c435109cf9a15dd624d53f4301047856b,1\n",
+        "045b812fd521aafa69835a849cce6fbdeb6983b442d2444fe70e134c027fc469637c75bf0c5c9f6d17ffb16d2726bf30a9c7aaf31a8d317472b1ea145ab66db616,313233343030,304502206f2347cab7dd76858fe0555ac3bc99048c4aacafdfb6bcbe05ea6c42c4934569022100bb726660235793aa9957a61e76e00c2c435109cf9a15dd624d53f4301047856b,0\n",
+        "046adda82b90261b0f319faa0d878665a6b6da497f09c903176222c34acfef72a647e6f50dcc40ad5d9b59f7602bb222fad71a41bf5e1f9df4959a364c62e488d9,313233343030,30250201010220555555550000000055555555555555553ef7a8e48d07df81a693439654210c70,0\n",
+        "042fca0d0a47914de77ed56e7eccc3276a601120c6df0069c825c8f6a01c9f382065f3450a1d17c6b24989a39beb1c7decfca8384fbdc294418e5d807b3c6ed7de,313233343030,3045022101000000000000000000000000000000000000000000000000000000000000000002203333333300000000333333333333333325c7cbbc549e52e763f1f55a327a3aa9,0\n",
+        "04dd86d3b5f4a13e8511083b78002081c53ff467f11ebd98a51a633db76665d25045d5c8200c89f2fa10d849349226d21d8dfaed6ff8d5cb3e1b7e17474ebc18f7,313233343030,30440220555555550000000055555555555555553ef7a8e48d07df81a693439654210c7002203333333300000000333333333333333325c7cbbc549e52e763f1f55a327a3aa9,0\n",
+        "044fea55b32cb32aca0c12c4cd0abfb4e64b0f5a516e578c016591a93f5a0fbcc5d7d3fd10b2be668c547b212f6bb14c88f0fecd38a8a4b2c785ed3be62ce4b280,313233343030,304402207cf27b188d034f7e8a52380304b51ac3c08969e277f21b35a60b48fc476699780220555555550000000055555555555555553ef7a8e48d07df81a693439654210c70,1\n",
+        "04c6a771527024227792170a6f8eee735bf32b7f98af669ead299802e32d7c3107bc3b4b5e65ab887bbd343572b3e5619261fe3a073e2ffd78412f726867db589e,313233343030,304502207cf27b188d034f7e8a52380304b51ac3c08969e277f21b35a60b48fc47669978022100b6db6db6249249254924924924924924625bd7a09bec4ca81bcdd9f8fd6b63cc,1\n",
+        "04851c2bbad08e54ec7a9af99f49f03644d6ec6d59b207fec98de85a7d15b956efcee9960283045075684b410be8d0f7494b91aa2379f60727319f10ddeb0fe9d6,313233343030,304502207cf27b188d034f7e8a52380304b51ac3c08969e277f21b35a60b48fc47669978022100cccccccc00000000cccccccccccccccc971f2ef152794b9d8fc7d568c9e8eaa7,1\n",
+        "04f6417c8a670584e388676949e53da7fc55911ff68318d1bf3061205acb19c48f8f2b743df34ad0f72674acb7505929784779cd9ac916c3669ead43026ab6d43f,313233343030,304402207cf27b188d034f7e8a52380304b51ac3c08969e277f21b35a60b48fc4766997802203333333300000000333333333333333325c7cbbc549e52e763f1f55a327a3aaa,1\n",
+        "04501421277be45a5eefec6c639930d636032565af420cf3373f557faa7f8a06438673d6cb6076e1cfcdc7dfe7384c8e5cac08d74501f2ae6e89cad195d0aa1371,313233343030,304402207cf27b188d034f7e8a52380304b51ac3c08969e277f21b35a60b48fc47669978022049249248db6db6dbb6db6db6db6db6db5a8b230d0b2b51dcd7ebf0c9fef7c185,1\n",
+        "040d935bf9ffc115a527735f729ca8a4ca23ee01a4894adf0e3415ac84e808bb343195a3762fea29ed38912bd9ea6c4fde70c3050893a4375850ce61d82eba33c5,313233343030,304402207cf27b188d034f7e8a52380304b51ac3c08969e277f21b35a60b48fc47669978022016a4502e2781e11ac82cbc9d1edd8c981584d13e18411e2f6e0478c34416e3bb,1\n",
+        "045e59f50708646be8a589355014308e60b668fb670196206c41e748e64e4dca215de37fee5c97bcaf7144d5b459982f52eeeafbdf03aacbafef38e213624a01de,313233343030,304402206b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c2960220555555550000000055555555555555553ef7a8e48d07df81a693439654210c70,1\n",
+        "04169fb797325843faff2f7a5b5445da9e2fd6226f7ef90ef0bfe924104b02db8e7bbb8de662c7b9b1cf9b22f7a2e582bd46d581d68878efb2b861b131d8a1d667,313233343030,304502206b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296022100b6db6db6249249254924924924924924625bd7a09bec4ca81bcdd9f8fd6b63cc,1\n",
+        "04271cd89c000143096b62d4e9e4ca885aef2f7023d18affdaf8b7b548981487540a1c6e954e32108435b55fa385b0f76481a609b9149ccb4b02b2ca47fe8e4da5,313233343030,304502206b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296022100cccccccc00000000cccccccccccccccc971f2ef152794b9d8fc7d568c9e8eaa7,1\n",
+        "043d0bc7ed8f09d2cb7ddb46ebc1ed799ab1563a9ab84bf524587a220afe499c12e22dc3b3c103824a4f378d96adb0a408abf19ce7d68aa6244f78cb216fa3f8df,313233343030,304402206b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c29602203333333300000000333333333333333325c7cbbc549e52e763f1f55a327a3aaa,1\n",
+        "04a6c885ade1a4c566f9bb010d066974abb281797fa701288c721bcbd23663a9b72e424b690957168d193a6096fc77a2b004a9c7d467e007e1f2058458f98af316,313233343030,304402206b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296022049249248db6db6dbb6db6db6db6db6db5a8b230d0b2b51dcd7ebf0c9fef7c185,1\n",
+        "048d3c2c2c3b765ba8289e6ac3812572a25bf75df62d87ab7330c3bdbad9ebfa5c4c6845442d66935b238578d43aec54f7caa1621d1af241d4632e0b780c423f5d,313233343030,304402206b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296022016a4502e2781e11ac82cbc9d1edd8c981584d13e18411e2f6e0478c34416e3bb,1\n",
+        "046b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c2964fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5,313233343030,3045022100bb5a52f42f9c9261ed4361f59422a1e30036e7c32b270c8807a419feca6050230220249249246db6db6ddb6db6db6db6db6dad4591868595a8ee6bf5f864ff7be0c2,0\n",
+        ",313233343030,3044022044a5ad0ad0636d9f12bc9e0a6bdd5e1cbcb012ea7bf091fcec15b0c43202d52e0220249249246db6db6ddb6db6db6db6db6dad4591868595a8ee6bf5f864ff7be0c2,0\n",
+        "046b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296b01cbd1c01e58065711814b583f061e9d431cca994cea1313449bf97c840ae0a,313233343030,3045022100bb5a52f42f9c9261ed4361f59422a1e30036e7c32b270c8807a419feca6050230220249249246db6db6ddb6db6db6db6db6dad4591868595a8ee6bf5f864ff7be0c2,0\n",
+        ",313233343030,3044022044a5ad0ad0636d9f12bc9e0a6bdd5e1cbcb012ea7bf091fcec15b0c43202d52e0220249249246db6db6ddb6db6db6db6db6dad4591868595a8ee6bf5f864ff7be0c2,0\n",
+        "0404aaec73635726f213fb8a9e64da3b8632e41495a944d0045b522eba7240fad587d9315798aaa3a5ba01775787ced05eaaf7b4e09fc81d6d1aa546e8365d525d,,3045022100b292a619339f6e567a305c951c0dcbcc42d16e47f219f9e98e76e09d8770b34a02200177e60492c5a8242f76f07bfe3661bde59ec2a17ce5bd2dab2abebdf89a62e2,1\n",
+        ",4d7367,30450220530bd6b0c9af2d69ba897f6b5fb59695cfbf33afe66dbadcf5b8d2a2a6538e23022100d85e489cb7a161fd55ededcedbf4cc0c0987e3e3f0f242cae934c72caa3f43e9,1\n",
+        ",313233343030,3046022100a8ea150cb80125d7381c4c1f1da8e9de2711f9917060406a73d7904519e51388022100f3ab9fa68bd47973a73b2d40480c2ba50c22c9d76ec217257288293285449b86,1\n",
+        ",0000000000000000000000000000000000000000,3045022100986e65933ef2ed4ee5aada139f52b70539aaf63f00a91f29c69178490d57fb7102203dafedfb8da6189d372308cbf1489bbbdabf0c0217d1c0ff0f701aaa7a694b9c,1\n",
+        "044f337ccfd67726a805e4f1600ae2849df3807eca117380239fbd816900000000ed9dea124cc8c396416411e988c30f427eb504af43a3146cd5df7ea60666d685,4d657373616765,3046022100d434e262a49eab7781e353a3565e482550dd0fd5defa013c7f29745eff3569f10221009b0c0a93f267fb6052fd8077be769c2b98953195d7bc10de844218305c6ba17a,1\n",
+        ",4d657373616765,304402200fe774355c04d060f76d79fd7a772e421463489221bf0a33add0be9b1979110b0220500dcba1c69a8fbd43fa4f57f743ce124ca8b91a1f325f3fac6181175df55737,1\n",
+        ",4d657373616765,3045022100bb40bf217bed3fb3950c7d39f03d36dc8e3b2cd79693f125bfd06595ee1135e30220541bf3532351ebb032710bdb6a1bf1bfc89a1e291ac692b3fa4780745bb55677,1\n",
+        "043cf03d614d8939cfd499a07873fac281618f06b8ff87e8015c3f49726500493584fa174d791c72bf2ce3880a8960dd2a7c7a1338a82f85a9e59cdbde80000000,4d657373616765,30440220664eb7ee6db84a34df3c86ea31389a5405badd5ca99231ff556d3e75a233e73a022059f3c752e52eca46137642490a51560ce0badc678754b8f72e51a2901426a1bd,1\n",
+        ",4d657373616765,304502204cd0429bbabd2827009d6fcd843d4ce39c3e42e2d1631fd001985a79d1fd8b430221009638bf12dd682f60be7ef1d0e0d98f08b7bca77a1a2b869ae466189d2acdabe3,1\n",
+        ",4d657373616765,3046022100e56c6ea2d1b017091c44d8b6cb62b9f460e3ce9aed5e5fd41e8added97c56c04022100a308ec31f281e955be20b457e463440b4fcf2b80258078207fc1378180f89b55,1\n",
+        "043cf03d614d8939cfd499a07873fac281618f06b8ff87e8015c3f4972650049357b05e8b186e38d41d31c77f5769f22d58385ecc857d07a561a6324217fffffff,4d657373616765,304402201158a08d291500b4cabed3346d891eee57c176356a2624fb011f8fbbf34668300220228a8c486a736006e082325b85290c5bc91f378b75d487dda46798c18f285519,1\n",
+        ",4d657373616765,3045022100b1db9289649f59410ea36b0c0fc8d6aa2687b29176939dd23e0dde56d309fa9d02203e1535e4280559015b0dbd987366dcf43a6d1af5c23c7d584e1c3f48a1251336,1\n",
+        ",4d657373616765,3046022100b7b16e762286cb96446aa8d4e6e7578b0a341a79f2dd1a220ac6f0ca4e24ed86022100ddc60a700a139b04661c547d07bbb0721780146df799ccf55e55234ecb8f12bc,1\n",
+        "042829c31faa2e400e344ed94bca3fcd0545956ebcfe8ad0f6dfa5ff8effffffffa01aafaf000e52585855afa7676ade284113099052df57e7eb3bd37ebeb9222e,4d657373616765,3045022100d82a7c2717261187c8e00d8df963ff35d796edad36bc6e6bd1c91c670d9105b402203dcabddaf8fcaa61f4603e7cbac0f3c0351ecd5988efb23f680d07debd139929,1\n",
+        ",4d657373616765,304402205eb9c8845de68eb13d5befe719f462d77787802baff30ce96a5cba063254af7802202c026ae9be2e2a5e7ca0ff9bbd92fb6e44972186228ee9a62b87ddbe2ef66fb5,1\n",
+        ",4d657373616765,304602210096843dd03c22abd2f3b782b170239f90f277921becc117d0404a8e4e36230c28022100f2be378f526f74a543f67165976de9ed9a31214eb4d7e6db19e1ede123dd991d,1\n",
+        "04fffffff948081e6a0458dd8f9e738f2665ff9059ad6aac0708318c4ca9a7a4f55a8abcba2dda8474311ee54149b973cae0c0fb89557ad0bf78e6529a1663bd73,4d657373616765,30440220766456dce1857c906f9996af729339464d27e9d98edc2d0e3b760297067421f60220402385ecadae0d8081dccaf5d19037ec4e55376eced699e93646bfbbf19d0b41,1\n",
+        ",4d657373616765,3046022100c605c4b2edeab20419e6518a11b2dbc2b97ed8b07cced0b19c34f777de7b9fd9022100edf0f612c5f46e03c719647bc8af1b29b2cde2eda700fb1cff5e159d47326dba,1\n",
+        ",4d657373616765,3046022100d48b68e6cabfe03cf6141c9ac54141f210e64485d9929ad7b732bfe3b7eb8a84022100feedae50c61bd00e19dc26f9b7e2265e4508c389109ad2f208f0772315b6c941,1\n",
+        "0400000003fa15f963949d5f03a6f5c7f86f9e0015eeb23aebbff1173937ba748e1099872070e8e87c555fa13659cca5d7fadcfcb0023ea889548ca48af2ba7e71,4d657373616765,3046022100b7c81457d4aeb6aa65957098569f0479710ad7f6595d5874c35a93d12a5dd4c7022100b7961a0b652878c2d568069a432ca18a1a9199f2ca574dad4b9e3a05c0a1cdb3,1\n",
+        ",4d657373616765,304402206b01332ddb6edfa9a30a1321d5858e1ee3cf97e263e669f8de5e9652e76ff3f702205939545fced457309a6a04ace2bd0f70139c8f7d86b02cb1cc58f9e69e96cd5a,1\n",
+        ",4d657373616765,3046022100efdb884720eaeadc349f9fc356b6c0344101cd2fd8436b7d0e6a4fb93f106361022100f24bee6ad5dc05f7613975473aadf3aacba9e77de7d69b6ce48cb60d8113385d,1\n",
+        "04bcbb2914c79f045eaa6ecbbc612816b3be5d2d6796707d8125e9f851c18af015000000001352bb4a0fa2ea4cceb9ab63dd684ade5a1127bcf300a698a7193bc2,4d657373616765,3044022031230428405560dcb88fb5a646836aea9b23a23dd973dcbe8014c87b8b20eb0702200f9344d6e812ce166646747694a41b0aaf97374e19f3c5fb8bd7ae3d9bd0beff,1\n",
+        ",4d657373616765,3046022100caa797da65b320ab0d5c470cda0b36b294359c7db9841d679174db34c4855743022100cf543a62f23e212745391aaf7505f345123d2685ee3b941d3de6d9b36242e5a0,1\n",
+        ",4d657373616765,304502207e5f0ab5d900d3d3d7867657e5d6d36519bc54084536e7d21c336ed8001859450221009450c07f201faec94b82dfb322e5ac676688294aad35aa72e727ff0b19b646aa,1\n",
+        "04bcbb2914c79f045eaa6ecbbc612816b3be5d2d6796707d8125e9f851c18af015fffffffeecad44b6f05d15b33146549c2297b522a5eed8430cff596758e6c43d,4d657373616765,3046022100d7d70c581ae9e3f66dc6a480bf037ae23f8a1e4a2136fe4b03aa69f0ca25b35602210089c460f8a5a5c2bbba962c8a3ee833a413e85658e62a59e2af41d9127cc47224,1\n",
+        ",4d657373616765,30440220341c1b9ff3c83dd5e0dfa0bf68bcdf4bb7aa20c625975e5eeee34bb396266b34022072b69f061b750fd5121b22b11366fad549c634e77765a017902a67099e0a4469,1\n",
+        ",4d657373616765,3045022070bebe684cdcb5ca72a42f0d873879359bd1781a591809947628d313a3814f67022100aec03aca8f5587a4d535fa31027bbe9cc0e464b1c3577f4c2dcde6b2094798a9,1\n",
+    );
+
+    #[test]
+    fn ECDH() {
+        for line in ECDH_VECTORS.lines() {
+            let mut f = line.split(',');
+            let tc = f.next().unwrap();
+            let sk = PrivateKey::decode(&hex::decode(f.next().unwrap())
+                .unwrap()).unwrap();
+            let peer = hex::decode(f.next().unwrap()).unwrap();
+            let shared = hex::decode(f.next().unwrap()).unwrap();
+            let valid = f.next().unwrap() == "1";
+            let (key1, ok1) = sk.ECDH(&peer);
+            let (key2, ok2) = sk.ECDH_hashed(&peer);
+            if valid {
+                assert!(ok1 == 0xFFFFFFFF && ok2 == 0xFFFFFFFF, "tcId {}", tc);
+                assert!(key1[..] == shared[..], "tcId {}", tc);
+                assert!(PublicKey::decode(&peer).is_some());
+
+                // The vectors only provide the x-coordinate of the shared
+                // point; the hashed secret must match one of the two
+                // possible compressed encodings.
+                let mut cs = [0u8; 33];
+                cs[1..].copy_from_slice(&shared);
+                cs[0] = 0x02;
+                let h2 = Sha256::digest(cs);
+                cs[0] = 0x03;
+                let h3 = Sha256::digest(cs);
+                assert!(key2[..] == h2[..] || key2[..] == h3[..]);
+            } else {
+                // Invalid peer keys are rejected, but still yield a
+                // deterministic output.
+                assert!(ok1 == 0 && ok2 == 0, "tcId {}", tc);
+                assert!(PublicKey::decode(&peer).is_none());
+                assert!(sk.ECDH(&peer).0 == key1);
+                assert!(sk.ECDH_hashed(&peer).0 == key2);
+                assert!(key1 != [0u8; 32] && key1 != key2);
+            }
+        }
+
+        // Hashed secret for tcId 1, checked against OpenSSL.
+        let mut f = ECDH_VECTORS.split(',');
+        f.next();
+        let sk = PrivateKey::decode(&hex::decode(f.next().unwrap())
+            .unwrap()).unwrap();
+        let peer = hex::decode(f.next().unwrap()).unwrap();
+        assert!(sk.ECDH_hashed(&peer).0[..] == hex::decode(
+            "da4236740077a16d38728cae320eb5af93bff0a0bd7775782384ba802140de82").unwrap()[..]);
+
+        // Both sides get the same key.
+        let mut sh = Sha256::new();
+        for i in 0..10u8 {
+            sh.update([i]);
+            let sk1 = PrivateKey::from_seed(&sh.finalize_reset());
+            sh.update([i, 1]);
+            let sk2 = PrivateKey::from_seed(&sh.finalize_reset());
+            let pk1 = sk1.to_public_key();
+            let pk2 = sk2.to_public_key();
+            let (k1, ok1) = sk1.ECDH(&pk2.encode_compressed());
+            let (k2, ok2) = sk2.ECDH(&pk1.encode_uncompressed());
+            assert!(ok1 == 0xFFFFFFFF && ok2 == 0xFFFFFFFF);
+            assert!(k1 == k2);
+            let (k3, _) = sk1.ECDH_hashed(&pk2.encode_uncompressed());
+            let (k4, _) = sk2.ECDH_hashed(&pk1.encode_compressed());
+            assert!(k3 == k4 && k3 != k1);
+        }
+    }
+
+    // ECDH test vectors from Wycheproof (file
+    // ecdh_secp256r1_ecpoint_test.json, all test cases). Each line contains
+    // the tcId, the private key, the peer public key, the raw shared secret
+    // (x-coordinate), and the expected result (1 = valid or acceptable,
+    // 0 = invalid). The private keys are normalized to 32 bytes. Invalid
+    // cases include the invalid curve attacks and the points on the twist
+    // (tcId 350 to 355).
+    static ECDH_VECTORS: &str = concat!(
+        "1,0612465c89a023ab17855b0a6bcebfd3febb53aef84138647b5352e02c10c346,0462d5bd3372af75fe85a040715d0f502428e07046868b0bfdfa61d731afe44f26ac333a93a9e70a81cd5a95b5bf8d13990eb741c8c38872b4a07d275a014e30cf,53020d908b0219328b658b525f26780e3ae12bcd952bb25a93bc0895e1714285,1\n",
+        "2,0612465c89a023ab17855b0a6bcebfd3febb53aef84138647b5352e02c10c346,0362d5bd3372af75fe85a040715d0f502428e07046868b0bfdfa61d731afe44f26,53020d908b0219328b658b525f26780e3ae12bcd952bb25a93bc0895e1714285,1\n",
+        "3,0a0d622a47e48f6bc1038ace438c6f528aa00ad2bd1da5f13ee46bf5f633d71a,0458fd4168a87795603e2b04390285bdca6e57de6027fe211dd9d25e2212d29e62080d36bd224d7405509295eed02a17150e03b314f96da37445b0d1d29377d12c,0000000000000000000000000000000000000000000000000000000000000000,1\n",
+        "4,0a0d622a47e48f6bc1038ace438c6f528aa00ad2bd1da5f13ee46bf5f633d71a,04a1ecc24bf0d0053d23f5fd80ddf1735a1925039dc1176c581a7e795163c8b9ba2cb5a4e4d5109f4527575e3137b83d79a9bcb3faeff90d2aca2bed71bb523e7e,ffffffff00000001000000000000000000000000fffffffffffffffffffffffc,1\n",
+        "5,0a0d622a47e48f6bc1038ace438c6f528aa00ad2bd1da5f13ee46bf5f633d71a,041b0e7437c33d379929430d3ec10df59bed7fe2a1d950c5791e1e9ddeef1f4d70fbdb0e3bbce63a27f27838c685207f2ccaf689d25eb622744db1168ac92619e8,0000000000000000000000000000000000000000000000000000000000010000,1\n",
+        "6,0a0d622a47e48f6bc1038ace438c6f528aa00ad2bd1da5f13ee46bf5f633d71a,0408d6148d0244d243b3d0d1777de6375fa7ebeab477f19915d05994db04df219727737d4f8ce0a7f390becce92b2bcd5c054f18ecb58e5dd59baf88b4ed6abea8,0000000000000000000000000000000000000000000000000000000100000000,1\n",
+        "7,0a0d622a47e48f6bc1038ace438c6f528aa00ad2bd1da5f13ee46bf5f633d71a,042424f9d7ba0a89ce3c7c1e8f15dfd83004d86680967a82cbf9bb6b11dae5fd72e05c3687187b1cf28438a17a7469fa3b094b7d6f36cccc3492c0b0a61fab2a38,0000000000000000000000000000000000000000000000010000000000000000,1\n",
+        "8,0a0d622a47e48f6bc1038ace438c6f528aa00ad2bd1da5f13ee46bf5f633d71a,044c9695b7668434fc85769acb10f0edcf87a96b7d5dc347b46bb304b0b1d3267fcf5d993bff2a8c774808231a34f41b33667d8ebeafd00689fa3a9bfa05a40c1c,0000000000000000000000000000000000000001000000000000000000000000,1\n",
+        "9,0a0d622a47e48f6bc1038ace438c6f528aa00ad2bd1da5f13ee46bf5f633d71a,04a492fe4b4908b6d675d687551f99c617e4e5c97aa266958953129eb381f0153b111b95c94fa1d1ecd1d41d2785c1db5195875ae98051732a59ba7720f9089af6,6522aed9ea48f2623b8eeae3e213b99da32e74c9421835804d374ce28fcca662,1\n",
+        "10,0a0d622a47e48f6bc1038ace438c6f528aa00ad2bd1da5f13ee46bf5f633d71a,04d5c96efd1907fd48de2ad715acf82eae5c6690fe3efe16a78d61c68d3bfd10df03eac816b9e7b776192a3f5075887c0e225617505833ca997cda32fd0f673c5e,507442007322aa895340cba4abc2d730bfd0b16c2c79a46815f8780d2c55a2dd,1\n",
+        "11,0a0d622a47e48f6bc1038ace438c6f528aa00ad2bd1da5f13ee46bf5f633d71a,04c74d546f2fcc6dd392f85e5be167e358de908756b0c0bb01cb69d864ca083e1c93f959eece6e10ee11bd3934207d65ae28af68b092585a1509260eceb39b92ef,085ec5a4af40176b63189069aeffcb229c96d3e046e0283ed2f9dac21b15ad3c,1\n",
+        "12,0a0d622a47e48f6bc1038ace438c6f528aa00ad2bd1da5f13ee46bf5f633d71a,0434fc9f1e7a094cd29598d1841fa9613dbe82313d633a51d63fb6eff074cc9b9a4ecfd9f258c5c4d4210b49751213a24c596982bd1d54e0445443f21ef15492a5,190c25f88ad9ae3a098e6cffe6fd0b1bea42114eb0cedd5868a45c5fe277dff3,1\n",
+        "13,0a0d622a47e48f6bc1038ace438c6f528aa00ad2bd1da5f13ee46bf5f633d71a,04f475f503a770df72c45aedfe42c008f59aa57e72b232f26600bdd0353957cb20bdb8f6405b4918050a3549f44c07a8eba820cdce4ece699888c638df66f54f7c,5f177bfe19baaaee597e68b6a87a519e805e9d28a70cb72fd40f0fe5a754ba45,1\n",
+        "14,0a0d622a47e48f6bc1038ace438c6f528aa00ad2bd1da5f13ee46bf5f633d71a,04949304889050834006ecf11eba0e73f0dd2a5e77a5ea642a34a3f9989eda2d57384509cd42763c87cf460d1ad75664402986bb8cca0acdfc3685d2c9c7d32933,3476a8fa9c7448124aa945a2e1103eb91f95c7b4d91aaa1c35b8965191fc0fe0,1\n",
+        "15,0a0d622a47e48f6bc1038ace438c6f528aa00ad2bd1da5f13ee46bf5f633d71a,04e25ead8342888d1fbd2bd211e9db5bc761da9cfa1e805078b53606df9e5444403d3421fa70f940dba104d842034d2efec9332161ccfb654a8e9f2ec42fc1a837,5555555555555555555555555555555555555555555555555555555555555554,1\n",
+        "16,0a0d622a47e48f6bc1038ace438c6f528aa00ad2bd1da5f13ee46bf5f633d71a,04cb32ab951d7581e7602c89320d91d7043e9dc56a10348d586a08d1870753152bf96bd53e7cfc2434dc61a09f8f4853e4c6dd49e786194ec0a988d0e8d29378b8,aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa9,1\n",
+        "17,0a0d622a47e48f6bc1038ace438c6f528aa00ad2bd1da5f13ee46bf5f633d71a,040b9d56422ed9c2082dd9d46d231fd0141f92e79f5a12da4c77c488c87363f944e6e3c2ec9779615f242bc30d1c28c1f984b8c252243f5f1eaa3d855e1cc2d8a6,3333333333333333333333333333333333333333333333333333333333333333,1\n",
+        "18,0a0d622a47e48f6bc1038ace438c6f528aa00ad2bd1da5f13ee46bf5f633d71a,040507fabb16755f4885ae45b3d497822388f4e6a531de512c200480b4e56b892ab70746c1d8a20637eff17948e557a2b4da2562ba6b62991b426b7b3787a19693,cccccccccccccccccccccccccccccccccccccccccccccccccccccccccccccccc,1\n",
+        "19,0a0d622a47e48f6bc1038ace438c6f528aa00ad2bd1da5f13ee46bf5f633d71a,043b18caef2cd982c3b58f258cb76daa029383f42c8f3f083493f42c915935243a260830b7d25b66378c5d25fb0cf23d32527a2cdb7f7d7e6c89ebea8277738ed8,0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f,1\n",
+        "20,0a0d622a47e48f6bc1038ace438c6f528aa00ad2bd1da5f13ee46bf5f633d71a,04123b1921231bee315e1ea66cb95318d6392f85695bbec03c334740ce7add768efec9a108770ff0eb448eee9f06367416d74b22a2f38157bb00d8c0be44ebf152,f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0,1\n",
+        "21,0a0d622a47e48f6bc1038ace438c6f528aa00ad2bd1da5f13ee46bf5f633d71a,04924fb33985c8a687fc04c9dd05e531ca0e0223aa58d58351e922ef482043d30cf504745e769b6dcbefe404da37f717b3109d2af23450fcfe2f075c2dabbe7194,00ff00ff00ff00ff00ff00ff00ff00ff00ff00ff00ff00ff00ff00ff00ff00f9,1\n",
+        "22,0a0d622a47e48f6bc1038ace438c6f528aa00ad2bd1da5f13ee46bf5f633d71a,0422495c6884937a1bc05871e9ee1a2c43f8f14db19ee60bd2d89c4293042f5a9452cea27e6fe574a00e5f15fe11aa54f54ebd8bde3aa41c80bf9d5f7b1dc6efec,ff00ff00ff00ff00ff00ff00ff00ff00ff00ff00ff00ff00ff00ff00ff00feff,1\n",
+        "23,0a0d622a47e48f6bc1038ace438c6f528aa00ad2bd1da5f13ee46bf5f633d71a,04f3cb6754b7e2a86d064dfb9f903185aaa4c92b481c2c1a1ff276303bbc4183e49c318599b0984c3563df339311fe143a7d921ee75b755a52c6f804f897b809f7,7fff0001fffc0007fff0001fffc0007fff0001fffc0007fff0001fffc0007fff,1\n",
+        "24,0a0d622a47e48f6bc1038ace438c6f528aa00ad2bd1da5f13ee46bf5f633d71a,04a8d93bebbaf25fa9a1f98c44b54dbf28634a5fd08402dba5d9e4873f8123da1db972d91a0eabadb630b271e6551b757d969301beddd11f82decdfbe4f9657f50,8000fffe0003fff8000fffe0003fff8000fffe0003fff8000fffe0003fff7ffe,1\n",
+        "25,0a0d622a47e48f6bc1038ace438c6f528aa00ad2bd1da5f13ee46bf5f633d71a,048d0086e678a0fe7b4ad7bd4f94a38267b9f9f1d2b82452db4cfb335595c26c55f78476a00a0153941da0b0b81c834682edc9fcc8bc21a7b5bacb460dbb566c19,0000ffff0000ffff0000ffff0000ffff0000ffff0000ffff0000ffff0000fffe,1\n",
+        "26,0a0d622a47e48f6bc1038ace438c6f528aa00ad2bd1da5f13ee46bf5f633d71a,0402a96834fe3b54d440003521f2355aea48d0bbc576473fecc6975100c589865263255764891ffc108cfe754af52a4362953955ddfadcf752637c11edeaa6a807,ffff0000ffff0000ffff0000ffff0000ffff0000ffff0000ffff0000fffefffd,1\n",
+        "27,0a0d622a47e48f6bc1038ace438c6f528aa00ad2bd1da5f13ee46bf5f633d71a,048721294f9eb3ba69a13ae7d933fa51c48393fe1b9b9b17a08cecff2ee6ad434d2c23cd11d26694c472dda8cea4133bbc68e1f234bc7e8bffcea9446091855491,8000003ffffff0000007fffffe000000ffffffc000001ffffff8000003fffffc,1\n",
+        "28,0a0d622a47e48f6bc1038ace438c6f528aa00ad2bd1da5f13ee46bf5f633d71a,043e99ec3c58ac3898936e4a61182b198823c9992209f67bf5291aa354fceb2f1312952518636add666320c8b7fa631541d0ca50796dbff0c1e72f94718776bd60,7fffffe000000ffffffc000001ffffff8000003ffffff0000007fffffdfffffe,1\n",
+        "29,0a0d622a47e48f6bc1038ace438c6f528aa00ad2bd1da5f13ee46bf5f633d71a,04e9484e58f3331b66ffed6d90cb1c78065fa28cfba5c7dd4352013d3252ee4277bd7503b045a38b4b247b32c59593580f39e6abfa376c3dca20cf7f9cfb659e13,000003ffffff0000003ffffff0000003ffffff0000003ffffff0000003ffffff,1\n",
+        "30,0a0d622a47e48f6bc1038ace438c6f528aa00ad2bd1da5f13ee46bf5f633d71a,04eb6eb532fe4af2a5a275ab4e9f728ae64e9dbc08e9359091d75e5334a6e08db64c146339c05d1d5782ebeeb86d3eb5c63e9f99f17187c039666c5237b736e9cd,fffffc000000ffffffc000000ffffffc000000ffffffc000000ffffffbffffff,1\n",
+        "31,0a0d622a47e48f6bc1038ace438c6f528aa00ad2bd1da5f13ee46bf5f633d71a,048e7b50f7d8c44d5d3496c43141a502f4a43f153d03ad43eda8e39597f1d477b8647f3da67969b7f989ff4addc393515af40c82085ce1f2ee195412c6f583774f,ffff00000003fffffff00000003fffffff00000003fffffff00000003fffffff,1\n",
+        "32,0a0d622a47e48f6bc1038ace438c6f528aa00ad2bd1da5f13ee46bf5f633d71a,0440d4f4f6d9a41ab6ea90d46610b924ab15ed4f259fd09ddd41a707782fa9e203a5080a7e118a50c4b3d2c43dcbbf35c39e276a0a50b33a9ffbe7c9095055fc25,0000fffffffc0000000fffffffc0000000fffffffc0000000fffffffbfffffff,1\n",
+        "33,0a0d622a47e48f6bc1038ace438c6f528aa00ad2bd1da5f13ee46bf5f633d71a,04a386ace573f87558a68ead2a20088e3fe928bdae9e109446f93a078c15741f0421261e6db2bf12106e4c6bf85b9581b4c0302a526222f90abc5a549206b11011,ff00000001fffffffc00000007fffffff00000001fffffffc00000007fffffff,1\n",
+        "34,0a0d622a47e48f6bc1038ace438c6f528aa00ad2bd1da5f13ee46bf5f633d71a,04739b5c2189729b577ba1a467a1a0851c556b860f6b2016bcd50beb22ee9c0d7961ea43cf1ea7aad1a2360b0f37a74ca532dc8d1af26cdf89a87d93dcaa0c9564,00fffffffe00000003fffffff80000000fffffffe00000003fffffff80000000,1\n",
+        "35,0a0d622a47e48f6bc1038ace438c6f528aa00ad2bd1da5f13ee46bf5f633d71a,046c03f055f710aea89341f0ef7a1c6d5c45a50923ce8adb33c2520f7881547748691f4dafcf6d5df8d9e6afda66ca81718bea8171abc87036a9c370385b60d969,00000000ffffffff00000000ffffffff00000000ffffffff00000000fffffffc,1\n",
+        "36,0a0d622a47e48f6bc1038ace438c6f528aa00ad2bd1da5f13ee46bf5f633d71a,048a3f60cfec64745bf625696f2fe4b6e4ce8208954f4fc4da21f87f9f7a2c3275b54d90851b39707586dc6cceccc5e50a1e4a955463107d1d87c8252df2110d6d,ffffffff00000000ffffffff00000000ffffffff00000000ffffffff00000000,1\n",
+        "37,0a0d622a47e48f6bc1038ace438c6f528aa00ad2bd1da5f13ee46bf5f633d71a,04c827fb930fd51d926086191b502af83abb5f717debc8de29897a3934b2571ca05990c0597b0b7a2e42febd56b13235d1d408d76ed2c93b3facf514d902f6910a,ffffffff00000000000000ffffffffffffff00000000000000ffffffffffffff,1\n",
+        "38,0a0d622a47e48f6bc1038ace438c6f528aa00ad2bd1da5f13ee46bf5f633d71a,04fc85bfee056478a1717582accd11ed49009891cbda5fe40b9f4c1742e127db2c97033f5f6405acfa57553f1e375a13e8d6e3a1151958c709be508e0bac7804d6,00000000ffffffffffffff00000000000000ffffffffffffff00000000000000,1\n",
+        "39,0a0d622a47e48f6bc1038ace438c6f528aa00ad2bd1da5f13ee46bf5f633d71a,0438bf171cdbd4e2e7128528db43d80655b789e69e6ba1d36b62e4e59d8ecf4c51bbe3244e4fbf79f24512f30c4a605314749e2f20d858ec961060a2cab467013f,0000000000000000ffffffffffffffff0000000000000000fffffffffffffffd,1\n",
+        "40,0a0d622a47e48f6bc1038ace438c6f528aa00ad2bd1da5f13ee46bf5f633d71a,04249f4f517d7788d4622d762c4312101fba71fe2657f1f2c33c0613d2e21890b778f32da1b61ed9385cd52f5e7103f77b01b403fb6537a0c0e866195d0a448f34,7fffffffffffffff0000000000000000ffffffffffffffff0000000000000000,1\n",
+        "41,0a0d622a47e48f6bc1038ace438c6f528aa00ad2bd1da5f13ee46bf5f633d71a,04e9c0af0bf6dadd8fbe04c5cf3fce80683a9c267b0dc9850cbc1bcdfeb744c3516efe878223dd9dd924e83e3ab22c9bf304129ab59c1f3d30fb58b8770bf9d9bf,5555555500000000555555555555555555555555aaaaaaaaaaaaaaaaaaaaaaab,1\n",
+        "42,0a0d622a47e48f6bc1038ace438c6f528aa00ad2bd1da5f13ee46bf5f633d71a,042b29f482bc43559f70447584919df1e03706ee63c987b6f7499ae29ab01ca828a4bd05d61f596e01623a8e2076c013889fbb0f9937e475a00ddd75e6a992e776,3333333300000000333333333333333333333333666666666666666666666666,1\n",
+        "43,0a0d622a47e48f6bc1038ace438c6f528aa00ad2bd1da5f13ee46bf5f633d71a,042766dc8e289fd27625d4765eb014aa54a44a7d5724050b9446e363071c260913aff9f1dc5e71b666501b24d70805cb8e1a01ee90ca84cb0c6f7ea453df85f520,249249246db6db6ddb6db6db6db6db6db6db6db7000000000000000000000000,1\n",
+        "44,0a0d622a47e48f6bc1038ace438c6f528aa00ad2bd1da5f13ee46bf5f633d71a,04d33314f13a7fe8357bfd7a11799498e55bc782c6f434856d733b668e1d05448348dc5aae3f6431dd20c018f54b1d65fd1864ddfeea04ad7533971237c60ac0c7,1c71c71c5555555571c71c71c71c71c71c71c71c8e38e38e38e38e38e38e38e4,1\n",
+        "45,0a0d622a47e48f6bc1038ace438c6f528aa00ad2bd1da5f13ee46bf5f633d71a,043cbc1b31b43f17dc200dd70c2944c04c6cb1b082820c234a300b05b7763844c74fde0a4ef93887469793270eb2ff148287da9265b0334f9e2609aac16e8ad503,7fffffffffffffffffffffffeecf2230ffffffffffffffffffffffffffffffff,1\n",
+        "46,0a0d622a47e48f6bc1038ace438c6f528aa00ad2bd1da5f13ee46bf5f633d71a,042830d96489ae24b79cad425056e82746f9e3f419ab9aa21ca1fbb11c7325e7d318abe66f575ee8a2f1c4a80e35260ae82ad7d6f661d15f06967930a585097ef7,000000000000000000000000111124f400000000000000000000000000000000,1\n",
+        "47,0a0d622a47e48f6bc1038ace438c6f528aa00ad2bd1da5f13ee46bf5f633d71a,04450b6b6e2097178e9d2850109518d28eb3b6ded2922a5452003bc2e4a4ec775c894e90f0df1b0e6cadb03b9de24f6a22d1bd0a4a58cd645c273cae1c619bfd61,000000000000000000000001ea77d449ffffffffffffffffffffffffffffffff,1\n",
+        "48,55d55f11bb8da1ea318bca7266f0376662441ea87270aa2077f1b770c4854a48,047fffffffffffffffffffffffeecf2230ffffffffffffffffffffffffffffffff00000001c7c30643abed0af0a49fe352cb483ff9b97dccdf427c658e8793240d,6fd26661851a8de3c6d06f834ef3acb8f2a5f9c136a985ffe10d5eeb51edcfa3,1\n",
+        "49,55d55f11bb8da1ea318bca7266f0376662441ea87270aa2077f1b770c4854a48,04000000000000000000000000111124f4000000000000000000000000000000000000000d12d381b0760b1c50be8acf859385052c7f53cde67ce13759de3123a0,f1f0e43b374feb7e7f96d4ffe7519fa8bb6c3cfd25f6f87dab2623d2a2d33851,1\n",
+        "50,55d55f11bb8da1ea318bca7266f0376662441ea87270aa2077f1b770c4854a48,04000000000000000000000001ea77d449ffffffffffffffffffffffffffffffff000000007afbc0b325e820646dec622fb558a51c342aa257f4b6a8ec5ddf144f,1b085213a9c89d353e1111af078c38c502b7b4771efba51f589b5be243417bdc,1\n",
+        "51,0a0d622a47e48f6bc1038ace438c6f528aa00ad2bd1da5f13ee46bf5f633d71a,043cbc1b31b43f17dc200dd70c2944c04c6cb1b082820c234a300b05b7763844c7b021f5b006c778ba686cd8f14d00eb7d78256d9b4fccb061d9f6553e91752afc,7fffffffffffffffffffffffeecf2230ffffffffffffffffffffffffffffffff,1\n",
+        "52,0a0d622a47e48f6bc1038ace438c6f528aa00ad2bd1da5f13ee46bf5f633d71a,042830d96489ae24b79cad425056e82746f9e3f419ab9aa21ca1fbb11c7325e7d3e754198fa8a1175e0e3b57f1cad9f517d528290a9e2ea0f96986cf5a7af68108,000000000000000000000000111124f400000000000000000000000000000000,1\n",
+        "53,0a0d622a47e48f6bc1038ace438c6f528aa00ad2bd1da5f13ee46bf5f633d71a,04450b6b6e2097178e9d2850109518d28eb3b6ded2922a5452003bc2e4a4ec775c76b16f0e20e4f194524fc4621db095dd2e42f5b6a7329ba3d8c351e39e64029e,000000000000000000000001ea77d449ffffffffffffffffffffffffffffffff,1\n",
+        "54,55d55f11bb8da1ea318bca7266f0376662441ea87270aa2077f1b770c4854a48,047fffffffffffffffffffffffeecf2230fffffffffffffffffffffffffffffffffffffffd383cf9bd5412f50f5b601cad34b7c00746823320bd839a71786cdbf2,6fd26661851a8de3c6d06f834ef3acb8f2a5f9c136a985ffe10d5eeb51edcfa3,1\n",
+        "55,55d55f11bb8da1ea318bca7266f0376662441ea87270aa2077f1b770c4854a48,04000000000000000000000000111124f400000000000000000000000000000000fffffff1ed2c7e5089f4e3af4175307a6c7afad480ac3219831ec8a621cedc5f,f1f0e43b374feb7e7f96d4ffe7519fa8bb6c3cfd25f6f87dab2623d2a2d33851,1\n",
+        "56,55d55f11bb8da1ea318bca7266f0376662441ea87270aa2077f1b770c4854a48,04000000000000000000000001ea77d449fffffffffffffffffffffffffffffffffffffffe85043f4dda17df9b92139dd04aa75ae4cbd55da80b495713a220ebb0,1b085213a9c89d353e1111af078c38c502b7b4771efba51f589b5be243417bdc,1\n",
+        "57,0a0d622a47e48f6bc1038ace438c6f528aa00ad2bd1da5f13ee46bf5f633d71a,049a0f0e3dd31417bbd9e298bc068ab6d5c36733af26ed67676f410c804b8b2ca1b02c82f3a61a376db795626e9400557112273a36cddb08caaa43953965454730,7fffffffffffffffffffffffca089011ffffffffffffffffffffffffffffffff,1\n",
+        "58,0a0d622a47e48f6bc1038ace438c6f528aa00ad2bd1da5f13ee46bf5f633d71a,048e5d22d5e53ec797c55ecd68a08a7c3361cd99ca7fad1a68ea802a6a4cb58a918ea7a07023ef67677024bd3841e187c64b30a30a3750eb2ee873fbe58fa1357b,0000000000000000000000001f6bd1e500000000000000000000000000000000,1\n",
+        "59,0a0d622a47e48f6bc1038ace438c6f528aa00ad2bd1da5f13ee46bf5f633d71a,04293aa349b934ab2c839cf54b8a737df2304ef9b20fa494e31ad62b315dd6a53c118182b85ef466eb9a8e87f9661f7d017984c15ea82043f536d1ee6a6d95b509,000000000000000000000002099f55d5ffffffffffffffffffffffffffffffff,1\n",
+        "60,55d55f11bb8da1ea318bca7266f0376662441ea87270aa2077f1b770c4854a48,047fffffffffffffffffffffffca089011ffffffffffffffffffffffffffffffff267bfdf8a61148decd80283732dd4c1095e4bb40b9658408208dc1147fffffff,44236c8b9505a19d48774a3903c0292759b0f826e6ac092ff898d87e53d353fc,1\n",
+        "61,55d55f11bb8da1ea318bca7266f0376662441ea87270aa2077f1b770c4854a48,040000000000000000000000001f6bd1e5000000000000000000000000000000004096edd6871c320cb8a9f4531751105c97b4c257811bbc32963eaf39ffffffff,3ebbace1098a81949d5605dd94a7aa88dc396c2c23e01a9c8cca5bb07bfbb6a1,1\n",
+        "62,55d55f11bb8da1ea318bca7266f0376662441ea87270aa2077f1b770c4854a48,04000000000000000000000002099f55d5ffffffffffffffffffffffffffffffff152c1a22d823a27855ed03f8e2ab5038bb1df4d87e43865f2daf6948ffffffff,67cb63566c7ceb12fdd85ce9d2f77c359242bbaa0ea1bf3cf510a4a26591d1f1,1\n",
+        "63,0a0d622a47e48f6bc1038ace438c6f528aa00ad2bd1da5f13ee46bf5f633d71a,049a0f0e3dd31417bbd9e298bc068ab6d5c36733af26ed67676f410c804b8b2ca14fd37d0b59e5c893486a9d916bffaa8eedd8c5ca3224f73555bc6ac69abab8cf,7fffffffffffffffffffffffca089011ffffffffffffffffffffffffffffffff,1\n",
+        "64,0a0d622a47e48f6bc1038ace438c6f528aa00ad2bd1da5f13ee46bf5f633d71a,048e5d22d5e53ec797c55ecd68a08a7c3361cd99ca7fad1a68ea802a6a4cb58a9171585f8edc1098998fdb42c7be1e7839b4cf5cf6c8af14d1178c041a705eca84,0000000000000000000000001f6bd1e500000000000000000000000000000000,1\n",
+        "65,0a0d622a47e48f6bc1038ace438c6f528aa00ad2bd1da5f13ee46bf5f633d71a,04293aa349b934ab2c839cf54b8a737df2304ef9b20fa494e31ad62b315dd6a53cee7e7d46a10b99156571780699e082fe867b3ea257dfbc0ac92e1195926a4af6,000000000000000000000002099f55d5ffffffffffffffffffffffffffffffff,1\n",
+        "66,55d55f11bb8da1ea318bca7266f0376662441ea87270aa2077f1b770c4854a48,047fffffffffffffffffffffffca089011ffffffffffffffffffffffffffffffffd984020659eeb722327fd7c8cd22b3ef6a1b44c0469a7bf7df723eeb80000000,44236c8b9505a19d48774a3903c0292759b0f826e6ac092ff898d87e53d353fc,1\n",
+        "67,55d55f11bb8da1ea318bca7266f0376662441ea87270aa2077f1b770c4854a48,040000000000000000000000001f6bd1e500000000000000000000000000000000bf69122878e3cdf447560bace8aeefa3684b3da97ee443cd69c150c600000000,3ebbace1098a81949d5605dd94a7aa88dc396c2c23e01a9c8cca5bb07bfbb6a1,1\n",
+        "68,55d55f11bb8da1ea318bca7266f0376662441ea87270aa2077f1b770c4854a48,04000000000000000000000002099f55d5ffffffffffffffffffffffffffffffffead3e5dc27dc5d88aa12fc071d54afc744e20b2881bc79a0d25096b700000000,67cb63566c7ceb12fdd85ce9d2f77c359242bbaa0ea1bf3cf510a4a26591d1f1,1\n",
+        "69,55d55f11bb8da1ea318bca7266f0376662441ea87270aa2077f1b770c4854a48,04000000000000000000000000000000000000000000000000000000000000000066485c780e2f83d72433bd5d84a06bb6541c2af31dae871728bf856a174f93f4,cfe4077c8730b1c9384581d36bff5542bc417c9eff5c2afcb98cc8829b2ce848,1\n",
+        "70,55d55f11bb8da1ea318bca7266f0376662441ea87270aa2077f1b770c4854a48,04ffffffff00000001000000000000000000000000fffffffffffffffffffffffc19719bebf6aea13f25c96dfd7c71f5225d4c8fc09eb5a0ab9f39e9178e55c121,4c25702d10cd12ec7e77c4f1979237976d6aa809c645d75a59d399a52377eb7b,1\n",
+        "71,55d55f11bb8da1ea318bca7266f0376662441ea87270aa2077f1b770c4854a48,04000000000000000000000000000000000000000000000000000000000001000073ca30641acabe581170b4d5253f7a342b039a87ab2447badad08ece2cf19f52,4cfd639fd80c84b935c33cfaacceeb256b2f7e273c3ae5f0ff92b74957376f22,1\n",
+        "72,55d55f11bb8da1ea318bca7266f0376662441ea87270aa2077f1b770c4854a48,040000000000000000000000000000000000000000000000000000000100000000328bbc85099acc7685cf4bb5e18d72c57271615ccd46377bfacb0e8501aa76fb,581bb2ea633af1e59b15c1342d5e14adb58f613a231149f7df7a69c0eca95b2c,1\n",
+        "73,55d55f11bb8da1ea318bca7266f0376662441ea87270aa2077f1b770c4854a48,04000000000000000000000000000000000000000000000001000000000000000010048b687675af9815297d73a7f25b1a3c42d33c64b466991a7573b4954aa0b0,9920473de58a319ebd87d57f4f6c09093c5e0072c01bc8df0dc60b8a7a684c6f,1\n",
+        "74,55d55f11bb8da1ea318bca7266f0376662441ea87270aa2077f1b770c4854a48,0400000000000000000000000000000000000000010000000000000000000000007d12de58d54423eb85ae8d157ae416fb004a7eb522ac1b67047ef3cdf9acdc3f,d9cd0d10ed4d3dc01f4216d229e21c6a447227a5bc2592c359b2d689a2dee3a8,1\n",
+        "75,55d55f11bb8da1ea318bca7266f0376662441ea87270aa2077f1b770c4854a48,046522aed9ea48f2623b8eeae3e213b99da32e74c9421835804d374ce28fcca6622c7fda3c84b704f27ffc0b2ab350d8cbc33a34df0c811155ed4389d2710e3fa9,af8bc612853197a6ee8b4af7300c0bc623f0cf4e4b301f47d66c27e8ecce9268,1\n",
+        "76,55d55f11bb8da1ea318bca7266f0376662441ea87270aa2077f1b770c4854a48,04507442007322aa895340cba4abc2d730bfd0b16c2c79a46815f8780d2c55a2dd4619d69f9940f51663aa12381bc7cf678bd1a72a49fbc11b0b69cb22d1af9f2d,4e173a80907f361fe5a5d335ba7685d5eba93e9dfc8d8fcdb1dcd2d2bde27507,1\n",
+        "77,55d55f11bb8da1ea318bca7266f0376662441ea87270aa2077f1b770c4854a48,04085ec5a4af40176b63189069aeffcb229c96d3e046e0283ed2f9dac21b15ad3c7859f97cb6e203f46bf3438f61282325e94e681b60b5669788aeb0655bf19d38,d874b55678d0a04d216c31b02f3ad1f30c92caaf168f34e3a743356d9276e993,1\n",
+        "78,55d55f11bb8da1ea318bca7266f0376662441ea87270aa2077f1b770c4854a48,04190c25f88ad9ae3a098e6cffe6fd0b1bea42114eb0cedd5868a45c5fe277dff321b8342ef077bc6724112403eaee5a15b4c31a71589f02ded09cd99cc5db9c83,11a8582057463fc76fda3ab8087eb0a420b0d601bb3134165a369646931e52a6,1\n",
+        "79,55d55f11bb8da1ea318bca7266f0376662441ea87270aa2077f1b770c4854a48,045f177bfe19baaaee597e68b6a87a519e805e9d28a70cb72fd40f0fe5a754ba4562ca1103f70a2006cd1f67f5f6a3580b29dc446abc90e0e910c1e05a9aa788cd,73220471ec8bad99a297db488a34a259f9bc891ffaf09922e6b5001f5df67018,1\n",
+        "80,55d55f11bb8da1ea318bca7266f0376662441ea87270aa2077f1b770c4854a48,043476a8fa9c7448124aa945a2e1103eb91f95c7b4d91aaa1c35b8965191fc0fe04ddac138688df5123fa5760d670247893f9ad5cad5e88895a0ae7fdf97b8f412,f4e5f1757c8a03a3aec57b598b66a93bb356a6917e26b60489c0d06236ac6a37,1\n",
+        "81,55d55f11bb8da1ea318bca7266f0376662441ea87270aa2077f1b770c4854a48,0455555555555555555555555555555555555555555555555555555555555555540a46712790e63f981f0103b2a609fa691c5291a17974eb0c3b518faac21535d6,be033e3db6216d3b3f9d66c96ca3b24475813ba8eb7820d7d6ff29c72fd39c64,1\n",
+        "82,55d55f11bb8da1ea318bca7266f0376662441ea87270aa2077f1b770c4854a48,04aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa9389142d4676cee2332eb8909fa13f95f20cdb3acc831a231802a4858a973ca63,a0da0b184f59ab0fdc0a6b03157c1b75269b284608b74a6e826f77104c42a9bd,1\n",
+        "83,55d55f11bb8da1ea318bca7266f0376662441ea87270aa2077f1b770c4854a48,04333333333333333333333333333333333333333333333333333333333333333311489212aff79cbed5b6440161d57c98a34e6b80ec3854c3b2855af35f0d17e1,ac03527f79d801081da4c0adcbba92e983ed46254354fd98e23ab659ee02a6cd,1\n",
+        "84,55d55f11bb8da1ea318bca7266f0376662441ea87270aa2077f1b770c4854a48,04cccccccccccccccccccccccccccccccccccccccccccccccccccccccccccccccc75552486fd8e6bc424492204207fece59d93fb124390497f841d9c808c8cc3ec,0d2759dae57c906a8cca8541a56f429a6ffedf028212f7f0ad06b696ee655caf,1\n",
+        "85,55d55f11bb8da1ea318bca7266f0376662441ea87270aa2077f1b770c4854a48,040f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f03425ba0d8a018b42861e816c79a17d3b47df8f5f7107c181168e9edc62cb3cf,ff6e930d08290769ef982bc568b0b45f88b7d5219b00667820793b3e824c1615,1\n",
+        "86,55d55f11bb8da1ea318bca7266f0376662441ea87270aa2077f1b770c4854a48,04f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f017b41bf72c9f5ec204dba0deb5dfaa43b793a83b243060d0375b969e0416bd56,bd106c43e45272091553f476e015fbb0982764c018d5f0666834ae0dc5dca131,1\n",
+        "87,55d55f11bb8da1ea318bca7266f0376662441ea87270aa2077f1b770c4854a48,0400ff00ff00ff00ff00ff00ff00ff00ff00ff00ff00ff00ff00ff00ff00ff00f918461138bca461c00fc474da0e4e35309be520e2e109e17a1cecb892b96598f6,b661d2bf35efdd264f32a3bb254cd6443e8115a297aba1d31610495d7c560dd5,1\n",
+        "88,55d55f11bb8da1ea318bca7266f0376662441ea87270aa2077f1b770c4854a48,04ff00ff00ff00ff00ff00ff00ff00ff00ff00ff00ff00ff00ff00ff00ff00feff2d6c62a47883c5899cac06b1367c21948689d522674a60f43f4b808adf618b0b,e818ea3c65c24330b7b8894680fe349b17f450e9d878292f1390ead78085ed1f,1\n",
+        "89,55d55f11bb8da1ea318bca7266f0376662441ea87270aa2077f1b770c4854a48,047fff0001fffc0007fff0001fffc0007fff0001fffc0007fff0001fffc0007fff2e2213caf03033e0fd0f7951154f6e6c3a9244a72faca65e9ce9eeb5c8e1cea9,55d0a203e22ffb523c8d2705060cee9d28308b51f184beefc518cff690bad346,1\n",
+        "90,55d55f11bb8da1ea318bca7266f0376662441ea87270aa2077f1b770c4854a48,048000fffe0003fff8000fffe0003fff8000fffe0003fff8000fffe0003fff7ffe1d3b684a87958143f250bf5a2f7859fd036620b4dc18dacd1d0c8c42eeb2e54a,50af11f10d130df5f9adcdd0103ff3d42808582487330932c2546022bf7c6a18,1\n",
+        "91,55d55f11bb8da1ea318bca7266f0376662441ea87270aa2077f1b770c4854a48,040000ffff0000ffff0000ffff0000ffff0000ffff0000ffff0000ffff0000fffe7ccb5c10c028f331230dd0a005e9faf37864c198c5bf7d91bdd71624520d4b04,ec90224ee61b2ed7cb6b9716e248aa099dbf1eae41d1ca477e8e5ba4a512913e,1\n",
+        "92,55d55f11bb8da1ea318bca7266f0376662441ea87270aa2077f1b770c4854a48,04ffff0000ffff0000ffff0000ffff0000ffff0000ffff0000ffff0000fffefffd620bbe0a621748ea2b0e0dfa463169e7c805412f4374dc9755c4db93989a7529,cf1acfa0595265623fbfee5d8498e609b6590760d1b04f31f3470cd1af3ba9dd,1\n",
+        "93,55d55f11bb8da1ea318bca7266f0376662441ea87270aa2077f1b770c4854a48,048000003ffffff0000007fffffe000000ffffffc000001ffffff8000003fffffc0c3527bd081c1c07b313bc1a0c3f845fb2fe22557699ccc8f1354e61a27b7f88,967e55f853dad60f678bd35a46bd3c7dc6cf7d18526ac0890af80ade84461005,1\n",
+        "94,55d55f11bb8da1ea318bca7266f0376662441ea87270aa2077f1b770c4854a48,047fffffe000000ffffffc000001ffffff8000003ffffff0000007fffffdfffffe232aa1c67f77cfb00e73898cf431831073c6b2a4bfa1135881a0595720759ece,f21a20024d8315ae877f90af5b0c68b6a50214fea797942870c35f4ca127e591,1\n",
+        "95,55d55f11bb8da1ea318bca7266f0376662441ea87270aa2077f1b770c4854a48,04000003ffffff0000003ffffff0000003ffffff0000003ffffff0000003ffffff1582fa32e2d4a89dfcfb3d0b149f667dba3329490f4d64ee2ad586c0c9e8c508,06fa1059935e47a9fd667e13f469614eb257cc9a7e3fc599bfb92780d59b146d,1\n",
+        "96,55d55f11bb8da1ea318bca7266f0376662441ea87270aa2077f1b770c4854a48,04fffffc000000ffffffc000000ffffffc000000ffffffc000000ffffffbffffff73f471909cfde3db2c13523bcf372877c28d23ed1ff6a3326df6bbc4b756723e,2e83d496538e7bd16b530d93b960317394b5f8b9da5128a03d08b3c8fbb46561,1\n",
+        "97,55d55f11bb8da1ea318bca7266f0376662441ea87270aa2077f1b770c4854a48,04ffff00000003fffffff00000003fffffff00000003fffffff00000003fffffff2c63650e6a5d332e2987dd09a79008e8faabbd37e49cb016bfb92c8cd0f5da77,e3c18e7d7377dc540bc45c08d389bdbe255fa80ca8faf1ef6b94d52049987d21,1\n",
+        "98,55d55f11bb8da1ea318bca7266f0376662441ea87270aa2077f1b770c4854a48,040000fffffffc0000000fffffffc0000000fffffffc0000000fffffffbfffffff71664ef90d87525ddf7815e676ae3b96c25ed9e7bdaa05da96227a1fe38c3d11,bc0a152832a8972bbeceb522e661e5249cc7455bf93cba324e810ab62a2e58c1,1\n",
+        "99,55d55f11bb8da1ea318bca7266f0376662441ea87270aa2077f1b770c4854a48,04ff00000001fffffffc00000007fffffff00000001fffffffc00000007fffffff5df80fc6cae26b6c1952fbd00ed174ee1209d069335f5b48588e29e80b9191ad,f503ac65637e0f17cb4408961cb882c875e4c6ef7a548d2d52d8c2f681838c55,1\n",
+        "100,55d55f11bb8da1ea318bca7266f0376662441ea87270aa2077f1b770c4854a48,0400fffffffe00000003fffffff80000000fffffffe00000003fffffff8000000007bc25fb4d2b4cd12a16515725d64cdbd5bc86b7f84ef005c9e51d0700a3a4b8,68e8765c71a63f3458e8edb2e52f499a1de13ae1ff1c95f18c4adad9d898667a,1\n",
+        "101,55d55f11bb8da1ea318bca7266f0376662441ea87270aa2077f1b770c4854a48,0400000000ffffffff00000000ffffffff00000000ffffffff00000000fffffffc4de9de6942e770033f5f1defbb36249e6bdf45a33e2ef4ec75a7ad880b5432c8,4060db7a9bf8ca17a5ffa614a85f9a25ed6f1854dc0f2396b9e8439ea8807a55,1\n",
+        "102,55d55f11bb8da1ea318bca7266f0376662441ea87270aa2077f1b770c4854a48,04ffffffff00000000ffffffff00000000ffffffff00000000ffffffff00000000462e0f7377a81a3a47a28e62dd8105687e457496c61fdce49ba1aaeefcf10b44,9e6b02ef374c5b7c8d1021d1e0520ef5f6e3cb7e454d6630bd270decc0343f1e,1\n",
+        "103,55d55f11bb8da1ea318bca7266f0376662441ea87270aa2077f1b770c4854a48,04ffffffff00000000000000ffffffffffffff00000000000000ffffffffffffff7a116c964a4cd60668bf89cffe157714a3ce21b93b3ca607c8a5b93ac54ffc0a,516d6d329b095a7c7e93b4023d4d05020c1445ef1ddcb3347b3a27d7d7f57265,1\n",
+        "104,55d55f11bb8da1ea318bca7266f0376662441ea87270aa2077f1b770c4854a48,0400000000ffffffffffffff00000000000000ffffffffffffff0000000000000012a69aaa5d3ac4cee07592ea0989dd9f426a0e4d16807b6c6d04baa628a659c0,e7e84fa13b2c443d0a5c3ea60cf87e772c7faf9f18ef5a0d77113dd6c92c8d17,1\n",
+        "105,55d55f11bb8da1ea318bca7266f0376662441ea87270aa2077f1b770c4854a48,040000000000000000ffffffffffffffff0000000000000000fffffffffffffffd137bd006820a8be23475ea25d8fa05c8ed47fca1236cd5d1f07667db6fcd2979,6c7b3006dccdb0b021f00307200793374419358b1367800ace76d988b10ff699,1\n",
+        "106,55d55f11bb8da1ea318bca7266f0376662441ea87270aa2077f1b770c4854a48,047fffffffffffffff0000000000000000ffffffffffffffff0000000000000000322a5313a994312a3f047d5a9c6cdeda173f1d3b35143b3b1a594f7dbe544d0e,b522e81a22720c57040f2e02781728577cb5603ea280be7a1c84750e33d7c963,1\n",
+        "107,55d55f11bb8da1ea318bca7266f0376662441ea87270aa2077f1b770c4854a48,045555555500000000555555555555555555555555aaaaaaaaaaaaaaaaaaaaaaab79dfbfa5e66fbea30e75ecc703e65db43e027416c01f6e20fe129be0e43457d8,f9ee8b2b668e2a76ed66dfc57006b6403ef8e388c066a0b0493ee0425f8dbe3e,1\n",
+        "108,55d55f11bb8da1ea318bca7266f0376662441ea87270aa2077f1b770c4854a48,04333333330000000033333333333333333333333366666666666666666666666610a4ea9bc365e1b224b173e9fd688a5234932ec90a5cb9ecc0a22a109927e73b,1b78098993807e9df54800e2c0f836ea1c4604f7b3c9401311784f69ac4657bb,1\n",
+        "109,55d55f11bb8da1ea318bca7266f0376662441ea87270aa2077f1b770c4854a48,04249249246db6db6ddb6db6db6db6db6db6db6db70000000000000000000000005ebbe906d14cfa3e7e90d397973f093a6c678a23bcb2896189ffece5a1ca398e,7ee2972f263079a49b5bfc664bcf42c2573af3514340e8e726e08881aa2d33b1,1\n",
+        "110,55d55f11bb8da1ea318bca7266f0376662441ea87270aa2077f1b770c4854a48,041c71c71c5555555571c71c71c71c71c71c71c71c8e38e38e38e38e38e38e38e46ef488581fbbd6b4ef78d03cdd161272243fdcc10979fd8e8fd199b98a63d378,7b5020080ce9bd76083ff132dc28ea996314be1a4ac21f987fbbe859f902fab1,1\n",
+        "111,e461c5b5e63d75b4c8c123bf8b9cd45e712af08f7e2e494a8f255ac9d80e058b,04851eb1b8699d1cc01c6b6048139797dab3981c924f9ef81bd1f464c3182c870901285b2ae7cbcabfd5bccb9441eb3376b491595f60a1ec21dbe9f972bc32abbe,6d03fef6b354840f4642fba1c8fce9f7d1cda8ae27b6ca01510fb44573fdaffb,1\n",
+        "112,e461c5b5e63d75b4c8c123bf8b9cd45e712af08f7e2e494a8f255ac9d80e058b,04f576e22747f6c77a8f685a7a7b527363e0fe7d351a9e3f5923fff2bf97e91af41e575d400331cfc9f97180d8a48c418c5a982609fab88937d2590bfdaf37d6a4,98009cee0e8ea4fb42dfb70a5ada46ece32e2885a4f9c3a6afba13e82f87e113,1\n",
+        "113,e461c5b5e63d75b4c8c123bf8b9cd45e712af08f7e2e494a8f255ac9d80e058b,04310785fa42c37bd200781d70e3859b39b2fa1d286a3b6c4f605a41e045634b81b8d32564a7a232c5d47407a7795c01eda819f23a5a190149eb8c9abf94ed7c49,e8f0e427cc30ae50197d2bbf3a451667a470797d5abe55ff1b1d6de88e41372c,1\n",
+        "114,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,0481bfb55b010b1bdf08b8d9d8590087aa278e28febff3b05632eeff09011c5579732d0e65267ea28b7af8cfcb148936c2af8664cbb4f04e188148a1457400c2a7,b485f3364354b738dc5402729b880c90c502d9ab52ccf81e3d21602c7b9bd909,1\n",
+        "115,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,0402495ac9f43e45aae30d3366e351cc08828cf3e11cc3b7209fbd1730c4a14f4e4aaa98dbe0d94caa44538fcf7949eb44debb7bcd228a54d9cd9b3c2eddf94611,e5781ddaf18dcfc33f4879a2660b7a32a599fc32ea5a1beb28a48caa3e6ec7b7,1\n",
+        "116,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04efe7754ed4c0b3c1dd301bc1ed69800aa2ff5d51fb85937715e60d2e7bcada8e4ea7e547a04c3869106b56245c27da9737b9e8160c05fb0d86040276708fb9fb,b5a0ec92aecc3010d27d2263d3da66e3d2f3395d23947024a3f4744454622027,1\n",
+        "117,e461c5b5e63d75b4c8c123bf8b9cd45e712af08f7e2e494a8f255ac9d80e058b,0466aeda76d73983caf2de921af389268ceffd278fb9eccf928702b1f4271b43f1d646d6cf8bc11a0ef97cd64c08a11f72f94af4e6b3c9870b8eb3a5b036c04fb8,8462e9df7960ece33089e5a98d61da4faaf3660f93d75f7cc5e397b64cd1591d,1\n",
+        "118,e461c5b5e63d75b4c8c123bf8b9cd45e712af08f7e2e494a8f255ac9d80e058b,04f483602787972cf7b7582d8e2f2c7998e537ca57eba69718b68d96240f035b1b55dcb4f7be7b79c469ba8a0b104900d7053ffcac04f394be925fe0cff2e969c3,d44d05d246e61892c1056e28865f60574dbeb6be62680776a537b0b2c3e6b4dc,1\n",
+        "119,e461c5b5e63d75b4c8c123bf8b9cd45e712af08f7e2e494a8f255ac9d80e058b,04579be77fa6698c4baf0132d2f7993ed76dfd36d78b584605e1be024595e3ad51c069473e1b25fd94a42b68ccb3004a851593a3af78f922d90920d8a877c365f3,0550ab2f9500b8580dad07073506da9b2db71bd974a85f81dfd148e260bc8ec1,1\n",
+        "120,e461c5b5e63d75b4c8c123bf8b9cd45e712af08f7e2e494a8f255ac9d80e058b,047f8128d883be31873efedab699206f889b776be270e163df43035409fa5215f1f36c3581f8f8d569a4323f8700ca09792150421521e2ef0a96ae9a4caed0e02c,1312f05b3e5cae60f27c9485cfd00584cce3f961fee2fdf103c32a79ba17ffbb,1\n",
+        "121,e461c5b5e63d75b4c8c123bf8b9cd45e712af08f7e2e494a8f255ac9d80e058b,04ee5d94bfb5db9cfe511a3aa70bafc93db93107383c12f5bebffd90c621a2051db4fce13261d54ab29c3c699b77358edb54c71801f3fe2a0d5cdf2ee3f184b4d6,be4792922519717e235366d64709c51b027d440c6fb8c216fbd92498aaf71277,1\n",
+        "122,e461c5b5e63d75b4c8c123bf8b9cd45e712af08f7e2e494a8f255ac9d80e058b,040ec4f8845226e864c088505d721a2155190d4caa9a1f5a3f0afdf2ff49cf6d8bad01fd3808aa03442cf5396e10e13efd24cbd5a52862822eb62dfd27b5040600,05a2ed58207e0d6a99f37a6da640f8b3b1acaf0c16826bc4fd1eb1e2a6a1534c,1\n",
+        "123,e461c5b5e63d75b4c8c123bf8b9cd45e712af08f7e2e494a8f255ac9d80e058b,043107892f3427842c8d6aa60afb1d3b1441e28a67f10e1198a07265125bbda7e2ca9d6df758904f2207cddb862806af0b8cb66ad72b161b4dc959acf614fc2f90,def48de01d86d7260e5c2a6439068542437a20b140dcd45623dd1ec0d1394742,1\n",
+        "124,e461c5b5e63d75b4c8c123bf8b9cd45e712af08f7e2e494a8f255ac9d80e058b,0409e5c414b7f5073858553b8b8b4295488e3f3ab966581f240a6124251512270f8fc12563fb9bd6f97b445417cfb14cf9944bac1a26c176b532b698ea8c1bed6f,c06f7373e67ee8c64dd86127fff8b2d23d284420d30e571a79fbb9b54aa70dfe,1\n",
+        "125,e461c5b5e63d75b4c8c123bf8b9cd45e712af08f7e2e494a8f255ac9d80e058b,04f93b07969211cafc1a30c5c578c8bf35be63a04daa2ba434da8b5e4b83769ef554b022e4f7f406bc93e71a8719ad0ccc392755ceb274e248829a72540e95f169,7a966ea1fd7c32b1fe01eb14b50d835d92c31f0a4204517aea66939e7106cc1c,1\n",
+        "126,e461c5b5e63d75b4c8c123bf8b9cd45e712af08f7e2e494a8f255ac9d80e058b,04513609657f902924e921dab1411685316b41d2bfc0be53d1de49db9c1ca78e55141d36501aac4b47dbf24fad43e47bac111596c05f66ef69258ddf0227e8e7ad,27347a05a945862499ce29fa7f6e4b2c496b6984b3936db3da2a5b82c4fe6302,1\n",
+        "127,e461c5b5e63d75b4c8c123bf8b9cd45e712af08f7e2e494a8f255ac9d80e058b,0493ebaf5ecc0aa486e7e735130611ab5b165fe395a4f1ea8f82097ce422f44202ae855c1eb4c334f53b87e426801a0376837db2c86137522894ef6d9d69520163,4f04382598f2288f95fda3657d5e517b5f8885f2257d12b3efabb624ad5d2626,1\n",
+        "128,e461c5b5e63d75b4c8c123bf8b9cd45e712af08f7e2e494a8f255ac9d80e058b,0411fd00b18efc4efa07e87878b5df2e7a596f93b950e50e9adda40d4e6ac854c0cc47edcf533fe03a614c03a709c9d0c0cf8dbfd4e2140b5b2df23848b49548c2,3b50f822d5c9311735fdb358e8c6fb2e4aa695656d75f84f151cbbdc40f7f259,1\n",
+        "129,e461c5b5e63d75b4c8c123bf8b9cd45e712af08f7e2e494a8f255ac9d80e058b,04501a72044f83c517316628b0bc9d89ab82131bc12df36c7a42b952d5bc23c85182ba2906e104dd0a7eed7b0b1772e12b3b3b7f40a2df049174fab09a02a1a5bf,ac45cd4afa8a5e4e4a0e3f60e7bfea0b759875781aa7722750022e02b66e1303,1\n",
+        "130,e461c5b5e63d75b4c8c123bf8b9cd45e712af08f7e2e494a8f255ac9d80e058b,041f3d7d05b846eab4dac4db695a441f194c226821f5429f66aba4ae0e6ba5b90400334b91fd4073d1b7c354bd62f6bced7cdbbbd7c2d392b03ac1fe52df084069,8682d123138dbb4dc40451478b078596e5b19c8b1b5c68c2a8a3b046873bfe6e,1\n",
+        "131,e461c5b5e63d75b4c8c123bf8b9cd45e712af08f7e2e494a8f255ac9d80e058b,0411ccde06e8de88d192897fd0f7bb5896553a524df319e4594a55623bc76462e122a170de0b9442314a947fbf5e035880b562ef88e3ac98594f48abc63741337b,246a119990f295924b62b290e591f428054c2d34314c54ee50dd3d85ed5516be,1\n",
+        "132,e461c5b5e63d75b4c8c123bf8b9cd45e712af08f7e2e494a8f255ac9d80e058b,04594b085272f89fae6813fd3944baddbb9f2b18236273cdabf3e607a73714a252956035b2531b98fdafa63ee7e72752ef03caa9ebbb1697a18e6d31e149485e5d,343c4a2c74e9416b07c855c2ec5ab053e11b51c47389d8455d6175e24f513717,1\n",
+        "133,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,048238f048c292e08d49bdb5dab1f08da6d39cdbefb1d75e03e76048b903d6baf9c761b25afbf041e0e65eb9e013c336fbfc364134355fd10a86c258de3e19a857,81fa8ca50ac155200de410dfdfd8093037674baba7ca102828a5d0026f24f0f5,1\n",
+        "134,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,042d08cf1230463b7102e8a54185986a62a3f2520c0c151ace12f5d073a40cbb2fecb8c2ed0a722185e987cf7c32acc42133f6ddb7230ea48dc0f7c6b5a43a4c0f,151411c3b8e6d25c515186c1d9b9c6e1a05da5a7f09cd4ea8844e0916846ba48,1\n",
+        "135,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04bcd1f2a75eee0baf7e0f55bbb1c876966d9d5d21f02a145ac9668491e4ca5e511c5be74b4082596f2c6cdbdb6cc9083895f333cee8075c2650963e7e727906e6,f72d64d6b5e38ae223c77ad3467818f8b03b9535011400157a5f426c4a512fa1,1\n",
+        "136,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,044700bc548e9d6256ece382112b93f8780fd2e427f690c35bf31f682284119c725ea408d7fc6599bd2b0e754f9de166ea47a4e48b14925fa7bb635ef09642af05,108a89a0bc8e1b85981fe4853a86a42648ccbe6855dda1079136db8c59fc2065,1\n",
+        "137,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04a0b351864a8c47ba445634bc107fed06451f97391c55921db61e74ca527a6a45fab27b1896d52df4611f627414c48ba7a131766002d5523ff883a499769a422b,7d362a6e1f00e525fbf012fcc9da98e09ddc281c143f1e023fac6a6b6303341f,1\n",
+        "138,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,041350cac374bb076ee89eb8af43d076afc7312021006431fd909d22df064f6637244f236a65e7c1c778f6cac2d9b4ec3bd999ac185797a227cf00552d6aa5163a,1de1656d961de1f584a2f7179bd3be3b4a282cbd3248f29e74b73a812cad8d90,1\n",
+        "139,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04c185508f464f67aacc24fa859e744fe57c3ecef2fa911c16dd2cbd073b308aaa8fef47dcef6b0fba6205f0b7e23d17758f1df5401cd9a39de42b07b92d153625,56ae038f61abb293f43328a5e6e40bdf73f9e7d5868e5bf1af3b03fafa559e86,1\n",
+        "140,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04dccff194a9d307b5900a13f2d7a192f6e82845af1007c951b5b9720bff98d0a028fb8ba9238ad537e0d7f31444086611430abaea547913a0591dd908ecd864b5,c75eb0a2cd617f0117f1d9adc1056a0252a0cca0b64b373bb30d5231380415ca,1\n",
+        "141,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,040cb05cb7a4b01db1382620ca756285e1a7c6846bbb595cbbac9f89e0a62489cca88829c95f6b2e8187d2e1c3a744f41d3ebc98fa01748b1248462b350256a5e8,010385fdf7f4c6402ae432e4b2f11f2f861c75c4dd27b4f35b7f1efa94bbeecf,1\n",
+        "142,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04f95894cfe4f14ccc483adf9cdf106709e3eca5ceb20e7c145d3bf18b6aeaa8039c42c0c8c3db57e0bbb2593ebe088771ebd0e2857fc2a877c3748adacd038542,af116b172237915370625d3ca7360a6b6d651c573031ac33c0ce4b72a9427a7c,1\n",
+        "143,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,0416a8b93a6b9529b5920566131a792c554ac421af3a4bfc341408d14ddc8971c5f60ea4663449bf3ec747481d730c4d97a1565b5bb207e27e55aec88969f2023e,10c3a41e0a1d0018444bbc3bcf76b2893f66b69cfb046255c82a17ffa3413246,1\n",
+        "144,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,047d9c0f7a011de080da8e6e225f7211e729c29425c9dc9a289d5cf8a0f63589aa129b3bfcb433ff084409e32f73741e592913003367123b3e6ec4b7fc398d1b29,2695b4e4a5c648ec17905e69286b6d4935a21772f5ded1e55136cf17331de9d5,1\n",
+        "145,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04fea54580f4096299c17c281a3fcf6738226b636c85e8efd360a4413848b07881fd47181ae8c3ad8948f65d8c845a863b2f86a6ce8aa37a5e5c20d3e96fc9c884,a6f648ff3373f9433a21a38829a5667ccc2b1bcd3de13516537d6d9b77c04044,1\n",
+        "146,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,0408beacc04ac27e3839ce74d515d238aa98e1aa1b5e580fc5fae0116e520d880cba0585f723c44f243813b07e2d7626605ee5861523d1ac075479da773efe026e,e1db68841b6000deba2e9a666ab2cd227fd9f33f69187b611dafec0d6c9b6e7e,1\n",
+        "147,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,048cc2807089ccab7bdfbf1697f153d4eea63f3043ce63135f6b906f8235f76bf99ebdb2c26b09f49b122d1ac73a8ba37ce9c385c0269c4e40ac3a9c20314a4437,36648fd5e125abe08ae21199d08eb78eb7e345a1fb3b26e5fd25d3c1e96e3bb5,1\n",
+        "148,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04e854fed884e6c76277af9d8dc83df66b81478a8ffff2c313f22abf0f8d4442f50f57244419b2075ce2b282440944213d87c50f58b9e7b71765d45fcad152b333,418df7a1a6315f55104b5b9f96da454725f59992c588a531e80a4744b8d7d4b8,1\n",
+        "149,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04c1f319279a7bae9f3dc527c42308a7a6255763cccd6f24d266e75f88eb4d022f6b3a3cf14ed3b5e6915ff322b705b175da07223cff4e9ad4520dec7028e1e396,32dda9876dcac25c58543304a2cf6c1b31fdabf3c89e43d6eee628a02422b19c,1\n",
+        "150,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,044753ee2e3ac201e9a4bf25e31513a45365b5d0526dcb2e0a88736fc8ae2681c1cc49b1e868d4de224593ec7e00e9f2c209f3b935428d3bfa978f74869af889e2,4c54d63b14b8ff10b653f663f3230c1827d682c833ead1f44ad6ae8e7df35e2d,1\n",
+        "151,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,0429c1e774c9d3a8930df8a663fadc8b9a6092d8054ff37adf598edd78d0a3ffc57173a3594be68b7ad191e0ff27d79eb4e7bb22f1090873cf307cd073b27368fc,4df3a98474884b1c3c78b6c81e4f90d53d8bb4daba4227c411a5747e6aac8cb8,1\n",
+        "152,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04893cb0c308aaa2092d9af93e0f02cd951ec386e866480eece443cc89e672733ff6c6f43caa1e1d168ba3ead9ea041cf4f227d76b90e9e25f497f2ece8935512b,d2aa087051fbdc35051d21ff415f934ab5698629abde5d4b39f2f6a76e3992cf,1\n",
+        "153,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04c5a2dc340e0983aad4f895bf54be9d9e176d6ba1ba6c859ca0851889a97b7dc5c90dab79207b861fd996429639cd8f31be0298b63f4934923a483a9e6f7c5ab9,ac7c60651653a4f3d4478ebb9aec4381183ffe1b152b6643c07c47c0b2a489cd,1\n",
+        "154,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04e9db2cf8037505fd8a8044f4b3f80f67f9688815c69c788ddb26fcb3c84cb8adf02e121e4020424c6d8f1d269c00f2a7f23a59237fb48a5ba17ee57c1c264282,509d06dc43813dba9114c13c337bdc0bccc2c7dfa7a7d8960e5593c52849c855,1\n",
+        "155,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04776aef1acb82b628e132cc29440988f0a15d4cc2b4f328aecb063c9b86e5018e91bb2038fbbb05573b1c943de8bae0853d6a934d4d164429aa145d68e9c2e0cb,28a88b6b258f233020ba6fa9c00d1d72831f4515b86966a9782f521315e18aa7,1\n",
+        "156,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,049ec06b0b08662c0e1dd9111696a63a1601cc83cee20695778adf84d43064fc90ea9ffe0e7b32c3e30e5f7809d9acc49a8da7b77742c2a3d3660f1cee1dd4be19,c4ff865ff3dc4953ea78d92a02f3345a53bdb6050cfd8f41baa4395ecb6acab8,1\n",
+        "157,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04fa51d128adc2000f09ff12c6fd8e25aa08556d708bf6b0ffff9e8eaad4783f0d1dd40ad51ae91e0ab471f2f6067052b1afe96a57cf5e4ddf899a6258f81c332f,de1069f051637e10166559cef44688afc809341855261215c4f381d9d7da76ca,1\n",
+        "158,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04614dcfbea4789a3f3eb4a8e2f111c887f0248d9316b99d0864c927a045d69417ac5f8c4001f7b6e67faf5b2692f745b86f51e725c1080f15330a631ef6a503ab,4207bf4159faa0e50ed238b9c0ff46194a539a1ba03a5a4c8d68f369aecd31a5,1\n",
+        "159,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,048321763f5533310a4779e83a58ff110480394690f7c2f65fc229c1a58ffbddc86d170141a108fc92f2bb96ddcea0652cb650c5e863af9289d37ae87a29022d9f,7c8e29caa01e9f325e1e70d71a59850749239adf4e1b8fa5d6d0abd70c83afb7,1\n",
+        "160,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04aa42c977da1ed42e52041c0af2c090f244b22ae354cc9b10781e44c89eebe336f7100a0eac8b55513ce57a8d0c195adcf50d1b05fd35b0576cd92805cbab4c1f,98afbf6e00eb004c79657b40b7105f8526b5205942af2218f453bc26337306be,1\n",
+        "161,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,040730d77d9114d5610d49a98dc196ca9e1e8fd2abe007da96b574f736bf1939258c8814055a774f7e79f7b4fb9cfb03d839566afc6059311f3d55d49ca4ddab31,c537409021ad95646fbccf9232ea47fa764d12db7595f436a65ad5b13cdd19f8,1\n",
+        "162,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,0485a82fabb60c7664e2500c59d0f24eac1aab0060c07b49828c55009fd27aa49647b85f72b467bfa2fe1b13258feb121aee75a3155b30e8b4b566b610f13b6dc5,ad60146b1cf2d9661884b9ef867cbb09e42466563936b03174efd8517ca8c6e0,1\n",
+        "163,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04833f2a6e5c76c3ddd13a106cee4aa8b2a9dc32e0bd7bbe2a6322abe0ab4110a2ab93845403808a7cc8e8b65fa42da414327ec3a19d539fc033ba8d8aae7cb23f,f677a03d68570cf6083a1ba415b40b5f7ffdace4eeb804983cbd03b979d939b2,1\n",
+        "164,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04cf8116ba0901b0b95308bc686fed90ceba0e5d424c8ec206cab8d7cffe93fba2d5e7a62cdeab84a20fce37b5b0ac168ca2a85a1d18cb04ee48deb250b54691aa,30c9fb67a3db93a4b3a54135d265171a14a2a0a79cd715e13515510793d8d081,1\n",
+        "165,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04e754cdf8e56944d5bd36d025b1c0f5fb2754a2eee0fb5200bd169cdfc83f07c38f2b7881de647c2c66294e1adf767e515adda742d3a863f0cdd6665b964e60a3,8451f3414247058c32b53be128cffd2f2887434ab902b67b05fbbe7aeed8cbd1,1\n",
+        "166,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,0474f1feb9a641fd87a49e16fbb1be363297ef971213bc7d36b6abbf29d0e88ac34b6c246b313be6c3c9f33defd4ade947af547168f7c22278df8cb7b06a8018c5,dd6bf055a9e431479707e64590fc58f97ad3238d063ce8f66d5d37b2e3475638,1\n",
+        "167,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,049368066a0748867a7b870244f5c9f82ea8bd51552959dd550bb7394497159a5dbf89b521e51db372c0bcd11fee41682cecf8e702f5956f1274efee4dfcb2f65f,4529f4b631c9984ab216a6801281fc4fd8731a58b65ca8d07bff07811116371f,1\n",
+        "168,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,0464e5d7b0f7fcf4c9c16156cb8745c43995f823e7e10954131e61f9fa09059e2c2f7a39dd0dc6e8a9bb7cc79d821e7c749963dd97c7070129db638c0dd1ba053a,12aeb42630e4885a93cac090c66479d011a1fdfaa46c0db33e0fd51e1e573cd8,1\n",
+        "169,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,043da0655a52e3d6857a39faef864cee42f1816bd8d83af8c657990846821f00228f76d10162e295fd7737d0d22fafbab24d5db0ff432b51354030e1fb09273c51,ad8deae400ae52dfc8d5ecd3518d78c789b3ec88cc1ec7719081a066def26c4e,1\n",
+        "170,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,0442e831d5dfd2b9f5387263cd686ee03dfa235cbc07f6f3a87a7e8150556362b32be47f8e3011572855ca487ffb594f7722ef9fc3292902a23146c19bbf6da76a,e6d3991eecb1a4b78aa700ea56934a38785b3662c59766e2ee5b157f77ecbb7c,1\n",
+        "171,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,041321838efd80e1dd8e4d0d95d17895c7dc60303a5c234182b592d9d21ef14752a44eaf3394c63708763f3b97b2d2863fd3b77ebda44862c7c23daaa437d5fe59,99bfe73eaba2599e79307488047236de003e9fd26f5ff1dbef62b3fec75fd612,1\n",
+        "172,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,042407bb2cef161472cc799db14e703183676f312c571e9f3df137f55df9442c4077bf00500aa7bece8c1f762ff08a0ec71bfdadfaf13380704e6202de62c5f22f,faedeb03f8b5178db58604ab69b10b176e92c5deccf4249a9d60010dcd16a635,1\n",
+        "173,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,042477b9ec12029a86f50cd95dda6cead7a34f1c2360f39e3d1159793c8ee663a6bcfed85bd19d4ed3855807267e39156413b41e39a701d8d17a23ac685fe5360c,47e5858af99e4e916054897807d685c8cb8b0f1692ba782bec2290a58248c683,1\n",
+        "174,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04c829d3a99bb47e4256fcc63210c13282ab59e7aae90877b1797bd699d672a38b8696a03730704204894aa967273c120b57cbb71f9fca62c64f692e7f49598d2b,ade4ff3437df254696fb2b19f5154108d88e775e57d345bbf57a02b2abb3f07a,1\n",
+        "175,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04fd61ec5552fbb24b575ebdc9d4a443e156bfc0d13c9dc611149e21d79c48c444b7b73a8dbb83a5e2267a8bcd398b10af0843474c750265e9d48d04ac4ab60405,598922a353fe1add65ac8f66fd225e826fa8ce493470322593735fc6627e6f2f,1\n",
+        "176,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,049cd9e0f17c11a10f8aaa1f47ea7141f6399f40b079b07b14cd388f3b6b0a47a8efc86998f717a83e53c8e58ad23c71d0ecc8bc841bc16ff232a5e326844cd4c9,923cca706deb11de7c17f8dc089dadcee15042d5619732d9ea537f7dd993fc31,1\n",
+        "177,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,043846b407782243fdf3b04042990b16e2abdb325ba098532879d14e16e914c274de571bc4ce2edd7d2d37df5593959b5c7ab59ac8fe5e06c44bbae6960b6c2510,bf6234cf4de2040548dd0ea2a99310389db48fe6c0eb81395ab2d42484f841ae,1\n",
+        "178,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04d3a07a4b6f9b660d92b3fe937e2ebf91dda8005a7ffd0292dad91d0592f1a89ed7209513bde00287b8234e280c00b3c59dc5e333c8c56864bb6cfb364e3f2d85,da223bd733d403e2f2f9f59ff712230b0ab7feafc87dc7021644569e3d513795,1\n",
+        "179,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,042ad9550728f65b142ed676438bafe80e365cff4eb557526560e4adfd696da778f0f01da8e947628346614ed7fefe24901f9eee79de6dc06d85d563722ec77961,fb888df1c34b3ff15620b94a20726954eb7989e6f8a266ee4902f45e7e86e21a,1\n",
+        "180,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,0419f5f0b4edd6124e340adbcce791b06d14054c7eb1f9914b44245c51d6c1101d82304d117f729ef06d97eb56aeb99a6222dc3de5e30c071956fd73ee8cdbeb81,c656a27fc48053f9bcfa3a240f904be76954cc8b8cb3f4790613e618c5069ddb,1\n",
+        "181,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,044485dc2fbc68079c357a04daa6d5306cc78827b0fa64935747b98123009b26605258c4a5608315007db08c27ba86c8971bddc529d1c14e66478ef7fd92b33ae0,59e168701d9676742fd203e9ff357dfafbbe51eccb2797f7bb416d4ae21bad99,1\n",
+        "182,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,042f98fd0dc4b37807d9b1dd015a08a90dc90c088014d1305e49017b60d5b07e0e1e8ec878731277193c54991353d1a424a1153b87bf2cf25309c74032fdc73f95,7f19652f3077e73a9677fee21d0cbad29914e3bb0270fe77500c4c0a9da8eeb8,1\n",
+        "183,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04df1a499387ece423215b041d19076b61902d31bec5135910b90d8819df3bd2fe9a9bffe9f1b68acab7f57f9a19ca5820389f1d73533dd73f6532d69652336fb7,b1c79105ffd4b5f4f92ce3a5f55624fd2aace8eee3b80f5438c8419c55051a7d,1\n",
+        "184,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04b8c2c47d8c6dd3bdee17a58081cba359e86ef4e19b368d46ed5e43fbe074b08c9b3031b7756e746099d22c985e9fd6213519186acb537fe4bcdb69f5f3a01968,e1f33be462ded1d121dd2a15d413fb8b9a105922cb90c550abe7ddf352266c75,1\n",
+        "185,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,0421a34eeb4e1e2ed7e4546a8144a8d1c83ed64f3318f72f7a2d786316bdb5e97412a8cfaa2fac8629fa7e20d9229f35662f5d05ea8cd38836537d16c1a64605aa,04b295929582f1a5273346623d9d07fa43c654e9b1564244ddf82d61793fecb8,1\n",
+        "186,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,047e9906202cd1abf7147027c8647b3a5816b363266326804d6d596e875f02e1e686a981ef38871ad59f427815cedc1aead339c90d61da12b0f7275f4084761afb,bc33390ac64b082bb0f2ec689f239e5931a54483e7acae90442a9401c3645223,1\n",
+        "187,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04bc21004d799ecb473253e3ed07b4aff2d3ca90f12943a76e9ebdcd4931c438eaa9b6e2c6834bfbe56f4c4e5e22b125814d83f2973dcd64dc7f51939574002fac,5a0c1a2a33176dcb56645a3548ff709e31c37067e5ef969d230c1b1927d75d62,1\n",
+        "188,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04ca6b6e4eda95cccd6cc8e16c3c6c748e2acbe31b70529bcb69445c1140e63e2583cf3c33723659b2e303051c3c1e9f702f9f27421f6b3457e377356018489b46,7c6a5a4a5608ae11acda6292f6d3b05b7153877dd6e1ad914ff5e3550f457661,1\n",
+        "189,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04884cf0d17df4ef5e488d725b47d069cd2b99940b05b26efcd55d0e5ecd205b61e3b191a7f9f920cfab05b0f9cddd02fecf857d0e405ca36f0106aa367d492460,d990ca49c9b414818622b9c69197fccb197c94bd1ce13c24110e94c5c321e2c9,1\n",
+        "190,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04b1e5aec6272dbb856417e7ce6102bace3e0dd7c32386511e065c1a32d5688fc5c078aff94b8eac78bdb54b210bc74676d2336ab7bc7236808291540f65c82982,973af53e8a92e7469856265229acbbe0adb5574beb32ea6780be385b28ae8b07,1\n",
+        "191,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04ff8d38f61f633c5eb570ef877e61b4466b511ecc3dc3de87e6224a8c78c0893d7153d4f87b7f9d0488b6588df56c9934ae75d1486c1ebdb49444bad832d08017,d9fdde34e48cadf6e63c1de84b22da7180ac644c42c055d28625b2bf11cf4951,1\n",
+        "192,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,044c381ef0302e1b428f4887f7b4fde4960245920f40c4005669a091644c27482bef739bc3a3d22c6e707557f2469f504aea9e8a07591776b3324beb5c498e6f55,9dda17a91ad44d472304c805b350f9ee55de2d94d7327160dbc50e2eaa85e4e8,1\n",
+        "193,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04245a530c56d07978f91177e8d97cb1368546bafefbf8148cef339fe7bb652ba5b27a60edd2f498ca636996a9e5e629d5c9ec203217c1b5402ff99a0d2fa97cad,7a69b8de61da48f9901ff440192fc90086c3a2bb56bdb224a55b89cb82c72162,1\n",
+        "194,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,043cc566a230b3df272a172cd80fb481cfdb8dd1c27a84dbb7a391f11484f14ef99be9d4cfd33748ee157625f809d0fbc1a6f0317d97dbfeda4999742455fdf78e,76a4006db3de3e098936d004cac1f7af41d515f753b9aa4433eb2466da42acfc,1\n",
+        "195,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04455fc13990663bf24cf5908c59e4c3a084fc39ee1a7b14d812c227a8dabd6f94685d00ad604b206d59b2f4d9a75442083b85a316dab78574a3bbaa6259e89a3e,3be92a857ffb42c5d5c128925b89aec34cfe649920dfd647f0a03f421fb20f09,1\n",
+        "196,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04f933578fd1938e44b271ae431ffd0df5548496945d91cb8d311ebec9dd50d036f3d88fa2aae4236cb08858158d725e1213fdb982503bc910a5b58366cd85ee0e,19e0d41257ccbee1c453f834fa5f349cd9bbb98a92e848354a7579dfd2c78e30,1\n",
+        "197,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,045606cbf0c5b52f0f464137d0133559fea50af271a9826ff6125746636d981850d7b10dab8e64b2f8df6fafb8d5943e7b37c6500fa95bfd6680d8f2ff74c4407a,3a7a54996053b41361b605e73852a5a0b17d85b42b3c4a6f119c6c87ebeb9f03,1\n",
+        "198,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04bf236450ef33bceffbb90e0b86f8e99deaa1337c89826363b3cb4e0a66d541030676703afc892b8059c92e24f653b9cb2a11a5f520c848303c17c2c48600c84c,72f042a5b276a980d50a0583483eeda72de8ac796b1d50a843b4552a151e199d,1\n",
+        "199,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04000000000000000000000000000000000000000000000000000000000000000066485c780e2f83d72433bd5d84a06bb6541c2af31dae871728bf856a174f93f4,40eafb850ca7e08f19ee6447f196aeabf292e27397856e106e60a6c8b48cdb27,1\n",
+        "200,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,0481bfb55b010b1bdf08b8d9d8590087aa278e28febff3b05632eeff09011c55798cd2f199d9815d7585073034eb76c93d50799b354b0fb1e77eb75eba8bff3d58,b485f3364354b738dc5402729b880c90c502d9ab52ccf81e3d21602c7b9bd909,1\n",
+        "201,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04614dcfbea4789a3f3eb4a8e2f111c887f0248d9316b99d0864c927a045d6941753a073befe08491a8050a4d96d08ba4790ae18db3ef7f0eaccf59ce1095afc54,4207bf4159faa0e50ed238b9c0ff46194a539a1ba03a5a4c8d68f369aecd31a5,1\n",
+        "202,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04dbfa466f12013255f9d57a6496c158ee7dd202a1ce4a5a53005b3564d509a0bbf2578007e857bdd082751ef2f3b4b9c38a0b87bab413d55ccb26a574f2b4be9d,e2d57eeec983756c9124f885a4d118ed5b8de7d2895fd91264cf291496949a12,1\n",
+        "203,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,048238f048c292e08d49bdb5dab1f08da6d39cdbefb1d75e03e76048b903d6baf9389e4da4040fbe2019a1461fec3cc90403c9becccaa02ef5793da721c1e657a8,81fa8ca50ac155200de410dfdfd8093037674baba7ca102828a5d0026f24f0f5,1\n",
+        "204,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,042d08cf1230463b7102e8a54185986a62a3f2520c0c151ace12f5d073a40cbb2f13473d11f58dde7b16783083cd533bdecc092249dcf15b723f08394a5bc5b3f0,151411c3b8e6d25c515186c1d9b9c6e1a05da5a7f09cd4ea8844e0916846ba48,1\n",
+        "205,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,046e8966a6013dde2bb3b2a28c6eb013c481ddb7249ef19d73b92da35cad1d6f14604bf43d798ae27621df2eddc8f5d90ee0289c54bbac82b3c5264c478761d153,5460bbc867f4f61b79ed491e41779e2ac8dea27ec1320b558f3cff5b6a94c224,1\n",
+        "206,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,044f612e0252b8d484bd2657a9ca1bba22afe978466aae6f12bf1d9171e01683ca48f4f3c986bf941b523889a7583fae695d3d4ea4adfb57d0c82370fd82364c1e,a78ac266fc5a85102db78180bd9ae4b77039ec97391a211403322e4d56b59049,1\n",
+        "207,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04e8b782dab2dba4db066670ae656c7eca4abc7b1a24c7976de2ce02f25b668413bda2f9991ad7aaf44437bfdd3d91c8f94178cde0f8af9833544ccc9e2d606b8e,f481ae23b9192191e8e1c47203f6a807e54e1ff471e6642716f07895b32f4451,1\n",
+        "208,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,041350cac374bb076ee89eb8af43d076afc7312021006431fd909d22df064f6637dbb0dc949a183e398709353d264b13c4266653e8a8685dd830ffaad2955ae9c5,1de1656d961de1f584a2f7179bd3be3b4a282cbd3248f29e74b73a812cad8d90,1\n",
+        "209,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04ec624cda95af6633088f9809e441104322ff529e0952aad4efe3286203e0c700396b89e7168b1da4dab123aa6eb7ed60069d07b241d6fff4ba04647e4fccf1a9,50b13920c2368b9a2e8c80a02a6f4e4a2f84af33532eaf5728da161e620a312a,1\n",
+        "210,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04d80a508b155f0c733936d523201bde339794417858cd4a34b685635138ddcf67b4af7eb47f6a8491c9147b49babcdd6538e0a126547493cdcb7e8c900d48337f,e597c39fd42649418bb49eca83589645df32ff1b3f3417087ccb3ee5bf38fbf5,1\n",
+        "211,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,040cb05cb7a4b01db1382620ca756285e1a7c6846bbb595cbbac9f89e0a62489cc5777d635a094d17f782d1e3c58bb0be2c1436706fe8b74edb7b9d4cafda95a17,010385fdf7f4c6402ae432e4b2f11f2f861c75c4dd27b4f35b7f1efa94bbeecf,1\n",
+        "212,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,0472aba18bcccea84909fa724888c2d1d007b11c97e02f61e44b125b402a11041a3ca1faea5f02dd5f1327d5028de70e78c81b05338a2b2e3da509f08e2bd9ea3f,5696fa199ef1088f88c8979db25721d0a707eb7a51bfd97a1278bcfd43b38304,1\n",
+        "213,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04276e45461a0e00704bee883b3e61016eec41678d8176d37eba25557a740284e05ab0fd1bf9fdac37fa83f83f5156648bb036d6ce46ff1f6ad4c72bc953214f0f,eab63813807eec138a696be8b9e0023dd3c6cee35b7378174aac70838866c216,1\n",
+        "214,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04ec90e45db57bc01f74d7d01e8783937723f8e27ba99541698e01c57cd2a39da0933bfd2d11d31af9cba4c53aeabe6a3635a0643c114afa109ada02004286aea1,c982c6d73a9307038bbed250369aca65a7e392bf15f440227eb9fea43cc4756e,1\n",
+        "215,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,0428993d7c18770eec4418c16d595987c196412f7b273da44136b19d4b516a2ac7b9ba3574a31169d3f2f7a62758d9d27a2a4fea904b24d42312a9ebfb49ae355a,5581a9f0971c60709ebaf53b1febd6e0d174664b82a36692656953507c0f6dc9,1\n",
+        "216,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,043e4851b2b0d1463b9c90772e10fe8cf29b181463de28e9cf81b4c9604931a4dabde252d07c1061e60f4f8cbae5c2a290c034438a06a8bac67b1ece8ed551a234,c7df69b1bc484804a3683449c3a228ff787387d56672588140c980b1fd4765e2,1\n",
+        "217,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,043cedff3609bd11397ed3aca84a0a7bffd009d48beab100719c7607ed28e56124a15f6af627e03f57a98012fba18321b1dff6c24f51d7b6415d102608b772a44e,973c078916ecc4c660aa00dd4ec1e1df8793cb7f8670766a5156f8344088002f,1\n",
+        "218,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,0441e9d4cfa8efe80b895a8cbcce2568e251db7ecdfd20a7ad710d4a4bf2addc6b5ec36a8339168a03f15b8c80f2a2a828f151d38791584853ba2ff44a2a0460a1,b48e119d29eef7dbb76b64218e728ddbf6ec600505ec7ced6ab6fb8763308da5,1\n",
+        "219,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04776aef1acb82b628e132cc29440988f0a15d4cc2b4f328aecb063c9b86e5018e6e44dfc60444faa9c4e36bc217451f7ac2956cb3b2e9bbd655eba297163d1f34,28a88b6b258f233020ba6fa9c00d1d72831f4515b86966a9782f521315e18aa7,1\n",
+        "220,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,049ec06b0b08662c0e1dd9111696a63a1601cc83cee20695778adf84d43064fc90156001f084cd3c1df1a087f626533b6572584889bd3d5c2c99f0e311e22b41e6,c4ff865ff3dc4953ea78d92a02f3345a53bdb6050cfd8f41baa4395ecb6acab8,1\n",
+        "221,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04fa51d128adc2000f09ff12c6fd8e25aa08556d708bf6b0ffff9e8eaad4783f0de22bf529e516e1f64b8e0d09f98fad4e501695a930a1b22076659da707e3ccd0,de1069f051637e10166559cef44688afc809341855261215c4f381d9d7da76ca,1\n",
+        "222,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04efe7754ed4c0b3c1dd301bc1ed69800aa2ff5d51fb85937715e60d2e7bcada8eb1581ab75fb3c797ef94a9dba3d82568c84617eaf3fa04f279fbfd898f704604,b5a0ec92aecc3010d27d2263d3da66e3d2f3395d23947024a3f4744454622027,1\n",
+        "223,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04d8e13fbd017f1f9a26be35c611d7b2299f5d10de3c8a26362273fffb85238f3ed1426b748c1f87e3afa2c1e7a0224310c980655e07399590d1494d6d6bea0396,d2a5bc66498c6036aecdfaad041cef732a893de190a0a5b42ff71e13f09280e7,1\n",
+        "224,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,045a1027666a0e372481fec0b3901e058d60107c07b1115550ceb05789b55a6d35063d4c8ee66ed45ff3e1dfdcfd73ed96a9e83193884adbcaa574b2dd118a692b,1f812313ddcf36bc38071d0e51a74100d630c8e20cc414326eefa42ecb1b5f8e,1\n",
+        "225,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,047937b9c40986dd755a0656203089782583da7d8113a44190762ab474a20bcf60efcbc1525aed5b4ad8e687cb02c2ef8887095cadca56c765b41b4a9544ff2fe8,f284089bddd5e2e1be3f82640efa0658468fa1f10b281963a3ca190c3982fda6,1\n",
+        "226,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,049368066a0748867a7b870244f5c9f82ea8bd51552959dd550bb7394497159a5d40764add1ae24c8e3f432ee011be97d3130718fe0a6a90ed8b1011b2034d09a0,4529f4b631c9984ab216a6801281fc4fd8731a58b65ca8d07bff07811116371f,1\n",
+        "227,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04981d7449bdf0013f5eeddbb7e42c442f7ccdd9427bd26d7b388755aa5e26f46a1292b88fa6bf5dffca054dd42ed3594277b593dcc402d80340fb7816e4dcab37,64bbc9fdd73643eb2954f4ab640381b938c5e601846a0c6b6954966e0dc73e6f,1\n",
+        "228,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,0409e78d4ef60d05f750f6636209092bc43cbdd6b47e11a9de20a9feb2a50bb96c0000000000000000000000000000000000000000000000000000000000000001,28f67757acc28b1684ba76ffd534aed42d45b8b3f10b82a5699416eff7199a74,1\n",
+        "229,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,045384d6c0def78960db967b8096d35477c5a5ce30ef0c6d8879a5568ca87e979401ee56c4581722610b43f3cbfcf3862c082a6e36baa36fd6f78403c0e399faa5,9ee653cda46db67612760ce35bac8450bbf48dbf74451ed93abb6db408a9fe10,1\n",
+        "230,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,044eca7641a4afd5eab0b214657ff3bdcbfc66f1551a53bb59493bc38ed78ff39614a0cadff14c14736edbdcdab510cba07a8924ffd0490ee514aedfaadb648b01,9736ad6b2a2ef17ec3f8c8dc2e35715fb1c06f28d82e4e26876f0214588165f1,1\n",
+        "231,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,048d0177ebab9c6e9e10db6dd095dbac0d6375e8a97b70f611875d877f0069d2c70000000000000000000000000000000000000000000000000000000000000001,748fa4f5a399320382dc920026938694c41a26fe2aaa318c5e710198dd71c793,1\n",
+        "232,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,045fdb7f0cffb8b5b1142d24698a4bda76bf9827d63b1a6bd85a4e2f9b59c510cfbcb35ba9c987108b6d4337ad5393f9f910ec92410c230869d66528ed88c1b98a,7f97db83b4d86f04fe286041ee21e80ec3d59f3ce82cdeeaf362016fc87a3e02,1\n",
+        "233,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04530b2293e60c6b6f14c75c90b1ef8b9f9fa6b2151b8d9855792eb2b3dc69f07a0db42440e73fd7d6df04aed5022fbe21ceaec33c5fbade1bd6ad321ef2e10d0b,21794cf24f56273fa4463cc7ae4232fa34dbe0f18b73613b8ae9cbfb9c36abf0,1\n",
+        "234,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,046916fac45e568b6b9e2e2ecd611b282e5fcc40a3067d601057f879ce5a8a73cc0000000000000000000000000000000000000000000000000000000000000001,915106d07816e879e7643f00abf6d79fb8f1cb78bf64a6a3827f91a7b0ef0f41,1\n",
+        "235,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04ed9568c85bc52a6b45733618c3602107c1fdacf23b1a38e486af95978a214e2efa0d71d5e737891c4276e247581ee6139011ca1460db9b1e20b364d9275683e2,2fcce552310819dd775ab7ba9ff0f96a1fcadd25a0c709703cef04bb6e1a7bd7,1\n",
+        "236,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,049ff7731c00f2aa88b3fc174aba907ad17595e602e768a5f1e9462a6d4b89b2d23f178a70b9bb3edce289118338a33df30c432c347f12a3de0a2b03b353878d96,757d926a2693bc8a3d2d8c0554a13579ef9e559186578911f37edc88b2f5e61a,1\n",
+        "237,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,048270f8179d57436b34dfc0bdf7d417a5c895116b90cb51aec718614f864a635d174804e0c0e06e3d68d3149e0b956621c6aa2bde83f4d17d03d28ef8aa389fff,3db29ec6f978d2269e92e9c7eb5c8b5a8e56c2228a4fb9e483feca50aa3e451f,1\n",
+        "238,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04c61750e98abaf20225a881dbfd3510532cfc3df971bbbca4a2bd52f91acc9c59d0fe79342097f88ae78fc79a8032245fdd2c30cc64aceaaa9fd57b0825692531,72c57c2e10d77318b3a796097bbf768c6366142d80f98c90a93780a841075f32,1\n",
+        "239,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,049c5d3bb54650d9550e1ee2efa3ea43c14ab99d18bb049f37b42a6dac48232f0bd3a2760d83d33afe4ce6f1d1245489c509bd26b0251f308f8c996e80f7a3f8eb,a96b07944e9eb2b22a9a36575eff1f4f6363b4aa3a53b100b8518a67ba5405dd,1\n",
+        "240,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04f1724efd481ad45a55795f06126b1f5ed28e7d9bb4fee910af2ad8c1373b18ff77edbc34da6c787ec73430347f4da86810032d88f7475f6c42f15914079d179e,855883316b6d097ae5eab6c67e8411a1397349a09b9d7d8f096b2ba1bd03ea31,1\n",
+        "241,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04fc3680af52fa89ffcd193ecc0b0714466fe5db277ee5872846c520bf4e3721d927260a0e225a3d377e6723ecb6bef8d4493c2da78a22a307fcca8f88f4527208,5a75bb7a0c96b8340d0842bcccf11974e1a5a2c8f4bc22b333433cce646b6a8a,1\n",
+        "242,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04106b6f81e3482db18d74029291821ae448c38844ef783bf1d6999a404401f63f6a5753f0edc68a62cfd6a0b181bb2599e1f3bac5fa8824af160de79ed867c350,d96412e31cf4d26195920cac952fb79ea25f6c50abc79b5ed0ef8026a6e83319,1\n",
+        "243,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04093cb5193a4f94cd18edaa20a973b87ff79b0c03684c79487ecfee347e5354eb04fcb5752539170777932be15cd84c97f03815ffee8b60b647c178eebb8e14d4,2b0eed9badc92a1068196dfec124fe8f9d3f451e294d322eb881cce02f286026,1\n",
+        "244,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04d6c38f448b964e27b5b450cc38d3cf41ef9df83d8a959771eb9c21855cb36445df638aef46a2aeb13199281e1a26d12fe61b029ec7f68b90faa89f88c7a95942,ed0b1d8dfd27a61fce91dc6405bfc53b6d48a8c13ba541c96ef3dcf31d7cdb88,1\n",
+        "245,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,048a748d61f59c3b6a29b733b0d554b2492e7f76fad7cae1c17f2ac3de9e4a65d2eedbe6c26b6fd22bfc03c1687555d2f0a38e02adee5570686171abfec6681917,a796dd144f21ba3318f9e10828ecefc9c0f6ef2c427ae31351c16c2fbfa3cfa6,1\n",
+        "246,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04f1052699d87e5677c75e26b2abe719310648d820a96e5b381fff58b392401581b1bb16ae8b68cbb76a3256870bad1ee5a30ff9fd662fd4f8d1fe5b5f1f98ff46,1f3a9615b0745046a972bad5d59794a0b60b032b4ac94fe85f77dfb380d1f32b,1\n",
+        "247,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,041219af5230064ee9778667225f0e009cdb961330e386edb34e4fa9fddd0e5be7e2a12554227f613aaaa78938ddbbc99b923f9d181b8192dc4b816577e8f3b7e9,caf9141d1fca4d0f10683b5e86d2b41af5602f017991fe7348d44e8d7014115c,1\n",
+        "248,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,0460def130f190e6dc44f5eb8a59e12e7efb27db968c7fa6cc6d31785f066b41b1f1bb556ac4cd77033e7aa6c5ba16f47ebafb14975a7fd72dd9b7fe23116bca55,6539ec1c98fa75197ba07c678b26300b3da1fe407dd4c68b89457ed669082e06,1\n",
+        "249,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04f23f09bdb7d17289eb005975a757a39325b4df9b29e55ba2ca679b5ec0973ae918c881f3c7b6c12bed1ec54b837d08c5908e89bdcedd84b9177720378f789600,0b6619827cfa948d63f021e9eddb92f884fb5ce8a404bfe059e993fc23447a69,1\n",
+        "250,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,045dbec098c1b7de3e3e2e73d0b62cd49c877e1a0130a1b39eb2fd4dbd4426aa4ccbeee217591a8d76cc8deaf14dde52e3f401e53b30cbb9c1807910d827d0041d,2a53a561acf5caec6eb0d8aa40727942881a75d136899dfbff91528236926c39,1\n",
+        "251,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,041e70730dc4f39c8970182e1a29cc836b9e9d6cbd6fcaa8c0dc1062fed9a849693e7b9151f9c8a3345366f8221c8fb700e8c3a9aa7f0cc46a48864e1605592094,9b377716ff1d056dac8e392249eaec740d2f5aa62303f4baf6bb1b03b2a276c5,1\n",
+        "252,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04f428c9ae3e23eaf9c2a5b9a7e41efd1cffbf35f881bfc35694d9c05d1e312b10ef6da9023cfd2dd0cb7b9e2a77d644affe62a63fb0f29d45291c6861aa063c5c,0c0c6867669743082547aa94451feb362fa29fbaf228dfb3eaf375f1a5ec2fb3,1\n",
+        "253,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04b9a16d9a5b85a714e2bb2aa22b086a17404c7a3ff62452732347419c99e90bdad578b462f523994304b6afcf6944a9cc5d0ad1afad956475c8f2953c06b06b97,d11f9e32587fd3b6f4a2354812618b4b3b4a7539b8a223b388bb7437f8d138a5,1\n",
+        "254,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,048f659a163a58e9f900c1e9b34fb1cd61ffc9890267be3417c8afe79d57214da05cd5cb68a2b93da0dbe56c1cfc0dce8b6c3260e0c48379c6d2091f16b39221c0,4babf6368e0359b78614060241ece46facca3f52f5bbc47ac0b46a075b5dd3a0,1\n",
+        "255,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04d257f133f00a079f4e6778ea4a9bf42b9f231290431b5b93d7e8b0e35b48010650d6c6b46574d1efce03510b8db4a0981ce138c5bd8fe0e54c988c40c5fc9200,9627cc5c8d8b72278be89c32b52210173e6f4b8e2f48e460c6429f46f9f469ae,1\n",
+        "256,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,045ef2ac57c4e93cf78d8f86c35d413b98dc1902dd245affde5c16034afc7ea45547b3e9f77fbc5075bad03c418094f1aec1d03edeafa167fa6af83526552f7034,d2b178bc9bb16b5a91a100bb72e15a9639e050c034346061413ec20c4fcc9bbc,1\n",
+        "257,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04a7b513f96266414fa6ff439a35d8f09ab615db0bb6a3b1a120c217683f724b2342007a2c9feabcd6249a0d17acecd995e2a217fb5f07bec96938016e297efa52,6cdca0a731aff1ccfb1904a769cef79eba965fbab1cc64d2049d0df45dccd276,1\n",
+        "258,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,047743ab7248dae5f1a59ac6b0a136e9f1e51aff8bd45795ace5f8187a13edf9adbd9642078378bab5c6d484f9e1ce39675b72170bf39abc9be7942fc01fc435d7,bd15e97a7f49aa33e57b54140a75fffce71b788ce0faa334cf8b45623dcc818a,1\n",
+        "259,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,040e3aa971bacdace350dc0957fa5bde0946324eb139939d7fc1997c701effd04a4e6c3625d9564168d3a752961221a1de8cf5f3d603752a8c2e6277ac3a918c25,c8b5e8e7488857a2dde62c5fc21e4525ebaba0e06b5be83ec6e7dd771e15a01a,1\n",
+        "260,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,040f563e21bf9b24015a7cdbb6f000a692784ac2e4bc2715c76f684264a899c8240cab0d76e6b01cabe4f327429d11be115ed6dc0ca74f02c1b987a082f5af43a8,1c63a457509b148272687e6e442bde51982d41b0080d8c0c5eb714257af971e7,1\n",
+        "261,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,045da49f10249e4df3dbb4e31ece0b0ee9aa073f2588195aaae63e74f6567a774810b5dd61b6bf219e9eab30ef09c13fc184b3d09ff7a4e192bca8f5111c4163c7,73a1ac9ece354a930dfd9c77577b4f50acc0a78964ea0d7775631d64c709c4a2,1\n",
+        "262,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,046f72e6e5c6300679d3f14f0f6e590665643576ae8bbcb7c05b2f4a83e75e6ac3e712cb056ff034da340543c5da6997e65a3ab4cd39e997892bb92ee2c22b8167,fcaa406329bb74f995862cea7cecc7425c6bd4148ef1a9f46b5d42da5994556a,1\n",
+        "263,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,046b544df9168e7787db282e2ae01dd72306d9c9bc80f5ab38ce594766c3d929e967493ff601ca60862b47d3a0785c917e44584044e36023a54424015e58be5040,e49ff11d46b6c4b5dde528b04132d15c040e79f9b7151fbc650030988028cb87,1\n",
+        "264,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,041c31385b9db9b374e92499939ab0fd7e7eda464561eba89fcd7b4769814a8638a4764cf8ce97b5d143bb8eeb9e1b27287f2b73942ecdbc6359aafb1ee7a152c2,fc8f64eac1c7e688c52c467185de21914e8b253056d9e4be010ed0128f92a889,1\n",
+        "265,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04aabcf8b1443d6cbb1de129a0ffe09f60b23fd9d0a44b6bdf25bed7373fdbfd1db716bde7fe9f2f46de0b688e3025e029cff15244429ad4f83484f5dea4af8583,6b56d8a01a884319ab5fb9d890cacfc7aabd81ad938cb5eaae207c8c1aa06efb,1\n",
+        "266,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04e7cd580bd957915d527056832e37793ab3b082ddfad9372412e1908e5c16bbb6208601a970d5844b780d9246e9583eb35918c42ed695c07d52244037f0e31db5,2f64b5c8046d41a4e1d631ff23846bff956a4925a47f8534490a20b4b1918b9c,1\n",
+        "267,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,042a52db1fe246b71c79c0d0ac49a7d38de67b202995efbbd2a9cc525f6f36010368f494be27e0593e2d612f1fa10a9211437e6aa16e65d97735014072f0dcec94,63ac31e718b9a780a85f0670e1d3685bbe306e5f06fee282a8784700b503c124,1\n",
+        "268,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,041c50dc49fef708c4cdd62e766f9b60f784d51afee17a8fe9f3701b2fae55b7a5d10f0d9639d83dce8f26a869705a6d6d38e6d328f5685581142aec0dcd1f90e7,555c1917b770cebe6a98337a008ae3d8d04f571565327c93debf61ef90ddddd8,1\n",
+        "269,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,046d0aa1bc1cee6d07d045002c13290d0ca25ca3c8783343a525fac70472b92c62d6fba71174448b472cf172b0ca9e377f1a2603ba7ae1276d153b20c63e7d24bf,3a65a9200f8f96635912faa5e7859fa303a76a1c2a41ea97ef61aa39287700a9,1\n",
+        "270,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04f07e3d8be2ba54c6084141e1fd2b29cfd00d4e6dd6ffb115ed839b10bd8a422f42992cb9a5243897d55408e9bb556043318d87349af35dcc0975ed805c8fa2c9,bb7bb52da570ba58e05fd322f82d556c2d65b365db30815879f67f233b089b51,1\n",
+        "271,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,0443a9b90274dbd5f36dd29046fc8390008dde74513ce4c3e8892b236efff80c9dc71547152a5897dbe16957bd15d1a87d770496f814fe2921c8f33df04393c7f8,e8cae9944233b867eedf5902fc49ecd07e4c81c46279531e89520b74ba5370b5,1\n",
+        "272,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04e9af8e8c19da9d5c2f3b3c03b8e927c3cbe2d717f98f500972e56d82eb07c2b14e83fcaacadc26f8bb5e7b94741fe54f31275ebd6e1c969d7ec2fecead8a0dae,e72ad0cdb25f4307d1d834a5f792e9af64fd1b69a47041ec8fa46d526f419e4d,1\n",
+        "273,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,0433d9582b567aadbe59606fa6ffc11848e4947b5179597317776317b2b4ff65d0b4d8568dc843319cc04f4bf110496dee7c9229fc68cb0958f3cbd37ecca6990f,000197fbc260a84dbcbf88136aeaa79b03bb8949aefd2416bef63929ef789bf3,1\n",
+        "274,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04e21c0282adb1b2055fda744644c68612cfb0c68a70b9812d007f21a78f1adc4849f3e7644bc6633e2773a2f3cc5214fa7208e30afb3de992f077ee321569dc48,cdb18bf62670a853488ca510d8f55bab2918991424925bd9b74a821d2c6e7e3c,1\n",
+        "275,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04af27de0da6556e4e64588c9694afee9a84e1cbd0c388972df3a997f760bbcd903c5a02e161551f333d770559ab1af49bf8b68274896590939ce956d9913b676f,167303505d22cf9ef78c5b9687a5418fa9fb284f2b0ff68316288ecd7f2e2e09,1\n",
+        "276,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,040da41b82550b358ff474915d83104d41a83a12ef70589b9d392f0f30dc32429edc76163c8fe07a3f709cbd92da0bbfc5045f3db82aa5344cf1fd5b27fcd2f7a6,85600ff23c3cde26009fea9b6539664bf045056883728ab0d4498ea0a8f4a453,1\n",
+        "277,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,0419c844b8c7209026a0996a782983e1bd0f0de9255b86739be9bef08ea5475cc669a779ddf57747cf7d9a22f00ed8efc6e818af5827b750d665fee6d6d58a22e8,a3250a2bfb145ce86e706ac3ab2bf503a66486ac0b2f7522601c124b0e0f9c5b,1\n",
+        "278,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04bd07bd4326cdcabf42905efa4559a30e68cb215d40c9afb60ce02d4fda617579b927b5cba02d24fb9aafe1d429351e48bae9dd92d7bc7be15e5b8a30a86be13d,2d70cc8c8af01366051cc8359c2fc8f258757e2601fd8f3e08422a7b23bfeff5,1\n",
+        "279,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,040089dee27a60d071dabbaf58f3e56614dad3b7f9a8030769fd0463b3e6e0f03a147b4d6e7e7fd939b9b54dab458fd556ad8fdaf4da6c3909588c4e050ca74a67,cbe0c571d1080ea34ee20ad1bfd21ea5ecc442ead733fb4eee3c0d7b0cce9935,1\n",
+        "280,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,0442ede106cf85aef46df7e5dba8a8b00459317d9e766a7b77c299aa0e17dea142b6e9a86f4fc3e945d4323ba8e459f6b7b14c563a698c757a2d5f7b0bc301ede2,33320fc7917fe4e19280bfbfe16f223c037f7c2dc30c0fda98310740f57fe289,1\n",
+        "281,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04974b4316c5e7d1348b28dbc4fd61d8d3470de744c30f5be237f85f29969dea77b5f00b58b83cfc7bc51655465b4a28abe1ed3dbec20c6b4643aec85b95a5bec6,35c726ead66c39414fe0c24604df7838e5725d2fc1bd0853261e1de3338ecb4f,1\n",
+        "282,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,0459873d7523936a121b629e9870f930419f253a5767b9d0dc49716f2c50e17bd0163b71f2bf4318fbde1ceaa585450080eec28474cd18bf7c21d2d1bfde4ff677,66ea42fe6fd8741b37599bbdada3ec0e6b08c0b52ea67c29a33172f72742583c,1\n",
+        "283,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04bd85a79f81c4f9613e64fa347886437856c7358d1b69cf1e923d7742d82f9b6767d26918eaa8acb113a1daadaedc709742457303ebc23cdda5572613dc827703,2f8a502e4f440133e84fb625292cbeabe2cb79da73987c76d4fed864d1b1b762,1\n",
+        "284,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,043e6a4effc47c2f5926bb6b4acf2eac48b9524c47d511f816976796778600d6c5bfce593242a5985a977590f8d7485df3f953352957f3c17c13e94583d9c0e7b9,06436817d8928b77b73d16c5c3b35e243ad3ef2ab59ad047142c67a6d0923c84,1\n",
+        "285,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,049a4487fcfce8396688e7449e095fe803caa253d4bd7c66dbc6261cc9d9f883a50e5251bae29c5a5cdfa31bc61105671a88a018467398158d35b88829237c0bff,7e83fd2c3d713bc85d6d85d9078b3a0842824d410e8abde04da0fd71c7d94705,1\n",
+        "286,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04fed6ce127290c1291ca5ce64acb4e0f2f8905654d1d25ba57c1f74ab52f21f42963d31671c06b802169929525c4a1fdeff5b1eafab919dc2df6c52be84dfaef3,0e3dfdab606ebdc6428282acd443f189c99b3b483aa101fd8d6bed38aec59e02,1\n",
+        "287,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04f7cee5b55f1869f137dd707c8f8fb8965a2be5840c3149fb759695a4661b9c0d23c78c4e9647b0d6cb2f2602be73ff25cf3d09c96d892b5745fe5eca814aec91,f489f2bd93f76b8e41fc6b9f211bc599d49db1f17a38e95bab1d31b2a2b55829,1\n",
+        "288,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,042baaaec3b3e8d54a4e18f0960b947da2535e3cfcca2cfa8b7113aad8e3b6626f72f71e7c9e96042c1d39cc8f1139d5147c6f4fe62e23cf6df364b5f4d899f842,cc5738b49d30d5d02cf7e0c54a3de09b5b6f3c4dea91dd0679072a3562444c37,1\n",
+        "289,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04a51ab1238bc1bed25247e7d179c83a61ae2d4a9fe2288c363ae0eb7a77de432a3c6d35d82ba8017e6ca9041cc785a30703f7bc4427506e624ac5979d715421dd,89a11177d6907a81d47467093bf6a3cc8ba55dee05239b160a31a3000f5d807b,1\n",
+        "290,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,048b5ae8a0e55f30f509061315abae79ac480f88b44655f7269a385c81526884be262974a31a0e2322126c2d77b26b108abd81f8b952c458ccc95d46fb4924c7c0,2cb03c30b20037a5cf4d5b33574f3abac895bfab37867eb2ebed260e0929058d,1\n",
+        "291,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,045f60c77e474dd66c8135ee3dafc75ba644649824c72737542091ad469adbb685312c09c69b629d0436bf3bd6c6083ff2a87be484a73ef3a5d2c3e06b5d9b21b3,e54d487d0c4b12fe522af3e663ce316e632ba9d63a1f02a36fc5a82bf82731a4,1\n",
+        "292,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04e06eaa73f6feae45417d859bbad4bc404b2885bcd213ebace594e16f4970e0c411ed3323a3d7afc7076239884307f91849ed5f5e36b6171d309c81344c53e06d,ccea969d40fa42933f4fbdc4cabe2185f8a452996254c1f4e0dde5e14feeea8d,1\n",
+        "293,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,040f1c1b89e9fc6fc0faefc9109fc4a1247d9f54c7497b6cc975e6a5455bef410836cb3818548ac9b41e2b8336c3eb8d97075ae47e1827fa1ff93d4341d43c0c1d,eaae0e188c9427bf3c8b3ded772122204c328d5941e389d808e2724638f9aff8,1\n",
+        "294,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04577069e8284a95f51dcab919b0536657058971dab76217f8d3ae722a64092e26e51f68a722cc0397f4801401771e9a3d1988d4af76f14f9e2f9c36e0773e29c2,fea0cce1358f1ff40ffeaaffbf91b2e8d426d4e31e9627731ace3a122eab6b0d,1\n",
+        "295,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,042406a2759050b925dd4f814c5033e355548f42bbf1afb791c110f0031f29f68099d5f4b005de3927f165abeff196a28c7217fab1be2b5209c324e7d62d2dd687,837621ea4827bba0376aaa8aa66cfe144a2ff1e359dc619a06441d3e055f9771,1\n",
+        "296,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04ccaac61f35a27861183621642bc573af913356fb47cf582f0b5299099d6f6c6991f7272b83b738a7a5d30447c87f126a7d98ec72fa2609d0939d18db7ea7eb3a,63974ce6153762e5b364523cead93e8ce8bcc77dda56365d676136169fc4e39b,1\n",
+        "297,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,0401415917272f1984e7217a36fb311fd2904d41a6b13973f92aae3b90e85e4d56d97c822eb7b21a84d0d1be4867404a80c34867f43139dadcc3619e10b222562b,0a0488144bc36d690b62148ac3076047d46d48f7adbb0f34fee9a636295fe737,1\n",
+        "298,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04b2575d100c6fa056bcd137ab111b5315a8908c29243b84f3dc996d0e45764b9166cabeb41885588ec08b47257df58bd58f7dcd9e012e2669fa2f52e25767fc4c,1232165538a44268aa7c199c54d6d207c4ef3f5aa790c10c926a20752ca645ce,1\n",
+        "299,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04c17355ed30ccd6427f9685709021b25c11ed176e9610c479bcc4cc7552a738e61f75114761dba0ec60cd264bbab763c5d5abcc75cd8fb5651d0645179988cc6d,dcab5e874e4fb76bc4312528e9d76dfae56145922533089734110bf5653f4d77,1\n",
+        "300,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04341592390ccce485de8880f3d727f664c381914a1becec383b35586751fc81c2add71852b87016e1019cae7a9080e75ce0b0b8aac175d692d5e7b4dad088f5cc,4ce2701b2be63a0083a4c53f7a0bf04cf871654f5edb6f625e3ea5e7d0bdcc90,1\n",
+        "301,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04fa764b6b76a86c3b762120825d353a24766208c1f5cc0fe3fe7998026a2ec5c43bb2f948fd94cdaa5869b1e0e73a4d97035cc49357fb7b74d7ed0a2c5b8d54eb,7abef9765cca721320fbf8edcbef6d2ba25d17b70ffa1776029bc38fe677a12c,1\n",
+        "302,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04a71fbb617199bd585b4b66212ca33ca9e09370e6bf15c8ea0acefd9c8e945d06840f058863078e743e220ff99f23bbc1daa36835d4b1269f0a7536e63f06d853,5f61404dbbbc2867dff95c1f37ed44f4cb8fabcd223b03739d888308d13bc412,1\n",
+        "303,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,0413c8292d854d39451c0c63a802b8c03e4fcb875ef01239896295ba1c0f386975f82df197086fd86032cb36b69a27876dd75a8e9679f36ffc2210edb128d4be13,8d673a577e35bf9d5d00676c08b2c739617c46a052188403aa06dc714af6acc1,1\n",
+        "304,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,040cd9df415acc0c32fd4e3d6924ce53075b0452bf919a2ab2ebe26597570f1ecd5985d8d2c5df78fc100f87efb6dfa9543757bdffecf083dfcd1ecb38de6c23f8,a7835ffee0f2a69dfcf70d4e798dbe3ed32ba03cfddae5ddd11d8c0ac3d74f9b,1\n",
+        "305,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04d2dbea4046b23fd2b233d1ce31dceddb89b25f26c0627a9d2db3c5605c9cc99535bdc8de7451c1e27e97aa91402cce3882c71269d9cbdcb5d7ac0ceb911b9b6d,e98ea22209cd397edb6c319648c1eb24bc4d39598ab11995571926684ce2ceca,1\n",
+        "306,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04888fb044fb2b6caa60366bfa662adba479b8365a6555a29887d580f587086ba8482f4ec24082a48d6402afa1622143f26e61d91b7e30d6a4b223630ee10f70fb,91b65733860b1bdb9541d9f55895a3dbb3f13c199251d33006b6dcf90ac349ed,1\n",
+        "307,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,042e2bec134249379d57700301f3a58e4b395a4d28370d2a06e65e7ac89ed76ac697dc960bd795cdf4fbcfdd75149057b8e022331c7b5461f383ac589d764df333,1fdf7c5c48047a113e5e5d1b7ed593337e769231cca5c7110160e0c1b97f4256,1\n",
+        "308,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04c78cda7e3b9e1772ebed30b2b51dcf155a69a0fc504557836e25147cfb8127d2f8289cf38b033d3763c8f9f6c091787a3142fb83dff5719590282c6f852e0105,ba0abc3e71726cb51330489176357b81b8074d7690e4e82e9a3c00151e1fa318,1\n",
+        "309,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,041e3df4dd7fb7718cb0aa0dd72f8a25c83c4e804e7cbd48c5e965651f9e23bf4ef0ff40dd9796e4a9a5eddd2c4ca4ebd10990d8fb8918d12d53c76001afa9de7f,16e632f9752d36602c95ec274b32ad594f39f6ac3bd4b0b20f8637392142cef4,1\n",
+        "310,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04e5c5dc3fd88d85668b3b709fd6b4232f1f80949cbccb5588363e6c217a2b3ed88dbd0d6e3cc97f3081d16602aa3d1b655ee0791c87fcb5abe6217d8c8513807e,9eed4b96569f604a4d3f5af97499807111fc9888c458ece2e3000e245c2c02b0,1\n",
+        "311,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04021c41eceec24e0fba894ad7415a9598cbcd14fa6ca46e25575268a1d8e5bbc63f846c6a185fa3f23bb92c14e7e2cba8c74047c09af766f55ef0c907c80d9451,21ac32013838812621dbb584965bded6fc851d3a029810679bc57b2381bb7a7d,1\n",
+        "312,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,048e24192cd33335a114f5070266c014cb0d8c704d16d6042e89c17597bcd4e77ebdb4c5171704c2c09275c22a310e0c4fe092e4084856da99b94abbfa9f469f48,fc5978da01ca83e127dddf989a0358871b3c4ce0755bfb020633db467e21a53c,1\n",
+        "313,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,0431c90ae47a93d09a2352b6f3677e7975ea62aadedb56c118eb8b9f771e2dd9f5f2601fb9cca2304e594423cf48064dbed17ae40452f18be6ae018321911e8cb3,9f417341261aa45d396b0ccf2a3dee7a466ca47e3ce86ecd2071d9c4db08820e,1\n",
+        "314,809c461d8b39163537ff8f5ef5b977e4cdb980e70e38a7ee0b37cc876729e9ff,04d2f211cfab84e01c8e5544036234debe35ae103bb878d7abcea6825f753e03a385f7f1870e64f1262af67a25ef9880419f45608e7f9da6dee83f5f46ceb53dcb,f419febb32c254611adf569c2d583b17542b1538caa0001967f0a4bc34b8b789,1\n",
+        "315,0000000000000000000000000000000000000000000000000000000000000003,0431028f3377fc8f2b1967edaab90213acad0da9f50897f08f57537f78f116744743a1930189363bbde2ac4cbd1649cdc6f451add71dd2f16a8a867f2b17caa16b,85a0b58519b28e70a694ec5198f72c4bfdabaa30a70f7143b5b1cd7536f716ca,1\n",
+        "316,00000000ffffffffffffffffffffffffffffffffffffffffffffffffffffffff,0431028f3377fc8f2b1967edaab90213acad0da9f50897f08f57537f78f116744743a1930189363bbde2ac4cbd1649cdc6f451add71dd2f16a8a867f2b17caa16b,a329a7d80424ea2d6c904393808e510dfbb28155092f1bac284dceda1f13afe5,1\n",
+        "317,0100000000000000000000000000000000000000000000000000000000000000,0431028f3377fc8f2b1967edaab90213acad0da9f50897f08f57537f78f116744743a1930189363bbde2ac4cbd1649cdc6f451add71dd2f16a8a867f2b17caa16b,bd26d0293e8851c51ebe0d426345683ae94026aca545282a4759faa85fde6687,1\n",
+        "318,7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff,0431028f3377fc8f2b1967edaab90213acad0da9f50897f08f57537f78f116744743a1930189363bbde2ac4cbd1649cdc6f451add71dd2f16a8a867f2b17caa16b,ea9350b2490a2010c7abf43fb1a38be729a2de375ea7a6ac34ff58cc87e51b6c,1\n",
+        "319,8000000000000000000000000000000000000000000000000000000000000000,0431028f3377fc8f2b1967edaab90213acad0da9f50897f08f57537f78f116744743a1930189363bbde2ac4cbd1649cdc6f451add71dd2f16a8a867f2b17caa16b,34eed3f6673d340b6f716913f6dfa36b5ac85fa667791e2d6a217b0c0b7ba807,1\n",
+        "320,ffffffff00000000ffffffffffffffffbce6faada7179e83f3b9cac2fc632551,0431028f3377fc8f2b1967edaab90213acad0da9f50897f08f57537f78f116744743a1930189363bbde2ac4cbd1649cdc6f451add71dd2f16a8a867f2b17caa16b,1354ce6692c9df7b6fc3119d47c56338afbedccb62faa546c0fe6ed4959e41c3,1\n",
+        "321,ffffffff00000000ffffffffffffffffbce6faada7179e84f3a9cac2fc632551,0431028f3377fc8f2b1967edaab90213acad0da9f50897f08f57537f78f116744743a1930189363bbde2ac4cbd1649cdc6f451add71dd2f16a8a867f2b17caa16b,fe7496c30d534995f0bf428b5471c21585aaafc81733916f0165597a55d12cb4,1\n",
+        "322,ffffffff00000000ffffffffffffffffbce6faada7179e84f3b1cac2fc632551,0431028f3377fc8f2b1967edaab90213acad0da9f50897f08f57537f78f116744743a1930189363bbde2ac4cbd1649cdc6f451add71dd2f16a8a867f2b17caa16b,348bf8042e4edf1d03c8b36ab815156e77c201b764ed4562cfe2ee90638ffef5,1\n",
+        "323,ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac1fc632551,0431028f3377fc8f2b1967edaab90213acad0da9f50897f08f57537f78f116744743a1930189363bbde2ac4cbd1649cdc6f451add71dd2f16a8a867f2b17caa16b,6e4ec5479a7c20a537501700484f6f433a8a8fe53c288f7a25c8e8c92d39e8dc,1\n",
+        "324,ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc6324f3,0431028f3377fc8f2b1967edaab90213acad0da9f50897f08f57537f78f116744743a1930189363bbde2ac4cbd1649cdc6f451add71dd2f16a8a867f2b17caa16b,f7407d61fdf581be4f564621d590ca9b7ba37f31396150f9922f1501da8c83ef,1\n",
+        "325,ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632533,0431028f3377fc8f2b1967edaab90213acad0da9f50897f08f57537f78f116744743a1930189363bbde2ac4cbd1649cdc6f451add71dd2f16a8a867f2b17caa16b,82236fd272208693e0574555ca465c6cc512163486084fa57f5e1bd2e2ccc0b3,1\n",
+        "326,ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632543,0431028f3377fc8f2b1967edaab90213acad0da9f50897f08f57537f78f116744743a1930189363bbde2ac4cbd1649cdc6f451add71dd2f16a8a867f2b17caa16b,06537149664dba1a9924654cb7f787ed224851b0df25ef53fcf54f8f26cd5f3f,1\n",
+        "327,ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc63254b,0431028f3377fc8f2b1967edaab90213acad0da9f50897f08f57537f78f116744743a1930189363bbde2ac4cbd1649cdc6f451add71dd2f16a8a867f2b17caa16b,f2b38539bce995d443c7bfeeefadc9e42cc2c89c60bf4e86eac95d51987bd112,1\n",
+        "328,ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc63254e,0431028f3377fc8f2b1967edaab90213acad0da9f50897f08f57537f78f116744743a1930189363bbde2ac4cbd1649cdc6f451add71dd2f16a8a867f2b17caa16b,85a0b58519b28e70a694ec5198f72c4bfdabaa30a70f7143b5b1cd7536f716ca,1\n",
+        "329,ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc63254f,0431028f3377fc8f2b1967edaab90213acad0da9f50897f08f57537f78f116744743a1930189363bbde2ac4cbd1649cdc6f451add71dd2f16a8a867f2b17caa16b,027b013a6f166db655d69d643c127ef8ace175311e667dff2520f5b5c75b7659,1\n",
+        "330,2a265f8bcbdcaf94d58519141e578124cb40d64a501fba9c11847b28965bc737,04023819813ac969847059028ea88a1f30dfbcde03fc791d3a252c6b41211882eaf93e4ae433cc12cf2a43fc0ef26400c0e125508224cdb649380f25479148a4ad,4d4de80f1534850d261075997e3049321a0864082d24a917863366c0724f5ae3,1\n",
+        "331,313f72ff9fe811bf573176231b286a3bdb6f1b14e05c40146590727a71c3bccd,04cc11887b2d66cbae8f4d306627192522932146b42f01d3c6f92bd5c8ba739b06a2f08a029cd06b46183085bae9248b0ed15b70280c7ef13a457f5af382426031,831c3f6b5f762d2f461901577af41354ac5f228c2591f84f8a6e51e2e3f17991,1\n",
+        "332,7e4aa54f714bf01df85c50269bea3a86721f84afe74f7b41ea58abcf3474e88d,0400000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000,,0\n",
+        "333,7e4aa54f714bf01df85c50269bea3a86721f84afe74f7b41ea58abcf3474e88d,0400000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001,,0\n",
+        "334,7e4aa54f714bf01df85c50269bea3a86721f84afe74f7b41ea58abcf3474e88d,040000000000000000000000000000000000000000000000000000000000000000ffffffff00000001000000000000000000000000fffffffffffffffffffffffe,,0\n",
+        "335,7e4aa54f714bf01df85c50269bea3a86721f84afe74f7b41ea58abcf3474e88d,040000000000000000000000000000000000000000000000000000000000000000ffffffff00000001000000000000000000000000ffffffffffffffffffffffff,,0\n",
+        "336,7e4aa54f714bf01df85c50269bea3a86721f84afe74f7b41ea58abcf3474e88d,0400000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000000,,0\n",
+        "337,7e4aa54f714bf01df85c50269bea3a86721f84afe74f7b41ea58abcf3474e88d,0400000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000001,,0\n",
+        "338,7e4aa54f714bf01df85c50269bea3a86721f84afe74f7b41ea58abcf3474e88d,040000000000000000000000000000000000000000000000000000000000000001ffffffff00000001000000000000000000000000fffffffffffffffffffffffe,,0\n",
+        "339,7e4aa54f714bf01df85c50269bea3a86721f84afe74f7b41ea58abcf3474e88d,040000000000000000000000000000000000000000000000000000000000000001ffffffff00000001000000000000000000000000ffffffffffffffffffffffff,,0\n",
+        "340,7e4aa54f714bf01df85c50269bea3a86721f84afe74f7b41ea58abcf3474e88d,04ffffffff00000001000000000000000000000000fffffffffffffffffffffffe0000000000000000000000000000000000000000000000000000000000000000,,0\n",
+        "341,7e4aa54f714bf01df85c50269bea3a86721f84afe74f7b41ea58abcf3474e88d,04ffffffff00000001000000000000000000000000fffffffffffffffffffffffe0000000000000000000000000000000000000000000000000000000000000001,,0\n",
+        "342,7e4aa54f714bf01df85c50269bea3a86721f84afe74f7b41ea58abcf3474e88d,04ffffffff00000001000000000000000000000000fffffffffffffffffffffffeffffffff00000001000000000000000000000000fffffffffffffffffffffffe,,0\n",
+        "343,7e4aa54f714bf01df85c50269bea3a86721f84afe74f7b41ea58abcf3474e88d,04ffffffff00000001000000000000000000000000fffffffffffffffffffffffeffffffff00000001000000000000000000000000ffffffffffffffffffffffff,,0\n",
+        "344,7e4aa54f714bf01df85c50269bea3a86721f84afe74f7b41ea58abcf3474e88d,04ffffffff00000001000000000000000000000000ffffffffffffffffffffffff0000000000000000000000000000000000000000000000000000000000000000,,0\n",
+        "345,7e4aa54f714bf01df85c50269bea3a86721f84afe74f7b41ea58abcf3474e88d,04ffffffff00000001000000000000000000000000ffffffffffffffffffffffff0000000000000000000000000000000000000000000000000000000000000001,,0\n",
+        "346,7e4aa54f714bf01df85c50269bea3a86721f84afe74f7b41ea58abcf3474e88d,04ffffffff00000001000000000000000000000000ffffffffffffffffffffffffffffffff00000001000000000000000000000000fffffffffffffffffffffffe,,0\n",
+        "347,7e4aa54f714bf01df85c50269bea3a86721f84afe74f7b41ea58abcf3474e88d,04ffffffff00000001000000000000000000000000ffffffffffffffffffffffffffffffff00000001000000000000000000000000ffffffffffffffffffffffff,,0\n",
+        "348,7e4aa54f714bf01df85c50269bea3a86721f84afe74f7b41ea58abcf3474e88d,,,0\n",
+        "349,6f953faff3599e6c762d7f4cabfeed092de2add1df1bc5748c6cbb725cf35458,02fd4bf61763b46581fd9174d623516cf3c81edd40e29ffa2777fb6cb0ae3ce535,,0\n",
+        "350,d27edf0ff5b6b6b465753e7158370332c153b468a1be087ad0f490bdb99e5f02,03efdde3b32872a9effcf3b94cbf73aa7b39f9683ece9121b9852167f4e3da609b,,0\n",
+        "351,d27edf0ff5b6b6b465753e7158370332c153b468a1be087ad0f490bdb99e5f03,02efdde3b32872a9effcf3b94cbf73aa7b39f9683ece9121b9852167f4e3da609b,,0\n",
+        "352,95ead84540c2d027aa3130ff1b47888cc1ed67e8dda46156e71ce0991791e835,02c49524b2adfd8f5f972ef554652836e2efb2d306c6d3b0689234cec93ae73db5,,0\n",
+        "353,a8681ef67fb1f189647d95e8db00c52ceef6d41a85ba0a5bd74c44e8e62c8aa4,0318f9bae7747cd844e98525b7ccd0daf6e1d20a818b2175a9a91e4eae5343bc98,,0\n",
+        "354,a8681ef67fb1f189647d95e8db00c52ceef6d41a85ba0a5bd74c44e8e62c8aa5,0218f9bae7747cd844e98525b7ccd0daf6e1d20a818b2175a9a91e4eae5343bc98,,0\n",
+        "355,95ead84540c2d027aa3130ff1b47888cc1ed67e8dda46156e71ce0991791e834,03c49524b2adfd8f5f972ef554652836e2efb2d306c6d3b0689234cec93ae73db5,,0\n",
+    );
 }
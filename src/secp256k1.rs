@@ -74,8 +74,77 @@
 //! and `s` starts), and that the two `r` and `s` values are still in the
 //! proper range (i.e. lower than the curve order).
 //!
+//! `PrivateKey::sign_hash_recoverable()` computes the same signature as
+//! `sign_hash()` but also returns a "recovery id" (0 to 3), with which
+//! the public key can be rebuilt from the signature and the hashed
+//! message, with `PublicKey::recover_from_hash()`.
+//!
+//! ECDH key exchange is performed with `PrivateKey::ECDH()`, which
+//! returns the x-coordinate of the shared point (as in NIST SP 800-56A
+//! and RFC 5903), or `PrivateKey::ECDH_hashed()`, which returns the
+//! SHA-256 hash of the compressed shared point (as the default
+//! `secp256k1_ecdh()` function of libsecp256k1). The peer public key is
+//! provided encoded (compressed or uncompressed); if it is invalid, this
+//! is reported through the returned status, and a key unguessable by
+//! outsiders is returned instead of the shared secret.
+//!
+//! Signatures can be converted to and from the ASN.1 DER format (a
+//! `SEQUENCE` of two `INTEGER` values, as used in X.509 and TLS) with
+//! the `encode_der()` and `decode_der()` functions; decoding is strict
+//! and rejects BER-only encodings. `normalize_s()` converts a signature
+//! to its "low-S" form (s is replaced with n - s if it is greater than
+//! n/2), and `PublicKey::verify_hash_strict()` rejects signatures which
+//! are not in that form (this is required by Bitcoin for transaction
+//! signatures).
+//!
+//! Schnorr signatures, as specified by [BIP-340], are generated with
+//! `PrivateKey::sign_schnorr()`. BIP-340 uses "x-only" public keys,
+//! represented by the `XOnlyPublicKey` structure (32 bytes: only the x
+//! coordinate is encoded, the point being the one with an even y
+//! coordinate). Signatures are verified with `XOnlyPublicKey::verify()`,
+//! or, more efficiently for many signatures, with
+//! `XOnlyPublicKey::verify_batch()`. The key tweaking used by Taproot
+//! ([BIP-341]) is provided by `XOnlyPublicKey::tweak_add()` and
+//! `XOnlyPublicKey::taproot_tweak()` (and their `PrivateKey`
+//! counterparts).
+//!
+//! Adaptor signatures are also supported. `PrivateKey::presign_schnorr()`
+//! computes a BIP-340 pre-signature relatively to an adaptor point `T`,
+//! which is verified with `XOnlyPublicKey::verify_schnorr_presig()`;
+//! `SchnorrPreSignature::adapt()` turns it into a valid signature with
+//! the discrete logarithm `t` of `T`, and `SchnorrPreSignature::extract()`
+//! recovers `t` from the final signature. The ECDSA equivalent is the
+//! one-time verifiably encrypted signature (`PrivateKey::presign_ecdsa()`,
+//! `PublicKey::verify_ecdsa_presig()` and the `EcdsaPreSignature`
+//! structure), which includes a discrete logarithm equality proof.
+//!
+//! # Truncated Signatures
+//!
+//! As with curve P-256, `PublicKey::verify_trunc_hash()` supports
+//! _truncated_ ECDSA signatures: a 64-byte signature is provided, but
+//! the last few bits (between 8 and 32) are considered to have been
+//! reused for encoding other data, and thus are ignored. The signature
+//! must first be processed with `PrivateKey::prepare_truncate()` (which
+//! does not use the private key) so that truncation removes the
+//! high-order bits of the `s` value. The verification function then
+//! rebuilds the complete, original signature, with a baby-step/giant-step
+//! search over the x coordinates of candidate points; the search space
+//! has size about 2^(rm-1), so that the cost grows as about 2^(rm/2)
+//! point additions (asymptotically, cost doubles for every 2 removed
+//! bits). When 24 bits are ignored, the verification cost is about 30
+//! times the cost of verifying an untruncated signature; for 32 bits,
+//! it is about 180 to 200 times that cost.
+//!
+//! As with P-256, no dynamic memory allocation is needed; the scratch
+//! buffer (1 kB, 16 kB or 256 kB, for up to 16, 24 or 32 ignored bits)
+//! is either allocated on the stack (`verify_trunc_hash()`) or provided
+//! by the caller (`verify_trunc_hash_buf()`, which should be used on
+//! systems with a small stack).
+//!
 //! [FIPS 186-4]: https://csrc.nist.gov/publications/detail/fips/186/4/final
 //! [RFC 6979]: https://datatracker.ietf.org/doc/html/rfc6979
+//! [BIP-340]: https://github.com/bitcoin/bips/blob/master/bip-0340.mediawiki
+//! [BIP-341]: https://github.com/bitcoin/bips/blob/master/bip-0341.mediawiki
 
 // Projective/fractional coordinates traditionally use uppercase letters,
 // using lowercase only for affine coordinates.
@@ -83,10 +152,11 @@
 
 use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 use super::field::{GFsecp256k1, ModInt256};
-use sha2::{Sha512, Digest};
+use sha2::{Sha256, Sha512, Digest};
 use super::{CryptoRng, RngCore};
 use core::convert::TryFrom;
 
+
 /// A point on the short Weierstraß curve secp256k1.
 #[derive(Clone, Copy, Debug)]
 pub struct Point {
@@ -111,7 +181,7 @@ impl Scalar {
 ///
 /// Source slice MUST have length at least 32 (only the first 32 bytes
 /// are accessed).
-fn bswap32(x: &[u8]) -> [u8; 32] {
+pub(crate) fn bswap32(x: &[u8]) -> [u8; 32] {
     let mut y = [0u8; 32];
     for i in 0..32 {
         y[i] = x[31 - i];
@@ -119,6 +189,15 @@ fn bswap32(x: &[u8]) -> [u8; 32] {
     y
 }
 
+/// Returns `true` if the provided scalar is greater than n/2 (with n
+/// being the curve order), i.e. if the "s" value of a signature is not
+/// in "low-S" form.
+fn is_high_s(s: &Scalar) -> bool {
+    // Since n is odd, s > n/2 if and only if s > n - s (as integers).
+    // Comparison is performed in big-endian convention.
+    bswap32(&s.encode()) > bswap32(&(-s).encode())
+}
+
 impl Point {
 
     // Curve equation is: y^2 = x^3 + b  (for a given constant b)
@@ -163,6 +242,12 @@ impl Point {
     /// Curve equation parameter b.
     const B: GFsecp256k1 = GFsecp256k1::w64be(0, 0, 0, 7);
 
+    /// 4*b
+    const B4: GFsecp256k1 = GFsecp256k1::w64be(0, 0, 0, 28);
+
+    /// 8*b
+    const B8: GFsecp256k1 = GFsecp256k1::w64be(0, 0, 0, 56);
+
     /// Tries to decode a point.
     ///
     /// This function accepts the following encodings and lengths:
@@ -330,6 +415,71 @@ impl Point {
         b
     }
 
+    /// Tries to decode a point from its x coordinate only (BIP-340
+    /// "x-only" format).
+    ///
+    /// The input must have length exactly 32 bytes, and contain the
+    /// unsigned big-endian encoding of the x coordinate (which must be
+    /// lower than the field modulus). Of the two points with that x
+    /// coordinate, the one with an even y coordinate is selected
+    /// (this is the `lift_x()` function of BIP-340).
+    ///
+    /// On success, this structure is set to the decoded point, and
+    /// 0xFFFFFFFF is returned. On failure, this structure is set to the
+    /// neutral point, and 0x00000000 is returned. The neutral point
+    /// cannot be encoded in x-only format.
+    pub fn set_decode_xonly(&mut self, buf: &[u8]) -> u32 {
+        if buf.len() != 32 {
+            *self = Self::NEUTRAL;
+            return 0;
+        }
+        let mut tmp = [0u8; 33];
+        tmp[0] = 0x02;
+        tmp[1..33].copy_from_slice(buf);
+        self.set_decode(&tmp)
+    }
+
+    /// Tries to decode a point from its x coordinate only (BIP-340
+    /// "x-only" format).
+    ///
+    /// The input must have length exactly 32 bytes, and contain the
+    /// unsigned big-endian encoding of the x coordinate (which must be
+    /// lower than the field modulus). Of the two points with that x
+    /// coordinate, the one with an even y coordinate is returned. If
+    /// there is no such point, then `None` is returned.
+    pub fn decode_xonly(buf: &[u8]) -> Option<Point> {
+        let mut P = Point::NEUTRAL;
+        if P.set_decode_xonly(buf) != 0 {
+            Some(P)
+        } else {
+            None
+        }
+    }
+
+    /// Encodes the x coordinate of this point (32 bytes, unsigned
+    /// big-endian).
+    ///
+    /// This is the BIP-340 "x-only" format; the y coordinate is not
+    /// encoded, and decoding will yield the point with the same x
+    /// coordinate and an even y coordinate (which is either this point,
+    /// or its opposite). If the point is the neutral then `[0u8; 32]` is
+    /// returned.
+    pub fn encode_xonly(self) -> [u8; 32] {
+        let mut b = [0u8; 32];
+        b.copy_from_slice(&self.encode_compressed()[1..33]);
+        b
+    }
+
+    /// Tests whether the affine y coordinate of this point is odd.
+    ///
+    /// Returned value is 0xFFFFFFFF for odd y, 0x00000000 for even y.
+    /// The neutral point is reported as having an even y coordinate.
+    pub fn has_odd_y(self) -> u32 {
+        let iZ = GFsecp256k1::ONE / self.Z;  // this is 0 if Z = 0
+        let y = self.Y * iZ;                 // 0 for the neutral
+        ((y.encode()[0] & 0x01) as u32).wrapping_neg()
+    }
+
     /// Gets the affine (x, y) coordinates for this point.
     ///
     /// Values (x, y, r) are returned, with x and y being field elements,
@@ -345,7 +495,7 @@ impl Point {
         let bb = self.encode_uncompressed();
 
         // First byte is 0x00 for the neutral, 0x04 for other points.
-        let r = (((bb[0] as i32) - 1) >> 8) as u32;
+        let r = !((((bb[0] as i32) - 1) >> 8) as u32);
 
         // The values necessarily decode successfully.
         let (x, _) = GFsecp256k1::decode32(&bswap32(&bb[1..33]));
@@ -1384,6 +1534,310 @@ impl Point {
         let T = self.mul_add_mulgen_vartime(&(-k), s);
         T.equals(*R) != 0
     }
+
+    /// Maximum number of (R, P) pairs processed by a single call to
+    /// `batch_mul_vartime()`.
+    const BATCH_LEN: usize = 8;
+
+    /// Given points `R[i]` and `P[i]`, 128-bit integers `a[i]`, and
+    /// scalars `u[i]`, returns the sum of all `a[i]*R[i] + u[i]*P[i]`.
+    ///
+    /// The four slices must have the same length, which must not exceed
+    /// `Self::BATCH_LEN`. All multiplications share the same sequence of
+    /// doublings (Straus's method), and the `u[i]` are split into
+    /// half-width integers with the endomorphism, so that the whole
+    /// computation involves only about 130 doublings. This function is
+    /// meant to support batch verification of Schnorr signatures.
+    ///
+    /// THIS FUNCTION IS NOT CONSTANT-TIME; it shall be used only with
+    /// public data.
+    fn batch_mul_vartime(R: &[Self], a: &[u128], P: &[Self], u: &[Scalar])
+        -> Self
+    {
+        const N: usize = Point::BATCH_LEN;
+        let n = R.len();
+        assert!(n <= N && a.len() == n && P.len() == n && u.len() == n);
+
+        // For each pair, compute the windows:
+        //   winR[j][i] = (2*i+1)*R[j]
+        //   win0[j][i] = (2*i+1)*sgn(u0)*P[j]
+        //   win1[j][i] = (2*i+1)*sgn(u1)*zeta(P[j])
+        // and the 5-bit wNAF recodings of a[j], u0 and u1 (with
+        // u[j] = u0 + u1*theta).
+        let mut winR = [[Self::NEUTRAL; 8]; N];
+        let mut win0 = [[Self::NEUTRAL; 8]; N];
+        let mut win1 = [[Self::NEUTRAL; 8]; N];
+        let mut sdR = [[0i8; 130]; N];
+        let mut sd0 = [[0i8; 130]; N];
+        let mut sd1 = [[0i8; 130]; N];
+        for j in 0..n {
+            winR[j][0] = R[j];
+            let Q = R[j].double();
+            for i in 1..8 {
+                winR[j][i] = winR[j][i - 1] + Q;
+            }
+
+            let (u0, s0, u1, s1) = Self::split_theta(&u[j]);
+            win0[j][0] = P[j];
+            win0[j][0].set_condneg(s0);
+            let Q = win0[j][0].double();
+            for i in 1..8 {
+                win0[j][i] = win0[j][i - 1] + Q;
+            }
+            for i in 0..8 {
+                win1[j][i] = win0[j][i].zeta();
+                win1[j][i].set_condneg(s0 ^ s1);
+            }
+
+            sdR[j] = Self::recode_u128_NAF(a[j]);
+            sd0[j] = Self::recode_u128_NAF(u0);
+            sd1[j] = Self::recode_u128_NAF(u1);
+        }
+
+        let mut T = Self::NEUTRAL;
+        let mut zz = true;
+        let mut ndbl = 0u32;
+        for i in (0..130).rev() {
+            // We have one more doubling to perform.
+            ndbl += 1;
+
+            // If all digits at this position are zero, then we can loop
+            // immediately.
+            let mut nz = false;
+            for j in 0..n {
+                nz |= (sdR[j][i] | sd0[j][i] | sd1[j][i]) != 0;
+            }
+            if !nz {
+                continue;
+            }
+
+            // Apply accumulated doubles.
+            if zz {
+                zz = false;
+            } else {
+                T.set_xdouble(ndbl);
+            }
+            ndbl = 0u32;
+
+            // Process digits.
+            for j in 0..n {
+                T.add_digit_vartime(&winR[j], sdR[j][i]);
+                T.add_digit_vartime(&win0[j], sd0[j][i]);
+                T.add_digit_vartime(&win1[j], sd1[j][i]);
+            }
+        }
+
+        if !zz && ndbl > 0 {
+            T.set_xdouble(ndbl);
+        }
+        T
+    }
+
+    /// Adds `e*P` to this point, for a wNAF digit `e` (odd, or zero) and
+    /// a window `win` containing the odd multiples of `P` (`win[i]`
+    /// contains `(2*i+1)*P`).
+    ///
+    /// THIS FUNCTION IS NOT CONSTANT-TIME; it shall be used only with
+    /// public data.
+    #[inline(always)]
+    fn add_digit_vartime(&mut self, win: &[Self; 8], e: i8) {
+        if e > 0 {
+            self.set_add(&win[e as usize >> 1]);
+        } else if e < 0 {
+            self.set_sub(&win[(-e) as usize >> 1]);
+        }
+    }
+
+    /// From points P0 and P1, returns the affine x coordinates of P0, P1
+    /// and P1 - P0, in that order.
+    ///
+    /// For the point-at-infinity (which does not have a defined x
+    /// coordinate), value 0 is used (there is no point with x = 0 on the
+    /// curve, since 7 is not a square modulo p).
+    ///
+    /// These values are what `x_sequence_vartime()` expects.
+    pub fn to_x_affine_diff(P0: Self, P1: Self)
+        -> (GFsecp256k1, GFsecp256k1, GFsecp256k1)
+    {
+        // For the neutral, X = 0 and Z = 0; we set Z to 1 so that the
+        // inversion works and the normalized x is 0.
+        let Q = P1 - P0;
+        let x0 = P0.X;
+        let mut z0 = P0.Z;
+        z0.set_cond(&GFsecp256k1::ONE, P0.isneutral());
+        let x1 = P1.X;
+        let mut z1 = P1.Z;
+        z1.set_cond(&GFsecp256k1::ONE, P1.isneutral());
+        let xq = Q.X;
+        let mut zq = Q.Z;
+        zq.set_cond(&GFsecp256k1::ONE, Q.isneutral());
+
+        let z0z1 = z0 * z1;
+        let mut k = GFsecp256k1::ONE / (z0z1 * zq);
+        let xq = xq * k * z0z1;
+        k *= zq;
+        let x1 = x1 * k * z0;
+        k *= z1;
+        let x0 = x0 * k;
+        (x0, x1, xq)
+    }
+
+    /// Given the x coordinates of points P0, P1 and Q (with Q = P1 - P0),
+    /// computes the x coordinates of points P\_i = P0 + i*Q for i = 0
+    /// to n-1, where n = `xx.len()`.
+    ///
+    /// The values are stored in the provided slice `xx[]`. Moreover, the
+    /// x coordinates of P\_n and P\_(n+1) are returned.
+    ///
+    /// For the purposes of this function, the x coordinate of the
+    /// point-at-infinity (the curve neutral element) is set to 0, both
+    /// in inputs and outputs. There is no non-infinity point with x = 0
+    /// on secp256k1. The x coordinates of P0, P1 and Q can be obtained
+    /// from the `to_x_affine_diff()` function.
+    /// 
+    /// THIS FUNCTION IS NOT CONSTANT-TIME; it shall be used only with
+    /// public data.
+    pub fn x_sequence_vartime(x0: GFsecp256k1, x1: GFsecp256k1,
+        xq: GFsecp256k1, xx: &mut [GFsecp256k1])
+        -> (GFsecp256k1, GFsecp256k1)
+    {
+        // We use the same x-line arithmetics as for curve P-256 (see
+        // the p256 module for details), with curve parameter a = 0.
+        // Since there is no point with x = 0 on the curve, we never
+        // need the special case formulas for x(P_i) = 0; internally,
+        // the point-at-infinity is represented with Z = 0.
+        //
+        //    Input:
+        //       x(P_i)     = X0 / Z0
+        //       x(P_(i+1)) = X1 / Z1
+        //       x(Q)       = xq
+        //       Q = P_(i+1) - P_i
+        //    
+        //    Assumptions:
+        //       Q != inf
+        //       P_i != inf
+        //       P_(i+1) != inf
+        //    
+        //    Output:
+        //       x(P_(i+2)) = X2 / Z2
+        //       X2 = Z0*((X1*xq)^2 - 4*b*(X1 + xq*Z1)*Z1)
+        //       Z2 = X0*(X1 - xq*Z1)^2
+        //
+        // If P_i = inf, then P_(i+2) = 2*Q, obtained with the
+        // pseudo-doubling formulas:
+        //    x(2*Q) = X' / Z'
+        //    X' = xq^4 - 8*b*xq
+        //    Z' = 4*(xq^3 + b)
+        //
+        // If P_(i+1) = inf, then P_(i+2) = Q.
+        fn xadd(X0: GFsecp256k1, Z0: GFsecp256k1,
+            X1: GFsecp256k1, Z1: GFsecp256k1, xq: GFsecp256k1)
+            -> (GFsecp256k1, GFsecp256k1)
+        {
+            let C = xq * Z1;                  // C = xq*Z1
+            let E = (X1 + C) * Z1;            // E = (X1 + xq*Z1)*Z1
+            let F = (X1 * xq).square();       // F = (X1*xq)^2
+            let G = E * Point::B4;            // G = 4*b*(X1 + xq*Z1)*Z1
+            let H = (X1 - C).square();        // H = (X1 - xq*Z1)^2
+            let X2 = Z0 * (F - G);
+            let Z2 = X0 * H;
+            (X2, Z2)
+        }
+
+        let n = xx.len();
+        if n == 0 {
+            return (x0, x1);
+        }
+
+        // Special case: Q is the point-at-infinity.
+        if xq.iszero() != 0 {
+            xx.fill(x0);
+            return (x0, x0);
+        }
+
+        // We keep x(P_i) = X0 / Z0 and x(P_(i+1)) = X1 / Z1; in
+        // fractional representation, we use Z = 0 for the point-at-infinity.
+        let mut X0 = x0;
+        let mut Z0 = if x0.iszero() != 0 {
+            GFsecp256k1::ZERO
+        } else {
+            GFsecp256k1::ONE
+        };
+        let mut X1 = x1;
+        let mut Z1 = if x1.iszero() != 0 {
+            GFsecp256k1::ZERO
+        } else {
+            GFsecp256k1::ONE
+        };
+
+        let mut i = 0;
+        loop {
+            // As in the P-256 implementation, we process batches of up
+            // to 198 values (plus the two output points for the last
+            // batch) so that batch normalization can use stack buffers.
+            let blen = if (n - i) < 198 { n - i } else { 198 };
+            let mut XX = [GFsecp256k1::ZERO; 200];
+            let mut ZZ = [GFsecp256k1::ZERO; 200];
+            for j in 0..blen {
+                // Write the current P_i in the array.
+                XX[j] = X0;
+                ZZ[j] = Z0;
+
+                // Compute P_(i+2).
+                let (X2, Z2) = if Z0.iszero() != 0 {
+                    // P_i = inf
+                    // P_(i+1) = Q
+                    // P_(i+2) = 2*Q
+                    let xq3 = xq.square() * xq;
+                    let Xt = xq * (xq3 - Self::B8);
+                    let Zt = (xq3 + Self::B).mul4();
+                    (Xt, Zt)
+                } else if Z1.iszero() != 0 {
+                    // P_(i+1) = inf
+                    // P_(i+2) = Q
+                    (xq, GFsecp256k1::ONE)
+                } else {
+                    // General case
+                    xadd(X0, Z0, X1, Z1, xq)
+                };
+                (X0, Z0) = (X1, Z1);
+                (X1, Z1) = (X2, Z2);
+            }
+
+            // We also add the two output points when processing the
+            // final batch.
+            let ilen = if (i + blen) == n {
+                XX[blen] = X0;
+                ZZ[blen] = Z0;
+                XX[blen + 1] = X1;
+                ZZ[blen + 1] = Z1;
+                blen + 2
+            } else {
+                blen
+            };
+
+            // Normalize the batch to affine coordinates. For the
+            // point-at-infinity, Z = 0, and batch_invert() yields 0 as
+            // "inverse", hence x = 0, which matches our convention.
+            GFsecp256k1::batch_invert(&mut ZZ[0..ilen]);
+            for j in 0..ilen {
+                let x = XX[j] * ZZ[j];
+                if j < blen {
+                    xx[i + j] = x;
+                } else {
+                    XX[j] = x;
+                }
+            }
+
+            // Batch process, get to the next one.
+            i += blen;
+            if i == n {
+                // We are finished; the two output points were normalized
+                // with the last batch.
+                return (XX[blen], XX[blen + 1]);
+            }
+        }
+    }
 }
 
 impl Add<Point> for Point {
@@ -1682,7 +2136,7 @@ impl SubAssign<&Point> for Point {
 /// A secp256k1 private key simply wraps around a scalar.
 #[derive(Clone, Copy, Debug)]
 pub struct PrivateKey {
-    x: Scalar,   // secret scalar
+    pub(crate) x: Scalar,   // secret scalar
 }
 
 /// A secp256k1 public key simply wraps around a curve point.
@@ -1790,6 +2244,29 @@ impl PrivateKey {
     /// Note: this does not follow the exact process of RFC 6979, but the
     /// same principle is applied.
     pub fn sign_hash(self, hv: &[u8], extra_rand: &[u8]) -> [u8; 64] {
+        self.sign_hash_recoverable(hv, extra_rand).0
+    }
+
+    /// Signs a hash value with ECDSA, and also returns the recovery id.
+    ///
+    /// This function computes exactly the same signature as `sign_hash()`
+    /// (with the same parameters), and additionally returns the recovery
+    /// id, an integer in the 0 to 3 range which allows rebuilding the
+    /// public key from the signature and the hashed message (see
+    /// `PublicKey::recover_from_hash()`):
+    ///
+    ///  - bit 0 of the recovery id is the parity of y(R);
+    ///
+    ///  - bit 1 is set if x(R) is not lower than the curve order n (this
+    ///    happens only with negligible probability).
+    ///
+    /// Here, R = k*G is the point computed during signature generation,
+    /// and r = x(R) mod n. The "Ethereum-style" 65-byte signature format
+    /// is the concatenation of the 64-byte signature with a final byte
+    /// that encodes the recovery id (often with 27 added to it).
+    pub fn sign_hash_recoverable(self, hv: &[u8], extra_rand: &[u8])
+        -> ([u8; 64], u8)
+    {
 
         // Convert the input hash value into an integer modulo n:
         //  - If hv.len() > 32, keep only the leftmost 32 bytes.
@@ -1821,9 +2298,15 @@ impl PrivateKey {
         loop {
             // R = k*G; then encode x(R), and decode-reduce as a scalar
             let R = Point::mulgen(&k);
-            let xR_le = bswap32(&R.encode_compressed()[1..33]);
+            let Rc = R.encode_compressed();
+            let xR_le = bswap32(&Rc[1..33]);
             let r = Scalar::decode_reduce(&xR_le);
 
+            // Recovery id: parity of y(R), and whether x(R) >= n
+            // (decode32() reports a failure exactly in that case).
+            let (_, cx) = Scalar::decode32(&xR_le);
+            let recid = (Rc[0] & 1) | ((!cx & 2) as u8);
+
             // Compute s.
             let s = (h + self.x * r) / k;
 
@@ -1832,7 +2315,7 @@ impl PrivateKey {
                 let mut sig = [0u8; 64];
                 sig[..32].copy_from_slice(&bswap32(&r.encode()));
                 sig[32..].copy_from_slice(&bswap32(&s.encode()));
-                return sig;
+                return (sig, recid);
             }
 
             // It is extremely improbable that either r or s is zero, and
@@ -1843,6 +2326,151 @@ impl PrivateKey {
             k.set_cond(&Scalar::ONE, k.iszero());
         }
     }
+
+    /// Prepares a signature value for truncation.
+    ///
+    ///  - Signature is parsed into (r,s) values (unsigned big-endian).
+    ///
+    ///  - If s >= 2^255 then it is replaced with -s (mod n).
+    ///
+    ///  - s is reencoded in little-endian format.
+    ///
+    /// A failure is reported (`None` is returned) if r or s is
+    /// out-of-range (invalid signature) or if r < p-n (with p = modulus,
+    /// n = curve order). The latter may theoretically happen with
+    /// probability about 2^(-127.6), i.e. never in practice.
+    ///
+    /// This function does not use the private key; it was defined in the
+    /// `PrivateKey` structure only because in a typical context where
+    /// truncated signatures are relevant, this operation should happen
+    /// on the signer's side (i.e. after signature generation but before
+    /// transmission to the verifier). See `PublicKey::verify_trunc_hash()`
+    /// for the verification side.
+    pub fn prepare_truncate(sig: &[u8]) -> Option<[u8; 64]> {
+        // Ensure that the signature has length exactly 64 bytes
+        // (Shorter lengths are possible if the source integers happen
+        // to be both lower than 2^248).
+        let siglen = sig.len();
+        if (siglen & 1) != 0 || siglen == 0 || siglen > 64 {
+            return None;
+        }
+        let numlen = siglen >> 1;
+        let mut tmp = [0u8; 64];
+        tmp[(32 - numlen)..32].copy_from_slice(&sig[..numlen]);
+        tmp[(64 - numlen)..64].copy_from_slice(&sig[numlen..]);
+
+        // Decode each of r and s with unsigned big-endian convention;
+        // we obtain the high and low halves of each as 128-bit integers.
+        let rh = u128::from_be_bytes(*<&[u8; 16]>::try_from(
+            &tmp[ 0..16]).unwrap());
+        let rl = u128::from_be_bytes(*<&[u8; 16]>::try_from(
+            &tmp[16..32]).unwrap());
+        let mut sh = u128::from_be_bytes(*<&[u8; 16]>::try_from(
+            &tmp[32..48]).unwrap());
+        let mut sl = u128::from_be_bytes(*<&[u8; 16]>::try_from(
+            &tmp[48..64]).unwrap());
+
+        // Check ranges:
+        //   p-n <= r < n
+        //   0 < s < n
+        // On secp256k1, p-n = 2^128 + PMNL (with PMNL < 2^128).
+        const NH: u128 = 340282366920938463463374607431768211454u128;
+        const NL: u128 = 248144347276217270074328348468568277313u128;
+        const PMNL: u128 = 92138019644721193389046258958904965870u128;
+
+        if rh == 0 || (rh == 1 && rl < PMNL)
+            || rh > NH || (rh == NH && rl >= NL)
+            || (sh == 0 && sl == 0) || sh > NH || (sh == NH && sl >= NL)
+        {
+            return None;
+        }
+
+        // If s does not fit in 255 bits, then replace it with n - s.
+        if tmp[32] >= 0x80 {
+            sl = NL.wrapping_sub(sl);
+            sh = NH.wrapping_sub(sh);
+            if sl > NL {
+                sh = sh.wrapping_sub(1);
+            }
+        }
+
+        // Reencode r and s. r was not changed from the source signature;
+        // s was possibly changed, and we want s in little-endian format.
+        let mut nsig = [0u8; 64];
+        nsig[..32].copy_from_slice(&tmp[..32]);
+        nsig[32..48].copy_from_slice(&sl.to_le_bytes());
+        nsig[48..64].copy_from_slice(&sh.to_le_bytes());
+
+        Some(nsig)
+    }
+
+    /// ECDH key exchange.
+    ///
+    /// Given this private key, and the provided peer public key (encoded),
+    /// return the 32-byte shared secret. The shared secret is the
+    /// x-coordinate of the shared point, in unsigned big-endian
+    /// convention, as specified by NIST SP 800-56A (primitive "ECC CDH").
+    /// The peer public key may use the compressed (33 bytes) or
+    /// uncompressed (65 bytes) format. The process fails if the `peer_pk`
+    /// slice does not have one of these two lengths, or does not encode
+    /// a valid curve point, or encodes the neutral point. On success,
+    /// the shared secret is returned along with 0xFFFFFFFFu32. On failure,
+    /// a different value (unguessable by outsiders) is returned, along
+    /// with 0x00000000u32.
+    ///
+    /// Processing is constant-time. If the `peer_pk` slice has length
+    /// exactly 33 or 65 bytes, then outsiders cannot know through
+    /// timing-based side-channels whether the process succeeded or failed
+    /// (though the encoding format of the peer public key is not hidden).
+    /// The raw x-coordinate is not uniformly distributed and should be
+    /// used only as input to a key derivation function.
+    pub fn ECDH(self, peer_pk: &[u8]) -> ([u8; 32], u32) {
+        let (Q, ok) = self.ecdh_point(peer_pk);
+        let mut shared = [0u8; 32];
+        shared[..].copy_from_slice(&Q.encode_compressed()[1..33]);
+        (shared, ok)
+    }
+
+    /// ECDH key exchange, with a hashed output.
+    ///
+    /// This function is similar to `ECDH()`, but the returned shared key
+    /// is the SHA-256 hash of the compressed encoding (33 bytes) of the
+    /// shared point. This matches the default output of the
+    /// `secp256k1_ecdh()` function of libsecp256k1. Failure conditions,
+    /// implicit rejection, and constant-time properties are the same as
+    /// with `ECDH()`.
+    pub fn ECDH_hashed(self, peer_pk: &[u8]) -> ([u8; 32], u32) {
+        let (Q, ok) = self.ecdh_point(peer_pk);
+        let mut key = [0u8; 32];
+        key[..].copy_from_slice(&Sha256::digest(Q.encode_compressed()));
+        (key, ok)
+    }
+
+    // Computes the ECDH shared point with the provided peer public key.
+    // If the peer public key is invalid, then the returned point is
+    // instead the product of the generator with a secret scalar derived
+    // from our private key and the peer bytes, so that the output is
+    // unknown by outsiders but still deterministic relatively to the
+    // received peer bytes; the returned status is then 0x00000000.
+    pub(crate) fn ecdh_point(self, peer_pk: &[u8]) -> (Point, u32) {
+        // Decode peer public key.
+        let mut Q = Point::NEUTRAL;
+        let mut ok = Q.set_decode(peer_pk);
+        ok &= !Q.isneutral();
+
+        // Alternate scalar for the failure case. A custom prefix
+        // ("crrl secp256k1 ECDH" in ASCII) is used for domain separation.
+        let mut sh = Sha512::new();
+        sh.update(b"crrl secp256k1 ECDH");
+        sh.update(self.encode());
+        sh.update(peer_pk);
+        let mut s = Scalar::decode_reduce(&sh.finalize()[..]);
+        s.set_cond(&Scalar::ONE, s.iszero());
+
+        Q.set_cond(&Point::BASE, !ok);
+        s.set_cond(&self.x, ok);
+        (Q * s, ok)
+    }
 }
 
 impl PublicKey {
@@ -1940,86 +2568,1381 @@ impl PublicKey {
         // the one that was received.
         return r.equals(rr) != 0;
     }
-}
 
-// ========================================================================
+    /// Verifies a signature on a given hashed message, in strict mode.
+    ///
+    /// This function is similar to `verify_hash()`, except that the
+    /// signature MUST have length exactly 64 bytes, and MUST be in
+    /// "low-S" form (i.e. s must not be greater than n/2, see
+    /// `normalize_s()`); otherwise, it is rejected.
+    ///
+    /// Note: this function is not constant-time; it assumes that the
+    /// public key and signature value are public data.
+    pub fn verify_hash_strict(self, sig: &[u8], hv: &[u8]) -> bool {
+        if sig.len() != 64 {
+            return false;
+        }
+        let (s, cs) = Scalar::decode32(&bswap32(&sig[32..]));
+        if cs == 0 || is_high_s(&s) {
+            return false;
+        }
+        self.verify_hash(sig, hv)
+    }
 
-// We hardcode known multiples of the points G, (2^65)*G, (2^130)*G
-// and (2^195)*G, with G being the conventional base point. These are
-// used to speed mulgen() operations up. The points are stored in affine
-// coordinates, i.e. their Z coordinate is implicitly equal to 1.
+    /// Recovers the public key from a signature and a hashed message.
+    ///
+    /// The signature (`sig`) MUST have length exactly 64 bytes (r and s,
+    /// both over 32 bytes, unsigned big-endian convention), and
+    /// `recid` is the recovery id, as returned by
+    /// `PrivateKey::sign_hash_recoverable()` (in the 0 to 3 range). The
+    /// hashed message `hv` is handled in the same way as in
+    /// `verify_hash()`. If the signature and recovery id are such that
+    /// a public key can be obtained, then that key is returned; the
+    /// signature is then valid relatively to that key and the provided
+    /// hashed message. Otherwise, `None` is returned.
+    ///
+    /// Note: this function is not constant-time; it assumes that the
+    /// signature, recovery id and hashed message are public data.
+    pub fn recover_from_hash(sig: &[u8], recid: u8, hv: &[u8])
+        -> Option<Self>
+    {
+        // Decode r and s; zeros and out-of-range values are rejected.
+        if sig.len() != 64 || recid > 3 {
+            return None;
+        }
+        let (r, cr) = Scalar::decode32(&bswap32(&sig[..32]));
+        if cr == 0 || r.iszero() != 0 {
+            return None;
+        }
+        let (s, cs) = Scalar::decode32(&bswap32(&sig[32..]));
+        if cs == 0 || s.iszero() != 0 {
+            return None;
+        }
 
-/// A curve point (non-infinity) in affine coordinates.
-#[derive(Clone, Copy, Debug)]
-struct PointAffine {
-    x: GFsecp256k1,
-    y: GFsecp256k1,
-}
+        // Rebuild the compressed encoding of R. Its x coordinate is r,
+        // or r + n if bit 1 of the recovery id is set (in which case the
+        // value must not overflow; the decoding of R then verifies that
+        // it is lower than the field modulus p).
+        let mut Rc = [0u8; 33];
+        Rc[0] = 0x02 | (recid & 1);
+        Rc[1..].copy_from_slice(&sig[..32]);
+        if (recid & 2) != 0 {
+            // n is odd, so its little-endian encoding is that of n - 1
+            // with the low bit set.
+            let mut nb = (-Scalar::ONE).encode();
+            nb[0] |= 1;
+            let mut cc = 0u32;
+            for i in 0..32 {
+                cc += (Rc[32 - i] as u32) + (nb[i] as u32);
+                Rc[32 - i] = cc as u8;
+                cc >>= 8;
+            }
+            if cc != 0 {
+                return None;
+            }
+        }
+        let R = Point::decode(&Rc)?;
 
-// Points i*G for i = 1 to 16, in affine coordinates.
-static PRECOMP_G: [PointAffine; 16] = [
-    // G * 1
-    PointAffine { x: GFsecp256k1::w64be(0x79BE667EF9DCBBAC, 0x55A06295CE870B07,
-                                        0x029BFCDB2DCE28D9, 0x59F2815B16F81798),
-                  y: GFsecp256k1::w64be(0x483ADA7726A3C465, 0x5DA4FBFC0E1108A8,
-                                        0xFD17B448A6855419, 0x9C47D08FFB10D4B8) },
-    // G * 2
-    PointAffine { x: GFsecp256k1::w64be(0xC6047F9441ED7D6D, 0x3045406E95C07CD8,
-                                        0x5C778E4B8CEF3CA7, 0xABAC09B95C709EE5),
-                  y: GFsecp256k1::w64be(0x1AE168FEA63DC339, 0xA3C58419466CEAEE,
-                                        0xF7F632653266D0E1, 0x236431A950CFE52A) },
-    // G * 3
-    PointAffine { x: GFsecp256k1::w64be(0xF9308A019258C310, 0x49344F85F89D5229,
-                                        0xB531C845836F99B0, 0x8601F113BCE036F9),
-                  y: GFsecp256k1::w64be(0x388F7B0F632DE814, 0x0FE337E62A37F356,
-                                        0x6500A99934C2231B, 0x6CB9FD7584B8E672) },
-    // G * 4
-    PointAffine { x: GFsecp256k1::w64be(0xE493DBF1C10D80F3, 0x581E4904930B1404,
-                                        0xCC6C13900EE07584, 0x74FA94ABE8C4CD13),
-                  y: GFsecp256k1::w64be(0x51ED993EA0D455B7, 0x5642E2098EA51448,
-                                        0xD967AE33BFBDFE40, 0xCFE97BDC47739922) },
-    // G * 5
-    PointAffine { x: GFsecp256k1::w64be(0x2F8BDE4D1A072093, 0x55B4A7250A5C5128,
-                                        0xE88B84BDDC619AB7, 0xCBA8D569B240EFE4),
-                  y: GFsecp256k1::w64be(0xD8AC222636E5E3D6, 0xD4DBA9DDA6C9C426,
-                                        0xF788271BAB0D6840, 0xDCA87D3AA6AC62D6) },
-    // G * 6
-    PointAffine { x: GFsecp256k1::w64be(0xFFF97BD5755EEEA4, 0x20453A14355235D3,
-                                        0x82F6472F8568A18B, 0x2F057A1460297556),
-                  y: GFsecp256k1::w64be(0xAE12777AACFBB620, 0xF3BE96017F45C560,
-                                        0xDE80F0F6518FE4A0, 0x3C870C36B075F297) },
-    // G * 7
-    PointAffine { x: GFsecp256k1::w64be(0x5CBDF0646E5DB4EA, 0xA398F365F2EA7A0E,
-                                        0x3D419B7E0330E39C, 0xE92BDDEDCAC4F9BC),
-                  y: GFsecp256k1::w64be(0x6AEBCA40BA255960, 0xA3178D6D861A54DB,
-                                        0xA813D0B813FDE7B5, 0xA5082628087264DA) },
-    // G * 8
-    PointAffine { x: GFsecp256k1::w64be(0x2F01E5E15CCA351D, 0xAFF3843FB70F3C2F,
-                                        0x0A1BDD05E5AF888A, 0x67784EF3E10A2A01),
-                  y: GFsecp256k1::w64be(0x5C4DA8A741539949, 0x293D082A132D13B4,
-                                        0xC2E213D6BA5B7617, 0xB5DA2CB76CBDE904) },
-    // G * 9
-    PointAffine { x: GFsecp256k1::w64be(0xACD484E2F0C7F653, 0x09AD178A9F559ABD,
-                                        0xE09796974C57E714, 0xC35F110DFC27CCBE),
-                  y: GFsecp256k1::w64be(0xCC338921B0A7D9FD, 0x64380971763B61E9,
-                                        0xADD888A4375F8E0F, 0x05CC262AC64F9C37) },
-    // G * 10
-    PointAffine { x: GFsecp256k1::w64be(0xA0434D9E47F3C862, 0x35477C7B1AE6AE5D,
-                                        0x3442D49B1943C2B7, 0x52A68E2A47E247C7),
-                  y: GFsecp256k1::w64be(0x893ABA425419BC27, 0xA3B6C7E693A24C69,
-                                        0x6F794C2ED877A159, 0x3CBEE53B037368D7) },
-    // G * 11
-    PointAffine { x: GFsecp256k1::w64be(0x774AE7F858A9411E, 0x5EF4246B70C65AAC,
-                                        0x5649980BE5C17891, 0xBBEC17895DA008CB),
-                  y: GFsecp256k1::w64be(0xD984A032EB6B5E19, 0x0243DD56D7B7B365,
-                                        0x372DB1E2DFF9D6A8, 0x301D74C9C953C61B) },
-    // G * 12
-    PointAffine { x: GFsecp256k1::w64be(0xD01115D548E7561B, 0x15C38F004D734633,
-                                        0x687CF4419620095B, 0xC5B0F47070AFE85A),
-                  y: GFsecp256k1::w64be(0xA9F34FFDC815E0D7, 0xA8B64537E17BD815,
-                                        0x79238C5DD9A86D52, 0x6B051B13F4062327) },
-    // G * 13
-    PointAffine { x: GFsecp256k1::w64be(0xF28773C2D975288B, 0xC7D1D205C3748651,
+        // Convert the input hash value into an integer modulo n.
+        let mut tmp = [0u8; 32];
+        if hv.len() >= 32 {
+            tmp[..].copy_from_slice(&hv[..32]);
+        } else {
+            tmp[32 - hv.len() .. 32].copy_from_slice(hv);
+        }
+        let h = Scalar::decode_reduce(&bswap32(&tmp));
+
+        // Q = (s*R - h*G)/r
+        let w = Scalar::ONE / r;
+        let Q = R.mul_add_mulgen_vartime(&(s * w), &-(h * w));
+        if Q.isneutral() != 0 {
+            return None;
+        }
+        Some(Self { point: Q })
+    }
+
+    /// Size (in 64-bit words) of the scratch buffer which is sufficient
+    /// for `verify_trunc_hash_buf()` to run at full speed, regardless of
+    /// the number of ignored bits.
+    pub const TRUNC_BUF_LEN: usize = 32769;
+
+    /// Verifies a truncated signature on a given hashed message.
+    ///
+    /// The signature (`sig`) MUST have length 64 bytes and MUST have
+    /// been prepared with `PrivateKey::prepare_truncate()`. The last
+    /// `rm` bits are ignored (i.e. the last `floor(rm/8)` bytes are
+    /// ignored, as well as the top `rm%8` bits of the last non-ignored
+    /// byte.
+    ///
+    /// The hashed message is provided as `hv`; it is nominally the
+    /// output of a suitable hash function (often SHA-256) computed over
+    /// the actual message. This function can tolerate arbitrary hash
+    /// output lengths; however, for proper security, the hash output
+    /// must not be too short, and it must be an actual hash function
+    /// output, not raw structured data.
+    ///
+    /// Returned value on success is the complete, untruncated signature
+    /// (reencoded in the standard all-big-endian format); otherwise,
+    /// `None` is returned (if a rebuilt signature value is returned,
+    /// then it has been verified to be valid and there is no need to
+    /// validate it again).
+    ///
+    /// This function uses a scratch buffer allocated on the stack, whose
+    /// size depends on `rm`, so that the reconstruction always runs at
+    /// full speed: 129 words (about 1 kB) for `rm` <= 16, 2049 words
+    /// (about 16 kB) for `rm` <= 24, and `TRUNC_BUF_LEN` words (256 kB)
+    /// for larger values of `rm`. On systems with a small stack, use
+    /// `verify_trunc_hash_buf()` with a caller-provided buffer instead.
+    ///
+    /// Note: this function is not constant-time; it assumes that the
+    /// public key and signature value are public data.
+    pub fn verify_trunc_hash(self,
+        sig: &[u8], rm: usize, hv: &[u8]) -> Option<[u8; 64]>
+    {
+        // For rm ignored bits, 2^floor((rm-1)/2) + 1 words are enough
+        // for full speed. Each buffer size is handled by a distinct
+        // non-inlined function, so that the stack usage for small
+        // values of rm is not inflated by the largest buffer.
+        if rm <= 16 {
+            self.verify_trunc_hash_stack::<129>(sig, rm, hv)
+        } else if rm <= 24 {
+            self.verify_trunc_hash_stack::<2049>(sig, rm, hv)
+        } else {
+            self.verify_trunc_hash_stack::<{ Self::TRUNC_BUF_LEN }>(
+                sig, rm, hv)
+        }
+    }
+
+    #[inline(never)]
+    fn verify_trunc_hash_stack<const N: usize>(self,
+        sig: &[u8], rm: usize, hv: &[u8]) -> Option<[u8; 64]>
+    {
+        let mut scratch = [0u64; N];
+        self.verify_trunc_hash_buf(sig, rm, hv, &mut scratch)
+    }
+
+    /// Verifies a truncated signature on a given hashed message, using
+    /// a caller-provided scratch buffer.
+    ///
+    /// This function is identical to `verify_trunc_hash()`, except that
+    /// the scratch buffer is provided by the caller, as the `scratch` slice.
+    /// That buffer MUST contain at least two elements. If it contains at
+    /// least `TRUNC_BUF_LEN` elements, then the reconstruction runs at
+    /// full speed; with a smaller buffer, the search uses a smaller table
+    /// and more lookups, and is slower: for 32 ignored bits, the cost
+    /// roughly doubles whenever the buffer size is divided by 2, below
+    /// 2^14 words or so. For `rm` ignored bits, a buffer of
+    /// 2^floor((rm-1)/2) + 1 words is sufficient for full speed (129
+    /// words for `rm` = 16, 2049 words for `rm` = 24). As an example of
+    /// the slowdown with a smaller buffer, on a 64-bit x86 system, a
+    /// 129-word (1 kB) buffer makes the verification about 6 times
+    /// slower for `rm` = 24, and a 2049-word (16 kB) buffer makes it
+    /// about 10 times slower for `rm` = 32, than with a full-size
+    /// buffer.
+    ///
+    /// The buffer contents on input are ignored; on output, they are
+    /// unspecified. Only public data is written into the buffer.
+    ///
+    /// Note: this function is not constant-time; it assumes that the
+    /// public key and signature value are public data.
+    pub fn verify_trunc_hash_buf(self,
+        sig: &[u8], rm: usize, hv: &[u8], scratch: &mut [u64])
+        -> Option<[u8; 64]>
+    {
+        // Check that we removed at least 8 bits. We also prevent trying
+        // to remove more than 32 bits because the cost would be excessive;
+        // it also allows keeping U_i[] indices over 16 bits.
+        assert!((8..=32).contains(&rm));
+        assert!(scratch.len() >= 2);
+
+        // Signature array must have length 64 bytes exactly; but
+        // we ignore the last rm bits. We copy the non-ignored bits
+        // to sig2, and clear the rest.
+        if sig.len() != 64 {
+            return None;
+        }
+        let n = (519 - rm) >> 3;
+        let mut sig2 = [0u8; 64];
+        sig2[0..n].copy_from_slice(&sig[0..n]);
+        if (rm & 7) != 0 {
+            sig2[n - 1] &= 0xFFu8 >> (rm & 7);
+        }
+
+        // First half of the signature is r, which is supposed to be the
+        // x coordinate of the point R (in big-endian format). We assume
+        // that the x value was not altered when reduced modulo n (since
+        // n is very close to p, alteration is very improbable; moreover,
+        // this assumption cannot make us accept an invalid signature).
+        // If no matching point R can be found, then we report a failure.
+        let mut R_enc = [0u8; 33];
+        R_enc[0] = 0x02;
+        R_enc[1..33].copy_from_slice(&sig2[..32]);
+        let R = Point::decode(&R_enc)?;
+
+        // We also want r as a scalar; and we are expected to reject
+        // signatures with r = 0.
+        let (r, cr) = Scalar::decode32(&bswap32(&sig2[..32]));
+        if cr == 0 || r.iszero() != 0 {
+            return None;
+        }
+
+        // Second half of the signature is s0 (in little-endian). Since we
+        // ensured that at least one top bit was cleared, the value cannot be
+        // out-of-range.
+        let (s0, _) = Scalar::decode32(&sig2[32..64]);
+
+        // Convert the input hash value into an integer modulo n.
+        let mut tmp = [0u8; 32];
+        if hv.len() >= 32 {
+            tmp[..].copy_from_slice(&hv[..32]);
+        } else {
+            tmp[32 - hv.len() .. 32].copy_from_slice(hv);
+        }
+        let h = Scalar::decode_reduce(&bswap32(&tmp));
+
+        // Signature verification equation can be written as:
+        //   s*R = h*G + r*Q
+        // Note that our R might be -R instead, since we only had the x
+        // coordinate of the point. The signature is also valid if the
+        // alternate equation matches:
+        //   s*R = -(h*G + r*Q)
+        //
+        // We know that s fits on 255 bits (this was ensured by the
+        // prepare_truncate() function), so we can write:
+        //   s = s0 + s1*2^n
+        // with n = 256 - rm (the number of bits of s that we received),
+        // s0 the value that we could decode from the truncated signature
+        // (0 <= s0 < 2^n), and s1 such that:
+        //   0 <= s1 < 2^m
+        // for m = 255 - n.
+        // Let a and b such that:
+        //   s1 = a + 2^k*b
+        // with:
+        //   k = ceil(m/2)   (or more, if the scratch buffer is small)
+        //   0 <= a < 2^k
+        //   0 <= b < +2^(m-k)
+        //
+        // Let:
+        //   U = (2^n)*R
+        //   V = h*G + r*Q
+        //   U_i = s0*R + i*(2^k)*U  for 0 <= i <= +2^(m-k)
+        //   V_j = V - j*U           for 0 <= j <= +2^k
+        // If s*R = h*G + r*Q, then:
+        //   s0*R + (a + b*2^k)*(2^n)*R = V
+        // hence:
+        //   U_b = V_a
+        // If instead we used the wrong sign for R, and the equation
+        // really is s*R = -(h*G + r*Q), then:
+        //   -(s0*R + (a + b*2^k)*(2^n)*R) = V
+        // hence:
+        //   -(s0*R + (b + 1)*(2^k)*U) + (2^k - a)*U = V
+        //   -U_(b+1) = V_(2^k-a)
+        // (Note that we included 2^(m-k) in the range of i, hence U_(b+1)
+        // is part of the list of computed U_i values; similarly, we
+        // includes 2^k in the range of j, hence V_(2^k-a) is also part of
+        // the list of computed V_j values.)
+        //
+        // Since points P and -P have the same x coordinate, it follows
+        // that in both cases, one of the U_i and one of the V_j will have
+        // the same x coordinate. If U_i and V_j have the same x coordinate,
+        // the the potential solutions (a,b) are (j,i) and (2^k-a,i-1).
+        // We can reconstruct s1 as:
+        //   s1 = a + b*2^k = j + i*2^k (first case)
+        //   s1 = 2^k - j + (i - 1)*2^k = -j + i*2^k (second case)
+        //
+        // We compute all U_i, and extract 64 bits out of each x coordinate
+        // (48 bits of the coordinate, and the 16-bit index i); these
+        // values are stored in the scratch buffer and sorted so that we
+        // may do efficient binary searches. We then compute all V_j and
+        // perform lookups. We proceed by batches since normalization to
+        // affine coordinates is much more efficient that way. We do not
+        // keep the complete x coordinates of the U_i: a match on 48 bits
+        // is confirmed by recomputing the points U_i and V_j; false
+        // positives are rare enough not to impact performance.
+        //
+        // The table contains 2^(m-k) + 1 values; the cost of building it
+        // is about the same as the cost of computing the 2^k + 1 values
+        // V_j (and sorting is comparatively inexpensive), so that we get
+        // optimal performance with k = ceil(m/2). If the provided buffer
+        // is too small, then we increase k accordingly.
+
+        let n = 256 - rm;      // s0 has size n bits
+        let m = 255 - n;
+        let mut k = (m + 1) >> 1;
+        while (1usize << (m - k)) >= scratch.len() {
+            k += 1;
+        }
+        let U = R.xdouble(n as u32);
+        let V = self.point.mul_add_mulgen_vartime(&r, &h);
+        let Rb = s0 * R;
+
+        // 0 <= i <= I
+        // 0 <= j <= J
+        let I = 1usize << (m - k);
+        let J = 1usize << k;
+
+        // Compute all U_i = s0*R + i*(2^k)*U for 0 <= i <= +2^(m-k)
+        // Since 8 <= rm <= 32, we have 7 <= m <= 31, hence m-k <= 15.
+        // We only need the x coordinates, from which we extract the
+        // search keys.
+        let Uk = U.xdouble(k as u32);
+        let ux = &mut scratch[..(I + 1)];
+        let (mut x0, mut x1, xq) = Point::to_x_affine_diff(Rb, Rb + Uk);
+        let mut ii = 0;
+        while ii <= I {
+            let mut Ui = [GFsecp256k1::ZERO; 198];
+            let blen = if (I + 1 - ii) < 198 { I + 1 - ii } else { 198 };
+            (x0, x1) = Point::x_sequence_vartime(x0, x1, xq, &mut Ui[..blen]);
+            for (i, x) in Ui[..blen].iter().enumerate() {
+                let mut tmp = [0u8; 8];
+                tmp[0..2].copy_from_slice(&((ii + i) as u16).to_le_bytes());
+                tmp[2..8].copy_from_slice(&x.encode()[0..6]);
+                ux[ii + i] = u64::from_le_bytes(tmp);
+            }
+            ii += blen;
+        }
+        ux.sort_unstable();
+
+        // For all V_j = V - j*U, look for the x coordinates among those
+        // of the U_i; we use the ux[] array for that, and confirm any
+        // match by recomputing the points. We compute the V_j by batches
+        // of 100 so that normalization to affine coordinates is properly
+        // optimized, but we still stop early when possible.
+        let (mut x0, mut x1, xq) = Point::to_x_affine_diff(V, V - U);
+        let mut jj = 0;
+        loop {
+            let mut Vj = [GFsecp256k1::ZERO; 100];
+            let mut blen = J + 1 - jj;
+            if blen > 100 {
+                blen = 100;
+            }
+            (x0, x1) = Point::x_sequence_vartime(x0, x1, xq, &mut Vj);
+            for (j, xv) in Vj[..blen].iter().enumerate() {
+                // Extract the search key from the x coordinate of V_j.
+                // We set the low 16 bits to 1, so that potential matches
+                // are keys which are lower than this value.
+                let mut tmp = [0u8; 8];
+                tmp[0] = 0xFF;
+                tmp[1] = 0xFF;
+                tmp[2..8].copy_from_slice(&xv.encode()[0..6]);
+                let x = u64::from_le_bytes(tmp);
+
+                // Perform a search in ux[].
+                if x < ux[0] {
+                    continue;
+                }
+                let mut i1 = 0usize;
+                let mut i2 = ux.len();
+                while (i2 - i1) > 1 {
+                    let im = (i1 + i2) >> 1;
+                    if x < ux[im] {
+                        i2 = im;
+                    } else {
+                        i1 = im;
+                    }
+                }
+
+                // Potential matches are lower than x but have the same high
+                // 48 bits.
+                loop {
+                    let xm = ux[i1];
+                    if ((xm ^ x) >> 16) != 0 {
+                        break;
+                    }
+                    let i = (xm & 0xFFFF) as usize;
+
+                    // We do not have the complete U_i and V_j (we
+                    // only kept 48 bits of their x coordinates) but we
+                    // can recompute them cheaply using the values we
+                    // already have:
+                    //   Rb = s0*R
+                    //   U = (2^n)*R
+                    //   Uk = (2^k)*(2^n)*R
+                    //   V = h*G + r*Q
+                    // If U_i and V_j have the same x coordinate, then
+                    // one of the following holds:
+                    //   U_i = V_j
+                    //  -U_i = V_j
+                    // First case yields:
+                    //   s0*R + i*(2^k)*U = h*G + r*Q - j*U
+                    // i.e.:
+                    //   s = s0 + (j + i*2^k)*2^n
+                    // Second case yields:
+                    //  -(s0*R + i*(2^k)*U) = h*G + r*Q - j*U
+                    // i.e.:
+                    //   s = s0 + (-j + i*2^k)*2^n
+                    // If neither holds, then this was a false positive
+                    // (only 48 bits matched) and we continue the search.
+                    let j = jj + j;  // the "real" j
+                    let zUi = Rb + (i as u64) * Uk;
+                    let zVj = V - (j as u64) * U;
+                    let ni = Scalar::from_w64le(0, 0, 0,
+                        (i as u64) << (n + k - 192));
+                    let nj = Scalar::from_w64le(0, 0, 0,
+                        (j as u64) << (n - 192));
+                    let s = if zUi.equals(zVj) != 0 {
+                        Some(s0 + ni + nj)
+                    } else if zUi.equals(-zVj) != 0 {
+                        Some(s0 + ni - nj)
+                    } else {
+                        None
+                    };
+                    if let Some(s) = s {
+                        // sig2[] already contains r, we just have to
+                        // encode the complete s in it.
+                        sig2[32..64].copy_from_slice(&bswap32(&s.encode()));
+                        return Some(sig2);
+                    }
+
+                    if i1 == 0 {
+                        break;
+                    }
+                    i1 -= 1;
+                }
+            }
+
+            jj += blen;
+            if jj > J {
+                break;
+            }
+        }
+
+        // No match; signature is not valid.
+        None
+    }
+}
+
+/// Encodes a signature with the ASN.1 DER format.
+///
+/// The source signature uses the same format as the output of
+/// `PrivateKey::sign_hash()` (r and s in unsigned big-endian
+/// convention, over the same length, concatenated); an even length of
+/// up to 64 bytes is accepted. The output is the DER encoding of an ASN.1
+/// `SEQUENCE` of two `INTEGER` values (as used in X.509 and TLS);
+/// it is written in the first bytes of the returned array, and the
+/// second returned value is the encoded length (between 8 and 72
+/// bytes). `None` is returned if the source length is not valid.
+pub fn encode_der(sig: &[u8]) -> Option<([u8; 72], usize)> {
+    let siglen = sig.len();
+    if (siglen & 1) != 0 || siglen == 0 || siglen > 64 {
+        return None;
+    }
+    let numlen = siglen >> 1;
+
+    let mut der = [0u8; 72];
+    let mut j = 2;
+    for v in [&sig[..numlen], &sig[numlen..]] {
+        // Skip leading zeros (but keep at least one byte), and add
+        // a 0x00 byte if the top bit is set, since INTEGER uses
+        // signed big-endian convention.
+        let mut i = 0;
+        while i < (numlen - 1) && v[i] == 0 {
+            i += 1;
+        }
+        let pad = (v[i] >> 7) as usize;
+        let len = numlen - i + pad;
+        der[j] = 0x02;
+        der[j + 1] = len as u8;
+        der[j + 2] = 0x00;
+        der[(j + 2 + pad)..(j + 2 + len)].copy_from_slice(&v[i..]);
+        j += 2 + len;
+    }
+    der[0] = 0x30;
+    der[1] = (j - 2) as u8;
+    Some((der, j))
+}
+
+/// Decodes a signature from the ASN.1 DER format.
+///
+/// The source must be the DER encoding of an ASN.1 `SEQUENCE` of
+/// two `INTEGER` values (r and s); the signature is returned in the
+/// format used by `PublicKey::verify_hash()` (64 bytes). Decoding is strict:
+/// the BER-only variants (e.g. non-minimal lengths or integer
+/// encodings, indefinite lengths, trailing garbage) are rejected,
+/// as are negative integers and values that do not fit over 32
+/// bytes. The range of r and s is not checked here, but in
+/// `PublicKey::verify_hash()`.
+pub fn decode_der(der: &[u8]) -> Option<[u8; 64]> {
+    // The SEQUENCE length is at most 70 bytes, thus always uses the
+    // short form.
+    if der.len() < 2 || der[0] != 0x30 || (der[1] as usize) != der.len() - 2 {
+        return None;
+    }
+    let mut sig = [0u8; 64];
+    let mut j = 2;
+    for k in 0..2 {
+        if der.len() < j + 2 || der[j] != 0x02 {
+            return None;
+        }
+        let mut len = der[j + 1] as usize;
+        j += 2;
+        if len == 0 || len > 33 || der.len() < j + len {
+            return None;
+        }
+
+        // Negative integers are rejected. A leading 0x00 byte is
+        // allowed only if it is needed (i.e. the next byte has its
+        // top bit set).
+        if (der[j] & 0x80) != 0 {
+            return None;
+        }
+        if der[j] == 0x00 && len > 1 {
+            if (der[j + 1] & 0x80) == 0 {
+                return None;
+            }
+            j += 1;
+            len -= 1;
+        }
+        if len > 32 {
+            return None;
+        }
+        sig[(32 * k + 32 - len)..(32 * k + 32)]
+            .copy_from_slice(&der[j..(j + len)]);
+        j += len;
+    }
+    if j != der.len() {
+        return None;
+    }
+    Some(sig)
+}
+
+/// Normalizes a signature to its "low-S" form.
+///
+/// If (r, s) is a valid signature, then so is (r, n - s). The low-S
+/// form uses whichever of s and n - s is lower than n/2; this makes
+/// signatures non-malleable, and it is mandated in some contexts (see
+/// `PublicKey::verify_hash_strict()`). The source signature uses the
+/// same format as in `encode_der()`; the returned signature always
+/// has length 64 bytes. `None` is returned if the source length is
+/// not valid, or if r or s is zero or out of range.
+pub fn normalize_s(sig: &[u8]) -> Option<[u8; 64]> {
+    let siglen = sig.len();
+    if (siglen & 1) != 0 || siglen == 0 || siglen > 64 {
+        return None;
+    }
+    let numlen = siglen >> 1;
+    let mut rb = [0u8; 32];
+    let mut sb = [0u8; 32];
+    rb[(32 - numlen)..].copy_from_slice(&sig[..numlen]);
+    sb[(32 - numlen)..].copy_from_slice(&sig[numlen..]);
+    let (r, cr) = Scalar::decode32(&bswap32(&rb));
+    let (s, cs) = Scalar::decode32(&bswap32(&sb));
+    if (cr & cs & !r.iszero() & !s.iszero()) == 0 {
+        return None;
+    }
+
+    let mut nsig = [0u8; 64];
+    nsig[..32].copy_from_slice(&rb);
+    if is_high_s(&s) {
+        nsig[32..].copy_from_slice(&bswap32(&(-s).encode()));
+    } else {
+        nsig[32..].copy_from_slice(&sb);
+    }
+    Some(nsig)
+}
+
+// ========================================================================
+
+// BIP-340 Schnorr signatures
+// ==========================
+//
+// BIP-340 public keys are "x-only": only the x coordinate of the public
+// point is encoded, and the point is implicitly the one with an even y
+// coordinate. The private key is negated when needed, so that it matches
+// that point. Signatures consist of the x coordinate of the nonce point
+// R (again implicitly with an even y coordinate) and the scalar s.
+// All hashes are "tagged": with tag t, the hash of data m is
+// SHA-256(SHA-256(t) || SHA-256(t) || m).
+//
+// BIP-341 (Taproot) tweaks an "internal key" P into an "output key"
+// Q = P + t*G, with t = hash_TapTweak(x(P) || h), h being the Merkle
+// root of the script tree (or an empty string).
+
+/// A BIP-340 public key.
+///
+/// This wraps around a curve point which is never the neutral, and
+/// always has an even y coordinate; only the x coordinate is encoded.
+#[derive(Clone, Copy, Debug)]
+pub struct XOnlyPublicKey {
+    pub point: Point,
+}
+
+/// Returns a SHA-256 context initialized for a BIP-340 tagged hash with
+/// the provided tag.
+pub(crate) fn tagged_sha256(tag: &[u8]) -> Sha256 {
+    let th = Sha256::digest(tag);
+    let mut sh = Sha256::new();
+    sh.update(th);
+    sh.update(th);
+    sh
+}
+
+/// Computes the BIP-340 challenge from the encoded nonce point (`r`),
+/// the encoded x-only public key (`pk`) and the message.
+pub(crate) fn schnorr_challenge(r: &[u8], pk: &[u8], msg: &[u8]) -> Scalar {
+    let mut sh = tagged_sha256(b"BIP0340/challenge");
+    sh.update(r);
+    sh.update(pk);
+    sh.update(msg);
+    Scalar::decode_reduce(&bswap32(&sh.finalize()))
+}
+
+/// Computes the BIP-341 tweak value for an encoded x-only internal key
+/// and a script tree Merkle root (32 bytes, or empty).
+fn taproot_tweak_hash(pk: &[u8], merkle_root: &[u8]) -> Option<[u8; 32]> {
+    if !merkle_root.is_empty() && merkle_root.len() != 32 {
+        return None;
+    }
+    let mut sh = tagged_sha256(b"TapTweak");
+    sh.update(pk);
+    sh.update(merkle_root);
+    Some(sh.finalize().into())
+}
+
+impl PrivateKey {
+
+    /// Gets the BIP-340 (x-only) public key corresponding to that
+    /// private key.
+    pub fn to_xonly_public_key(self) -> XOnlyPublicKey {
+        self.xonly_normalize().1
+    }
+
+    /// Gets the private scalar, negated if necessary so that it matches
+    /// the x-only public key, and that public key.
+    fn xonly_normalize(self) -> (Scalar, XOnlyPublicKey) {
+        let mut point = Point::mulgen(&self.x);
+        let ny = point.has_odd_y();
+        point.set_condneg(ny);
+        let mut d = self.x;
+        d.set_cond(&-d, ny);
+        (d, XOnlyPublicKey { point })
+    }
+
+    /// Signs a message with the BIP-340 Schnorr signature scheme.
+    ///
+    /// The message may have an arbitrary length (BIP-340 signs messages
+    /// directly, though most protocols that use it actually sign a
+    /// 32-byte hash value). The auxiliary data `aux_rand` is mixed into
+    /// the per-signature nonce; BIP-340 recommends using 32 fresh random
+    /// bytes, but the signature is still safe if they are not random
+    /// (e.g. all zeros), in which case the process is deterministic.
+    ///
+    /// The signature is returned over 64 bytes: the x coordinate of the
+    /// nonce point R, followed by the scalar s (both in unsigned
+    /// big-endian convention). For a given set of inputs, the output is
+    /// the same as with the BIP-340 reference implementation.
+    pub fn sign_schnorr(self, msg: &[u8], aux_rand: &[u8; 32]) -> [u8; 64] {
+        let (d, pk) = self.xonly_normalize();
+        let pk_enc = pk.encode();
+
+        // t = bytes(d) xor hash_BIP0340/aux(aux_rand)
+        let mut sh = tagged_sha256(b"BIP0340/aux");
+        sh.update(aux_rand);
+        let ha = sh.finalize();
+        let mut t = bswap32(&d.encode());
+        for i in 0..32 {
+            t[i] ^= ha[i];
+        }
+
+        // k' = int(hash_BIP0340/nonce(t || bytes(P) || msg)) mod n
+        let mut sh = tagged_sha256(b"BIP0340/nonce");
+        sh.update(t);
+        sh.update(pk_enc);
+        sh.update(msg);
+        let mut k = Scalar::decode_reduce(&bswap32(&sh.finalize()));
+
+        // BIP-340 reports a failure if k' = 0. This has negligible
+        // probability, and nobody knows an input that would yield such
+        // a result; we use 1 instead, so that a signature is always
+        // returned.
+        k.set_cond(&Scalar::ONE, k.iszero());
+
+        // R = k'*G, and k = -k' if R has an odd y coordinate.
+        let R = Point::mulgen(&k);
+        k.set_cond(&-k, R.has_odd_y());
+        let r_enc = R.encode_xonly();
+
+        // s = k + e*d
+        let e = schnorr_challenge(&r_enc, &pk_enc, msg);
+        let s = k + e * d;
+
+        let mut sig = [0u8; 64];
+        sig[..32].copy_from_slice(&r_enc);
+        sig[32..].copy_from_slice(&bswap32(&s.encode()));
+        sig
+    }
+
+    /// Adds a tweak to this private key, in the x-only (BIP-340) sense.
+    ///
+    /// The private key is first negated if needed, so that it matches
+    /// its x-only public key; then the tweak (32 bytes, unsigned
+    /// big-endian) is added to it. The new private key matches the
+    /// public key returned by `XOnlyPublicKey::tweak_add()` with the
+    /// same tweak. `None` is returned if the tweak is not lower than the
+    /// curve order, or if the new private key would be zero.
+    pub fn tweak_add_xonly(self, tweak: &[u8]) -> Option<Self> {
+        if tweak.len() != 32 {
+            return None;
+        }
+        let (t, ct) = Scalar::decode32(&bswap32(tweak));
+        let (d, _) = self.xonly_normalize();
+        let x = d + t;
+        if (ct & !x.iszero()) != 0 {
+            Some(Self { x })
+        } else {
+            None
+        }
+    }
+
+    /// Computes the Taproot output private key from this (internal)
+    /// private key, as per BIP-341.
+    ///
+    /// `merkle_root` is the Merkle root of the script tree (32 bytes),
+    /// or an empty slice for an output without a script path (as
+    /// recommended by BIP-86). `None` is returned if `merkle_root` has
+    /// an invalid length, or (with negligible probability) if the tweak
+    /// is out of range.
+    pub fn taproot_tweak(self, merkle_root: &[u8]) -> Option<Self> {
+        let t = taproot_tweak_hash(
+            &self.to_xonly_public_key().encode(), merkle_root)?;
+        self.tweak_add_xonly(&t)
+    }
+}
+
+impl PublicKey {
+
+    /// Gets the BIP-340 (x-only) public key for this public key.
+    ///
+    /// The x-only public key designates either the same point as this
+    /// public key, or its opposite (whichever has an even y coordinate).
+    pub fn to_xonly_public_key(self) -> XOnlyPublicKey {
+        let mut point = self.point;
+        point.set_condneg(point.has_odd_y());
+        XOnlyPublicKey { point }
+    }
+}
+
+impl XOnlyPublicKey {
+
+    /// Decodes a BIP-340 public key from bytes.
+    ///
+    /// The input must have length exactly 32 bytes (unsigned big-endian
+    /// encoding of the x coordinate), and designate a valid curve point.
+    pub fn decode(buf: &[u8]) -> Option<Self> {
+        Some(Self { point: Point::decode_xonly(buf)? })
+    }
+
+    /// Encodes this public key into exactly 32 bytes.
+    pub fn encode(self) -> [u8; 32] {
+        self.point.encode_xonly()
+    }
+
+    /// Verifies a BIP-340 Schnorr signature on a given message.
+    ///
+    /// The signature must have length exactly 64 bytes; its two halves
+    /// must be in the proper ranges (lower than the field modulus for
+    /// the first half, and lower than the curve order for the second
+    /// half), otherwise it is rejected.
+    ///
+    /// Note: this function is not constant-time; it assumes that the
+    /// public key and signature value are public data.
+    pub fn verify(self, sig: &[u8], msg: &[u8]) -> bool {
+        if sig.len() != 64 {
+            return false;
+        }
+        let (r, cr) = GFsecp256k1::decode32(&bswap32(&sig[..32]));
+        let (s, cs) = Scalar::decode32(&bswap32(&sig[32..]));
+        if (cr & cs) == 0 {
+            return false;
+        }
+
+        // R = s*G - e*P must be a non-neutral point with an even y
+        // coordinate and x coordinate equal to r.
+        let e = schnorr_challenge(&sig[..32], &self.encode(), msg);
+        let R = self.point.mul_add_mulgen_vartime(&-e, &s);
+        let (x, y, ok) = R.to_affine();
+        ok != 0 && (y.encode()[0] & 0x01) == 0 && x.equals(r) != 0
+    }
+
+    /// Verifies a batch of BIP-340 Schnorr signatures.
+    ///
+    /// Signature `sigs[i]` is verified against public key `pks[i]` and
+    /// message `msgs[i]`; the three slices must have the same length.
+    /// Returned value is true only if all signatures are valid (an empty
+    /// batch is valid). When it returns false, this function does not
+    /// report which signature(s) failed; callers needing that
+    /// information should verify signatures individually with
+    /// `verify()`.
+    ///
+    /// As allowed by BIP-340, the random multipliers are derived from a
+    /// hash of all inputs. They are 128-bit integers: the probability
+    /// that a batch containing an invalid signature is accepted is lower
+    /// than 2^(-127). All multiplications share the same doublings, so
+    /// that each extra signature in the batch costs substantially less
+    /// than an individual verification.
+    ///
+    /// Note: this function is not constant-time; it assumes that the
+    /// public keys and signature values are public data.
+    pub fn verify_batch(pks: &[Self], sigs: &[&[u8]], msgs: &[&[u8]]) -> bool {
+        let n = pks.len();
+        if sigs.len() != n || msgs.len() != n {
+            return false;
+        }
+        if sigs.iter().any(|sig| sig.len() != 64) {
+            return false;
+        }
+
+        // Seed for the multipliers.
+        let mut sh = tagged_sha256(b"BIP0340/batch");
+        for i in 0..n {
+            sh.update(pks[i].encode());
+            sh.update(sigs[i]);
+            sh.update((msgs[i].len() as u64).to_be_bytes());
+            sh.update(msgs[i]);
+        }
+        let seed = sh.finalize();
+
+        // We check that:
+        //   (sum_i a_i*s_i)*G = sum_i (a_i*R_i + (a_i*e_i)*P_i)
+        // with a_0 = 1, and a_i pseudorandom 128-bit integers for i > 0.
+        // Pairs (R_i, P_i) are processed by chunks of Point::BATCH_LEN.
+        const N: usize = Point::BATCH_LEN;
+        let mut S = Scalar::ZERO;
+        let mut T = Point::NEUTRAL;
+        let mut i = 0;
+        while i < n {
+            let blen = core::cmp::min(n - i, N);
+            let mut R = [Point::NEUTRAL; N];
+            let mut a = [0u128; N];
+            let mut P = [Point::NEUTRAL; N];
+            let mut u = [Scalar::ZERO; N];
+            for j in 0..blen {
+                let k = i + j;
+                let sig = sigs[k];
+                R[j] = match Point::decode_xonly(&sig[..32]) {
+                    Some(Rk) => Rk,
+                    None => return false,
+                };
+                let (s, cs) = Scalar::decode32(&bswap32(&sig[32..]));
+                if cs == 0 {
+                    return false;
+                }
+                let e = schnorr_challenge(&sig[..32], &pks[k].encode(), msgs[k]);
+
+                a[j] = if k == 0 {
+                    1
+                } else {
+                    let mut sh = Sha256::new();
+                    sh.update(seed);
+                    sh.update((k as u64).to_be_bytes());
+                    let v = sh.finalize();
+                    let ak = u128::from_le_bytes(
+                        *<&[u8; 16]>::try_from(&v[..16]).unwrap());
+                    if ak == 0 { 1 } else { ak }
+                };
+                let ak = Scalar::from_u128(a[j]);
+                S += ak * s;
+                P[j] = pks[k].point;
+                u[j] = ak * e;
+            }
+            T += Point::batch_mul_vartime(
+                &R[..blen], &a[..blen], &P[..blen], &u[..blen]);
+            i += blen;
+        }
+        T.equals(Point::mulgen(&S)) != 0
+    }
+
+    /// Adds a tweak to this public key, in the x-only (BIP-340) sense.
+    ///
+    /// The tweak (32 bytes, unsigned big-endian) is interpreted as a
+    /// scalar t, and the point Q = P + t*G is computed, with P the point
+    /// for this public key. Returned values are the x-only public key
+    /// for Q, and the parity of the y coordinate of Q (0 for even, 1 for
+    /// odd), which is needed, for instance, in a Taproot control block.
+    /// `None` is returned if the tweak is not lower than the curve
+    /// order, or if Q is the neutral point.
+    pub fn tweak_add(self, tweak: &[u8]) -> Option<(Self, u8)> {
+        if tweak.len() != 32 {
+            return None;
+        }
+        let (t, ct) = Scalar::decode32(&bswap32(tweak));
+        if ct == 0 {
+            return None;
+        }
+        let mut Q = self.point + Point::mulgen(&t);
+        if Q.isneutral() != 0 {
+            return None;
+        }
+        let ny = Q.has_odd_y();
+        Q.set_condneg(ny);
+        Some((Self { point: Q }, (ny & 1) as u8))
+    }
+
+    /// Computes the Taproot output key from this (internal) public key,
+    /// as per BIP-341.
+    ///
+    /// `merkle_root` is the Merkle root of the script tree (32 bytes),
+    /// or an empty slice for an output without a script path (as
+    /// recommended by BIP-86). Returned values are the output key and
+    /// the parity of its y coordinate (0 or 1), as in `tweak_add()`.
+    /// `None` is returned if `merkle_root` has an invalid length, or
+    /// (with negligible probability) if the tweak is out of range.
+    pub fn taproot_tweak(self, merkle_root: &[u8]) -> Option<(Self, u8)> {
+        let t = taproot_tweak_hash(&self.encode(), merkle_root)?;
+        self.tweak_add(&t)
+    }
+}
+
+// ========================================================================
+
+// Adaptor signatures
+// ==================
+//
+// An adaptor signature (or "pre-signature") is computed relatively to
+// an adaptor point T = t*G; it can be verified by anybody who knows T,
+// but it becomes a valid signature only when "adapted" with the secret
+// scalar t. Conversely, from the pre-signature and the final signature,
+// the secret t can be extracted. This is used in atomic swaps and
+// payment channels.
+//
+// For BIP-340 Schnorr signatures, the pre-signature contains the point
+// R = k*G + T (compressed, so that the parity of y(R) is known) and the
+// scalar s' = k + e*d, with k negated if R has an odd y coordinate. The
+// adapted signature is (x(R), s' + t) (or s' - t if y(R) is odd).
+//
+// For ECDSA, this is the "one-time verifiably encrypted signature" of
+// Fournier: with encryption key Y = y*G, the pre-signature contains
+// R = k*Y, R' = k*G, s' = (h + r*x)/k (with r = x(R) mod n), and a
+// proof that R and R' have the same discrete logarithm relatively to
+// Y and G (DLEQ proof). The final signature is (r, s'/y).
+
+/// A BIP-340 Schnorr pre-signature (adaptor signature).
+#[derive(Clone, Copy, Debug)]
+pub struct SchnorrPreSignature {
+    R: Point,
+    s: Scalar,
+}
+
+/// An ECDSA pre-signature (one-time verifiably encrypted signature).
+#[derive(Clone, Copy, Debug)]
+pub struct EcdsaPreSignature {
+    R: Point,
+    Rp: Point,
+    s: Scalar,
+    dleq_e: Scalar,
+    dleq_z: Scalar,
+}
+
+/// Converts a hash value into a scalar (the leftmost 32 bytes are
+/// interpreted in unsigned big-endian convention, then reduced).
+fn hash_to_scalar(hv: &[u8]) -> Scalar {
+    let mut tmp = [0u8; 32];
+    if hv.len() >= 32 {
+        tmp[..].copy_from_slice(&hv[..32]);
+    } else {
+        tmp[(32 - hv.len())..32].copy_from_slice(hv);
+    }
+    Scalar::decode_reduce(&bswap32(&tmp))
+}
+
+/// Computes the challenge for the ECDSA adaptor DLEQ proof.
+fn dleq_challenge(Y: Point, Rp: Point, R: Point, A1: Point, A2: Point)
+    -> Scalar
+{
+    let mut sh = tagged_sha256(b"ECDSAadaptor/dleq");
+    sh.update(Y.encode_compressed());
+    sh.update(Rp.encode_compressed());
+    sh.update(R.encode_compressed());
+    sh.update(A1.encode_compressed());
+    sh.update(A2.encode_compressed());
+    Scalar::decode_reduce(&bswap32(&sh.finalize()))
+}
+
+impl PrivateKey {
+
+    /// Computes a BIP-340 Schnorr pre-signature relatively to the
+    /// adaptor point `T`.
+    ///
+    /// The message and `aux_rand` are used as in `sign_schnorr()`. The
+    /// per-signature nonce also depends on `T`, and uses a hash tag
+    /// distinct from that of plain BIP-340 signatures.
+    pub fn presign_schnorr(self, msg: &[u8], T: &Point, aux_rand: &[u8; 32])
+        -> SchnorrPreSignature
+    {
+        let (d, pk) = self.xonly_normalize();
+        let pk_enc = pk.encode();
+
+        // k = int(hash_SchnorrAdaptor/nonce(t || T || bytes(P) || msg))
+        // with t = bytes(d) xor hash_BIP0340/aux(aux_rand)
+        let mut sh = tagged_sha256(b"BIP0340/aux");
+        sh.update(aux_rand);
+        let ha = sh.finalize();
+        let mut t = bswap32(&d.encode());
+        for i in 0..32 {
+            t[i] ^= ha[i];
+        }
+        let mut sh = tagged_sha256(b"SchnorrAdaptor/nonce");
+        sh.update(t);
+        sh.update(T.encode_compressed());
+        sh.update(pk_enc);
+        sh.update(msg);
+        let mut k = Scalar::decode_reduce(&bswap32(&sh.finalize()));
+        k.set_cond(&Scalar::ONE, k.iszero());
+
+        // R = k*G + T, and k is negated if R has an odd y coordinate.
+        let R = Point::mulgen(&k) + T;
+        k.set_cond(&-k, R.has_odd_y());
+        let e = schnorr_challenge(&R.encode_xonly(), &pk_enc, msg);
+        SchnorrPreSignature { R, s: k + e * d }
+    }
+
+    /// Computes an ECDSA pre-signature relatively to the encryption key
+    /// `Y`.
+    ///
+    /// The hash value `hv` and the extra randomness `extra_rand` are
+    /// used as in `sign_hash()`; the process is deterministic if
+    /// `extra_rand` is empty. The pre-signature includes a proof that
+    /// it was properly computed relatively to `Y`; it can be decrypted
+    /// into a standard ECDSA signature with the discrete logarithm of
+    /// `Y`. Since that discrete logarithm can then be recovered by
+    /// anybody who sees both the pre-signature and the signature (see
+    /// `EcdsaPreSignature::extract()`), a given encryption key should be
+    /// used for a single pre-signature.
+    pub fn presign_ecdsa(self, hv: &[u8], Y: &Point, extra_rand: &[u8])
+        -> EcdsaPreSignature
+    {
+        let h = hash_to_scalar(hv);
+        let Y_enc = Y.encode_compressed();
+
+        // k is derived from the private key, hash value, encryption key
+        // and extra randomness.
+        let mut sh = Sha512::new();
+        sh.update(Sha256::digest(b"ECDSAadaptor/nonce"));
+        sh.update(self.x.encode());
+        sh.update(h.encode());
+        sh.update(Y_enc);
+        sh.update(extra_rand);
+        let mut k = Scalar::decode_reduce(&sh.finalize_reset());
+        k.set_cond(&Scalar::ONE, k.iszero());
+
+        loop {
+            let Rp = Point::mulgen(&k);
+            let R = Y * k;
+            let r = Scalar::decode_reduce(
+                &bswap32(&R.encode_compressed()[1..33]));
+            let s = (h + self.x * r) / k;
+            if (r.iszero() | s.iszero()) == 0 {
+                // DLEQ proof: a is the proof nonce, A1 = a*G, A2 = a*Y,
+                // e = H(Y, R', R, A1, A2) and z = a + e*k.
+                sh.update(Sha256::digest(b"ECDSAadaptor/dleq_nonce"));
+                sh.update(k.encode());
+                sh.update(Y_enc);
+                sh.update(extra_rand);
+                let mut a = Scalar::decode_reduce(&sh.finalize_reset());
+                a.set_cond(&Scalar::ONE, a.iszero());
+                let dleq_e = dleq_challenge(*Y, Rp, R,
+                    Point::mulgen(&a), Y * a);
+                let dleq_z = a + dleq_e * k;
+                return EcdsaPreSignature { R, Rp, s, dleq_e, dleq_z };
+            }
+
+            // As in sign_hash(), a zero r or s is extremely improbable;
+            // we try again with another k in that case.
+            k += Scalar::ONE;
+            k.set_cond(&Scalar::ONE, k.iszero());
+        }
+    }
+}
+
+impl PublicKey {
+
+    /// Verifies an ECDSA pre-signature, for the hashed message `hv` and
+    /// the encryption key `Y`.
+    ///
+    /// If this function returns `true`, then decrypting the
+    /// pre-signature with the discrete logarithm of `Y` yields a valid
+    /// signature for this public key and the hashed message.
+    ///
+    /// Note: this function is not constant-time; it assumes that the
+    /// public key, pre-signature and encryption key are public data.
+    pub fn verify_ecdsa_presig(self, presig: &EcdsaPreSignature, hv: &[u8],
+        Y: &Point) -> bool
+    {
+        let ps = presig;
+        if Y.isneutral() != 0 || ps.s.iszero() != 0 {
+            return false;
+        }
+
+        // Verify the DLEQ proof: A1 = z*G - e*R' and A2 = z*Y - e*R.
+        let A1 = ps.Rp.mul_add_mulgen_vartime(&-ps.dleq_e, &ps.dleq_z);
+        let A2 = Y * ps.dleq_z - ps.R * ps.dleq_e;
+        if dleq_challenge(*Y, ps.Rp, ps.R, A1, A2).equals(ps.dleq_e) == 0 {
+            return false;
+        }
+
+        // Verify that R' = (h/s')*G + (r/s')*Q.
+        let r = Scalar::decode_reduce(
+            &bswap32(&ps.R.encode_compressed()[1..33]));
+        if r.iszero() != 0 {
+            return false;
+        }
+        let w = Scalar::ONE / ps.s;
+        let h = hash_to_scalar(hv);
+        self.point.mul_add_mulgen_vartime(&(r * w), &(h * w))
+            .equals(ps.Rp) != 0
+    }
+}
+
+impl XOnlyPublicKey {
+
+    /// Verifies a BIP-340 Schnorr pre-signature, for the message `msg`
+    /// and the adaptor point `T`.
+    ///
+    /// If this function returns `true`, then adapting the
+    /// pre-signature with the discrete logarithm of `T` yields a valid
+    /// BIP-340 signature for this public key and the message.
+    ///
+    /// Note: this function is not constant-time; it assumes that the
+    /// public key, pre-signature and adaptor point are public data.
+    pub fn verify_schnorr_presig(self, presig: &SchnorrPreSignature,
+        msg: &[u8], T: &Point) -> bool
+    {
+        let e = schnorr_challenge(&presig.R.encode_xonly(),
+            &self.encode(), msg);
+
+        // s'*G - e*P must be equal to R - T (or T - R if y(R) is odd).
+        let U = self.point.mul_add_mulgen_vartime(&-e, &presig.s);
+        let mut V = presig.R - T;
+        V.set_condneg(presig.R.has_odd_y());
+        U.equals(V) != 0
+    }
+}
+
+impl SchnorrPreSignature {
+
+    /// Encoded pre-signature length (in bytes).
+    pub const ENC_LEN: usize = 65;
+
+    /// Adapts this pre-signature into a BIP-340 signature, using the
+    /// discrete logarithm `t` of the adaptor point.
+    pub fn adapt(self, t: &Scalar) -> [u8; 64] {
+        let mut tt = *t;
+        tt.set_cond(&-tt, self.R.has_odd_y());
+        let mut sig = [0u8; 64];
+        sig[..32].copy_from_slice(&self.R.encode_xonly());
+        sig[32..].copy_from_slice(&bswap32(&(self.s + tt).encode()));
+        sig
+    }
+
+    /// Extracts the discrete logarithm of the adaptor point from this
+    /// pre-signature and the corresponding BIP-340 signature.
+    ///
+    /// `None` is returned if the signature does not match this
+    /// pre-signature. If the pre-signature was verified, and the
+    /// signature is valid, then the returned scalar is the discrete
+    /// logarithm of the adaptor point.
+    pub fn extract(self, sig: &[u8]) -> Option<Scalar> {
+        if sig.len() != 64 || sig[..32] != self.R.encode_xonly() {
+            return None;
+        }
+        let (s, cs) = Scalar::decode32(&bswap32(&sig[32..]));
+        if cs == 0 {
+            return None;
+        }
+        let mut t = s - self.s;
+        t.set_cond(&-t, self.R.has_odd_y());
+        Some(t)
+    }
+
+    /// Encodes this pre-signature into bytes.
+    ///
+    /// The encoding is the compressed encoding of R (33 bytes) followed
+    /// by s' (32 bytes, unsigned big-endian).
+    pub fn encode(self) -> [u8; Self::ENC_LEN] {
+        let mut buf = [0u8; Self::ENC_LEN];
+        buf[..33].copy_from_slice(&self.R.encode_compressed());
+        buf[33..].copy_from_slice(&bswap32(&self.s.encode()));
+        buf
+    }
+
+    /// Decodes a pre-signature from bytes.
+    ///
+    /// `None` is returned if the source does not have the proper length,
+    /// or if it is not the encoding of a valid point and a scalar.
+    pub fn decode(buf: &[u8]) -> Option<Self> {
+        if buf.len() != Self::ENC_LEN {
+            return None;
+        }
+        let R = Point::decode(&buf[..33])?;
+        let (s, cs) = Scalar::decode32(&bswap32(&buf[33..]));
+        if cs == 0 {
+            return None;
+        }
+        Some(Self { R, s })
+    }
+}
+
+impl EcdsaPreSignature {
+
+    /// Encoded pre-signature length (in bytes).
+    pub const ENC_LEN: usize = 162;
+
+    /// Decrypts this pre-signature into an ECDSA signature, using the
+    /// discrete logarithm `y` of the encryption key.
+    ///
+    /// The signature is encoded as with `PrivateKey::sign_hash()`.
+    pub fn adapt(self, y: &Scalar) -> [u8; 64] {
+        let r = Scalar::decode_reduce(
+            &bswap32(&self.R.encode_compressed()[1..33]));
+        let s = self.s / y;
+        let mut sig = [0u8; 64];
+        sig[..32].copy_from_slice(&bswap32(&r.encode()));
+        sig[32..].copy_from_slice(&bswap32(&s.encode()));
+        sig
+    }
+
+    /// Recovers the discrete logarithm of the encryption key `Y` from
+    /// this pre-signature and the corresponding ECDSA signature.
+    ///
+    /// The signature may have been normalized to "low-S" form (i.e. s
+    /// replaced with -s) after decryption. `None` is returned if the
+    /// signature does not match this pre-signature.
+    ///
+    /// Note: this function is not constant-time.
+    pub fn extract(self, sig: &[u8], Y: &Point) -> Option<Scalar> {
+        if sig.len() != 64 {
+            return None;
+        }
+        let (s, cs) = Scalar::decode32(&bswap32(&sig[32..]));
+        if cs == 0 || s.iszero() != 0 {
+            return None;
+        }
+        let y = self.s / s;
+        let Yc = Point::mulgen(&y);
+        if Yc.equals(*Y) != 0 {
+            Some(y)
+        } else if Yc.equals(-Y) != 0 {
+            Some(-y)
+        } else {
+            None
+        }
+    }
+
+    /// Encodes this pre-signature into bytes.
+    ///
+    /// The encoding consists of R (33 bytes), R' (33 bytes), s'
+    /// (32 bytes), and the DLEQ proof (two scalars of 32 bytes each);
+    /// points use the compressed format and scalars the unsigned
+    /// big-endian convention.
+    pub fn encode(self) -> [u8; Self::ENC_LEN] {
+        let mut buf = [0u8; Self::ENC_LEN];
+        buf[..33].copy_from_slice(&self.R.encode_compressed());
+        buf[33..66].copy_from_slice(&self.Rp.encode_compressed());
+        buf[66..98].copy_from_slice(&bswap32(&self.s.encode()));
+        buf[98..130].copy_from_slice(&bswap32(&self.dleq_e.encode()));
+        buf[130..].copy_from_slice(&bswap32(&self.dleq_z.encode()));
+        buf
+    }
+
+    /// Decodes a pre-signature from bytes.
+    ///
+    /// `None` is returned if the source does not have the proper length,
+    /// or if it is not the encoding of two valid points and three
+    /// scalars.
+    pub fn decode(buf: &[u8]) -> Option<Self> {
+        if buf.len() != Self::ENC_LEN {
+            return None;
+        }
+        let R = Point::decode(&buf[..33])?;
+        let Rp = Point::decode(&buf[33..66])?;
+        let (s, c1) = Scalar::decode32(&bswap32(&buf[66..98]));
+        let (dleq_e, c2) = Scalar::decode32(&bswap32(&buf[98..130]));
+        let (dleq_z, c3) = Scalar::decode32(&bswap32(&buf[130..]));
+        if (c1 & c2 & c3) == 0 {
+            return None;
+        }
+        Some(Self { R, Rp, s, dleq_e, dleq_z })
+    }
+}
+
+// ========================================================================
+
+// We hardcode known multiples of the points G, (2^65)*G, (2^130)*G
+// and (2^195)*G, with G being the conventional base point. These are
+// used to speed mulgen() operations up. The points are stored in affine
+// coordinates, i.e. their Z coordinate is implicitly equal to 1.
+
+/// A curve point (non-infinity) in affine coordinates.
+#[derive(Clone, Copy, Debug)]
+struct PointAffine {
+    x: GFsecp256k1,
+    y: GFsecp256k1,
+}
+
+// Points i*G for i = 1 to 16, in affine coordinates.
+static PRECOMP_G: [PointAffine; 16] = [
+    // G * 1
+    PointAffine { x: GFsecp256k1::w64be(0x79BE667EF9DCBBAC, 0x55A06295CE870B07,
+                                        0x029BFCDB2DCE28D9, 0x59F2815B16F81798),
+                  y: GFsecp256k1::w64be(0x483ADA7726A3C465, 0x5DA4FBFC0E1108A8,
+                                        0xFD17B448A6855419, 0x9C47D08FFB10D4B8) },
+    // G * 2
+    PointAffine { x: GFsecp256k1::w64be(0xC6047F9441ED7D6D, 0x3045406E95C07CD8,
+                                        0x5C778E4B8CEF3CA7, 0xABAC09B95C709EE5),
+                  y: GFsecp256k1::w64be(0x1AE168FEA63DC339, 0xA3C58419466CEAEE,
+                                        0xF7F632653266D0E1, 0x236431A950CFE52A) },
+    // G * 3
+    PointAffine { x: GFsecp256k1::w64be(0xF9308A019258C310, 0x49344F85F89D5229,
+                                        0xB531C845836F99B0, 0x8601F113BCE036F9),
+                  y: GFsecp256k1::w64be(0x388F7B0F632DE814, 0x0FE337E62A37F356,
+                                        0x6500A99934C2231B, 0x6CB9FD7584B8E672) },
+    // G * 4
+    PointAffine { x: GFsecp256k1::w64be(0xE493DBF1C10D80F3, 0x581E4904930B1404,
+                                        0xCC6C13900EE07584, 0x74FA94ABE8C4CD13),
+                  y: GFsecp256k1::w64be(0x51ED993EA0D455B7, 0x5642E2098EA51448,
+                                        0xD967AE33BFBDFE40, 0xCFE97BDC47739922) },
+    // G * 5
+    PointAffine { x: GFsecp256k1::w64be(0x2F8BDE4D1A072093, 0x55B4A7250A5C5128,
+                                        0xE88B84BDDC619AB7, 0xCBA8D569B240EFE4),
+                  y: GFsecp256k1::w64be(0xD8AC222636E5E3D6, 0xD4DBA9DDA6C9C426,
+                                        0xF788271BAB0D6840, 0xDCA87D3AA6AC62D6) },
+    // G * 6
+    PointAffine { x: GFsecp256k1::w64be(0xFFF97BD5755EEEA4, 0x20453A14355235D3,
+                                        0x82F6472F8568A18B, 0x2F057A1460297556),
+                  y: GFsecp256k1::w64be(0xAE12777AACFBB620, 0xF3BE96017F45C560,
+                                        0xDE80F0F6518FE4A0, 0x3C870C36B075F297) },
+    // G * 7
+    PointAffine { x: GFsecp256k1::w64be(0x5CBDF0646E5DB4EA, 0xA398F365F2EA7A0E,
+                                        0x3D419B7E0330E39C, 0xE92BDDEDCAC4F9BC),
+                  y: GFsecp256k1::w64be(0x6AEBCA40BA255960, 0xA3178D6D861A54DB,
+                                        0xA813D0B813FDE7B5, 0xA5082628087264DA) },
+    // G * 8
+    PointAffine { x: GFsecp256k1::w64be(0x2F01E5E15CCA351D, 0xAFF3843FB70F3C2F,
+                                        0x0A1BDD05E5AF888A, 0x67784EF3E10A2A01),
+                  y: GFsecp256k1::w64be(0x5C4DA8A741539949, 0x293D082A132D13B4,
+                                        0xC2E213D6BA5B7617, 0xB5DA2CB76CBDE904) },
+    // G * 9
+    PointAffine { x: GFsecp256k1::w64be(0xACD484E2F0C7F653, 0x09AD178A9F559ABD,
+                                        0xE09796974C57E714, 0xC35F110DFC27CCBE),
+                  y: GFsecp256k1::w64be(0xCC338921B0A7D9FD, 0x64380971763B61E9,
+                                        0xADD888A4375F8E0F, 0x05CC262AC64F9C37) },
+    // G * 10
+    PointAffine { x: GFsecp256k1::w64be(0xA0434D9E47F3C862, 0x35477C7B1AE6AE5D,
+                                        0x3442D49B1943C2B7, 0x52A68E2A47E247C7),
+                  y: GFsecp256k1::w64be(0x893ABA425419BC27, 0xA3B6C7E693A24C69,
+                                        0x6F794C2ED877A159, 0x3CBEE53B037368D7) },
+    // G * 11
+    PointAffine { x: GFsecp256k1::w64be(0x774AE7F858A9411E, 0x5EF4246B70C65AAC,
+                                        0x5649980BE5C17891, 0xBBEC17895DA008CB),
+                  y: GFsecp256k1::w64be(0xD984A032EB6B5E19, 0x0243DD56D7B7B365,
+                                        0x372DB1E2DFF9D6A8, 0x301D74C9C953C61B) },
+    // G * 12
+    PointAffine { x: GFsecp256k1::w64be(0xD01115D548E7561B, 0x15C38F004D734633,
+                                        0x687CF4419620095B, 0xC5B0F47070AFE85A),
+                  y: GFsecp256k1::w64be(0xA9F34FFDC815E0D7, 0xA8B64537E17BD815,
+                                        0x79238C5DD9A86D52, 0x6B051B13F4062327) },
+    // G * 13
+    PointAffine { x: GFsecp256k1::w64be(0xF28773C2D975288B, 0xC7D1D205C3748651,
                                         0xB075FBC6610E58CD, 0xDEEDDF8F19405AA8),
                   y: GFsecp256k1::w64be(0x0AB0902E8D880A89, 0x758212EB65CDAF47,
                                         0x3A1A06DA521FA91F, 0x29B5CB52DB03ED81) },
@@ -2297,9 +4220,13 @@ static PRECOMP_G195: [PointAffine; 16] = [
 #[cfg(test)]
 mod tests {
 
-    use super::{Point, Scalar, PrivateKey, PublicKey};
+    use super::{bswap32, Point, Scalar, PrivateKey, PublicKey, XOnlyPublicKey};
+    use super::{SchnorrPreSignature, EcdsaPreSignature};
+    use super::{encode_der, decode_der, normalize_s};
     use sha2::{Sha256, Digest};
 
+    use crate::field::GFsecp256k1;
+
     /* unused
     fn print_gf(name: &str, x: GFsecp256k1) {
         print!("{} = 0x", name);
@@ -2327,6 +4254,18 @@ mod tests {
     }
     */
 
+    #[test]
+    fn to_affine() {
+        let (x, y, r) = Point::NEUTRAL.to_affine();
+        assert!(r == 0);
+        assert!(x.iszero() == 0xFFFFFFFF && y.iszero() == 0xFFFFFFFF);
+        let (x, y, r) = Point::BASE.to_affine();
+        assert!(r == 0xFFFFFFFF);
+        let mut P = Point::NEUTRAL;
+        assert!(P.set_affine(x, y) == 0xFFFFFFFF);
+        assert!(P.equals(Point::BASE) == 0xFFFFFFFF);
+    }
+
     #[test]
     fn base_arith() {
         // Encoding of neutral.
@@ -2730,4 +4669,1539 @@ mod tests {
             assert!(!pkey.verify_hash(&sig2, &hv2));
         }
     }
+
+    #[test]
+    fn recovery() {
+        // Edge cases from libsecp256k1 (modules/recovery/tests_impl.h).
+        let hv = b"This is a very secret message...";
+        let sig = hex::decode("67cb285f9cd194e840d629397af5569662fde4464999596317\
+9a7dd17bd235324b1b7df34ce1f68e694ff6f11ac751dd7dd73e387ee4fc866e1be8ecc7dd9557").unwrap();
+        for recid in 0..4 {
+            let pk = PublicKey::recover_from_hash(&sig, recid, hv);
+            assert!(pk.is_some() == (recid == 1));
+            if let Some(pk) = pk {
+                assert!(pk.verify_hash(&sig, hv));
+            }
+        }
+
+        // (r, s) = (4, 4) can be recovered with all four recovery ids
+        // (x(R) = 4 + n is lower than p), and (1, 1) with recovery id 0;
+        // zero values are rejected.
+        let mut sig = [0u8; 64];
+        sig[31] = 4;
+        sig[63] = 4;
+        for recid in 0..4 {
+            let pk = PublicKey::recover_from_hash(&sig, recid, hv).unwrap();
+            assert!(pk.verify_hash(&sig, hv));
+        }
+        sig[31] = 1;
+        sig[63] = 1;
+        let pk = PublicKey::recover_from_hash(&sig, 0, hv).unwrap();
+        assert!(pk.verify_hash(&sig, hv));
+        sig[31] = 0;
+        assert!(PublicKey::recover_from_hash(&sig, 0, hv).is_none());
+        sig[31] = 1;
+        sig[63] = 0;
+        assert!(PublicKey::recover_from_hash(&sig, 0, hv).is_none());
+
+        // Signature with private key 1 (from rust-secp256k1).
+        let mut one = [0u8; 32];
+        one[31] = 1;
+        let sig = hex::decode("6673ffad2147741f04772b6f921f0ba6af0c1e77fc439e65c36dedf4092e8898\
+4c1a971652e0ada880120ef8025e709fff2080c4a39aae068d12eed009b68c89").unwrap();
+        let pk = PublicKey::recover_from_hash(&sig, 1, &one).unwrap();
+        assert!(pk.point.equals(Point::BASE) != 0);
+        let pk = PublicKey::recover_from_hash(&sig, 0, &one).unwrap();
+        assert!(pk.point.equals(Point::BASE) == 0);
+
+        let mut sh = Sha256::new();
+        for i in 0..20 {
+            sh.update((i as u64).to_le_bytes());
+            let seed: [u8; 32] = sh.finalize_reset().into();
+            let sk = PrivateKey::from_seed(&seed);
+            let pk = sk.to_public_key();
+            let (sig, recid) = sk.sign_hash_recoverable(&seed, &[]);
+            assert!(sig == sk.sign_hash(&seed, &[]));
+            assert!(recid <= 1);
+            let pk2 = PublicKey::recover_from_hash(&sig, recid, &seed).unwrap();
+            assert!(pk2.point.equals(pk.point) != 0);
+            let pk3 = PublicKey::recover_from_hash(&sig, recid ^ 1, &seed)
+                .unwrap();
+            assert!(pk3.point.equals(pk.point) == 0);
+            assert!(pk3.verify_hash(&sig, &seed));
+            assert!(PublicKey::recover_from_hash(&sig, 4, &seed).is_none());
+        }
+    }
+
+    #[test]
+    fn der_low_s() {
+        let mut sh = Sha256::new();
+        let mut num_high = 0;
+        for i in 0..40 {
+            sh.update((i as u64).to_le_bytes());
+            let seed: [u8; 32] = sh.finalize_reset().into();
+            let sk = PrivateKey::from_seed(&seed);
+            let pk = sk.to_public_key();
+            let sig = sk.sign_hash(&seed, &[]);
+
+            let (der, len) = encode_der(&sig).unwrap();
+            assert!(len <= 72 && der[0] == 0x30 && der[1] as usize == len - 2);
+            assert!(decode_der(&der[..len]).unwrap() == sig);
+            assert!(decode_der(&der[..(len - 1)]).is_none());
+            let mut der2 = [0u8; 73];
+            der2[..len].copy_from_slice(&der[..len]);
+            assert!(decode_der(&der2[..(len + 1)]).is_none());
+
+            let nsig = normalize_s(&sig).unwrap();
+            assert!(pk.verify_hash(&nsig, &seed));
+            assert!(pk.verify_hash_strict(&nsig, &seed));
+            assert!(nsig[32] < 0x80);
+            assert!(normalize_s(&nsig).unwrap() == nsig);
+            if nsig != sig {
+                num_high += 1;
+                assert!(nsig[..32] == sig[..32]);
+                assert!(!pk.verify_hash_strict(&sig, &seed));
+            } else {
+                assert!(pk.verify_hash_strict(&sig, &seed));
+            }
+        }
+        assert!(num_high > 0 && num_high < 40);
+
+        // Small values and leading zeros.
+        let mut sig = [0u8; 64];
+        sig[31] = 0x01;
+        sig[32] = 0x80;
+        let (der, len) = encode_der(&sig).unwrap();
+        assert!(der[..len] == hex::decode(
+            "3026020101022100800000000000000000000000000000000000000000000000000000000000000000")
+            .unwrap()[..len]);
+        assert!(decode_der(&der[..len]).unwrap() == sig);
+        let (der, len) = encode_der(&[0x00, 0x7F]).unwrap();
+        assert!(der[..len] == [0x30, 0x06, 0x02, 0x01, 0x00, 0x02, 0x01, 0x7F]);
+        assert!(encode_der(&sig[..63]).is_none());
+        assert!(normalize_s(&[0u8; 64]).is_none());
+
+        // Non-minimal encodings and negative integers are rejected.
+        for bad in [
+            "3007020101020200010000",
+            "300702020001020101",
+            "3006020101020180",
+            "30060201ff020101",
+            "3081060201010201010000",
+            "3080020101020101000000",
+            "31060201010201010000",
+        ] {
+            assert!(decode_der(&hex::decode(bad).unwrap()).is_none());
+        }
+        assert!(decode_der(&hex::decode(
+            "3006020101020101").unwrap()).is_some());
+    }
+
+    #[test]
+    fn x_sequence() {
+        fn tt(P0: Point, P1: Point, n: usize) {
+            let mut xx = [GFsecp256k1::ZERO; 400];
+            let xx = &mut xx[..n];
+            let Q = P1 - P0;
+            let (x0, _, _) = P0.to_affine();
+            let (x1, _, _) = P1.to_affine();
+            let (xq, _, _) = Q.to_affine();
+            let (xf0, xf1) = Point::x_sequence_vartime(x0, x1, xq, xx);
+            let mut T = P0;
+            for i in 0..n {
+                if T.isneutral() != 0 {
+                    assert!(xx[i].iszero() != 0);
+                } else {
+                    assert!(T.X.equals(xx[i] * T.Z) != 0);
+                }
+                T += Q;
+            }
+            let T0 = T;
+            let T1 = T + Q;
+            if T0.isneutral() != 0 {
+                assert!(xf0.iszero() != 0);
+            } else {
+                assert!(T0.X.equals(xf0 * T0.Z) != 0);
+            }
+            if T1.isneutral() != 0 {
+                assert!(xf1.iszero() != 0);
+            } else {
+                assert!(T1.X.equals(xf1 * T1.Z) != 0);
+            }
+
+            // to_x_affine_diff() must yield the same values as to_affine().
+            let (y0, y1, yq) = Point::to_x_affine_diff(P0, P1);
+            assert!(y0.equals(x0) != 0);
+            assert!(y1.equals(x1) != 0);
+            assert!(yq.equals(xq) != 0);
+        }
+
+        let mut sh = Sha256::new();
+        sh.update([0u8]);
+        let U = Point::mulgen(&Scalar::decode_reduce(&sh.finalize_reset()[..]));
+        sh.update([1u8]);
+        let V = Point::mulgen(&Scalar::decode_reduce(&sh.finalize_reset()[..]));
+
+        // Normal tests with pseudorandom points.
+        tt(U, V, 0);
+        tt(U, V, 1);
+        tt(U, V, 2);
+        tt(U, V, 3);
+        tt(U, V, 100);
+        tt(U, V, 101);
+        tt(U, V, 102);
+        tt(U, V, 400);
+
+        // Tests with the neutral.
+        tt(5 * U, 4 * U, 3);
+        tt(5 * U, 4 * U, 4);
+        tt(5 * U, 4 * U, 5);
+        tt(5 * U, 4 * U, 6);
+        tt(5 * U, 4 * U, 7);
+        tt(Point::NEUTRAL, U, 5);
+        tt(U, U, 10);
+    }
+
+    #[test]
+    fn signatures_trunc() {
+        let mut seed = [0u8; 48];
+        let mut sh = Sha256::new();
+        sh.update([0u8]);
+        seed[0..32].copy_from_slice(&sh.finalize_reset()[..]);
+        sh.update([1u8]);
+        seed[32..48].copy_from_slice(&sh.finalize_reset()[0..16]);
+        let skey = PrivateKey::from_seed(&seed);
+        let pkey = skey.to_public_key();
+        for i in 0..2 {
+            let mut msg = [0u8; 8];
+            msg[..].copy_from_slice(&(i as u64).to_le_bytes());
+            sh.update(msg);
+            let hv = sh.finalize_reset();
+            let sig1 = skey.sign_hash(&hv[..], &[]);
+            let mut sig2 = PrivateKey::prepare_truncate(&sig1).unwrap();
+            sig2[63] = 0;
+            for rm in 8..(if i == 0 { 33 } else { 25 }) {
+                let n = 512 - rm;
+                sig2[n >> 3] &= !(0x01u8 << (n & 7));
+                let vv = pkey.verify_trunc_hash(&sig2, rm, &hv[..]);
+                assert!(vv.is_some());
+                let sig3 = vv.unwrap();
+                assert!(pkey.verify_hash(&sig3, &hv[..]));
+                msg[0] ^= 1;
+                assert!(pkey.verify_trunc_hash(&sig2, rm, &msg).is_none());
+                msg[0] ^= 1;
+            }
+
+            // The normalized (low-S) signature must yield the same
+            // prepared value.
+            let sig4 = normalize_s(&sig1).unwrap();
+            let sig5 = PrivateKey::prepare_truncate(&sig1).unwrap();
+            assert!(PrivateKey::prepare_truncate(&sig4).unwrap() == sig5);
+        }
+    }
+
+    #[test]
+    fn signatures_trunc_buf() {
+        let mut sh = Sha256::new();
+        sh.update([2u8]);
+        let skey = PrivateKey::from_seed(&sh.finalize_reset()[..]);
+        let pkey = skey.to_public_key();
+        sh.update([3u8]);
+        let hv = sh.finalize_reset();
+        let sig1 = skey.sign_hash(&hv[..], &[]);
+        let sig2 = PrivateKey::prepare_truncate(&sig1).unwrap();
+        let mut scratch = [0u64; 1000];
+        for (rm, blen) in [(8, 2), (8, 3), (13, 5), (20, 17), (20, 1000)] {
+            // Ignored bits are set to 1; they must not matter.
+            let mut sig3 = sig2;
+            for i in (512 - rm)..512 {
+                sig3[i >> 3] |= 1u8 << (i & 7);
+            }
+            let sig4 = pkey.verify_trunc_hash(&sig3, rm, &hv[..]).unwrap();
+            assert!(pkey.verify_hash(&sig4, &hv[..]));
+
+            // Smaller buffers must yield the same result (only slower).
+            let sig5 = pkey.verify_trunc_hash_buf(&sig3, rm, &hv[..],
+                &mut scratch[..blen]).unwrap();
+            assert!(sig4 == sig5);
+            assert!(pkey.verify_trunc_hash_buf(&sig3, rm, &sig1[..32],
+                &mut scratch[..blen]).is_none());
+        }
+    }
+
+    #[test]
+    fn wycheproof() {
+        let mut pk = None;
+        for line in WYCHEPROOF_VECTORS.lines() {
+            let mut f = line.split(',');
+            let pk_enc = f.next().unwrap();
+            if !pk_enc.is_empty() {
+                pk = Some(PublicKey::decode(&hex::decode(pk_enc).unwrap())
+                    .unwrap());
+            }
+            let pk = pk.unwrap();
+            let msg = hex::decode(f.next().unwrap()).unwrap();
+            let der = hex::decode(f.next().unwrap()).unwrap();
+            let valid = f.next().unwrap() == "1";
+            let mut sh = Sha256::new();
+            sh.update(&msg);
+            let hv = sh.finalize();
+
+            // The "Bitcoin" variant of the test vectors requires low-S
+            // signatures; high-S signatures would be accepted by the
+            // non-strict verification function.
+            let ok = match decode_der(&der) {
+                Some(sig) => pk.verify_hash_strict(&sig, &hv),
+                None => false,
+            };
+            assert!(ok == valid);
+            if valid {
+                // Valid signatures use DER, which is canonical.
+                let sig = decode_der(&der).unwrap();
+                let (der2, len) = encode_der(&sig).unwrap();
+                assert!(der2[..len] == der[..]);
+            }
+        }
+    }
+
+    #[test]
+    fn bip340() {
+        for line in BIP340_VECTORS.lines().skip(1) {
+            let mut f = line.split(',');
+            let _index = f.next().unwrap();
+            let sk_enc = hex::decode(f.next().unwrap()).unwrap();
+            let pk_enc = hex::decode(f.next().unwrap()).unwrap();
+            let aux_rand = hex::decode(f.next().unwrap()).unwrap();
+            let msg = hex::decode(f.next().unwrap()).unwrap();
+            let sig = hex::decode(f.next().unwrap()).unwrap();
+            let expected = f.next().unwrap() == "TRUE";
+
+            if sk_enc.len() > 0 {
+                let sk = PrivateKey::decode(&sk_enc).unwrap();
+                assert!(sk.to_xonly_public_key().encode()[..] == pk_enc[..]);
+                let mut aux = [0u8; 32];
+                aux[..].copy_from_slice(&aux_rand);
+                assert!(sk.sign_schnorr(&msg, &aux)[..] == sig[..]);
+            }
+            match XOnlyPublicKey::decode(&pk_enc) {
+                Some(pk) => {
+                    assert!(pk.verify(&sig, &msg) == expected);
+                    assert!(XOnlyPublicKey::verify_batch(
+                        &[pk], &[&sig[..]], &[&msg[..]]) == expected);
+                }
+                None => assert!(!expected),
+            }
+        }
+    }
+
+    #[test]
+    fn bip340_batch() {
+        let mut sh = Sha256::new();
+        let mut pks = [XOnlyPublicKey { point: Point::BASE }; 20];
+        let mut sigs = [[0u8; 64]; 20];
+        let mut msgs = [[0u8; 32]; 20];
+        for i in 0..20 {
+            sh.update((i as u64).to_le_bytes());
+            let seed: [u8; 32] = sh.finalize_reset().into();
+            let sk = PrivateKey::from_seed(&seed);
+            pks[i] = sk.to_xonly_public_key();
+            msgs[i][0] = i as u8;
+            sigs[i] = sk.sign_schnorr(&msgs[i], &seed);
+            assert!(pks[i].verify(&sigs[i], &msgs[i]));
+        }
+        for n in [0, 1, 2, 7, 8, 9, 16, 20] {
+            let sr: [&[u8]; 20] = core::array::from_fn(|i| &sigs[i][..]);
+            let mr: [&[u8]; 20] = core::array::from_fn(|i| &msgs[i][..]);
+            assert!(XOnlyPublicKey::verify_batch(&pks[..n], &sr[..n], &mr[..n]));
+            if n == 0 {
+                continue;
+            }
+
+            // Any altered signature, message or key must make the batch
+            // fail.
+            let mut bad_sigs = sigs;
+            bad_sigs[n - 1][63] ^= 0x01;
+            let sr2: [&[u8]; 20] = core::array::from_fn(|i| &bad_sigs[i][..]);
+            assert!(!XOnlyPublicKey::verify_batch(&pks[..n], &sr2[..n], &mr[..n]));
+            let mut bad_msgs = msgs;
+            bad_msgs[n >> 1][1] ^= 0x01;
+            let mr2: [&[u8]; 20] = core::array::from_fn(|i| &bad_msgs[i][..]);
+            assert!(!XOnlyPublicKey::verify_batch(&pks[..n], &sr[..n], &mr2[..n]));
+            let mut bad_pks = pks;
+            bad_pks[0] = pks[n - 1];
+            if n > 1 {
+                assert!(!XOnlyPublicKey::verify_batch(&bad_pks[..n], &sr[..n], &mr[..n]));
+            }
+            assert!(!XOnlyPublicKey::verify_batch(&pks[..n], &sr[..n], &mr[..(n - 1)]));
+        }
+    }
+
+    #[test]
+    fn taproot_tweak() {
+        // Test vector from BIP-86 (first receiving address of account 0).
+        let internal = hex::decode("cc8a4bc64d897bddc5fbc2f670f7a8ba0b386779106cf1223c6fc5d7cd6fc115").unwrap();
+        let output = hex::decode("a60869f0dbcf1dc659c9cecbaf8050135ea9e8cdc487053f1dc6880949dc684c").unwrap();
+        let pk = XOnlyPublicKey::decode(&internal).unwrap();
+        let (qk, _) = pk.taproot_tweak(&[]).unwrap();
+        assert!(qk.encode()[..] == output[..]);
+        assert!(pk.taproot_tweak(&[0u8; 31]).is_none());
+
+        // Tweaked private keys must match tweaked public keys, and
+        // produce valid signatures.
+        let mut sh = Sha256::new();
+        for i in 0..20 {
+            sh.update((i as u64).to_le_bytes());
+            let seed: [u8; 32] = sh.finalize_reset().into();
+            let sk = PrivateKey::from_seed(&seed);
+            let pk = sk.to_xonly_public_key();
+            assert!(sk.to_public_key().to_xonly_public_key().encode() == pk.encode());
+
+            let root = if (i & 1) == 0 { &seed[..] } else { &[][..] };
+            let (qk, parity) = pk.taproot_tweak(root).unwrap();
+            let tsk = sk.taproot_tweak(root).unwrap();
+            let Q = tsk.to_public_key().point;
+            assert!(Q.equals(qk.point) != 0 || Q.equals(-qk.point) != 0);
+            assert!(parity as u32 == (Q.has_odd_y() & 1));
+            assert!(tsk.to_xonly_public_key().encode() == qk.encode());
+            let sig = tsk.sign_schnorr(&seed, &[0u8; 32]);
+            assert!(qk.verify(&sig, &seed));
+            assert!(!pk.verify(&sig, &seed));
+
+            let (qk2, _) = pk.tweak_add(&seed).unwrap();
+            let tsk2 = sk.tweak_add_xonly(&seed).unwrap();
+            assert!(tsk2.to_xonly_public_key().encode() == qk2.encode());
+        }
+        let n_enc: [u8; 32] = bswap32(&(-Scalar::ONE).encode());
+        let mut n_enc_p1 = n_enc;
+        n_enc_p1[31] = n_enc_p1[31].wrapping_add(1);
+        assert!(XOnlyPublicKey { point: Point::BASE }.tweak_add(&n_enc).is_none());
+        assert!(XOnlyPublicKey { point: Point::BASE }.tweak_add(&n_enc_p1).is_none());
+    }
+
+    #[test]
+    fn adaptor() {
+        let mut sh = Sha256::new();
+        for i in 0..20 {
+            sh.update((i as u64).to_le_bytes());
+            let seed: [u8; 32] = sh.finalize_reset().into();
+            let sk = PrivateKey::from_seed(&seed);
+            sh.update(seed);
+            let t = Scalar::decode_reduce(&sh.finalize_reset());
+            let T = Point::mulgen(&t);
+            let msg = &seed[..(i + 1)];
+
+            // BIP-340 Schnorr.
+            let xpk = sk.to_xonly_public_key();
+            let aux = [i as u8; 32];
+            let presig = sk.presign_schnorr(msg, &T, &aux);
+            assert!(xpk.verify_schnorr_presig(&presig, msg, &T));
+            assert!(!xpk.verify_schnorr_presig(&presig, &seed[..i], &T));
+            assert!(!xpk.verify_schnorr_presig(&presig, msg, &T.double()));
+            let enc = presig.encode();
+            let presig = SchnorrPreSignature::decode(&enc).unwrap();
+            assert!(presig.encode() == enc);
+            let sig = presig.adapt(&t);
+            assert!(xpk.verify(&sig, msg));
+            assert!(!xpk.verify(&presig.adapt(&(t + Scalar::ONE)), msg));
+            assert!(presig.extract(&sig).unwrap().equals(t) != 0);
+            let sig2 = sk.sign_schnorr(msg, &aux);
+            assert!(presig.extract(&sig2).is_none());
+
+            // ECDSA (one-time verifiably encrypted signature).
+            let pk = sk.to_public_key();
+            let presig = sk.presign_ecdsa(&seed, &T, &[]);
+            assert!(pk.verify_ecdsa_presig(&presig, &seed, &T));
+            assert!(!pk.verify_ecdsa_presig(&presig, &seed[1..], &T));
+            assert!(!pk.verify_ecdsa_presig(&presig, &seed, &T.double()));
+            let enc = presig.encode();
+            let presig = EcdsaPreSignature::decode(&enc).unwrap();
+            assert!(presig.encode() == enc);
+            let mut sig = presig.adapt(&t);
+            assert!(pk.verify_hash(&sig, &seed));
+            assert!(presig.extract(&sig, &T).unwrap().equals(t) != 0);
+
+            // Extraction still works after normalization to low-S.
+            let (s, _) = Scalar::decode32(&bswap32(&sig[32..]));
+            sig[32..].copy_from_slice(&bswap32(&(-s).encode()));
+            assert!(pk.verify_hash(&sig, &seed));
+            assert!(presig.extract(&sig, &T).unwrap().equals(t) != 0);
+            let sig2 = sk.sign_hash(&seed, &[]);
+            assert!(presig.extract(&sig2, &T).is_none());
+
+            // The pre-signature must not be a valid signature.
+            let mut sig3 = [0u8; 64];
+            sig3[..32].copy_from_slice(&sig[..32]);
+            sig3[32..].copy_from_slice(&enc[66..98]);
+            assert!(!pk.verify_hash(&sig3, &seed));
+        }
+    }
+
+    // Test vectors from BIP-340 (file bip-0340/test-vectors.csv).
+    static BIP340_VECTORS: &str = concat!(
+        "index,secret key,public key,aux_rand,message,signature,verification result,comment\n",
+        "0,0000000000000000000000000000000000000000000000000000000000000003,F9308A019258C31049344F85F89D5229B531C845836F99B08601F113BCE036F9,0000000000000000000000000000000000000000000000000000000000000000,0000000000000000000000000000000000000000000000000000000000000000,E907831F80848D1069A5371B402410364BDF1C5F8307B0084C55F1CE2DCA821525F66A4A85EA8B71E482A74F382D2CE5EBEEE8FDB2172F477DF4900D310536C0,TRUE,\n",
+        "1,B7E151628AED2A6ABF7158809CF4F3C762E7160F38B4DA56A784D9045190CFEF,DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659,0000000000000000000000000000000000000000000000000000000000000001,243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89,6896BD60EEAE296DB48A229FF71DFE071BDE413E6D43F917DC8DCF8C78DE33418906D11AC976ABCCB20B091292BFF4EA897EFCB639EA871CFA95F6DE339E4B0A,TRUE,\n",
+        "2,C90FDAA22168C234C4C6628B80DC1CD129024E088A67CC74020BBEA63B14E5C9,DD308AFEC5777E13121FA72B9CC1B7CC0139715309B086C960E18FD969774EB8,C87AA53824B4D7AE2EB035A2B5BBBCCC080E76CDC6D1692C4B0B62D798E6D906,7E2D58D8B3BCDF1ABADEC7829054F90DDA9805AAB56C77333024B9D0A508B75C,5831AAEED7B44BB74E5EAB94BA9D4294C49BCF2A60728D8B4C200F50DD313C1BAB745879A5AD954A72C45A91C3A51D3C7ADEA98D82F8481E0E1E03674A6F3FB7,TRUE,\n",
+        "3,0B432B2677937381AEF05BB02A66ECD012773062CF3FA2549E44F58ED2401710,25D1DFF95105F5253C4022F628A996AD3A0D95FBF21D468A1B33F8C160D8F517,FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF,FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF,7EB0509757E246F19449885651611CB965ECC1A187DD51B64FDA1EDC9637D5EC97582B9CB13DB3933705B32BA982AF5AF25FD78881EBB32771FC5922EFC66EA3,TRUE,test fails if msg is reduced modulo p or n\n",
+        "4,,D69C3509BB99E412E68B0FE8544E72837DFA30746D8BE2AA65975F29D22DC7B9,,4DF3C3F68FCC83B27E9D42C90431A72499F17875C81A599B566C9889B9696703,00000000000000000000003B78CE563F89A0ED9414F5AA28AD0D96D6795F9C6376AFB1548AF603B3EB45C9F8207DEE1060CB71C04E80F593060B07D28308D7F4,TRUE,\n",
+        "5,,EEFDEA4CDB677750A420FEE807EACF21EB9898AE79B9768766E4FAA04A2D4A34,,243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89,6CFF5C3BA86C69EA4B7376F31A9BCB4F74C1976089B2D9963DA2E5543E17776969E89B4C5564D00349106B8497785DD7D1D713A8AE82B32FA79D5F7FC407D39B,FALSE,public key not on the curve\n",
+        "6,,DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659,,243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89,FFF97BD5755EEEA420453A14355235D382F6472F8568A18B2F057A14602975563CC27944640AC607CD107AE10923D9EF7A73C643E166BE5EBEAFA34B1AC553E2,FALSE,has_even_y(R) is false\n",
+        "7,,DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659,,243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89,1FA62E331EDBC21C394792D2AB1100A7B432B013DF3F6FF4F99FCB33E0E1515F28890B3EDB6E7189B630448B515CE4F8622A954CFE545735AAEA5134FCCDB2BD,FALSE,negated message\n",
+        "8,,DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659,,243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89,6CFF5C3BA86C69EA4B7376F31A9BCB4F74C1976089B2D9963DA2E5543E177769961764B3AA9B2FFCB6EF947B6887A226E8D7C93E00C5ED0C1834FF0D0C2E6DA6,FALSE,negated s value\n",
+        "9,,DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659,,243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89,0000000000000000000000000000000000000000000000000000000000000000123DDA8328AF9C23A94C1FEECFD123BA4FB73476F0D594DCB65C6425BD186051,FALSE,sG - eP is infinite. Test fails in single verification if has_even_y(inf) is defined as true and x(inf) as 0\n",
+        "10,,DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659,,243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89,00000000000000000000000000000000000000000000000000000000000000017615FBAF5AE28864013C099742DEADB4DBA87F11AC6754F93780D5A1837CF197,FALSE,sG - eP is infinite. Test fails in single verification if has_even_y(inf) is defined as true and x(inf) as 1\n",
+        "11,,DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659,,243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89,4A298DACAE57395A15D0795DDBFD1DCB564DA82B0F269BC70A74F8220429BA1D69E89B4C5564D00349106B8497785DD7D1D713A8AE82B32FA79D5F7FC407D39B,FALSE,sig[0:32] is not an X coordinate on the curve\n",
+        "12,,DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659,,243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89,FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F69E89B4C5564D00349106B8497785DD7D1D713A8AE82B32FA79D5F7FC407D39B,FALSE,sig[0:32] is equal to field size\n",
+        "13,,DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659,,243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89,6CFF5C3BA86C69EA4B7376F31A9BCB4F74C1976089B2D9963DA2E5543E177769FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141,FALSE,sig[32:64] is equal to curve order\n",
+        "14,,FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC30,,243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89,6CFF5C3BA86C69EA4B7376F31A9BCB4F74C1976089B2D9963DA2E5543E17776969E89B4C5564D00349106B8497785DD7D1D713A8AE82B32FA79D5F7FC407D39B,FALSE,public key is not a valid X coordinate because it exceeds the field size\n",
+        "15,0340034003400340034003400340034003400340034003400340034003400340,778CAA53B4393AC467774D09497A87224BF9FAB6F6E68B23086497324D6FD117,0000000000000000000000000000000000000000000000000000000000000000,,71535DB165ECD9FBBC046E5FFAEA61186BB6AD436732FCCC25291A55895464CF6069CE26BF03466228F19A3A62DB8A649F2D560FAC652827D1AF0574E427AB63,TRUE,message of size 0 (added 2022-12)\n",
+        "16,0340034003400340034003400340034003400340034003400340034003400340,778CAA53B4393AC467774D09497A87224BF9FAB6F6E68B23086497324D6FD117,0000000000000000000000000000000000000000000000000000000000000000,11,08A20A0AFEF64124649232E0693C583AB1B9934AE63B4C3511F3AE1134C6A303EA3173BFEA6683BD101FA5AA5DBC1996FE7CACFC5A577D33EC14564CEC2BACBF,TRUE,message of size 1 (added 2022-12)\n",
+        "17,0340034003400340034003400340034003400340034003400340034003400340,778CAA53B4393AC467774D09497A87224BF9FAB6F6E68B23086497324D6FD117,0000000000000000000000000000000000000000000000000000000000000000,0102030405060708090A0B0C0D0E0F1011,5130F39A4059B43BC7CAC09A19ECE52B5D8699D1A71E3C52DA9AFDB6B50AC370C4A482B77BF960F8681540E25B6771ECE1E5A37FD80E5A51897C5566A97EA5A5,TRUE,message of size 17 (added 2022-12)\n",
+        "18,0340034003400340034003400340034003400340034003400340034003400340,778CAA53B4393AC467774D09497A87224BF9FAB6F6E68B23086497324D6FD117,0000000000000000000000000000000000000000000000000000000000000000,99999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999,403B12B0D8555A344175EA7EC746566303321E5DBFA8BE6F091635163ECA79A8585ED3E3170807E7C03B720FC54C7B23897FCBA0E9D0B4A06894CFD249F22367,TRUE,message of size 100 (added 2022-12)\n",
+    );
+
+    // Wycheproof test vectors (file ecdsa_secp256k1_sha256_bitcoin_test.json). Each line
+    // contains the public key (uncompressed format, omitted when the
+    // same as in the previous line), the message (hashed with SHA-256),
+    // the DER-encoded signature, and the expected result (1 = valid).
+    static WYCHEPROOF_VECTORS: &str = concat!(
+        "04b838ff44e5bc177bf21189d0766082fc9d843226887fc9760371100b7ee20a6ff0c9d75bfba7b31a6bca1974496eeb56de357071955d83c4b1badaa0b21832e9,313233343030,3046022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc9832365022100900e75ad233fcc908509dbff5922647db37c21f4afd3203ae8dc4ae7794b0f87,0\n",
+        ",313233343030,3045022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,1\n",
+        ",313233343030,308145022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,30820045022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,3046022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,3044022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,30850100000045022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,3089010000000000000045022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,30847fffffff022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,308480000000022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,3084ffffffff022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,3085ffffffffff022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,3088ffffffffffffffff022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,30ff022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,3080022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,,0\n",
+        ",313233343030,30,0\n",
+        ",313233343030,3047022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba0000,0\n",
+        ",313233343030,30470000022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,3045022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba0000,0\n",
+        ",313233343030,3047022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba0500,0\n",
+        ",313233343030,304a4981773045022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,304925003045022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,30473045022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba0004deadbeef,0\n",
+        ",313233343030,304daa00bb00cd003045022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,304d2229aa00bb00cd00022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,304d022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc98323652228aa00bb00cd0002206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,3081,0\n",
+        ",313233343030,304baa02aabb3045022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,30803045022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba0000,0\n",
+        ",313233343030,30803145022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba0000,0\n",
+        ",313233343030,0500,0\n",
+        ",313233343030,2e45022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,2f45022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,3145022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,3245022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,ff45022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,3000,0\n",
+        ",313233343030,304930010230442100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,3044022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31,0\n",
+        ",313233343030,30442100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,30821046022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000,0\n",
+        ",313233343030,3080022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba0000,0\n",
+        ",313233343030,3080022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba00,0\n",
+        ",313233343030,3080022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba05000000,0\n",
+        ",313233343030,3080022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba060811220000,0\n",
+        ",313233343030,3080022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba0000fe02beef,0\n",
+        ",313233343030,3080022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba0002beef,0\n",
+        ",313233343030,30473000022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,3047022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba3000,0\n",
+        ",313233343030,3048022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba020100,0\n",
+        ",313233343030,3048022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31babf7f00,0\n",
+        ",313233343030,3049022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31baa0020500,0\n",
+        ",313233343030,3047022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31baa000,0\n",
+        ",313233343030,30473045022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,3023022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc9832365,0\n",
+        ",313233343030,3067022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba02206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,304300813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236402206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,304300813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccac983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,304300813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5133ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,304300813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc08b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,304602812100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,30470282002100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,3045022200813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,3045022000813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,304a0285010000002100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,304e028901000000000000002100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,304902847fffffff00813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,304902848000000000813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,30490284ffffffff00813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,304a0285ffffffffff00813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,304d0288ffffffffffffffff00813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,304502ff00813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,3045028000813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,302202206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,30230202206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,3024022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502,0\n",
+        ",313233343030,3047022300813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc9832365000002206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,30470223000000813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,3047022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc9832365000002206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,3047022300813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc9832365050002206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,304a2226498177022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,304922252500022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,304d2223022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc98323650004deadbeef02206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,3024028102206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,304b2227aa02aabb022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,30492280022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc9832365000002206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,30492280032100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc9832365000002206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,3024050002206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,3045002100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,3045012100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,3045032100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,3045042100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,3045ff2100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,3024020002206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,304922250201000220813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,3045022102813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,3045022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc98323e502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,3044022000813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc9832302206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,30440220813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,308210480282102200813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc9832365000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000002206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,30460222ff00813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,302509018002206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,302502010002206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,3043022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc98323656ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31bb,0\n",
+        ",313233343030,3043022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc98323656ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a456eb31ba,0\n",
+        ",313233343030,3043022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc98323656ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f713a556eb31ba,0\n",
+        ",313233343030,3043022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc98323656ff18a52dcc0336f7af62400a6dd9b810732baf1ff758001d6f613a556eb31ba,0\n",
+        ",313233343030,3046022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc98323650281206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,3047022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc9832365028200206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,3045022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502216ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,3045022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc9832365021f6ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,304a022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc9832365028501000000206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,304e022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502890100000000000000206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,3049022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502847fffffff6ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,3049022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc98323650284800000006ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,3049022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc98323650284ffffffff6ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,304a022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc98323650285ffffffffff6ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,304d022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc98323650288ffffffffffffffff6ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,3045022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502ff6ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,3045022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502806ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,3047022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502226ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba0000,0\n",
+        ",313233343030,3047022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc9832365022200006ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,3047022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502226ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba0500,0\n",
+        ",313233343030,304a022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc9832365222549817702206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,3049022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc98323652224250002206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,304d022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc9832365222202206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba0004deadbeef,0\n",
+        ",313233343030,3025022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc98323650281,0\n",
+        ",313233343030,304b022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc98323652226aa02aabb02206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,3049022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc9832365228002206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba0000,0\n",
+        ",313233343030,3049022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc9832365228003206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba0000,0\n",
+        ",313233343030,3025022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc98323650500,0\n",
+        ",313233343030,3045022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236500206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,3045022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236501206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,3045022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236503206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,3045022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236504206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,3045022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc9832365ff206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,3025022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc98323650200,0\n",
+        ",313233343030,3049022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc9832365222402016f021ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,3045022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206df18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,3045022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb313a,0\n",
+        ",313233343030,3044022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc9832365021f6ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31,0\n",
+        ",313233343030,3044022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc9832365021ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,30821048022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc9832365028210216ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000,0\n",
+        ",313233343030,3046022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc98323650221ff6ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,3026022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc9832365090180,0\n",
+        ",313233343030,3026022100813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc9832365020100,0\n",
+        ",313233343030,3045022101813ef79ccefa9a56f7ba805f0e478583b90deabca4b05c4574e49b5899b964a602206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,30440220813ef79ccefa9a56f7ba805f0e47858643b030ef461f1bcdf53fde3ef94ce22402206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,304602220100813ef79ccefa9a56f7ba805f0e47843fad3bf4853e07f7c98770c99bffc4646502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,30450221ff7ec10863310565a908457fa0f1b87a7b01a0f22a0a9843f64aedc334367cdc9b02206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,304402207ec10863310565a908457fa0f1b87a79bc4fcf10b9e0e4320ac021c106b31ddc02206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,30450221fe7ec10863310565a908457fa0f1b87a7c46f215435b4fa3ba8b1b64a766469b5a02206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,3045022101813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,304d0229010000000000000000813ef79ccefa9a56f7ba805f0e478584fe5f0dd5f567bc09b5123ccbc983236502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,30450221016ff18a52dcc0336f7af62400a6dd9b7fc1e197d8aebe203c96c87232272172fb02206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,30450221ff6ff18a52dcc0336f7af62400a6dd9b824c83de0b502cdfc51723b51886b4f07902206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,3046022201006ff18a52dcc0336f7af62400a6dd9a3bb60fa1a14815bbc0a954a0758d2c72ba02206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,30440220900e75ad233fcc908509dbff5922647ef8cd450e008a7fff2909ec5aa914ce4602206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,30450221fe900e75ad233fcc908509dbff592264803e1e68275141dfc369378dcdd8de8d0502206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,30450221016ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba02206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,30450221ff6ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba02206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,304d02290100000000000000006ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba02206ff18a52dcc0336f7af62400a6dd9b810732baf1ff758000d6f613a556eb31ba,0\n",
+        ",313233343030,3006020100020100,0\n",
+        ",313233343030,3006020100020101,0\n",
+        ",313233343030,30060201000201ff,0\n",
+        ",313233343030,3026020100022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141,0\n",
+        ",313233343030,3026020100022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364140,0\n",
+        ",313233343030,3026020100022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364142,0\n",
+        ",313233343030,3026020100022100fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f,0\n",
+        ",313233343030,3026020100022100fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc30,0\n",
+        ",313233343030,3006020101020100,0\n",
+        ",313233343030,3006020101020101,0\n",
+        ",313233343030,30060201010201ff,0\n",
+        ",313233343030,3026020101022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141,0\n",
+        ",313233343030,3026020101022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364140,0\n",
+        ",313233343030,3026020101022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364142,0\n",
+        ",313233343030,3026020101022100fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f,0\n",
+        ",313233343030,3026020101022100fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc30,0\n",
+        ",313233343030,30060201ff020100,0\n",
+        ",313233343030,30060201ff020101,0\n",
+        ",313233343030,30060201ff0201ff,0\n",
+        ",313233343030,30260201ff022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141,0\n",
+        ",313233343030,30260201ff022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364140,0\n",
+        ",313233343030,30260201ff022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364142,0\n",
+        ",313233343030,30260201ff022100fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f,0\n",
+        ",313233343030,30260201ff022100fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc30,0\n",
+        ",313233343030,3026022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141020100,0\n",
+        ",313233343030,3026022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141020101,0\n",
+        ",313233343030,3026022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd03641410201ff,0\n",
+        ",313233343030,3046022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141,0\n",
+        ",313233343030,3046022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364140,0\n",
+        ",313233343030,3046022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364142,0\n",
+        ",313233343030,3046022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141022100fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f,0\n",
+        ",313233343030,3046022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141022100fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc30,0\n",
+        ",313233343030,3026022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364140020100,0\n",
+        ",313233343030,3026022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364140020101,0\n",
+        ",313233343030,3026022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd03641400201ff,0\n",
+        ",313233343030,3046022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364140022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141,0\n",
+        ",313233343030,3046022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364140022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364140,0\n",
+        ",313233343030,3046022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364140022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364142,0\n",
+        ",313233343030,3046022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364140022100fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f,0\n",
+        ",313233343030,3046022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364140022100fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc30,0\n",
+        ",313233343030,3026022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364142020100,0\n",
+        ",313233343030,3026022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364142020101,0\n",
+        ",313233343030,3026022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd03641420201ff,0\n",
+        ",313233343030,3046022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364142022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141,0\n",
+        ",313233343030,3046022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364142022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364140,0\n",
+        ",313233343030,3046022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364142022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364142,0\n",
+        ",313233343030,3046022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364142022100fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f,0\n",
+        ",313233343030,3046022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364142022100fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc30,0\n",
+        ",313233343030,3026022100fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f020100,0\n",
+        ",313233343030,3026022100fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f020101,0\n",
+        ",313233343030,3026022100fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f0201ff,0\n",
+        ",313233343030,3046022100fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141,0\n",
+        ",313233343030,3046022100fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364140,0\n",
+        ",313233343030,3046022100fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364142,0\n",
+        ",313233343030,3046022100fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f022100fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f,0\n",
+        ",313233343030,3046022100fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f022100fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc30,0\n",
+        ",313233343030,3026022100fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc30020100,0\n",
+        ",313233343030,3026022100fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc30020101,0\n",
+        ",313233343030,3026022100fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc300201ff,0\n",
+        ",313233343030,3046022100fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc30022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141,0\n",
+        ",313233343030,3046022100fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc30022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364140,0\n",
+        ",313233343030,3046022100fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc30022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364142,0\n",
+        ",313233343030,3046022100fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc30022100fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f,0\n",
+        ",313233343030,3046022100fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc30022100fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc30,0\n",
+        ",313233343030,3008020100090380fe01,0\n",
+        ",313233343030,3006020100090142,0\n",
+        ",313233343030,3006020100010101,0\n",
+        ",313233343030,3006020100010100,0\n",
+        ",313233343030,30050201000500,0\n",
+        ",313233343030,30050201000c00,0\n",
+        ",313233343030,30060201000c0130,0\n",
+        ",313233343030,30050201003000,0\n",
+        ",313233343030,30080201003003020100,0\n",
+        ",313233343030,3008020101090380fe01,0\n",
+        ",313233343030,3006020101090142,0\n",
+        ",313233343030,3006020101010101,0\n",
+        ",313233343030,3006020101010100,0\n",
+        ",313233343030,30050201010500,0\n",
+        ",313233343030,30050201010c00,0\n",
+        ",313233343030,30060201010c0130,0\n",
+        ",313233343030,30050201013000,0\n",
+        ",313233343030,30080201013003020100,0\n",
+        ",313233343030,30080201ff090380fe01,0\n",
+        ",313233343030,30060201ff090142,0\n",
+        ",313233343030,30060201ff010101,0\n",
+        ",313233343030,30060201ff010100,0\n",
+        ",313233343030,30050201ff0500,0\n",
+        ",313233343030,30050201ff0c00,0\n",
+        ",313233343030,30060201ff0c0130,0\n",
+        ",313233343030,30050201ff3000,0\n",
+        ",313233343030,30080201ff3003020100,0\n",
+        ",313233343030,3028022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141090380fe01,0\n",
+        ",313233343030,3026022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141090142,0\n",
+        ",313233343030,3026022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141010101,0\n",
+        ",313233343030,3026022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141010100,0\n",
+        ",313233343030,3025022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd03641410500,0\n",
+        ",313233343030,3025022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd03641410c00,0\n",
+        ",313233343030,3026022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd03641410c0130,0\n",
+        ",313233343030,3025022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd03641413000,0\n",
+        ",313233343030,3028022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd03641413003020100,0\n",
+        ",313233343030,3028022100fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f090380fe01,0\n",
+        ",313233343030,3026022100fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f090142,0\n",
+        ",313233343030,3026022100fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f010101,0\n",
+        ",313233343030,3026022100fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f010100,0\n",
+        ",313233343030,3025022100fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f0500,0\n",
+        ",313233343030,3025022100fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f0c00,0\n",
+        ",313233343030,3026022100fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f0c0130,0\n",
+        ",313233343030,3025022100fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f3000,0\n",
+        ",313233343030,3028022100fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f3003020100,0\n",
+        ",313233343030,300a090380fe01090380fe01,0\n",
+        ",313233343030,3006090142090142,0\n",
+        ",313233343030,3006010101010101,0\n",
+        ",313233343030,3006010100010100,0\n",
+        ",313233343030,300405000500,0\n",
+        ",313233343030,30040c000c00,0\n",
+        ",313233343030,30060c01300c0130,0\n",
+        ",313233343030,300430003000,0\n",
+        ",313233343030,300a30030201003003020100,0\n",
+        ",313233343030,3008090380fe01020100,0\n",
+        ",313233343030,3006090142020100,0\n",
+        ",313233343030,3006010101020100,0\n",
+        ",313233343030,3006010100020100,0\n",
+        ",313233343030,30050500020100,0\n",
+        ",313233343030,30050c00020100,0\n",
+        ",313233343030,30060c0130020100,0\n",
+        ",313233343030,30053000020100,0\n",
+        ",313233343030,30083003020100020100,0\n",
+        ",3235353835,3045022100dd1b7d09a7bd8218961034a39a87fecf5314f00c4d25eb58a07ac85e85eab516022035138c401ef8d3493d65c9002fe62b43aee568731b744548358996d9cc427e06,1\n",
+        ",343236343739373234,304502210095c29267d972a043d955224546222bba343fc1d4db0fec262a33ac61305696ae02206edfe96713aed56f8a28a6653f57e0b829712e5eddc67f34682b24f0676b2640,1\n",
+        ",37313338363834383931,3044022028f94a894e92024699e345fe66971e3edcd050023386135ab3939d550898fb25022032963e5bd41fa5911ed8f37deb86dae0a762bb6121c894615083c5d95ea01db3,1\n",
+        ",3130333539333331363638,3045022100be26b18f9549f89f411a9b52536b15aa270b84548d0e859a1952a27af1a77ac6022070c1d4fa9cd03cc8eaa8d506edb97eed7b8358b453c88aefbb880a3f0e8d472f,1\n",
+        ",33393439343031323135,3045022100b1a4b1478e65cc3eafdf225d1298b43f2da19e4bcff7eacc0a2e98cd4b74b1140220179aa31e304cc142cf5073171751b28f3f5e0fa88c994e7c55f1bc07b8d56c16,1\n",
+        ",31333434323933303739,30440220325332021261f1bd18f2712aa1e2252da23796da8a4b1ff6ea18cafec7e171f2022040b4f5e287ee61fc3c804186982360891eaa35c75f05a43ecd48b35d984a6648,1\n",
+        ",33373036323131373132,3045022100a23ad18d8fc66d81af0903890cbd453a554cb04cdc1a8ca7f7f78e5367ed88a0022023e3eb2ce1c04ea748c389bd97374aa9413b9268851c04dcd9f88e78813fee56,1\n",
+        ",333433363838373132,304402202bdea41cda63a2d14bf47353bd20880a690901de7cd6e3cc6d8ed5ba0cdb109102203cea66bccfc9f9bf8c7ca4e1c1457cc9145e13e936d90b3d9c7786b8b26cf4c7,1\n",
+        ",31333531353330333730,3045022100d7cd76ec01c1b1079eba9e2aa2a397243c4758c98a1ba0b7404a340b9b00ced602203575001e19d922e6de8b3d6c84ea43b5c3338106cf29990134e7669a826f78e6,1\n",
+        ",36353533323033313236,3045022100a872c744d936db21a10c361dd5c9063355f84902219652f6fc56dc95a7139d960220400df7575d9756210e9ccc77162c6b593c7746cfb48ac263c42750b421ef4bb9,1\n",
+        ",31353634333436363033,30450221009fa9afe07752da10b36d3afcd0fe44bfc40244d75203599cf8f5047fa3453854022050e0a7c013bfbf51819736972d44b4b56bc2a2b2c180df6ec672df171410d77a,1\n",
+        ",34343239353339313137,3045022100885640384d0d910efb177b46be6c3dc5cac81f0b88c3190bb6b5f99c2641f2050220738ed9bff116306d9caa0f8fc608be243e0b567779d8dab03e8e19d553f1dc8e,1\n",
+        ",3130393533323631333531,304402202d051f91c5a9d440c5676985710483bc4f1a6c611b10c95a2ff0363d90c2a45802206ddf94e6fba5be586833d0c53cf216ad3948f37953c26c1cf4968e9a9e8243dc,1\n",
+        ",35393837333530303431,3045022100f3ac2523967482f53d508522712d583f4379cd824101ff635ea0935117baa54f022027f10812227397e02cea96fb0e680761636dab2b080d1fc5d11685cbe8500cfe,1\n",
+        ",33343633303036383738,304502210096447cf68c3ab7266ed7447de3ac52fed7cc08cbdfea391c18a9b8ab370bc91302200f5e7874d3ac0e918f01c885a1639177c923f8660d1ceba1ca1f301bc675cdbc,1\n",
+        ",39383137333230323837,30440220530a0832b691da0b5619a0b11de6877f3c0971baaa68ed122758c29caaf46b7202206c89e44f5eb33060ea4b46318c39138eaedec72de42ba576579a6a4690e339f3,1\n",
+        ",33323232303431303436,30450221009c54c25500bde0b92d72d6ec483dc2482f3654294ca74de796b681255ed58a770220677453c6b56f527631c9f67b3f3eb621fd88582b4aff156d2f1567d6211a2a33,1\n",
+        ",36363636333037313034,3045022100e7909d41439e2f6af29136c7348ca2641a2b070d5b64f91ea9da7070c7a2618b022042d782f132fa1d36c2c88ba27c3d678d80184a5d1eccac7501f0b47e3d205008,1\n",
+        ",31303335393531383938,304402205924873209593135a4c3da7bb381227f8a4b6aa9f34fe5bb7f8fbc131a039ffe02201f1bb11b441c8feaa40f44213d9a405ed792d59fb49d5bcdd9a4285ae5693022,1\n",
+        ",31383436353937313935,3045022100eeb692c9b262969b231c38b5a7f60649e0c875cd64df88f33aa571fa3d29ab0e0220218b3a1eb06379c2c18cf51b06430786d1c64cd2d24c9b232b23e5bac7989acd,1\n",
+        ",33313336303436313839,3045022100a40034177f36091c2b653684a0e3eb5d4bff18e4d09f664c2800e7cafda1daf802203a3ec29853704e52031c58927a800a968353adc3d973beba9172cbbeab4dd149,1\n",
+        ",32363633373834323534,3045022100b5d795cc75cea5c434fa4185180cd6bd21223f3d5a86da6670d71d95680dadbf022054e4d8810a001ecbb9f7ca1c2ebfdb9d009e9031a431aca3c20ab4e0d1374ec1,1\n",
+        ",31363532313030353234,3044022007dc2478d43c1232a4595608c64426c35510051a631ae6a5a6eb1161e57e42e102204a59ea0fdb72d12165cea3bf1ca86ba97517bd188db3dbd21a5a157850021984,1\n",
+        ",35373438303831363936,3045022100ddd20c4a05596ca868b558839fce9f6511ddd83d1ccb53f82e5269d559a0155202205b91734729d93093ff22123c4a25819d7feb66a250663fc780cb66fc7b6e6d17,1\n",
+        ",36333433393133343638,30450221009cde6e0ede0a003f02fda0a01b59facfe5dec063318f279ce2de7a9b1062f7b702202886a5b8c679bdf8224c66f908fd6205492cb70b0068d46ae4f33a4149b12a52,1\n",
+        ",31353431313033353938,3045022100c5771016d0dd6357143c89f684cd740423502554c0c59aa8c99584f1ff38f609022054b405f4477546686e464c5463b4fd4190572e58d0f7e7357f6e61947d20715c,1\n",
+        ",3130343738353830313238,3045022100a24ebc0ec224bd67ae397cbe6fa37b3125adbd34891abe2d7c7356921916dfe6022034f6eb6374731bbbafc4924fb8b0bdcdda49456d724cdae6178d87014cb53d8c,1\n",
+        ",3130353336323835353638,304402202557d64a7aee2e0931c012e4fea1cd3a2c334edae68cdeb7158caf21b68e5a2402207f06cdbb6a90023a973882ed97b080fe6b05af3ec93db6f1a4399a69edf7670d,1\n",
+        ",393533393034313035,3045022100c4f2eccbb6a24350c8466450b9d61b207ee359e037b3dcedb42a3f2e6dd6aeb502203263c6b59a2f55cdd1c6e14894d5e5963b28bc3e2469ac9ba1197991ca7ff9c7,1\n",
+        ",393738383438303339,3045022100eff04781c9cbcd162d0a25a6e2ebcca43506c523385cb515d49ea38a1b12fcad022015acd73194c91a95478534f23015b672ebed213e45424dd2c8e26ac8b3eb34a5,1\n",
+        ",33363130363732343432,3045022100f58b4e3110a64bf1b5db97639ee0e5a9c8dfa49dc59b679891f520fdf0584c8702202cd8fe51888aee9db3e075440fd4db73b5c732fb87b510e97093d66415f62af7,1\n",
+        ",31303534323430373035,3045022100f8abecaa4f0c502de4bf5903d48417f786bf92e8ad72fec0bd7fcb7800c0bbe302204c7f9e231076a30b7ae36b0cebe69ccef1cd194f7cce93a5588fd6814f437c0e,1\n",
+        ",35313734343438313937,304402205d5b38bd37ad498b2227a633268a8cca879a5c7c94a4e416bd0a614d09e606d2022012b8d664ea9991062ecbb834e58400e25c46007af84f6007d7f1685443269afe,1\n",
+        ",31393637353631323531,304402200c1cd9fe4034f086a2b52d65b9d3834d72aebe7f33dfe8f976da82648177d8e3022013105782e3d0cfe85c2778dec1a848b27ac0ae071aa6da341a9553a946b41e59,1\n",
+        ",33343437323533333433,3045022100ae7935fb96ff246b7b5d5662870d1ba587b03d6e1360baf47988b5c02ccc1a5b02205f00c323272083782d4a59f2dfd65e49de0693627016900ef7e61428056664b3,1\n",
+        ",333638323634333138,3044022000a134b5c6ccbcefd4c882b945baeb4933444172795fa6796aae1490675470980220566e46105d24d890151e3eea3ebf88f5b92b3f5ec93a217765a6dcbd94f2c55b,1\n",
+        ",33323631313938363038,304402202e4721363ad3992c139e5a1c26395d2c2d777824aa24fde075e0d7381171309d0220740f7c494418e1300dd4512f782a58800bff6a7abdfdd20fbbd4f05515ca1a4f,1\n",
+        ",39363738373831303934,304402206852e9d3cd9fe373c2d504877967d365ab1456707b6817a042864694e1960ccf0220064b27ea142b30887b84c86adccb2fa39a6911ad21fc7e819f593be52bc4f3bd,1\n",
+        ",34393538383233383233,30440220188a8c5648dc79eace158cf886c62b5468f05fd95f03a7635c5b4c31f09af4c5022036361a0b571a00c6cd5e686ccbfcfa703c4f97e48938346d0c103fdc76dc5867,1\n",
+        ",383234363337383337,3045022100a74f1fb9a8263f62fc4416a5b7d584f4206f3996bb91f6fc8e73b9e92bad0e1302206815032e8c7d76c3ab06a86f33249ce9940148cb36d1f417c2e992e801afa3fa,1\n",
+        ",3131303230383333373736,3044022007244865b72ff37e62e3146f0dc14682badd7197799135f0b00ade7671742bfe02200d80c2238edb4e4a7a86a8c57ca9af1711f406f7f5da0299aa04e2932d960754,1\n",
+        ",313333383731363438,3045022100da7fdd05b5badabd619d805c4ee7d9a84f84ddd5cf9c5bf4d4338140d689ef08022028f1cf4fa1c3c5862cfa149c0013cf5fe6cf5076cae000511063e7de25bb38e5,1\n",
+        ",333232313434313632,3045022100d3027c656f6d4fdfd8ede22093e3c303b0133c340d615e7756f6253aea927238022009aef060c8e4cef972974011558df144fed25ca69ae8d0b2eaf1a8feefbec417,1\n",
+        ",3130363836363535353436,304402200bf6c0188dc9571cd0e21eecac5fbb19d2434988e9cc10244593ef3a98099f6902204864a562661f9221ec88e3dd0bc2f6e27ac128c30cc1a80f79ec670a22b042ee,1\n",
+        ",3632313535323436,3045022100ae459640d5d1179be47a47fa538e16d94ddea5585e7a244804a51742c686443a02206c8e30e530a634fae80b3ceb062978b39edbe19777e0a24553b68886181fd897,1\n",
+        ",37303330383138373734,304402201cf3517ba3bf2ab8b9ead4ebb6e866cb88a1deacb6a785d3b63b483ca02ac4950220249a798b73606f55f5f1c70de67cb1a0cff95d7dc50b3a617df861bad3c6b1c9,1\n",
+        ",35393234353233373434,3045022100e69b5238265ea35d77e4dd172288d8cea19810a10292617d5976519dc5757cb802204b03c5bc47e826bdb27328abd38d3056d77476b2130f3df6ec4891af08ba1e29,1\n",
+        ",31343935353836363231,304402205f9d7d7c870d085fc1d49fff69e4a275812800d2cf8973e7325866cb40fa2b6f02206d1f5491d9f717a597a15fd540406486d76a44697b3f0d9d6dcef6669f8a0a56,1\n",
+        ",34303035333134343036,304402200a7d5b1959f71df9f817146ee49bd5c89b431e7993e2fdecab6858957da685ae02200f8aad2d254690bdc13f34a4fec44a02fd745a422df05ccbb54635a8b86b9609,1\n",
+        ",33303936343537353132,3044022079e88bf576b74bc07ca142395fda28f03d3d5e640b0b4ff0752c6d94cd553408022032cea05bd2d706c8f6036a507e2ab7766004f0904e2e5c5862749c0073245d6a,1\n",
+        ",32373834303235363230,30450221009d54e037a00212b377bc8874798b8da080564bbdf7e07591b861285809d01488022018b4e557667a82bd95965f0706f81a29243fbdd86968a7ebeb43069db3b18c7f,1\n",
+        ",32363138373837343138,304402202664f1ffa982fedbcc7cab1b8bc6e2cb420218d2a6077ad08e591ba9feab33bd022049f5c7cb515e83872a3d41b4cdb85f242ad9d61a5bfc01debfbb52c6c84ba728,1\n",
+        ",31363432363235323632,304402205827518344844fd6a7de73cbb0a6befdea7b13d2dee4475317f0f18ffc81524b02204f5ccb4e0b488b5a5d760aacddb2d791970fe43da61eb30e2e90208a817e46db,1\n",
+        ",36383234313839343336,304502210097ab19bd139cac319325869218b1bce111875d63fb12098a04b0cd59b6fdd3a30220431d9cea3a243847303cebda56476431d034339f31d785ee8852db4f040d4921,1\n",
+        ",343834323435343235,3044022052c683144e44119ae2013749d4964ef67509278f6d38ba869adcfa69970e123d02203479910167408f45bda420a626ec9c4ec711c1274be092198b4187c018b562ca,1\n",
+        "0407310f90a9eae149a08402f54194a0f7b4ac427bf8d9bd6c7681071dc47dc36226a6d37ac46d61fd600c0bf1bff87689ed117dda6b0e59318ae010a197a26ca0,313233343030,30160211014551231950b75fc4402da1722fc9baeb020103,1\n",
+        ",313233343030,3026022100fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2c020103,0\n",
+        "04bc97e7585eecad48e16683bc4091708e1a930c683fc47001d4b383594f2c4e22705989cf69daeadd4e4e4b8151ed888dfec20fb01728d89d56b3f38f2ae9c8c5,313233343030,3026022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd036413f020103,1\n",
+        "0444ad339afbc21e9abf7b602a5ca535ea378135b6d10d81310bdd8293d1df3252b63ff7d0774770f8fe1d1722fa83acd02f434e4fc110a0cc8f6dddd37d56c463,313233343030,304402207ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffc02203e9a7582886089c62fb840cf3b83061cd1cff3ae4341808bb5bdee6191174177,1\n",
+        "041260c2122c9e244e1af5151bede0c3ae23b54d7c596881d3eebad21f37dd878c5c9a0c1a9ade76737a8811bd6a7f9287c978ee396aa89c11e47229d2ccb552f0,313233343030,304402207ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffc022024238e70b431b1a64efdf9032669939d4b77f249503fc6905feb7540dea3e6d2,1\n",
+        "041877045be25d34a1d0600f9d5c00d0645a2a54379b6ceefad2e6bf5c2a3352ce821a532cc1751ee1d36d41c3d6ab4e9b143e44ec46d73478ea6a79a5c0e54159,313233343030,3006020101020101,1\n",
+        "04455439fcc3d2deeceddeaece60e7bd17304f36ebb602adf5a22e0b8f1db46a50aec38fb2baf221e9a8d1887c7bf6222dd1834634e77263315af6d23609d04f77,313233343030,3006020101020102,1\n",
+        "042e1f466b024c0c3ace2437de09127fed04b706f94b19a21bb1c2acf35cece7180449ae3523d72534e964972cfd3b38af0bddd9619e5af223e4d1a40f34cf9f1d,313233343030,3006020101020103,1\n",
+        "048e7abdbbd18de7452374c1879a1c3b01d13261e7d4571c3b47a1c76c55a2337326ed897cd517a4f5349db809780f6d2f2b9f6299d8b5a89077f1119a718fd7b3,313233343030,3006020102020101,1\n",
+        "047b333d4340d3d718dd3e6aff7de7bbf8b72bfd616c8420056052842376b9af1942117c5afeac755d6f376fc6329a7d76051b87123a4a5d0bc4a539380f03de7b,313233343030,3006020102020102,1\n",
+        "04d30ca4a0ddb6616c851d30ced682c40f83c62758a1f2759988d6763a88f1c0e503a80d5415650d41239784e8e2fb1235e9fe991d112ebb81186cbf0da2de3aff,313233343030,3006020102020103,1\n",
+        ",313233343030,3026022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364143020103,0\n",
+        "0448969b39991297b332a652d3ee6e01e909b39904e71fa2354a7830c7750baf24b4012d1b830d199ccb1fc972b32bfded55f09cd62d257e5e844e27e57a1594ec,313233343030,30080201020203ed2979,0\n",
+        "0402ef4d6d6cfd5a94f1d7784226e3e2a6c0a436c55839619f38fb4472b5f9ee777eb4acd4eebda5cd72875ffd2a2f26229c2dc6b46500919a432c86739f3ae866,313233343030,30260202010102203a74e9d3a74e9d3a74e9d3a74e9d3a749f8ab3732a0a89604a09bce5b2916da4,1\n",
+        "04464f4ff715729cae5072ca3bd801d3195b67aec65e9b01aad20a2943dcbcb584b1afd29d31a39a11d570aa1597439b3b2d1971bf2f1abf15432d0207b10d1d08,313233343030,302b02072d9b4d347952cc02200343aefc2f25d98b882e86eb9e30d55a6eb508b516510b34024ae4b6362330b3,1\n",
+        "04157f8fddf373eb5f49cfcf10d8b853cf91cbcd7d665c3522ba7dd738ddb79a4cdeadf1a5c448ea3c9f4191a8999abfcc757ac6d64567ef072c47fec613443b8f,313233343030,3031020d1033e67e37b32b445580bf4efc02206f906f906f906f906f906f906f906f8fe1cab5eefdb214061dce3b22789f1d6f,1\n",
+        "040934a537466c07430e2c48feb990bb19fb78cecc9cee424ea4d130291aa237f0d4f92d23b462804b5b68c52558c01c9996dbf727fccabbeedb9621a400535afa,313233343030,3026020201010220783266e90f43dafe5cd9b3b0be86de22f9de83677d0f50713a468ec72fcf5d57,1\n",
+        "04d6ef20be66c893f741a9bf90d9b74675d1c2a31296397acb3ef174fd0b300c654a0c95478ca00399162d7f0f2dc89efdc2b28a30fbabe285857295a4b0c4e265,313233343030,3031020d062522bbd3ecbe7c39e93e7c260220783266e90f43dafe5cd9b3b0be86de22f9de83677d0f50713a468ec72fcf5d57,1\n",
+        "04b7291d1404e0c0c07dab9372189f4bd58d2ceaa8d15ede544d9514545ba9ee0629c9a63d5e308769cc30ec276a410e6464a27eeafd9e599db10f053a4fe4a829,313233343030,3045022100fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd03640c1022055555555555555555555555555555554e8e4f44ce51835693ff0ca2ef01215c0,1\n",
+        "046e28303305d642ccb923b722ea86b2a0bc8e3735ecb26e849b19c9f76b2fdbb8186e80d64d8cab164f5238f5318461bf89d4d96ee6544c816c7566947774e0f6,313233343030,30160209009c44febf31c3594d020900839ed28247c2b06b,1\n",
+        "04375bda93f6af92fb5f8f4b1b5f0534e3bafab34cb7ad9fb9d0b722e4a5c302a9a00b9f387a5a396097aa2162fc5bbcf4a5263372f681c94da51e9799120990fd,313233343030,301e020d09df8b682430beef6f5fd7c7cf020d0fd0a62e13778f4222a0d61c8a,1\n",
+        "04d75b68216babe03ae257e94b4e3bf1c52f44e3df266d1524ff8c5ea69da73197da4bff9ed1c53f44917a67d7b978598e89df359e3d5913eaea24f3ae259abc44,313233343030,30260211008a598e563a89f526c32ebec8de26367a02110084f633e2042630e99dd0f1e16f7a04bf,1\n",
+        "0478bcda140aed23d430cb23c3dc0d01f423db134ee94a3a8cb483f2deac2ac653118114f6f33045d4e9ed9107085007bfbddf8f58fe7a1a2445d66a990045476e,313233343030,302e021500aa6eeb5823f7fa31b466bb473797f0d0314c0bdf021500e2977c479e6d25703cebbc6bd561938cc9d1bfb9,1\n",
+        "04bb79f61857f743bfa1b6e7111ce4094377256969e4e15159123d9548acc3be6c1f9d9f8860dcffd3eb36dd6c31ff2e7226c2009c4c94d8d7d2b5686bf7abd677,313233343030,3025022055555555555555555555555555555554e8e4f44ce51835693ff0ca2ef01215c1020101,1\n",
+        ",313233343030,3025022055555555555555555555555555555554e8e4f44ce51835693ff0ca2ef01215c1020100,0\n",
+        "0493591827d9e6713b4e9faea62c72b28dfefa68e0c05160b5d6aae88fd2e36c36073f5545ad5af410af26afff68654cf72d45e493489311203247347a890f4518,313233343030,3044022055555555555555555555555555555554e8e4f44ce51835693ff0ca2ef01215c10220419d981c515af8cc82545aac0c85e9e308fbb2eab6acd7ed497e0b4145a18fd9,1\n",
+        "0431ed3081aefe001eb6402069ee2ccc1862937b85995144dba9503943587bf0dada01b8cc4df34f5ab3b1a359615208946e5ee35f98ee775b8ccecd86ccc1650f,313233343030,3044022055555555555555555555555555555554e8e4f44ce51835693ff0ca2ef01215c102201b21717ad71d23bbac60a9ad0baf75b063c9fdf52a00ebf99d022172910993c9,1\n",
+        "047dff66fa98509ff3e2e51045f4390523dccda43a3bc2885e58c248090990eea854c76c2b9adeb6bb571823e07fd7c65c8639cf9d905260064c8e7675ce6d98b4,313233343030,3044022055555555555555555555555555555554e8e4f44ce51835693ff0ca2ef01215c102202f588f66018f3dd14db3e28e77996487e32486b521ed8e5a20f06591951777e9,1\n",
+        "044280509aab64edfc0b4a2967e4cbce849cb544e4a77313c8e6ece579fbd7420a2e89fe5cc1927d554e6a3bb14033ea7c922cd75cba2c7415fdab52f20b1860f1,313233343030,3044022055555555555555555555555555555554e8e4f44ce51835693ff0ca2ef01215c10220091a08870ff4daf9123b30c20e8c4fc8505758dcf4074fcaff2170c9bfcf74f4,1\n",
+        "044f8df145194e3c4fc3eea26d43ce75b402d6b17472ddcbb254b8a79b0bf3d9cb2aa20d82844cb266344e71ca78f2ad27a75a09e5bc0fa57e4efd9d465a0888db,313233343030,3044022055555555555555555555555555555554e8e4f44ce51835693ff0ca2ef01215c102207c370dc0ce8c59a8b273cba44a7c1191fc3186dc03cab96b0567312df0d0b250,1\n",
+        "049598a57dd67ec3e16b587a338aa3a10a3a3913b41a3af32e3ed3ff01358c6b14122819edf8074bbc521f7d4cdce82fef7a516706affba1d93d9dea9ccae1a207,313233343030,3044022055555555555555555555555555555554e8e4f44ce51835693ff0ca2ef01215c1022070b59a7d1ee77a2f9e0491c2a7cfcd0ed04df4a35192f6132dcc668c79a6160e,1\n",
+        "049171fec3ca20806bc084f12f0760911b60990bd80e5b2a71ca03a048b20f837e634fd17863761b2958d2be4e149f8d3d7abbdc18be03f451ab6c17fa0a1f8330,313233343030,3044022055555555555555555555555555555554e8e4f44ce51835693ff0ca2ef01215c102202736d76e412246e097148e2bf62915614eb7c428913a58eb5e9cd4674a9423de,1\n",
+        "04777c8930b6e1d271100fe68ce93f163fa37612c5fff67f4a62fc3bafaf3d17a9ed73d86f60a51b5ed91353a3b054edc0aa92c9ebcbd0b75d188fdc882791d68d,313233343030,3044022055555555555555555555555555555554e8e4f44ce51835693ff0ca2ef01215c102204a1e12831fbe93627b02d6e7f24bccdd6ef4b2d0f46739eaf3b1eaf0ca117770,1\n",
+        "04eabc248f626e0a63e1eb81c43d461a39a1dba881eb6ee2152b07c32d71bcf4700603caa8b9d33db13af44c6efbec8a198ed6124ac9eb17eaafd2824a545ec000,313233343030,3044022055555555555555555555555555555554e8e4f44ce51835693ff0ca2ef01215c1022006c778d4dfff7dee06ed88bc4e0ed34fc553aad67caf796f2a1c6487c1b2e877,1\n",
+        "049f7a13ada158a55f9ddf1a45f044f073d9b80030efdcfc9f9f58418fbceaf001f8ada0175090f80d47227d6713b6740f9a0091d88a837d0a1cd77b58a8f28d73,313233343030,3044022055555555555555555555555555555554e8e4f44ce51835693ff0ca2ef01215c102204de459ef9159afa057feb3ec40fef01c45b809f4ab296ea48c206d4249a2b451,1\n",
+        "0411c4f3e461cd019b5c06ea0cea4c4090c3cc3e3c5d9f3c6d65b436826da9b4dbbbeb7a77e4cbfda207097c43423705f72c80476da3dac40a483b0ab0f2ead1cb,313233343030,3044022055555555555555555555555555555554e8e4f44ce51835693ff0ca2ef01215c10220745d294978007302033502e1acc48b63ae6500be43adbea1b258d6b423dbb416,1\n",
+        "04e2e18682d53123aa01a6c5d00b0c623d671b462ea80bddd65227fd5105988aa4161907b3fd25044a949ea41c8e2ea8459dc6f1654856b8b61b31543bb1b45bdb,313233343030,3044022055555555555555555555555555555554e8e4f44ce51835693ff0ca2ef01215c102207b2a785e3896f59b2d69da57648e80ad3c133a750a2847fd2098ccd902042b6c,1\n",
+        "0490f8d4ca73de08a6564aaf005247b6f0ffe978504dce52605f46b7c3e56197dafadbe528eb70d9ee7ea0e70702db54f721514c7b8604ac2cb214f1decb7e383d,313233343030,3044022055555555555555555555555555555554e8e4f44ce51835693ff0ca2ef01215c1022071ae94a72ca896875e7aa4a4c3d29afdb4b35b6996273e63c47ac519256c5eb1,1\n",
+        "04824c195c73cffdf038d101bce1687b5c3b6146f395c885976f7753b2376b948e3cdefa6fc347d13e4dcbc63a0b03a165180cd2be1431a0cf74ce1ea25082d2bc,313233343030,3044022055555555555555555555555555555554e8e4f44ce51835693ff0ca2ef01215c102200fa527fa7343c0bc9ec35a6278bfbff4d83301b154fc4bd14aee7eb93445b5f9,1\n",
+        "042788a52f078eb3f202c4fa73e0d3386faf3df6be856003636f599922d4f5268f30b4f207c919bbdf5e67a8be4265a8174754b3aba8f16e575b77ff4d5a7eb64f,313233343030,3044022055555555555555555555555555555554e8e4f44ce51835693ff0ca2ef01215c102206539c0adadd0525ff42622164ce9314348bd0863b4c80e936b23ca0414264671,1\n",
+        "04d533b789a4af890fa7a82a1fae58c404f9a62a50b49adafab349c513b415087401b4171b803e76b34a9861e10f7bc289a066fd01bd29f84c987a10a5fb18c2d4,313233343030,304402207fffffffffffffffffffffffffffffff5d576e7357a4501ddfe92f46681b20a0022055555555555555555555555555555554e8e4f44ce51835693ff0ca2ef01215c0,0\n",
+        "043a3150798c8af69d1e6e981f3a45402ba1d732f4be8330c5164f49e10ec555b4221bd842bc5e4d97eff37165f60e3998a424d72a450cf95ea477c78287d0343a,313233343030,304402207fffffffffffffffffffffffffffffff5d576e7357a4501ddfe92f46681b20a002207fffffffffffffffffffffffffffffff5d576e7357a4501ddfe92f46681b20a0,1\n",
+        "043b37df5fb347c69a0f17d85c0c7ca83736883a825e13143d0fcfc8101e851e800de3c090b6ca21ba543517330c04b12f948c6badf14a63abffdf4ef8c7537026,313233343030,304402207fffffffffffffffffffffffffffffff5d576e7357a4501ddfe92f46681b20a002207fffffffffffffffffffffffffffffff5d576e7357a4501ddfe92f46681b20a1,0\n",
+        "04feb5163b0ece30ff3e03c7d55c4380fa2fa81ee2c0354942ff6f08c99d0cd82ce87de05ee1bda089d3e4e248fa0f721102acfffdf50e654be281433999df897e,313233343030,3044022055555555555555555555555555555554e8e4f44ce51835693ff0ca2ef01215b8022044a5ad0bd0636d9e12bc9e0a6bdd5e1bba77f523842193b3b82e448e05d5f11e,1\n",
+        "04238ced001cf22b8853e02edc89cbeca5050ba7e042a7a77f9382cd414922897640683d3094643840f295890aa4c18aa39b41d77dd0fb3bb2700e4f9ec284ffc2,313233343030,3044022055555555555555555555555555555554e8e4f44ce51835693ff0ca2ef01215b8022044a5ad0bd0636d9e12bc9e0a6bdd5e1bba77f523842193b3b82e448e05d5f11e,1\n",
+        "04961cf64817c06c0e51b3c2736c922fde18bd8c4906fcd7f5ef66c4678508f35ed2c5d18168cfbe70f2f123bd7419232bb92dd69113e2941061889481c5a027bf,313233343030,3044022055555555555555555555555555555554e8e4f44ce51835693ff0ca2ef01215b8022055555555555555555555555555555554e8e4f44ce51835693ff0ca2ef01215b8,1\n",
+        "0413681eae168cd4ea7cf2e2a45d052742d10a9f64e796867dbdcb829fe0b1028816528760d177376c09df79de39557c329cc1753517acffe8fa2ec298026b8384,313233343030,3044022055555555555555555555555555555554e8e4f44ce51835693ff0ca2ef01215b8022055555555555555555555555555555554e8e4f44ce51835693ff0ca2ef01215b8,1\n",
+        "045aa7abfdb6b4086d543325e5d79c6e95ce42f866d2bb84909633a04bb1aa31c291c80088794905e1da33336d874e2f91ccf45cc59185bede5dd6f3f7acaae18b,313233343030,304402207ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffc022016e1e459457679df5b9434ae23f474b3e8d2a70bd6b5dbe692ba16da01f1fb0a,1\n",
+        "0400277791b305a45b2b39590b2f05d3392a6c8182cef4eb540120e0f5c206c3e464108233fb0b8c3ac892d79ef8e0fbf92ed133addb4554270132584dc52eef41,313233343030,304402207ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffc02201c940f313f92647be257eccd7ed08b0baef3f0478f25871b53635302c5f6314a,1\n",
+        "046efa092b68de9460f0bcc919005a5f6e80e19de98968be3cd2c770a9949bfb1ac75e6e5087d6550d5f9beb1e79e5029307bc255235e2d5dc99241ac3ab886c49,313233343030,304402207ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffc022015d94a85077b493f91cb7101ec63e1b01be58b594e855f45050a8c14062d689b,1\n",
+        "0472d4a19c4f9d2cf5848ea40445b70d4696b5f02d632c0c654cc7d7eeb0c6d058e8c4cd9943e459174c7ac01fa742198e47e6c19a6bdb0c4f6c237831c1b3f942,313233343030,304402207ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffc02205b1d27a7694c146244a5ad0bd0636d9d9ef3b9fb58385418d9c982105077d1b7,1\n",
+        "042a8ea2f50dcced0c217575bdfa7cd47d1c6f100041ec0e35512794c1be7e740258f8c17122ed303fda7143eb58bede70295b653266013b0b0ebd3f053137f6ec,313233343030,304402207ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffc02202d85896b3eb9dbb5a52f42f9c9261ed3fc46644ec65f06ade3fd78f257e43432,1\n",
+        "0488de689ce9af1e94be6a2089c8a8b1253ffdbb6c8e9c86249ba220001a4ad3b80c4998e54842f413b9edb1825acbb6335e81e4d184b2b01c8bebdc85d1f28946,313233343030,304402207ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffc02205b0b12d67d73b76b4a5e85f3924c3da7f88cc89d8cbe0d5bc7faf1e4afc86864,1\n",
+        "04fea2d31f70f90d5fb3e00e186ac42ab3c1615cee714e0b4e1131b3d4d8225bf7b037a18df2ac15343f30f74067ddf29e817d5f77f8dce05714da59c094f0cda9,313233343030,304402207ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffc0220694c146244a5ad0bd0636d9e12bc9e09e60e68b90d0b5e6c5dddd0cb694d8799,1\n",
+        "047258911e3d423349166479dbe0b8341af7fbd03d0a7e10edccb36b6ceea5a3db17ac2b8992791128fa3b96dc2fbd4ca3bfa782ef2832fc6656943db18e7346b0,313233343030,304402207ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffc02203d7f487c07bfc5f30846938a3dcef696444707cf9677254a92b06c63ab867d22,1\n",
+        "044f28461dea64474d6bb34d1499c97d37b9e95633df1ceeeaacd45016c98b3914c8818810b8cc06ddb40e8a1261c528faa589455d5a6df93b77bc5e0e493c7470,313233343030,304402207ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffc02206c7648fc0fbf8a06adb8b839f97b4ff7a800f11b1e37c593b261394599792ba4,1\n",
+        "0474f2a814fb5d8eca91a69b5e60712732b3937de32829be974ed7b68c5c2f5d66eff0f07c56f987a657f42196205f588c0f1d96fd8a63a5f238b48f478788fe3b,313233343030,304402207ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffc0220641c9c5d790dc09cdd3dfabb62cdf453e69747a7e3d7aa1a714189ef53171a99,1\n",
+        "04195b51a7cc4a21b8274a70a90de779814c3c8ca358328208c09a29f336b82d6ab2416b7c92fffdc29c3b1282dd2a77a4d04df7f7452047393d849989c5cee9ad,313233343030,304402207ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffc022029798c5c45bdf58b4a7b2fdc2c46ab4af1218c7eeb9f0f27a88f1267674de3b0,1\n",
+        "04622fc74732034bec2ddf3bc16d34b3d1f7a327dd2a8c19bab4bb4fe3a24b58aa736b2f2fae76f4dfaecc9096333b01328d51eb3fda9c9227e90d0b449983c4f0,313233343030,304402207ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffc02200b70f22ca2bb3cefadca1a5711fa3a59f4695385eb5aedf3495d0b6d00f8fd85,1\n",
+        "041f7f85caf2d7550e7af9b65023ebb4dce3450311692309db269969b834b611c70827f45b78020ecbbaf484fdd5bfaae6870f1184c21581baf6ef82bd7b530f93,313233343030,304402207ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffc022016e1e459457679df5b9434ae23f474b3e8d2a70bd6b5dbe692ba16da01f1fb0a,1\n",
+        "0449c197dc80ad1da47a4342b93893e8e1fb0bb94fc33a83e783c00b24c781377aefc20da92bac762951f72474becc734d4cc22ba81b895e282fdac4df7af0f37d,313233343030,304402207ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffc02202252d685e831b6cf095e4f0535eeaf0ddd3bfa91c210c9d9dc17224702eaf88f,1\n",
+        "04d8cb68517b616a56400aa3868635e54b6f699598a2f6167757654980baf6acbe7ec8cf449c849aa03461a30efada41453c57c6e6fbc93bbc6fa49ada6dc0555c,313233343030,304402207ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffc022075135abd7c425b60371a477f09ce0f274f64a8c6b061a07b5d63e93c65046c53,1\n",
+        "04030713fb63f2aa6fe2cadf1b20efc259c77445dafa87dac398b84065ca347df3b227818de1a39b589cb071d83e5317cccdc2338e51e312fe31d8dc34a4801750,313233343030,304402207ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffc02202aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa3e3a49a23a6d8abe95461f8445676b17,1\n",
+        "04babb3677b0955802d8e929a41355640eaf1ea1353f8a771331c4946e3480afa7252f196c87ed3d2a59d3b1b559137fed0013fecefc19fb5a92682b9bca51b950,313233343030,304402207ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffc02203e888377ac6c71ac9dec3fdb9b56c9feaf0cfaca9f827fc5eb65fc3eac811210,1\n",
+        "041aab2018793471111a8a0e9b143fde02fc95920796d3a63de329b424396fba60bbe4130705174792441b318d3aa31dfe8577821e9b446ec573d272e036c4ebe9,313233343030,304402207ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffc022030bbb794db588363b40679f6c182a50d3ce9679acdd3ffbe36d7813dacbdc818,1\n",
+        "048cb0b909499c83ea806cd885b1dd467a0119f06a88a0276eb0cfda274535a8ff47b5428833bc3f2c8bf9d9041158cf33718a69961cd01729bc0011d1e586ab75,313233343030,304402207ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffc02202c37fd995622c4fb7fffffffffffffffc7cee745110cb45ab558ed7c90c15a2f,1\n",
+        "048f03cf1a42272bb1532723093f72e6feeac85e1700e9fbe9a6a2dd642d74bf5d3b89a7189dad8cf75fc22f6f158aa27f9c2ca00daca785be3358f2bda3862ca0,313233343030,304402207ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffc02207fd995622c4fb7ffffffffffffffffff5d883ffab5b32652ccdcaa290fccb97d,1\n",
+        "0444de3b9c7a57a8c9e820952753421e7d987bb3d79f71f013805c897e018f8acea2460758c8f98d3fdce121a943659e372c326fff2e5fc2ae7fa3f79daae13c12,313233343030,304302207ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffc021f4cd53ba7608fffffffffffffffffffff9e5cf143e2539626190a3ab09cce47,1\n",
+        "046fb8b2b48e33031268ad6a517484dc8839ea90f6669ea0c7ac3233e2ac31394a0ac8bbe7f73c2ff4df9978727ac1dfc2fd58647d20f31f99105316b64671f204,313233343030,304402207ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffc02205622c4fb7fffffffffffffffffffffff928a8f1c7ac7bec1808b9f61c01ec327,1\n",
+        "04bea71122a048693e905ff602b3cf9dd18af69b9fc9d8431d2b1dd26b942c95e6f43c7b8b95eb62082c12db9dbda7fe38e45cbe4a4886907fb81bdb0c5ea9246c,313233343030,304402207ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffc022044104104104104104104104104104103b87853fd3b7d3f8e175125b4382f25ed,1\n",
+        "04da918c731ba06a20cb94ef33b778e981a404a305f1941fe33666b45b03353156e2bb2694f575b45183be78e5c9b5210bf3bf488fd4c8294516d89572ca4f5391,313233343030,304402207ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffc02202739ce739ce739ce739ce739ce739ce705560298d1f2f08dc419ac273a5b54d9,1\n",
+        "043007e92c3937dade7964dfa35b0eff031f7eb02aed0a0314411106cdeb70fe3d5a7546fc0552997b20e3d6f413e75e2cb66e116322697114b79bac734bfc4dc5,313233343030,304402207ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffc02204888888888888888888888888888888831c83ae82ebe0898776b4c69d11f88de,1\n",
+        "0460e734ef5624d3cbf0ddd375011bd663d6d6aebc644eb599fdf98dbdcd18ce9bd2d90b3ac31f139af832cccf6ccbbb2c6ea11fa97370dc9906da474d7d8a7567,313233343030,304402207ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffc02206492492492492492492492492492492406dd3a19b8d5fb875235963c593bd2d3,1\n",
+        "0485a900e97858f693c0b7dfa261e380dad6ea046d1f65ddeeedd5f7d8af0ba33769744d15add4f6c0bc3b0da2aec93b34cb8c65f9340ddf74e7b0009eeeccce3c,313233343030,304402207ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffc02206aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa3e3a49a23a6d8abe95461f8445676b15,1\n",
+        "0438066f75d88efc4c93de36f49e037b234cc18b1de5608750a62cab0345401046a3e84bed8cfcb819ef4d550444f2ce4b651766b69e2e2901f88836ff90034fed,313233343030,304402207ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffc02202aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa3e3a49a23a6d8abe95461f8445676b17,1\n",
+        "0498f68177dc95c1b4cbfa5245488ca523a7d5629470d035d621a443c72f39aabfa33d29546fa1c648f2c7d5ccf70cf1ce4ab79b5db1ac059dbecd068dbdff1b89,313233343030,304402207ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffc02203ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffe,1\n",
+        "045c2bbfa23c9b9ad07f038aa89b4930bf267d9401e4255de9e8da0a5078ec8277e3e882a31d5e6a379e0793983ccded39b95c4353ab2ff01ea5369ba47b0c3191,313233343030,304402207ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffc0220185ddbca6dac41b1da033cfb60c152869e74b3cd66e9ffdf1b6bc09ed65ee40c,1\n",
+        "042ea7133432339c69d27f9b267281bd2ddd5f19d6338d400a05cd3647b157a3853547808298448edb5e701ade84cd5fb1ac9567ba5e8fb68a6b933ec4b5cc84cc,313233343030,3044022032b0d10d8d0e04bc8d4d064d270699e87cffc9b49c5c20730e1c26f6105ddcda022029ed3d67b3d505be95580d77d5b792b436881179b2b6b2e04c5fe592d38d82d9,1\n",
+        "042ea7133432339c69d27f9b267281bd2ddd5f19d6338d400a05cd3647b157a385cab87f7d67bb7124a18fe5217b32a04e536a9845a1704975946cc13a4a337763,313233343030,3044022032b0d10d8d0e04bc8d4d064d270699e87cffc9b49c5c20730e1c26f6105ddcda022029ed3d67b3d505be95580d77d5b792b436881179b2b6b2e04c5fe592d38d82d9,0\n",
+        "048aa2c64fa9c6437563abfbcbd00b2048d48c18c152a2a6f49036de7647ebe82e1ce64387995c68a060fa3bc0399b05cc06eec7d598f75041a4917e692b7f51ff,313233343030,3044022055555555555555555555555555555554e8e4f44ce51835693ff0ca2ef01215c0022033333333333333333333333333333332f222f8faefdb533f265d461c29a47373,0\n",
+        "04391427ff7ee78013c14aec7d96a8a062209298a783835e94fd6549d502fff71fdd6624ec343ad9fcf4d9872181e59f842f9ba4cccae09a6c0972fb6ac6b4c6bd,313233343030,3045022100c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5022055555555555555555555555555555554e8e4f44ce51835693ff0ca2ef01215c0,1\n",
+        "04e762b8a219b4f180219cc7a9059245e4961bd191c03899789c7a34b89e8c138ec1533ef0419bb7376e0bfde9319d10a06968791d9ea0eed9c1ce6345aed9759e,313233343030,3045022100c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5022049249249249249249249249249249248c79facd43214c011123c1b03a93412a5,1\n",
+        "049aedb0d281db164e130000c5697fae0f305ef848be6fffb43ac593fbb950e952fa6f633359bdcd82b56b0b9f965b037789d46b9a8141b791b2aefa713f96c175,313233343030,3045022100c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5022066666666666666666666666666666665e445f1f5dfb6a67e4cba8c385348e6e7,1\n",
+        "048ad445db62816260e4e687fd1884e48b9fc0636d031547d63315e792e19bfaee1de64f99d5f1cd8b6ec9cb0f787a654ae86993ba3db1008ef43cff0684cb22bd,313233343030,3045022100c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5022066666666666666666666666666666665e445f1f5dfb6a67e4cba8c385348e6e7,1\n",
+        "041f5799c95be89063b24f26e40cb928c1a868a76fb0094607e8043db409c91c32e75724e813a4191e3a839007f08e2e897388b06d4a00de6de60e536d91fab566,313233343030,3045022100c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5022049249249249249249249249249249248c79facd43214c011123c1b03a93412a5,1\n",
+        "04a3331a4e1b4223ec2c027edd482c928a14ed358d93f1d4217d39abf69fcb5ccc28d684d2aaabcd6383775caa6239de26d4c6937bb603ecb4196082f4cffd509d,313233343030,3045022100c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee502200eb10e5ab95f2f275348d82ad2e4d7949c8193800d8c9c75df58e343f0ebba7b,1\n",
+        "043f3952199774c7cf39b38b66cb1042a6260d8680803845e4d433adba3bb248185ea495b68cbc7ed4173ee63c9042dc502625c7eb7e21fb02ca9a9114e0a3a18d,313233343030,3044022079be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798022055555555555555555555555555555554e8e4f44ce51835693ff0ca2ef01215c0,1\n",
+        "04cdfb8c0f422e144e137c2412c86c171f5fe3fa3f5bbb544e9076288f3ced786e054fd0721b77c11c79beacb3c94211b0a19bda08652efeaf92513a3b0a163698,313233343030,3044022079be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798022049249249249249249249249249249248c79facd43214c011123c1b03a93412a5,1\n",
+        "0473598a6a1c68278fa6bfd0ce4064e68235bc1c0f6b20a928108be336730f87e3cbae612519b5032ecc85aed811271a95fe7939d5d3460140ba318f4d14aba31d,313233343030,3044022079be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798022066666666666666666666666666666665e445f1f5dfb6a67e4cba8c385348e6e7,1\n",
+        "0458debd9a7ee2c9d59132478a5440ae4d5d7ed437308369f92ea86c82183f10a16773e76f5edbf4da0e4f1bdffac0f57257e1dfa465842931309a24245fda6a5d,313233343030,3044022079be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798022066666666666666666666666666666665e445f1f5dfb6a67e4cba8c385348e6e7,1\n",
+        "048b904de47967340c5f8c3572a720924ef7578637feab1949acb241a5a6ac3f5b950904496f9824b1d63f3313bae21b89fae89afdfc811b5ece03fd5aa301864f,313233343030,3044022079be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798022049249249249249249249249249249248c79facd43214c011123c1b03a93412a5,1\n",
+        "04f4892b6d525c771e035f2a252708f3784e48238604b4f94dc56eaa1e546d941a346b1aa0bce68b1c50e5b52f509fb5522e5c25e028bc8f863402edb7bcad8b1b,313233343030,3044022079be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f8179802200eb10e5ab95f2f275348d82ad2e4d7949c8193800d8c9c75df58e343f0ebba7b,1\n",
+        "0479be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8,313233343030,3045022100bb5a52f42f9c9261ed4361f59422a1e30036e7c32b270c8807a419feca60502302202492492492492492492492492492492463cfd66a190a6008891e0d81d49a0952,0\n",
+        ",313233343030,3044022044a5ad0bd0636d9e12bc9e0a6bdd5e1bba77f523842193b3b82e448e05d5f11e02202492492492492492492492492492492463cfd66a190a6008891e0d81d49a0952,0\n",
+        "0479be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798b7c52588d95c3b9aa25b0403f1eef75702e84bb7597aabe663b82f6f04ef2777,313233343030,3045022100bb5a52f42f9c9261ed4361f59422a1e30036e7c32b270c8807a419feca60502302202492492492492492492492492492492463cfd66a190a6008891e0d81d49a0952,0\n",
+        ",313233343030,3044022044a5ad0bd0636d9e12bc9e0a6bdd5e1bba77f523842193b3b82e448e05d5f11e02202492492492492492492492492492492463cfd66a190a6008891e0d81d49a0952,0\n",
+        "04782c8ed17e3b2a783b5464f33b09652a71c678e05ec51e84e2bcfc663a3de963af9acb4280b8c7f7c42f4ef9aba6245ec1ec1712fd38a0fa96418d8cd6aa6152,,3045022100f80ae4f96cdbc9d853f83d47aae225bf407d51c56b7776cd67d0dc195d99a9dc02204cfc1d941e08cb9aceadde0f4ccead76b30d332fc442115d50e673e28686b70b,1\n",
+        ",4d7367,30440220109cd8ae0374358984a8249c0a843628f2835ffad1df1a9a69aa2fe72355545c02205390ff250ac4274e1cb25cd6ca6491f6b91281e32f5b264d87977aed4a94e77b,1\n",
+        ",313233343030,3045022100d035ee1f17fdb0b2681b163e33c359932659990af77dca632012b30b27a057b302201939d9f3b2858bc13e3474cb50e6a82be44faa71940f876c1cba4c3e989202b6,1\n",
+        ",0000000000000000000000000000000000000000,304402204f053f563ad34b74fd8c9934ce59e79c2eb8e6eca0fef5b323ca67d5ac7ed23802204d4b05daa0719e773d8617dce5631c5fd6f59c9bdc748e4b55c970040af01be5,1\n",
+        "046e823555452914099182c6b2c1d6f0b5d28d50ccd005af2ce1bba541aa40caff00000001060492d5a5673e0f25d8d50fb7e58c49d86d46d4216955e0aa3d40e1,4d657373616765,304402206d6a4f556ccce154e7fb9f19e76c3deca13d59cc2aeb4ecad968aab2ded45965022053b9fa74803ede0fc4441bf683d56c564d3e274e09ccf47390badd1471c05fb7,1\n",
+        ",4d657373616765,3044022100aad503de9b9fd66b948e9acf596f0a0e65e700b28b26ec56e6e45e846489b3c4021f0ddc3a2f89abb817bb85c062ce02f823c63fc26b269e0bc9b84d81a5aa123d,1\n",
+        ",4d657373616765,30450221009182cebd3bb8ab572e167174397209ef4b1d439af3b200cdf003620089e43225022054477c982ea019d2e1000497fc25fcee1bccae55f2ac27530ae53b29c4b356a4,1\n",
+        "046e823555452914099182c6b2c1d6f0b5d28d50ccd005af2ce1bba541aa40cafffffffffef9fb6d2a5a98c1f0da272af0481a73b62792b92bde96aa1e55c2bb4e,4d657373616765,304402203854a3998aebdf2dbc28adac4181462ccac7873907ab7f212c42db0e69b56ed802203ed3f6b8a388d02f3e4df9f2ae9c1bd2c3916a686460dffcd42909cd7f82058e,1\n",
+        ",4d657373616765,3045022100e94dbdc38795fe5c904d8f16d969d3b587f0a25d2de90b6d8c5c53ff887e360702207a947369c164972521bb8af406813b2d9f94d2aeaa53d4c215aaa0a2578a2c5d,1\n",
+        ",4d657373616765,3044022049fc102a08ca47b60e0858cd0284d22cddd7233f94aaffbb2db1dd2cf08425e102205b16fca5a12cdb39701697ad8e39ffd6bdec0024298afaa2326aea09200b14d6,1\n",
+        "04000000013fd22248d64d95f73c29b48ab48631850be503fd00f8468b5f0f70e0f6ee7aa43bc2c6fd25b1d8269241cbdd9dbb0dac96dc96231f430705f838717d,4d657373616765,3044022041efa7d3f05a0010675fcb918a45c693da4b348df21a59d6f9cd73e0d831d67a02204454ada693e5e26b7bd693236d340f80545c834577b6f73d378c7bcc534244da,1\n",
+        ",4d657373616765,3045022100b615698c358b35920dd883eca625a6c5f7563970cdfc378f8fe0cee17092144c022025f47b326b5be1fb610b885153ea84d41eb4716be66a994e8779989df1c863d4,1\n",
+        ",4d657373616765,304502210087cf8c0eb82d44f69c60a2ff5457d3aaa322e7ec61ae5aecfd678ae1c1932b0e02203add3b115815047d6eb340a3e008989eaa0f8708d1794814729094d08d2460d3,1\n",
+        "0425afd689acabaed67c1f296de59406f8c550f57146a0b4ec2c97876dfffffffffa46a76e520322dfbc491ec4f0cc197420fc4ea5883d8f6dd53c354bc4f67c35,4d657373616765,3044022062f48ef71ace27bf5a01834de1f7e3f948b9dce1ca1e911d5e13d3b104471d8202205ea8f33f0c778972c4582080deda9b341857dd64514f0849a05f6964c2e34022,1\n",
+        ",4d657373616765,3045022100f6b0e2f6fe020cf7c0c20137434344ed7add6c4be51861e2d14cbda472a6ffb402206416c8dd3e5c5282b306e8dc8ff34ab64cc99549232d678d714402eb6ca7aa0f,1\n",
+        ",4d657373616765,3045022100db09d8460f05eff23bc7e436b67da563fa4b4edb58ac24ce201fa8a358125057022046da116754602940c8999c8d665f786c50f5772c0a3cdbda075e77eabc64df16,1\n",
+        "04d12e6c66b67734c3c84d2601cf5d35dc097e27637f0aca4a4fdb74b6aadd3bb93f5bdff88bd5736df898e699006ed750f11cf07c5866cd7ad70c7121ffffffff,4d657373616765,30440220592c41e16517f12fcabd98267674f974b588e9f35d35406c1a7bb2ed1d19b7b802203e65a06bd9f83caaeb7b00f2368d7e0dece6b12221269a9b5b765198f840a3a1,1\n",
+        ",4d657373616765,3045022100be0d70887d5e40821a61b68047de4ea03debfdf51cdf4d4b195558b959a032b202207d994b2d8f1dbbeb13534eb3f6e5dccd85f5c4133c27d9e64271b1826ce1f67d,1\n",
+        ",4d657373616765,3045022100fae92dfcb2ee392d270af3a5739faa26d4f97bfd39ed3cbee4d29e26af3b206a02206c9ba37f9faa6a1fd3f65f23b4e853d4692a7274240a12db7ba3884830630d16,1\n",
+        "046d4a7f60d4774a4f0aa8bbdedb953c7eea7909407e3164755664bc2800000000e659d34e4df38d9e8c9eaadfba36612c769195be86c77aac3f36e78b538680fb,4d657373616765,30440220176a2557566ffa518b11226694eb9802ed2098bfe278e5570fe1d5d7af18a94302201291df6a0ed5fc0d15098e70bcf13a009284dfd0689d3bb4be6ceeb9be1487c4,1\n",
+        ",4d657373616765,3044022060be20c3dbc162dd34d26780621c104bbe5dace630171b2daef0d826409ee5c20220427f7e4d889d549170bda6a9409fb1cb8b0e763d13eea7bd97f64cf41dc6e497,1\n",
+        ",4d657373616765,3045022100edf03cf63f658883289a1a593d1007895b9f236d27c9c1f1313089aaed6b16ae02201a4dd6fc0814dc523d1fefa81c64fbf5e618e651e7096fccadbb94cd48e5e0cd,1\n",
+    );
+
+    #[test]
+    fn ECDH() {
+        for line in ECDH_VECTORS.lines() {
+            let mut f = line.split(',');
+            let tc = f.next().unwrap();
+            let sk = PrivateKey::decode(&hex::decode(f.next().unwrap())
+                .unwrap()).unwrap();
+            let peer = hex::decode(f.next().unwrap()).unwrap();
+            let shared = hex::decode(f.next().unwrap()).unwrap();
+            let valid = f.next().unwrap() == "1";
+            let (key1, ok1) = sk.ECDH(&peer);
+            let (key2, ok2) = sk.ECDH_hashed(&peer);
+            if valid {
+                assert!(ok1 == 0xFFFFFFFF && ok2 == 0xFFFFFFFF, "tcId {}", tc);
+                assert!(key1[..] == shared[..], "tcId {}", tc);
+                assert!(PublicKey::decode(&peer).is_some());
+
+                // The vectors only provide the x-coordinate of the shared
+                // point; the hashed secret must match one of the two
+                // possible compressed encodings.
+                let mut cs = [0u8; 33];
+                cs[1..].copy_from_slice(&shared);
+                cs[0] = 0x02;
+                let h2 = Sha256::digest(cs);
+                cs[0] = 0x03;
+                let h3 = Sha256::digest(cs);
+                assert!(key2[..] == h2[..] || key2[..] == h3[..]);
+            } else {
+                // Invalid peer keys are rejected, but still yield a
+                // deterministic output.
+                assert!(ok1 == 0 && ok2 == 0, "tcId {}", tc);
+                assert!(PublicKey::decode(&peer).is_none());
+                assert!(sk.ECDH(&peer).0 == key1);
+                assert!(sk.ECDH_hashed(&peer).0 == key2);
+                assert!(key1 != [0u8; 32] && key1 != key2);
+            }
+        }
+
+        // Hashed secret for tcId 1, checked against libsecp256k1.
+        let mut f = ECDH_VECTORS.split(',');
+        f.next();
+        let sk = PrivateKey::decode(&hex::decode(f.next().unwrap())
+            .unwrap()).unwrap();
+        let peer = hex::decode(f.next().unwrap()).unwrap();
+        assert!(sk.ECDH_hashed(&peer).0[..] == hex::decode(
+            "81165066322732362ca5d3f0991d7f1f7d0aad7ea533276496785d369e35159a").unwrap()[..]);
+
+        // Both sides get the same key.
+        let mut sh = Sha256::new();
+        for i in 0..10u8 {
+            sh.update([i]);
+            let sk1 = PrivateKey::from_seed(&sh.finalize_reset());
+            sh.update([i, 1]);
+            let sk2 = PrivateKey::from_seed(&sh.finalize_reset());
+            let pk1 = sk1.to_public_key();
+            let pk2 = sk2.to_public_key();
+            let (k1, ok1) = sk1.ECDH(&pk2.encode_compressed());
+            let (k2, ok2) = sk2.ECDH(&pk1.encode_uncompressed());
+            assert!(ok1 == 0xFFFFFFFF && ok2 == 0xFFFFFFFF);
+            assert!(k1 == k2);
+            let (k3, _) = sk1.ECDH_hashed(&pk2.encode_uncompressed());
+            let (k4, _) = sk2.ECDH_hashed(&pk1.encode_compressed());
+            assert!(k3 == k4 && k3 != k1);
+        }
+    }
+
+    // ECDH test vectors from Wycheproof (file ecdh_secp256k1_test.json).
+    // Each line contains the tcId, the private key, the peer public key,
+    // the raw shared secret (x-coordinate), and the expected result (1 =
+    // valid or acceptable, 0 = invalid). There is no "ecpoint" variant of
+    // this file, so the peer point is extracted from the
+    // SubjectPublicKeyInfo when it uses the named secp256k1 curve; the test
+    // cases that only exercise the ASN.1 encoding (including modifications
+    // of the encoded point flagged as "InvalidAsn") or explicit curve
+    // parameters are omitted. The private keys are normalized to 32 bytes.
+    // Invalid cases include the invalid curve attacks and the point on the
+    // twist (tcId 529 and 530).
+    static ECDH_VECTORS: &str = concat!(
+        "1,f4b7ff7cccc98813a69fae3df222bfe3f4e28f764bf91b4a10d8096ce446b254,04d8096af8a11e0b80037e1ee68246b5dcbb0aeb1cf1244fd767db80f3fa27da2b396812ea1686e7472e9692eaf3e958e50e9500d3b4c77243db1f2acd67ba9cc4,544dfae22af6af939042b1d85b71a1e49e9a5614123c4d6ad0c8af65baf87d65,1\n",
+        "2,f4b7ff7cccc98813a69fae3df222bfe3f4e28f764bf91b4a10d8096ce446b254,02d8096af8a11e0b80037e1ee68246b5dcbb0aeb1cf1244fd767db80f3fa27da2b,544dfae22af6af939042b1d85b71a1e49e9a5614123c4d6ad0c8af65baf87d65,1\n",
+        "3,a2b6442a37f8a3764aeff4011a4c422b389a1e509669c43f279c8b7e32d80c3a,04965ff42d654e058ee7317cced7caf093fbb180d8d3a74b0dcd9d8cd47a39d5cb9c2aa4daac01a4be37c20467ede964662f12983e0b5272a47a5f2785685d8087,0000000000000000000000000000000000000000000000000000000000000001,1\n",
+        "4,a2b6442a37f8a3764aeff4011a4c422b389a1e509669c43f279c8b7e32d80c3a,0406c4b87ba76c6dcb101f54a050a086aa2cb0722f03137df5a922472f1bdc11b982e3c735c4b6c481d09269559f080ad08632f370a054af12c1fd1eced2ea9211,0000000000000000000000000000000000000000000000000000000000000002,1\n",
+        "5,a2b6442a37f8a3764aeff4011a4c422b389a1e509669c43f279c8b7e32d80c3a,04bba30eef7967a2f2f08a2ffadac0e41fd4db12a93cef0b045b5706f2853821e6d50b2bf8cbf530e619869e07c021ef16f693cfc0a4b0d4ed5a8f464692bf3d6e,0000000000000000000000000000000000000000000000000000000000000003,1\n",
+        "6,a2b6442a37f8a3764aeff4011a4c422b389a1e509669c43f279c8b7e32d80c3a,046da9eb2cdac02122d5f05cf6a8cd768e378f664ea4a7871d10e25f57eb1ee1cc5b2b5abf9c6c6596f8f383ddbcb3bcc2d5a7cc605984931239ca9669946032ee,fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2c,1\n",
+        "7,a2b6442a37f8a3764aeff4011a4c422b389a1e509669c43f279c8b7e32d80c3a,04f2976154c4f53ce392d1fe39a891a4611ba8cf046023cd8f1bcd9fdd2e921191b25cf31caedfbb415381637bc3f599a34fba3e1413f644cb1668469f4558a772,0000000000000000000000000000000000000000000000000000000000010000,1\n",
+        "8,a2b6442a37f8a3764aeff4011a4c422b389a1e509669c43f279c8b7e32d80c3a,045e422fea67cca5ebaeac87745c81b10ef807030367e6fce012254176ec8cf199881592f42c264371e19e3037388ab64f32fa8870e62905e7af205e43b02aad12,0000000000000000000000000000000000000000000000000000000100000007,1\n",
+        "9,a2b6442a37f8a3764aeff4011a4c422b389a1e509669c43f279c8b7e32d80c3a,04bb57b9a1231be042d185c03eda6926a6def177fe6745eda000c520d66581f0cdf1d73c80453f2fe30725adf951390c739e36fc8677691db107881342613d00ab,0000000000000000000000000000000000000000000000010000000000000001,1\n",
+        "10,a2b6442a37f8a3764aeff4011a4c422b389a1e509669c43f279c8b7e32d80c3a,045563c76c19377638f7d517bdbe0ace467eb5d4dd9fb4bf18332bab8f07b1d80c261332d46e316711278bacccd88005ee4c115fa84089fd190674626e5ed1ebfe,0000000000000000000000000000000000000001000000000000000000000001,1\n",
+        "11,a2b6442a37f8a3764aeff4011a4c422b389a1e509669c43f279c8b7e32d80c3a,048983aae8c002f2b555acb2370adb9b50ba4cac1bfcc9039a125c70ca7c5fc0d1f6efeb8ae4ba8c69429d93244382447ac534891c66090025282655719bd72512,0b7beba34feb647da200bed05fad57c0348d249e2a90c88f31f9948bb65d5207,1\n",
+        "12,a2b6442a37f8a3764aeff4011a4c422b389a1e509669c43f279c8b7e32d80c3a,0423556564850c50fba51f1e64ef98378ef5c22feafa29499ca27600c473cace889d5679e917daa7f4c7899517d37826284f031de01a60bc813696414d04531a21,210c790573632359b1edb4302c117d8a132654692c3feeb7de3a86ac3f3b53f7,1\n",
+        "13,a2b6442a37f8a3764aeff4011a4c422b389a1e509669c43f279c8b7e32d80c3a,04ddbf807e22c56a19cf6c472829150350781034a5eddec365694d4bd5c865ead14e674127028c91d3394cac37293a866055d10f0f40a3706ad16b64fc9d5998bd,4218f20ae6c646b363db68605822fb14264ca8d2587fdd6fbc750d587e76a7ee,1\n",
+        "14,a2b6442a37f8a3764aeff4011a4c422b389a1e509669c43f279c8b7e32d80c3a,0464688eae7aabd248f6f44a0d6e2c438e4100001813eb71f9f082fad3dfe43e287dab3dabe7d436001a0fb763015dedbb90f811000ec8f5f29953e3af42f92065,39f883f105ac7f09f4e7e4dcc84bc7ff4b3b74f301efaaaf8b638f47720fdaec,1\n",
+        "15,a2b6442a37f8a3764aeff4011a4c422b389a1e509669c43f279c8b7e32d80c3a,04c404e17141d102bba2f1cb16bb954a208798b04dca8dd139a8ab7f01f0dbef39c7b8e55f2257a480077e4190570a004cbe668200c9c78eaa53b61b20fce4c685,5555555555555555555555555555555555555555555555555555555555555550,1\n",
+        "16,a2b6442a37f8a3764aeff4011a4c422b389a1e509669c43f279c8b7e32d80c3a,04e160e87c0a562a1dbb59b4c2f614720e7753608672eb8d883b91e25f8cfc58474623cba584e1324bc49bcdf0891166b545b7704e2bbda705d0d73b7530e47952,aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa,1\n",
+        "17,a2b6442a37f8a3764aeff4011a4c422b389a1e509669c43f279c8b7e32d80c3a,045d4d182b18782a02685dcc7b671ec742ce308c7acc8e6260f67e81516eb546e8a38f0756074eea4857953398b6d05597c7ceb5e65e4e8cee31e81c5658824ce4,3333333333333333333333333333333333333333333333333333333333333333,1\n",
+        "18,a2b6442a37f8a3764aeff4011a4c422b389a1e509669c43f279c8b7e32d80c3a,048ecd6a2576f42626792076935e2fe961599e484cd212bce2623b83aa22f546d2a7f855b09bef286bcbe9e8bab17fd56d7055df64f344310c3522e8f227e472c8,cccccccccccccccccccccccccccccccccccccccccccccccccccccccccccccccb,1\n",
+        "19,a2b6442a37f8a3764aeff4011a4c422b389a1e509669c43f279c8b7e32d80c3a,046826f79ef84da803460aed09198d2bbb42d7892ed608aacbb281a95acae11465a25809191aa5bdfa61b8963beacb4eb133266a90f33d1b2ca4f6152d37a94fd8,0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0c,1\n",
+        "20,a2b6442a37f8a3764aeff4011a4c422b389a1e509669c43f279c8b7e32d80c3a,04a54bb2ae80086053a5fa4fdb1836a8c6ac41783650b0f79a5428c98ff64d078a12bbb4cb8af20ca75ec15b2e0d47a83ca93fc78cd92640a02e8002966f1fe80b,f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0,1\n",
+        "21,a2b6442a37f8a3764aeff4011a4c422b389a1e509669c43f279c8b7e32d80c3a,04bace46eed492743c693e1a33adb046b7722c55ce369d1438e67f9c5b3412783145262dd4a86c8a527b23f4114b8a9b9f36f9701835f50b678b24d2a9155ebc2c,00ff00ff00ff00ff00ff00ff00ff00ff00ff00ff00ff00ff00ff00ff00ff00ff,1\n",
+        "22,a2b6442a37f8a3764aeff4011a4c422b389a1e509669c43f279c8b7e32d80c3a,0401055147863aa060c0e104e243ec01eda2b0e0c6814e232d671abcba9715d5ce0c13006aa7960c54fe3f20220bef766756c910fd05764afc318375540cef2d5c,ff00ff00ff00ff00ff00ff00ff00ff00ff00ff00ff00ff00ff00ff00ff00ff00,1\n",
+        "23,a2b6442a37f8a3764aeff4011a4c422b389a1e509669c43f279c8b7e32d80c3a,04595e46ee7c2d7183ff2ea760ffd8472fb834ec89c08b6ef48ff92b44a13a6e1ae563e23953c97c26441323d2500c84e8cee04c15d4d5d2cc458703d1f2d02d31,7fff0001fffc0007fff0001fffc0007fff0001fffc0007fff0001fffc0007fff,1\n",
+        "24,a2b6442a37f8a3764aeff4011a4c422b389a1e509669c43f279c8b7e32d80c3a,046a40adc811b09e83ba0fb8a94fea50591ca9e58bb7d47304950dbff78dad777ee3bd08f742d7e8e30cff31bc6a6cc02c8717ee25838aabffa6e48f65cce74d81,8000fffe0003fff8000fffe0003fff8000fffe0003fff8000fffe0003fff7fff,1\n",
+        "25,a2b6442a37f8a3764aeff4011a4c422b389a1e509669c43f279c8b7e32d80c3a,045a33fe91d7e35db7875208bee77f4cc0006f1439cc845f695b6a12673dcd03d18f86ee121c5ea0da3eb0210509e12db845296225ca973e2e19ce3e3d01486090,0000ffff0000ffff0000ffff0000ffff0000ffff0000ffff0000ffff0000fffd,1\n",
+        "26,a2b6442a37f8a3764aeff4011a4c422b389a1e509669c43f279c8b7e32d80c3a,04f6ebaab62c35fd4b8bec9d95bcfc433e6bde7c0f0d5ef75d6fd326aaf28f23b0b2f4d1c2e891706b7bada59fb0f6a32b5463982a9c8c2d8ea38954418183b634,ffff0000ffff0000ffff0000ffff0000ffff0000ffff0000ffff0000fffefffe,1\n",
+        "27,a2b6442a37f8a3764aeff4011a4c422b389a1e509669c43f279c8b7e32d80c3a,04524392416f8cfc5f84dc9b72f2887c684e4bd24796f0065078e18d16bc43b56ea02178311799eb61ad3b3e7dcda10404dc4541c13e3de0ceb40c9aa7afabc53b,8000003ffffff0000007fffffe000000ffffffc000001ffffff8000003fffffd,1\n",
+        "28,a2b6442a37f8a3764aeff4011a4c422b389a1e509669c43f279c8b7e32d80c3a,0499965c477a240aebbd19cd094c8b62852de8663d0cc9f06eeb395ffc92d121f64811882f406080d7d04ea4f339bddd2e5ef0345b5834142f75b562154d5ec7ae,7fffffe000000ffffffc000001ffffff8000003ffffff0000007fffffdfffffe,1\n",
+        "29,a2b6442a37f8a3764aeff4011a4c422b389a1e509669c43f279c8b7e32d80c3a,04ad3d179877e74ee258ba6f8e128bc2a0045c06a3d3c30fcce01ca8d9e1afee4ea3fe47156fb727fc1c55ef9db516df665cbb073405c2c301a8fe1d10f3b9b300,000003ffffff0000003ffffff0000003ffffff0000003ffffff0000003fffffc,1\n",
+        "30,a2b6442a37f8a3764aeff4011a4c422b389a1e509669c43f279c8b7e32d80c3a,044bb19deae638fc5fa7070cc90e969bac3f8384a59ea11cb01bc091edf1a4cbd677ed6bdf8971d3e63c903d9acabc28b75af661a03457261c5a8d5940ad02c509,fffffc000000ffffffc000000ffffffc000000ffffffc000000ffffffbfffffe,1\n",
+        "31,a2b6442a37f8a3764aeff4011a4c422b389a1e509669c43f279c8b7e32d80c3a,0424175c078e305d3139e5dab727a6ab8587b26daa470a529a23c10585cb56c038bf1f2b937ae074ff94b15f5cb5e60eb5d32afba2077539db794294bcaab71a81,ffff00000003fffffff00000003fffffff00000003fffffff00000003fffffff,1\n",
+        "32,a2b6442a37f8a3764aeff4011a4c422b389a1e509669c43f279c8b7e32d80c3a,04ef691afe2ee4aa18a8485a71c0e20eff1337ae0622acc09ccda10f49574ae840b82730bb2eef59a17ab095acd131e5fcf8ba11150a9421bbab6b9f146aa78ffb,0000fffffffc0000000fffffffc0000000fffffffc0000000fffffffbffffffd,1\n",
+        "33,a2b6442a37f8a3764aeff4011a4c422b389a1e509669c43f279c8b7e32d80c3a,04067e7df09f5e38f2b2823f65a6b1135c3290586fef6eceffa6d59595748879f66932b3f70d603229e10a57344ecde503a2df930651046c2f1d2b719bfc93e0a1,ff00000001fffffffc00000007fffffff00000001fffffffc00000007ffffffd,1\n",
+        "34,a2b6442a37f8a3764aeff4011a4c422b389a1e509669c43f279c8b7e32d80c3a,04b8722ecdde7c85317e486b03656b83910ac3c88687a4291e8bb9a4b6a52cc6e02e4158a5a88de023d6a135bd04c1585ef46741890376135453ec562da5b3760b,00fffffffe00000003fffffff80000000fffffffe00000003fffffff80000000,1\n",
+        "35,a2b6442a37f8a3764aeff4011a4c422b389a1e509669c43f279c8b7e32d80c3a,04728e15d578212bc42287c0118c82c84b126f97d549223c10ad07f4e98af912385d23b1a6e716925855a247b16effe92773315241ac951cdfefdfac0ed16467f6,00000000ffffffff00000000ffffffff00000000ffffffff00000000ffffffff,1\n",
+        "36,a2b6442a37f8a3764aeff4011a4c422b389a1e509669c43f279c8b7e32d80c3a,04c3ef35fd4cda66e8e850095e1e697aee56decc29484aa463f879c7b6dd7669e625945351276719c5e3bb8e514f69305b6085b7c782a07b26a842887c33a93dc6,ffffffff00000000ffffffff00000000ffffffff00000000fffffffeffffffff,1\n",
+        "37,a2b6442a37f8a3764aeff4011a4c422b389a1e509669c43f279c8b7e32d80c3a,04784907c6be6202770b98d01f1ffe11b9ed2c97515843f57c2c06363a9dadc7011de5fbaa7356cf3ba28cb7b932a07c8321007c7c45396751fe70724343d2b19f,ffffffff00000000000000ffffffffffffff00000000000000fffffffffffffe,1\n",
+        "38,a2b6442a37f8a3764aeff4011a4c422b389a1e509669c43f279c8b7e32d80c3a,047c016dee8b5411f8e95184daf8e318119e844b8bdc70d75efb99b8d0ff10ab745e905103d57d6537908e6e9864aee4f0917f5b920d06f980aa823f043ef9139e,00000000ffffffffffffff00000000000000ffffffffffffff00000000000000,1\n",
+        "39,a2b6442a37f8a3764aeff4011a4c422b389a1e509669c43f279c8b7e32d80c3a,0436e1e76ffdbe8577520b0716eb88c18ea72a49e5a4e5680a7d290093f841cb6e7310728b59c7572c4b35fb6c29c36ebabfc53553c06ecf747fcfbefcf6114e1c,0000000000000000ffffffffffffffff0000000000000000ffffffffffffffff,1\n",
+        "40,a2b6442a37f8a3764aeff4011a4c422b389a1e509669c43f279c8b7e32d80c3a,047a19501d646fc9332a8525af4cc79523b57d736b69bb24b06270c1b1dadf88ce834efa1bce854ff5bcade40cbcee9f40154bc26036adc5cf87e50ea388af2987,ffffffffffffffff0000000000000000fffffffffffffffefffffffffffffff5,1\n",
+        "41,a2b6442a37f8a3764aeff4011a4c422b389a1e509669c43f279c8b7e32d80c3a,04f43b610a2a5c5f6e2b395567489657059e3351c6f9a7e2ebde52638abfea006ab2d690513e9187c0cc903ceee022ee421c594a8bd7610c68cd8143adfc741dde,55555555555555555555555555555555555555555555555555555554fffffebc,1\n",
+        "42,a2b6442a37f8a3764aeff4011a4c422b389a1e509669c43f279c8b7e32d80c3a,04d93bfdaa797cd4bd81dea80d7c72a24923ce50e94bfc4ee1bd5f5f10eea3f8ecc0b5941890a26e88e5029c283e0fadeccc0b980f8a5098aa7835c5c958d471e5,33333333333333333333333333333333333333333333333333333332ffffff3c,1\n",
+        "43,a2b6442a37f8a3764aeff4011a4c422b389a1e509669c43f279c8b7e32d80c3a,040ac1ea7a29f7ace8a38b2fedbfe4d0d9ae45344432ab3eb5e0a5b66716f61c6aaaa39a5f098fd4472587d14bdf72b3dd3e966b5f0b6e400fff6e0e9c8453fc79,249249249249249249249249249249249249249249249249249249246db6dae2,1\n",
+        "44,a2b6442a37f8a3764aeff4011a4c422b389a1e509669c43f279c8b7e32d80c3a,04bf2e8a61a21d96e74a296b397e53044f373acb73a6ea4a398d89c56549e96b7fe846fd0df239691d0682b067a50a2423d88b4d970b1d3d8141a066d13c186f96,1c71c71c71c71c71c71c71c71c71c71c71c71c71c71c71c71c71c71c555554e8,1\n",
+        "45,a2b6442a37f8a3764aeff4011a4c422b389a1e509669c43f279c8b7e32d80c3a,0456baf1d72606c7af5a5fa108620b0839e2c7dd40b832ef847e5b64c86efe1aa563e586a667a65bbb5692500df1ff8403736838b30ea9791d9d390e3dc6689e2c,800000000000000000000000009fa2f1ffffffffffffffffffffffffffffffff,1\n",
+        "46,2bc15cf3981eab61e594ebf591290a045ca9326a8d3dd49f3de1190d39270bb8,04800000000000000000000000009fa2f1ffffffffffffffffffffffffffffffff07ed353c9f1039edcc9cc5336c034dc131a4087692c2e56bc1dd1904e3ffffff,7c07b199b6a62e7ac646c7e1dee94aca55de1a97251ddf92fcd4fe0145b40f12,1\n",
+        "47,a2b6442a37f8a3764aeff4011a4c422b389a1e509669c43f279c8b7e32d80c3a,045e4c2cf1320ec84ef8920867b409a9a91d2dd008216a282e36bd84e884726fa05a5e4af11cf63ceaaa42a6dc9e4ccb394852cf84284e8d2627572fbf22c0ba88,80000000000000000000000000a3037effffffffffffffffffffffffffffffff,1\n",
+        "48,a2b6442a37f8a3764aeff4011a4c422b389a1e509669c43f279c8b7e32d80c3a,0402a30c2fabc87e6730625dec2f0d03894387b7f743ce69c47351ebe5ee98a48307eb78d38770fea1a44f4da72c26f85b17f3501a4f9394fe29856ccbf15fd284,8000000000000000000000000124dcb0ffffffffffffffffffffffffffffffff,1\n",
+        "49,2bc15cf3981eab61e594ebf591290a045ca9326a8d3dd49f3de1190d39270bb8,0480000000000000000000000000a3037effffffffffffffffffffffffffffffff0000031a6bf344b86730ac5c54a7751aefdba135759b9d535ca64111f298a38d,5206c3de46949b9da160295ee0aa142fe3e6629cc25e2d671e582e30ff875082,1\n",
+        "50,2bc15cf3981eab61e594ebf591290a045ca9326a8d3dd49f3de1190d39270bb8,048000000000000000000000000124dcb0ffffffffffffffffffffffffffffffff0000013bc6f08431e729ed2863f2f4ac8a30279695c8109c340a39fa86f451cd,8a8c18b78e1b1fcfd22ee18b4a3a9f391a3fdf15408fb7f8c1dba33c271dbd2f,1\n",
+        "51,a2b6442a37f8a3764aeff4011a4c422b389a1e509669c43f279c8b7e32d80c3a,045e4c2cf1320ec84ef8920867b409a9a91d2dd008216a282e36bd84e884726fa0a5a1b50ee309c31555bd592361b334c6b7ad307bd7b172d9d8a8d03fdd3f41a7,80000000000000000000000000a3037effffffffffffffffffffffffffffffff,1\n",
+        "52,a2b6442a37f8a3764aeff4011a4c422b389a1e509669c43f279c8b7e32d80c3a,0402a30c2fabc87e6730625dec2f0d03894387b7f743ce69c47351ebe5ee98a483f814872c788f015e5bb0b258d3d907a4e80cafe5b06c6b01d67a93330ea029ab,8000000000000000000000000124dcb0ffffffffffffffffffffffffffffffff,1\n",
+        "53,2bc15cf3981eab61e594ebf591290a045ca9326a8d3dd49f3de1190d39270bb8,0480000000000000000000000000a3037efffffffffffffffffffffffffffffffffffffce5940cbb4798cf53a3ab588ae510245eca8a6462aca359beed0d6758a2,5206c3de46949b9da160295ee0aa142fe3e6629cc25e2d671e582e30ff875082,1\n",
+        "54,2bc15cf3981eab61e594ebf591290a045ca9326a8d3dd49f3de1190d39270bb8,048000000000000000000000000124dcb0fffffffffffffffffffffffffffffffffffffec4390f7bce18d612d79c0d0b5375cfd8696a37ef63cbf5c604790baa62,8a8c18b78e1b1fcfd22ee18b4a3a9f391a3fdf15408fb7f8c1dba33c271dbd2f,1\n",
+        "55,a2b6442a37f8a3764aeff4011a4c422b389a1e509669c43f279c8b7e32d80c3a,045450cace04386adc54a14350793e83bdc5f265d6c29287ecd07f791ad2784c4cebd3c24451322334d8d51033e9d34b6bb592b1995d07867863d1044bd59d7501,80000000000000000000000001126b54ffffffffffffffffffffffffffffffff,1\n",
+        "56,2bc15cf3981eab61e594ebf591290a045ca9326a8d3dd49f3de1190d39270bb8,0480000000000000000000000001126b54ffffffffffffffffffffffffffffffff4106a369068d454ea4b9c3ac6177f87fc8fd3aa240b2ccb4882bdccbd4000000,e59ddc7646e4aef0623c71c486f24d5d32f7257ef3dab8fa524b394eae19ebe1,1\n",
+        "57,2bc15cf3981eab61e594ebf591290a045ca9326a8d3dd49f3de1190d39270bb8,0400000000000000000000000000000000000000000000000000000000000000014218f20ae6c646b363db68605822fb14264ca8d2587fdd6fbc750d587e76a7ee,12c2ad36a59fda5ac4f7e97ff611728d0748ac359fca9b12f6d4f43519516487,1\n",
+        "58,2bc15cf3981eab61e594ebf591290a045ca9326a8d3dd49f3de1190d39270bb8,04000000000000000000000000000000000000000000000000000000000000000266fbe727b2ba09e09f5a98d70a5efce8424c5fa425bbda1c511f860657b8535e,45aa9666757815e9974140d1b57191c92c588f6e5681131e0df9b3d241831ad4,1\n",
+        "59,2bc15cf3981eab61e594ebf591290a045ca9326a8d3dd49f3de1190d39270bb8,0400000000000000000000000000000000000000000000000000000000000000032f233395c8b07a3834a0e59bda43944b5df378852e560ebc0f22877e9f49bb4b,b90964c05e464c23acb747a4c83511e93007f7499b065c8e8eccec955d8731f4,1\n",
+        "60,2bc15cf3981eab61e594ebf591290a045ca9326a8d3dd49f3de1190d39270bb8,04fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2c0e994b14ea72f8c3eb95c71ef692575e775058332d7e52d0995cf8038871b67d,e97fb4c4fb33d6a114da6e0d180e54f99ec1ece9ff558871054e99d221930d16,1\n",
+        "61,2bc15cf3981eab61e594ebf591290a045ca9326a8d3dd49f3de1190d39270bb8,0400000000000000000000000000000000000000000000000000000000000100003c81e87241d9451d286ddbe65b14d47234307b80ce74b8921af7d4935707549d,1eea9c2756a3305bb5178f2c37436e7b41cf3805cd0a1087d2d02407fc553c09,1\n",
+        "62,2bc15cf3981eab61e594ebf591290a045ca9326a8d3dd49f3de1190d39270bb8,04000000000000000000000000000000000000000000000000000000010000000715098598dc12cf294ea5ac1eb5eeae9139f5cfd3d0ffdcfa7297a01dce1ee9df,2f1c5c590f97f79351fb9d36c597d1c61f1c409fcdedaeae795112fa1a2c7453,1\n",
+        "63,2bc15cf3981eab61e594ebf591290a045ca9326a8d3dd49f3de1190d39270bb8,04000000000000000000000000000000000000000000000001000000000000000161bd3a38f707713b97eaf8d0184e0079e2a62cfba75d428b1326ea861aade950,82b8e90e6b6441b7164c9725ac1a35f098788096af95c276fac3c5a383d6b56c,1\n",
+        "64,2bc15cf3981eab61e594ebf591290a045ca9326a8d3dd49f3de1190d39270bb8,04000000000000000000000000000000000000000100000000000000000000000115820e7e26670c6b45c1e0caa951eab312754180baa9fcff9f7e7bf46deea7fc,8a955b6cf4d518558e59372444d3fd9b78933e2d3229dfdfa6f5f66403290e19,1\n",
+        "65,2bc15cf3981eab61e594ebf591290a045ca9326a8d3dd49f3de1190d39270bb8,040b7beba34feb647da200bed05fad57c0348d249e2a90c88f31f9948bb65d52077435a6bef91b92ae32cf51d7149cad0353a46513851427c34436536ec7eae483,5626bbf79f10827e23fa5aef9a26533f5f4e7472934ed9759b7b3a77cda04b82,1\n",
+        "66,2bc15cf3981eab61e594ebf591290a045ca9326a8d3dd49f3de1190d39270bb8,04210c790573632359b1edb4302c117d8a132654692c3feeb7de3a86ac3f3b53f75f450dbbf718a4f6582d7af83953170b3037fb81a450a5ca5acbec74ad6cac89,1908ae936f53b9a8a2d09707ae414084090b175365401425479b10b8c3e8d1ba,1\n",
+        "67,2bc15cf3981eab61e594ebf591290a045ca9326a8d3dd49f3de1190d39270bb8,044218f20ae6c646b363db68605822fb14264ca8d2587fdd6fbc750d587e76a7ee37269a64bbcf3a3f227631c7a8ce532c77245a1c0db4343f16aa1d339fd2591a,5e13b3dc04e33f18d1286c606cb0191785f694e82e17796145c9e7b49bc2af58,1\n",
+        "68,2bc15cf3981eab61e594ebf591290a045ca9326a8d3dd49f3de1190d39270bb8,0439f883f105ac7f09f4e7e4dcc84bc7ff4b3b74f301efaaaf8b638f47720fdaec24f50efd39b8ae7536e8806927eac6fd52210a239fb4129e0bfed333476575ea,a995572ad174897ff1971e6d1e39f908448a5878da1e60f3901f57cacd49e5f6,1\n",
+        "69,2bc15cf3981eab61e594ebf591290a045ca9326a8d3dd49f3de1190d39270bb8,045555555555555555555555555555555555555555555555555555555555555550134a74fc6e7d7acef5bb20e969abb6f026ec0cb04dff34f7916ca64b07fff511,cd8427ea93f9fede38a70d0c39dbd96759613ba00f27b9db3971c80aec07e2d6,1\n",
+        "70,2bc15cf3981eab61e594ebf591290a045ca9326a8d3dd49f3de1190d39270bb8,04aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa769afe397a5709201bda50ce2d31a13fde4076722a857719924009cc28159869,766b0752cd895b4b8543d44c9a348868ffff12aed632f8070e731d450d8a8c94,1\n",
+        "71,2bc15cf3981eab61e594ebf591290a045ca9326a8d3dd49f3de1190d39270bb8,0433333333333333333333333333333333333333333333333333333333333333330434e877eaa71340aa5e57e58a01f0b0aec8d24b5c64aa77ef95fae9b4958c5d,09b0aa839893b7ad37cc83160e6f3c5506bbe323497c21505ae9937c75d943c8,1\n",
+        "72,2bc15cf3981eab61e594ebf591290a045ca9326a8d3dd49f3de1190d39270bb8,04cccccccccccccccccccccccccccccccccccccccccccccccccccccccccccccccb2e808a8b6c6e5bc068f96348d68171e66159a0ee27073c82fc3f9581a4a1fb28,3c2a61121f094d5eecddf7d3b0016c170b90fd3f2fea0b12e31db04ae7c279a2,1\n",
+        "73,2bc15cf3981eab61e594ebf591290a045ca9326a8d3dd49f3de1190d39270bb8,040f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0c1d854210f797c547bd3b3feccde1ce3e67c61c3400141da2068520e2bae9bf90,9a641d5efa8be7dc723aa58e2e52a150c8efced2fa1084041249773c7562c66d,1\n",
+        "74,2bc15cf3981eab61e594ebf591290a045ca9326a8d3dd49f3de1190d39270bb8,04f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f0f022bcbf40d658bf3ff02d98aea5ae45d43ed85f6de9268f0eae85210f2fed81c6,d32977eca64d223ea90f10f72f810ec64d661833acc4c839591da813ef86f736,1\n",
+        "75,2bc15cf3981eab61e594ebf591290a045ca9326a8d3dd49f3de1190d39270bb8,0400ff00ff00ff00ff00ff00ff00ff00ff00ff00ff00ff00ff00ff00ff00ff00ff210a46304881329c9807b71b6393ba104b9f27d976065e852429fd664de98eee,55137fecb21eb3ebed1b41fb2f7e1ca337009465f855f3f920bc7d0b73c2da32,1\n",
+        "76,2bc15cf3981eab61e594ebf591290a045ca9326a8d3dd49f3de1190d39270bb8,04ff00ff00ff00ff00ff00ff00ff00ff00ff00ff00ff00ff00ff00ff00ff00ff007011d6e851e5a53fde41c1f348690c0188f24c105d5cfca5b6ff3c93dbfdef99,0bde659ed89281e6c8a5fbdab764d0499b86d19d33f4c978e260bbae587d4057,1\n",
+        "77,2bc15cf3981eab61e594ebf591290a045ca9326a8d3dd49f3de1190d39270bb8,047fff0001fffc0007fff0001fffc0007fff0001fffc0007fff0001fffc0007fff4b66003c7482d0f2fd7b1cb2b0b7078cd199f2208fc37eb2ef286ccb2f1224e7,3135a6283b97e7537a8bc208a355c2a854b8ee6e4227206730e6d725da044dee,1\n",
+        "78,2bc15cf3981eab61e594ebf591290a045ca9326a8d3dd49f3de1190d39270bb8,048000fffe0003fff8000fffe0003fff8000fffe0003fff8000fffe0003fff7fff0a2331880cb3f8f9004bf68fc379beb6e3affadcbe81bd4f9bf76e4ac5ab2c37,2a3d29ce049fc50b00fab50e7581b84d441d297be6515fbe83dc485bdf32b6dc,1\n",
+        "79,2bc15cf3981eab61e594ebf591290a045ca9326a8d3dd49f3de1190d39270bb8,040000ffff0000ffff0000ffff0000ffff0000ffff0000ffff0000ffff0000fffd3b6a2629d598a045be28a1687288cc4d0c389cc6fe627c5cc3aa2ab963db7495,03c202a64e60ff5948d29816d68420c64c0518a7522a929381365b1245770a02,1\n",
+        "80,2bc15cf3981eab61e594ebf591290a045ca9326a8d3dd49f3de1190d39270bb8,04ffff0000ffff0000ffff0000ffff0000ffff0000ffff0000ffff0000fffefffe35e39d53d101a6aa4ab434c55a70b03d244b6a2025a18d4d549dea451c031392,d07fcf7b89bd1ba24194caf977db68a5503a471a37d374e0917a5fe31d48c99e,1\n",
+        "81,2bc15cf3981eab61e594ebf591290a045ca9326a8d3dd49f3de1190d39270bb8,048000003ffffff0000007fffffe000000ffffffc000001ffffff8000003fffffd3aa774f4d29fefddd9546ad1f7b2b79cf42634284fbb1d7c702e9fca3fe049af,ea9f3a53ab4053df0bae0156767a62ec5ba0de4373ef12cbfb19aa80c6bcd904,1\n",
+        "82,2bc15cf3981eab61e594ebf591290a045ca9326a8d3dd49f3de1190d39270bb8,047fffffe000000ffffffc000001ffffff8000003ffffff0000007fffffdfffffe23e4bca0984da424a6120a13dc676c777607562d16ed9b8fa94c21fff7151d4e,f0557be2b26ddb56d44d2cb852224a291de771418fe148a730a76dadf5882f18,1\n",
+        "83,2bc15cf3981eab61e594ebf591290a045ca9326a8d3dd49f3de1190d39270bb8,04000003ffffff0000003ffffff0000003ffffff0000003ffffff0000003fffffc2a95c81253ac554846812d2a4415f6edcf954209008d260a806b85aba759ff72,c68f07233efd0745d8bcd51a89158717c2dc532f75a9e4de2076e1b830654ec8,1\n",
+        "84,2bc15cf3981eab61e594ebf591290a045ca9326a8d3dd49f3de1190d39270bb8,04fffffc000000ffffffc000000ffffffc000000ffffffc000000ffffffbfffffe031537fcabe5d5e25165a18b1bd408212cb523efea0fc0fd1eac46e83b0d0b52,6eec8a68eb5f9caf2ab3053a3047bbc08412a1d433d79eea65effc5e0cd583bf,1\n",
+        "85,2bc15cf3981eab61e594ebf591290a045ca9326a8d3dd49f3de1190d39270bb8,04ffff00000003fffffff00000003fffffff00000003fffffff00000003fffffff63a88b2e0c8987c6310cf81d0c935f00213f98a3dad2f43c8128fa313a90d55b,bbd9d305b99ff3db56f77fea9e89f32260ee7326040067ce05dd15e0dcc13ed8,1\n",
+        "86,2bc15cf3981eab61e594ebf591290a045ca9326a8d3dd49f3de1190d39270bb8,040000fffffffc0000000fffffffc0000000fffffffc0000000fffffffbffffffd2407bddc5a50b2a7b96a288efb838bf768c6066e60b72f08a9782da2e39bd34f,1f81aa3d70f8756b9495fba82921717d4006206a4451d8d59f3c9b8d95b548e8,1\n",
+        "87,2bc15cf3981eab61e594ebf591290a045ca9326a8d3dd49f3de1190d39270bb8,04ff00000001fffffffc00000007fffffff00000001fffffffc00000007ffffffd4af9cc406a46943ffe0fe630bd21f205eefa05355f3a13c9943d58e16e880435,66e707faf954d1ec84fe0f68f829beb2fe95058271b636362e3eb5c5d492cbf8,1\n",
+        "88,2bc15cf3981eab61e594ebf591290a045ca9326a8d3dd49f3de1190d39270bb8,0400fffffffe00000003fffffff80000000fffffffe00000003fffffff800000002796cf7bde36dc6b1950001228b7249d3438a35fe5be98661255bf63a879b3a5,42dd6d83bbce6afab5045e1393838a97a46161c25ae91db0143e985d29162faa,1\n",
+        "89,2bc15cf3981eab61e594ebf591290a045ca9326a8d3dd49f3de1190d39270bb8,0400000000ffffffff00000000ffffffff00000000ffffffff00000000ffffffff73b0886496aed70db371e2e49db640abba547e5e0c2763b73a0a42f84348a6b1,ab43917a64c1b010159643c18e2eb06d25eedae5b78d02fa9b3debacbf31b777,1\n",
+        "90,2bc15cf3981eab61e594ebf591290a045ca9326a8d3dd49f3de1190d39270bb8,04ffffffff00000000ffffffff00000000ffffffff00000000fffffffeffffffff0013a9be0cbaaacf4e0f53ee45bc573eaa44dbf48d5fafc26856b44d6d00e2be,f39bf49011cb323ee00f77e0344a9b9da1256db92646dda0e342f8c1ad3741c5,1\n",
+        "91,2bc15cf3981eab61e594ebf591290a045ca9326a8d3dd49f3de1190d39270bb8,04ffffffff00000000000000ffffffffffffff00000000000000fffffffffffffe6e563bca873bd591c9663391c826150795e3c42cedd269e68ff0e56dc971d554,27860fa0679edd4556f0423a21cc21e1e3f1701da3e62a544974ae94f15f91a0,1\n",
+        "92,2bc15cf3981eab61e594ebf591290a045ca9326a8d3dd49f3de1190d39270bb8,0400000000ffffffffffffff00000000000000ffffffffffffff000000000000005b5b2ec553be67fd73add4cc2bced4ebe6d04a05b0e926e312037b3951667847,2bcfc95bba84524d8093dce1092bc157ca1fa42a37aaca9b0759437f940c3e7d,1\n",
+        "93,2bc15cf3981eab61e594ebf591290a045ca9326a8d3dd49f3de1190d39270bb8,040000000000000000ffffffffffffffff0000000000000000ffffffffffffffff31cf13671b574e313c35217566f18bd2c5f758c140d24e94e6a4fda7f4c7b12b,1a32749dcf047a7e06194ccb34d7c9538a16ddabeeede74bea5f7ef04979f7f7,1\n",
+        "94,2bc15cf3981eab61e594ebf591290a045ca9326a8d3dd49f3de1190d39270bb8,04ffffffffffffffff0000000000000000fffffffffffffffefffffffffffffff53a54141598334650d1f99a12850769f53d34529b07ae591244c6ed702f1aa171,119aa477afad550e98db77bfb4e71a4b6ec79ec4fe17b7283f9b8bb7b9fdb5ec,1\n",
+        "95,2bc15cf3981eab61e594ebf591290a045ca9326a8d3dd49f3de1190d39270bb8,0455555555555555555555555555555555555555555555555555555554fffffebc7c976bddab1d1a302cfa176c25434558ec7cac238e739ca9849aa104323b106c,21edb700cf62c1bb816a877988ee8c5bc16a8464bcb6454adb8abf8b5cef7ceb,1\n",
+        "96,2bc15cf3981eab61e594ebf591290a045ca9326a8d3dd49f3de1190d39270bb8,0433333333333333333333333333333333333333333333333333333332ffffff3c7b2bf3716a9e336e162966597e5c423bb9d3d0d0c3c02b9e2dc4aabad17bfdcb,1ba54571d1d280f5fa2d0c5846ec392c721acd4ba7e4aadc3dc2353957abd80b,1\n",
+        "97,2bc15cf3981eab61e594ebf591290a045ca9326a8d3dd49f3de1190d39270bb8,04249249249249249249249249249249249249249249249249249249246db6dae22817588aa19f910e8bed1f89a6b5ea6cde4800dd9beb28d1336bb46075118144,9d422ce42f74aa0272e5530b5dd094225f11d1100fed954ff714a2d471559cef,1\n",
+        "98,2bc15cf3981eab61e594ebf591290a045ca9326a8d3dd49f3de1190d39270bb8,041c71c71c71c71c71c71c71c71c71c71c71c71c71c71c71c71c71c71c555554e83c4d16ba6991011cf3f94feeff3f48ad29ed9a22bcef8fac40d9b2af25e2b909,a5ab2cc5bb6881f7e734d7ccc9d448127d9465fd342d81c8381572059b3aa2b7,1\n",
+        "99,938f3dbe37135cd8c8c48a676b28b2334b72a3f09814c8efb6a451be00c93d23,0459294e8bc54e76d48b5594f01fe4729566d9b6df6385982fbb533183921f1a124543e4110bf4cd22e1d444d83e24c5ecdb328a98f2f93e8edcb99b07d5d9fafc,e976057e8a322dfdb2debd55d8e58802fb54425950b2dbfd00f0813de27105e4,1\n",
+        "100,938f3dbe37135cd8c8c48a676b28b2334b72a3f09814c8efb6a451be00c93d23,0429b579690264954985187aaa9ea313d39b5c828e022afce8fd0cb764ed693473ba8cde1b2be1749cf4d5bc0df578009c9650e44b6c385c5ee2621ffffc205cb7,09fa5a510558a12110daf75117af1e175f93d7c4d8ba41c5bf3efe95d829ff50,1\n",
+        "101,938f3dbe37135cd8c8c48a676b28b2334b72a3f09814c8efb6a451be00c93d23,044150a111e0489cc82d43ba66f404ba0df2b1fa13ffea361442f7854f9abb381465627e96f372fd0400eca42113890cb110c11eda22405bcd295b1caab9d93af7,98bc618faef7c4311c3d8fd37b39e9baad780e14f0527fa69a3f4c2b66ac6394,1\n",
+        "102,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04a74646c798fd5a0af442da69c822cdf1134adba361f90663d626481aa10e0004567160696818286b72f01a3e5e8caca736249160c7ded69dd51913c303a2fa97,8a0b2ddef3a1108f6ea367ed08079a0ec98494fe46cfad584bdc98e99e6d7f99,1\n",
+        "103,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04611c65eecd9e3de528f639e8b6698688db1f4fc8c11650a601fe6daeca5c59665fa45a23400633ba3630244aa6b0144de2ab3b6295e3dfa15f586e40a84053af,89b86329f0f13aab07a48d0d3b7afe530ad260a90de6c25ec3da8b6905502551,1\n",
+        "104,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04b49c6791647937568c7570064856420835d44af1ceddd682967fbd44fc97294cd135651bd7ee3aab957eba10ed4b7a5c40ca00d959ca663080c4eaf0e189bc21,751b521de6384a017caafa10419fc35d58f6dbace86f6b533c117e38dab1d689,1\n",
+        "105,938f3dbe37135cd8c8c48a676b28b2334b72a3f09814c8efb6a451be00c93d23,04c73c71d856cb949a31c249c1e99b11ffb698cbc1dbf4002e956cdeb655f84045716e98dec10a9905fa1d3a851f4f1fe617356cb56d5643a148eec376237a27f1,f282a78942218fac638eeb0eb15098f5aabae15b3ddb7abdd40a8ad3b5540c8e,1\n",
+        "106,938f3dbe37135cd8c8c48a676b28b2334b72a3f09814c8efb6a451be00c93d23,04acabedbe760e9330af3508209ba0081b9ce061327d1ea0b6ffdc577dbaf28e269cd00176358828215d30ade0cff8cdc0856c84fcdb424feb93ce58a2554a9bcd,6aeb7004f6cf6b05f30bf481e8b32a1e25fc66d96a4a53165727bb304cc27baa,1\n",
+        "107,938f3dbe37135cd8c8c48a676b28b2334b72a3f09814c8efb6a451be00c93d23,044cc9197bfdef17d33a9ea743bf83747b564d6ad11e6080957a9d3ac44165fa793ce20d13d431071be367e592f8a22f88edee1cd51cadb0845ebea64b11c45708,67b5a9926bc58025c8bc2b9504b72c3a8465173d70f5d5ec1580fe88c5a4887b,1\n",
+        "108,938f3dbe37135cd8c8c48a676b28b2334b72a3f09814c8efb6a451be00c93d23,04fbf411afc88358dff2ba156ce273d7b15d0ba3980a60a82eb38bfa58995e163d57c62e53070e8e6cb1df4ef509eb2598dbdb07a5ffd71301eaa2892ad1238f4a,12182c05568a6b18a98ea19110330146e7dbc49274f324b5edef4eb861f72bec,1\n",
+        "109,938f3dbe37135cd8c8c48a676b28b2334b72a3f09814c8efb6a451be00c93d23,04cd7863adddaf0099647139ce64ca0b39dbd312ccf96c15a62f2c49e628248235999f82afd0f76e744afd0fca2aab36f22ff7ebefd8e541fcb6e972704b8ac521,f75920e61e7d05c3cf4107e5e81f3c1be7ffb0637f0ac8b895d87361345d9a87,1\n",
+        "110,938f3dbe37135cd8c8c48a676b28b2334b72a3f09814c8efb6a451be00c93d23,04bd4fd857640a6bdf5da42ffc5c2c1755c4c125a99d380a5935eb1c4c3a9c2a3a4760df25ca561724a82e3f9c9d782536db4310d6c9c769f51b733de44a9c02f1,373aca70b036b70cf8e46fc9457a8e19c6821be2f2d6c16edadd20d7b30eb3ba,1\n",
+        "111,938f3dbe37135cd8c8c48a676b28b2334b72a3f09814c8efb6a451be00c93d23,0445654b3b66065743ac86854daa77c9e5cf713a402fbd4ada365f4f96bf1717cd63cf23ba035de430a2128dab0d2c7b939d44c66624f6979275cd37cd02370669,caec9de4a74d76603c5d5d07de2df0d435bef2b9063b5123305d2fcbd5dbb318,1\n",
+        "112,938f3dbe37135cd8c8c48a676b28b2334b72a3f09814c8efb6a451be00c93d23,04dda793fe7fdea5c7481c756f59fbff48481777a54218d95eaa24e7b86d8a5858fdac18590cd96e193db51c50307d2606674d5b8afcc82d1b672dd8e09719a6ac,27980511f433feea84475b82281b1fa6b946c97c646738d5ac3345250f86037d,1\n",
+        "113,938f3dbe37135cd8c8c48a676b28b2334b72a3f09814c8efb6a451be00c93d23,042e043b851fc5a5f12deb76fe94182b99bbce727b476783f9d868ad3ab7ac7a251462b469c2e02491e05a3a4523e09a6be8e5b2d10419cb7760a8503ae4eb7e7b,20b27f84ae128f674e144d82bcd1544146bfd0150b0843ea585314f59cc54aae,1\n",
+        "114,938f3dbe37135cd8c8c48a676b28b2334b72a3f09814c8efb6a451be00c93d23,043d8fddf41e52320c8081e0d60f5397993abdfa979c4b5e832ac61bf3cc2e6fd94504fe3207dbd18ebad2b921a52a16a33659939c16fbb9186caf5e2cf3170346,92592791ff90b595dd2ae7ec039bf6b7bfeae7f044761f5e7fa86564ebc46b2b,1\n",
+        "115,938f3dbe37135cd8c8c48a676b28b2334b72a3f09814c8efb6a451be00c93d23,0465106fdca0c408738c2316f3ec2238d459157bab2c2855323b95bd271c91dedcd9fc2d685446789829251d293a50d150df5f1fc1a0604e4defaa9a8e3f8c9169,8e61b2e072bd1401da12a3f3d8164daedab0bf0ca795bcf56aff81d07caf7281,1\n",
+        "116,938f3dbe37135cd8c8c48a676b28b2334b72a3f09814c8efb6a451be00c93d23,04320c813548183aadb0e7d21a0ffe472bfa9b4ffe815adefd09180a3ae2d15fbcd0ca20611d2232847aa80e7f7691c008ff886dfce550f90c4c19982ed779b466,a41170f616c5499e289b4893b3973e1155f66ff354ae6a812bcd0e33bd7dd5cc,1\n",
+        "117,938f3dbe37135cd8c8c48a676b28b2334b72a3f09814c8efb6a451be00c93d23,04a0e2b1a92a6afa9fe68424bc63dcad620b7dc844e4571f5404ab9d18bf08545ccba1c1ff49bf7baa9be1fc0ac4bba63b41ba7a374e15fc39b884d80a75b07092,b8cbc27d4ea1b25f2292292ae53a3bb954b7ca77ccca5b4dccf1b958b0aad163,1\n",
+        "118,938f3dbe37135cd8c8c48a676b28b2334b72a3f09814c8efb6a451be00c93d23,04dc97139a3dd1411d74616154aa0d6bce787cfafbd8fd060b680b04b422b0d22f6ab50e5c68e027805953bf7c3be40a8f7c9b56c6dbbe86337e6163ada01d9d63,4baaee93a752397bf2ad0be72ac82b0ad2417e167bfdfce4904f012d4c33fea6,1\n",
+        "119,938f3dbe37135cd8c8c48a676b28b2334b72a3f09814c8efb6a451be00c93d23,040978d42e1594569589b578266cedb6088a84c9cc9baff0070dc1d934342605e62ce80a966b5ca0344981f4229c7ab622a853bd9bc59b662ecd92df238e4e46ed,3b3d86187d05a0012d83be280987dc95b1c0c9b57f253b64530d1d4220aa4abf,1\n",
+        "120,938f3dbe37135cd8c8c48a676b28b2334b72a3f09814c8efb6a451be00c93d23,04a8630a7bdb78a970a01b20c3e7b95d25d3eebdc8e94ecfe0f508e4136eca49afa5eb12114b50ac77d68d410cd5ef5107b2e68f08600e5e6938c452d51d6993ba,472d4b34f5be6b499f76b0d9e439e115f6a89b725d9e9e811185a615f14007d0,1\n",
+        "121,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04c4ea8ed31ab4a8c994a965efd4770bbb5e26ee54cb7217ffd31fa888c108feca063c415201329dda130f43973f442ad320da0ccf289cd1b71489ca0a7201d5a6,fa67f4a9ea34fde196a7dff6bc1a2917b1526d54950335bea2abe22e1edab410,1\n",
+        "122,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0491780d1905316105d6a6aca94a0d4488d134f985f7e29adecb1bc6cd0c211a788035b06e495d1e58b085bfb6720bca84557b670de34587df0d7e3aad5bbc803a,95c131de0c89e5b17f91e56779c1571de2c8a20794084fa274eccc8eed1d3d65,1\n",
+        "123,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,041961a1a4b29671f1d835b313ffeba4d8203d8414cdc0ea11e47d619b47038b1de50a63b89cbc8956a5870c6c4830e2102d5281b9b5dc127b1052fe7b3e11c438,5bae6d6d23a68f283fe0de46f1d74c0f52e278cb181f55c4353f768ba162aac7,1\n",
+        "124,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0429a9e7f25109a8c4bd80dbea05fbb46aade58797c3b2fa5f00f0f081669ae39d2c78fb1160de6eda50f472ba659d4f1db4ea6e297244b6ae68a051d96e62e75e,b4fe1201a8647be6d6d59f406fa970cc858f5a46a50a6ae9d992c0e23f5e2ad3,1\n",
+        "125,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,041617dc03d3eea42e8ea2c5bd034a38c5a3d74165a548074b7b5765ccd8465b7f61089d6dde53430f34cf8285ddbc584d1543fdc70c2333fc315eed4e930ac3a1,b721ebc7eb1b09438d754ae80302b2a2bf40f866ec507540ab5120b22f868886,1\n",
+        "126,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0420e7a1358436f675f3774d60954b5621145b8f5260b5503636f54878ecaaff8dccaf2fffcb7c7084e325dae5e24bff5a34e37980d1722016dd6667da71f164c4,b8da5d1bf9419e2b876e708871a9a29574686689bae8d87985d72a4e573dded4,1\n",
+        "127,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,042759bf4c336501340cbc67afb4a8f5744f9131d973966a9de50ded60fbe045121b67a9e81e53b064adeddd16a4c030dbb189ccd7019b329d67a527c311723469,39aa2bbc4b6f30c268b19909d5070155c39c60649b7a2ebec266bdd18fff8cbf,1\n",
+        "128,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0419b8a10f5021f11e29e18611fa8284b7e9a3f67cf36eec8ecc4d7a5b54803411311a8a4e199d98eb358e19a27e80cda6af142d6091ddaa9370ed610453abc6c8,e40396a908c2cca4504f4f40be394a12244ae184f6909ec725ce723485bbbb97,1\n",
+        "129,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,049ba841f41245ac08955966470425593290b9e1d87bda8f47df19048db8e3d83097f68905f360ced26801872a7ff124c3637b02c4a596b83abafe7bce567ca177,13a21dc50cdfaeabd572f2d94dc0f3f768f17f990ee59d7f16ace9bfad8a705c,1\n",
+        "130,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0468af761d053dee64aca5e98f547feb2dfb6f5edb8138011c7f5c33809b4b9e00466dd76cb8ceeb5132862052ad3e08bfea245ef16ca0d00ed0c4b45fb6bd3028,a82fb3bbdf6d69c7398ee9020fe006d5b28c632f2da357393fe58deb8d27fd08,1\n",
+        "131,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0405dc8b18bc286f203213b1319413dfa4911d6c2e30f3c778c55e4e5f5d9bfdbacd0b3b209e76049895ae80ff63c0225a563228cd99243f628a9dbae70d773c66,8fa44f09cfddef86aa9007cd4bea6f0bc9b5b2115256303df09f8a20909c5271,1\n",
+        "132,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04bacb606384b1930bb4b74ded236d03d3bb1739a51b73f20dc3349ec3b383180a6896ed59fa0b654a9c404b34fee2c767be2383f4b8b171d2359806b04b502d16,ef762992d22bacaf06aa1e482c0711046b52e0e40de2a21d4e38df0109ad67c0,1\n",
+        "133,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0448ab89b2a312de510a6d3c9ac9e4c4f5b46e04d3f858433b7646e46273d94dce4a0c7da616388f1eb8d55ece64ab695e5405d779c92f3bc2595c27d65def8db9,3b0936f22337ece971ee102178f37bca3cb69b50b8ec9c9b47334c68b5d4320b,1\n",
+        "134,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04fd9de304ea5f18dd641510b9809473d39a2373ed5a470ffc5ea7c83093911b4540baabb9d912279aeea44379110abec75ab7994a6183c6294bad27bab5bbf821,ec571878fb1e3b1f5d4f66b8b080bd4e50410b6eeea4dcd3cedd4622bf876160,1\n",
+        "135,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04bf8976a28221000d7f5219fa8d06f9f8ae47be626f89c2bb6c4d0323bf02f8490c78bc948c6bf82a191f1de972e57db35b05918594ccfbe8da19bd46facbda78,9f3d9de87d9cc5099ff4f56d913b98b5eb1260e2b3a2d7a3c5e01a7e68219d10,1\n",
+        "136,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04c3229c9b4f409a6539484152b39535c512a66748972025165fd888c388369fb3298cc41dda36fcb15a0d97cabf757bf0737dae70829f4b9a1d499d9e9911673a,23adda6571d4ad7e940c21023af3ffedef9d8f64e83cc1cf6e992d1da1451d91,1\n",
+        "137,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,046f2c3dd84b44daca936a2edaf43adc8c1bd5f42801231718fce6f5e94d144717a247598c11eaa2c507b0e96dfdd03294cba4472ae8a2128e36f1eabd315aeb25,91e70bd8bf85bc6311b2cd7791b7edf00e22f9cb8bfd72571ec9a03bbf716f37,1\n",
+        "138,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04a511b09334f032cc33ee4ddbb839304f6bbf1daa4a80de524ca24ebb65a0a92e4ea48243cf7e26deaf4de7779ca71f76d9dc6c8c1b7f44cf190fddbe82c2c940,a7d2f3e3faa772d7a86026e2f183dbe7a298ae3d1bc3abcea0df3c11cae4ca60,1\n",
+        "139,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,047cf4a6ec110db892e45a7b2ab38b411a6c41e86fd21a6455ca1a4c2e2220681309b3e399ae30098bf872c9aed5db69d14cb71149abb05cf5227a620c4b16b740,c611d27e7cb52e7c56cfa9062e59f3defe7c1e225727b9049384a180bd1688a8,1\n",
+        "140,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0436c7dcd152fb7e53fd16228465ea0c419da29cc6c79fd4266303b3bd06aa0b9036363a959f8c0b400da525ad7674677f829092ae7f7e8dbf88397fcd19047af5,661f5d36b57af48982e44ff89ae75f849a08b1daed6417a20212bea88c7f2f8a,1\n",
+        "141,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04b61d3cd27bfa1269234a777e118f7db10a3844e8c7d1162c099a8099d887dfb849520e9a038f8ba8804d44f22b37452514f0aefea93bab7bdf180db54485aada,540e255f6cb58d237990a7437cc7aae770428796deb607bc29fbf0a4d11873c8,1\n",
+        "142,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04ac9cbc8bd917192928d9a065fb1f89be4bea850186fd466a7a9014066ce002c51a906c90eee55cb5692f0ac046746ee4bd2205fe5f435d1e71f19a8cb8550f3e,ac6705af9d059cac9977967c0ce514d70dc51d88fde684123a921244933ba8ec,1\n",
+        "143,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04c050d058aada9e43767f1f760abdbc421ae220fd01e832ae81c628bfb1277c99d35483fe6aea51dea9c017c326ba7bbd4175687a72dc5c4f449eed0c53a08052,f7a5b69bc39a976bfa6644a152789c3149352093b1dcc4b6b06f6c4c7c90fdf3,1\n",
+        "144,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04399542882ca4d5fae3282c4edffc3c7eda7c451e46adee4219015e91c8c69cf8b123f8ede48ab76fe2c9218326cb06542a832d0a32b7ac0d485b4629bfaf0d76,f0587fbd10e332ad297b5e463d4f09d2167c8589c46dc6680c13b044a34485ea,1\n",
+        "145,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04bfc304fd88ad8f11801d35286a49505cf349403d8100efe903d078efd5d3a66ebf05d6fe2a14c069902f0d8eb6800460731d48395efac4428ed87b00f3fc6fb0,2fb4991332a5d648df5ca6bbd08575c7553773a97312303440cfe7e43d3a268c,1\n",
+        "146,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,046881678d6c6d8ceb01de5d6664a0b57b470f149492e8e7513e121fad849aba1b2ad34db024ccd2694e497f6adf4d3cf5adbf518c768a4628bc2e159d0949f2aa,cb65082df5f54cdc668625017cdf45f22f305a8f34ad91fabf36c071496c84cc,1\n",
+        "147,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04e38dac9ca4ef5b35da77d846093e0d29c1ca350e72b5a6ce901bed9f472ea199f805fc3202920782f49f4b6e7257a4364dd5451d982f29b62d5d4b8e07a33068,6441ac7be81c2fb6472655528f21454d40236a878fbac2ce31e4358ab4ed02cc,1\n",
+        "148,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,042d8c6732e3d0e1822193243bb9ec3fc2c7f264e94ee61b295de5b3c10db937f135343453838114a4752a5514bcfb9dce10f83e0190c540fef10675cb42584a05,0b586c442eaf016f382199729f60240ce50c0f7107c488a423d42794db5f6663,1\n",
+        "149,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0420bec8d2b5aae1f955b7992198bcfe20880494150058cf6151fe14f6071bad3132dc1ce503969b824c5a9e23aeb472255dd23f97d02f68281ad0269818b17e49,b376d9bc1909eef92953cebc3bd6f2bc0cd6cca620c190141740f62239579334,1\n",
+        "150,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04f456946770af5d069d60b279ae519ea18e719abaf5787476873a5e61f969074d47eb27520d72fce10650d312a5431bbd6b3f37cd46755b7a8e1ef1a796f90908,ae739f624ccb1f0ec964b2d1896d2df83ca1969ad6ca26b334342013d83282aa,1\n",
+        "151,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04d1390a944d24f300fdab9bd272bbaca056feb71c0c37468e0327b08504d55f3a80a4b240565aa43be8f3e2089b4788049c5d378b667e987e01aa8a08a4cd2c95,3c25126ece58ad8e93ebfe6e7547b05b39c6d9858e559fc01ff6b6e50b0a22ac,1\n",
+        "152,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,043e61ff2443d10b1e25fb0ce19f57ae39223d33fbb0e5ee2b4740fa19384b7d0e1408119a70aa9b230d9f18269c065c53d4c2619673b49377af4cdd536c931aae,e47d658df5c1f9599d4e560954ab860e9a6377decb0b56ef3c13dee36185b2f3,1\n",
+        "153,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04b06028d729039617f912e86d1d1f44e93e63aa216ab0641813d06c16a3edaee979d21572f9540d7b07b0a6667f7e0a9452f6f9f3671e522e2b497eec138a46ea,d8279c1ec95189fe63d75d1c6d7fc312e411a3d11e4d671a49fa17fa36c3cee1,1\n",
+        "154,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,040a8f74cee50d1e853a38c026f627fe47d81fc11f886268b35379a32ada249bb91d63cf0198e1c926bcb65ce21813e4d72118b7092a5e8bc152909222ac19603a,077c7a4e606099d781cbe5a89caf7bdf4f448b1c0d7d3097263a045170275a3a,1\n",
+        "155,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,040edb7020cf4d6ab14b5a3f8f698d66eff983588846d718b4845d674e7bbfc0edd92a27e40e5ab2e0cd2d0ac1ab679402ce36f16d3ebfc0fd9df817dab17292d9,2025808c609ab0b07924444ea4aa0fa52563858a53221f719c91b15576f49ea2,1\n",
+        "156,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04f06c77cac24a6ee51421863a0d1469418f0a6430e062da18f27dd57401c0b612032b7e0591455ca33b4e49e53facf5864410ba046ba5d4fc6bacfea9a0782ff3,9832405d565c97ba1d6ff46e1d8fe33886222cbaa69963868d12a8be07abac6d,1\n",
+        "157,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04cb2b9df4ddc430df7b0befcb5a826da1589a15bef1b6b25f1201daab5b2fa4ac3801e27d112f0f3276722dcb58b8b4f4844a2e614de49db440b7cc7620812734,c4bbf44547e8128b9a46ed92ceb07df691e2e91d0b47dac0dc2afd14121e7a80,1\n",
+        "158,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0445206b62e4a3c4404c74ae8695cdb905a8e6a9456da09c72c72eb7712d9d52e81ddc2d56b634e4ab66b798cdb4db86cf94f02208f747304ab3d5aa2bb125e137,170a91aa8196df6f0d09ec197fc526996ffcb6792880f01018b3327a096fe638,1\n",
+        "159,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04584d2dc258bd4650e6fa04fe9d3d2a5e768d795945ed2323f844d0a8fa0c6fbd5f96256b9e1b7263fa00fa758cd6be15d9f6157fad66c729ab0dad694564e834,ed527e31223f175aa786f146b3fe0561a41b1051d5eb32249790481eab1ef381,1\n",
+        "160,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,046c5527898ae8067da56ac82caf338c9e7f40ee4489115daf0aba923a8b6e501e430f5970ce9d01d03ec076f8daf685cf4d5a9ccd5eb9e849d43ae2f36f2e80e5,5a74555732d8541d2f73e3a59eb31a131c8d41464a1f2c37531a25f4a6d3bfe4,1\n",
+        "161,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0452cd9924795fe2a251af7cb569f66d9141db894545d798a0db3d30e50f100fe204ea81c808587c90f3f2c94d993c2d0cc4be64dd6aeb9dc81c70d78885b2f776,f2750c996f22762629a3f808da6eedd7cc72af4fb0bd816c86e636264bf57664,1\n",
+        "162,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04bbad8da4c018bdc15a5af8f3da4b384c530ea75560cdfd242bfa3235d8d3595f734cbd866487b83fcb84a4ac74ac548f2535b79b57d02f03a1a37e2791a096e4,5d2163ca850749991cf782c3852e86b05e6b05ec8662905b60cc7b7e37434fbd,1\n",
+        "163,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04389aa52235043bbd759868898bbe277ab996ea9387bd7098b0072442bd2b42f5b823364e9144a1eef1f10093fda0c30168f3004e2c2ea74fde4978f3aa1a31c0,f8cf2cccdcb53b3d3c6d1990ae16c71ad9d141ca49f8574a72047ce6c2da950b,1\n",
+        "164,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04fd1bac144354cfe1cd4c64aa3a2f77f0aefa26cc5141082676370a0f1ec92cd8fee66992d2d2fcb87f90da0a6743378466655519bc782dd7b0ab570f6ed451d8,ccf6e14d1add6e4b5a4228e5aad0b31fac4b45e2112c1c767e933c6a0c3f2edb,1\n",
+        "165,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0478c926b0ee01c000c25a83631219f08d8b34745d2ea2fdc9ebdc5a2288fa9b0306bc00ab3790508e5705eeabfbaa0744719c9bd7b467ca4a37a06f6fdbe6d86c,57556459e9d32b75a13776bddc8f547cb64708133e7917b61e3697c392003de7,1\n",
+        "166,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,044cae5606bad6013f7f36190d7254cbf0d5a92b338e4a47702a3c97a3371d7ec280d273dd598c20392c540e58bec9b180406f3fa6e6c529a851bcf2b96d8f3809,e5490cb494bc6ab2108da2cb0b926cd878712f54bfa72b59f702c180c62b0c91,1\n",
+        "167,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0408ccbd74f297fe71ca3115c0b1ef4e0421b99ce91ffcd4b72a530b22993e18e9ba0ae1bdbe1c2836ffe9a61ae5a899f152c90b42823638be4d51dc3afa99e6a0,b3ca753c1e1067b550736a66c0d6b6f47e9394c56bb80b5d4204fbec9e59b490,1\n",
+        "168,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04aaccef834f57e6c5526fe92748cd8cdc1375c2ac71139f5d2587305bd3fdd3cd965dd5374b6a319850c23ebc2ec7a2deb7ff3e428679d4afc9df7e75f2e06e4d,b0cf8c178ad95952520264d0f4a24389bf1b23dc7ac1b65d4e8fe822dcf20d67,1\n",
+        "169,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04a585e1ed6e47224a472cf4ed4ff34e6251c62ac682e4b70992d5002f08d9e203e9b7b28895b9db4016e5d94a9f59385c16db738a83b84e6d43ecef820c55d462,15e40dc49ed62d35e8c91999b05068f419238a222deba206df47d909d3a1f40f,1\n",
+        "170,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04b104d0cc4a987771655105cfc840f195746e112334c54801fd93f4be8b114a1d3cd8cbcf4b274166f82cfe57393042e3534e68df2f4c3dad1b7ce72b47cad256,e9458064d3bfc444417486ac1334a93c9aa4468031134ee0196ca6e31713956c,1\n",
+        "171,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0482bed3d552098d2fc9e02f1f3cc32f5f31cf6cd101bbb8b42bc6f732badc1976229257d92b241f2031ecaeba10f1ac154d8a3bea309328231272eb6aa01aa65f,f4446e98a63b0598011baaa4f930513218e8370abfbd46f721c8dbf37e170d85,1\n",
+        "172,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,045c4fb681213bf39b68e7ca914d2830b12a7a32c96a9c788ad2987c009e08d0a376a02ccf594c28995cfcb285ed5d91dded92921108a0b40928487cd07180ab21,dca827687aa24f2fcbcab5c38069f4860dee6698fc23908b06c7dae713a141f9,1\n",
+        "173,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,046b29f8c006869ab6be793ea72b970aceebb7a4c4b6fbafecd1e35713a28bf284c76b07dc14f1dc533f1c4ccb0973eb53e53023f0b0f1a8914c7708c2d73d4817,19714f1d4aaf8bd61520b647633a8e53099499ac368c3dd6f1b084891619b0c0,1\n",
+        "174,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,046e6849c2b07a37c4f36be911b323e4ce70c18b15902612c4fc0fe6d91e7c180de925544363c68035498cbb2236f5c1ecd0e4b2cbb5801a8cac4d0883f651bbd0,d7a2da8de2434e2ad264f9706b30d0657c727606d8285d2179800a970b4faee3,1\n",
+        "175,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0433649a1e74c7ffb5edc3949c58b7a7f4b5348288f621c50fbbdb714fa42aa793cbfd969e077b00ead21082f0980009868f79e430ef1c216394bb0e9eda135e9c,853d4e01d4dd4c9d6e7820adc16f32ce7bfeec0d578deaf28af9cbb3315e8f1b,1\n",
+        "176,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04bf976ef204532bf67443e8b8d9987a683184ec26420329ba268e54e90b480de0beb108df26eda91eb4fd23d26af6f2d78a4281d5ede075c2c715fb1c4f876784,35715befcfded16e67fc0dcebe945c6264ca0d91b3663bd3ec0722b585e5d652,1\n",
+        "177,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,042c435d9faa598070b4920277506c100de62a7df05c34a39317785d628d74dde3c7f5d0bedf54af1c7d21ff955128002fd5296237384723fef1fb806c2a6d8ea9,2baf108668e82b7fcaf3f5e3272637b426c551d8af0e55f5d74bc317a4474767,1\n",
+        "178,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,043819dfaab5537863c8dbf406ac18dd675619c9a7a554620ad8a14492bba425a3d68e8c68181555e22362415c95a31724ebfe8b2bf1764e209eae9e53b3f462a0,bba443b160997aa8b7fb2748911dc2154c0f6b986fbe9a49e0a2934fa5f32954,1\n",
+        "179,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0470ad3a9c9a9db71d420abae84ccdb12768851bac6a82ffd03d89621a50a7c311bfff7c664c211f93768f84b5255d95c7f67887c3305d789d7fcedc2d29989f9a,b4831736601e7387050ba3d401aea241c3506b56a0473886c408b366c8696429,1\n",
+        "180,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0458391361fbc8115c978e82037814d31aa3a88873ed6c74c4aaea9727e300d94542924c67b5cf828be827e581dafcbd16e653e72a4f2d4d0550805387b9417e77,ecd0c9e1acb90ff8bde88f7757a089cc86cba27f0d15fdf737ab3b8ecf9fd9c8,1\n",
+        "181,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0423a2be684b0b5f04bef5c6ca8a991bf752f5964f6fdf36d7129100daf80f1434b6f3ca2a5e85ce005e1cb6d2b13094c434fdc1c095a3ae5e53f64949ca56691b,6b9ee618598f33c184cd63cf8930a4db3a2d4ea022d50e63cdfff85734a77ab4,1\n",
+        "182,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04147b8a2fb4f6e85eead81ca0b3f230b8d8cc230de73107d9cabcbc5b39e4e7eadaa44ec1ed0b95f6109223bc480e917419d860f9b9a75f81d6f8ca3ada377533,d801dc9354cb756e6c27de5a7cc88ed5cb214ac5091b4090624ee8afbcba35f9,1\n",
+        "183,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,042b3fe64bb142789e89e1092db46b613012bcfae57759ea908165c0362f804f36c0053faf3266ad7eecedcb24636b99c935f1c8e73168f0eeb3ddfb660801e55b,03307ea557024686910d2d1d2d2760d82664413b8feec66ae8d2dbf1025f0c45,1\n",
+        "184,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04c4ed72445465cdb44f58b0e1af0823226ea79eb2e1bc3f27fb8e4ce7b85f4a30c237e574c59a992406fd517f4d905e03d7a2b0a40ef85aa3c73bd46a1a06a918,dc2cd94321643e89dcc92acb0128d886b28cb7d66a0eaa5b96194465708780d6,1\n",
+        "185,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0418ef4e2fadaac1b982a7d2d12e9d5148ecf336b1d3775da2f7df822ad49a1324bd07046a3f8e949e7a0d960fe9d9a1de0f61497cb4e7b2f39aee6844396f997f,b831f4a0fb75927bdd2945c0081f11cce871c9d6dbf83b7895748c3f46375ac7,1\n",
+        "186,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04398ca1a944210a10b1f5732071259528df87d42d3d7b006bc6fd9e1e09f6fa30fed379db3f1bd915db2ba27384ec13715417446ee84fb5fd0a4bf6431cfd3f15,ed607a9e6d41a4bc0535c5161c98613edac6b519590b481420fb2ba1ed2c35e6,1\n",
+        "187,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04d1c182adb101ebe5fec3910f80058e091d1325433d4fd3bbb38eb75bcaf2698a21218f7544ce84dcfe52e817ec0ba6bf84460f49932b3ec5ed27682d337f270d,64a68fad2378591a18f8f2a4e346faf59da29446ec16b3fb8c37aef2d79faea5,1\n",
+        "188,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,043f68c4e412c57da015568e0a9fcc3db499b77e6c0f55050828c50c35493af5e3d0b53fe30b0c6cf42cdf9f4f01d5c9058f8169b241bdea225932f9033f8bc5eb,0fddd50bb27666d4d38e6ec18c8ae1be3d763be7dd11067213e997fa4059c67a,1\n",
+        "189,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0456dd4c2b1d7a1a2d6559b5203fcb8974fa81be7d64cf0ae7a14fd965dfd69cddebe1ca78d5583fda3487040dcd94764f8dc619e8d74aae8d9665f340693c21b3,9c412023b7a66ebc9579a8d16bfd3109ba085c42f3fd395e07534529ad2340a4,1\n",
+        "190,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04e1e5053b6f43b8714a025acfb86a8f51195488099b1f5d63310a6becd7ccb47ef0d16bc0c3234470ffa8d45f582fcb65ff9ccaaa6ae0cd6b572bebaa50c17741,28717726dc3674abb4f82b66837e8685ede16cb0cd965824352ac0a2f9d893a7,1\n",
+        "191,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,041a46571a1438ca23dc7912a8a7b2245d70c852a6e9f4d385dd608427ec3c41e7fe06e2dedfbaa376a614657ce61701a7db181e5b1f3139045b8424ee54964b7a,2e74661109f0cbc6d587790330d67988658bcfabf1f7498a2b3279212828e207,1\n",
+        "192,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,048ce5a3c8bd2544695a005841612a7c5d05beb07cf7bca1027172b030acf7d275fba0c339f74ce36d104fffbd5ae1c9c72588693190ed2b3687433087213b5bdf,d202df6662ba06d3088363c60e341283f7b6300104d58cf6d707262be6972b59,1\n",
+        "193,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04a0abcdb1ef035e186e720606b07fd615532039275ac1b6f22720b756c0f857cf76e465cfdef30602b2e055a303bc6e176dfe972d06cc6f3821780387bd6357c1,a54a81c18f4b7ab0f3702013678566ce29e91c4142114d62f867a5278f89cfff,1\n",
+        "194,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04859b6beb70671b3e64991bb661180dbbe835f63c0a5878c3f83f0922660a7c093389bf4ce6b5c1c2f801c84c54391d53aa953ead5e51b7757b3508345bb4cdeb,9a954112f52d76709d64739dc75e9ce7a76aa19242b306391fcf25ff92b76901,1\n",
+        "195,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0435510d43f4d1a173a0467d5cb35a4170c3fc407e55b416b4dfce28650f8802afe8ef2adbde8b40a1714286176d674489bf9acb2e4a8353a7dae1a9e97cbb4150,8763cb235f2780c1bbd35fe6c387d5505f72eb0a77b104c775c2b3b42786d7c9,1\n",
+        "196,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04fa4af089c14d6a8be1881135989411607160d141a7a8cb4546f358a797d2aafdbe0086796436344daeec063f4f4a414a8779e72a960892335acdfbfd452f727a,b9309ddc5eb64a4d819a8a332b061a59f163a5f50d4865697e4d123efc9b2b29,1\n",
+        "197,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,043cdcaa086427023bbd91b5b2e212be77de5591a1a0c210d54f0482f27c426558f8e1f4fe6e3bf037c0e03d4043c1d9b25436e0803b1a42b6de2e40d99e839c68,87e9f5ec4a9091d94ac22a6a71408213f444be094c618d459682e17357631939,1\n",
+        "198,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04d7adbe30a5682acf9d398f58da8fd3b583283d9eda74ae067b9b533cd6c0824cfe50d0371c0e7b59043ffad25e17445cfbdfb3fea40e55bc7de19ac5f27c64a2,c69a00017184a13c713cc0a70e89c60174361d07dea5085fd707f4b5ed3faead,1\n",
+        "199,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04029e1c6eb37c383fb4e27ebb3197688f8d8af755db83b7628e17579cb3f90f058a2bf57857f5ff6331cdcf87440b6e69cc1b6e444ce540b8222b955c98a99955,c139b75615e1010c920b07d14f6a1980ff4c97a0a9bb8a097aec2a456b6bc4ed,1\n",
+        "200,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,043a35de213b2dc33eb348948a22ed5a93600fad071bb017a6a250e6609b13f7cafbec06b663a5f54689d0ee6709fd0da46acfd26038935935f749d6d4bc21060f,70aab5e96e4991321faf440ca2dea861ba007df08ee46c6f579731ead51636da,1\n",
+        "201,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,046b910d9e9943cbeff717ca9546aa5677e06118f5f04a0246b5bab73505775d65c87a4c1fd7bc584c56991119699b90b4b3a568e508eaa83f118332da9152b13a,e2ae03b844b3f279d5cd16bff20ab5cad07e4c984f21cbe73e1997a02bd2c291,1\n",
+        "202,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04f102b90ad378725fb7ffe3fc3fe6efb320a728a03ce09a88ee25bab2cf133c04af2cfee528f3913c83504498ca8b3b6deb9e284241b8d01c678ab79ad8091888,0aab6b19e4205548f929362e72b077f2365667bdd81d93a404343e7a5f84c6ba,1\n",
+        "203,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,047f420fcef93b0b9d0a9f86b2c65e18938e17aa84eade2a7a6440adec914cb2f6ec1663baab8af308333399adceff908ee33c8f86b3df9ef93a51520931f851ec,d96cdd08e6eeeca690989b659024f324e18c2faf5c50958da6985f70826095c5,1\n",
+        "204,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,046b83adf58bbf00da4b77b6c4615925cf5a8f7b72997ad96904855490834bcf82224db940bba028dbddaf3cba949dc41b0db795515e34549fac11a183b89d5bb7,434bd68e45630ca1b3484517da080e3c3198ddec5ef1f7e9d2e3425df214b90d,1\n",
+        "205,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0404102367dc53576a8385fc58ee2337e2b9af547e69934fe3ec797a84c225df0c621cecc727669f2e558762b65b33b3cf3f228fe9a9c22223ab71e77f904d6aa9,aea47444c897f7157f336c77b7401979066d6617b59a01988f78f6c9a98feedf,1\n",
+        "206,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04258340105842ebe760c4fde13e31eefd52e51aaef938c4477d148bbac6d37412301f4b4d1bfe0e7046cb1f993a359f9191fd7bca7c53e039fa51db8a117efaa3,486cf8959f7d939c4c79a0715ba7bbf0cc3fa7b2a1d60e86ad097c91e5612e24,1\n",
+        "207,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04d9a521d8147c1e83df82b9db62b25e6ff1417ddd41aef3ffb182ad23f27822f7b0ad917462cd2a5abce2ec2c4a4f7456ebdb65db10d962056e75f6f8853d2a4c,5a1f0674b1397b6e653ff6e473d641da4fb9e7bc90a73802739a0349148500fa,1\n",
+        "208,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,040b58006e371570686658d458f26faa34ccf8b49fba8234ebd7304cbba3ab1b2468787e9c7ea3043e0bf27aa9730a5abe473060b77c53bddc70e201d7f5b1d89c,9a907514a9ecc85f9659b9e97909a38f972b0c9a7c009778b8190438a8ebc00a,1\n",
+        "209,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,044ab8f5ac88eecfcb0394f6cfe5528596b6b4c4fdac8247fd62957289133e620e1af51852e11b19d6137852e218fd64d2ebb567f8fa92a1ed43a5e34f5694a94b,941685fe4de816261157b2fe3d3ac28195d81fac6225fd3103ee60a0c24df472,1\n",
+        "210,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04903201ba08353ba6158c06e66df0b413b771d21acc0832213bd03d589575e67677a90ccf2f3079cd2ac6c59cc0256a612c079b8a91b59ece1efd076f53bf5b04,b203eb0365a40624a442572e0bad80e1f0c9958e5709512e76b28f4e0bfb2291,1\n",
+        "211,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04c8021be2269a2ee83853e4a12bb0680825088d9ac0e56fb505109f4708dd9d5dd802ad690d8e8b817a815de607865afabfbed7650988f925ecf23fe5654d0c9c,e09d25f822471e647064b5b68cd2ae42d6be7b8765cdc026bb7696a524c83f49,1\n",
+        "212,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04a53f7c412c11ad6a362bd2be2e7d1f20440297be86594abbcbea2594ddf9372379db08ad87b536939a70582682cb7570263655cc25a2979f845fd68be3d82953,97e3c29f69d9032e676933edb152f2438ec7dffb17641442ce9342e138f81667,1\n",
+        "213,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0446feed4e522963192cbd6c6edabd5175d10f93999a585a045a3026b69bb4d528ed7f6abd7b39e40e08e2126991ed410394bfdabea990abb7b2ca5eb9f048fa4f,cd7874612d68c907b434bd81bf1b1a83cf9429b24cee753cc228ecbdd6657388,1\n",
+        "214,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0467db11ee0b73071bf3b815864a178581ada3d100918365e7120d9bdec9cd9c3325f5eb5a1b66ad104a5c9e43b07afa4b152a75fa22a3e429af41e459e7993e45,621ddb1893137c12147e909dff830859dcd73ddb00acdb4097f1d66e14fe366e,1\n",
+        "215,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0414cdc4f16c07d6e6074caa8ecca26a0186347e723dcedf9aff9dc6fc8c3815bf5d64fe2d7e6abc20802a1c158040cebd614deda0347987e0cdcfd41e09618cf5,19ad362ef44a36342cf9143b88470d5659fca6a3a30c904271f6d6bdc05e9407,1\n",
+        "216,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0424193c3501ffa77ebf1ee62f7c118b28c05a1c0a946f442b208a8305c6a745f8863603299dfdf5d2bda19230007d0e03ae61fe1caeafa584ddad4cea6dc7d76a,43bd8cf370257bc88f38b0ded68af2f9d93977234a19fbf67abf2e0a4c09c120,1\n",
+        "217,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04081ceab1d3cd5317fc782c9c8dc33399705aba6899c0b804efa96ed4ee944da900adf51cd31b5000f2d175695d48a12213ae1595b98372643ec0eb400ef79d41,9d920e7a6f61fe4140bbe56f4317e3892d21a80fb480a091a3c16a0a67a7a97d,1\n",
+        "218,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0413e56b207855eabb4b8a27dfe8d1ec89644be7c096f6c2f3a122c9cd0b8b508bb5b7970e3a1411f4ffe3711405ec65ef98db12a2b37d8c18c8d1984134e85492,0796221792860298f1b4c5aa1561ec5db01ad876c3552bd96b00aa23ef47b71d,1\n",
+        "219,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,045cf95e23e681059f008b32df326ffb7795bdf74bb337f77cff5052de7826794b5cb038ec1fdefde51f6c68dc5e12a198a51ce86b92167883687b253415d6d37a,8cec890842617de7789c3c2909e2d38031d5aad957d995bdec622b010ce9e0b7,1\n",
+        "220,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,049c49754a4c45d97e5b70d0931b98f60b3a99f51a95497537bd85ede7e9879429dcadfe273a4086c30dde4755667923e58c463e8d94cbb7d56c9e0f4de79e6d21,1d4c7c7aa359140edf907c8234ef16b92f201f562c2237aa32adbcbdc0cabd0c,1\n",
+        "221,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04fc7fd984dd0dc3c93846f8b41b07296ea854401325f155f1236f2e4414a9b9da473f38a5f84d08c0ac7a1dab8a568eac21066074947449a8c3d16f055a379bff,b15134b9e91996e228ea7ba6cb4500a117983c8eeb687174657354e59961e521,1\n",
+        "222,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04337e2e260a3565ff81e0be900c8dafb2ce2310688c3eeb6c025cac208b08a18a4484fc5fb01c2d404da99b56a4dc226420dc3e676fd0223ba3a45d43cdcf3562,906acb96120ec7684e391100cf0bb747691678eb3e147f53db886ba0fc5aa70d,1\n",
+        "223,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04e2b170d1dc4d9e329514a54f10dc81d902f3752c3a6e2f8be5d820fafefa9d8be087dbd390152ebb04c73b8c504b994a768372d3f920a5cedc4242bf834ccc6f,73dd087b1cb3c5a07acdb9b0a4a02c64b7087ae97836e943439dbfdf41eac833,1\n",
+        "224,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,046702ab6b257c24440bf719c02d2161e4e31e22d55ed8ad0f33e5af9568ac4a9abf87accc758577389042f5b650c37db6b0c7682203156de73728a582bed6a6d4,f73c49f6da537b2ff30ead7538c04726bc74152535d22b6baf92d06adb45e676,1\n",
+        "225,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,040cec0aa4de0c143f5d4c3d36de3db4cd72e8fe0fbd336de879a562ac87e628d8e75d0d0ae3d7b4d869e7f6ff564e21efc30a15ff2d4c87618104fbd42ef5e00b,f4a757ceb0ba6cb9618acd8fef68d2c8fe9901fff14177f27b6e6b8c6bd34cb7,1\n",
+        "226,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,049276151fb999ff3f7fcf542491fb62479fd1eae93fc2e7d22c38d944867c447ef0e7185e4d55a1c2eafa2cf8d262636d6e4b353fe71ae3d3cce6b158d86cf5fe,3eb4a634d0090175347613b7eed6d49b9b5944e70acc3ed98474989d30a4c299,1\n",
+        "227,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04e657a91abdcb67bffa8f78565ec796b4901f2991c12722d27bca6a0217f2b00c9bb2cf5f6c5780c70fa8f03159bcb0d56096aeecf53ea5e28d1058c3a50d2091,520dff4d04085871cff69b3a20a75b8f3b103da0e468365e8c9287c0a7ad7d9e,1\n",
+        "228,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,047c051c1eebc76746eb267e8e91a47d8ab81b89bd3b3a9de6f1c3e6b98db81c7b75df088882150b97e20146547ee07b6b5620bcece4d40a53eeed84e5d4779a1f,c80ac13c5d2e6723e848edb023fc17ecae553781a4aac90f2577fae7511711cf,1\n",
+        "229,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04d890400f1230fa80d8d4c95173924e9e7b3458f7e54680ab1834e505a2dccb26f714374c9978432830b8e1b82742ca86777f9b8b686b1924ee55e7c572c2b119,b0c28a9938cf095a4b0ebf3daa6a16e19e3f4199e3475ca3aa58746f651b921e,1\n",
+        "230,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04f44866b8ee1b937d182ff79aade41b549b71ff1bfa882a192ec90dc87a51774d5e335f19880e8438b9f205932664512cd6dd53d5a40a7008fc5c98124a7d9554,78dbfec6b4aad2d0a99bdde7b90996324c0f7b9d136a6ede5c2995197d0d412a,1\n",
+        "231,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04fd298ab944a08702816a7395f84e45ed782968b701838b67fa2528111cd4f4148599867c89174f00ccf30627815e6618bd2845f35819db0754180535bb4d4b2f,36a2e77f56c3e5b11e35bf4ba5de1885cf0264643cac5d6f7bfb1ae01e39a6c0,1\n",
+        "232,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,047b78d59967ed07c83f0ed7f8f0b26388db76b0863b64ac14b7ecbed8e3a1bda24b49dae1adf948860741376c919cfd50ffcf749672f19f78ad565e88f6096df6,9f4e92d9a959c09eeddd152f6d95ff2c3157446f477fbed214a00621d014f936,1\n",
+        "233,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04791c9017b3a93ca2f2d03fcf18b4230331fdc3de5785e847c9f51d22caf50cdbedc729c92f0a88233a29a2259e7e6265b92a1438c0b5959167fbe2aa4a65a6c0,d0b16561349f182ef2792d0c2dd585601ce4e032754b7628b3d801f187c14fd4,1\n",
+        "234,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04475786ce215a1873e04a0c67642c319a6d24dffb06a4cffbb15a8256d2c811ec5a1bba7f661e38d694d9a11564b511af6c6632a5efc933732642dd5c4928a41b,5fd6d7f0ba317a36434e1a995bed54a42898d940ee5fa4578373e8d4c23f5567,1\n",
+        "235,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04759ae77233b119fb3789059760112f38e8d9e69f431cf0e8f0bbe6a06e23bc5b18d69b80980f53b7e8c76c9b82dc61f05cdc03826c2c9637cc02af2a6db0e4fa,bbe559b77e83905b08954dc3736a2752d56a5bc366a5fcd84e042a78c8af68d3,1\n",
+        "236,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,043af2b8629a3475294ee0d5437321fcd5fa4554c780b6b18b86242d3edf36f551ede37c4ea319d42f8fc3cf97cfe7dd17e85ba6e11ba260ed991c22ee891abc2b,e62429b8eb322d24e4bdb9a72bf6e9c94d82962b26d99f633e1f21709b7eddbe,1\n",
+        "237,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04ca2df3b3d7d958b0d46ed6e0ffe3b7488f2e13660951eb821c24246d6c7f2ec2055e780e6a534f9ff469b0ba3c8d38962ac0acdc7b4b3dc057c07ead3f4b7aa0,5822c168aab9bb6ffe00b7c4c7be5551daa8304b8d2c0696e2d77fe50b9d8d8d,1\n",
+        "238,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04ef17ac084abad12496df80d80dbe21dfade58e302ac0398002c5349d852528ccef34500266a5dd3fb454828ed85684a62e6eb142f65f5497e64d23148f757976,bae26fa69da00aa03fd9028fa84d46b92c13d5e555b2e7b3db0d09bb95d41486,1\n",
+        "239,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04624cf7459a3e097f114383a125c7cdec33b947c5bc0a2679d7aae508b5d46479408cac791f2ed71d9bd594bd66f6ce70d928d3b20fe02b5b66cf743b51739a74,97ce8c4b6e031776b19fc7c9577cb26f085274a58407267bca35a97692a2e8e6,1\n",
+        "240,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04e41ec556bb3f85cef6651a2db1816dab3bc82898871482dbf1cc801407ce4d1dedeafe8c33721250bf75cdb9181e990492d37080e7dab41da1673d62a8b835df,5eb5722f98aad0622097d944bb120e09e7a122498b20ae2bfff91c8b362daad2,1\n",
+        "241,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04b5c3cb146d30fecfd7fed0093dcba01846a28aa50c7fe3c0cf4b8c5aa837d5b0b21b7605cadbc7b6206e5dd4289e1de9cc36bc98094fb18223be636e6d36e0fa,f5c9b88d48112041334c574f93313670cdecbe0c0b6c2655778df8ff62025d3f,1\n",
+        "242,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04b3c62848beb063fc8f285c0da7207e707c71460b8f792ae0890f2362fc8f02109cf80c0e0d75d2f54a6bffe3fef39441ed0cbf29c8397b76a824ff9ecf4c772b,effa362fce62e271016d50a0e35c031455fca280b80ed2cce87c83e57e3cfd36,1\n",
+        "243,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0407ad8cd055528feb4b3a53d354c7c7cc0616ca3ff787bbb0bf79909606d27e8a70b4d271ebd8363d9ad910cf4d84e52171b5b359792f7ff8a89c4427fb6afa21,59b8dcdc6676029109871fc67b466a7fb622225cd6c77bbc21b1b628464798a2,1\n",
+        "244,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,047c66fd67b79f88531a0747288726dbeb299dd8e1159612bff2d979fe4bd1060c15c54c5cf40b7a6b36f4400bdbaa2b6dd0669c3b45a55925635287116aaaff1c,42dd3a8e14dfe9fe2ebf80746989ba66f28e5460116a02bce24c549d117d6b0f,1\n",
+        "245,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0494064698280e7eb6e14dd81efa9f0ab527fe6ceeded60cd4d422162c397d5bad163a63342b44629e57c09bb490118b1daf06bc0bd1de48a98ea7ac3e893e4470,b0e1025b39c5481a748a0461aa7773a6b342adead4b4587521a1953d4ff0296b,1\n",
+        "246,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04b454039384abb191e593463976dea937428b76a2f21f8553a994e0e23a0de3282888d4e22eaa986dfcd20e5a4c9666a2a341eaadcdf86b6e137660c95561566f,9dd7118006ee56fccf307a31a6334be7e19db2deca68fd45b3f4f94f100de6a4,1\n",
+        "247,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04d43b704bcda6ed2cd8cacd64a67191da2f68f25a6a983dd79010b1066942730f2eaa0d0933f710917e3223f2feb23388add3fed3a2a7de18af50803b0b20d6c9,1fb16336393ba603fb2eb701fed0e982ad32964afae7dbbfbc5a8112382e51e3,1\n",
+        "248,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,049ea3db44d3c1e09715ec330d3607a06cfdc1b0baf4f570fbad15d63e1a8d190bdae78a1a46ed6fdaa02ea2785c2bad33aace95397b290eb7c26428ef68494abf,9a6d03a17e68561a105da66d2fb1d9ec9e3ca6c686f65d9da926849d7af4fcb7,1\n",
+        "249,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04e4cb3b67d62108687c74b36a081c3adb9fc4e188b5e611727312b70886e81a795edba4df71b9c4b06f7b052b5b48d9e0be855ffcc2f27926524cb22ffbb9e865,3c03077e0098a845f7c9b22b73eecd495a2d6a0b34d211154ee3898634f823b4,1\n",
+        "250,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,046e833dc786039cb081ca12034adfae41e3454cad0976a09612f1af4c390d589f16f499bb679ce63d15bd4b821392e6c3deb9ac2163d0211a68a6167bcb5dd0e2,b8454bcf8463c2bd59f7538b65f11b9c98c18c13438417cc08a39c8842a0b7ed,1\n",
+        "251,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0493b0cf66e6c51ec9f5b02589607443bab7b97b18f3dd2c9cc831c0a356b60c21f960bebf79b0c295794237c60576d6a74e5f694d9fccdc2c4a469e00b18115ac,b4df5f335a0461a38852205ac73bc51512b5c7f6a8305f1a8d4f191cb6fd3b2a,1\n",
+        "252,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0467f4d7cf5b8574fa36ec8d3d4caa369efe0521ff9e25760cf99894c64f064ca34db1597fbd96d7b7e319236e0660b05800ed99099c8c1022d55be3a8fd231e96,a9de6e92af14bf4eb11d7533bbcb28cf622ec5e52e4a2f4cde4ddc3d21babcee,1\n",
+        "253,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04439638adcda870137436eeb09e27c32637307921974b64b9f73e266d8e95393094cfcf350b98282437974db3e402fd86e3ebdddc5e23fcd07303a0a5cf282ba4,edcd8e48df11aa67a90c75614983466d244e4b5473f8ac01a41c146db13c4827,1\n",
+        "254,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04bf32693dd77e182d8b2650382832f37f6770090132aa77a7ebc18215e00c44c04642ea3461ff10e2e1800dc392738d7d01174679c9d2e382a80ed4961fe48b6b,2c8dd74792ced281f9cb161d2f64d238cbac2d18f8661b0f5674d79cd5c6edb8,1\n",
+        "255,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04d20a02a6d0424820f7c2ed6afd1b7c149f6762bf8ce4dba50ded9792368dceacc574cc6298fa1d96edd178309f7508ce8aabf69fc0c49b85299baf91239e6665,503fb9bf4b57b00e0c239b3e8371f24660aa01cbf79f4c499e4fe1a155528ff9,1\n",
+        "256,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04afd321e9ff7b24d856bf14bbc5afef1952744867cae4a9f3e38f6673da908aed714966dfee5af5b7ddfc1779db74987e9e87f532bea76a2cbed717a36c9100e7,e61ad5f80632382b626f9a77fb7f5db020dbc084c888c6b09993e12fe4d31604,1\n",
+        "257,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04cfd6d84113fc920b44bf6d67cb841691dbae07bd6732e5dec045e60d90b98f7110cbf8c9ffaef36f3d53132b1c10db5672acd5df5b87cb98d19daf87b0de3573,51c10b8332b33faf529c58cc2da45a23bbfecc1d4e0aa4ea54fd819b7e31e555,1\n",
+        "258,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04f09616827b93b6017d770c75e35b0162c5455ce2380ef2fec54e336dfe94cbbcf3d01b7b102bec4ff0245db8c943c68c23cf1172c65544aa1174e44cd524f049,98d67822c318db0653a470a6ed96e7c22a046a2a25664c19539af62ae1d3a96b,1\n",
+        "259,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04bfeb62d5cdb7333e0976fad3a259ddb9cb525aeee68327657aed59285352f3476e88bc9799df4d0c142bc632c81d40486fe2376392e0180af93debcb82c639cd,7c4648cd808df9a54f992b294a3ece562ba5efbeba7e1760f1f107ed1af8c187,1\n",
+        "260,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,046d864a7cb7f8e3a1fe1c8094e3852f8f43cc4ca6a9039512b2ade5f040e3b4237c908ec1cb9fbc1f6d49460ac19f2d4526f66e00db60d207408bd46c95bffff0,41b89b46f018a3ac884ad921e49fcf5d9677ae84e39e6ea8de844acc337d8481,1\n",
+        "261,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04feb68f41e806a239f62445d23d1b925978a9b696d6f0caa9dc29f40539b073cc2c902affb20066d2c2c920ceb8a453e42cd2454988c332cf0db907bb4fe95943,d155b0e0e37da1a19af0e85e68e7bb4180aab55b1e95501a1a3ae0cd95404aee,1\n",
+        "262,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0457d04c653325a6cb998f61ce347109eca0efff9a16a734134a69cd1e0b081aceb43aea4f71b1f2802fbe4107d0bfb9f6fbbda464501b87ff73c47103e372f635,12bd72e952e6b81255ad79af19310da2e0ef9772003384a1f35753e6beab71d6,1\n",
+        "263,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04605fd33a42d921e01ee7f75806106de72cb5039f65ff31d6ca2e1efd6aa81a1c95789f0923d705fd19d5a8ae18b66687cb29091e17944b37d27f398bd5546bdb,3514b8362ee1e70e846ede7ed57283f5d5891fdb9b0c5605da45dbc5c6f44e53,1\n",
+        "264,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04303ce896aa570cf8f97954ba48fcc25f5f252867f01a9b9edeceaa6bfccedf561134d6290e1649bb028a16c6f54eb06c7e724a947a6248274a4bf6a6aa139096,f6c829ed9ca3dfdd1f165a204461e1c16620e752216e2b6e3aab6197f3dd2b3b,1\n",
+        "265,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,041c2ca67311dc5c454dc830386b7997e50bc67e3d5ff522d3e8a39f144998f884862c975f548a5f55dd8504dab5c9e88f0ed3123688d475b211da5a4d6920dd63,3719f7afe82c3fa54c0c1260476246441d387970935e4c76965ced96da3a07de,1\n",
+        "266,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,045633cbcfdf74327de0883f59e1788eed76bb0b9e0f9e55e2769ec9aa365a30e1d913bd531f4a61c2d07b847d318ee96482d2f8fa7a12aab3b303c10851ce7fcd,0a368c74c56c61aab02440e64b2699c24cdacb4dfbcdebe0b3cf801e86f1f74f,1\n",
+        "267,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0419d53e44b058c317fbedbf106c98f31832cdfb84f21add753cf213ba5de9026a614cf7b7b60e759a15a6c7d864eddec6dc253519975df7f3e9bc0c77fd80e510,de5b6c29f2f4f17f8ed925f129159410e4557dffc5472944b8862c42bd2b180a,1\n",
+        "268,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,041c96cc2b22e5bdb195b2a47187fad5ee6736bd96dcefef20259a551e9847b5e0c5ab052c8836e4f7cc7b65545775d55b0c7b0c7f830c6539915cb624a507dbfe,8fca30fc5d731f42d37664d52b64e022d98a25065fb1f8bd77853d7f2bbf07e0,1\n",
+        "269,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04733242cc4afb82271034111b81309e156ae4466e7f2fc5fc1042f4f6e3c44f435c6f614d4be18a73170c85a6b68a9614052934e1d612466dd4921989474ff513,c9431b6deda0c9f92f368f7ca12986f0e07e012422b840c7aa784a0c713b501f,1\n",
+        "270,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,041a24b6520af4f028824beece59b603d15d6d15cde0719ad2f7b8e3fcb6c1342c7ed702a30e875b2436db2f2d3687d9580d3bd7b3f8d1280a81071f3ccd6b407d,672c2339e4c39e36cfe13e2cfe352859e1ef66318fe9f97dd26d9d03a9171f7f,1\n",
+        "271,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,044ade6fa13e59117e054ac1bca3ca52f414035493ac2ee7b1a811f1fb52521e8116ad612cd7ab0c21ef78938945d870dac827becb5b873c84225c4aef159ee4bb,8cd11340313b978fa37749f4b367c087fd900f17941002de22ce4029aa550e7f,1\n",
+        "272,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0434b2ac5a3e4916d081d1ed404b5bccfe076aa7f41e29d0362390f7f08458b44c25987b7f7a214323763e1aa1044a8779bbffc5e22be628138a1d80268364698e,6f1cc2d07f6ee07d0b138b601c94deb20aa234e526fab3ee4adc98707085a73d,1\n",
+        "273,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0458c25f4047782b815b41001dea636c86ef19d67ec056324127225aaf6ff10832761325c4a70307dceb9bf451c7405e42580868e665f3f259995f8c358eb0799d,c2812eb9b456297572e8d870754b48489ea366f351f822759de831726815e582,1\n",
+        "274,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04e3d05f1aff72acff70e4d51b4207880ec06b4c269db02753d6d858aa5e6d561e7c756f6b0cd106bb732e5f20c91ddde4f24a3699df1125206fcc47449abb7d1e,9cf1689a015ac3958dc95fc71cb0d103e81b4594684638933a5daaa99fb3b1fa,1\n",
+        "275,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0475638ca9ef9fa252429d21243c778be355bd130c1ef626593ca0c244cf2b6ef253b88766230ce8ded7900956a5291a6967c2a54279844cb07d7c585d87d40661,2d6049f8e46c67c17ddfc8178dd918b23fd1969e11c959b64ea42e39c9a87dea,1\n",
+        "276,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0444682e437448730f594a820ead232c4443f7e784370bfb031304b85199c4159f7151eceaa0a698d15785cc7a2e812aeda12f9ba4238a7f5e76e930f3905015aa,9b7f8be262b9cd2751ef8eae2bad7b1ecf07cb76613cfe7088cc9bdef1d04436,1\n",
+        "277,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04c98b01fe92feae441d9f4de50d4dfbe9789711d911be6ef7cd9c55f4b3e8cabdd9e3aaf16605b0ab50632df6c00ec8554f36ecf427d31df930d4458fe1cbaf11,52980b5980df38f7c2d59e4e307da25655d50c6e030234b241c098c935a5596d,1\n",
+        "278,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,049173ae014b645724587ee26e17bfeb61f91253fe8653dafbda4381da9fa57e9815a9166e1dfc2a81cbe126a2594e51fb98fbee7b3d6588ad86a86431141444f4,2f1f6990c30136d6f44de8145f191840c4b9efbcf87c39b7995c262bdcdf9d40,1\n",
+        "279,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0400000000000000000000000000000000000000000000000000000000000000014218f20ae6c646b363db68605822fb14264ca8d2587fdd6fbc750d587e76a7ee,afe5b60fe3e1dc873b3f3022893a359880e817537beb96b3d48d375766ab59e6,1\n",
+        "280,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04e4102f16fa7f386e912d3a7f77dcc7dc9f8af54cae117ddba10a3d09620eff8c689c20e12ce8f78412945e1d3acbf9935e4653fb0dce02b14a7d526a114f1387,33150538973510827cafdfe9449e7a5a2e1a7946f4e485a00ff219b2cd58d801,1\n",
+        "281,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,047aa67d0033226fb2b1bf975d4568e1f2299e82f2e459ff0b6ee3c0c57dbd40417c20634644993bd84aa361037ce8bf3fb72286dfdf4482458b076a7a5f46d1dd,b5523f9382b35ed9e1c4f2b420df9f61e6ac8f6d342213fa4d75458f5bd828d1,1\n",
+        "282,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0480694ba7d6ad6efa8ad5ce0435a1bd225e0288b6fc22a11e7013aa0d4e9a496b316d67d1c70e6c130420f57cb6e0d60cda154c737f0118007cfea5c2d5b4e397,ad9a53d05315009fb1487369f72fdc33e6dbba1485efaede2951433526d2fd0a,1\n",
+        "283,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0406c66970e539d9ae0f8f67a72f426c100b3b2cf2e276e9b0aea75b4efc98832524eeab2b413ba17db811f740f9fb9fc3c73b5ce51f1e74e7e08bcd8ab48dae83,a88cca88b59cd86edda202cb4ea1b2d541d5c8c22c062a08f9db496d56257330,1\n",
+        "284,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0441277a6f20d855af6acec53e9923216d74ee2aed18a4140591ebbb0b3455072669bc7f19d64647e74ff00d0c89bbfe508e322b4397ddb8564ed2832eaa5b2d92,b7f531b4a9ac00952bfcf2cbec41e58b54c4f412f464bcf1f1bf10a24b9b1974,1\n",
+        "285,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04146dee2bcaa5cc0817fe191b6d10def6259df744afdc9e5b0dde523b348aaab445b1546f79b7a6aadfa547bfa416f62b54f7a476d6d888056b9c05c72e0139f1,473473518f0e843d1fb5105b16fe88edaa418b396cab7cb5532416d171f2e7bc,1\n",
+        "286,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04c48ee90f8fe800664086ed5ba12930cacdfa175a67a2c4398168f626699deb8dd78c35a48042aafbc6c7caf3a68385ddb5d406acee86d96403e75baffece00e3,c2563f49e623b139a83c4cb71cb73deb06458385658bf8796bac0c2ed12c8a67,1\n",
+        "287,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04737d92f5dad51d58261a77e755678ab02b107912041c5d295f5829cbd10cd8c59b55dd084f84937c27565a9075fe108745e17001666743db551436e691ea818d,28c247ae91fdac1b29896415fec60f4252feed9c9ffa0216d31350d708646d89,1\n",
+        "288,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0459efed747303891baab0e1dfdc32d69906e0fc6815b056dae0eda2080957a3ebf205fd299c63e549d24c153935d950141c3dc2699afe8731a46304e203cac15d,76e9e6f1339bff54b82a45980745526ff9249e942b1f836aab719fd959fc8099,1\n",
+        "289,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,048ef9a7997b717fdbc5d2a7f9a67f705e5dee4c82ca383b7ee2d07c24850396d072c98f7dc1658f9dc3c434a9fddc2f986cc0e3e3ef409827537617ee67105f2b,0de82177c80f4e35f3b7a300e89ac288f30e01a8658933c16b8c90605e35d6c7,1\n",
+        "290,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0404569ec9fb3d6bcedc059e7fd04ab7d3f6bac730b1b75a11749e4346458f9296a051c84d558dbd2957c15907477776af660ac01582c001dc1f868ebfc6b3b264,f41de3a77597835fa904d1f05411368e6e878abd0485477d162b2c764ef045ac,1\n",
+        "291,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,044b298a3eba6a09fcd8415976e0faea997fd519ffd3363bd20107752123e101466abb70c013ba2389c371be19dd3296f0600e64f05755e15cf89320ac7ffb25d6,c4dbe3b94e729b1e0ae34ffb0f6b0d95d7e619ab3943aa3836cf1e721a470a9e,1\n",
+        "292,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0418bde07952d7be8914d2b2544c65a3debdddd9e7ce8a9c46a03d124acfb8548b01a4a175a2a81af98e6028770d055e22f1016df15462b65f55a2d4850cc415e5,83b31061f1b70148870a9282a4641cc8428943a3b10e0301955f5960c386fb04,1\n",
+        "293,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04002beb755f694a09f60bce5b34dc347c5c3aa236de9007bcdd0707e9bc8071694f443b0045999f2f5899ca793424a9b423b0ec0a3edcbbf4afb9e66526cf89b2,5f74c066595fe9ea283274964ae83fba1a73ef9d29d24e6604a4aa0881fe390d,1\n",
+        "294,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04957e5bcd11fc450bffcefe636c0b73f10fe8585e04c6c7aa7fa0b603d24162d99e553e940956aa04a237a0c2570a0c7bc3712172b8f78c7b470a042ae31f3223,c672601d951bdb550ea9cc58d2031337db39a3799de21af4e5c23e2fd7f537da,1\n",
+        "295,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04c43b1b4099da70f8d33fbb61b68d9b0e9c7aedd4f4761c6722996666974e298e978c02ea7899cdd46a47405ac0d89fd6a4d66718a4502438ad45463260976841,b2c2c00532d468d25374ae2e6ec9bc52bcb2e8df20ad1a40719b7d91746daeab,1\n",
+        "296,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04deb85f604be1930dbac6629cb96210f6fbc87ce2b260b66cc7d661861806afe1120bbcf8356dcfbf1de4bbb7d2066c3dddfbaf330af754c57859137a9cc4a68e,e5fe7c96371878cf86db4210a487d7d33ac4bcc45b8df2152e82aa7228a991e2,1\n",
+        "297,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0427899fe24811adc869d49ac451cb210631d19aff8971ac7c3dd2fe826262507fd9ddffef4cc9cd81bdd3eab8acdd5c287a8934f82dfc255dded1ac1f1100aa17,1bb9a501ab9d215230cd1072042b3c8271aec3b2c1da10d1a8c810fceaed47a4,1\n",
+        "298,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,044301f54b3592d1ea2a40989c94261d2b1d1fe297ed6ed64125ee241de05d004bc79014f156e9b7bfb36b8ad2d66d55f3a753829a9ddb86055bb9166dd3aff457,fdc15a26abbade3416e1201a6d737128a2f897f0d88108645453a1b3ddd05688,1\n",
+        "299,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0436b0f66bf5f9fd4b2df9cdae2af873a075c55497d7fec4737a7c9643c2c76fe5da9f7287b3cd4e5f05b9a1a4f64e8a8d96c316e452594d02a4592a2107ece90b,e36348e3a464bc518384806c548e156edd994cb6946473c265a24914d5559f1c,1\n",
+        "300,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0482abb58afb62d261878bdee12664df1499b824f1d60fb02811642cb02f4aff5d30719835d96f32dc03c49d815ffa21285733137f507ce316cec65ca562ce2ad0,7d65684bdce4ac95db002fba350dc89d0d0fc9e12260d01868543f2a6c8c5b8d,1\n",
+        "301,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,047de7b7cf5c5ff4240daf31a50ac6cf6b169aad07d2c5936c73b83ee3987e22a1940c1bd78e4be6692585c99dc92b47671e2ccbcf12a9a9854c6607f98213c108,6ec6ba2374ab0a9ae663f3f73671158aaabac3ac689d6c2702ebdf4186597a85,1\n",
+        "302,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0406fa93527294c8533aa401ce4e6c8aeb05a6921bc48798a8e20a0f84a5085af4ec4828f8394d22de43043117b8595fb113245f7285cb35439389e8547a105039,6d6e87787d0a947ecfbf7962142fde8ff9b590e472c0c46bbc5d39020e4f78a7,1\n",
+        "303,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,048a4f625210b448dc846ad2399b31cd1bc3f1788c7bed69cc1cb7aac8ab28d5393007c6f11f3e248de651c6622de308ee5576be84ef1ed8ed91fd244f14fc2053,56ea4382f8e1abfcb211989f500676449abcebfe2cd2204dd8923deb530a6c7b,1\n",
+        "304,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04885e452cbb0e4b2a9768b7596c153198a922dabbb8d0ca1dc3faf4f097f09113be9aaa630918d5056053ecf7388f448b912d9ccfbed80d7ca23c0e7991a34901,2c362c27b3107ea8a042c05cc50c4a8ddaae8cdc33d058492951a03f8d8f8194,1\n",
+        "305,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04e226df1fcf7c137a41c920ff74d6204faa2093eeffc4a9ee0a23fb2e994041c3457107442cc4b3af631c4dfb5f53e2c5608bed04ff6653b771f7cd4670f81034,0188da289ce8974a4f44520960fae8b353750aca789272e9f90d1215bacdd870,1\n",
+        "306,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04f53ead9575eebba3b0eb0d033acb7e99388e8590b4ad2db5ea4f6bd9bde16995b5f3ab15f973ca9e3aa9dfe2914eebbd2e11010b455513907908800396fb9d1a,f78bd7ff899c81b866be17c0a94bec592838d78d1f0c0cf532829b6c464c28ac,1\n",
+        "307,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04882773ec7e10605c8f9e2e3b8700943be26bcc4c9d1fedf2bdcfb36994f23c7f8e5d05b2fdd2954b6188736ebe3f5646602a58d978b716b5304ea56777691db3,99f6151fba28067eac73354920fcc1fa17fea63225a583323cb6c3d4054ecaca,1\n",
+        "308,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04a60b6458256b38d4644451b490bd357feade7bb6b8453c1fc89794d5a45f768d81eee90548a59e5d2cecd72d4b0b5e6574d65a9d837c7c590d1d125ee37c4d51,68ca39de0cec2297529f56876bc3de7be370f300e87c2b09cdbb5120382d6977,1\n",
+        "309,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04000000000000000000000000000000000000000000000000000000000000000266fbe727b2ba09e09f5a98d70a5efce8424c5fa425bbda1c511f860657b8535e,1af254af90c16dbd217f3356f7fef9ad532d4902a6d67218e3188a9e840fc929,1\n",
+        "310,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0452d9a44bf0bc729e5f3ffc8a73a4da332e2962b22013391b60eb66de6e1b83431eb0d9c6e92a424bc24ab23caf99e3cda830263689653626f8be91590fb75cbd,9e232223afd0d57a7b150f65700ac60a78bae2aafc0cf9d1a820452ca1e57a14,1\n",
+        "311,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0478a99dfcb7df4d9277f97b5e24e979f48a8aa8983ef9dd86765dccc33d8ade9f9857dccce2a7ff0ac41b255eb8df45df61b4db58fb5e997614bf0d5ab217dd90,ec2f7542cc1df665764c5b9bff751208d668be9f3d61cd6c33b35ed0f4fe5a17,1\n",
+        "312,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,041162424aa9fa0d42bf60e06a16b7e7ea45ac0e2f07f1e36735bd0d98c70b8850693f2ac128f47f213322c5f8872dde9261affe614e3f364a792d17b0e8421840,b095e9c2f933a00053a95758dc20fe1e72a798462f90fd67fafbbd68d761dd67,1\n",
+        "313,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0430d2d42a85385b64817d0900bc8c984716934529056da032d5fde844915d669b0e5ef40d566f5b23992132c4ae588017ebd160e5dbf4804f936cb0f257a93446,95484c5554b543f8eb2fc218cc46ffe648a3bfac41e6dfafca1ba11f8c53ed6e,1\n",
+        "314,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04a77a259a55ed98d643e1a3e13804c95e543c1557e6141e4ed47dcf13b941a6fa8bfa5f879ab14aeba7b2ac06e5a719c86f4a2ed391160380aa3b6f74141cd354,f22ebb6843281b54b22a9ff1a91485c7db8f95db4bf8a1131f892b3bfce56662,1\n",
+        "315,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04f89454593dba5720164d17bc1ca32f10ddd1a7d37b7bf02e5ec0d59794f4d63d34268de3f6a2c108514a52702f7e67d27829fa0340b3c4710651291483c8b213,99623d9f447b66cb322488ea463b3e40d5620f4df78f89c62fe0ba8b90ff386e,1\n",
+        "316,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04b00befcb868eeb5d558ef2ec2ec679dc082ec15a57c5899311178424674b8f50588742728a6384a180506b8739a79c4ce95e1055c0d0eab2254ca55b18a3e7b2,d611afb4046c9f4b2887b7dd4d45b80e9584eca93f5a855dc30e529eedbf5017,1\n",
+        "317,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04b3e2f9c7f9f068c5da8882fd581e7112e538aa01feb5f017433c00fc8a828fccc56a3f692e3b237b7caf49869009e6743e35ec5aed19d814cfc13869f78eb895,0438085ab0104fb47c696be5c08f95e319ed5507ab781fe1cdccd6ddb34bda67,1\n",
+        "318,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04e5dae9779e0c168e60b842508e253d2ac80e7e504daed9fac077b9b449c368b57bd8661bbbccef478f050f4ffec8aa47ed7f98e89514d9083facf0a7f2f7b70f,326dbabffe17c6efc710bdb8d04d16c8624c083d48bfa6e4411d221264d8277f,1\n",
+        "319,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04420e10bb81b379d728879fe600e6f1bf2b85d8023848a040c7654a9734da1ac4cbee561571a616b094a38436e02c6d7b54b4279a234193a828e86e21e6b71d16,f47a91e29ebe69baab6b340bb64a6dc34fca7546fd6eba53f5bbe41f6178c7c6,1\n",
+        "320,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,048dbf1ba87597004af552317225916abf3d71dff90fe9e61f9d2863a6de218d4a0897e334000139b0849d772757b150e5d86b55d7a00a744bccbb7cb8d1a6b07b,cf83319c735348dd13c44b055f67a292f7afc5d9d2bd0706c966ad765368d422,1\n",
+        "321,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,041accb85b612d32d58459caec0bb6768f05ce8094e3862422a7c12340dd31bd7397e0377d33ccdce8bd872f898be6cbcf7274b3beefb5dd7cadddf027d0c02c2e,41ee7801ccb702f2f633d1d0ec20d7c427936886df89ad33d19dbb56f66a2656,1\n",
+        "322,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0414eef41b67c17b1d4a040554287cd6a9e6b3080335ea4e16821dbd643ec67dba6d67cadcbd1a3f0227b7caf2c0604d2b3507aeb96ed98c32e2350fe295ed8998,af828d6bc21ad6b4570b5f68a6208d3a2f46edca69b1980fe5046792c68cab80,1\n",
+        "323,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04a99af5dec3c995080ddcc15d7994daff266aa53f181fba4bcdd504d206bfca2f3739588f071e4192b615361ec81735fe2ef2923c4056c432f4c2782e5d722215,4ebf7f174cdb3fda94f99698317ffef5f4d4fb933f3292f1aaa782c354ba03e7,1\n",
+        "324,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04a355d8d17d50f6428e0af345921662587e2b6249eed1e326abb7c8605036b1db1fd72efaca9082bb6fab44359fa7f6ef8a45d036852832e2ade9d41f28219144,1649f83c47a6640a94b773ab4309bd6964109433e3f3ee5b024d1915ef5139de,1\n",
+        "325,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04fa53e5b58d55ebf517d8db07b021d80918d1f260f9e0b3d00bd47b24a91ae6ab85ab2adcc31b98caaec2681a841d50bc0eda875561fab70c979463ffb6a1d74c,f434c3ffe109528456c23d6cfe51ec0b10be606d7a26775fe2ff0a9b18f92f39,1\n",
+        "326,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0433fe37949375debd9734f54b7036b7a978bc8fc4ae3fe927a521f940d9e35dd38f81a9160c05df04e34290db40c3e045b832373941ca85b433854e43caed323d,bd02b9dfc8ef760708950bd972f2dc244893b61b6b46c3b19be1b2da7b034ac5,1\n",
+        "327,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04b9ba8445067d0e81bd32dd99e6b4ea3d442d063a8eb9873518ee3bb18c053706099964b6889105784d9d6d9d9aa79c76b6a3d3376315953afdcb5a7439e7c706,412269bfc15d8b1fd7f25de33b1515ea67f2194e73ba06c85ef99bb42722f95d,1\n",
+        "328,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0478b248634270a7a6640bd0c64595dc4e98adfe6bdb8112593a4173e36d4a9b4969a1f3d19b325898e36459c41eba1de99229b0ba2cf1337461c84391d9aea1fc,0ad7556a621074a771bf129163d09a2e9d2e174f2b8a4b6973e89ea138c9a603,1\n",
+        "329,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04c788884ac868593db241f5b3ea7013810d3ce28a02680a96ff357b261fad611bef353b0e82c1c68c471ff1ed5c4749e168e7af8591a5e6dab599b96620de0ede,76acc74dd60872ab29e1bcb99dd46365c7c7f792619c901c7ba5c68378b233f5,1\n",
+        "330,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,041864e373ac60c23543fea9e1f237950a8169e07c817db69d500e5592d1df9d5a10da4651efccc46d37e7eae16c36ac86a9a86b88ad08751a8dcd15060019704b,50e23b94fcc33d93dbcd71e955e195fe0bf6ac9b04b15f001e53b5dc7bad158e,1\n",
+        "331,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04dd7308d2a6757f924dc979066e75ee6fa52b03393d2892f59788effa553b690d1fef00c1c22ba80b95d529782dbef55a63046179fb4ef00fdccf5b62ce55c136,3324bf2fb3486b1104fffa35ef38975faffba1ebe42c54399206face505448c7,1\n",
+        "332,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04575d51be2bddf5bf1ab42431ba7e3b5f2947bc574df9f60a448b8db5ca28c92cd836f55c556440a7df125de6599b21ae68f15d5b9f422d6eec88ab2f65406bf9,235fd3e7216c92f73860f0ac0121b4264ff89d80bc75d59dd455298597c5f2ec,1\n",
+        "333,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04c7422a80aebdb518bd2daba691d39a25ea2fe49a35cdfb2a0f94bdfbadc6629ae55ac7c400afd2976b7c3b24f7126807a5a0afb931cfa5c6ada1f4ff984ea5a7,0bf758de27052319dac39b324a6ea55e928603a3ef9049ad147f8ca35f55b656,1\n",
+        "334,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04ad4062216f84ffd66e326497bcbcab982283493392ec0f739cef8cd7eaf3453414c5a289a846e28bf2042ea5dc7b15e252f48d3cf980e7c4751cc35493a1c328,312eeaaee397224fc17dec7191ce69cef40e8fb373516c2b1edada0336c99c13,1\n",
+        "335,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04ccacd1bf7c7f4ea9c7e59bd802804a9d335262716ac288c6eefd7a7135349a4f7b8612e2bdbd43b3fc4fa6941ac15a8f37e34fe7810a0c0d43c05afafb6480ef,aa1691858e6a1a06c6df57ce10a4c730974c06d1e0106d1a31b51b915cd6b60e,1\n",
+        "336,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,049aa7f1f93474fa370c4df3805bd2839328895880dce197a06cf9052e6ab7a6938c9a208b335bfda6b01321f029a0d83c8bb96561208481f7af6c6cf1d2657843,5786d6ea09ffb21a63adb020b117096484ef995e8c4a72afa479cba95c959920,1\n",
+        "337,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0483b7affcec11685d15614e2d53c1e73504e3d98344bbd5fc0ad86dc4c36704323a7f73a09533d1a1076aa9c4af22a6bab92f3f0d766798db7aa4183c037f86e6,ae0c7845b82995263c51e13e297412d17b650aa83dce4f55a069dbee671c16b8,1\n",
+        "338,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04aeebdd971cf4e988fabd8070c75c64aab90a83a36735fecfb385605979c008ae8c39888f0daf74f98cebbb08f6b91a5193f684a56761b9f2b63d87d3f60491ed,e5e764a38b73816f6e11e7cf298b2be54d11249c615f0a71498a0a821b5736bb,1\n",
+        "339,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0400000000000000000000000000000000000000000000000000000000000000032f233395c8b07a3834a0e59bda43944b5df378852e560ebc0f22877e9f49bb4b,6aa7e74a7a838efa9607f3587d4117f1914c57fa924b441c27fb7a7c31fbaac4,1\n",
+        "340,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04e38eec4c1a4d81a5d994b0d7780305af651892cbf07f3a07628f4e2473a8ab754bda96622462880d3536d390132a85db6147f814c62efb580a6f529598b0dd1e,391b624f28eb398156666dbec1d635f8ff1753eb86297973a1c2831b1091e2df,1\n",
+        "341,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0410f02d496e9a8758c831389892a45ed009282ea1eb201ab8caf09e6f2de9fa4acc126becc204c41a94aff4f2ead7552ec23fc68f0005147625a95622b521090d,e3f39d071b743c8041454d1dacba93dc9f3f12a5ae1bfbebaa59fc4cefee6b82,1\n",
+        "342,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,044c859aff342c56a9508b859ab1509edcabf66e044e2026cc293474389b3d58c16bc06cf99dd6d8249c5d24386a55a97214ea0cda270ad9470986c3a3d023cb07,09d0d1b92af1e7111d223bf5efcdfa6df2622ab3cf161af0ebbf06ad00c09b6e,1\n",
+        "343,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04d1eb4f7c680a247a14f3d67f85cdb1c4c6f13d44821fc456c9247a606622afec4952cf05ff06f06d7030e88904737096cbf8dd90e478a3b5dfed2ee487a0835c,a738727e0f5b20448009d1029ca727f2380d2c6e152a6e2da8ea50531ce39499,1\n",
+        "344,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,043bf79d6c1a20d85115e973079707f5131eed2f83be5683c34d0fb3eee1ae40dfd2ea4f1b735cf62341835a5721c25daa0dc1a3886ab75ef653f472d8f3aa1e97,a314e4ee9e303d970b4e9f0cdc262657d5c5192f055466b9d09d9c888d6b7256,1\n",
+        "345,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04b1f808286a42eeee85d585e54dc28aba2aebfb956805f5c01127bcdf435154cb5b178fda5896d9e7508661fee7ee55fa9623610b3d9f4a59156b76d8877b4ef1,8f4e1bf8e5182a1fbcdeac924df1ba2f937162d48a206783c48132cb582c07db,1\n",
+        "346,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04a21e80d09e11acbccbc909de6c9f1159addbb5dd477211b90a370f8c7548e60d1d7aacb6e455bcdc230331d79ad9464a77b702c858400900cb4488cb6c28bd61,16682c862cf53755b3c28adf7de052d5cf0e81e5d8acb346702a392bc6b2b1d5,1\n",
+        "347,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,045055a8c45e81385f4144c7b6fb32119395a94dbd07665ed7bc1cce1e62dc47c8b6d50a39a55d3b8e996624fb6ec2f2960c7c2bc0bc94b2a63d65096fd99ca41a,cc33e840b5354bf6e088047e76dc168f15c0c1aa946731600f52d6f1c9299b27,1\n",
+        "348,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04af522fc0a61440173945b914d6404b1940b547a6f768550280fe28bd331c9c661d282429f2911298f9c5b82f87c7f5044706748c19035689b216d64474bf5ad0,d7b4f5e2dd1ebecfc92318d92085271be482fe65a03e83b2e358a397a597449b,1\n",
+        "349,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04ec1a88652de714d21fddb54db4a3423521aead5828b843bdde9a42cf4a8bf124a69568c664e2d9317ac732c98c435548dcec0eeb8ab31027ee5f1693ccd97c68,1d88ed8e4579a4c62fed95eafe1528f8d5056041fc41f3ef063605ddc980ee09,1\n",
+        "350,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0448e4c9cc88a601eb639f81ffa679540bf1d7bcbe876a955e73bfade055384160bae130243ef5fd328f65278e00cad6001327ab42fdf3b9654aad6f260542b02b,a6698578650dadb452d677c3983e6b809152d7d2d8fba349cec686e72e6f8693,1\n",
+        "351,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04e861379f1c1b07540155bbfef4a69a84be81b1441d43e7850c7ac1005a804238bb33c7981d383a06d1b795552a7b31f49145fba937876fdc9f0d138aa5b3f322,8e42c38902b25fa138b95f4f280b684c09e1212c4f06a2bc2c2b2b8790112034,1\n",
+        "352,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04b4489cd499168bd48cd2c31167edad246c63859bf8b48398617a7a0556341e3c0b0f66f665038baa29db8c296d4f2ae07b9ab9193bfc00981d7df0599ce0648d,a19f1165d31c1695359929deebcd24846ccb9a3c2a38c10bdc7c855bf8a32df7,1\n",
+        "353,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04884e74885ea450f5aab0cc8f06c006630e8b183a06bda509322fcd97ba5d2d2b00e1373d533bd5920427d106b7f33eeb53d21b5cf46ca0151e91859e811a39cb,0a87935c036de666b7619f14ec58f9f78698cc23a667616f84c177f34661ebe2,1\n",
+        "354,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04c50224a8cf3a19da6133e746d00217285df85589ed334b54d95b005b8f033f7df7bc6a5ddc59d033f0c66bed57149a160f25723117a2fcd413aff8c9ada43bf9,90accd1c3af1cd782ea1e864a307aaef6a01fd3a6305a0adae37e76844b9ce10,1\n",
+        "355,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04c66a917fc435c9f41c47428e718a3017b3c5c992b4d94a663602f73fd825d043a03cb9f58174cffb359e2f541cfb5e551c50fd811b82362eadb216a4cfd0f8c3,e164d88fcbd1ccdd654de0415bfbd2a171590713015f4a1755504e62f7a03870,1\n",
+        "356,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04f55a451e6e0d8f7600c7bf7c05741c5e5985b8ffe4eab62d4ffc04aedb725a66e942cf486efcf3b85488cf3cd4fb0248b820703b938ce77a074a2f9286af03bf,d4b6d4251ac1f8a0b0c75bbac3517a13ea0c857b5499b03d153be663a8715480,1\n",
+        "357,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04a96aa25bee8c8cb6f6aaf40be98ca047da245e8d4cae28fc892a0369b299cba3ef5f54bb59f4ad58a84332a00d89a1cf3d56c4e6ec9c3a467a4a2a04ad3bce97,f5270d0c4119f1ef467a37501688b4bf4a516e5f58a0f5a40f23ae70ee813c26,1\n",
+        "358,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0449f572fa8cdf6750adabc3ab0e46bf23dd7ad7114bf319f35afa6a2ccbe2e7d343c44f018f8efc9f52691b274a8c89283d13ce93d1b58aea11d62c88599c309c,1763eb5bd3677de88e98aad84038838422d6e55605a14761788cf305672eb670,1\n",
+        "359,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04b36f6b02bf1d213d9abcfc98c25fc81ab3b98cb13d678da871310aa093ab7b58a50b134818321a48ffc1ef9f8624e371ddf078d8983fda6c4eb27dfb255174e9,98c95ef9fded241da09392573144daf44b0332518f458167e09d672011ea4618,1\n",
+        "360,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0474f57ff3da8d60ec0b382e6866be4502f695688384b405e2179aab61066196d7d24064185d68de95bd72b219c0c0a93879324f299fb19214b33a3ed2f1bf4823,b0fe8fc6abe853b1ea6aa4f4b4490bcd94ffd3532c1d7cce36d059ac8f29cd67,1\n",
+        "361,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04924fe3439d35427e2ad9b1f6e67877ed3441d74bdd0eb9f82ae360434bc20624537e3400007cd2d140f2caa0f7b61c7118abb9ac5c766ecab3f8f72ea5d96cdf,4fdc7cc04b2459f73c856023e892699b9018baca1b8e3b040ec74324607c97c5,1\n",
+        "362,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04fb0e48a3815a2b80e9e725036a239757e9c5987850a941c5f5d2b89b776aac683adb5481fcd85f0013feb20505ebbaff27edf8474a7cf4d985ec567365ecbc1d,561138979956fe6a1aeae06277456c7e5e7e7d643910e247fb248dd7435691a0,1\n",
+        "363,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04e669915ee160694e8559d7965f7cff945c1cb076f194ec9894b1a38b10726fb0389675e3155b069b3862da3d1112179a04accbe7dbb70b3cc48bedb7591d2eac,06c06abb603bff68f356ce496c17fcd0662fa040eb0cd45a98112e6c1eea11db,1\n",
+        "364,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04b760dfff4c5c200aec18b930b18df34297bb421b96017ef902139fe6b12349f6ccb8cf83d2837c7520300f197b491c0368470ee86f74ca0381682bb6ad80344f,319297f77a75381b65d73107e826eea0e69d85985db4568fea21d12dda696921,1\n",
+        "365,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04b0e4de914fe71b61636e61391528efac8b6c11edcd41c9766af8693dbb6e41f2517293725552f22dd6e1db7c2c243f80c10713f6aa48fc5e395bd9ec51f1e9c5,c3ef6eb91aad69456ede5174b0e5e3d6863c024aef3185d2258946362903e576,1\n",
+        "366,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,048eb6ee2ee9e061acb9312ad015b1954ea47ca304a2cebb77f3bf6c78678c1149d93fc6e80561f3110fd0e95fdc0ce8da2c3f32f7f581f9b666d74900b3760b9f,a18b1fba4d496419ede70cc23ceed674526f34299e5b09c0ee2dd1669693fef9,1\n",
+        "367,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0490121021546a96e7879d53e7b85c21f4047df49b9ad85020104f216d010f520d1bba6e765742395b4c894fd0eaaf87275d1c77494c01cce882de2805d1922c0b,9fc7fff3bb4d6e5a4d52eb0e3c513a3c9fa56014c030449546cda744aa126f6e,1\n",
+        "368,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0464c1712e3e9ef440c7ea8faf0540d2e6a05adccbd53a7fb24ff16a9502a818f747cfafd2209430eb7794f5da91d6c5e2db505ba287bc6ef397bf7f30c747536a,61cb2fb99b695ecbaf91a95e6e0c7e24633bc7613ebf518c6f1c8161dc75ea5f,1\n",
+        "369,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04cbb0deab125754f1fdb2038b0434ed9cb3fb53ab735391129994a535d925f6730000000000000000000000000000000000000000000000000000000000000001,af306c993dee0dcfc441ebe53360b569e21f186052db8197f4a124fa77b98148,1\n",
+        "370,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0424800deac3fe4c765b6dec80ea299d771ada4f30e4e156b3acb720dba37394715fe4c64bb0648e26d05cb9cc98ac86d4e97b8bf12f92b9b2fdc3aecd8ea6648b,aa7fc9fe60445eac2451ec24c1a44909842fa14025f2a1d3dd7f31019f962be5,1\n",
+        "371,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,048f33652f5bda2c32953ebf2d2eca95e05b17c8ab7d99601bee445df844d46a369cf5ac007711bdbe5c0333dc0c0636a64823ee48019464940d1f27e05c4208de,082a43a8417782a795c8d4c70f43edcabbc245a8820ac01be90c1acf0343ba91,1\n",
+        "372,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04146d3b65add9f54ccca28533c88e2cbc63f7443e1658783ab41f8ef97c2a10b50000000000000000000000000000000000000000000000000000000000000001,70810b4780a63c860427d3a0269f6c9d3c2ea33494c50e58a20b9480034bc7a0,1\n",
+        "373,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04b0344418a4504c07e7921ed9f00714b5d390e5cb5e793bb1465f73174f6c26fe5fe4c64bb0648e26d05cb9cc98ac86d4e97b8bf12f92b9b2fdc3aecd8ea6648b,a7d34ee25fbb354f8638d31850dab41e4b086886f7ed3f2d6e035bceb8cab8a0,1\n",
+        "374,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,048a98c1bc6be75c5796be4b29dd885c3485e75e37b4ccac9b37251e67175ff0d69cf5ac007711bdbe5c0333dc0c0636a64823ee48019464940d1f27e05c4208de,3f09cbc12ed1701f59dd5aa83daef5e6676adf7fd235c53f69aeb5d5b67799e0,1\n",
+        "375,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,041fe1e5ef3fceb5c135ab7741333ce5a6e80d68167653f6b2b24bcbcfaaaff5070000000000000000000000000000000000000000000000000000000000000001,e04e881f416bb5aa3796407aa5ffddf8e1b2446b185f700f6953468384faaf76,1\n",
+        "376,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,042b4badfc97b16781bcfff4a525cf4dd31194cb03bca56d9b0ce96c0c0d2040c05fe4c64bb0648e26d05cb9cc98ac86d4e97b8bf12f92b9b2fdc3aecd8ea6648b,adace71f40006c04557540c2ed8102d830c7f638e2201efeb47d732da79f13d9,1\n",
+        "377,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04e633d914383e7775d402f5a8f3ad0deb1f00d91ccd99f348da96839ea3cb9d529cf5ac007711bdbe5c0333dc0c0636a64823ee48019464940d1f27e05c4208de,b8cbf0968fb70d391059d090b30d1c4edcd2dad7abbf7aa4ad452f5a4644a7be,1\n",
+        "378,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04d1c1b509c9ddb76221a066a22a3c333fee5e1d2d1a4babde4a1d33ec247a7ea30162f954534eadb1b4ea95c57d40a10214e5b746ee6aa4194ed2b2012b72f97d,07257245da4bc26696e245531c7a97c2b529f1ca2d8c051626520e6b83d7faf2,1\n",
+        "379,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04755d8845e7b4fd270353f6999e97242224015527bf3f94cc2c693d1b6ba12298604f8174e3605b8f18bed3742b6871a8cffce006db31b8d7d836f50cfcda7d16,d6aa401b9ce17ecf7dd7b0861dfeb36bb1749d12533991e66c0d942281ae13ab,1\n",
+        "380,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04c6f9fc8644ba5c9ea9beb12ce2cb911c5487e8b1be91d5a168318f4ae44d66807bc337a1c82e3c5f7a2927987b8fae13627237d220fafb4013123bfbd95f0ba5,f43bfe4eccc24ebf6e36c5bcaca47b770c17bcb59ea788b15c74ae6c9dd055a1,1\n",
+        "381,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04d3179fce5781d0c49ce8480a811f6f08e3f123d9f6010fbf619b5d868a8ea833ddf9a666bf0015b20e4912f70f655ef21b82087596aa1e2f1e2865350d159185,009bc3abb3cf0aca214f0e8db5088d520b3d4aadb1d44c4a2be7f031461c9420,1\n",
+        "382,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,049e098095463c91ac7107a920ccb276d45e1f7240ef2b93b957ee09393d32e001503af4a2e3b26279564fed8e772a043e75630e4e3859976ede88ffcf16f5ca71,8bcb07a3d0fa82af60c88a8d67810ebca0ea27548384e96d3483310212219312,1\n",
+        "383,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04bf3034a9935182da362570315011544ac2ce8a9c22777c2fc767ac9c5c0daeebcf333562f3e018892374353674de8490fc9d30426598eb600779154baf2aec17,a09ddc7cfe023acd9571ef0754010289c804678c043f900f2691dd801b942ed4,1\n",
+        "384,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04709c7179c2bb27ce3985ba42feb870f069dacead9294c80557be882fb57790481e6fe2c1a715163efaf86ea8b1e55ea5742d6b042e6cbf8acc69c99f8271a902,da98054d51ac9615e9d4f5ceda1f1bad40302ac11603431efec13ab50e32fcf2,1\n",
+        "385,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04264c00a2d92514a6dbe655de3c71a5740cec4fcb251aa48ca6745dbea6f5f7cfc1d5ee9fc3ce49fd4509d33c4dcfcc1a20a660529fa9ebd6e6afc3d5c84c72bb,d60795d8f310b155726534b8be3d0b8a7bc2ced468c6e64c8b9ae087b33ee00b,1\n",
+        "386,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04a12124606bcbbb33cecec7fc8d78b3897192ca851560c539e47dd276c63bd3c2f20a0ca618ba0131a2e373f31f73b3f55e9188d46fddbc6387e32aefb9f3ba12,675fef8f5680bf76220e91362613944099046b0ba07e5824e93f3e3cc2cc2758,1\n",
+        "387,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04244b7afe7f31289f9d6aaeb7f70d29a7b49a228c7bb202764aba94daaaa3332270c60975748f0c749a8b0f8fc1e222ddcbd3384f6d68f0b6b6ff679b435cdcb1,76b439f8ea7b42f11cd59e6d91b2d2a72577c185386b6af6639be8e3864a7f27,1\n",
+        "388,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,042ac29db2ebc4fa9473b42bd335a60226579cc186b2c676a3b01bc60e589616165aa9c0d1b240e6dd4211e3235425634b278ad88fede0337d5acf3136587d8413,56e63fa788121d5efa0ce3caf4605af18d48c631496cdfa862c43ecf5e5fc127,1\n",
+        "389,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04e62aee5205a8063e3ae401d53e9343001e55eb5f4e4d6b70e2b84159cf3157e64ba2e420cabc43b6e8e86590fc2383d17827dd99a60c211f190a74269100c141,cff3b5e19ed67e5111dd76e310a1f11d7f99a93fbe9cc5c6f3384086cacd1142,1\n",
+        "390,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0431dce6de741f10267f2e8f3d572a4f49be5fe52ff7bff3c3b4646f38076c06752702a515a9a50db1d86fd42aea0834daeb62be03d0cd9033f84b9c4b56a19f12,e29483884a74fb84f4601654885a0f574691394f064ea6937a846175ef081fc5,1\n",
+        "391,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,046518cd66b1d841e689d5dc6674c7cc7d964574d1490fff7906bd373494791599104277170692fa6bf2270580d56d1bc81b54f477d8ab6c3f5842650ac7176d71,9c6a4bcb2fc086aca8726d850fa79920214af4c151acea0fcf12a769ad1f3574,1\n",
+        "392,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04952a88ce31ad4cb086978e6c5621c3d8023b2c11418d6fd0dcef8de72123efc15d367688fde5e082f097855a0c0adc305dd6cf46f50ca75859bb243b70249605,34b7abc3f3e36e37e2d5728a870a293a16403146ca67ff91cbabeee2bb2e038b,1\n",
+        "393,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,042a43f33573b619719099cf54f6cccb28d16df3992239fadf79c7acb9c64f7af0f4d1d22af7187c8de1b992a4046c419b801cde57d638d30f2e1ac49353117a20,9bd1284f1bcb1934d483834cae41a77db28cd9553869384755b6983f4f3848a0,1\n",
+        "394,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,041b1b0c75408785e84727b0e55e4ba20d0f2599c4ed08482dc1f3b5df545691380162f954534eadb1b4ea95c57d40a10214e5b746ee6aa4194ed2b2012b72f97d,167e3db6a912ac6117644525911fc8872ed33b8e0bbd50073dd3c17a744e61e0,1\n",
+        "395,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,044dd1283bccd36cc3402f3a81e2e9b0d6a2b2b1debbbd44ffc1f179bd49cf0a7e604f8174e3605b8f18bed3742b6871a8cffce006db31b8d7d836f50cfcda7d16,7c3020e279cb5af14184b4653cc87c1ddd7f49cd31cd371ae813681dd6617d0e,1\n",
+        "396,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04a499dbf732e438be0eb084b9e6ad879dd7a2904bbb004b40027969a171f2d4267bc337a1c82e3c5f7a2927987b8fae13627237d220fafb4013123bfbd95f0ba5,acfdff566b8b55318869fa646f789f8036d40b90f0fc520ae2a5a27544f962c0,1\n",
+        "397,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04adcf0ffba9cb6ef0c8031c4291a434b18d78f42e45e62ba01fbe91f9273f0ad1ddf9a666bf0015b20e4912f70f655ef21b82087596aa1e2f1e2865350d159185,5c6b01cff4e6ce81a630238b5db3662e77fb88bffdde61443a7d8554ba001ef2,1\n",
+        "398,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0421712725d9806acf54d3a6c82bf93c0fe249268ca9f42eceac19e93a5eab8056503af4a2e3b26279564fed8e772a043e75630e4e3859976ede88ffcf16f5ca71,e7281d12b74b06eecb273ec3e0d8fe663e9ec1d5a50c2b6c68ec8b3693f23c4c,1\n",
+        "399,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,041e02176824bd31eabdce03a9403c7d3c2ac631f9b0e88d9a924701c1b2f29b85cf333562f3e018892374353674de8490fc9d30426598eb600779154baf2aec17,80643ed8b9052a2e746a26d9178fe2ccff35edbb81f60cd78004fb8d5f143aae,1\n",
+        "400,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0463e7a1af36d6b540a49276aac3fec9cb45ed6bab167c06b0419a77b91399f6181e6fe2c1a715163efaf86ea8b1e55ea5742d6b042e6cbf8acc69c99f8271a902,75873ac544ad69d3ddc5c9cffe384d275e9da2949d6982da4b990f8bf2b76474,1\n",
+        "401,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,041e265ab5b7f7199470e532653d2a7b9a8b728970b838137c9692ed0692897b2ac1d5ee9fc3ce49fd4509d33c4dcfcc1a20a660529fa9ebd6e6afc3d5c84c72bb,355c9faca29cf7cc968853ee29ffe62d1127fcc1dc57e9ddaf0e0f447146064e,1\n",
+        "402,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0454d2a4394c109fcbd3cb9886fec3add51ba4d2e44e1d5676e4b98f0c13655fc5f20a0ca618ba0131a2e373f31f73b3f55e9188d46fddbc6387e32aefb9f3ba12,fc175a5ef18595b69e45be2cda8ae00d9c8bdbefbcf7f692f91cefdc560e4722,1\n",
+        "403,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0493f1459207fb09c6f0a88c398ac80d1052a4cd33e7eef5687da99ab97c6024b770c60975748f0c749a8b0f8fc1e222ddcbd3384f6d68f0b6b6ff679b435cdcb1,46559146a93aae904dbcaaaa07e6cd1bb450f1b37c83929a994b45792333d5f6,1\n",
+        "404,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,041fa049a1892b679857c6dff08af19db70cbc99b6f2d7bc51a341fe79d1647f4a5aa9c0d1b240e6dd4211e3235425634b278ad88fede0337d5acf3136587d8413,c64b07119054a37961c0a177158256081b38b0087b307e0cad7e30d790ceb0ce,1\n",
+        "405,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0484e0b192d60abf531e828e887d366d869e1033a16e9c7f1167458c8134c10fba4ba2e420cabc43b6e8e86590fc2383d17827dd99a60c211f190a74269100c141,bea8cfc0bee8571ccf0c525654ef26d1fc782bb22deccf67ea4ea0803dc15daf,1\n",
+        "406,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,042f9707c67118724111efbbbbf06b623ab2ffd9259ddc354fcaaf81ba01f6fa7b2702a515a9a50db1d86fd42aea0834daeb62be03d0cd9033f84b9c4b56a19f12,60451da4adfe5bb393109069efdc84415ec8a2c429955cbf22a4340f8fc48936,1\n",
+        "407,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04ac1fbbe42293a9f9ae104ee2da0b0a9b3464d5d8b1e854df19d3c4456af8f9a6104277170692fa6bf2270580d56d1bc81b54f477d8ab6c3f5842650ac7176d71,d68e746f3d43feac5fd4898de943dc38205af7e2631ed732079bbfc8ab52511c,1\n",
+        "408,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04bae10cf93ff7b72d6ed98519602e9f03aa40303fa0674fb3ddee7d2db1c92bb25d367688fde5e082f097855a0c0adc305dd6cf46f50ca75859bb243b70249605,28daeaadc609386d770dff4c7120b2a87cab3e21fdb8a6e4dc1240a51d12e55c,1\n",
+        "409,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04edb4288cf5567673d50a1cd9e6bea45317823f30383f60d9bc3b9ee42ac29871f4d1d22af7187c8de1b992a4046c419b801cde57d638d30f2e1ac49353117a20,bb4110b734c8ef8a08bb6011acb35cbda9ae8e2ef6c4d0862576a68792667bb9,1\n",
+        "410,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0413233e80f59ac2b59737e87877782ab3027c490df8ac0bf3f3ef1633872eec540162f954534eadb1b4ea95c57d40a10214e5b746ee6aa4194ed2b2012b72f97d,e25c50037ca1913851b9758752659fb61c02d2a7c6b6aae29bda301907d99f5d,1\n",
+        "411,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,043cd14f7e4b779615bc7ccee47e7f2b07394bf8f98503263411a549264a8fcf19604f8174e3605b8f18bed3742b6871a8cffce006db31b8d7d836f50cfcda7d16,ad259f01e953263f40a39b14a538d076710c19207af936feabdf03bda7f067a5,1\n",
+        "412,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04946c278288616aa34790ca193686e745d3d58702866ddf1e95550711a9bfbdb87bc337a1c82e3c5f7a2927987b8fae13627237d220fafb4013123bfbd95f0ba5,5ec6025ac7b25c0f095f3fdee3e2e508bd1437b9705c2543c0e5af1c1d363ffd,1\n",
+        "413,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,047f195035feb2c04a9b149bb2ed3c5c458e95e7f7c418c4a07ea6107e4e32455addf9a666bf0015b20e4912f70f655ef21b82087596aa1e2f1e2865350d159185,a2f93a84574a26b43880cde6ed440c7f7cc72c92504d5271999a8a78ffe3491d,1\n",
+        "414,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0440855844e04303843a24b01707544d1bbf97673266e03d77fbf80d8b64219bd8503af4a2e3b26279564fed8e772a043e75630e4e3859976ede88ffcf16f5ca71,8d0cdb4977ba7661d41036aeb7a5f2dd207716d5d76eeb26629043c559ec2900,1\n",
+        "415,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0422cdb3ee47f14b3b0c0c8c256fb22e79126b436a2c9ff635a65151a0f0ffb1bfcf333562f3e018892374353674de8490fc9d30426598eb600779154baf2aec17,defde4aa48f89b03f623ea1f946f1aa938c5aab879ca6319596926f085578edc,1\n",
+        "416,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,042b7becd7066e22f121e7cf123d48c5445037c5a756ef314a66a7001636ee75cf1e6fe2c1a715163efaf86ea8b1e55ea5742d6b042e6cbf8acc69c99f8271a902,afe0bfed69a600163865406127a8972b613232aa4c933a06b5a5b5bcff1596f8,1\n",
+        "417,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04bb8da4a76ee3d1c4b33477bc8663def167a126c422ad47f6c2f8b539c6808936c1d5ee9fc3ce49fd4509d33c4dcfcc1a20a660529fa9ebd6e6afc3d5c84c72bb,f49bca7a6a5256ddf712775917c30e4873153469bae12fd5c5571031db7b1205,1\n",
+        "418,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,040a0c37664823a5005d659f7c73c39ea172c862969c81e44f36c89e7c265ec8a8f20a0ca618ba0131a2e373f31f73b3f55e9188d46fddbc6387e32aefb9f3ba12,9c88b611b7f9aad33fabb09cff618bb1ca6fb904a289b1481da3d1e4e72589e4,1\n",
+        "419,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0447c33f6f78d3cd9971ecc50e7e2ac947f8c1103f9c5f0821379bd06ad8fca45670c60975748f0c749a8b0f8fc1e222ddcbd3384f6d68f0b6b6ff679b435cdcb1,42f634c06c4a0e7e956db6e86666603d26374cc74b11026f0318d1a25681a712,1\n",
+        "420,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04b59d18ab8b0f9dd33484f43c3f6860229ba6a4c25a61cd0aaca23b76d60566cf5aa9c0d1b240e6dd4211e3235425634b278ad88fede0337d5acf3136587d8413,e2ceb946e7993f27a4327abdf61d4f06577e89c63b62a24aefbd905710d18669,1\n",
+        "421,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0494f4601b244d3a6ea6996fa244364f794399e0ff4316157db6023222fc0d90be4ba2e420cabc43b6e8e86590fc2383d17827dd99a60c211f190a74269100c141,71637a5da2412a921f1636c69a6ee81083ee2b0e13766ad122791ef6f771896d,1\n",
+        "422,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,049e8c115b1ac87d986ee1b506b86a4e7b8ea041aa6a63d6ec80ec0f0cf69cfb3f2702a515a9a50db1d86fd42aea0834daeb62be03d0cd9033f84b9c4b56a19f12,bd265ed3078ca8c7788f594187c96c675aa623ecd01bfcad62d76a7881334f63,1\n",
+        "423,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04eec776b52b94141fc819d4b6b12d28e73555b5560507aba7df6f0484008de91f104277170692fa6bf2270580d56d1bc81b54f477d8ab6c3f5842650ac7176d71,8d073fc592fb7aa6f7b908ed07148aa7be5a135c4b343ebe295198cba78e71ce,1\n",
+        "424,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04aff46a388e5afc220a8eec7a49af9d245384a3af1e0b407b4521f4e92d12dceb5d367688fde5e082f097855a0c0adc305dd6cf46f50ca75859bb243b70249605,a26d698e4613595aa61c8e2907d5241d6d14909737df59895841d07727bf1348,1\n",
+        "425,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04e807e43d96f3701a9a5c13d122749084170fcd36a586a446c9fcb4600eede4fdf4d1d22af7187c8de1b992a4046c419b801cde57d638d30f2e1ac49353117a20,a8edc6f9af6bf74122c11ca1a50afbc4a3c4987bd0d1f73284d2c1371e613405,1\n",
+        "426,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04798868a56916d341e7d6f96359ae3658836e221459f4f7b7b63694de18a5e9247713fdb03a8de8c6d29ca38a9fbaa82e5e02bead2f9eec69b6444b7adb05333b,17963de078996eb8503c7cc3e1a2d5147d7f0bfb251a020b4392033063587c8d,1\n",
+        "427,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04ff419909d8a8ce0a9416051f4e256208c1dc035581a53312d566137e22104e9877421ab01e00e83841b946dae5bb5a23973daa98fe1a8172883abcbedced7021,062799a19545d31b3ed72253bcde59762aa6104a88ac5e2fb68926b0f7146698,1\n",
+        "428,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,048b48119d7089d3b95cd2eaf8c85584fa8f5e56c4c4ccee7037d74cdbf88e571714c1aac5f0bf1b48a4abcf1d9291b9a8776a004380546a5a1c1f294690f61969,9f42dd8fce13f8103b3b2bc15e61242e6820fe1325a20ef460fe64d9eb12b231,1\n",
+        "429,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04e2888119379b5b2151bd788505def1d6bd786329431caf39705d9cbf96a42ea43bb7328839d2aecac64b1cdb182f08adccaac327ed008987a10edc9732413ced,d1b204e52d1fac6d504132c76ca233c87e377dcc79c893c970ddbb9f87b27fa0,1\n",
+        "430,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,046dcc3971bd20913d59a91f20d912f56d07e7f014206bef4a653ddfe5d12842c39b51b17b76ea6cc137eebd93c811e636d8ae26c70d064650f7205a865d01a6ee,c8d6bd28c1e65ae7c7a5debe67a7dfaf92b429ede368efc9da7d578a539b7054,1\n",
+        "431,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,047ebea45854569a1f7ea6b95b82d6befefbf6296ebc87c810b6cba93c0c1220b23f1874fa08a693b086643ef21eb59d75562da9422d13d9a39b0b17e241b04d32,0d1f905cc74720bde67ae84f582728588c75444c273dae4106fa20d1d6946430,1\n",
+        "432,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04ceab5937900d34fa88378d371f4acaa7c6a2028b6143213413f16ba2dc7147877713fdb03a8de8c6d29ca38a9fbaa82e5e02bead2f9eec69b6444b7adb05333b,3f014e309192588fa83e47d4ac9685d2041204e2eaf633a1312812e51ae74cbd,1\n",
+        "433,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04a4ffea5e25f75e4f689c81084a35c1220e8e6b914c482f4a2e8f93cffca6964777421ab01e00e83841b946dae5bb5a23973daa98fe1a8172883abcbedced7021,68b404d556c82004c6c4bba4518ec00b1d4f1161cafe6c89aeb8494a9ba09db5,1\n",
+        "434,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04de8809ea0ecce1d24a0431429510383a6f6e5a1c51cea32d830c6c353042603e14c1aac5f0bf1b48a4abcf1d9291b9a8776a004380546a5a1c1f294690f61969,c331ade7a457df7f12a2f5c43d7ea9486c1563b81cd8a0f23f923c1a9fa612e3,1\n",
+        "435,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04566209f174d6bf79720b70edb27e51350beeb2b0bcd083bbae7214f71cf824d43bb7328839d2aecac64b1cdb182f08adccaac327ed008987a10edc9732413ced,17b5c7a311eea9d2ab7571f8b9f848d4705997cf3eaf9bdcbe0e34a670f81f45,1\n",
+        "436,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04cc3181c0127137536ceec94fd45996657df72e0f97c44b9dad14763ce506e9dc9b51b17b76ea6cc137eebd93c811e636d8ae26c70d064650f7205a865d01a6ee,2f0e4eccbc4518ace558e06604f9bff4787f5b019437b52195ecb6b82191a6ae,1\n",
+        "437,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04d7052a1eeafc0e78d79e7f26003aa0a409287cf476007df28d281b142be1a0e23f1874fa08a693b086643ef21eb59d75562da9422d13d9a39b0b17e241b04d32,7494d864cb6ea9c5d982d40a5f103700d02dc982637753cfc7d8afe1beafff70,1\n",
+        "438,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04b7cc3e2306dbf7c38ff179658706feffb5efdb6044c7e71435d7ff7d0ae8c7b37713fdb03a8de8c6d29ca38a9fbaa82e5e02bead2f9eec69b6444b7adb05333b,a96873eef5d438b807853b6771c6a5197e6eef21efefca538b45e9e981c032e5,1\n",
+        "439,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,045bbe7c98015fd3a6034d79d867a4dcd52f95911932129da2fc0a58afe149137f77421ab01e00e83841b946dae5bb5a23973daa98fe1a8172883abcbedced7021,9124618913f20cdffa642207f192e67eb80ade53ac5535469abe90036d4af7e2,1\n",
+        "440,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04962fe47880a94a745928e3c4a29a42cb01334f1ee9646e62451c46ecd72f410914c1aac5f0bf1b48a4abcf1d9291b9a8776a004380546a5a1c1f294690f61969,9d8b74888d942870b221de7a642032892bc99e34bd8550195f6f5f097547334a,1\n",
+        "441,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04c71574f5538de5653c37168d47a2bcf43698ea260012cd0ae1304e474c63a4e63bb7328839d2aecac64b1cdb182f08adccaac327ed008987a10edc9732413ced,16983377c0f1a9c004495b3fd9658363116eea644787d059d1140fb907555d4a,1\n",
+        "442,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04c60244ce306e376f3968178f5293742d7a20e1dc47cfc517edada9db49d0cbbf9b51b17b76ea6cc137eebd93c811e636d8ae26c70d064650f7205a865d01a6ee,081af40a81d48c6b530140db935e605bf4cc7b10885f5b148f95f1bc8ad2e52d,1\n",
+        "443,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04aa3c3188c0ad5767a9bac77e7ceea05cfae1599ccd77b9fcbc0c3badc80c36ca3f1874fa08a693b086643ef21eb59d75562da9422d13d9a39b0b17e241b04d32,7e4b973e6d4a357c400243a648c8a0a6a35cf231754afdef312d2f4b6abb988f,1\n",
+        "444,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,042cce8ddfe4827dc030ddf38f998b3f2ed5e0621d0b3805666daf48c8c31e75e5198d9ef4e973b6bdebe119a35faae86191acd758c1ed8accaf1e706ad55d83d7,0f0235da2a06c8d408c27151f3f15342ed8c1945aaf84ed14993786d6ac5f570,1\n",
+        "445,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0414bfc3e5a46b69881a9a346d95894418614ed91476a1ddce48676b7cbab9ba02f334d64f2caf561b063bc1f7889e937302a455ff685d8ae57cb2444a17dad068,5622c2fbe8af5ad6cef72a01be186e554847576106f8979772fa56114d1160ab,1\n",
+        "446,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04bd442fa5a2a8d72e13e44fd2222c85a006f03375e0211b272f555052b03db750be345737f7c6b5e70e97d9fe9dc4ca94fb185f4b9d2a00e086c1d47273b33602,bb95e0d0fbaad86c5bd87b95946c77ff1d65322a175ccf16419102c0a17f5a72,1\n",
+        "447,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,040d7a3ff49bda6a587ed07691450425aa02d253ba573a16ad86c61af412dd3c770b6d3b9e570ba004877c9a69e481fe215de03a70126305a452826e66d9b5583e,4510683c7bfa251f0cb56bba7e0ab74d90f5e2ca01e91e7ca99312ccff2d90b6,1\n",
+        "448,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04bdea5d2a3adde7df2e839ff63f62534b3f27cb191bb54dfa1d39cbff713ba9ed307d8f1d02c6f07146655e6383b0ef3035bee7067c336fdb91365e197a97b616,025485142ca1ced752289f772130fc10c75a4508c46bffdef9290ad3e7baf9ca,1\n",
+        "449,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04d4c063e3c036f47c92f6f5470a26a835e1a24505b14d1b29279062a16cf6f489198d9ef4e973b6bdebe119a35faae86191acd758c1ed8accaf1e706ad55d83d7,9067932150724965aa479c1ef1be55544bed9fa94500a3b67887ed91ae3b81e5,1\n",
+        "450,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,043cb9f07997756859e9b9a85b681fa50ee20357f535c1b311c4637d16b76b9ebff334d64f2caf561b063bc1f7889e937302a455ff685d8ae57cb2444a17dad068,f8084a89adccdc3aef89e5091a0f07d6160a66cb9575241100c1d39bf0549ae2,1\n",
+        "451,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04793412ff636c08a2d0f6d60cc608e9a9098349a2501f91c95f692010bc1238b2be345737f7c6b5e70e97d9fe9dc4ca94fb185f4b9d2a00e086c1d47273b33602,4462558c89902117051cb2c599ad66f00887b54cae3da9c04d317a5b2afb463b,1\n",
+        "452,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04bd1eb0849e2e6a13d54b76518f11ba8775c2d7634d85152534bc7c3af4161efa0b6d3b9e570ba004877c9a69e481fe215de03a70126305a452826e66d9b5583e,30b4741a64f87d28ec0029bd196b5a74555f2c9a976a46d628572474466a631d,1\n",
+        "453,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04624b3b4ba993a8b938125689f6cf757392ee390d14a90fea6db944b5a8deb8d0307d8f1d02c6f07146655e6383b0ef3035bee7067c336fdb91365e197a97b616,3afc04ac92117e50b0913b09dbbb4e6c780c051500201fad512b79080bff39e2,1\n",
+        "454,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04fe710e3c5b468dc33c2b17295c4e189b487d58dd437adf706ac05493cfea8df0198d9ef4e973b6bdebe119a35faae86191acd758c1ed8accaf1e706ad55d83d7,609637048586edc64cf5f28f1a505768c686471110070d783de499ffe6fe84da,1\n",
+        "455,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04ae864ba0c41f2e1dfbac2337025716d8bcadcef6539c6f1ff335176b8ddaa36ef334d64f2caf561b063bc1f7889e937302a455ff685d8ae57cb2444a17dad068,b1d4f27a6983c8ee417ef0f527d889d4a1ae41d3639244578c43d650c299fcd1,1\n",
+        "456,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04c987bd5af9eb202f1b24da2117ca90b6ef8c82e7cfbf530f71418f9a93b0085cbe345737f7c6b5e70e97d9fe9dc4ca94fb185f4b9d2a00e086c1d47273b33602,0007c9a27ac5067c9f0ad1a4d1e62110da1318893a658729713d82e333855b82,1\n",
+        "457,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,0435670f86c5f72b93abe4131d2bea1fce876ad4e25b40d42d447d68cff90ca0be0b6d3b9e570ba004877c9a69e481fe215de03a70126305a452826e66d9b5583e,8a3b23a91f0d5db8074a6a886889ee3e19aaf09b66ac9aad2e15c8bdba68085c,1\n",
+        "458,c1781d86cac2c052b865f228e64bd1ce433c78ca7dfca9e8b810473e2ce17da5,04dfca678a1b8e6f67996a097fc9ce37412de9fbd9cfa1a21b750cef48e5e595a1307d8f1d02c6f07146655e6383b0ef3035bee7067c336fdb91365e197a97b616,c2af763f414cb2d7fd46257f0313b582c099b5e23b73e073b5ab7c230c45c883,1\n",
+        "459,0000000000000000000000000000000000000000000000000000000000000003,0432bdd978eb62b1f369a56d0949ab8551a7ad527d9602e891ce457586c2a8569e981e67fae053b03fc33e1a291f0a3beb58fceb2e85bb1205dacee1232dfd316b,34005694e3cac09332aa42807e3afdc3b3b3bc7c7be887d1f98d76778c55cfd7,1\n",
+        "460,00000000ffffffffffffffffffffffffffffffffffffffffffffffffffffffff,0432bdd978eb62b1f369a56d0949ab8551a7ad527d9602e891ce457586c2a8569e981e67fae053b03fc33e1a291f0a3beb58fceb2e85bb1205dacee1232dfd316b,5841acd3cff2d62861bbe11084738006d68ccf35acae615ee9524726e93d0da5,1\n",
+        "461,0100000000000000000000000000000000000000000000000000000000000000,0432bdd978eb62b1f369a56d0949ab8551a7ad527d9602e891ce457586c2a8569e981e67fae053b03fc33e1a291f0a3beb58fceb2e85bb1205dacee1232dfd316b,4348e4cba371ead03982018abc9aacecaebfd636dda82e609fd298947f907de8,1\n",
+        "462,7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff,0432bdd978eb62b1f369a56d0949ab8551a7ad527d9602e891ce457586c2a8569e981e67fae053b03fc33e1a291f0a3beb58fceb2e85bb1205dacee1232dfd316b,e56221c2b0dc33b98b90dfd3239a2c0cb1e4ad0399a3aaef3f9d47fb103daef0,1\n",
+        "463,8000000000000000000000000000000000000000000000000000000000000000,0432bdd978eb62b1f369a56d0949ab8551a7ad527d9602e891ce457586c2a8569e981e67fae053b03fc33e1a291f0a3beb58fceb2e85bb1205dacee1232dfd316b,5b34a29b1c4ddcb2101162d34bed9f0702361fe5af505df315eff7befd0e4719,1\n",
+        "464,fffffffffffffffffffffffffffffffebaaedce6af48a03abfd25e8cd0364141,0432bdd978eb62b1f369a56d0949ab8551a7ad527d9602e891ce457586c2a8569e981e67fae053b03fc33e1a291f0a3beb58fceb2e85bb1205dacee1232dfd316b,cece521b8b5a32bbee38936ba7d645824f238e561701a386fb888e010db54b2f,1\n",
+        "465,fffffffffffffffffffffffffffffffebaaedce6af48a03bbfc25e8cd0364141,0432bdd978eb62b1f369a56d0949ab8551a7ad527d9602e891ce457586c2a8569e981e67fae053b03fc33e1a291f0a3beb58fceb2e85bb1205dacee1232dfd316b,829521b79d71f5011e079756b851a0d5c83557866189a6258c1e78a1700c6904,1\n",
+        "466,fffffffffffffffffffffffffffffffebaaedce6af48a03bbfca5e8cd0364141,0432bdd978eb62b1f369a56d0949ab8551a7ad527d9602e891ce457586c2a8569e981e67fae053b03fc33e1a291f0a3beb58fceb2e85bb1205dacee1232dfd316b,8c5934793505a6a1f84d41283341680c4923f1f4d562989a11cc626fea5eda5a,1\n",
+        "467,fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8bd0364141,0432bdd978eb62b1f369a56d0949ab8551a7ad527d9602e891ce457586c2a8569e981e67fae053b03fc33e1a291f0a3beb58fceb2e85bb1205dacee1232dfd316b,356caee7e7eee031a15e54c3a5c4e72f9c74bb287ce601619ef85eb96c289452,1\n",
+        "468,fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd03640c3,0432bdd978eb62b1f369a56d0949ab8551a7ad527d9602e891ce457586c2a8569e981e67fae053b03fc33e1a291f0a3beb58fceb2e85bb1205dacee1232dfd316b,09c7337df6c2b35edf3a21382511cc5add1a71a84cbf8d3396a5be548d92fa67,1\n",
+        "469,fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364103,0432bdd978eb62b1f369a56d0949ab8551a7ad527d9602e891ce457586c2a8569e981e67fae053b03fc33e1a291f0a3beb58fceb2e85bb1205dacee1232dfd316b,d16caedd25793666f9e26f5331382106f54095b3d20d40c745b68ca76c0e6983,1\n",
+        "470,fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364123,0432bdd978eb62b1f369a56d0949ab8551a7ad527d9602e891ce457586c2a8569e981e67fae053b03fc33e1a291f0a3beb58fceb2e85bb1205dacee1232dfd316b,b8ae1e21d8b34ce4caffed7167a26868ec80a7d4a6a98b639d4d05cd226504de,1\n",
+        "471,fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364133,0432bdd978eb62b1f369a56d0949ab8551a7ad527d9602e891ce457586c2a8569e981e67fae053b03fc33e1a291f0a3beb58fceb2e85bb1205dacee1232dfd316b,02776315fe147a36a4b0987492b6503acdea60f926450e5eddb9f88fc82178d3,1\n",
+        "472,fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd036413b,0432bdd978eb62b1f369a56d0949ab8551a7ad527d9602e891ce457586c2a8569e981e67fae053b03fc33e1a291f0a3beb58fceb2e85bb1205dacee1232dfd316b,3988c9c7050a28794934e5bd67629b556d97a4858d22812835f4a37dca351943,1\n",
+        "473,fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd036413e,0432bdd978eb62b1f369a56d0949ab8551a7ad527d9602e891ce457586c2a8569e981e67fae053b03fc33e1a291f0a3beb58fceb2e85bb1205dacee1232dfd316b,34005694e3cac09332aa42807e3afdc3b3b3bc7c7be887d1f98d76778c55cfd7,1\n",
+        "474,fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd036413f,0432bdd978eb62b1f369a56d0949ab8551a7ad527d9602e891ce457586c2a8569e981e67fae053b03fc33e1a291f0a3beb58fceb2e85bb1205dacee1232dfd316b,4b52257d8b3ba387797fdf7a752f195ddc4f7d76263de61d0d52a5ec14a36cbf,1\n",
+        "475,c6cafb74e2a50c83b3d232c4585237f44d4c5433c4b3f50ce978e6aeda3a4f5d,0400000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000,,0\n",
+        "476,c6cafb74e2a50c83b3d232c4585237f44d4c5433c4b3f50ce978e6aeda3a4f5d,0400000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001,,0\n",
+        "477,c6cafb74e2a50c83b3d232c4585237f44d4c5433c4b3f50ce978e6aeda3a4f5d,040000000000000000000000000000000000000000000000000000000000000000fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2e,,0\n",
+        "478,c6cafb74e2a50c83b3d232c4585237f44d4c5433c4b3f50ce978e6aeda3a4f5d,040000000000000000000000000000000000000000000000000000000000000000fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f,,0\n",
+        "479,c6cafb74e2a50c83b3d232c4585237f44d4c5433c4b3f50ce978e6aeda3a4f5d,0400000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000000,,0\n",
+        "480,c6cafb74e2a50c83b3d232c4585237f44d4c5433c4b3f50ce978e6aeda3a4f5d,0400000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000001,,0\n",
+        "481,c6cafb74e2a50c83b3d232c4585237f44d4c5433c4b3f50ce978e6aeda3a4f5d,040000000000000000000000000000000000000000000000000000000000000001fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2e,,0\n",
+        "482,c6cafb74e2a50c83b3d232c4585237f44d4c5433c4b3f50ce978e6aeda3a4f5d,040000000000000000000000000000000000000000000000000000000000000001fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f,,0\n",
+        "483,c6cafb74e2a50c83b3d232c4585237f44d4c5433c4b3f50ce978e6aeda3a4f5d,04fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2e0000000000000000000000000000000000000000000000000000000000000000,,0\n",
+        "484,c6cafb74e2a50c83b3d232c4585237f44d4c5433c4b3f50ce978e6aeda3a4f5d,04fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2e0000000000000000000000000000000000000000000000000000000000000001,,0\n",
+        "485,c6cafb74e2a50c83b3d232c4585237f44d4c5433c4b3f50ce978e6aeda3a4f5d,04fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2efffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2e,,0\n",
+        "486,c6cafb74e2a50c83b3d232c4585237f44d4c5433c4b3f50ce978e6aeda3a4f5d,04fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2efffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f,,0\n",
+        "487,c6cafb74e2a50c83b3d232c4585237f44d4c5433c4b3f50ce978e6aeda3a4f5d,04fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f0000000000000000000000000000000000000000000000000000000000000000,,0\n",
+        "488,c6cafb74e2a50c83b3d232c4585237f44d4c5433c4b3f50ce978e6aeda3a4f5d,04fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f0000000000000000000000000000000000000000000000000000000000000001,,0\n",
+        "489,c6cafb74e2a50c83b3d232c4585237f44d4c5433c4b3f50ce978e6aeda3a4f5d,04fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2ffffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2e,,0\n",
+        "490,c6cafb74e2a50c83b3d232c4585237f44d4c5433c4b3f50ce978e6aeda3a4f5d,04fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2ffffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f,,0\n",
+        "494,cfe75ee764197aa7732a5478556b478898423d2bc0e484a6ebb3674a6036a65d,0449c248edc659e18482b7105748a4b95d3a46952a5ba72da0d702dc97a64e99799d8cff7a5c4b925e4360ece25ccf307d7a9a7063286bbd16ef64c65f546757e4,,0\n",
+        "495,cfe75ee764197aa7732a5478556b478898423d2bc0e484a6ebb3674a6036a65d,0400000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000,,0\n",
+        "528,d09182a4d0c94ba85f82eff9fc1bddb0b07d3f2af8632fc1c73a3604e8f0b335,02977cb7fb9a0ec5b208e811d6a0795eb78d7642e3cac42a801bcc8fc0f06472d4,,0\n",
+        "529,98b5c223cf9cc0920a5145ba1fd2f6afee7e1f66d0120b8536685fdf05ebb300,020000000000000000000000000000000000000000000000000000000000000000,,0\n",
+        "530,98b5c223cf9cc0920a5145ba1fd2f6afee7e1f66d0120b8536685fdf05ebb2ff,030000000000000000000000000000000000000000000000000000000000000000,,0\n",
+    );
 }
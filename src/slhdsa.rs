@@ -0,0 +1,4318 @@
+//! SLH-DSA implementation.
+//!
+//! This follows FIPS 205 (Stateless Hash-Based Digital Signature
+//! Standard, derived from SPHINCS+). All twelve approved parameter sets
+//! are implemented, each in its own module, named after the FIPS 205
+//! name (e.g. `SLH_DSA_SHA2_128s` for SLH-DSA-SHA2-128s).
+//!
+//! Contrary to LMS and XMSS, SLH-DSA is stateless: the private key is
+//! not modified by signing, and can be used for an unbounded number of
+//! signatures. This comes at the cost of larger signatures and slower
+//! signature generation; the "s" parameter sets have smaller signatures,
+//! while the "f" parameter sets have faster signature generation.
+//!
+//! Signature generation and verification use the "pure" SLH-DSA
+//! interface, with an optional context string (at most 255 bytes). By
+//! default, signatures are hedged (using some randomness from the
+//! provided random source); the deterministic variant is also available.
+//! The internal functions (`slh_sign_internal` and `slh_verify_internal`
+//! in FIPS 205) are exposed as `sign_internal()` and `verify_internal()`,
+//! mostly for test purposes. Private and public keys use the FIPS 205
+//! encodings (`SK.seed || SK.prf || PK.seed || PK.root` and
+//! `PK.seed || PK.root`, respectively).
+
+// We use the names from FIPS 205, which do not following the default
+// casing style rules of Rust.
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+
+macro_rules! define_slhdsa_core { () => {
+
+    use crate::{CryptoRng, RngCore};
+    use core::convert::TryFrom;
+
+    /// An SLH-DSA private key.
+    #[derive(Clone, Copy, Debug)]
+    pub struct PrivateKey {
+        SK_seed: [u8; n],
+        SK_prf: [u8; n],
+        PK_seed: [u8; n],
+        PK_root: [u8; n],
+    }
+
+    /// An SLH-DSA public key.
+    #[derive(Clone, Copy, Debug)]
+    pub struct PublicKey {
+        PK_seed: [u8; n],
+        PK_root: [u8; n],
+    }
+
+    // WOTS+ parameters (w = 16).
+    const len1: usize = 2 * n;
+    const len2: usize = 3;
+    const len: usize = len1 + len2;
+
+    // Height of each XMSS tree.
+    const hp: usize = h / d;
+
+    // Digest split (lengths in bytes).
+    const md_len: usize = (k * a + 7) >> 3;
+    const idx_tree_len: usize = (h - hp + 7) >> 3;
+    const idx_leaf_len: usize = (hp + 7) >> 3;
+
+    const fors_siglen: usize = k * (a + 1) * n;
+    const xmss_siglen: usize = (len + hp) * n;
+
+    /// Signature length (in bytes).
+    pub const SIG_LEN: usize = n + fors_siglen + d * xmss_siglen;
+
+    // Address types.
+    const WOTS_HASH: u32 = 0;
+    const WOTS_PK: u32 = 1;
+    const TREE: u32 = 2;
+    const FORS_TREE: u32 = 3;
+    const FORS_ROOTS: u32 = 4;
+    const WOTS_PRF: u32 = 5;
+    const FORS_PRF: u32 = 6;
+
+    // Hash address (ADRS, 32 bytes).
+    #[derive(Clone, Copy)]
+    struct Adrs([u8; 32]);
+
+    impl Adrs {
+
+        fn set_word(&mut self, off: usize, v: u32) {
+            self.0[off..(off + 4)].copy_from_slice(&v.to_be_bytes());
+        }
+
+        fn get_word(&self, off: usize) -> u32 {
+            u32::from_be_bytes(*<&[u8; 4]>::try_from(&self.0[off..(off + 4)]).unwrap())
+        }
+
+        fn set_layer_address(&mut self, l: u32) {
+            self.set_word(0, l);
+        }
+
+        fn set_tree_address(&mut self, t: u64) {
+            self.0[4..8].copy_from_slice(&[0u8; 4]);
+            self.0[8..16].copy_from_slice(&t.to_be_bytes());
+        }
+
+        fn set_type_and_clear(&mut self, y: u32) {
+            self.set_word(16, y);
+            self.0[20..32].copy_from_slice(&[0u8; 12]);
+        }
+
+        fn set_key_pair_address(&mut self, i: u32) {
+            self.set_word(20, i);
+        }
+
+        fn get_key_pair_address(&self) -> u32 {
+            self.get_word(20)
+        }
+
+        fn set_chain_address(&mut self, i: u32) {
+            self.set_word(24, i);
+        }
+
+        fn set_tree_height(&mut self, z: u32) {
+            self.set_word(24, z);
+        }
+
+        fn set_hash_address(&mut self, i: u32) {
+            self.set_word(28, i);
+        }
+
+        fn set_tree_index(&mut self, i: u32) {
+            self.set_word(28, i);
+        }
+
+        fn get_tree_index(&self) -> u32 {
+            self.get_word(28)
+        }
+
+        // Compressed address (for the SHA2 parameter sets).
+        #[allow(dead_code)]
+        fn compress(&self) -> [u8; 22] {
+            let mut c = [0u8; 22];
+            c[0] = self.0[3];
+            c[1..9].copy_from_slice(&self.0[8..16]);
+            c[9] = self.0[19];
+            c[10..22].copy_from_slice(&self.0[20..32]);
+            c
+        }
+    }
+
+    // Context for hashing: the public seed (with hash function states
+    // precomputed over it, when applicable) and the secret seed.
+    struct Ctx {
+        hc: HashCtx,
+        SK_seed: [u8; n],
+    }
+
+    impl Ctx {
+
+        fn new(PK_seed: &[u8; n], SK_seed: &[u8; n]) -> Self {
+            Self { hc: HashCtx::new(PK_seed), SK_seed: *SK_seed }
+        }
+    }
+
+    // Converts a byte string into base-2^b digits (big-endian).
+    fn base_2b(X: &[u8], b: usize, out: &mut [u32]) {
+        let mut j = 0;
+        let mut bits = 0;
+        let mut total = 0u32;
+        for o in out.iter_mut() {
+            while bits < b {
+                total = (total << 8) | (X[j] as u32);
+                j += 1;
+                bits += 8;
+            }
+            bits -= b;
+            *o = (total >> bits) & ((1u32 << b) - 1);
+            total &= (1u32 << bits) - 1;
+        }
+    }
+
+    fn chain(ctx: &Ctx, X: &[u8; n], i: u32, s: u32, adrs: &mut Adrs)
+        -> [u8; n]
+    {
+        let mut tmp = *X;
+        for j in i..(i + s) {
+            adrs.set_hash_address(j);
+            tmp = F(&ctx.hc, adrs, &tmp);
+        }
+        tmp
+    }
+
+    fn wots_sk(ctx: &Ctx, adrs: &Adrs, i: usize) -> [u8; n] {
+        let mut skADRS = *adrs;
+        skADRS.set_type_and_clear(WOTS_PRF);
+        skADRS.set_key_pair_address(adrs.get_key_pair_address());
+        skADRS.set_chain_address(i as u32);
+        PRF(&ctx.hc, &skADRS, &ctx.SK_seed)
+    }
+
+    fn wots_pk_compress(ctx: &Ctx, adrs: &Adrs, tmp: &[[u8; n]; len])
+        -> [u8; n]
+    {
+        let mut wotspkADRS = *adrs;
+        wotspkADRS.set_type_and_clear(WOTS_PK);
+        wotspkADRS.set_key_pair_address(adrs.get_key_pair_address());
+        T(&ctx.hc, &wotspkADRS, tmp)
+    }
+
+    fn wots_pkgen(ctx: &Ctx, adrs: &mut Adrs) -> [u8; n] {
+        let mut tmp = [[0u8; n]; len];
+        for i in 0..len {
+            let sk = wots_sk(ctx, adrs, i);
+            adrs.set_chain_address(i as u32);
+            tmp[i] = chain(ctx, &sk, 0, 15, adrs);
+        }
+        wots_pk_compress(ctx, adrs, &tmp)
+    }
+
+    // Converts an n-byte message into base-16 digits, with the checksum.
+    fn wots_digits(M: &[u8; n]) -> [u32; len] {
+        let mut msg = [0u32; len];
+        base_2b(M, 4, &mut msg[..len1]);
+        let mut csum = 0u32;
+        for i in 0..len1 {
+            csum += 15 - msg[i];
+        }
+        csum <<= 4;
+        base_2b(&(csum as u16).to_be_bytes(), 4, &mut msg[len1..]);
+        msg
+    }
+
+    fn wots_sign(ctx: &Ctx, M: &[u8; n], adrs: &mut Adrs, sig: &mut [u8]) {
+        let msg = wots_digits(M);
+        for i in 0..len {
+            let sk = wots_sk(ctx, adrs, i);
+            adrs.set_chain_address(i as u32);
+            sig[(i * n)..((i + 1) * n)].copy_from_slice(
+                &chain(ctx, &sk, 0, msg[i], adrs));
+        }
+    }
+
+    fn wots_pk_from_sig(ctx: &Ctx, sig: &[u8], M: &[u8; n], adrs: &mut Adrs)
+        -> [u8; n]
+    {
+        let msg = wots_digits(M);
+        let mut tmp = [[0u8; n]; len];
+        for i in 0..len {
+            adrs.set_chain_address(i as u32);
+            let s = <&[u8; n]>::try_from(&sig[(i * n)..((i + 1) * n)]).unwrap();
+            tmp[i] = chain(ctx, s, msg[i], 15 - msg[i], adrs);
+        }
+        wots_pk_compress(ctx, adrs, &tmp)
+    }
+
+    fn xmss_node(ctx: &Ctx, i: u32, z: usize, adrs: &mut Adrs) -> [u8; n] {
+        if z == 0 {
+            adrs.set_type_and_clear(WOTS_HASH);
+            adrs.set_key_pair_address(i);
+            wots_pkgen(ctx, adrs)
+        } else {
+            let lnode = xmss_node(ctx, 2 * i, z - 1, adrs);
+            let rnode = xmss_node(ctx, 2 * i + 1, z - 1, adrs);
+            adrs.set_type_and_clear(TREE);
+            adrs.set_tree_height(z as u32);
+            adrs.set_tree_index(i);
+            H(&ctx.hc, adrs, &lnode, &rnode)
+        }
+    }
+
+    fn xmss_sign(ctx: &Ctx, M: &[u8; n], idx: u32, adrs: &mut Adrs,
+        sig: &mut [u8])
+    {
+        for j in 0..hp {
+            let kk = (idx >> j) ^ 1;
+            let node = xmss_node(ctx, kk, j, adrs);
+            let off = (len + j) * n;
+            sig[off..(off + n)].copy_from_slice(&node);
+        }
+        adrs.set_type_and_clear(WOTS_HASH);
+        adrs.set_key_pair_address(idx);
+        wots_sign(ctx, M, adrs, &mut sig[..(len * n)]);
+    }
+
+    fn xmss_pk_from_sig(ctx: &Ctx, idx: u32, sig: &[u8], M: &[u8; n],
+        adrs: &mut Adrs) -> [u8; n]
+    {
+        adrs.set_type_and_clear(WOTS_HASH);
+        adrs.set_key_pair_address(idx);
+        let mut node = wots_pk_from_sig(ctx, &sig[..(len * n)], M, adrs);
+        adrs.set_type_and_clear(TREE);
+        adrs.set_tree_index(idx);
+        for j in 0..hp {
+            let off = (len + j) * n;
+            let auth = <&[u8; n]>::try_from(&sig[off..(off + n)]).unwrap();
+            adrs.set_tree_height((j + 1) as u32);
+            let ti = adrs.get_tree_index();
+            adrs.set_tree_index(ti >> 1);
+            node = if ((idx >> j) & 1) == 0 {
+                H(&ctx.hc, adrs, &node, auth)
+            } else {
+                H(&ctx.hc, adrs, auth, &node)
+            };
+        }
+        node
+    }
+
+    fn split_tree(idx_tree: u64) -> (u64, u32) {
+        (idx_tree >> hp, (idx_tree & ((1u64 << hp) - 1)) as u32)
+    }
+
+    fn ht_sign(ctx: &Ctx, M: &[u8; n], idx_tree: u64, idx_leaf: u32,
+        sig: &mut [u8])
+    {
+        let mut adrs = Adrs([0u8; 32]);
+        adrs.set_tree_address(idx_tree);
+        xmss_sign(ctx, M, idx_leaf, &mut adrs, &mut sig[..xmss_siglen]);
+        let mut root = xmss_pk_from_sig(ctx, idx_leaf, &sig[..xmss_siglen],
+            M, &mut adrs);
+        let mut idx_tree = idx_tree;
+        for j in 1..d {
+            let (t, l) = split_tree(idx_tree);
+            idx_tree = t;
+            adrs.set_layer_address(j as u32);
+            adrs.set_tree_address(idx_tree);
+            let s = &mut sig[(j * xmss_siglen)..((j + 1) * xmss_siglen)];
+            xmss_sign(ctx, &root, l, &mut adrs, s);
+            if j < d - 1 {
+                root = xmss_pk_from_sig(ctx, l, s, &root, &mut adrs);
+            }
+        }
+    }
+
+    fn ht_verify(ctx: &Ctx, M: &[u8; n], sig: &[u8], idx_tree: u64,
+        idx_leaf: u32, PK_root: &[u8; n]) -> bool
+    {
+        let mut adrs = Adrs([0u8; 32]);
+        adrs.set_tree_address(idx_tree);
+        let mut node = xmss_pk_from_sig(ctx, idx_leaf, &sig[..xmss_siglen],
+            M, &mut adrs);
+        let mut idx_tree = idx_tree;
+        for j in 1..d {
+            let (t, l) = split_tree(idx_tree);
+            idx_tree = t;
+            adrs.set_layer_address(j as u32);
+            adrs.set_tree_address(idx_tree);
+            node = xmss_pk_from_sig(ctx, l,
+                &sig[(j * xmss_siglen)..((j + 1) * xmss_siglen)],
+                &node, &mut adrs);
+        }
+        node == *PK_root
+    }
+
+    fn fors_skgen(ctx: &Ctx, adrs: &Adrs, idx: u32) -> [u8; n] {
+        let mut skADRS = *adrs;
+        skADRS.set_type_and_clear(FORS_PRF);
+        skADRS.set_key_pair_address(adrs.get_key_pair_address());
+        skADRS.set_tree_index(idx);
+        PRF(&ctx.hc, &skADRS, &ctx.SK_seed)
+    }
+
+    fn fors_node(ctx: &Ctx, i: u32, z: usize, adrs: &mut Adrs) -> [u8; n] {
+        if z == 0 {
+            let sk = fors_skgen(ctx, adrs, i);
+            adrs.set_tree_height(0);
+            adrs.set_tree_index(i);
+            F(&ctx.hc, adrs, &sk)
+        } else {
+            let lnode = fors_node(ctx, 2 * i, z - 1, adrs);
+            let rnode = fors_node(ctx, 2 * i + 1, z - 1, adrs);
+            adrs.set_tree_height(z as u32);
+            adrs.set_tree_index(i);
+            H(&ctx.hc, adrs, &lnode, &rnode)
+        }
+    }
+
+    fn fors_sign(ctx: &Ctx, md: &[u8], adrs: &mut Adrs, sig: &mut [u8]) {
+        let mut indices = [0u32; k];
+        base_2b(md, a, &mut indices);
+        for i in 0..k {
+            let off = i * (a + 1) * n;
+            let base = (i as u32) << a;
+            sig[off..(off + n)].copy_from_slice(
+                &fors_skgen(ctx, adrs, base + indices[i]));
+            for j in 0..a {
+                let s = (indices[i] >> j) ^ 1;
+                let node = fors_node(ctx, ((i as u32) << (a - j)) + s, j, adrs);
+                let off2 = off + (j + 1) * n;
+                sig[off2..(off2 + n)].copy_from_slice(&node);
+            }
+        }
+    }
+
+    fn fors_pk_from_sig(ctx: &Ctx, sig: &[u8], md: &[u8], adrs: &mut Adrs)
+        -> [u8; n]
+    {
+        let mut indices = [0u32; k];
+        base_2b(md, a, &mut indices);
+        let mut root = [[0u8; n]; k];
+        for i in 0..k {
+            let off = i * (a + 1) * n;
+            let sk = <&[u8; n]>::try_from(&sig[off..(off + n)]).unwrap();
+            adrs.set_tree_height(0);
+            adrs.set_tree_index(((i as u32) << a) + indices[i]);
+            let mut node = F(&ctx.hc, adrs, sk);
+            for j in 0..a {
+                let off2 = off + (j + 1) * n;
+                let auth = <&[u8; n]>::try_from(&sig[off2..(off2 + n)]).unwrap();
+                adrs.set_tree_height((j + 1) as u32);
+                let ti = adrs.get_tree_index();
+                adrs.set_tree_index(ti >> 1);
+                node = if ((indices[i] >> j) & 1) == 0 {
+                    H(&ctx.hc, adrs, &node, auth)
+                } else {
+                    H(&ctx.hc, adrs, auth, &node)
+                };
+            }
+            root[i] = node;
+        }
+        let mut forspkADRS = *adrs;
+        forspkADRS.set_type_and_clear(FORS_ROOTS);
+        forspkADRS.set_key_pair_address(adrs.get_key_pair_address());
+        T(&ctx.hc, &forspkADRS, &root)
+    }
+
+    // Splits the message digest into the FORS message, the tree index
+    // and the leaf index.
+    fn split_digest(digest: &[u8; m]) -> (&[u8], u64, u32) {
+        let md = &digest[..md_len];
+        let mut idx_tree = 0u64;
+        for &b in digest[md_len..(md_len + idx_tree_len)].iter() {
+            idx_tree = (idx_tree << 8) | (b as u64);
+        }
+        if (h - hp) < 64 {
+            idx_tree &= (1u64 << (h - hp)) - 1;
+        }
+        let mut idx_leaf = 0u32;
+        let off = md_len + idx_tree_len;
+        for &b in digest[off..(off + idx_leaf_len)].iter() {
+            idx_leaf = (idx_leaf << 8) | (b as u32);
+        }
+        idx_leaf &= (1u32 << hp) - 1;
+        (md, idx_tree, idx_leaf)
+    }
+
+    // Message prefix for the pure signature interface. This returns
+    // `None` if the context string is longer than 255 bytes.
+    fn msg_prefix(ctx: &[u8]) -> Option<[u8; 2]> {
+        if ctx.len() > 255 {
+            return None;
+        }
+        Some([0x00, ctx.len() as u8])
+    }
+
+    impl PrivateKey {
+
+        /// Generates a new private key.
+        pub fn generate<T: CryptoRng + RngCore>(rng: &mut T) -> Self {
+            let mut SK_seed = [0u8; n];
+            let mut SK_prf = [0u8; n];
+            let mut PK_seed = [0u8; n];
+            rng.fill_bytes(&mut SK_seed);
+            rng.fill_bytes(&mut SK_prf);
+            rng.fill_bytes(&mut PK_seed);
+            Self::from_seeds(&SK_seed, &SK_prf, &PK_seed)
+        }
+
+        /// Creates a private key from its seeds.
+        ///
+        /// This is the `slh_keygen_internal` function of FIPS 205; the
+        /// public root is computed from the seeds.
+        pub fn from_seeds(SK_seed: &[u8; n], SK_prf: &[u8; n],
+            PK_seed: &[u8; n]) -> Self
+        {
+            let ctx = Ctx::new(PK_seed, SK_seed);
+            let mut adrs = Adrs([0u8; 32]);
+            adrs.set_layer_address((d - 1) as u32);
+            let PK_root = xmss_node(&ctx, 0, hp, &mut adrs);
+            Self {
+                SK_seed: *SK_seed,
+                SK_prf: *SK_prf,
+                PK_seed: *PK_seed,
+                PK_root,
+            }
+        }
+
+        /// Gets the public key corresponding to this private key.
+        pub fn compute_public(&self) -> PublicKey {
+            PublicKey { PK_seed: self.PK_seed, PK_root: self.PK_root }
+        }
+
+        /// Encoded private key length (in bytes).
+        pub const ENC_LEN: usize = 4 * n;
+
+        /// Encodes this private key into bytes (FIPS 205 format).
+        pub fn encode(&self) -> [u8; Self::ENC_LEN] {
+            let mut buf = [0u8; Self::ENC_LEN];
+            buf[..n].copy_from_slice(&self.SK_seed);
+            buf[n..(2 * n)].copy_from_slice(&self.SK_prf);
+            buf[(2 * n)..(3 * n)].copy_from_slice(&self.PK_seed);
+            buf[(3 * n)..].copy_from_slice(&self.PK_root);
+            buf
+        }
+
+        /// Decodes a private key from bytes (FIPS 205 format).
+        ///
+        /// The public root is not recomputed. This function returns
+        /// `None` if the source does not have the proper length.
+        pub fn decode(buf: &[u8]) -> Option<Self> {
+            if buf.len() != Self::ENC_LEN {
+                return None;
+            }
+            let mut sk = Self {
+                SK_seed: [0u8; n],
+                SK_prf: [0u8; n],
+                PK_seed: [0u8; n],
+                PK_root: [0u8; n],
+            };
+            sk.SK_seed.copy_from_slice(&buf[..n]);
+            sk.SK_prf.copy_from_slice(&buf[n..(2 * n)]);
+            sk.PK_seed.copy_from_slice(&buf[(2 * n)..(3 * n)]);
+            sk.PK_root.copy_from_slice(&buf[(3 * n)..]);
+            Some(sk)
+        }
+
+        /// Signs a message.
+        ///
+        /// No context is used; this is equivalent to `sign_ctx()` with an
+        /// empty context.
+        pub fn sign<T: CryptoRng + RngCore>(&self, rng: &mut T, msg: &[u8])
+            -> [u8; SIG_LEN]
+        {
+            self.sign_ctx(rng, &[], msg).unwrap()
+        }
+
+        /// Signs a message (with context).
+        ///
+        /// This is the hedged variant: some randomness is obtained from
+        /// the provided random source. The context string can have length
+        /// at most 255 bytes; if it is longer, then `None` is returned.
+        pub fn sign_ctx<T: CryptoRng + RngCore>(&self, rng: &mut T,
+            ctx: &[u8], msg: &[u8]) -> Option<[u8; SIG_LEN]>
+        {
+            let pp = msg_prefix(ctx)?;
+            let mut addrnd = [0u8; n];
+            rng.fill_bytes(&mut addrnd);
+            Some(self.sign_inner(&[&pp, ctx, msg], &addrnd))
+        }
+
+        /// Signs a message (with context), deterministically.
+        ///
+        /// The context string can have length at most 255 bytes; if it
+        /// is longer, then `None` is returned.
+        pub fn sign_ctx_deterministic(&self, ctx: &[u8], msg: &[u8])
+            -> Option<[u8; SIG_LEN]>
+        {
+            let pp = msg_prefix(ctx)?;
+            Some(self.sign_inner(&[&pp, ctx, msg], &self.PK_seed))
+        }
+
+        /// Signs a message with the internal signature function.
+        ///
+        /// This is `slh_sign_internal` from FIPS 205: the message is used
+        /// as is (no prefix or context). If `addrnd` is `None`, then
+        /// the signature is deterministic.
+        pub fn sign_internal(&self, msg: &[u8], addrnd: Option<&[u8; n]>)
+            -> [u8; SIG_LEN]
+        {
+            match addrnd {
+                Some(r) => self.sign_inner(&[msg], r),
+                None => self.sign_inner(&[msg], &self.PK_seed),
+            }
+        }
+
+        fn sign_inner(&self, M: &[&[u8]], opt_rand: &[u8; n])
+            -> [u8; SIG_LEN]
+        {
+            let ctx = Ctx::new(&self.PK_seed, &self.SK_seed);
+            let mut sig = [0u8; SIG_LEN];
+            let R = PRF_msg(&self.SK_prf, opt_rand, M);
+            sig[..n].copy_from_slice(&R);
+            let digest = H_msg(&R, &self.PK_seed, &self.PK_root, M);
+            let (md, idx_tree, idx_leaf) = split_digest(&digest);
+            let mut adrs = Adrs([0u8; 32]);
+            adrs.set_tree_address(idx_tree);
+            adrs.set_type_and_clear(FORS_TREE);
+            adrs.set_key_pair_address(idx_leaf);
+            let (sig_fors, sig_ht) = sig[n..].split_at_mut(fors_siglen);
+            fors_sign(&ctx, md, &mut adrs, sig_fors);
+            let PK_fors = fors_pk_from_sig(&ctx, sig_fors, md, &mut adrs);
+            ht_sign(&ctx, &PK_fors, idx_tree, idx_leaf, sig_ht);
+            sig
+        }
+    }
+
+    impl PublicKey {
+
+        /// Encoded public key length (in bytes).
+        pub const ENC_LEN: usize = 2 * n;
+
+        /// Encodes this public key into bytes (FIPS 205 format).
+        pub fn encode(&self) -> [u8; Self::ENC_LEN] {
+            let mut buf = [0u8; Self::ENC_LEN];
+            buf[..n].copy_from_slice(&self.PK_seed);
+            buf[n..].copy_from_slice(&self.PK_root);
+            buf
+        }
+
+        /// Decodes a public key from bytes (FIPS 205 format).
+        ///
+        /// This function returns `None` if the source does not have the
+        /// proper length.
+        pub fn decode(buf: &[u8]) -> Option<Self> {
+            if buf.len() != Self::ENC_LEN {
+                return None;
+            }
+            let mut PK_seed = [0u8; n];
+            let mut PK_root = [0u8; n];
+            PK_seed.copy_from_slice(&buf[..n]);
+            PK_root.copy_from_slice(&buf[n..]);
+            Some(Self { PK_seed, PK_root })
+        }
+
+        /// Verifies a signature on a message.
+        ///
+        /// No context is used; this is equivalent to `verify_ctx()` with
+        /// an empty context.
+        pub fn verify(&self, sig: &[u8], msg: &[u8]) -> bool {
+            self.verify_ctx(sig, &[], msg)
+        }
+
+        /// Verifies a signature on a message (with context).
+        ///
+        /// The context string can have length at most 255 bytes; if it
+        /// is longer, then the signature is rejected.
+        pub fn verify_ctx(&self, sig: &[u8], ctx: &[u8], msg: &[u8]) -> bool {
+            match msg_prefix(ctx) {
+                Some(pp) => self.verify_inner(sig, &[&pp, ctx, msg]),
+                None => false,
+            }
+        }
+
+        /// Verifies a signature with the internal verification function.
+        ///
+        /// This is `slh_verify_internal` from FIPS 205: the message is
+        /// used as is (no prefix or context).
+        pub fn verify_internal(&self, sig: &[u8], msg: &[u8]) -> bool {
+            self.verify_inner(sig, &[msg])
+        }
+
+        fn verify_inner(&self, sig: &[u8], M: &[&[u8]]) -> bool {
+            if sig.len() != SIG_LEN {
+                return false;
+            }
+            let ctx = Ctx::new(&self.PK_seed, &[0u8; n]);
+            let R = &sig[..n];
+            let sig_fors = &sig[n..(n + fors_siglen)];
+            let sig_ht = &sig[(n + fors_siglen)..];
+            let digest = H_msg(R, &self.PK_seed, &self.PK_root, M);
+            let (md, idx_tree, idx_leaf) = split_digest(&digest);
+            let mut adrs = Adrs([0u8; 32]);
+            adrs.set_tree_address(idx_tree);
+            adrs.set_type_and_clear(FORS_TREE);
+            adrs.set_key_pair_address(idx_leaf);
+            let PK_fors = fors_pk_from_sig(&ctx, sig_fors, md, &mut adrs);
+            ht_verify(&ctx, &PK_fors, sig_ht, idx_tree, idx_leaf, &self.PK_root)
+        }
+    }
+
+} } // end of macro define_slhdsa_core
+
+// ========================================================================
+
+// Hash functions for the SHA2 parameter sets. F and PRF always use
+// SHA-256; H, T_l, H_msg and PRF_msg use SHA-256 for security category 1,
+// and SHA-512 for categories 3 and 5. Hash states over PK.seed and the
+// padding are precomputed.
+macro_rules! define_slhdsa_sha2 { ($Big:ident, $bs:expr) => {
+
+    use sha2::{Sha256, Digest};
+    #[allow(unused_imports)]
+    use sha2::Sha512;
+
+    // Block size of the hash function used for H and T_l.
+    const bs: usize = $bs;
+
+    struct HashCtx {
+        s256: Sha256,
+        sbig: $Big,
+    }
+
+    impl HashCtx {
+
+        fn new(PK_seed: &[u8; n]) -> Self {
+            let mut s256 = Sha256::new();
+            s256.update(PK_seed);
+            s256.update(&[0u8; 64 - n]);
+            let mut sbig = $Big::new();
+            sbig.update(PK_seed);
+            sbig.update(&[0u8; bs - n]);
+            Self { s256, sbig }
+        }
+    }
+
+    fn F(hc: &HashCtx, adrs: &Adrs, M1: &[u8; n]) -> [u8; n] {
+        let mut sh = hc.s256.clone();
+        sh.update(&adrs.compress());
+        sh.update(M1);
+        let mut r = [0u8; n];
+        r.copy_from_slice(&sh.finalize()[..n]);
+        r
+    }
+
+    fn PRF(hc: &HashCtx, adrs: &Adrs, SK_seed: &[u8; n]) -> [u8; n] {
+        F(hc, adrs, SK_seed)
+    }
+
+    fn H(hc: &HashCtx, adrs: &Adrs, M1: &[u8; n], M2: &[u8; n]) -> [u8; n] {
+        let mut sh = hc.sbig.clone();
+        sh.update(&adrs.compress());
+        sh.update(M1);
+        sh.update(M2);
+        let mut r = [0u8; n];
+        r.copy_from_slice(&sh.finalize()[..n]);
+        r
+    }
+
+    fn T(hc: &HashCtx, adrs: &Adrs, M: &[[u8; n]]) -> [u8; n] {
+        let mut sh = hc.sbig.clone();
+        sh.update(&adrs.compress());
+        for x in M.iter() {
+            sh.update(x);
+        }
+        let mut r = [0u8; n];
+        r.copy_from_slice(&sh.finalize()[..n]);
+        r
+    }
+
+    fn H_msg(R: &[u8], PK_seed: &[u8; n], PK_root: &[u8; n], M: &[&[u8]])
+        -> [u8; m]
+    {
+        let mut sh = $Big::new();
+        sh.update(R);
+        sh.update(PK_seed);
+        sh.update(PK_root);
+        for x in M.iter() {
+            sh.update(x);
+        }
+        let hv = sh.finalize();
+
+        // MGF1
+        let mut r = [0u8; m];
+        let mut j = 0;
+        let mut ctr = 0u32;
+        while j < m {
+            let mut sh = $Big::new();
+            sh.update(R);
+            sh.update(PK_seed);
+            sh.update(&hv);
+            sh.update(&ctr.to_be_bytes());
+            let bv = sh.finalize();
+            let clen = core::cmp::min(m - j, bv.len());
+            r[j..(j + clen)].copy_from_slice(&bv[..clen]);
+            j += clen;
+            ctr += 1;
+        }
+        r
+    }
+
+    fn PRF_msg(SK_prf: &[u8; n], opt_rand: &[u8; n], M: &[&[u8]]) -> [u8; n] {
+        // HMAC (the key is shorter than a block).
+        let mut kb = [0u8; bs];
+        kb[..n].copy_from_slice(SK_prf);
+        for b in kb.iter_mut() {
+            *b ^= 0x36;
+        }
+        let mut sh = $Big::new();
+        sh.update(&kb);
+        sh.update(opt_rand);
+        for x in M.iter() {
+            sh.update(x);
+        }
+        let hv = sh.finalize();
+        for b in kb.iter_mut() {
+            *b ^= 0x36 ^ 0x5C;
+        }
+        let mut sh = $Big::new();
+        sh.update(&kb);
+        sh.update(&hv);
+        let mut r = [0u8; n];
+        r.copy_from_slice(&sh.finalize()[..n]);
+        r
+    }
+
+} } // end of macro define_slhdsa_sha2
+
+// Hash functions for the SHAKE parameter sets.
+macro_rules! define_slhdsa_shake { () => {
+
+    use sha3::{Shake256, digest::{Update, ExtendableOutput, XofReader}};
+
+    struct HashCtx {
+        s: Shake256,
+    }
+
+    impl HashCtx {
+
+        fn new(PK_seed: &[u8; n]) -> Self {
+            let mut s = Shake256::default();
+            s.update(PK_seed);
+            Self { s }
+        }
+    }
+
+    fn F(hc: &HashCtx, adrs: &Adrs, M1: &[u8; n]) -> [u8; n] {
+        let mut sh = hc.s.clone();
+        sh.update(&adrs.0);
+        sh.update(M1);
+        let mut r = [0u8; n];
+        sh.finalize_xof().read(&mut r);
+        r
+    }
+
+    fn PRF(hc: &HashCtx, adrs: &Adrs, SK_seed: &[u8; n]) -> [u8; n] {
+        F(hc, adrs, SK_seed)
+    }
+
+    fn H(hc: &HashCtx, adrs: &Adrs, M1: &[u8; n], M2: &[u8; n]) -> [u8; n] {
+        let mut sh = hc.s.clone();
+        sh.update(&adrs.0);
+        sh.update(M1);
+        sh.update(M2);
+        let mut r = [0u8; n];
+        sh.finalize_xof().read(&mut r);
+        r
+    }
+
+    fn T(hc: &HashCtx, adrs: &Adrs, M: &[[u8; n]]) -> [u8; n] {
+        let mut sh = hc.s.clone();
+        sh.update(&adrs.0);
+        for x in M.iter() {
+            sh.update(x);
+        }
+        let mut r = [0u8; n];
+        sh.finalize_xof().read(&mut r);
+        r
+    }
+
+    fn H_msg(R: &[u8], PK_seed: &[u8; n], PK_root: &[u8; n], M: &[&[u8]])
+        -> [u8; m]
+    {
+        let mut sh = Shake256::default();
+        sh.update(R);
+        sh.update(PK_seed);
+        sh.update(PK_root);
+        for x in M.iter() {
+            sh.update(x);
+        }
+        let mut r = [0u8; m];
+        sh.finalize_xof().read(&mut r);
+        r
+    }
+
+    fn PRF_msg(SK_prf: &[u8; n], opt_rand: &[u8; n], M: &[&[u8]]) -> [u8; n] {
+        let mut sh = Shake256::default();
+        sh.update(SK_prf);
+        sh.update(opt_rand);
+        for x in M.iter() {
+            sh.update(x);
+        }
+        let mut r = [0u8; n];
+        sh.finalize_xof().read(&mut r);
+        r
+    }
+
+} } // end of macro define_slhdsa_shake
+
+// ========================================================================
+
+// Defines a parameter set module. Parameters are: n, h, d, a, k, m, and
+// the hash functions.
+macro_rules! define_slhdsa_params {
+    ($(#[$attr:meta])* $name:ident, $n:expr, $h:expr, $d:expr, $a:expr,
+        $k:expr, $m:expr, $hash:ident $( ( $($hp:tt)* ) )?) =>
+    {
+        $(#[$attr])*
+        pub mod $name {
+
+            define_slhdsa_core!{}
+
+            const n: usize = $n;
+            const h: usize = $h;
+            const d: usize = $d;
+            const a: usize = $a;
+            const k: usize = $k;
+            const m: usize = $m;
+
+            $hash!{ $( $($hp)* )? }
+        }
+    }
+}
+
+define_slhdsa_params!{
+    /// SLH-DSA-SHA2-128s
+    SLH_DSA_SHA2_128s, 16, 63, 7, 12, 14, 30, define_slhdsa_sha2(Sha256, 64)
+}
+define_slhdsa_params!{
+    /// SLH-DSA-SHAKE-128s
+    SLH_DSA_SHAKE_128s, 16, 63, 7, 12, 14, 30, define_slhdsa_shake
+}
+define_slhdsa_params!{
+    /// SLH-DSA-SHA2-128f
+    SLH_DSA_SHA2_128f, 16, 66, 22, 6, 33, 34, define_slhdsa_sha2(Sha256, 64)
+}
+define_slhdsa_params!{
+    /// SLH-DSA-SHAKE-128f
+    SLH_DSA_SHAKE_128f, 16, 66, 22, 6, 33, 34, define_slhdsa_shake
+}
+define_slhdsa_params!{
+    /// SLH-DSA-SHA2-192s
+    SLH_DSA_SHA2_192s, 24, 63, 7, 14, 17, 39, define_slhdsa_sha2(Sha512, 128)
+}
+define_slhdsa_params!{
+    /// SLH-DSA-SHAKE-192s
+    SLH_DSA_SHAKE_192s, 24, 63, 7, 14, 17, 39, define_slhdsa_shake
+}
+define_slhdsa_params!{
+    /// SLH-DSA-SHA2-192f
+    SLH_DSA_SHA2_192f, 24, 66, 22, 8, 33, 42, define_slhdsa_sha2(Sha512, 128)
+}
+define_slhdsa_params!{
+    /// SLH-DSA-SHAKE-192f
+    SLH_DSA_SHAKE_192f, 24, 66, 22, 8, 33, 42, define_slhdsa_shake
+}
+define_slhdsa_params!{
+    /// SLH-DSA-SHA2-256s
+    SLH_DSA_SHA2_256s, 32, 64, 8, 14, 22, 47, define_slhdsa_sha2(Sha512, 128)
+}
+define_slhdsa_params!{
+    /// SLH-DSA-SHAKE-256s
+    SLH_DSA_SHAKE_256s, 32, 64, 8, 14, 22, 47, define_slhdsa_shake
+}
+define_slhdsa_params!{
+    /// SLH-DSA-SHA2-256f
+    SLH_DSA_SHA2_256f, 32, 68, 17, 9, 35, 49, define_slhdsa_sha2(Sha512, 128)
+}
+define_slhdsa_params!{
+    /// SLH-DSA-SHAKE-256f
+    SLH_DSA_SHAKE_256f, 32, 68, 17, 9, 35, 49, define_slhdsa_shake
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::{CryptoRng, RngCore, RngError};
+
+    // A pretend RNG for test purposes (not random at all).
+    struct CRNG(u8);
+
+    impl RngCore for CRNG {
+        fn next_u32(&mut self) -> u32 {
+            let mut buf = [0u8; 4];
+            self.fill_bytes(&mut buf);
+            u32::from_le_bytes(buf)
+        }
+        fn next_u64(&mut self) -> u64 {
+            let mut buf = [0u8; 8];
+            self.fill_bytes(&mut buf);
+            u64::from_le_bytes(buf)
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            for b in dst.iter_mut() {
+                self.0 = self.0.wrapping_mul(29).wrapping_add(17);
+                *b = self.0;
+            }
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), RngError> {
+            self.fill_bytes(dst);
+            Ok(())
+        }
+    }
+
+    impl CryptoRng for CRNG { }
+
+    #[test]
+    fn sig_len() {
+        // Signature sizes from FIPS 205, table 2.
+        assert_eq!(SLH_DSA_SHA2_128s::SIG_LEN, 7856);
+        assert_eq!(SLH_DSA_SHAKE_128s::SIG_LEN, 7856);
+        assert_eq!(SLH_DSA_SHA2_128f::SIG_LEN, 17088);
+        assert_eq!(SLH_DSA_SHAKE_128f::SIG_LEN, 17088);
+        assert_eq!(SLH_DSA_SHA2_192s::SIG_LEN, 16224);
+        assert_eq!(SLH_DSA_SHAKE_192s::SIG_LEN, 16224);
+        assert_eq!(SLH_DSA_SHA2_192f::SIG_LEN, 35664);
+        assert_eq!(SLH_DSA_SHAKE_192f::SIG_LEN, 35664);
+        assert_eq!(SLH_DSA_SHA2_256s::SIG_LEN, 29792);
+        assert_eq!(SLH_DSA_SHAKE_256s::SIG_LEN, 29792);
+        assert_eq!(SLH_DSA_SHA2_256f::SIG_LEN, 49856);
+        assert_eq!(SLH_DSA_SHAKE_256f::SIG_LEN, 49856);
+    }
+
+    macro_rules! check_sign { ($name:ident) => {
+        {
+            use $name::{PrivateKey, PublicKey, SIG_LEN};
+
+            let mut rng = CRNG(3);
+            let sk = PrivateKey::generate(&mut rng);
+            let ske = sk.encode();
+            let sk2 = PrivateKey::decode(&ske).unwrap();
+            assert!(sk2.encode() == ske);
+            assert!(PrivateKey::decode(&ske[1..]).is_none());
+            let pk = PublicKey::decode(&sk.compute_public().encode()).unwrap();
+            assert!(pk.encode() == sk.compute_public().encode());
+
+            // The public root is recomputed from the seeds.
+            let n = ske.len() / 4;
+            let mut seeds = [[0u8; PrivateKey::ENC_LEN / 4]; 3];
+            for (i, s) in seeds.iter_mut().enumerate() {
+                s.copy_from_slice(&ske[(i * n)..((i + 1) * n)]);
+            }
+            let sk3 = PrivateKey::from_seeds(&seeds[0], &seeds[1], &seeds[2]);
+            assert!(sk3.encode() == ske);
+
+            let msg = b"sample message";
+            let sig = sk.sign(&mut rng, msg);
+            assert!(pk.verify(&sig, msg));
+            assert!(!pk.verify(&sig, b"other message"));
+            assert!(!pk.verify_ctx(&sig, b"ctx", msg));
+            assert!(!pk.verify(&sig[1..], msg));
+
+            // Hedged signatures differ; deterministic ones do not.
+            let sig2 = sk.sign(&mut rng, msg);
+            assert!(sig[..] != sig2[..]);
+            assert!(pk.verify(&sig2, msg));
+            let sig3 = sk.sign_ctx_deterministic(b"ctx", msg).unwrap();
+            assert!(sig3[..]
+                == sk.sign_ctx_deterministic(b"ctx", msg).unwrap()[..]);
+            assert!(pk.verify_ctx(&sig3, b"ctx", msg));
+            assert!(!pk.verify(&sig3, msg));
+
+            // Contexts longer than 255 bytes are rejected, both when
+            // signing and when verifying.
+            let long_ctx = [0x42u8; 256];
+            assert!(sk.sign_ctx(&mut rng, &long_ctx, msg).is_none());
+            assert!(sk.sign_ctx_deterministic(&long_ctx, msg).is_none());
+            assert!(!pk.verify_ctx(&sig3, &long_ctx, msg));
+            let sig7 = sk.sign_ctx_deterministic(&long_ctx[..255], msg)
+                .unwrap();
+            assert!(pk.verify_ctx(&sig7, &long_ctx[..255], msg));
+
+            // The pure interface is the internal one over a prefixed
+            // message.
+            let mut mp = [0u8; 2 + 3 + 14];
+            mp[1] = 3;
+            mp[2..5].copy_from_slice(b"ctx");
+            mp[5..].copy_from_slice(msg);
+            let sig4 = sk.sign_internal(&mp, None);
+            assert!(sig4[..] == sig3[..]);
+            assert!(pk.verify_internal(&sig4, &mp));
+            let sig5 = sk.sign_internal(&mp, Some(&seeds[0]));
+            assert!(sig5[..] != sig4[..]);
+            assert!(pk.verify_internal(&sig5, &mp));
+
+            // Any modification of the signature is detected.
+            for i in [0, 100, SIG_LEN / 2, SIG_LEN - 1].iter() {
+                let mut sig6 = sig;
+                sig6[*i] ^= 0x01;
+                assert!(!pk.verify(&sig6, msg));
+            }
+        }
+    } }
+
+    #[test]
+    fn sha2_128f() {
+        check_sign!(SLH_DSA_SHA2_128f);
+    }
+
+    #[test]
+    fn shake_128f() {
+        check_sign!(SLH_DSA_SHAKE_128f);
+    }
+
+    #[test]
+    fn sha2_192f() {
+        check_sign!(SLH_DSA_SHA2_192f);
+    }
+
+    // Light round-trip test, for the slow ("s") parameter sets.
+    macro_rules! check_roundtrip { ($name:ident) => {
+        {
+            use $name::{PrivateKey, PublicKey};
+
+            let mut rng = CRNG(5);
+            let sk = PrivateKey::generate(&mut rng);
+            let pk = PublicKey::decode(&sk.compute_public().encode()).unwrap();
+            let msg = b"sample message";
+            let sig = sk.sign(&mut rng, msg);
+            assert!(pk.verify(&sig, msg));
+            assert!(!pk.verify(&sig, b"other message"));
+            let mut sig2 = sig;
+            sig2[sig.len() - 1] ^= 0x01;
+            assert!(!pk.verify(&sig2, msg));
+        }
+    } }
+
+    #[test]
+    fn shake_192f() {
+        check_sign!(SLH_DSA_SHAKE_192f);
+    }
+
+    #[test]
+    fn sha2_256f() {
+        check_sign!(SLH_DSA_SHA2_256f);
+    }
+
+    #[test]
+    fn shake_256f() {
+        check_sign!(SLH_DSA_SHAKE_256f);
+    }
+
+    #[test]
+    fn sha2_128s() {
+        check_roundtrip!(SLH_DSA_SHA2_128s);
+    }
+
+    #[test]
+    fn shake_128s() {
+        check_roundtrip!(SLH_DSA_SHAKE_128s);
+    }
+
+    #[test]
+    fn sha2_192s() {
+        check_roundtrip!(SLH_DSA_SHA2_192s);
+    }
+
+    #[test]
+    fn shake_192s() {
+        check_roundtrip!(SLH_DSA_SHAKE_192s);
+    }
+
+    #[test]
+    fn sha2_256s() {
+        check_roundtrip!(SLH_DSA_SHA2_256s);
+    }
+
+    #[test]
+    fn shake_256s() {
+        check_roundtrip!(SLH_DSA_SHAKE_256s);
+    }
+
+    // NIST ACVP test vectors, from the ACVP-Server repository
+    // (gen-val/json-files/SLH-DSA-{keyGen,sigGen,sigVer}-FIPS205/
+    // internalProjection.json). These exercise the internal functions
+    // (slh_keygen_internal, slh_sign_internal and slh_verify_internal).
+    // keyGen vectors are (SK.seed, SK.prf, PK.seed, PK.root); sigGen
+    // vectors are (private key, message, addrnd, SHA-256 of the
+    // signature), with an empty addrnd for deterministic signatures (the
+    // signatures themselves are hashed to keep this file to a reasonable
+    // size); sigVer vectors are (public key, message, signature, result).
+
+    // SLH-DSA-SHA2-128s, keyGen (tcId 1, 2)
+    static ACVP_KEYGEN_SHA2_128s: [[&str; 4]; 2] = [
+        [
+            "2f896d61d9cd9038ca303394fadaa22a",
+            "24ac5ec1d86a989ca2196c3c8632419c",
+            "1a05a42fe300e87b16aee116cb2e2363",
+            "58e2c3e62632c9de03d08a535a0eb7e7",
+        ],
+        [
+            "87dd614ea188940d93c7df9943caa283",
+            "cbdf2ccc4940e7007b7b6c799e95e160",
+            "bbaca7b93fec1a097e115f10d18e6611",
+            "2aa087f0eeed95be3ab301ebc812406f",
+        ],
+    ];
+
+    // SLH-DSA-SHAKE-256f, keyGen (tcId 31, 32)
+    static ACVP_KEYGEN_SHAKE_256f: [[&str; 4]; 2] = [
+        [
+            "758161eaf6db91c65f185b26fd490afd808e987b339d2cbb5b3045bc2ed9a33d",
+            "5a703a6a2a687a8ae1883a9fc957c26e17f5569d5a28c144780df013c9aa8324",
+            "0a0ee387983fc9fbe9d5b80a2787c39c6fc1be3364b9d1fd0c6dc4edf70e28aa",
+            "397dc9d892e41418fcff892135d8b33fbcfdf7ffb82b62c7cd2618e18648151c",
+        ],
+        [
+            "ad257c491cc25283ba16bfad348ad683f91bd4a12339c4e2c4f756f3f03110af",
+            "657adb93a06c76f9f0100b0b7b3c6455a9e2e52c41d451cbfe3f957973a4a137",
+            "f06b9e280279b3912ba1a940e5c719709d8ed5ea5b688b96ee80a830ffec8c9f",
+            "5832fe33091b1d4ac0d7ffe9527e799b9ab15fca8c76d64ae0e6bea4de38bd5a",
+        ],
+    ];
+
+    // SLH-DSA-SHAKE-128f, sigGen (tcId 40, 85)
+    static ACVP_SIGGEN_SHAKE_128f: [[&str; 4]; 2] = [
+        [
+            "52c2840e6a38c6e55eeabe79de18b361b96325ac9ef94882d72739b2ef2bc552\
+            e4be8ce1413df2d2b6eb37bb17405eec21ec2c89539aa5cee330045c58b9b2f2",
+            "20714bc7219c7c68ccf619072251bd756516d4cba27e5e042a46491b621479f1\
+            2f5c03ada12a68268d268cc93df12ba806208e580ae3be231fecf289c30a8f59\
+            c057f94ef9c496f36edeae60a27df11dd00c25f16e394828abfc71371ed6043c\
+            872f472e0efbb42b12fc91520b691f9024105fc74616880786074aaeefa8f000",
+            "",
+            "3dddc0f004dbebb2ee1199601d8e450f3cfbb9d5c9a6ad0bc6e5367d9df3b693",
+        ],
+        [
+            "1804cb1eaabd94a530bd7556ed83c37b2af21e2528df4106e38a765efe2ab987\
+            fa1eb3b6be7e3c0ffd720c7698da8878d0b99cefa50474cdd10d7c6a9d278203",
+            "42be4fd16bc2a1c53d5e7352246ccd73c9f9f95b55ca3779c48ae7c9a57e6556\
+            dd9acbcb9d8281115f21e9e136582f17871ad552d9a0150e8aec54ecd401e726\
+            3faaab5384548d9e4cd3fb6bd9c1661876bcca8ca5a40188ee50202a21cde55b\
+            8cc3a29d71a5d451a67af6db29045faa1775b9b95c74eae2471cf959a6331843",
+            "860b959515e69f3804e3bf2417e4aa9d",
+            "b385c42fccc5c7b4be23d8520f3d61fd046aaec715e8a0d45959159e3d1e1023",
+        ],
+    ];
+
+    // SLH-DSA-SHA2-256f, sigGen (tcId 12, 58)
+    static ACVP_SIGGEN_SHA2_256f: [[&str; 4]; 2] = [
+        [
+            "a6bf226c31c203276be4ee7cc025ff2ccff5eb7e56f74201e5e296df30a43a01\
+            de78b0d3db09395612dc12672880735fe4710e3f9f3f722f1c4ce7e1b8544a7b\
+            7e4871dda761426d693f97a2f9d2de92192b44af0a9008b245ee3d9634db5083\
+            e04f40147b963edd14560f61f9c04aaccbbc0485229ffa8b368384502242d8d2",
+            "59",
+            "",
+            "1e24262996a73359989c86a029859f5c28a680c3075a7312cd2fff7eb8002372",
+        ],
+        [
+            "b1ed3b7f0cd0cdf4e6aa612bcfa1a0ffa7334b580828d744ccbd9efd6d8269f7\
+            084cb0cd30789cbf964f9b946cbdcb19983f85ae640dc75cdfb71eac44865d11\
+            ac1187a50f84f00d751d807db4f2378d6f3746546472c2e9e0fbefc4faf676ea\
+            629c7f31d141c32b3588a96ebfc0ad4a34ea8aba16876593b5435cca8805008d",
+            "7b",
+            "623aeb6bd4ed8159698a98bd96a209647942eb29d182dccf2ad3458e3779047a",
+            "e0cdc29f09a86dac627c0f18c687c818b323cb81f8f7d79ea0f26b2686e102f6",
+        ],
+    ];
+
+    // SLH-DSA-SHAKE-256f, sigGen (tcId 27, 76)
+    static ACVP_SIGGEN_SHAKE_256f: [[&str; 4]; 2] = [
+        [
+            "c4a1367fe57e09b78634b8c50cedcdf02d34f9e0ae2ea334a48e34e2b4b57b19\
+            fe25a9b5d9fd2f46609ab0e3d4ec26b5ef6f1d5d8344062a3100f3915e076e97\
+            817c27f66117a6d4e6e1cc4a592f8e999928b173bee2bbccfd9cd6f271166a01\
+            c53725e672482e3b3662f335bde0cf68b92b91ac3b8861d8acce0d5173d02404",
+            "5f",
+            "",
+            "7cce3b90f25c420806e6aa897d546428d96879759c9862bf53600e7fde0a6f23",
+        ],
+        [
+            "9738ff7964964ebcd4f3b93843feecd3eaad7d5d0be08ac5fc493fa434c00629\
+            c683e5a85677eb13709face1e5600ff0ce26fbe0486dcfde117749aacf67769b\
+            f86820d2bebcf7d6eeb6539e6973c727c1f4ab4b6865ec748940dd15e6704851\
+            a9bed3a43ab98534d57820f41d2c069757b478161e81bb50995fc4d5f05a3fa5",
+            "d7",
+            "53b781211e108192fe271971e12fb6f768243647f470d7ce12b93f8b97f6a74c",
+            "9021958d8fc68ae1b2c0e3a84d37852eae8364892322bf5ccb833d8ce5e15438",
+        ],
+    ];
+
+    // SLH-DSA-SHAKE-128f, sigVer (tcId 37, 41)
+    static ACVP_SIGVER_SHAKE_128f: [(&str, &str, &str, bool); 2] = [
+        (
+            "b4b0c44173465ccf542085e7c39d5cf04990bb3dbdfecfaf04b82c2f2cdddb40",
+            "42d11ff34155bffa271bc9ae0167987d2e55a412169ec1218860e98b655fe635\
+            a0ebd95882d06479e05c1518aac31af4538d6db5f2f0631b2aa1c3a5cea0573c\
+            3474711c5beb0a9c0b970379baebc34c06b82bbc9b1aaa120734448c6c6f11e4\
+            d6cf046d1020ac7f4909d09d036a506e2e3015df852d0c39e2813c076a54a5db",
+            "ec7033ba6d0ef53a4ffa8fc379f2165f72147f15090638e4ad29794a171d3f79\
+            27bacaa73a936d1c46c009bd325320ce496e7400bf7517c1f0f2939a070d995d\
+            9d33f5df225f094046548a0dd0632f9dfd0944ecc38c16d458bca57ca663a8d1\
+            05216487d405cde9278b7619c2b174ebf6e8831024d1e2edf481b53530b8be02\
+            793b8b2739d290f353d8764c7749d1e88ff2a8a326c19935fca0b25638618eef\
+            90746d98961e9fd2a2230ed85b96355abdc090abde7f277f3aea30d97f870782\
+            0503d3adec0efa88a5c50f4b3e97c55e52055ed3d44bd48c81f3e72ef347f815\
+            9c6fbdb31166ce5b6519c87ba0f0ae975281bc9b50d5dc5981545f9dcc0c6480\
+            749a2165696bf3fdbe98a23868d73dd3124cd73fc200500b22e350019279b31f\
+            1f1f77567a608c3ef9ac5cb673ef6d69ef01ef42b23644d2234aa2e0d032a62b\
+            35b586b5cdef973a9574541a3619735d6555d20f411ad2ba44d3d1dbbf4c6dac\
+            8d8045b574d9b65775f85ac846ee980d018b950ced002cbe98e39b3a71785e2e\
+            42e00ee280f45ddef97f1d7eea3483f76f40597eeaad8916f139a18a8fe9bab4\
+            353bcc3e87699cba38805b1b953912bb4072f6e57618857f0952c85d508c7d5f\
+            8a16f377e9a81f1e9d6ddf0cecc1eec7ac96d10a92477dd8c419960ed4a8509d\
+            697895b79e146347b06050955203945425b748e3ba1cdfad6116b1331acbc8d7\
+            d5de00f7b0fb2077ae1e4450e3562d19fd70f74849147eab17a13d5b0b747a2c\
+            e36155230c630c3640cf651e363365ecb39791356db3aed7de7a0ea1cea3ab35\
+            17ed2eca94f3769c133542a6b9f03cf70cef594af3574b88c96c4c675e57dcfe\
+            685762f4933b1e164e0e043813dffd3b42ed2eb5a40950056324451dd11836e5\
+            808e24f0207aea5c61c1da651724042bfbb20ffd968b6e5deb66231dd7c1c502\
+            6aa6d09f487b37286ea533e71a18b89cbeaf29d19aa3106fab0a022b62d2ed47\
+            8ba4ad19c4f72d2b085d6cd8305512003c63e8dda816834d85d53f78b01df291\
+            65864d22dab63fa69ad0ae8201a19a3dd429cf5c993202d2cf29725a51567b68\
+            6277f693739ff77e4cb465b74c3b9762fe41d2a066972a528030d6e1dc748c06\
+            7f0730afcb5f6b2f50adf4c75d20faaf0fef25ec4d4869dc136925ec0a9d8274\
+            2751cfaa49d19d20da320a6f0f063b863686cf49796438e4340a87500b4b4c81\
+            756289bd487f3b8377b9563c6fbf785121f08d4c97ec16fd1bdb43d98cc31fe7\
+            b5e853710c716adcac90f1233f5ed5c0208b43b677b7fb3caec406a364489539\
+            33b81ccb1dce2917f9240ba4d2f67d0deef9efa9d220e86a6fb9893e18a9bccc\
+            35a678ff708628593da91b305128a95b02ad07e8912163b1135eab291b29ff84\
+            ea769443815337822dd44681d5e8ba848a2c5a54750470bfc939b9cdd8894565\
+            e4bf0fe7911b24d956a7f604cdc2e94d09abb07aa4692573d022fdf0f2431fbd\
+            da575479bd516eab7a460b83ad457e0ca88381278b0a0d1d776d87d9343c24bb\
+            f8c04af73435ec54dd4f1ced6d372b4f3a1c701f5cba0b9fbb1bab89d94525e2\
+            e79c2226308885ffd0185fe5f616e8dc87b2924b96b7481648cf28a90060db05\
+            ede12378965def3c873bd5314266e5e1514f8f8c7c507f368c45bd4098d0f318\
+            628763aedd12b11091dcd422ef97531d609bd8ebcc98cdb87cc67141d89f25ca\
+            7c72d1c1e8f356076014bc0cd2cc8251b8e307510a533079378fa18b2986eb9b\
+            cb1207d497a5be703d38be58330893154a8b723b21c00cdc928ca98f126a1795\
+            6fd5e1fce6d0237b912e61787cb2ec7d266ad2e4d6c3568329e3504240a22bc8\
+            bfac10fc94bf7e464a2fd855158efb54db18a2e4114f2b46dbc2afe0a93e56ce\
+            8788d927f2086d564fc3482b077d7c5bce7574d072216477c881c548d00896dd\
+            0ab8fd1fbccba27d44baca1778094ebc97880a92b1490ba6b30f8de5a6da41d2\
+            1db06aab23ea5c9e0448ea44f6489fe04c21fa319700f97da02515767327c0f4\
+            e35c422ea47eccf3e5fbe197c2954ab5ddf07593b4ce8d17a3d338d047dd8c1d\
+            d3e8911e86826c9710e8235b00a0138cae1892f62d01e5e93eaeaef54b0ca05c\
+            118f3329180ab62138c806ffc50192d8f6be93bbb67002a62693530e4de3a1b5\
+            394f84eef3bb7a7dfc2cc102ca5a7197342bfe18bc7d2d5926e58d350d8116af\
+            f2b73d1e0a1de07922540fae9471d29e5a89a1f7eea427507bab663e848e7118\
+            039ded87933f057f4bc7df8a97dfd750c82ea14d443b9505a67335234bb31585\
+            881f6d9bc7b6365c75a8ac6b2b9aaa8bdc84702680ed3f7fd32aee4841a28d41\
+            67769ed9c96e2b37921278c6d190bfc95b4cc9934573c630b23b7d216a95131b\
+            2a8a9e5d9629a18e32f9d143502e2148e80ac469efe860f9dc0405762b629b6c\
+            571a7c64009bc617172fe768b2d1ba7a4bb9dbffcfd6f8183c806b223a77ef8f\
+            92d1fee20bac9d0799d4940126a977209b37989adb243ecd4e743dbf863db402\
+            7c47d7bed2ed596794ecdb604ab4f7f7a9946330e959844d0084c2a7af3fd023\
+            405e04fe3b247078f82cb1aa684d8127e44dca9dc92f77a53ee473a3a7289490\
+            7c2a46a34d84b9776172e16b6b23f9847606a43f7c2589508ba81f75f07e5d45\
+            8edf89cc9fb41042767ff3b225e7ea929c2e4bdd93702e282826796c7832df9c\
+            7460f75cf77a0514b9b2d2746e5f459eb5be030734696065cd269395b5c09563\
+            d2f87f2fd0829d0423a85d8185527d5ff258c06592c03f1341a30179cb06e441\
+            4d3b01d5d68429929d51ba73174c332c5c44a6c028df24a372a9eb5f9bb46547\
+            0d895ec631e3baab7e31c215524bcae48a54ec059ed6cfc4e796e5c86783027f\
+            ae9e692e3d91279dc2bff232f7b9d002864d9b21b0f87a247939802f360cd193\
+            7f3d999bba4ba897b04cd4a06df66f2da7a597c47bf36e39499b0973f970ca05\
+            1d4ec4f2d00b6db2eb790b4024c592d671ff39ff0200db4f07a3c1d344c5b285\
+            fb1f319b4d5f078f69e8881d161df8c80ce39b2762e69218bfab667af0b9f7cf\
+            cf38e8bc7a980b877fea81b2809331bf9d6432ff2ea865b08c1add87b61dd0db\
+            98b6f795c392655b9cf6d647f0e8b0c84767167165f663d705455659aaf830c0\
+            6518890267d07b824f342fd2c7e5c82857813d5f4d494c67c3a595067158f59d\
+            9d5f24ba002082cd4b00cc98ea31a844e0f76bd278822c55c0edecdada6fd2c9\
+            2d3408be28090153be74fc9693b7c272228f1867daca4d6cc62a5182dcad6163\
+            8800e7b63dd1ef53b446477d738884484d968f9be39f979103043db994ee2883\
+            3768670acb7437088404504f6d9c60d8d55a61537f5f72df36ee3ec7531af32f\
+            c847a330f0d9b2d9bfca509738b9a249c1e9f65c7b89f1b1eca24d159e59caa4\
+            e6ea54a6b659c0e5832c4218e18d2e20dbb826421ff32325a07b42a90146bcb1\
+            77db5d3dac8a25c0e5af59aa05043a6ad6b1f89b929837cea1d59644c0c020b8\
+            65e09c4f69d16c2c861025da66f127e2d6777e7397d6d5e7bf21cc73da1e6516\
+            e28211abe6896d06f65f592f32174bda4f5372a43c1d250dcdbc1ea543b613bf\
+            bb72942021193df5a3bb85bad18fd9d48e29ba758bf071be22eb2286b0f08032\
+            a47cc947682a0c5a6595d6f41c65c6480c5fe5b6d128649fc31039b1c5bae83f\
+            9b446f5237fcd26f9374bd0efb828825f7b48c0f0967661181029b8401ce73b4\
+            55c95e8c6e5d43b5e0baa7ef78878116b6ce35cb1a6f051cce89785ebe9c85e6\
+            48bd98a8a850a3e26d8f721b13d921666e2ff17f5b49618f147cb0cf61a0b841\
+            a2564110941141129b8a191a840edc7ace788d3d2ff59d8b357fcb000f4bafc8\
+            23466bee29550c98565b7b2b9ddb3bd8846a6f13d61967121057e0657ab7242d\
+            06e831721b90a4b806c0e5fa48d1057ab959f0ba9d1b087b6e1b408313ddfefd\
+            54f59f6794a595416465d7c2fd57a27ce9efb7d96b15e5e4cd441d5794447576\
+            7099e9eb8f7e42ba68e9c8b1be4900affc3f27510b3c6e0a1ac3482a6c70ffe7\
+            fb16b29e9b5988693cb42b090c69f42f223bef90902f5a9d747cdfa49f7cc539\
+            529ea9728b502da96d410303a81ce3e519eaf15562c7291ac64f321d63e353cb\
+            dec326d234347e7f8bb34e7fd253f52efed0f827aa5dd382b7ec4eec65e301fe\
+            6a92335645553a2973d16b7629515041897697d110fc0078f1f98ed38753a8c2\
+            4e821926a6e62dbee3cf052f8f96055d1c188dcba926a7157cda84f60b25e3c9\
+            c896786b65201d4c8413de20c27c73491d93232aa52014081516098d80713078\
+            52dd82c55b28f19d36105f2ca253f3819fef627747d9599229941387a546e816\
+            0318c6a8479dd073120a8b88a7c0a412f489be0e17f5e62c74db8a40a8f0d7fc\
+            e0b7d920ba7d7e9a7e21285b63f8f9bf3f4a0bcdb1ab60cfbc230502f59a9e66\
+            1d5368a4ba74f6eeece7dcc6c439bd92da92e7dc3ab34be4525a25971f6ba897\
+            8603e85290c8c500da48dfc4cd625451839859d179155a760f6ac3b0f972ea88\
+            db33beb614435a50d8deb3f498c67214d24ebf76da3a84083eab1f17489252bd\
+            47ea2e3432ec491c38945a585e6aeac23cd8357ec84740669140940bcc9f3dac\
+            a5dae6fe75fe049b347550ca747f9048c951fa42873569a69eefab09a8a5a9a4\
+            67b01306f3ce67bda0339b28c44201bb15a68ac0d4d72957c3b45ef4969966cd\
+            e3cba09c2f37a7e543459fd6e8a09bdb26083b81a74e357731fb0046a7910c3c\
+            0ba6b292cf26f4daeae9bc35d3058675f1043c9d4aad8dc8532a6c5a664aa947\
+            3e74029f20fd4ec895e5c8086bad189792b1e1652c80dda412eb389aadad3a6a\
+            f39cc6d6132a3f55774f64b1d954fbf617d6c5fdfa003257a094f99722258f63\
+            5149823fb9a830448e5267cf996edc1feaf2f72195ba5793de1ee24c3b0ed9af\
+            744bc7751a9e99dcf1cec83e9073573cd19d54e1c45c48edf55739a8c47432b1\
+            b252cec388f1d5222fb9452cb82af3742a00724eb781c74bec053ee0156a4a37\
+            89c9339bf1ce501917d57b1669e68fd52a514a2423be383c22f6e078d2807897\
+            7d9f95c41b56755f47a02d79c6b1a2cc5cf87f2acd2127a255d2b5d4a0b40207\
+            4ed0639578c053a52e98609e469d5f20b432259d62859dfebe5b38cd382be71b\
+            3727a630f85eae6d4d9ecc493e8a0b4e2553a1a762459d9162bf1492d6426d13\
+            cd4b2757fafb043d40828e0c8cf7cdb1d227b6433de0ae84f6d712380278ded0\
+            1732deaa055d17b6cee09b30f3bb52163173255872d2e2104f2ba164e2ed07e9\
+            317b40a6070c62d627dc9a042f37476dc5cd00199b4de2df25cd55bd2f303b52\
+            e28b10a8a1f940df32719e075353f97f9334e180f6f074ffcbc3b7a7f2d5a67c\
+            6092ee102ac4d6116f7c24240c155bfec04005b8cef1bec22909bf98d9e429de\
+            604ea50cb0e47da4aca69ce028887b1db080eb4f6273469fca2b5994812f7477\
+            52979047306b14e9a00ee7ff196d0c8647a5170f571b0994fcbff56a2b1731f9\
+            d3e0f9fb9c4d7d5d4b59e868243cb8fb8769a779b1452a6c1592853451891389\
+            189d3c8a9df630eb0ccb405e98deafdf915a655cc31117a969f8d4201afc8608\
+            27a188077de199227e0094eb41a3e2d8b01fac567e7ae246a6aa5ea8948478fa\
+            8d6716119f5dc2cb70d65ba93ba73adb08564d63630c9d7ab3d072532b999723\
+            070a797b3b6927790349175cfcf460075d59a1d22ddab9b97e2036a63685500b\
+            368960c5f4bd4214eaefb007c883c7ddc5e31712abbdd555fbc6a6b4c487333f\
+            20e75505024fb72e6751cf900fa7a56514d941480124561bd911479467c9baf1\
+            e785b634ba074a33abb72e209819cde067959b041eb07cd6e1a2316b13bfec09\
+            5dd62767e4f26481a639ee3514a17686fb4f5b765db58a3261282a94dfb13c02\
+            49f493a926f7710fa0502f788cd928e24a10dd6f329effba46e4e3f8d5e98645\
+            9404408968f0b6e80fb1ddbcd66dbacd33c23e523306ea2dcc919bbdbcf5350f\
+            093e36aed3d278a3e7d203ef77d4613b98ff6537e9ea343d7ab9d8338f4b0882\
+            f96f44a4ae5d2cc923b32d87b9bc827827871bbd01b0aafbe3cac7f3bde70f4e\
+            fb3e0feafafeecabbf1057c7477900576cad8e57ab4c88d261d6c5ad1c4545f8\
+            694190aab6d77c113e7fcdc89eea998701150629e861bb8f117626edd62942b7\
+            d452cff1507a3f4d95a78baba4d70c143f0b4185e6c50665540b7be124613c8c\
+            722cf95b91acde61fbd6ab9c2bc1d03ea1b808652e5cc1688d25fe054f477886\
+            9520bcc434be7d2e77c9b02e1d93ee060c28e5f6c5d2e0ff15f227764e90fe8c\
+            43c5f3660b45752e227c6a90c6f5b28ca1c76441c80337d2d818c7303f3db769\
+            3eca331069b95cd0e295be6f1d06549ba17df57dae62c04dc630d1f80dd25823\
+            f9d243670025498fd929a4ec1a0d3890d168d8d954c3c275deb37ebe9400b7f8\
+            aecca40055c9c38bb1b651734a6c2a623a820e0012593a60ffa0fcc620412fbd\
+            4e3df9ee1b66c0fb93e49aba1ceb6e867148dbf727615eec600a383232168f1e\
+            81c5f7d61966d2a3a7a5b0ffe13e7d8e5b75b55cbb98773c1beceae76e4c5fcd\
+            d1c677e443484440243fcf4a49800634467e215f797e9fc0e05180f0015a2508\
+            d98ed53624292825076ad51a3e587ea927be622ab1be8cacd837a2f357447bd6\
+            140292df550f6a14f883eec6b147019209e57bd6038a8834685eeacdb2f6128e\
+            3c41ac0c4567fef23c06db082e445f634fb89fe0901fd5720a7d6c1f362c82ba\
+            4ca9e32b5b3add9cb9fec8ae3cbc125b5e3f88e735b6b28f90af13db98cf2ea7\
+            5c949a8691d4190735684f621130ab2e2b8e9fe331d63b3ed559191447c6c217\
+            83fabfb4dcc700ba79437d8a7e9b4685335b25e90e47f36555195dc4155ab2c8\
+            b29ad8f9e95895b90f49e3967931c72c8002984ef9156593391f82d0f862343b\
+            fc377b55ff7a637d9e354d129b9da2c85fac9b4b090b7460e54568604c22a585\
+            963ed9b0c24de6d9fafd010c7800cae453e721ee0990a63841857ca40be90cb8\
+            f51e7ef73b678ffdf57b796938b20fee3d9edc305c009d513e8148dbdf436642\
+            77694a45751ad7f7e4152052f41eee1a3851fcf1152fb6367c19bfc753dcfb28\
+            ea881c567aec013676a986b51f05ff66b61605b95531779fc40d6f01f3f5a01a\
+            9865d6c1209d71237e795f2c66565ef30efcf0e3cc0edd5c269dc8b7f4e81bda\
+            ee7f90ae996137a91b8694378010454b6e8f12d8efa864df9a14bbe548f5ca47\
+            a6d9ddb534b24f92462a52b084ccffa68438831b5baf6b72b9e3cbdc78dd9344\
+            84e1487422acd1ae51fd782e2ba0a0ed3de9655713114d7487a4480e61a28702\
+            cd9f5252b37b785b90dda4395d63151b7912ba56c2e57a4c52e2ad7d402d25ee\
+            914b9831b464383357fc1ad64c8ae0104d5a6c53fe3553a649d99dfa914bedf8\
+            7901beadb5b936e89b502054368ab2b3db479921ca40c6599ee33776e9658c74\
+            ac0c0969184a8090d4720800a78705db6d604ba95559adc2f0f37a1422fb7f71\
+            30249da1962761425fe7c8413124e459fff6a6e34194a34aa21a15e3b8e67dd9\
+            261cb8f75979f4a9c384ebbcb95943b0d7dec8c389a7ae065617fe625c28ca16\
+            756dc4d6972bcc42ae99b6dd98b25d8ec7bc528ec869f57200d60b6ca0640896\
+            c8349e5896f51c55764b7eab4169656982bf729a219d6b97d4e633f377d6f821\
+            b428766a0704593aae29427ac31b4b232d3884ffebe2cd642ffc43b3d670e14c\
+            18a68afbf1b3a04af07642a828af6e071d7933fc570e05a34d86480eef1fcafe\
+            da42826703cdb25215bc988697daed560ae9eb55addb5fcbfaf3b34ef5cf8923\
+            87d0a493a57cc2ecf22a7f14ccc454f7aedf497e944446ecee642869197bfd05\
+            8b4d4106b5d6388973f804c888a16cd062c7e6f57ca60bb9c649dfa8f2b4473f\
+            fbec1b422dd27126b3dc3192471687d4609afc943d5edecf14a99cc64b482e30\
+            5d6137d39b79273db88ff8431da5bd868caad13dae4bb3b0b7d296c9b9d9c196\
+            49bdf864ddab1dbc6c31ca46d0cc1939f55a016db5e4faa2939e5e9ea6e79079\
+            b3b040f5968bc6686e2f422f14e5850c1a77b66abb9be05f055040c11ebc2553\
+            7f9af4dac15f3bc7117ab326e47b2f0db70c417a5cccd39322929c38d49e64a7\
+            41af0f5c4e17483c2913cd3508c7548c66f046f2bc123740dbfc81cfe6260447\
+            21a6d85bf944aadd6e4b0583fe3352cbb4c95752778fc79fdf033063e58c5a05\
+            7b1b7e99564525087af457462e29979dfcacdc2e907c7d88872ff641134195dc\
+            904be4ed888b7b84212d92780a8b3324e7b38b5afc5b6500757ea420619fafc6\
+            e1b510033b46ef64ffb90434f9bb7f2dbb912232c70e3f1a71492c3fee9c1f52\
+            dd20c59fb7e5edb0e8f4e56f1a4fbf84ba28f394fd1bba89bfcdc20f496b4451\
+            0a6e6d8f3a2c1aa2cc7429e81049c206e932c71a5aa4c18e4c76714cf77976a4\
+            43862c199d4e840524bf24aae05b16f7222d86c3fdd8597644e81765a1bfb5f0\
+            8b076d2102a7a0ed3a05be59eab73caf225fd4dbcf9a4b602d8198bcaa1d04c2\
+            272a11489199d38492ab7e91cd535be405dd9b32dd1baac0dafce2736bb08aba\
+            3ab613d12179ade60c5c65641fe0d5cbc4439a82d11dd586b6d377c176ad0132\
+            07ac73c6f58f0a42312d80115f26ce46ced42f940d87942dc434c75696a60b6e\
+            e1b25a9c7be9c9693a0725235c5a723ffd7959f5984998297f4d707fad432ed8\
+            b4517ca02130a4479d1f8a36f2fff94bcda20c9cea463db5a49c47f5aa59236b\
+            5c21acfd30d2487c5c5e2215977bcdcba328ca9827c1cf23bd1767454bd6fbb5\
+            a9d08bf10275369f1c4d9a3e7a3dcbeaead56f30f19c3e3451cf00751653290f\
+            0893c271d75946db54030848089a2dcbeeea16152009c383683b88466adaf072\
+            0586df8eca44c4447916ca3228bfa072213a90af9fdc45183c7affc13f0cbc6c\
+            dc16c2bd77210878e214452dd4046a42fc6d2263ffd0d3ad6c7ee175dc62a38d\
+            e2ce5edaf7e5210ff678ced97eaeac2034b434657b2938117e6c648313d0929b\
+            db221071aaac67fbe275f251b50265b487d5e58ea402a608e0ecfc82fa305361\
+            981434d9a892d48502f236c6a94e2b74e928a606d11a61461a9d949e7b09404a\
+            226c98f1513d56368f7040f2f0668887a1c1ae6013d0cfffcd751065719c3405\
+            2bd81da8107a424e9c60575ad092f7d48f468bc3dc2ddb7c62414f66efe7a1b3\
+            b30bccdf91f549cd3388a17a5c702858f034ed998f105e64033d0b302b1eb79d\
+            6fb49afb582b4a312674d18e31f3c0fd43205139af5d3ae8d5fdaee6dcd8c6c6\
+            ecc7eb41e1eb748781b184faf1603f6858d1205011c59a7777a8120cdfecf4bc\
+            6015f6f30eeb354a5d5e03f5419ef2c70fea3a081db3623b4cfbaf61fe2eceea\
+            380ed01f58b2ee41c62cf54572054bb8adcf711f5ab823ec97a9a4256acca397\
+            ffa39a062dbc3af4a7f119e5431fa134deb07a240f7edcedabce176fc97edc83\
+            89a643576551a5331465c97f55faae39e70bfd24a2cded83c94e47e0f76075f1\
+            266ea0c682448320c5837fc4dd69518887efb9bc78654186b05b31fdfda0b9e8\
+            05e6845259978450a1366fdfe30ebee91b20b70bd73634bb2cb77a1040ae7564\
+            2fe7f703fbd252dcb4a0cc643a955bcc1d42de8ac70894d8d8e8fc7f6f2038be\
+            7d30c72802df49150835efb280df8d7de3a8454452acd0fff9c7697cf88c3e42\
+            df03463db9128d674fa586dbf563e0bc8f98cef0c40dc791c4d2f3bd253ec180\
+            a770cbc9d80a845537d919c676f37fb112c6edca57af2a2bf77deae038d2df4f\
+            f40e6d52e34ae42f387f4d5cfb4f0a10a9b8c9326dc92d8395775c1731fdcd53\
+            6b5a01cd3b88683d15eb0a223c2bafbd39ab5e2c6f71c5d8e5b9e11d67691cb5\
+            f0c893dcf20456824405e42f6099b7742109b7c2e2e5b59c03cf42dd1710de35\
+            11db6f9b88f2bf97109aae754d5b11436e93cb7d99484873c1b7faa01d7814ae\
+            a30468183a6df1a1bd725fb54d4cac42cd40d4706f99cf4e5439a0da57ff2fc5\
+            2bc2c8aaf069ba1a70026628c5a6030f8b9d4eb73f6bac28d55dfc58fd462b90\
+            a7df646ec23e91fd8b7d8c814ff3fc7901a9de4c91b80d3c10123c99fc1be1f5\
+            1b871de864cd226c089ea2bca389249e5c952c5616742395b14b59d7b7bb00e7\
+            693aa2f75157eb22853d6eecd1d7ee0c90008c72e2bc3797b258d5ea3ecec28e\
+            62055c18413d46b6921dbf962e9f2f913ca86e02fadcb0a69e200737ca81e04f\
+            c0fdc91ffcb215ae02f2c16360d2d5aacd54a204a80b6d5b59edfec6c49edceb\
+            1f411a4279ec8a124d4a4ad3e7a97959e3779da2cc8d1f5b70fe04dddb138041\
+            a6412e6dda865cf1a97a213147c9bf6e48a1d512a48a82c7558462e8ccfaa2ea\
+            b6bc9408172887c7f31ee0d8042b69aee824f7cb2c77e8ad885b56c4fcefb878\
+            494b430b8e747239d7eb4da7a9196744c9ed6472982b55ad6517fec436b67bea\
+            748ee64923cc55884cbf8a07eff7086e086dab6da06faaa10b995545ff8f370e\
+            a77a6bca83953ab87409cd1021a12e1f1b1d6e106fcf19826a4d336347d2b076\
+            b8021e009fb35ba1c4a4f96ac0885c6325199559217003bd3b9bfdfcd79efcf5\
+            ffee749052896e7911b2965ab0fcf2cf5c385ab20ee82feb115e5e4af2c56387\
+            dd969d294d20d26eaca8e35448dd5b9024a422675bed67cf83f1dd9146b27ef2\
+            b7bf7fc24797169933acf8aece9206d153e46c656924a7923435bfa4d3e573b3\
+            b20b346028fc13b2f0d4c868b5e853ed742bf646d0b6ac223fba2fca6be82e33\
+            553ee77bd0f14477d4d88ca854d1aa0c9836157b8f02ed5aa9ee9088eec5a158\
+            26026278a360088ca74f47743be7a68272f4d0fdac7a24d84c620874e1ff1d89\
+            8912b56a665ed20b555fbadf7864a33a63a54ae2f1c62d69b128373f6ba05b87\
+            f18010130b2d443679b5fb0bf692780605049b47925109065ac644f79765754d\
+            849d543e0776ae8661ef8507cef03743f02281358434e5e24ebeef0b56c2bcb6\
+            6bbf02297283f0e73be7c7455d6d9a6afda7053f357a61f56e57e167515c3ebf\
+            d6d1f2c333a078ee94431c18a3f484cbf201769ab4e3385f60f92d6eaf3fc5a1\
+            d53124c097cdbe444a0483f4f98916fb9eee6d5899143fad6bafb9d2241c61bc\
+            c0039626e1bb6059eb5704efd5413886e7bcdb12b1e33fd7a78af9a2700be878\
+            e0884e00e14a377c3eb210bb5aac5278fb25611eaf7f372f37a76f8c527db952\
+            e16f93f647011582566565bb90bb1ece43c447d282a545b79e775c937cd82d88\
+            04e1e53c1a2b0ecf4d0cf677c55bfa8ef89f86436645f646c1a0ce0b96352264\
+            0cf641ce4aabef7791a3ef78c076e6cd87cc9d9e46c206bb5a9adbf4cbe24dc8\
+            ea8e7d516c0ad0ef00195d9f11dc01c053855ce9650fe5198f9a536063b08b46\
+            64069a91013d8c7d833d56f36bc2b1ba2c9a390bb84cec3649c06abccb9facde\
+            83723863cffa59ec16eb04d51653e6f7b286acfbe1df186f234e6f95b5e14e67\
+            6c2e9c4840da69fa4b26bbe9381a6db2d6d7f1ff3865e22afaab1d4867139229\
+            7e4b04888455c2a0204045ac5df27e7a1903eb69031928e81b2fc3dab7a9a6f1\
+            9dd48d4de809c9f6eb9503c5d10407ab531920a22d06d79bc32899d3b819b801\
+            2705fb3624e1ce88976313c06dceddc257c63ea4f96fd9bf3d244024cfb6af42\
+            bf42c792d58c4c421e498ba535339cc07251fea87468a264b080249cd4469cd5\
+            0bce386695595135a30b6f77ad8d2c5c5b9ea18dc3d998cf3822ad0a816cdc61\
+            12ab7d9c0de5a1c309a31bd12eb48b4c8314b3c1cd7bf2d2f23f0714aaa18fe0\
+            da7840a825f2128c5913a9a23581580f90ae39deb6d7269d2570954ebcc71a68\
+            51d8625292e882c35a988c2595e719621eec9652885fecc54be86c6624bbd862\
+            474c5a045400905fd101ad36483cda219c50c4175a367d27530d9960debf5b2b\
+            a9db80025df3313942668d7499346fecfa9508173fb07ba2d93b6b25d15848ae\
+            37d22e0849013943649575bacb8151ad2ccd208c71512f5abcbaf4e57d0b01eb\
+            72af8d61320fa3fa4de5778f19577a0c96285a5468117451be48711084165098\
+            c6904b568b57d95f017811fcab032d4a66a2eaf240e85c6f75ac398138819919\
+            2fc765fa7e5c5788a969b74d933909033ac1bf6bbdffbaca17436ce6b411517d\
+            ed2c4bcd67d3fc7167b3c27065a388be2849faca5214436a499eaef04a415422\
+            89e18e65b43d1295cf475028f5b0579fc207f70c5d8f6b6b324fa50fb48e1b60\
+            f5b3d39a17c975706c1bc26c75e58ccc4484510b8ac597199ec960c7891918ba\
+            8627dc411af85fd2cc90ba52c4ab19ea0ce1efc610554a11f80c1117465c3f21\
+            104605fae78099a3d8385d952d58a4980a52f697dcd0a4fabe1fc3af0444dbbf\
+            71a5bdb8b7667d87cbe88d0a3fc858ee2ad2486fcab230247ff57d492ab4ff63\
+            ecbe8a625cc3f851819a84c5a4fd6f6f318ad98160897312d8b839a6933e3ef0\
+            eaffb1ba87e7d710128ab8461a6f5b2bd666b6eab0e8ddd703d2f5695ab82560\
+            5a9e78417f8b57cf6ee461cf66ae2371d6b6ea129187f0d681f542276b951b4d\
+            3b37f24dbf53ca4af030f652399350aaa84a028a5eb732e43d0d9931e7f5fa3a\
+            52a5d9fbe267f25e679cbf1c539e8d452d158b1c5efcb0d20deae10a788e5169\
+            4543f7ebf15b7231578f5cd7b562aa54d823a8f7525bf8218dd056860614844b\
+            dea0a204bbedf3c12603a6f578d426dd80e508a78f11118deec52c2239ae40b8\
+            b43c0ed53969672990260aa708edf312fb90c80710d7c9766749a23bde9e9338\
+            5641bf37f8105edf55219535c14fe057c1fe033ca18d6e5a62325462cefc0a5c\
+            d9b99312cc37f84f73fb1bd01dcf4f739df331d7f46dc4b32ee209c3aa396c27\
+            3f627e254cfe67a9b5cd6d3fad295bb5e22b175ca0fb90f9fab4177e186401bc\
+            ad00a5bea54fc9cf243f57ebad379e817a7c2d87814446e85a6824e29cfa0305\
+            f6380689229dfe911eeeeeb71345b0f20c35b1c0d8b2963763ec0b51ec26b333\
+            14fd86a296b0c8ca110fdceb1f71596f9f7a83d521e355aaba64f1257c66cbd5\
+            17c048efda2cc922c4777225ffd4ef42a4aeeb5862980c7ed18c1fb6b720ca71\
+            b1e0f8951b63d8f7eb797380c0a94044f4508bb7ce88ce32657db81961521036\
+            d268c952560b2b1ce97b0a96a862d6f43112877163210436d0c6959eca2d7410\
+            7661097e324790b53b3eacf5a522e67c20a721c26424ec49a63e87b45c61947b\
+            9b19c7286525b87dfbbebfe1370bac2b33786ea87ce098a9cfe68919fdab58c5\
+            934cefe401bbb1f62aad0cfb68c5b16283d887cf76956b80887d66ac080d59cf\
+            d73aecb9d26a8ebc893011a7aa93759b231bfba0b97d2e39cc84fa05c11cacfa\
+            aa374d26d8d4d404bf7833c8de6b6a8942fa5dd82747e728b352e31827a160af\
+            48c8cfc41b9b4680116d945fa3ff6c3f00024d9a6d0ade159c571dcfc4ed4593\
+            1f445129c87f5dfa90a753be1d00302041632018dac11cd7a654fafab2708b41\
+            2c74b97e223a326c0041a78c29d841f3755eb56fa2c9c3a2f59a9d09c9a07eab\
+            06e00bf63fc6774320500c168d6308ac2a365035a98582c072b9ddeeabc7a862\
+            f0798685a3cfb005ef88f8ffbe62448091bbdd18cb0b84aed4f856b4f89a11b4\
+            b69d473c4699eef787fb3d97dbde7d15af02d51130fdcc61b7455056d7047332\
+            2cc47888c31ce60bc45b15ef2bbe8b9d08a68aeec073991642737a4783d670ca\
+            6ae8423d8bda13dca21ffdaaf47b80b2925b566cab6f527465364ee7ac5c4893\
+            7c88f809642ae9dea8d8bc4b2c4c00cab4202bc500bb9dd2ebf9c63554304dc7\
+            f098015a016299a79d60d1a225e2f72ce4ccce1c1681d5239f9a99346e368830\
+            1d2a7d78028cc05d2badba41dfcb9a0fb4247de9f0dd1cc5eabf64c820dd743b\
+            094b40d71ee43d9798105ebfffa84501dff6cf1fde3ea4dbb3bc609a9f5fbaa4\
+            72fa750dfbc4bab53d87de65a626f0838d3975dd019ed95ad83339e8f30b7c77\
+            60019e92c608d4fcabd01ae260cf3aa7496540d3c6c3fff4229066ea693621ee\
+            dbee0d0f9e6e711d635177bc64807c5a6fb8d4902f460f5c158b31d35b47cd84\
+            ed774f6ae6c16086f30390fc20848cfbff60bba646c0dac4078898f79adaa076\
+            6341e2e944f46f68b00fc28c2ee8c0238835969acf00cc5ab755cef98e14b607\
+            d9eacfc2b718965f42fd295e34c34c3bf3ff2464c42b2efe8787970c4af000fa\
+            1adff7e778d37cdf378327df5869a4fd90fa379e9d1c8a115437c02c6dcf487b\
+            4c4384f5d3e2e78df0ee6efb0f57aae12894b8ede60a625028a5a23a2552a3eb\
+            cbe9e3c5d0a9c76678099e909f9c2ac4f0b866bd36c08d5a26bf601197f55ea8\
+            26c46553171a51ae76547d04466fcd5402660a597ea80104ae68a7ba2c7820b1\
+            30e880f7ca307eab45376ff344e02c5e2495a14feb85fa9078f434d601c17ee0\
+            08716c46c465f9a70b1ff775a776b16072b14671c1153dc6d4842eb991d1e630\
+            34bccd8f5d5cd4835c069ad3f1e220792dc7acd7f9dea37515405d40db73e6b5\
+            696e9ba36ce200b499a191226fdadb61e922ae8e87b7b772ff5776d307ab0513\
+            ce882be6fa7d9a9d8eb4a5644c4c6a13ae1ccc4b1b0754dd3aca47cda02cbab0\
+            43ff6577930f25993decc203f454d05429ec227f66cc4327be86e5cdd6210c64\
+            2ef7791a5b840301f075bdbf4acc0d914756bc42c29f599a319e3d08444e082a\
+            633881b376ed321c2693a657b25045721a3bd8ad91cc9bc4c6109cf09ccb6dfc\
+            4ca5b17b8f61a90861818aca5ae57a8872fa5677bf723d80dee083a4a93962a1\
+            49eb7c670c588708967a78c52caac49db119ba0037f46494b7aa06a2838dbdaa\
+            da22c615dcb8b993870ee47b89d09b984f9987dcd466e916612e34a542da9e19\
+            72898c306813aa220a1d7daa2c4393deb03b41999b4221feed52300d1eaf4340\
+            cc6a8f14fe1044977101a2380dfc60120ffd88a912abe47956dea0972db77846\
+            e185b9c76f5487b7e4dafa78271a4fe79b5a000aee4e75cdbb137223afd83a0a\
+            6f561cf1dceb56c6e6cd21058d666d45f8d5f87360e047ff4a9005ff0ddb4c8d\
+            53b76cfb7fc78556f87151ecd03c30979e3c8eba8eafb28b324d14a10ba7e8f9\
+            c8364324a62042101a9fea3953b4b936868e801b60154eb617b91c44b4229c8f\
+            456620b28d910315d8b65da48a8317ad7b55fbc5f84e1abf9b0841c9439dadc6\
+            b5e4d89d8d8a9b796f46649b639df4dccca9cc209e81139fadfc1c6afb460720\
+            6759e9648d1ed5a96fe95f06756bee9027ef94e5239b262e6822ee3954fa1f48\
+            9714b16a6537eecc370e6a4e77eb93f9f8dadcb2200ff178d1eac70e3cae8cab\
+            3249056c6707e9ba306c7b23392b6c61633e95da931c6b6d3e8f9b3db6668b87\
+            2c5728f991c0cf834328a83e9281ac13ba6636aaac538ed50be6a3d818b67563\
+            f8982d53afc13985dd4debf0af8c06b7e04cf4517370d7c394c4844e0752e30e\
+            dbd0e5acb5d03843fa386cce276007f91c24b2ea2723aeb65039a31a33d56538\
+            161a89397771ccf745a2d06e28f4092b4a7d77afa9dcc696d04a978e8cae12bd\
+            adaa6375a73a02795ee439d7a6a25a311d8d36ec0ca844ade7fe40d81cf7bce8\
+            ffc0e4d5fee0f79f7a18bbd6997a72ce06f107aa738d08a3f458ce76fae242e7\
+            4b129b51896e11e083964d4a2fc17b0c5dbf9749981f9768eefb5d3eacd288cb\
+            f85a7b56afdda545331fc32aab0919f0dbe5e19e0142dcd854e412ba94b61e9e\
+            9fa6666bcd4e5c3af031b891a9f1772a9282eccb2588d6ccb2e7a6d151e8332a\
+            19de225d9e71c907662bab9a828151afeb5f7335a8ebdf515b35e852d16dc8c1\
+            bd4f2c655a5ff96d37bbcf269fc54483658604e87dfffac7ed8abb61d01d39e7\
+            a97cc063ed0c8ae944122538798dca705e606b9aaad37368eee7b92406c433ee\
+            f277accd04f8ca574d2daf44a98481c6d2874d8600105e55cdb749417ae91843\
+            1330544920216fb30c1d2fc4a570a21864f0ac727ddb4fa711f417601daee1dd\
+            50e105745b31bec46fd8c58989641ee47acd9199afab1b6dffc2f1fbafb2a3c2\
+            88b9e4276ca616ff8c84ba0ba5ff0935526aeb9b956175a7c5bf80dadff174ee\
+            99bcdee4494906300efed79dd37aa9e206c5b1b3f39a5493f92a767ac8d34293\
+            0503bca638dff1e545e677a8d29dd2c8fa16f02703893341a0ccc45c7e34f54a\
+            b46d8b6c4a3ce1b09d6ec88f4141fdec23680ef8babcf2c930524f577b4acd4c\
+            4e8b44bcd8d507230037df55cacbf34d0a171f6b5f3c46a15db0cf7cdd4241f1\
+            1cf971d42dc1a9a56ee5d50ccda1c366532454df788298f2958d95930bd37260\
+            b5987b3f025f2fc544f77c6e61692fd74ba766dee06fb5013dfa686d940deab1\
+            7030559958f50f180f72346b34b3526c4e596e47e145c2150388400ff61ffc5c\
+            7f6736a1481bfe83fc4988ffab0dfb94fa34da7c5ff3f7852ede86c188e2663f\
+            b8d5149a9cbe30f5c87e4032e6c33c2a88bc3b14add39489c83c1a2a67a3908c\
+            af4be783edb7bf31ebeb5bff6bf25f916b58a89c27983fc90f981ca0d1080984\
+            68e62adee3cb010f01f2242cc6d49d8adec79a759ac0d571976e558a781caff8\
+            d92029a74f6bb7a2e896f0cd1fadccdb1196d1607716df79adc535e4878646e5\
+            259a65f7e4615a4d26371d84a36cf2a8aae5b29c534b353d2f0d4618f356f1c3\
+            7f698750ca43fed1dfc2f60be876a8a93f07e35adabcfb2283f3f2ffeca235e2\
+            c8f8e568b81889b3afecdfd75fb33979acb242089df426caa43a4f86aa3f3d30\
+            05118375980e49b468f300a3bc7bd35890239ed9e4a3614931b261bfa981d764\
+            b1a1c1d7192b8095165534aa792e179b330fbddc99aa2980fc73771244b5f04e\
+            895d62fbd1227aba0e30156c3911ac08615dd3bdcf90dc5a468386c3a0ec0d66\
+            437b4806dae42ba4214f88fed6f1538f45304e634a919da29f4f1c9152cab545\
+            210305a55606f08a326b2ea195a9d248e013ec1496c70a89385ba9aa847a090a\
+            0aa628ee9698d90e2909b95aa5cd6f322fc1b9032b637eb1dd01fe17c71966ac\
+            5adcd7c3c0abb050e78c519febf7518b1a11253791a6b8ae5bc1cc390e770590\
+            c496778fb583833df16fc72d9112712dde605ad54fac481ced3911a668b7f7a4\
+            906cac23a192815faae59dfc9f2c99d4f27de453ee4a10ca260e596051985765\
+            67d4f7d67b6c296474be883bbf79db02d24b37661f5d1a4b8d32be77c14b97ee\
+            c381d4664469259513f222b5f1747eab145321e94d3c44de0b07d3c8f54a3b43\
+            1f18fbb85fc2d70f955cbfe96b8e048edcecad9559095fd5712c465c7761e96f\
+            89fc405198d52917d0c5f59d38057a2221f47f5c7aa8cc4fc66bc27361e9c919\
+            9494d967ed7cc4a9631288b479f01296cecd8b9b10ffb98c31df3b9d1c1c55b0\
+            5d100b670c5b4d22ee8082312a14793f677e15e6e62788b3bd1e225486ba13fb\
+            331db99438284782b0fae489a1ba135f039ec4011803043fe5570c1fb2a5d059\
+            d6f2856d30fd05fe076dcb0a68dd1721393b6b61dd3a7ded04831f261e340bde\
+            6f5a7a025651085a936871ff8797d035322f73554328e87599926f092b793465\
+            322cdb0f7e091a1bf983b1ee80cba09d4cf714cb7753af79761cce4c72d42ab8\
+            909c14a18f808c2c3902a35eee4a2b1458808b8e6618f2f3addb41b717c6e6cf\
+            b86d3ae508062ebd31a7baddc59a440853e4f988707a57e997aa917cf9a12526\
+            d917377a0645a832e69d67d629ea843af315e0cbe7c7d3b95c4676cb2ba5db9f\
+            0acfb14e5f1772e45e2cfaee2060ba7f798e6186df0d48f2732c04ba58c23eae\
+            79b1eb7fa2499fed75217ba6157ef50826fc969bee33dd5b73da56a6f95a8964\
+            64c0458998d2c282e2397001ba48215e60d77f508cfea137bd33099e45ebf6f0\
+            31cac54aa3ce467b1ba207f0b7717c5b751dc62322c595d964a62682b958f4f5\
+            6b47818da2835a07bad800589a3b114220f276dabc47c0d74a83aa1f628c98f1\
+            0187d4ed0de4b0eb42ae9f254a8b7e8097b2a734323918f2e3b45024e851dd83\
+            bc7d904b0109bd7fd4e4769950c5490505b7e72e70015b8f3a76d0f88cc75c22\
+            a349e2ba36f85af0a6b2f522076580bcc60c2cfc51589b5a4f1427b0a54ee0a4\
+            8e53c4f88a25399f9ee12ac2f89cda22d7660ac0d7329ea16b1b22879ff6870d\
+            37a8056794c90f1274cba84c81f9033e66455f361c06d29aea04c9bbd6d4eec4\
+            837d8fb811c399271fac8795d67ad16d7b8acddb84797565c7887d32e6e4d5a2\
+            32c9cfa86161bde75f019dae14b3f549177f7c0f9f62ed38207f071e0bc167dd\
+            c52e88e4a959847c19faee3d72ed99accb953724227d16b2792e2e7fffc68b45\
+            0b8f1b13c5a7b69ebb2b81e8dc15aeae201064af8f063529736f69581e9cc68a\
+            6bf19f16d9432904c1a1a8b2ce76f015bfd3179e25fe28a714b3d6057b123ccf\
+            2f736c4721ab0faebb68b46c59af6cc0cce772a1901072762eb650432c52759e\
+            7bf9efd6f4e1946b5e4db7a4dbbb2e3eddb20f41699dfb8336aafb6420b985ff\
+            4043642c8a93ff7aa7f78a51b8a97c5bd2f87e6c10373d4c54f4bfe109de5ab7\
+            884947aa44014efedbd48e7754bf6ba4db731a99a8be13e48ff95e56ff906965\
+            785bb6607f5c107256688d0cd4ab01ab811ccf22f711e30c987ed8b33217cc03\
+            159ea0d3675938e0e046bad7030bf39f7e829c519b431dceab5ed628af5d6fed\
+            898c395bc7b48da8e7871cbe6906b235a0caab92793cf2ffef298b9ed1c7e543\
+            0e17d33e13555f2d9b300f593d3b60eaa71b9989702750e9d938239633c29b2f\
+            41bd1407ca1835e237afdbe86663bb905ae29b981fcd18c605b9229e07a7a838\
+            867902610b4d5c7bca0ff51a6a164323912649d94994c6abc976bc6c3cae2884\
+            ec0eee8c51a003a3224247fca84a36a695cb59e2c7b04a56cf54d7c4d5a1f0f3\
+            7ee7abb081558df8baeb3ccdc3addddf5c508fdc3b65ccf1a987d41d1da72abf\
+            46fe50572d9722f3064d55d21bc3ade602b5438e9864093bee9fba76d5a670ae\
+            99de7a5a491654d5f71a73656b10af5a6895ac71507b113ef59c162e47cad3f8\
+            d8c862edce00bedd5cc627e3a9000106782ffa767f00db555e5f65d6ebe9f2d5\
+            0de977b808df7614a343c0b1b4a80cf34c0b1fbfb6413f41b259aec3d09f0eb8\
+            0d674af6d2df68cc3934d70e1182a1621b44b81e894a665e6583dcaebb3731a0\
+            a75d9c3232ca748d710946bfb09d35ff6ca4a46f9e91bd076ca3bf9d9f491715\
+            d326fedc9e7d4c782b0baced1850fcdd72f91d291dee874b15ebb5d4f3b7b37a\
+            02644ee4e0eb57def6ccd29391f4513ee31a6bb7c0d6eba16e74afcf8434f94e\
+            cdb9d3cfb03cfac3ed97177bb28121f3786382cf3984da1de8fa8b476a51520f\
+            6363d0373ea3f02d5f41272476ddd867abc40cfd6a0f5b244c190f41f1f0e5c4\
+            176539fb6a76439e6a3078ccefc368caec46ca20c8236ca6c1d8c1dbad8f7e24\
+            1a8ba2c4667fb5ae263d4aab18dc840f82da54cd87662f75dbd6771647020773\
+            a6bf2ac80207e27195c0cd170b642ac5a9a2c2cb5367e9c8338751989bc7214c\
+            670d9352776eda434528adc2475b1318a1a89ce8332c0e4aacd5cd33817830e2\
+            390e92526230b00417e1be8e5aae098e7eac202ec8ef47103bde02e75cf08ea4\
+            ba004188ecb264e95e86c7d3a4a530a68e8ee88616f753c38d42029f8f09a8f4\
+            0529c348959f66c7115e940d18ffd210214b5e28b3d28a3c0afef1b2d83944b2\
+            5ed46b45faafcbe73c14b769e46eff2576edd9a8b91d1c8f4cc562b3c3c6acb5\
+            ca12d5df3f65648a528e18eeec8ae139edba942545bc98de06ac2b97f64e28de\
+            269a55fee15e1a830572e097cadf3650b043b17dbbda7194358da998ac3732aa\
+            34858652ba6d0ec21126eebc60fb3f4c20ac5089a59205c7d3a2d52ca0abf850\
+            657da06656f0c79f2af57121e91e1bfd8acf9cb58cddb1e66146fb095ee18959\
+            9a368cffafc0079dc577f7d9d4458dcd4f5165c80364de0c78750b372b32fa8f\
+            ed92b0f786b06bd6a1d149064cdfd3434777268ad6fdfc810d5e24a1e43817b7\
+            d5136ae75826367a2cb5100a064977045e7173ce622b2878484c6ddc635d3532\
+            f50374f2824421617ddcb3ae84e99e7d5f22083ac4727175de9fada3e8f3a07b\
+            8a431591b6d5913e23c6b5c4243a15e07fec5fe2b387664848f2735c4fd734dd\
+            de96f5c71e90237c9d5fd9b2b794b76f0caaf5e422197259db241e880beefedd\
+            fc3fc30461c6d5cfc6b733a5eb93a70a289a0cad31db7e658d9b12b999a3ac47\
+            d152507280cc28fd8cf139cdb0885e22403ec3afe7b8494982fd7d27d3e96f8e\
+            0eff828717ade5a1e92d1cac004eea25facbbdef19f5b74e85ff930573a0a3b5\
+            02651f2f2db45f46627a453e37e5beea0602839c43c1d9b53d1d76afa52d2dab\
+            28e1f60ad305bdf0c05273f830e3e15bb8478a62294560e41db28ec97a480243\
+            ac1ab7dc4beccf2be3fc6429ec82ef5b48dc5145ad5fbec21fee5d4168be50e9\
+            a6ef7cbe550ef2df61923a1ddec86173e7a97279eeb4cc3087160a7fc8683043\
+            e6f56f277ccde3c741621e3b68e3258ac1ce1d82e17f8adcc258cd739ed6d242\
+            611d9842bbc59241d61cc12f9984e685714e23c8b8de34d375cc48c36f1e868b\
+            84de5547cdd04e94f1a289853b5da4a8065a8df576432c3059ba48e112379623\
+            776eb703ad378a14e582634d51a0bcea7e8c1ba853987c1de15b21cfa404b2ce\
+            6a20831238486e195a001f06aca3abd589721bc01c0215ea30fbf9220581d82f\
+            618f3387dcc0bd7e457ffa7304bacac664f824fe4f0d56cd9d5ef3390c1703e1\
+            54150c7a5bab509f47cdaf981214494dcc979b7289dce39d19f5b6f084401a95\
+            6debe81cfd7eedca590b72a4d6c3982893c70af2d259effd8188b49eff2df369\
+            b7cd694d1890c8a903b27b3aa87b7e95ae3d50bf734dc775c331bb8b94fcbf68\
+            74869831392828d62eb11858d37f641c2abe7abeabab7c6765f079a9ad49fa52\
+            7316050bfce2d033f57c12eaef860bc57502560a98e9f4a94c6cccd6674ad7fb\
+            a5c41cf2c0c465a2b351c7fb8b9a8b6a7650d153db4cea1939ef1198a3cf4b34\
+            eab52f77275eb18184443c4121b7003a4e73956598d854a812fcf67eab2c73cf\
+            a08c1fc9f374d5313cac0918b912401e9d8be13450f2e8e1649a1ef7b3a69221\
+            bd20796763189ac353b7e20ea5d55d75365e376f9c0ab1d0d858451640df8080\
+            31bcfec2d6c4c7c43efd033c72ca8df0e0b77d71cf8c90ab683a37386b1239c7\
+            7b3aaad4a5e9778f68953a653202fbccae26a4b721ed5d4b7becd0df61462552\
+            599e6892f2ff0e4fed7673eca3245ddcb06956410fe2ac17990ae52592a55130\
+            35b79edd0501d7f41e4e156803947a65cd407276ba121b07410bf2c8780fdcd5\
+            222e7e24ef3347d87f109b8a467630831617afe9bb3f9b8f4f9e0f31019d08d8\
+            d79062c3268601cf08e4303c70368f6b2869ba343b30a05915dea201bbd4234f\
+            26f18a01bd7667681aaf0aa17bea0d52115ef50df92125811c69768b67bf6da7\
+            c58ad58f6103bcbcfcba3a21c10e62baf90c7a4c7ffd3dc306c3ddd96c237e61\
+            1d3f4eab76d86844794cbd6f07713525cf82dc0c4a5a264a59554a762a02bcad\
+            c4eb31eac697474217bd71707fe4781b9a060d1ec5ae5fb7bf010b39b2f923c6\
+            e5814f534779a52961fe98b16f7106c03743eacd50cee33a1128ae2e3066f198\
+            1c2a678f833089eb20b276cd015c591a5778f6d18471c7e2af28d83d192f48d0\
+            27c6b07161d7a91af3d5aad72a6557cbefca1868f2fac4142d57d633e9fba1ff\
+            bb05cf3dcd6382a6501587dcb58951ac3a20bfde916f080ccf5ba931263c1e86\
+            5f3c0866660e029e8949177451c52b8fd1ee5c5d43bf2bee8ad3ba570ee749df\
+            c92e9d5d94c1609b948861349749774f4bf05399184df24cd1da3a769129ad5d\
+            88dd54c346663ed72262ccececfec28830408cb5b20678bef58601c16bf457dd\
+            f80ed8f5aecb6143cb6d56336eee627bf421b1b53decc0cf66d7154144518044\
+            2075472f6dc806859bac57aa68f4eb9421e857df2b765bbd4040532ab3e83f08\
+            daaffa5745756f96ff602d44819321ec9e2f3bab537f29eaa28f05437336de3c\
+            5cc917e0578da5f6aab892227a5b031646b7325b4bec235976ff75bb3dfbfcf2\
+            704d84b5de1981f778832e8cf0fc5b80f9b5ed6197a7c00a0a1d032077e1a501\
+            1810e5d015cb990f74bcd0e4f0560922d26fbb36198852dd3976252c254585db\
+            3c2ef23e55555492cb61bdc4a3660fb3e2d01f88c3b80455de9b46fd616665f3\
+            adf7b21dd1e411963462f93b7b6f44b17eecbff38968f70258a00d10727169a6\
+            18a3c38e0e67ece115fb5f4756e2e5fe3e48708a1a513112f017d6a117436283\
+            3e32d4ef281e9456f11995352c84fba1ae6c9d41e3466afb76bb902c8daf4274\
+            64e5023135f3d7578664406ca034397ede3401f14bb0620e6659504785adbc3d\
+            adf3924c73d191189683e229c8e01c6ba3778b2212ca78ce9c7544a583135dcf\
+            5f008382046f6313dc1ffcc170c9524e30523cad001cc5f1e9373999cfdb9941\
+            774433c878383fb805a3d6377745fdf3392ebaf21f4054fdcac62b3c844f1646\
+            6d92ff5ea406af3a29d3940022f83f4b40022e93b6a52f7fb5089398f3e9f6b1\
+            5dec49f7ade6f60d0729588907710a67033e787130531a2a697e5d695bfdfc8d\
+            9b176e97fb2c18ddee29356403daa4a0ccd34d5743dba715a46e34c67f5deca9\
+            e74232f13ce7069148937f279d34831780f995f579540887f73bc43037135798\
+            21adb68819eb6ed600f3a82e36fb3e31f99ce9e4f6edb5a960b65390e9af8ec8\
+            99e4fad2e30719a0fd7ec6c1ecc4b01aacae63cdbc273a4e46926e8bade80404\
+            f03a8b54fbb51e13809cd3a06ae6dd6325f9a17b0f8886bd35fb572af4c98142\
+            f839074b87fc0916e87b32a5f0e20f8f6ec1d1dbd52c02e9eb3ebb489331ccc9\
+            3c749ec7724ed55aa0c95655681188d4cf71df18f0c0c3ec42741df7bcbb01c5\
+            9c30803f1a6e140d6b612c074e75b9025db5e850899966932135e9c552fa3903\
+            180343ff8f9679f1798b4ee7638c11845dd8211c651245a0999c90bccbe1365d\
+            cdbc2aa7f844dae40df951d08036f1503ac9675bc82d8e1f8565d05717eb17c4\
+            98840404819f4bbce294460a4f4336a224222c2215fcfe1effce8db39184d5a9\
+            d46ed4147c9f228a67acc7d1b7aef6fc527f8058b89117cb57cd0841eff93e9f\
+            be8c22e1bdb6d24bb766975470f362657855046983b3ecade63207db3bac656d\
+            b10af1869040fe15888a1b4fbb725ab2a9a608dbec12505a199db35b26e0edd4\
+            5b789211c0f0619b7a1f22600efa449b23bd7890c2beab0006d13077ef647a28\
+            96e5e3450dd1f3ec5a8faab163a632a5fccefd2cfce7ccbc4dd34a3a04f0828d\
+            3144bd1da813384fe3af0029ec2d50fac6b2f047823677ebb63d3130a48f8e0f\
+            c2388fcfd99b15870768456fe489a5520f4f5aa1364ab270c97857a2234ff19a\
+            876022012ee02c323bc66ef8feba41451ebd26cc562dd7dc09a0b6e9574be546\
+            d308ed6cea764107a1a53a15b0d4472a7898c9fa2ff57636b254f3102896c1af\
+            212e88154ac116e987fda018546304547a2078f26c2970512af664b9201ee580\
+            30b5b64e65ccfb728c26b6cb60941240ddae00b22d622a90b4235c7c676e8b9a\
+            7a9c028a72e9716e977d3f992d99c17cb04266cbea6fbe2a0472592c3357c681\
+            2f751708607cfea6814c4290a8d4e8aa58b4daf87e4cdb150cf90f16ef8fadca\
+            be5ce7c05f9c41c8f0687853e0ec260a353127b0427270c4bf1f1d9aa1ca785f\
+            14c34d1823846807919b776bba552442e6da14689d27c736c1f12e08562fef68\
+            fea334bbf2c796ab8972eac822063910b98eb22c26e7713cf0ee3185ff49169a",
+            true,
+        ),
+        (
+            "91791f4c814c157c917e06c6fb4f60d9981ed3379d6cbc0c80f260e142b636d6",
+            "5fe734580c941d38ccce53ceab3eff8e5260a31df6bfc04461d86fe6f583de98\
+            caeaed5170dc868a8d9e126f4aae379869f2573875ec924d38a434921fabd7df\
+            3d9e7ce3d76881aa981809890df8e9966b8f91cc621c3646cec5b9f1871ea397\
+            2720683444a227fabfba54ab326012f296bcb1c242ffffa5d2c186ff634064ec\
+            c33cea1feb7f521c51d3cc62c1080854c41d31eb0b67d3123f53b397bf79ce8b\
+            1c16b33bb5d70fc7a7bc2a15113ff98559457831cb810377779ff6fa46fad97c\
+            e9dda1bb8e61993d4ffc6d0edf4ab1364dfa9198f5b868bed1419c4f145ec415\
+            cb0960c3764680eadd6eea3340d4b914ef0b590b10a4864aa017010e4c77ab39\
+            30aee2985fa042a605a13c7c0309d6d34fe8c6c098f4c4aea2bf64b8ed858c1a\
+            eaed3d17a709fcb5cb",
+            "7a86da7460ebf2837d3dca896bd29a46b3e4c9fa9cec9bfa3bece9d6ba38846c\
+            ebcecdc77abad661c10047d08ba779a61f61c18ac25eb599880b8856fce47c2e\
+            a645c9e0eb5a87fa43ab865333b2087551df88dbdce79333298f3993166519e6\
+            385870dea6e76ef25a6b5198baae6a0128aa60e0863121d15adb91eb6e1ed330\
+            957d4d15c6947d0144b1448e4a5952de6236af4e11d3c966889ef4fe6f2816a2\
+            2e27051c120f5fbe2c80995fa23dc32cdece8b1a028303a3fc8c5ae0f564f3d5\
+            757a61f23cea31883f2cbc5e46e5b81cde67026968c6c2e3c88552f05a05e84d\
+            8736c05508b0565b85ce45b8d463fe25d2a51f8d80aeba3ef89ee11b3803f540\
+            46dabfa38eae97137ac2abca07c2ddbd590e6a2c5ba8bcd3400c49a1c92d50a3\
+            d7c3848b26b7d844052fef4b0153098c343ee90a3aad1a121428282c18943378\
+            6d597bdc8f965f20b5eef7b45c3be8dcb5d2f2dcb9a89c201861629d5922b8d1\
+            52f9c26325f20eedbbf5a8b10e7fba79685e2fba8d09722db1eb818024d6923f\
+            301e630825b55cc29f612ce67f22e17da7b8fb5d533c5223820f91fee535d5c2\
+            1199fc8870d907738e63f851212edbefc6818cf91d0a3b7ba0ef939892304089\
+            e1306e13a7501082f937b2e20edc31ea2e1d7d1bdbd3c5caa921a2205eb35571\
+            461b037d10a7fc2a84e06fcd4f0fb615bd33c04529dc9eb25e94b588b0088260\
+            d37491a7f86aa9f37c259686511ddfe1780e49e09040bb2743c01cba84c61451\
+            c777c8f94fa6cfb18ed1babc3bea0bb90909d7ab677fa9a9cf9c5ec9a3196038\
+            25dae9deccc356797e3ff2638eaedbd95b0b4e7b426eba3937d8496db94ffba1\
+            ced2bf49631d5fb1f44a875fef40672ac37b3ad5721d57a469df053cac7e7eb6\
+            4bbc9ebb60274d3d2ccfc690300cd781c9348d5bfcf9a48725ab4bcf331348a8\
+            58683f72e72bddb45fa779196010766cef4419225ff29b3483a4545ea6fb7925\
+            5f6f0831c76fe168ae968612b074c3525cb61daf3518a9b7e177b9e8026c9a39\
+            d54967729b29b1269b1df0440b12833098e4c1cec444a1784387ad36401eec3c\
+            466264e35ee276b0f52dd8b55f04db809f943c68070af1c2643d1ae586f18713\
+            99eb06459088e34b6334abc1085502aa167e99c76529fab4c301b94c030a2b91\
+            f8bf5dc97dab6c76a2ad9fe8a7b7a515580d0a5f3c0d64d58bb4e15d0708ba6e\
+            327aaf8118f9111843a27cd3a898652e662950698378926e5ab8beb5d2d9a69a\
+            97d687933d8047c67b0e2fd098c95549fa9fa101fb5b38e86999e3897af0d59d\
+            f086d8cbe4095f25165fc63ffb2ea5bd365eb656fdbd6be81852243cd5b1cc41\
+            795b53fa2d6e490111ef555e77fe6c8aef6c0e2d2a802ea4b1385fd52f5dcc40\
+            21cf29e833112e867fa6c4ff878291355fa9bc9b735beb36d886fd0a9de4daaf\
+            65016116217abb504933b7d02b86148733544f4ea9b6cc25426e19885f696bc7\
+            3f77f6022ac9553fff4855d45e9e61c5dd5fab54be552e5930878590fa9b1453\
+            1b0f7bb611697781a62efcc840d5abc0306f4b9b489ffcc6823ab47ddfa06ba9\
+            63c699b1f5f9e827508f4d0b772072d8e30c0cf33ccb6648cf848dc07b6f11d2\
+            dc8fed3fed6f80321f8a86673d701725e69286934841a7d066c0ecd00d47a3e0\
+            abf8cbc2639c169b60fef17d9e457fbeb01bf06e476ea6955f2bf7eb8aaead8d\
+            ba76abbe93cded967255ba9ee894408e9dc5b562db0af18600dc3c177e9f0159\
+            dc44e5a8b53fe2871d7c795242f27290b7570d8aa850eb7fe19a5d00d3a46fbc\
+            3a62d19011ae87a5464c126741c42229dcb972beff43b92f60b13bc5de70a65a\
+            e0c959b13945d66245933c44fc7724b742134d5b4113f90faf67007fbb9fa555\
+            de864a4227b3a9bfd428213f311409bd2c3ed5aa7878a88d45cf258d0b0fa4a3\
+            ecfc329e7acad3a2c2bf577e83643414008ef5681d2c26286c069c858bec5bb7\
+            d6dabe5b6ffc96f374b5d1dedcaad1a73d965299d324fd0527d161d8860ea050\
+            52bd6d5b5249975ecb009376c77499de2852fb299a35c5bb8c4c0f88612df71a\
+            4de173836f3584e8ae69d0d721fa8767c78581f33aa9c76b5ae038096a80e706\
+            0fc22cf5f78b8bf00473e6eaa8ff6cbf9473c4abaa5707d550e97448355a78b5\
+            6914c57ca26088c10c59c349fa192483ed397e30af7847b446e44e2ad67fe947\
+            2458d2c182437dbb5996cf96d6cf59c8ac6777dc389e4b8bfde8d98f52104925\
+            8d2af19ce6d849e0d509aca8e637127cd5898bb4f3202bec88ad5bf2d9fa1ce3\
+            019ebf32b0786b676631e98c3339ff0f9b9b9deda5d71f5d230eb82631912d0a\
+            eb2651077699f7d205fea2f9656639ceb552b6065a998f94b761e64ca2d02886\
+            2245d52e1dca081317a0077dceacc7e41e982e45d3b304d227244ec8f9104750\
+            5dd523ef0c62fdb59647f10a0183148bf64079e9987909fec3a37022b05d6755\
+            9ab4b33bcbbd004dd88d913da15e7a169a9bbf286f9c2455b2f1ae674f88f7c0\
+            8d4624807236e3177d0d77be1aec2789f733c947016fa1a72de06ef2a553f91a\
+            208555181db3b9041618ffa44b0e31203c4213b41729c2ebf1f790ad95a3ce5a\
+            c737350d1afcc069aca698b3109135fcb5caeab78574f0fb73dc46e99e3c341c\
+            7d322f961a23be04325c233d4e9278bf8e12c86d309dcee9fa7a593ec85fe9b1\
+            fac15258786f38987ca59adaaf9bbf79a4d48f298a6a2bb374f60cde31f96950\
+            3136f521b57a924c4b2dbb93a1cc2b8d4cba9500d8c0e8078c9f4890fb39a29b\
+            b26c77fda58f3be3792a0a96ce485f90b5d88f6006c88bc92db884fbca0087d3\
+            2478120c56517815a3ed1eb9d37c1aa5cb0eb812f7d6eba9e66befaa6771b21d\
+            f577801296971db1001c0dd6c5cbc7d83707e131012d107f3c9807b305abf917\
+            7e22d67d84c34951d9cf73159332d117725fb7f202344427acc15c578cffda51\
+            dc9f83d12de472f193b948906c378e9351342edac53b1a86cb1b5a20e4a2b4be\
+            9912b11cfd3d400f4e723cafcad11bb2e528010edc6ff88b6a42584bb769087c\
+            770292e564742529e60adedf23f852b1e8cebcaffa70a4aefcb3c24fafd45450\
+            8cbc45605492bcd0ed4ee5d3f04e85951c976940077903bfcabcaa4b437105e6\
+            db39df2f178529b1d425cc4223631f857eb9403f27d351380c493e5bcb46b4a7\
+            a0304294b0eefcc224f08ff614bb1a587b060aad53da4524f94539920beb2eff\
+            b6868dac213db2812892dcd18925e4cf8b3b8b590acaa5228b2e22147284b8dd\
+            0383d1bb26a5ef92ffd1976822d1b07ee932dccee48ff45e4ae697f5bd9f6cbc\
+            a68de5acecdf4b609434ed16fd72adf9460fb77b5416f6d05d8d2817bfc0972b\
+            59a03ae2dcf659c0e45c6c5d945d971f275463f0632552fa964bf80c0d054c22\
+            a41a9bad9047434a29024f1972b70954537bb8c78e937feda13a89e1462e099f\
+            b5d11e2bdafc6f781848f608a5015f1ca96de7e61707a00d54c7746fc96c2e77\
+            abacc092d11d576c2260781391819014cbfa3c39971a74fb1abaffc900b8c2fc\
+            60732b4aabed3241d4109109fcb645da3af4ec8d15decf7a074c5a12424e85b0\
+            1aa6d896533dc5e0b8e4ef9de5d91291b4001e59b6a5bec0052ae04558c54423\
+            8d8418f86f2f1bf41bd60b543bbfc2504a176bf22860e2b1296a81b5c1abe24b\
+            4cc75203071b9993a37bfa523dc56d3558ad61f4ce709727222da1c9d365b5e1\
+            a63f2cd304b013bfb19247ca4762494eadda6ae0a447d70e5955ad8c2a6393eb\
+            1d1bef83b0156db5d5481310f26c1b6ca24c5d5660636b293d07cd4246ab449c\
+            7007eca2f9b785691353cd88a4d5f544db4059aa3662617c5e445ed24ac78c68\
+            0d788ef389da3357f7cd560afeef16bd7523f51e5dfdc5f390bbef764ac59108\
+            bc3e4afbb708e8070be81b40515cfc685885354edddcbcdb620dc6051448a06b\
+            601c1a6c3f2d69c3c4d494384d71e2057d8d566ec3cbd42edb6af2581d194a16\
+            b9686dea986ff74b740afc1e335ce4bc7af8dd22e197d14b72fa07a69e1bc77b\
+            7416e3123c21a667bc8536e461fe97fd520bcb9829b741f1699596b92ed17504\
+            8064fcf4b43272a995472e9d64537e520871e084e0eaa020a64c83acda9d4200\
+            93345eae3b05bb37af29ff4d33e8c653db6803757fba13a4fc4eccea53c050bd\
+            da575d208f8e23ae9f23dd66b56f6c2f79109e8e9468e52b0a8e08bd437d7bd4\
+            8d6a331a038509f8ce74353397da3a2d4869f2ea162d6651e7ce2e3ef5b7b839\
+            8900b588c6e6452233c40465c2bf368d561d2c8ca817577e5bef8f368c49e826\
+            35182a14f6ee9940065717958665af4ca1f93cdc560e1cc19c3c7566e32626db\
+            eafc8d2d2ab5d77b11b662e904a917d664d4207a1d727cc55151e2a6491f6832\
+            e89a56af910ab6d2978b69faea1642b714ea221e27b6aabd42e93ac80def9bf2\
+            d55d6e55e016710cfcc083ce9e2c471818b7685436f84e80ac6593dbdd75c5d4\
+            538e6087659052602f42de0413a7cb66504219c198521d992b7c639a5d945c17\
+            9c5829fd93f48b4b2311448a96be15a013e44a5fac6ddd67fcbec8c6bdada117\
+            b9b8cf5d250c3cfe36364fef3bf22bbce14d07f3f6acf4c07bb97e4246ec1104\
+            93c18de52adbd5a6a11adefbcdf6be6ffaf903ae48f6a4e15df9d9ea0894c2c3\
+            0b61809cf5aa8472b9ab210b9a02fb686da2eefd40a5d8661a77336787e10116\
+            730d0186421d02af01964d96bf5fa691ade9cc6d1bc444b850e2251afbeb5592\
+            039250a6ab54643ea22f1ff905deb501c39566f62e07aad9c01f279e2cc9fc14\
+            a7c7db0873431644dd4a5b684a756044ba7175e01949a6e5ea6feeb3a3aa5dfb\
+            80d0f5c78ee5e050b5ac380f77376d1e4c5f2898f814b633f1715e9c823a0e6b\
+            8aa17fb1f01c3f66df6848c3d827e7966a5d70daa0ebfcbf512f50170da40e68\
+            53c3a6864eed78503238fd2f50899c5bd66dbb4efb77d11ae64b085bbc4e600e\
+            0542dbc22ee7d4c71d119127ac489c384601cfe3e55deef985b10a3955a26578\
+            0712c2e48408da7b1cda1164e44e06d2a973ef00be36f8f8a36bb4effa1dedf8\
+            282d7874402e2221c1f29d87c39377a6828ce64bce99198dba1735c9df6c98ed\
+            ca5d3ee581fb79898bc7472fdc140a5c37c818759eb32f40a79f29abdd93f483\
+            551caf21576925ed4a5dac76af96898a72bdaa43605db85507384e0ee4f8aab4\
+            c3ac65e613c02a288a5f2be7530d075ff595413a3b843e69f2371c3bbba6e753\
+            e864a91ff526cf6c6a87b8d386d5640d29e2e1dc842f3303c6b3cc8eb99b5dec\
+            791ed3785cbb71bc62629e8863257da5d9bdfeb0e75fceb42e40779963537380\
+            63cb4d01e1927c1427a42d43d339d528f1f7a783fe315708ce8bb40b7d774e88\
+            ac6a425e00d644f42e36023516c360cc701b95630c408b218eaa2f1b2f48d728\
+            3a2ddbad63033de94f9425256adbcff4c7b38ed04900f863a54015e764174ff5\
+            11b854d5ccefe39536f6ed7ca413ebb5136de5f82aad5f44542b862b49c26bf0\
+            c689c3af95fbc8e3d94c78b5668e8bed24813fe5e72b812f293c7991338e381d\
+            043d2826fd2209c64be7e4fc7029b1b25ac6adb147a59f37557d790ed7eaefbb\
+            583cf1da69fafa082ccaab6fe07a99deebf2b011b1e4e6db5a55d36544e1d96b\
+            c0b083a62d7fdfe1d51530a365dc5351e4afae6f0c3fd3b301a477215d4c6115\
+            83b9d090c4343d4ce2acf349bfc70a19f6e616750b1fa874834ed4c29ccce152\
+            ae9e824e2d4c4ca05494c353eaf595c400037e9451948a0e252291d4e34de276\
+            5ee67372004099e94fcb246dfcba7a5dd768b09511608270bd7c8280cf50e2b6\
+            39886f48c8aadd62ea9aa39b0444ab48518656969e8526429fccfd01e50700b6\
+            b0c574ae6988034b17860379b092588c4debe3395ac0ee2a73af2f0397a638a2\
+            1a8b2d58dbdd1c04c02ab35834c5db7a5af2d7ce15878887a58f11ad8722af74\
+            0c53f23a8dc14319720cc8011093ad29ec45e08dc735b1decc08182b59d58f44\
+            3bc95615b8bf04b21bb5f43ba58aab71585a33d58a9400c34d9227e87cece8ea\
+            33b54c7a66470612f2d54a0b84d6c3796a5189f2216d6dc9d12ac6b7312a056f\
+            adea091049c23ca99aa954542cd6c1983d787302ccfe9eba4e213323d29d600c\
+            4e965bca476030fc46563823b853fac52b64379f7d0f6bfce18c962db3a16330\
+            89e2e1ac9101a78ca9097b12269e281ba5e32005f63651f7d723185d84725d88\
+            ba19a9b568db67fb3578a736b9bf391b6d7e6c9b9452376662593eba9ce2ce51\
+            fa5088b9914a3cd56f70921addafa998597bbbe34e2d12985d71fcbf389645f8\
+            cdcc40c578dff0111998fdf1e0e3e9a0c185a24ffe50ef60d448ba75d95a4da8\
+            a42086bfc24dfbe56f8b4a61bde18f88e9726adb54c0885c55a1785c273d6631\
+            c7d1f3e5debdbb42088434493ca4a1928e4a58e48bb3814b175aeb1f88185e24\
+            8950673cab74e08abe3598e3a422e0992c63753c8cfe33092b904afd19de947f\
+            0193c01080406442392b118b61b2d1e80fabcd6b00311db3897c021c1e00d54a\
+            9511651413f98e5c4433b371de25ead6bef38649b44d42b9842c88c05098c729\
+            a7bc2c1f062fb04777f858795dd7e33905d85e048031f3d55a23290a4c7dc59e\
+            278276455890d48a5c43c783d70c49502e27361490dc9aec9652d298011584e7\
+            280ce3778986883643a26df40bd948d35beee7492d6a77c0027fdbd78a63ef6b\
+            c0a6d2653cb55813373bcae8dd91bea156885808a6aa160a4261c83bc900da11\
+            d7e501ac937143d2655fcf77814ca1ab79b03fc48ca43cee547de9275e0e6f97\
+            a2e83e119efe170e96c1d8f8fc68ce5f9cdddf1e4f3e71f5653dd143531fc1e3\
+            8da5de20b4fae7bc7067a9925223361c66e71ec1e34051d3abfd88e579dcb3a9\
+            dc2f0cd63d4ebd09ee458b55ce2e85451f10813e0422838a511e8424e25d3166\
+            ffc9c9ad1b9daaec3c7e2140d494c8dce2096859c3974eb692c20f5809bcd2ed\
+            4e612633c0eddfee5fecdb5fb8ce608a85c76e1b39db78b814e51ca4b70d41f8\
+            3541982031cc483d7fe99fbd692d34e4123b3210cb6114a4b7d5c0ad14f24e1d\
+            845a7c05cdcc97d195ef5771dcaf6c2c33019c7b79888c75bae1a2460401565e\
+            9b136f97e822910acc77767d1d691776eedc67f3c3043e5137f6e7fefe9c14b4\
+            09438bf3dacd151007bd4536d190388075351555760016d69bb19ad73993a968\
+            ea81450ffdb3567c1d28a630b2df6760ea59f250c2f1ff88988fac5c615a4602\
+            ae5f4abd18ae84e318ca1f3b978910810eaeb614913ba994686bc84d98831652\
+            3114af4d06267390218cb9c1c00f2a0375524709f60105c19b89e9f381b3af45\
+            21210ff3ef5892060be932f9134019f6c22d555048fb47dcdc2db0d5de20cc50\
+            b914b2c7e56258b6668f255ebd68ccae75b8433e66ae0da9bb09ef6513e7f08b\
+            8688f582f7ad8fdd8c25931da7b4efefa1d54442a52685385be0c8c87e11b079\
+            ada5753d96efca5aeafc58ed1828337affbe9afcb4d6b68f3f366c2386f20d95\
+            2b62efc2efc148bf9095ae94f0bdbfd551b18dfa9aeb2cb03a3578a03c0c992f\
+            3653b3ec6a52f1747c6d679060c132c19ec9072ebf9f94d9a585fc1cba4f2947\
+            f161d346570823f367ac35368aa0eec8fb213d86e4b9286ceba927d54731670a\
+            07e5e89a491416af6aa109b0b253d20e875eae3d499898243854b951666d2cfe\
+            90e2bfc1dd82d123851c96d057b190509a3d9b1e166a1734a4619b6627b64acc\
+            45f2e26b8ec19fd3fa964f5cf956e7b89049f94b84912e7a9430360dbc39e5f4\
+            638b9725caed7e8f06e0f14ebfe724ba31c39a1c2032cfa4cdaf5bc6f950ce46\
+            d66c2291210fbcd0aa0fa6a2888d8003e93ed81ba22b47f1bbdcb01ebab50699\
+            bcaf819315e28874aaad9efdc6e5a32a5d4842b8523b4c463e6d4cc71466f424\
+            6929010715f097e1bd9bcb63ca1b0997de58e5501e89650092b490b5a299128c\
+            55346f23f9da25302e708afa9d3c82b81383047c2afdf7639fe16155d5f9d450\
+            2171bc936dbece79d426a9b3083ae73bff2ba64cc099234e05b768ab58a80910\
+            da64ae75590d6e8af674a3b5d2633652d02c3b0a6522d832d0104986ec49e526\
+            24b28c79fb24bd77b7808b82df9b81bd19ca7f63ca7c80d914bdca3ea04b530f\
+            a102237bb70d94024a622643e2f453d61a5fcedd4f8cd8bb3b432fa92754d9b9\
+            53554bad0cc0e64f9fdd0b471d216bff638bcf9688392dca7906b64f0edbc20b\
+            a213caeca539814e9005c8e253a21833551a15947e92419329bcea0b9c5f3f94\
+            15c3308dcd3770eb42cd79fcff40a469c1e0d1576c99e01bb37e6a8347917030\
+            4e0ca29e239d2e61a695bbc548195f0e0722b192a58a71d9697253366e988f31\
+            edad80af029910b34704526e4c8d85ba294a191721495252b07263748e85e4f9\
+            2b6f4da0b55f41c17eca34c9c3beee2fcaa2fc58de4c6ecb5891bc67c5399746\
+            ca76bba5a51792ac1b56deb5eb052e23d8e1e4f5ddf827488d34f6f9f263cde3\
+            aa789c1431551f2691077d4f82e33bc804960262bd111820ac399956d7cc1ebb\
+            e0aa589bf15d7a33bf83dcbf2bca09887286a29e0a0115ce71862558417e58a9\
+            ad6eecc7cba519eb92e970a4e233990d57b6c035db9d7738212929f37799a625\
+            fd1d77f784ea586d7bb8362fbb130bde6abb3c2ccb2f00e4c89fd6c89c41df4d\
+            126371b0062f8b486b246f947687a30fa42afdc777aa6b810abcd7ff14e0516f\
+            a21065fc64e3e2c1dfb71043aefae97b9c7f7c9f42b190c54475119b6f05f6eb\
+            d0187e04e516509fa59a5742b87263db557317dbe311c84b270da85b9c45bc3b\
+            fc59aa264a04cb0ea132d6850d094ae67546a4cf16ccb8c4f6ed4c04e8bd7bfe\
+            fe15edb36d82f5663aa56583ccb8e6adc06f9bd710cbc92e303ecb03412b3416\
+            b63ea12559d1a83900f236f2aee28baa955a121a860cf705b2a143ef9a9e6e57\
+            5ddb69c686fd2d18826f946d7f5fe53e30073f44cd952d83749bacada4c4c831\
+            108eae99d0fe89c73b6c534240d2d1ba8fc853f121cbeeafd2b7a181f6ae4294\
+            9e92c40f4b646c252967a795125fdedbfaedf5b9a7ecc96faa1bc2645b6064f1\
+            8fb2205180208c526f361c396a3095a81b97c10867dc15f0af0d3d5d75f936ff\
+            ac56515fe1d5aff196462382456a71c81481a56c20d6d8056ff2957f2ff1a7d1\
+            510e8847eb4d73b905e05c16909fba6930d292758e9c86674bc2132dc5bf7cf9\
+            14ff84af6dc84bb9d96e2f4bda7544c8868c3d6b0b6978ae23b9dc9b5507ac57\
+            ee692f03bc88abc357434d2b3b3e1f4b80f568476c5225524a2ab83dbee18e62\
+            5435855808d76a6d86f7b7060db4660128c3e42cde0b7b0e88e8977fada7cfc3\
+            d2d1170b3652fcdde96fc700acc49545a97898e0100da9bca49d6841c053d139\
+            ec92a89c0932833b754e0d3299d8e154ccfb3b13da341c7cc1131c185c9c42b6\
+            32d0168ad524f2c7a8a7a09567665bb69d2bb5175f2068839110770b28b6b0ce\
+            26b2f9fb35333d050f3b319a1516303f97b1d5094a3ef9af7f28354680451b0c\
+            ea9dd9f16bfe3aea1e812b417569e41f22da86109487a2ebf62805b5ce437965\
+            c4f594041cfd4be9c9442c8291727b26554fd6faf443e59bdb2f41babd2db30a\
+            6a4cbf9ab784671a19aa80f5882fb0784513b2fd17ac9ef6eb944be3f21f11b2\
+            470adf19a00771de28448b0c4b0119a888ed7efd2ba6f150171d00d6ff67c940\
+            5c02640071ca6adc7c5f241dd91882e4de52119460a28c5b17cb5b2706bc7c57\
+            dc9f093d8f938fba06fbdbb42546fa46f2119a45f24fc4ae3844d0e547513b12\
+            f29f0ebcfe220d5ccf9c3c422b76d1ce88f77393d8592e34270553ff54cbc7a6\
+            60768cc154a3b8cc9f4b06425a436df209dff577d63bea01cae734a86a998402\
+            63acead233ab69aed9dceb4dcb92d6a1399db4edb4316455f852a8d211a18b46\
+            97a2785c26f72c82faa396e3b08c8591fa0fdf048185f21859c4aaefdcf8dfd7\
+            37befe91c496bf5c328f6b6010cc7cfdb2c7c1cabab7c19c2ada50f5a4193a43\
+            df0d0503ea6b711aa3ca69ea534c56a3c8cae143678bcdaa3d1e259d625dd08d\
+            819a1c87210eea18ebb9c6d312f2d13ddd9897b06c8396640c9fa79314848e67\
+            6229478a96e05fd6330228b9c085715707289501f009c0a36472fcd6641aa22a\
+            2e96d521d604e0b637de670034c84160fe4d0bc8b5f086a325a623a5fa536cb2\
+            3c2d662c026742a82e9d80de15978e98c037be6a839bbf349c3adcfc00541dc0\
+            9d9b18dc2e1e7dafe83fd4bdc5a8e7e9a616371a796d17708961b4ca83ceb731\
+            44130237c722dbe1a6e356c5551277305c3aaaf3f3f5abb5376a693e991956ae\
+            157a3e75781dd634f29f09ff91c1167b18049097fe7a07d5fedd34e1de08dfc9\
+            507983e11d1808d45161844f42776864034261245d369c60052df85bf84f7e01\
+            e168f5f732fc632884e9139982261d999a26803d661fc88541bc7f7de6ba009c\
+            fa11f69a708951af5b9f11dc9c26b467fada48f9a9d67ec567b69840daa660d5\
+            7ddb236298aa1cfc7cf93b236a123449236f0bd034ea92dcf399a9e8a7e9a2c2\
+            2a149484868cb04e7084320d8bae3d9f82b88dfc05c8c4a6be0a214b3c975130\
+            a7a80fd106e1e932325bafe2bec33fdf4f3b254f9609a6f248519c081a12f566\
+            e0b802aa5788a15659c117a3013147f6ea4768841a4ff4c1799c92b205b1f9ad\
+            c5d938fc45c6af83fe7ac2691f1725851d7ea4f7e994324945c691de2c452f56\
+            e967195da708880c83089036b164e45584f38c6a4cf47d1473006d27b1094bb1\
+            e8e45c806b80d8dc9bddf6a705fa698e54d6926cea9b6a81f1a663aaa5c5b4eb\
+            84af1dca25181b62f2d797cd65ea29529e41e35ec007d94a381814bd0bc84438\
+            4ba7e31eeb6a344ec3186841962a215bf78c2db3fa6e15452e0eafe110901cfe\
+            5321959b4434295f20ad1ec9fd323f45d4853fec5c1bec118a34aa6d1ce57d7f\
+            9f28f3fa2b9c8c5ac5d0e4649d1d53dce330b93daca747456ba5ad56621b0cbd\
+            51de77d34959a0b585f451d0e06151a62938bd6ac87d44b1298b709797d0c0dc\
+            47747d285a18c04ce12044d1ee944975998174c59e771db6661f82ac08c4255f\
+            ea54066d22032153206276bc38f25731b3102fdbc3c42a15c2f7e67e5e14c4c6\
+            eef63611884a523fe6bf972daa3a64ba3f7d846bf2417a838ad802d9e81eeee5\
+            9295ea9a1704113abf63e1efeb7e3c68c885d2bc8e6a08891949bd1f1a5d8ea9\
+            8dc0dd9f4ae26b54d373658089aece2662fb1cf74ef28de15ac6e1b14c2a3cde\
+            e17d806e8439dd9969188fb8acbea75c47a6df5d0d015e7fe281adb33e43b546\
+            5ee147c1747ac37839ed30c1799a7b2fee21fa78fe411b1dee546dd526618054\
+            2a4debc7a9322a11509da4452e1c4e7b547a4ad6b9029c414318103c07f756ec\
+            e3ebcc2731726f255b68722fa6dc8a5c357e30d450897b11c594d04f8caf0bd2\
+            2c7b318de0bd086a9893687b86d914e903ce0a9fee667dc2e5bbea3389fa3f1f\
+            3ff6ede364dc13c788a9332295e824fd9b72e6530fbf2bdc1a4f87e27e88af11\
+            9fae7937a1cbe41a5d96d79f86baa8ed06d7ebe4247c7365f21068a93e556a13\
+            78b8c1447269eeceb1b1282094fcf5a731a530a9e6b1843da97dd1cee419af02\
+            84f3bb0b76bf1b2e10d831e9d338952dcd5beb993902f9c7cbc91872b461d113\
+            1b5e3f8a66262de0c6419666cbbd5e3424c353e5cd3118f03714c0a034848f7e\
+            bfb768cf21fc4eea160f52f6c9422d45888bc66e34110aa5667227d0b663bd15\
+            433afbde770d8ac52afb8f89af696c4dff7503983882d82f232d9846df76dd1d\
+            406106f406ec4fc9840f33af158d207675dbde7502b581e93a388e7790bb9044\
+            22e7cb24e624bc080c35b7f304a7c93c4b0cae28a67fced926ac11cb0d536320\
+            71cbf7a2523e1476a53958e2c9709351a6615be32a27e25315c4c66caaff1d65\
+            b224306f7b4b4235ac32aa64756c9d8d8becfdba70dbb85f95af502ca983b0ba\
+            7848157f804ed1277be4f29f01a844af08c7c37825df6b542d6989034f844976\
+            21cac900dfdef7de34fd6b01f5437abd11585843d959121ffd19673d2df27bc4\
+            77f3337c97c42f38a8a6f6b51060a3965acf53d6ca0138a4bac0d3a4cde3eede\
+            152a2d5ff632d4607c7c2bd5274b98355ae8aa61c5d5d48dcb84f06ce31e3681\
+            7a39de6b4faa4e7281515d232089eec6a92a0b0475f96b14ea26cecd1ba2487b\
+            c73bb30ed1bdbac5aba67479d837f8dabf2d754c37efb95fcfa3216432a87a92\
+            5f806e1bd760f25054f3e6973aa32eb91ecb05418a8087223f9affff01911014\
+            0f0ffd30fd73ae654c8968587ddcc3685ae5cc132a0d23cadec8d67cfd2708d4\
+            2252673b8a1674bbb7999bd17844e3f3b0ab3fb64426e4c6487ab7e523e9881a\
+            f816ddfcec9c489c02deb9c1b2669517842ee110d8a414bc761edb632fa7375b\
+            ceede6b7f72f7efc2a016a916801eab3d06572d4f04ce9f4508c477cf79c2052\
+            a4c1b6507c997b9dfa5b779432a090f3002c896c0cb87a575b70a96ae636dbfe\
+            8f980a76a78a280ac05387511f0fcf263fec349c7ad1df9fc22f79e892eb70a0\
+            205c03da94be71341613885e7af7bd7e49dc9444f57caa6845423ea0e551fec5\
+            a86984eac5e9e9d9c2a1988448cbc17ce33164c21979414b26dd453a162624f9\
+            8b316444ba2990c54985232bcabc310be8dd9462c323f6d6b3aafaa193e5f499\
+            d6bb80531c5e2abadb71af4304f34c8198f7b499be313dc3256b12110a06c5e2\
+            d251ebdfa071f4cb609badfc68f24f267f66c5b8549497eb52e8697ea8f07990\
+            37abd40a49bfe918fa6e999de8b265c9c557b6b8b805e0a346f9fd911edf3444\
+            d5bc098240079d287a53b6648ce86f5a1eef33fdefc0f5308cd66e62aae03788\
+            2e2bf82ed41a263fce4b0be80e60d6922c24904a8fb4317a50249472c419edb4\
+            0fe2429f8a1f42b3ab4f4a8f540f3a6a04fc2f56cd9446df9fe514d69f6f9c3c\
+            e5c4dccf4f71af00a6387a4f8a74b9e60e4ebbe4ee5bf3e4aeec2831313c6bd1\
+            a4d050d42625a56b3c80c6c6234ca6ae65acd500499686f6073c3da974e01970\
+            ac243f202c8e2d791012c799578931990726192393e9cee6af8335f8a914d8b0\
+            69dd4bf4885250a45ba987425082cc64a9e9a5afba7e18215d1672a8b7448d38\
+            940bcbda5f50c321af49789bd78803d3a78243d006c98cf48f4543d2d34a4d7d\
+            76f866a8836ebf0a2cc28c64ed5ce2bb86d88fe1b33f3274288f30cae0c27402\
+            058a1ee8c36f348a065a1e97338870c8a012b6100f8fd6cd3b2d1e2f38b5a3c3\
+            c44a3f83cb7f2e68dfcd9d444408becf8de8826b84f38181b79285b0b0e59295\
+            05da2505292bb201f185bd56a393741678e8777f6ade2a3a84b7ee109a63c9f8\
+            7567b0dc3881a9ebd486880c6ce49d3080752a77f4196019cfbcfcef3e8444ca\
+            a4d4fbc47f40d247c417ea75e9b0c37327e5794a4719c5ca82aae6d0452bab30\
+            c685bdfc64cba49650f45d30575ae737d4d2ca9987c9ec5ddf1b3dacd1080560\
+            c31e6e452cab1c2b20be42805d5c8d88e52c45024fed7b3cf969f394797ec58a\
+            e3e7be8ab8c16dd5421f8d5efac0bf237aa492ae705935cfa8168ca0bbe79de2\
+            bbd0a73ab1a42f348cd777f12b9fecaf719d8985a05adac88ce4d7c4d2b4cefa\
+            2f967691b9b32cc6ef1b4aa99edf915ee9ca6900968fd80ac51b5d3cd2cbde4c\
+            6bdf3e747b546805dcfa04aad6483c3344e148c7d44f37607365811cba048011\
+            0b56fd060ea3626e80f3d2ec30c5bd9d6a9a46fdc3a2d1a1439e63943ad61991\
+            201944505e182c263e9658603bd97493346d74bea643524dd85d953a0160141a\
+            baa79986c1e03419464f5bafe4ba0b3791a63e6d9897ff629852b8f9205c1afc\
+            ed3ef666bcbe65cdeb48d0dea3cab0874329de7482b46cfb81992a6568885e01\
+            735455eab005d3cc87e17e84ccefc122aafd0d803b7df08b9d2b110a73380107\
+            e488546158d47e873fabd4f35f4561aece7be1e3591ecf51ba433c3514f0bf23\
+            b611dd1f42613304e01c255de5a790873cebc064d72dfe922e6a9914e87a627a\
+            67b507b8c16ed095544aaaf541f8bb16fa1cd46e5ea66fca608b9c236e7c6d80\
+            7558ee46a30c9440d1ffa899dd8178fead6839e131dd81c7e07a7ee7a1bc1b38\
+            b5e75f1bf029b51c31343f84a5824194c823ec13a8381dacc24a7074110c0882\
+            6029c94c91498fc2f20bfc8788256b65ba21344c45ea5071d0f962409ea8fb2d\
+            f71a369f2618821e8fa4ae90f3500b8988de7a816d4d3917bc5da086cb68f6ff\
+            6941bd9b5c8184549bdd3277b03c5a431ba27802333fc0076ae44c8cdd458f93\
+            6984f425ae03899814747233650a4480bdde67fbc4e23af52ca383887065b86a\
+            e648eec60a60a3898643cac8aca9ade82c7c1dc94607ded6ec2e2ffed9770eca\
+            2564baa9bdbdd2004382a7b026d064745205f3a1fcc8f6ea43bde393b6eef1da\
+            282f52662a4ab430340e64e663ec262564baa414ca5d248ebc24b500cb395b78\
+            c7c099fc6a919309996c59d54dd7cb354ab82b8485542eca30c2b27f7708415c\
+            f508a90f4045dc885b970f1d0a6ce667c2686aca6ac9b9faa8bcd6f19a032173\
+            ae5feab9d4e1b5e05f8328ace65540057bce653f2a5c3ddde8101ff1f07421cf\
+            e56afddaad3a3597c4f63bfe30562acd1d27721eb2fc74914f4b743e3bc3f815\
+            131383da81f49bc867367b0ec96b214dc90ebcd92d720bcb6d693ac0b5fb900a\
+            7530463f18883d23336758c4d1d1e8193938338b77aa06601ef2485810a7077b\
+            46fdb7efb9caddf39189dc87b6fabda8525ab7cc95e80d16126a956daaf08546\
+            1ab877cbded16e0779d90288a3f3a9dee46abf30ed1a3cbe989639e995a527a5\
+            a5b168bebc9ede131d569f71688ac4d4d147d5335e1dc37e440084676cab41c8\
+            0a07b86b9403b0d20fddedfbb6a65f57720f2af6d8a1e0992885f3b56e8acd43\
+            cb1bdbb6fee22b3d69367c0f2cd3cd319321c6105052894a41e3882e9d590325\
+            af7d3c86dfd2edfcf43fb4135e944ac569ea3923a59378d570387d9d69933f51\
+            db693d68b78e843a00e1552b47edbf7fa31cdf8f9b84cb67181e0356a05f413e\
+            a6c3d6c26a0f1b9fb272fbe48a0016d29fe12f86d1b9098d84180aadb7cd12d1\
+            038eb4859738eb0f8935a89143bfdd43eb7a62f73ec2ce6a47306c121cea5841\
+            fa480ed93afab66d7d9a6f2c1c48930bd52ab00cd226f0f8280761e032625ba0\
+            d4981ce8d0dba3b826037f5e83bd05aeb0ca948c54321f6faf66b98a963241ef\
+            f3095c4c0f3949b5533e2575741a0824cdb3ba55e53f3904bb6459146f1fdad9\
+            91b86aba2dee27b0a1e2435a235a1ce74a66c4f56d51f996197f33112b84dd27\
+            95ed8b72169c607d85520a0442ce85abae8b3245845122d882be7674900d4696\
+            50f021fcc01dd677074a676ee56457ea0f170687357d5b5bad3c3aa362c13434\
+            27eaa6f7694ca11cd0de17599d3170b77d6760d3202f01f348fa1e4b042b48bb\
+            0b7d7ca23173b9778f8855ad0373fd631f7e7e122fa8f266fb275efed5491ca8\
+            29cadec6b6037615b68a9e4eea167d769dc4b9a0bcb459868455ce10169e0d02\
+            9a16eb064a6332891a72e5622f86add4464af8db778840b03fdb3faee4da1bb5\
+            98b135bd462d7cde0a1f7302de1609e05abcd94e22e8e26616033bed6fe796b6\
+            a2c65f7fd828428524ff66dd35f9b05271cb91b80ad1b88da57706a5add73f23\
+            f0e3c90a82c06dc566596ba390a9dc58cb482f6eff07157b244d1537be1751a8\
+            c4447a8704bb0c3f30c097a14296a7aa7f03e5d34748d302cb10ff635c33699d\
+            b16432700c1a43ba3f5f443774b3557de7fab36b28dc67b6f9768ec451f5fab8\
+            68d0f22ba0936f96fbce236832a1903449ad163926ce2f65f6c3e0b21bb58000\
+            f712ad563ea869ee738ae2844ff4f777dbbf1ec94e9c52932db4912025c75e5f\
+            8268d3a26537482bc4cce87b8391ba88c5668c59e015f0fe9e6c91bf5e9f29bc\
+            b0e5f78e5325741c4a8700efdb432ff55ee7a30156a7b904a2691a9c82122841\
+            d420c8528960ae3bfed002bf0de6e8af6b441c78aad13e55bfdc6727671d6f4a\
+            b16ca033d34e68156b39974bbe11edf892e0f5c1d171eb1d25534f029a533746\
+            b542bd4171d9284a9144bee72def2e44f3f1b79efaa2261ed1e7bb362716fdce\
+            9e4c35f8f1c1c9d37506f4cbafada04e6d1e6894695011fe1a259386e85a1d32\
+            9c13eeb64d791e33353b4d28818f20d5e2b4bc4ad64249ec8a1daecfdd11fbc6\
+            c4c2a3cb2ff29256b2ce293669aa41c267dde7acb8ecd31d07f5bae7235d9d5d\
+            cf08921db384d9589264d4901c27b3bb199cb2177f6125db0a983f2abe803b3e\
+            1cb1dedb53259d8746a79d13af26520ece1f9c07201e1730680bcbc2e0cf80b1\
+            1347351d0b8fce88be003b30a9e85d6c9b9eaa7e61f63de1e456729c3db1b9cc\
+            137e9148c6adc9162f6dc24e5faddc11afd2aaaa0e0f09820702851a94897047\
+            ab0aeadfeab64b1dc932764ab4d16fc7bb0f960f3b649d15b8ad8183830941e2\
+            4cde8082747a48689ed3d8239d37db45e41b956b9ed5e01c2c7f63520ad8f771\
+            806d824aefc38b99a382774a8d564dcbc8bd6c4c7804fee44e6d2dd0a8daa675\
+            689343e9ca8946f71dd0a822482dd47ad59a8bf98f98b43180c64ab38665c481\
+            1f0fb2c87f9e09df96e188774bc201dbb9441e15aa043042f3b62e42be1666b3\
+            69f1bdfbd0066ac262543a7808c3a57f41743a716ab82611c19e07338b1597ba\
+            224b2f776318b78d0a3d9c5d174a41dbd5d35e4cb1a25cb3bbbff2878501688d\
+            e82be25d080b4665be6b7ad9b2f88442f19e6fda7e9312bddb759870a562a82e\
+            3a9b3f9dbe83ce23c85a6f4365b5872d27b8e8a3cd4605f462963df921ed0b38\
+            3110b66cb2368f112a250713f30ecfd24c5652dda0d35c94f0bed1de2e9b0e51\
+            71c32fa180f8767e19f35b9c7ed5f941a222242a35022ad41576e47de76d8261\
+            5d8b234384659cf2a7890db0818e3f602a7c08153a1efe3d32f93f63a8e8c86d\
+            5c3f092ed19d9ef72bd260a8a3aa5fd8e8ed7a015dc5234b77c462c5e436b72f\
+            aa6a9de9b541d523fe24305215e7a5bb7373f52acd3e2d6bfbf8ce95d2112b45\
+            5b08c00806937a3c67c5d1cae2d5eb9c634228574b22c64128a81b940b40f034\
+            083cbfe92ba7ef93425a059bed40d2425ce4dd945ff6b12014d5aa4ecf3b8f01\
+            afdf50c77ad8344dca800f4ebe8c484c5c878993d028787d488855213a0d4990\
+            a611b5a0cddccead2041e4396d25e4279e81b81e3e376893ca9c49137375ebc7\
+            0868fa56764e675d07929b734f2c58a89303bf1664039125bb2fc8ba61ce5ad0\
+            a051b7453ba1cb2af0e5fbe09db5c3629b113efff51929bb2a00f04fc60ff5e2\
+            94af57cbad438856af4292c412e78a56884e9ab5766d8a8b08fcd204e0257a6e\
+            969f0fc294b2f069128ccd93539258d2a9c38f14a105f9028710505bc5c61efc\
+            952141cbb29b6cb5f20517b0ff41fe5c035f97ffa89e43e93f3373d33f41040e\
+            e84fe214aa3fe461ffa20e8a3d76c29aa64079f5c74ec0f7e6537a87b1a3cd7e\
+            1d35e6968cd326ef22cb4bbfe01a9645e9ea484f2f9892bb02c30c9f4c74ca33\
+            dbde97e074bd7f2b033fa5dfa9cc3b81cd4552bb0f563b2bfbb4b5d7be91ccea\
+            62793c2ff2b96dc14ceea91b5bc76f5d7f97980fb355e01275a32d5840f42b6a\
+            f787fe523fbfccd65655e34ea19db3ac989dd6221c3bd3bc464d7017d8ba9cca\
+            1cc161ec2a4f0eb863b0d31059f9a560c77e3a653144c96e79c212f3726f9335\
+            fd60992e9876d24d93542c162e01031291b2be703ffbb407a207d6d2ff4e1ad0\
+            510be3e0d845eba2533e287118eba315ad60fc5c744a6ae94a2a5343b754b921\
+            00f72e2e2d76f93b0e714ada178e863f325ee8e9db8948d6a8e360abfd7a9c05\
+            8790d54c28f6458b5cd066fb1364b6729bf36e9881725b0793c4de3f0c507da2\
+            444e4c61dd2dffa11a99d88c61bb1792987f511cf56238aa062ce07f1ec96e6d\
+            9c279040eab033c4da4fdbc57c786cb83a7ce02421f42c20d2b7a3ce9bbadee8\
+            287b1b2d17f69088fc5b8b6b1e7e3547bd1a118a7c09ea3fbf4cd966d1faec2b\
+            2efe8a3d6c04ba6c20244e426d68b3bb93c09955840a70db7f0642083b8de852\
+            d46e1e779e686514d8b72bdaea550f8afe7f4cb549f011e3ffe30c62fb5715e3\
+            9e3b58a044a75c030f05d8d57b156b72b2ffb26e540621dfd3df2752cff04beb\
+            44444fe4c5fae9701cf1036c80a99a5ffb5948e01e43af88d53a778b36983a58\
+            bcb7583e87c7d2786d1a0c25e02a37a74fb021470d43d73eea6d2f22cc7d2407\
+            863aef85ec94d65b581fb26c5b53187b9322df928596eb6bc0f65bbb6ef93010\
+            14facba7d885c86681ffc5f496e21a375704aac28856455919319ee1458ee98c\
+            750c55b99c011b20fb82b3d4bafafe43b3061a53750eb4d1ed85e4ddced70104\
+            6922daf40dee3e2a51caa3935e53f62b8605db43e7e00503db501205396c53f9\
+            16ca461803f20c0e48663b2e56f9efbca169157c0cb4a0993aa98347e745aa87\
+            5c5b514a2ae9956c91c56c3fae53f72f0abdafee11a376e2053e6fd469b8f323\
+            2a45f49398378a809fb4220e81bf6f187ae2aa1bb2aa78e2babfcd349ee07e7b\
+            838265c367d401571bf0a813224137dcf7d8187bdcb784b68cb2b8d80fe8b01b\
+            85aa269e7759e1a12760b65c10885f14490057fca2baa8f0bf9684e3acc45e04\
+            d77a07a02cb15f7030e09e583ac090827685e2922701e2c61dcb439d74b64e54\
+            201f9aeebfdd783d94e63b2370dc030ec9decbe9a6c6d07b569646e9c13fa170\
+            81f0ae0fa9ebf09117aef1ba7d59fce342d9a840b35076d9d20ab5026b9890ac\
+            8fd3c90784af6926af4709203b9bb89852de4759859a7cfea08b67dcf6fef463\
+            47404b257fd5bf8f23b474d5eab6b7f656a6709bb2129d2af48e947837bda848\
+            c72df1b5e69f52411ed86a282af3cf665811e25b3577fce0757eef2d0d244014\
+            afede5fe1814e4138a40e36fcec53916333bb5dbeed9e131bb438c17e28952bb\
+            eb33fe49ee0d8d83efaf061066045b0d693a7bae6522fe12d67332b10a094984\
+            e3464d7970071aee93c970f6e3444edf11919d674445e7d1ce867ae2a8807b5c\
+            9748aa85673bdfc692d9a55304c80fafcd24e3ed0ac974d0946247702ab7b5d3\
+            9e62b9d354b4072909b1b833e0fe7d1b952f7b431e5e4ae61cc9d411cc525f6b\
+            a3dd3917a937ea257f1d4e4a220a5e3fad10d082a2013350cb47d72d249c7048\
+            d73752384fc88e7cab81fafa872fa93985fbbd3f97f37e0aaacceee63ac24209\
+            ea234d851f54ea515dfcfe326175a841ffaff75ac977aa21263c1e6a1947a589\
+            524cfa5bc7e023da0b5a783dc34739fa12cc235a128b844797b5fe06b0cc8f42\
+            6b23788d0550a45a3301a2d5dd250b9b867e9ab103fd4ea498daab9b8bc9ed6c\
+            d3e2e36d3e50f9c2ddd5b05aa0f5a1bcd44cd7947291a197bf3abae02a16c6da\
+            e45e0e1fa392213c7b0651586273c552d4391da55eef9507bd290f1b3e8f7faa\
+            b8bab19a06a004d352ec80b6c4426250e26ea89c67a76c9313a74ee9f04a8be9\
+            4d52e9ab0fd4d98b42bd51ce4e818161c61aa084c5d6fce2ce2174737bc80828\
+            39c29c5e5e30c4280bf8162f0c732348f5fc063e581144bb923aab1aee5eb48a\
+            0d4bef08af21bac628747c84b9b29e886e377488401b3bc24884b67ee5264699\
+            6b09841dd04dddc55bc2c71a69cd1badd3d2be6722e7be8ede3fb42998c2a746\
+            fd40844dcb5ef6e887ab648d34c4431aa1af66eb3165c472222e7827a9cc9ccc\
+            cfa4aa0c71da3013fa98c1d7fc0368d5282693612408820ea4d4f589966835ef\
+            18ed445477b4a9bed3b1ceb49fb65282d6cf108450a69a5e7a7b6bdca08d187f\
+            aef63ce08ff9ff9efc171e50be2091c0b1e4ec9c9aadd43c0efd91472909ef3f\
+            67a64d0f7f43f739f0060e3ebae9637cb801e737c0bdcf28209660b008ab055d\
+            ebcbc86d2f3c241eaa249235c2a4f03d1c0a3f6f6f3ebb66418a795405c6b1fa\
+            898fbfed920163a445258a6976bf61a0ab284837c2d993671317c32f425369d6\
+            139060585571ef57478d279dfffe332fef3cd052c92bf0a8cc8f31db6b8bc0d5\
+            975800f91d74a5179d03ef7647757fc606743562aba77b58cf84a1434daa465b\
+            8c2a5e1510abeb789faf1b3cfda52dea8c0c3df71d38040686ca646f9b09bd96\
+            211deb2fe42dc74b2207f70f8ef5ecee59058aa751508a8def3b699eebe66094\
+            28125b52d470e3294ec3c3ebcf48175af987f8181894ec6f1f4a00b6e5386d49\
+            50a03a13a18caa1842438879957998f8804e2b6647ddec2e07054020a49a279f\
+            af728507f52e937653aab34a3d1ce00d19b87271a5d9aedc3b6d7a2daf94a19c\
+            e8cbdedf63a60ec9d9d64c8028248fc8a0aba2d014de6f4925a0b0dfcafd0da2\
+            e8927b202b6be75df60e8b922d179dc3adaef8dc6faf1ecf7992af6375eceac3\
+            0d8031c74d394913899c25945a7fd92c892830882e213dbc04742c248cf52a8f\
+            f563f5b17e6fc9b9ef20c0c671c80801e21683bac41cf98f06445fb1eb39d0a4\
+            4c7aaced12fd719fc9bb3f8a48a1681a939b21dd70a6b0a8059d0a51369d986f\
+            3440ad76863b9dade4697ca072cd6215fc7850e0e43221a75640c4d1307788b1\
+            dcaf31d8442bfe53456b17940f65f2fac2904b731d04ee5724332630a0890969\
+            d587e5f6506378960a415fe32fe90d70c5ce6ae62119cc8fc71ce05c69759b00\
+            cadaf19e5597c85f324e564b7e0eff76693f1bc8641037bc9a670cc7b848eafe\
+            359a28015ed7553a2d9d48a9e7ec04810f70ef9dcee51358c94bd57e739c6cc6\
+            17f95edde2a13c53d84cdc9d7838cb7381cef222c374ae147c35e8b5bdd774a3\
+            8a5d07ac6377f65975e018f16d9b911ab21493d7c66fa016f18006ea2fc905ac\
+            8c138f909261aab8d82857bb5bef2e9439c52e0eefa61abadd338a3aa2f45f88\
+            d8a0bc92d894eea7a352b7db0186f2b301002c23968ee7e71fd1f8093015a486\
+            8ec5a8fb7c807d656c570a0f0fa898c3014ce6ff4782c5fb00aca00949963050\
+            001b6804b7b657ef49d153fea6c5e423c04eaaf160391a930d1b77b95f2dff0c\
+            05e37c819e3395021167c004eeaee32ff23150803f354453884dc9847de24fcd\
+            8d275ef74926866e1e3a57ec7e67a597be951ca74ec8b1ef5f54ddefab9faefc\
+            2f8171174ac6e7ea8d187bd25ab8f999f5ea8fe8624a304957792c345849b8db\
+            0e0b5a5ec6c5e8b9c1e82634824efdc483eba8d2d3eb71a3bcdc970b4ef5de74\
+            dee7c73f95b26e0954c193b62988fe0b06566709319300a772308084eeade5a4\
+            120e1f0e18252a4e1532d299861bf4ac5734e9cd51b79a5aa3e88d22d5a8d9a7\
+            1bc792f492390d562a710cd84b9ad14ac67b4d37f7f3a0dded7074f3323932ff\
+            d38e4ba3fceee12561dacf30396786f5260767bf7b92d8c2640e5b3bea56ae1b\
+            9b8aebf96f173a3b25fb6510e5848e7732a2868665595d8a2705101e771d867b\
+            a928f37637c694d572a1f371f7ef4dde00c1c6bcb8c211c01066e450680506c3\
+            15baa78404876a46c880827d0f5e3f9d8aed2049cbbe49bac073ee5a393dd9e6\
+            5b4b237042bbf147e3326113148af856fe5d4bf95f42305f2f4a569495bb4b21\
+            f221c3aed454cb87d76133f3bfabc34088aa1668dd5108f3253ab524ed52021a\
+            db0e45e09959797a9b467a7e57aedb1df7476e224413e1592b012b14c89f4ce8\
+            8a3199deb9685417a9acdadcfb7a01a11f8a6bebcb662b65b694aa8de9dd0e77\
+            b375e7c44d3521bd111561bcf41a8619402230263d7672d36c7743ecd84b9ebc\
+            9085f53f09cdf8a16b5913b2420403d5101e6b2311857e7af9f66864a2704402\
+            30d1bd01ea49731a4abc9b282e32d0c0c52d7f917aef16538344784f691f1ef9\
+            441939987d5ab9cda931b7aac70236d4a6f9ab664cb74b458baa4732ac2b4c4a\
+            b81d281ebc653064d7612568f2563fecfe19cf52645f2446b0eea9f9f8a4e055\
+            81af66e8ef068466ac71b14363820c3fd0c17933b4a2fc27c5beddedb3c0837e\
+            8293d693b84ccf2ea9d8aeef0bc2b36f2b4bc9be61800292910ca2998994a822\
+            e94d6b9f96c5e14a4053cd3bc89e352b0cded3012c4cda8f816a54f9e92af1f4\
+            3094f4bed855edfc09e63fed72b30fa6c947963b81bc0a77f9c761f1dbe808b8\
+            bd4e498b220f133f1c4a788d2459e6ec6b23ff470350795b7d69be56d2a59579\
+            11d3146c4253e13e391544994a650d9907946e935428f1571ed0a49f33c12863\
+            abf83a86d9e9958682c6079bbc1f6c164b25355266a8ca4f544ed692e67b8d04\
+            bfe73f5f947d4a2b79778e674e2e09a68f39a90ab3cab6bcc56e1442abf07463\
+            dcda39a57310393def551067cea2d70083f01a57da5c5c47351d726861a6e4a1\
+            e8ac0a987145e065408d30652908c1cee06ddf7ca9ed468cb86169f47893f7fe\
+            36e59f8fd2ac17e6e412cd7644e56ad415d6b7e2e80a93b42b98a8423f3ee89a\
+            d89e8e09d0ef3df7bb9c044ae2e4290018b92057b7c4eb2a5a9637d0851142bc\
+            e0c666753a9aed674c8ae96480854657fb90e9a04f3d25f28918b9a53633b087\
+            3e382fd874da593f36cb906571a98421c6317d52f80ec9290a713a1cd4750b5f\
+            f6d718c4f4f5ae20cf52f7d75b307cc36e06e9453ccf92a56e5f562e522d9619\
+            bb7304ce5d42e1998d937b9fa1225129c493433b7ecf42ba3d391e73af4e489d\
+            35f48c58ed4cbb0df9ca7910d217048a5c1b868b972a59865e69e56b1575fc8d\
+            317f22c6aa737c701f6c4d6b0ea005f1a6dc11744892e371b8bc0a97b01c841e\
+            16588864c708af9e4262f3770902ade2b43a5c7f8f1f5c6ab049621c2db5023f\
+            396b911946b8831924c448154288a94c6725f2f64c25696f24e410fc97897cfa\
+            6933efbaae036d2a284634424a1d221a0d9a4eeb9d0399c149718af2189260bf\
+            28a21bf40f895c4bb433099851d52ef07643d03b362936c5d05ff7f81eccea84\
+            fec2cecbc3d3ada94c500983c5c97771bc596454bd2e7bb76c73a4eec589832c\
+            9b7d4c52f29201b7c9eb27e7829b72f3c3f93a8a9cd9dce38c4e72dbf58bb717\
+            8def30b9c9316bc6c9b73ebfd3b5e9ccb911e7624f59ed57347a27efef2a2200\
+            22631a3eb20f79ef02f6efa25aa2857d38b7e47e40ef0b4d4f82226ea54dd103\
+            28ae2387aa7acf1d71495c72b0eec7393dba2e3efa2bcc537242ecd297987b3b\
+            1055e2647a7aafb34535556dcee1d453679417da9c2f4657d4bd3e540bc6cc9c\
+            5cdffbcdf4654053515f68b4de9a38b2058f61f4ea6d049df3a5df869fed00d8\
+            385294088fa093a6a9d6a0a7b87b68d3f76208e84ca7840e130ae47eaa7a9c57\
+            b7dad24e343820b0690269de79f532edbd45dbae31e307f6653ab14cc24331a5\
+            26f6948eaf40415bc1fc36720694250ec1f2071c5e091fac54dd8932f69bd80b\
+            33a2eeb3750c72df881a2f587929c4acfb100d268fe0956bdd73e86a35be1725\
+            86564ddbef41ee7b04473898c99ba8b4e4b33aa22fe3b5b6edbfba6b4e003640\
+            e60702b1865e9dfa690ca0bb27056f1b430c6158aea68d1f6f444883ba30dd9a\
+            d3b9b642607c7db5c94b49b3a314f5d9494eb324e59d667e4d3d75245cf8518d\
+            461d78465673ae1070380fd2e3d2ae80dcf76c9b262362170a2b6cc09e7fa27e\
+            42653e3606e5d37e3e4ea12e351940dbe450067510a67161fff4a27e6caddd2e\
+            3a29ae887ed7b9474d929672b0da4f8eab3a81dcaa801c7b53c0dc8f7d090b55\
+            37b2ab4f41cb54a9115c5ec099cdad1a153f12d89f8489c6df6d6f05ea469b22\
+            6c9f7e0ca551debbab2b74995b2277e60e6548017191e1ae7d7629158931f0db\
+            9e8e8f896305a5383e1ac69520abbbc4459b735d5313097a526f1f745821c3d7\
+            85d963d9730dc66f8de3532df0b69efdc516a23d74cfc896ace8742ca07130e8",
+            false,
+        ),
+    ];
+
+    // SLH-DSA-SHAKE-256f, sigVer (tcId 30)
+    static ACVP_SIGVER_SHAKE_256f: [(&str, &str, &str, bool); 1] = [
+        (
+            "9f3f427207d41c6dfa80ad8ffb81718824d0be7e73437df1aaf0a970b0f7301e\
+            56f478d26d36af319e2fb809666097d71e2257d215cc0e7f5391da7f2ac23081",
+            "a4",
+            "268714ff8cf14d90a3b16b5d3c4f803210efee9213415931ca42f90a912ab766\
+            b36e61b16020baa4e83f9c385fb4f16b90045604ef2c2c5d8c9cfd63af0bc02f\
+            9e4580de6fd01987021d2bb2ed2858e565fe2c14be0249456252eeb9cb3609e9\
+            a9c55311ddb4804b6b2adfd83ea0389edca9e1c86457601944e065b5f0a25dbf\
+            58c1ac77303227fdd7de00c96b6a51046542dd6ffb500ca1f308af22b6c25fe6\
+            5e057a848ac001f0bbb55f842d188880b725adcee7c1b742ccb4e06a84e3112d\
+            26cd0c5b87fbc986cb244379878373cfdfb488afb4025aa05dfdb39d3ebb6f74\
+            2f1ef9ea410d0f27d2101270cf5f2f33b6292077666c44c07f5d214c089c0640\
+            23dfc1731c31ff6b34d1902af7a8722136e4eacbbb739789ff3ae024b72be614\
+            602556ae9a6d00b83ee834ffb734df54a680549bca8a2da9d1489913ae8d1a6e\
+            c94eba284e0dda2001ae484d3c598b42ce5cb79680b2a7662e7729877ea47bcc\
+            1003626430776cdc2493c8c798cac5985f12beaf8006b6e50e813e018571479c\
+            b100a328bddd30f87bf02101c552a854c122821a68e13cf0e810bf0e82e54ace\
+            d1799097c597d89f2e1f97ec673c84a11ff776a9bd2fb7b780ef217cfae446b2\
+            b78013d7d84a1c6d8c1011362f6a8fcdb0b936c3d57d920b72ee3d81828681fe\
+            f35e8006f8baca3de8059cc6ba731b4776415468982726cbe01ea43a37a36e9c\
+            26d2627f4c0349f677554e908260497357e35017a79a409251dc02dde4f0b428\
+            f268a4ad3e87bd12334969e62a6a0df5643934d6753e3403368a727f9ea4b4d7\
+            d4ec525ecf8b0ea36ed61896ca7d4856cc4ddc23f45a028152e4dba78c9baa7d\
+            83545fc839656319c109ea855985a524f6973fd078a511ca8b4d67159dd6494b\
+            ddd223a8bd03420844d49ba11887b15e9ac9261134931bb928eabb21e76c7f63\
+            a1c01a51e5a414ef1a30e3febcb6124804e5d2ccd1883d329422d016dc155b26\
+            a908c86b87c07ad85507732bee00a63df5f9914fe0e648b164e83fc36de20951\
+            16cc58b0873ca80321764f03ed47eb0278a5c6473a71b3fb80339cec2b547c40\
+            bdf9196e875e89c767fa6c662858466c0106c99690b06df5a772a49ca06a0caf\
+            a65d165595d12ad40ad4a0021c55b07e15d0fabb488b248e23a81932ce8b020a\
+            723ce224124a741c62704de1e7c20d1bf29d924b738fa51ec4aa8649127b59b4\
+            d57c22162c8c4ec1754bf077d23e83160a81bd2fbf39219cbd418ac6496fde29\
+            edd7459942909215ffdbe3437fa1cd2276bf943821639ab0e5b233366c0c84d6\
+            480a2186f0021ec7bfe4f377918f6e5571a38efd409cbb1a344f1c17fe324fea\
+            33d388a456bfca90bfcec0715f53b5fd024bf78b7311c56d0126dd309a52f1f1\
+            4c702577609a5a8d59a4a5dc628d7b2169c435a8422f57825ec438937603cb2c\
+            80dd21e5709450af83792cfc3a7b40bd5402bb3ae398688a4194686ac7e87969\
+            7fc1d56c242fa61d1f3f49270b3e635de6416a6d4e96ec3768796db2a33e0776\
+            faa1871b1917951312640571ec668778ccf76e6005454a943f38b4d0e50d3490\
+            75de47b5a3d4759858c24da6c84d7df032a74ce10c4de311275fb0ac92e83c18\
+            9364bac73a03366264ad1867d7da9a3e749d468a344810218aafe507fd2161a8\
+            9b2e3022b3a2cb7391156a7df6422059cc84e957dc950e8b5fd86e2d01477461\
+            5f44d09927babd5e0d64bba5846cb1012b62f934a1aae1d61068efd27bbb46c1\
+            8c1030212ed278dd8f1a21626dd927f68276579439ec7604e5218d2d4f2085f7\
+            36b08a2b9e08bf1e82e0c4b54079963d85a4cc630e312eea24a07734f9da7f56\
+            ba49c8d8217485e0521e50684bc5d17b252425125217d562416120c99c4b40b4\
+            3a68abcb6f177f4d74fe09fefc72c9a8114f98cf280ec49d5b2908a28c360379\
+            d28a9ffb45496e8cb158c716bf3cb48477d7f8ceefb4c708ca130450f0c5203e\
+            9aa4a0a786e8cd0f86bc9cbf74e93877ce441fc027677214499d0f1d41ed5225\
+            196ddac7d931c57af314e82201e060ed86d4cc514320e6211d5d2dcf0a4a7155\
+            0133af728e53f1b7c1b21e5c8f354a1b6a0d05e4a8b2bebca7d739ffc3ad316e\
+            cbd8fab56906b8b053ea7c205b9e0ef7d9d16b7651ffc786ce38871d9bdebc07\
+            156e7be6267cf77c0114f9213cf634e17372d370512e41bf790a5fb632bdd9c9\
+            69d32a0a8bced9bf994275f831487f1879a47f13db37bd4d871b96dfd6ccb58c\
+            5300494adae18b9acf6351f8bc492fbeb271ff38a97ea5f9db64737936a7f613\
+            134dd491909697b57cee7eca538af996380f7eb939d28d91c7d9d8938f842344\
+            e5a86cc41e9c50d9f6c7bd719692ff54f9161157638985beab3cdf04f285ced7\
+            bc9375970d769136375c98d1290b26ab53d0b76ce047a74d795a30745cd2ccfc\
+            8d970837169a8ac7b5de25426d52320d27e743463485d72f56565b6c311e72be\
+            7b85bdd9d0322aac893a4867b157d42d4b9cdf464de246a769f21d507c0bcd2a\
+            e1e0776710dfed2116a3940e3f6b186c3726f8229683d9b478ec4c061d78777c\
+            b2b2eb0e7fecc0118beeb8923632b33a790ad6799063bfcb4b0bf03862637fc4\
+            b82873c3df8dc89151a273f44b326de4c5a783919550f329ca258b4c2c4c9fbe\
+            61b99f457627a5abf58d550e0ee6700b7af0a4ef164b268376a69c2ffc290241\
+            af0eff2b1ca0de24dc10730db94ad811bf3c542bb9e9c9d1baf67ca7d796e02f\
+            5930fac10d9f741aa217c6f563ef622a229bd9d47157d24d0080c0fb82355fdc\
+            6b4393f988f3eff730771722e7c35420d2545d47d4b60e198d184d0d49ea778c\
+            2379dd7f4eca32d5eb052e693e8c5f5054ff583cc179af65fe5a7374b553aa38\
+            7944cba391b5324c54c9b33ef8f90c37c9232eccdfa490001c92e9c42cba816a\
+            276f83d126fa5f4eed9ed64a1c094988becbdb06e1e27dcfe1f6ef7f697cb785\
+            c24ad3c10d787f424c092300bf63eb67609a1c08ce329cc9f558f2cd5de2cf8e\
+            62bd26137238e9bdd40f5a2b8cc356bb5be48e757ee97548ba294bbf76132e03\
+            b826eccedc0ed7275a3620cfb901872c7e68b25638fbe6c80f4ecc0ef873f4a4\
+            2a0530639d5c2a6bdeea6337eee87a645063cc8fed413d20dd77214f548c78b7\
+            0bc3e0e2dca023145b4c8e8e5bd27693c02f1dd639c7ff7f5957214233684d99\
+            270df84e5d7363f5b04e993c34796ee55308a4f6a8cf8c77461d47a967b17e98\
+            a5bba7f41cb8e8c143923267999a2a92d2d68ca0aa41ae8eaa57137dc8b6bac6\
+            6fab7f82bf72910dae2d3c931ec572c93ee0b0200ec97c93e8eb6100c2a78adf\
+            ffc5e971b4723d3d08965c2ec5be04dc1dfafb33698769f0d107c7346ddd82eb\
+            ae25dba004971119b33263c29ee9d41d89081b01d90aa2b03c2655647ddfb124\
+            2b9fad9c97618c6156f84f8641faf60ca4ee160d3c37c09a84f53781bd4d725d\
+            01da82980c8ed435370c3f0247f0319a9535890da7c560974acc889630c7e874\
+            0c390f982f5c53e7098587e9efab638060830287335370bd984df6d41342336c\
+            1423421848c76df17d7d5bdee2d9b5ecb39ab18face50a6a233a99ef087d9f6a\
+            f5104561285e6ff01d712082efc5968c43499bc73d077e6bdaf616ab834e6716\
+            7599d90feb7e14ae2af07771293cbc31133852a518587ecf951a96c5f32e8f14\
+            cf2dd72098c5cb014d2049deb03803a4ed3590f4e623fbc9cf48abe5ab431003\
+            68f4edebaf91f3bbc75240acd340fbcf75f4f5015acfc8ed8558d332a0c1f771\
+            77e5f94ee5786f6277477d0567ce656a9edc80a2db0a6fe722b0330eac29ea9b\
+            d7f711c09261d2a898659ab13bc365c4dcaecc7b0de3cac46d82b174aef0c6d1\
+            dc21f23cccea618d05b31f7533eaee955bded3fa99b7efa394b9c92012697bc0\
+            ddb566e1f399ce454dfbcdcefdf832abe0ab57a9d7436b545f4ca7000cd7f5db\
+            898a1056c00104bdfac1afd85f787bdfa370f23c6e248605e22da5a063c83c12\
+            93d3c0f2b8b5503f66215ca31b71419ea616c3f2277426529d886f807d9fb4bf\
+            c9421d4be13e9069054882946ce98e5b3690ab61e4d999455fbdeb708423987b\
+            31e50c92b3e02c5d315b3026c82cfc8a46a2334c6c19cf2e59b06a0f7d1602c7\
+            11a8eeb2acdb922d1c56aae8983d7ab1a5366fc1dd350bd0514b6bdb36d406a6\
+            452ca2d3e04ea307f01cf759e8a2a253b65c56bafd299bc82932e7e50646ffec\
+            327d681218fb0d962f52d40c4320e10c6403dcb99aeae98dd5d01d3a9024b3be\
+            6fcd6b7611a73d3574c0e43abf710ee93c2d292ddf9342bafc1f06f882a9c36d\
+            d91d0e57edf2965488bf4e07168472fae5419c687d86a7d1b3c8e46911fe58cb\
+            2a9cefbb0099dfe8e3c4382a1750f58330cd5050e107316adca4f3f89cf26b8b\
+            22dc902bc98a6dbf478ee44da737be04fd9c7c36bc248edd0e90bf7890ce3af5\
+            cb4c49807d7d3f784b2ec00f26fc63b885aca74db962c52dbba5517595be877a\
+            0c9abeb6621eead877ff29013676bc3d343d6232efc66e29decec65a38b51820\
+            121bb6ebc495868103e99230194ba31325ac8a18c499c60d5fafd0fc01f1987e\
+            e825c1adae1878732f52af2b41d6bbca65ede38717566ac2ee29c5f317375691\
+            847b189321f0e717d8a32d8d8b3accfc8d0ef2a18bc7291c0965f5ff8f3b212f\
+            11c25c328abc04cf8740c8cc79ee211c03d4fba869ccbb23a03bab2a53ae6f58\
+            f4b368d2b07431c95e45385681a32d07730a2501943ee070c29a96106aa2823e\
+            48400b2c42e213b9252be3234333eab576dab9b941e2868c715e14b3178f4335\
+            24ace50a0a5e2e65e583e97efd4dda41b7e7f01e5efbbf8c01fe975b3c535d3b\
+            5f9715c264decb31a5bdff53e6e0fb2e623b92fbee6e0fc2d62d1e4b5988296a\
+            41e59a39cf7bfebeec528212cc714a42fe6632f717d77eca8c0c7ffc65dc3f00\
+            47ec2c106f3d97fd8f41033dda49653da13edc118bda54d87a63610e03b7c30d\
+            8a57c8d1da15933b791412dc822870d54febadb434a6448a132f2c6270eeb3f7\
+            60b8b24442fdf7c38026631d196319bb1dc1c440d3706fd99021d44094eaa110\
+            231bb810c1b4619b90b93ac7698b58d75ee27a0c75bb39fc1e1a2bbf199e22e7\
+            f71e4cf9910942491229c0245d6be47f9d3dacfba5485f15291328cc5c195f81\
+            5650062f1f314fd12e3e0b9dcfaa1b2f319a829c77cf4b0a087783c96b8dcfcf\
+            5c69c20ee5f4b36513d27e2db91f33bc6872c7262dfd0f1dfddf1c21df9e2b80\
+            c6ab985a4c88dd3a9fbcd9c59e1e79ab2aba9f5a8bcee05c7a509abf6d8d5ec9\
+            d93266bfd5506f20a8677dbc9f53bd6096b8b527e691adb0d66eb13c2c5e6e8a\
+            65edeff3da3ee24d1bbc0a14719bb87c15d141814f21983676c4a5c1fad05189\
+            a9dd4c3fc0f8d8786893d039f72de5953e624f4db3a678db7a282ff0d1a69caf\
+            d645ad0a5f5efb4980f7490c7ec70bdb77a884e20b90132fcd3ad78c0f4aec41\
+            53e7e3f10e6b30a3979ae00bed5ac579b8699dae6fb12895cd85e600b6872ccc\
+            117899651f528f481d11048d39ee01930fc1e4508acd1d3c4bcf9235e45cfda6\
+            0040beb4f4e50b5415e462ed95f4855e5a3870194a1f8a0cdd909c1c6689d0c3\
+            3264f1f68d3c2bae22cbc05762aab4716eda41a972dd3aa819daf767849b26dc\
+            f16fc1956226453361b11f379df87f5e645eed671b21be39406f7890442222f2\
+            ad5e7546efae02fc1e09148fa7ba0ebad9102d685dffcc47dc4d67abfee77660\
+            472bdce324c7c9a3560a313c3520b59b957656499c6a840b9779e6f2bc897004\
+            f814f9e813346bff0a9a01e330fb19166146b7565c3a8e4344fa02a5eb57beed\
+            ad398c387d66137c6740a322fc80d5085dd5fa1465f3dd30f3a4feeb3b7e3a76\
+            ffe68be0d5b1223e2c88b4a5be3eb3db303be065b4b7c7156f2ef8fa6af01cc5\
+            8be34d3ddee4c20ec985ca470a186fb459ce935e7cf796030833afe21e94d157\
+            969ce73666c5f33bb39a86c9c17d65037b9a2add6b61c24014fb741f041132fa\
+            e83db6b8cc93f2c40d1869292df53eb9f192e5ac09fa4ea31f79a2e9fb342b45\
+            a50ad2ec3006624b9801b3bd6c31a66ef597164c73166ee7b76e5b141d661cee\
+            64ca993efee63be904ec8f7b5450de10824f33cfefdcbbf1ff45d5b9b6f4df7c\
+            2f973b1aeddecdd2a59a3bd39cac16861114f83bc069f0f4a71313a004fdb5aa\
+            e8c9a3e8251d670bda5688e691cb14d136fa880848acc825ea758c716a03542e\
+            5e552c204b8d53d5ded297f191ba18f60c90e4c87ed7a173f5813a6421645f8d\
+            335e11e15136f87bfdaefbe27ea8f10841f2e7717fa592ac84a2fd403835f6f7\
+            96f8f2615a3f73ace53f4293160e51acb4fdf379f3b8164bbcfda155681cbbd4\
+            b7527f9150b979681af8e960ac864d0f14d0dbfd89745715b1cf604eaae031c9\
+            1bb8b04aeb7685541bdeaa97bf87785e7ab9db4be47efdaf9b9c2fa55949589d\
+            01bb8864e17cd4b850d52d73457082179d630bec6a83e88f5b02f3f4d675c764\
+            0275ddf9d916f5245279165091bdd41713fb6fe9102d1e67e11d49b152354882\
+            98682ec6d23b768b348bcdeaef387f84ce77b4ceda4ba29e26c1c749c8710913\
+            402763185421552722f959b866dc1c6f2f3028fdb997ebb3f1d53db2c05f27e0\
+            51b8ec57821f183c02def725feb12933b76552710eb706340e4973fb708721f9\
+            4e2cc9f41f05eb9b7d37e3d783bccfdeb3c09d368461b8c6422e699feba5cc92\
+            2976c9fa307b8668374d5aca2928fd19414f4e92b268f18c4f17a39b41da240d\
+            29188716a3aea5fec615c8e974c07babca516ab6b490253bf3063f4e93ef5816\
+            1de351dd8e1f1f5e3d036c9c021902f71765919a794ab8e53d5cd3bb471a6df7\
+            f13ca2b570aa5cb18f39fde9845e9f850b22c175b03e7cb75b9763640013e06b\
+            f0442a5c155fccfc2e876b0477b2d52501fd2846ae064008d1e0d4b027c1396a\
+            490ec8718cb83f4c5bc1d7e532a19a70168601478ca0987aae6fb2b6c0fbd839\
+            ced6211293bdd2a0f8f57159b06c5af0b0432f9f68cdcdc324d07043b40778ba\
+            b69d0c1f4c9062d11b54ce0e42193a796425eb9d6fc2ba080cd5ec5790aa5cc9\
+            8840147513e470b92d915d256de180d5437eaf4d5f6b9c2548b38ed3d1ee1c09\
+            9a04a51787b6377cd07de30bf3d12d19c9fc7422e33010eaaeb9239af92fd915\
+            79b4f6fdd1bcdd5f43708443a46ff21a702d7e8064da5767d124d7e3090acbcc\
+            12cf20cbf707ddc7aa2dafcecb97d58d03a48f2a7a6c2cac90f36d49e7784597\
+            578e2af67f456d8de9c6ed3dba32cf8cd0ec3474b89797dae5b6c821d61cb42f\
+            97817558b516f548762840822c101269ba67236a11c532935c8ccb588bd240f5\
+            51e4a22a6fc2731ebc2fc8a77947537a33271c9b9570f9a2894fb7bcf1ca5a64\
+            d66736a7eb9b15d63eb230ba7e93ad2a3ad573a307fcbb0f0402fff7802994a9\
+            70fd589d84c90710d1d9fe87a77a86d5c6c868e64b7188f90cf2901912ba6286\
+            48e651f3509bebc3bb7eb5f496309632b33fc4214fcf4e8d3258b2a12c9e7d9f\
+            4c80c207d42e4be5c0ecead9f7afb27c034fd9c1cfa14d37f092d9c52943a476\
+            e008389ccd482927cb336bccc78bec81f0f15ca3da7c4b6681dd717e0db26763\
+            4f5f9321096eb480c05649e267cbdcf97951e81e6d99cd4aacab37da8a11143e\
+            15eddf1a08b1923638ebc8455341348d07cd6cbfc6cfeaa575b77fe24981b802\
+            779c112cf7a63e8150f5ee4368bf56f23ce290d23e147b55fadde7269ea3f8e2\
+            262bde79f300fbfff96ce74c5d5a7ed3e2854eab7967bb9fb1592658a5600f45\
+            44a289af38989fd7a87309c068f54d9ceaf906edcfd77b554ed1757d63f4ee3d\
+            24e5f0a08b5aadfee60ce5ea722dd03e6f5f0fb2ec13e6d9d98463926ec75993\
+            96dab121ad2980982a24f2dc1942a36895621ad60f8d898d722af611631b8aa2\
+            7175335969ccad0430122c2d68dda163e0425d182b2d30c3094eb398d3480ef3\
+            e199f1a5b2bb416b8d0763b4be8690a44b673bf857946793c4a7c87f14aa0616\
+            5712967fd2516bb9f35d9b7e9b7db7b918d79b73087377319214dd4b0f4d3e99\
+            b60549d366aae3324525318f842ed77ff9bdfe48f666392d04801d6510b4fc74\
+            143433cd92ac124870106fc5167ef71a4335beb5bc37d1a427be6330e8bc113f\
+            66dd46665d8a3b7a2b9f0513a4fc329639e8e68dbe12c8500cffaf1b42a6500a\
+            d8eb1f0d4e389cc47c301a65f4992a2f04fa5dfe3b259fe8ba3740a6bc50b3cf\
+            f065f323eee459c606d4ef279614da0cd523f23200a8c4619997adf8c6550901\
+            42a91ebcb5ac505523f154deb86c9788a08220b6b98266577be6c842e21e3bf2\
+            5364299fb19434dc7f6bb5272387e258968d338141388f6cc88852d856da39ba\
+            0774ec6af895068cd207a10b101c5c068e8f137526b9dcfa0ceed04e27a0ccba\
+            33d032dda6111adb30b9a3aa13ac8433c57edfb5b709b51702d95926ced725ec\
+            ee0cfed2952249b538e5b37b2bc669c45629afa105b1bce6984b0079ef54d06f\
+            b00c15da34d65c6a1abb7db9cd78354d48138ff6c490c46cb9362b21047a3a1b\
+            83b9369df69c1b08e926c3d75fd5273cf3702abf43b650e7b1e70473d3a441b3\
+            41c12285122a1fd751957e46f1484d5c475711ea96effcd7de83f19de12bde7e\
+            0f8aacf9d88e86751751417d8ce2a0c39e14d8716fc67d03172108a3add99653\
+            0d6a03ed7d1bd48e0a6c2263fa319d584d905969188f251f3f6a6684017b0ffe\
+            e4346e0f23df732713a24d7d36816fbd2ed6f61dd75d151c59372246a81f451f\
+            5a61f15c252268c661602d5d772f941104283a57d871924be73e13c578d874ea\
+            54699fc0ef3f1b70dd77d2e755b38a723d53835b4749df9777745684ddfe68bd\
+            1190b8db69e050660946db53972cef50eda2265e98933e7bc77942360f7914c7\
+            c5867d5a2175b861f68ff4790cfd07763e522292c2bf05aadfcc8022bbdfc1f4\
+            08c75a2116f4b4e0100e4711e0eddac50692673f6503e9808ccca1416033dcbd\
+            d0890354fb11eb13d2f06bf4fffe8bd680b3737e7ed500dd78776859f9b9c8a2\
+            8a823cd2b16de93044a9c95310df45c88d23ade7d555e152d97afd508613f253\
+            928b1a516bc43b71d3c446cb996a3941d4fbb3e3ea69ca4d3a4385e8711cfe19\
+            544c1ff3750b52c7d3ea109c105d7dc1b297efb0ee511a2d598e88b849ab12c4\
+            8420b5e6ae75865b0d9695d526c03f1392eff38c55bdb8d4787a7ba5b8e6606e\
+            00235bdc6c4351a357e46968c256b0ef64230224fd029db633b17c9bb51742bd\
+            aef3adf164836c3c350fb1ce0f17f47212201470df0247d8b7ea9915f4ac53c6\
+            e1d5136b01da9e0800240ed7fad506cff4f981376b633754c810e5ecf0fa4b22\
+            9ba7f66f7c9130853465581cfba60a8a0629c2491604184427dfe8b999a002fa\
+            ebe52806da546b8b3b87bfcef8181382a14061acb2bea2e6aa18aecb5b5a771e\
+            a636ed03cdc897880e4df4e01fa295844e6802ac03079e067de272eda0779ad1\
+            a6ca2fde6cfa63bd7b186555ed9e7ef32d2a92d76d8c21b75dd37659bb86ce9c\
+            8370e8a94bb42f12db6ffac35d2c953d418136dc8721ca96ac050ecb7f3c6c04\
+            2e1d601f90837dba25498a6f0afbdbf2bc90a45518e769437d3bb368d25b9344\
+            0a5d5b8a6671c7f5be3b125c123a5adffc8b7b59a1111f03affec06f559e5b67\
+            1445da485ce74b0b9aa442e0bf6ed5eb8b2ae20a0c5abc1205922cf68f05a83f\
+            450522f40c274c064cf60862794ab5e39b9cb7d9b9427a1ffc3b18a41c3f4062\
+            e088156b12bcfa2451dee26330ac22e485523bf389d1a186d05b61083e452044\
+            c4804f929234fb09315fb628dd95e669aa81e1dcf183c73de67023592a92b753\
+            88015dea967a87187222d584eac2098e57e9761aab6a887d70cb155c0d5458ce\
+            bc6526d260c895f102ed60c61e08feb4a601f73e5ca68ba2f3b9b8ccc3a0ae69\
+            41e5d1d4cc090c81aa0e72232e3db477f33d81c5f3a282232171e8cbd036ad03\
+            656b0b1e8edf8e33870dc0efa6d5c0903dea6a922c09ec3d6e3573720764c891\
+            0c232d89711870b2fc21e5cbcc952e0f378155d741416515348b62d4526a9742\
+            6f32a53fec41a9ca38380739abd5b84a8cf027d12dd57d0b36b265564fdb1f6d\
+            0036989c6bfef809e1f28ff21e52d535dabf1b01ed349b3099ec686ebc8874d8\
+            cb0fe1ec6bdc7e2fe359f0e515625e7134d56c3aa5d7c3d558411eb708fc0ba1\
+            97a27a2938efeb12deba024d9240789d9730a0d8823bdf3d333ce821bb3974f9\
+            4aaf1191b1a64587b4d7c135243eb9e5073dd8838f7be53ca0f3ce9ae9498eac\
+            1b5c7eb8de63951c79a275050005ddba68fde3d93191c3980c6d2c0632815452\
+            8b19906bce2a70d95a5b2afa8d6e3fe61af5075196b2871ff12ee3682e75c142\
+            1cfccd4b1204e8ab04fcd92f9484765407f1cfc08e8018db9ac91ccf164f49eb\
+            46583b74507c8d7d8563e08485e7c90feb289b2862aee625d3fd2f4e30672752\
+            c74dfd082e9a12058e5192ef1993a2bf64ab18cf65224d4e9ab30cd42f91309e\
+            3fe3611f3e965881dd2c209efcf5b2fafebaac54440a05383fbbf246f96d9b4e\
+            c000b727bb8aaa89e01ae0dac3f55c91c47978ef699c3dfdcac636532d319cd0\
+            cd3d03aed9d1a6f0226e8d8dde8dc5f6ec9c2f0d5e6199ae88e96b5abc9a714a\
+            46ab27da0fd2710b60a30cac077021b47936d81599a5a48f3d0cda406918c5aa\
+            81dd0b79f5a77501c6f8b8a426bf18d526f83da0fdcf657b2f33ba592769bc3c\
+            170f233b2f2772162bdd98b41dd43ead20365bd3e612da55cb8ef6d70411384e\
+            c8f0ba36e2380e8c90e7cf5eda646a321e1d9af1a0347fe91a633aadf251c1d5\
+            dc64b6a5367e0c1973fcfec96a69e05e3f6c21935318482ae2a00d7fb82087fe\
+            5c0f18740ae3fb462f05d579ed577a2b5b73c790e283bbd1abc0912608ac0632\
+            0398d195b3b5c8165bdfca687ae7e977b62908595f5c0c4ff1a3304e75dce30b\
+            e0cfcae6e94f4531c704855881fe803b443d6050f3f8d8cd62e671c0e2c63fe7\
+            a942f1a28b9f2361ac86a67c0720f70078c2a74fec16cd8e28f3676bdb098a18\
+            a4e74edf585b92a789a3f2c71b10a30761c3a5bc40fcc021ba6e050e26b5528b\
+            16f162f5f379060d02a54ed7bacce89122e6d4ee1c26f4c9d9a393cbc71e767e\
+            393545fa8097b5b8a800820819dcc3da66c47e84afcbe1609036f85e3dc18620\
+            32df29d1c43b88e8f3931bfbd35c837b480292b421ba5b87c6fcbbe970319f1d\
+            525261d1106d40e1e22798a14c15e685154177fea33a010e46b35419bc4fd8bf\
+            f0fe4cf54ee48dc332fc93e4e57a47a19907e3e62b1d19a272ff8bbcaf03cc7c\
+            421cd78c33a919fa2732317da111d84bdbc3aadf2f9d52235b9fee96f1e29bfb\
+            9dad1706429abbae6ad045b2e3908ffb816702bb9a9fb78ae97e3850b829ac60\
+            6d1ae6b10259eb85239d2e0706d99a42aeda33b81f516ac6ff29a6ff87629812\
+            4c7e37d222ffb21e530d09b4d98e7e8b13d083581ad9bdf9ade92a9f4c5c6e41\
+            407c5876cd1737f5f3f107d00900cda268c34de5d600f765f6aa9d31732785c6\
+            b5bf32311b27288876a77bbc1ccd7751eaf9514666d8b55136949ad112b0b7d6\
+            066e9f680725baedb7bcffe0ef20fe0894a7235ef46fd1bdce0021b71af5babf\
+            b79d216467738fdb399e211a3cea29f636c75c0a437e3244f094cd6293243101\
+            0a62fda84f3743f951b6c14133639834aab64b7f98050ae49a9dba516e82e6d6\
+            0fd998a696ab6451521c8363466c1b88e65866b846a06d9c864b807b661d91c4\
+            fe7a5b8a1b9db641ba6cb488f26fd480800112291de1f1b9b507b0802994b4ad\
+            488614cbd3eb0e79168073ed591c2290bfed6e1f00c21a7e049e208ae1b426cf\
+            b08b964eb5bb54898d33ec88e4678646b3de4746948061ced657cafc1747b901\
+            1354522a9ac216952faf2fc90847d128c78e2c5c70136e4d9049c95c5847bd7b\
+            c95ce92a3ee75ed184b280e53671a14081830b547e37e34611dd6c22ee921a96\
+            dcefb11021d5eca985e0bc7cb29984aa63bdf679ce6b0cc626aa95b126257d91\
+            f54534a868262182cb9ec7b4e36b11b811e066a8a7841ce3e70bda34d09252c5\
+            cffcbce5356969db39323343838fc4d8d35509507fac44635062675b20a74c3a\
+            5b3db9157d7c6c742531de1fcdd5f871d9bc2fbe8d692d4b7bc619d16372088f\
+            a1f3d50db28eb049f2c4eda9489e037e375ba6ceadd1788a57da18b184077e65\
+            5656591d1596111c7a1a4f64103119681694639030f772254bef03aa0914178a\
+            358c5c175a18d066b3fc768e1b2681e1d8c5b65496be4eafc66e1ff71f39c494\
+            288f7ca7a3b5ccab454539035c3f03c149502bdacf91318d39d408c02f5edd0d\
+            c0664dd3c702b03b9886ee39f505e10368df930e4ab73930cec7a7a513efdb9d\
+            399f601c4f404b44d5e651557a5b10998abb4acbc467a997c9db02cb0d962e62\
+            3635d972edba66ce1ac8d52311c147d03be994480e7f017a756f01dd0eaa96a2\
+            88f14f807a1b214cc0e69252beb4360cfe1f4a305cc5165116924ae7b520d2e6\
+            b01dcde18f71453936d9290f169277c06d3b18fede1ff947846cb91e824f0590\
+            ba12c82f2abc79958c2e63da09d4f0fc12cca913866a14473547bd32c3c88836\
+            120d248452506883014f1616fe454345e9a104850bc0face8948d8b52805b8fa\
+            90146dc4f633ff5ac37e6d54f4031c7104943ecef86ae5906fb71118f2f0be9a\
+            40a61366fcb722108c70426e331ff807c56807ee5195214b24586ff90ed4de3d\
+            d6ce64fd120e5983517152e2cd910036a5137dabc358a612694652b3fb0bb606\
+            b322acab614646cc3fde8e5dd6321f3fa972406e065725d463c865be7260be09\
+            ff89300010e0127cf2eb5e0309767210a22c5b2edd3a204040016492f6c8c8a2\
+            8390288a04d24f2aa5339fc6eab9884471496405ce2cbcf86765da9a2a3d12d7\
+            51ac83274a9996fc825a2ddb38a77fed4eadb14613391c3076650b49bbef69cc\
+            12b084438c62cb5e94f9626e4f9985767f0c3205b9fedd566f83fd53ab401973\
+            89cc9fb667d1b5c44ba2f6ee9fb69f4d860cd32f916797ab708dc745c96e05ea\
+            28029d8bae003dd7d7dbc80ceb82edcf7f7f63751cdc9d5c0abcaac7f7d687bd\
+            60ef53d667b0e64f2824f718a9aaffbbba87ce568c499fb250a577c15a305191\
+            5f1c946d455588ec1aa30f37493fd5ba1954cb5178f8a8ef21ea040989d57546\
+            1109eaef07c1f920bd6acc5a2701c503e25e458399af7f32afa8c36c5f5155a2\
+            e40b8ea62f562651d8c3219ab76bfc341b397bbbce28bc0786f4507bdd285bdb\
+            0bc3cc96f8906e5f65dcd354f078a70b1ffc60d3254440415101a37c2b490d11\
+            19b0ce8ca4ee4e28b3ac002d8c1c312c26677de9270f81cc8385653148c6c185\
+            7ebc1551f63840614724d7ce2ac913329fc5b9cc9436a7ad7bfceb5ec19e12ad\
+            57db15a771bc41ce0c13d90934c128985ce019faf5f781c5bda68800623df0c2\
+            c4ff16a3b3297ecf82ae908b70d2ecc7002dd867e306bdd5d4c281a4b6f73bde\
+            7c32a308470c5483a46287ac4bc69a90155c94db9cf4955bdce1e8ed79145e64\
+            5eaf949320ad10cdb5f58898d5ac18cad16b875899a5f8298d000c7cd440a834\
+            b13f80a727a74d8a0781388f6e5d0e46e011ae68a1d44c04f6d46f900a19770d\
+            1b0e4449121f90b4b7f9053e9b1489e26d92ef77e752ddd7aaa316fbb73bdd1f\
+            cc5cc5a78b48704723a2f724eb447a9a1a69b8e8c689375868bca0ae2effa07f\
+            efbc6451494f253a16f95662d9ceea690cc41c7d685f39212bf371b5b9b51b6a\
+            574d443f58745440417fabf05bb57ccf3d6d541e18f756a1996cfb993df3829f\
+            98e049d10334f96164043d135434e26aea59743ce4de5403606d49c8b40cd51b\
+            82bb27aa998930fcca0f0d2ab82f2a1f83c0c83d3d7974c7caf993eca67ea2bd\
+            cb27628158466f2415ee90b082c90a8cd47fafc389d0103043d26afc2ada027f\
+            06998b876d8ed05fd0a6d3c0d3143d8d3d81bab09eee81205e00fc5959c56eed\
+            ce8fe62a45ca2b85cc147b8cfe69f5b8d77c12b2fe5bb469f6a610cc493902a9\
+            fe2cefeda78eaca752b4d7e307b14d7d9ace31cb5a3ea1d083f2bd5ca535dbbd\
+            9a8378e3169d4f4adbe792339748867862bc6d54ed33933e44c41814c0d577df\
+            5d5dfb20fb6e650b4a507233ba30d7c9723630bf5fc2b21e5b9f6ef1dd832203\
+            b370837d7d5c3432737b00ba2ba33880a13de1fad65e9bee0c25f00480283ec7\
+            fd2ed475808c7e1617d1f540ab10a224ebd5c59dba08ce17f8eec5b4560cea12\
+            5e6a0d4257590cf80010cf3578713c1216e0b871eeea912cefeb7c203fc54add\
+            5d9edde1c5f251bc2fc3ea0e5d47d21b6ac662897690fc0f28613d7b3bacf209\
+            228c382dc27c6e48e9943f3e097a742e2165fe48b4e9c75d98d948427c28c503\
+            9521947f924648303be8debbc542712442d181933ad864cebdc7fbd93fa12c9c\
+            ad10c44e347e4d0b5c0db84955bb0fc04fb5538faff426ed65f94b16106adf32\
+            646ab0264482df8756a0406c5b667d85f9224e9863aa695506255e07e8d36fc9\
+            e2906611a5b058ac94f53de5d3f25769ebbf0c9c2065bfc2a558aa784f86381c\
+            25adc23c1457c631de96a0ebce27995032e377310016b97de1e3312717770eb9\
+            2ccf5910528408394979d93b0c47a12749931268ad41bd040246698ee1af94d7\
+            4927bcc8a016abbd1398cbd999979cc55765517b117b8def81dbd77e4caeabe8\
+            7ed05b248bcba0ea7e17189240c04b7df6f4730b635f8c2cbbf557885b70a761\
+            675a5d7362a8528219f2cc4bc74246e8f0a09dffbe71d0684ada3817e10914d1\
+            a00d0915b0388f17ce2be697ea8601063cd7ff27d25cd0264ab9c85710b436dd\
+            64548d806823a8249eb45d15ef5006005a644997e6f1a6b10b58f58052ef217d\
+            5a1656cb5376aa805d88832bf6366d313bb00662fa64c72eece0ad0dc46d3693\
+            9f23e419782ec382ef5c6c2a87c49c0796645183777a332775ea5f36c5948af1\
+            7f2c119b90ca0b4787633b96ab6d1637379196dd6b423d7404d674b91a793cf0\
+            8bd960d1da430e8f659b477830135a1cd230575b8977d23f1be977aef9d67fff\
+            88e3f358e72db42c0a667b7d418131aac5d14a142e39334568abc2f84ec3ded3\
+            140aca9f03be6a9c4d6ec8a49573f764e2c18b7a91f80b28b444c1427f6d582f\
+            2c85fdedce3d8961b3babd220c4c16e55141544b9156f609eb394a5faf721555\
+            bd1607ce1ce1261dbcdd0a05669bd62fd8013dc3128d1a3d58b6a0a93ced66d2\
+            a5b06fa2c60707a096a8a37b8d9c92d0ff705922d72d3c93b6d9b7e6dc71fda4\
+            09e47dffcb26f7860e2d243c341dd33fe117e42e5b005cfc9e0a69411adc8982\
+            0f7c5290d8578ec4f1d3e283da365fe329afd92f165428434b9b4bdb6d5fc1c3\
+            0be97a6e06c62268e239972248f21e8accec571d0e6ec633ea018f0d1f5e2fc0\
+            d7eb2f4606b8b8a50ff4f450ffe241bea657a2a4b4f89af1656cba98fc655035\
+            559fc8a99535bbedae5b841eb4858c9749eea419394e467346cae5df6a175a99\
+            fcf54f08b0704b2b9d20e7cb71eb0c31708ccf54bdb5d6d154c3d1d3efffea37\
+            21c74eb738a6c22d249698e87239b0611dbed39956edb1a41c1679df388dab2a\
+            97c25669c986e16d03cae7b8c8f34a6be744002c2febb5c17ee7f10cf80f4dba\
+            6f76cfd971ad2d296a65ed81d510dba30cac32696eed5ba1a1257b4a7281fd55\
+            b0fb5ac2180a294237d2a98afb848aeab6e25ba7c88f67ce8e4f92032dc9e8ab\
+            3a6c9ec0f530a11f3b6bdedfee5c2030abde49458c00b77d7863e577e46e9f51\
+            03219277e2fa3bdae050646b48e98502de5779b3b999d47ba4d54e4c4dfcb9af\
+            edb220a8d906ccedb51f4d3b924fa270f51077550bf65e41b7cfa5d3d8ad2936\
+            f21c4b38182915d2607eb3b7b56986fd5abfdb2adabf9d21632fc24c3443dc96\
+            3eb8f44e5e579f83e223e2ada0e6ca70f3f62ad9e5179aa3ff728ffc146ce449\
+            5677fde807c6c4b6ba3823303b9fc64dd0945923d373d836c4fbc2bafe636558\
+            bb5ca2caa532ab1a2f1cc168926de23717203158cf22ead94fc3f3cf184441da\
+            72f4e10df21488d4a0cf49d634ce1e57524bae160e69746f6768c377de1e93c6\
+            8d0f8e9b73159de08ddc727237346c0359aae85906b8e947d1fef4a5cbc2c4b9\
+            d1f0e542ebe98066ec680b36a674980062a5f1b22c0b20c7df8ddc12a2b17479\
+            4d7f90362c03c0fd01ecfe8e364dbe047f3de9980224231644c8fa870f4764ef\
+            57a6ec781f6cdcefd150240d0e231a9a7fcc5bc0a0cf40fbdb8af9a55aefa8dd\
+            df71eb85af88e464bbebef1ddc22da17f2ecf1c00a84a3fc39622c6f86e07d92\
+            5ddbcda9802f7d7ef450bc9b3ca146f6cce18b5aebd239d79ef39821c9d09726\
+            5e0940e4ec293faecbab696a7628456c94097ccd38907a97917195d8bed73895\
+            996643929f2efcd3ae32e274ea72af59d2a3e4490f344b16ddee493407b15619\
+            5b55b807b8b528faf8f8228f5f4254aa692f8d7a1c50cc879134b13919c4387d\
+            1fd25a0be4b5273ee371ef711be6dd862a42aaf3e130777e7cdc484cd4557372\
+            9c2d66726dad0b2798c699638d2f06eefbe50474d49d3c673500de4ba4f5e3cf\
+            9ddf41d44b19b055636b4aff8bbeffe5c12d0504ed55a11ea0f20fa668b2be7e\
+            fb8b920989854c7a13f13f6e533aebfe04833bf189f5b0ffb790d25b8ba471d3\
+            94bccb58c6c61a27a4c8ff2f955981e1269abd311349f99efcac42602042dc18\
+            6a4228f36b9aa5d853e40182b6e579dbaf480e8d734684e38acdb3291a7c8bd4\
+            49d4cbf2d69edcc4a82d932d42a15bd41b3628be1ab434247f424ebba900939f\
+            0c46884540b9220abeaaa565d159587593d44fcbacc7cd2f576c98d0bcfff563\
+            d7f8410e806ec30a9133c893afa3b3febd9d6631af32b3ea31bc4913eb565bbd\
+            5f4e7a5f94e6cf999142c19642af57eb74d491973777ce2423c0c5aaed84cb46\
+            10c0c346bbec8d43e8fa8d677cb7ca0d1d0d146aac175f348b180fa6c67bd50f\
+            aa06fe51ded5a74a0369a81777ccc0851ff9c0bf25688d103486226b1380cf2a\
+            f045ac64fecd4642632cb78042051e26bb659c60faac3af88d08c7f147754893\
+            25feff2e374d493e309db3096d466ef7170674b3a45a80f853ba23f014dc48d5\
+            d8e2e833afccb72d95dc14d929240fa50bd49548442243a480c728e3655a71b8\
+            78a481566d1fd0850cbe65a7d525e16158f30b54ef027991c3409c7ce6b0731e\
+            69f8b456d6e941835dac3fc97bb70a1b981a5ba5bd128df1974a75fca3b0be99\
+            1931e73f23ddca68922caf6d0d7857f29c97a0785421006fd039151e9e996e9b\
+            f0526220df626f958fb02748fd895c239882301dc9d589ee9eff91f4455d3788\
+            338fbe81273d26a5a22a3b2d6a9a1b51aafe7fc9fb7ba01f1407dae5417d95e4\
+            91693f75d1547a8a95a4bd8ef89856642d9ba7d45b2a6e2b863a26060565bd24\
+            96d27648f2281bee586ddc8ce47de41105af452392bee5f20e8ad260523c6b49\
+            3ec45c608a54cd7e5b1a6c02ae0dd448dff7482922c2f6a8428fb0bee31c5800\
+            3b3995a3ef8aa3937c4df42e8a2e459758762d0d12e0788b79ce6491188fd066\
+            694c32c6f408a33acdab7f2c24f28223747446676239514beeef46e71d76ae74\
+            afe4515ff944c8d0b51c8970d41447570bf6e0da1571e68ab89c51d85452faca\
+            0da1165c22353424e95a6b12a404d05851c4d0265179ca5e96951293401af578\
+            67b6c87e5409590d8a6ce8a6d33f78ec729d2be57cbe897d8c010a8c3d380343\
+            3480c1d59ad980c63b83cf2c6b01742e00e9ba122fb2a67d974f456290e43dd7\
+            45e29a493a22f9a19c8521835fb25dfc5173f1330fa02388aee348969e96ef52\
+            d44d1c81af04dc3e3cd553f7b02e7e8d857a99332a3deb59df8558bca3a39fb4\
+            15a65144869cf7f6811c21162ed1150e87816b217f335564e9c87b1c430b66c9\
+            9924baf6a1834e3c9bfde1e305e71b10e2655057df716321655f3b67055526d0\
+            a446d3b3fd9b181494c7af9ab0ba63916114de7b1140d7d0c5c2c34f876bc448\
+            cd343f0e26a0a29dd3f46922de798202af1a6bb266bd0eb71afe9f08a7dcf40c\
+            3ac058c832f0e840cfe0acb4368a6322e14b8b22355f22ad80ef9e578e1ec5c5\
+            f012f6622822ba02e58cb93b4a1740a58e91c87ce7f9c7a1c00ddae56fb811c5\
+            749fc64e4181c0ac0497ffb1e7813a9c1b750eedb14ce6b2dcb37af6a889de46\
+            9e94c5da354f9296b4d14c484300d69a1eaa17a87c9bd7ee793736ef538e30dd\
+            bfd494072884870ea63e270959c1c01b8deec63cc9655995629c5f1bce491e5d\
+            eaabcba81350a9cc7b7d631dcea21d98becdef4bd84da1c6d0360e245340086e\
+            04a4f4a067376e8f45e51e70aab5a6384a6845850ff0f5dd80610ab4060c08f9\
+            8fc819ee7697bcc25f8c0a5d6c4586e58bd93d90632e6082242a2c6d0e530c21\
+            65b0198984abd88944c4a85d3f3a6024da25953fefe71f088d2540311d8652f7\
+            8a76b9aee6df6b925b8238e13f57aa01b7967ad33ac0e277bc3f113d4c99beeb\
+            bae669809ec881aac070dd18fa1ed09449c1dbdf6081ad154d3fc001fd460019\
+            260c9edad6cae95f1d0509f0220303e056a464ca91e54b13f7e8f8aa5a81d04c\
+            b547d06958c35d1e88c8859d8fe32f13321d847e44809f230817f3d9cb8bb2b4\
+            a397a1929a77424bf736f1604dd471f8cd2680d504e8d8075263ea0054a2f342\
+            576a60d48ed182b671fe7fb5b88c3e8da642ad207e83099e0e2fffd2b2fad45c\
+            1323edb20a2e1303aff5d1316f24b410f44f7e7685493adf695c0e19439128d3\
+            a31de7305aac028bf0e9561fa3726160b5ffe2cb2e690af1cc93675928a59ba9\
+            a61b39a4676ee066b34912e09dad666a4ef6dd0dc0b6b9aa381ee8489428786f\
+            82616f61fa510c0f2dcbdf158950968abebe1712329dfc171b2967d418f16516\
+            dd3f1c2c26adbc31d02170dfa0fdebbfb9485ad71404b2923bb05d059f42140f\
+            12ea1c1aff5e85459ad187a11c423e86e881ce68d4c1dcac9166a88089a66c38\
+            8ec4e3c257644f1e3e23cba5c92061f8071a9b98e8a98b9de6fe40cee2d2df75\
+            ad31400d823155851a9c6660354ca9b89b521cdda1aa000a812be8b3bd131dc7\
+            dec461b5c71bd51a6cab71b6981c197c257a0e5f9345573f7dc2451ad932a2a6\
+            394714819cf6faeda6e59d13aba4f70172219e9fc99d01f7b9642b28472e21a0\
+            f08e5453e418a83a36ca589ec6c7d15c8d02b8948430ac0c422c49567fd87539\
+            97fc703069c5c7e03812b56af438de2c962e135d913a4c59be236cf4d91a8d80\
+            26243150828db4d898080b7bfa585425fb1b500030b3b48df4b902836f55f690\
+            3161ac21ba8fd1ef21f749d47f657a87b20d7067303685fa19dc74b89ce35c96\
+            59c1b75e34780c187a65d8afb2f3998f1d612b2aa6597e3f39c30eef83127320\
+            5592ae60d48b00f0f5dbea01ae702135db85fe1639c765e40959079ba9f150ab\
+            547ddbd96b8bc2bfde580b2f62fb4ca82170ec5d31ab54390fdcae53ed513514\
+            d825341398bbfe05602ab1513edb025567aff2f5dea0068cc07920c9a5ac10bb\
+            eea1813db18105fe4e9f46615fa81e99f2dde223cb6841bcd57d79aefab44ba2\
+            5a5523c2d92d2690f977d1fd372be17cbea2e72617ef45e8a492bf3f1fe4a882\
+            f20cbee94046fd62eaf70f022f6c62a8634ae4fdd93b8ce7973751c6191a8a83\
+            4d38923b2af4101c661858e6dd8eb84d1c5e2a9093d58ca84a497a601a9273dd\
+            a10cb02abe7ab6a627161ddeaaaa5d1f863bacbdf74f1ea65c2f77fffbca4f5f\
+            2232773da49e77e050fed35f694e3b6c935d9e7bde5e158c0dee39e5aeaed81b\
+            1100151619541d24a2cd84219637f636dff864c2c3749ea02aa6c79985563ab8\
+            ee4d47ef4e5867616c5697890ff3bb9444cc8b84a63bbd16c893e7c6aac73a2a\
+            ae4cd86397eeaaa70597135c786c45af25e2bd46a02599a177326587314e2706\
+            aed7a557287906ee6d01b8140a0623a07a340aa072314c2b88a1a1dc6d76afea\
+            e4459870efb41a5b447b36c7984adfa5cd8ecc0f22a9c871b5c67fdc5a36167c\
+            7c61d12f38b15285961aa0f529ed4ba46280a7a1b6a00c2b6f25d035c76c321b\
+            2050125097d75f6f69a448dad8cfa682faf6aca3fb7e70b0fdf24dc639579dc1\
+            3498b10b61fa805b3fff77b7d6db08296dee21231bf2f9a75c269e59bdd4f8ab\
+            fe3fbb84b4b00faf18d5d0bcf8028557319472bf6851ba3ab7a60a704aa71e42\
+            18cb276a2435c67b285e2d51e8eeae63f9549115bb505f8d84b3b15623dd455c\
+            241ef678955c840662c0a4a6631aefa4479a28d00b92b3e5842efeb74d8c00f9\
+            3b903b3aa1d7b3513218bf5bfa259f6f516ba0e7581c8f9cccf4ea8fab2f47a7\
+            7a478bd78fabcb1f98966c7ba0b3ad52e4c6d15653041b02aa6cc16c39696aa5\
+            d91fb75beac7b08d8497a8c586d362dbb50aa0ca9790000d44e13a598173b3f7\
+            2c86d3ca68e5291a0487d997806bf5aa7fc9faa560bda829b08048d5982244be\
+            d7324ef1bac7fcc1397ea7b5269d2169e634828d64fbf4d1a50dc7d548dcb9c7\
+            f0e4d1b2c2d5ee1ca1bb105a25184f6cd7faf5980584c58ad062358294de7db9\
+            f92f248a1110952e7203aa066f00fd9a07414e84411a8a84adc97c2a153b7834\
+            52dcae76abe9a68eb864c9c450470069a81099f2ab8c89306c02d84bc79f3bcc\
+            66a3e6d175dd554174d81a7d979de95e7c1149434f6a637192fe6fe3e93f13eb\
+            1d80b2d3939b6b2ca54897c93cfcec7211f409d77e21679df7850c599420dcfd\
+            82879382610c99caba9a685c9b434cfd6d118b795a6a1522516a11ed94457a58\
+            a3dff7106f224162bc44d253cc49927a401a6c39087abb44d08750fa5354bf6d\
+            fb9db0865bff659028897008066f0612c00d4d2ee77de135ab4b5324c21d9a7a\
+            05fca5d9a8e5e881514e38790bff2e2c1ec58dc562d2ba6a374027de1c932b49\
+            2ee2f79158c2b56b2ce7743b298a7292389a65b7181a7c243c3c455bb1450856\
+            6a6ee76d17bf93121bd5c6d6d02f796f1b3acaf2684c2b97a5a62728d10d702e\
+            3bf520df93e9cd8383965902ee068ab746b3adef7d32ce905b6c075ba760620e\
+            744c92594ea0180f2a12bc2cee3e8872d30132af36c980922884bf01b9abdea0\
+            e7afc5274561315e529214be8ce1f68e81a7da66ed4d28e9b3774fe3384c0995\
+            c66df10a6066ffd60639f0aa6cd8f652e9be18fe0d2594f845c808676c74878b\
+            5fac91854861db4b5e5bed660e0416b4794dc995b8e99a9084ccc76c3d48ceaf\
+            240aa93f433c94bb1e1129d5de5eeea19f059cbaa1eaccb89b695e93c4a99029\
+            9506db89a9603b8c70cb8af571d56d13412fd9c2edc905b735f1dd18f6a6dcc6\
+            c3c2689b6f853c41e734d168a9277a0d3e09b533dee244061025e5dd8dc0214a\
+            a447c0c753caad8c70a8017248d58a674adc4e26d76f8270b19199f18524ac18\
+            a414021f32f5dea2e3654ee08da70016a2fff9c98aa204673fd305f240d68209\
+            432f4a7b29aa9b4a06ebc6a464a9d5f63188d752bc5639fea62af1786ee84a3f\
+            8e50eac7c2ed4f63a29c1d057bcb65e44abe67c75c1aafb79cce842520e78abb\
+            6adbb3257e2faf96c45fd3247b34484f0530f279b55769293ecdeb23aa1d2ef9\
+            457a4ac43cd97224e6562792733b4afcb762457c4ffa5c0aea87c8143cef66f2\
+            6317fd61a2e23fa6e048427d6f8f073c4f5488328accaba48a0e5e5b8c5f093b\
+            f26e4aa6f230e2f9b12a3ce0658c18f2fb47f2b74336d4cafd3bc37b657a4a5e\
+            85280ed3e9c15e332fab9a993d24db66b2331584b70b7614e5d177a1af524d2d\
+            1d738da9566c35458c578196d2c83770eb546030fbaf97225d9f6efe25ac5af5\
+            44b76c5b7675d72e9f3d323b132365253cc02c9da2c6de1eef74ecd4324e2281\
+            41f550a63690803a01f3e5d4f78d3f15ac634d49c619377adf2950d660f60497\
+            b4352ada3770f1803db119263693af178dd0a70e51f9b3b99b5a5db608a0ef90\
+            c4255553950182e505f8552425997efae1d5bb9bebe9ff4cb3e93e046e34d128\
+            651ca41039c89cfeb5d785ba9d625613385ad369793d65f7fe3b9986774f24db\
+            b4d9e05ed6ca171caad732889fc87b2687caad9f8ca2c366cb8c25219d995024\
+            15456e5eb295fce443a2e7ed97544f55f9e42f8b97c27fa126d05936633a8686\
+            dae7a37e46d5bbaea8e7dbae8190259c66f1d85642a83a75fd8b35c830cfe20b\
+            433b9a140811b245ed707c985587cedb8ba34eb913bf34dc1980d84d1d43811e\
+            bd8d41064099bba28dc24515ad88b1dc5e37e8955ed265f704bcb622c58455d6\
+            7212ce5bae5d2630584d840c1f6b796f95f9c78ee8f0f8f3d1d406becf3df1cc\
+            31d5f067950a96d78a741f5be8d1b593482aad706816889e2d3fd042b0492f57\
+            408c6cde10306bcdf37332aff409bef677dcded074bd72fb130a38eabe5de8d7\
+            c747c516795435ec3433efe738b365bfd9baac730e5e0264d4b3b6c43c26ea5c\
+            60d3a0cae0d0cb23d0c7add0338e9190ac9d4efd92d916c2feb266182fec9e8a\
+            8663f2954e4c48337d6a8211b75b2e2bc47915ff2674db7f1e6a4fe00a44a8c6\
+            7332b93e1b0715b1468ea54a265c8bdd3e348750646b2c0255e39d4e7e31bd2b\
+            10f79bfd171a7f1726c057c1b1cc34073c45c58ed35e8e7645018478ddd6c10e\
+            2331bee7631890775039e449f3ba0aeebcccfa4bdc90b8b0213c41bf319de956\
+            9aca2e3b47ad428b8eff244d9674df55927ea1b6e0db38eb7b6c3b422b0846a4\
+            5d18b559b972358d1176a44d511e57caf5f8ed9994b3e829d66fecb91f32e6ea\
+            80c50e6e8071e375fa8810981e84a3fb4323f2e0e4d88dda77f61693bf0a1665\
+            8faade2f8fb504c398e46388fbacc7a7fea9ab0c0e0cab0292d9ead68cafd585\
+            9ef432bdf92133d8cb20758f4e4f5fcfb38c061a12610f5c6f311c49f25dc282\
+            8a430b51649016803b2ddf7812131d7c222c84090d6d2874a3e5bdde18a599c6\
+            706160c36b9eba8e9f9acc68497d97045021c07944cb86f7d501a5589acaf636\
+            f89c16b5bfc3aaeb086ae04cb92e9a4ba25b081035547eebabec04d354717e6d\
+            d5c4642a771677cf7c24a16393f25e3fbcdc45a8d9336362f5775ead7cea9a4f\
+            79896fa18671e882be41be9733365c5d954389f2d17fa0c68d5f653fb13c92b3\
+            47380d2e51e987395ef15f76adf31d52bd2d28979baf2f9287fef527e48c3841\
+            b7af9f2083f8fce7498c1b2ae1e4ef2ef8975a282713c224db5342149399eccc\
+            f7e9bbf2e5e6b9eafe9a65f21eabc5b820196d90cb7787a3cd5ed701e2558af8\
+            831cec45543ca0a5795e081c6664747567cabc2471d693a1618c46c2f2a35620\
+            5c0ff9a8dc1b682a86d9258089ade366f14402fbee04603c30c2a676fde36cdd\
+            8e0e0ad0eb5efaf34ebe3c93abb20e6b0309151a525faabee90c76666488bc5a\
+            537711d1ba1bcc1d6e6d1e7d06d4076b49446058789a8dabeea0394795ec7aef\
+            1e53f3ec9d3084014f16a3839cda1992c9523c568bea4a396951273e2a9ad654\
+            ad28d84d231689fb50ef67fb745a86a94f08e2d92099cbde1cce1a209edc91b3\
+            b17ad67d01f20d3a722b7753f04cddbcd3b8b9f4ed45b4455385915f55825fa4\
+            a2dbb482504b38cc51092d1571ec7ce230bf65359b06b30abbbd66e6a2d17b74\
+            fe09fb247247123d8db0a2a01815a3f4941e74d96b2905656e1fb1752095d6eb\
+            0febf57195b36b49a097e3db45c7d709400dcc089741040ebb5622a0c960d4f4\
+            a0ca2dc73e0e2a2ef1f3b7aa4fa1a87c72d646952d266ec8b76569d83485a979\
+            818c2ed2183d415c4a24aa1c07ecfcca247d01326f208567a3ceedc7eae1de9c\
+            2e85719cf454ee6d29a480247decf438ecf8f19665209107a9a79416bb3b0212\
+            a337a78db01cac4b58e0a640acfd054970309a6610ab4ba88da1a577a23e2d34\
+            0d7a73be5efdf6ea5b8a701a7c29f934d4f1f32cc0100d7ffb795693337f6a68\
+            d44352837fd644aa33da98a77a155b0f29f6c173caf4f90b1f846a74564de1ee\
+            6d28753e8c353db827635c1526a045656ed5621050ad7d1ab727d1eb3469c516\
+            fc91a5ddebcc6495c1283e5be7911fc2cad902c6b33d1e66f7ba3d3fd0078dd2\
+            13e06796272f388c41e61b765c296790fb9b1a5fa26547bb29a8c9f21a658964\
+            8c79d1af397d449beea6b8f5a732fbba896eab08a7b0c797d9c2aef10c816faf\
+            a3de5e74c217a672414480ff28f159338c7dcff94ba6253809e41e85f183c454\
+            2c6bc1baaead61543013f91a15fd20e6b4abb418dcb9ce041a6c0a09fd94722a\
+            eadbc9cb20e37bcafcdb3ccbff3b320bdb1c6c9c58d6b7e3d0b616d755dbb5df\
+            7737faefa1987b84c1ce7f02e73d93b326788ac5b00bdf9c5268ec8ab7e8c7ea\
+            f00bce632d41abe2cd6f1ecc227e09f4e47549a938faebc101499cadcce9ca70\
+            37e1b7fc7f9e3c458f96471cbce73182ea09482c528b8c670560cfb09b2ab795\
+            9ba0710ec7d8335ff7ab4c4cf16fa30286c9f2ecfda8d4b5f2b7379263daa41f\
+            dab9f799e1f8afc91f752f937cf2505b5284f6c2a9213fb1ed161a7cd6689cc2\
+            8e0ce195559f4d912aa7560e73b34b153aba6c451ddb90c9173fceeabc4ce847\
+            341d749600e9d5ade3345ba2cc9e6cc5556b861bc23d0292b4d908ba13d02409\
+            5c96cff50d00105dd5ca1f4db7aa5c2f91c0ca6b461eda48764e8f7eb22f1246\
+            569900bb500ca811164fea75a4db329bae4c74780fb183c5d8ee7f3b12c20aba\
+            14df7553b1199477ce8fe87d1a02ecb5c1f02a7614307b0b74d3428d600c803a\
+            9222e67e9abdee1161e09a7b694b796267e5087681fe19f2a8814cd92422ee8b\
+            af3a43d1815c707ef64280d8e2eba83e6796a7a2d20bb7b1569d46acc6056f82\
+            b9e4e59dc2da2eb9318e51d963ed5af2baa26929022957d5287e206fcbd057fe\
+            fd78272903bb3021db0c59028a38fb0ca5e88c799a2994cc119c4737e3538c47\
+            322ef333d90475c7730608fa4193216475a588e8deda00b9d867debd94910677\
+            78af17d363442eeec917bb98e6b6752fa7a22ccf2674d4372c708346a91682ec\
+            d1d48c7fee654e826c06e5856d663181370bd75a9dc82e9bcb092e46885f910c\
+            5b56f247e8a888e908a63d7f002e797060a7e860ee36138fe76051d670090519\
+            8955327d22d957f10914265340495499e1bd9d1e2e49419e00beade544cbd4da\
+            63a56d327dde3712e17e20b4e88b9c18d5845b098bbf20c941e858dbd9005d6b\
+            1964ff82a655dcf4276b240f373e13eb3726521bad6949e09775595c8c77c324\
+            f34673c3afb879502a48ebeb3082a9d5122044f098679af4d26572c84ad214dc\
+            24923e2a9535c433c76b4349ea6c9a13c44a924f700624c8adb78f7cc799e750\
+            b95f9f78fbb34efaabc255efc4fea36dc829c6d850c82bb8455b8cd08101edf6\
+            80de439208415a9e9f7aae0c3669d811f68dc871d71c6ee9be5be3c4b1e0e82e\
+            49466f9f4ebb8f3e918913d3e1e58d32a5676ea1c2748e322cdcb343b560e965\
+            ff9a41752d50d8ef2703006071ab3db05ea863671cd064202e18faaf616b3af8\
+            c3604be602a22666142e7dea719764c12eaefba5b861040bf24449115426804f\
+            7b84393f85542b29ad93bfdf28e4d98273d7d992515ba567388cd99bfce5d8ce\
+            ecda29ff74167b7d2f9e149d4424ecbb259e5ec1dac075560cd84d66962a893f\
+            b4a1a30c034f2173c859e4b358b057c7f426487791e2b53c1595807254eab502\
+            b3dc38220e6da75975d9dbd12e8612a70a3a288ba6c932fc6f7f51c9606a15dc\
+            acf458269dd0040079eede0491da24def4344f832491ee4b6c98cbcb31d5be73\
+            4c46748c9997e62ed6d1500ff378c108c213ae91759c3d271a21880987f2f502\
+            e9f6fe40f97a037be156d5f061b349899c40ac250b8124236251dfc3b5640d35\
+            f7877d18f6668d8ebe310d0db8e5cdd37396b1486b17ae9de51bc637b903eb25\
+            e713e5849ae510b89c3fe858cb77cbc954cce1c011e184c8d65fbce4472eafb6\
+            3cf3d229527eaab35c202f6623b75c85d464b7631c3973604088895f91f0697d\
+            031dce7d58f4fde7b4bc09df1a8ead80d127705465a6a9894a1d09993d3a19f2\
+            30cbc10bfae6a8953d5b5bfdb131297f75e9de4fdc4dc7056b6f9032b17ee85a\
+            f053c01f10a08b9d9680b2b5d98f03dbeb515910679c4bfae0f528f6f55cdb6a\
+            efbcddbe4233452b5a83a971e892d51b8ea2a6078ee1d48902427b9486a317a4\
+            cb2fa100a98fb82f58984f134cbbf7909be23b48a14f966ae93ab970bf26eb82\
+            cb0c4603e22bac5d4ad53c9e9a3ce57371ec67980078e8d5dd7c24eefc1600c2\
+            5422a5fd2f128805c57d5fc88c6f7dc5465fb20d561c0f324beece77036ff091\
+            d51ca361ca675351535290d6e1e72056156048f0d6d0a3f58056880426f5070e\
+            fb89a98a85acec71a72d6940b5cb2a57cedb4986bb5b14d419fc8df836a886a8\
+            f0473a6e2e3e73c010f8c0150af40c1bea83cb8ec6bbf1630bde97be022e56b4\
+            f9fe52efd3976a1a34f4bd759b18688ee4d0bafd417749d6dc973831a7d9b486\
+            1163d7d548d7d317c16d30349a7a1443d640a813e29bac32b002382b58772432\
+            291f5dd4878c092b4dc3d284e52fa7a05abe7a9cb06a066cd193ed7a8ed86632\
+            e4b9d96d300c0fed032c73bad2cebc9d0efca762bfa7fb6ab88f80f77fa3e26a\
+            d26f05611681423ccf93fc30ec2bfc2e6d73473be64c6a99240205fb55823cde\
+            13f9ed9b1ce6d0e4295c77820eb79c6d02abc840bf26df906c3383631e417bd5\
+            ceff9a443dbf5678d3b7c4f882d6b66a327a5995cd03e23261ddf18d08470fa3\
+            94b17f204dc27dee41f6a7281684b29d2e942850c82ae13c9ffa6cd293284aab\
+            8d0a944d5275594681a91a70a8800d765e04541fedc1274f480ea1efa0e1e355\
+            b903179e7d52921b160bda0fee643b8cdf2f95a24aae742f9e728a62e150ca23\
+            7ce6b05da2024a1b1649248f345ae9fe71f6a9dafbe53627ac027510e06a55fe\
+            99aabbb8eb54761db5e0ecfc1470562e683a66e0590abd604115b09637c84499\
+            e73d40f043880eea3d4efe5b819d93e3d83806ed32ce8f953a4e7b7284e6e8ea\
+            03cc42d1594235f57f806d209a3b852db4bc3a5cbd315711114b5a158a00a118\
+            4eefe7532ca0f4739584c21bdaf6e20d308dd39a5a8f1607e1f5341db04b3a57\
+            a0aec0a183ae456523835553eb59dd14d80d946a3b06797b3803d8b75b831007\
+            5a0ef5996a4916b8133cbd32aca6af5210e2975da8910cad6b36fe0cea1e64e3\
+            4e2eef9a241be6cde2d26056cb383ee24675de94dee5706f0cb149a3230dc63e\
+            e9107e7c7a2c288bf37ab3450397fe06a068018b1f9e7a7bc84689d6a81354e5\
+            4d47ddd416bc56e2d14abd8e1a8310ef67793152493a2b877d2fe38a49844dd9\
+            2622c2afdc414dbc9fd377092cb4f215d291f3956ae08e3038c61ff2a1bb7749\
+            348ae80a2aece8e6b6dadea00616882fd255f894e52c79eda28684037a9475e0\
+            06141760672ec0f74ab65aca4b8ec0fd61044539b73294c8dfa6907415df2f42\
+            dca7416eb0d31364e0d702b1d95b8145a829c1820b5f48723f4b5a64c1a0d2fd\
+            dbeb4e886f40b99d2c900fc52c9f4931d1b756bd1708c60b58b500696c077d16\
+            eb6629781d3e4181b80420efd6044da048ff9428b1457a626e94c36ea828ca98\
+            c5b3edc3a30d6d1b75f824b62943e97175f8ba0d6bf4c979391efa5b97c79b8c\
+            76fe8cb8d7fcd6dfdbd6a777b9017f8b334fa845dd26879240e2934ac2607f6c\
+            d9da534bd10d4f5d1ea4054e9cfab4a1426ce492f03cde056a8159ad52001216\
+            8500ee9168a83d93852d47ff8709a6e33be3c47e12ddf7fa3997c56857cfeef0\
+            11368fde0f0b97a2cf43a14023a9fde8839cc0a33a6d1db2dfd2042c44880d8c\
+            7285073b9acbc27bc3891107f2bae7565c0ef1ad8e61321a776a107fd5d614f4\
+            cd5eb71e2a0e587d7780900ad40c59011eaf6639929fbab5529ddd19e5c917e6\
+            09446eb6fd2f585929887ce9bf316bb3bc58fb93106047d88b5cd164757a8d7c\
+            7b1b8d0d437f8c98096dcddfc3b278a084a3d7c6a316145d31eb249a3762ed65\
+            843ca0a7b8ff22311db11518fc3e457550762c92243ad3a662307ceb4976b5a9\
+            46b2c6795e5a9463f7c78f236ed051ecebedf9185df74854b5a7686b89cb798b\
+            369c2dedafd0f14f9b23fde45c72af4aa2fe324113bd74e8b562a1d40956eeb6\
+            39b80b1051585b1bb751a63d6b9c91c5cca2da3037281781aa1659956770cf9e\
+            50d0db853dd711f0e97ad90a910d190ea528675a5ddc559ac22ddd421924e45c\
+            38d9af583ecd6602f8b4230107eecc870fe19e9ed74cb49680d24a7d07d60945\
+            48782819d748feaa22db4987de5accf6272124ebc6bfd7dc276f4946acaa26c5\
+            a00fdb4bd618c5f6de272069e3d3f07f9ef474849505ae43c69f1f6c9cf79595\
+            e8e54686905ef34de9621a19e8304442a06380a012b29f2a86510a9a9afcc780\
+            f200ca0c71d1404b5f915d0364005c73f163c2e05b2426d8b66ebcf2d72cd976\
+            8425e2694475e0e9fd6ede028b51a44e43f5378215728f1478fe557f2b0d14c4\
+            8bf30d6706f3b67349d74bbeeb52ae5dffa0a6652809c43f52f2f79fa65a822e\
+            31940bdd265cd213532ecc1cf89aefc2838d2e7bbc103e65e8a98a0274321aec\
+            f66b1d9e4110a63995dd99f0c92d4184c67f951f39c391c061718a25923ac22d\
+            592292428e16f3841efdf2498d725a46470d0ff0a1674de332343d8ab3472408\
+            36d889acac76c1a0fed90a206d52e6d2c5e17f78c944ba31c232b9eca81d8702\
+            0cff6fa06e418f5f86cf1e282c99c834f5cad6bad8c0759814cd8353bb7a3844\
+            8f0b46a15c0f17e32929696a74a6199c372f9eff0a6b4f270f70960f275e0e8e\
+            9067575ebc4fc8bd2f1f6c8a0cac156595b1247f2522f9c2e93bdef34d4eb85a\
+            0c1d29c29d58b1e1924ce95f2fbbbb574437a116a83bb970c6dca97f4bdec61b\
+            b6a669b1f5d62db3976813097e72f68b1749b2c31f8be715ab7a16eec099f047\
+            c138e49be5527c5ef290b27b5739fae44cf042e49355cba02b94f3962d2f7891\
+            16da41daa76d3db7c8fe75900b6373c7e783dce252a1fa07dbd72a229e882a80\
+            2cff2ed0500e9e24c02b08de70c1551e9e227718a076991af6a568ba38ed6ecc\
+            27160d8e5c8ad3cafe920088da7fc38dc47d93363dfa94793edd631e1e89fa72\
+            f2a37670e5238e9ccbfbbd467e8325f15b0470e53900753d02d88ad844fe6ca2\
+            06d16a87a96ab4d8dbb3409069c69dd19a1dee7abbfb737eeed604c8f405890f\
+            6713ba0d51445c2a399c7ef1e5840964c16d1255303fc2038065e9bef1d69bcd\
+            45598632d148fc2a0d15d2b2527d78e31c0e57490f90c3aa18d3ab60ba66ba71\
+            89aa562c6f4bc17196cd067dee59c253f0ef4ac346b16a3af0294f770c8e5880\
+            4873422807a3f505777551a8fe3bb0c4a7425640e2b93c8a4a2991ecde73bd38\
+            81d0c1989788bb39ce8ac5a13337bb01c977f9c0864d821912658e1cddf43c4a\
+            a625308957c1bcb68c7d12099debf4c5045a9bf849ad6857927d88a4772188d0\
+            f1b8dfdfb9bd3692cbacd3a9986861961a56967eb7e399be782d1fcdd1427a76\
+            51066ac06fc5439c0767a9de34da7e781e07ee46ffdf010e12e423815edc5c8d\
+            060a7e5475a6ade90aa48dccc52433a7142cb10c9c61df2c23bb5991796f15d7\
+            2c12385d31aa69dcb101e9e4571afcb39456cd06d4d82a0645c3102a2eda9cd0\
+            727667bbfa685c3092d225db67a5cc223f4aa0913079d5ec8e26106f42b26422\
+            7d80c555a6a30b25f405784b078fbeef0ca249c8dc378b10b4381bd935f3579b\
+            f88a56f05d7389584a7fa721dd9b8eb542d9fa60d419854a5c8456b4d2b73457\
+            b3e32c2c175d2b36271d1c075f029fcd8404ec3176baaf3dcafc2f51317779f2\
+            f679baa46e4e6490dcb77dfff91893d315f184b79ca435daa22a9bbda927cae9\
+            5e095d9cd67c09f4f6aa26635bc846f26c3b912eaee7621bda8a0ea9aa5babe0\
+            d81fb42470df5efd5b6956ea704fff59649fb406880ebbf63887f1368de6facf\
+            fdafc17446f4eea5735dee03282d2bd2d3b6c9db20d4b33b4aa684729a46eea4\
+            c3544b867cccc67765bd54ee085ce17c6fcee573088ffe3d63cb64e16cdb0885\
+            8050e3e9c8365ac6237c4cd9907d82350c0b1d1d6a0fa75efc1a70667106b839\
+            a6ad28f74853d48c58f8b5c61e0f299ce3e5089242cef96844850dec255f2f57\
+            6160e13490649650aea028a0a0f0394ec143859b46c8e3c3bf73ce15635b8da3\
+            2319dd8e67959c82e069776fe8455b51413bed3c52ea026a7a29f16d1d338434\
+            529558626eae8eca7981082b68a073aa901f39b16657be7aef39fb24f3b11058\
+            8abe06b442be715fd67b437d8079ce3aa10d10cf3ca704a05a39a476a9755860\
+            7d54729bfe5e20f8042893328cc47c7ea8bedd6ddf3b1e1c44db8c8a255a2739\
+            2dbdbbf79ee269dfcc4963c97ead2f1df6694251526273e2876590c4c3485eea\
+            d3d134ced596f2318a82cd8322b71418709e029cf707cfb679ac1e2fc4f3a32c\
+            0253e8e5e6e226bbf9da038edc802c1d36aa31ba61f3e621ae4ca774c988bb53\
+            843719d3434bf83982dd028941c6182a7739168116d2f2f9114342e64bcc91ab\
+            8f5f8db40bcfefbcb253ff2fd6c6383a354b14ced6010c98f15ad1d31ec02a97\
+            896fc0215be9b06377bb73f81da5cdee2d057f46d4e7207ed5315c363b63743d\
+            d634a80c61a2662d856b35627fc90bbe66625725f00357bddb796bd004e0e2a9\
+            9cdd4c0dbe7cc68f2581013927f7f02f8aed164ffa1416ac5b88f93bf85887a7\
+            425e6f2ccd4f98bfc1c5a2869dacf4e71118dbe52710e217af98dbd500fc4fc8\
+            1bb2af06f757ec4fedfaa9a7007657dbdc0d9377b96e73555fb0cb3dacf12562\
+            77264f707ff31e3c95907e1279ece9ae2273527a2200c0e5ae1a11f23edaf543\
+            417f6a37a100c2a246b2bf10b06bb62cac5ce601e3f96932767a78290724c3ac\
+            c4e55b08d09c70d5070527fb597e10ba018131b39e6c0b57b54d7c5057bcf9d6\
+            d80012efc3a51ab204ab05e25c7c9047f5dc7ff017606350fcc5040889a85166\
+            e56ce30a8b9337514a1a1ddd84cd20bb28482378df9cd77998334570be64a29b\
+            1dd20d3f94b8ddb0b4be7fa45082d08cd34f8373c3ef2878f4d97fa9d81c1fa6\
+            e3a0e839042766811d29665abbd2cb5d435ba1f944dab218e405943b053b69b5\
+            895ffaf5f493318a6733e218d3ec3c47568d4451c7e35c55a1a2beaf74c1f239\
+            6e86332b9e3dcdf2573b379c66e2a564ae0766c5cba71c0ebaddf2fe6026c104\
+            a017c6c6ec392840af694376c76a952c3710c4082f1ac1411a682a7473a0508d\
+            2d00fd3d3cb6c9cfb92abc8676219c2c81b4fd3f64d6b34b9813ff9eb70a51df\
+            023dd11407d65012b0cbb374a987bc67ba574c2c1f3e5d93aa90dd9d24c0257d\
+            b8ac58aea08c6134218e1a90c650f873da70c3fe1eaab251f187e818330db120\
+            eb46592a69f0327f0bea76bd5d7fc11e2773acf01125b197ace429bfeaf2cb89\
+            cacc473434885c199fdb0e1c29fc59d4515d4adc4f7221d8f789d8096e64f01a\
+            ddf62cffc069f2268e3f4aaa359aff062145e47ee06799f9f1188e59fc3c2493\
+            9c191919b5650e77ce3cda01c3144bf5799bfe13294957c24b49b595fb164d66\
+            b399d04a25e0ac454c09d971a620c81da00afb3d66e9dca68feb158b8557a625\
+            9168464399e75c9c621efaa3c63a98a1131555d022e6d9294488942167f81110\
+            2662d0c9c96e9d65f98b7d0edc30f41d7e6ab6bccd63c9dbaae90cd6c844c658\
+            3a6274d5e23529c51f2acf2b50408f10cb809b9c5cea7f30fd8c5b16f6761b3e\
+            3152f097fc2c91714494ec6f3e44346f89064e9828bc53ae6267893b373c4a14\
+            e30b85fc45178a719b4b49b09d7463ffba036b34718edb5c64787de1ac1f0e58\
+            74678d8a724a8d2c9eb18c33ae8da3155298d8bfb2a01dda455dd8a43901e23f\
+            909541ecfeba85a3ec56346e333f282607d2f3cfc6d24ad76f08476a25b0e425\
+            39b1ffa8c8ee99ee0b88ce67027999e1adbc59f3c84b8a06d66d717fddc6b1b8\
+            8553b24725650c17fc929cfe1842f5480b88f8eb6312c87ac3a57aaa89b65254\
+            07549fe47df37001388d61a6d473ee95af5703a6db5d192fc75e23de4cf4a7a0\
+            18be65d47c6260e244dee54e9c5dbb36a8aa8ac5d124b0ad3538a50c39a55ab9\
+            8125e280c052910e8577ed4e4ff5217f45ae6f8853d09d2c3a1cf0d6c484a90a\
+            79ded54e9dedb20086d69263fbbd71b4dccfcaed44f9f4f475c2fbd4159b96b8\
+            97322747e5412d6fde9d0ee226a908be4b2c839388cccc6461a5497afb8c2828\
+            71ce8486091a199b6213721faecd5b65ab16251dbdfcb10b474b9a3823747ec8\
+            cf03229bac8aac0fc386bc633beccab444c00cb80e513500e6fd8115a93cb345\
+            f267075cd92c4b172f88bc42913a3aa34fbed32970bd25a89522e3fbe1abeab6\
+            ec9db89389f18603c76b68a38439dec3008ad7ce146f78405903398f581265d0\
+            f8aa2503b2ffe657d6b1added2f887280d07f2e81bfd02b368e5712b8f2033a2\
+            4e094bdca30c27abfc6b5aa68ebd099a15139ef425ba8cd79ab0569152475fe2\
+            ce31fdfb1672588370ef4eaa812c398a0c8a43938b8e6ed4c6afd746326c7c33\
+            48d14b4018e0cfc2c65dd5573cb833bb900b6cd293e6cce3fa37d2c28d9d1858\
+            8f37281c153a6e345396a0bf60266ed08e7bcd9da1345d6664254df08c373b61\
+            cb868dbacd463fa61bef043da8c5ab0dbf6f05df62f4314b144c85000e4f3e37\
+            02d4fa87c4d768ede68249482f9cf5eaf45c82cc5dc72a09f527803b7f68d08b\
+            3a98d4f6db70bec27dc875079aef9316f563fb12eeabf7d167f869a2ca72366f\
+            4a92f5b73e3a62d0d7bc228bbf3dbf6cf115231a5c1f5adfcf11e03f60aa356c\
+            4cb18cc3d2cda57394ebf90fc139e2543d89c5dde7daebc5eef886460bd9a945\
+            dcdee691db68637d8ea1708243324ecdd087fd824a3263918810f34a332165e4\
+            78e3a663199b3d525dd8080b8dc9ac653b18c9a6646a2aa4b9065b015cdc0d5f\
+            3211fd79cff5bf76934f90e0f422a8ee1fb39d65f8b4b80ae96f10a1977fda6a\
+            50f328551771da72db193a61d1b229aa807268100d9a96071d9b650c5cae0631\
+            e50747c0f4877438cdbc6cc5b0a4c983a17cb11fec61d8302a5b2a4cd117f19d\
+            380ecf8e949738afba5054a527213abcf5403c49227d65fb2b2b19fb4113f78e\
+            b6986d4c32bcecbf37b9dc6f8909b14eeff1accf4e3f7983f8bcccdf6baf310c\
+            91603a3c637fa72d87953cbdeaf4b4ab53d0394ff73063ba28830673570ce485\
+            46e1da45dfa82c5dfa061f36d15172cbb601093164628deb1a793248e77e6b51\
+            5a3d0a2875f5ea031146adebfadc33070d70d5d4197044a31f827b855b187dd5\
+            59f1624c659ef71fb6c35971221d0f38a626b0da0c5e6fe46f7cac9af1f94481\
+            fb88aa971729c6ec29050c6492db1b419970171393af2215badf1af89ff33e02\
+            a352b8f81a4b1da024d23edac70bdb3758b8b84fba25d4f8c3ca4f9aac8a0860\
+            7392b2183658a45b50d4f0a8a8994bae136a2b1ef3dbcd0c53787ab85bf3e120\
+            8fed57b8dec27502932bae9d54eee8438128672fd1bad3f121c05f9ea024e23b\
+            12d95292de4be1c9ee2f4b2744e096d3888bd8aa33cde57bc063f68d53857c90\
+            9cd1a6ae2a37c4ac51eef251d2b32ac7a364b2fcb2980d75508d3a7db247f323\
+            ed291c81a6107f0bc19795cfdbbdbd72f282fbf4e9b3c441a8f82d7bab83d865\
+            62360301d622b15e9d7a24e124a82170e7cb3b59ae94c00e94265f592fa3330a\
+            1035a6f0546ab9161edf8e9da965ad5f08dce241ad9ade1d6eb735e3f5d6e25e\
+            c3a94eab161003aaed2946fa672bc973b230aa5ce4fc4dddf3ce4b4b8cc1db6e\
+            ab028863f831f37304d9af5a4d8f3116c35b745736416d97156d1a24e27521d1\
+            f84d0ae357df706bf510f0ef6747987dc4e3fdbdc28db3e4285888f306e86d0f\
+            6cb8870bd65dfd066fd403db5e7a456419bb5740d80a7858f2e0bdebaac45ab2\
+            0fe18437f0f28e4ef0e7e0c57e3574d9254ff1a2fcde94fb1321262731d26441\
+            170e0894b8511c64e04c66865d9485f1133143e539a5d63b297c3a623396587a\
+            cc2d3377bf3c17a634352d1a0828d8dc7ff1df6fea4cdd410d7b188b94194074\
+            aaf71f80c153b5757e7c17ad89e9f0f3a6deec2cc3228b1667d2ac6f6562b7f4\
+            b8951668266129023665c9bfc9792d53f6e31ca9764856f346557178af6525b5\
+            0e11b2c2af009aad4191418bc7c8b72b66990bcb033d1b9f9c8ac191652e78df\
+            5e74d0fe1d5244379eb036fc60612b2a638f8f38bc3196e579a27b9a49814c91\
+            dfb72968ec0150677ecb60dac601fbe5256a175ca88f08dfbdb72a705b30a122\
+            bcb6d69e2ba34eb9c5caaabde1b4b4802aac930cd52ab72206cc3abdaf5bce6d\
+            a2ca7df43a6c7d30f116a2fbfb6ccc3c20dc3098686e0fe99db60c1eaadafea6\
+            eac0600a3eb40e7dc3926875126f10cda4e06b7027cca1867ca4a525defde95a\
+            f2742e109ffb98a876a5991ffa97c73e01863b8cebadc3f3dbdfe12af8df1d42\
+            c186b5eeb1c36376074b97018d3e074afd2d617288b70207f5671469bb6606f5\
+            cc83e5d5229e7cd50e946a834a575328f0ef748a7e8a83f66ff03b9f1d0dec10\
+            9722c89e450f96c1aa030fc8fc1a73f32d1f496006bf70f699f76dc3a5608b97\
+            70b6b2c9d4ff28c82206e7ae4f5365cb6f54cb6cb88160c23b32e2542620aa30\
+            dbf00e36cbfc5e1b26d23f4dc40f06cbdd13a731d51d807fc7f9741af7bd81ea\
+            7ca7ce0d21ad07fa3db777369c55c6e4e51e9db0ff6bcfde9d1442e92119b8c2\
+            5c230ce1f2eef3c3f3e05287b7b3172212944db7bd91fb8123ae65d94b2352f8\
+            8aa36acf8c3cbe04bb4812eab2d2e48fe96b785cc89d1284026a708b9371f4de\
+            79d66ba8638033e521fe87c43be7acdd5dc9d1b3e488f6abd69476e8019d89ce\
+            8995e41bcfd21a76ca204e8d4f1d1b115a1b6da2e079d5b2695890d01a7bca06\
+            13060ef19c1cca30bd4b58b3f536465a4e90f8a1700c342aac550c98ec1dc74f\
+            801960f227b4ea7284edc258f4ff17482d2245fbfebb050df85ef396e5227d48\
+            d2803eda88c3703a5301ce79ab3dfc42866869dadee6047b8801e57397832a53\
+            8e18cbdab0ae882e66a5fca878615371c2258ff61ac588ead14c1292ce99d77c\
+            35e6600dfb3ded0728b7c774b934f9214736adbc31e9345c62455c99f5d7f3b7\
+            3d1ca6e103915fe65820ad4e2224f47598b2a3e1d52e90bc13f1e7f6b67a3a28\
+            4ef2fb221e8b567948b237b2a1aff4e1d28ef75d2e9ae5731be9d532d4414d5e\
+            0aac1d03cb8a5eacc06c14380f2a9ad3da222b8b25c79c4c7d7e3bc4bcf071d2\
+            4456392b1f4a473c6e26d145954ad5e5232b8a4079e0a32a84d357c0d8faba05\
+            df02595cff1dd413e858f1a3705528595364de93ddb43684c3221fa9b170f43f\
+            bb24499812608593efcddfca223e6045877ea6e2fa941cc0ad7abc60eb38b3a9\
+            4774fcfc81f3b06658679b963ec2c46c25de1c0245b96284b2bfb70e160a2929\
+            c9e498ad1bcffe6402b72c223110b7cfac2771619808ea4802be7150acf130ff\
+            a0fb62ce76136dbdbecdf848f982adf8b80d5ac198987820ee1327ac2719ae7a\
+            deecfd1c662d5a784df9b334ca854e105ffff56a73b4eb0226b5058a6041d78a\
+            99125eb8bc0fc219ef8d6fae215581b4417a920865f93c34ae4c6e3685176fd9\
+            9ea901b9152e08a776359094853c7d79ab442f085263fafec1576c91369f1c0c\
+            b0084053f5bff599a8f275761da2c661a3eab51e97a59a34dd3bf81b4f171fa1\
+            b000d9407c535310adb39e32960ab884e2c4e5e9bd1f066d36184f77f54e936c\
+            1c0ac0651922f49988684bd4a179c39ccee4c0d77a816fbaf53aeb3ba0c19e02\
+            94993567cb56a9d5c4824d0a09521e3dd5509e713e5901c7c4755de8aa26a827\
+            cf301abe21b01af71362a68667598d6e7c1ca5116faa4155027e28743cb5b44a\
+            5604bff0b040072da67f0e7dcbe75064df46063ba5862aa05b2f5dd80d7de500\
+            4e39223bb04379da6886fb0119bb05bfab74c75b0e76364e9536edc08a6a9d3f\
+            7ffec9ad275458c8a5c88eef6f9c043f2d680f4bf074e5c778b51f241f8d77df\
+            08d20a1ea2553b445cbfc87aebe467902861bf0a38c9dbdd66f1c41877e9b0dc\
+            fc8b7af5c9709a1d98d0dd5b01f29e5d86dd252e4c8b7b3697f08f94e2b1653c\
+            40be8bcac548575be3ff859eea7af8799aff4993ecdf1da9bff680bdcb2085f6\
+            b126878a3e2c8b03cf2004ad472bd201c0d0b5b0b08f9dbf441ad38d974779f6\
+            202f054aec0779a919206489d30886cde62d53355e6ebff0c818cb06373f2533\
+            ebee92b1fe0a35dc82ecea4344cc95d9ec31309cac21f24c4af01592e2b0824f\
+            0d2e4e63e786837286e1fbb0e8c0af6c86a0776073f3566dbb40a0df74246e8c\
+            61e137818208925c36b44292361eaad4de8358f54b3174d5a2ee1674ad2a31b1\
+            137a82743c4fc00b35fabf134d55ac48c1b63ad768c2721cb3012b67867ac0ca\
+            526a47bd3cc71d4fb56d7971486d9b8dc441c536172c28ce7e468bc852eeba07\
+            21dba27b60a4d6a49fa0258e96a25497198627411bd347ba22662fb6965c1696\
+            b0b0f1d0b76f8fd528576079c01f536c7d04a4f84898180b85f4ab6c20a63925\
+            5d23a1a161e678e9ad29f6b3398661ccb530081d61e61b5f31df006c1ef62f1e\
+            03e7dfe28d63b26f38ba47d9c7fea0aaac5fbeab46a070369d913ba30a91ea60\
+            88147fa2fe6552132b334c2159cb953abbaa92f9052c86db303a647cf3d16f24\
+            0ebd3bb0ba9e270d5febd66ac1385fe80f37132c1d1121973b08bdb3e9f3d9c5\
+            7daf78d6bf43e33ef2e415d02283354ef99106fed6711be679588ed7dc60fc8e\
+            65984f54d62452205dccabae60d118855159a5980f50bae908bb72061cc934dc\
+            851fad9e8bc961974fca4a724c15e721970d59617a746a594191d4e59caef4db\
+            06099d9f9beef4b19534b9bbc637078a7e264dfa2e8dea410fdf8550fcddbfcb\
+            5cb6b4309fb266a8b10726b772d40dccf5e89308928a32f3f6a7aec7f5daa62c\
+            24b8f596cfeeb3ba19b33298dc69bb5fc7e5552cf86e468e1ad36c54ea5a99f0\
+            bf54375583b5fac4820f179580c2a4bcdad3d22faeeef5c7f18e2ed668a4c854\
+            0052b1daefb0dfd5c2093941bbfabe3d9dda071c9e5f948875ac533f751ae031\
+            50ff30ed4873417673dfce37ab0d44f44b483bcc6df1caa4af0779c9849d8ce1\
+            500ee84248489a8af029ad91d088eb988d9c4b0f4337ccc40107d91f111712b6\
+            0af78af28d1f026e6f090632033ff8991587f8aa3602ee57d67f8f3220d41980\
+            12c6d623192662f92df7fb9a8eda98eaf53613a9150a86b2b814cd18576eec9c\
+            a3b7ecdcb06d82ff129e308f95b583d4c9250bbfbe72a1891bea31dfc6d0a46f\
+            5dac8cb17d35319ebe95e11abde5626329cb408a132108b95ea57fb20583070b\
+            59c2a52b867b3d7f0076778bd2b3f133b6169db08aae1bc464929117547d6d9e\
+            2ae02a28d5f34446c0d1898e12dbc5ca8470aec197d66c56c2b4aa67998bf2b5\
+            d331c5d875400db71199aab621d13bce6dbd25102f3bf519bbb8270133ebab52\
+            bd711008e9ec15437351f83b47f7536454f07da6b3dedc7e2b3c28161b01ace7\
+            c25fd61b5bac881156079d0df56d312d79952e355a7e4e1ffd2d688efb51b13b\
+            b987729019486c36b3d87ef1db97f8c75d3a014dd3158588f5b10cb40c948716\
+            2da03d9011622dd16ea9fc6a48777a2936fc10019f8429f20dd0872844dc4baa\
+            8d083380950ab8e6b490c828f54710ed0168c37e700042913a1012ce9c473f72\
+            6c51a7b8d75b1a5bdfb342a67f2db4672c67d782dca56162493ed23b0415b6dc\
+            9bd79ec2ecf1d1d798461c14969e9b6e092954af0910bfc5245972599984461c\
+            b7b7e56c6b5253bffbe0d40fcd0b64d2eae354e73df9e2f2cd6ab17550ecde08\
+            687d9967dad3ae5c10d653a5c4abee9a43cbf0f4e205c189fd395e6182b77c5e\
+            a6bd37b399b843d86d88f445a9993b64771fdbe14abcaba4111051a7f5df1c7b\
+            fefe298deae352f79df55ab6d87570345c875014ff9d175164eed7e91fb0a42c\
+            03a507ad5b1dfe91f6060ad2bb627b8f23c6689897191e761c3d26104f4bed28\
+            9a4b56fda5c5bfeca24ba73367682c7f993be730821b5ff8480a4838cee124c5\
+            5e4f49bccab88d83fc802402b83977e7d9c1aca4eb9cbefecff9a986363e8054\
+            5ff014100a57d5b087bfcf6b0cc00918fc167deb0b9435f825ff20669d852efc\
+            ffb3e7ebb28912802bd04bbf234f4875af1eba1d21d84fb00a3ebe4b705cdc17\
+            07635a03786cef135ce420fb6b5860253883a6dad6118c3fc2758e9d155dbc2e\
+            d31b5d9f016536d33c8c126c254c4d8eae160ee7a85b15a61cdd6a813e1d5a2f\
+            7866e28ba16c1f4dc1724294067651b398a7fd8ad99cf40a9c13add764ea2f9a\
+            8659225f7bd30d736d1c5c68ac336e5f077bce536e0189a1c4e8e5f851239ee2\
+            6240d08e3579c1b7f704987ffa7929c78da52128ed58c1e9a5e153476d212016\
+            7bf8af8ea60fbe6cd3097434c3b026fd82d47fdc8c478c1dcbadb55d4dd54deb\
+            9bec4a2e3b5e36e2d16ba3816ce0b9e45e250b99e06720c4ec152384c54b3045\
+            2f612147ba6daf6b1bd9be43c1d65d7d5be63c0c5717a6b8502732fc9a7e3e64\
+            b09e2462532210fa758ae615e20a1d3afe2efd3c5367cc13e12fa8e9e1d90b82\
+            cb16e8949d045e1e0cce1e9990655dab6c167a9e4453a0ec62861d65580c9cf8\
+            e4f154ffe50b5459fea48d2e271fdb42107275d3296cb034ec4be5613504356d\
+            df38a7a38e6c3180180a60e0d85523f0c15ab226cbd942a54e46e955d80496a9\
+            7fbe930f284db30cd59de2d23f72514baca8fd86fdbc76d804def3d9dc160064\
+            fa624f2c05414084e718d70f701b3ae267b5a4eb9af9053eb459b7c22aa8ca2b\
+            037cbb6414394471e94e6d7de614572c15c82ed7eb841d318cee0707dac5499e\
+            2bccc3c6eb91311c6e60bbd421f857d490471e8078e3b8406978f5a4b7ad20f1\
+            bb92faeca5c0b9fc0a10949cc429dfcf69c9279c2c72aec4f2c8fd2dcd2f880a\
+            3052405d07bb806e054490c3c71a0ebd6d1a8c32d3d2a73c64d7b9e5a14a6c80\
+            0d5b0bd0a7cb6a0ebd41a53477517bcf01cc567039ac758956f17be19e9ec615\
+            a18d8ff5ba488b410140e547a139505975d51fc12fa89a42c6f59a121e274bd5\
+            28439d0d1d0913a2b3361735bc92303db705c1e7d03532a21650e9aea7bec90b\
+            8c6d84bbb9cbaec540577f371338b8045b50f6cff392dc24cb30be7e5d25ba5d\
+            653214aa363e56993e4cd1cd5c1f4cfa25e0d3a5ed1962bdf02525b4c6585d70\
+            964229bbbbb667d3838bd8098dd6c3d39c0e2771f500dc2b3d0738743ddb26ef\
+            726d7becb807608fe6ae59b1e620cf623fa3c723a2614f453279ed6e90a18500\
+            5a0eec47513f9dcfe2a20e9389ddf72576bc438428d5d456a4a2fbf38d81d902\
+            d1a8d431b55d2b6353427980f1f9e9e64511ad351fa1e8e47a85214fc5624795\
+            90c9de33f00539eb241bca5ed7ae548490fdfc9f5914ab0888b3afa9e3ce7fe7\
+            6e77a2b6a1e1875156da18103aa6d15f54125ae0d51bc35918e7183f670b575b\
+            a17c6daa2361eb40efcd4fd9b4e9b47eed2eb06e7e4b0ff97b2c8425b732434d\
+            ec4cf07ccaa6179565a790817c6e2bfa952398a0ce6db137b0fa995120a99b0c\
+            e8e4a6dd6d890394bb532b8cb047504aad3ea37f52b339ae7f445cdadf26dae1\
+            855990f604c9f5454a36b3bc1622ff0b3a00e1b4a7b43d2cd2ccd1b180960153\
+            81c9bed8cae681a222baf9ed88740e046b28bad6e97fd60582ec9e0c1095970a\
+            65e3617a64fe11f554ac0a02cf484c2512e9974d51591bac2b00dcfece639d7e\
+            48b8148f824fc78b24df411bd7a6a9c772844496ba1e2533691ef363789f180b\
+            41a34c18ef9d5d6240724831253b46c0aab7184b5d5fe55e0a052b8a1bb7c8a0\
+            d02ed9e311b94e662f7b9145f5927f534cce1e4b9e5726851a855bc971c5977a\
+            640d46cffbb2f19c8cd11186eeee104564e52f8e2ed9f03eab417638f6d6f1c9\
+            7b138721765baaf9e5733139fbf31709493d1af44fe136b980d68d89731c7014\
+            86e875564548c64c4509201ce5b9e283af5b52bb8c13f936a8e9693cc79d4ab8\
+            96c7e610af2b20722309121775ade3475198eedcf10f4e9ed13907fe5920a22f\
+            78a2a3b54794f2bbd62db2b9231f297e69fcf16145b519fad655f4c5978fa65c\
+            d3d9a97ae38c46529bfd21ea7f5fb658c50bc2a252dcb7b5d6d2d64776779160\
+            d3004e973b122d735118a7fb430992a1de53b0070f611926a9b4f1d2242bd656\
+            2b0bd5b11188797725a5a9e62b07a8cc5ef48bf7e4e2ea7b4b56dcdaf2609b9f\
+            3c0127eaef8582a548a93ff39ffc1ddbf38bd17633ad2195b0895a3926b61e57\
+            917024e03143c82d446ce2e4cfd9e7ad1e40f1e87e9464d3c2d738a52a533fb6\
+            c18a0fd17e289e33013752cfa939d933a318dc839b414400393f0e24700f9bf6\
+            a3f5b9e7ad9a1ff093b176ac6fbb1ac1b07bcad6e1e81ed830ee50cd2377f58f\
+            05e1d30eadb863f4825296fa14c6bdb841eb9940c6522ab46aeda91a76d96ce6\
+            045c991065e4a334286448792d3fbb40636393f141484a8b6a3440f760e17d4c\
+            7960a653ba831cf983c269473eeef9d788bb76f7eb14eb185ff81e6084ea52d3\
+            22d48d8ec7076399f1f126b20882294886df9d261fc4c5538552cee7b2cfebca\
+            a9d68840b6f641698d55d04641cfecb790c098d75062d40d173b12f53eb4cd94\
+            f4fa3ddfee9012fc37a174f10ac9d0c15e784702efc918f49824d27252761951\
+            cb10056778f0e1b29af6c055e6beff3b49ef941845079a2ae26f486e55346e00\
+            a9324c87e1be4365c270303135b6c7d56c2ed4775a14150303094883782df29d\
+            40449ee537368ebec7416b8033850023593a03b747e1d53ff6006ab260bf527a\
+            3a4ce79b061f4ff12dfde37e8197b36c0336c7d0e35acce07695789282ada88b\
+            818675daa5799dec78b836a280a10820f70a7f95b6846dc3ebf8797f5edc8626\
+            8c9bb96af523646d5593aeeb6ea932336600b695e84d7ee7f800902b8d9265c6\
+            cfde9912a9eabe0ec6b617f920b81a7745fd360139205cfe84a7f6325acb5fed\
+            ab692c802862305c85740cacd541bbaa197e75350205d9ba79756bc8cd1e30b4\
+            3244edf99d884207aa84765f52059d68b7671623328740bd4405beef24aff6dc\
+            be52bdc47f7ae214f5c42329fcc0da61f0a3b7ff6b5eb93758ac5c981e82b8c1\
+            6b69031e5de78b4f21e31b8d52569f00e735a5accb3770318e95b97a52c316aa\
+            e3843893f9a3e1ed18eaa93b4c8b4aac28693ae7c405e6503d786c654f371a34\
+            642a04e457eb4a66d2ddc3a07bca19fb1a824dbd634ea41ea98dcbb8a8e58e0b\
+            4288c3797d93644af9012d7a5e57a909e0f586d5a2652053c173d39cbd2f9b83\
+            248ff8e90954006c4a9913629c71aead580285f1f94dde4bfd4d4880f4d9f570\
+            d8f236afd4da10256684624a44bbf2699d33eeb0d61eb50fb019e042d38234b8\
+            f182374ed20f51a5dcf78490507ab48eb8cb97edb7a1c6edff7452d23501b135\
+            1ee7e8daf2d2ee965a62d8c6e7da0222af52d432ba730a45dc2b565852fcdb32\
+            696f1887acb6df74c92924b6778cea2495a1b19367cfca42129b517dea8b2139\
+            03e64654685e8803f8f9c8fadafab948f3cb978f2fda4173823646ae8e7b0ee7\
+            212ae00db5e0eb70b8cc9aee6dba5d93ec3b3f7b9c79dc8c8f1729168bfca57d\
+            b12c1dcfb023b6b4d0eeafe1cf834a7763f124169963c04ede55e95bc7d89442\
+            982edbb732c1ddaefc0d54a08a539f274ac164f332e96096d02019a9e308bfc3\
+            b94c1e4935a95ea39e7c5a679a9fdea273e7a210567963e8c8d892d2cdb681aa\
+            12dd650e927ba493a3186cb9d5360a48dbdbd498e5aca1223d4283ed226e1c2d\
+            3acf60a2c61a7efa5a24cadb3a6c50a17a47e7553c7b9adfe16d3304e9c7f09a\
+            185e2ea9fc92c1ee6bec4b006186aa9662178de89b7cacf985674579b21ad716\
+            c812eacc0b6e99be971654d7097c9155787198b4845608c3575aa656688fddc9\
+            addb4e871cb87d39f84809b7e4d4abf7c83ce984ef4d25be82f8c944600658ae\
+            168a6512e809e0da88a8711ed0cabf8b8b433426d44288aab25ff0f002c2bbd8\
+            936bed64136634d9e8c24d9dabd90e47676d38e894f7b596d61719516a5d3072\
+            019258ac0ebb8bb34be6ecd1e2ed9d3a50f99fd2cea465b04a954f8a48f5632a\
+            06b32551cea422910531216faf0b967376ce057b811ed76670788c64b60e5fc7\
+            744b1aece9d75391a13854e4fc76730b4612e3063af6e34b3387e1113da93ec6\
+            762121579527be1edea63c55101f3858417294d84d28b5440f6906338b2a8d7f\
+            0bfe725e26c118909067a7031ba493117f4e31bbc1196fffd70ab39e819966fd\
+            5880c47192d30f49e8da88da52aa7f09ed732eb17d664ab09a9c588644b5460c\
+            021435f6faaa9ff02be5bc285699d3fdb9543f7ac19ff49157cd067ca43793d0\
+            65f1a547e9acf880556c1600cf3aebb66d60fc46b289536d63ed6505c4cd2ac3\
+            d5fdc2c48b79ed1eb6eb4df30134f71ba7172f746d67c9f7baff4dc7a2f506c2\
+            05a1310c619a8e036fa4500269e88250a7a0be8f84f100993140cc804cd7f307\
+            d557d8b301809fa643422338e51be39f9ff1ed5a3ba4c36bb4d36fc98814b033\
+            d1115ee2a7206b48e915cfc7fdf393d5650491fd8cc172da85c3fea3f61c8174\
+            d6c1117bee90f424266abc63df4e34bd2e6cc06c93cc64d0c25851ca58f80680\
+            120fb9b8b7bb0a421f7dfac4070cc96a722fcba7c0946fcf8701dd1277409da1\
+            342158449bbbe01fb0708ec8564ae12b3aa35ab4bc1fb006e256c3221d343264\
+            5811c9e4a4f6c7858e1eae1b9865a1042f0a0b461d082ad3e7414236e08232b9\
+            c2a7ecdd3139f1d5d3b359e834cff0b8e1da2a9d36ec8d23c3cef7ee9bb1bf58\
+            11e762dc7d06692dec27980575d2b974618cf5854b13835f46c5da92c1eee0fc\
+            ff792bc4fef1ffb3101c1d5a95857151ae6fe4600bc4fb775fda60040671a91f\
+            a2517703c04fd311b0ea2f7b5d71551711d582f8eff2cb89d23b069c1c299c9a\
+            cb0bc58a43a926ff0dde0405ee2ffafd4253b5ef6555ce5ed24af7c2db547d1e\
+            279a700cf5e721863845c2495669a8e388af49362d4d1c77cdc4c2790550223d\
+            f1d01cbfb1b9f29eeafa3fb7f52fa835e77adddeef97704009a1f68f21cd3133\
+            3834c9a76802a631c6421ff4dd76412808a0498f22ed6ac14e9688c37dda2069\
+            a880d3cf4210b4f45e4f9b53a8d1f465ed073427688b1a2167126631be9b308f\
+            5e10be967b253e24ba1ca7c7a94f4de72e0d5ceae15c7e36f046cf599e48895c\
+            09ae46667d2728739a6e28732800441565ceba54b6980342f3987a23cc1a83e5\
+            bfa1011cf7af4e69ccd70bf03f19cb449c72e42c79cf8e1c5efcbb31e3f4555d\
+            db096d4a666a42c8864e8746432d691a87345fb13789b6cd81571accbc73762c\
+            7e35fafa1bebf6f5886d3dfaac089cb070c44cebcccf641b58d1d546008f1cb2\
+            f804b621f143b938a3d59b5f0bc5c784c6dc13c9a5cf9a29eb45e6b22640c5e8\
+            f6bc1520d573211e6f8e748c2b1a865eac3906904e2f4b9788eaff0e3a2025b9\
+            76c5074724b77543c56443f6cbaafd4ff63967a9d6d39c4ae795adae14c0ebc5\
+            d160728030b7fe59f7ac690f396ca7cde87b217a87501e7ea75bec134190eea8\
+            6a3fc5e97a96fc745e80a68e5c40aba7424e0368183537495b2bf68f72783b9e\
+            cf81d12d03fdf514094c0911fdf5c0a050f689e619664a9085e3c0a7c1741df5\
+            205f5e30404f22b80896d5ed3959ab65e170eea7fc084cfc70b4925325f81e39\
+            6b2dd42f06d5d960f05e5e98c422ac0724b188a422a0dba3c2aac60b0ff8ac2b\
+            ec8460e160fa39630b03f3df265f8762a2ae2e1d039b16be40cd243df832eafd\
+            14b0cbe216cdef1d85106579c045a2f867729af9591a84104d3d641ff0e20312\
+            77a0dcf79cec34da7c3a72ad931741f0af138701cf3f42764387c7f98d253810\
+            12909183e936335cd9ff74cfa75c48d7b24746bcb2c9a59c74b48552520c1d33\
+            321f9bdb52363ba2c1482ecd362aa926d1908ed4ff5b3c200e4057e627777076\
+            1017dab547569d73d3b328de0fecfcb8fcf8212368a34d00a60307c01055af1f\
+            89c7c3c30d15a7306955b83d9ed6cbf2aef01c65bac4018423266f0cb1ffe430\
+            676217510a32b1f9453c8c7070995d0e86453ade7fbda64cacc15ea086445f0f\
+            114d473c51b8e929363be37da82f8d8c2d3058a9fd71a3e97bdc1d888a157a39\
+            48dc0e3304a455142b6fdd99d1a642c4f9923adb83012451716deba117d134a6\
+            6abf804955a4db90d4e462726a161a0a42cca5cb602034e47b9ca985a1845f5f\
+            eba54837c19768871bcc070a1c414acc7d0bdd41db340cb504551d8caab17638\
+            d16e83b531fc17f0ea7def3eeb8e4a54f8e807798c4f4777542edd05fce4f6da\
+            52774867cc892f0376afbf36f14236504bfd06f8b0e1289a7976954f79d67487\
+            b867630dc9a7a32da97421ead47631947ceb8a7baadaa61e7fe06491e7e75388\
+            b31657e06cc339dfcf079881ea5416b297b48dc7475ad25bb56c82c76fa61d29\
+            c3c5ae0b94d648edc815bea058a8a94d78e7ed9e10a1be2a1c9a91fd91a8af32\
+            44c7a48e31c0f0175442e7d298eb196d7a9d898fe2368c6cc4d9f7dddf65108a\
+            aaac2fdf524f8aa4c8e20c7a901e2f81de30bd692fc723ac079dfc9cdfbdb996\
+            6dccb013d97796fe7d70f86e9eea5988061309608250034cf8d627ae6aec2498\
+            fe3d715714d4c73190ecab4b860af83b2676f1d9c0438c0a26f06363f1d004c9\
+            97f4c44678f95bdba1bbb62efa68ae050dd7202d37fe0c47bb52e669ba7cb4a4\
+            0a8b4f6d2864dcf7d34affc9e1e704a2b77da3ba29da990aab11feeec50699e6\
+            81dec3399ed3c0de7b10e73eaec491907a1676e35448749a3d7160f3d21554a2\
+            88cf7ebe393bc0235ddabaecbf59438ca5ea8659095bc2f866f17fccaee1946a\
+            8188d7ce162aa81c0d65318e4bd9759bbf006bf6d3fa96dd95c60e6a3baec68a\
+            7777060ae518009511fe06726948888c7a9a8676f46f85dbd5084c75148d4c19\
+            c58845414bff71aa21c9bffd9949caff7936d22d4fa5bff3fe40e3f2e6f50af8\
+            4e5bd970e8685b1f06cb83964ca11a9be53adb0e044b99ff7301fdb4ce7338c3\
+            089d4d27914e06b5f82c612a5397169bbdd6780808c29443f18fbdc945f55419\
+            d5ba99de0f48d4003269032b3a0ae5b45817ada320e61947ab6d20707ac5acbb\
+            b902124829b9fe34ee85652f5660ca1233badcbee47f1909027608686111e2ae\
+            a15e2560e8662de44eb0a09d939cb0b1fe8406f287cbce7c54f96983e3284219\
+            b32fc224c9c581c1d35943b459ed720847fc0866eeb302ed809ec555c1d6f436\
+            f81d28345bfffda46e079bfe740925fc624a8daebc6ac9f562cd056d6be83d91\
+            6c48b654ff729ef81b6bb77235cbc9ea0672f30dbf9d3dd54331a88c5408835d\
+            7e626f8657d3c95270e9128a5935e6dbe4367a19e0043a32c977bd43e06a3681\
+            24fdbad8cd048aea8d6d513f0c85423e55df5590ad496ff1d70585aa3ed28802\
+            ba496675d17ac1eb19546b081424518767d38f43f196ef99bf2148a7bb8e8ccf\
+            b02216fe7c96570ddb1c93c228a3713a173d90610654fc5a8ef88c8d05185e4a\
+            0f3cdf5055d1bae2d7f9af002587ccc40810b0a4ec7c3d0fffa1831561270563\
+            6158ae0c621baf7bdec64b1f2bfa317004342b942685312bf731287afdd17df9\
+            3ec989c5fdea61c667f6da0935311ef9abfc9bcb91ff8f7b7756c80b97bff0db\
+            8a57356fcc4e91513e28971f0c468d0c659be885b4b53f95d30f1fac8332c616\
+            c321649b5f9e8441d0a24d34b8bec8c42e1386bdce7ce8850e16a016a0dc1dfe\
+            88b2738ad6b1ea2b8f2c81085b6ce1a88f62b444b762fe669955e2d92245465f\
+            64cd6ae9ee1aeadef7940319553e23f096eb1b72ac22dbe1d4fa4a59869363f3\
+            47fe0bdf13aac9a0d604907c0b868b3119b55319dbefd8610981dbc6f91204e6\
+            efb255bef13d3a22d3b8773ab55d19e8fd74715ae9bcca683b715108a8c1ae22\
+            1f0d9c68ecd2f06b8b87b8586ea83e4b4ad326ac0f8157c886fe74aaea63f69b\
+            32f37176cbda6b5bababb83ced583948056cca6065a8cfafd8005974e86e2d7d\
+            d8e473942410b4e1d2137751bc4442fe72fe6b8df5d34a30fd6a488fd1221408\
+            5ef040151303e6b91ece37b7c3c1880964aba3aee5516a9d7b619ffcc2d7e0f8\
+            6f9adbcd476edcf31f0e35b907de1491bdddfda7b02c58264cc78aaad19ba85b\
+            a41a38b1fe664e37b025f492fbe6b9aba8e64093f11208dc95f5d68b2f7704f0\
+            1bba0cffa2829e8586e3e180affa3caac0d2039536ded16e049b7c58dd1e603e\
+            53025cd84d731b2e11d03399851dab754fc2d22cde4fb60b71b0337a5ce208a1\
+            d38f5d25502b7090fec75139cad38e8ff53f159e5492d11d018285d9c2d6e988\
+            033490c40b33e25274cc7b903faf5c4e073bdce3dfa83c739ae4da2bed8a895f\
+            20c058a085aa13047bf7121f12e7b4c70d3368064e15225c665de1ba45380ffa\
+            bdfd82e79625183b2c5bfe519f1b491b4f19339d914e7e43f0421653c133cf1d\
+            3a5669690db8d446e55ecd241d39675a3654d3ffa8fe11b7247027c5f85ae8c2\
+            beec0d14538858ef764645c1861a37f829a78e2046c0ab7a44bbcad8f61081ce\
+            1060809594892f9067bbcbfeb7f00850b20e87cdf9811131e8d03833f09d4734\
+            550928b00f87e9bce707e2c98b619ca5979af3c653348b279e232f68a6862afe\
+            47cd8bd2c55aae2392eff09579cb006c6d113ccfe29293e3bb9318636ea0db01\
+            f35dfd9247507d0c97592d7fe203c81b94f5bc6e97f6bbb5077d6888bcd60b15\
+            1caad8e148c369c8c1cf0ca43797c98497609977e1a76f56c10c5d3a4bec7a99\
+            ff27fa1dbb8f81387b5616d65feb6de733463d555367fb875f7dc1a6a0de1f02\
+            8e98326b0de7e27cafa78bbdd85e7fa864585e13b8eb2a18c52cbc23c1627066\
+            ef21fb3f3f787872d923016a5e21a8a247742b1eb32e27925b1bc455bfe7e8dd\
+            8780c54d8d513618b1828944eabfef5857675341464c71ec24c8f84e91c1762d\
+            6319bc28d0c35b27f85b75d1fc6919498e91d602dfd27f49a8c8f1706468b540\
+            36fa979cec0d57575bf598a8c591c282d701cb39755a80c9226681bd251b0ae4\
+            d27a252151b674ba0b098c71c0395996dfc7f7d4be571b5eff7ec051d9dd8743\
+            1a803f2f9f6f832782182837b162683b5cd3fe9e6559417437bc0d069a112854\
+            4cedf621f29398f82d889f7d22adc4a801bdc5424c732cb4721728fba4317a71\
+            465b44fb009852e7b544ce069d18b63268645bb9663417c4657d54935574ab47\
+            1c3cdd2064d388d9977e888347362b8b50df25383a51a4a766532d9a09a40201\
+            7ecc0a3836846e61c93997e9218dd5a7b435b63e93678360fffe762bdcfaa80d\
+            079bbc17d5b73fe9e3382624dd6398c9e567d1477e64c48e9cd9ee9844a36883\
+            6b0f57aa9e66f32c85fed179675a3285236d3e66cc8e899a0dba6467de77a821\
+            97d75a7148544aaa9189bfe2ae2df7ee4476c9a90fe074e0482012c2b8e756fe\
+            4501639c2bf0984f330153dbf4f4ec11bd1b761a086ac37fa886cfb730ba225e\
+            648d3df8bcbda90d367e0ab432458af234076f5a67e0ca07724cd37add867757\
+            95d076f2ca7f9459338d2b5911a400b8bf20a93b7194320e2cb73b26aaed7aa8\
+            ca20198fb2fb79227b66c5a8c20be3b7b39ff83ce49dc3da123cc4f345c85c82\
+            c9758412136ca245abb4cd2a6f25e5cda901ce81111cde03fbd2eb39b490a524\
+            289c9b3fb9450a9c3d923d5676b13d59401640cb122d946b6fbbd25d98ed4538\
+            9afcbfa360b66698dba8ff23fe29434379eb1ccf9dd629d215faa07bcae6d37e\
+            1ef05835f11ff46db69ac7c0cf7a485129a6c272107c2f2ea9cc5aeea248bbee\
+            11431e550d76cc38e130948f5f8f98a7d62363fa55550b2119be4c04de8ed417\
+            1d33e5d58c4806bf2b6b4e3a80ae7248bbefc8289eb12af53755858cb3fbeafc\
+            1063bcf7d13ba331adaa51f0f048841c05af351d188373d805a569012997c2cd\
+            a51b1473e26570cd52ab27078402407ed34f6bcf44530c00f798b63ec99082fc\
+            b4ef496a3d4f3200d4bc8bbde4c538ab3e1f214cc2e5793d45b8491462acc5ba\
+            6702ff1814d3e905c4c8b65d37b3b91aa29ccbb8c1cd619300c9e1921487dca0\
+            056830fedbbf6798258f6c126bff14952915c72ee06c59101a320f494bb0bee9\
+            670ee6792f61a1ca7efa03db4349127e70ca468b99923f45ef38ba8d25afa741\
+            7d6838309c46845badf77a4b633b6b5903e7a33f983ca426ca9967dabd9b87db\
+            f51523e505640f8b7c9f291d826543a71cb78313c334e47ab62887f4e60d5105\
+            85a040f1399f361f92cc38e176f847ac2fc90f8b4e3b44cbc43925c7a6abdc1e\
+            a48412cb8b71c4c81994efef6264dd8f6c55d74d71164d6e1a2d6d5d2eea6556\
+            674362acf8d56b6b0493fb39e0ea1f4ad085fb66f5312c0f30cbae7a2b2f2470\
+            c5c4ebf02e930571598fa0592146bd131ca58c5915ba5c97e696ac3a1bfe99fa\
+            1436dd093caaa1a409a445b9261764e443f39403a928faa85b0f310b28ec45ed\
+            b8789001685f704312d30732c9c6d9683eff3ca00a299ac6a2ca3226aa78ca24\
+            99b31d4913651bd075842d8bfa75e798863275cd9466306abc83f59ca6aa18fb\
+            12987e4941683a4278cd6c4f0e9b98383ea5208e183381221d0ae7d6658c32b5\
+            17ac4a03b0fb6cb40bfd92836089c3390a8818d2e1dd81d9653b2144df7aa820\
+            6d8e9c9fa5cf668e800e5947f78782bd115221f798cf37a025ad2f6228da9cc1\
+            37cc68dc4362007765f1fc5e46885c67b542a9830c2c90920bc3eebfe28b4f1c\
+            2642e794287d96896b8af03c366bcea92111ee0afd674ef16033edc15027c7fa\
+            fe09a9b10ebea0a5dca700e93ff5504e04feb61e5c050706a4085b4bb4d611f8\
+            3bf0d72a41bed2cf5b27560689438d9bbd0e62bbbb542b159256e39805d65441\
+            3123234d46e9dfedc934abc56d69316d0c249f9520f03f1fc2858e5dd4de3332\
+            95201657ac07c930781931b40bcdceba951c2017bd11f41014688d3891878411\
+            ee489fcd037afb3911634851ca6103b3f2e870389b5488f79ff75c5c09a7bd2d\
+            612cb22588080e5e6be659eef570f9808d760ba83315ab8b01d548ff5b30a159\
+            4a2d51b241bd5612a7a68b95efc0c7d48eefc5bdc8d630a116dddb367247f91a\
+            1eda3325493e744b45354f8570523acb3bf695c6fd214a435f200c63b0de3929\
+            d1bf94339248e6f99f41708a31734ef96160cf26b6f242e0ef8ac03d345dba48\
+            ac2c22e3c35ea9a52a2f7ed1be8804e15facc3f6d97080bc19b0c77adc1abed8\
+            a8b71ffd19e57885e1a66e8398244da179ab2b15d9c42d2dc7089e3396006131\
+            1e0f4e7c5adc750ba797a0a68b8e3e13e86e01f7b1c8136b3a77f524f37dc562\
+            37e65c7baf182c431e7fce37bfabd65fe9e01d04961162fb9fb526243ea690a7\
+            d75159e0cf66ee8c28fba937076a6ed0e0a902e708e644420a6c4a2e4be83162\
+            1320a9873ea2b2d31c2859e3e3bc35ea92acd22f8603d8034b548c330870fe1e\
+            91a26513c6ebe8e6f849c4ba70a73da3fd8fa1b01e7849f01351b643a3d357ec\
+            5e27c3cecf57ddfd2ea8a9a0cc674465e1d1f7213c03b7c9dd75a6a46b9f8efd\
+            6a31cd15258fd0fa604e202bf84620482808e168680313f9f7ded2dbe3c74b08\
+            a99653d10cf5bf26227a6202c28551192c706fb5b83b18d681f0fc8b8d1019ae\
+            8613ebecfaa6001efb1fcb0adf2f1a07638020e56817537c012393fafd924bb1\
+            5e694bb19877f97c5c48a709c796002ec2598b5bdb2c0c87022c50a63719da80\
+            ac4f8b49a4bdc60c0df48646f9ff81c20c53147c8ea71ec847783e09c90b12ea\
+            4cb41b18c5b85ecb229714fe16c12a865f8ee2b96dd8f36365436cbcf550df4c\
+            740186fc4cd88721025599db0d76397a0050175796ee1d39a6738752f426ecdf\
+            7c01379b227e570bbb8df286a284a9d1b795d5abb4d9ef1709f4fcde462a3fe3\
+            1641371e7be329f3e1978088aa635eb2790bd3b70b855d9960f615b43ba3ba15\
+            4f7c2a6b5705257a1b009cb9d467046beecb83cf543eaff34947627c267eacba\
+            3ef75dbd96e2041cec18ba5173070ae20ca2f7a5447cbb72d3b55100cc1a0091\
+            23c4c8decc38428660f811073027c355d8a0fe77f9d903cd4d8897cd369e6072\
+            bc299cd10656b3738eeb3afcb43561c5c4d306d23938b4623503f8ed6054d465\
+            eb3540543f4591a2abb7421d696329a2ab6da0b838d5dd85519aa6a959f46828\
+            cb47c16267b0ef08e1364497df8a84974cdceb5e57ddbe4f28cd9006f09d3f04\
+            7be998ba6582bd8e3bc0d824313c94aabe38d1c3d112d36e79830bad8d9f3e6f\
+            f8b44f5805248dc1a5965b168c55d31cfa068a798e189c480fa54902844f0ddf\
+            9eb2657c0b94236e56c0cf44fe98a245f3989a2807473954d8c7c5526922fb26\
+            a0e360ef34ad571fe4968acc02454840683b1722e7f527904c1c856701aff359\
+            4c9160a91ea2906456f3c08d7a3ff32e4b879306d4ec66aa51cb322b9a7e4837\
+            f230b623905ea2e60b1e9e2150cb2008322c0b1d599cd5bc08e77593964f9dea\
+            b0e43acf099e996aa3d235bea18294ae115b1b101950bb577e95ca9969bbb156\
+            1c898a25f936269061b5c202b7d7a1e9def84874102ed75cac920a7aa23c7f75\
+            a1b059ece02d187307e61b7e7a17dd2c1270f57d2fe03167103e4e17cda1abc2\
+            0ea2937e6c366062e166c7af3bd0d504e30eb19230359c553cb8723625f21696\
+            ec493e8576f9ba3c46b9aa35da7dabdf891a9e808154a35c117d71b4f269acd7\
+            fb3e33c0b1bec9b087a3dbacb742fedf1eae6baa047cfa5f77539f9f74da1e10\
+            48fa4e4c6cbab85be700113276c24ced321d3f6625bf94ee10ea293339b4ff6f\
+            ea6a1c5897d6774a6584046757c3f3e5c28bf19f04a8fa9d918377a15233789f\
+            2f84dbfa3525fa35f31a3aea0ef54294359a05e3edbb1f7915cde76d54b76709\
+            f546fffc32f7f992fe21dae8cb0bbc5b38633263a5cb1da21c3cf89deb573888\
+            48359022d8585d0e18e469d1371de880e76bc68f7d200e12c574e16faf3e133e\
+            802393ea97102f029d7f1f0cb010f427a0bc649a39144f9b788d9a902b50b72c\
+            3c79bbd28a9ad63088f10212c6888a77c8af0b8d749fda4ee3f4eda2837a0267\
+            0bce9daefe242d7bc3fe1371c4dc311dfeb0b2d7b1f82d73ba2d8bc3f6cf1b02\
+            55d2023708924a9f4707e085a3ca635857a0545e4db105290673fabd1531911a\
+            37543cd406ee39b73bcb994483a4607adcea4c3e4c800d90fcc1dee2f08eadf0\
+            775a0d64133c18a1b5a0e0474ef0684478fbb8b83c90d08ea0735fe2cf6bb9ca\
+            e36ed53f94521bda365a7ab7de1198024ceaf8be8f28e3717db9635bdbbeaffa\
+            42a9b225524c4ec847537ffb6bd6218f4b6a3d5b52ebffece8ed2d01b7cb8997\
+            4f7cac0944cfb6549c25460e2247a7044d2a90f74e7297852edaecdb9050f166\
+            0ca3b1ee1b38d1fed2e7b63f50bd78d716a187be4db538005c82fda3c2e25721\
+            565dc92d8a1d3bced981b6be5d89d3052cce940ed3f9878e4d394ed695d307b9\
+            0cb99da27c4e1fce40e93735a96c5ad05966feb383b78290e554ee7359e705d9\
+            450770c3bd7aefb558313710afafdb22af27b0fdff588d5bb221fafbd7399ea9\
+            5cd7f4b335344a5386e6bdd9b6d0368a3722b7d3c49a0862bba187b10021c5d4\
+            cbfa90f18da8221ca89ad3194a7d9e3e688dd0a6e0e97a80e4c51ed839c27cef\
+            df4d38bf01ec8d74f8be4d2079ab8373b97d39c22367463d27863e2c778de0fe\
+            3e8212f3bdbbb2181b5f24d8b55d01d9a4a0e1bfb942cfcde5c60937ffe817e0\
+            35b936f109a408546e887a8ee79746cd5421f662f0e6c93e49af52d65d7e83f8\
+            5a33319cbb94dd84732569ed30ceaf5de19932113ae0d8b86c6533beec058010\
+            c8b2baff6d0b1566d0fa554b2ac454b7138f9f8651899b11000817e6e094ef6f\
+            d6fa240e7eb94a4f5680fffe838b0f2533056fa053298c6cc0a4afaac5dedade\
+            fc3faf23bcc4425199a9eb382d78dd0b430de8f4074bbc6675383b23bf74c3c3\
+            8ea7cbcb3554e0225c15230ee8be993198c3184e92c397022d502ad76d80bb81\
+            03b35c2dc7e8139f78d1400ffa94c2424791c730137057c523966d3354054129\
+            fde87bb3f1bf5a4494f962f69527497600042991fa447c71b0d9defd8e6b9d0c\
+            3285047043626e931aaa287efefd1b7af889ce97ac1723f31488f1a739e79689\
+            4941293003edb49d76db26fd7abffe45703def851da0cfb1a3a0cc91609f5b9a\
+            a071ddf39b347a4e2e6cb7f448bad491a9e5bf7d408d124f245cdad26464d4be\
+            f76c8cdc7602e8572cf31e70a54833fd3a26d842e91845d510309ee08be24e14\
+            d9d5915b742007a1d1ae8fbf9da47ba3997c4f38fd7419d519d9239519a9a554\
+            8daa81edc33e0a927a515ef949e5ded79e2df5a7826b873d222dc1353253af93\
+            228d2d743091345f4d5316a0912c129ff82d842789c8eb9ef09552c0b927aa5a\
+            5b78b8581eb38c981e53404137ea8b1d0dc61df396643e05af3d72130fb774df\
+            b8bc18e1568289021455a71784c1de2b1891b8acd7930afad3a2d54966f8c6e6\
+            11dd1fe83061e83181b1758bd5cb7fb098268310a7b1f935338ff6eca5f694c8\
+            f846bc2986df76e45cc3e5caab20a84e1999b8fb231d2e3109713daf4674f147\
+            1b378cb418b437a7f504cdf257fffefbc8108c2eb45aa90fc5982f8494028ed5\
+            b0635fa492772f44df5980e10957b6de5a20f01e1a98712d51546e0e8a088448\
+            07ccae1d0896cb048d770c60308b4e17199c6fd6729b24de9012da1444437c5b\
+            d573618c1c0728c40e9d0893cbc965ee85d4d630694972df0bf0a44120743f53\
+            2fc79e758dcb160c98e0e0cfb40a737915b33b555da725442e64e8cf8f50a8e6\
+            97111f5a8a279de5ab76627e7e58713f3ab7ea247dae7fff7c03a86fa697ea7f\
+            8dceccb00afec224319674b4db8615f395b3e3a35d2fbcc9da234bf537e4929e\
+            bbb56d41b752a001264554ea015a20a3abe647fb8d4881fd2656aa9540c23cac\
+            cf8ce61fa49ad4b65ed82e65afd2b3fe52ec2d0b4215ee664cefb95cc3428afd\
+            960e3500c56447554365c632f58742dbb1d1bbd82c90df951bd91cad14c0c8c1\
+            1e612543ca45baea77b2d8fa0739a01bcbe6b0b012f69abdb9b3bb9e110547a7\
+            461b9a8ba26504c3ec40d28377a65e5a34cc7b1644b041d48c78612432362a61\
+            f9aa2c2ff4bdb37e36cee6107235b29e28ebaf2c08d3747387995a9ead720cbd\
+            d119a91f62a183ba89005c1c2169184a616d6264afc081f0b696f7f133c9cc1b\
+            1da744f0d485b9c0a7956e36ca8ce0e88110fe912b57bf5af17920949eba84c1\
+            d229314d53f75b36b721318bd7818f596d1a103e9bfb736f9889f83c784f5dee\
+            cd2293c5d3ac548824b770f1e4ff5a725e8dc23235e87a0d061f101554091675\
+            851c3ba12bcb5934891f265ddd9baee9a61ec1b92650aef18c341735df62637a\
+            ac0f79c5003fbe9d28a2be1230119b3f16a5a7280791fe1eac5d90cecc53c565\
+            72aa046dd0709e33789815381bbf00874963f493054fd98fdec60841de825320\
+            9ac13a5cb5d04564f3fe76ee45c3517634a6632a173051c2bb80e9d8a06b9b54\
+            42c5f80af9d946ef841b06b8400f78c90c7ec78085692abb590df9d962b9b2c3\
+            ea2a44785419c19aaeb768324fb368c05605e05e54f7cc656c3eb0a859d4400a\
+            c5952633e0ab5340ed29ed8de265a0867a6589be4a16915b6535116790b6aeef\
+            5be9055c6fb5ba57a5a672abbb3f25d18c40186d71d6a6643e9332740b9cfb9d\
+            f74b7af1fa2377dc2a54224bf5d21db1470c98795d2b5c18a69aa8a54b428873\
+            9ec67f3047b04e03e3d776009cd956ffcb1bdd77ed4a5b2c061bdb08f7ccd2e2\
+            51491ad9852b41ec4fb2dd8b7a408dc4c77363a130c9367376df22f19748fd27\
+            3274b9d08825ba3ee5522e45b39e91508dc343bdc3391712cb4393106a202dc7\
+            c994e339dd02fd7a350adcb54e5f38dc43ffb5bed067813d77d3a4f493dd1127\
+            ce77566513a08a9c4b5dd872ea5bbd4c1ed04d69c255f4aa032c9e4da30e7f0d\
+            d44fae4a232c4ac7cdef7f56a6c29ffd33692e45512a3f4638d78160b3000cc5\
+            1182f277f93e9a458a14a377adc1d2800b9cc71201ab9de2a91fbd32f0f8298e\
+            abe20eb29761fe66cd04ece6b68ca463f1f2faab6e122928e5fd5266fe777d5c\
+            75f503edbcbeed0bcf5f6d4d75b054c9fd04ccb8ad459c0d640d91bfea3f97e1\
+            0d103fa87d4535eb070c6519f171dfc574127e1bcc906e7d541f82614e5c4174\
+            a8608d614c73f32287f34b900cd134c95ac5c687b64c90098ebf65d68475f5dd\
+            8a099cee5cc689c37ed05d40b75361de19ed447ec70ad755ffc1043ab6631cbe\
+            5e0429e32a20ee1c5c88f87626eb452a4742c30d517aba9d05c603967ace1308\
+            a5b296e4fe9ad2de4bca606d4a7b24670499a44f12a7bc8e3d4b1c9386dfd816\
+            5a5dc1d726a40c59cf7b4da049f0a55286ef16c964f3cdec66cf86e6cc26be38\
+            c9faf6fb16c6c1e54893055da00371b944c75101875b5658fa64a22dadf89dba\
+            ba781f7eff38f08a708ecbb3e9367ea809a6a9e355d3b495d1ceba7d7bbade37\
+            6d2a41256e1155db423441a580ce39e3dced940f22886b68d017ab0e378a7229\
+            67d3f1ca7ffe746027b2dd91b14d99037caf7f0c45a74ad11dfe2437896c23dc\
+            13bb88905919b78792a1f0321100d0c8866e005fad2d65f7658eeb7e274d7502\
+            e97c6921684e07584c73593c8c8d6ac115e551248dbeb3eda584a16a51a3b172\
+            a65dd2389c33054278a9833ec5ede7247ab00853e9037743148c3d9d11d59156\
+            2e4e626b1a32f1327b1e21b1ecc2f5ea6a2c8d64747ea4e701447dad8e606ca2\
+            39f78e0c69cc22f7ba492bcf84433a4ef23b28a5c652573cd1b840fe9378c1af\
+            5b0ce90389d49b6b88ab3d36b7d7e023af07fd9d21027896681d84865d0aa89c\
+            4241c3556822824846a2413a86bdc3dee1a59edc239d3416e747ff75877ae99a\
+            094f5c8e9b85aaacf29a49c6406edd1bfe34a656b4c76fb9c267adf6a77b96ad\
+            ccf23ef5d63d34503518497939367c78db326fd12aae2c616822fabfe89d0588\
+            b77566c3ae6aade5ee19bc494530c57ca62ff656c77469be13437d5e9f3477eb\
+            58ae8ca2d92712d6a5456a27fce4253adaeebfb05a0e43a35a7c31bbf8dbe348\
+            e425446c7e744c06d1cef5ab4fcd74d9508e8d9f3fb44bcbebdb04db9e148eef\
+            6cdb630e69067216eaaf61f86e33b44df9660363403908c105cc77b28b502dca\
+            a5dce4ff7d62e717337b57274581d2ceec21f4a6f13a77947f6bb6af8011166d\
+            b71a795bfa7b70887bb2efc00bde2e5d5260f4c752d3a69414303a87a2c410ed\
+            59e9fce3bd242e95cb66558c07df26573ed0566c4c361be54fb75c2758325568\
+            232daaababfab4409531a3a85019aec2b0d07fc37ad3d54245df81313ec46a9e\
+            58c9dab637f260ac300fcae068ad017fd6129dfed109f50cc57f32a10592f948\
+            80d8a08df1bf5ca64a5d8a371803057a054509f2a2560defc6c46f1781dc7a5b\
+            b5a2a9519ccaaa63485354ab04bed9b03d7d29044952817f7e0dff5ae78e8d49\
+            037aabe89eaec8c07a0c68bbb08ea3d1d1ff0ef47409a097770001993262f3be\
+            26cccf72d8b259282bd26e4a5e70259a349a2d9010bcaeeda9bcfd52e8061f86\
+            823a4e68eb479d3106b6965e07fb252668415c4ddac4f52d21071b5335984fed\
+            b62aa4a27a25c6ba07afdfc77add1bdf82b7344fd4862effad87484d657cd5dc\
+            b47c88c35e1d75a0eed2e452beca9f0ef6adaeafac1f889433dd0ed7d4202f56\
+            91d266aac82a15b7fe27646b09b5f5a83320000efbf2bfd91fcea17236e9714b\
+            55cdc30c4a044a75f3f1d4222c38462ddea91423693f2c715938706dfb7e339d\
+            68eee6ff767f38d8ceac1fedbb0bbc96a57d96b923e94edf5e0e6cd64a06a4ae\
+            dbe48904467e567c66728ac8e2fa38fb52248d7cf39b468c39ae6742f7a0e33c\
+            9fcbdbf5fb589e29f92d09b8fc76aa4228c26b903a6469f944be7dcadc5c0c30\
+            ec19624145400d08ea488c8d352769ebf8cb9647b939e5adb3f08eef934cd95f\
+            0ecbbe64be0d1be88c4f66f4dddb1639ef05e918b4fe4172076587ff0b65c327\
+            583bf40983c095b1add0a22350f0cf246db58a57f570047422ed485498a76b3e\
+            f7f0ce1ef402dffe304904694d9705fdc940ea2fcf75e9664b6d7f90b48a85a2\
+            5fc86056c43c67decefa1e813ade6105d34dff8d28e8ff2cf1b961bbc299e4a6\
+            bf2f7dcd9c804d43a7008f73f0907a29cc34859c66043aada411b63c86593fff\
+            c8f43e62d6669b5eab9219ddbf2d0c1ba442e46d7d19d58bd63965c9aa4a4d04\
+            ce9454af1e06af50c7b63131ce5ded4665229b2727e87ea568e586c616d9c461\
+            56c2876fbc979ad880e11e2d9bd9a938e06e884d8b84c1a97e52d2c036ac6bfc\
+            ba041d329ff6fa81b236ed20e011cb6735544dc62cc284001c447ff3d898d926\
+            321f24039015ca33a3b97028bab659a2995edce5b7852cad0b03c6386e58a6ca\
+            feece9f140c2a2d9cdbe0611c61da2596c11e4b75de12b83e641a9c7aa5957d8\
+            2a67226fe183566b0fb73c199c6f58b2301160246ee33e3b29d3d738890849ab\
+            02a151dbfe3eb573605f35fcde8b615b77c91c9a96cb889b28d8bcacd8f6fedf\
+            d542dcf87d12090471aace4d2de81e76c44a211a84302031b8d61e882ddbe57d\
+            a85e9edc130cf407ea81ccfbeb77e7d85812c429fa0c40f6fa2e0cdb81380b99\
+            c62bc82a4465f8ba975d5719b039af817db29eec2eec485bdaf0db97d5f0accf\
+            a773347a5d5ee33b4173f34773003f9be9ba4f19a7b16795d74a54e68dcea505\
+            54c61bb4d8fdf87962aeb354a5b5a40eb39c09f78d6048f9b96a7b46c3675038\
+            a59f64d81da24e39994f917cfb0ee039021267661e5f9340e116d1b7f2513882\
+            2ecdd8de700e6884128f44435a49a011044af00e2a42b9652088e444c751b474\
+            11cad8ad00200bccf44af2b54c7b7c818e31f0e353049f1fd2df28145db48cca\
+            47a739ccc986a3dfd553e09f2673913059d1dbbc064c31ac2f816b6cf3a04731\
+            48e17fcee710c1104343a8c98b08ce315def5b915743f9d8b433af89a9cce5e3\
+            4f32f1fd8cfa334f94df7e20404b609485588676d90a089ef3b70b211643aefd\
+            2b6ec643459bc36919b35c9d4e4ea2fdf8bdb9a862592d99140c500744d035eb\
+            a48f5ef85ebf2063524a132f3d1504c709ba9eef2188428b914ddcd6df3c3e52\
+            96a3708d7cec96c65beae2ab7bdebb766e3b9ece77dd6a6e8c1ebaf057c45332\
+            1014916ff773865dcb368c4db6420281f5595d9b4a0c09629231a9bf8c2c04c4\
+            494c47e1a8addf040452136a60dc6eec1334002e1f14267ff0b9d687697c06f5\
+            163a0c023deec4c1e6162a67116531df8872a831c6c64bbb7ed5796e41bed437\
+            f883c673582fdad037fbbc60aae129d504b5a29c3ae1dfd3cc84ba59c4f83e94\
+            f099b33ebe5552e7f909ccfaf67861a45c07d2e4ac6b109a5df739fcc766d931\
+            3192938e3a4909ba18a4263d610be10b733cd746595922f0720f57d428c8cd1b\
+            af51569ca1b1d3c8f357e552a871a4c374ef857352192de9f1a3ae3e4116c779\
+            786ad10c9754e0835639e179717a2b491c27a62f027426cc97184e2bd0401f79\
+            2d27d0b55944f687940e4d88046ea62ad35df823a2c63b6766d6bf1d1e4dffb3\
+            f427b50ed1c18846fe78b3aac42347f60451ca7a00297ddfcc3d56f1f170b39d\
+            175ccdf52d08864b1240392e6926ac98f4a57795453d5b48b33d77911d9035a0\
+            b860287eb7e33907eca32722b8db64835c3ff6967fb9b61d3b129bccb0d9ae46\
+            4d6cd66daf92f24e0ce55ae1da5738139384202d4eee1b22dae299b7c35f20c2\
+            5c88b5b99e3e56ce6ceb0f4bcee626d382848fec7c1fdef882381460a3913229\
+            183afe4488b1bea2b0c4f4280ffc6364454355faf4093dd2a38858b5158f4f26\
+            5419f130385ca1442384220d4038636f4deae540a41f7aae51fb0f3c01e9d995\
+            1ad015c4366c837b755c98885066aa7e3473f89cfcf0fbb627b0cbef0a79754c\
+            8302c5a1d015091ffd8b1ab3fa6492dda27517115f3f6e1b9b89003b6e357897\
+            ac0eb380c632a07aa3dc38c4aec6a10da51a9a1900082732c5f555fcc47de385\
+            760cca3167876ee61cad32f98d6f61b352810c4095bbad4e759c16bde8c14b59\
+            1cf6e46606b78b5a5109a8addbc37a4a507147500c5051f52f7a1b9fcc9544b4\
+            0887641ea876839618dfebdcda9cbf20f27703aa3332236279d86e9c38adc9f4\
+            96bddd34e56437f15a42201cf550a8585050c3a78e352b49e16ee5a11a42a1b0\
+            92365e38db4dae200e41ca53b8f72b07e11affb2b2d60c89e2b3c47730abc687\
+            b3d07f6e52cd7ab62024e5568fa5d76485fadfd69a826f79ac24b71a7b7c7786\
+            4bc1c8386102a1d43b1c5089e677a2e19127ab39f2327da1253e919118958384\
+            1a30651ec93eabedd3a3b75431ae0a9f4b912b2669c3383f68a9b39e9a0d5ed4\
+            790b0645420a0eeee494ec4b3c1a81e0ddc30a6dc0b62055c2d202ee8782e4cf\
+            494ea46fbfee51fb5adf87f35c1ad7b53dd834c166373233c71b8b4fc07145c4\
+            dda19f80b585e5a98a4581fb46f279e0dbc31d51a7a84093f340407b503fb722\
+            7e01dda6e9164e4fd9abf9134ab93906444fcc4e5d1049b670c27f04331662c3\
+            c674026c8f4de093df8259ea654cddcf5e96560046ee69c98bc031e330b5b541\
+            47e40c60d4f2f46690af3bb20ba712b767c4c7d5eb4c10130d60d35ec12b9130\
+            23b3a822bd6e8f895e1282dbf3ded426abe01653e6a6dd0a56d55fcea16fa0ae\
+            73fbea2dcc517fbcc19c49315a23a4cd9df311c5c1316182170a1dcf3c8b67a0\
+            91f37e4d461fb779751e2e3f4b98024145a4da10b6c6e63adcbbea3bd5e66990\
+            bc8cf6e2ba3512f978266fa4e39d16f67da789e5b30f8e0f927b476b5a5f025b\
+            f58acce7016b2640d839e853f8b07b8d509ad3fd5eedd24e0649c600d9df9d53\
+            df92e5f5619d05523b28a5490a99dab709961be731bee5f869d3c8cf26a879e6\
+            08404df22d4d2e00b99c9615e0b9cc5e3e4214e1e95c8a2e24185dc15f93834f\
+            bf50773d3d07759f367693e9f52a042b9e70ed0d2380c61ac7e58b074a53c1df\
+            3c00edf7c05dd70e1c4320bdaeac8b68e8e9aa85a8d977e7b4ed6f30622729df\
+            6b03ebfecb1143136902bb4fc8488a84dadf214e949fe380f4b41d0111c6fb18\
+            ea7215ed7ba2ff4e2682ddd0f666b2e5b2ae5df3812fb448417723955200f008\
+            6089db02e623964997233d756d824a9a23fb141766e9c6027118931865095b3f\
+            f79904c2cedc99da5e6733af511590814c36b4066f22cea809ac3d60490e4d80\
+            29ab77a11018cf05a9194a1b45515d56de57038434a6fba268c6a16e7a157613\
+            16abad3db003e2eda18846bae7dedb4fd4593730bab4ee1460c1632a499c4dbe\
+            ce53a4590ca57415fe3a5b109b652c4bd6055f93426e9ff8f7366bcbf792af1f\
+            7d1774e8e50fdd3c28a6777c8757d2403cd04dad60ab0f350c55ebde33591c91\
+            c59c1c1cf4c04d0de2e70bbdf3187c44268ae17af989849d9935f8cba7e8b269\
+            174866e01d6435f70a602a10a90c1cec040bae80b6271ca74d163d42a87227bd\
+            836ddfa294cf96b10c3507acfa35379d4e013f67dffec629da4c5361435f1467\
+            0a817c0aa74ef4aaba0def330bb1673dea5feba06e7f05ee9b84a1d16b9ea076\
+            1b3940a7ab0f93b127e698aa08e989f510f3bae3e28420e1b54f915863742315\
+            db4ebf05261279a6af5e740449c8fc7bade5b8fd4aef0ea30e656d69f53ee0b0\
+            96fead5ea4ce362eddd448afc60f9049e7ad0e8494e36ec90534cb223e381f84\
+            8623da0a9a89abb4334d8e2f61442488b944b7394c05bad3b99bb892413ceca8\
+            5f5f42cbbff0fe6c3f893e538b03eb2d3289f0a3ea9868092f2a8cd639196ac8\
+            de61072b48fa5503ee91f9a1b2a97f95bd12ad4a3ddb72778dcd54793407de28\
+            16ae2578738179eb8553ebc3cf369db1c12d4abd4b3c3c7fb735d4de48575bb7\
+            f56bf69b2dfc40f562fceb6b8f295158e9b8e49833ed2894818b7216d8c0aabf\
+            71b53824ac6bb1a30b7f989735447f59fb44402740236e42fbfa3ab45fa27348\
+            070a3195c7c9f81741488803f2475b25198a198f2cdde7cdd82a722e360750d9\
+            dc60bd645a1ec821e2569a5ea7dbc9eb1c053e8abc131516656c90dc43a695ad\
+            c22d04859ba4e3b4db93c3727ff0e58bf4f31a9eb8944c4ece490c98e4e59571\
+            d1d66c2851ab6e179f4aadfee165454726e04445ac3fd5797ef1a31053b5c20e\
+            27754561234df5f4400933b53ba848655c8883657afdd6f8bac26d80d8eaee46\
+            90a66de480716ed750143a0355676e63a37c19871e61db4c9bdb808cb71043db\
+            1b2553ebb5ac404530ccb1f0a52ec41372b41b1f2d0744e578523990ace9d2ce\
+            6968ca50e9af8601137de00fca1c02cab86840e9a4c52b0ae80755f427039531\
+            2b1ef697d5fcf4e557d1d401971fab2dc0199f18bbe71f8473d7ed5ba57452b0\
+            e352a9e8606e7b51b1313b8a8e878246c69b1172ebd98fc9c8ad22e9cf245a89\
+            54e890591fdd6b0359302d94e1ae334cdac099b6b990a45c99106ac86fe298e1\
+            69a6bbbfc289684aee9785a913f187a544c582c642c597251d35981571a2dfa6\
+            6b0d7a0f6c285ef850c1baebcbe238c75c6cae29f8057d06f6f2f631aa119485\
+            40221781e616ab7e43c3ebc9f34038814448d94f9b4288467b13cbd49330a111\
+            f40bb2e1a872b824b768f741d4c9d71a6730e6b604844875ec5580c9873e1199\
+            1f01278a1d4981345bb712c23062287ecdc525a577cb6a2b720f99524d9a92f5\
+            f8d6c6e11a6772fd8264b1cda3b21a1df19e2eefad2bb7e6d2c6cf0d4fff2634\
+            212d4cd50fa48e1f41f6ce7f75164314a354a37dc290bf65805986c398074870\
+            33c3ebdc28430c2a9a6288ac8cccf06c72ae6e80569ab30270a19acc22f184d5\
+            08b2a7cec6ce9d7089cba5d4f353aa4d1a84857cf452f45209de633796aa77f4\
+            498b18cccc66618b711b39c0889c26291303e2e859143307b865dc3c41a6f206\
+            96b3000c357870e993d2a4221c88612631b041cccf9750ba1ffae2ca2fdb5e18\
+            c714c73b10aece45b81ef8c59517e7a2cc22727ea40774a98dbff0c151e91b0b\
+            beef2d1adc3110da70dd1b772a9e6b29f4c8ceec2ac0eac2c1be7085e43276b1\
+            abb6e27b3d3aef820a840c9d069eb4d7574614553650e135a811ac29dbba6b75\
+            c794c1b03e89e9bec403bb436d31b4ce4de360e83efc4385774cdd27ae88fae5\
+            43c80c153a05c1ffe818822fac1964cbb5edcfa21682797420248306c958f5bc\
+            81ae197ac8b16e8a78ed7ce8b2962939edd59451bda766b3a72a7de7b717ece0\
+            488461eb2d4bfacef0af34ab8aaa857a57d7fb6b3e9f5d3b77235265f2785e56\
+            8f18780c20b6fe9e926950ac4518e3ced87030496b44775e6c36e4c30514c60d\
+            345295b7a7d2e0ec8a1f4ea90fcec476a3656d057f31f23ae6e40443a5dbee80\
+            661953ee2fdc709e400877b7b24c40a0a8442a9fc983a2132c2e109f59a77134\
+            480a4ab68bd0a01e239e33c1dc496373576e7d74e540a285b815f522700a2c52\
+            6661e5d65726f1c39da4c135f21f97e9e076044cc5b453c9c24bb8256eb219c1\
+            b8cb55b874525d2d38d03baccf19fea90e11c95b0eff3d191f0c6883fd9cebb2\
+            8904df38d744278586f27383bab60db917e77292947707ea049fbb8e115a6bc5\
+            8972a7a2d91808fe591881ca4b0ea83dec70c1f002afc105ae4f675866c8d2a5\
+            d9f1a68eb1b36fc1b0c9bf2a3d67c8838e04bfd9ec4635da88c5975492a76dd4\
+            9816e25756dc0ee64420c7692cda6bcac7bdc82f3553d5a183fb99d648407030\
+            b6ca2e32dc159c6be8add70df6792064e0fdcc3fe347a0efcf3520b20b2d3e56\
+            b00c84d6380fc90aaa06d4236cd44825110a414d87146b5a04fbd1860907668f\
+            4d0f3bc44dda20f6a4b80ba8e2dab29e75092c5c52379d7cebdcd4dcee6a1f69\
+            a1b67834caeee4407416d9367c8c54fdef2b64eafceb2e22909917a895406718\
+            d8725cf01e876242768b4cd2f81b49d0971b365972290a794d5d6d00714a839f\
+            3bda94e7a98b26a92abeba783c64565ee487387221a7030dfb279e90c34af653\
+            a4389949918e4942482d989e0e4a62f2b733e923d75b5d52361de8388f5007e2\
+            c72044d610be882fadf2d1d97023b9ed1a63a295aa1c4f07af7b3dfd34e399f4\
+            0e721a27a96f4fe92fe93d026ec3911dfbb46f2ee66c58426132fde749831c54\
+            422e768d6a635b92f9208c2492aade895b00c87960813a2ce7aa1b55f0f33f6c\
+            a00598abbf08089f8e79be87ef2f6bfd124aa5301ee7a37e1d328b24ebbf71a9\
+            d366d417b108e0e4b75749e2b651a763bad83a662179d58b74deadae320a1d3c\
+            b4c7077447e47ade0d2c88710dfc6070c82bf736b3faaff276fb4cc3805fe150\
+            eed2133cf53cbc1a7aca546f869316923b828d73cded2f9a9bb0743d999e503d\
+            630258793e9e6973763ca1291378e79c223663a0c35dc9aa00b02d5491519b13\
+            9c1b6b262820feda2a982f703554d8f415f3d8eab8b619e5b9030224b0e266a1\
+            11acdc57c491023805bfab2e08821ac88e9f3fc8a1791d7606e8bc7ed2eb7f8e\
+            05417f053eb3b5653deb66bbb71d614b6837605b721e47a23f1498f17d6dc50e\
+            4d2e45dc9a2c548a95f25730240a50b4fa28ded4fef73a611d4ebd9c300619ec\
+            ca3ab0dcaf0f268a07bd526a04d51412f905ecfb643ceca9866c40b2b95af5e3\
+            036dbde31332411ecdeaaae1e54cfff2bb19be301c88a65c6ee13c5f15d43844\
+            b7536d14e87023f72f0616d48b6d3d5361aaba3c0fa087be6b628750b1906086\
+            a125ce63fff081e3fed1e2992a95bdd4cb0d4518c98f1bbffbd92afb156a6f4e\
+            059c02b74d01cd8fa0d4882c0d913edb290cc2f7593d94a5bda717f770628a34\
+            dcb48dee85dac454731452bfec61c95f15110ba98166adabdad258b79229ffb6\
+            07bd731fed2ac7f117b3c8b4bbe689d344943f6812d5a58b132cb832cedf9397\
+            a1c414edcb085fb2d28f347cfd6741421dff726ac465f53e11e7f818c56eabe5\
+            8f6beab683cf39e79827ebc993e4aca52af276c310c11085d64f7f0b2a1be6f5\
+            6969d6ac5b2cc614d51ef62ef68b30c67138e677634fe4d199af5a91ac661a8d\
+            af92228c1f6e271ba7bd82567ae7870983b07071acaa7c9d608e6daaa8037547\
+            0c454305ebedd0d59fbbb4457faee07a5155b59f3571ce03c854e88a50df8e10\
+            a141011e0f9d2664d5a0387bc39022455e7bbac157b49feec8ada61e79852c60\
+            86047cc14a94df69205532a2c2b322878696a41e917f75d10504aacd9b4a83d1\
+            e02999724151f9f7360c65365b2216dd5af379bfe482a9dc505f0bcd1f195f3c\
+            ba7c085f537edae514ea170ec14c5a05e0a81b72fdb9ec55a2dbab54348db6d0\
+            9004ec3a281a6bb3421d7780a5511199892d1d08cc1cc578793f8e699ab311b4\
+            e4ba94502cdfc70d2b91029fab2cb16a8a59e1ad77daf2a2ac3a644ba8c07d5c\
+            b09045cd705b16f7c0122ec4e0a0d249748f99005ed65cb4fd1bf06dbac74030\
+            4c8028217919ac63cf25491be68caf93e0fe6f03dc8eb6ded1d3c20283e8ac55\
+            a9a59449bb81abf926cdd27e15a086fbc72654f6e554d3dc89d7f90dec0146f4\
+            7b001ed3ec2dee85a876fb99c58045962d4b031495433932261b647e266e0c25\
+            78520006bd10119f427e4435cfa360e05f828c3c61f3a0a0fb564479491b95b0\
+            328915ea4bcd09918848f8dd7a87dfae69c2e8fde8ae448b35eec5072eb8f9c6\
+            d1013257e380add6d95f6c7eb31070f4445336f20006645a284968368181e5f0\
+            8c417d9ff78a540081bcd9327534803b9428ac3ee14645c99d81e990eff3849e\
+            7aaf25bdbb7890bf721b3931d6402a6e5ca448b0502f6b3e98db3483ec54051c\
+            4b24818936eb20d0f3700ecef10a2b9939882a2ac5ba59784c78539b30061ca2\
+            9d9fc85a39b90548d56893e7fb2cb1a262b7dad8f72d8eaf0a622e558322962a\
+            74ccb823bc66421e7bd80147b94206fdd466b717f54b707b8b1c34d617fc58c9\
+            42eb51a1b3f0daff1a21708468665c6e7bfd0fbcda37f04e12e31b6231e9aa41\
+            074d19df0d8836e7a800f93f61ee28ac95602fbb6c127ebbfb7668d0aff2044b\
+            62de57da5bf10ee2557fd44bed508747823402a57b84c1df31012d707b1e0e42\
+            61e346a2f418ed86877a0829e29b8223e98ab16f6c04e637aad7da91969a6f39\
+            b9e59dbcaa27176ba5b312efaf12fd555a7e8ed0fc764b3e37efd11bb5bb512b\
+            0d81bab25b069d52a0b60874b98065fa50c24fcad4d264bc406a52388d71d5a1\
+            f199264b5700ee552c01de527b17b49a18e906e461d2822f5a1668491ba0f27f\
+            2a88a45f323713cac2e796cb2e2bc5f9147c1d63736d90bfe35008dab7f6abfc\
+            93b1d97022777aee2434a0e208a2f671abba19e505cea1116998d8857e7cb578\
+            05dc11112a6917ccf9c0c6b7f57341dcdf9255bb6c3b0bcd980b07e73e24a9a4\
+            f9abc606d8760e3a1d7eeffef96dd40d843c38c58f4964afae86545f8ee8b32e\
+            24952696ff8838e1a536cdbf18cd0c9c0b6851031308ba184a3e421499a340ca\
+            d90ce356f24a1e7482dc91d06543f7383689745a69a2feb0dc4d29a3163f58a2\
+            48bd72f8d1742e8475ee5d78df3509cc30563acd95e7843ceb15570225011f5c\
+            5914680d951390607ef6a589e213f86fe10f91686e86219f3441a435c530cb1e\
+            5056806b25451e8626a1cd69ed0be05b96232403dfa793e6c9062db9cbc8be28\
+            5218b7e17d9287e4d584fc98d01f93cdd3fe88b7971b39056315d7800c28d27d\
+            5f278e9d881c3778f88d05479b3f4684066e761f88f5bcb1a66d781027874058\
+            98f7b66df4f2152f0f5bf0e83c1d2023c8c79442222d4b4aa0a63408de060e3e\
+            fc5025435b6f013fd14dac02f19d6f7f5af1c019b7ca7afa479f1889ffb48bfc\
+            535cba6ad19a555511e7b2cd6c85839df679aabefded60d03d4159707f9aa626\
+            a1f942eff3952bcedbc8f4f10d77f83649c3d6be7f70c334b2e8bef9e68de419\
+            2bb8f12f6eeef6b522d345910f08b25622e4eacca98a4809a6c34638eb3c5cf6\
+            c8013ad0fa7c36b8d18edd0a11f8a010a9563841d063c362b96fb0fcb3d4d1d6\
+            e7486ae802276689acd8d82b2a7abe573bb6f4af0222512a681c6502a8b76449\
+            3973fb92d9b11d97c1869355f65d1555a3665151073a8a840f5f02d3c3ebf7fd\
+            4c4be943b5f394cbe6b6ddcc97b11440683fb526c9611df55b4773b2e4263468\
+            00fa79a84c40d38bdf275f55eadf9b244a2b1532098c18613506bc04786015b4\
+            974a0614b52582627c014b93fa99c8ee886dc1995390b273862967167f84605e\
+            0b087230060e5103fb8495a050413af3b8810ebfab5fabc52ff213c408a84c7f\
+            161dda52af669f2ee483a2dcc0f55165d9e379f14722fa43900a97d1096a5511\
+            1ba800741d76e0a7f51f80aebceac3cd46586989d18141e3403f52103f487551\
+            edc719c6a0ce89ca349a0048be91dd1d8a32a6bb1766e31f139bcf2f50fe4edb\
+            b1e7178a1165bcfe72c85ce1eec4c3ac66c1f25ee8ebdf08ff1e5bed649a06ed\
+            dc37de7064f08adb5f011a07e85256b42a209a5f2abfc544fcb7abba86d00426\
+            a33d81b91768cc5f94413b2b69603943c5ad428e2efece728260f8ee29b71da4\
+            50c7206c5a6cbfdabc7685d6bae61a78a560c802273f31d6958e09d7a80b39f0\
+            bc699a4a5072775a903f00928dd605ba41ccc260859128319301aa1d8a608e06\
+            2264559705ee682048110df6c881b3e940fd92a9d4dc004d92e73614f085a53c\
+            146dfe38a8b8f96a8cbf72b7aec4fbc32f492663e5e73ac2da618e1fb3416b20\
+            a712229edceb358b4e78a0d726e44b7971dc6c5bbb8c0b12b55f44b1630a0a64\
+            925b7d4e6011bd7602ff9fe8ad17f7ba28ad10c0e7bdac82089e4fceefba4ec1\
+            1b5bd2794fdabdce778b7ac7a414ed800af17dd93545cc6b4ca3b929caeda364\
+            99cc1cebb9afdcf64afc50b3e62fb7e5498631911886eaf789401b328501b1c0\
+            f999bea8166517b35c3a9d1dd5b0c603ac25f0a87ed2d5cc61c397f0b0bf4f02\
+            34fde3d97690e93910381e28b2a490a5430188492610104d664d0e32ae3ce565\
+            b8b1e69759ce87da152e022cf0c40b1b3c33b3c99ff47b2af1efdb8a283b3214\
+            6431b30edb9dab583e9c819032f2dfe32820b74f222b7d354c5a43dd1cf4fe4f\
+            d3cff39a74f1a5ede9e0dc67eff323738a93dc2fafcf7dc3ea97b346fb58b032\
+            4e9df220776667cfb23f28621da6e211ff18d2fa2f648ab5a17a5f969b1caa37\
+            9a935acbeb1a759f2e31114b2a3648c45dc424da05f2918e819fa4398ee4b919\
+            c940c45e5110009c479c6b0c7efef6316fe1b41aef9c8fc6408599b2c1176e52\
+            e907cbaef3b6d47229900e51656f84c83f359a6c1f72cdb721f25f3db02649f3\
+            69433689b651a57a6c2c48a5b657347f8c25e5f2a16a68376ea14c03ecaf8e23\
+            51bdae2787be42aae9e86af49b128716b711d2b1f2a80f942f842c955a50d4c8\
+            93a8cf1796c02e7cfa8ba2ab7f9df1b868e91e376cc717f1ce59a4a01874da1a\
+            72152e3a79fa7b47bad6d34aacf92aed2da4aba6aaa5918a94751be66ecdc236\
+            d5a0ee266e48f28dcaccd8d11c36599bcad205710aad9602b489795d4917b620\
+            7e19783fdfaf919d77a00064a0b89c744aa0b0f5206646b3e777a3055a7d7cc8\
+            370591d057d25afffb5bee1ba92a5a8f597ba84d3316c07666bd686fd935c58a\
+            cd1c2cb381828c6e5ef6963761c4648b9dd6cc67a1b7a4110c02ac9ec9eb6415\
+            8f2c3b568579b88d18ab239baaa712b6273a28704352c3fae63f0a6a8a9996d6\
+            7faeac683bc43769f0ed28afbcde6e9dadb0508a23b806b14a0902b034ed7ae9\
+            3f970b760e39fe09e2254e11e943b28e30f17958a030aa93f34c8652306f22e3\
+            80a9343a3edd4c50f9a7b49a6e560596190118f1245962885e9477de69e2a2a9\
+            caa62f27e0d09eb57b13b2fbe8af2d96f00ecd0e417d7e1075247833a8fbf70b\
+            d8d03da384025c5fdcb36eaf99538501316fbb09a8517d8ff8b532a572480540\
+            5b3d6c486cecc1403c00a542b5219af625d8e35be3d6ac83f74098c070ec192b\
+            94cc269072dfbda194ed45a5627d9556e122afa50a1beef0eb529f6647afd605\
+            79f2ff87299e3ddeef780b4c823a2bbfda61f16fadb55f67640407e3b8f1ad70\
+            49d3641645693346c29c8b41b27e9eb6e78de882038ea8f5b64540844d55704a\
+            c0bdb32ed3fed623b5917e0f2be2a63953ed147cc5c3ed9f0b0ccf1457056c84\
+            3979a193aa6879a78c152119649be46ef38c8ba19c9e2ca01d628e895944a040\
+            b9448cf9c55d19c5cbec237155e2d1f2bdb9354e28c0ecab1468aee9c00a3700\
+            7c81c7a79ac4a9f440859f10f60b3d797b641d5c6b306c43f525ec444de7e1f5\
+            07b8aafbc7dbd5c9f5ea23a60c3d35ae9fe1ce2c12935adf107d66d260ffbf4c\
+            b8c0c7970d6e579f76932345ddc45f04b68762a2746a3b2e656664bcdc26f252\
+            09edfc9e5b36518c59089aa8964394fb55c3fe0f9b6a0026d5a706373234a213\
+            ee21de403bcec25efe85d28c5bd943254975c9ccef6f77f77c80aeee69c71df0\
+            2ce11afdacbbdffe6e8ef231c44d2c0d9fcc6fbf4bf79b7a5feed10e74a9d538\
+            78a217d8e0e8821afcd6da608ea1f69978cb7b78d7183f1f9b9bcdd57b582f01\
+            721d3f99b3e94ae7b6e86f40f5be76133a18a7f3bc7ea35d170126162253a594\
+            7ae021f97c6a03976a291de775729815e4536f4bdfd750dd991d047154ea0b87\
+            d28917ed1e16e8da65874ad70bf0eacab9938d7809738b7b88f57cace3c65d1d\
+            f6afdbc4a1511b28bb66b26aba8ce81c4d0d8f47476533d16b58323d1ba14d33\
+            6135db2404ded90e749a6b6c2a4d30f51528d3048923cf9f836b4d03d2614a8c\
+            2b9335d8cbbcee64b370b689ecd26e00e56a14b786bca6a4516fb89f1cf6ee2b\
+            2a614479503580e93058221901ab9ca69205f38e89eecca2527c0e21a52bbdb6\
+            d67058f299a403d0db099b90fb9c948f5bdf412edd190b6034dc00612ef9762c\
+            1a87482900d6d385dd4e7282e1a017b6503c4a9e6782cefd9b211c7fde026b9d\
+            723e854f12d4e8664df1233933c95f596ee9b3c921730c3c32da8c82fe28d53b\
+            ed0d6afe2def23bb5c0073628bd403f8be2f472efb0de8ab3b8fa265ad8d0724\
+            b0ef643f687aa75b2a8c65056ae3a31f056e7c163c3aa337de1dc725191e6c65\
+            38c00686928348ef0dbc5fc502a7ff8ffc279f0d51903725122863804c62b260\
+            f5d85d704ec426e9f24ef5620b582fb4fe5c901fccf5486c340b1455098a0d0d\
+            8d4ab0e78ba91cb4bdba491e4781aa2c1509f5700b9a4227d87d707f6d125470\
+            a8d677560b9cdd8efacc9065915ef50b68b44fb68b25c179844a6128b23fd7b6\
+            da887d76613570d00fa2b1e6036918fbeba0a1d5e1b47ddc8f3066a19e3a29c1\
+            dc29ba4cbfea86d3c9c18b47e8ecc8cb05e54b3c36ea1c09fe4d43b843616f75\
+            84da0f90985b18f8ef0107e6a8427a68ace89117ecb6cf8243edcaba64e51e28\
+            89f6dac752e38034be3fd0837e53135af64ee6f5d899c7180845dda7ea85b70b\
+            0454ef6581047a506c4c92fef7ca2361a1641a5a891db34cf948aa72f929adbe\
+            29c507bce266a1b2aab0d398782e71eab09e0728d53cedfc712d859658cf2984\
+            3ca49cfe937df4e8e350f901a306dcc21b34b1b84b617fe3ac64d8f5ee3dd3a1\
+            f55d8c5d72cb3cf0af7f85291066322e85e3743a77aed437edaef870a85bf85f\
+            cbbe0cffb184a0ef88b7d0b4eb463406cc5218c1f8498bc9ccdc3948334495d3\
+            ac1f98750974fd90f0a04d753c5529afbd129a065f785ac28cbaa388b1ade78b\
+            dbd6280f512f0a3730b3dd81fff0cf84c277210f9f82c4458f400cdceaa3a3db\
+            a68725f20e54fb5833c66c780578a032d45b8e0bf95b66abb18c52a79d8b0ff8\
+            03504b33253fcce4bb6a291fe44e1c1f36e13f9d919d22d2058ca8d245a9f311\
+            c74a62b3aaa4a7b8e404f7829e9b0fb64d973c90782068416a2ad840a66ab7a8\
+            5d15b5d2b26e5cd4c34d2f6d51bc1bb101778070663d7abfae8ec23863eb9107\
+            037d5dbe511f9a3759ebeb7835cbc1db92f0cd118232565d09b42f6c13f022b2\
+            fbc630c1a933ff764aff9b1df54a7cc25a096c9c448092211e955e6917201e6f\
+            3cf3a80adb017a0b46fbd521845e289f755653a128f7dc3dc15194d251dcdbab\
+            b922a9a9c522d46e19eb0a6d22a5d7f7a25089a1f6c3ecd1265ea9185c28bbe6\
+            3217804b1fbbad96f9c6c64c00cbcb808f9398ff4887f62ab175a55ad5b69327\
+            d14218c6b157613c7328d3e9b46c6fd1cf91d9944c926c7df7438ab32651910c\
+            80bdc87e10c9d85568da65c2c621d2467f129acfadc7367f8321f7336c920f3a\
+            5862438387879455b7dc5ca14a287c68d4950530244f4991496996d29c4a40c2\
+            fe3931bd4c647cfd6069f7073d34a74b911c53f5c5fb215f290f9d39a90311eb\
+            517372c8d4027333564586d7956c8f5bd8c5c5f91b6cf62d1220738c7454a7e2\
+            8ec1f5052b85357541aa9dba7c65e5cc38c4a8be7b6c7c20d0325a5b7b6a40e2\
+            167e3286ffc136c8a6569b71098810db6756fdbf3a17910a1db77eaa8ae8b907\
+            b01e19716ce0d60a170000bc96b5def62dc1bedd919b82e82b88cff526501f6b\
+            54d9e90fb89c6ac2dc6c0624607746c53f274bd6de58223fb17817c640a9bb98",
+            true,
+        ),
+    ];
+
+    // Test vectors generated with the ACVP reference implementation
+    // (https://github.com/mjosaarinen/py-acvp-pqc), from the Botan test
+    // suite (src/tests/data/pubkey/slh_dsa.vec): SK.seed, SK.prf,
+    // PK.seed, PK.root, message, and hash of the deterministic signature
+    // (pure interface, empty context). The signature is hashed with
+    // SHA-256 for the SHA2 parameter sets, and SHA3-256 for the SHAKE
+    // parameter sets.
+
+    // SLH-DSA-SHA2-128s
+    static PQC_SHA2_128s: [&str; 6] = [
+        "f696484048ec21f96cf50a56d0759c44",
+        "8f3779752f0383d37449690694cf7a68",
+        "5ef56915bb461280cd7a1428bf89ecfb",
+        "ace44265b801314a0d20a6006c9ff1e6",
+        "d81c4d8d734fcbfbeade3d3f8a039faa2a2c9957e835ad55b22e75bf57bb556a\
+        c8",
+        "34f54432d5af7acc187bb6221ed7ea6863a6eeddda016849c1ca8b7506251003",
+    ];
+
+    // SLH-DSA-SHAKE-128s
+    static PQC_SHAKE_128s: [&str; 6] = [
+        "6de62e3465a55c9c78a07d265be8540b",
+        "3e58b0801a124d07ff12b438d5202ea0",
+        "f08e01a57a272b738fa8ea2f465d5940",
+        "0b96b2f9934daa0da7c9873550013599",
+        "d81c4d8d734fcbfbeade3d3f8a039faa2a2c9957e835ad55b22e75bf57bb556a\
+        c8",
+        "3f74a7a28e32da3be820e7ffa9344814a3227bdfbfdebec60dc883b0a9f141b6",
+    ];
+
+    // SLH-DSA-SHA2-128f
+    static PQC_SHA2_128f: [&str; 6] = [
+        "1eaae6bb91b27cd748c402c4111140d5",
+        "a942cf3c95ff7977f88d2ef515bb26d0",
+        "67b4319d2f67bae52055dcd010a25a16",
+        "220b1ddabbed69f503b5f07cbba7f134",
+        "d81c4d8d734fcbfbeade3d3f8a039faa2a2c9957e835ad55b22e75bf57bb556a\
+        c8",
+        "a19e2a5ae585ec140dc8c6910847c1e67ea2a96e6707e96f3f5646c23393ed1e",
+    ];
+
+    // SLH-DSA-SHAKE-128f
+    static PQC_SHAKE_128f: [&str; 6] = [
+        "b585d4eb01085111a172a87688d0032e",
+        "3381a9e9a35fdd6ef2f8aeb3b40eb5ce",
+        "a27e9866875fd15dfb375e035774304b",
+        "8c608a901a6194334b70b7c8d4e2bf82",
+        "d81c4d8d734fcbfbeade3d3f8a039faa2a2c9957e835ad55b22e75bf57bb556a\
+        c8",
+        "0c63b2fe04da937129a6649b87ab6e3bcdcb3c587d1b25567b13624d75bb4449",
+    ];
+
+    // Known-answer vectors for the parameter sets that are not covered
+    // by the ACVP and Botan vectors above (SHA2 and SHAKE, 192s, 192f
+    // and 256s). These are NOT official vectors: they were generated
+    // with extra/slhdsa_ref.py, a separate, straightforward Python
+    // implementation of FIPS 205 (written from the algorithms in the
+    // standard, using Python's hashlib and hmac), which reproduces all
+    // the ACVP and Botan vectors above, including the SHA-512 based
+    // functions of SLH-DSA-SHA2-256f. The seeds, messages and addrnd
+    // values are taken from SHAKE256("SLH-DSA-<name> test"). The format
+    // is the same as for the ACVP keyGen and sigGen vectors (the first
+    // sigGen vector is deterministic, the second one is hedged).
+
+    // SLH-DSA-SHA2-192s, keyGen
+    static REF_KEYGEN_SHA2_192s: [[&str; 4]; 1] = [
+        [
+            "40eac72a9145e59506d4f7a6fa4ddf1618743a7aafabde2c",
+            "b18ac5313831f8ba6d43eb0c5df4103d377e1f820905dfca",
+            "a29ef90580f6cb79c0ef6f2f85ded965fe8a66984790bc9c",
+            "b48617d1aa636f2b37cb7d9c27e7986a7dab5e541563beed",
+        ],
+    ];
+
+    // SLH-DSA-SHA2-192s, sigGen
+    static REF_SIGGEN_SHA2_192s: [[&str; 4]; 2] = [
+        [
+            "40eac72a9145e59506d4f7a6fa4ddf1618743a7aafabde2cb18ac5313831f8ba\
+            6d43eb0c5df4103d377e1f820905dfcaa29ef90580f6cb79c0ef6f2f85ded965\
+            fe8a66984790bc9cb48617d1aa636f2b37cb7d9c27e7986a7dab5e541563beed",
+            "d7375c023c27e5e177aa8791917b40c37d91de5a90ae387d0013f1a93dee7cd6\
+            96",
+            "",
+            "0774ffe93adfd1c31871047db8796a74f80eba981cdfe3aaa2a7e85385170428",
+        ],
+        [
+            "40eac72a9145e59506d4f7a6fa4ddf1618743a7aafabde2cb18ac5313831f8ba\
+            6d43eb0c5df4103d377e1f820905dfcaa29ef90580f6cb79c0ef6f2f85ded965\
+            fe8a66984790bc9cb48617d1aa636f2b37cb7d9c27e7986a7dab5e541563beed",
+            "447d812d81447f",
+            "65130d11ce21943b713f4e025321bfcfa2e257e2b9e40bfc",
+            "df4fc8135c17a952fb387f461aa59de35c73a8f4e97034a60240469bb6d4646b",
+        ],
+    ];
+
+    // SLH-DSA-SHAKE-192s, keyGen
+    static REF_KEYGEN_SHAKE_192s: [[&str; 4]; 1] = [
+        [
+            "fbd8375f084440aba314caee93dad5103bfba32d6a051941",
+            "9338d3a56973da9bc7d6a8af075ddc895641c62345d9f9c1",
+            "469ea9d6c6c461244a96911fd6250970d41b540e71adb571",
+            "5d33f8770c1e895fd453d458767649b08ae3dcb06be9fe27",
+        ],
+    ];
+
+    // SLH-DSA-SHAKE-192s, sigGen
+    static REF_SIGGEN_SHAKE_192s: [[&str; 4]; 2] = [
+        [
+            "fbd8375f084440aba314caee93dad5103bfba32d6a0519419338d3a56973da9b\
+            c7d6a8af075ddc895641c62345d9f9c1469ea9d6c6c461244a96911fd6250970\
+            d41b540e71adb5715d33f8770c1e895fd453d458767649b08ae3dcb06be9fe27",
+            "043faeba8aa699d22744811cd99a6635d90f171145afd64402d67211360812f9\
+            e5",
+            "",
+            "d0a46f4728a6d41ea444fb995d27f84afbbe3ea08dd8123f706245ce4bf46a33",
+        ],
+        [
+            "fbd8375f084440aba314caee93dad5103bfba32d6a0519419338d3a56973da9b\
+            c7d6a8af075ddc895641c62345d9f9c1469ea9d6c6c461244a96911fd6250970\
+            d41b540e71adb5715d33f8770c1e895fd453d458767649b08ae3dcb06be9fe27",
+            "7dff2210c6baaa",
+            "a426c8382cc00026eb8b89f93bff8109ef7c921d7345a950",
+            "7bad08b86cbc3526651c4116c559061453dbdbf3bccc91bd89c5b4b9c36ef341",
+        ],
+    ];
+
+    // SLH-DSA-SHA2-192f, keyGen
+    static REF_KEYGEN_SHA2_192f: [[&str; 4]; 1] = [
+        [
+            "a8137cc743a508e60e8829f3ee979c84fc009464b9c88ed5",
+            "a6fa3c59c53f69d455a6848513c8ac090a3a7cd54ae9a20f",
+            "c44325ecd0acd6def06df23506b20b7a278b91ff6b3b8a87",
+            "1b6e22c85206a340a5b70413d0a3d4edebb9ba7d54a45c24",
+        ],
+    ];
+
+    // SLH-DSA-SHA2-192f, sigGen
+    static REF_SIGGEN_SHA2_192f: [[&str; 4]; 2] = [
+        [
+            "a8137cc743a508e60e8829f3ee979c84fc009464b9c88ed5a6fa3c59c53f69d4\
+            55a6848513c8ac090a3a7cd54ae9a20fc44325ecd0acd6def06df23506b20b7a\
+            278b91ff6b3b8a871b6e22c85206a340a5b70413d0a3d4edebb9ba7d54a45c24",
+            "2b63278e5ac35f3bc4c51df0bd6c670c1d3dab4f2122c9fe616f7214af928f2f\
+            6e",
+            "",
+            "6fa2359702542138d59fe9580c78753ef046e035acfd1e8bd3d2d230b1e4e3c6",
+        ],
+        [
+            "a8137cc743a508e60e8829f3ee979c84fc009464b9c88ed5a6fa3c59c53f69d4\
+            55a6848513c8ac090a3a7cd54ae9a20fc44325ecd0acd6def06df23506b20b7a\
+            278b91ff6b3b8a871b6e22c85206a340a5b70413d0a3d4edebb9ba7d54a45c24",
+            "dfcaeed7fc525f",
+            "76d6b871fc038315deb5b5f71228ac4d62ec1fd4d5149996",
+            "6bd59156629cdc5308c37dfef0628cebdb0406e4f59f691976e61357d2dc29aa",
+        ],
+    ];
+
+    // SLH-DSA-SHAKE-192f, keyGen
+    static REF_KEYGEN_SHAKE_192f: [[&str; 4]; 1] = [
+        [
+            "f192b4cda485fbff75600a74f5a5d77af2000bf18b383a67",
+            "c12703a94c85c3f07faf36cd0b64214b46d641a48aef7727",
+            "82d40b4076eeba5c3960289b5e8971df44ba1047b10efc35",
+            "cd5b516f76dcf39b7567ec69c0659ea9a482e4910d32ba5f",
+        ],
+    ];
+
+    // SLH-DSA-SHAKE-192f, sigGen
+    static REF_SIGGEN_SHAKE_192f: [[&str; 4]; 2] = [
+        [
+            "f192b4cda485fbff75600a74f5a5d77af2000bf18b383a67c12703a94c85c3f0\
+            7faf36cd0b64214b46d641a48aef772782d40b4076eeba5c3960289b5e8971df\
+            44ba1047b10efc35cd5b516f76dcf39b7567ec69c0659ea9a482e4910d32ba5f",
+            "9b050462fb8706ede0f5281a7ba24cfcf4cc34299a560ecb62a336278fc5f0f8\
+            95",
+            "",
+            "e89c0fa1f1b4f438a279321d5c69076da6431b8590d44b7e5ea3f3057fe338b9",
+        ],
+        [
+            "f192b4cda485fbff75600a74f5a5d77af2000bf18b383a67c12703a94c85c3f0\
+            7faf36cd0b64214b46d641a48aef772782d40b4076eeba5c3960289b5e8971df\
+            44ba1047b10efc35cd5b516f76dcf39b7567ec69c0659ea9a482e4910d32ba5f",
+            "7a476084cf703f",
+            "92eeffc2201a9b45601b64dfa4ec64c8261259bdf38fea3e",
+            "fce951ada914f31e00262591306e8d3ddba9800e1af24fe3b986dcd392f1c828",
+        ],
+    ];
+
+    // SLH-DSA-SHA2-256s, keyGen
+    static REF_KEYGEN_SHA2_256s: [[&str; 4]; 1] = [
+        [
+            "37daddf746361061077aac37722a96fb5338a596a796c545367d0fe258190f07",
+            "31fdc35ea12f473e6b716032d8b48a91806cfed24ddf3755950b4eb2ecafa209",
+            "467f8d923626d753748d712d1c80fb2814d9355317984c2857b8043779a2f56f",
+            "3e692bfa899b8018009b62719609a918410c70961294e5422ed1b55b84c661c8",
+        ],
+    ];
+
+    // SLH-DSA-SHA2-256s, sigGen
+    static REF_SIGGEN_SHA2_256s: [[&str; 4]; 2] = [
+        [
+            "37daddf746361061077aac37722a96fb5338a596a796c545367d0fe258190f07\
+            31fdc35ea12f473e6b716032d8b48a91806cfed24ddf3755950b4eb2ecafa209\
+            467f8d923626d753748d712d1c80fb2814d9355317984c2857b8043779a2f56f\
+            3e692bfa899b8018009b62719609a918410c70961294e5422ed1b55b84c661c8",
+            "3fb387737035b4dd6ea7af04498fc7a0a2058f7fb1f586cef08e1594e38f6c78\
+            71",
+            "",
+            "1c6d0af1eeb1489c10b97486a8f3bc7f431b002dde9e324a7ff1eb4d6cb84341",
+        ],
+        [
+            "37daddf746361061077aac37722a96fb5338a596a796c545367d0fe258190f07\
+            31fdc35ea12f473e6b716032d8b48a91806cfed24ddf3755950b4eb2ecafa209\
+            467f8d923626d753748d712d1c80fb2814d9355317984c2857b8043779a2f56f\
+            3e692bfa899b8018009b62719609a918410c70961294e5422ed1b55b84c661c8",
+            "aab51db2c67ca0",
+            "82e4732dd2363b187fe149d0dc0171679053baf55d6c5d57a64dfaeaa5213aa5",
+            "a8230c6a820bc3d27a6a3dcf06152ef4342a78ca7750a9791fac66035e50f2cb",
+        ],
+    ];
+
+    // SLH-DSA-SHAKE-256s, keyGen
+    static REF_KEYGEN_SHAKE_256s: [[&str; 4]; 1] = [
+        [
+            "3b59f3ebbd7d260ac8d994099a9ce63152975991dbd67af36a89973321f3b008",
+            "ad682c64273084f72afca2d781a234d257d90100071b45d7073a5bf1aad92aab",
+            "38e59aa96d7b14f3a1e63ce2b83bedef46e7185c7381c095d21b961e0475a988",
+            "7ffc83b11e84a9aa9909395aa5a40becb6947857eed22db3e2707805a9211eaf",
+        ],
+    ];
+
+    // SLH-DSA-SHAKE-256s, sigGen
+    static REF_SIGGEN_SHAKE_256s: [[&str; 4]; 2] = [
+        [
+            "3b59f3ebbd7d260ac8d994099a9ce63152975991dbd67af36a89973321f3b008\
+            ad682c64273084f72afca2d781a234d257d90100071b45d7073a5bf1aad92aab\
+            38e59aa96d7b14f3a1e63ce2b83bedef46e7185c7381c095d21b961e0475a988\
+            7ffc83b11e84a9aa9909395aa5a40becb6947857eed22db3e2707805a9211eaf",
+            "f749874763cef3be04b4bbc4b5678bb266266407e38559b591a7fe127fab5c27\
+            3d",
+            "",
+            "f330d8acc3b6da537b9fe6ef3dd3c9687e1f15709970a0a1d90d03a2f24211bb",
+        ],
+        [
+            "3b59f3ebbd7d260ac8d994099a9ce63152975991dbd67af36a89973321f3b008\
+            ad682c64273084f72afca2d781a234d257d90100071b45d7073a5bf1aad92aab\
+            38e59aa96d7b14f3a1e63ce2b83bedef46e7185c7381c095d21b961e0475a988\
+            7ffc83b11e84a9aa9909395aa5a40becb6947857eed22db3e2707805a9211eaf",
+            "13d44a2492fae1",
+            "59c5d7ccea63130a5e5207cf6f34bab780cc36261d721c3890ea3e99fa979dc4",
+            "a386cf11e1d6214d5e38ff5ca14e8c721771667e2821967e7aa531f203ce2676",
+        ],
+    ];
+
+    macro_rules! check_acvp_keygen { ($name:ident, $kat:ident) => {
+        {
+            use $name::PrivateKey;
+
+            const N: usize = PrivateKey::ENC_LEN / 4;
+            for kv in $kat.iter() {
+                let mut v = [[0u8; N]; 4];
+                for (x, e) in v.iter_mut().zip(kv.iter()) {
+                    x.copy_from_slice(&hex::decode(e).unwrap());
+                }
+                let sk = PrivateKey::from_seeds(&v[0], &v[1], &v[2]);
+                let pk = sk.compute_public().encode();
+                assert!(pk[..N] == v[2] && pk[N..] == v[3]);
+            }
+        }
+    } }
+
+    macro_rules! check_acvp_siggen { ($name:ident, $kat:ident) => {
+        {
+            use $name::PrivateKey;
+            use sha2::{Digest, Sha256};
+
+            for kv in $kat.iter() {
+                let sk = PrivateKey::decode(&hex::decode(kv[0]).unwrap())
+                    .unwrap();
+                let msg = hex::decode(kv[1]).unwrap();
+                let sig = if kv[2].is_empty() {
+                    sk.sign_internal(&msg, None)
+                } else {
+                    let mut addrnd = [0u8; PrivateKey::ENC_LEN / 4];
+                    addrnd.copy_from_slice(&hex::decode(kv[2]).unwrap());
+                    sk.sign_internal(&msg, Some(&addrnd))
+                };
+                assert!(Sha256::digest(&sig[..])[..]
+                    == hex::decode(kv[3]).unwrap()[..]);
+                assert!(sk.compute_public().verify_internal(&sig, &msg));
+            }
+        }
+    } }
+
+    macro_rules! check_acvp_sigver { ($name:ident, $kat:ident) => {
+        {
+            use $name::PublicKey;
+
+            for &(epk, emsg, esig, ok) in $kat.iter() {
+                let pk = PublicKey::decode(&hex::decode(epk).unwrap())
+                    .unwrap();
+                let msg = hex::decode(emsg).unwrap();
+                let sig = hex::decode(esig).unwrap();
+                assert!(pk.verify_internal(&sig, &msg) == ok);
+            }
+        }
+    } }
+
+    macro_rules! check_pqc_kat { ($name:ident, $kat:ident, $hash:ty) => {
+        {
+            use $name::PrivateKey;
+            use sha2::Digest;
+
+            const N: usize = PrivateKey::ENC_LEN / 4;
+            let mut v = [[0u8; N]; 4];
+            for (x, e) in v.iter_mut().zip($kat.iter()) {
+                x.copy_from_slice(&hex::decode(e).unwrap());
+            }
+            let sk = PrivateKey::from_seeds(&v[0], &v[1], &v[2]);
+            let pk = sk.compute_public();
+            assert!(pk.encode()[N..] == v[3]);
+            let msg = hex::decode($kat[4]).unwrap();
+            let sig = sk.sign_ctx_deterministic(&[], &msg).unwrap();
+            assert!(<$hash>::digest(&sig[..])[..]
+                == hex::decode($kat[5]).unwrap()[..]);
+            assert!(pk.verify(&sig, &msg));
+        }
+    } }
+
+    #[test]
+    fn kat_sha2_128s() {
+        check_acvp_keygen!(SLH_DSA_SHA2_128s, ACVP_KEYGEN_SHA2_128s);
+        check_pqc_kat!(SLH_DSA_SHA2_128s, PQC_SHA2_128s, sha2::Sha256);
+    }
+
+    #[test]
+    fn kat_shake_128s() {
+        check_pqc_kat!(SLH_DSA_SHAKE_128s, PQC_SHAKE_128s, sha3::Sha3_256);
+    }
+
+    #[test]
+    fn kat_sha2_128f() {
+        check_pqc_kat!(SLH_DSA_SHA2_128f, PQC_SHA2_128f, sha2::Sha256);
+    }
+
+    #[test]
+    fn kat_shake_128f() {
+        check_acvp_siggen!(SLH_DSA_SHAKE_128f, ACVP_SIGGEN_SHAKE_128f);
+        check_acvp_sigver!(SLH_DSA_SHAKE_128f, ACVP_SIGVER_SHAKE_128f);
+        check_pqc_kat!(SLH_DSA_SHAKE_128f, PQC_SHAKE_128f, sha3::Sha3_256);
+    }
+
+    #[test]
+    fn kat_sha2_256f() {
+        check_acvp_siggen!(SLH_DSA_SHA2_256f, ACVP_SIGGEN_SHA2_256f);
+    }
+
+    #[test]
+    fn kat_shake_256f() {
+        check_acvp_keygen!(SLH_DSA_SHAKE_256f, ACVP_KEYGEN_SHAKE_256f);
+        check_acvp_siggen!(SLH_DSA_SHAKE_256f, ACVP_SIGGEN_SHAKE_256f);
+        check_acvp_sigver!(SLH_DSA_SHAKE_256f, ACVP_SIGVER_SHAKE_256f);
+    }
+
+    #[test]
+    fn kat_sha2_192s() {
+        check_acvp_keygen!(SLH_DSA_SHA2_192s, REF_KEYGEN_SHA2_192s);
+        check_acvp_siggen!(SLH_DSA_SHA2_192s, REF_SIGGEN_SHA2_192s);
+    }
+
+    #[test]
+    fn kat_shake_192s() {
+        check_acvp_keygen!(SLH_DSA_SHAKE_192s, REF_KEYGEN_SHAKE_192s);
+        check_acvp_siggen!(SLH_DSA_SHAKE_192s, REF_SIGGEN_SHAKE_192s);
+    }
+
+    #[test]
+    fn kat_sha2_192f() {
+        check_acvp_keygen!(SLH_DSA_SHA2_192f, REF_KEYGEN_SHA2_192f);
+        check_acvp_siggen!(SLH_DSA_SHA2_192f, REF_SIGGEN_SHA2_192f);
+    }
+
+    #[test]
+    fn kat_shake_192f() {
+        check_acvp_keygen!(SLH_DSA_SHAKE_192f, REF_KEYGEN_SHAKE_192f);
+        check_acvp_siggen!(SLH_DSA_SHAKE_192f, REF_SIGGEN_SHAKE_192f);
+    }
+
+    #[test]
+    fn kat_sha2_256s() {
+        check_acvp_keygen!(SLH_DSA_SHA2_256s, REF_KEYGEN_SHA2_256s);
+        check_acvp_siggen!(SLH_DSA_SHA2_256s, REF_SIGGEN_SHA2_256s);
+    }
+
+    #[test]
+    fn kat_shake_256s() {
+        check_acvp_keygen!(SLH_DSA_SHAKE_256s, REF_KEYGEN_SHAKE_256s);
+        check_acvp_siggen!(SLH_DSA_SHAKE_256s, REF_SIGGEN_SHAKE_256s);
+    }
+}
@@ -0,0 +1,1648 @@
+//! XMSS and XMSS^MT implementation.
+//!
+//! This follows RFC 8391, with the additional parameter sets from NIST
+//! SP 800-208 (SHA-256 truncated to 192 bits, and SHAKE256 with 256-bit
+//! or 192-bit outputs). The WOTS+ private keys are derived from the
+//! secret seed with the `PRF_keygen` function defined in SP 800-208.
+//! The parameter sets that use SHA-512 or SHAKE128 (from RFC 8391 but not
+//! approved by SP 800-208) are not implemented.
+//!
+//! Each parameter set is in its own module; XMSS parameter sets are
+//! named after their RFC 8391 or SP 800-208 name (e.g. `XMSS_SHA2_10_256`
+//! for XMSS-SHA2_10_256), and XMSS^MT parameter sets use an underscore
+//! instead of the slash (e.g. `XMSSMT_SHA2_20_2_256` for
+//! XMSSMT-SHA2_20/2_256).
+//!
+//! WARNING: XMSS is a stateful signature scheme. The state handling
+//! follows the same conventions as the LMS implementation: signatures
+//! can only be generated through a `Reservation`, obtained with
+//! `PrivateKey::reserve()` once the updated private key has been
+//! committed to stable storage by a caller-provided function. Indices
+//! that were reserved but not used are simply lost. `PrivateKey` is
+//! deliberately neither `Copy` nor `Clone`. Signature generation is
+//! deterministic (as specified in RFC 8391), thus it does not need a
+//! random source.
+//!
+//! Public keys and signatures use the RFC 8391 formats. Private keys use
+//! a compact, non-standard format that contains the OID, the index of
+//! the next signature, and the three secret and public seeds; the trees
+//! are recomputed when decoding. Each tree uses a fractal traversal (the
+//! tree is split into layers of small subtrees), so that memory usage and
+//! per-signature cost remain bounded. With XMSS^MT, a new tree at a
+//! given layer is fully computed when the previous one is exhausted.
+
+// We use the names from RFC 8391, which do not following the default
+// casing style rules of Rust.
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+
+macro_rules! define_xmss_core { () => {
+
+    use crate::{CryptoRng, RngCore};
+    use core::convert::TryFrom;
+
+    /// An XMSS or XMSS^MT private key.
+    ///
+    /// The private key contains the index of the next signature, which is
+    /// updated when signatures are reserved (see `reserve()`). This type
+    /// is neither `Copy` nor `Clone`, so that a given state cannot be
+    /// inadvertently duplicated; it does not implement `Debug` either, so
+    /// that the secret seeds are not written into logs.
+    pub struct PrivateKey {
+        SK_SEED: [u8; n],
+        SK_PRF: [u8; n],
+        PUB_SEED: [u8; n],
+        idx: u64,
+        // Current tree at each layer (the last one is the top tree).
+        trees: [Tree; d],
+        // WOTS+ signature of the root of trees[j], computed by trees[j+1].
+        root_sigs: [[u8; wots_siglen]; d - 1],
+    }
+
+    /// An XMSS or XMSS^MT public key.
+    #[derive(Clone, Copy, Debug)]
+    pub struct PublicKey {
+        root: [u8; n],
+        PUB_SEED: [u8; n],
+    }
+
+    /// A reservation of signature indices.
+    ///
+    /// A reservation is obtained from `PrivateKey::reserve()`, after the
+    /// updated private key state has been committed. Each signature uses
+    /// the next reserved index; once all reserved indices have been used,
+    /// signing fails.
+    pub struct Reservation<'a> {
+        sk: &'a mut PrivateKey,
+        next: u64,
+        end: u64,
+    }
+
+    // A tree at a given layer, with its traversal state (fractal
+    // traversal). The tree is split into nL layers of subtrees of height
+    // sub_h; for each layer, we keep the subtree that contains the
+    // current leaf (all nodes, in heap order, index 1 being the subtree
+    // root), and the next subtree of the same layer, which is built
+    // incrementally (one leaf per signature) and whose bottom nodes are
+    // filled as they are obtained.
+    #[derive(Clone, Copy, Debug)]
+    struct Tree {
+        layer: u32,
+        tree: u64,
+        root: [u8; n],
+        leaf: u32,
+        cur: [[[u8; n]; SUB_N]; nL],
+        next: [[[u8; n]; SUB_N]; nL],
+        next_leaf: [u32; nL],
+        stack: [[[u8; n]; th]; nL],
+        stack_level: [[usize; th]; nL],
+        stack_len: [usize; nL],
+    }
+
+    // WOTS+ parameters (w = 16).
+    const len1: usize = 2 * n;
+    const len2: usize = 3;
+    const len: usize = len1 + len2;
+    const wots_siglen: usize = len * n;
+
+    // Total height.
+    const h: usize = th * d;
+
+    const SUB_N: usize = 1usize << (sub_h + 1);
+    const nL: usize = th / sub_h;
+
+    const siglen: usize = idx_len + n + d * (wots_siglen + th * n);
+
+    // Hash address (ADRS), as eight 32-bit words.
+    #[derive(Clone, Copy)]
+    struct Adrs([u32; 8]);
+
+    const ADRS_OTS: u32 = 0;
+    const ADRS_LTREE: u32 = 1;
+    const ADRS_HASH: u32 = 2;
+
+    impl Adrs {
+
+        // Words 4 to 7 are set to zero.
+        fn new(layer: u32, tree: u64, typ: u32) -> Self {
+            Self([ layer, (tree >> 32) as u32, tree as u32, typ, 0, 0, 0, 0 ])
+        }
+
+        fn encode(&self) -> [u8; 32] {
+            let mut buf = [0u8; 32];
+            for i in 0..8 {
+                buf[(4 * i)..(4 * i + 4)].copy_from_slice(&self.0[i].to_be_bytes());
+            }
+            buf
+        }
+    }
+
+    fn F(key: &[u8; n], m: &[u8; n]) -> [u8; n] {
+        hash(0, key, m, &[], &[])
+    }
+
+    fn H(key: &[u8; n], m1: &[u8; n], m2: &[u8; n]) -> [u8; n] {
+        hash(1, key, m1, m2, &[])
+    }
+
+    fn H_msg(r: &[u8], root: &[u8; n], idx: u64, msg: &[u8]) -> [u8; n] {
+        let mut ib = [0u8; n];
+        ib[(n - 8)..].copy_from_slice(&idx.to_be_bytes());
+        hash(2, r, root, &ib, msg)
+    }
+
+    fn PRF(key: &[u8; n], m: &[u8; 32]) -> [u8; n] {
+        hash(3, key, m, &[], &[])
+    }
+
+    fn PRF_keygen(sk_seed: &[u8; n], pub_seed: &[u8; n], adrs: &Adrs)
+        -> [u8; n]
+    {
+        hash(4, sk_seed, pub_seed, &adrs.encode(), &[])
+    }
+
+    fn xor_into(x: &mut [u8; n], y: &[u8; n]) {
+        for i in 0..n {
+            x[i] ^= y[i];
+        }
+    }
+
+    fn rand_hash(left: &[u8; n], right: &[u8; n], pub_seed: &[u8; n],
+        adrs: &mut Adrs) -> [u8; n]
+    {
+        adrs.0[7] = 0;
+        let key = PRF(pub_seed, &adrs.encode());
+        adrs.0[7] = 1;
+        let bm0 = PRF(pub_seed, &adrs.encode());
+        adrs.0[7] = 2;
+        let bm1 = PRF(pub_seed, &adrs.encode());
+        let mut l = *left;
+        let mut r = *right;
+        xor_into(&mut l, &bm0);
+        xor_into(&mut r, &bm1);
+        H(&key, &l, &r)
+    }
+
+    // Applies steps iterations of the chaining function, starting at
+    // position start. The OTS and chain addresses must already be set.
+    fn chain(x: &[u8; n], start: usize, steps: usize, pub_seed: &[u8; n],
+        adrs: &mut Adrs) -> [u8; n]
+    {
+        let mut tmp = *x;
+        for j in start..(start + steps) {
+            adrs.0[6] = j as u32;
+            adrs.0[7] = 0;
+            let key = PRF(pub_seed, &adrs.encode());
+            adrs.0[7] = 1;
+            let bm = PRF(pub_seed, &adrs.encode());
+            xor_into(&mut tmp, &bm);
+            tmp = F(&key, &tmp);
+        }
+        tmp
+    }
+
+    // Converts a message into base-16 digits, with the checksum.
+    fn wots_digits(msg: &[u8; n]) -> [u8; len] {
+        let mut dd = [0u8; len];
+        let mut csum = 0u32;
+        for i in 0..len1 {
+            let v = (msg[i >> 1] >> (4 - 4 * (i & 1))) & 0x0F;
+            dd[i] = v;
+            csum += 15 - (v as u32);
+        }
+        csum <<= 4;
+        dd[len1] = (csum >> 12) as u8 & 0x0F;
+        dd[len1 + 1] = (csum >> 8) as u8 & 0x0F;
+        dd[len1 + 2] = (csum >> 4) as u8 & 0x0F;
+        dd
+    }
+
+    // Gets the WOTS+ private key element for chain c, using the OTS
+    // address set in adrs.
+    fn wots_sk(sk_seed: &[u8; n], pub_seed: &[u8; n], adrs: &Adrs, c: usize)
+        -> [u8; n]
+    {
+        let mut a = *adrs;
+        a.0[5] = c as u32;
+        a.0[6] = 0;
+        a.0[7] = 0;
+        PRF_keygen(sk_seed, pub_seed, &a)
+    }
+
+    fn wots_sign(sk_seed: &[u8; n], pub_seed: &[u8; n],
+        layer: u32, tree: u64, i: u32, msg: &[u8; n]) -> [u8; wots_siglen]
+    {
+        let mut adrs = Adrs::new(layer, tree, ADRS_OTS);
+        adrs.0[4] = i;
+        let dd = wots_digits(msg);
+        let mut sig = [0u8; wots_siglen];
+        for c in 0..len {
+            let sk = wots_sk(sk_seed, pub_seed, &adrs, c);
+            adrs.0[5] = c as u32;
+            sig[(c * n)..((c + 1) * n)].copy_from_slice(
+                &chain(&sk, 0, dd[c] as usize, pub_seed, &mut adrs));
+        }
+        sig
+    }
+
+    // Compresses a WOTS+ public key with an L-tree.
+    fn ltree(pk: &mut [[u8; n]; len], pub_seed: &[u8; n],
+        layer: u32, tree: u64, i: u32) -> [u8; n]
+    {
+        let mut adrs = Adrs::new(layer, tree, ADRS_LTREE);
+        adrs.0[4] = i;
+        let mut ll = len;
+        while ll > 1 {
+            for j in 0..(ll >> 1) {
+                adrs.0[6] = j as u32;
+                pk[j] = rand_hash(&pk[2 * j], &pk[2 * j + 1], pub_seed, &mut adrs);
+            }
+            if (ll & 1) != 0 {
+                pk[ll >> 1] = pk[ll - 1];
+            }
+            ll = (ll + 1) >> 1;
+            adrs.0[5] += 1;
+        }
+        pk[0]
+    }
+
+    // Computes leaf i of a tree.
+    fn leaf_node(sk_seed: &[u8; n], pub_seed: &[u8; n],
+        layer: u32, tree: u64, i: u32) -> [u8; n]
+    {
+        let mut adrs = Adrs::new(layer, tree, ADRS_OTS);
+        adrs.0[4] = i;
+        let mut pk = [[0u8; n]; len];
+        for c in 0..len {
+            let sk = wots_sk(sk_seed, pub_seed, &adrs, c);
+            adrs.0[5] = c as u32;
+            pk[c] = chain(&sk, 0, 15, pub_seed, &mut adrs);
+        }
+        ltree(&mut pk, pub_seed, layer, tree, i)
+    }
+
+    // Computes the internal node at level l (leaves are at level 0) and
+    // index j within that level, from its two children.
+    fn intr_node(pub_seed: &[u8; n], layer: u32, tree: u64,
+        l: usize, j: u32, left: &[u8; n], right: &[u8; n]) -> [u8; n]
+    {
+        let mut adrs = Adrs::new(layer, tree, ADRS_HASH);
+        adrs.0[5] = (l - 1) as u32;
+        adrs.0[6] = j;
+        rand_hash(left, right, pub_seed, &mut adrs)
+    }
+
+    // Computes a tree root from a WOTS+ signature and an authentication
+    // path.
+    fn root_from_sig(pub_seed: &[u8; n], layer: u32, tree: u64, i: u32,
+        sig: &[u8], auth: &[u8], msg: &[u8; n]) -> [u8; n]
+    {
+        let mut adrs = Adrs::new(layer, tree, ADRS_OTS);
+        adrs.0[4] = i;
+        let dd = wots_digits(msg);
+        let mut pk = [[0u8; n]; len];
+        for c in 0..len {
+            adrs.0[5] = c as u32;
+            let s = <&[u8; n]>::try_from(&sig[(c * n)..((c + 1) * n)]).unwrap();
+            pk[c] = chain(s, dd[c] as usize, 15 - dd[c] as usize,
+                pub_seed, &mut adrs);
+        }
+        let mut node = ltree(&mut pk, pub_seed, layer, tree, i);
+        for k in 0..th {
+            let a = <&[u8; n]>::try_from(&auth[(k * n)..((k + 1) * n)]).unwrap();
+            let j = i >> (k + 1);
+            node = if ((i >> k) & 1) == 0 {
+                intr_node(pub_seed, layer, tree, k + 1, j, &node, a)
+            } else {
+                intr_node(pub_seed, layer, tree, k + 1, j, a, &node)
+            };
+        }
+        node
+    }
+
+    // Splits a signature index into tree and leaf indices for layer j.
+    fn split_idx(idx: u64, j: usize) -> (u64, u32) {
+        let leaf = ((idx >> (j * th)) & ((1u64 << th) - 1)) as u32;
+        let sh = (j + 1) * th;
+        let tree = if sh >= 64 { 0 } else { idx >> sh };
+        (tree, leaf)
+    }
+
+    impl Tree {
+
+        fn empty() -> Self {
+            Self {
+                layer: 0,
+                tree: 0,
+                root: [0u8; n],
+                leaf: 0,
+                cur: [[[0u8; n]; SUB_N]; nL],
+                next: [[[0u8; n]; SUB_N]; nL],
+                next_leaf: [0u32; nL],
+                stack: [[[0u8; n]; th]; nL],
+                stack_level: [[0usize; th]; nL],
+                stack_len: [0usize; nL],
+            }
+        }
+
+        // Computes the whole tree, and sets the traversal state for
+        // leaf q.
+        fn set(&mut self, sk_seed: &[u8; n], pub_seed: &[u8; n],
+            layer: u32, tree: u64, q: u32)
+        {
+            self.layer = layer;
+            self.tree = tree;
+            let mut stack = [[0u8; n]; th + 1];
+            let mut levels = [0usize; th + 1];
+            let mut sp = 0;
+            for x in 0..(1u32 << th) {
+                let mut node = leaf_node(sk_seed, pub_seed, layer, tree, x);
+                let mut l = 0;
+                let mut j = x;
+                self.store_init(q, l, j, &node);
+                while sp > 0 && levels[sp - 1] == l {
+                    sp -= 1;
+                    node = intr_node(pub_seed, layer, tree, l + 1, j >> 1,
+                        &stack[sp], &node);
+                    l += 1;
+                    j >>= 1;
+                    self.store_init(q, l, j, &node);
+                }
+                stack[sp] = node;
+                levels[sp] = l;
+                sp += 1;
+            }
+            self.root = stack[0];
+            self.leaf = q;
+            for i in 0..nL {
+                let sh = sub_h * (i + 1);
+                self.next_leaf[i] = (((q >> sh) + 2) << sh).min(1u32 << th);
+                self.stack_len[i] = 0;
+            }
+        }
+
+        // Stores a node, obtained while computing the whole tree, in the
+        // relevant subtrees for leaf q.
+        fn store_init(&mut self, q: u32, l: usize, j: u32, node: &[u8; n]) {
+            for i in 0..nL {
+                if l < sub_h * i || l > sub_h * (i + 1) {
+                    continue;
+                }
+                let dd = sub_h * (i + 1) - l;
+                let s = j >> dd;
+                let pos = (1usize << dd) + (j as usize & ((1usize << dd) - 1));
+                let c = q >> (sub_h * (i + 1));
+                if s == c {
+                    self.cur[i][pos] = *node;
+                } else if s == c + 1 {
+                    self.next[i][pos] = *node;
+                }
+            }
+        }
+
+        // Advances the traversal state to the next leaf.
+        fn advance(&mut self, sk_seed: &[u8; n], pub_seed: &[u8; n]) {
+            let q = self.leaf + 1;
+            self.leaf = q;
+            if q >= (1u32 << th) {
+                return;
+            }
+            // The top layer has a single subtree, and is never updated
+            // (its next subtree is empty).
+            for i in 0..nL {
+                let sh = sub_h * (i + 1);
+                let c = q >> sh;
+                // The subtree under construction is the one after the
+                // subtree of the previous leaf.
+                let end = ((((q - 1) >> sh) + 2) << sh).min(1u32 << th);
+                if self.next_leaf[i] < end {
+                    self.build_step(sk_seed, pub_seed, i);
+                }
+                if (q & ((1u32 << sh) - 1)) == 0 {
+                    // We just entered a new subtree for this layer; it
+                    // was completed as the next subtree.
+                    for pos in (1..(SUB_N >> 1)).rev() {
+                        let dd = 31 - (pos as u32).leading_zeros() as usize;
+                        let j = (c << dd) + (pos as u32 - (1u32 << dd));
+                        self.next[i][pos] = intr_node(pub_seed,
+                            self.layer, self.tree, sh - dd, j,
+                            &self.next[i][2 * pos], &self.next[i][2 * pos + 1]);
+                    }
+                    self.cur[i] = self.next[i];
+                    self.next_leaf[i] = ((c + 1) << sh).min(1u32 << th);
+                    self.stack_len[i] = 0;
+                }
+            }
+        }
+
+        // Processes one leaf for the next subtree of layer i.
+        fn build_step(&mut self, sk_seed: &[u8; n], pub_seed: &[u8; n],
+            i: usize)
+        {
+            let x = self.next_leaf[i];
+            self.next_leaf[i] = x + 1;
+            let mut node = leaf_node(sk_seed, pub_seed, self.layer, self.tree, x);
+            let mut l = 0;
+            let mut j = x;
+            let tl = sub_h * i;
+            loop {
+                if l == tl {
+                    let pos = (SUB_N >> 1) + (j as usize & ((SUB_N >> 1) - 1));
+                    self.next[i][pos] = node;
+                    return;
+                }
+                let sp = self.stack_len[i];
+                if sp == 0 || self.stack_level[i][sp - 1] != l {
+                    self.stack[i][sp] = node;
+                    self.stack_level[i][sp] = l;
+                    self.stack_len[i] = sp + 1;
+                    return;
+                }
+                self.stack_len[i] = sp - 1;
+                node = intr_node(pub_seed, self.layer, self.tree, l + 1, j >> 1,
+                    &self.stack[i][sp - 1], &node);
+                l += 1;
+                j >>= 1;
+            }
+        }
+
+        // Moves the traversal state to leaf q. Leaves are normally used
+        // in order; if some leaves were skipped, then we advance the
+        // traversal state, unless recomputing the whole tree is cheaper.
+        fn goto(&mut self, sk_seed: &[u8; n], pub_seed: &[u8; n], q: u32) {
+            let lq = self.leaf;
+            if q < lq || (((q - lq) as u64) * ((nL - 1) as u64)) >= (1u64 << th) {
+                let (layer, tree) = (self.layer, self.tree);
+                self.set(sk_seed, pub_seed, layer, tree, q);
+            }
+            while self.leaf < q {
+                self.advance(sk_seed, pub_seed);
+            }
+        }
+
+        // Writes the authentication path for the current leaf.
+        fn auth_path(&self, out: &mut [u8]) {
+            let q = self.leaf;
+            for l in 0..th {
+                let i = l / sub_h;
+                let dd = sub_h * (i + 1) - l;
+                let k = (q >> l) ^ 1;
+                let pos = (1usize << dd) + (k as usize & ((1usize << dd) - 1));
+                out[(l * n)..((l + 1) * n)].copy_from_slice(&self.cur[i][pos]);
+            }
+        }
+    }
+
+    impl PrivateKey {
+
+        /// Generates a new private key.
+        ///
+        /// The index of the new key is zero; the key state is committed
+        /// to storage by the first call to `reserve()`.
+        pub fn generate<T: CryptoRng + RngCore>(rng: &mut T) -> Self {
+            let mut SK_SEED = [0u8; n];
+            let mut SK_PRF = [0u8; n];
+            let mut PUB_SEED = [0u8; n];
+            rng.fill_bytes(&mut SK_SEED);
+            rng.fill_bytes(&mut SK_PRF);
+            rng.fill_bytes(&mut PUB_SEED);
+            Self::from_parts(SK_SEED, SK_PRF, PUB_SEED, 0)
+        }
+
+        fn from_parts(SK_SEED: [u8; n], SK_PRF: [u8; n], PUB_SEED: [u8; n],
+            idx: u64) -> Self
+        {
+            let mut sk = Self {
+                SK_SEED, SK_PRF, PUB_SEED, idx,
+                trees: [Tree::empty(); d],
+                root_sigs: [[0u8; wots_siglen]; d - 1],
+            };
+            // Trees are computed for the next signature (or the last
+            // one, if the key is exhausted).
+            let q = if idx >= (1u64 << h) { (1u64 << h) - 1 } else { idx };
+            for j in (0..d).rev() {
+                let (tree, leaf) = split_idx(q, j);
+                sk.set_tree(q, j, tree, leaf);
+            }
+            sk
+        }
+
+        // Recomputes the tree at layer j for the signature index q, and
+        // the signature of its root by the upper layer.
+        fn set_tree(&mut self, q: u64, j: usize, tree: u64, leaf: u32) {
+            self.trees[j].set(&self.SK_SEED, &self.PUB_SEED,
+                j as u32, tree, leaf);
+            if j + 1 < d {
+                let (t1, l1) = split_idx(q, j + 1);
+                self.root_sigs[j] = wots_sign(&self.SK_SEED, &self.PUB_SEED,
+                    (j + 1) as u32, t1, l1, &self.trees[j].root);
+            }
+        }
+
+        /// Gets the public key corresponding to this private key.
+        pub fn compute_public(&self) -> PublicKey {
+            PublicKey { root: self.trees[d - 1].root, PUB_SEED: self.PUB_SEED }
+        }
+
+        /// Encoded private key length (in bytes).
+        pub const ENC_LEN: usize = 4 + 8 + 3 * n;
+
+        /// Encodes this private key into bytes.
+        ///
+        /// The encoding contains the OID, the index of the next signature
+        /// (64 bits), and the `SK_SEED`, `SK_PRF` and `PUB_SEED` values.
+        pub fn encode(&self) -> [u8; Self::ENC_LEN] {
+            let mut buf = [0u8; Self::ENC_LEN];
+            buf[0..4].copy_from_slice(&oid.to_be_bytes());
+            buf[4..12].copy_from_slice(&self.idx.to_be_bytes());
+            buf[12..(12 + n)].copy_from_slice(&self.SK_SEED);
+            buf[(12 + n)..(12 + 2 * n)].copy_from_slice(&self.SK_PRF);
+            buf[(12 + 2 * n)..].copy_from_slice(&self.PUB_SEED);
+            buf
+        }
+
+        /// Decodes a private key from bytes.
+        ///
+        /// The current tree at each layer is recomputed (this has the
+        /// same cost as key pair generation). This function returns `None`
+        /// if the source does not have the proper length, or if the
+        /// encoded OID does not match this parameter set, or if the index
+        /// is out of range.
+        pub fn decode(buf: &[u8]) -> Option<Self> {
+            if buf.len() != Self::ENC_LEN {
+                return None;
+            }
+            let eoid = u32::from_be_bytes(*<&[u8; 4]>::try_from(&buf[0..4]).unwrap());
+            let idx = u64::from_be_bytes(*<&[u8; 8]>::try_from(&buf[4..12]).unwrap());
+            if eoid != oid || idx > (1u64 << h) {
+                return None;
+            }
+            let mut SK_SEED = [0u8; n];
+            let mut SK_PRF = [0u8; n];
+            let mut PUB_SEED = [0u8; n];
+            SK_SEED.copy_from_slice(&buf[12..(12 + n)]);
+            SK_PRF.copy_from_slice(&buf[(12 + n)..(12 + 2 * n)]);
+            PUB_SEED.copy_from_slice(&buf[(12 + 2 * n)..]);
+            Some(Self::from_parts(SK_SEED, SK_PRF, PUB_SEED, idx))
+        }
+
+        /// Gets the number of signatures that have not been reserved yet.
+        pub fn remaining(&self) -> u64 {
+            (1u64 << h) - self.idx
+        }
+
+        /// Reserves `count` signature indices.
+        ///
+        /// The index is advanced by `count`, and the encoded updated
+        /// private key is provided to `persist`, which must commit it to
+        /// stable storage. If `persist` returns `true`, then the
+        /// reservation is returned, and can be used to generate up to
+        /// `count` signatures. If `persist` returns `false`, then the
+        /// private key is left unchanged and `None` is returned. This
+        /// function also returns `None` (without calling `persist`) if
+        /// `count` is zero or greater than the number of remaining
+        /// signatures.
+        pub fn reserve<F>(&mut self, count: u64, persist: F)
+            -> Option<Reservation<'_>>
+            where F: FnOnce(&[u8; Self::ENC_LEN]) -> bool
+        {
+            if count == 0 || count > self.remaining() {
+                return None;
+            }
+            let next = self.idx;
+            let end = next + count;
+            let mut enc = self.encode();
+            enc[4..12].copy_from_slice(&end.to_be_bytes());
+            if !persist(&enc) {
+                return None;
+            }
+            self.idx = end;
+            Some(Reservation { sk: self, next, end })
+        }
+
+        fn sign_idx(&mut self, idx: u64, msg: &[u8]) -> [u8; siglen] {
+            // Update the trees, from the top.
+            for j in (0..d).rev() {
+                let (tree, leaf) = split_idx(idx, j);
+                if self.trees[j].tree != tree {
+                    self.set_tree(idx, j, tree, leaf);
+                } else {
+                    self.trees[j].goto(&self.SK_SEED, &self.PUB_SEED, leaf);
+                }
+            }
+
+            let mut ib = [0u8; 32];
+            ib[24..].copy_from_slice(&idx.to_be_bytes());
+            let r = PRF(&self.SK_PRF, &ib);
+            let root = self.trees[d - 1].root;
+            let mp = H_msg(&r, &root, idx, msg);
+
+            let mut sig = [0u8; siglen];
+            sig[..idx_len].copy_from_slice(&idx.to_be_bytes()[(8 - idx_len)..]);
+            sig[idx_len..(idx_len + n)].copy_from_slice(&r);
+            let mut off = idx_len + n;
+            for j in 0..d {
+                if j == 0 {
+                    let (tree, leaf) = split_idx(idx, 0);
+                    sig[off..(off + wots_siglen)].copy_from_slice(
+                        &wots_sign(&self.SK_SEED, &self.PUB_SEED,
+                            0, tree, leaf, &mp));
+                } else {
+                    sig[off..(off + wots_siglen)].copy_from_slice(
+                        &self.root_sigs[j - 1]);
+                }
+                off += wots_siglen;
+                self.trees[j].auth_path(&mut sig[off..(off + th * n)]);
+                off += th * n;
+            }
+            sig
+        }
+    }
+
+    impl<'a> Reservation<'a> {
+
+        /// Gets the number of reserved indices that have not been used yet.
+        pub fn remaining(&self) -> u64 {
+            self.end - self.next
+        }
+
+        /// Signs a message with the next reserved index.
+        ///
+        /// This function returns `None` if all reserved indices have
+        /// already been used.
+        pub fn sign(&mut self, msg: &[u8]) -> Option<[u8; siglen]> {
+            if self.next >= self.end {
+                return None;
+            }
+            let idx = self.next;
+            self.next = idx + 1;
+            Some(self.sk.sign_idx(idx, msg))
+        }
+    }
+
+    impl PublicKey {
+
+        /// Encoded public key length (in bytes).
+        pub const ENC_LEN: usize = 4 + 2 * n;
+
+        /// Encodes this public key into bytes (RFC 8391 format).
+        pub fn encode(&self) -> [u8; Self::ENC_LEN] {
+            let mut buf = [0u8; Self::ENC_LEN];
+            buf[0..4].copy_from_slice(&oid.to_be_bytes());
+            buf[4..(4 + n)].copy_from_slice(&self.root);
+            buf[(4 + n)..].copy_from_slice(&self.PUB_SEED);
+            buf
+        }
+
+        /// Decodes a public key from bytes (RFC 8391 format).
+        ///
+        /// This function returns `None` if the source does not have the
+        /// proper length, or if the encoded OID does not match this
+        /// parameter set.
+        pub fn decode(buf: &[u8]) -> Option<Self> {
+            if buf.len() != Self::ENC_LEN {
+                return None;
+            }
+            let eoid = u32::from_be_bytes(*<&[u8; 4]>::try_from(&buf[0..4]).unwrap());
+            if eoid != oid {
+                return None;
+            }
+            let mut root = [0u8; n];
+            let mut PUB_SEED = [0u8; n];
+            root.copy_from_slice(&buf[4..(4 + n)]);
+            PUB_SEED.copy_from_slice(&buf[(4 + n)..]);
+            Some(Self { root, PUB_SEED })
+        }
+
+        /// Verifies a signature (RFC 8391 format) on a message.
+        pub fn verify(self, sig: &[u8], msg: &[u8]) -> bool {
+            if sig.len() != siglen {
+                return false;
+            }
+            let mut ib = [0u8; 8];
+            ib[(8 - idx_len)..].copy_from_slice(&sig[..idx_len]);
+            let idx = u64::from_be_bytes(ib);
+            if h < 64 && idx >= (1u64 << h) {
+                return false;
+            }
+            let r = &sig[idx_len..(idx_len + n)];
+            let mut node = H_msg(r, &self.root, idx, msg);
+            let mut off = idx_len + n;
+            for j in 0..d {
+                let (tree, leaf) = split_idx(idx, j);
+                let ws = &sig[off..(off + wots_siglen)];
+                off += wots_siglen;
+                let auth = &sig[off..(off + th * n)];
+                off += th * n;
+                node = root_from_sig(&self.PUB_SEED, j as u32, tree, leaf,
+                    ws, auth, &node);
+            }
+            node == self.root
+        }
+    }
+
+} } // end of macro define_xmss_core
+
+// ========================================================================
+
+// Hash function for the SHA-256 parameter sets (SHA-256 and SHA-256/192).
+// The first input is the domain separation value, encoded over pad bytes.
+macro_rules! define_xmss_sha256 { () => {
+
+    use sha2::{Sha256, Digest};
+
+    fn hash(x: u32, m1: &[u8], m2: &[u8], m3: &[u8], m4: &[u8]) -> [u8; n] {
+        let mut sh = Sha256::new();
+        sh.update(&[0u8; pad - 4]);
+        sh.update(&x.to_be_bytes());
+        sh.update(m1);
+        sh.update(m2);
+        sh.update(m3);
+        sh.update(m4);
+        let mut r = [0u8; n];
+        r[..].copy_from_slice(&sh.finalize()[..n]);
+        r
+    }
+
+} } // end of macro define_xmss_sha256
+
+// Hash function for the SHAKE256 parameter sets.
+macro_rules! define_xmss_shake { () => {
+
+    use sha3::{Shake256, digest::{Update, ExtendableOutput, XofReader}};
+
+    fn hash(x: u32, m1: &[u8], m2: &[u8], m3: &[u8], m4: &[u8]) -> [u8; n] {
+        let mut sh = Shake256::default();
+        sh.update(&[0u8; pad - 4]);
+        sh.update(&x.to_be_bytes());
+        sh.update(m1);
+        sh.update(m2);
+        sh.update(m3);
+        sh.update(m4);
+        let mut r = [0u8; n];
+        sh.finalize_xof().read(&mut r);
+        r
+    }
+
+} } // end of macro define_xmss_shake
+
+// ========================================================================
+
+// Defines a parameter set module. Parameters are: hash function, output
+// size (n), length of the domain separation prefix, height of each tree,
+// number of layers (d, which is 1 for XMSS), height of the subtrees
+// used for traversal, length of the index in signatures, and OID.
+macro_rules! define_xmss_params {
+    ($(#[$attr:meta])* $name:ident, $hash:ident, $n:expr, $pad:expr,
+        $th:expr, $d:expr, $sub_h:expr, $idx_len:expr, $oid:expr) =>
+    {
+        $(#[$attr])*
+        pub mod $name {
+
+            define_xmss_core!{}
+
+            const n: usize = $n;
+            const pad: usize = $pad;
+            const th: usize = $th;
+            const d: usize = $d;
+            const sub_h: usize = $sub_h;
+            const idx_len: usize = $idx_len;
+            const oid: u32 = $oid;
+
+            $hash!{}
+        }
+    }
+}
+
+define_xmss_params!{
+    /// XMSS-SHA2_10_256
+    XMSS_SHA2_10_256, define_xmss_sha256, 32, 32, 10, 1, 5, 4, 0x00000001
+}
+define_xmss_params!{
+    /// XMSS-SHA2_16_256
+    XMSS_SHA2_16_256, define_xmss_sha256, 32, 32, 16, 1, 4, 4, 0x00000002
+}
+define_xmss_params!{
+    /// XMSS-SHA2_20_256
+    XMSS_SHA2_20_256, define_xmss_sha256, 32, 32, 20, 1, 5, 4, 0x00000003
+}
+define_xmss_params!{
+    /// XMSS-SHA2_10_192
+    XMSS_SHA2_10_192, define_xmss_sha256, 24, 4, 10, 1, 5, 4, 0x0000000d
+}
+define_xmss_params!{
+    /// XMSS-SHA2_16_192
+    XMSS_SHA2_16_192, define_xmss_sha256, 24, 4, 16, 1, 4, 4, 0x0000000e
+}
+define_xmss_params!{
+    /// XMSS-SHA2_20_192
+    XMSS_SHA2_20_192, define_xmss_sha256, 24, 4, 20, 1, 5, 4, 0x0000000f
+}
+define_xmss_params!{
+    /// XMSS-SHAKE256_10_256
+    XMSS_SHAKE256_10_256, define_xmss_shake, 32, 32, 10, 1, 5, 4, 0x00000010
+}
+define_xmss_params!{
+    /// XMSS-SHAKE256_16_256
+    XMSS_SHAKE256_16_256, define_xmss_shake, 32, 32, 16, 1, 4, 4, 0x00000011
+}
+define_xmss_params!{
+    /// XMSS-SHAKE256_20_256
+    XMSS_SHAKE256_20_256, define_xmss_shake, 32, 32, 20, 1, 5, 4, 0x00000012
+}
+define_xmss_params!{
+    /// XMSS-SHAKE256_10_192
+    XMSS_SHAKE256_10_192, define_xmss_shake, 24, 4, 10, 1, 5, 4, 0x00000013
+}
+define_xmss_params!{
+    /// XMSS-SHAKE256_16_192
+    XMSS_SHAKE256_16_192, define_xmss_shake, 24, 4, 16, 1, 4, 4, 0x00000014
+}
+define_xmss_params!{
+    /// XMSS-SHAKE256_20_192
+    XMSS_SHAKE256_20_192, define_xmss_shake, 24, 4, 20, 1, 5, 4, 0x00000015
+}
+
+define_xmss_params!{
+    /// XMSSMT-SHA2_20/2_256
+    XMSSMT_SHA2_20_2_256, define_xmss_sha256, 32, 32, 10, 2, 5, 3, 0x00000001
+}
+define_xmss_params!{
+    /// XMSSMT-SHA2_20/4_256
+    XMSSMT_SHA2_20_4_256, define_xmss_sha256, 32, 32, 5, 4, 5, 3, 0x00000002
+}
+define_xmss_params!{
+    /// XMSSMT-SHA2_40/2_256
+    XMSSMT_SHA2_40_2_256, define_xmss_sha256, 32, 32, 20, 2, 5, 5, 0x00000003
+}
+define_xmss_params!{
+    /// XMSSMT-SHA2_40/4_256
+    XMSSMT_SHA2_40_4_256, define_xmss_sha256, 32, 32, 10, 4, 5, 5, 0x00000004
+}
+define_xmss_params!{
+    /// XMSSMT-SHA2_40/8_256
+    XMSSMT_SHA2_40_8_256, define_xmss_sha256, 32, 32, 5, 8, 5, 5, 0x00000005
+}
+define_xmss_params!{
+    /// XMSSMT-SHA2_60/3_256
+    XMSSMT_SHA2_60_3_256, define_xmss_sha256, 32, 32, 20, 3, 5, 8, 0x00000006
+}
+define_xmss_params!{
+    /// XMSSMT-SHA2_60/6_256
+    XMSSMT_SHA2_60_6_256, define_xmss_sha256, 32, 32, 10, 6, 5, 8, 0x00000007
+}
+define_xmss_params!{
+    /// XMSSMT-SHA2_60/12_256
+    XMSSMT_SHA2_60_12_256, define_xmss_sha256, 32, 32, 5, 12, 5, 8, 0x00000008
+}
+define_xmss_params!{
+    /// XMSSMT-SHA2_20/2_192
+    XMSSMT_SHA2_20_2_192, define_xmss_sha256, 24, 4, 10, 2, 5, 3, 0x00000021
+}
+define_xmss_params!{
+    /// XMSSMT-SHA2_20/4_192
+    XMSSMT_SHA2_20_4_192, define_xmss_sha256, 24, 4, 5, 4, 5, 3, 0x00000022
+}
+define_xmss_params!{
+    /// XMSSMT-SHA2_40/2_192
+    XMSSMT_SHA2_40_2_192, define_xmss_sha256, 24, 4, 20, 2, 5, 5, 0x00000023
+}
+define_xmss_params!{
+    /// XMSSMT-SHA2_40/4_192
+    XMSSMT_SHA2_40_4_192, define_xmss_sha256, 24, 4, 10, 4, 5, 5, 0x00000024
+}
+define_xmss_params!{
+    /// XMSSMT-SHA2_40/8_192
+    XMSSMT_SHA2_40_8_192, define_xmss_sha256, 24, 4, 5, 8, 5, 5, 0x00000025
+}
+define_xmss_params!{
+    /// XMSSMT-SHA2_60/3_192
+    XMSSMT_SHA2_60_3_192, define_xmss_sha256, 24, 4, 20, 3, 5, 8, 0x00000026
+}
+define_xmss_params!{
+    /// XMSSMT-SHA2_60/6_192
+    XMSSMT_SHA2_60_6_192, define_xmss_sha256, 24, 4, 10, 6, 5, 8, 0x00000027
+}
+define_xmss_params!{
+    /// XMSSMT-SHA2_60/12_192
+    XMSSMT_SHA2_60_12_192, define_xmss_sha256, 24, 4, 5, 12, 5, 8, 0x00000028
+}
+define_xmss_params!{
+    /// XMSSMT-SHAKE256_20/2_256
+    XMSSMT_SHAKE256_20_2_256, define_xmss_shake, 32, 32, 10, 2, 5, 3, 0x00000029
+}
+define_xmss_params!{
+    /// XMSSMT-SHAKE256_20/4_256
+    XMSSMT_SHAKE256_20_4_256, define_xmss_shake, 32, 32, 5, 4, 5, 3, 0x0000002a
+}
+define_xmss_params!{
+    /// XMSSMT-SHAKE256_40/2_256
+    XMSSMT_SHAKE256_40_2_256, define_xmss_shake, 32, 32, 20, 2, 5, 5, 0x0000002b
+}
+define_xmss_params!{
+    /// XMSSMT-SHAKE256_40/4_256
+    XMSSMT_SHAKE256_40_4_256, define_xmss_shake, 32, 32, 10, 4, 5, 5, 0x0000002c
+}
+define_xmss_params!{
+    /// XMSSMT-SHAKE256_40/8_256
+    XMSSMT_SHAKE256_40_8_256, define_xmss_shake, 32, 32, 5, 8, 5, 5, 0x0000002d
+}
+define_xmss_params!{
+    /// XMSSMT-SHAKE256_60/3_256
+    XMSSMT_SHAKE256_60_3_256, define_xmss_shake, 32, 32, 20, 3, 5, 8, 0x0000002e
+}
+define_xmss_params!{
+    /// XMSSMT-SHAKE256_60/6_256
+    XMSSMT_SHAKE256_60_6_256, define_xmss_shake, 32, 32, 10, 6, 5, 8, 0x0000002f
+}
+define_xmss_params!{
+    /// XMSSMT-SHAKE256_60/12_256
+    XMSSMT_SHAKE256_60_12_256, define_xmss_shake, 32, 32, 5, 12, 5, 8, 0x00000030
+}
+define_xmss_params!{
+    /// XMSSMT-SHAKE256_20/2_192
+    XMSSMT_SHAKE256_20_2_192, define_xmss_shake, 24, 4, 10, 2, 5, 3, 0x00000031
+}
+define_xmss_params!{
+    /// XMSSMT-SHAKE256_20/4_192
+    XMSSMT_SHAKE256_20_4_192, define_xmss_shake, 24, 4, 5, 4, 5, 3, 0x00000032
+}
+define_xmss_params!{
+    /// XMSSMT-SHAKE256_40/2_192
+    XMSSMT_SHAKE256_40_2_192, define_xmss_shake, 24, 4, 20, 2, 5, 5, 0x00000033
+}
+define_xmss_params!{
+    /// XMSSMT-SHAKE256_40/4_192
+    XMSSMT_SHAKE256_40_4_192, define_xmss_shake, 24, 4, 10, 4, 5, 5, 0x00000034
+}
+define_xmss_params!{
+    /// XMSSMT-SHAKE256_40/8_192
+    XMSSMT_SHAKE256_40_8_192, define_xmss_shake, 24, 4, 5, 8, 5, 5, 0x00000035
+}
+define_xmss_params!{
+    /// XMSSMT-SHAKE256_60/3_192
+    XMSSMT_SHAKE256_60_3_192, define_xmss_shake, 24, 4, 20, 3, 5, 8, 0x00000036
+}
+define_xmss_params!{
+    /// XMSSMT-SHAKE256_60/6_192
+    XMSSMT_SHAKE256_60_6_192, define_xmss_shake, 24, 4, 10, 6, 5, 8, 0x00000037
+}
+define_xmss_params!{
+    /// XMSSMT-SHAKE256_60/12_192
+    XMSSMT_SHAKE256_60_12_192, define_xmss_shake, 24, 4, 5, 12, 5, 8, 0x00000038
+}
+
+// ========================================================================
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::{CryptoRng, RngCore, RngError};
+
+    // A pretend RNG for test purposes (not random at all).
+    struct CRNG(u8);
+
+    impl RngCore for CRNG {
+        fn next_u32(&mut self) -> u32 {
+            let mut buf = [0u8; 4];
+            self.fill_bytes(&mut buf);
+            u32::from_le_bytes(buf)
+        }
+        fn next_u64(&mut self) -> u64 {
+            let mut buf = [0u8; 8];
+            self.fill_bytes(&mut buf);
+            u64::from_le_bytes(buf)
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            for b in dst.iter_mut() {
+                self.0 = self.0.wrapping_mul(29).wrapping_add(17);
+                *b = self.0;
+            }
+        }
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), RngError> {
+            self.fill_bytes(dst);
+            Ok(())
+        }
+    }
+
+    impl CryptoRng for CRNG { }
+
+    // Known-answer tests from the XMSS reference implementation
+    // (https://github.com/XMSS/xmss-reference), as included in the liboqs
+    // test suite (tests/KATs/sig_stfl/xmss). Each vector contains the
+    // private key (re-encoded in our format: OID, index, SK_SEED, SK_PRF
+    // and PUB_SEED), the public key, the message and the signature.
+
+    // XMSS-SHA2_10_256
+    static KAT_XMSS_SHA2_10_256: [&str; 4] = [
+        "000000010000000000000000061550234d158c5ec95595fe04ef7a25767f2e24\
+        cc2bc479d09d86dc9abcfde7056a8c266f9ef97ed08541dbd2e1ffa19810f539\
+        2d076276ef41277c3ab6e94a04562ad35e8ecafaafda16981cdaa147606beea6\
+        2801342af13c8b5535f72f94",
+        "00000001b901b8d9332fe458eb6de87af74655d0b5ad936a66fdb6ac9d1b8cf2\
+        5bb6db8404562ad35e8ecafaafda16981cdaa147606beea62801342af13c8b55\
+        35f72f94",
+        "b338dd755d5618c464ab331f14de3dd4a358bba00d28fb35236741e902f7b248\
+        ce",
+        "00000000404dff9b9f3931fe6158fff355a8ee715c9bc6a87fe6627928f3ca10\
+        55fa7010c534b0d4c6ffdf4dbfe00e72405efe83bbcf19aa2030a8cb16380848\
+        2b6376ff8ce01fb8090f4842896a1ea5e9282f35cacd245a4b9de9fe84e93158\
+        51d68a72b3ecb9f440937c8ba4ac3f0429246cbc2777e8b92d84f4ba49fab894\
+        65fcb0fc8017e582746f531b4697925154a22e2d6a0f1b81913438000c295153\
+        d7adca8f852c50d360f65f887479e9631a2ca30fe3ad92e7bf648643835f4f8c\
+        c081a6c951b83b77608a08c021821da61962cfcc8e97d75441921d39c5ad5375\
+        43efbaf0345dc70826e6e950929570c72e51619600c58d932a72657b19af163e\
+        0b8f7aaf2949a5eb26c517909e0e663e36753491182975206009107509dffc89\
+        8d308b903e84a8b29718bf7125397aff5467d53cf8f36eb945b6b98d48e81c01\
+        74a0e03541d24369cf8edda4288ffa615d16fbc7355cfc0966ba9256e5b8a44d\
+        a95760dfb61301b10fd3e82436e267db089773e43b984297d1e0d395dcc77fcf\
+        eccefebd4b80b3f241872ea251da466ca6c5324346f4b5e6886654a86592641a\
+        8c32ac554261b2d9130462c976b039e593f873ad1712820ff3e723fe57f13775\
+        1ab3ca8b5b20d28d1b9384df1d710ac39faf699989418b7856c2034c695a693e\
+        cc336eb472de5049c743089529695b028f2f72be0893e59169e9a2376c64bc5c\
+        cac5482e5a6e9c88d710a3ff8f23c206b09d314bf50568228b1bacf1ce330d52\
+        9bd3793d7c7cd9ec770c111d9681d6f1b97d908cbbd436444853feb47f234d31\
+        f5e92b9e0465d67ac0fe48859126befa7f7d121a67c2c2970b37b8081b4e73c5\
+        a21a41f60160a61fafbd48649a3d2032c1679a67f348e3e25275fcd9af650937\
+        feb0a30f25878ceed7d6ca693518b5a2f5418135ea9316effdecdb1dffc9ee3a\
+        62eff0e66f3d05bd9d5f8679b536bb6d39792b28df2481a6eecb9bee40b11a10\
+        d39a90ea1aac47bf956fbfe9b0427b599b9bc024f326515e71615419423fec3f\
+        19f621d49b6eed59f129a6b1411b7b1afcf073095d57b03f25a16f946ed716bf\
+        705f567a151be85b8e8195cc2f070bfd482702182b8a4a43ed942f6bd3cbf9de\
+        7e8aeb17c41e1c009c94ff4a2050e3731088b75474b38dc52badf53c7dcd3fb9\
+        8d023649fc4799ce060addacec7cd4e656074e631c1cb8aef88efee0817c2e3d\
+        79e287f4510e48dfb7e23cb49d6fca39a1e0f471f16a8bb65af02150d059036d\
+        00386dd287bea4d52fb263b57ae5add901cade838b1d7347d9e47eaf6456148c\
+        6c4e44b0fa3dfcf5c9cec2d80ad509a65aef0e3e663b7f31bca437311ba799d4\
+        c2acc138f85d73cb40792ff03f8f20427d951444990ca3976a71368a7dc1455e\
+        880722f06f02163bc712e852a914f22e5675eb9b1c6c8b7fd20a8880ad2eef97\
+        982c065c937bd3639357e4c7450cbda0b51cca8e3e078dc760fd99ebf646b823\
+        69576539b2bd5b2c866ed5ae94423a5ce18c685352398d01c983f080d7beb8a9\
+        243aaa9ac1ddcc1b058b92bead301e8f3b8f5ef71eee7966302b44d2e26d2a02\
+        393713e5d4d3fef42196faa368274c78c2932d22840eca6018ce7d16b19a0727\
+        cb1966eb28b57d137c5264cc2e627f24a3bad50ea4f75c7bd8998709c01ed5ac\
+        fff0891934e94da2cacca212fb48be3f9eaa310547e73c388d881f36ae21efed\
+        d23744f6b07c5d6d2776c191ed41e607316f61bbef7a20e1a03150ae833d1895\
+        2ae35188fbfdfa55c12a388836717bb2bdd97e89121c56c3b53e8198242315c9\
+        e438512e0c8354a3e599cb7217ae688647a72985606bbd0720f6fa5c5b6f70e8\
+        8234ee54c6db0a41106c866564650829fe4b232635b06b18240c9f86369c75b2\
+        f7d237211a380c43f95d362e0680d9ea2ca47e1dc8c49703e22650b765f847ad\
+        86be25a3b7630d640a0097632df13f600e8a025dd9a1fc67b0eb09c1ca9fa392\
+        3896927dee1e3cc0c81f4b82e43b89cacc69c9b8adca1670f7d4e50db7bcd94c\
+        2115e75f2bfd2336da5a304d0f3455927360bf5040e95d1454106f2a8a7cd27d\
+        5510e7b5be7b5b9edefdc3d4249d655c51f4c1dba0f359be4769ab66edbc8028\
+        24e9ab866e8eeaa2feb1cc855f0a745aac84a610df0238112c6519f8e7346c45\
+        331a6036f84d5b6250f4b5bc0a2a6a31daf9c60eb13c20cc649a18e27a6c98b8\
+        2f08e21706a8bdf338cc69c1679d25ecff733a721211c1f6dd28091aaa9c93b0\
+        47efcd2c8a55f2da65e616f07dcc0f44081d4e359c1688a00f062ec925d24432\
+        862b547bb70f2af126a3daba5c918b224de444b8733e6fa601b3d349307e9458\
+        3d0ec976aeda2b90972324b3ace8c7b79a67723aea037e12da9efa9ca9668a4f\
+        5fdadfb9eee13398921f5023e354a6894825431dba7317e6a6f69f0e77294bcd\
+        02d7616e75ac31ec528fc070b8c34027c4e9cd0672903412fca6b723650d56af\
+        562069312fc7ef1891a77e1a3f29d810c205ee212e75863f3b8b1ed216df888a\
+        dd07aff45f1b5c01196329311414797cd5f67ffc54aad04c803ff7e83c2e8ba2\
+        24ce83695bb7916ac42b1861f5cb527fdbcd82dbfa31c5acf981d84142038375\
+        04263c96a0015841fbcc721f96d50a86d6e096ab54af9980f06cee6341c78d65\
+        83f6bae8081b3c44b0f10fb7300874b5011ff0f97c52f975a31355884c2f12b6\
+        ffee20e8371d38183c9d04977bfa037c9bd4dd7f7ce203fd7fad3852b3c2ae9d\
+        078adec70db1a7140ef1114ebb03e8de03237e0a27ff510015ac76fcefe4ebd4\
+        c3a1b6c67db2a82fe2b1bf18723db0f29fe4ad47b2eef22ac3c6661cfa7da747\
+        6d23b470fa2e0441b6473ebd291791f09b4ada70a5286eb05167bd59bfd8c464\
+        27413d60692382efb7882f60dc53aaafdf2014ca7d27f8fa93c187a8371b4179\
+        6557ae739912e5991c713532e81fa57f9ba562e1d3026d2d2d7373d99871bc62\
+        768ad70d3db184eabed83e30c11c9bc62f3340923a0082b987ec45cc7bd1db4b\
+        2b15e8ad3ead74e96d8c20d85617bbedc0bdaf8ed48b7ee8d7c42990028ec066\
+        9afc0861c22f2e9109f9bb35426bddb4a69eb8f45cd5b226f92e8026f1e62de1\
+        de435a4fc0caeda91c38a88f0037bdb296cd7b07ff040b1e08f02711e946b307\
+        a5a38487f53070985b8e28be6cce809f34100f0ca780996cd38e91ba7773bb63\
+        2d0be7978f3af3a92b961bd3a8759590726d6c1811f9e0bca87377334e7c1f12\
+        fe37401ca0200823938c816ed98981521470f7f2ccdd69d85e7530ebf39e3a59\
+        2b1c09bc6c352c3fdb108fb26e7acd3d5a4fc0442962e2c09651ac0d026e370f\
+        1ee1a8219c4833d70793d6e581fd25b0e95fab1eda67232c2fa12c4e379a6627\
+        e75ad408c1d2526005f2567ced8608e88cf53064fcdc58007198adfa860f9fed\
+        1df80efacc768a0a063e1afee6df1be3483105b1c45eb50bf7863b4278422ceb\
+        a9001ea00299ac0415bf28a9c49cc2e92fc15565b547538a027886c6eb0d83b7\
+        1138ce1a",
+    ];
+
+    // XMSSMT-SHA2_20/2_256
+    static KAT_XMSSMT_SHA2_20_2_256: [&str; 4] = [
+        "000000010000000000000000061550234d158c5ec95595fe04ef7a25767f2e24\
+        cc2bc479d09d86dc9abcfde7056a8c266f9ef97ed08541dbd2e1ffa19810f539\
+        2d076276ef41277c3ab6e94a04562ad35e8ecafaafda16981cdaa147606beea6\
+        2801342af13c8b5535f72f94",
+        "00000001049d5fe86ea348f4c6d28583aa3f9f86c36156fd23aae68bd09b1041\
+        63e2e2eb04562ad35e8ecafaafda16981cdaa147606beea62801342af13c8b55\
+        35f72f94",
+        "b338dd755d5618c464ab331f14de3dd4a358bba00d28fb35236741e902f7b248\
+        ce",
+        "000000404dff9b9f3931fe6158fff355a8ee715c9bc6a87fe6627928f3ca1055\
+        fa701095690ed3caa8519b752cdfbace3666edc260ee5325f9ea849cf9dfe6cd\
+        ad655bfaeee83253874cd01d7c5d07c53050812648a4891b86ed9b949459337c\
+        e4e48a389b5fa7e67c06e689894eba9ef2b30a6e85fae21db5e8d5f6499cf1c5\
+        a59a6d7a8d490a5464af68854e65606d09c887c8a95a511e76865a1b432139e0\
+        c164eeaedb66976eb9610da9403923b229a8a81ad64a8292003bcc9a8fbd9728\
+        4fb83f255ca9569583d0b40dc8a5942b9284515ccd9497d7ac749befab0032a0\
+        8d412c0291b9f93ea852a8937854abe02da2e505de98be606df1ecf315300f61\
+        45c26529510438e87ab06d786cf52c18d7f9c95d7b82f4f0e1aeb59e4221d40c\
+        67eb13ef80bb3601c44e35c901abee1ee25bfaab80639021377fda25672d97ed\
+        4126b888b571bcf4a34ce363bf227a5830acb4844cb17a942625686feb09dbda\
+        47af7568c4ed2ad3dc8191be8f775ab35e37528f5b94be4ddff4e56600e48885\
+        6881a622bb8619a78752ab810e1ef1e95a04a10597d7b674c38102590fa8bb21\
+        b909b3e05a4453e58d0d1efc4ee285c0b3eb81f2db7e57c22946c3ed1d4155d0\
+        a6041072fb2968834d281510fe4dcce2d85ed825c3f2c646cb718c5c742c8e76\
+        94c288548f3b542b812369de5c5b650683ccaa23d4b8bbeb833b694be7eab094\
+        f39bd8d4e6fc2cff5a94df26da0c0170fd8713cfc44391eb96da0957fd5066fd\
+        83c6a49e07417e807c087062711dcc7bbfa4b0d54077ad8db142a191e6ba6bb2\
+        518374f77e9f7fb8c2790cba9844a7fc11d1e6c38e1e9ea573e0a80ce46db790\
+        375d915d3c1f311f819d63f288caac1f49dcefcf8f9b30e025fb946b8a852086\
+        0ed5d425878ceed7d6ca693518b5a2f5418135ea9316effdecdb1dffc9ee3a62\
+        eff0e6647a2d39c98b01a8ba4a8b9fdff89292303c02c3aeec2eedb69db6cab0\
+        f45463ba7a25d6c3e4b7d39a28a65a5628a93556fa9f54e273b583f9a197bff4\
+        731e04237d992bab4119585a36f7584d2b25a2263a428a218cf009bf9eb53383\
+        9059e362fdfac5e8ee98639254eb106410a8bee8214c66a7bb81c99c989737a7\
+        ec3edb303edd88d20a7d32fe8e2735a21a0055b3473ba260666dc3a9cd83e3ae\
+        3b6ff7d7d8d5964af6a4ddd928553d5d44d3a6ed501ced954e06f89f82d334c4\
+        58125844219ee3db69a83dded1030cbea57d3d2efe8ae168d83856da3fbadb01\
+        02d52c5eb3e72f1046cbff94254043d879ca0b64f7bd7aec79f5f87c11de3de8\
+        0756e92f7bdf93266ea7d5a17b974c518c7024de642d12b495063ac5363ef6c5\
+        32e0d9c96aa96bfc9e1d6a851663336861da97a10ffc00a4d5a8e4f1ca04a8c9\
+        1fab90cea2895370c57b8bd4daeba7b426b8e8b3968a6eca166d917889aebdb3\
+        35e3b8115dafadb4f258d8bbed23b21c65486e46d8bca833b6967a09fc7da038\
+        de146403c9af2d41510fe1d89c15ce442c7fce52dd7aef5b65923dd8ce7c031e\
+        671882b33206242916d836656273e7bcf440a020f6bd2212afed89db1df7c77f\
+        eda1143cc52238532a9293d465022828337d62d54cd964879d20bd79f2f5c804\
+        2f16a9c2fb2e1a7013828ff9cfa9903e4c46d7f0a4409133ea2af9207a68e5df\
+        1ebb203398c519742b581d604c13e10dfedbcad3eb1f66133d21f83b2c15ba70\
+        e2084e20169b68e73b20457198ba678c4496b02f7124e0474ee3bb9b71076463\
+        85f85e396d5b6413a9ff0bc969b011dc3639f1798ce4edacacc625459a25639f\
+        6f2c5c15db24488cac196fd09e1a5aa1adf13d6a4316b27babdf3add912c5d1a\
+        25114b322fac7f9853ad29b44eafa7aa9a4ed2471b0bc91b4e1fdb7e6a80056c\
+        0f264c07ea4901dd4ff16e8e94b742ab1ba0d9b7c12674d959df58da15c7e223\
+        63c8ae8b2d2003632cbb912a4f788e97b9bb1c7eed5532b3026f7b8574061c60\
+        7f615f7be429b3d9a386e40b99329de24163911705bc3137f0c728ab58485329\
+        99315d2bfd2336da5a304d0f3455927360bf5040e95d1454106f2a8a7cd27d55\
+        10e7b54e165db2cbf8027ee9b5cf5ebcc9dd06a5c319e2b9611be946b6020ce4\
+        d9dd7329b336bf3e1a68ce17d1fb3485ec4ae8a823ad73c293a8ad9c8a45b231\
+        3792cec3a649fb0db6ee6f511b9b48e3da2b198695df9acdd096ed9be58cb5a6\
+        dfe702d4f9cef844f63d60f6e671df4c58fa9737ef38e41d273d28cb5091afd0\
+        a9857c87ad54963c2b8344f1b0b7d04cf60af2f462fc9e118d52827fea10bb9f\
+        fe8a0669c43c7f0fd2b44afc59f5f1e04e13d3faec42ef2e5ce5c39bb7e9a671\
+        f6fc6ae6bf9d49bb099e99e115fb80548bdca3276ca7dd2f3200da1fc5724e17\
+        d63321e7518484f9cbc19eaa901c9b4359152fcd7c0e51c82c962ff3f9a68b4f\
+        8b30440b23ad28725612f5fb98ff740afb457915740084644120add17b445078\
+        aaf541ddaae3b630834d387aa4b42958aafd178d333b9e1d92ddddc028609dd1\
+        c65c57a704637ad2e628163ee49d33ffa1530ed03f0a3e771b74ccf546bef58e\
+        f21dd186bd74bb36d42e7d9d5f94dd718412dd7417024ba0156a865cbf27a461\
+        847e450f0dd03d0b6940bf0a7a3d0dcf04ffa9f744e8ede879679e9b2b30df30\
+        ec5c8c9ab598e42c39ce458f83c500efae48c4b8b2b688a9ae8c84c68cca9d73\
+        c640bf005bbec6c139005a872f0d032278ddcfee8e636303308f418f73e3fcb7\
+        b63464d0b798af6c9717bbc5dee4c9150e8b271e12b53d2dc24d62bb1b522696\
+        ba13c5f73022d8b7cf740d798573335caa3b04cee0bdcadcc2dfd20e920a0b83\
+        391e2cfa2e0441b6473ebd291791f09b4ada70a5286eb05167bd59bfd8c46427\
+        413d6079846be00fc21d586d7f2c2af4fef5a3f2e0ad8f4d487b9b6bf50ace60\
+        4177339912e5991c713532e81fa57f9ba562e1d3026d2d2d7373d99871bc6276\
+        8ad70d676b893c9b7bef24df70145e4ce1dd2b660884c82fb0ee47d1473fdd0b\
+        8c4414011cbe8e48bfcbc428382a66b103b905c0cab36a7511b1bd6e23f4c690\
+        73cbe6c22f2e9109f9bb35426bddb4a69eb8f45cd5b226f92e8026f1e62de1de\
+        435a4fc0caeda91c38a88f0037bdb296cd7b07ff040b1e08f02711e946b307a5\
+        a38487f53070985b8e28be6cce809f34100f0ca780996cd38e91ba7773bb632d\
+        0be7978f3af3a92b961bd3a8759590726d6c1811f9e0bca87377334e7c1f12fe\
+        37401ca0200823938c816ed98981521470f7f2ccdd69d85e7530ebf39e3a592b\
+        1c09bc6c352c3fdb108fb26e7acd3d5a4fc0442962e2c09651ac0d026e370f1e\
+        e1a8219c4833d70793d6e581fd25b0e95fab1eda67232c2fa12c4e379a6627e7\
+        5ad408c1d2526005f2567ced8608e88cf53064fcdc58007198adfa860f9fed1d\
+        f80efacc768a0a063e1afee6df1be3483105b1c45eb50bf7863b4278422ceba9\
+        001ea00299ac0415bf28a9c49cc2e92fc15565b547538a027886c6eb0d83b711\
+        38ce1a14bcbe4fa64052b0853ede00cd41bf95b66da2a519216fa1a0a8ba5f10\
+        b4eafd8be62c40da2a76dfd8a5ee8ef42a8b808c55a533fc488a2b33a935a635\
+        e24f3e0c717e2320ff575addb18c567b1333decb0855e069d5759c48fe6d8c6a\
+        9d217bfcb7a9d40735a3151382e3456cc4bedf6c7ec94f186fcb6bf9398fec93\
+        4714e8e231402fc7bd5153981c7c789b26208ddd77e4796f70d6a72b7b9c5ec7\
+        5e4e3cd122f72621e92b1ac515a33b12b1801b2c3e461af788661815e6bad2e6\
+        472116b89b941a0e68a232089c6f831229be2eb0cd244be4fed78c8371d2dc61\
+        4445c76907f4a3edf18722bf0998fd03209c31c348cc79a2ad06abb1b3cc549e\
+        73b206a05345ec801a1c5c42d794ba1a35747493d76ca8567b62f0c2151923d8\
+        d9cd2f37d77e70ef7e12a80a3dc73ce284f2baae3816c351baf037f5fbdf29a9\
+        70bb385c19d6ac3bdeca5be59f322a17fbaff466e945fbb943bfe35854802837\
+        fefea3937069240712886742e890428c0c21bc057e82d5bb961095a5a18de149\
+        cd19f79e9dc3cf0feb5149f856c5a7bb82b6afd4b5310993d9e4e1d33b4cc601\
+        265906186b98cab4af1570cfdc928c0a221a3bbffe8e566d580a689ab51bb698\
+        26fa60135f60c1a1ea97cccc9ae96cc93b66942370bcf910d916cbb7f87a0bf5\
+        ef46dfebb050637754b0e40509441e19465b238ea45270d7bf5e0610a89b4a47\
+        aa821d9a3ba58127ddcee4e7204c38e0eeddff72b07fa5ad8eb1fa89554d940c\
+        2c88ef588f62ea602ba30fa9ecf3462711612b3e1ac0cdc4c11c85abb04ae596\
+        6743689380fd336a081b8c7f753b889bd73f10345b8c4988a7a4c865250e3707\
+        a8424b606059e3acea75f2f732058477097d5bf8f5a2df82e4af0ee9c4bb251a\
+        5657c15808b7ef26806e2f3d61137b44c9df4196a9208065ed1c70f9dce3c75a\
+        fd719f14d79818812360e4709e521e78b983f99b31863039069d3f86ffaaf318\
+        853eb4a1affc5e598acb6b15198e016c779bb7d77c54971e4566c2071edfc0d1\
+        9b41827913c5f7ee5d8a9411ab2706f3c9dd8e2cf4af497765a647c1aa42e364\
+        5485ad6598a776e2a46c9609c73aadc67a7477172d9f497556348b5cc055d8a6\
+        a0a752e5b9a508bcdc346bd1ad8643fa19eb36d922a018690d37d0e437857a78\
+        c47291b3530d6094fdedb782e1c927c11235ee632f6c3fa150de1bc1125feb33\
+        0079edb0733b58f1cdd3d20904f85de31c06fe375e7d1e20f4c79484c5431a02\
+        6ef8f5c8ac47e7fea2a80e17256ed956484e9004bd99ad8ecd0d1ee5790a83cf\
+        9a14827c3b5d5c25aa18255f5d512917bc1fa868af35ed3540bf0c10cabb267f\
+        612c26ae27dedb5665a4de3913aa2631c034c1bd22e5a721194be6d1e4337d1c\
+        f488e9f438bf7f77a856295e0d55a3af7f55bef0d7643d85b8892a69304ab1fe\
+        ce1ea498b7da996b2692eb8c3d1d8bc9beccfecc8ec67ee3a87df5b0b9c7c887\
+        dcb0cba7f5e1372399f3a4f4cc7df247752994ce0d024d2c6e620edd0b8cd308\
+        91faf58d6603d01c8308f95620a16b4b993aec83dc9e71a16c2d22b89e7cf829\
+        0db074aa6092a1f23dcd01d8b8c70674d55670c07a6d0655cf37d0516e6e1102\
+        865f0c53c5af80a45b09b078337d61afbd12da2820ae45cc4b213c00576ff3d5\
+        fc21d0db8d877757226f81078653eb4c90c0d2c7d304a6e0c4265dc3c1db3432\
+        02664008385964c6c56fa11532d7cf41e93f92ef28f3de2ca1d5817af2114a97\
+        bda7f6504ebb2a6ee6bf4753274be064d3ce467673717ad7350de4e83a1ba273\
+        06f11df36a2e30572e3fef2ef6b2518419395da9b7d4b191c88f3a863a477a2d\
+        226e5bcc04e39aaf1aa042a7b115ca26be8da52a162fdcec6e511b1497ffb8b8\
+        ad23d3c429f71236dcaf9de275d7b1d2dbe83822ff7d8c9bc7ba3ab5cb517228\
+        afe2e30c53e64c44d02cf9cb51ce371827cdbdf798b1723b418ec7cdf66cf09f\
+        444a06ddb94355f529337d6a3178d754d68be658934feabd4f4874b11e739f0e\
+        e4e95d2d23b41f037b9668c9f74d2b3d31027861779ff8516a29246d766d2a61\
+        a02cd5b8e338b9630e8e0ed5bdacb017a6d3b89c8a1108e525bad96e203e7a0c\
+        0b7f2148274fd20f9b53601f2b38df303d7f8785d06260485d7507782e11855e\
+        a62f44c755e11dc4e5e06ca263a2e6d229726b08a66962c1aafe0b85a896d3a2\
+        1ab0e695ccf3818c69ae16dc71782d99440ec9af4a9c33ffbc728c9c62c47e0d\
+        37cea661064246a8b2bba14abf5767f33e490aead721929515f091663b4437bd\
+        c34f5b15c7816b7c5cc8035f4fddb37c9a09712ba1a8e1fb4e0d8b37f0beaba9\
+        d1acffbd90b13035960abef4e3cff91b9871e49b16a6f0ff86445c441921d2e6\
+        98117109d810c864f024f62f8d25c263cada33916763373d76ec8955ed113f71\
+        c40834e79a1bd5e21cc2373598c66168492ffcd083d2a8e7e480f76274c04871\
+        9aff98c5e2774bb1039646bd25a240875655a77023b7f884f5852dcd9c5da173\
+        dacef7f01f6527cb7f5375fec1fd2d5c90a46d3d0501715b2d4cf51166226d8f\
+        35db7a9abe320e88f04f460f239dc2c0b65987adb734c1f9068b89f56e3abb3b\
+        35c1edbf72e5ce393330ff905f02da9c591fac66cbaf1ff1dabb3b199ac4a764\
+        eb5272ac230107f230e29845c2e2283763a5832809af2428c304c07cb21a96d7\
+        b7cdadf857f54c91a22b8ae6e4edc0dee01fa60697269bb1299f9fd7d3699d4d\
+        865a25bf0f31f93dae1d51c42fe755219bc2a4b2505487483a1b81bfa86bf6a9\
+        9642c51ac3dc78d5e42fea4adcc51c0501a8fd543217134694262e0ff5957ce7\
+        19766eb0cb34ca2e541992cc2619c65822a763fe6572e3b33c4c8c216b4a62a1\
+        3be7fe6fea1da8ec1d45ce65c4dfd09532fdaf74f99152ddbf0aaa53806f2c4e\
+        b3a156d49ed44c7713b7a50eeebc575166a1b6cc3aec2ca98398971f648242c3\
+        5e8eaa21257bfac587485d48ac54bc306344edebbf2a42b7e37b6086b1d9f542\
+        55742f000794155e7245f6cae1088c20619158f78f7b9554b43c2872dc68aab4\
+        15f3065688612ec88d83577278c8a7b64334993f80be7edcbf5cef5b00a2fc5b\
+        0ca04564db35ee027bfb28da1a7eee4e72e366a22f6b50780f70355da825fc21\
+        01bf7a057e5d26bf4216269a4c807f6b2055367d88910fbc65533cd0ede91523\
+        2b023d039ae21a53217dcf8398a5b70c3f2f1820f5ce459dfbfe7c3c9387f93d\
+        488d001f20b039229a704ff0193076f164c378e0ad63a1f11bd3332fad6a4a6f\
+        39302c69607400e8a4b9d9ec1682e88656cf619de7ba7384b1fd26850b80702b\
+        ee5893a4ab526f983ae3f8ad933b2d60caf51baaa828b87f55357ddc75a69f41\
+        f46493810eb69b9289f0954c9b9aa0a9c4b5b739bb75617c38ecbfe977be182b\
+        e7eeba3de73a9f25e491756d4ae3ba047a9542bf62a8aef9ba9025aafecba1f2\
+        5590f7",
+    ];
+
+    // XMSS-SHAKE256_10_256
+    static KAT_XMSS_SHAKE256_10_256: [&str; 4] = [
+        "0000001000000000000000000d1f5a068b0fa9ab98100dcfd3e56ea65e53822b\
+        d35b41d40f43dedf14ab24fa1851f7ede90910a5ba0031962a3f56cdc07e0ac2\
+        e9d9507f5d8d46de2e7618366d3ce02b040b4b4cbbf43bb759203806370b5730\
+        70be1c53d0f9d174f6fb92f2",
+        "0000001021eefb96a7e288e57ede182d6ce08f66e96079e6e32e0514e3f02f03\
+        d95c16976d3ce02b040b4b4cbbf43bb759203806370b573070be1c53d0f9d174\
+        f6fb92f2",
+        "b338dd755d5618c464ab331f14de3dd4a358bba00d28fb35236741e902f7b248\
+        ce",
+        "0000000013616cab98336dd083bf9dc3c7a576a5c1163ecb976628a2256008fc\
+        1fa8ce0fa89b33837fce4dedfda975d093b78bf029d3071dca013dbe61ce4147\
+        9cd6d574fdbf75e68efca9b1d81575e8d226a7779ff72790eae4231ea22d6192\
+        2d74a4d3d8786376d8214cf4a9fe1ff0faad65386bad115d5b21861a3eeba099\
+        254898c4e5e4792504feb58ca96dd968b805d5a621890cf9353b77afb1be89e7\
+        20b15e58c52cafa152166ff6399232050f29a9a572ab510cc95a93350617c630\
+        b9c6238514f0fdd37db537b5422c0334af9062e99465cf4980d36b7f93f5cea7\
+        39acbbabb04b935c7efb850ec31bed6cd79163e54c223947f580700d991d5f90\
+        f3f20c6c5c474f8f5eca45c5433fa7ec7e2efbcc2ab89d0ac530b73caac08c1e\
+        11b3a49e220175c79d4f8b9f0b262e18d9ef55b5be147af0b9b67a77cf869cee\
+        d955be9d2c1ce8651362609cc6adae9f9b892adcc84aee5100b500ee75d0413c\
+        2922b98f47e013af62a55a96bca2538f8113b3cc40633e12e3e088a46b1a3152\
+        cfc93f7014e9d094efb3ba2fdd46aff7d36fc62e91258d21b7ef5ba2b3430cc1\
+        352fb80d6134e8051fc2c9035ec792e5f161125f11bdeff382dd773c108c47c4\
+        71e736b37592508b38cda20b1aff993a6e93410c9d94201d04d136128d7498e0\
+        d4d97a8605465d9700ed10a665c02c16c34e65d13f911f301d5e22c6e8c1a370\
+        60b5ee4435daa884821d5554645ac0ee3cd5bba522cdab408e9ada13b4652a8b\
+        a914cc649fa0e8257d63bfc9d676fa4a18ef9b1a029f72e15336a85c3193b6b2\
+        b39d2199f0ed7fc2eac63f132f47bc625027db44f374c192078fe174ae0fbdb8\
+        6ce937733268b1abe7027a148cd611737164796d9b6a6e69b21e05ccfbcf067e\
+        63aa6ca94436aefccd1a19c35499ffd628fbc3c218eef8d83526515a7271879a\
+        e8c87f1c9997aa8184c4325353ac00d339d7fc66e540da01e8ca05944032461a\
+        13a2552c5082dea96861d0bfdee48e36f700533de8e87a0cb0f93524d57a09a4\
+        eebd300c68a9975e0002403f2cb722efb0454f50db9311c0805906173f046061\
+        80e6e8a672ab4edbe3a579348856de28175bcea08b1e5e39bb9263be645024bd\
+        6f3ea26de4861020e41be83149ab5908a354463d01ba2b5539e046a66eae9457\
+        3cb8ea7a12faff6adf344252bf4578ca6455015a2994dd660d307d78fecf6d72\
+        f1e3a6f7a6e22544affb8f5377cefe86c651d62547f4a0f8dace20b02dbb9154\
+        362d5f012e0dc6b5cdcaebc32fd2294ed2dff76b7bb00ed47251989fab6d8f74\
+        6a3d46ddeab02ef3972c45163d43b5122da563ced1c1e9928c436ad37b81ec4f\
+        7b7d3b296be0d6100c680a08543925b16c433adbd45a08c56e71d26852d2dac5\
+        749dde0f03f4b31dec415d6958fb9463e2d3b94c659f5082d8294210d51253a2\
+        74dd2e612204d5cca844b71cc92a694cbbcacebf9ec7f78a9b4e4520590c54db\
+        cf0b1c700fa41f358ed316c0abda6cccb7d1392ad35a6a15cfc751def69d03be\
+        0c53f24694db5621fdd5c534b641fc3bf9c0d8e0938974e5e0b61555f594fdda\
+        49b5700b0e6b905df5867e66ff6e427a17cd8ee6e343e7a66290a8ef2507d982\
+        19b05a5b15e51fbaefb21b0f348c66c7aae6ab39370ced295ddef21e144b4fc1\
+        e053951b35a1b1a54c801e6b3ee8695f67a694d8df4f7556614d18817ebc02b0\
+        c5d78f1db5cdb8a82f6ee4b3a1e0fc0040082b536c0c6c38e4f1efea31bbc55b\
+        a13a344f610acb13f64590836c56f21f8b8db9c26135dc2d5bb703d5b89072bd\
+        5c6cc11b84776c153e02532dcfeaa8b25f641e1689f417fea89ef72aa217e50b\
+        a4c1c83ebfa3c844f7877d162ad0e67c549f5827a3c9cfa72827d7e88b2f8431\
+        52356c9cafcdab218fbda01c772355d8f797f343caec74973f9ec45df2687fd2\
+        c2932ba173fefa0503793004f90703b17e6f1e284112f03146736ead99c387bf\
+        39e6e61fe939c299c9bdf1fa95c36b4fcd327c4aa4db2a3b3550c4be82845b79\
+        caa3bee871717b2a687b1ead60f22dc6bfa3b3378ab90107542bb955a1157346\
+        a49f0535d01c5c209a20eec6002e52ffbae945bd2eccced3fd73065a521e593d\
+        25ed83154c7a32dbd3a9ef01e5ce24fbfca7d712fdf8a6f6f66ca135d0bf3b03\
+        2f6ae2c47bc4d80aa6646082f541ed715446d5cfc2600a9e9561f1e031285eeb\
+        f9757478f7f1e2055d36469b6915df7bc556478ee9a0814931756d308c922435\
+        eea09785e45601586492b91c27fd34b9c4ca36c4ccfafdecc56c6943b07fc316\
+        cb214a2e866a67f3e24f8b9779a7054b5517a368a05bca37466749b3b32f0af3\
+        8b8f1d2cbc198e74066a8e59be92ce3634b7f82ea89ebcdb33da06bdbde74332\
+        03620490206ae12ffa522bff50a4e7931c24bedc78e36cdf629c8d287febf519\
+        cb89d182a764156da53466bc570856577d257d15a4033de88824418c4693a9cd\
+        14df8b0a5804cb221e550b1bbf54eea78470b59ef9b67054d79ed9b0a24c0330\
+        f3b0579a2843e658189bf4ec18d089ada09aa4edf94ed1c6dea63b19aa3c7d29\
+        a0e6d734a846f6cf88c646c0b0ae52bed10cc27d5f40c05a77b95922ee2f3840\
+        9f8d8a3f5d4d491b7ab9fdd6b7762b2aa9333c9ca27988b5c1fffa9e4c169fc9\
+        030d09668298eae0e0b7d1d9624bf47ad302ebfb452fd34992b6fdfffe4d5fea\
+        d1e43be519bf2a633313b0abfccea65348878928266ae27e5b4368a9c09aaf5e\
+        eafd7ea51a7a754903335c7264d68387612e4c44421b438777811b18f717c27e\
+        1720b4dda81619419bcd575c264f1c86d37cdac009e6655f386e9e65f5b5d4e2\
+        5dcc291c050f99ab685d566d76e397f52b3977aeccb6e5c2aecf89d19e0ddf88\
+        ca9542b6f30313069282605a132b55fea2607e0a3a42714a25363e11ff4fb51f\
+        20cdd291310726c23a8dfdd59796f74053d8af6754ad98d47b4b3b5601b21b1d\
+        27d18b2cc16b15f7813f61674e09311a3965c0f75cf0d6c196badb890d20c1ec\
+        af8e438be6260a963d20f05bc944aaf2e8a63d8d5e7a59aee0beec95d5dd4d19\
+        b48f7574bb7650903a1529b19873a5f4da0d16ca08661f2a542bdd3bf5b7a24c\
+        bded5664ea4b04bd62bcf3f867b60131502f55815c18834c234d5db86b1b45e5\
+        c65169834a8d4ef0278a940ce665301ae2df7ab554ffc58f2ff02685a9f6ebab\
+        dcf308ac3ffe0a61b923aa8d6cd876230edd6cc63a9b08d067d57646cbd9ba52\
+        864313bca7f76ce1ea8c66eb61d6466776ec1a44990cc381c992ccb7cdadf6c9\
+        e84c137c079e91b573b37e32aaf7591e45971e015a8782c68543bb1bde63ad7c\
+        0819868bbbd126f0a0f3f76d1e659b977690a08144a91b2fa9af11598e0a7297\
+        e07ef779ce117008fb1f04dd661f2ee4056c28ff6c227edab8314cfd16a5adfe\
+        01a87f2b57a6e76727b90df64a24fc4ed369af4d4156e40df838740e33e1fcb2\
+        f98c81b7df0c6a70737c157709894600c21cbca79ec85c2ce40f4721a375e285\
+        26cb3728",
+    ];
+
+    // XMSS-SHA2_10_192
+    static KAT_XMSS_SHA2_10_192: [&str; 4] = [
+        "0000000d00000000000000002dd531a0d3679ab6ece314a0c19ed7b6ef6502c0\
+        a19ae893126c416a7b2d87c8214b021884395e88d8b11cc1319db32bff80651b\
+        655440c29c4f8c44cff827186201048a795c23a7",
+        "0000000ddfee7003383a9856b5a927ad8894838e63df18211e6b116dff80651b\
+        655440c29c4f8c44cff827186201048a795c23a7",
+        "b338dd755d5618c464ab331f14de3dd4a358bba00d28fb35236741e902f7b248\
+        ce",
+        "00000000d4682686e4bc708328567694bbc69086bd1aa7abdb02b96ec681e846\
+        a4d195f39a1778dbc444c0efc1913d7f18c11574a52f398ea01422342540b8dd\
+        40ef5a7f95b462f457bb8c1b9af469fa550580c6a8dea3b364dbec7c11dfcf71\
+        dfae8e413c1a1589b8ec1e840ebb2f37f24b3e9331c5c13f8b3b73c0766930d1\
+        703645214a8a9b28e580b1bd0a6c051d809018b239d5eaa5dd1dde5060b662dc\
+        5997ae84b78290f8b13e6b4ab470321dbc8e30f62bbeb7a3fe083be727014e1f\
+        13ac06b85920818da1120929ba8ee3336bf89b45c57db634b524fe15ed395f69\
+        19ef0fc1fe5bcb12c478cdc8aa262dc4aad9b45fa6f1ade212a6e7a78988b7a5\
+        372e8e00ec3fbdafe00d7b60064b132d4a6526539a24c5ca5659d610851fb5fd\
+        af08f1e65720745019c6505ac7ded6387adf498c25aa330837505d86566a3d01\
+        617067b9ac895d9f88b5cca99f2c28763af20770ee0c27d89b5ebfa1aafec65c\
+        6d64211d7ccde4958db702389d32f0a7bc08a141fd0989af84c20faf47000aa3\
+        fa596a1728dcc2e6e59336658ac65601a635cb5be10c2e6e46c0b128dc3d5f99\
+        9751db25679736b0793e1cfbbf7cf8224d9e6a48c173352f8e0b8c4b94a42251\
+        ed4c07595d790076831aa749c476e13d7d2e7ed3ce2a872eb73e0739d20d3da9\
+        34ef8de1b7c3b0a8b3eeda0112dc0a14b59edf322de876897e4b2b3af7967e80\
+        eb0f52c235bcf802e53ba73a738b277fc5029e1b7859d19ef39ac7ba4d149836\
+        84bdb1d3ad01ad74ce10739be6db38720d9c72d516855a5e75293e250edfef37\
+        239780cee7a812c9e2dc8b7a8630707248d518137873b08728e85ed8b420b9c4\
+        0614d204b073e52be35cdc8a54c0d5d94413a9890e068be915eda550fb654ae6\
+        58dbaa9300244659ffd68b53f6a3e987ede8e93fedc2a188b41364f1ac068ca3\
+        b2761882755ad185e9cf286eec8fcc0f2c6144e464a73065c376bf868d423753\
+        bcdc24476b6b347c0d4706ebaccd8624ed05a8a6cd292dbf02293f7b8e875201\
+        53a4d484e10dae81505afbf1ad60fcba896be5dda4c3335add505b6956dad9d7\
+        be9fbea8d067d9c5e1102041576c1584f3a067839368e5ec784998fb60a6d029\
+        e333b8efe4a9cfe607873fab91fc070c5eb6a013939d832c0efa885139a6312b\
+        f2a415b0b85f3573c6a2cc8fc4270daa29909d70a8a3a4807e2a22b5985837b1\
+        83d907fc21c02e84e0baefb464b8deab1195a92ff583c08c20037f2d3410c637\
+        db3cb034c689b5fb46c3db55cd48f30298fa6fb74755dfd200c7dfa6bfbc98b5\
+        864491066ca36ea5a928189768e0d253131c38012c4c1a2ab6b34728dfe8fe58\
+        6cbd4cc4782233f2e56c81fa452a8dc37152928a30261f4facc057bcc9108326\
+        366c4f5b4773e589ccf574fde739078f83277ef0aa60de831f156af362f69b60\
+        d8e6585af4aaea2ac7e7541691b63d15c727b66b87ac24e5352faf2e2f4f7163\
+        9ab264ab3436d3a1d9abfadf758906fd82e4bb2e602eb8819558430426cd14e5\
+        f3555a5379041aec1e0a46f068e9e827564b25fd13e0825c341e639320c9c07c\
+        a6502bcf857659e39f54ddcbdf12cc440aac2e404465656fbba6b44d457c2592\
+        65008e1ba24f671a738575bf9e99dc75ebfcd61947c96912f1cc003ef7d6960b\
+        461a8d35687e198702adae606ab11dc89b437bdd61dda603c89e0dab6355c5b0\
+        5b98546626b8981a8c6f852b10a31c0b4369cb7f3526a60d5a694c810350bc11\
+        669fac54d37d333d7d5226ebe3c2c662f1debc10c3e3a1e3757dc500612edb25\
+        7fcd3b880f8d819a815cc288f5b7b09acbb658a489f767c79beabba8b5291c31\
+        34928d5f27c4645fafacf77c16dc7c70da356b9c24272a346aecdfaed9555121\
+        33e303a7327c6f3ccf37cd6e2be5a858cdb88ee802152092dbdfe0cb8350d2b0\
+        cbec212549e24fb82c3a98ce1032c09344867163ad35dc45612c1f91a86c29aa\
+        ba053bc329530415aaf376e55223aff201bb179895ad9cbe08c56f15fa72b9b4\
+        9f16981e519a61fb2ef2eed066b67d5386c2d41b76bba61cb85e4cb4dab96203\
+        ff5a9edf880ddefe7b3fc94e7344f3c04454cd07",
+    ];
+
+    // XMSS-SHAKE256_10_192
+    static KAT_XMSS_SHAKE256_10_192: [&str; 4] = [
+        "00000013000000000000000090d31ca2483030376a8eafb11754f7f8e6c065df\
+        2e9deecd4255ceb90c8729910fb0e3ca8630bc2ee5c02979cab59c5adaf99fc4\
+        89a89297f443ab348784b6e568b83cda69e5039e",
+        "00000013169573bfa70085b1fcb20c54b1a7e1854125ff73e7e3906ddaf99fc4\
+        89a89297f443ab348784b6e568b83cda69e5039e",
+        "b338dd755d5618c464ab331f14de3dd4a358bba00d28fb35236741e902f7b248\
+        ce",
+        "00000000ab78c376b92dba4cdeb56adec9e559d3300a3a4303654d96ac79259d\
+        9e0889681de0a14f6d7b4d4377a548cede7e204938a0b383f54d42f1ec6c722d\
+        f31dfc861881dbacf33e2f6ca641a7592a2c93457b2714cdfc2a4bd483ab85f5\
+        0e2ba9a4c852145312960dcd6b5515318d8c8da2ea4465d4aeaf37e6bacace7f\
+        d577dd9010ec0fb7770c3b65c97e89535a7707ee294c1a1b11584405f563c79b\
+        a331df838e8517743052011d261ae87009b97d3f62c78cb40862f611a5b87651\
+        e551f10e5de23ea1684782a66a587c8b0de8b2c68783bf71f86411406efafb85\
+        1da5987955f16d46f3c550a8a794e0b571e996053649373c69fa4fddec16a6f7\
+        89eaabd499828b0c302dde570101ba82100e47c60c60f799c367b0eb6e24f659\
+        ca07bb38d328dbbfb661dab4bdf389d7fabc78f69eca685e1079da6c958aa428\
+        8570d29a95f1fd4f05ca308b8f5b165cf69de6c9f7c0c7b6f5c9ac9339ad7538\
+        3c232dee13ebf76bcee260fb80d2aefd018510b75d13b9fff54ec6c5a5dec0f7\
+        2c8e315d68464174572a7d65ce06251cfece255de8438af73982d08057049067\
+        a491edd8bb9798a9c3230a0c1eebd72a5bbb0533db68817c84077b5473f36997\
+        f26bf6843a187c7038a256146f74c0ab07999a69b8d842a1455659f228a139a0\
+        8692a8a4906fc55ed49e8193209f34129a21eca72eb0ddab4e6e77f240dbf23b\
+        db187a623cfda18e89ff18c7cf1a75df6e8e00265c9beae6825ed1fcd283c9f9\
+        544b583e5e9ba7f54269d1a0235bdb8c19f26fb25062053545cd5439a7ebc423\
+        37f2ae6d54e0bb96ab0ff8a893315844b52eee85c7408e0e7c5750768a205dce\
+        54aa7f444f92de9a9dd61e0e34381e7c235a6b279b477a0ff880d13efd96277e\
+        8a4f794c2d8e455528d33a6fabf0ad513d4116f715362ce85169e24bf8033c9a\
+        5e499f1daf50e3ad12132a89bf720b8f62d981216393c23f975b388b474c9705\
+        0e94e40a1a2374c404ea0049158c1c7dac83cec3c0d6211495e0aab82e834377\
+        a7e1e78789a7378e5ca0c8b76c2e7d5c0f0c82e7a8000f0191e660a436ba4b34\
+        ca27fdf1381b94330fa1e005dd1af3ec042f388335bdc52fa48a17d6d593e884\
+        3ea6f24228259988b4a094b8d45537130fc52d6acee13319782a7e1e7a742212\
+        4fa3603c96ad29d7117cc38c4bb18d2978281b3fb87b634f561aaea47dfe9219\
+        ac9829a85a642b2287373b35550eb3d7e4e1226c3fb944ae8612cca14c7d438f\
+        3069e23bb44b108201552eb002b0a004c9195c2571971dfccd6df08c92f136f0\
+        834076ed52809bd8e56b33a2402c1f0309640928313e24944acb6c47760cd01b\
+        4956236e8524429a95d5aa5ff0f70ed62d5871f64568ed9e8aaf1dd50012cf0b\
+        a9d31ae7b8eed6ffcf9d23c9c660cbaba284a88595ef8d275c6e023e3dbe318d\
+        ea096f910cb9bf1c80777da4a436b12a2eabc12429989fb2fe7d432a717d7f1b\
+        3ead0067b9c8315e4cdbde2992a579e5cab2ff056227e546d004dc6438fc7d62\
+        b4bb09cd64de415c58dca0c3ac075cd8d428856748cbef91057d5c8c50ef4783\
+        dfa2a33c445a0aa4621604bf72262904a2096c90553d6987a7c51d807d92ad78\
+        b87969c92e3506b69c4cbd2a738db178079f3a47f06a8fa5876d1dab516992dc\
+        abe1d14ce7e3ebd62a008a33c29273b1e0aac76e167cdf9ad08724fcfcf9ecaa\
+        06fd8ad132f4f7e8a81cf1ed956d4b66e24c9061622aaf54db02f74c5b88ec91\
+        e478987722c6d157560b27358d178f04a1375a750453a62ec564c784ad807645\
+        1b0c1795a8ed33ed5e9cf6b1cdec434290109cea337a5b00fc4fcd573fcc4308\
+        b898f7932aad9819f6a49bc0de7c797d9d5996c44434724660560cb4696c83d5\
+        f2faea74617ddd9ef334c9c0d5375f47dba7ec26808db817331a6c9218bdc496\
+        5b18088e055bb176c6387a9dcde935ff52465aa501df704bd6a9560d7e9f8eaa\
+        a43035708d8bbf813970cd37076fcae8bcd3517f9b4b15cff732acc7a565d3e6\
+        8a62eee0802016cf8d3f383206dbdf7ea482a3dbbaee7ae0e7c323f9dc2a518b\
+        941b2f90baa5b531349e9b54314eed182a900abb",
+    ];
+
+    macro_rules! check_kat { ($name:ident, $kat:ident) => { {
+        use $name::{PrivateKey, PublicKey};
+
+        let esk = hex::decode($kat[0]).unwrap();
+        let epk = hex::decode($kat[1]).unwrap();
+        let msg = hex::decode($kat[2]).unwrap();
+        let esig = hex::decode($kat[3]).unwrap();
+        let mut sk = PrivateKey::decode(&esk).unwrap();
+        assert!(sk.compute_public().encode()[..] == epk[..]);
+        let sig = sk.reserve(1, |_| true).unwrap().sign(&msg).unwrap();
+        assert!(sig[..] == esig[..]);
+        let pk = PublicKey::decode(&epk).unwrap();
+        assert!(pk.verify(&esig, &msg));
+        assert!(!pk.verify(&esig, &msg[1..]));
+    } } }
+
+    #[test]
+    fn kat_xmss() {
+        check_kat!(XMSS_SHA2_10_256, KAT_XMSS_SHA2_10_256);
+        check_kat!(XMSS_SHAKE256_10_256, KAT_XMSS_SHAKE256_10_256);
+        check_kat!(XMSS_SHA2_10_192, KAT_XMSS_SHA2_10_192);
+        check_kat!(XMSS_SHAKE256_10_192, KAT_XMSS_SHAKE256_10_192);
+    }
+
+    #[test]
+    fn kat_xmssmt() {
+        check_kat!(XMSSMT_SHA2_20_2_256, KAT_XMSSMT_SHA2_20_2_256);
+    }
+
+    #[test]
+    fn xmss() {
+        use XMSS_SHA2_10_192::{PrivateKey, PublicKey};
+
+        // Start just before the end of the first bottom subtree.
+        let mut enc = [0u8; PrivateKey::ENC_LEN];
+        enc[0..4].copy_from_slice(&0x0Du32.to_be_bytes());
+        enc[4..12].copy_from_slice(&30u64.to_be_bytes());
+        for (i, b) in enc.iter_mut().enumerate().skip(12) {
+            *b = i as u8;
+        }
+        let mut sk = PrivateKey::decode(&enc).unwrap();
+        assert!(sk.encode() == enc);
+        assert!(sk.remaining() == 1024 - 30);
+        let epk = sk.compute_public().encode();
+        assert!(epk.len() == 4 + 48 && epk[0..4] == enc[0..4]);
+        let pk = PublicKey::decode(&epk).unwrap();
+        assert!(PublicKey::decode(&epk[1..]).is_none());
+        let mut bad = epk;
+        bad[3] = 0x0E;
+        assert!(PublicKey::decode(&bad).is_none());
+
+        let mut stored = [0u8; PrivateKey::ENC_LEN];
+        let msg = b"sample";
+        let mut sigs = [[0u8; 4 + 24 + 51 * 24 + 10 * 24]; 5];
+        {
+            let mut res = sk.reserve(5, |e| { stored = *e; true }).unwrap();
+            for sig in sigs.iter_mut() {
+                *sig = res.sign(msg).unwrap();
+            }
+            assert!(res.sign(msg).is_none());
+        }
+        assert!(stored == sk.encode());
+        assert!(sk.remaining() == 1024 - 35);
+        for (j, sig) in sigs.iter().enumerate() {
+            assert!(sig[0..4] == (30 + j as u32).to_be_bytes());
+            assert!(pk.verify(sig, msg));
+            assert!(!pk.verify(sig, b"other"));
+            assert!(!pk.verify(&sig[1..], msg));
+            let mut bad = *sig;
+            bad[100] ^= 1;
+            assert!(!pk.verify(&bad, msg));
+        }
+
+        // Failed commits, invalid reservations and invalid encodings.
+        assert!(sk.reserve(1, |_| false).is_none());
+        assert!(sk.remaining() == 1024 - 35);
+        assert!(sk.reserve(0, |_| true).is_none());
+        assert!(sk.reserve(1024, |_| true).is_none());
+        assert!(PrivateKey::decode(&enc[1..]).is_none());
+        let mut bad = enc;
+        bad[3] = 0x01;
+        assert!(PrivateKey::decode(&bad).is_none());
+        bad = enc;
+        bad[4..12].copy_from_slice(&1025u64.to_be_bytes());
+        assert!(PrivateKey::decode(&bad).is_none());
+    }
+
+    #[test]
+    fn xmssmt() {
+        use XMSSMT_SHA2_20_4_256::{PrivateKey, PublicKey};
+
+        let mut rng = CRNG(0);
+        let mut sk = PrivateKey::generate(&mut rng);
+        let pk = PublicKey::decode(&sk.compute_public().encode()).unwrap();
+        let msg = b"sample";
+        let siglen = 3 + 32 + 4 * (67 * 32 + 5 * 32);
+        let sig = sk.reserve(1, |_| true).unwrap().sign(msg).unwrap();
+        assert!(sig.len() == siglen);
+        assert!(sig[0..3] == [0, 0, 0]);
+        assert!(pk.verify(&sig, msg));
+        assert!(!pk.verify(&sig, b"other"));
+
+        // Skip to the end of the first bottom tree, and sign across
+        // the tree boundary.
+        let mut stored = [0u8; PrivateKey::ENC_LEN];
+        assert!(sk.reserve(29, |e| { stored = *e; true }).is_some());
+        let mut sigs = [[0u8; 3 + 32 + 4 * (67 * 32 + 5 * 32)]; 4];
+        {
+            let mut res = sk.reserve(4, |_| true).unwrap();
+            for (j, sig) in sigs.iter_mut().enumerate() {
+                *sig = res.sign(msg).unwrap();
+                assert!(sig[0..3] == [0, 0, 30 + j as u8]);
+                assert!(pk.verify(sig, msg));
+            }
+        }
+
+        // Signatures are deterministic: a decoded copy of the key yields
+        // the same signatures.
+        let mut sk2 = PrivateKey::decode(&stored).unwrap();
+        let mut res = sk2.reserve(4, |_| true).unwrap();
+        for sig in sigs.iter() {
+            assert!(res.sign(msg).unwrap() == *sig);
+        }
+    }
+
+    #[test]
+    fn xmssmt_shake() {
+        use XMSSMT_SHAKE256_20_4_192::PrivateKey;
+
+        let mut rng = CRNG(1);
+        let mut sk = PrivateKey::generate(&mut rng);
+        let pk = sk.compute_public();
+        let epk = pk.encode();
+        assert!(epk[0..4] == 0x32u32.to_be_bytes());
+        let msg = b"sample";
+        let mut res = sk.reserve(2, |_| true).unwrap();
+        for _ in 0..2 {
+            let sig = res.sign(msg).unwrap();
+            assert!(pk.verify(&sig, msg));
+            assert!(!pk.verify(&sig, b"other"));
+        }
+    }
+}